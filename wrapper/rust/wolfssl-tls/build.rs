@@ -0,0 +1,301 @@
+extern crate bindgen;
+
+use regex::Regex;
+use std::env;
+use std::fs;
+use std::io::{self, Read, Result};
+use std::path::{Path,PathBuf};
+
+/// Perform crate build.
+fn main() {
+    if let Err(e) = run_build() {
+        eprintln!("Build failed: {}", e);
+        std::process::exit(1);
+    }
+}
+
+/// Perform all build steps.
+///
+/// Returns `Ok(())` if successful, or an error if any step fails.
+fn run_build() -> Result<()> {
+    generate_bindings()?;
+    setup_wolfssl_link()?;
+    scan_cfg()?;
+    Ok(())
+}
+
+fn crate_dir() -> Result<String> {
+    Ok(std::env::current_dir()?.display().to_string())
+}
+
+fn wolfssl_repo_base_dir() -> Result<String> {
+    Ok(format!("{}/../../..", crate_dir()?))
+}
+
+fn wolfssl_repo_lib_dir() -> Result<String> {
+    Ok(format!("{}/src/.libs", wolfssl_repo_base_dir()?))
+}
+
+fn wolfssl_user_prefix() -> Option<String> {
+    match env::var("WOLFSSL_PREFIX") {
+        Ok(prefix) => {
+            if !prefix.is_empty() && !prefix.contains('\n') {
+                Some(prefix)
+            } else {
+                println!("cargo:warning=ignoring WOLFSSL_PREFIX");
+                None
+            }
+        }
+        Err(_) => None,
+    }
+}
+
+/// Returns the include directory for wolfssl headers.
+///
+/// If `WOLFSSL_PREFIX` is set, returns `{WOLFSSL_PREFIX}/include`.
+/// Otherwise falls back to the repo root if it exists (for in-tree host builds).
+fn wolfssl_include_dir() -> Result<Option<String>> {
+    if let Some(prefix) = wolfssl_user_prefix() {
+        let include_dir = format!("{}/include", prefix);
+        let wolfssl_dir = Path::new(&include_dir).join("wolfssl");
+        if !wolfssl_dir.is_dir() {
+            println!("cargo:warning=WOLFSSL_PREFIX is set but {} is not a directory", wolfssl_dir.display());
+            return Ok(None);
+        }
+        Ok(Some(include_dir))
+    } else {
+        let base = wolfssl_repo_base_dir()?;
+        let base_path = Path::new(&base);
+        // Treat this as an in-tree wolfSSL repo only if the expected layout exists.
+        let wolfssl_dir = base_path.join("wolfssl");
+        let wolfssl_options = wolfssl_dir.join("options.h");
+        if wolfssl_options.is_file() {
+            Ok(Some(base))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// Returns the library directory for libwolfssl.
+///
+/// If `WOLFSSL_PREFIX` is set, returns `{WOLFSSL_PREFIX}/lib`.
+/// Otherwise falls back to the in-tree build output directory if it exists.
+fn wolfssl_lib_dir() -> Result<Option<String>> {
+    if let Some(prefix) = wolfssl_user_prefix() {
+        let lib_dir = format!("{}/lib", prefix);
+        let lib_path = Path::new(&lib_dir);
+        if !lib_path.is_dir() {
+            println!("cargo:warning=WOLFSSL_PREFIX is set but {} is not a directory", lib_dir);
+            return Ok(None);
+        }
+        Ok(Some(lib_dir))
+    } else {
+        let repo_lib_dir = wolfssl_repo_lib_dir()?;
+        if Path::new(&repo_lib_dir).exists() {
+            Ok(Some(repo_lib_dir))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+fn bindings_path() -> String {
+    PathBuf::from(env::var("OUT_DIR").unwrap()).join("bindings.rs").display().to_string()
+}
+
+/// Map a Rust target triple to the equivalent clang target triple.
+///
+/// Rust triples embed ISA extensions in the arch component
+/// (e.g. `riscv64imac-unknown-none-elf`) while clang uses only the base arch
+/// (e.g. `riscv64-unknown-elf`).  Bare-metal targets use `<arch>-<vendor>-elf`
+/// in clang convention.
+fn rust_target_to_clang_target(rust_target: &str) -> String {
+    let parts: Vec<&str> = rust_target.splitn(4, '-').collect();
+    if parts.len() < 3 {
+        return rust_target.to_string();
+    }
+
+    // Strip ISA extensions: riscv64imac → riscv64, riscv32imac → riscv32
+    let arch = if parts[0].starts_with("riscv64") {
+        "riscv64"
+    } else if parts[0].starts_with("riscv32") {
+        "riscv32"
+    } else {
+        parts[0]
+    };
+
+    let vendor = parts[1];
+    let os     = parts[2];
+    let abi    = parts.get(3).copied().unwrap_or("");
+
+    // Bare-metal: (os=none, abi=elf) → <arch>-<vendor>-elf
+    if os == "none" && abi == "elf" {
+        format!("{}-{}-elf", arch, vendor)
+    } else if abi.is_empty() {
+        format!("{}-{}-{}", arch, vendor, os)
+    } else {
+        format!("{}-{}-{}-{}", arch, vendor, os, abi)
+    }
+}
+
+/// Return the sysroot path for a bare-metal clang target triple, if it exists.
+///
+/// Queries the cross-compiler for its sysroot via `--print-sysroot` rather
+/// than assuming a fixed install prefix.  Tries the candidate compiler names
+/// `<arch>-<vendor>-elf-gcc` and `<arch>-elf-gcc` (vendor omitted) in order.
+/// Returns `None` if no suitable compiler is found or its sysroot is invalid.
+fn bare_metal_sysroot(clang_target: &str) -> Option<String> {
+    let parts: Vec<&str> = clang_target.splitn(3, '-').collect();
+    if parts.len() < 3 || !clang_target.ends_with("-elf") {
+        return None;
+    }
+    let (arch, vendor) = (parts[0], parts[1]);
+    let candidates = [
+        format!("{}-{}-elf-gcc", arch, vendor),
+        format!("{}-elf-gcc", arch),
+    ];
+    for compiler in &candidates {
+        if let Ok(output) = std::process::Command::new(compiler)
+                .arg("--print-sysroot")
+                .output()
+                && output.status.success() {
+            let sysroot = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            if !sysroot.is_empty() && sysroot != "/" && Path::new(&sysroot).exists() {
+                return Some(sysroot);
+            }
+        }
+    }
+    None
+}
+
+/// Generate Rust bindings for the wolfssl C library using bindgen.
+///
+/// Returns `Ok(())` if successful, or an error if binding generation fails.
+fn generate_bindings() -> Result<()> {
+    let mut builder = bindgen::Builder::default()
+        .header("headers.h")
+        .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
+        .use_core();
+
+    if let Some(include_dir) = wolfssl_include_dir()? {
+        builder = builder.clang_arg(format!("-I{}", include_dir));
+    }
+
+    // When cross-compiling, tell clang the target so it generates correct
+    // type layouts and evaluates architecture-specific preprocessor guards.
+    let target = env::var("TARGET").unwrap();
+    let host = env::var("HOST").unwrap();
+    if target != host {
+        let clang_target = rust_target_to_clang_target(&target);
+        builder = builder.clang_arg(format!("--target={}", clang_target));
+
+        if target.ends_with("-none-elf") {
+            // For bare-metal targets, add the toolchain C runtime headers
+            // (newlib's time.h etc.) using -idirafter so they appear after
+            // clang's own built-in includes.  This lets clang's stdatomic.h
+            // take priority over newlib's incompatible version.
+            if let Some(sysroot) = bare_metal_sysroot(&clang_target) {
+                builder = builder
+                    .clang_arg("-ffreestanding")
+                    .clang_arg(format!("-idirafter{}/include", sysroot));
+            }
+        }
+    }
+
+    let bindings = builder
+        .generate()
+        .map_err(|_| io::Error::other("Failed to generate bindings"))?;
+
+    bindings
+        .write_to_file(bindings_path())
+        .map_err(|e| {
+            io::Error::other(format!("Couldn't write bindings: {}", e))
+        })
+}
+
+/// Instruct cargo to link against wolfssl C library
+///
+/// Returns `Ok(())` if successful, or an error if any step fails.
+fn setup_wolfssl_link() -> Result<()> {
+    if let Some(lib_dir) = wolfssl_lib_dir()? {
+        println!("cargo:rustc-link-search={}", lib_dir);
+
+        // Prefer a shared library if present, otherwise fall back to static.
+        let has_shared = Path::new(&lib_dir).join("libwolfssl.so").exists()
+            || Path::new(&lib_dir).join("libwolfssl.dylib").exists();
+        if has_shared {
+            println!("cargo:rustc-link-lib=wolfssl");
+            // Only set rpath where a dynamic linker exists (not bare-metal).
+            let target = env::var("TARGET").unwrap();
+            if !target.ends_with("-none-elf") {
+                println!("cargo:rustc-link-arg=-Wl,-rpath,{}", lib_dir);
+            }
+        } else {
+            println!("cargo:rustc-link-lib=static=wolfssl");
+        }
+    } else {
+        // No local lib dir found; rely on whatever is installed system-wide.
+        println!("cargo:rustc-link-lib=wolfssl");
+    }
+
+    Ok(())
+}
+
+fn read_file(path: String) -> Result<String> {
+    let mut file = fs::File::open(path)?;
+    let mut content = String::new();
+    file.read_to_string(&mut content)?;
+    Ok(content)
+}
+
+fn check_cfg(binding: &str, function_name: &str, cfg_name: &str) -> bool {
+    let pattern = format!(r"\b{}(_fips)?\b", function_name);
+    let re = match Regex::new(&pattern) {
+        Ok(r) => r,
+        Err(e) => {
+            eprintln!("Error compiling regex '{}': {}", pattern, e);
+            std::process::exit(1);
+        }
+    };
+    println!("cargo::rustc-check-cfg=cfg({})", cfg_name);
+    if re.is_match(binding) {
+        println!("cargo:rustc-cfg={}", cfg_name);
+        true
+    } else {
+        false
+    }
+}
+
+fn scan_cfg() -> Result<()> {
+    let binding = read_file(bindings_path())?;
+
+    /* protocol versions */
+    check_cfg(&binding, "wolfTLS_client_method", "tls_client");
+    check_cfg(&binding, "wolfTLS_server_method", "tls_server");
+    check_cfg(&binding, "wolfTLSv1_2_method", "tls12");
+    check_cfg(&binding, "wolfTLSv1_3_method", "tls13");
+
+    /* dtls */
+    check_cfg(&binding, "wolfDTLS_method", "dtls");
+    check_cfg(&binding, "wolfDTLSv1_2_method", "dtls12");
+    check_cfg(&binding, "wolfSSL_dtls13_has_pending_msg", "dtls13");
+    check_cfg(&binding, "wolfSSL_dtls_set_mtu", "dtls_mtu");
+
+    /* certificate loading */
+    check_cfg(&binding, "wolfSSL_CTX_load_verify_buffer", "certs_buffer");
+
+    /* certificate manager */
+    check_cfg(&binding, "wolfSSL_CertManagerNew_ex", "cert_manager");
+    check_cfg(&binding, "wolfSSL_CertManagerSetVerify", "cm_verify_cb");
+    check_cfg(&binding, "DecodedCRL", "crl");
+    check_cfg(&binding, "wc_SetTimeCb", "verify_time");
+    check_cfg(&binding, "wc_MakeCert", "cert_gen");
+
+    /* extensions */
+    check_cfg(&binding, "wolfSSL_UseSNI", "sni");
+    check_cfg(&binding, "wolfSSL_UseALPN", "alpn");
+    check_cfg(&binding, "wolfSSL_check_domain_name", "domain_name_check");
+
+    Ok(())
+}
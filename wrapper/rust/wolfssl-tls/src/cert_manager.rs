@@ -0,0 +1,611 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+This module provides a Rust wrapper for the wolfSSL library's
+`WOLFSSL_CERT_MANAGER` object.
+
+The [`CertManager`] struct verifies X.509 certificates against a set of
+trusted CA certificates outside of a TLS handshake. It supports verifying a
+leaf certificate through a chain of untrusted intermediates, checking
+certificates against loaded CRLs, verifying at a fixed point in time (with
+the `global-verify-time` feature), and a Rust closure verify callback that
+can inspect and override failures.
+
+# Examples
+
+```rust
+use std::fs;
+use wolfssl_tls::WolfCrypt;
+use wolfssl_tls::cert_manager::CertManager;
+
+let ca = fs::read("../../../certs/ca-cert.pem").expect("Error reading CA");
+let cert = fs::read("../../../certs/server-cert.pem").expect("Error reading cert");
+let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+let mut cm = CertManager::new(&wolfcrypt).expect("Error with CertManager::new()");
+cm.load_ca_buffer(&ca, CertManager::FILETYPE_PEM)
+    .expect("Error with load_ca_buffer()");
+cm.verify_buffer(&cert, CertManager::FILETYPE_PEM)
+    .expect("Error with verify_buffer()");
+```
+*/
+
+use crate::{Env, Error, WolfCrypt, WolfSsl};
+use crate::context::check_success;
+use crate::sys;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use std::cell::Cell;
+#[cfg(cm_verify_cb)]
+use std::ffi::c_int;
+use wolfssl_wolfcrypt::memory::HeapHint;
+
+/// Verification state shared with the C callbacks for the duration of a
+/// single certificate manager call.
+struct VerifyState {
+    callback: Option<Box<dyn FnMut(i32, i32) -> bool>>,
+    depth: i32,
+    #[cfg(all(verify_time, feature = "global-verify-time"))]
+    time: Option<i64>,
+}
+
+thread_local! {
+    /// State of the `CertManager` call currently running on this thread.
+    ///
+    /// Neither the verify callback nor the time callback carry a user data
+    /// pointer, so the state is made available to them through this
+    /// thread-local while the call is in progress.
+    static ACTIVE: Cell<*mut VerifyState> = const { Cell::new(core::ptr::null_mut()) };
+}
+
+#[cfg(cm_verify_cb)]
+unsafe extern "C" fn verify_cb(preverify_ok: c_int,
+        store: *mut sys::WOLFSSL_X509_STORE_CTX) -> c_int {
+    if preverify_ok != 0 {
+        return preverify_ok;
+    }
+    let state = ACTIVE.with(|a| a.get());
+    if state.is_null() || store.is_null() {
+        return 0;
+    }
+    let state = unsafe { &mut *state };
+    let error = unsafe { (*store).error };
+    match state.callback.as_mut() {
+        // Failures of intermediates are reported but cannot be overridden:
+        // an accepted intermediate would be trusted as a CA for the rest of
+        // the chain.
+        Some(callback) => (callback(state.depth, error) && state.depth == 0) as c_int,
+        None => 0,
+    }
+}
+
+#[cfg(all(verify_time, feature = "global-verify-time"))]
+unsafe extern "C" fn verify_time_cb(t: *mut sys::time_t) -> sys::time_t {
+    let state = ACTIVE.with(|a| a.get());
+    let time = if state.is_null() { None } else { unsafe { (*state).time } };
+    let now = time.unwrap_or_else(|| {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0)
+    }) as sys::time_t;
+    if !t.is_null() {
+        unsafe { *t = now; }
+    }
+    now
+}
+
+/// Rust wrapper for a wolfSSL `WOLFSSL_CERT_MANAGER` object.
+///
+/// Manages the lifecycle of the underlying certificate manager, including
+/// deallocation via the [`Drop`] trait. The certificate manager cannot
+/// outlive the memory pool of the heap hint it was created with (`'h`).
+///
+/// This struct does not implement Send or Sync because the verify callback
+/// is not required to be thread safe.
+pub struct CertManager<'h> {
+    cm: *mut sys::WOLFSSL_CERT_MANAGER,
+    state: VerifyState,
+    _wolfssl: WolfSsl,
+    _heap: PhantomData<HeapHint<'h>>,
+}
+
+impl<'h> CertManager<'h> {
+    /// PEM encoded certificate or CRL buffer.
+    pub const FILETYPE_PEM: i32 = sys::WOLFSSL_FILETYPE_PEM as i32;
+    /// DER (ASN.1) encoded certificate or CRL buffer.
+    pub const FILETYPE_ASN1: i32 = sys::WOLFSSL_FILETYPE_ASN1 as i32;
+
+    /// Check the CRL status of every certificate that is verified.
+    pub const CRL_CHECK: i32 = sys::WOLFSSL_CRL_CHECK as i32;
+    /// Check the CRL status of every certificate in a TLS peer chain rather
+    /// than only the leaf. Must be combined with [`CertManager::CRL_CHECK`].
+    pub const CRL_CHECKALL: i32 = sys::WOLFSSL_CRL_CHECKALL as i32;
+
+    /// Create a new `CertManager` with no trusted CA certificates.
+    ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library. The certificate
+    ///   manager holds a clone of it.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(CertManager) containing the certificate manager
+    /// instance or Err(e) containing the wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use wolfssl_tls::WolfCrypt;
+    /// use wolfssl_tls::cert_manager::CertManager;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let cm = CertManager::new(&wolfcrypt).expect("Error with CertManager::new()");
+    /// ```
+    pub fn new(wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&Env::new(wolfcrypt))
+    }
+
+    /// Create a new `CertManager` in the given environment.
+    ///
+    /// The memory of the certificate manager is allocated with the heap
+    /// hint of `env`, so it cannot outlive its memory pool.
+    ///
+    /// # Parameters
+    ///
+    /// * `env`: Library guard and heap hint to use. The device is not used.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(CertManager) containing the certificate manager
+    /// instance or Err(e) containing the wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use wolfssl_tls::{Env, WolfCrypt};
+    /// use wolfssl_tls::cert_manager::CertManager;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let cm = CertManager::new_ex(&Env::new(&wolfcrypt))
+    ///     .expect("Error with CertManager::new_ex()");
+    /// ```
+    pub fn new_ex(env: &Env<'_, 'h>) -> Result<Self, Error> {
+        let wolfssl = WolfSsl::new(env.wolfcrypt())?;
+        let heap = env.heap().map_or(core::ptr::null_mut(), HeapHint::as_ptr);
+        let cm = unsafe { sys::wolfSSL_CertManagerNew_ex(heap) };
+        if cm.is_null() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_MEMORY_E));
+        }
+        let state = VerifyState {
+            callback: None,
+            depth: 0,
+            #[cfg(all(verify_time, feature = "global-verify-time"))]
+            time: None,
+        };
+        Ok(CertManager { cm, state, _wolfssl: wolfssl, _heap: PhantomData })
+    }
+
+    /// Run a certificate manager operation with this manager's verification
+    /// state made available to the C callbacks.
+    fn call<F: FnOnce(*mut sys::WOLFSSL_CERT_MANAGER) -> i32>(&mut self,
+            depth: i32, f: F) -> i32 {
+        self.state.depth = depth;
+        let cm = self.cm;
+        let prev = ACTIVE.with(|a| a.replace(&mut self.state));
+        let rc = f(cm);
+        ACTIVE.with(|a| a.set(prev));
+        rc
+    }
+
+    /// Load trusted CA certificates (trust anchors) from a buffer.
+    ///
+    /// The signatures of the CA certificates are not verified. The validity
+    /// dates are checked against the verification time.
+    ///
+    /// # Parameters
+    ///
+    /// * `buf`: Buffer containing one or more CA certificates.
+    /// * `format`: [`CertManager::FILETYPE_PEM`] or
+    ///   [`CertManager::FILETYPE_ASN1`].
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::fs;
+    /// use wolfssl_tls::WolfCrypt;
+    /// use wolfssl_tls::cert_manager::CertManager;
+    /// let ca = fs::read("../../../certs/ca-cert.der").expect("Error reading CA");
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut cm = CertManager::new(&wolfcrypt).expect("Error with CertManager::new()");
+    /// cm.load_ca_buffer(&ca, CertManager::FILETYPE_ASN1)
+    ///     .expect("Error with load_ca_buffer()");
+    /// ```
+    pub fn load_ca_buffer(&mut self, buf: &[u8], format: i32) -> Result<(), Error> {
+        let rc = self.call(0, |cm| unsafe {
+            sys::wolfSSL_CertManagerLoadCABuffer(cm, buf.as_ptr(),
+                buf.len() as core::ffi::c_long, format)
+        });
+        check_success(rc)
+    }
+
+    /// Unload all trusted CA certificates.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn unload_cas(&mut self) -> Result<(), Error> {
+        let rc = unsafe { sys::wolfSSL_CertManagerUnloadCAs(self.cm) };
+        check_success(rc)
+    }
+
+    /// Verify a certificate against the loaded CA certificates.
+    ///
+    /// The certificate must be issued directly by a loaded CA certificate;
+    /// use [`CertManager::verify_chain()`] to verify through intermediates.
+    /// If CRL checking is enabled the certificate is also checked against
+    /// the loaded CRLs.
+    ///
+    /// # Parameters
+    ///
+    /// * `buf`: Buffer containing the certificate.
+    /// * `format`: [`CertManager::FILETYPE_PEM`] or
+    ///   [`CertManager::FILETYPE_ASN1`].
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) if the certificate is valid or Err(e)
+    /// containing the wolfSSL library error code value.
+    pub fn verify_buffer(&mut self, buf: &[u8], format: i32) -> Result<(), Error> {
+        self.verify_at_depth(buf, format, 0)
+    }
+
+    fn verify_at_depth(&mut self, buf: &[u8], format: i32, depth: i32) -> Result<(), Error> {
+        let rc = self.call(depth, |cm| unsafe {
+            sys::wolfSSL_CertManagerVerifyBuffer(cm, buf.as_ptr(),
+                buf.len() as core::ffi::c_long, format)
+        });
+        check_success(rc)
+    }
+
+    /// Verify a certificate chain against the loaded CA certificates.
+    ///
+    /// `certs` holds the leaf certificate first, followed by any untrusted
+    /// intermediate CA certificates in issuing order (as sent by a TLS
+    /// peer). Each intermediate is verified starting from the one closest to
+    /// the trust anchor, then temporarily trusted to verify the certificate
+    /// below it. The intermediates are unloaded again before this function
+    /// returns, whether verification succeeded or not.
+    ///
+    /// Each intermediate must be a CA certificate (basicConstraints
+    /// CA:TRUE) with the keyCertSign key usage, and its path length
+    /// constraint must allow the intermediates below it. `NOT_CA_ERROR`,
+    /// `ASN_NO_KEYUSAGE` or `ASN_PATHLEN_SIZE_E` is returned otherwise.
+    ///
+    /// The verify callback, if set, receives the index of the failing
+    /// certificate in `certs` as its depth. Only a failure of the leaf
+    /// certificate can be overridden by the callback.
+    ///
+    /// # Parameters
+    ///
+    /// * `certs`: Leaf certificate followed by intermediate certificates.
+    /// * `format`: [`CertManager::FILETYPE_PEM`] or
+    ///   [`CertManager::FILETYPE_ASN1`].
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) if the chain is valid or Err(e) containing the
+    /// wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::fs;
+    /// use wolfssl_tls::WolfCrypt;
+    /// use wolfssl_tls::cert_manager::CertManager;
+    /// let ca = fs::read("../../../certs/ca-cert.pem").expect("Error reading CA");
+    /// let int1 = fs::read("../../../certs/intermediate/ca-int-cert.pem").expect("Error reading cert");
+    /// let int2 = fs::read("../../../certs/intermediate/ca-int2-cert.pem").expect("Error reading cert");
+    /// let leaf = fs::read("../../../certs/intermediate/server-int-cert.pem").expect("Error reading cert");
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut cm = CertManager::new(&wolfcrypt).expect("Error with CertManager::new()");
+    /// cm.load_ca_buffer(&ca, CertManager::FILETYPE_PEM)
+    ///     .expect("Error with load_ca_buffer()");
+    /// cm.verify_chain(&[&leaf, &int2, &int1], CertManager::FILETYPE_PEM)
+    ///     .expect("Error with verify_chain()");
+    /// ```
+    pub fn verify_chain(&mut self, certs: &[&[u8]], format: i32) -> Result<(), Error> {
+        if certs.is_empty() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+        }
+        let result = self.verify_chain_temp(certs, format);
+        let rc = unsafe {
+            sys::wolfSSL_CertManagerUnloadTypeCerts(self.cm, sys::WOLFSSL_TEMP_CA as u8)
+        };
+        result?;
+        check_success(rc)
+    }
+
+    fn verify_chain_temp(&mut self, certs: &[&[u8]], format: i32) -> Result<(), Error> {
+        for (depth, cert) in certs.iter().enumerate().skip(1).rev() {
+            // Convert to DER so that only the certificate checked below is
+            // loaded, even if the buffer holds more than one PEM certificate.
+            let der = cert_to_der(cert, format)?;
+            check_intermediate(&der, depth - 1)?;
+            let depth = depth as i32;
+            self.verify_at_depth(&der, Self::FILETYPE_ASN1, depth)?;
+            let rc = self.call(depth, |cm| unsafe {
+                sys::wolfSSL_CertManagerLoadCABufferType(cm, der.as_ptr(),
+                    der.len() as core::ffi::c_long, Self::FILETYPE_ASN1, 0,
+                    sys::WOLFSSL_LOAD_FLAG_NONE,
+                    sys::WOLFSSL_TEMP_CA as i32)
+            });
+            check_success(rc)?;
+        }
+        self.verify_at_depth(certs[0], format, 0)
+    }
+
+    /// Enable CRL checking for certificates verified by this manager.
+    ///
+    /// # Parameters
+    ///
+    /// * `options`: Bitwise OR of [`CertManager::CRL_CHECK`] and
+    ///   [`CertManager::CRL_CHECKALL`].
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value. `NOT_COMPILED_IN` is returned if the
+    /// wolfSSL library was built without CRL support.
+    pub fn enable_crl(&mut self, options: i32) -> Result<(), Error> {
+        let rc = unsafe { sys::wolfSSL_CertManagerEnableCRL(self.cm, options) };
+        check_success(rc)
+    }
+
+    /// Disable CRL checking. Loaded CRLs are kept.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn disable_crl(&mut self) -> Result<(), Error> {
+        let rc = unsafe { sys::wolfSSL_CertManagerDisableCRL(self.cm) };
+        check_success(rc)
+    }
+
+    /// Load a CRL from a buffer.
+    ///
+    /// The CRL signature is verified against the loaded CA certificates.
+    /// CRL checking is enabled with [`CertManager::CRL_CHECK`] if it has
+    /// not been enabled yet.
+    ///
+    /// # Parameters
+    ///
+    /// * `buf`: Buffer containing the CRL.
+    /// * `format`: [`CertManager::FILETYPE_PEM`] or
+    ///   [`CertManager::FILETYPE_ASN1`].
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::fs;
+    /// use wolfssl_tls::WolfCrypt;
+    /// use wolfssl_tls::cert_manager::CertManager;
+    /// let ca = fs::read("../../../certs/ca-cert.pem").expect("Error reading CA");
+    /// let crl = fs::read("../../../certs/crl/crl.pem").expect("Error reading CRL");
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut cm = CertManager::new(&wolfcrypt).expect("Error with CertManager::new()");
+    /// cm.load_ca_buffer(&ca, CertManager::FILETYPE_PEM)
+    ///     .expect("Error with load_ca_buffer()");
+    /// cm.load_crl_buffer(&crl, CertManager::FILETYPE_PEM)
+    ///     .expect("Error with load_crl_buffer()");
+    /// ```
+    #[cfg(crl)]
+    pub fn load_crl_buffer(&mut self, buf: &[u8], format: i32) -> Result<(), Error> {
+        let rc = self.call(0, |cm| unsafe {
+            sys::wolfSSL_CertManagerLoadCRLBuffer(cm, buf.as_ptr(),
+                buf.len() as core::ffi::c_long, format)
+        });
+        check_success(rc)
+    }
+
+    /// Check a certificate against the loaded CRLs.
+    ///
+    /// Only the revocation status is checked; the certificate signature
+    /// and validity dates are not. Does nothing if CRL checking is not
+    /// enabled.
+    ///
+    /// # Parameters
+    ///
+    /// * `der`: DER encoded certificate.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) if the certificate is not revoked or Err(e)
+    /// containing the wolfSSL library error code value (`CRL_CERT_REVOKED`
+    /// if the certificate has been revoked).
+    #[cfg(crl)]
+    pub fn check_crl(&mut self, der: &[u8]) -> Result<(), Error> {
+        let der_size = crate::buffer_len_to_i32(der.len())?;
+        let rc = self.call(0, |cm| unsafe {
+            sys::wolfSSL_CertManagerCheckCRL(cm, der.as_ptr(), der_size)
+        });
+        check_success(rc)
+    }
+
+    /// Set the time used to check certificate and CRL validity dates.
+    ///
+    /// By default the current system time is used. The time applies to all
+    /// calls on this `CertManager` made after it is set, including loading
+    /// CA certificates and CRLs.
+    ///
+    /// # Global state
+    ///
+    /// wolfSSL has no per-manager verification time, so the first call with
+    /// a time installs a process-wide wolfCrypt time callback
+    /// (`wc_SetTimeCb()`). The callback returns the configured time during
+    /// calls on a `CertManager` with a verification time set, and the
+    /// current system time for every other wolfSSL call in the process,
+    /// including TLS handshakes. It replaces any time callback installed
+    /// elsewhere and any time source configured in the wolfSSL library
+    /// build, and stays installed for the lifetime of the process. This
+    /// function is therefore only available with the `global-verify-time`
+    /// crate feature.
+    ///
+    /// # Parameters
+    ///
+    /// * `time`: Verification time in seconds since the Unix epoch, or None
+    ///   to use the current time.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use wolfssl_tls::WolfCrypt;
+    /// use wolfssl_tls::cert_manager::CertManager;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut cm = CertManager::new(&wolfcrypt).expect("Error with CertManager::new()");
+    /// cm.set_verify_time(Some(1_800_000_000));
+    /// ```
+    #[cfg(all(verify_time, feature = "global-verify-time"))]
+    pub fn set_verify_time(&mut self, time: Option<i64>) {
+        static INSTALL: std::sync::Once = std::sync::Once::new();
+        if time.is_some() {
+            INSTALL.call_once(|| unsafe {
+                sys::wc_SetTimeCb(Some(verify_time_cb));
+            });
+        }
+        self.state.time = time;
+    }
+
+    /// Set a callback that is called when certificate verification fails.
+    ///
+    /// The callback receives the depth of the failing certificate (0 for
+    /// the leaf, or its index in the slice passed to
+    /// [`CertManager::verify_chain()`]) and the wolfSSL library error code
+    /// value. Returning true overrides the failure and accepts the
+    /// certificate; returning false keeps the error. The return value is
+    /// ignored for intermediates (depth greater than 0), which are always
+    /// rejected on failure.
+    ///
+    /// # Parameters
+    ///
+    /// * `callback`: Closure called with `(depth, error)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use wolfssl_tls::WolfCrypt;
+    /// use wolfssl_tls::cert_manager::CertManager;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut cm = CertManager::new(&wolfcrypt).expect("Error with CertManager::new()");
+    /// cm.set_verify_callback(|depth, error| {
+    ///     eprintln!("certificate at depth {} failed: {}", depth, error);
+    ///     false
+    /// });
+    /// ```
+    #[cfg(cm_verify_cb)]
+    pub fn set_verify_callback<F>(&mut self, callback: F)
+    where
+        F: FnMut(i32, i32) -> bool + 'static,
+    {
+        self.state.callback = Some(Box::new(callback));
+        unsafe { sys::wolfSSL_CertManagerSetVerify(self.cm, Some(verify_cb)); }
+    }
+
+    /// Remove the verify callback set with
+    /// [`CertManager::set_verify_callback()`].
+    #[cfg(cm_verify_cb)]
+    pub fn clear_verify_callback(&mut self) {
+        unsafe { sys::wolfSSL_CertManagerSetVerify(self.cm, None); }
+        self.state.callback = None;
+    }
+}
+
+/// Convert the first certificate in `cert` to DER.
+fn cert_to_der(cert: &[u8], format: i32) -> Result<Vec<u8>, i32> {
+    if format != CertManager::FILETYPE_PEM {
+        return Ok(cert.to_vec());
+    }
+    let pem_size = crate::buffer_len_to_i32(cert.len())?;
+    let mut der = vec![0u8; cert.len()];
+    let rc = unsafe {
+        sys::wc_CertPemToDer(cert.as_ptr(), pem_size, der.as_mut_ptr(), pem_size,
+            sys::CertType_CERT_TYPE as i32)
+    };
+    if rc < 0 {
+        return Err(rc);
+    }
+    der.truncate(rc as usize);
+    Ok(der)
+}
+
+/// Check that the DER certificate `der` may act as an intermediate CA with
+/// `below` intermediate CA certificates between it and the leaf.
+///
+/// wolfSSL does not check the basicConstraints of certificates loaded as
+/// temporary CAs, so the certificate must be CA:TRUE with the keyCertSign
+/// key usage and a path length constraint of at least `below`.
+fn check_intermediate(der: &[u8], below: usize) -> Result<(), i32> {
+    let der_size = u32::try_from(der.len())
+        .map_err(|_| sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG)?;
+    let mut cert: MaybeUninit<sys::DecodedCert> = MaybeUninit::uninit();
+    unsafe {
+        sys::wc_InitDecodedCert(cert.as_mut_ptr(), der.as_ptr(), der_size,
+            core::ptr::null_mut());
+    }
+    let mut cert = unsafe { cert.assume_init() };
+    let rc = unsafe {
+        sys::wc_ParseCert(&mut cert, sys::CertType_CERT_TYPE as i32,
+            sys::VerifyType_NO_VERIFY as i32, core::ptr::null_mut())
+    };
+    let result = if rc != 0 {
+        Err(rc)
+    } else if cert.extBasicConstSet() == 0 || cert.isCA() == 0 {
+        Err(sys::wolfSSL_ErrorCodes_NOT_CA_ERROR)
+    } else if cert.extKeyUsageSet() == 0
+            || cert.extKeyUsage & sys::KEYUSE_KEY_CERT_SIGN as u16 == 0 {
+        Err(sys::wolfCrypt_ErrorCodes_ASN_NO_KEYUSAGE)
+    } else if cert.pathLengthSet() != 0 && below > cert.pathLength as usize {
+        Err(sys::wolfCrypt_ErrorCodes_ASN_PATHLEN_SIZE_E)
+    } else {
+        Ok(())
+    };
+    unsafe { sys::wc_FreeDecodedCert(&mut cert); }
+    result
+}
+
+impl Drop for CertManager<'_> {
+    /// Safely free the underlying wolfSSL certificate manager.
+    ///
+    /// This calls the `wolfSSL_CertManagerFree()` wolfssl library function.
+    fn drop(&mut self) {
+        unsafe { sys::wolfSSL_CertManagerFree(self.cm); }
+    }
+}
@@ -0,0 +1,487 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+This module provides a Rust wrapper for the wolfSSL library's `WOLFSSL_CTX`
+object.
+
+The primary component is the [`Context`] struct, which holds the
+configuration shared by every [`crate::session::Session`] created from it:
+protocol version, trusted CA certificates, the local certificate and private
+key, peer verification mode and cipher suite list.
+
+# Examples
+
+```rust
+use std::fs;
+use wolfssl_tls::WolfCrypt;
+use wolfssl_tls::context::{Context, Method};
+
+let ca = fs::read("../../../certs/ca-cert.pem").expect("Error reading CA");
+let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+let mut ctx = Context::new(Method::TlsClient, &wolfcrypt).expect("Error with Context::new()");
+ctx.load_verify_buffer(&ca, Context::FILETYPE_PEM)
+    .expect("Error with load_verify_buffer()");
+```
+*/
+
+use crate::{Env, Error, WolfCrypt, WolfSsl};
+use crate::sys;
+use core::marker::PhantomData;
+use std::ffi::CString;
+use wolfssl_wolfcrypt::memory::HeapHint;
+
+/// TLS protocol method used to create a [`Context`].
+///
+/// The `Tls*` methods negotiate the highest protocol version supported by
+/// both peers; the version-specific methods only accept that exact version.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Method {
+    /// Client, highest mutually supported TLS version.
+    #[cfg(tls_client)]
+    TlsClient,
+    /// Server, highest mutually supported TLS version.
+    #[cfg(tls_server)]
+    TlsServer,
+    /// Client, TLS 1.2 only.
+    #[cfg(all(tls12, tls_client))]
+    Tls12Client,
+    /// Server, TLS 1.2 only.
+    #[cfg(all(tls12, tls_server))]
+    Tls12Server,
+    /// Client, TLS 1.3 only.
+    #[cfg(all(tls13, tls_client))]
+    Tls13Client,
+    /// Server, TLS 1.3 only.
+    #[cfg(all(tls13, tls_server))]
+    Tls13Server,
+    /// DTLS client, highest mutually supported DTLS version.
+    #[cfg(all(dtls, tls_client))]
+    DtlsClient,
+    /// DTLS server, highest mutually supported DTLS version.
+    #[cfg(all(dtls, tls_server))]
+    DtlsServer,
+    /// Client, DTLS 1.2 only.
+    #[cfg(all(dtls12, tls_client))]
+    Dtls12Client,
+    /// Server, DTLS 1.2 only.
+    #[cfg(all(dtls12, tls_server))]
+    Dtls12Server,
+    /// Client, DTLS 1.3 only.
+    #[cfg(all(dtls13, tls_client))]
+    Dtls13Client,
+    /// Server, DTLS 1.3 only.
+    #[cfg(all(dtls13, tls_server))]
+    Dtls13Server,
+}
+
+impl Method {
+    /// Return whether this method creates server-side sessions.
+    pub fn is_server(&self) -> bool {
+        match self {
+            #[cfg(tls_client)]
+            Method::TlsClient => false,
+            #[cfg(tls_server)]
+            Method::TlsServer => true,
+            #[cfg(all(tls12, tls_client))]
+            Method::Tls12Client => false,
+            #[cfg(all(tls12, tls_server))]
+            Method::Tls12Server => true,
+            #[cfg(all(tls13, tls_client))]
+            Method::Tls13Client => false,
+            #[cfg(all(tls13, tls_server))]
+            Method::Tls13Server => true,
+            #[cfg(all(dtls, tls_client))]
+            Method::DtlsClient => false,
+            #[cfg(all(dtls, tls_server))]
+            Method::DtlsServer => true,
+            #[cfg(all(dtls12, tls_client))]
+            Method::Dtls12Client => false,
+            #[cfg(all(dtls12, tls_server))]
+            Method::Dtls12Server => true,
+            #[cfg(all(dtls13, tls_client))]
+            Method::Dtls13Client => false,
+            #[cfg(all(dtls13, tls_server))]
+            Method::Dtls13Server => true,
+        }
+    }
+
+    /// Return whether this method creates DTLS (datagram) sessions.
+    ///
+    /// DTLS sessions must be used with [`crate::dtls::DtlsSession`] rather
+    /// than [`crate::session::Session`].
+    pub fn is_dtls(&self) -> bool {
+        match self {
+            #[cfg(all(dtls, tls_client))]
+            Method::DtlsClient => true,
+            #[cfg(all(dtls, tls_server))]
+            Method::DtlsServer => true,
+            #[cfg(all(dtls12, tls_client))]
+            Method::Dtls12Client => true,
+            #[cfg(all(dtls12, tls_server))]
+            Method::Dtls12Server => true,
+            #[cfg(all(dtls13, tls_client))]
+            Method::Dtls13Client => true,
+            #[cfg(all(dtls13, tls_server))]
+            Method::Dtls13Server => true,
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+
+    fn to_wolfssl(self, heap: *mut core::ffi::c_void) -> *mut sys::WOLFSSL_METHOD {
+        unsafe {
+            match self {
+                #[cfg(tls_client)]
+                Method::TlsClient => sys::wolfTLS_client_method_ex(heap),
+                #[cfg(tls_server)]
+                Method::TlsServer => sys::wolfTLS_server_method_ex(heap),
+                #[cfg(all(tls12, tls_client))]
+                Method::Tls12Client => sys::wolfTLSv1_2_client_method_ex(heap),
+                #[cfg(all(tls12, tls_server))]
+                Method::Tls12Server => sys::wolfTLSv1_2_server_method_ex(heap),
+                #[cfg(all(tls13, tls_client))]
+                Method::Tls13Client => sys::wolfTLSv1_3_client_method_ex(heap),
+                #[cfg(all(tls13, tls_server))]
+                Method::Tls13Server => sys::wolfTLSv1_3_server_method_ex(heap),
+                #[cfg(all(dtls, tls_client))]
+                Method::DtlsClient => sys::wolfDTLS_client_method_ex(heap),
+                #[cfg(all(dtls, tls_server))]
+                Method::DtlsServer => sys::wolfDTLS_server_method_ex(heap),
+                #[cfg(all(dtls12, tls_client))]
+                Method::Dtls12Client => sys::wolfDTLSv1_2_client_method_ex(heap),
+                #[cfg(all(dtls12, tls_server))]
+                Method::Dtls12Server => sys::wolfDTLSv1_2_server_method_ex(heap),
+                #[cfg(all(dtls13, tls_client))]
+                Method::Dtls13Client => sys::wolfDTLSv1_3_client_method_ex(heap),
+                #[cfg(all(dtls13, tls_server))]
+                Method::Dtls13Server => sys::wolfDTLSv1_3_server_method_ex(heap),
+            }
+        }
+    }
+}
+
+/// Rust wrapper for a wolfSSL `WOLFSSL_CTX` object.
+///
+/// Manages the lifecycle of the underlying context, including deallocation
+/// via the [`Drop`] trait. The C library reference counts the context, so
+/// sessions created from a `Context` remain valid after it is dropped.
+/// The context and its sessions cannot outlive the memory pool of the heap
+/// hint they were created with (`'h`).
+///
+/// This struct does not implement Send or Sync because the configuration
+/// functions of the underlying C API are not safe to call from multiple
+/// threads concurrently.
+pub struct Context<'h> {
+    pub(crate) ctx: *mut sys::WOLFSSL_CTX,
+    method: Method,
+    pub(crate) wolfssl: WolfSsl,
+    _heap: PhantomData<HeapHint<'h>>,
+}
+
+impl<'h> Context<'h> {
+    /// PEM encoded certificate or key buffer.
+    pub const FILETYPE_PEM: i32 = sys::WOLFSSL_FILETYPE_PEM as i32;
+    /// DER (ASN.1) encoded certificate or key buffer.
+    pub const FILETYPE_ASN1: i32 = sys::WOLFSSL_FILETYPE_ASN1 as i32;
+
+    /// Do not verify the peer certificate.
+    pub const VERIFY_NONE: i32 = sys::WOLFSSL_VERIFY_NONE as i32;
+    /// Verify the peer certificate if one is presented.
+    pub const VERIFY_PEER: i32 = sys::WOLFSSL_VERIFY_PEER as i32;
+    /// Server only: fail the handshake if the client does not present a
+    /// certificate. Must be combined with [`Context::VERIFY_PEER`].
+    pub const VERIFY_FAIL_IF_NO_PEER_CERT: i32 =
+        sys::WOLFSSL_VERIFY_FAIL_IF_NO_PEER_CERT as i32;
+
+    /// TLS 1.2 protocol version for [`Context::set_min_version()`].
+    pub const VERSION_TLS12: i32 = sys::WOLFSSL_TLSV1_2 as i32;
+    /// TLS 1.3 protocol version for [`Context::set_min_version()`].
+    pub const VERSION_TLS13: i32 = sys::WOLFSSL_TLSV1_3 as i32;
+    /// DTLS 1.2 protocol version for [`Context::set_min_version()`].
+    pub const VERSION_DTLS12: i32 = sys::WOLFSSL_DTLSV1_2 as i32;
+    /// DTLS 1.3 protocol version for [`Context::set_min_version()`].
+    pub const VERSION_DTLS13: i32 = sys::WOLFSSL_DTLSV1_3 as i32;
+
+    /// Create a new `Context` using the given protocol method.
+    ///
+    /// # Parameters
+    ///
+    /// * `method`: Protocol method, e.g. [`Method::TlsClient`].
+    /// * `wolfcrypt`: Guard of the initialized library. The context and
+    ///   each session created from it hold a clone of it.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(Context) containing the context instance or Err(e)
+    /// containing the wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use wolfssl_tls::WolfCrypt;
+    /// use wolfssl_tls::context::{Context, Method};
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let ctx = Context::new(Method::TlsServer, &wolfcrypt).expect("Error with Context::new()");
+    /// ```
+    pub fn new(method: Method, wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(method, &Env::new(wolfcrypt))
+    }
+
+    /// Create a new `Context` using the given protocol method in the given
+    /// environment.
+    ///
+    /// The memory of the context and of the sessions created from it is
+    /// allocated with the heap hint of `env`, so they cannot outlive its
+    /// memory pool.
+    ///
+    /// # Parameters
+    ///
+    /// * `method`: Protocol method, e.g. [`Method::TlsClient`].
+    /// * `env`: Library guard and heap hint to use. The device is not used.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(Context) containing the context instance or Err(e)
+    /// containing the wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use wolfssl_tls::{Env, WolfCrypt};
+    /// use wolfssl_tls::context::{Context, Method};
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let ctx = Context::new_ex(Method::TlsClient, &Env::new(&wolfcrypt))
+    ///     .expect("Error with Context::new_ex()");
+    /// ```
+    pub fn new_ex(method: Method, env: &Env<'_, 'h>) -> Result<Self, Error> {
+        let wolfssl = WolfSsl::new(env.wolfcrypt())?;
+        let heap = env.heap().map_or(core::ptr::null_mut(), HeapHint::as_ptr);
+        let ws_method = method.to_wolfssl(heap);
+        if ws_method.is_null() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_MEMORY_E));
+        }
+        // wolfSSL_CTX_new_ex() takes ownership of the method and frees it on
+        // failure.
+        let ctx = unsafe { sys::wolfSSL_CTX_new_ex(ws_method, heap) };
+        if ctx.is_null() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_MEMORY_E));
+        }
+        Ok(Context { ctx, method, wolfssl, _heap: PhantomData })
+    }
+
+    /// Return the protocol method this context was created with.
+    pub fn method(&self) -> Method {
+        self.method
+    }
+
+    /// Load trusted CA certificates from a buffer.
+    ///
+    /// # Parameters
+    ///
+    /// * `buf`: Buffer containing one or more CA certificates.
+    /// * `format`: [`Context::FILETYPE_PEM`] or [`Context::FILETYPE_ASN1`].
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::fs;
+    /// use wolfssl_tls::WolfCrypt;
+    /// use wolfssl_tls::context::{Context, Method};
+    /// let ca = fs::read("../../../certs/ca-cert.der").expect("Error reading CA");
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut ctx = Context::new(Method::TlsClient, &wolfcrypt).expect("Error with Context::new()");
+    /// ctx.load_verify_buffer(&ca, Context::FILETYPE_ASN1)
+    ///     .expect("Error with load_verify_buffer()");
+    /// ```
+    #[cfg(certs_buffer)]
+    pub fn load_verify_buffer(&mut self, buf: &[u8], format: i32) -> Result<(), Error> {
+        let rc = unsafe {
+            sys::wolfSSL_CTX_load_verify_buffer(self.ctx, buf.as_ptr(),
+                buf.len() as core::ffi::c_long, format)
+        };
+        check_success(rc)
+    }
+
+    /// Load the local certificate from a buffer.
+    ///
+    /// # Parameters
+    ///
+    /// * `buf`: Buffer containing the certificate.
+    /// * `format`: [`Context::FILETYPE_PEM`] or [`Context::FILETYPE_ASN1`].
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::fs;
+    /// use wolfssl_tls::WolfCrypt;
+    /// use wolfssl_tls::context::{Context, Method};
+    /// let cert = fs::read("../../../certs/server-cert.pem").expect("Error reading cert");
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut ctx = Context::new(Method::TlsServer, &wolfcrypt).expect("Error with Context::new()");
+    /// ctx.use_certificate_buffer(&cert, Context::FILETYPE_PEM)
+    ///     .expect("Error with use_certificate_buffer()");
+    /// ```
+    #[cfg(certs_buffer)]
+    pub fn use_certificate_buffer(&mut self, buf: &[u8], format: i32) -> Result<(), Error> {
+        let rc = unsafe {
+            sys::wolfSSL_CTX_use_certificate_buffer(self.ctx, buf.as_ptr(),
+                buf.len() as core::ffi::c_long, format)
+        };
+        check_success(rc)
+    }
+
+    /// Load the local certificate chain from a PEM buffer.
+    ///
+    /// The buffer must contain the leaf certificate first, followed by any
+    /// intermediate CA certificates.
+    ///
+    /// # Parameters
+    ///
+    /// * `buf`: Buffer containing the PEM certificate chain.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    #[cfg(certs_buffer)]
+    pub fn use_certificate_chain_buffer(&mut self, buf: &[u8]) -> Result<(), Error> {
+        let rc = unsafe {
+            sys::wolfSSL_CTX_use_certificate_chain_buffer(self.ctx, buf.as_ptr(),
+                buf.len() as core::ffi::c_long)
+        };
+        check_success(rc)
+    }
+
+    /// Load the local private key from a buffer.
+    ///
+    /// # Parameters
+    ///
+    /// * `buf`: Buffer containing the private key.
+    /// * `format`: [`Context::FILETYPE_PEM`] or [`Context::FILETYPE_ASN1`].
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::fs;
+    /// use wolfssl_tls::WolfCrypt;
+    /// use wolfssl_tls::context::{Context, Method};
+    /// let key = fs::read("../../../certs/server-key.pem").expect("Error reading key");
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut ctx = Context::new(Method::TlsServer, &wolfcrypt).expect("Error with Context::new()");
+    /// ctx.use_private_key_buffer(&key, Context::FILETYPE_PEM)
+    ///     .expect("Error with use_private_key_buffer()");
+    /// ```
+    #[cfg(certs_buffer)]
+    pub fn use_private_key_buffer(&mut self, buf: &[u8], format: i32) -> Result<(), Error> {
+        let rc = unsafe {
+            sys::wolfSSL_CTX_use_PrivateKey_buffer(self.ctx, buf.as_ptr(),
+                buf.len() as core::ffi::c_long, format)
+        };
+        check_success(rc)
+    }
+
+    /// Set the peer certificate verification mode.
+    ///
+    /// # Parameters
+    ///
+    /// * `mode`: Bitwise OR of [`Context::VERIFY_NONE`],
+    ///   [`Context::VERIFY_PEER`] and [`Context::VERIFY_FAIL_IF_NO_PEER_CERT`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use wolfssl_tls::WolfCrypt;
+    /// use wolfssl_tls::context::{Context, Method};
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut ctx = Context::new(Method::TlsServer, &wolfcrypt).expect("Error with Context::new()");
+    /// ctx.set_verify(Context::VERIFY_PEER | Context::VERIFY_FAIL_IF_NO_PEER_CERT);
+    /// ```
+    pub fn set_verify(&mut self, mode: i32) {
+        unsafe { sys::wolfSSL_CTX_set_verify(self.ctx, mode, None) };
+    }
+
+    /// Set the list of allowed cipher suites.
+    ///
+    /// # Parameters
+    ///
+    /// * `list`: Colon separated list of cipher suite names, e.g.
+    ///   `"TLS13-AES128-GCM-SHA256:TLS13-CHACHA20-POLY1305-SHA256"`.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn set_cipher_list(&mut self, list: &str) -> Result<(), Error> {
+        let list = CString::new(list).map_err(|_| sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG)?;
+        let rc = unsafe { sys::wolfSSL_CTX_set_cipher_list(self.ctx, list.as_ptr()) };
+        check_success(rc)
+    }
+
+    /// Set the minimum protocol version accepted for sessions created with
+    /// a version-flexible [`Method`].
+    ///
+    /// # Parameters
+    ///
+    /// * `version`: One of the `Context::VERSION_*` constants matching the
+    ///   protocol family (TLS or DTLS) of this context.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn set_min_version(&mut self, version: i32) -> Result<(), Error> {
+        let rc = unsafe { sys::wolfSSL_CTX_SetMinVersion(self.ctx, version) };
+        check_success(rc)
+    }
+}
+
+/// Map a wolfSSL API return value that uses `WOLFSSL_SUCCESS` to a Result.
+pub(crate) fn check_success(rc: i32) -> Result<(), Error> {
+    if rc != sys::WOLFSSL_SUCCESS as i32 {
+        return Err(Error::from(rc));
+    }
+    Ok(())
+}
+
+impl Drop for Context<'_> {
+    /// Safely free the underlying wolfSSL context.
+    ///
+    /// This calls the `wolfSSL_CTX_free()` wolfssl library function, which
+    /// drops this reference to the reference counted context.
+    fn drop(&mut self) {
+        unsafe { sys::wolfSSL_CTX_free(self.ctx); }
+    }
+}
@@ -0,0 +1,428 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+This module provides a Rust wrapper for DTLS 1.2 and DTLS 1.3 sessions.
+
+A [`DtlsSession`] is created from a [`Context`] using one of the DTLS
+[`crate::context::Method`] values and owns a [`DatagramTransport`] over which
+DTLS records are exchanged. [`DatagramTransport`] is implemented for a
+connected [`std::net::UdpSocket`] and can be implemented for any other
+datagram carrier (for example an in-process channel in tests).
+
+Sessions always operate in non-blocking mode: when no datagram is available
+the transport returns [`std::io::ErrorKind::WouldBlock`] and the operation
+returns [`Error::WantRead`]. Retransmission is driven by the caller: wait up
+to [`DtlsSession::timeout()`] for the transport to become readable and call
+[`DtlsSession::handle_timeout()`] if nothing arrived.
+
+# Examples
+
+```rust,no_run
+use std::net::UdpSocket;
+use wolfssl_tls::{Error, WolfCrypt};
+use wolfssl_tls::context::{Context, Method};
+use wolfssl_tls::dtls::DtlsSession;
+
+let ca = std::fs::read("../../../certs/ca-cert.pem").unwrap();
+let wolfcrypt = WolfCrypt::new().unwrap();
+let mut ctx = Context::new(Method::DtlsClient, &wolfcrypt).unwrap();
+ctx.load_verify_buffer(&ca, Context::FILETYPE_PEM).unwrap();
+let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+socket.connect("127.0.0.1:11111").unwrap();
+socket.set_nonblocking(true).unwrap();
+let mut session = DtlsSession::new(&ctx, socket).unwrap();
+loop {
+    match session.connect() {
+        Ok(()) => break,
+        Err(Error::WantRead) => {
+            // Wait up to session.timeout() for the socket to become
+            // readable; on expiry:
+            session.handle_timeout().unwrap();
+        }
+        Err(e) => panic!("handshake failed: {}", e),
+    }
+}
+session.send(b"hello").unwrap();
+```
+*/
+
+#![cfg(dtls)]
+
+use crate::{Error, WolfSsl};
+use crate::context::{check_success, Context};
+use crate::session::{io_error_to_cb, ssl_error};
+use crate::sys;
+use core::marker::PhantomData;
+use std::ffi::{c_char, c_int, c_void, CStr};
+use std::io;
+use std::net::UdpSocket;
+use std::time::Duration;
+use wolfssl_wolfcrypt::memory::HeapHint;
+
+/// A datagram transport carrying DTLS records.
+///
+/// Each call to `send()` must transmit exactly one datagram and each call
+/// to `recv()` must return exactly one datagram. `recv()` must not block:
+/// when no datagram is available it returns an error of kind
+/// [`std::io::ErrorKind::WouldBlock`].
+pub trait DatagramTransport {
+    /// Send a single datagram to the peer.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes sent or
+    /// Err(e) containing the transport error.
+    fn send(&mut self, datagram: &[u8]) -> io::Result<usize>;
+
+    /// Receive a single datagram from the peer. An empty datagram is
+    /// skipped as if no datagram was available.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the datagram size or Err(e)
+    /// containing the transport error.
+    fn recv(&mut self, buf: &mut [u8]) -> io::Result<usize>;
+}
+
+/// A connected UDP socket. The socket should be set to non-blocking mode.
+impl DatagramTransport for UdpSocket {
+    fn send(&mut self, datagram: &[u8]) -> io::Result<usize> {
+        UdpSocket::send(self, datagram)
+    }
+
+    fn recv(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        UdpSocket::recv(self, buf)
+    }
+}
+
+/// State shared with the wolfSSL I/O callbacks.
+struct IoState<T> {
+    transport: T,
+    /// Last transport error not representable as a wolfSSL I/O error code.
+    error: Option<io::Error>,
+}
+
+unsafe extern "C" fn dgram_recv<T: DatagramTransport>(_ssl: *mut sys::WOLFSSL,
+        buf: *mut c_char, sz: c_int, ctx: *mut c_void) -> c_int {
+    if ctx.is_null() || buf.is_null() || sz < 0 {
+        return sys::IOerrors_WOLFSSL_CBIO_ERR_GENERAL;
+    }
+    let state = unsafe { &mut *(ctx as *mut IoState<T>) };
+    let buf = unsafe { core::slice::from_raw_parts_mut(buf as *mut u8, sz as usize) };
+    match state.transport.recv(buf) {
+        // An empty datagram carries no record; returning 0 would signal that
+        // the connection was closed.
+        Ok(0) => sys::IOerrors_WOLFSSL_CBIO_ERR_WANT_READ,
+        Ok(n) => n as c_int,
+        Err(e) => io_error_to_cb(&mut state.error, e, sys::IOerrors_WOLFSSL_CBIO_ERR_WANT_READ),
+    }
+}
+
+unsafe extern "C" fn dgram_send<T: DatagramTransport>(_ssl: *mut sys::WOLFSSL,
+        buf: *mut c_char, sz: c_int, ctx: *mut c_void) -> c_int {
+    if ctx.is_null() || buf.is_null() || sz < 0 {
+        return sys::IOerrors_WOLFSSL_CBIO_ERR_GENERAL;
+    }
+    let state = unsafe { &mut *(ctx as *mut IoState<T>) };
+    let buf = unsafe { core::slice::from_raw_parts(buf as *const u8, sz as usize) };
+    match state.transport.send(buf) {
+        Ok(n) => n as c_int,
+        Err(e) => io_error_to_cb(&mut state.error, e, sys::IOerrors_WOLFSSL_CBIO_ERR_WANT_WRITE),
+    }
+}
+
+/// Rust wrapper for a wolfSSL DTLS `WOLFSSL` session object.
+///
+/// A `DtlsSession` is created from a DTLS [`Context`] and owns the
+/// datagram transport `T` over which DTLS records are exchanged. Its memory
+/// is allocated with the heap hint of the context, so it cannot outlive that
+/// memory pool (`'h`).
+pub struct DtlsSession<'h, T: DatagramTransport> {
+    ssl: *mut sys::WOLFSSL,
+    io: Box<IoState<T>>,
+    _wolfssl: WolfSsl,
+    _heap: PhantomData<HeapHint<'h>>,
+}
+
+// The WOLFSSL object and the transport are only ever accessed through
+// `&mut self`, and the library reference is only released on drop, so the
+// session may move between threads if the transport can.
+unsafe impl<T: DatagramTransport + Send> Send for DtlsSession<'_, T> {}
+
+impl<'h, T: DatagramTransport> DtlsSession<'h, T> {
+    /// Create a new `DtlsSession` from a `Context`, using `transport` to
+    /// exchange datagrams with the peer.
+    ///
+    /// # Parameters
+    ///
+    /// * `ctx`: Context created with a DTLS [`crate::context::Method`].
+    /// * `transport`: Datagram transport connected to the peer.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(DtlsSession) containing the session instance or
+    /// Err(e) containing the error. `BAD_FUNC_ARG` is returned if `ctx` was
+    /// not created with a DTLS method.
+    pub fn new(ctx: &Context<'h>, transport: T) -> Result<Self, Error> {
+        if !ctx.method().is_dtls() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+        }
+        let wolfssl = WolfSsl::new(ctx.wolfssl.wolfcrypt())?;
+        let ssl = unsafe { sys::wolfSSL_new(ctx.ctx) };
+        if ssl.is_null() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_MEMORY_E));
+        }
+        let mut io = Box::new(IoState { transport, error: None });
+        let io_ptr = &mut *io as *mut IoState<T> as *mut c_void;
+        unsafe {
+            sys::wolfSSL_SSLSetIORecv(ssl, Some(dgram_recv::<T>));
+            sys::wolfSSL_SSLSetIOSend(ssl, Some(dgram_send::<T>));
+            sys::wolfSSL_SetIOReadCtx(ssl, io_ptr);
+            sys::wolfSSL_SetIOWriteCtx(ssl, io_ptr);
+            sys::wolfSSL_dtls_set_using_nonblock(ssl, 1);
+        }
+        Ok(DtlsSession { ssl, io, _wolfssl: wolfssl, _heap: PhantomData })
+    }
+
+    /// Convert a failed wolfSSL return value to an [`Error`].
+    fn error(&mut self, rc: c_int) -> Error {
+        ssl_error(self.ssl, rc, &mut self.io.error)
+    }
+
+    /// Perform the client side of the DTLS handshake.
+    ///
+    /// Returns [`Error::WantRead`] while waiting for the next flight from
+    /// the server; call it again when a datagram arrives, or call
+    /// [`DtlsSession::handle_timeout()`] once [`DtlsSession::timeout()`]
+    /// has elapsed without one.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) once the handshake has completed or Err(e).
+    pub fn connect(&mut self) -> Result<(), Error> {
+        let rc = unsafe { sys::wolfSSL_connect(self.ssl) };
+        if rc != sys::WOLFSSL_SUCCESS as c_int {
+            return Err(self.error(rc));
+        }
+        Ok(())
+    }
+
+    /// Perform the server side of the DTLS handshake.
+    ///
+    /// Returns [`Error::WantRead`] while waiting for the next flight from
+    /// the client; call it again when a datagram arrives, or call
+    /// [`DtlsSession::handle_timeout()`] once [`DtlsSession::timeout()`]
+    /// has elapsed without one.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) once the handshake has completed or Err(e).
+    pub fn accept(&mut self) -> Result<(), Error> {
+        let rc = unsafe { sys::wolfSSL_accept(self.ssl) };
+        if rc != sys::WOLFSSL_SUCCESS as c_int {
+            return Err(self.error(rc));
+        }
+        Ok(())
+    }
+
+    /// Encrypt and send application data as a single DTLS record.
+    ///
+    /// # Parameters
+    ///
+    /// * `data`: Application data. Must fit in a single record.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes sent or
+    /// Err(e).
+    pub fn send(&mut self, data: &[u8]) -> Result<usize, Error> {
+        let sz = crate::buffer_len_to_i32(data.len()).map_err(Error::from)?;
+        let rc = unsafe { sys::wolfSSL_write(self.ssl, data.as_ptr() as *const c_void, sz) };
+        if rc > 0 {
+            return Ok(rc as usize);
+        }
+        Err(self.error(rc))
+    }
+
+    /// Receive and decrypt the application data of a single DTLS record.
+    ///
+    /// Handshake and retransmitted records received from the peer are
+    /// processed internally. Returns [`Error::WantRead`] when no application
+    /// data is available and [`Error::ZeroReturn`] once the peer has closed
+    /// the session.
+    ///
+    /// # Parameters
+    ///
+    /// * `buf`: Buffer in which to store the application data.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes received or
+    /// Err(e).
+    pub fn recv(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
+        let sz = buf.len().min(i32::MAX as usize) as c_int;
+        let rc = unsafe { sys::wolfSSL_read(self.ssl, buf.as_mut_ptr() as *mut c_void, sz) };
+        if rc > 0 {
+            return Ok(rc as usize);
+        }
+        Err(self.error(rc))
+    }
+
+    /// Return how long to wait for a datagram before calling
+    /// [`DtlsSession::handle_timeout()`].
+    ///
+    /// The timeout doubles after each retransmission up to the maximum set
+    /// with [`DtlsSession::set_timeout_max()`]. For DTLS 1.3 a shorter
+    /// timeout is returned when the session wants to send acknowledgements
+    /// promptly.
+    pub fn timeout(&self) -> Duration {
+        let secs = unsafe { sys::wolfSSL_dtls_get_current_timeout(self.ssl) };
+        let timeout = Duration::from_secs(secs.max(0) as u64);
+        #[cfg(dtls13)]
+        if unsafe { sys::wolfSSL_dtls13_use_quick_timeout(self.ssl) } != 0 {
+            return timeout / 4;
+        }
+        timeout
+    }
+
+    /// Handle expiry of the retransmission timer.
+    ///
+    /// Call this when [`DtlsSession::timeout()`] has elapsed without a
+    /// datagram arriving. The last flight is retransmitted and the timeout
+    /// is increased.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e). An error is returned if
+    /// the maximum timeout has been exceeded.
+    pub fn handle_timeout(&mut self) -> Result<(), Error> {
+        let rc = unsafe { sys::wolfSSL_dtls_got_timeout(self.ssl) };
+        if rc != sys::WOLFSSL_SUCCESS as c_int {
+            return Err(self.error(rc));
+        }
+        Ok(())
+    }
+
+    /// Set the initial retransmission timeout.
+    ///
+    /// # Parameters
+    ///
+    /// * `secs`: Initial timeout in seconds. Must not exceed the maximum
+    ///   timeout.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the error.
+    pub fn set_timeout_init(&mut self, secs: u32) -> Result<(), Error> {
+        let secs = i32::try_from(secs)
+            .map_err(|_| Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG))?;
+        let rc = unsafe { sys::wolfSSL_dtls_set_timeout_init(self.ssl, secs) };
+        check_success(rc)
+    }
+
+    /// Set the maximum retransmission timeout.
+    ///
+    /// # Parameters
+    ///
+    /// * `secs`: Maximum timeout in seconds. Must not be less than the
+    ///   initial timeout.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the error.
+    pub fn set_timeout_max(&mut self, secs: u32) -> Result<(), Error> {
+        let secs = i32::try_from(secs)
+            .map_err(|_| Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG))?;
+        let rc = unsafe { sys::wolfSSL_dtls_set_timeout_max(self.ssl, secs) };
+        check_success(rc)
+    }
+
+    /// Set the maximum datagram size sent by this session.
+    ///
+    /// # Parameters
+    ///
+    /// * `mtu`: Maximum transmission unit in bytes.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the error.
+    #[cfg(dtls_mtu)]
+    pub fn set_mtu(&mut self, mtu: u16) -> Result<(), Error> {
+        let rc = unsafe { sys::wolfSSL_dtls_set_mtu(self.ssl, mtu) };
+        check_success(rc)
+    }
+
+    /// Send a close_notify alert to the peer.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e).
+    pub fn shutdown(&mut self) -> Result<(), Error> {
+        let rc = unsafe { sys::wolfSSL_shutdown(self.ssl) };
+        if rc == sys::WOLFSSL_SUCCESS as c_int || rc == sys::WOLFSSL_SHUTDOWN_NOT_DONE as c_int {
+            return Ok(());
+        }
+        Err(self.error(rc))
+    }
+
+    /// Return whether the handshake has completed.
+    pub fn is_handshake_done(&self) -> bool {
+        unsafe { sys::wolfSSL_is_init_finished(self.ssl) != 0 }
+    }
+
+    /// Return the negotiated protocol version string, e.g. `"DTLSv1.3"`.
+    pub fn version(&self) -> Option<&str> {
+        let s = unsafe { sys::wolfSSL_get_version(self.ssl) };
+        if s.is_null() {
+            return None;
+        }
+        unsafe { CStr::from_ptr(s) }.to_str().ok()
+    }
+
+    /// Return the negotiated cipher suite name.
+    pub fn cipher_name(&self) -> Option<&str> {
+        let s = unsafe { sys::wolfSSL_get_cipher_name(self.ssl) };
+        if s.is_null() {
+            return None;
+        }
+        unsafe { CStr::from_ptr(s) }.to_str().ok()
+    }
+
+    /// Return a reference to the underlying transport.
+    pub fn get_ref(&self) -> &T {
+        &self.io.transport
+    }
+
+    /// Return a mutable reference to the underlying transport.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.io.transport
+    }
+}
+
+impl<T: DatagramTransport> Drop for DtlsSession<'_, T> {
+    /// Safely free the underlying wolfSSL session.
+    ///
+    /// This calls the `wolfSSL_free()` wolfssl library function. The
+    /// transport is dropped afterwards, once no callback can reference it.
+    fn drop(&mut self) {
+        unsafe { sys::wolfSSL_free(self.ssl); }
+    }
+}
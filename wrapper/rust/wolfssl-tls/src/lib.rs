@@ -0,0 +1,217 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/* bindgen-generated bindings to the C library */
+pub mod sys;
+
+pub mod cert_manager;
+pub mod context;
+pub mod dtls;
+pub mod session;
+
+pub use wolfssl_wolfcrypt::{Env, WolfCrypt};
+
+/// Error returned by TLS session operations.
+#[derive(Debug)]
+pub enum Error {
+    /// The operation could not complete because the transport has no data
+    /// available yet. Call the operation again once more data has arrived.
+    WantRead,
+    /// The operation could not complete because the transport cannot accept
+    /// more data yet. Call the operation again once it is writable.
+    WantWrite,
+    /// The peer has closed the TLS connection with a close_notify alert.
+    ZeroReturn,
+    /// The underlying transport returned an I/O error.
+    Io(std::io::Error),
+    /// The wolfSSL library returned a TLS layer error code.
+    Ssl(i32),
+    /// The wolfSSL library returned a wolfCrypt error code.
+    Crypto(wolfssl_wolfcrypt::Error),
+}
+
+impl Error {
+    /// Return the wolfSSL library error code for this error, if any.
+    pub fn code(&self) -> Option<i32> {
+        match self {
+            Error::Ssl(code) => Some(*code),
+            Error::Crypto(e) => Some(e.code()),
+            _ => None,
+        }
+    }
+
+    /// Return whether the operation should be retried once the transport is
+    /// ready (i.e. the error is [`Error::WantRead`] or [`Error::WantWrite`]).
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Error::WantRead | Error::WantWrite)
+    }
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::WantRead => write!(f, "TLS operation wants to read"),
+            Error::WantWrite => write!(f, "TLS operation wants to write"),
+            Error::ZeroReturn => write!(f, "TLS connection closed by peer"),
+            Error::Io(e) => write!(f, "transport I/O error: {}", e),
+            Error::Ssl(code) => write!(f, "wolfSSL error {}", code),
+            Error::Crypto(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Crypto(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<wolfssl_wolfcrypt::Error> for Error {
+    fn from(e: wolfssl_wolfcrypt::Error) -> Self {
+        Error::Crypto(e)
+    }
+}
+
+/// Classify a raw wolfSSL error code: codes in the wolfCrypt error ranges
+/// become [`Error::Crypto`], everything else becomes [`Error::Ssl`].
+impl From<i32> for Error {
+    fn from(code: i32) -> Self {
+        let span1 = sys::wolfCrypt_ErrorCodes_WC_SPAN1_MIN_CODE_E..=
+            sys::wolfCrypt_ErrorCodes_WC_SPAN1_FIRST_E;
+        let span2 = sys::wolfCrypt_ErrorCodes_WC_SPAN2_MIN_CODE_E..=
+            sys::wolfCrypt_ErrorCodes_WC_SPAN2_FIRST_E;
+        if span1.contains(&code) || span2.contains(&code) {
+            Error::Crypto(wolfssl_wolfcrypt::Error::from(code))
+        } else {
+            Error::Ssl(code)
+        }
+    }
+}
+
+impl From<Error> for std::io::Error {
+    fn from(e: Error) -> Self {
+        use std::io::ErrorKind;
+        match e {
+            Error::WantRead | Error::WantWrite =>
+                std::io::Error::new(ErrorKind::WouldBlock, e),
+            Error::ZeroReturn => std::io::Error::new(ErrorKind::UnexpectedEof, e),
+            Error::Io(e) => e,
+            Error::Ssl(_) | Error::Crypto(_) => std::io::Error::other(e),
+        }
+    }
+}
+
+/// Convert a buffer length to `i32`, returning `BUFFER_E` if it overflows.
+pub(crate) fn buffer_len_to_i32(len: usize) -> Result<i32, i32> {
+    i32::try_from(len).map_err(|_| sys::wolfCrypt_ErrorCodes_BUFFER_E)
+}
+
+/// Reference to the initialized wolfSSL library held by each object of this
+/// crate.
+///
+/// Holds a clone of the [`WolfCrypt`] guard the object was created with and
+/// one `wolfSSL_Init()` reference, released with `wolfSSL_Cleanup()` when
+/// dropped, so neither wolfCrypt nor the TLS layer can be cleaned up while
+/// the object is alive.
+#[derive(Debug)]
+pub(crate) struct WolfSsl {
+    wolfcrypt: WolfCrypt,
+}
+
+impl WolfSsl {
+    /// Take a reference on the wolfSSL library.
+    pub(crate) fn new(wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        let rc = unsafe { sys::wolfSSL_Init() };
+        if rc != sys::WOLFSSL_SUCCESS as i32 {
+            return Err(Error::from(rc));
+        }
+        Ok(WolfSsl { wolfcrypt: wolfcrypt.clone() })
+    }
+
+    /// Get the wolfCrypt guard this reference was taken with.
+    pub(crate) fn wolfcrypt(&self) -> &WolfCrypt {
+        &self.wolfcrypt
+    }
+}
+
+impl Drop for WolfSsl {
+    fn drop(&mut self) {
+        unsafe { sys::wolfSSL_Cleanup(); }
+    }
+}
+
+/// Initialize the wolfSSL library.
+///
+/// Calling this function is not needed: every object of this crate takes a
+/// [`WolfCrypt`] guard when it is created and keeps the library initialized
+/// until it is dropped.
+///
+/// # Returns
+///
+/// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+/// library error code value.
+///
+/// # Example
+///
+/// ```rust
+/// #![allow(deprecated)]
+/// use wolfssl_tls::*;
+/// wolfssl_init().expect("Error with wolfssl_init()");
+/// // ... use the library ...
+/// wolfssl_cleanup().expect("Error with wolfssl_cleanup()");
+/// ```
+#[deprecated(note = "pass a WolfCrypt guard to the constructors, which keep the library initialized")]
+pub fn wolfssl_init() -> Result<(), Error> {
+    let rc = unsafe { sys::wolfSSL_Init() };
+    if rc != sys::WOLFSSL_SUCCESS as i32 {
+        return Err(Error::from(rc));
+    }
+    Ok(())
+}
+
+/// Clean up resources used by the wolfSSL library.
+///
+/// # Returns
+///
+/// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+/// library error code value.
+///
+/// Calling this while objects of this crate are alive releases a reference
+/// they hold, so it must only be paired with [`wolfssl_init`].
+///
+/// See also: [`wolfssl_init`]
+#[deprecated(note = "drop the objects of this crate and their WolfCrypt guard instead")]
+pub fn wolfssl_cleanup() -> Result<(), Error> {
+    let rc = unsafe { sys::wolfSSL_Cleanup() };
+    if rc != sys::WOLFSSL_SUCCESS as i32 {
+        return Err(Error::from(rc));
+    }
+    Ok(())
+}
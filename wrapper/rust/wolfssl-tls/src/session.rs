@@ -0,0 +1,419 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+This module provides a Rust wrapper for the wolfSSL library's `WOLFSSL`
+session object.
+
+A [`Session`] owns a transport implementing [`std::io::Read`] and
+[`std::io::Write`] (a `TcpStream`, an in-memory pipe, ...) and installs
+per-session wolfSSL I/O callbacks that route TLS records through it. Once the
+handshake has completed the session itself implements `Read` and `Write` for
+application data.
+
+Non-blocking transports are supported: when the transport returns
+[`std::io::ErrorKind::WouldBlock`], handshake operations return
+[`Error::WantRead`] or [`Error::WantWrite`] and should be called again once
+the transport is ready.
+
+# Examples
+
+```rust,no_run
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use wolfssl_tls::WolfCrypt;
+use wolfssl_tls::context::{Context, Method};
+use wolfssl_tls::session::Session;
+
+let ca = std::fs::read("../../../certs/ca-cert.pem").unwrap();
+let wolfcrypt = WolfCrypt::new().unwrap();
+let mut ctx = Context::new(Method::TlsClient, &wolfcrypt).unwrap();
+ctx.load_verify_buffer(&ca, Context::FILETYPE_PEM).unwrap();
+let stream = TcpStream::connect("127.0.0.1:11111").unwrap();
+let mut session = Session::new(&ctx, stream).unwrap();
+session.connect().unwrap();
+session.write_all(b"hello").unwrap();
+let mut buf = [0u8; 64];
+let n = session.read(&mut buf).unwrap();
+```
+*/
+
+use crate::{Error, WolfSsl};
+use crate::context::{check_success, Context};
+use crate::sys;
+use core::marker::PhantomData;
+use std::ffi::{c_char, c_int, c_void, CStr, CString};
+use std::io::{self, Read, Write};
+use wolfssl_wolfcrypt::memory::HeapHint;
+
+/// State shared with the wolfSSL I/O callbacks.
+///
+/// The callbacks receive a raw pointer to this struct as their context, so
+/// it is boxed to keep its address stable while the `Session` moves.
+struct IoState<T> {
+    stream: T,
+    /// Last transport error not representable as a wolfSSL I/O error code.
+    error: Option<io::Error>,
+}
+
+/// Map a transport error to a wolfSSL I/O callback return value.
+///
+/// Errors that have no wolfSSL equivalent are stashed in `error` so that
+/// they can be returned to the caller unchanged.
+pub(crate) fn io_error_to_cb(error: &mut Option<io::Error>, e: io::Error,
+        want: c_int) -> c_int {
+    match e.kind() {
+        io::ErrorKind::WouldBlock => want,
+        io::ErrorKind::Interrupted => sys::IOerrors_WOLFSSL_CBIO_ERR_ISR,
+        io::ErrorKind::ConnectionReset => sys::IOerrors_WOLFSSL_CBIO_ERR_CONN_RST,
+        io::ErrorKind::TimedOut => sys::IOerrors_WOLFSSL_CBIO_ERR_TIMEOUT,
+        _ => {
+            *error = Some(e);
+            sys::IOerrors_WOLFSSL_CBIO_ERR_GENERAL
+        }
+    }
+}
+
+/// Convert a failed wolfSSL return value to an [`Error`].
+///
+/// A transport error stashed by the I/O callbacks takes precedence over the
+/// wolfSSL error code it caused.
+pub(crate) fn ssl_error(ssl: *mut sys::WOLFSSL, rc: c_int,
+        io_error: &mut Option<io::Error>) -> Error {
+    let err = unsafe { sys::wolfSSL_get_error(ssl, rc) };
+    if let Some(e) = io_error.take() {
+        return Error::Io(e);
+    }
+    match err {
+        e if e == sys::WOLFSSL_ERROR_WANT_READ as c_int => Error::WantRead,
+        e if e == sys::WOLFSSL_ERROR_WANT_WRITE as c_int => Error::WantWrite,
+        e if e == sys::WOLFSSL_ERROR_ZERO_RETURN as c_int => Error::ZeroReturn,
+        e => Error::from(e),
+    }
+}
+
+unsafe extern "C" fn io_recv<T: Read + Write>(_ssl: *mut sys::WOLFSSL,
+        buf: *mut c_char, sz: c_int, ctx: *mut c_void) -> c_int {
+    if ctx.is_null() || buf.is_null() || sz < 0 {
+        return sys::IOerrors_WOLFSSL_CBIO_ERR_GENERAL;
+    }
+    let state = unsafe { &mut *(ctx as *mut IoState<T>) };
+    let buf = unsafe { core::slice::from_raw_parts_mut(buf as *mut u8, sz as usize) };
+    match state.stream.read(buf) {
+        Ok(0) => sys::IOerrors_WOLFSSL_CBIO_ERR_CONN_CLOSE,
+        Ok(n) => n as c_int,
+        Err(e) => io_error_to_cb(&mut state.error, e, sys::IOerrors_WOLFSSL_CBIO_ERR_WANT_READ),
+    }
+}
+
+unsafe extern "C" fn io_send<T: Read + Write>(_ssl: *mut sys::WOLFSSL,
+        buf: *mut c_char, sz: c_int, ctx: *mut c_void) -> c_int {
+    if ctx.is_null() || buf.is_null() || sz < 0 {
+        return sys::IOerrors_WOLFSSL_CBIO_ERR_GENERAL;
+    }
+    let state = unsafe { &mut *(ctx as *mut IoState<T>) };
+    let buf = unsafe { core::slice::from_raw_parts(buf as *const u8, sz as usize) };
+    match state.stream.write(buf) {
+        Ok(0) => sys::IOerrors_WOLFSSL_CBIO_ERR_CONN_CLOSE,
+        Ok(n) => n as c_int,
+        Err(e) => io_error_to_cb(&mut state.error, e, sys::IOerrors_WOLFSSL_CBIO_ERR_WANT_WRITE),
+    }
+}
+
+/// Rust wrapper for a wolfSSL `WOLFSSL` session object.
+///
+/// A `Session` is created from a [`Context`] and owns the transport `T`
+/// over which TLS records are exchanged. Its memory is allocated with the
+/// heap hint of the context, so it cannot outlive that memory pool (`'h`).
+pub struct Session<'h, T: Read + Write> {
+    ssl: *mut sys::WOLFSSL,
+    io: Box<IoState<T>>,
+    _wolfssl: WolfSsl,
+    _heap: PhantomData<HeapHint<'h>>,
+}
+
+// The WOLFSSL object and the transport are only ever accessed through
+// `&mut self`, and the library reference is only released on drop, so the
+// session may move between threads if the transport can.
+unsafe impl<T: Read + Write + Send> Send for Session<'_, T> {}
+
+impl<'h, T: Read + Write> Session<'h, T> {
+    /// Create a new `Session` from a `Context`, using `stream` as the
+    /// transport.
+    ///
+    /// # Parameters
+    ///
+    /// * `ctx`: Context holding the session configuration.
+    /// * `stream`: Transport used to exchange TLS records with the peer.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(Session) containing the session instance or Err(e)
+    /// containing the wolfSSL library error code value. `BAD_FUNC_ARG` is
+    /// returned if `ctx` was created with a DTLS [`crate::context::Method`].
+    pub fn new(ctx: &Context<'h>, stream: T) -> Result<Self, Error> {
+        if ctx.method().is_dtls() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+        }
+        let wolfssl = WolfSsl::new(ctx.wolfssl.wolfcrypt())?;
+        let ssl = unsafe { sys::wolfSSL_new(ctx.ctx) };
+        if ssl.is_null() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_MEMORY_E));
+        }
+        let mut io = Box::new(IoState { stream, error: None });
+        let io_ptr = &mut *io as *mut IoState<T> as *mut c_void;
+        unsafe {
+            sys::wolfSSL_SSLSetIORecv(ssl, Some(io_recv::<T>));
+            sys::wolfSSL_SSLSetIOSend(ssl, Some(io_send::<T>));
+            sys::wolfSSL_SetIOReadCtx(ssl, io_ptr);
+            sys::wolfSSL_SetIOWriteCtx(ssl, io_ptr);
+        }
+        Ok(Session { ssl, io, _wolfssl: wolfssl, _heap: PhantomData })
+    }
+
+    /// Convert a failed wolfSSL return value to an [`Error`].
+    fn error(&mut self, rc: c_int) -> Error {
+        ssl_error(self.ssl, rc, &mut self.io.error)
+    }
+
+    /// Perform the client side of the TLS handshake.
+    ///
+    /// With a non-blocking transport this returns [`Error::WantRead`] or
+    /// [`Error::WantWrite`] until the handshake completes; call it again
+    /// once the transport is ready.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) once the handshake has completed or Err(e).
+    pub fn connect(&mut self) -> Result<(), Error> {
+        let rc = unsafe { sys::wolfSSL_connect(self.ssl) };
+        if rc != sys::WOLFSSL_SUCCESS as c_int {
+            return Err(self.error(rc));
+        }
+        Ok(())
+    }
+
+    /// Perform the server side of the TLS handshake.
+    ///
+    /// With a non-blocking transport this returns [`Error::WantRead`] or
+    /// [`Error::WantWrite`] until the handshake completes; call it again
+    /// once the transport is ready.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) once the handshake has completed or Err(e).
+    pub fn accept(&mut self) -> Result<(), Error> {
+        let rc = unsafe { sys::wolfSSL_accept(self.ssl) };
+        if rc != sys::WOLFSSL_SUCCESS as c_int {
+            return Err(self.error(rc));
+        }
+        Ok(())
+    }
+
+    /// Send a close_notify alert to the peer.
+    ///
+    /// # Returns
+    ///
+    /// Returns Ok(true) if the bidirectional shutdown has completed (the
+    /// peer's close_notify was also received), Ok(false) if only our
+    /// close_notify has been sent so far, or Err(e).
+    pub fn shutdown(&mut self) -> Result<bool, Error> {
+        let rc = unsafe { sys::wolfSSL_shutdown(self.ssl) };
+        if rc == sys::WOLFSSL_SUCCESS as c_int {
+            return Ok(true);
+        }
+        if rc == sys::WOLFSSL_SHUTDOWN_NOT_DONE as c_int {
+            return Ok(false);
+        }
+        Err(self.error(rc))
+    }
+
+    /// Return whether the handshake has completed.
+    pub fn is_handshake_done(&self) -> bool {
+        unsafe { sys::wolfSSL_is_init_finished(self.ssl) != 0 }
+    }
+
+    /// Return the number of decrypted application data bytes buffered and
+    /// available to read without touching the transport.
+    pub fn pending(&self) -> usize {
+        let n = unsafe { sys::wolfSSL_pending(self.ssl) };
+        if n < 0 { 0 } else { n as usize }
+    }
+
+    /// Return the negotiated protocol version string, e.g. `"TLSv1.3"`.
+    pub fn version(&self) -> Option<&str> {
+        let s = unsafe { sys::wolfSSL_get_version(self.ssl) };
+        if s.is_null() {
+            return None;
+        }
+        unsafe { CStr::from_ptr(s) }.to_str().ok()
+    }
+
+    /// Return the negotiated cipher suite name.
+    pub fn cipher_name(&self) -> Option<&str> {
+        let s = unsafe { sys::wolfSSL_get_cipher_name(self.ssl) };
+        if s.is_null() {
+            return None;
+        }
+        unsafe { CStr::from_ptr(s) }.to_str().ok()
+    }
+
+    /// Set the Server Name Indication (SNI) host name sent by the client.
+    ///
+    /// # Parameters
+    ///
+    /// * `host`: Server host name.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    #[cfg(sni)]
+    pub fn set_sni(&mut self, host: &str) -> Result<(), Error> {
+        let len = u16::try_from(host.len())
+            .map_err(|_| sys::wolfCrypt_ErrorCodes_BUFFER_E)?;
+        let rc = unsafe {
+            sys::wolfSSL_UseSNI(self.ssl, sys::WOLFSSL_SNI_HOST_NAME as u8,
+                host.as_ptr() as *const c_void, len)
+        };
+        check_success(rc)
+    }
+
+    /// Require the peer certificate to match the given domain name.
+    ///
+    /// # Parameters
+    ///
+    /// * `domain`: Expected domain name of the peer.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    #[cfg(domain_name_check)]
+    pub fn check_domain_name(&mut self, domain: &str) -> Result<(), Error> {
+        let domain = CString::new(domain)
+            .map_err(|_| sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG)?;
+        let rc = unsafe { sys::wolfSSL_check_domain_name(self.ssl, domain.as_ptr()) };
+        check_success(rc)
+    }
+
+    /// Set the list of Application-Layer Protocol Negotiation (ALPN)
+    /// protocols offered or accepted by this session.
+    ///
+    /// The handshake fails if the peer does not support any of the given
+    /// protocols.
+    ///
+    /// # Parameters
+    ///
+    /// * `protocols`: Protocol names in order of preference, e.g.
+    ///   `&["h2", "http/1.1"]`.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    #[cfg(alpn)]
+    pub fn set_alpn(&mut self, protocols: &[&str]) -> Result<(), Error> {
+        if protocols.is_empty() || protocols.iter().any(|p| p.is_empty() || p.contains(',')) {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+        }
+        let mut list = protocols.join(",").into_bytes();
+        let list_len = crate::buffer_len_to_i32(list.len())? as u32;
+        let rc = unsafe {
+            sys::wolfSSL_UseALPN(self.ssl, list.as_mut_ptr() as *mut c_char,
+                list_len, sys::WOLFSSL_ALPN_FAILED_ON_MISMATCH as u8)
+        };
+        check_success(rc)
+    }
+
+    /// Return the ALPN protocol negotiated during the handshake, if any.
+    #[cfg(alpn)]
+    pub fn alpn_protocol(&self) -> Option<&[u8]> {
+        let mut proto: *mut c_char = core::ptr::null_mut();
+        let mut len: u16 = 0;
+        let rc = unsafe { sys::wolfSSL_ALPN_GetProtocol(self.ssl, &mut proto, &mut len) };
+        if rc != sys::WOLFSSL_SUCCESS as c_int || proto.is_null() {
+            return None;
+        }
+        Some(unsafe { core::slice::from_raw_parts(proto as *const u8, len as usize) })
+    }
+
+    /// Return a reference to the underlying transport.
+    pub fn get_ref(&self) -> &T {
+        &self.io.stream
+    }
+
+    /// Return a mutable reference to the underlying transport.
+    ///
+    /// Reading from or writing to the transport directly will corrupt the
+    /// TLS stream.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.io.stream
+    }
+}
+
+impl<T: Read + Write> Read for Session<'_, T> {
+    /// Read decrypted application data.
+    ///
+    /// Returns Ok(0) once the peer has sent a close_notify alert.
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        let sz = buf.len().min(i32::MAX as usize) as c_int;
+        let rc = unsafe { sys::wolfSSL_read(self.ssl, buf.as_mut_ptr() as *mut c_void, sz) };
+        if rc > 0 {
+            return Ok(rc as usize);
+        }
+        match self.error(rc) {
+            Error::ZeroReturn => Ok(0),
+            e => Err(e.into()),
+        }
+    }
+}
+
+impl<T: Read + Write> Write for Session<'_, T> {
+    /// Encrypt and send application data.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        let sz = buf.len().min(i32::MAX as usize) as c_int;
+        let rc = unsafe { sys::wolfSSL_write(self.ssl, buf.as_ptr() as *const c_void, sz) };
+        if rc > 0 {
+            return Ok(rc as usize);
+        }
+        Err(self.error(rc).into())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.io.stream.flush()
+    }
+}
+
+impl<T: Read + Write> Drop for Session<'_, T> {
+    /// Safely free the underlying wolfSSL session.
+    ///
+    /// This calls the `wolfSSL_free()` wolfssl library function. The
+    /// transport is dropped afterwards, once no callback can reference it.
+    fn drop(&mut self) {
+        unsafe { sys::wolfSSL_free(self.ssl); }
+    }
+}
@@ -0,0 +1,118 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::io::{self, Read, Write};
+use std::rc::Rc;
+use wolfssl_tls::WolfCrypt;
+
+#[allow(dead_code)]
+pub fn setup() -> WolfCrypt
+{
+    WolfCrypt::new().expect("Error with WolfCrypt::new()")
+}
+
+/// One end of an in-memory, non-blocking, bidirectional byte pipe.
+///
+/// Reads return `WouldBlock` when no data is available.
+#[allow(dead_code)]
+pub struct MemPipe {
+    rx: Rc<RefCell<VecDeque<u8>>>,
+    tx: Rc<RefCell<VecDeque<u8>>>,
+}
+
+#[allow(dead_code)]
+pub fn mem_pipe() -> (MemPipe, MemPipe) {
+    let a = Rc::new(RefCell::new(VecDeque::new()));
+    let b = Rc::new(RefCell::new(VecDeque::new()));
+    (MemPipe { rx: a.clone(), tx: b.clone() }, MemPipe { rx: b, tx: a })
+}
+
+impl Read for MemPipe {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut rx = self.rx.borrow_mut();
+        if rx.is_empty() {
+            return Err(io::ErrorKind::WouldBlock.into());
+        }
+        let n = buf.len().min(rx.len());
+        for (dst, src) in buf.iter_mut().zip(rx.drain(..n)) {
+            *dst = src;
+        }
+        Ok(n)
+    }
+}
+
+impl Write for MemPipe {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.tx.borrow_mut().extend(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// One end of an in-memory, non-blocking datagram channel that drops a
+/// deterministic pseudo-random fraction of the datagrams sent through it.
+#[cfg(dtls)]
+#[allow(dead_code)]
+pub struct LossyChannel {
+    rx: Rc<RefCell<VecDeque<Vec<u8>>>>,
+    tx: Rc<RefCell<VecDeque<Vec<u8>>>>,
+    pub loss_percent: u32,
+    seed: u32,
+    pub sent: usize,
+    pub dropped: usize,
+}
+
+/// Create a connected pair of lossy datagram channel ends.
+#[cfg(dtls)]
+#[allow(dead_code)]
+pub fn lossy_channel(loss_percent: u32, seed: u32) -> (LossyChannel, LossyChannel) {
+    let a = Rc::new(RefCell::new(VecDeque::new()));
+    let b = Rc::new(RefCell::new(VecDeque::new()));
+    (LossyChannel { rx: a.clone(), tx: b.clone(), loss_percent, seed, sent: 0, dropped: 0 },
+     LossyChannel { rx: b, tx: a, loss_percent, seed: seed.wrapping_add(1), sent: 0, dropped: 0 })
+}
+
+#[cfg(dtls)]
+#[allow(dead_code)]
+impl LossyChannel {
+    /// Return whether a datagram is waiting to be received.
+    pub fn has_pending(&self) -> bool {
+        !self.rx.borrow().is_empty()
+    }
+
+    /// Queue a datagram to be received, as if it had been sent by the peer.
+    pub fn inject(&self, datagram: &[u8]) {
+        self.rx.borrow_mut().push_back(datagram.to_vec());
+    }
+
+    fn next_random(&mut self) -> u32 {
+        self.seed = self.seed.wrapping_mul(1103515245).wrapping_add(12345);
+        (self.seed >> 16) & 0x7fff
+    }
+}
+
+#[cfg(dtls)]
+impl wolfssl_tls::dtls::DatagramTransport for LossyChannel {
+    fn send(&mut self, datagram: &[u8]) -> io::Result<usize> {
+        self.sent += 1;
+        if self.next_random() % 100 < self.loss_percent {
+            self.dropped += 1;
+        } else {
+            self.tx.borrow_mut().push_back(datagram.to_vec());
+        }
+        Ok(datagram.len())
+    }
+
+    fn recv(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self.rx.borrow_mut().pop_front() {
+            Some(d) => {
+                let n = buf.len().min(d.len());
+                buf[..n].copy_from_slice(&d[..n]);
+                Ok(n)
+            }
+            None => Err(io::ErrorKind::WouldBlock.into()),
+        }
+    }
+}
@@ -0,0 +1,183 @@
+#![cfg(cert_manager)]
+
+mod common;
+
+use std::fs;
+use wolfssl_tls::WolfCrypt;
+use wolfssl_tls::cert_manager::CertManager;
+use wolfssl_tls::sys;
+
+const CA_CERT: &str = "../../../certs/ca-cert.pem";
+const SERVER_CERT: &str = "../../../certs/server-cert.pem";
+const INT_CA_CERT: &str = "../../../certs/intermediate/ca-int-cert.pem";
+const INT2_CA_CERT: &str = "../../../certs/intermediate/ca-int2-cert.pem";
+const SERVER_INT_CERT: &str = "../../../certs/intermediate/server-int-cert.pem";
+
+fn ca_cm(wolfcrypt: &WolfCrypt) -> CertManager<'static> {
+    let ca = fs::read(CA_CERT).expect("Error reading CA cert");
+    let mut cm = CertManager::new(wolfcrypt).expect("Error with CertManager::new()");
+    cm.load_ca_buffer(&ca, CertManager::FILETYPE_PEM).expect("Error with load_ca_buffer()");
+    cm
+}
+
+#[test]
+fn test_verify_buffer() {
+    let wolfcrypt = common::setup();
+
+    let cert = fs::read(SERVER_CERT).expect("Error reading cert");
+    let mut cm = CertManager::new(&wolfcrypt).expect("Error with CertManager::new()");
+    assert!(cm.verify_buffer(&cert, CertManager::FILETYPE_PEM).is_err());
+
+    let mut cm = ca_cm(&wolfcrypt);
+    cm.verify_buffer(&cert, CertManager::FILETYPE_PEM).expect("Error with verify_buffer()");
+    let der = fs::read("../../../certs/server-cert.der").expect("Error reading cert");
+    cm.verify_buffer(&der, CertManager::FILETYPE_ASN1).expect("Error with verify_buffer()");
+
+    cm.unload_cas().expect("Error with unload_cas()");
+    assert!(cm.verify_buffer(&cert, CertManager::FILETYPE_PEM).is_err());
+}
+
+#[test]
+fn test_verify_chain() {
+    let wolfcrypt = common::setup();
+
+    let int1 = fs::read(INT_CA_CERT).expect("Error reading cert");
+    let int2 = fs::read(INT2_CA_CERT).expect("Error reading cert");
+    let leaf = fs::read(SERVER_INT_CERT).expect("Error reading cert");
+    let mut cm = ca_cm(&wolfcrypt);
+
+    assert_eq!(cm.verify_buffer(&leaf, CertManager::FILETYPE_PEM).unwrap_err().code(),
+        Some(sys::wolfCrypt_ErrorCodes_ASN_NO_SIGNER_E));
+    cm.verify_chain(&[&leaf, &int2, &int1], CertManager::FILETYPE_PEM)
+        .expect("Error with verify_chain()");
+    // Intermediates are only trusted for the duration of verify_chain().
+    assert!(cm.verify_buffer(&leaf, CertManager::FILETYPE_PEM).is_err());
+    assert!(cm.verify_buffer(&int2, CertManager::FILETYPE_PEM).is_err());
+
+    assert!(cm.verify_chain(&[&leaf, &int2], CertManager::FILETYPE_PEM).is_err());
+    assert!(cm.verify_chain(&[&leaf, &int1, &int2], CertManager::FILETYPE_PEM).is_err());
+    assert!(cm.verify_chain(&[], CertManager::FILETYPE_PEM).is_err());
+}
+
+#[test]
+#[cfg(cert_gen)]
+fn test_verify_chain_non_ca_intermediate() {
+    use wolfssl_wolfcrypt::cert_gen::{CertBuilder, Name};
+    use wolfssl_wolfcrypt::random::RNG;
+    use wolfssl_wolfcrypt::rsa::RSA;
+    let wolfcrypt = common::setup();
+
+    // server-cert.pem is CA:TRUE but has no keyCertSign key usage, so it
+    // must not be accepted as an intermediate for a certificate it signed.
+    let server = fs::read("../../../certs/server-cert.der").expect("Error reading cert");
+    let key = fs::read("../../../certs/server-key.der").expect("Error reading key");
+    let mut issuer_key = RSA::new_from_der(&key, &wolfcrypt).expect("Error with new_from_der()");
+    let mut subject_key = RSA::new_from_der(&key, &wolfcrypt).expect("Error with new_from_der()");
+    let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut builder = CertBuilder::new(&wolfcrypt).expect("Error with CertBuilder::new()");
+    builder.set_subject(&Name { common_name: Some("forged.example.com"), ..Default::default() })
+        .expect("Error with set_subject()");
+    builder.set_issuer_from_cert(&server).expect("Error with set_issuer_from_cert()");
+    builder.set_days_valid(365);
+    let mut leaf = [0u8; 4096];
+    let leaf_size = builder.sign(&mut subject_key, &mut issuer_key, &rng, &mut leaf)
+        .expect("Error with sign()");
+    let leaf = &leaf[..leaf_size];
+
+    let mut cm = ca_cm(&wolfcrypt);
+    assert_eq!(cm.verify_chain(&[leaf, &server], CertManager::FILETYPE_ASN1).unwrap_err().code(),
+        Some(sys::wolfCrypt_ErrorCodes_ASN_NO_KEYUSAGE));
+    #[cfg(cm_verify_cb)]
+    {
+        cm.set_verify_callback(|_depth, _error| true);
+        assert!(cm.verify_chain(&[leaf, &server], CertManager::FILETYPE_ASN1).is_err());
+        cm.clear_verify_callback();
+    }
+    assert!(cm.verify_buffer(leaf, CertManager::FILETYPE_ASN1).is_err());
+
+    // The leaf itself is valid when its issuer is an explicit trust anchor.
+    let mut cm = CertManager::new(&wolfcrypt).expect("Error with CertManager::new()");
+    cm.load_ca_buffer(&server, CertManager::FILETYPE_ASN1).expect("Error with load_ca_buffer()");
+    cm.verify_buffer(leaf, CertManager::FILETYPE_ASN1).expect("Error with verify_buffer()");
+}
+
+#[test]
+#[cfg(cm_verify_cb)]
+fn test_verify_callback() {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    let wolfcrypt = common::setup();
+
+    let int2 = fs::read(INT2_CA_CERT).expect("Error reading cert");
+    let leaf = fs::read(SERVER_INT_CERT).expect("Error reading cert");
+    let mut cm = ca_cm(&wolfcrypt);
+
+    let failures = Rc::new(RefCell::new(Vec::new()));
+    let seen = failures.clone();
+    cm.set_verify_callback(move |depth, error| {
+        seen.borrow_mut().push((depth, error));
+        false
+    });
+    let cert = fs::read(SERVER_CERT).expect("Error reading cert");
+    cm.verify_buffer(&cert, CertManager::FILETYPE_PEM).expect("Error with verify_buffer()");
+    assert!(failures.borrow().is_empty());
+
+    assert!(cm.verify_chain(&[&leaf, &int2], CertManager::FILETYPE_PEM).is_err());
+    assert_eq!(*failures.borrow(), [(1, sys::wolfCrypt_ErrorCodes_ASN_NO_SIGNER_E)]);
+
+    // Returning true overrides the failure.
+    cm.set_verify_callback(|depth, _error| depth == 0);
+    cm.verify_buffer(&leaf, CertManager::FILETYPE_PEM).expect("Error with verify_buffer()");
+    assert!(cm.verify_chain(&[&leaf, &int2], CertManager::FILETYPE_PEM).is_err());
+
+    cm.clear_verify_callback();
+    assert!(cm.verify_buffer(&leaf, CertManager::FILETYPE_PEM).is_err());
+}
+
+#[test]
+#[cfg(crl)]
+fn test_crl() {
+    let wolfcrypt = common::setup();
+
+    let crl = fs::read("../../../certs/crl/crl.pem").expect("Error reading CRL");
+    let good = fs::read("../../../certs/server-cert.der").expect("Error reading cert");
+    let revoked = fs::read("../../../certs/server-revoked-cert.pem").expect("Error reading cert");
+    let mut cm = ca_cm(&wolfcrypt);
+
+    cm.verify_buffer(&revoked, CertManager::FILETYPE_PEM).expect("Error with verify_buffer()");
+    cm.load_crl_buffer(&crl, CertManager::FILETYPE_PEM).expect("Error with load_crl_buffer()");
+    cm.check_crl(&good).expect("Error with check_crl()");
+    cm.verify_buffer(&good, CertManager::FILETYPE_ASN1).expect("Error with verify_buffer()");
+    assert_eq!(cm.verify_buffer(&revoked, CertManager::FILETYPE_PEM).unwrap_err().code(),
+        Some(sys::wolfSSL_ErrorCodes_CRL_CERT_REVOKED));
+
+    cm.disable_crl().expect("Error with disable_crl()");
+    cm.verify_buffer(&revoked, CertManager::FILETYPE_PEM).expect("Error with verify_buffer()");
+    cm.enable_crl(CertManager::CRL_CHECK).expect("Error with enable_crl()");
+    assert!(cm.verify_buffer(&revoked, CertManager::FILETYPE_PEM).is_err());
+}
+
+#[test]
+#[cfg(all(verify_time, feature = "global-verify-time"))]
+fn test_verify_time() {
+    let wolfcrypt = common::setup();
+
+    let cert = fs::read(SERVER_CERT).expect("Error reading cert");
+    let mut cm = ca_cm(&wolfcrypt);
+
+    // 2000-01-01, before the certificate validity period.
+    cm.set_verify_time(Some(946_684_800));
+    assert_eq!(cm.verify_buffer(&cert, CertManager::FILETYPE_PEM).unwrap_err().code(),
+        Some(sys::wolfCrypt_ErrorCodes_ASN_BEFORE_DATE_E));
+    // 2100-01-01, after the certificate validity period.
+    cm.set_verify_time(Some(4_102_444_800));
+    assert_eq!(cm.verify_buffer(&cert, CertManager::FILETYPE_PEM).unwrap_err().code(),
+        Some(sys::wolfCrypt_ErrorCodes_ASN_AFTER_DATE_E));
+
+    // The verification time only applies to this CertManager.
+    let mut other = ca_cm(&wolfcrypt);
+    other.verify_buffer(&cert, CertManager::FILETYPE_PEM).expect("Error with verify_buffer()");
+
+    cm.set_verify_time(None);
+    cm.verify_buffer(&cert, CertManager::FILETYPE_PEM).expect("Error with verify_buffer()");
+}
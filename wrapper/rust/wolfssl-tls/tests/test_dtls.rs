@@ -0,0 +1,193 @@
+#![cfg(all(dtls, tls_client, tls_server, certs_buffer))]
+
+mod common;
+
+use common::LossyChannel;
+use std::fs;
+use wolfssl_tls::{Error, WolfCrypt};
+use wolfssl_tls::context::{Context, Method};
+use wolfssl_tls::dtls::DtlsSession;
+
+const CA_CERT: &str = "../../../certs/ca-cert.pem";
+const SERVER_CERT: &str = "../../../certs/server-cert.pem";
+const SERVER_KEY: &str = "../../../certs/server-key.pem";
+
+fn client_ctx(method: Method, wolfcrypt: &WolfCrypt) -> Context<'static> {
+    let ca = fs::read(CA_CERT).expect("Error reading CA cert");
+    let mut ctx = Context::new(method, wolfcrypt).expect("Error with Context::new()");
+    ctx.load_verify_buffer(&ca, Context::FILETYPE_PEM).expect("Error with load_verify_buffer()");
+    ctx.set_verify(Context::VERIFY_PEER);
+    ctx
+}
+
+fn server_ctx(method: Method, wolfcrypt: &WolfCrypt) -> Context<'static> {
+    let cert = fs::read(SERVER_CERT).expect("Error reading server cert");
+    let key = fs::read(SERVER_KEY).expect("Error reading server key");
+    let mut ctx = Context::new(method, wolfcrypt).expect("Error with Context::new()");
+    ctx.use_certificate_buffer(&cert, Context::FILETYPE_PEM).expect("Error with use_certificate_buffer()");
+    ctx.use_private_key_buffer(&key, Context::FILETYPE_PEM).expect("Error with use_private_key_buffer()");
+    ctx.set_verify(Context::VERIFY_NONE);
+    ctx
+}
+
+/// Advance one side of the handshake. Once the handshake is done, keep
+/// reading so that retransmitted flights from the peer are answered.
+fn step(session: &mut DtlsSession<LossyChannel>, server: bool) -> Result<(), Error> {
+    let rc = if !session.is_handshake_done() {
+        if server { session.accept() } else { session.connect() }
+    } else {
+        let mut buf = [0u8; 64];
+        session.recv(&mut buf).map(|_| ())
+    };
+    match rc {
+        Err(Error::WantRead) | Err(Error::WantWrite) => Ok(()),
+        rc => rc,
+    }
+}
+
+/// Drive both handshakes to completion. When no datagram is in flight the
+/// retransmission timers of both sides are treated as expired.
+fn handshake(client: &mut DtlsSession<LossyChannel>, server: &mut DtlsSession<LossyChannel>)
+        -> Result<usize, Error> {
+    let mut timeouts = 0;
+    for _ in 0..1000 {
+        step(client, false)?;
+        step(server, true)?;
+        let idle = !client.get_ref().has_pending() && !server.get_ref().has_pending();
+        if !idle {
+            continue;
+        }
+        if client.is_handshake_done() && server.is_handshake_done() {
+            return Ok(timeouts);
+        }
+        assert!(client.timeout().as_millis() > 0);
+        client.handle_timeout()?;
+        server.handle_timeout()?;
+        timeouts += 1;
+    }
+    panic!("handshake did not complete");
+}
+
+fn check_exchange(client: &mut DtlsSession<LossyChannel>, server: &mut DtlsSession<LossyChannel>) {
+    let mut buf = [0u8; 64];
+    assert_eq!(client.send(b"hello server").expect("Error with client send()"), 12);
+    let n = server.recv(&mut buf).expect("Error with server recv()");
+    assert_eq!(&buf[..n], b"hello server");
+    assert_eq!(server.send(b"hello client").expect("Error with server send()"), 12);
+    let n = client.recv(&mut buf).expect("Error with client recv()");
+    assert_eq!(&buf[..n], b"hello client");
+    assert!(matches!(client.recv(&mut buf), Err(Error::WantRead)));
+}
+
+fn run(client_method: Method, server_method: Method, loss_percent: u32, seed: u32,
+        wolfcrypt: &WolfCrypt) -> usize {
+    let client_ctx = client_ctx(client_method, wolfcrypt);
+    let server_ctx = server_ctx(server_method, wolfcrypt);
+    let (c, s) = common::lossy_channel(loss_percent, seed);
+    let mut client = DtlsSession::new(&client_ctx, c).expect("Error with DtlsSession::new()");
+    let mut server = DtlsSession::new(&server_ctx, s).expect("Error with DtlsSession::new()");
+    let timeouts = handshake(&mut client, &mut server).expect("Error with handshake");
+    assert!(client.cipher_name().is_some());
+    assert_eq!(client.version(), server.version());
+
+    client.get_mut().loss_percent = 0;
+    server.get_mut().loss_percent = 0;
+    check_exchange(&mut client, &mut server);
+
+    client.shutdown().expect("Error with shutdown()");
+    let mut buf = [0u8; 16];
+    assert!(matches!(server.recv(&mut buf), Err(Error::ZeroReturn)));
+    timeouts
+}
+
+#[test]
+#[cfg(dtls13)]
+fn test_dtls13() {
+    let wolfcrypt = common::setup();
+    assert_eq!(run(Method::Dtls13Client, Method::Dtls13Server, 0, 1, &wolfcrypt), 0);
+}
+
+#[test]
+#[cfg(dtls12)]
+fn test_dtls12() {
+    let wolfcrypt = common::setup();
+    assert_eq!(run(Method::Dtls12Client, Method::Dtls12Server, 0, 1, &wolfcrypt), 0);
+}
+
+#[test]
+fn test_dtls_negotiated() {
+    let wolfcrypt = common::setup();
+    run(Method::DtlsClient, Method::DtlsServer, 0, 1, &wolfcrypt);
+}
+
+#[test]
+#[cfg(dtls13)]
+fn test_dtls13_lossy() {
+    let wolfcrypt = common::setup();
+    let mut timeouts = 0;
+    for seed in 1..=8 {
+        timeouts += run(Method::Dtls13Client, Method::Dtls13Server, 25, seed, &wolfcrypt);
+    }
+    assert!(timeouts > 0);
+}
+
+#[test]
+#[cfg(dtls12)]
+fn test_dtls12_lossy() {
+    let wolfcrypt = common::setup();
+    let mut timeouts = 0;
+    for seed in 1..=8 {
+        timeouts += run(Method::Dtls12Client, Method::Dtls12Server, 25, seed, &wolfcrypt);
+    }
+    assert!(timeouts > 0);
+}
+
+#[test]
+fn test_dtls_empty_datagram() {
+    let wolfcrypt = common::setup();
+
+    let client_ctx = client_ctx(Method::DtlsClient, &wolfcrypt);
+    let server_ctx = server_ctx(Method::DtlsServer, &wolfcrypt);
+    let (c, s) = common::lossy_channel(0, 1);
+    let mut client = DtlsSession::new(&client_ctx, c).expect("Error with DtlsSession::new()");
+    let mut server = DtlsSession::new(&server_ctx, s).expect("Error with DtlsSession::new()");
+    // An empty datagram is not a close of the session.
+    server.get_ref().inject(&[]);
+    assert!(matches!(server.accept(), Err(Error::WantRead)));
+    handshake(&mut client, &mut server).expect("Error with handshake");
+
+    let mut buf = [0u8; 16];
+    client.get_ref().inject(&[]);
+    assert!(matches!(client.recv(&mut buf), Err(Error::WantRead)));
+    check_exchange(&mut client, &mut server);
+}
+
+#[test]
+#[cfg(dtls12)]
+fn test_dtls_timeout_settings() {
+    let wolfcrypt = common::setup();
+
+    let client_ctx = client_ctx(Method::Dtls12Client, &wolfcrypt);
+    let (c, _s) = common::lossy_channel(0, 1);
+    let mut client = DtlsSession::new(&client_ctx, c).expect("Error with DtlsSession::new()");
+    client.set_timeout_max(8).expect("Error with set_timeout_max()");
+    client.set_timeout_init(2).expect("Error with set_timeout_init()");
+    assert!(client.set_timeout_init(16).is_err());
+    assert!(matches!(client.connect(), Err(Error::WantRead)));
+    assert_eq!(client.timeout().as_secs(), 2);
+    client.handle_timeout().expect("Error with handle_timeout()");
+    assert_eq!(client.timeout().as_secs(), 4);
+}
+
+#[test]
+fn test_dtls_method_mismatch() {
+    let wolfcrypt = common::setup();
+
+    let dtls_ctx = client_ctx(Method::DtlsClient, &wolfcrypt);
+    let tls_ctx = client_ctx(Method::TlsClient, &wolfcrypt);
+    let (c, _s) = common::lossy_channel(0, 1);
+    let err = DtlsSession::new(&tls_ctx, c).err().expect("DtlsSession::new() should fail");
+    assert!(matches!(err, Error::Crypto(wolfssl_wolfcrypt::Error::BadFunc(_))));
+    let (p, _q) = common::mem_pipe();
+    assert!(wolfssl_tls::session::Session::new(&dtls_ctx, p).is_err());
+}
@@ -0,0 +1,205 @@
+#![cfg(all(tls_client, tls_server, certs_buffer))]
+
+mod common;
+
+use std::fs;
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+use wolfssl_tls::{Error, WolfCrypt};
+use wolfssl_tls::context::{Context, Method};
+use wolfssl_tls::session::Session;
+
+const CA_CERT: &str = "../../../certs/ca-cert.pem";
+const SERVER_CERT: &str = "../../../certs/server-cert.pem";
+const SERVER_KEY: &str = "../../../certs/server-key.pem";
+
+fn client_ctx(method: Method, wolfcrypt: &WolfCrypt) -> Context<'static> {
+    let ca = fs::read(CA_CERT).expect("Error reading CA cert");
+    let mut ctx = Context::new(method, wolfcrypt).expect("Error with Context::new()");
+    ctx.load_verify_buffer(&ca, Context::FILETYPE_PEM).expect("Error with load_verify_buffer()");
+    ctx.set_verify(Context::VERIFY_PEER);
+    ctx
+}
+
+fn server_ctx(method: Method, wolfcrypt: &WolfCrypt) -> Context<'static> {
+    let cert = fs::read(SERVER_CERT).expect("Error reading server cert");
+    let key = fs::read(SERVER_KEY).expect("Error reading server key");
+    let mut ctx = Context::new(method, wolfcrypt).expect("Error with Context::new()");
+    ctx.use_certificate_buffer(&cert, Context::FILETYPE_PEM).expect("Error with use_certificate_buffer()");
+    ctx.use_private_key_buffer(&key, Context::FILETYPE_PEM).expect("Error with use_private_key_buffer()");
+    ctx.set_verify(Context::VERIFY_NONE);
+    ctx
+}
+
+/// Step both handshakes alternately until both complete.
+fn handshake<T: Read + Write>(client: &mut Session<T>, server: &mut Session<T>) -> Result<(), Error> {
+    let mut client_done = false;
+    let mut server_done = false;
+    for _ in 0..100 {
+        if !client_done {
+            match client.connect() {
+                Ok(()) => client_done = true,
+                Err(e) if e.is_retryable() => {}
+                Err(e) => return Err(e),
+            }
+        }
+        if !server_done {
+            match server.accept() {
+                Ok(()) => server_done = true,
+                Err(e) if e.is_retryable() => {}
+                Err(e) => return Err(e),
+            }
+        }
+        if client_done && server_done {
+            return Ok(());
+        }
+    }
+    panic!("handshake did not complete");
+}
+
+fn check_exchange<T: Read + Write>(client: &mut Session<T>, server: &mut Session<T>) {
+    let mut buf = [0u8; 64];
+    client.write_all(b"hello server").expect("Error with client write");
+    let n = server.read(&mut buf).expect("Error with server read");
+    assert_eq!(&buf[..n], b"hello server");
+    server.write_all(b"hello client").expect("Error with server write");
+    let n = client.read(&mut buf).expect("Error with client read");
+    assert_eq!(&buf[..n], b"hello client");
+}
+
+#[test]
+fn test_mem_pipe_handshake() {
+    let wolfcrypt = common::setup();
+
+    let client_ctx = client_ctx(Method::TlsClient, &wolfcrypt);
+    let server_ctx = server_ctx(Method::TlsServer, &wolfcrypt);
+    let (c, s) = common::mem_pipe();
+    let mut client = Session::new(&client_ctx, c).expect("Error with Session::new()");
+    let mut server = Session::new(&server_ctx, s).expect("Error with Session::new()");
+    assert!(!client.is_handshake_done());
+    handshake(&mut client, &mut server).expect("Error with handshake");
+    assert!(client.is_handshake_done());
+    assert!(server.is_handshake_done());
+    assert!(client.cipher_name().is_some());
+    assert_eq!(client.version(), server.version());
+    check_exchange(&mut client, &mut server);
+
+    assert!(!client.shutdown().expect("Error with client shutdown()"));
+    let mut buf = [0u8; 16];
+    assert_eq!(server.read(&mut buf).expect("Error with server read"), 0);
+}
+
+#[test]
+#[cfg(tls13)]
+fn test_mem_pipe_tls13() {
+    let wolfcrypt = common::setup();
+
+    let client_ctx = client_ctx(Method::Tls13Client, &wolfcrypt);
+    let server_ctx = server_ctx(Method::Tls13Server, &wolfcrypt);
+    let (c, s) = common::mem_pipe();
+    let mut client = Session::new(&client_ctx, c).expect("Error with Session::new()");
+    let mut server = Session::new(&server_ctx, s).expect("Error with Session::new()");
+    handshake(&mut client, &mut server).expect("Error with handshake");
+    assert_eq!(client.version(), Some("TLSv1.3"));
+    check_exchange(&mut client, &mut server);
+}
+
+#[test]
+#[cfg(tls12)]
+fn test_mem_pipe_tls12() {
+    let wolfcrypt = common::setup();
+
+    let client_ctx = client_ctx(Method::Tls12Client, &wolfcrypt);
+    let server_ctx = server_ctx(Method::Tls12Server, &wolfcrypt);
+    let (c, s) = common::mem_pipe();
+    let mut client = Session::new(&client_ctx, c).expect("Error with Session::new()");
+    let mut server = Session::new(&server_ctx, s).expect("Error with Session::new()");
+    handshake(&mut client, &mut server).expect("Error with handshake");
+    assert_eq!(client.version(), Some("TLSv1.2"));
+    check_exchange(&mut client, &mut server);
+}
+
+#[test]
+fn test_untrusted_server() {
+    let wolfcrypt = common::setup();
+
+    let mut client_ctx = Context::new(Method::TlsClient, &wolfcrypt).expect("Error with Context::new()");
+    client_ctx.set_verify(Context::VERIFY_PEER);
+    let server_ctx = server_ctx(Method::TlsServer, &wolfcrypt);
+    let (c, s) = common::mem_pipe();
+    let mut client = Session::new(&client_ctx, c).expect("Error with Session::new()");
+    let mut server = Session::new(&server_ctx, s).expect("Error with Session::new()");
+    let err = handshake(&mut client, &mut server).expect_err("handshake should fail");
+    assert!(err.code().is_some());
+}
+
+#[test]
+#[cfg(domain_name_check)]
+fn test_domain_name_check() {
+    let wolfcrypt = common::setup();
+
+    let client_ctx = client_ctx(Method::TlsClient, &wolfcrypt);
+    let server_ctx = server_ctx(Method::TlsServer, &wolfcrypt);
+
+    let (c, s) = common::mem_pipe();
+    let mut client = Session::new(&client_ctx, c).expect("Error with Session::new()");
+    let mut server = Session::new(&server_ctx, s).expect("Error with Session::new()");
+    client.check_domain_name("example.com").expect("Error with check_domain_name()");
+    handshake(&mut client, &mut server).expect("Error with handshake");
+
+    let (c, s) = common::mem_pipe();
+    let mut client = Session::new(&client_ctx, c).expect("Error with Session::new()");
+    let mut server = Session::new(&server_ctx, s).expect("Error with Session::new()");
+    client.check_domain_name("www.example.com").expect("Error with check_domain_name()");
+    assert!(handshake(&mut client, &mut server).is_err());
+}
+
+#[test]
+#[cfg(alpn)]
+fn test_alpn() {
+    let wolfcrypt = common::setup();
+
+    let client_ctx = client_ctx(Method::TlsClient, &wolfcrypt);
+    let server_ctx = server_ctx(Method::TlsServer, &wolfcrypt);
+    let (c, s) = common::mem_pipe();
+    let mut client = Session::new(&client_ctx, c).expect("Error with Session::new()");
+    let mut server = Session::new(&server_ctx, s).expect("Error with Session::new()");
+    client.set_alpn(&["h2", "http/1.1"]).expect("Error with set_alpn()");
+    server.set_alpn(&["http/1.1"]).expect("Error with set_alpn()");
+    handshake(&mut client, &mut server).expect("Error with handshake");
+    assert_eq!(client.alpn_protocol(), Some(&b"http/1.1"[..]));
+    assert_eq!(server.alpn_protocol(), Some(&b"http/1.1"[..]));
+
+    assert!(client.set_alpn(&[]).is_err());
+}
+
+#[test]
+fn test_tcp_loopback() {
+    let wolfcrypt = common::setup();
+
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Error with bind()");
+    let addr = listener.local_addr().expect("Error with local_addr()");
+
+    let server_wolfcrypt = wolfcrypt.clone();
+    let server = thread::spawn(move || {
+        let server_ctx = server_ctx(Method::TlsServer, &server_wolfcrypt);
+        let (stream, _) = listener.accept().expect("Error with accept()");
+        let mut server = Session::new(&server_ctx, stream).expect("Error with Session::new()");
+        server.accept().expect("Error with server accept()");
+        let mut buf = [0u8; 64];
+        let n = server.read(&mut buf).expect("Error with server read");
+        server.write_all(&buf[..n]).expect("Error with server write");
+        let _ = server.shutdown();
+    });
+
+    let client_ctx = client_ctx(Method::TlsClient, &wolfcrypt);
+    let stream = TcpStream::connect(addr).expect("Error with connect()");
+    let mut client = Session::new(&client_ctx, stream).expect("Error with Session::new()");
+    client.connect().expect("Error with client connect()");
+    client.write_all(b"echo").expect("Error with client write");
+    let mut buf = [0u8; 64];
+    let n = client.read(&mut buf).expect("Error with client read");
+    assert_eq!(&buf[..n], b"echo");
+    server.join().expect("server thread panicked");
+}
@@ -363,6 +363,7 @@ fn scan_cfg() -> Result<()> {
     check_cfg(&binding, "wc_AesOfbEncrypt", "aes_ofb");
     check_cfg(&binding, "wc_AesXtsInit", "aes_xts");
     check_cfg(&binding, "wc_AesXtsEncryptInit", "aes_xts_stream");
+    check_cfg(&binding, "wc_AesInit_Id", "aes_key_id");
     check_cfg(&binding, "WC_AES_BLOCK_SIZE", "aes_wc_block_size");
 
     /* blake2 */
@@ -371,6 +372,20 @@ fn scan_cfg() -> Result<()> {
     check_cfg(&binding, "wc_InitBlake2s", "blake2s");
     check_cfg(&binding, "wc_Blake2sHmac", "blake2s_hmac");
 
+    /* cert */
+    check_cfg(&binding, "wc_ParseCert", "cert");
+    check_cfg(&binding, "subjectRawLen", "cert_subject_raw");
+    check_cfg(&binding, "issuerRawLen", "cert_issuer_raw");
+    check_cfg(&binding, "wc_MakeCert_ex", "cert_gen");
+    check_cfg(&binding, "wc_MakeCertReq_ex", "cert_req");
+    check_cfg(&binding, "wc_SetKeyUsage", "cert_ext");
+    check_cfg(&binding, "altNamesSz", "cert_alt_names");
+    check_cfg(&binding, "altEmailNames", "cert_alt_email_names");
+    check_cfg(&binding, "wc_SetCert_Free", "cert_gen_cache");
+    check_cfg(&binding, "wc_DerToPem", "der_to_pem");
+    check_cfg(&binding, "wc_KeyPemToDer", "key_pem_to_der");
+    check_cfg(&binding, "wc_PubKeyPemToDer", "pub_key_pem_to_der");
+
     /* chacha20_poly1305 */
     check_cfg(&binding, "wc_ChaCha20Poly1305_Encrypt", "chacha20_poly1305");
     check_cfg(&binding, "wc_XChaCha20Poly1305_Encrypt", "xchacha20_poly1305");
@@ -378,13 +393,26 @@ fn scan_cfg() -> Result<()> {
     /* cmac */
     check_cfg(&binding, "wc_InitCmac", "cmac");
 
+    /* coding */
+    check_cfg(&binding, "Base64_Decode", "coding");
+    check_cfg(&binding, "Base64_Encode", "base64_encode");
+    check_cfg(&binding, "Base16_Encode", "base16");
+
     /* curve25519 */
     check_cfg(&binding, "wc_curve25519_make_pub", "curve25519");
     check_cfg(&binding, "wc_curve25519_make_pub_blind", "curve25519_blinding");
+    check_cfg(&binding, "wc_Curve25519PrivateKeyDecode", "curve25519_key_decode");
+    check_cfg(&binding, "wc_Curve25519PrivateKeyToDer", "curve25519_key_to_der");
+
+    /* curve448 */
+    check_cfg(&binding, "wc_curve448_make_pub", "curve448");
+    check_cfg(&binding, "wc_Curve448PrivateKeyDecode", "curve448_key_decode");
+    check_cfg(&binding, "wc_Curve448PrivateKeyToDer", "curve448_key_to_der");
 
     /* dh */
     check_cfg(&binding, "wc_InitDhKey", "dh");
     check_cfg(&binding, "wc_DhGenerateParams", "dh_keygen");
+    check_cfg(&binding, "wc_DhPrivKeyToDer", "dh_key_to_der");
     check_cfg(&binding, "wc_Dh_ffdhe2048_Get", "dh_ffdhe_2048");
     check_cfg(&binding, "wc_Dh_ffdhe3072_Get", "dh_ffdhe_3072");
     check_cfg(&binding, "wc_Dh_ffdhe4096_Get", "dh_ffdhe_4096");
@@ -402,6 +430,9 @@ fn scan_cfg() -> Result<()> {
     check_cfg(&binding, "wc_ecc_verify_hash", "ecc_verify");
     check_cfg(&binding, "wc_ecc_export_x963", "ecc_export");
     check_cfg(&binding, "wc_ecc_import_x963", "ecc_import");
+    check_cfg(&binding, "wc_EccKeyToPKCS8", "ecc_key_to_pkcs8");
+    check_cfg(&binding, "wc_EccKeyToDer", "ecc_key_to_der");
+    check_cfg(&binding, "wc_ecc_init_id", "ecc_key_id");
     if check_cfg(&binding, "ecc_curve_ids_ECC_CURVE_INVALID", "ecc_curve_ids") {
         check_cfg(&binding, "ecc_curve_ids_ECC_SM2P256V1", "ecc_curve_sm2p256v1");
         check_cfg(&binding, "ecc_curve_ids_ECC_X25519", "ecc_curve_25519");
@@ -435,6 +466,12 @@ fn scan_cfg() -> Result<()> {
     /* fips */
     check_cfg(&binding, "wc_SetSeed_Cb_fips", "fips");
 
+    /* frodokem / FrodoKEM */
+    check_cfg(&binding, "wc_FrodoKemKey_Init", "frodokem");
+    check_cfg(&binding, "wc_FrodoKemKey_MakeKey", "frodokem_make_key");
+    check_cfg(&binding, "wc_FrodoKemKey_Encapsulate", "frodokem_encapsulate");
+    check_cfg(&binding, "wc_FrodoKemKey_Decapsulate", "frodokem_decapsulate");
+
     /* hkdf */
     check_cfg(&binding, "wc_HKDF_Extract_ex", "hkdf");
 
@@ -450,6 +487,27 @@ fn scan_cfg() -> Result<()> {
     check_cfg(&binding, "wc_SSH_KDF", "kdf_ssh");
     check_cfg(&binding, "wc_Tls13_HKDF_Extract_ex", "kdf_tls13");
 
+    /* logging */
+    check_cfg(&binding, "wc_PullErrorNode", "error_queue");
+    check_cfg(&binding, "wc_debug_trace_error_codes_set", "debug_trace_error_codes");
+
+    /* memory */
+    check_cfg(&binding, "wolfSSL_SetAllocators", "memory_callbacks");
+    check_cfg(&binding, "wc_LoadStaticMemory", "static_memory");
+
+    /* pkcs11 */
+    check_cfg(&binding, "wc_Pkcs11_Initialize", "pkcs11");
+
+    /* pkcs12 */
+    check_cfg(&binding, "wc_PKCS12_create", "pkcs12");
+
+    /* pkcs7 */
+    check_cfg(&binding, "wc_PKCS7_New", "pkcs7");
+
+    /* pkcs8 */
+    check_cfg(&binding, "wc_CreatePKCS8Key", "pkcs8");
+    check_cfg(&binding, "wc_EncryptPKCS8Key_ex", "pkcs8_encrypt");
+
     /* prf */
     check_cfg(&binding, "wc_PRF", "prf");
 
@@ -476,7 +534,10 @@ fn scan_cfg() -> Result<()> {
     /* rsa */
     check_cfg(&binding, "wc_InitRsaKey", "rsa");
     check_cfg(&binding, "wc_RsaDirect", "rsa_direct");
+    check_cfg(&binding, "wc_InitRsaKey_Id", "rsa_key_id");
     check_cfg(&binding, "wc_MakeRsaKey", "rsa_keygen");
+    check_cfg(&binding, "wc_RsaKeyToDer", "rsa_key_to_der");
+    check_cfg(&binding, "wc_RsaKeyToPublicDer", "rsa_key_to_public_der");
     check_cfg(&binding, "wc_RsaPSS_Sign", "rsa_pss");
     check_cfg(&binding, "wc_RsaPublicEncrypt_ex", "rsa_oaep");
     check_cfg(&binding, "wc_RsaSetRNG", "rsa_setrng");
@@ -499,6 +560,9 @@ fn scan_cfg() -> Result<()> {
     check_cfg(&binding, "wc_MlDsaKey_ImportPubRaw", "mldsa_import");
     check_cfg(&binding, "wc_MlDsaKey_ExportPubRaw", "mldsa_export");
     check_cfg(&binding, "wc_MlDsaKey_CheckKey", "mldsa_check_key");
+    check_cfg(&binding, "wc_MlDsaKey_PrivateKeyToDer", "mldsa_private_key_to_der");
+    check_cfg(&binding, "wc_MlDsaKey_PublicKeyToDer", "mldsa_public_key_to_der");
+    check_cfg(&binding, "wc_MlDsaKey_PublicKeyDecode", "mldsa_public_key_decode");
     check_cfg(&binding, "WC_MLDSA_44_KEY_SIZE", "mldsa_level2");
     check_cfg(&binding, "WC_MLDSA_65_KEY_SIZE", "mldsa_level3");
     check_cfg(&binding, "WC_MLDSA_87_KEY_SIZE", "mldsa_level5");
@@ -512,6 +576,17 @@ fn scan_cfg() -> Result<()> {
     check_cfg(&binding, "wc_LmsParm_WC_LMS_PARM_L1_H5_W1", "lms_sha256_256");
     check_cfg(&binding, "wc_LmsParm_WC_LMS_PARM_SHA256_192_L1_H5_W1", "lms_sha256_192");
 
+    /* xmss / XMSS^MT */
+    check_cfg(&binding, "wc_XmssKey_Init", "xmss");
+    check_cfg(&binding, "wc_XmssKey_MakeKey", "xmss_make_key");
+    check_cfg(&binding, "wc_XmssKey_GetParamStr", "xmss_get_param_str");
+    check_cfg(&binding, "wc_XmssKey_ImportPubRaw_ex", "xmss_import_pub_raw_ex");
+
+    /* slhdsa / SLH-DSA */
+    check_cfg(&binding, "wc_SlhDsaKey_Init", "slhdsa");
+    check_cfg(&binding, "wc_SlhDsaKey_MakeKey", "slhdsa_make_key");
+    check_cfg(&binding, "SlhDsaParam_SLHDSA_SHA2_128S", "slhdsa_sha2");
+
     /* sha */
     check_cfg(&binding, "wc_InitSha", "sha");
     check_cfg(&binding, "wc_InitSha224", "sha224");
@@ -524,5 +599,15 @@ fn scan_cfg() -> Result<()> {
     check_cfg(&binding, "wc_InitShake128", "shake128");
     check_cfg(&binding, "wc_InitShake256", "shake256");
 
+    /* tsp */
+    check_cfg(&binding, "wc_TspRequest_Decode", "tsp");
+    check_cfg(&binding, "wc_TspRequest_Init", "tsp_requester");
+    check_cfg(&binding, "wc_TspTstInfo_Init", "tsp_responder");
+    check_cfg(&binding, "wc_TspResponse_Decode", "tsp_verifier");
+    check_cfg(&binding, "wc_TspTstInfo_Sign", "tsp_sign");
+    check_cfg(&binding, "wc_TspResponse_Verify", "tsp_verify");
+    check_cfg(&binding, "wc_TspTstInfo_CheckGenTime", "tsp_check_gen_time");
+    check_cfg(&binding, "wc_TspTstInfo_GetGenTimeAsTime", "tsp_gen_time_as_time");
+
     Ok(())
 }
@@ -7,6 +7,7 @@
 #include "wolfssl/wolfcrypt/rsa.h"
 #include "wolfssl/wolfcrypt/sha256.h"
 #include "wolfssl/wolfcrypt/curve25519.h"
+#include "wolfssl/wolfcrypt/curve448.h"
 #include "wolfssl/wolfcrypt/ed25519.h"
 #include "wolfssl/wolfcrypt/ed448.h"
 #include "wolfssl/wolfcrypt/ecc.h"
@@ -15,10 +16,18 @@
 #include "wolfssl/wolfcrypt/chacha20_poly1305.h"
 #include "wolfssl/wolfcrypt/kdf.h"
 #include "wolfssl/wolfcrypt/coding.h"
+#include "wolfssl/wolfcrypt/cryptocb.h"
 #include "wolfssl/wolfcrypt/signature.h"
 #include "wolfssl/wolfcrypt/logging.h"
 #include "wolfssl/wolfcrypt/aes.h"
 #include "wolfssl/wolfcrypt/pwdbased.h"
+#include "wolfssl/wolfcrypt/pkcs7.h"
+#include "wolfssl/wolfcrypt/pkcs12.h"
+#include "wolfssl/wolfcrypt/wc_pkcs11.h"
+#include "wolfssl/wolfcrypt/tsp.h"
 #include "wolfssl/wolfcrypt/wc_mldsa.h"
 #include "wolfssl/wolfcrypt/wc_mlkem.h"
+#include "wolfssl/wolfcrypt/wc_frodokem.h"
 #include "wolfssl/wolfcrypt/wc_lms.h"
+#include "wolfssl/wolfcrypt/wc_xmss.h"
+#include "wolfssl/wolfcrypt/wc_slhdsa.h"
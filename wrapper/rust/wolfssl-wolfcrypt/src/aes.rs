@@ -25,7 +25,10 @@ Encryption Standard (AES) functionality.
 
 #![cfg(aes)]
 
+use crate::Error;
+use crate::memory::HeapHint;
 use crate::sys;
+use core::marker::PhantomData;
 use core::mem::MaybeUninit;
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
@@ -59,7 +62,9 @@ pub const AES_BLOCK_SIZE: usize = sys::AES_BLOCK_SIZE as usize;
 /// #[cfg(aes_cbc)]
 /// {
 /// use wolfssl_wolfcrypt::aes::CBC;
-/// let mut cbc = CBC::new().expect("Failed to create CBC");
+/// use wolfssl_wolfcrypt::WolfCrypt;
+/// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+/// let mut cbc = CBC::new(&wolfcrypt).expect("Failed to create CBC");
 /// let key: &[u8; 16] = b"0123456789abcdef";
 /// let iv: &[u8; 16] = b"1234567890abcdef";
 /// let msg: [u8; 16] = [
@@ -81,49 +86,56 @@ pub const AES_BLOCK_SIZE: usize = sys::AES_BLOCK_SIZE as usize;
 /// }
 /// ```
 #[cfg(aes_cbc)]
-pub struct CBC {
+pub struct CBC<'a> {
     ws_aes: sys::Aes,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 #[cfg(aes_cbc)]
-impl CBC {
+impl<'a> CBC<'a> {
     /// Create a new `CBC` instance.
     ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
     /// # Returns
     ///
     /// A Result which is Ok(CBC) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn new() -> Result<Self, i32> {
-        Self::new_ex(None, None)
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&crate::Env::new(wolfcrypt))
     }
 
     /// Create a new `CBC` instance with optional heap and device ID.
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
     /// A Result which is Ok(CBC) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn new_ex(heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
-        let ws_aes = new_ws_aes(heap, dev_id)?;
-        let cbc = CBC {ws_aes};
+    pub fn new_ex(env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let ws_aes = new_ws_aes(env)?;
+        let cbc = CBC {ws_aes, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData};
         Ok(cbc)
     }
 
-    fn init(&mut self, key: &[u8], iv: &[u8], dir: i32) -> Result<(), i32> {
+    fn init(&mut self, key: &[u8], iv: &[u8], dir: i32) -> Result<(), Error> {
         let key_size = crate::buffer_len_to_u32(key.len())?;
         if iv.len() != AES_BLOCK_SIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesSetKey(&mut self.ws_aes, key.as_ptr(), key_size,
                 iv.as_ptr(), dir)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -143,7 +155,7 @@ impl CBC {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn init_encrypt(&mut self, key: &[u8], iv: &[u8]) -> Result<(), i32> {
+    pub fn init_encrypt(&mut self, key: &[u8], iv: &[u8]) -> Result<(), Error> {
         self.init(key, iv, sys::AES_ENCRYPTION as i32)
     }
 
@@ -162,7 +174,7 @@ impl CBC {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn init_decrypt(&mut self, key: &[u8], iv: &[u8]) -> Result<(), i32> {
+    pub fn init_decrypt(&mut self, key: &[u8], iv: &[u8]) -> Result<(), Error> {
         self.init(key, iv, sys::AES_DECRYPTION as i32)
     }
 
@@ -181,17 +193,17 @@ impl CBC {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn encrypt(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), i32> {
+    pub fn encrypt(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), Error> {
         let in_size = crate::buffer_len_to_u32(din.len())?;
         let out_size = crate::buffer_len_to_u32(dout.len())?;
         if in_size != out_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesCbcEncrypt(&mut self.ws_aes, dout.as_mut_ptr(), din.as_ptr(), in_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -211,29 +223,29 @@ impl CBC {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn decrypt(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), i32> {
+    pub fn decrypt(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), Error> {
         let in_size = crate::buffer_len_to_u32(din.len())?;
         let out_size = crate::buffer_len_to_u32(dout.len())?;
         if in_size != out_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesCbcDecrypt(&mut self.ws_aes, dout.as_mut_ptr(), din.as_ptr(), in_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
 }
 #[cfg(aes_cbc)]
-impl CBC {
+impl<'a> CBC<'a> {
     fn zeroize(&mut self) {
         unsafe { crate::zeroize_raw(&mut self.ws_aes); }
     }
 }
 #[cfg(aes_cbc)]
-impl Drop for CBC {
+impl Drop for CBC<'_> {
     /// Safely free the wolfSSL resources.
     fn drop(&mut self) {
         unsafe { sys::wc_AesFree(&mut self.ws_aes); }
@@ -248,6 +260,8 @@ impl Drop for CBC {
 /// #[cfg(aes_ccm)]
 /// {
 /// use wolfssl_wolfcrypt::aes::CCM;
+/// use wolfssl_wolfcrypt::WolfCrypt;
+/// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
 /// let key: [u8; 16] = [
 ///     0xc0, 0xc1, 0xc2, 0xc3, 0xc4, 0xc5, 0xc6, 0xc7,
 ///     0xc8, 0xc9, 0xca, 0xcb, 0xcc, 0xcd, 0xce, 0xcf
@@ -272,7 +286,7 @@ impl Drop for CBC {
 ///     0x17, 0xe8, 0xd1, 0x2c, 0xfd, 0xf9, 0x26, 0xe0
 /// ];
 ///
-/// let mut ccm = CCM::new().expect("Failed to create CCM");
+/// let mut ccm = CCM::new(&wolfcrypt).expect("Failed to create CCM");
 /// ccm.init(&key).expect("Error with init()");
 /// let mut auth_tag_out: [u8; 8] = [0; 8];
 /// let mut cipher_out: [u8; 23] = [0; 23];
@@ -288,35 +302,42 @@ impl Drop for CBC {
 /// }
 /// ```
 #[cfg(aes_ccm)]
-pub struct CCM {
+pub struct CCM<'a> {
     ws_aes: sys::Aes,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 #[cfg(aes_ccm)]
-impl CCM {
+impl<'a> CCM<'a> {
     /// Create a new `CCM` instance.
     ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
     /// # Returns
     ///
     /// A Result which is Ok(CCM) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn new() -> Result<Self, i32> {
-        Self::new_ex(None, None)
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&crate::Env::new(wolfcrypt))
     }
 
     /// Create a new `CCM` instance with optional heap and device ID.
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
     /// A Result which is Ok(CCM) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn new_ex(heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
-        let ws_aes = new_ws_aes(heap, dev_id)?;
-        let ccm = CCM {ws_aes};
+    pub fn new_ex(env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let ws_aes = new_ws_aes(env)?;
+        let ccm = CCM {ws_aes, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData};
         Ok(ccm)
     }
 
@@ -333,13 +354,13 @@ impl CCM {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn init(&mut self, key: &[u8]) -> Result<(), i32> {
+    pub fn init(&mut self, key: &[u8]) -> Result<(), Error> {
         let key_size = crate::buffer_len_to_u32(key.len())?;
         let rc = unsafe {
             sys::wc_AesCcmSetKey(&mut self.ws_aes, key.as_ptr(), key_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -361,14 +382,14 @@ impl CCM {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn encrypt(&mut self, din: &[u8], dout: &mut [u8], nonce: &[u8], auth: &[u8], auth_tag: &mut [u8]) -> Result<(), i32> {
+    pub fn encrypt(&mut self, din: &[u8], dout: &mut [u8], nonce: &[u8], auth: &[u8], auth_tag: &mut [u8]) -> Result<(), Error> {
         let in_size = crate::buffer_len_to_u32(din.len())?;
         let out_size = crate::buffer_len_to_u32(dout.len())?;
         let nonce_size = crate::buffer_len_to_u32(nonce.len())?;
         let auth_size = crate::buffer_len_to_u32(auth.len())?;
         let auth_tag_size = crate::buffer_len_to_u32(auth_tag.len())?;
         if in_size != out_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesCcmEncrypt(&mut self.ws_aes, dout.as_mut_ptr(), din.as_ptr(), in_size,
@@ -376,7 +397,7 @@ impl CCM {
                 auth.as_ptr(), auth_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -398,14 +419,14 @@ impl CCM {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn decrypt(&mut self, din: &[u8], dout: &mut [u8], nonce: &[u8], auth: &[u8], auth_tag: &[u8]) -> Result<(), i32> {
+    pub fn decrypt(&mut self, din: &[u8], dout: &mut [u8], nonce: &[u8], auth: &[u8], auth_tag: &[u8]) -> Result<(), Error> {
         let in_size = crate::buffer_len_to_u32(din.len())?;
         let out_size = crate::buffer_len_to_u32(dout.len())?;
         let nonce_size = crate::buffer_len_to_u32(nonce.len())?;
         let auth_size = crate::buffer_len_to_u32(auth.len())?;
         let auth_tag_size = crate::buffer_len_to_u32(auth_tag.len())?;
         if in_size != out_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesCcmDecrypt(&mut self.ws_aes, dout.as_mut_ptr(), din.as_ptr(), in_size,
@@ -413,19 +434,19 @@ impl CCM {
                 auth.as_ptr(), auth_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
 }
 #[cfg(aes_ccm)]
-impl CCM {
+impl<'a> CCM<'a> {
     fn zeroize(&mut self) {
         unsafe { crate::zeroize_raw(&mut self.ws_aes); }
     }
 }
 #[cfg(aes_ccm)]
-impl Drop for CCM {
+impl Drop for CCM<'_> {
     /// Safely free the wolfSSL resources.
     fn drop(&mut self) {
         unsafe { sys::wc_AesFree(&mut self.ws_aes); }
@@ -450,7 +471,8 @@ fn ccm_encrypt_in_place(
         || tag.len() > u32::MAX as usize || aad.len() > u32::MAX as usize {
         return Err(aead::Error);
     }
-    let mut ccm = CCM::new().map_err(|_| aead::Error)?;
+    let wolfcrypt = crate::WolfCrypt::global().map_err(|_| aead::Error)?;
+    let mut ccm = CCM::new(wolfcrypt).map_err(|_| aead::Error)?;
     ccm.init(key).map_err(|_| aead::Error)?;
     // wolfCrypt CCM supports in-place operation (out == in).
     let buf_ptr = buffer.as_mut_ptr();
@@ -483,7 +505,8 @@ fn ccm_decrypt_in_place(
         || tag.len() > u32::MAX as usize || aad.len() > u32::MAX as usize {
         return Err(aead::Error);
     }
-    let mut ccm = CCM::new().map_err(|_| aead::Error)?;
+    let wolfcrypt = crate::WolfCrypt::global().map_err(|_| aead::Error)?;
+    let mut ccm = CCM::new(wolfcrypt).map_err(|_| aead::Error)?;
     ccm.init(key).map_err(|_| aead::Error)?;
     let buf_ptr = buffer.as_mut_ptr();
     let in_ptr = buf_ptr as *const u8;
@@ -665,7 +688,9 @@ impl AeadInPlace for Aes256Ccm {
 /// #[cfg(aes_cfb)]
 /// {
 /// use wolfssl_wolfcrypt::aes::CFB;
-/// let mut cfb = CFB::new().expect("Failed to create CFB");
+/// use wolfssl_wolfcrypt::WolfCrypt;
+/// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+/// let mut cfb = CFB::new(&wolfcrypt).expect("Failed to create CFB");
 /// let key: [u8; 16] = [
 ///     0x2b,0x7e,0x15,0x16,0x28,0xae,0xd2,0xa6,
 ///     0xab,0xf7,0x15,0x88,0x09,0xcf,0x4f,0x3c
@@ -705,35 +730,42 @@ impl AeadInPlace for Aes256Ccm {
 /// }
 /// ```
 #[cfg(aes_cfb)]
-pub struct CFB {
+pub struct CFB<'a> {
     ws_aes: sys::Aes,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 #[cfg(aes_cfb)]
-impl CFB {
+impl<'a> CFB<'a> {
     /// Create a new `CFB` instance.
     ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
     /// # Returns
     ///
     /// A Result which is Ok(CFB) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn new() -> Result<Self, i32> {
-        Self::new_ex(None, None)
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&crate::Env::new(wolfcrypt))
     }
 
     /// Create a new `CFB` instance with optional heap and device ID.
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
     /// A Result which is Ok(CFB) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn new_ex(heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
-        let ws_aes = new_ws_aes(heap, dev_id)?;
-        let cfb = CFB {ws_aes};
+    pub fn new_ex(env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let ws_aes = new_ws_aes(env)?;
+        let cfb = CFB {ws_aes, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData};
         Ok(cfb)
     }
 
@@ -753,17 +785,17 @@ impl CFB {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn init(&mut self, key: &[u8], iv: &[u8]) -> Result<(), i32> {
+    pub fn init(&mut self, key: &[u8], iv: &[u8]) -> Result<(), Error> {
         let key_size = crate::buffer_len_to_u32(key.len())?;
         if iv.len() != AES_BLOCK_SIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesSetKey(&mut self.ws_aes, key.as_ptr(), key_size,
                 iv.as_ptr(), sys::AES_ENCRYPTION as i32)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -782,17 +814,17 @@ impl CFB {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn encrypt(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), i32> {
+    pub fn encrypt(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), Error> {
         let in_size = crate::buffer_len_to_u32(din.len())?;
         let out_size = crate::buffer_len_to_u32(dout.len())?;
         if in_size != out_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesCfbEncrypt(&mut self.ws_aes, dout.as_mut_ptr(), din.as_ptr(), in_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -813,19 +845,19 @@ impl CFB {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn encrypt1(&mut self, din: &[u8], dout: &mut [u8], size: usize) -> Result<(), i32> {
+    pub fn encrypt1(&mut self, din: &[u8], dout: &mut [u8], size: usize) -> Result<(), Error> {
         if din.len() != dout.len() {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         if din.len() < size.div_ceil(8) {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let bit_size = crate::buffer_len_to_u32(size)?;
         let rc = unsafe {
             sys::wc_AesCfb1Encrypt(&mut self.ws_aes, dout.as_mut_ptr(), din.as_ptr(), bit_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -844,17 +876,17 @@ impl CFB {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn encrypt8(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), i32> {
+    pub fn encrypt8(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), Error> {
         let in_size = crate::buffer_len_to_u32(din.len())?;
         let out_size = crate::buffer_len_to_u32(dout.len())?;
         if in_size != out_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesCfb8Encrypt(&mut self.ws_aes, dout.as_mut_ptr(), din.as_ptr(), in_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -874,17 +906,17 @@ impl CFB {
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
     #[cfg(aes_decrypt)]
-    pub fn decrypt(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), i32> {
+    pub fn decrypt(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), Error> {
         let in_size = crate::buffer_len_to_u32(din.len())?;
         let out_size = crate::buffer_len_to_u32(dout.len())?;
         if in_size != out_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesCfbDecrypt(&mut self.ws_aes, dout.as_mut_ptr(), din.as_ptr(), in_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -906,19 +938,19 @@ impl CFB {
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
     #[cfg(aes_decrypt)]
-    pub fn decrypt1(&mut self, din: &[u8], dout: &mut [u8], size: usize) -> Result<(), i32> {
+    pub fn decrypt1(&mut self, din: &[u8], dout: &mut [u8], size: usize) -> Result<(), Error> {
         if din.len() != dout.len() {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         if din.len() < size.div_ceil(8) {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let bit_size = crate::buffer_len_to_u32(size)?;
         let rc = unsafe {
             sys::wc_AesCfb1Decrypt(&mut self.ws_aes, dout.as_mut_ptr(), din.as_ptr(), bit_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -938,29 +970,29 @@ impl CFB {
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
     #[cfg(aes_decrypt)]
-    pub fn decrypt8(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), i32> {
+    pub fn decrypt8(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), Error> {
         let in_size = crate::buffer_len_to_u32(din.len())?;
         let out_size = crate::buffer_len_to_u32(dout.len())?;
         if in_size != out_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesCfb8Decrypt(&mut self.ws_aes, dout.as_mut_ptr(), din.as_ptr(), in_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
 }
 #[cfg(aes_cfb)]
-impl CFB {
+impl<'a> CFB<'a> {
     fn zeroize(&mut self) {
         unsafe { crate::zeroize_raw(&mut self.ws_aes); }
     }
 }
 #[cfg(aes_cfb)]
-impl Drop for CFB {
+impl Drop for CFB<'_> {
     /// Safely free the wolfSSL resources.
     fn drop(&mut self) {
         unsafe { sys::wc_AesFree(&mut self.ws_aes); }
@@ -975,6 +1007,8 @@ impl Drop for CFB {
 /// #[cfg(aes_ctr)]
 /// {
 /// use wolfssl_wolfcrypt::aes::CTR;
+/// use wolfssl_wolfcrypt::WolfCrypt;
+/// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
 /// let iv: [u8; 16] = [
 ///     0xf0,0xf1,0xf2,0xf3,0xf4,0xf5,0xf6,0xf7,
 ///     0xf8,0xf9,0xfa,0xfb,0xfc,0xfd,0xfe,0xff
@@ -1003,7 +1037,7 @@ impl Drop for CFB {
 ///     0x1e,0x03,0x1d,0xda,0x2f,0xbe,0x03,0xd1,
 ///     0x79,0x21,0x70,0xa0,0xf3,0x00,0x9c,0xee
 /// ];
-/// let mut ctr = CTR::new().expect("Failed to create CTR");
+/// let mut ctr = CTR::new(&wolfcrypt).expect("Failed to create CTR");
 /// ctr.init(&key, &iv).expect("Error with init()");
 /// let mut outbuf: [u8; 64] = [0; 64];
 /// ctr.encrypt(&msg, &mut outbuf).expect("Error with encrypt()");
@@ -1015,35 +1049,42 @@ impl Drop for CFB {
 /// }
 /// ```
 #[cfg(aes_ctr)]
-pub struct CTR {
+pub struct CTR<'a> {
     ws_aes: sys::Aes,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 #[cfg(aes_ctr)]
-impl CTR {
+impl<'a> CTR<'a> {
     /// Create a new `CTR` instance.
     ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
     /// # Returns
     ///
     /// A Result which is Ok(CTR) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn new() -> Result<Self, i32> {
-        Self::new_ex(None, None)
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&crate::Env::new(wolfcrypt))
     }
 
     /// Create a new `CTR` instance with optional heap and device ID.
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
     /// A Result which is Ok(CTR) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn new_ex(heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
-        let ws_aes = new_ws_aes(heap, dev_id)?;
-        let ctr = CTR {ws_aes};
+    pub fn new_ex(env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let ws_aes = new_ws_aes(env)?;
+        let ctr = CTR {ws_aes, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData};
         Ok(ctr)
     }
 
@@ -1062,32 +1103,32 @@ impl CTR {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn init(&mut self, key: &[u8], iv: &[u8]) -> Result<(), i32> {
+    pub fn init(&mut self, key: &[u8], iv: &[u8]) -> Result<(), Error> {
         let key_size = crate::buffer_len_to_u32(key.len())?;
         if iv.len() != AES_BLOCK_SIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesSetKeyDirect(&mut self.ws_aes, key.as_ptr(), key_size,
                 iv.as_ptr(), sys::AES_ENCRYPTION as i32)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
 
-    fn encrypt_decrypt(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), i32> {
+    fn encrypt_decrypt(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), Error> {
         let in_size = crate::buffer_len_to_u32(din.len())?;
         let out_size = crate::buffer_len_to_u32(dout.len())?;
         if in_size != out_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesCtrEncrypt(&mut self.ws_aes, dout.as_mut_ptr(), din.as_ptr(), in_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1106,7 +1147,7 @@ impl CTR {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn encrypt(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), i32> {
+    pub fn encrypt(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), Error> {
         self.encrypt_decrypt(din, dout)
     }
 
@@ -1124,18 +1165,18 @@ impl CTR {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn decrypt(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), i32> {
+    pub fn decrypt(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), Error> {
         self.encrypt_decrypt(din, dout)
     }
 }
 #[cfg(aes_ctr)]
-impl CTR {
+impl<'a> CTR<'a> {
     fn zeroize(&mut self) {
         unsafe { crate::zeroize_raw(&mut self.ws_aes); }
     }
 }
 #[cfg(aes_ctr)]
-impl Drop for CTR {
+impl Drop for CTR<'_> {
     /// Safely free the wolfSSL resources.
     fn drop(&mut self) {
         unsafe { sys::wc_AesFree(&mut self.ws_aes); }
@@ -1208,7 +1249,7 @@ impl EAX {
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
     pub fn encrypt(din: &[u8], dout: &mut [u8], key: &[u8], nonce: &[u8],
-            auth: &[u8], auth_tag: &mut [u8]) -> Result<(), i32> {
+            auth: &[u8], auth_tag: &mut [u8]) -> Result<(), Error> {
         let in_size = crate::buffer_len_to_u32(din.len())?;
         let out_size = crate::buffer_len_to_u32(dout.len())?;
         let key_size = crate::buffer_len_to_u32(key.len())?;
@@ -1216,7 +1257,7 @@ impl EAX {
         let auth_size = crate::buffer_len_to_u32(auth.len())?;
         let auth_tag_size = crate::buffer_len_to_u32(auth_tag.len())?;
         if in_size != out_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesEaxEncryptAuth(key.as_ptr(), key_size, dout.as_mut_ptr(),
@@ -1224,7 +1265,7 @@ impl EAX {
                 auth_tag.as_mut_ptr(), auth_tag_size, auth.as_ptr(), auth_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1247,7 +1288,7 @@ impl EAX {
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
     pub fn decrypt(din: &[u8], dout: &mut [u8], key: &[u8], nonce: &[u8],
-            auth: &[u8], auth_tag: &[u8]) -> Result<(), i32> {
+            auth: &[u8], auth_tag: &[u8]) -> Result<(), Error> {
         let in_size = crate::buffer_len_to_u32(din.len())?;
         let out_size = crate::buffer_len_to_u32(dout.len())?;
         let key_size = crate::buffer_len_to_u32(key.len())?;
@@ -1255,7 +1296,7 @@ impl EAX {
         let auth_size = crate::buffer_len_to_u32(auth.len())?;
         let auth_tag_size = crate::buffer_len_to_u32(auth_tag.len())?;
         if in_size != out_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesEaxDecryptAuth(key.as_ptr(), key_size, dout.as_mut_ptr(),
@@ -1263,7 +1304,7 @@ impl EAX {
                 auth_tag.as_ptr(), auth_tag_size, auth.as_ptr(), auth_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1276,7 +1317,9 @@ impl EAX {
 /// #[cfg(aes_ecb)]
 /// {
 /// use wolfssl_wolfcrypt::aes::ECB;
-/// let mut ecb = ECB::new().expect("Failed to create ECB");
+/// use wolfssl_wolfcrypt::WolfCrypt;
+/// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+/// let mut ecb = ECB::new(&wolfcrypt).expect("Failed to create ECB");
 /// let key_128: &[u8; 16] = b"0123456789abcdef";
 /// let msg: [u8; 16] = [
 ///     0x6e, 0x6f, 0x77, 0x20, 0x69, 0x73, 0x20, 0x74,
@@ -1297,46 +1340,53 @@ impl EAX {
 /// }
 /// ```
 #[cfg(aes_ecb)]
-pub struct ECB {
+pub struct ECB<'a> {
     ws_aes: sys::Aes,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 #[cfg(aes_ecb)]
-impl ECB {
+impl<'a> ECB<'a> {
     /// Create a new `ECB` instance.
     ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
     /// # Returns
     ///
     /// A Result which is Ok(ECB) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn new() -> Result<Self, i32> {
-        Self::new_ex(None, None)
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&crate::Env::new(wolfcrypt))
     }
 
     /// Create a new `ECB` instance with optional heap and device ID.
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
     /// A Result which is Ok(ECB) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn new_ex(heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
-        let ws_aes = new_ws_aes(heap, dev_id)?;
-        let ecb = ECB {ws_aes};
+    pub fn new_ex(env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let ws_aes = new_ws_aes(env)?;
+        let ecb = ECB {ws_aes, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData};
         Ok(ecb)
     }
 
-    fn init(&mut self, key: &[u8], dir: i32) -> Result<(), i32> {
+    fn init(&mut self, key: &[u8], dir: i32) -> Result<(), Error> {
         let key_size = crate::buffer_len_to_u32(key.len())?;
         let rc = unsafe {
             sys::wc_AesSetKey(&mut self.ws_aes, key.as_ptr(), key_size,
                 core::ptr::null(), dir)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1354,7 +1404,7 @@ impl ECB {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn init_encrypt(&mut self, key: &[u8]) -> Result<(), i32> {
+    pub fn init_encrypt(&mut self, key: &[u8]) -> Result<(), Error> {
         self.init(key, sys::AES_ENCRYPTION as i32)
     }
 
@@ -1371,7 +1421,7 @@ impl ECB {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn init_decrypt(&mut self, key: &[u8]) -> Result<(), i32> {
+    pub fn init_decrypt(&mut self, key: &[u8]) -> Result<(), Error> {
         self.init(key, sys::AES_DECRYPTION as i32)
     }
 
@@ -1390,17 +1440,17 @@ impl ECB {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn encrypt(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), i32> {
+    pub fn encrypt(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), Error> {
         let in_size = crate::buffer_len_to_u32(din.len())?;
         let out_size = crate::buffer_len_to_u32(dout.len())?;
         if in_size != out_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesEcbEncrypt(&mut self.ws_aes, dout.as_mut_ptr(), din.as_ptr(), in_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1420,29 +1470,29 @@ impl ECB {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn decrypt(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), i32> {
+    pub fn decrypt(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), Error> {
         let in_size = crate::buffer_len_to_u32(din.len())?;
         let out_size = crate::buffer_len_to_u32(dout.len())?;
         if in_size != out_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesEcbDecrypt(&mut self.ws_aes, dout.as_mut_ptr(), din.as_ptr(), in_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
 }
 #[cfg(aes_ecb)]
-impl ECB {
+impl<'a> ECB<'a> {
     fn zeroize(&mut self) {
         unsafe { crate::zeroize_raw(&mut self.ws_aes); }
     }
 }
 #[cfg(aes_ecb)]
-impl Drop for ECB {
+impl Drop for ECB<'_> {
     /// Safely free the wolfSSL resources.
     fn drop(&mut self) {
         unsafe { sys::wc_AesFree(&mut self.ws_aes); }
@@ -1460,6 +1510,8 @@ impl Drop for ECB {
 /// #[cfg(aes_gcm)]
 /// {
 /// use wolfssl_wolfcrypt::aes::GCM;
+/// use wolfssl_wolfcrypt::WolfCrypt;
+/// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
 /// let key: [u8; 16] = [
 ///     0x29, 0x8e, 0xfa, 0x1c, 0xcf, 0x29, 0xcf, 0x62,
 ///     0xae, 0x68, 0x24, 0xbf, 0xc1, 0x95, 0x57, 0xfc
@@ -1488,7 +1540,7 @@ impl Drop for ECB {
 ///     0x54, 0x24, 0x65, 0xef, 0x59, 0x93, 0x16, 0xf7,
 ///     0x3a, 0x7a, 0x56, 0x05, 0x09, 0xa2, 0xd9, 0xf2
 /// ];
-/// let mut gcm = GCM::new().expect("Failed to create GCM");
+/// let mut gcm = GCM::new(&wolfcrypt).expect("Failed to create GCM");
 /// gcm.init(&key).expect("Error with init()");
 /// let mut cipher: [u8; 32] = [0; 32];
 /// let mut auth_tag: [u8; 16] = [0; 16];
@@ -1501,35 +1553,90 @@ impl Drop for ECB {
 /// }
 /// ```
 #[cfg(aes_gcm)]
-pub struct GCM {
+pub struct GCM<'a> {
     ws_aes: sys::Aes,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 #[cfg(aes_gcm)]
-impl GCM {
+impl<'a> GCM<'a> {
     /// Create a new `GCM` instance.
     ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
     /// # Returns
     ///
     /// A Result which is Ok(GCM) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn new() -> Result<Self, i32> {
-        Self::new_ex(None, None)
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&crate::Env::new(wolfcrypt))
     }
 
     /// Create a new `GCM` instance with optional heap and device ID.
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
+    ///
+    /// # Returns
+    ///
+    /// A Result which is Ok(GCM) on success or an Err containing the wolfSSL
+    /// library return code on failure.
+    pub fn new_ex(env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let ws_aes = new_ws_aes(env)?;
+        let gcm = GCM {ws_aes, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData};
+        Ok(gcm)
+    }
+
+    /// Create a new `GCM` instance referring to an AES key held by a crypto
+    /// callback device (such as a PKCS#11 token) under the given key ID.
+    ///
+    /// `init()` does not need to be called; `encrypt()` and `decrypt()` are
+    /// performed by the device registered with `dev_id`, which locates the
+    /// key by ID.
+    ///
+    /// # Parameters
+    ///
+    /// * `id`: Key identifier on the device (at most 32 bytes).
+    /// * `env`: Heap hint and crypto callback device to use.
+    ///
+    /// # Returns
+    ///
+    /// A Result which is Ok(GCM) on success or an Err containing the wolfSSL
+    /// library return code on failure.
+    #[cfg(aes_key_id)]
+    pub fn new_from_id(id: &[u8], env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let ws_aes = new_ws_aes_id(id, env)?;
+        let gcm = GCM {ws_aes, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData};
+        Ok(gcm)
+    }
+
+    /// Create a new `GCM` instance referring to an AES key held by a crypto
+    /// callback device (such as a PKCS#11 token) under the given label.
+    ///
+    /// `init()` does not need to be called; `encrypt()` and `decrypt()` are
+    /// performed by the device registered with `dev_id`, which locates the
+    /// key by label.
+    ///
+    /// # Parameters
+    ///
+    /// * `label`: Key label on the device (1 to 32 bytes).
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
     /// A Result which is Ok(GCM) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn new_ex(heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
-        let ws_aes = new_ws_aes(heap, dev_id)?;
-        let gcm = GCM {ws_aes};
+    #[cfg(aes_key_id)]
+    pub fn new_from_label(label: &str, env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let ws_aes = new_ws_aes_label(label, env)?;
+        let gcm = GCM {ws_aes, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData};
         Ok(gcm)
     }
 
@@ -1546,13 +1653,13 @@ impl GCM {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn init(&mut self, key: &[u8]) -> Result<(), i32> {
+    pub fn init(&mut self, key: &[u8]) -> Result<(), Error> {
         let key_size = crate::buffer_len_to_u32(key.len())?;
         let rc = unsafe {
             sys::wc_AesGcmSetKey(&mut self.ws_aes, key.as_ptr(), key_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1575,14 +1682,14 @@ impl GCM {
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
     pub fn encrypt(&mut self, din: &[u8], dout: &mut [u8], iv: &[u8],
-            auth: &[u8], auth_tag: &mut [u8]) -> Result<(), i32> {
+            auth: &[u8], auth_tag: &mut [u8]) -> Result<(), Error> {
         let in_size = crate::buffer_len_to_u32(din.len())?;
         let out_size = crate::buffer_len_to_u32(dout.len())?;
         let iv_size = crate::buffer_len_to_u32(iv.len())?;
         let auth_size = crate::buffer_len_to_u32(auth.len())?;
         let auth_tag_size = crate::buffer_len_to_u32(auth_tag.len())?;
         if in_size != out_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesGcmEncrypt(&mut self.ws_aes, dout.as_mut_ptr(), din.as_ptr(), in_size,
@@ -1590,7 +1697,7 @@ impl GCM {
                 auth.as_ptr(), auth_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1613,14 +1720,14 @@ impl GCM {
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
     pub fn decrypt(&mut self, din: &[u8], dout: &mut [u8], iv: &[u8],
-            auth: &[u8], auth_tag: &[u8]) -> Result<(), i32> {
+            auth: &[u8], auth_tag: &[u8]) -> Result<(), Error> {
         let in_size = crate::buffer_len_to_u32(din.len())?;
         let out_size = crate::buffer_len_to_u32(dout.len())?;
         let iv_size = crate::buffer_len_to_u32(iv.len())?;
         let auth_size = crate::buffer_len_to_u32(auth.len())?;
         let auth_tag_size = crate::buffer_len_to_u32(auth_tag.len())?;
         if in_size != out_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesGcmDecrypt(&mut self.ws_aes, dout.as_mut_ptr(), din.as_ptr(), in_size,
@@ -1628,19 +1735,19 @@ impl GCM {
                 auth.as_ptr(), auth_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
 }
 #[cfg(aes_gcm)]
-impl GCM {
+impl<'a> GCM<'a> {
     fn zeroize(&mut self) {
         unsafe { crate::zeroize_raw(&mut self.ws_aes); }
     }
 }
 #[cfg(aes_gcm)]
-impl Drop for GCM {
+impl Drop for GCM<'_> {
     /// Safely free the wolfSSL resources.
     fn drop(&mut self) {
         unsafe { sys::wc_AesFree(&mut self.ws_aes); }
@@ -1667,7 +1774,8 @@ fn gcm_encrypt_in_place(
         || tag.len() > u32::MAX as usize || aad.len() > u32::MAX as usize {
         return Err(aead::Error);
     }
-    let mut gcm = GCM::new().map_err(|_| aead::Error)?;
+    let wolfcrypt = crate::WolfCrypt::global().map_err(|_| aead::Error)?;
+    let mut gcm = GCM::new(wolfcrypt).map_err(|_| aead::Error)?;
     gcm.init(key).map_err(|_| aead::Error)?;
     let buf_ptr = buffer.as_mut_ptr();
     let in_ptr = buf_ptr as *const u8;
@@ -1699,7 +1807,8 @@ fn gcm_decrypt_in_place(
         || tag.len() > u32::MAX as usize || aad.len() > u32::MAX as usize {
         return Err(aead::Error);
     }
-    let mut gcm = GCM::new().map_err(|_| aead::Error)?;
+    let wolfcrypt = crate::WolfCrypt::global().map_err(|_| aead::Error)?;
+    let mut gcm = GCM::new(wolfcrypt).map_err(|_| aead::Error)?;
     gcm.init(key).map_err(|_| aead::Error)?;
     let buf_ptr = buffer.as_mut_ptr();
     let in_ptr = buf_ptr as *const u8;
@@ -1884,6 +1993,8 @@ impl AeadInPlace for Aes256Gcm {
 /// #[cfg(aes_gcm_stream)]
 /// {
 /// use wolfssl_wolfcrypt::aes::GCMStream;
+/// use wolfssl_wolfcrypt::WolfCrypt;
+/// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
 /// let plain: [u8; 60] = [
 ///     0xd9, 0x31, 0x32, 0x25, 0xf8, 0x84, 0x06, 0xe5,
 ///     0xa5, 0x59, 0x09, 0xc5, 0xaf, 0xf5, 0x26, 0x9a,
@@ -1923,7 +2034,7 @@ impl AeadInPlace for Aes256Gcm {
 ///     0x76, 0xfc, 0x6e, 0xce, 0x0f, 0x4e, 0x17, 0x68,
 ///     0xcd, 0xdf, 0x88, 0x53, 0xbb, 0x2d, 0x55, 0x1b
 /// ];
-/// let mut gcmstream = GCMStream::new().expect("Failed to create GCMStream");
+/// let mut gcmstream = GCMStream::new(&wolfcrypt).expect("Failed to create GCMStream");
 /// for chunk_size in 1..=auth.len() {
 ///     gcmstream.init(&key, &iv).expect("Error with init()");
 ///     let mut cipher: [u8; 60] = [0; 60];
@@ -1953,35 +2064,42 @@ impl AeadInPlace for Aes256Gcm {
 /// }
 /// ```
 #[cfg(aes_gcm_stream)]
-pub struct GCMStream {
+pub struct GCMStream<'a> {
     ws_aes: sys::Aes,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 #[cfg(aes_gcm_stream)]
-impl GCMStream {
+impl<'a> GCMStream<'a> {
     /// Create a new `GCMStream` instance.
     ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
     /// # Returns
     ///
     /// A Result which is Ok(GCMStream) on success or an Err containing the
     /// wolfSSL library return code on failure.
-    pub fn new() -> Result<Self, i32> {
-        Self::new_ex(None, None)
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&crate::Env::new(wolfcrypt))
     }
 
     /// Create a new `GCMStream` instance with heap and device ID.
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
     /// A Result which is Ok(GCMStream) on success or an Err containing the
     /// wolfSSL library return code on failure.
-    pub fn new_ex(heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
-        let ws_aes = new_ws_aes(heap, dev_id)?;
-        let gcmstream = GCMStream {ws_aes};
+    pub fn new_ex(env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let ws_aes = new_ws_aes(env)?;
+        let gcmstream = GCMStream {ws_aes, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData};
         Ok(gcmstream)
     }
 
@@ -2000,7 +2118,7 @@ impl GCMStream {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn init(&mut self, key: &[u8], iv: &[u8]) -> Result<(), i32> {
+    pub fn init(&mut self, key: &[u8], iv: &[u8]) -> Result<(), Error> {
         let key_size = crate::buffer_len_to_u32(key.len())?;
         let iv_size = crate::buffer_len_to_u32(iv.len())?;
         let rc = unsafe {
@@ -2008,7 +2126,7 @@ impl GCMStream {
                 iv.as_ptr(), iv_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -2035,19 +2153,19 @@ impl GCMStream {
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
     pub fn encrypt_update(&mut self, din: &[u8], dout: &mut [u8],
-            auth: &[u8]) -> Result<(), i32> {
+            auth: &[u8]) -> Result<(), Error> {
         let in_size = crate::buffer_len_to_u32(din.len())?;
         let out_size = crate::buffer_len_to_u32(dout.len())?;
         let auth_size = crate::buffer_len_to_u32(auth.len())?;
         if in_size != out_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesGcmEncryptUpdate(&mut self.ws_aes, dout.as_mut_ptr(),
                 din.as_ptr(), in_size, auth.as_ptr(), auth_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -2067,14 +2185,14 @@ impl GCMStream {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn encrypt_final(&mut self, auth_tag: &mut [u8]) -> Result<(), i32> {
+    pub fn encrypt_final(&mut self, auth_tag: &mut [u8]) -> Result<(), Error> {
         let auth_tag_size = crate::buffer_len_to_u32(auth_tag.len())?;
         let rc = unsafe {
             sys::wc_AesGcmEncryptFinal(&mut self.ws_aes,
                 auth_tag.as_mut_ptr(), auth_tag_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -2101,19 +2219,19 @@ impl GCMStream {
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
     pub fn decrypt_update(&mut self, din: &[u8], dout: &mut [u8],
-            auth: &[u8]) -> Result<(), i32> {
+            auth: &[u8]) -> Result<(), Error> {
         let in_size = crate::buffer_len_to_u32(din.len())?;
         let out_size = crate::buffer_len_to_u32(dout.len())?;
         let auth_size = crate::buffer_len_to_u32(auth.len())?;
         if in_size != out_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesGcmDecryptUpdate(&mut self.ws_aes, dout.as_mut_ptr(),
                 din.as_ptr(), in_size, auth.as_ptr(), auth_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -2133,26 +2251,26 @@ impl GCMStream {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn decrypt_final(&mut self, auth_tag: &[u8]) -> Result<(), i32> {
+    pub fn decrypt_final(&mut self, auth_tag: &[u8]) -> Result<(), Error> {
         let auth_tag_size = crate::buffer_len_to_u32(auth_tag.len())?;
         let rc = unsafe {
             sys::wc_AesGcmDecryptFinal(&mut self.ws_aes,
                 auth_tag.as_ptr(), auth_tag_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
 }
 #[cfg(aes_gcm_stream)]
-impl GCMStream {
+impl<'a> GCMStream<'a> {
     fn zeroize(&mut self) {
         unsafe { crate::zeroize_raw(&mut self.ws_aes); }
     }
 }
 #[cfg(aes_gcm_stream)]
-impl Drop for GCMStream {
+impl Drop for GCMStream<'_> {
     /// Safely free the wolfSSL resources.
     fn drop(&mut self) {
         unsafe { sys::wc_AesFree(&mut self.ws_aes); }
@@ -2167,6 +2285,8 @@ impl Drop for GCMStream {
 /// #[cfg(aes_ofb)]
 /// {
 /// use wolfssl_wolfcrypt::aes::OFB;
+/// use wolfssl_wolfcrypt::WolfCrypt;
+/// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
 /// let key: [u8; 32] = [
 ///     0xc4,0xc7,0xfa,0xd6,0x53,0x5c,0xb8,0x71,
 ///     0x4a,0x5c,0x40,0x77,0x9a,0x8b,0xa1,0xd2,
@@ -2193,7 +2313,7 @@ impl Drop for GCMStream {
 ///     0x04,0x53,0xe1,0x73,0xf5,0x18,0x74,0xae,
 ///     0xfd,0x64,0xa2,0xe1,0xe2,0x76,0x13,0xb0
 /// ];
-/// let mut ofb = OFB::new().expect("Failed to create OFB");
+/// let mut ofb = OFB::new(&wolfcrypt).expect("Failed to create OFB");
 /// ofb.init(&key, &iv).expect("Error with init()");
 /// let mut cipher: [u8; 48] = [0; 48];
 /// ofb.encrypt(&plain, &mut cipher).expect("Error with encrypt()");
@@ -2208,35 +2328,42 @@ impl Drop for GCMStream {
 /// }
 /// ```
 #[cfg(aes_ofb)]
-pub struct OFB {
+pub struct OFB<'a> {
     ws_aes: sys::Aes,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 #[cfg(aes_ofb)]
-impl OFB {
+impl<'a> OFB<'a> {
     /// Create a new `OFB` instance.
     ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
     /// # Returns
     ///
     /// A Result which is Ok(OFB) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn new() -> Result<Self, i32> {
-        Self::new_ex(None, None)
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&crate::Env::new(wolfcrypt))
     }
 
     /// Create a new `OFB` instance with optional heap and device ID.
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
     /// A Result which is Ok(OFB) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn new_ex(heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
-        let ws_aes = new_ws_aes(heap, dev_id)?;
-        let ofb = OFB {ws_aes};
+    pub fn new_ex(env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let ws_aes = new_ws_aes(env)?;
+        let ofb = OFB {ws_aes, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData};
         Ok(ofb)
     }
 
@@ -2255,17 +2382,17 @@ impl OFB {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn init(&mut self, key: &[u8], iv: &[u8]) -> Result<(), i32> {
+    pub fn init(&mut self, key: &[u8], iv: &[u8]) -> Result<(), Error> {
         let key_size = crate::buffer_len_to_u32(key.len())?;
         if iv.len() != AES_BLOCK_SIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesSetKey(&mut self.ws_aes, key.as_ptr(),
                 key_size, iv.as_ptr(), sys::AES_ENCRYPTION as i32)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -2284,17 +2411,17 @@ impl OFB {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn encrypt(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), i32> {
+    pub fn encrypt(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), Error> {
         let in_size = crate::buffer_len_to_u32(din.len())?;
         let out_size = crate::buffer_len_to_u32(dout.len())?;
         if in_size != out_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesOfbEncrypt(&mut self.ws_aes, dout.as_mut_ptr(), din.as_ptr(), in_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -2314,29 +2441,29 @@ impl OFB {
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
     #[cfg(aes_decrypt)]
-    pub fn decrypt(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), i32> {
+    pub fn decrypt(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), Error> {
         let in_size = crate::buffer_len_to_u32(din.len())?;
         let out_size = crate::buffer_len_to_u32(dout.len())?;
         if in_size != out_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesOfbDecrypt(&mut self.ws_aes, dout.as_mut_ptr(), din.as_ptr(), in_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
 }
 #[cfg(aes_ofb)]
-impl OFB {
+impl<'a> OFB<'a> {
     fn zeroize(&mut self) {
         unsafe { crate::zeroize_raw(&mut self.ws_aes); }
     }
 }
 #[cfg(aes_ofb)]
-impl Drop for OFB {
+impl Drop for OFB<'_> {
     /// Safely free the wolfSSL resources.
     fn drop(&mut self) {
         unsafe { sys::wc_AesFree(&mut self.ws_aes); }
@@ -2355,6 +2482,8 @@ impl Drop for OFB {
 /// #[cfg(aes_xts)]
 /// {
 /// use wolfssl_wolfcrypt::aes::XTS;
+/// use wolfssl_wolfcrypt::WolfCrypt;
+/// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
 /// let key: [u8; 32] = [
 ///     0xa1, 0xb9, 0x0c, 0xba, 0x3f, 0x06, 0xac, 0x35,
 ///     0x3b, 0x2c, 0x34, 0x38, 0x76, 0x08, 0x17, 0x62,
@@ -2384,7 +2513,7 @@ impl Drop for OFB {
 ///     0x77, 0x8a, 0xe8, 0xb4, 0x3c, 0xb9, 0x8d, 0x5a
 /// ];
 ///
-/// let mut xts = XTS::new().expect("Failed to create XTS");
+/// let mut xts = XTS::new(&wolfcrypt).expect("Failed to create XTS");
 /// xts.init_encrypt(&key).expect("Error with init_encrypt()");
 /// let mut cipher: [u8; 16] = [0; 16];
 /// xts.encrypt(&plain, &mut cipher, &tweak).expect("Error with encrypt()");
@@ -2405,46 +2534,53 @@ impl Drop for OFB {
 /// }
 /// ```
 #[cfg(aes_xts)]
-pub struct XTS {
+pub struct XTS<'a> {
     ws_xtsaes: sys::XtsAes,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 #[cfg(aes_xts)]
-impl XTS {
+impl<'a> XTS<'a> {
     /// Create a new `XTS` instance.
     ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
     /// # Returns
     ///
     /// A Result which is Ok(XTS) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn new() -> Result<Self, i32> {
-        Self::new_ex(None, None)
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&crate::Env::new(wolfcrypt))
     }
 
     /// Create a new `XTS` instance with optional heap and device ID.
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
     /// A Result which is Ok(XTS) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn new_ex(heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
-        let ws_xtsaes = new_ws_xtsaes(heap, dev_id)?;
-        let xts = XTS {ws_xtsaes};
+    pub fn new_ex(env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let ws_xtsaes = new_ws_xtsaes(env)?;
+        let xts = XTS {ws_xtsaes, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData};
         Ok(xts)
     }
 
-    fn init(&mut self, key: &[u8], dir: i32) -> Result<(), i32> {
+    fn init(&mut self, key: &[u8], dir: i32) -> Result<(), Error> {
         let key_size = crate::buffer_len_to_u32(key.len())?;
         let rc = unsafe {
             sys::wc_AesXtsSetKeyNoInit(&mut self.ws_xtsaes,
                 key.as_ptr(), key_size, dir)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -2462,7 +2598,7 @@ impl XTS {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn init_encrypt(&mut self, key: &[u8]) -> Result<(), i32> {
+    pub fn init_encrypt(&mut self, key: &[u8]) -> Result<(), Error> {
         self.init(key, sys::AES_ENCRYPTION as i32)
     }
 
@@ -2479,7 +2615,7 @@ impl XTS {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn init_decrypt(&mut self, key: &[u8]) -> Result<(), i32> {
+    pub fn init_decrypt(&mut self, key: &[u8]) -> Result<(), Error> {
         self.init(key, sys::AES_DECRYPTION as i32)
     }
 
@@ -2498,19 +2634,19 @@ impl XTS {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn encrypt(&mut self, din: &[u8], dout: &mut [u8], tweak: &[u8]) -> Result<(), i32> {
+    pub fn encrypt(&mut self, din: &[u8], dout: &mut [u8], tweak: &[u8]) -> Result<(), Error> {
         let in_size = crate::buffer_len_to_u32(din.len())?;
         let out_size = crate::buffer_len_to_u32(dout.len())?;
         let tweak_size = crate::buffer_len_to_u32(tweak.len())?;
         if in_size != out_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesXtsEncrypt(&mut self.ws_xtsaes, dout.as_mut_ptr(),
                 din.as_ptr(), in_size, tweak.as_ptr(), tweak_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -2535,18 +2671,18 @@ impl XTS {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn encrypt_sector(&mut self, din: &[u8], dout: &mut [u8], sector: u64) -> Result<(), i32> {
+    pub fn encrypt_sector(&mut self, din: &[u8], dout: &mut [u8], sector: u64) -> Result<(), Error> {
         let in_size = crate::buffer_len_to_u32(din.len())?;
         let out_size = crate::buffer_len_to_u32(dout.len())?;
         if in_size != out_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesXtsEncryptSector(&mut self.ws_xtsaes, dout.as_mut_ptr(),
                 din.as_ptr(), in_size, sector)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -2573,18 +2709,18 @@ impl XTS {
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
     pub fn encrypt_consecutive_sectors(&mut self, din: &[u8], dout: &mut [u8],
-            sector: u64, sector_size: u32) -> Result<(), i32> {
+            sector: u64, sector_size: u32) -> Result<(), Error> {
         let in_size = crate::buffer_len_to_u32(din.len())?;
         let out_size = crate::buffer_len_to_u32(dout.len())?;
         if in_size != out_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesXtsEncryptConsecutiveSectors(&mut self.ws_xtsaes,
                 dout.as_mut_ptr(), din.as_ptr(), in_size, sector, sector_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -2604,19 +2740,19 @@ impl XTS {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn decrypt(&mut self, din: &[u8], dout: &mut [u8], tweak: &[u8]) -> Result<(), i32> {
+    pub fn decrypt(&mut self, din: &[u8], dout: &mut [u8], tweak: &[u8]) -> Result<(), Error> {
         let in_size = crate::buffer_len_to_u32(din.len())?;
         let out_size = crate::buffer_len_to_u32(dout.len())?;
         let tweak_size = crate::buffer_len_to_u32(tweak.len())?;
         if in_size != out_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesXtsDecrypt(&mut self.ws_xtsaes, dout.as_mut_ptr(),
                 din.as_ptr(), in_size, tweak.as_ptr(), tweak_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -2641,18 +2777,18 @@ impl XTS {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn decrypt_sector(&mut self, din: &[u8], dout: &mut [u8], sector: u64) -> Result<(), i32> {
+    pub fn decrypt_sector(&mut self, din: &[u8], dout: &mut [u8], sector: u64) -> Result<(), Error> {
         let in_size = crate::buffer_len_to_u32(din.len())?;
         let out_size = crate::buffer_len_to_u32(dout.len())?;
         if in_size != out_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesXtsDecryptSector(&mut self.ws_xtsaes, dout.as_mut_ptr(),
                 din.as_ptr(), in_size, sector)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -2679,30 +2815,30 @@ impl XTS {
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
     pub fn decrypt_consecutive_sectors(&mut self, din: &[u8], dout: &mut [u8],
-            sector: u64, sector_size: u32) -> Result<(), i32> {
+            sector: u64, sector_size: u32) -> Result<(), Error> {
         let in_size = crate::buffer_len_to_u32(din.len())?;
         let out_size = crate::buffer_len_to_u32(dout.len())?;
         if in_size != out_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesXtsDecryptConsecutiveSectors(&mut self.ws_xtsaes,
                 dout.as_mut_ptr(), din.as_ptr(), in_size, sector, sector_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
 }
 #[cfg(aes_xts)]
-impl XTS {
+impl<'a> XTS<'a> {
     fn zeroize(&mut self) {
         unsafe { crate::zeroize_raw(&mut self.ws_xtsaes); }
     }
 }
 #[cfg(aes_xts)]
-impl Drop for XTS {
+impl Drop for XTS<'_> {
     /// Safely free the wolfSSL resources.
     fn drop(&mut self) {
         unsafe { sys::wc_AesXtsFree(&mut self.ws_xtsaes); }
@@ -2721,6 +2857,8 @@ impl Drop for XTS {
 /// #[cfg(aes_xts_stream)]
 /// {
 /// use wolfssl_wolfcrypt::aes::XTSStream;
+/// use wolfssl_wolfcrypt::WolfCrypt;
+/// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
 /// let keys: [u8; 32] = [
 ///     0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
 ///     0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
@@ -2746,7 +2884,7 @@ impl Drop for XTS {
 ///     0x3F, 0xED, 0x11, 0x37, 0xCB, 0x4D, 0xAD, 0xA4
 /// ];
 ///
-/// let mut xtsstream = XTSStream::new().expect("Failed to create XTSStream");
+/// let mut xtsstream = XTSStream::new(&wolfcrypt).expect("Failed to create XTSStream");
 /// xtsstream.init_encrypt(&keys, &tweak).expect("Error with init_encrypt()");
 /// let mut cipher: [u8; 40] = [0; 40];
 /// xtsstream.encrypt_update(&plain[0..16], &mut cipher[0..16]).expect("Error with encrypt_update()");
@@ -2761,38 +2899,46 @@ impl Drop for XTS {
 /// }
 /// ```
 #[cfg(aes_xts_stream)]
-pub struct XTSStream {
+pub struct XTSStream<'a> {
     ws_xtsaes: sys::XtsAes,
     ws_xtsaesstreamdata: sys::XtsAesStreamData,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 #[cfg(aes_xts_stream)]
-impl XTSStream {
+impl<'a> XTSStream<'a> {
     /// Create a new `XTSStream` instance.
     ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
     /// # Returns
     ///
     /// A Result which is Ok(XTSStream) on success or an Err containing the
     /// wolfSSL library return code on failure.
-    pub fn new() -> Result<Self, i32> {
-        Self::new_ex(None, None)
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&crate::Env::new(wolfcrypt))
     }
 
     /// Create a new `XTSStream` instance with optional heap and device ID.
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
     /// A Result which is Ok(XTSStream) on success or an Err containing the
     /// wolfSSL library return code on failure.
-    pub fn new_ex(heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
-        let ws_xtsaes = new_ws_xtsaes(heap, dev_id)?;
+    pub fn new_ex(env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let ws_xtsaes = new_ws_xtsaes(env)?;
         let ws_xtsaesstreamdata: MaybeUninit<sys::XtsAesStreamData> = MaybeUninit::zeroed();
         let ws_xtsaesstreamdata = unsafe { ws_xtsaesstreamdata.assume_init() };
-        let xtsstream = XTSStream {ws_xtsaes, ws_xtsaesstreamdata};
+        let xtsstream = XTSStream {ws_xtsaes, ws_xtsaesstreamdata, _wolfcrypt: wolfcrypt,
+            _device: env.device_ref(), _heap: PhantomData};
         Ok(xtsstream)
     }
 
@@ -2810,14 +2956,14 @@ impl XTSStream {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn init_encrypt(&mut self, key: &[u8], tweak: &[u8]) -> Result<(), i32> {
+    pub fn init_encrypt(&mut self, key: &[u8], tweak: &[u8]) -> Result<(), Error> {
         let key_size = crate::buffer_len_to_u32(key.len())?;
         let rc = unsafe {
             sys::wc_AesXtsSetKeyNoInit(&mut self.ws_xtsaes,
                 key.as_ptr(), key_size, sys::AES_ENCRYPTION as i32)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let tweak_size = crate::buffer_len_to_u32(tweak.len())?;
         let rc = unsafe {
@@ -2825,7 +2971,7 @@ impl XTSStream {
                 tweak.as_ptr(), tweak_size, &mut self.ws_xtsaesstreamdata)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -2844,14 +2990,14 @@ impl XTSStream {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn init_decrypt(&mut self, key: &[u8], tweak: &[u8]) -> Result<(), i32> {
+    pub fn init_decrypt(&mut self, key: &[u8], tweak: &[u8]) -> Result<(), Error> {
         let key_size = crate::buffer_len_to_u32(key.len())?;
         let rc = unsafe {
             sys::wc_AesXtsSetKeyNoInit(&mut self.ws_xtsaes,
                 key.as_ptr(), key_size, sys::AES_DECRYPTION as i32)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let tweak_size = crate::buffer_len_to_u32(tweak.len())?;
         let rc = unsafe {
@@ -2859,7 +3005,7 @@ impl XTSStream {
                 tweak.as_ptr(), tweak_size, &mut self.ws_xtsaesstreamdata)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -2882,18 +3028,18 @@ impl XTSStream {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn encrypt_update(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), i32> {
+    pub fn encrypt_update(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), Error> {
         let in_size = crate::buffer_len_to_u32(din.len())?;
         let out_size = crate::buffer_len_to_u32(dout.len())?;
         if in_size != out_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesXtsEncryptUpdate(&mut self.ws_xtsaes, dout.as_mut_ptr(),
                 din.as_ptr(), in_size, &mut self.ws_xtsaesstreamdata)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -2915,18 +3061,18 @@ impl XTSStream {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn encrypt_final(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), i32> {
+    pub fn encrypt_final(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), Error> {
         let in_size = crate::buffer_len_to_u32(din.len())?;
         let out_size = crate::buffer_len_to_u32(dout.len())?;
         if in_size != out_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesXtsEncryptFinal(&mut self.ws_xtsaes, dout.as_mut_ptr(),
                 din.as_ptr(), in_size, &mut self.ws_xtsaesstreamdata)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -2949,18 +3095,18 @@ impl XTSStream {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn decrypt_update(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), i32> {
+    pub fn decrypt_update(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), Error> {
         let in_size = crate::buffer_len_to_u32(din.len())?;
         let out_size = crate::buffer_len_to_u32(dout.len())?;
         if in_size != out_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesXtsDecryptUpdate(&mut self.ws_xtsaes, dout.as_mut_ptr(),
                 din.as_ptr(), in_size, &mut self.ws_xtsaesstreamdata)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -2982,24 +3128,24 @@ impl XTSStream {
     ///
     /// A Result which is Ok(()) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn decrypt_final(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), i32> {
+    pub fn decrypt_final(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), Error> {
         let in_size = crate::buffer_len_to_u32(din.len())?;
         let out_size = crate::buffer_len_to_u32(dout.len())?;
         if in_size != out_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let rc = unsafe {
             sys::wc_AesXtsDecryptFinal(&mut self.ws_xtsaes, dout.as_mut_ptr(),
                 din.as_ptr(), in_size, &mut self.ws_xtsaesstreamdata)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
 }
 #[cfg(aes_xts_stream)]
-impl XTSStream {
+impl<'a> XTSStream<'a> {
     fn zeroize(&mut self) {
         unsafe {
             crate::zeroize_raw(&mut self.ws_xtsaes);
@@ -3008,7 +3154,7 @@ impl XTSStream {
     }
 }
 #[cfg(aes_xts_stream)]
-impl Drop for XTSStream {
+impl Drop for XTSStream<'_> {
     /// Safely free the wolfSSL resources.
     fn drop(&mut self) {
         unsafe { sys::wc_AesXtsFree(&mut self.ws_xtsaes); }
@@ -3026,7 +3172,7 @@ impl Drop for XTSStream {
 /// [`cipher::KeyInit::new`] or [`cipher::KeyInit::new_from_slice`].
 #[cfg(all(aes_ecb, feature = "cipher"))]
 pub struct Aes128EcbEnc {
-    inner: ECB,
+    inner: ECB<'static>,
 }
 
 #[cfg(all(aes_ecb, feature = "cipher"))]
@@ -3042,7 +3188,8 @@ impl cipher::BlockSizeUser for Aes128EcbEnc {
 #[cfg(all(aes_ecb, feature = "cipher"))]
 impl cipher::KeyInit for Aes128EcbEnc {
     fn new(key: &cipher::Key<Self>) -> Self {
-        let mut ecb = ECB::new().expect("wc_AesInit failed");
+        let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+        let mut ecb = ECB::new(wolfcrypt).expect("wc_AesInit failed");
         ecb.init_encrypt(key.as_ref()).expect("wc_AesSetKey failed");
         Self { inner: ecb }
     }
@@ -3080,7 +3227,7 @@ impl BlockModeEncrypt for Aes128EcbEnc {
 /// AES-192 ECB block cipher (encryption) implementing [`cipher::BlockModeEncrypt`].
 #[cfg(all(aes_ecb, feature = "cipher"))]
 pub struct Aes192EcbEnc {
-    inner: ECB,
+    inner: ECB<'static>,
 }
 
 #[cfg(all(aes_ecb, feature = "cipher"))]
@@ -3096,7 +3243,8 @@ impl cipher::BlockSizeUser for Aes192EcbEnc {
 #[cfg(all(aes_ecb, feature = "cipher"))]
 impl cipher::KeyInit for Aes192EcbEnc {
     fn new(key: &cipher::Key<Self>) -> Self {
-        let mut ecb = ECB::new().expect("wc_AesInit failed");
+        let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+        let mut ecb = ECB::new(wolfcrypt).expect("wc_AesInit failed");
         ecb.init_encrypt(key.as_ref()).expect("wc_AesSetKey failed");
         Self { inner: ecb }
     }
@@ -3134,7 +3282,7 @@ impl BlockModeEncrypt for Aes192EcbEnc {
 /// AES-256 ECB block cipher (encryption) implementing [`cipher::BlockModeEncrypt`].
 #[cfg(all(aes_ecb, feature = "cipher"))]
 pub struct Aes256EcbEnc {
-    inner: ECB,
+    inner: ECB<'static>,
 }
 
 #[cfg(all(aes_ecb, feature = "cipher"))]
@@ -3150,7 +3298,8 @@ impl cipher::BlockSizeUser for Aes256EcbEnc {
 #[cfg(all(aes_ecb, feature = "cipher"))]
 impl cipher::KeyInit for Aes256EcbEnc {
     fn new(key: &cipher::Key<Self>) -> Self {
-        let mut ecb = ECB::new().expect("wc_AesInit failed");
+        let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+        let mut ecb = ECB::new(wolfcrypt).expect("wc_AesInit failed");
         ecb.init_encrypt(key.as_ref()).expect("wc_AesSetKey failed");
         Self { inner: ecb }
     }
@@ -3191,7 +3340,7 @@ impl BlockModeEncrypt for Aes256EcbEnc {
 /// [`cipher::KeyInit::new`] or [`cipher::KeyInit::new_from_slice`].
 #[cfg(all(aes_ecb, feature = "cipher"))]
 pub struct Aes128EcbDec {
-    inner: ECB,
+    inner: ECB<'static>,
 }
 
 #[cfg(all(aes_ecb, feature = "cipher"))]
@@ -3207,7 +3356,8 @@ impl cipher::BlockSizeUser for Aes128EcbDec {
 #[cfg(all(aes_ecb, feature = "cipher"))]
 impl cipher::KeyInit for Aes128EcbDec {
     fn new(key: &cipher::Key<Self>) -> Self {
-        let mut ecb = ECB::new().expect("wc_AesInit failed");
+        let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+        let mut ecb = ECB::new(wolfcrypt).expect("wc_AesInit failed");
         ecb.init_decrypt(key.as_ref()).expect("wc_AesSetKey failed");
         Self { inner: ecb }
     }
@@ -3245,7 +3395,7 @@ impl BlockModeDecrypt for Aes128EcbDec {
 /// AES-192 ECB block cipher (decryption) implementing [`cipher::BlockModeDecrypt`].
 #[cfg(all(aes_ecb, feature = "cipher"))]
 pub struct Aes192EcbDec {
-    inner: ECB,
+    inner: ECB<'static>,
 }
 
 #[cfg(all(aes_ecb, feature = "cipher"))]
@@ -3261,7 +3411,8 @@ impl cipher::BlockSizeUser for Aes192EcbDec {
 #[cfg(all(aes_ecb, feature = "cipher"))]
 impl cipher::KeyInit for Aes192EcbDec {
     fn new(key: &cipher::Key<Self>) -> Self {
-        let mut ecb = ECB::new().expect("wc_AesInit failed");
+        let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+        let mut ecb = ECB::new(wolfcrypt).expect("wc_AesInit failed");
         ecb.init_decrypt(key.as_ref()).expect("wc_AesSetKey failed");
         Self { inner: ecb }
     }
@@ -3299,7 +3450,7 @@ impl BlockModeDecrypt for Aes192EcbDec {
 /// AES-256 ECB block cipher (decryption) implementing [`cipher::BlockModeDecrypt`].
 #[cfg(all(aes_ecb, feature = "cipher"))]
 pub struct Aes256EcbDec {
-    inner: ECB,
+    inner: ECB<'static>,
 }
 
 #[cfg(all(aes_ecb, feature = "cipher"))]
@@ -3315,7 +3466,8 @@ impl cipher::BlockSizeUser for Aes256EcbDec {
 #[cfg(all(aes_ecb, feature = "cipher"))]
 impl cipher::KeyInit for Aes256EcbDec {
     fn new(key: &cipher::Key<Self>) -> Self {
-        let mut ecb = ECB::new().expect("wc_AesInit failed");
+        let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+        let mut ecb = ECB::new(wolfcrypt).expect("wc_AesInit failed");
         ecb.init_decrypt(key.as_ref()).expect("wc_AesSetKey failed");
         Self { inner: ecb }
     }
@@ -3360,7 +3512,7 @@ impl BlockModeDecrypt for Aes256EcbDec {
 /// the [`cipher::inout::InOutBuf`] are passed directly to `wc_AesCtrEncrypt`.
 #[cfg(all(aes_ctr, feature = "cipher"))]
 pub struct Aes128Ctr {
-    inner: CTR,
+    inner: CTR<'static>,
 }
 
 #[cfg(all(aes_ctr, feature = "cipher"))]
@@ -3376,7 +3528,8 @@ impl IvSizeUser for Aes128Ctr {
 #[cfg(all(aes_ctr, feature = "cipher"))]
 impl KeyIvInit for Aes128Ctr {
     fn new(key: &cipher::Key<Self>, iv: &cipher::Iv<Self>) -> Self {
-        let mut ctr = CTR::new().expect("wc_AesInit failed");
+        let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+        let mut ctr = CTR::new(wolfcrypt).expect("wc_AesInit failed");
         ctr.init(key.as_ref(), iv.as_ref()).expect("wc_AesSetKeyDirect failed");
         Self { inner: ctr }
     }
@@ -3410,7 +3563,7 @@ impl StreamCipher for Aes128Ctr {
 /// AES-192 CTR stream cipher implementing [`cipher::StreamCipher`].
 #[cfg(all(aes_ctr, feature = "cipher"))]
 pub struct Aes192Ctr {
-    inner: CTR,
+    inner: CTR<'static>,
 }
 
 #[cfg(all(aes_ctr, feature = "cipher"))]
@@ -3426,7 +3579,8 @@ impl IvSizeUser for Aes192Ctr {
 #[cfg(all(aes_ctr, feature = "cipher"))]
 impl KeyIvInit for Aes192Ctr {
     fn new(key: &cipher::Key<Self>, iv: &cipher::Iv<Self>) -> Self {
-        let mut ctr = CTR::new().expect("wc_AesInit failed");
+        let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+        let mut ctr = CTR::new(wolfcrypt).expect("wc_AesInit failed");
         ctr.init(key.as_ref(), iv.as_ref()).expect("wc_AesSetKeyDirect failed");
         Self { inner: ctr }
     }
@@ -3459,7 +3613,7 @@ impl StreamCipher for Aes192Ctr {
 /// AES-256 CTR stream cipher implementing [`cipher::StreamCipher`].
 #[cfg(all(aes_ctr, feature = "cipher"))]
 pub struct Aes256Ctr {
-    inner: CTR,
+    inner: CTR<'static>,
 }
 
 #[cfg(all(aes_ctr, feature = "cipher"))]
@@ -3475,7 +3629,8 @@ impl IvSizeUser for Aes256Ctr {
 #[cfg(all(aes_ctr, feature = "cipher"))]
 impl KeyIvInit for Aes256Ctr {
     fn new(key: &cipher::Key<Self>, iv: &cipher::Iv<Self>) -> Self {
-        let mut ctr = CTR::new().expect("wc_AesInit failed");
+        let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+        let mut ctr = CTR::new(wolfcrypt).expect("wc_AesInit failed");
         ctr.init(key.as_ref(), iv.as_ref()).expect("wc_AesSetKeyDirect failed");
         Self { inner: ctr }
     }
@@ -3516,7 +3671,7 @@ impl StreamCipher for Aes256Ctr {
 /// in-place operation.
 #[cfg(all(aes_ofb, feature = "cipher"))]
 pub struct Aes128Ofb {
-    inner: OFB,
+    inner: OFB<'static>,
 }
 
 #[cfg(all(aes_ofb, feature = "cipher"))]
@@ -3532,7 +3687,8 @@ impl IvSizeUser for Aes128Ofb {
 #[cfg(all(aes_ofb, feature = "cipher"))]
 impl KeyIvInit for Aes128Ofb {
     fn new(key: &cipher::Key<Self>, iv: &cipher::Iv<Self>) -> Self {
-        let mut ofb = OFB::new().expect("wc_AesInit failed");
+        let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+        let mut ofb = OFB::new(wolfcrypt).expect("wc_AesInit failed");
         ofb.init(key.as_ref(), iv.as_ref()).expect("wc_AesSetKey failed");
         Self { inner: ofb }
     }
@@ -3566,7 +3722,7 @@ impl StreamCipher for Aes128Ofb {
 /// AES-192 OFB stream cipher implementing [`cipher::StreamCipher`].
 #[cfg(all(aes_ofb, feature = "cipher"))]
 pub struct Aes192Ofb {
-    inner: OFB,
+    inner: OFB<'static>,
 }
 
 #[cfg(all(aes_ofb, feature = "cipher"))]
@@ -3582,7 +3738,8 @@ impl IvSizeUser for Aes192Ofb {
 #[cfg(all(aes_ofb, feature = "cipher"))]
 impl KeyIvInit for Aes192Ofb {
     fn new(key: &cipher::Key<Self>, iv: &cipher::Iv<Self>) -> Self {
-        let mut ofb = OFB::new().expect("wc_AesInit failed");
+        let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+        let mut ofb = OFB::new(wolfcrypt).expect("wc_AesInit failed");
         ofb.init(key.as_ref(), iv.as_ref()).expect("wc_AesSetKey failed");
         Self { inner: ofb }
     }
@@ -3615,7 +3772,7 @@ impl StreamCipher for Aes192Ofb {
 /// AES-256 OFB stream cipher implementing [`cipher::StreamCipher`].
 #[cfg(all(aes_ofb, feature = "cipher"))]
 pub struct Aes256Ofb {
-    inner: OFB,
+    inner: OFB<'static>,
 }
 
 #[cfg(all(aes_ofb, feature = "cipher"))]
@@ -3631,7 +3788,8 @@ impl IvSizeUser for Aes256Ofb {
 #[cfg(all(aes_ofb, feature = "cipher"))]
 impl KeyIvInit for Aes256Ofb {
     fn new(key: &cipher::Key<Self>, iv: &cipher::Iv<Self>) -> Self {
-        let mut ofb = OFB::new().expect("wc_AesInit failed");
+        let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+        let mut ofb = OFB::new(wolfcrypt).expect("wc_AesInit failed");
         ofb.init(key.as_ref(), iv.as_ref()).expect("wc_AesSetKey failed");
         Self { inner: ofb }
     }
@@ -3673,7 +3831,7 @@ impl StreamCipher for Aes256Ofb {
 /// so blocks can be encrypted one at a time and the chaining is preserved.
 #[cfg(all(aes_cbc, feature = "cipher"))]
 pub struct Aes128CbcEnc {
-    inner: CBC,
+    inner: CBC<'static>,
 }
 
 #[cfg(all(aes_cbc, feature = "cipher"))]
@@ -3694,7 +3852,8 @@ impl IvSizeUser for Aes128CbcEnc {
 #[cfg(all(aes_cbc, feature = "cipher"))]
 impl KeyIvInit for Aes128CbcEnc {
     fn new(key: &cipher::Key<Self>, iv: &cipher::Iv<Self>) -> Self {
-        let mut cbc = CBC::new().expect("wc_AesInit failed");
+        let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+        let mut cbc = CBC::new(wolfcrypt).expect("wc_AesInit failed");
         cbc.init_encrypt(key.as_ref(), iv.as_ref()).expect("wc_AesSetKey failed");
         Self { inner: cbc }
     }
@@ -3732,7 +3891,7 @@ impl BlockModeEncrypt for Aes128CbcEnc {
 /// AES-192 CBC block cipher (encryption) implementing [`cipher::BlockModeEncrypt`].
 #[cfg(all(aes_cbc, feature = "cipher"))]
 pub struct Aes192CbcEnc {
-    inner: CBC,
+    inner: CBC<'static>,
 }
 
 #[cfg(all(aes_cbc, feature = "cipher"))]
@@ -3753,7 +3912,8 @@ impl IvSizeUser for Aes192CbcEnc {
 #[cfg(all(aes_cbc, feature = "cipher"))]
 impl KeyIvInit for Aes192CbcEnc {
     fn new(key: &cipher::Key<Self>, iv: &cipher::Iv<Self>) -> Self {
-        let mut cbc = CBC::new().expect("wc_AesInit failed");
+        let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+        let mut cbc = CBC::new(wolfcrypt).expect("wc_AesInit failed");
         cbc.init_encrypt(key.as_ref(), iv.as_ref()).expect("wc_AesSetKey failed");
         Self { inner: cbc }
     }
@@ -3791,7 +3951,7 @@ impl BlockModeEncrypt for Aes192CbcEnc {
 /// AES-256 CBC block cipher (encryption) implementing [`cipher::BlockModeEncrypt`].
 #[cfg(all(aes_cbc, feature = "cipher"))]
 pub struct Aes256CbcEnc {
-    inner: CBC,
+    inner: CBC<'static>,
 }
 
 #[cfg(all(aes_cbc, feature = "cipher"))]
@@ -3812,7 +3972,8 @@ impl IvSizeUser for Aes256CbcEnc {
 #[cfg(all(aes_cbc, feature = "cipher"))]
 impl KeyIvInit for Aes256CbcEnc {
     fn new(key: &cipher::Key<Self>, iv: &cipher::Iv<Self>) -> Self {
-        let mut cbc = CBC::new().expect("wc_AesInit failed");
+        let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+        let mut cbc = CBC::new(wolfcrypt).expect("wc_AesInit failed");
         cbc.init_encrypt(key.as_ref(), iv.as_ref()).expect("wc_AesSetKey failed");
         Self { inner: cbc }
     }
@@ -3853,7 +4014,7 @@ impl BlockModeEncrypt for Aes256CbcEnc {
 /// blocks can be decrypted one at a time and the chaining is preserved.
 #[cfg(all(aes_cbc, feature = "cipher"))]
 pub struct Aes128CbcDec {
-    inner: CBC,
+    inner: CBC<'static>,
 }
 
 #[cfg(all(aes_cbc, feature = "cipher"))]
@@ -3874,7 +4035,8 @@ impl IvSizeUser for Aes128CbcDec {
 #[cfg(all(aes_cbc, feature = "cipher"))]
 impl KeyIvInit for Aes128CbcDec {
     fn new(key: &cipher::Key<Self>, iv: &cipher::Iv<Self>) -> Self {
-        let mut cbc = CBC::new().expect("wc_AesInit failed");
+        let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+        let mut cbc = CBC::new(wolfcrypt).expect("wc_AesInit failed");
         cbc.init_decrypt(key.as_ref(), iv.as_ref()).expect("wc_AesSetKey failed");
         Self { inner: cbc }
     }
@@ -3912,7 +4074,7 @@ impl BlockModeDecrypt for Aes128CbcDec {
 /// AES-192 CBC block cipher (decryption) implementing [`cipher::BlockModeDecrypt`].
 #[cfg(all(aes_cbc, feature = "cipher"))]
 pub struct Aes192CbcDec {
-    inner: CBC,
+    inner: CBC<'static>,
 }
 
 #[cfg(all(aes_cbc, feature = "cipher"))]
@@ -3933,7 +4095,8 @@ impl IvSizeUser for Aes192CbcDec {
 #[cfg(all(aes_cbc, feature = "cipher"))]
 impl KeyIvInit for Aes192CbcDec {
     fn new(key: &cipher::Key<Self>, iv: &cipher::Iv<Self>) -> Self {
-        let mut cbc = CBC::new().expect("wc_AesInit failed");
+        let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+        let mut cbc = CBC::new(wolfcrypt).expect("wc_AesInit failed");
         cbc.init_decrypt(key.as_ref(), iv.as_ref()).expect("wc_AesSetKey failed");
         Self { inner: cbc }
     }
@@ -3971,7 +4134,7 @@ impl BlockModeDecrypt for Aes192CbcDec {
 /// AES-256 CBC block cipher (decryption) implementing [`cipher::BlockModeDecrypt`].
 #[cfg(all(aes_cbc, feature = "cipher"))]
 pub struct Aes256CbcDec {
-    inner: CBC,
+    inner: CBC<'static>,
 }
 
 #[cfg(all(aes_cbc, feature = "cipher"))]
@@ -3992,7 +4155,8 @@ impl IvSizeUser for Aes256CbcDec {
 #[cfg(all(aes_cbc, feature = "cipher"))]
 impl KeyIvInit for Aes256CbcDec {
     fn new(key: &cipher::Key<Self>, iv: &cipher::Iv<Self>) -> Self {
-        let mut cbc = CBC::new().expect("wc_AesInit failed");
+        let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+        let mut cbc = CBC::new(wolfcrypt).expect("wc_AesInit failed");
         cbc.init_decrypt(key.as_ref(), iv.as_ref()).expect("wc_AesSetKey failed");
         Self { inner: cbc }
     }
@@ -4027,42 +4191,69 @@ impl BlockModeDecrypt for Aes256CbcDec {
     }
 }
 
-fn new_ws_aes(heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<sys::Aes, i32> {
-    let heap = match heap {
-        Some(heap) => heap,
-        None => core::ptr::null_mut(),
-    };
-    let dev_id = match dev_id {
-        Some(dev_id) => dev_id,
-        None => sys::INVALID_DEVID,
-    };
+fn new_ws_aes(env: &crate::Env) -> Result<sys::Aes, Error> {
+    let heap = env.heap_ptr();
+    let dev_id = env.dev_id();
     let mut ws_aes: MaybeUninit<sys::Aes> = MaybeUninit::uninit();
     let rc = unsafe {
         sys::wc_AesInit(ws_aes.as_mut_ptr(), heap, dev_id)
     };
     if rc != 0 {
-        return Err(rc);
+        return Err(Error::from(rc));
     }
     let ws_aes = unsafe { ws_aes.assume_init() };
     Ok(ws_aes)
 }
 
-#[cfg(any(aes_xts, aes_xts_stream))]
-fn new_ws_xtsaes(heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<sys::XtsAes, i32> {
-    let heap = match heap {
-        Some(heap) => heap,
-        None => core::ptr::null_mut(),
+#[cfg(all(aes_gcm, aes_key_id))]
+fn new_ws_aes_id(id: &[u8], env: &crate::Env) -> Result<sys::Aes, Error> {
+    let id_size = crate::buffer_len_to_i32(id.len())?;
+    let heap = env.heap_ptr();
+    let dev_id = env.dev_id();
+    let mut ws_aes: MaybeUninit<sys::Aes> = MaybeUninit::uninit();
+    let rc = unsafe {
+        sys::wc_AesInit_Id(ws_aes.as_mut_ptr(), id.as_ptr() as *mut u8, id_size,
+            heap, dev_id)
     };
-    let dev_id = match dev_id {
-        Some(dev_id) => dev_id,
-        None => sys::INVALID_DEVID,
+    if rc != 0 {
+        return Err(Error::from(rc));
+    }
+    let ws_aes = unsafe { ws_aes.assume_init() };
+    Ok(ws_aes)
+}
+
+#[cfg(all(aes_gcm, aes_key_id))]
+fn new_ws_aes_label(label: &str, env: &crate::Env) -> Result<sys::Aes, Error> {
+    let mut c_label = [0 as core::ffi::c_char; sys::AES_MAX_LABEL_LEN as usize + 1];
+    if label.len() >= c_label.len() {
+        return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+    }
+    for (d, s) in c_label.iter_mut().zip(label.as_bytes()) {
+        *d = *s as core::ffi::c_char;
+    }
+    let heap = env.heap_ptr();
+    let dev_id = env.dev_id();
+    let mut ws_aes: MaybeUninit<sys::Aes> = MaybeUninit::uninit();
+    let rc = unsafe {
+        sys::wc_AesInit_Label(ws_aes.as_mut_ptr(), c_label.as_ptr(), heap, dev_id)
     };
+    if rc != 0 {
+        return Err(Error::from(rc));
+    }
+    let ws_aes = unsafe { ws_aes.assume_init() };
+    Ok(ws_aes)
+}
+
+#[cfg(any(aes_xts, aes_xts_stream))]
+fn new_ws_xtsaes(env: &crate::Env) -> Result<sys::XtsAes, Error> {
+    let heap = env.heap_ptr();
+    let dev_id = env.dev_id();
     let mut ws_xtsaes: MaybeUninit<sys::XtsAes> = MaybeUninit::uninit();
     let rc = unsafe {
         sys::wc_AesXtsInit(ws_xtsaes.as_mut_ptr(), heap, dev_id)
     };
     if rc != 0 {
-        return Err(rc);
+        return Err(Error::from(rc));
     }
     let ws_xtsaes = unsafe { ws_xtsaes.assume_init() };
     Ok(ws_xtsaes)
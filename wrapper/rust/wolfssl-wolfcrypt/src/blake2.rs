@@ -25,6 +25,7 @@ functionality.
 
 #![cfg(any(blake2b, blake2s))]
 
+use crate::Error;
 use crate::sys;
 use core::mem::MaybeUninit;
 
@@ -32,6 +33,7 @@ use core::mem::MaybeUninit;
 #[cfg(blake2b)]
 pub struct BLAKE2b {
     wc_blake2b: sys::Blake2b,
+    _wolfcrypt: crate::WolfCrypt,
 }
 
 #[cfg(blake2b)]
@@ -41,6 +43,7 @@ impl BLAKE2b {
     /// # Parameters
     ///
     /// * `digest_size`: Length of the blake 2 digest to implement.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
@@ -51,19 +54,22 @@ impl BLAKE2b {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::blake2::BLAKE2b;
-    /// let blake2b = BLAKE2b::new(64).expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let blake2b = BLAKE2b::new(64, &wolfcrypt).expect("Error with new()");
     /// ```
-    pub fn new(digest_size: usize) -> Result<Self, i32> {
+    pub fn new(digest_size: usize, wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        let wolfcrypt = wolfcrypt.clone();
         let digest_size = crate::buffer_len_to_u32(digest_size)?;
         let mut wc_blake2b: MaybeUninit<sys::Blake2b> = MaybeUninit::uninit();
         let rc = unsafe {
             sys::wc_InitBlake2b(wc_blake2b.as_mut_ptr(), digest_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_blake2b = unsafe { wc_blake2b.assume_init() };
-        let blake2b = BLAKE2b { wc_blake2b };
+        let blake2b = BLAKE2b { wc_blake2b, _wolfcrypt: wolfcrypt };
         Ok(blake2b)
     }
 
@@ -73,6 +79,7 @@ impl BLAKE2b {
     ///
     /// * `digest_size`: Length of the blake 2 digest to implement.
     /// * `key`: Key to use for BLAKE2b operation.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
@@ -83,10 +90,13 @@ impl BLAKE2b {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::blake2::BLAKE2b;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let key = [42u8; 32];
-    /// let blake2b = BLAKE2b::new_with_key(64, &key).expect("Error with new()");
+    /// let blake2b = BLAKE2b::new_with_key(64, &key, &wolfcrypt).expect("Error with new()");
     /// ```
-    pub fn new_with_key(digest_size: usize, key: &[u8]) -> Result<Self, i32> {
+    pub fn new_with_key(digest_size: usize, key: &[u8], wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        let wolfcrypt = wolfcrypt.clone();
         let key_size = crate::buffer_len_to_u32(key.len())?;
         let digest_size = crate::buffer_len_to_u32(digest_size)?;
         let mut wc_blake2b: MaybeUninit<sys::Blake2b> = MaybeUninit::uninit();
@@ -95,10 +105,10 @@ impl BLAKE2b {
                 key.as_ptr(), key_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_blake2b = unsafe { wc_blake2b.assume_init() };
-        let blake2b = BLAKE2b { wc_blake2b };
+        let blake2b = BLAKE2b { wc_blake2b, _wolfcrypt: wolfcrypt };
         Ok(blake2b)
     }
 
@@ -120,16 +130,18 @@ impl BLAKE2b {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::blake2::BLAKE2b;
-    /// let mut blake2b = BLAKE2b::new(64).expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut blake2b = BLAKE2b::new(64, &wolfcrypt).expect("Error with new()");
     /// blake2b.update(&[0u8; 16]).expect("Error with update()");
     /// ```
-    pub fn update(&mut self, data: &[u8]) -> Result<(), i32> {
+    pub fn update(&mut self, data: &[u8]) -> Result<(), Error> {
         let data_size = crate::buffer_len_to_u32(data.len())?;
         let rc = unsafe {
             sys::wc_Blake2bUpdate(&mut self.wc_blake2b, data.as_ptr(), data_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -150,24 +162,26 @@ impl BLAKE2b {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::blake2::BLAKE2b;
-    /// let mut blake2b = BLAKE2b::new(64).expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut blake2b = BLAKE2b::new(64, &wolfcrypt).expect("Error with new()");
     /// blake2b.update(&[0u8; 16]).expect("Error with update()");
     /// let mut hash = [0u8; 64];
     /// blake2b.finalize(&mut hash).expect("Error with finalize()");
     /// ```
-    pub fn finalize(&mut self, hash: &mut [u8]) -> Result<(), i32> {
+    pub fn finalize(&mut self, hash: &mut [u8]) -> Result<(), Error> {
         let hash_size = crate::buffer_len_to_u32(hash.len())?;
         if hash_size == 0 {
             // The C function uses the internal state configured digest size
             // if hash_size is passed in as 0. We do not want to allow a
             // buffer overrun, so do not allow an empty hash buffer here.
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let rc = unsafe {
             sys::wc_Blake2bFinal(&mut self.wc_blake2b, hash.as_mut_ptr(), hash_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -192,6 +206,7 @@ impl Drop for BLAKE2b {
 #[cfg(blake2b_hmac)]
 pub struct BLAKE2bHmac {
     wc_blake2b: sys::Blake2b,
+    _wolfcrypt: crate::WolfCrypt,
 }
 
 #[cfg(blake2b_hmac)]
@@ -204,6 +219,7 @@ impl BLAKE2bHmac {
     /// # Parameters
     ///
     /// * `key`: Key to use for HMAC-BLAKE2b computation.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
@@ -214,19 +230,22 @@ impl BLAKE2bHmac {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::blake2::BLAKE2bHmac;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let key = [42u8, 43, 44];
-    /// let hmac_blake2b = BLAKE2bHmac::new(&key).expect("Error with new()");
+    /// let hmac_blake2b = BLAKE2bHmac::new(&key, &wolfcrypt).expect("Error with new()");
     /// ```
-    pub fn new(key: &[u8]) -> Result<Self, i32> {
+    pub fn new(key: &[u8], wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        let wolfcrypt = wolfcrypt.clone();
         let mut wc_blake2b: MaybeUninit<sys::Blake2b> = MaybeUninit::uninit();
         let rc = unsafe {
             sys::wc_Blake2bHmacInit(wc_blake2b.as_mut_ptr(), key.as_ptr(), key.len())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_blake2b = unsafe { wc_blake2b.assume_init() };
-        let hmac_blake2b = BLAKE2bHmac { wc_blake2b };
+        let hmac_blake2b = BLAKE2bHmac { wc_blake2b, _wolfcrypt: wolfcrypt };
         Ok(hmac_blake2b)
     }
 
@@ -248,17 +267,19 @@ impl BLAKE2bHmac {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::blake2::BLAKE2bHmac;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let key = [42u8, 43, 44];
-    /// let mut hmac_blake2b = BLAKE2bHmac::new(&key).expect("Error with new()");
+    /// let mut hmac_blake2b = BLAKE2bHmac::new(&key, &wolfcrypt).expect("Error with new()");
     /// let data = [33u8, 34, 35];
     /// hmac_blake2b.update(&data).expect("Error with update()");
     /// ```
-    pub fn update(&mut self, data: &[u8]) -> Result<(), i32> {
+    pub fn update(&mut self, data: &[u8]) -> Result<(), Error> {
         let rc = unsafe {
             sys::wc_Blake2bHmacUpdate(&mut self.wc_blake2b, data.as_ptr(), data.len())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -280,20 +301,22 @@ impl BLAKE2bHmac {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::blake2::BLAKE2bHmac;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let key = [42u8, 43, 44];
-    /// let mut hmac_blake2b = BLAKE2bHmac::new(&key).expect("Error with new()");
+    /// let mut hmac_blake2b = BLAKE2bHmac::new(&key, &wolfcrypt).expect("Error with new()");
     /// let data = [33u8, 34, 35];
     /// hmac_blake2b.update(&data).expect("Error with update()");
     /// let mut mac = [0u8; 64];
     /// hmac_blake2b.finalize(&key, &mut mac).expect("Error with finalize()");
     /// ```
-    pub fn finalize(&mut self, key: &[u8], mac: &mut [u8; Self::DIGEST_SIZE]) -> Result<(), i32> {
+    pub fn finalize(&mut self, key: &[u8], mac: &mut [u8; Self::DIGEST_SIZE]) -> Result<(), Error> {
         let rc = unsafe {
             sys::wc_Blake2bHmacFinal(&mut self.wc_blake2b,
                 key.as_ptr(), key.len(), mac.as_mut_ptr(), mac.len())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -312,13 +335,13 @@ impl BLAKE2bHmac {
     ///
     /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
     /// library error code value.
-    pub fn hmac(data: &[u8], key: &[u8], out: &mut [u8; Self::DIGEST_SIZE]) -> Result<(), i32> {
+    pub fn hmac(data: &[u8], key: &[u8], out: &mut [u8; Self::DIGEST_SIZE]) -> Result<(), Error> {
         let rc = unsafe {
             sys::wc_Blake2bHmac(data.as_ptr(), data.len(), key.as_ptr(),
                 key.len(), out.as_mut_ptr(), out.len())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -343,6 +366,7 @@ impl Drop for BLAKE2bHmac {
 #[cfg(blake2s)]
 pub struct BLAKE2s {
     wc_blake2s: sys::Blake2s,
+    _wolfcrypt: crate::WolfCrypt,
 }
 
 #[cfg(blake2s)]
@@ -352,6 +376,7 @@ impl BLAKE2s {
     /// # Parameters
     ///
     /// * `digest_size`: Length of the blake 2 digest to implement.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
@@ -362,19 +387,22 @@ impl BLAKE2s {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::blake2::BLAKE2s;
-    /// let blake2s = BLAKE2s::new(32).expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let blake2s = BLAKE2s::new(32, &wolfcrypt).expect("Error with new()");
     /// ```
-    pub fn new(digest_size: usize) -> Result<Self, i32> {
+    pub fn new(digest_size: usize, wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        let wolfcrypt = wolfcrypt.clone();
         let digest_size = crate::buffer_len_to_u32(digest_size)?;
         let mut wc_blake2s: MaybeUninit<sys::Blake2s> = MaybeUninit::uninit();
         let rc = unsafe {
             sys::wc_InitBlake2s(wc_blake2s.as_mut_ptr(), digest_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_blake2s = unsafe { wc_blake2s.assume_init() };
-        let blake2s = BLAKE2s { wc_blake2s };
+        let blake2s = BLAKE2s { wc_blake2s, _wolfcrypt: wolfcrypt };
         Ok(blake2s)
     }
 
@@ -384,6 +412,7 @@ impl BLAKE2s {
     ///
     /// * `digest_size`: Length of the blake 2 digest to implement.
     /// * `key`: Key to use for BLAKE2s operation.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
@@ -394,10 +423,13 @@ impl BLAKE2s {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::blake2::BLAKE2s;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let key = [42u8; 32];
-    /// let blake2s = BLAKE2s::new_with_key(32, &key).expect("Error with new()");
+    /// let blake2s = BLAKE2s::new_with_key(32, &key, &wolfcrypt).expect("Error with new()");
     /// ```
-    pub fn new_with_key(digest_size: usize, key: &[u8]) -> Result<Self, i32> {
+    pub fn new_with_key(digest_size: usize, key: &[u8], wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        let wolfcrypt = wolfcrypt.clone();
         let key_size = crate::buffer_len_to_u32(key.len())?;
         let digest_size = crate::buffer_len_to_u32(digest_size)?;
         let mut wc_blake2s: MaybeUninit<sys::Blake2s> = MaybeUninit::uninit();
@@ -406,10 +438,10 @@ impl BLAKE2s {
                 key.as_ptr(), key_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_blake2s = unsafe { wc_blake2s.assume_init() };
-        let blake2s = BLAKE2s { wc_blake2s };
+        let blake2s = BLAKE2s { wc_blake2s, _wolfcrypt: wolfcrypt };
         Ok(blake2s)
     }
 
@@ -431,16 +463,18 @@ impl BLAKE2s {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::blake2::BLAKE2s;
-    /// let mut blake2s = BLAKE2s::new(32).expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut blake2s = BLAKE2s::new(32, &wolfcrypt).expect("Error with new()");
     /// blake2s.update(&[0u8; 16]).expect("Error with update()");
     /// ```
-    pub fn update(&mut self, data: &[u8]) -> Result<(), i32> {
+    pub fn update(&mut self, data: &[u8]) -> Result<(), Error> {
         let data_size = crate::buffer_len_to_u32(data.len())?;
         let rc = unsafe {
             sys::wc_Blake2sUpdate(&mut self.wc_blake2s, data.as_ptr(), data_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -461,24 +495,26 @@ impl BLAKE2s {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::blake2::BLAKE2s;
-    /// let mut blake2s = BLAKE2s::new(32).expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut blake2s = BLAKE2s::new(32, &wolfcrypt).expect("Error with new()");
     /// blake2s.update(&[0u8; 16]).expect("Error with update()");
     /// let mut hash = [0u8; 32];
     /// blake2s.finalize(&mut hash).expect("Error with finalize()");
     /// ```
-    pub fn finalize(&mut self, hash: &mut [u8]) -> Result<(), i32> {
+    pub fn finalize(&mut self, hash: &mut [u8]) -> Result<(), Error> {
         let hash_size = crate::buffer_len_to_u32(hash.len())?;
         if hash_size == 0 {
             // The C function uses the internal state configured digest size
             // if hash_size is passed in as 0. We do not want to allow a
             // buffer overrun, so do not allow an empty hash buffer here.
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let rc = unsafe {
             sys::wc_Blake2sFinal(&mut self.wc_blake2s, hash.as_mut_ptr(), hash_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -503,6 +539,7 @@ impl Drop for BLAKE2s {
 #[cfg(blake2s_hmac)]
 pub struct BLAKE2sHmac {
     wc_blake2s: sys::Blake2s,
+    _wolfcrypt: crate::WolfCrypt,
 }
 
 #[cfg(blake2s_hmac)]
@@ -515,6 +552,7 @@ impl BLAKE2sHmac {
     /// # Parameters
     ///
     /// * `key`: Key to use for HMAC-BLAKE2s computation.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
@@ -525,19 +563,22 @@ impl BLAKE2sHmac {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::blake2::BLAKE2sHmac;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let key = [42u8, 43, 44];
-    /// let hmac_blake2s = BLAKE2sHmac::new(&key).expect("Error with new()");
+    /// let hmac_blake2s = BLAKE2sHmac::new(&key, &wolfcrypt).expect("Error with new()");
     /// ```
-    pub fn new(key: &[u8]) -> Result<Self, i32> {
+    pub fn new(key: &[u8], wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        let wolfcrypt = wolfcrypt.clone();
         let mut wc_blake2s: MaybeUninit<sys::Blake2s> = MaybeUninit::uninit();
         let rc = unsafe {
             sys::wc_Blake2sHmacInit(wc_blake2s.as_mut_ptr(), key.as_ptr(), key.len())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_blake2s = unsafe { wc_blake2s.assume_init() };
-        let hmac_blake2s = BLAKE2sHmac { wc_blake2s };
+        let hmac_blake2s = BLAKE2sHmac { wc_blake2s, _wolfcrypt: wolfcrypt };
         Ok(hmac_blake2s)
     }
 
@@ -559,17 +600,19 @@ impl BLAKE2sHmac {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::blake2::BLAKE2sHmac;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let key = [42u8, 43, 44];
-    /// let mut hmac_blake2s = BLAKE2sHmac::new(&key).expect("Error with new()");
+    /// let mut hmac_blake2s = BLAKE2sHmac::new(&key, &wolfcrypt).expect("Error with new()");
     /// let data = [33u8, 34, 35];
     /// hmac_blake2s.update(&data).expect("Error with update()");
     /// ```
-    pub fn update(&mut self, data: &[u8]) -> Result<(), i32> {
+    pub fn update(&mut self, data: &[u8]) -> Result<(), Error> {
         let rc = unsafe {
             sys::wc_Blake2sHmacUpdate(&mut self.wc_blake2s, data.as_ptr(), data.len())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -591,20 +634,22 @@ impl BLAKE2sHmac {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::blake2::BLAKE2sHmac;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let key = [42u8, 43, 44];
-    /// let mut hmac_blake2s = BLAKE2sHmac::new(&key).expect("Error with new()");
+    /// let mut hmac_blake2s = BLAKE2sHmac::new(&key, &wolfcrypt).expect("Error with new()");
     /// let data = [33u8, 34, 35];
     /// hmac_blake2s.update(&data).expect("Error with update()");
     /// let mut mac = [0u8; 32];
     /// hmac_blake2s.finalize(&key, &mut mac).expect("Error with finalize()");
     /// ```
-    pub fn finalize(&mut self, key: &[u8], mac: &mut [u8; Self::DIGEST_SIZE]) -> Result<(), i32> {
+    pub fn finalize(&mut self, key: &[u8], mac: &mut [u8; Self::DIGEST_SIZE]) -> Result<(), Error> {
         let rc = unsafe {
             sys::wc_Blake2sHmacFinal(&mut self.wc_blake2s,
                 key.as_ptr(), key.len(), mac.as_mut_ptr(), mac.len())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -623,13 +668,13 @@ impl BLAKE2sHmac {
     ///
     /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
     /// library error code value.
-    pub fn hmac(data: &[u8], key: &[u8], out: &mut [u8; Self::DIGEST_SIZE]) -> Result<(), i32> {
+    pub fn hmac(data: &[u8], key: &[u8], out: &mut [u8; Self::DIGEST_SIZE]) -> Result<(), Error> {
         let rc = unsafe {
             sys::wc_Blake2sHmac(data.as_ptr(), data.len(), key.as_ptr(),
                 key.len(), out.as_mut_ptr(), out.len())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -58,8 +58,9 @@ macro_rules! impl_blake2_digest {
         $(#[$attr])*
         impl Default for $name {
             fn default() -> Self {
+                let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
                 Self {
-                    blake2: <$wc_ty>::new($digest_size)
+                    blake2: <$wc_ty>::new($digest_size, wolfcrypt)
                         .expect("wolfCrypt BLAKE2 init failed"),
                 }
             }
@@ -89,7 +90,8 @@ macro_rules! impl_blake2_digest {
         $(#[$attr])*
         impl digest::Reset for $name {
             fn reset(&mut self) {
-                self.blake2 = <$wc_ty>::new($digest_size)
+                let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+                self.blake2 = <$wc_ty>::new($digest_size, wolfcrypt)
                     .expect("wolfCrypt BLAKE2 init failed");
             }
         }
@@ -107,7 +109,8 @@ macro_rules! impl_blake2_digest {
             fn finalize_into_reset(&mut self, out: &mut digest::Output<Self>) {
                 <$wc_ty>::finalize(&mut self.blake2, out.as_mut_slice())
                     .expect("wolfCrypt BLAKE2 finalize failed");
-                self.blake2 = <$wc_ty>::new($digest_size)
+                let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+                self.blake2 = <$wc_ty>::new($digest_size, wolfcrypt)
                     .expect("wolfCrypt BLAKE2 init failed");
             }
         }
@@ -73,8 +73,9 @@ macro_rules! impl_blake2_mac {
         $(#[$attr])*
         impl digest::KeyInit for $name {
             fn new(key: &digest::Key<Self>) -> Self {
+                let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
                 Self {
-                    blake2: <$wc_ty>::new_with_key($digest_size, key.as_slice())
+                    blake2: <$wc_ty>::new_with_key($digest_size, key.as_slice(), wolfcrypt)
                         .expect("wolfCrypt BLAKE2 init failed"),
                 }
             }
@@ -83,8 +84,9 @@ macro_rules! impl_blake2_mac {
                 if key.len() > <Self as digest::common::KeySizeUser>::key_size() {
                     return Err(digest::InvalidLength);
                 }
+                let wolfcrypt = crate::WolfCrypt::global().map_err(|_| digest::InvalidLength)?;
                 Ok(Self {
-                    blake2: <$wc_ty>::new_with_key($digest_size, key)
+                    blake2: <$wc_ty>::new_with_key($digest_size, key, wolfcrypt)
                         .map_err(|_| digest::InvalidLength)?,
                 })
             }
@@ -0,0 +1,741 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+This module provides a Rust wrapper for the wolfCrypt library's X.509
+certificate parsing functionality (`DecodedCert` / `wc_ParseCert()`).
+
+A [`DecodedCert`] borrows the DER certificate it was parsed from; the
+accessors return slices into that buffer or into the decoded structure, so no
+copies are made.
+
+The certificate signature is not verified by [`DecodedCert::parse()`]. Use a
+certificate manager to validate a chain.
+*/
+
+#![cfg(cert)]
+
+use crate::Error;
+use crate::memory::HeapHint;
+use crate::sys;
+use core::ffi::CStr;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+
+/// The public key algorithm of a certificate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyType {
+    /// RSA (rsaEncryption).
+    Rsa,
+    /// Elliptic curve (id-ecPublicKey).
+    Ecc,
+    /// Ed25519.
+    Ed25519,
+    /// Ed448.
+    Ed448,
+    /// ML-DSA-44.
+    MlDsa44,
+    /// ML-DSA-65.
+    MlDsa65,
+    /// ML-DSA-87.
+    MlDsa87,
+    /// Any other algorithm, identified by its wolfSSL OID sum.
+    Other(u32),
+}
+
+impl KeyType {
+    fn from_oid(oid: u32) -> Self {
+        match oid {
+            sys::Key_Sum_RSAk => KeyType::Rsa,
+            sys::Key_Sum_ECDSAk => KeyType::Ecc,
+            sys::Key_Sum_ED25519k => KeyType::Ed25519,
+            sys::Key_Sum_ED448k => KeyType::Ed448,
+            #[cfg(mldsa)]
+            sys::Key_Sum_ML_DSA_44k => KeyType::MlDsa44,
+            #[cfg(mldsa)]
+            sys::Key_Sum_ML_DSA_65k => KeyType::MlDsa65,
+            #[cfg(mldsa)]
+            sys::Key_Sum_ML_DSA_87k => KeyType::MlDsa87,
+            _ => KeyType::Other(oid),
+        }
+    }
+}
+
+/// A UTC date and time decoded from a certificate validity field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Time {
+    /// Four-digit year.
+    pub year: u16,
+    /// Month, 1 to 12.
+    pub month: u8,
+    /// Day of the month, 1 to 31.
+    pub day: u8,
+    /// Hour, 0 to 23.
+    pub hour: u8,
+    /// Minute, 0 to 59.
+    pub minute: u8,
+    /// Second, 0 to 59.
+    pub second: u8,
+}
+
+impl Time {
+    /// Decode an ASN.1 UTCTime (tag 0x17) or GeneralizedTime (tag 0x18)
+    /// value, given as tag, length and contents.
+    ///
+    /// Only the `Z` (UTC) form required by RFC 5280 is accepted.
+    fn from_der(raw: &[u8]) -> Option<Self> {
+        const UTC_TIME: u8 = 0x17;
+        const GENERALIZED_TIME: u8 = 0x18;
+        let (&tag, rest) = raw.split_first()?;
+        let (&len, value) = rest.split_first()?;
+        let value = value.get(..len as usize)?;
+        let (year, value) = match tag {
+            UTC_TIME => {
+                let yy = digits(value.get(..2)?)? as u16;
+                // RFC 5280 4.1.2.5.1: YY >= 50 is 19YY, otherwise 20YY.
+                (if yy >= 50 { 1900 + yy } else { 2000 + yy }, &value[2..])
+            }
+            GENERALIZED_TIME => (digits(value.get(..4)?)? as u16, &value[4..]),
+            _ => return None,
+        };
+        if value.len() != 11 || value[10] != b'Z' {
+            return None;
+        }
+        let time = Time {
+            year,
+            month: digits(&value[0..2])? as u8,
+            day: digits(&value[2..4])? as u8,
+            hour: digits(&value[4..6])? as u8,
+            minute: digits(&value[6..8])? as u8,
+            second: digits(&value[8..10])? as u8,
+        };
+        if !(1..=12).contains(&time.month) || !(1..=31).contains(&time.day)
+                || time.hour > 23 || time.minute > 59 || time.second > 59 {
+            return None;
+        }
+        Some(time)
+    }
+
+    /// Convert to seconds since the Unix epoch (1970-01-01T00:00:00Z).
+    pub fn unix_time(&self) -> i64 {
+        // Days from civil algorithm (proleptic Gregorian calendar).
+        let month = self.month as i64;
+        let year = self.year as i64 - if month <= 2 { 1 } else { 0 };
+        let era = year.div_euclid(400);
+        let yoe = year - era * 400;
+        let mp = (month + 9) % 12;
+        let doy = (153 * mp + 2) / 5 + self.day as i64 - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        let days = era * 146097 + doe - 719468;
+        days * 86400 + self.hour as i64 * 3600 + self.minute as i64 * 60 + self.second as i64
+    }
+
+    /// Create a time from seconds since the Unix epoch.
+    ///
+    /// # Parameters
+    ///
+    /// * `secs`: Seconds since 1970-01-01T00:00:00Z.
+    ///
+    /// # Returns
+    ///
+    /// Returns the time, or None if the year is outside 0 to 9999.
+    pub fn from_unix_time(secs: i64) -> Option<Self> {
+        // Civil from days algorithm, the inverse of unix_time().
+        let days = secs.div_euclid(86400);
+        let rem = secs.rem_euclid(86400);
+        let z = days + 719468;
+        let era = z.div_euclid(146097);
+        let doe = z - era * 146097;
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+        if !(0..=9999).contains(&year) {
+            return None;
+        }
+        Some(Time {
+            year: year as u16,
+            month: month as u8,
+            day: day as u8,
+            hour: (rem / 3600) as u8,
+            minute: (rem / 60 % 60) as u8,
+            second: (rem % 60) as u8,
+        })
+    }
+
+    /// Encode as a DER UTCTime for years 1950 to 2049 and as a
+    /// GeneralizedTime otherwise, as required by RFC 5280.
+    ///
+    /// Returns the number of bytes written to `out`.
+    #[allow(dead_code)]
+    pub(crate) fn to_der(self, out: &mut [u8; 17]) -> usize {
+        fn put(out: &mut [u8], value: u32, width: usize) {
+            let mut value = value;
+            for c in out[..width].iter_mut().rev() {
+                *c = b'0' + (value % 10) as u8;
+                value /= 10;
+            }
+        }
+        let mut pos;
+        if (1950..2050).contains(&self.year) {
+            out[0] = 0x17;
+            out[1] = 13;
+            put(&mut out[2..], self.year as u32 % 100, 2);
+            pos = 4;
+        } else {
+            out[0] = 0x18;
+            out[1] = 15;
+            put(&mut out[2..], self.year as u32, 4);
+            pos = 6;
+        }
+        for field in [self.month, self.day, self.hour, self.minute, self.second] {
+            put(&mut out[pos..], field as u32, 2);
+            pos += 2;
+        }
+        out[pos] = b'Z';
+        pos + 1
+    }
+}
+
+/// Parse a run of ASCII decimal digits.
+fn digits(s: &[u8]) -> Option<u32> {
+    s.iter().try_fold(0u32, |acc, &c| {
+        c.is_ascii_digit().then(|| acc * 10 + (c - b'0') as u32)
+    })
+}
+
+/// The basicConstraints extension of a certificate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BasicConstraints {
+    /// Whether the subject is a CA.
+    pub ca: bool,
+    /// Maximum number of intermediate CAs that may follow this one, if
+    /// constrained.
+    pub path_len: Option<u16>,
+}
+
+/// An entry of the subjectAltName extension.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GeneralName<'a> {
+    /// dNSName.
+    Dns(&'a str),
+    /// rfc822Name (email address).
+    Email(&'a str),
+    /// uniformResourceIdentifier.
+    Uri(&'a str),
+    /// iPAddress: 4 bytes for IPv4 or 16 bytes for IPv6.
+    Ip(&'a [u8]),
+    /// Any other GeneralName form, with its context-specific tag number and
+    /// the value as decoded by wolfSSL.
+    Other(i32, &'a [u8]),
+}
+
+/// Iterator over the subjectAltName entries of a [`DecodedCert`].
+///
+/// wolfSSL keeps rfc822Name entries in a separate list, so email addresses
+/// are returned after the other names. The order within each list follows
+/// wolfSSL and depends on its build options.
+pub struct SubjectAltNames<'a> {
+    entry: *const sys::DNS_entry,
+    emails: *const sys::DNS_entry,
+    _cert: PhantomData<&'a DecodedCert<'a>>,
+}
+
+impl<'a> Iterator for SubjectAltNames<'a> {
+    type Item = GeneralName<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.entry.is_null() {
+                if self.emails.is_null() {
+                    return None;
+                }
+                self.entry = core::mem::replace(&mut self.emails, core::ptr::null());
+            }
+            let entry = unsafe { &*self.entry };
+            self.entry = entry.next;
+            if entry.name.is_null() || entry.len < 0 {
+                continue;
+            }
+            let value = unsafe {
+                core::slice::from_raw_parts(entry.name as *const u8, entry.len as usize)
+            };
+            let text = core::str::from_utf8(value);
+            let name = match (entry.type_ as u32, text) {
+                (sys::ASN_Tags_ASN_DNS_TYPE, Ok(s)) => GeneralName::Dns(s),
+                (sys::ASN_Tags_ASN_RFC822_TYPE, Ok(s)) => GeneralName::Email(s),
+                (sys::ASN_Tags_ASN_URI_TYPE, Ok(s)) => GeneralName::Uri(s),
+                (sys::ASN_Tags_ASN_IP_TYPE, _) => GeneralName::Ip(value),
+                _ => GeneralName::Other(entry.type_, value),
+            };
+            return Some(name);
+        }
+    }
+}
+
+/// The `DecodedCert` struct manages the lifecycle of a wolfSSL `DecodedCert`
+/// object holding a parsed X.509 certificate.
+///
+/// The certificate DER buffer is borrowed for the lifetime `'a`.
+pub struct DecodedCert<'a> {
+    wc_cert: sys::DecodedCert,
+    der: &'a [u8],
+    _wolfcrypt: crate::WolfCrypt,
+    _heap: PhantomData<HeapHint<'a>>,
+}
+
+impl<'a> DecodedCert<'a> {
+    /// Key usage bit: digitalSignature.
+    pub const KEY_USAGE_DIGITAL_SIGNATURE: u16 = sys::KEYUSE_DIGITAL_SIG as u16;
+    /// Key usage bit: contentCommitment (nonRepudiation).
+    pub const KEY_USAGE_CONTENT_COMMITMENT: u16 = sys::KEYUSE_CONTENT_COMMIT as u16;
+    /// Key usage bit: keyEncipherment.
+    pub const KEY_USAGE_KEY_ENCIPHERMENT: u16 = sys::KEYUSE_KEY_ENCIPHER as u16;
+    /// Key usage bit: dataEncipherment.
+    pub const KEY_USAGE_DATA_ENCIPHERMENT: u16 = sys::KEYUSE_DATA_ENCIPHER as u16;
+    /// Key usage bit: keyAgreement.
+    pub const KEY_USAGE_KEY_AGREEMENT: u16 = sys::KEYUSE_KEY_AGREE as u16;
+    /// Key usage bit: keyCertSign.
+    pub const KEY_USAGE_KEY_CERT_SIGN: u16 = sys::KEYUSE_KEY_CERT_SIGN as u16;
+    /// Key usage bit: cRLSign.
+    pub const KEY_USAGE_CRL_SIGN: u16 = sys::KEYUSE_CRL_SIGN as u16;
+    /// Key usage bit: encipherOnly.
+    pub const KEY_USAGE_ENCIPHER_ONLY: u16 = sys::KEYUSE_ENCIPHER_ONLY as u16;
+    /// Key usage bit: decipherOnly.
+    pub const KEY_USAGE_DECIPHER_ONLY: u16 = sys::KEYUSE_DECIPHER_ONLY as u16;
+
+    /// Extended key usage bit: anyExtendedKeyUsage.
+    pub const EXT_KEY_USAGE_ANY: u8 = sys::EXTKEYUSE_ANY as u8;
+    /// Extended key usage bit: id-kp-serverAuth.
+    pub const EXT_KEY_USAGE_SERVER_AUTH: u8 = sys::EXTKEYUSE_SERVER_AUTH as u8;
+    /// Extended key usage bit: id-kp-clientAuth.
+    pub const EXT_KEY_USAGE_CLIENT_AUTH: u8 = sys::EXTKEYUSE_CLIENT_AUTH as u8;
+    /// Extended key usage bit: id-kp-codeSigning.
+    pub const EXT_KEY_USAGE_CODE_SIGNING: u8 = sys::EXTKEYUSE_CODESIGN as u8;
+    /// Extended key usage bit: id-kp-emailProtection.
+    pub const EXT_KEY_USAGE_EMAIL_PROTECTION: u8 = sys::EXTKEYUSE_EMAILPROT as u8;
+    /// Extended key usage bit: id-kp-timeStamping.
+    pub const EXT_KEY_USAGE_TIME_STAMPING: u8 = sys::EXTKEYUSE_TIMESTAMP as u8;
+    /// Extended key usage bit: id-kp-OCSPSigning.
+    pub const EXT_KEY_USAGE_OCSP_SIGNING: u8 = sys::EXTKEYUSE_OCSP_SIGN as u8;
+
+    /// Parse a DER-encoded X.509 certificate.
+    ///
+    /// The certificate signature and validity dates are not checked.
+    ///
+    /// # Parameters
+    ///
+    /// * `der`: DER-encoded certificate.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(DecodedCert) containing the parsed certificate or
+    /// Err(e) containing the wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate std;
+    /// use std::fs;
+    /// use wolfssl_wolfcrypt::cert::DecodedCert;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let der = fs::read("../../../certs/server-cert.der").expect("Error reading cert file");
+    /// let cert = DecodedCert::parse(&der, &wolfcrypt).expect("Error with parse()");
+    /// assert!(cert.subject().unwrap().contains("CN=www.wolfssl.com"));
+    /// ```
+    pub fn parse(der: &'a [u8], wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::parse_ex(der, &crate::Env::new(wolfcrypt))
+    }
+
+    /// Parse a DER-encoded X.509 certificate with the given heap hint.
+    ///
+    /// # Parameters
+    ///
+    /// * `der`: DER-encoded certificate.
+    /// * `env`: Heap hint to use. The device is not used.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(DecodedCert) containing the parsed certificate or
+    /// Err(e) containing the wolfSSL library error code value.
+    pub fn parse_ex(der: &'a [u8], env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let der_size = crate::buffer_len_to_u32(der.len())?;
+        let heap = env.heap_ptr();
+        let mut wc_cert: MaybeUninit<sys::DecodedCert> = MaybeUninit::uninit();
+        unsafe {
+            sys::wc_InitDecodedCert(wc_cert.as_mut_ptr(), der.as_ptr(), der_size, heap);
+        }
+        let wc_cert = unsafe { wc_cert.assume_init() };
+        let mut cert = DecodedCert { wc_cert, der, _wolfcrypt: wolfcrypt, _heap: PhantomData };
+        let rc = unsafe {
+            sys::wc_ParseCert(&mut cert.wc_cert, sys::CertType_CERT_TYPE as i32,
+                sys::VerifyType_NO_VERIFY as i32, core::ptr::null_mut())
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(cert)
+    }
+
+    /// Get the DER certificate this was parsed from.
+    pub fn der(&self) -> &'a [u8] {
+        self.der
+    }
+
+    /// Get the X.509 version: 1, 2 or 3.
+    pub fn version(&self) -> i32 {
+        /* wolfSSL stores the raw encoded value, which is one less. */
+        self.wc_cert.version + 1
+    }
+
+    /// Get the raw serial number bytes (big-endian, without the DER
+    /// INTEGER header).
+    pub fn serial(&self) -> &[u8] {
+        let len = (self.wc_cert.serialSz.max(0) as usize).min(self.wc_cert.serial.len());
+        &self.wc_cert.serial[..len]
+    }
+
+    /// Get the subject distinguished name in wolfSSL's one-line form, for
+    /// example `/C=US/O=wolfSSL/CN=www.wolfssl.com`.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(name) or Err(e) containing the UTF-8 decoding error
+    /// if the name is not valid UTF-8.
+    pub fn subject(&self) -> Result<&str, core::str::Utf8Error> {
+        c_array_str(&self.wc_cert.subject)
+    }
+
+    /// Get the issuer distinguished name in wolfSSL's one-line form.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(name) or Err(e) containing the UTF-8 decoding error
+    /// if the name is not valid UTF-8.
+    pub fn issuer(&self) -> Result<&str, core::str::Utf8Error> {
+        c_array_str(&self.wc_cert.issuer)
+    }
+
+    /// Get the subject common name, if present.
+    pub fn common_name(&self) -> Option<&str> {
+        let cn = self.wc_cert.subjectCN;
+        if cn.is_null() || self.wc_cert.subjectCNLen <= 0 {
+            return None;
+        }
+        let cn = unsafe {
+            core::slice::from_raw_parts(cn as *const u8, self.wc_cert.subjectCNLen as usize)
+        };
+        core::str::from_utf8(cn).ok()
+    }
+
+    /// Get the DER-encoded subject Name.
+    #[cfg(cert_subject_raw)]
+    pub fn subject_raw(&self) -> Option<&[u8]> {
+        raw_slice(self.wc_cert.subjectRaw, self.wc_cert.subjectRawLen)
+    }
+
+    /// Get the DER-encoded issuer Name.
+    #[cfg(cert_issuer_raw)]
+    pub fn issuer_raw(&self) -> Option<&[u8]> {
+        raw_slice(self.wc_cert.issuerRaw, self.wc_cert.issuerRawLen)
+    }
+
+    /// Get the notBefore validity date.
+    ///
+    /// # Returns
+    ///
+    /// Returns the decoded time, or None if the date is missing or is not a
+    /// well-formed UTC time.
+    pub fn not_before(&self) -> Option<Time> {
+        Time::from_der(self.not_before_raw()?)
+    }
+
+    /// Get the notAfter validity date.
+    ///
+    /// # Returns
+    ///
+    /// Returns the decoded time, or None if the date is missing or is not a
+    /// well-formed UTC time.
+    pub fn not_after(&self) -> Option<Time> {
+        Time::from_der(self.not_after_raw()?)
+    }
+
+    /// Get the DER encoding (tag, length and contents) of the notBefore
+    /// validity date.
+    pub fn not_before_raw(&self) -> Option<&[u8]> {
+        raw_slice(self.wc_cert.beforeDate, self.wc_cert.beforeDateLen)
+    }
+
+    /// Get the DER encoding (tag, length and contents) of the notAfter
+    /// validity date.
+    pub fn not_after_raw(&self) -> Option<&[u8]> {
+        raw_slice(self.wc_cert.afterDate, self.wc_cert.afterDateLen)
+    }
+
+    /// Get the public key algorithm.
+    pub fn key_type(&self) -> KeyType {
+        KeyType::from_oid(self.wc_cert.keyOID)
+    }
+
+    /// Get the wolfSSL OID sum of the public key algorithm.
+    pub fn key_oid(&self) -> u32 {
+        self.wc_cert.keyOID
+    }
+
+    /// Get the wolfSSL OID sum of the signature algorithm (one of the
+    /// `Ctc_SigType` values).
+    pub fn signature_oid(&self) -> u32 {
+        self.wc_cert.signatureOID
+    }
+
+    /// Get the public key as stored by wolfSSL.
+    ///
+    /// This is the contents of the SubjectPublicKeyInfo BIT STRING (a PKCS #1
+    /// `RSAPublicKey` for RSA, the raw key for Ed25519, Ed448 and ML-DSA),
+    /// except for ECC where wolfSSL keeps the whole SubjectPublicKeyInfo so
+    /// that the curve parameters are available.
+    pub fn public_key(&self) -> &[u8] {
+        raw_slice(self.wc_cert.publicKey, self.wc_cert.pubKeySize as i32).unwrap_or(&[])
+    }
+
+    /// Copy the DER-encoded SubjectPublicKeyInfo into `dout`.
+    ///
+    /// # Parameters
+    ///
+    /// * `dout`: Output buffer.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to
+    /// `dout` or Err(e) containing the wolfSSL library error code value.
+    pub fn public_key_info(&self, dout: &mut [u8]) -> Result<usize, Error> {
+        let der_size = crate::buffer_len_to_u32(self.der.len())?;
+        let mut dout_size = crate::buffer_len_to_u32(dout.len())?;
+        let rc = unsafe {
+            sys::wc_GetSubjectPubKeyInfoDerFromCert(self.der.as_ptr(), der_size,
+                dout.as_mut_ptr(), &mut dout_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(dout_size as usize)
+    }
+
+    /// Get the keyUsage extension bits (`KEY_USAGE_*`), if present.
+    pub fn key_usage(&self) -> Option<u16> {
+        (self.wc_cert.extKeyUsageSet() != 0).then_some(self.wc_cert.extKeyUsage)
+    }
+
+    /// Get the extendedKeyUsage extension bits (`EXT_KEY_USAGE_*`), if
+    /// present.
+    pub fn ext_key_usage(&self) -> Option<u8> {
+        (self.wc_cert.extExtKeyUsageSet() != 0).then_some(self.wc_cert.extExtKeyUsage)
+    }
+
+    /// Get the basicConstraints extension, if present.
+    pub fn basic_constraints(&self) -> Option<BasicConstraints> {
+        if self.wc_cert.extBasicConstSet() == 0 {
+            return None;
+        }
+        Some(BasicConstraints {
+            ca: self.wc_cert.isCA() != 0,
+            path_len: (self.wc_cert.pathLengthSet() != 0).then_some(self.wc_cert.pathLength),
+        })
+    }
+
+    /// Get whether the subject and issuer names are identical.
+    pub fn is_self_signed(&self) -> bool {
+        self.wc_cert.selfSigned() != 0
+    }
+
+    /// Get an iterator over the subjectAltName entries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate std;
+    /// use std::fs;
+    /// use wolfssl_wolfcrypt::cert::{DecodedCert, GeneralName};
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let der = fs::read("../../../certs/server-cert.der").expect("Error reading cert file");
+    /// let cert = DecodedCert::parse(&der, &wolfcrypt).expect("Error with parse()");
+    /// assert!(cert.subject_alt_names().any(|n| n == GeneralName::Dns("example.com")));
+    /// ```
+    pub fn subject_alt_names(&self) -> SubjectAltNames<'_> {
+        SubjectAltNames {
+            entry: self.wc_cert.altNames,
+            #[cfg(cert_alt_email_names)]
+            emails: self.wc_cert.altEmailNames,
+            #[cfg(not(cert_alt_email_names))]
+            emails: core::ptr::null(),
+            _cert: PhantomData,
+        }
+    }
+
+    /// Get the DER-encoded TBSCertificate, the part covered by the
+    /// signature.
+    pub fn tbs(&self) -> &'a [u8] {
+        let begin = self.wc_cert.certBegin as usize;
+        let end = self.wc_cert.sigIndex as usize;
+        self.der.get(begin..end).unwrap_or(&[])
+    }
+
+    /// Get the signature value (the contents of the signatureValue BIT
+    /// STRING).
+    pub fn signature(&self) -> &[u8] {
+        raw_slice(self.wc_cert.signature, self.wc_cert.sigLength as i32).unwrap_or(&[])
+    }
+
+    /// Check that the public key type is `expected`.
+    #[allow(dead_code)]
+    fn expect_key_type(&self, expected: KeyType) -> Result<(), Error> {
+        if self.key_type() != expected {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+        }
+        Ok(())
+    }
+
+    /// Import the certificate public key into an `ECC` instance.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(ECC) or Err(e) containing the wolfSSL library error
+    /// code value. `BAD_FUNC_ARG` is returned if the key is not an ECC key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate std;
+    /// #[cfg(ecc)]
+    /// {
+    /// use std::fs;
+    /// use wolfssl_wolfcrypt::cert::DecodedCert;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let der = fs::read("../../../certs/server-ecc.der").expect("Error reading cert file");
+    /// let cert = DecodedCert::parse(&der, &wolfcrypt).expect("Error with parse()");
+    /// let mut ecc = cert.ecc_public_key().expect("Error with ecc_public_key()");
+    /// ecc.check().expect("Error with check()");
+    /// }
+    /// ```
+    #[cfg(ecc)]
+    pub fn ecc_public_key(&self) -> Result<crate::ecc::ECC<'static>, Error> {
+        self.expect_key_type(KeyType::Ecc)?;
+        // Large enough for a P-521 SubjectPublicKeyInfo.
+        let mut spki = [0u8; 256];
+        let len = self.public_key_info(&mut spki)?;
+        crate::ecc::ECC::import_public_der(&spki[..len], &crate::Env::new(&self._wolfcrypt))
+    }
+
+    /// Import the certificate public key into an `RSA` instance.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(RSA) or Err(e) containing the wolfSSL library error
+    /// code value. `BAD_FUNC_ARG` is returned if the key is not an RSA key.
+    #[cfg(rsa)]
+    pub fn rsa_public_key(&self) -> Result<crate::rsa::RSA<'static>, Error> {
+        self.expect_key_type(KeyType::Rsa)?;
+        crate::rsa::RSA::new_public_from_der(self.public_key(), &self._wolfcrypt)
+    }
+
+    /// Import the certificate public key into an `Ed25519` instance.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(Ed25519) or Err(e) containing the wolfSSL library
+    /// error code value. `BAD_FUNC_ARG` is returned if the key is not an
+    /// Ed25519 key.
+    #[cfg(all(ed25519, ed25519_import))]
+    pub fn ed25519_public_key(&self) -> Result<crate::ed25519::Ed25519<'static>, Error> {
+        self.expect_key_type(KeyType::Ed25519)?;
+        let mut key = crate::ed25519::Ed25519::new(&self._wolfcrypt)?;
+        key.import_public(self.public_key())?;
+        Ok(key)
+    }
+
+    /// Import the certificate public key into an `Ed448` instance.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(Ed448) or Err(e) containing the wolfSSL library
+    /// error code value. `BAD_FUNC_ARG` is returned if the key is not an
+    /// Ed448 key.
+    #[cfg(all(ed448, ed448_import))]
+    pub fn ed448_public_key(&self) -> Result<crate::ed448::Ed448<'static>, Error> {
+        self.expect_key_type(KeyType::Ed448)?;
+        let mut key = crate::ed448::Ed448::new(&self._wolfcrypt)?;
+        key.import_public(self.public_key())?;
+        Ok(key)
+    }
+
+    /// Import the certificate public key into an `MlDsa` instance with the
+    /// matching parameter set.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(MlDsa) or Err(e) containing the wolfSSL library
+    /// error code value. `BAD_FUNC_ARG` is returned if the key is not an
+    /// ML-DSA key.
+    #[cfg(all(mldsa, mldsa_import))]
+    pub fn mldsa_public_key(&self) -> Result<crate::mldsa::MlDsa<'static>, Error> {
+        use crate::mldsa::MlDsa;
+        let level = match self.key_type() {
+            KeyType::MlDsa44 => MlDsa::LEVEL_44,
+            KeyType::MlDsa65 => MlDsa::LEVEL_65,
+            KeyType::MlDsa87 => MlDsa::LEVEL_87,
+            _ => return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG)),
+        };
+        let mut key = MlDsa::new(&self._wolfcrypt)?;
+        key.set_level(level)?;
+        key.import_public(self.public_key())?;
+        Ok(key)
+    }
+}
+
+/// Build a slice from a wolfSSL pointer and length pair.
+fn raw_slice<'b>(ptr: *const u8, len: i32) -> Option<&'b [u8]> {
+    if ptr.is_null() || len <= 0 {
+        return None;
+    }
+    Some(unsafe { core::slice::from_raw_parts(ptr, len as usize) })
+}
+
+/// Get the NUL-terminated string held in a fixed-size C char array.
+fn c_array_str(buf: &[core::ffi::c_char]) -> Result<&str, core::str::Utf8Error> {
+    let bytes = unsafe { core::slice::from_raw_parts(buf.as_ptr() as *const u8, buf.len()) };
+    match CStr::from_bytes_until_nul(bytes) {
+        Ok(s) => s.to_str(),
+        Err(_) => core::str::from_utf8(bytes),
+    }
+}
+
+impl<'a> Drop for DecodedCert<'a> {
+    /// Safely free the wolfSSL resources.
+    fn drop(&mut self) {
+        unsafe { sys::wc_FreeDecodedCert(&mut self.wc_cert); }
+    }
+}
@@ -0,0 +1,920 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+This module provides a Rust wrapper for the wolfCrypt library's X.509
+certificate and certificate signing request (CSR) generation functionality
+(`wc_InitCert()`, `wc_MakeCert_ex()`, `wc_MakeCertReq_ex()` and
+`wc_SignCert_ex()`).
+
+A [`CertBuilder`] or [`CsrBuilder`] is configured with its `set_*()` methods
+and then signed with any key type implementing [`CertKey`]: [`crate::ecc::ECC`],
+[`crate::rsa::RSA`], [`crate::ed25519::Ed25519`], [`crate::ed448::Ed448`] or
+[`crate::mldsa::MlDsa`]. The signature algorithm defaults to the one matching
+the signing key and can be overridden with `set_sig_type()`.
+
+# Examples
+
+```rust
+#[cfg(all(ecc, random))]
+{
+use wolfssl_wolfcrypt::Env;
+use wolfssl_wolfcrypt::cert::{BasicConstraints, DecodedCert};
+use wolfssl_wolfcrypt::cert_gen::{CertBuilder, Name};
+use wolfssl_wolfcrypt::ecc::ECC;
+use wolfssl_wolfcrypt::random::RNG;
+use wolfssl_wolfcrypt::WolfCrypt;
+let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+
+let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+let mut key = ECC::generate(32, &rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
+let mut builder = CertBuilder::new(&wolfcrypt).expect("Error with new()");
+builder.set_subject(&Name {
+    country: Some("US"),
+    org: Some("wolfSSL"),
+    common_name: Some("Example Root CA"),
+    ..Default::default()
+}).expect("Error with set_subject()");
+builder.set_basic_constraints(&BasicConstraints { ca: true, path_len: None })
+    .expect("Error with set_basic_constraints()");
+let mut der = [0u8; 1024];
+let der_size = builder.sign_self(&mut key, &rng, &mut der).expect("Error with sign_self()");
+let cert = DecodedCert::parse(&der[..der_size], &wolfcrypt).expect("Error with parse()");
+assert_eq!(cert.common_name(), Some("Example Root CA"));
+}
+```
+*/
+
+#![cfg(all(cert, cert_gen))]
+
+use crate::cert::{BasicConstraints, Time};
+#[cfg(cert_alt_names)]
+use crate::cert::GeneralName;
+use crate::random::RNG;
+use crate::Error;
+use crate::memory::HeapHint;
+use crate::sys;
+use core::marker::PhantomData;
+use core::ffi::c_void;
+use core::mem::MaybeUninit;
+
+mod private {
+    use crate::Error;
+
+    pub trait Sealed {
+        /// wolfSSL `CertType` key type value (e.g. `ECC_TYPE`).
+        fn cert_key_type(&mut self) -> Result<i32, Error>;
+        /// Pointer to the wolfSSL key structure.
+        fn cert_key_ptr(&mut self) -> *mut core::ffi::c_void;
+        /// Default signature algorithm (`Ctc_SigType`) when signing with this
+        /// key.
+        fn cert_sig_type(&mut self) -> Result<i32, Error>;
+    }
+}
+
+/// A key which can be the subject public key of, or sign, a certificate or
+/// CSR.
+///
+/// This trait is implemented for the key types of this crate and cannot be
+/// implemented outside of it.
+pub trait CertKey: private::Sealed {}
+
+#[cfg(ecc)]
+impl private::Sealed for crate::ecc::ECC<'_> {
+    fn cert_key_type(&mut self) -> Result<i32, Error> {
+        Ok(sys::CertType_ECC_TYPE as i32)
+    }
+    fn cert_key_ptr(&mut self) -> *mut c_void {
+        self.wc_ecc_key as *mut c_void
+    }
+    fn cert_sig_type(&mut self) -> Result<i32, Error> {
+        let size = unsafe { sys::wc_ecc_size(self.wc_ecc_key) };
+        if size <= 0 {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+        }
+        // Match the digest strength to the curve size.
+        let sig_type = match size {
+            ..=32 => sys::Ctc_SigType_CTC_SHA256wECDSA,
+            33..=48 => sys::Ctc_SigType_CTC_SHA384wECDSA,
+            _ => sys::Ctc_SigType_CTC_SHA512wECDSA,
+        };
+        Ok(sig_type as i32)
+    }
+}
+#[cfg(ecc)]
+impl CertKey for crate::ecc::ECC<'_> {}
+
+#[cfg(rsa)]
+impl private::Sealed for crate::rsa::RSA<'_> {
+    fn cert_key_type(&mut self) -> Result<i32, Error> {
+        Ok(sys::CertType_RSA_TYPE as i32)
+    }
+    fn cert_key_ptr(&mut self) -> *mut c_void {
+        &mut self.wc_rsakey as *mut sys::RsaKey as *mut c_void
+    }
+    fn cert_sig_type(&mut self) -> Result<i32, Error> {
+        Ok(sys::Ctc_SigType_CTC_SHA256wRSA as i32)
+    }
+}
+#[cfg(rsa)]
+impl CertKey for crate::rsa::RSA<'_> {}
+
+#[cfg(ed25519)]
+impl private::Sealed for crate::ed25519::Ed25519<'_> {
+    fn cert_key_type(&mut self) -> Result<i32, Error> {
+        Ok(sys::CertType_ED25519_TYPE as i32)
+    }
+    fn cert_key_ptr(&mut self) -> *mut c_void {
+        &mut self.ws_key as *mut sys::ed25519_key as *mut c_void
+    }
+    fn cert_sig_type(&mut self) -> Result<i32, Error> {
+        Ok(sys::Ctc_SigType_CTC_ED25519 as i32)
+    }
+}
+#[cfg(ed25519)]
+impl CertKey for crate::ed25519::Ed25519<'_> {}
+
+#[cfg(ed448)]
+impl private::Sealed for crate::ed448::Ed448<'_> {
+    fn cert_key_type(&mut self) -> Result<i32, Error> {
+        Ok(sys::CertType_ED448_TYPE as i32)
+    }
+    fn cert_key_ptr(&mut self) -> *mut c_void {
+        &mut self.ws_key as *mut sys::ed448_key as *mut c_void
+    }
+    fn cert_sig_type(&mut self) -> Result<i32, Error> {
+        Ok(sys::Ctc_SigType_CTC_ED448 as i32)
+    }
+}
+#[cfg(ed448)]
+impl CertKey for crate::ed448::Ed448<'_> {}
+
+#[cfg(mldsa)]
+impl private::Sealed for crate::mldsa::MlDsa<'_> {
+    fn cert_key_type(&mut self) -> Result<i32, Error> {
+        use crate::mldsa::MlDsa;
+        let key_type = match self.get_level()? {
+            MlDsa::LEVEL_44 => sys::CertType_ML_DSA_44_TYPE,
+            MlDsa::LEVEL_65 => sys::CertType_ML_DSA_65_TYPE,
+            MlDsa::LEVEL_87 => sys::CertType_ML_DSA_87_TYPE,
+            _ => return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG)),
+        };
+        Ok(key_type as i32)
+    }
+    fn cert_key_ptr(&mut self) -> *mut c_void {
+        &mut self.ws_key as *mut sys::wc_MlDsaKey as *mut c_void
+    }
+    fn cert_sig_type(&mut self) -> Result<i32, Error> {
+        use crate::mldsa::MlDsa;
+        let sig_type = match self.get_level()? {
+            MlDsa::LEVEL_44 => sys::Ctc_SigType_CTC_ML_DSA_44,
+            MlDsa::LEVEL_65 => sys::Ctc_SigType_CTC_ML_DSA_65,
+            MlDsa::LEVEL_87 => sys::Ctc_SigType_CTC_ML_DSA_87,
+            _ => return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG)),
+        };
+        Ok(sig_type as i32)
+    }
+}
+#[cfg(mldsa)]
+impl CertKey for crate::mldsa::MlDsa<'_> {}
+
+/// A distinguished name for the subject or issuer of a certificate or CSR.
+///
+/// Fields left as `None` are omitted from the encoded name. Each value must
+/// be shorter than `CTC_NAME_SIZE` (64 by default) bytes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Name<'a> {
+    /// countryName (C), a two-letter country code.
+    pub country: Option<&'a str>,
+    /// stateOrProvinceName (ST).
+    pub state: Option<&'a str>,
+    /// streetAddress (STREET).
+    pub street: Option<&'a str>,
+    /// localityName (L).
+    pub locality: Option<&'a str>,
+    /// surname (SN).
+    pub surname: Option<&'a str>,
+    /// organizationName (O).
+    pub org: Option<&'a str>,
+    /// organizationalUnitName (OU).
+    pub unit: Option<&'a str>,
+    /// commonName (CN).
+    pub common_name: Option<&'a str>,
+    /// serialNumber, for example a device serial number.
+    pub serial_number: Option<&'a str>,
+    /// userId (UID).
+    pub user_id: Option<&'a str>,
+    /// postalCode.
+    pub postal_code: Option<&'a str>,
+    /// emailAddress.
+    pub email: Option<&'a str>,
+}
+
+/// Copy an optional string into a fixed-size, NUL-terminated C char array.
+fn set_name_field(dst: &mut [core::ffi::c_char], value: Option<&str>) -> Result<(), Error> {
+    let value = value.unwrap_or("").as_bytes();
+    if value.len() >= dst.len() || value.contains(&0) {
+        return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+    }
+    for (d, &s) in dst.iter_mut().zip(value) {
+        *d = s as core::ffi::c_char;
+    }
+    dst[value.len()] = 0;
+    Ok(())
+}
+
+/// Fill a wolfSSL `CertName` from a [`Name`].
+fn set_name(cn: &mut sys::CertName, name: &Name) -> Result<(), Error> {
+    set_name_field(&mut cn.country, name.country)?;
+    set_name_field(&mut cn.state, name.state)?;
+    set_name_field(&mut cn.street, name.street)?;
+    set_name_field(&mut cn.locality, name.locality)?;
+    set_name_field(&mut cn.sur, name.surname)?;
+    set_name_field(&mut cn.org, name.org)?;
+    set_name_field(&mut cn.unit, name.unit)?;
+    set_name_field(&mut cn.commonName, name.common_name)?;
+    set_name_field(&mut cn.serialDev, name.serial_number)?;
+    set_name_field(&mut cn.userId, name.user_id)?;
+    set_name_field(&mut cn.postalCode, name.postal_code)?;
+    set_name_field(&mut cn.email, name.email)?;
+    Ok(())
+}
+
+/// Write a DER tag and definite length header, returning its size.
+#[cfg(cert_alt_names)]
+fn der_header(tag: u8, len: usize, out: &mut [u8; 4]) -> Result<usize, Error> {
+    out[0] = tag;
+    match len {
+        0..=0x7f => {
+            out[1] = len as u8;
+            Ok(2)
+        }
+        0x80..=0xff => {
+            out[1] = 0x81;
+            out[2] = len as u8;
+            Ok(3)
+        }
+        0x100..=0xffff => {
+            out[1] = 0x82;
+            out[2] = (len >> 8) as u8;
+            out[3] = len as u8;
+            Ok(4)
+        }
+        _ => Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E)),
+    }
+}
+
+/// Append a GeneralName to the DER `GeneralNames` SEQUENCE held in
+/// `Cert.altNames`, re-encoding the SEQUENCE header.
+#[cfg(cert_alt_names)]
+fn add_alt_name(wc_cert: &mut sys::Cert, name: &GeneralName) -> Result<(), Error> {
+    let (tag, value): (u8, &[u8]) = match *name {
+        GeneralName::Email(s) => (0x81, s.as_bytes()),
+        GeneralName::Dns(s) => (0x82, s.as_bytes()),
+        GeneralName::Uri(s) => (0x86, s.as_bytes()),
+        GeneralName::Ip(ip) if ip.len() == 4 || ip.len() == 16 => (0x87, ip),
+        _ => return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG)),
+    };
+    let buf = &mut wc_cert.altNames;
+    // Locate the contents of the existing SEQUENCE, which this function
+    // encoded with a minimal length.
+    let (start, len) = match wc_cert.altNamesSz as usize {
+        0 => (0, 0),
+        _ => match buf[1] {
+            0x81 => (3, buf[2] as usize),
+            0x82 => (4, (buf[2] as usize) << 8 | buf[3] as usize),
+            n => (2, n as usize),
+        },
+    };
+    let mut entry_hdr = [0u8; 4];
+    let entry_hdr_len = der_header(tag, value.len(), &mut entry_hdr)?;
+    let new_len = len + entry_hdr_len + value.len();
+    let mut seq_hdr = [0u8; 4];
+    let seq_hdr_len = der_header(0x30, new_len, &mut seq_hdr)?;
+    if seq_hdr_len + new_len > buf.len() {
+        return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+    }
+    buf.copy_within(start..start + len, seq_hdr_len);
+    buf[..seq_hdr_len].copy_from_slice(&seq_hdr[..seq_hdr_len]);
+    let mut pos = seq_hdr_len + len;
+    buf[pos..pos + entry_hdr_len].copy_from_slice(&entry_hdr[..entry_hdr_len]);
+    pos += entry_hdr_len;
+    buf[pos..pos + value.len()].copy_from_slice(value);
+    wc_cert.altNamesSz = (seq_hdr_len + new_len) as i32;
+    Ok(())
+}
+
+/// Initialize a wolfSSL `Cert` structure.
+fn init_cert(env: &crate::Env) -> Result<sys::Cert, Error> {
+    let heap = env.heap_ptr();
+    let dev_id = env.dev_id();
+    let mut wc_cert: MaybeUninit<sys::Cert> = MaybeUninit::uninit();
+    let rc = unsafe { sys::wc_InitCert_ex(wc_cert.as_mut_ptr(), heap, dev_id) };
+    if rc != 0 {
+        return Err(Error::from(rc));
+    }
+    Ok(unsafe { wc_cert.assume_init() })
+}
+
+/// Set the basicConstraints fields of a wolfSSL `Cert` structure.
+// WC_BITFIELD is `unsigned` rather than `byte` when wolfSSL is built with
+// WOLF_C89, so the bitfield setters are passed converted values.
+#[allow(clippy::useless_conversion)]
+fn set_basic_constraints(wc_cert: &mut sys::Cert, bc: &BasicConstraints) -> Result<(), Error> {
+    let path_len = match bc.path_len {
+        Some(path_len) if !bc.ca || path_len > u8::MAX as u16 => {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+        }
+        Some(path_len) => Some(path_len as u8),
+        None => None,
+    };
+    wc_cert.isCA = bc.ca as i32;
+    wc_cert.pathLen = path_len.unwrap_or(0);
+    wc_cert.set_pathLenSet((path_len.is_some() as u8).into());
+    wc_cert.set_basicConstSet(1u8.into());
+    // RFC 5280 4.2.1.9: the extension must be critical in CA certificates.
+    wc_cert.basicConstCrit = bc.ca as u8;
+    Ok(())
+}
+
+/// Sign the TBS data written to `dout` by `wc_MakeCert_ex()` or
+/// `wc_MakeCertReq_ex()`.
+fn sign_body(body_size: i32, sig_type: i32, dout: &mut [u8], key_type: i32,
+        key: *mut c_void, rng: &RNG) -> Result<usize, Error> {
+    let dout_size = crate::buffer_len_to_u32(dout.len())?;
+    let rc = unsafe {
+        sys::wc_SignCert_ex(body_size, sig_type, dout.as_mut_ptr(), dout_size,
+            key_type, key, rng.wc_rng)
+    };
+    if rc < 0 {
+        return Err(Error::from(rc));
+    }
+    Ok(rc as usize)
+}
+
+/// Convert DER to PEM with the header for `typ` (a `CertType` value).
+#[cfg(all(der_to_pem, feature = "alloc"))]
+fn der_to_pem(der: &[u8], typ: u32, dout: &mut [u8]) -> Result<usize, Error> {
+    let der_size = crate::buffer_len_to_u32(der.len())?;
+    let dout_size = crate::buffer_len_to_u32(dout.len())?;
+    let rc = unsafe {
+        sys::wc_DerToPem(der.as_ptr(), der_size, dout.as_mut_ptr(), dout_size, typ as i32)
+    };
+    if rc < 0 {
+        return Err(Error::from(rc));
+    }
+    Ok(rc as usize)
+}
+
+/// The `CertBuilder` struct manages a wolfSSL `Cert` object used to generate
+/// an X.509 v3 certificate.
+///
+/// By default the certificate is self-signed, valid for 500 days from the
+/// current time and gets a random serial number.
+pub struct CertBuilder<'a> {
+    wc_cert: sys::Cert,
+    sig_type: Option<i32>,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
+}
+
+impl<'a> CertBuilder<'a> {
+    /// Create a new certificate builder.
+    ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(CertBuilder) or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&crate::Env::new(wolfcrypt))
+    }
+
+    /// Create a new certificate builder with optional heap and device ID.
+    ///
+    /// # Parameters
+    ///
+    /// * `env`: Heap hint and crypto callback device to use.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(CertBuilder) or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn new_ex(env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let wc_cert = init_cert(env)?;
+        Ok(CertBuilder { wc_cert, sig_type: None, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData })
+    }
+
+    /// Set the subject name.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn set_subject(&mut self, name: &Name) -> Result<(), Error> {
+        set_name(&mut self.wc_cert.subject, name)
+    }
+
+    /// Set the issuer name and mark the certificate as not self-signed.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn set_issuer(&mut self, name: &Name) -> Result<(), Error> {
+        set_name(&mut self.wc_cert.issuer, name)?;
+        self.wc_cert.selfSigned = 0;
+        Ok(())
+    }
+
+    /// Set the issuer name to the subject of the DER-encoded CA certificate
+    /// `ca_der` and mark the certificate as not self-signed.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn set_issuer_from_cert(&mut self, ca_der: &[u8]) -> Result<(), Error> {
+        let ca_der_size = crate::buffer_len_to_u32(ca_der.len())? as i32;
+        let rc = unsafe {
+            sys::wc_SetIssuerBuffer(&mut self.wc_cert, ca_der.as_ptr(), ca_der_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Set the serial number, given as big-endian bytes.
+    ///
+    /// If no serial number is set, a random one is generated when signing.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn set_serial(&mut self, serial: &[u8]) -> Result<(), Error> {
+        if serial.is_empty() || serial.len() > self.wc_cert.serial.len() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+        }
+        self.wc_cert.serial[..serial.len()].copy_from_slice(serial);
+        self.wc_cert.serialSz = serial.len() as i32;
+        Ok(())
+    }
+
+    /// Set the validity period to `days` days starting from the current
+    /// time.
+    pub fn set_days_valid(&mut self, days: u16) {
+        self.wc_cert.daysValid = days as i32;
+        self.wc_cert.beforeDateSz = 0;
+        self.wc_cert.afterDateSz = 0;
+    }
+
+    /// Set an explicit validity period.
+    ///
+    /// # Parameters
+    ///
+    /// * `not_before`: Start of the validity period.
+    /// * `not_after`: End of the validity period.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn set_validity(&mut self, not_before: &Time, not_after: &Time) -> Result<(), Error> {
+        if not_after < not_before {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+        }
+        let mut der = [0u8; 17];
+        let len = not_before.to_der(&mut der);
+        self.wc_cert.beforeDate[..len].copy_from_slice(&der[..len]);
+        self.wc_cert.beforeDateSz = len as i32;
+        let len = not_after.to_der(&mut der);
+        self.wc_cert.afterDate[..len].copy_from_slice(&der[..len]);
+        self.wc_cert.afterDateSz = len as i32;
+        Ok(())
+    }
+
+    /// Set the basicConstraints extension.
+    ///
+    /// A `path_len` may only be given for a CA and must be at most 255.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn set_basic_constraints(&mut self, bc: &BasicConstraints) -> Result<(), Error> {
+        set_basic_constraints(&mut self.wc_cert, bc)
+    }
+
+    /// Add an entry to the subjectAltName extension.
+    ///
+    /// Only the `Dns`, `Email`, `Uri` and `Ip` forms of [`GeneralName`] are
+    /// supported.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    #[cfg(cert_alt_names)]
+    pub fn add_alt_name(&mut self, name: &GeneralName) -> Result<(), Error> {
+        add_alt_name(&mut self.wc_cert, name)
+    }
+
+    /// Set the keyUsage extension bits (`DecodedCert::KEY_USAGE_*`).
+    #[cfg(cert_ext)]
+    pub fn set_key_usage(&mut self, key_usage: u16) {
+        self.wc_cert.keyUsage = key_usage;
+    }
+
+    /// Set the extendedKeyUsage extension bits
+    /// (`DecodedCert::EXT_KEY_USAGE_*`).
+    #[cfg(cert_ext)]
+    pub fn set_ext_key_usage(&mut self, ext_key_usage: u8) {
+        self.wc_cert.extKeyUsage = ext_key_usage;
+    }
+
+    /// Set the subjectKeyIdentifier extension from the subject public key.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    #[cfg(cert_ext)]
+    pub fn set_subject_key_id<K: CertKey>(&mut self, key: &mut K) -> Result<(), Error> {
+        let key_type = key.cert_key_type()?;
+        let rc = unsafe {
+            sys::wc_SetSubjectKeyIdFromPublicKey_ex(&mut self.wc_cert, key_type,
+                key.cert_key_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Set the authorityKeyIdentifier extension from the issuer public key.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    #[cfg(cert_ext)]
+    pub fn set_auth_key_id<K: CertKey>(&mut self, key: &mut K) -> Result<(), Error> {
+        let key_type = key.cert_key_type()?;
+        let rc = unsafe {
+            sys::wc_SetAuthKeyIdFromPublicKey_ex(&mut self.wc_cert, key_type,
+                key.cert_key_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Set the authorityKeyIdentifier extension from the subjectKeyIdentifier
+    /// of the DER-encoded CA certificate `ca_der`.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    #[cfg(cert_ext)]
+    pub fn set_auth_key_id_from_cert(&mut self, ca_der: &[u8]) -> Result<(), Error> {
+        let ca_der_size = crate::buffer_len_to_u32(ca_der.len())? as i32;
+        let rc = unsafe {
+            sys::wc_SetAuthKeyIdFromCert(&mut self.wc_cert, ca_der.as_ptr(), ca_der_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Override the signature algorithm, one of the `sys::Ctc_SigType_*`
+    /// values. By default it is chosen from the signing key.
+    pub fn set_sig_type(&mut self, sig_type: u32) {
+        self.sig_type = Some(sig_type as i32);
+    }
+
+    /// Generate the certificate for `subject_key`, signed by `issuer_key`.
+    ///
+    /// The issuer name should have been set with `set_issuer()` or
+    /// `set_issuer_from_cert()`.
+    ///
+    /// # Parameters
+    ///
+    /// * `subject_key`: Key whose public part is certified.
+    /// * `issuer_key`: Private key of the issuer.
+    /// * `rng`: RNG used for the serial number and signature.
+    /// * `dout`: Output buffer for the DER-encoded certificate.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the size of the DER certificate
+    /// written to `dout` or Err(e) containing the wolfSSL library error code
+    /// value.
+    pub fn sign<S: CertKey, I: CertKey>(&mut self, subject_key: &mut S, issuer_key: &mut I,
+            rng: &RNG, dout: &mut [u8]) -> Result<usize, Error> {
+        let subject = (subject_key.cert_key_type()?, subject_key.cert_key_ptr());
+        let issuer_sig_type = issuer_key.cert_sig_type()?;
+        let issuer = (issuer_key.cert_key_type()?, issuer_key.cert_key_ptr());
+        self.make_and_sign(subject, issuer, issuer_sig_type, rng, dout)
+    }
+
+    /// Generate a self-signed certificate for `key`.
+    ///
+    /// # Parameters
+    ///
+    /// * `key`: Key whose public part is certified and which signs the
+    ///   certificate.
+    /// * `rng`: RNG used for the serial number and signature.
+    /// * `dout`: Output buffer for the DER-encoded certificate.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the size of the DER certificate
+    /// written to `dout` or Err(e) containing the wolfSSL library error code
+    /// value.
+    pub fn sign_self<K: CertKey>(&mut self, key: &mut K, rng: &RNG, dout: &mut [u8])
+            -> Result<usize, Error> {
+        self.wc_cert.selfSigned = 1;
+        let sig_type = key.cert_sig_type()?;
+        let key = (key.cert_key_type()?, key.cert_key_ptr());
+        self.make_and_sign(key, key, sig_type, rng, dout)
+    }
+
+    /// Generate the certificate for `subject_key`, signed by `issuer_key`,
+    /// in PEM format.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the size of the PEM certificate
+    /// written to `dout` or Err(e) containing the wolfSSL library error code
+    /// value.
+    #[cfg(all(der_to_pem, feature = "alloc"))]
+    pub fn sign_pem<S: CertKey, I: CertKey>(&mut self, subject_key: &mut S,
+            issuer_key: &mut I, rng: &RNG, dout: &mut [u8]) -> Result<usize, Error> {
+        let mut der = alloc::vec![0u8; dout.len()];
+        let der_size = self.sign(subject_key, issuer_key, rng, &mut der)?;
+        der_to_pem(&der[..der_size], sys::CertType_CERT_TYPE, dout)
+    }
+
+    /// Generate a self-signed certificate for `key` in PEM format.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the size of the PEM certificate
+    /// written to `dout` or Err(e) containing the wolfSSL library error code
+    /// value.
+    #[cfg(all(der_to_pem, feature = "alloc"))]
+    pub fn sign_self_pem<K: CertKey>(&mut self, key: &mut K, rng: &RNG, dout: &mut [u8])
+            -> Result<usize, Error> {
+        let mut der = alloc::vec![0u8; dout.len()];
+        let der_size = self.sign_self(key, rng, &mut der)?;
+        der_to_pem(&der[..der_size], sys::CertType_CERT_TYPE, dout)
+    }
+
+    fn make_and_sign(&mut self, subject: (i32, *mut c_void), issuer: (i32, *mut c_void),
+            issuer_sig_type: i32, rng: &RNG, dout: &mut [u8]) -> Result<usize, Error> {
+        let sig_type = self.sig_type.unwrap_or(issuer_sig_type);
+        self.wc_cert.sigType = sig_type;
+        let dout_size = crate::buffer_len_to_u32(dout.len())?;
+        let rc = unsafe {
+            sys::wc_MakeCert_ex(&mut self.wc_cert, dout.as_mut_ptr(), dout_size,
+                subject.0, subject.1, rng.wc_rng)
+        };
+        if rc < 0 {
+            return Err(Error::from(rc));
+        }
+        sign_body(self.wc_cert.bodySz, sig_type, dout, issuer.0, issuer.1, rng)
+    }
+}
+
+impl Drop for CertBuilder<'_> {
+    /// Safely free the wolfSSL resources.
+    fn drop(&mut self) {
+        #[cfg(cert_gen_cache)]
+        unsafe { sys::wc_SetCert_Free(&mut self.wc_cert); }
+    }
+}
+
+/// The `CsrBuilder` struct manages a wolfSSL `Cert` object used to generate
+/// a PKCS #10 certificate signing request.
+#[cfg(cert_req)]
+pub struct CsrBuilder<'a> {
+    wc_cert: sys::Cert,
+    sig_type: Option<i32>,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
+}
+
+#[cfg(cert_req)]
+impl<'a> CsrBuilder<'a> {
+    /// Create a new CSR builder.
+    ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(CsrBuilder) or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&crate::Env::new(wolfcrypt))
+    }
+
+    /// Create a new CSR builder with optional heap and device ID.
+    ///
+    /// # Parameters
+    ///
+    /// * `env`: Heap hint and crypto callback device to use.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(CsrBuilder) or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn new_ex(env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let wc_cert = init_cert(env)?;
+        Ok(CsrBuilder { wc_cert, sig_type: None, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData })
+    }
+
+    /// Set the subject name.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn set_subject(&mut self, name: &Name) -> Result<(), Error> {
+        set_name(&mut self.wc_cert.subject, name)
+    }
+
+    /// Request a basicConstraints extension.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn set_basic_constraints(&mut self, bc: &BasicConstraints) -> Result<(), Error> {
+        set_basic_constraints(&mut self.wc_cert, bc)
+    }
+
+    /// Add an entry to the requested subjectAltName extension.
+    ///
+    /// Only the `Dns`, `Email`, `Uri` and `Ip` forms of [`GeneralName`] are
+    /// supported.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    #[cfg(cert_alt_names)]
+    pub fn add_alt_name(&mut self, name: &GeneralName) -> Result<(), Error> {
+        add_alt_name(&mut self.wc_cert, name)
+    }
+
+    /// Set the requested keyUsage extension bits
+    /// (`DecodedCert::KEY_USAGE_*`).
+    #[cfg(cert_ext)]
+    pub fn set_key_usage(&mut self, key_usage: u16) {
+        self.wc_cert.keyUsage = key_usage;
+    }
+
+    /// Set the requested extendedKeyUsage extension bits
+    /// (`DecodedCert::EXT_KEY_USAGE_*`).
+    #[cfg(cert_ext)]
+    pub fn set_ext_key_usage(&mut self, ext_key_usage: u8) {
+        self.wc_cert.extKeyUsage = ext_key_usage;
+    }
+
+    /// Set the challengePassword attribute.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn set_challenge_password(&mut self, password: &str) -> Result<(), Error> {
+        set_name_field(&mut self.wc_cert.challengePw, Some(password))
+    }
+
+    /// Set the unstructuredName attribute.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn set_unstructured_name(&mut self, name: &str) -> Result<(), Error> {
+        set_name_field(&mut self.wc_cert.unstructuredName, Some(name))
+    }
+
+    /// Override the signature algorithm, one of the `sys::Ctc_SigType_*`
+    /// values. By default it is chosen from the key.
+    pub fn set_sig_type(&mut self, sig_type: u32) {
+        self.sig_type = Some(sig_type as i32);
+    }
+
+    /// Generate the CSR for `key`, signed with the same key.
+    ///
+    /// # Parameters
+    ///
+    /// * `key`: Key pair requesting the certificate.
+    /// * `rng`: RNG used for the signature.
+    /// * `dout`: Output buffer for the DER-encoded CSR.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the size of the DER CSR written to
+    /// `dout` or Err(e) containing the wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(all(ecc, random, cert_req))]
+    /// {
+    /// use wolfssl_wolfcrypt::Env;
+    /// use wolfssl_wolfcrypt::cert_gen::{CsrBuilder, Name};
+    /// use wolfssl_wolfcrypt::ecc::ECC;
+    /// use wolfssl_wolfcrypt::random::RNG;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    ///
+    /// let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut key = ECC::generate(32, &rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
+    /// let mut csr = CsrBuilder::new(&wolfcrypt).expect("Error with new()");
+    /// csr.set_subject(&Name {
+    ///     common_name: Some("device-0001"),
+    ///     ..Default::default()
+    /// }).expect("Error with set_subject()");
+    /// let mut der = [0u8; 1024];
+    /// let der_size = csr.sign(&mut key, &rng, &mut der).expect("Error with sign()");
+    /// assert!(der_size > 0);
+    /// }
+    /// ```
+    pub fn sign<K: CertKey>(&mut self, key: &mut K, rng: &RNG, dout: &mut [u8])
+            -> Result<usize, Error> {
+        let sig_type = match self.sig_type {
+            Some(sig_type) => sig_type,
+            None => key.cert_sig_type()?,
+        };
+        let key_type = key.cert_key_type()?;
+        let key_ptr = key.cert_key_ptr();
+        self.wc_cert.sigType = sig_type;
+        let dout_size = crate::buffer_len_to_u32(dout.len())?;
+        let rc = unsafe {
+            sys::wc_MakeCertReq_ex(&mut self.wc_cert, dout.as_mut_ptr(), dout_size,
+                key_type, key_ptr)
+        };
+        if rc < 0 {
+            return Err(Error::from(rc));
+        }
+        sign_body(self.wc_cert.bodySz, sig_type, dout, key_type, key_ptr, rng)
+    }
+
+    /// Generate the CSR for `key` in PEM format.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the size of the PEM CSR written to
+    /// `dout` or Err(e) containing the wolfSSL library error code value.
+    #[cfg(all(der_to_pem, feature = "alloc"))]
+    pub fn sign_pem<K: CertKey>(&mut self, key: &mut K, rng: &RNG, dout: &mut [u8])
+            -> Result<usize, Error> {
+        let mut der = alloc::vec![0u8; dout.len()];
+        let der_size = self.sign(key, rng, &mut der)?;
+        der_to_pem(&der[..der_size], sys::CertType_CERTREQ_TYPE, dout)
+    }
+}
+
+#[cfg(cert_req)]
+impl Drop for CsrBuilder<'_> {
+    /// Safely free the wolfSSL resources.
+    fn drop(&mut self) {
+        #[cfg(cert_gen_cache)]
+        unsafe { sys::wc_SetCert_Free(&mut self.wc_cert); }
+    }
+}
@@ -25,12 +25,14 @@ ChaCha20-Poly1305 functionality.
 
 #![cfg(chacha20_poly1305)]
 
+use crate::Error;
 use crate::sys;
 use core::mem::MaybeUninit;
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
 pub struct ChaCha20Poly1305 {
     wc_ccp: sys::ChaChaPoly_Aead,
+    _wolfcrypt: crate::WolfCrypt,
 }
 
 impl ChaCha20Poly1305 {
@@ -64,18 +66,18 @@ impl ChaCha20Poly1305 {
     /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
     /// library error code value.
     pub fn decrypt(key: &[u8], iv: &[u8], aad: &[u8], ciphertext: &[u8],
-        auth_tag: &[u8], plaintext: &mut [u8]) -> Result<(), i32> {
+        auth_tag: &[u8], plaintext: &mut [u8]) -> Result<(), Error> {
         if key.len() != Self::KEYSIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         if iv.len() != Self::IV_SIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         if auth_tag.len() != Self::AUTH_TAG_SIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         if plaintext.len() < ciphertext.len() {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let aad_size = crate::buffer_len_to_u32(aad.len())?;
         let ciphertext_size = crate::buffer_len_to_u32(ciphertext.len())?;
@@ -85,7 +87,7 @@ impl ChaCha20Poly1305 {
                 ciphertext_size, auth_tag.as_ptr(), plaintext.as_mut_ptr())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -109,18 +111,18 @@ impl ChaCha20Poly1305 {
     /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
     /// library error code value.
     pub fn encrypt(key: &[u8], iv: &[u8], aad: &[u8], plaintext: &[u8],
-        ciphertext: &mut [u8], auth_tag: &mut [u8]) -> Result<(), i32> {
+        ciphertext: &mut [u8], auth_tag: &mut [u8]) -> Result<(), Error> {
         if key.len() != Self::KEYSIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         if iv.len() != Self::IV_SIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         if auth_tag.len() != Self::AUTH_TAG_SIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         if ciphertext.len() < plaintext.len() {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let aad_size = crate::buffer_len_to_u32(aad.len())?;
         let plaintext_size = crate::buffer_len_to_u32(plaintext.len())?;
@@ -130,7 +132,7 @@ impl ChaCha20Poly1305 {
                 ciphertext.as_mut_ptr(), auth_tag.as_mut_ptr())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -143,15 +145,17 @@ impl ChaCha20Poly1305 {
     /// * `iv`: Initialization Vector (must be 12 bytes).
     /// * `encrypt`: Whether the instance will be used to encrypt (true) or
     ///   decrypt (false).
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// Returns either Ok(chacha20poly1305) on success or Err(e) containing the
     /// wolfSSL library error code value.
-    pub fn new(key: &[u8], iv: &[u8], encrypt: bool) -> Result<Self, i32> {
+    pub fn new(key: &[u8], iv: &[u8], encrypt: bool, wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        let wolfcrypt = wolfcrypt.clone();
         if key.len() != Self::KEYSIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         if iv.len() != Self::IV_SIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let mut wc_ccp: MaybeUninit<sys::ChaChaPoly_Aead> = MaybeUninit::uninit();
         let rc = unsafe {
@@ -159,10 +163,10 @@ impl ChaCha20Poly1305 {
                 iv.as_ptr(), if encrypt {1} else {0})
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_ccp = unsafe { wc_ccp.assume_init() };
-        Ok(ChaCha20Poly1305 { wc_ccp })
+        Ok(ChaCha20Poly1305 { wc_ccp, _wolfcrypt: wolfcrypt })
     }
 
     /// Update AAD (additional authenticated data).
@@ -177,14 +181,14 @@ impl ChaCha20Poly1305 {
     ///
     /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
     /// library error code value.
-    pub fn update_aad(&mut self, aad: &[u8]) -> Result<(), i32> {
+    pub fn update_aad(&mut self, aad: &[u8]) -> Result<(), Error> {
         let aad_size = crate::buffer_len_to_u32(aad.len())?;
         let rc = unsafe {
             sys::wc_ChaCha20Poly1305_UpdateAad(&mut self.wc_ccp,
                 aad.as_ptr(), aad_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -206,9 +210,9 @@ impl ChaCha20Poly1305 {
     ///
     /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
     /// library error code value.
-    pub fn update_data(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), i32> {
+    pub fn update_data(&mut self, din: &[u8], dout: &mut [u8]) -> Result<(), Error> {
         if din.len() != dout.len() {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let din_size = crate::buffer_len_to_u32(din.len())?;
         let rc = unsafe {
@@ -216,7 +220,7 @@ impl ChaCha20Poly1305 {
                 din.as_ptr(), dout.as_mut_ptr(), din_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -241,16 +245,16 @@ impl ChaCha20Poly1305 {
     ///
     /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
     /// library error code value.
-    pub fn finalize(mut self, auth_tag: &mut [u8]) -> Result<(), i32> {
+    pub fn finalize(mut self, auth_tag: &mut [u8]) -> Result<(), Error> {
         if auth_tag.len() != Self::AUTH_TAG_SIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let rc = unsafe {
             sys::wc_ChaCha20Poly1305_Final(&mut self.wc_ccp,
                 auth_tag.as_mut_ptr())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -275,9 +279,9 @@ impl ChaCha20Poly1305 {
     /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
     /// library error code value. A tag mismatch is reported as Err with the
     /// `MAC_CMP_FAILED_E` error code.
-    pub fn finalize_verify(mut self, auth_tag: &[u8]) -> Result<(), i32> {
+    pub fn finalize_verify(mut self, auth_tag: &[u8]) -> Result<(), Error> {
         if auth_tag.len() != Self::AUTH_TAG_SIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let mut calculated_tag = [0u8; Self::AUTH_TAG_SIZE];
         let rc = unsafe {
@@ -285,14 +289,14 @@ impl ChaCha20Poly1305 {
                 calculated_tag.as_mut_ptr())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let rc = unsafe {
             sys::wc_ChaCha20Poly1305_CheckTag(auth_tag.as_ptr(),
                 calculated_tag.as_ptr())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -436,12 +440,12 @@ impl XChaCha20Poly1305 {
     /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
     /// library error code value.
     pub fn decrypt(key: &[u8], iv: &[u8], aad: &[u8], ciphertext: &[u8],
-        plaintext: &mut [u8]) -> Result<(), i32> {
+        plaintext: &mut [u8]) -> Result<(), Error> {
         if key.len() != Self::KEYSIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         if iv.len() != Self::IV_SIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let rc = unsafe {
             sys::wc_XChaCha20Poly1305_Decrypt(
@@ -452,7 +456,7 @@ impl XChaCha20Poly1305 {
                 key.as_ptr(), key.len())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -477,12 +481,12 @@ impl XChaCha20Poly1305 {
     /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
     /// library error code value.
     pub fn encrypt(key: &[u8], iv: &[u8], aad: &[u8], plaintext: &[u8],
-        ciphertext: &mut [u8]) -> Result<(), i32> {
+        ciphertext: &mut [u8]) -> Result<(), Error> {
         if key.len() != Self::KEYSIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         if iv.len() != Self::IV_SIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let rc = unsafe {
             sys::wc_XChaCha20Poly1305_Encrypt(
@@ -493,7 +497,7 @@ impl XChaCha20Poly1305 {
                 key.as_ptr(), key.len())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
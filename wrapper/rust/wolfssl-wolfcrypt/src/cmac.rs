@@ -25,7 +25,10 @@ Message Authentication Code (CMAC) functionality.
 
 #![cfg(cmac)]
 
+use crate::Error;
+use crate::memory::HeapHint;
 use crate::sys;
+use core::marker::PhantomData;
 use core::mem::MaybeUninit;
 
 /// The `CMAC` struct manages the lifecycle of a wolfSSL `Cmac` object.
@@ -33,10 +36,13 @@ use core::mem::MaybeUninit;
 /// It ensures proper initialization and deallocation.
 ///
 /// An instance can be created with `new()`.
-pub struct CMAC {
+pub struct CMAC<'a> {
     ws_cmac: sys::Cmac,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
-impl CMAC {
+impl<'a> CMAC<'a> {
     /// One-shot CMAC generation function.
     ///
     /// # Parameters
@@ -69,7 +75,7 @@ impl CMAC {
     /// }
     /// ```
     #[cfg(aes)]
-    pub fn generate(key: &[u8], data: &[u8], dout: &mut [u8]) -> Result<(), i32> {
+    pub fn generate(key: &[u8], data: &[u8], dout: &mut [u8]) -> Result<(), Error> {
         let key_size = crate::buffer_len_to_u32(key.len())?;
         let data_size = crate::buffer_len_to_u32(data.len())?;
         let mut dout_size = crate::buffer_len_to_u32(dout.len())?;
@@ -79,7 +85,7 @@ impl CMAC {
                 key.as_ptr(), key_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -89,6 +95,7 @@ impl CMAC {
     /// # Parameters
     ///
     /// * `key`: Key to use for CMAC generation.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
@@ -99,14 +106,16 @@ impl CMAC {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::cmac::CMAC;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let key = [
     ///     0x2bu8, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6,
     ///     0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c
     /// ];
-    /// let mut cmac = CMAC::new(&key).expect("Error with new()");
+    /// let mut cmac = CMAC::new(&key, &wolfcrypt).expect("Error with new()");
     /// ```
-    pub fn new(key: &[u8]) -> Result<Self, i32> {
-        Self::new_ex(key, None, None)
+    pub fn new(key: &[u8], wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(key, &crate::Env::new(wolfcrypt))
     }
 
     /// Create a new CMAC object using the given key with optional heap and
@@ -115,8 +124,7 @@ impl CMAC {
     /// # Parameters
     ///
     /// * `key`: Key to use for CMAC generation.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -126,34 +134,32 @@ impl CMAC {
     /// # Example
     ///
     /// ```rust
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::cmac::CMAC;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let key = [
     ///     0x2bu8, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6,
     ///     0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c
     /// ];
-    /// let mut cmac = CMAC::new_ex(&key, None, None).expect("Error with new_ex()");
+    /// let mut cmac = CMAC::new_ex(&key, &Env::new(&wolfcrypt)).expect("Error with new_ex()");
     /// ```
-    pub fn new_ex(key: &[u8], heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn new_ex(key: &[u8], env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let key_size = crate::buffer_len_to_u32(key.len())?;
         let mut ws_cmac: MaybeUninit<sys::Cmac> = MaybeUninit::uninit();
         let typ = sys::CmacType_WC_CMAC_AES as i32;
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe {
             sys::wc_InitCmac_ex(ws_cmac.as_mut_ptr(), key.as_ptr(), key_size,
                 typ, core::ptr::null_mut(), heap, dev_id)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let ws_cmac = unsafe { ws_cmac.assume_init() };
-        let cmac = CMAC { ws_cmac };
+        let cmac = CMAC { ws_cmac, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData };
         Ok(cmac)
     }
 
@@ -192,7 +198,7 @@ impl CMAC {
     /// }
     /// ```
     #[cfg(aes)]
-    pub fn verify(key: &[u8], data: &[u8], check: &[u8]) -> Result<bool, i32> {
+    pub fn verify(key: &[u8], data: &[u8], check: &[u8]) -> Result<bool, Error> {
         let key_size = crate::buffer_len_to_u32(key.len())?;
         let data_size = crate::buffer_len_to_u32(data.len())?;
         let check_size = crate::buffer_len_to_u32(check.len())?;
@@ -205,7 +211,7 @@ impl CMAC {
             return Ok(false);
         }
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(rc == 0)
     }
@@ -217,8 +223,7 @@ impl CMAC {
     /// * `key`: Key to use for CMAC generation.
     /// * `data`: CMAC input data.
     /// * `dout`: Output buffer where CMAC is written.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -230,7 +235,10 @@ impl CMAC {
     /// ```rust
     /// #[cfg(aes)]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::cmac::CMAC;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let key = [
     ///     0x2bu8, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6,
     ///     0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c
@@ -240,31 +248,26 @@ impl CMAC {
     ///     0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a,
     /// ];
     /// let mut generate_out = [0u8; 16];
-    /// let mut cmac = CMAC::new(&key).expect("Error with new()");
-    /// cmac.generate_ex(&key, &message, &mut generate_out, None, None).expect("Error with generate_ex()");
+    /// let mut cmac = CMAC::new(&key, &wolfcrypt).expect("Error with new()");
+    /// cmac.generate_ex(&key, &message, &mut generate_out, &Env::new(&wolfcrypt)).expect("Error with generate_ex()");
     /// }
     /// ```
     #[cfg(aes)]
-    pub fn generate_ex(&mut self, key: &[u8], data: &[u8], dout: &mut [u8], heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<(), i32> {
+    pub fn generate_ex(&mut self, key: &[u8], data: &[u8], dout: &mut [u8], env: &crate::Env<'_, 'a>) -> Result<(), Error> {
         let key_size = crate::buffer_len_to_u32(key.len())?;
         let data_size = crate::buffer_len_to_u32(data.len())?;
         let mut dout_size = crate::buffer_len_to_u32(dout.len())?;
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe {
             sys::wc_AesCmacGenerate_ex(&mut self.ws_cmac,
                 dout.as_mut_ptr(), &mut dout_size,
                 data.as_ptr(), data_size,
                 key.as_ptr(), key_size, heap, dev_id)
         };
+        self._device = env.device_ref();
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -284,6 +287,8 @@ impl CMAC {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::cmac::CMAC;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let key = [
     ///     0x2bu8, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6,
     ///     0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c
@@ -292,16 +297,16 @@ impl CMAC {
     ///     0x6bu8, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96,
     ///     0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a,
     /// ];
-    /// let mut cmac = CMAC::new(&key).expect("Error with new()");
+    /// let mut cmac = CMAC::new(&key, &wolfcrypt).expect("Error with new()");
     /// cmac.update(&message).expect("Error with update()");
     /// ```
-    pub fn update(&mut self, data: &[u8]) -> Result<(), i32> {
+    pub fn update(&mut self, data: &[u8]) -> Result<(), Error> {
         let data_size = crate::buffer_len_to_u32(data.len())?;
         let rc = unsafe {
             sys::wc_CmacUpdate(&mut self.ws_cmac, data.as_ptr(), data_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -324,6 +329,8 @@ impl CMAC {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::cmac::CMAC;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let key = [
     ///     0x2bu8, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6,
     ///     0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c
@@ -332,19 +339,19 @@ impl CMAC {
     ///     0x6bu8, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96,
     ///     0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a,
     /// ];
-    /// let mut cmac = CMAC::new(&key).expect("Error with new()");
+    /// let mut cmac = CMAC::new(&key, &wolfcrypt).expect("Error with new()");
     /// cmac.update(&message).expect("Error with update()");
     /// let mut finalize_out = [0u8; 16];
     /// cmac.finalize(&mut finalize_out).expect("Error with finalize()");
     /// ```
-    pub fn finalize(mut self, dout: &mut [u8]) -> Result<(), i32> {
+    pub fn finalize(mut self, dout: &mut [u8]) -> Result<(), Error> {
         let mut dout_size = crate::buffer_len_to_u32(dout.len())?;
         let rc = unsafe {
             sys::wc_CmacFinalNoFree(&mut self.ws_cmac,
                 dout.as_mut_ptr(), &mut dout_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -356,8 +363,7 @@ impl CMAC {
     /// * `key`: Key to use for CMAC generation.
     /// * `data`: CMAC input data.
     /// * `check`: CMAC value to compare to.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -370,7 +376,10 @@ impl CMAC {
     /// ```rust
     /// #[cfg(aes)]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::cmac::CMAC;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let key = [
     ///     0x2bu8, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6,
     ///     0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c
@@ -381,46 +390,41 @@ impl CMAC {
     /// ];
     /// let mut generate_out = [0u8; 16];
     /// CMAC::generate(&key, &message, &mut generate_out).expect("Error with generate()");
-    /// let mut cmac = CMAC::new(&key).expect("Error with new()");
-    /// let valid = cmac.verify_ex(&key, &message, &generate_out, None, None).expect("Error with verify_ex()");
+    /// let mut cmac = CMAC::new(&key, &wolfcrypt).expect("Error with new()");
+    /// let valid = cmac.verify_ex(&key, &message, &generate_out, &Env::new(&wolfcrypt)).expect("Error with verify_ex()");
     /// assert!(valid);
     /// }
     /// ```
     #[cfg(aes)]
-    pub fn verify_ex(&mut self, key: &[u8], data: &[u8], check: &[u8], heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<bool, i32> {
+    pub fn verify_ex(&mut self, key: &[u8], data: &[u8], check: &[u8], env: &crate::Env) -> Result<bool, Error> {
         let key_size = crate::buffer_len_to_u32(key.len())?;
         let data_size = crate::buffer_len_to_u32(data.len())?;
         let check_size = crate::buffer_len_to_u32(check.len())?;
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe {
             sys::wc_AesCmacVerify_ex(&mut self.ws_cmac,
                 check.as_ptr(), check_size,
                 data.as_ptr(), data_size,
                 key.as_ptr(), key_size, heap, dev_id)
         };
+        self._device = env.device_ref();
         if rc == sys::wolfCrypt_ErrorCodes_MAC_CMP_FAILED_E {
             return Ok(false);
         }
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(rc == 0)
     }
 }
-impl CMAC {
+impl<'a> CMAC<'a> {
     fn zeroize(&mut self) {
         unsafe { crate::zeroize_raw(&mut self.ws_cmac); }
     }
 }
 
-impl Drop for CMAC {
+impl Drop for CMAC<'_> {
     /// Safely free the wolfSSL resources.
     fn drop(&mut self) {
         unsafe { sys::wc_CmacFree(&mut self.ws_cmac); }
@@ -42,7 +42,7 @@ macro_rules! impl_cmac_mac {
     ) => {
         $(#[$attr])*
         pub struct $name {
-            cmac: crate::cmac::CMAC,
+            cmac: crate::cmac::CMAC<'static>,
         }
 
         $(#[$attr])*
@@ -61,8 +61,9 @@ macro_rules! impl_cmac_mac {
         $(#[$attr])*
         impl digest::KeyInit for $name {
             fn new(key: &digest::Key<Self>) -> Self {
+                let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
                 Self {
-                    cmac: crate::cmac::CMAC::new(key.as_slice())
+                    cmac: crate::cmac::CMAC::new(key.as_slice(), wolfcrypt)
                         .expect("wolfCrypt CMAC init failed"),
                 }
             }
@@ -0,0 +1,599 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+This module provides a Rust wrapper for the wolfCrypt library's Base64 and
+Base16 (hex) encoding functionality, along with PEM armoring and unarmoring
+of DER data by label.
+
+Base64 decoding uses wolfCrypt's constant-time decoder. Base64 encoding and
+Base16 encoding and decoding are table based and are not constant time.
+
+PEM blocks carrying legacy OpenSSL encryption headers
+(`Proc-Type: 4,ENCRYPTED`) can be decrypted with a password using
+[`pem_decode_encrypted()`].
+
+# Examples
+
+```rust
+#[cfg(base64_encode)]
+{
+use wolfssl_wolfcrypt::coding::*;
+
+let der = [0x30u8, 0x03, 0x02, 0x01, 0x00];
+let mut pem = [0u8; 128];
+let pem_size = pem_encode("EXAMPLE", &der, &mut pem).expect("Error with pem_encode()");
+let pem = &pem[..pem_size];
+assert_eq!(pem_label(pem), Ok("EXAMPLE"));
+let mut out = [0u8; 16];
+let out_size = pem_decode(pem, "EXAMPLE", &mut out).expect("Error with pem_decode()");
+assert_eq!(&out[..out_size], &der);
+}
+```
+*/
+
+#![cfg(coding)]
+
+use crate::Error;
+use crate::sys;
+
+/// Number of Base64 characters per line in PEM and line-wrapped Base64
+/// output.
+pub const BASE64_LINE_SIZE: usize = 64;
+
+const PEM_BEGIN: &[u8] = b"-----BEGIN ";
+const PEM_END: &[u8] = b"-----END ";
+const PEM_DASHES: &[u8] = b"-----";
+
+/// Compute the size of the Base64 encoding of `input_size` bytes.
+///
+/// # Parameters
+///
+/// * `input_size`: Size of the data to encode.
+/// * `newlines`: Whether the output is wrapped every `BASE64_LINE_SIZE`
+///   characters, as done by `base64_encode()`.
+///
+/// # Returns
+///
+/// Returns the size of the encoded output.
+pub const fn base64_encoded_size(input_size: usize, newlines: bool) -> usize {
+    let size = input_size.div_ceil(3) * 4;
+    if newlines {
+        size + size.div_ceil(BASE64_LINE_SIZE)
+    } else {
+        size
+    }
+}
+
+/// Compute the maximum size of the data decoded from `input_size` Base64
+/// characters.
+///
+/// # Parameters
+///
+/// * `input_size`: Size of the Base64 data to decode.
+///
+/// # Returns
+///
+/// Returns the maximum size of the decoded output.
+pub const fn base64_decoded_max_size(input_size: usize) -> usize {
+    input_size.div_ceil(4) * 3
+}
+
+/// Encode data as Base64 with a newline after every `BASE64_LINE_SIZE`
+/// characters and at the end of the output.
+///
+/// # Parameters
+///
+/// * `input`: Data to encode.
+/// * `dout`: Output buffer. Must be at least `base64_encoded_size(input.len(),
+///   true)` bytes.
+///
+/// # Returns
+///
+/// Returns either Ok(size) containing the number of bytes written to `dout`
+/// or Err(e) containing the wolfSSL library error code value.
+///
+/// # Example
+///
+/// ```rust
+/// #[cfg(base64_encode)]
+/// {
+/// use wolfssl_wolfcrypt::coding::*;
+/// let mut out = [0u8; 16];
+/// let out_size = base64_encode(b"wolfSSL", &mut out).expect("Error with base64_encode()");
+/// assert_eq!(&out[..out_size], b"d29sZlNTTA==\n");
+/// }
+/// ```
+#[cfg(base64_encode)]
+pub fn base64_encode(input: &[u8], dout: &mut [u8]) -> Result<usize, Error> {
+    let input_size = crate::buffer_len_to_u32(input.len())?;
+    let mut dout_size = crate::buffer_len_to_u32(dout.len())?;
+    let rc = unsafe {
+        sys::Base64_Encode(input.as_ptr(), input_size, dout.as_mut_ptr(), &mut dout_size)
+    };
+    if rc != 0 {
+        return Err(Error::from(rc));
+    }
+    Ok(dout_size as usize)
+}
+
+/// Encode data as Base64 on a single line without newlines.
+///
+/// # Parameters
+///
+/// * `input`: Data to encode.
+/// * `dout`: Output buffer. Must be at least `base64_encoded_size(input.len(),
+///   false)` bytes.
+///
+/// # Returns
+///
+/// Returns either Ok(size) containing the number of bytes written to `dout`
+/// or Err(e) containing the wolfSSL library error code value.
+///
+/// # Example
+///
+/// ```rust
+/// #[cfg(base64_encode)]
+/// {
+/// use wolfssl_wolfcrypt::coding::*;
+/// let mut out = [0u8; 16];
+/// let out_size = base64_encode_no_newlines(b"wolfSSL", &mut out)
+///     .expect("Error with base64_encode_no_newlines()");
+/// assert_eq!(&out[..out_size], b"d29sZlNTTA==");
+/// }
+/// ```
+#[cfg(base64_encode)]
+pub fn base64_encode_no_newlines(input: &[u8], dout: &mut [u8]) -> Result<usize, Error> {
+    let input_size = crate::buffer_len_to_u32(input.len())?;
+    let mut dout_size = crate::buffer_len_to_u32(dout.len())?;
+    let rc = unsafe {
+        sys::Base64_Encode_NoNl(input.as_ptr(), input_size, dout.as_mut_ptr(),
+            &mut dout_size)
+    };
+    if rc != 0 {
+        return Err(Error::from(rc));
+    }
+    Ok(dout_size as usize)
+}
+
+/// Decode Base64 data in constant time.
+///
+/// Newlines (LF or CRLF) and spaces between characters are skipped.
+///
+/// # Parameters
+///
+/// * `input`: Base64 data to decode.
+/// * `dout`: Output buffer. `base64_decoded_max_size(input.len())` bytes is
+///   always sufficient.
+///
+/// # Returns
+///
+/// Returns either Ok(size) containing the number of bytes written to `dout`
+/// or Err(e) containing the wolfSSL library error code value.
+///
+/// # Example
+///
+/// ```rust
+/// use wolfssl_wolfcrypt::coding::*;
+/// let mut out = [0u8; 16];
+/// let out_size = base64_decode(b"d29sZlNTTA==", &mut out).expect("Error with base64_decode()");
+/// assert_eq!(&out[..out_size], b"wolfSSL");
+/// ```
+pub fn base64_decode(input: &[u8], dout: &mut [u8]) -> Result<usize, Error> {
+    let input_size = crate::buffer_len_to_u32(input.len())?;
+    let mut dout_size = crate::buffer_len_to_u32(dout.len())?;
+    let rc = unsafe {
+        sys::Base64_Decode(input.as_ptr(), input_size, dout.as_mut_ptr(), &mut dout_size)
+    };
+    if rc != 0 {
+        return Err(Error::from(rc));
+    }
+    Ok(dout_size as usize)
+}
+
+/// Encode data as upper case Base16 (hex).
+///
+/// # Parameters
+///
+/// * `input`: Data to encode.
+/// * `dout`: Output buffer. Must be at least twice the size of `input`.
+///
+/// # Returns
+///
+/// Returns either Ok(size) containing the number of bytes written to `dout`
+/// or Err(e) containing the wolfSSL library error code value.
+///
+/// # Example
+///
+/// ```rust
+/// #[cfg(base16)]
+/// {
+/// use wolfssl_wolfcrypt::coding::*;
+/// let mut out = [0u8; 8];
+/// let out_size = base16_encode(&[0x01, 0xab, 0xff], &mut out).expect("Error with base16_encode()");
+/// assert_eq!(&out[..out_size], b"01ABFF");
+/// }
+/// ```
+#[cfg(base16)]
+pub fn base16_encode(input: &[u8], dout: &mut [u8]) -> Result<usize, Error> {
+    let input_size = crate::buffer_len_to_u32(input.len())?;
+    /* Base16_Encode() appends a NUL terminator when there is room for one,
+     * so only offer it the space needed for the encoding. */
+    let needed = input.len().checked_mul(2).ok_or(sys::wolfCrypt_ErrorCodes_BUFFER_E)?;
+    if dout.len() < needed {
+        return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+    }
+    let mut dout_size = crate::buffer_len_to_u32(needed)?;
+    let rc = unsafe {
+        sys::Base16_Encode(input.as_ptr(), input_size, dout.as_mut_ptr(), &mut dout_size)
+    };
+    if rc != 0 {
+        return Err(Error::from(rc));
+    }
+    Ok(dout_size as usize)
+}
+
+/// Decode Base16 (hex) data. Upper and lower case digits are accepted.
+///
+/// # Parameters
+///
+/// * `input`: Hex data to decode. Must have an even length.
+/// * `dout`: Output buffer. Must be at least half the size of `input`.
+///
+/// # Returns
+///
+/// Returns either Ok(size) containing the number of bytes written to `dout`
+/// or Err(e) containing the wolfSSL library error code value.
+///
+/// # Example
+///
+/// ```rust
+/// #[cfg(base16)]
+/// {
+/// use wolfssl_wolfcrypt::coding::*;
+/// let mut out = [0u8; 3];
+/// let out_size = base16_decode(b"01abFF", &mut out).expect("Error with base16_decode()");
+/// assert_eq!(&out[..out_size], &[0x01, 0xab, 0xff]);
+/// }
+/// ```
+#[cfg(base16)]
+pub fn base16_decode(input: &[u8], dout: &mut [u8]) -> Result<usize, Error> {
+    let input_size = crate::buffer_len_to_u32(input.len())?;
+    let mut dout_size = crate::buffer_len_to_u32(dout.len())?;
+    let rc = unsafe {
+        sys::Base16_Decode(input.as_ptr(), input_size, dout.as_mut_ptr(), &mut dout_size)
+    };
+    if rc != 0 {
+        return Err(Error::from(rc));
+    }
+    Ok(dout_size as usize)
+}
+
+/// Compute the size of the PEM encoding of DER data.
+///
+/// # Parameters
+///
+/// * `label`: PEM label, such as "CERTIFICATE".
+/// * `der_size`: Size of the DER data.
+///
+/// # Returns
+///
+/// Returns the size of the output of `pem_encode()`.
+pub const fn pem_encoded_size(label: &str, der_size: usize) -> usize {
+    /* "-----BEGIN " label "-----\n" and "-----END " label "-----\n" */
+    PEM_BEGIN.len() + PEM_END.len() + 2 * (label.len() + PEM_DASHES.len() + 1)
+        + base64_encoded_size(der_size, true)
+}
+
+/// Check that a PEM label is non-empty printable ASCII without leading or
+/// trailing spaces or hyphens (RFC 7468).
+fn check_label(label: &[u8]) -> Result<(), Error> {
+    let valid = match (label.first(), label.last()) {
+        (Some(first), Some(last)) => {
+            !matches!(*first, b' ' | b'-') && !matches!(*last, b' ' | b'-')
+                && label.iter().all(|b| (0x20..0x7f).contains(b))
+        }
+        _ => false,
+    };
+    if !valid {
+        return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+    }
+    Ok(())
+}
+
+/// Armor DER data as a PEM block with the given label.
+///
+/// The Base64 body is wrapped every `BASE64_LINE_SIZE` characters, matching
+/// the output of `wc_DerToPem()`.
+///
+/// # Parameters
+///
+/// * `label`: PEM label, such as "CERTIFICATE" or "PRIVATE KEY".
+/// * `der`: DER data to encode.
+/// * `dout`: Output buffer. Must be at least `pem_encoded_size(label,
+///   der.len())` bytes.
+///
+/// # Returns
+///
+/// Returns either Ok(size) containing the number of bytes written to `dout`
+/// or Err(e) containing the wolfSSL library error code value.
+///
+/// # Example
+///
+/// ```rust
+/// #[cfg(base64_encode)]
+/// {
+/// use wolfssl_wolfcrypt::coding::*;
+/// let mut pem = [0u8; 64];
+/// let pem_size = pem_encode("EXAMPLE", &[0x05, 0x00], &mut pem).expect("Error with pem_encode()");
+/// assert_eq!(&pem[..pem_size], b"-----BEGIN EXAMPLE-----\nBQA=\n-----END EXAMPLE-----\n");
+/// }
+/// ```
+#[cfg(base64_encode)]
+pub fn pem_encode(label: &str, der: &[u8], dout: &mut [u8]) -> Result<usize, Error> {
+    check_label(label.as_bytes())?;
+    if dout.len() < pem_encoded_size(label, der.len()) {
+        return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+    }
+    let mut idx = 0;
+    for part in [PEM_BEGIN, label.as_bytes(), PEM_DASHES, b"\n"] {
+        dout[idx..idx + part.len()].copy_from_slice(part);
+        idx += part.len();
+    }
+    if !der.is_empty() {
+        idx += base64_encode(der, &mut dout[idx..])?;
+    }
+    for part in [PEM_END, label.as_bytes(), PEM_DASHES, b"\n"] {
+        dout[idx..idx + part.len()].copy_from_slice(part);
+        idx += part.len();
+    }
+    Ok(idx)
+}
+
+/// A PEM block located within a buffer.
+struct PemBlock<'a> {
+    label: &'a str,
+    /// Base64 body, with any encapsulated headers removed.
+    body: &'a [u8],
+    encrypted: bool,
+    /// Offset just past the END line.
+    end: usize,
+}
+
+fn find(haystack: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+    haystack.get(from..)?.windows(needle.len())
+        .position(|w| w == needle).map(|pos| from + pos)
+}
+
+fn trim_start(data: &[u8]) -> &[u8] {
+    let start = data.iter().position(|b| !b.is_ascii_whitespace()).unwrap_or(data.len());
+    &data[start..]
+}
+
+/// Locate the next PEM block starting at offset `from`.
+///
+/// Returns Ok(None) if there are no further blocks.
+fn next_block(pem: &[u8], from: usize) -> Result<Option<PemBlock<'_>>, Error> {
+    let begin = match find(pem, PEM_BEGIN, from) {
+        Some(begin) => begin,
+        None => return Ok(None),
+    };
+    let label_start = begin + PEM_BEGIN.len();
+    let label_end = find(pem, PEM_DASHES, label_start)
+        .ok_or(sys::wolfCrypt_ErrorCodes_ASN_PARSE_E)?;
+    let label = &pem[label_start..label_end];
+    let label = core::str::from_utf8(label).map_err(|_| sys::wolfCrypt_ErrorCodes_ASN_PARSE_E)?;
+    check_label(label.as_bytes()).map_err(|_| sys::wolfCrypt_ErrorCodes_ASN_PARSE_E)?;
+
+    let body_start = label_end + PEM_DASHES.len();
+    let end = find(pem, PEM_END, body_start).ok_or(sys::wolfCrypt_ErrorCodes_ASN_PARSE_E)?;
+    let footer = &pem[end + PEM_END.len()..];
+    if !footer.starts_with(label.as_bytes())
+            || !footer[label.len()..].starts_with(PEM_DASHES) {
+        return Err(Error::from(sys::wolfCrypt_ErrorCodes_ASN_PARSE_E));
+    }
+
+    /* Skip RFC 1421 encapsulated headers such as "Proc-Type" and
+     * "DEK-Info", which end with an empty line. */
+    let mut body = trim_start(&pem[body_start..end]);
+    let mut encrypted = false;
+    let first_line_end = body.iter().position(|b| *b == b'\n').unwrap_or(body.len());
+    if body[..first_line_end].contains(&b':') {
+        let mut headers_end = None;
+        let mut idx = 0;
+        while idx < body.len() {
+            let line_end = find(body, b"\n", idx).unwrap_or(body.len());
+            let line = &body[idx..line_end];
+            let line = line.strip_suffix(b"\r").unwrap_or(line);
+            if line.is_empty() {
+                headers_end = Some(line_end);
+                break;
+            }
+            if line.starts_with(b"Proc-Type:") && find(line, b"ENCRYPTED", 0).is_some() {
+                encrypted = true;
+            }
+            idx = line_end + 1;
+        }
+        let headers_end = headers_end.ok_or(sys::wolfCrypt_ErrorCodes_ASN_PARSE_E)?;
+        body = &body[headers_end..];
+    }
+
+    Ok(Some(PemBlock {
+        label,
+        body,
+        encrypted,
+        end: end + PEM_END.len() + label.len() + PEM_DASHES.len(),
+    }))
+}
+
+/// Get the label of the first PEM block in a buffer.
+///
+/// # Parameters
+///
+/// * `pem`: Buffer containing one or more PEM blocks.
+///
+/// # Returns
+///
+/// Returns either Ok(label) containing the label of the first PEM block, or
+/// Err(e) containing the wolfSSL library error code value.
+/// `ASN_NO_PEM_HEADER` is returned if no PEM block is found.
+///
+/// # Example
+///
+/// ```rust
+/// use wolfssl_wolfcrypt::coding::*;
+/// let pem = b"-----BEGIN EXAMPLE-----\nBQA=\n-----END EXAMPLE-----\n";
+/// assert_eq!(pem_label(pem), Ok("EXAMPLE"));
+/// ```
+pub fn pem_label(pem: &[u8]) -> Result<&str, Error> {
+    match next_block(pem, 0)? {
+        Some(block) => Ok(block.label),
+        None => Err(Error::from(sys::wolfCrypt_ErrorCodes_ASN_NO_PEM_HEADER)),
+    }
+}
+
+/// Check whether the first PEM block in a buffer is encrypted with legacy
+/// OpenSSL PEM encryption (`Proc-Type: 4,ENCRYPTED`).
+///
+/// # Parameters
+///
+/// * `pem`: Buffer containing one or more PEM blocks.
+///
+/// # Returns
+///
+/// Returns either Ok(encrypted) or Err(e) containing the wolfSSL library
+/// error code value. `ASN_NO_PEM_HEADER` is returned if no PEM block is
+/// found.
+///
+/// # Example
+///
+/// ```rust
+/// use wolfssl_wolfcrypt::coding::*;
+/// let pem = b"-----BEGIN EXAMPLE-----\nBQA=\n-----END EXAMPLE-----\n";
+/// assert_eq!(pem_is_encrypted(pem), Ok(false));
+/// ```
+pub fn pem_is_encrypted(pem: &[u8]) -> Result<bool, Error> {
+    match next_block(pem, 0)? {
+        Some(block) => Ok(block.encrypted),
+        None => Err(Error::from(sys::wolfCrypt_ErrorCodes_ASN_NO_PEM_HEADER)),
+    }
+}
+
+/// Unarmor the first PEM block with the given label.
+///
+/// Blocks with other labels are skipped, so the certificate in a buffer
+/// holding a private key followed by a certificate can be decoded with the
+/// label "CERTIFICATE".
+///
+/// # Parameters
+///
+/// * `pem`: Buffer containing one or more PEM blocks.
+/// * `label`: PEM label of the block to decode, such as "CERTIFICATE".
+/// * `dout`: Output buffer in which to store the DER data.
+///
+/// # Returns
+///
+/// Returns either Ok(size) containing the number of bytes written to `dout`
+/// or Err(e) containing the wolfSSL library error code value.
+/// `ASN_NO_PEM_HEADER` is returned if no block with the label is found and
+/// `NO_PASSWORD` is returned if the block is encrypted, in which case
+/// `pem_decode_encrypted()` can be used.
+///
+/// # Example
+///
+/// ```rust
+/// use wolfssl_wolfcrypt::coding::*;
+/// let pem = b"-----BEGIN EXAMPLE-----\nBQA=\n-----END EXAMPLE-----\n";
+/// let mut der = [0u8; 8];
+/// let der_size = pem_decode(pem, "EXAMPLE", &mut der).expect("Error with pem_decode()");
+/// assert_eq!(&der[..der_size], &[0x05, 0x00]);
+/// ```
+pub fn pem_decode(pem: &[u8], label: &str, dout: &mut [u8]) -> Result<usize, Error> {
+    let mut from = 0;
+    while let Some(block) = next_block(pem, from)? {
+        if block.label == label {
+            if block.encrypted {
+                return Err(Error::from(sys::wolfCrypt_ErrorCodes_NO_PASSWORD));
+            }
+            return base64_decode(block.body, dout);
+        }
+        from = block.end;
+    }
+    Err(Error::from(sys::wolfCrypt_ErrorCodes_ASN_NO_PEM_HEADER))
+}
+
+/// Unarmor and decrypt a private key PEM block protected with legacy
+/// OpenSSL PEM encryption (`Proc-Type: 4,ENCRYPTED`).
+///
+/// Unencrypted private key PEM blocks are also accepted, in which case the
+/// password is ignored. The key is returned in the DER format it was
+/// encoded in, for example PKCS #1 for "RSA PRIVATE KEY".
+///
+/// Legacy PEM encryption has no integrity check, so a wrong password is only
+/// detected when the decrypted data has invalid padding. Otherwise garbage
+/// is returned, and the caller should check the key decodes.
+///
+/// # Parameters
+///
+/// * `pem`: Buffer containing the PEM encoded private key.
+/// * `password`: Password used to encrypt the key.
+/// * `dout`: Output buffer in which to store the DER encoded key.
+///
+/// # Returns
+///
+/// Returns either Ok(size) containing the number of bytes written to `dout`
+/// or Err(e) containing the wolfSSL library error code value.
+///
+/// # Example
+///
+/// ```rust
+/// #[cfg(all(feature = "alloc", key_pem_to_der))]
+/// {
+/// use wolfssl_wolfcrypt::coding::*;
+/// let pem = std::fs::read("../../../certs/ecc-key.pem").expect("Error reading key");
+/// let der = std::fs::read("../../../certs/ecc-key.der").expect("Error reading key");
+/// let mut out = [0u8; 256];
+/// let out_size = pem_decode_encrypted(&pem, "unused", &mut out)
+///     .expect("Error with pem_decode_encrypted()");
+/// assert_eq!(&out[..out_size], &der[..]);
+/// }
+/// ```
+#[cfg(all(feature = "alloc", key_pem_to_der))]
+pub fn pem_decode_encrypted(pem: &[u8], password: &str, dout: &mut [u8]) -> Result<usize, Error> {
+    use alloc::vec::Vec;
+    use zeroize::Zeroize;
+    if password.as_bytes().contains(&0) {
+        return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+    }
+    let pem_size = crate::buffer_len_to_i32(pem.len())?;
+    let dout_size = crate::buffer_len_to_i32(dout.len())?;
+    let mut c_password: Vec<u8> = Vec::with_capacity(password.len() + 1);
+    c_password.extend_from_slice(password.as_bytes());
+    c_password.push(0);
+    let rc = unsafe {
+        sys::wc_KeyPemToDer(pem.as_ptr(), pem_size, dout.as_mut_ptr(), dout_size,
+            c_password.as_ptr() as *const core::ffi::c_char)
+    };
+    c_password.as_mut_slice().zeroize();
+    if rc < 0 {
+        return Err(Error::from(rc));
+    }
+    Ok(rc as usize)
+}
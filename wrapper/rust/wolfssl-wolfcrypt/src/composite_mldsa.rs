@@ -0,0 +1,877 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+This module provides composite ML-DSA signatures, pairing an ML-DSA key from
+[`crate::mldsa`] with a traditional ECDSA ([`crate::ecc`]) or Ed25519
+([`crate::ed25519`]) key as specified by the IETF LAMPS composite signatures
+draft (draft-ietf-lamps-pq-composite-sigs). A composite signature is only
+valid if both component signatures verify.
+
+The primary component is the [`CompositeMlDsa`] struct. The following
+composite algorithms are supported, depending on the wolfSSL build options:
+
+| Constant                                        | ML-DSA    | Traditional            | Pre-hash |
+|-------------------------------------------------|-----------|------------------------|----------|
+| [`CompositeMlDsa::MLDSA44_ED25519_SHA512`]      | ML-DSA-44 | Ed25519                | SHA-512  |
+| [`CompositeMlDsa::MLDSA44_ECDSA_P256_SHA256`]   | ML-DSA-44 | ECDSA P-256 / SHA-256  | SHA-256  |
+| [`CompositeMlDsa::MLDSA65_ECDSA_P256_SHA512`]   | ML-DSA-65 | ECDSA P-256 / SHA-256  | SHA-512  |
+| [`CompositeMlDsa::MLDSA65_ECDSA_P384_SHA512`]   | ML-DSA-65 | ECDSA P-384 / SHA-384  | SHA-512  |
+| [`CompositeMlDsa::MLDSA65_ED25519_SHA512`]      | ML-DSA-65 | Ed25519                | SHA-512  |
+| [`CompositeMlDsa::MLDSA87_ECDSA_P384_SHA512`]   | ML-DSA-87 | ECDSA P-384 / SHA-384  | SHA-512  |
+
+Both components sign the same message representative
+
+```text
+M' = Prefix || Label || len(ctx) || ctx || PH(M)
+```
+
+where `Prefix` is [`CompositeMlDsa::PREFIX`], `Label` is the algorithm's
+domain separation label (see [`CompositeMlDsa::label()`]) and `ctx` is an
+optional application context of at most 255 bytes. The ML-DSA component
+signs `M'` with `Label` as its context string.
+
+The encodings are:
+
+* Public key: the raw ML-DSA public key followed by the Ed25519 public key or
+  the uncompressed ECDSA point.
+* Private key: the 32 byte ML-DSA seed followed by the Ed25519 private key or
+  the DER `ECPrivateKey` (RFC 5915).
+* Signature: the ML-DSA signature followed by the Ed25519 signature or the
+  DER ECDSA signature.
+
+wolfCrypt does not retain the ML-DSA key generation seed, so private keys are
+always generated from (and stored as) a seed.
+
+# Examples
+
+```rust
+#[cfg(all(mldsa, mldsa_make_key_from_seed, mldsa_sign, mldsa_verify,
+    mldsa_import, mldsa_export, mldsa_level2, ed25519, ed25519_sign,
+    ed25519_verify, ed25519_import, ed25519_export, sha256, sha512, random))]
+{
+use wolfssl_wolfcrypt::composite_mldsa::CompositeMlDsa;
+use wolfssl_wolfcrypt::random::RNG;
+use wolfssl_wolfcrypt::WolfCrypt;
+let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+let rng = RNG::new(&wolfcrypt).expect("RNG creation failed");
+let mut key = CompositeMlDsa::generate(CompositeMlDsa::MLDSA44_ED25519_SHA512, &rng, &wolfcrypt)
+    .expect("Key generation failed");
+let message = b"Hello, composite ML-DSA!";
+let mut sig = [0u8; CompositeMlDsa::MAX_SIG_SIZE];
+let sig_len = key.sign_msg(message, &mut sig, &rng).expect("Signing failed");
+
+let mut public = [0u8; CompositeMlDsa::MAX_PUB_KEY_SIZE];
+let public_len = key.export_public(&mut public).expect("export_public failed");
+let mut verifier = CompositeMlDsa::import_public(
+    CompositeMlDsa::MLDSA44_ED25519_SHA512, &public[..public_len], &wolfcrypt)
+    .expect("import_public failed");
+assert!(verifier.verify_msg(&sig[..sig_len], message).expect("Verification failed"));
+}
+```
+*/
+
+#![cfg(all(mldsa, mldsa_make_key_from_seed, mldsa_sign, mldsa_verify,
+    mldsa_import, mldsa_export, sha256, sha512, random,
+    any(all(ecc, ecc_sign, ecc_verify, ecc_import, ecc_export, ecc_key_to_der, sha384),
+        all(ed25519, ed25519_sign, ed25519_verify, ed25519_import, ed25519_export))))]
+
+#[cfg(all(ecc, ecc_sign, ecc_verify, ecc_import, ecc_export, ecc_key_to_der, sha384))]
+use crate::ecc::ECC;
+#[cfg(all(ed25519, ed25519_sign, ed25519_verify, ed25519_import, ed25519_export))]
+use crate::ed25519::Ed25519;
+use crate::mldsa::MlDsa;
+use crate::random::RNG;
+#[cfg(all(ecc, ecc_sign, ecc_verify, ecc_import, ecc_export, ecc_key_to_der, sha384))]
+use crate::sha::SHA384;
+use crate::sha::{SHA256, SHA512};
+use crate::Error;
+use crate::sys;
+use zeroize::Zeroize;
+
+/// Traditional component algorithm.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TradAlg {
+    EcdsaP256,
+    EcdsaP384,
+    Ed25519,
+}
+
+/// Parameters of a composite algorithm.
+struct Params {
+    level: u8,
+    mldsa_pub_size: usize,
+    mldsa_sig_size: usize,
+    trad: TradAlg,
+    /// Whether `M` is pre-hashed with SHA-512 (otherwise SHA-256).
+    ph_sha512: bool,
+    label: &'static [u8],
+}
+
+/// Traditional component key.
+#[allow(clippy::large_enum_variant)]
+enum Trad {
+    #[cfg(all(ecc, ecc_sign, ecc_verify, ecc_import, ecc_export, ecc_key_to_der, sha384))]
+    Ecdsa(ECC<'static>),
+    #[cfg(all(ed25519, ed25519_sign, ed25519_verify, ed25519_import, ed25519_export))]
+    Ed25519(Ed25519<'static>),
+}
+
+/// Composite ML-DSA signature key: an ML-DSA key paired with an ECDSA or
+/// Ed25519 key.
+///
+/// An instance is created with [`CompositeMlDsa::generate()`],
+/// [`CompositeMlDsa::import_private()`] or
+/// [`CompositeMlDsa::import_public()`].
+pub struct CompositeMlDsa {
+    alg: u32,
+    mldsa: MlDsa<'static>,
+    /// ML-DSA key generation seed, present for private keys.
+    seed: Option<[u8; CompositeMlDsa::SEED_SIZE]>,
+    trad: Trad,
+    wolfcrypt: crate::WolfCrypt,
+}
+
+impl CompositeMlDsa {
+    /// id-MLDSA44-Ed25519-SHA512.
+    pub const MLDSA44_ED25519_SHA512: u32 = 0;
+    /// id-MLDSA44-ECDSA-P256-SHA256.
+    pub const MLDSA44_ECDSA_P256_SHA256: u32 = 1;
+    /// id-MLDSA65-ECDSA-P256-SHA512.
+    pub const MLDSA65_ECDSA_P256_SHA512: u32 = 2;
+    /// id-MLDSA65-ECDSA-P384-SHA512.
+    pub const MLDSA65_ECDSA_P384_SHA512: u32 = 3;
+    /// id-MLDSA65-Ed25519-SHA512.
+    pub const MLDSA65_ED25519_SHA512: u32 = 4;
+    /// id-MLDSA87-ECDSA-P384-SHA512.
+    pub const MLDSA87_ECDSA_P384_SHA512: u32 = 5;
+
+    /// Message representative prefix ("CompositeAlgorithmSignatures2025").
+    pub const PREFIX: &'static [u8] = b"CompositeAlgorithmSignatures2025";
+    /// ML-DSA seed size in bytes.
+    pub const SEED_SIZE: usize = 32;
+    /// Maximum context string size in bytes.
+    pub const MAX_CTX_SIZE: usize = 255;
+    /// Maximum composite public key size in bytes (ML-DSA-87 with P-384).
+    pub const MAX_PUB_KEY_SIZE: usize = 2592 + 97;
+    /// Maximum composite private key size in bytes.
+    pub const MAX_PRV_KEY_SIZE: usize = Self::SEED_SIZE + MAX_EC_PRIVATE_KEY_DER_SIZE;
+    /// Maximum composite signature size in bytes (ML-DSA-87 with P-384).
+    pub const MAX_SIG_SIZE: usize = 4627 + 104;
+
+    fn params(alg: u32) -> Result<Params, Error> {
+        let p = match alg {
+            Self::MLDSA44_ED25519_SHA512 => Params {
+                level: MlDsa::LEVEL_44, mldsa_pub_size: 1312, mldsa_sig_size: 2420,
+                trad: TradAlg::Ed25519, ph_sha512: true,
+                label: b"COMPSIG-MLDSA44-Ed25519-SHA512",
+            },
+            Self::MLDSA44_ECDSA_P256_SHA256 => Params {
+                level: MlDsa::LEVEL_44, mldsa_pub_size: 1312, mldsa_sig_size: 2420,
+                trad: TradAlg::EcdsaP256, ph_sha512: false,
+                label: b"COMPSIG-MLDSA44-ECDSA-P256-SHA256",
+            },
+            Self::MLDSA65_ECDSA_P256_SHA512 => Params {
+                level: MlDsa::LEVEL_65, mldsa_pub_size: 1952, mldsa_sig_size: 3309,
+                trad: TradAlg::EcdsaP256, ph_sha512: true,
+                label: b"COMPSIG-MLDSA65-ECDSA-P256-SHA512",
+            },
+            Self::MLDSA65_ECDSA_P384_SHA512 => Params {
+                level: MlDsa::LEVEL_65, mldsa_pub_size: 1952, mldsa_sig_size: 3309,
+                trad: TradAlg::EcdsaP384, ph_sha512: true,
+                label: b"COMPSIG-MLDSA65-ECDSA-P384-SHA512",
+            },
+            Self::MLDSA65_ED25519_SHA512 => Params {
+                level: MlDsa::LEVEL_65, mldsa_pub_size: 1952, mldsa_sig_size: 3309,
+                trad: TradAlg::Ed25519, ph_sha512: true,
+                label: b"COMPSIG-MLDSA65-Ed25519-SHA512",
+            },
+            Self::MLDSA87_ECDSA_P384_SHA512 => Params {
+                level: MlDsa::LEVEL_87, mldsa_pub_size: 2592, mldsa_sig_size: 4627,
+                trad: TradAlg::EcdsaP384, ph_sha512: true,
+                label: b"COMPSIG-MLDSA87-ECDSA-P384-SHA512",
+            },
+            _ => return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG)),
+        };
+        Ok(p)
+    }
+
+    /// Generate a new composite key pair.
+    ///
+    /// # Parameters
+    ///
+    /// * `alg`: Composite algorithm, e.g.
+    ///   [`CompositeMlDsa::MLDSA65_ECDSA_P384_SHA512`].
+    /// * `rng`: `RNG` instance to use for random number generation.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(CompositeMlDsa) containing the key instance or
+    /// Err(e) containing the wolfSSL library error code value.
+    /// `NOT_COMPILED_IN` is returned if the traditional algorithm is not
+    /// available.
+    pub fn generate(alg: u32, rng: &RNG, wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        let p = Self::params(alg)?;
+        let mut seed = [0u8; Self::SEED_SIZE];
+        rng.generate_block(&mut seed)?;
+        let mldsa = MlDsa::generate_from_seed(p.level, &seed, wolfcrypt);
+        let trad = mldsa.and_then(|mldsa| Ok((mldsa, Trad::generate(p.trad, rng, wolfcrypt)?)));
+        match trad {
+            Ok((mldsa, trad)) => Ok(CompositeMlDsa {
+                alg, mldsa, seed: Some(seed), trad, wolfcrypt: wolfcrypt.clone(),
+            }),
+            Err(e) => {
+                seed.zeroize();
+                Err(e)
+            }
+        }
+    }
+
+    /// Import a composite private key.
+    ///
+    /// # Parameters
+    ///
+    /// * `alg`: Composite algorithm.
+    /// * `private`: Private key: the 32 byte ML-DSA seed followed by the
+    ///   Ed25519 private key or DER `ECPrivateKey`.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(CompositeMlDsa) containing the key instance or
+    /// Err(e) containing the wolfSSL library error code value.
+    pub fn import_private(alg: u32, private: &[u8], wolfcrypt: &crate::WolfCrypt)
+            -> Result<Self, Error> {
+        let p = Self::params(alg)?;
+        if private.len() < Self::SEED_SIZE {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        let (seed, trad_private) = private.split_at(Self::SEED_SIZE);
+        let seed: [u8; Self::SEED_SIZE] = seed.try_into().unwrap();
+        let mldsa = MlDsa::generate_from_seed(p.level, &seed, wolfcrypt)?;
+        let trad = Trad::import_private(p.trad, trad_private, wolfcrypt)?;
+        Ok(CompositeMlDsa { alg, mldsa, seed: Some(seed), trad, wolfcrypt: wolfcrypt.clone() })
+    }
+
+    /// Import a composite public key.
+    ///
+    /// # Parameters
+    ///
+    /// * `alg`: Composite algorithm.
+    /// * `public`: Public key: the ML-DSA public key followed by the Ed25519
+    ///   public key or uncompressed ECDSA point.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(CompositeMlDsa) containing the key instance or
+    /// Err(e) containing the wolfSSL library error code value.
+    pub fn import_public(alg: u32, public: &[u8], wolfcrypt: &crate::WolfCrypt)
+            -> Result<Self, Error> {
+        let p = Self::params(alg)?;
+        if public.len() != p.mldsa_pub_size + trad_pub_size(p.trad) {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        let (mldsa_public, trad_public) = public.split_at(p.mldsa_pub_size);
+        let mut mldsa = MlDsa::new(wolfcrypt)?;
+        mldsa.set_level(p.level)?;
+        mldsa.import_public(mldsa_public)?;
+        let trad = Trad::import_public(p.trad, trad_public, wolfcrypt)?;
+        Ok(CompositeMlDsa { alg, mldsa, seed: None, trad, wolfcrypt: wolfcrypt.clone() })
+    }
+
+    /// Get the composite algorithm of this key.
+    pub fn alg(&self) -> u32 {
+        self.alg
+    }
+
+    /// Get the domain separation label of this key's composite algorithm,
+    /// e.g. `COMPSIG-MLDSA65-ECDSA-P384-SHA512`.
+    pub fn label(&self) -> &'static [u8] {
+        Self::params(self.alg).unwrap().label
+    }
+
+    /// Get the composite public key size in bytes.
+    pub fn pub_size(&self) -> usize {
+        let p = Self::params(self.alg).unwrap();
+        p.mldsa_pub_size + trad_pub_size(p.trad)
+    }
+
+    /// Get the maximum composite signature size in bytes.
+    ///
+    /// ECDSA signatures are DER encoded, so ECDSA composite signatures may be
+    /// shorter.
+    pub fn sig_size(&self) -> usize {
+        let p = Self::params(self.alg).unwrap();
+        p.mldsa_sig_size + trad_max_sig_size(p.trad)
+    }
+
+    /// Export the composite public key.
+    ///
+    /// # Parameters
+    ///
+    /// * `public`: Output buffer. Must be at least `pub_size()` bytes.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to
+    /// `public` or Err(e) containing the wolfSSL library error code value.
+    pub fn export_public(&mut self, public: &mut [u8]) -> Result<usize, Error> {
+        if public.len() < self.pub_size() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        let mldsa_len = self.mldsa.export_public(public)?;
+        let trad_len = self.trad.export_public(&mut public[mldsa_len..])?;
+        Ok(mldsa_len + trad_len)
+    }
+
+    /// Export the composite private key.
+    ///
+    /// # Parameters
+    ///
+    /// * `private`: Output buffer. `MAX_PRV_KEY_SIZE` bytes is always
+    ///   sufficient.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to
+    /// `private` or Err(e) containing the wolfSSL library error code value.
+    /// `MISSING_KEY` is returned for a public key.
+    pub fn export_private(&mut self, private: &mut [u8]) -> Result<usize, Error> {
+        let seed = self.seed.as_ref().ok_or(sys::wolfCrypt_ErrorCodes_MISSING_KEY)?;
+        if private.len() < Self::SEED_SIZE {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        private[..Self::SEED_SIZE].copy_from_slice(seed);
+        let trad_len = self.trad.export_private(&mut private[Self::SEED_SIZE..]);
+        match trad_len {
+            Ok(trad_len) => Ok(Self::SEED_SIZE + trad_len),
+            Err(e) => {
+                private[..Self::SEED_SIZE].zeroize();
+                Err(e)
+            }
+        }
+    }
+
+    /// Build the message representative `M'` into `buf`, returning its
+    /// length.
+    fn message_representative(&self, p: &Params, ctx: &[u8], msg: &[u8],
+            buf: &mut [u8; M_PRIME_MAX_SIZE]) -> Result<usize, Error> {
+        if ctx.len() > Self::MAX_CTX_SIZE {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        let mut len = 0;
+        for part in [Self::PREFIX, p.label, &[ctx.len() as u8], ctx] {
+            buf[len..len + part.len()].copy_from_slice(part);
+            len += part.len();
+        }
+        if p.ph_sha512 {
+            let mut sha = SHA512::new(&self.wolfcrypt)?;
+            sha.update(msg)?;
+            sha.finalize(&mut buf[len..len + SHA512::DIGEST_SIZE])?;
+            len += SHA512::DIGEST_SIZE;
+        } else {
+            let mut sha = SHA256::new(&self.wolfcrypt)?;
+            sha.update(msg)?;
+            sha.finalize(&mut buf[len..len + SHA256::DIGEST_SIZE])?;
+            len += SHA256::DIGEST_SIZE;
+        }
+        Ok(len)
+    }
+
+    /// Sign a message with an empty context string.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg`: Message to sign.
+    /// * `sig`: Output buffer for the signature. Must be at least
+    ///   `sig_size()` bytes.
+    /// * `rng`: `RNG` instance for the ML-DSA and ECDSA signing randomness.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to
+    /// `sig` or Err(e) containing the wolfSSL library error code value.
+    /// `MISSING_KEY` is returned for a public key.
+    pub fn sign_msg(&mut self, msg: &[u8], sig: &mut [u8], rng: &RNG) -> Result<usize, Error> {
+        self.sign_ctx_msg(&[], msg, sig, rng)
+    }
+
+    /// Sign a message with a context string.
+    ///
+    /// # Parameters
+    ///
+    /// * `ctx`: Application context string (at most 255 bytes).
+    /// * `msg`: Message to sign.
+    /// * `sig`: Output buffer for the signature. Must be at least
+    ///   `sig_size()` bytes.
+    /// * `rng`: `RNG` instance for the ML-DSA and ECDSA signing randomness.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to
+    /// `sig` or Err(e) containing the wolfSSL library error code value.
+    /// `MISSING_KEY` is returned for a public key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(all(mldsa_level3, ecc, ecc_sign, ecc_verify, ecc_import, ecc_export,
+    ///     ecc_key_to_der, sha384))]
+    /// {
+    /// use wolfssl_wolfcrypt::composite_mldsa::CompositeMlDsa;
+    /// use wolfssl_wolfcrypt::random::RNG;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut key = CompositeMlDsa::generate(CompositeMlDsa::MLDSA65_ECDSA_P384_SHA512, &rng, &wolfcrypt)
+    ///     .expect("Error with generate()");
+    /// let mut sig = [0u8; CompositeMlDsa::MAX_SIG_SIZE];
+    /// let sig_len = key.sign_ctx_msg(b"context", b"message", &mut sig, &rng)
+    ///     .expect("Error with sign_ctx_msg()");
+    /// let valid = key.verify_ctx_msg(&sig[..sig_len], b"context", b"message")
+    ///     .expect("Error with verify_ctx_msg()");
+    /// assert!(valid);
+    /// }
+    /// ```
+    pub fn sign_ctx_msg(&mut self, ctx: &[u8], msg: &[u8], sig: &mut [u8], rng: &RNG)
+            -> Result<usize, Error> {
+        if self.seed.is_none() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_MISSING_KEY));
+        }
+        let p = Self::params(self.alg)?;
+        if sig.len() < p.mldsa_sig_size {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        let mut m_prime = [0u8; M_PRIME_MAX_SIZE];
+        let m_prime_len = self.message_representative(&p, ctx, msg, &mut m_prime)?;
+        let m_prime = &m_prime[..m_prime_len];
+        let (mldsa_sig, trad_sig) = sig.split_at_mut(p.mldsa_sig_size);
+        let mldsa_len = self.mldsa.sign_ctx_msg(p.label, m_prime, mldsa_sig, rng)?;
+        if mldsa_len != p.mldsa_sig_size {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_STATE_E));
+        }
+        let trad_len = self.trad.sign(m_prime, trad_sig, rng, &self.wolfcrypt)?;
+        Ok(mldsa_len + trad_len)
+    }
+
+    /// Verify a signature over a message with an empty context string.
+    ///
+    /// # Parameters
+    ///
+    /// * `sig`: Composite signature to verify.
+    /// * `msg`: Message the signature was created over.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(true) if both component signatures are valid,
+    /// Ok(false) if either is invalid, or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn verify_msg(&mut self, sig: &[u8], msg: &[u8]) -> Result<bool, Error> {
+        self.verify_ctx_msg(sig, &[], msg)
+    }
+
+    /// Verify a signature over a message with a context string.
+    ///
+    /// # Parameters
+    ///
+    /// * `sig`: Composite signature to verify.
+    /// * `ctx`: Application context string used when signing.
+    /// * `msg`: Message the signature was created over.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(true) if both component signatures are valid,
+    /// Ok(false) if either is invalid, or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn verify_ctx_msg(&mut self, sig: &[u8], ctx: &[u8], msg: &[u8]) -> Result<bool, Error> {
+        let p = Self::params(self.alg)?;
+        if sig.len() <= p.mldsa_sig_size ||
+                sig.len() > p.mldsa_sig_size + trad_max_sig_size(p.trad) {
+            return Ok(false);
+        }
+        let mut m_prime = [0u8; M_PRIME_MAX_SIZE];
+        let m_prime_len = self.message_representative(&p, ctx, msg, &mut m_prime)?;
+        let m_prime = &m_prime[..m_prime_len];
+        let (mldsa_sig, trad_sig) = sig.split_at(p.mldsa_sig_size);
+        let mldsa_valid = match self.mldsa.verify_ctx_msg(mldsa_sig, p.label, m_prime) {
+            Err(e) if e == sys::wolfCrypt_ErrorCodes_SIG_VERIFY_E => false,
+            res => res?,
+        };
+        let trad_valid = self.trad.verify(trad_sig, m_prime, &self.wolfcrypt)?;
+        Ok(mldsa_valid && trad_valid)
+    }
+}
+
+impl Drop for CompositeMlDsa {
+    fn drop(&mut self) {
+        if let Some(seed) = self.seed.as_mut() {
+            seed.zeroize();
+        }
+    }
+}
+
+/// Maximum size of the message representative `M'`: prefix, longest label,
+/// context length byte, longest context and a SHA-512 digest.
+const M_PRIME_MAX_SIZE: usize = 32 + 33 + 1 + CompositeMlDsa::MAX_CTX_SIZE + 64;
+
+/// Maximum size of a DER `ECPrivateKey` for P-384 including the curve
+/// parameters and public key.
+const MAX_EC_PRIVATE_KEY_DER_SIZE: usize = 192;
+
+fn trad_pub_size(trad: TradAlg) -> usize {
+    match trad {
+        TradAlg::EcdsaP256 => 65,
+        TradAlg::EcdsaP384 => 97,
+        TradAlg::Ed25519 => 32,
+    }
+}
+
+fn trad_max_sig_size(trad: TradAlg) -> usize {
+    match trad {
+        TradAlg::EcdsaP256 => 72,
+        TradAlg::EcdsaP384 => 104,
+        TradAlg::Ed25519 => 64,
+    }
+}
+
+#[cfg(all(ecc, ecc_sign, ecc_verify, ecc_import, ecc_export, ecc_key_to_der, sha384))]
+fn ecc_curve(trad: TradAlg) -> (i32, i32) {
+    if trad == TradAlg::EcdsaP256 {
+        (ECC::SECP256R1, 32)
+    } else {
+        (ECC::SECP384R1, 48)
+    }
+}
+
+/// Hash `M'` for the ECDSA component: SHA-256 for P-256, SHA-384 for P-384.
+#[cfg(all(ecc, ecc_sign, ecc_verify, ecc_import, ecc_export, ecc_key_to_der, sha384))]
+fn ecdsa_digest(curve_size: i32, m_prime: &[u8], digest: &mut [u8; 48],
+        wolfcrypt: &crate::WolfCrypt) -> Result<usize, Error> {
+    if curve_size == 32 {
+        let mut sha = SHA256::new(wolfcrypt)?;
+        sha.update(m_prime)?;
+        sha.finalize(&mut digest[..SHA256::DIGEST_SIZE])?;
+        Ok(SHA256::DIGEST_SIZE)
+    } else {
+        let mut sha = SHA384::new(wolfcrypt)?;
+        sha.update(m_prime)?;
+        sha.finalize(&mut digest[..SHA384::DIGEST_SIZE])?;
+        Ok(SHA384::DIGEST_SIZE)
+    }
+}
+
+impl Trad {
+    fn generate(trad: TradAlg, rng: &RNG, wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        match trad {
+            #[cfg(all(ecc, ecc_sign, ecc_verify, ecc_import, ecc_export, ecc_key_to_der, sha384))]
+            TradAlg::EcdsaP256 | TradAlg::EcdsaP384 => {
+                let (curve_id, curve_size) = ecc_curve(trad);
+                let env = crate::Env::new(wolfcrypt);
+                Ok(Trad::Ecdsa(ECC::generate_ex(curve_size, rng, curve_id, &env)?))
+            }
+            #[cfg(all(ed25519, ed25519_sign, ed25519_verify, ed25519_import, ed25519_export))]
+            TradAlg::Ed25519 => Ok(Trad::Ed25519(Ed25519::generate(rng, wolfcrypt)?)),
+            #[allow(unreachable_patterns)]
+            _ => Err(Error::from(sys::wolfCrypt_ErrorCodes_NOT_COMPILED_IN)),
+        }
+    }
+
+    fn import_private(trad: TradAlg, private: &[u8], wolfcrypt: &crate::WolfCrypt)
+            -> Result<Self, Error> {
+        match trad {
+            #[cfg(all(ecc, ecc_sign, ecc_verify, ecc_import, ecc_export, ecc_key_to_der, sha384))]
+            TradAlg::EcdsaP256 | TradAlg::EcdsaP384 => {
+                let ecc = ECC::import_der(private, &crate::Env::new(wolfcrypt))?;
+                let (curve_id, _) = ecc_curve(trad);
+                if unsafe { sys::wc_ecc_get_curve_id((*ecc.wc_ecc_key).idx) } != curve_id {
+                    return Err(Error::from(sys::wolfCrypt_ErrorCodes_ECC_BAD_ARG_E));
+                }
+                Ok(Trad::Ecdsa(ecc))
+            }
+            #[cfg(all(ed25519, ed25519_sign, ed25519_verify, ed25519_import, ed25519_export))]
+            TradAlg::Ed25519 => {
+                if private.len() != Ed25519::KEY_SIZE {
+                    return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+                }
+                let mut ed = Ed25519::new(wolfcrypt)?;
+                ed.import_private_only(private)?;
+                let mut public = [0u8; Ed25519::PUB_KEY_SIZE];
+                ed.make_public(&mut public)?;
+                ed.import_private_key(private, Some(&public))?;
+                Ok(Trad::Ed25519(ed))
+            }
+            #[allow(unreachable_patterns)]
+            _ => Err(Error::from(sys::wolfCrypt_ErrorCodes_NOT_COMPILED_IN)),
+        }
+    }
+
+    fn import_public(trad: TradAlg, public: &[u8], wolfcrypt: &crate::WolfCrypt)
+            -> Result<Self, Error> {
+        match trad {
+            #[cfg(all(ecc, ecc_sign, ecc_verify, ecc_import, ecc_export, ecc_key_to_der, sha384))]
+            TradAlg::EcdsaP256 | TradAlg::EcdsaP384 => {
+                let (curve_id, _) = ecc_curve(trad);
+                let env = crate::Env::new(wolfcrypt);
+                Ok(Trad::Ecdsa(ECC::import_x963_ex(public, curve_id, &env)?))
+            }
+            #[cfg(all(ed25519, ed25519_sign, ed25519_verify, ed25519_import, ed25519_export))]
+            TradAlg::Ed25519 => {
+                let mut ed = Ed25519::new(wolfcrypt)?;
+                ed.import_public(public)?;
+                Ok(Trad::Ed25519(ed))
+            }
+            #[allow(unreachable_patterns)]
+            _ => Err(Error::from(sys::wolfCrypt_ErrorCodes_NOT_COMPILED_IN)),
+        }
+    }
+
+    fn export_public(&mut self, public: &mut [u8]) -> Result<usize, Error> {
+        match self {
+            #[cfg(all(ecc, ecc_sign, ecc_verify, ecc_import, ecc_export, ecc_key_to_der, sha384))]
+            Trad::Ecdsa(ecc) => ecc.export_x963(public),
+            #[cfg(all(ed25519, ed25519_sign, ed25519_verify, ed25519_import, ed25519_export))]
+            Trad::Ed25519(ed) => {
+                let public = public.get_mut(..Ed25519::PUB_KEY_SIZE)
+                    .ok_or(sys::wolfCrypt_ErrorCodes_BUFFER_E)?;
+                ed.export_public(public)?;
+                Ok(Ed25519::PUB_KEY_SIZE)
+            }
+        }
+    }
+
+    fn export_private(&mut self, private: &mut [u8]) -> Result<usize, Error> {
+        match self {
+            #[cfg(all(ecc, ecc_sign, ecc_verify, ecc_import, ecc_export, ecc_key_to_der, sha384))]
+            Trad::Ecdsa(ecc) => {
+                let private_size = crate::buffer_len_to_u32(private.len())?;
+                let rc = unsafe {
+                    sys::wc_EccKeyToDer(ecc.wc_ecc_key, private.as_mut_ptr(), private_size)
+                };
+                if rc < 0 {
+                    return Err(Error::from(rc));
+                }
+                Ok(rc as usize)
+            }
+            #[cfg(all(ed25519, ed25519_sign, ed25519_verify, ed25519_import, ed25519_export))]
+            Trad::Ed25519(ed) => {
+                let private = private.get_mut(..Ed25519::KEY_SIZE)
+                    .ok_or(sys::wolfCrypt_ErrorCodes_BUFFER_E)?;
+                ed.export_private_only(private)?;
+                Ok(Ed25519::KEY_SIZE)
+            }
+        }
+    }
+
+    #[allow(unused_variables)]
+    fn sign(&mut self, m_prime: &[u8], sig: &mut [u8], rng: &RNG, wolfcrypt: &crate::WolfCrypt)
+            -> Result<usize, Error> {
+        match self {
+            #[cfg(all(ecc, ecc_sign, ecc_verify, ecc_import, ecc_export, ecc_key_to_der, sha384))]
+            Trad::Ecdsa(ecc) => {
+                let mut digest = [0u8; 48];
+                let curve_size = unsafe { sys::wc_ecc_size(ecc.wc_ecc_key) };
+                let digest_len = ecdsa_digest(curve_size, m_prime, &mut digest, wolfcrypt)?;
+                ecc.sign_hash(&digest[..digest_len], sig, rng)
+            }
+            #[cfg(all(ed25519, ed25519_sign, ed25519_verify, ed25519_import, ed25519_export))]
+            Trad::Ed25519(ed) => ed.sign_msg(m_prime, sig),
+        }
+    }
+
+    #[allow(unused_variables)]
+    fn verify(&mut self, sig: &[u8], m_prime: &[u8], wolfcrypt: &crate::WolfCrypt)
+            -> Result<bool, Error> {
+        match self {
+            #[cfg(all(ecc, ecc_sign, ecc_verify, ecc_import, ecc_export, ecc_key_to_der, sha384))]
+            Trad::Ecdsa(ecc) => {
+                let mut digest = [0u8; 48];
+                let curve_size = unsafe { sys::wc_ecc_size(ecc.wc_ecc_key) };
+                let digest_len = ecdsa_digest(curve_size, m_prime, &mut digest, wolfcrypt)?;
+                match ecc.verify_hash(sig, &digest[..digest_len]) {
+                    Err(e) if e == sys::wolfCrypt_ErrorCodes_SIG_VERIFY_E => Ok(false),
+                    res => res,
+                }
+            }
+            #[cfg(all(ed25519, ed25519_sign, ed25519_verify, ed25519_import, ed25519_export))]
+            Trad::Ed25519(ed) => ed.verify_msg(sig, m_prime),
+        }
+    }
+}
+
+#[cfg(feature = "signature")]
+mod signature_impl {
+    use super::CompositeMlDsa;
+    use crate::random::RNG;
+    use signature::Error;
+
+    /// Composite ML-DSA signature of up to `CompositeMlDsa::MAX_SIG_SIZE`
+    /// bytes.
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct Signature {
+        bytes: [u8; CompositeMlDsa::MAX_SIG_SIZE],
+        len: usize,
+    }
+
+    impl Signature {
+        /// Return the raw signature bytes.
+        pub fn as_bytes(&self) -> &[u8] {
+            &self.bytes[..self.len]
+        }
+    }
+
+    impl AsRef<[u8]> for Signature {
+        fn as_ref(&self) -> &[u8] {
+            self.as_bytes()
+        }
+    }
+
+    impl TryFrom<&[u8]> for Signature {
+        type Error = Error;
+        fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+            if bytes.len() > CompositeMlDsa::MAX_SIG_SIZE {
+                return Err(Error::new());
+            }
+            let mut sig = [0u8; CompositeMlDsa::MAX_SIG_SIZE];
+            sig[..bytes.len()].copy_from_slice(bytes);
+            Ok(Self { bytes: sig, len: bytes.len() })
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl From<Signature> for alloc::vec::Vec<u8> {
+        fn from(sig: Signature) -> Self {
+            sig.as_bytes().to_vec()
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl signature::SignatureEncoding for Signature {
+        type Repr = alloc::vec::Vec<u8>;
+    }
+
+    /// Composite ML-DSA verifying (public) key.
+    ///
+    /// Owns the composite algorithm and a copy of the composite public key,
+    /// and instantiates a short-lived [`CompositeMlDsa`] on each
+    /// verification.
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct VerifyingKey {
+        alg: u32,
+        key: [u8; CompositeMlDsa::MAX_PUB_KEY_SIZE],
+        len: usize,
+    }
+
+    impl VerifyingKey {
+        /// Construct a verifying key from a composite algorithm and
+        /// composite public key.
+        pub fn from_bytes(alg: u32, bytes: &[u8]) -> Result<Self, Error> {
+            let wolfcrypt = crate::WolfCrypt::global().map_err(|_| Error::new())?;
+            CompositeMlDsa::import_public(alg, bytes, wolfcrypt).map_err(|_| Error::new())?;
+            let mut key = [0u8; CompositeMlDsa::MAX_PUB_KEY_SIZE];
+            key[..bytes.len()].copy_from_slice(bytes);
+            Ok(Self { alg, key, len: bytes.len() })
+        }
+
+        /// Return the composite algorithm.
+        pub fn alg(&self) -> u32 {
+            self.alg
+        }
+
+        /// Return the composite public key bytes.
+        pub fn as_bytes(&self) -> &[u8] {
+            &self.key[..self.len]
+        }
+    }
+
+    impl AsRef<[u8]> for VerifyingKey {
+        fn as_ref(&self) -> &[u8] {
+            self.as_bytes()
+        }
+    }
+
+    impl signature::Verifier<Signature> for VerifyingKey {
+        fn verify(&self, msg: &[u8], signature: &Signature) -> Result<(), Error> {
+            let wolfcrypt = crate::WolfCrypt::global().map_err(|_| Error::new())?;
+            let mut key = CompositeMlDsa::import_public(self.alg, self.as_bytes(), wolfcrypt)
+                .map_err(|_| Error::new())?;
+            let valid = key
+                .verify_msg(signature.as_bytes(), msg)
+                .map_err(|_| Error::new())?;
+            if valid { Ok(()) } else { Err(Error::new()) }
+        }
+    }
+
+    /// Composite ML-DSA signing key (private key + owned RNG + cached public
+    /// key).
+    ///
+    /// Signatures are created with an empty context string.
+    pub struct SigningKey {
+        inner: CompositeMlDsa,
+        rng: RNG<'static>,
+        verifying_key: VerifyingKey,
+    }
+
+    impl SigningKey {
+        /// Generate a fresh signing key for a composite algorithm using the
+        /// provided RNG.
+        pub fn generate(alg: u32, rng: RNG<'static>) -> Result<Self, crate::Error> {
+            let key = CompositeMlDsa::generate(alg, &rng, crate::WolfCrypt::global()?)?;
+            Self::new(key, rng)
+        }
+
+        /// Pair a composite private key with an RNG.
+        pub fn new(mut key: CompositeMlDsa, rng: RNG<'static>) -> Result<Self, crate::Error> {
+            if key.seed.is_none() {
+                return Err(crate::Error::from(crate::sys::wolfCrypt_ErrorCodes_MISSING_KEY));
+            }
+            let mut pub_bytes = [0u8; CompositeMlDsa::MAX_PUB_KEY_SIZE];
+            let len = key.export_public(&mut pub_bytes)?;
+            let verifying_key = VerifyingKey { alg: key.alg, key: pub_bytes, len };
+            Ok(Self { inner: key, rng, verifying_key })
+        }
+
+        /// Consume the signing key and return its `CompositeMlDsa` and `RNG`
+        /// parts.
+        pub fn into_parts(self) -> (CompositeMlDsa, RNG<'static>) {
+            (self.inner, self.rng)
+        }
+    }
+
+    impl signature::Keypair for SigningKey {
+        type VerifyingKey = VerifyingKey;
+        fn verifying_key(&self) -> VerifyingKey {
+            self.verifying_key.clone()
+        }
+    }
+
+    impl signature::SignerMut<Signature> for SigningKey {
+        fn try_sign(&mut self, msg: &[u8]) -> Result<Signature, Error> {
+            let mut sig = [0u8; CompositeMlDsa::MAX_SIG_SIZE];
+            let len = self.inner.sign_msg(msg, &mut sig, &self.rng)
+                .map_err(|_| Error::new())?;
+            Ok(Signature { bytes: sig, len })
+        }
+    }
+}
+
+#[cfg(feature = "signature")]
+pub use signature_impl::{Signature, SigningKey, VerifyingKey};
@@ -0,0 +1,988 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+This module provides a safe Rust interface to the wolfCrypt crypto callback
+(`WOLF_CRYPTO_CB`) framework, allowing a cryptographic device such as an HSM,
+a remote key management service or a software mock to be implemented in Rust.
+
+A device implements the [`CryptoDevice`] trait and is registered under a
+device ID with [`register_device()`], which returns a [`Registration`] owning
+the [`Device`] handle. Any wolfCrypt object created in an
+[`Env`](crate::Env) with that device (for example
+`RNG::new_ex(&Env::new(&wolfcrypt).with_device(registration.device()))` or
+`ECC::generate(32, &rng, &Env::new(&wolfcrypt).with_device(registration.device()))`)
+then routes its operations through the device, until the `Registration` is
+dropped. Objects created with the device keep its device ID reserved until
+they are dropped, so the ID cannot be registered for another device while
+they exist. The raw `wc_CryptoInfo` structure is marshalled into a
+typed request ([`HashRequest`], [`HmacRequest`], [`AesRequest`],
+[`EccRequest`], [`RsaRequest`] or [`Ed25519Request`]) before it is passed to
+the device.
+
+Every trait method has a default implementation returning
+`CRYPTOCB_UNAVAILABLE`, which tells wolfCrypt to fall back to its software
+implementation, so a device only needs to implement the operations it
+offloads.
+
+# Examples
+
+```rust
+#[cfg(random)]
+{
+use wolfssl_wolfcrypt::{Env, Error, WolfCrypt};
+use wolfssl_wolfcrypt::cryptocb::{self, CryptoDevice};
+use wolfssl_wolfcrypt::random::RNG;
+
+struct ConstantRng;
+
+impl CryptoDevice for ConstantRng {
+    fn rng(&self, out: &mut [u8]) -> Result<(), Error> {
+        out.fill(0x5a);
+        Ok(())
+    }
+}
+
+let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+let registration = cryptocb::register_device(0x1234, ConstantRng, &wolfcrypt)
+    .expect("Error with register_device()");
+let rng = RNG::new_ex(&Env::new(&wolfcrypt).with_device(registration.device())).expect("Error with RNG::new_ex()");
+let mut buf = [0u8; 16];
+rng.generate_block(&mut buf).expect("Error with generate_block()");
+assert_eq!(buf, [0x5a; 16]);
+drop(rng);
+drop(registration);
+}
+```
+*/
+
+#![cfg(all(wolf_crypto_cb, feature = "alloc"))]
+
+use crate::{Device, Error, WolfCrypt};
+use crate::sys;
+use alloc::sync::Arc;
+#[cfg(any(aes_cbc, aes_ctr, aes_ecb, aes_gcm, rsa))]
+use alloc::vec::Vec;
+use core::cell::UnsafeCell;
+use core::marker::PhantomData;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// Error returned by a device to have wolfCrypt fall back to its software
+/// implementation of an operation.
+pub const CRYPTOCB_UNAVAILABLE: Error =
+    Error::Hardware(sys::wolfCrypt_ErrorCodes_CRYPTOCB_UNAVAILABLE);
+
+/// A cryptographic device servicing wolfCrypt crypto callback requests.
+///
+/// Each method corresponds to one `wc_AlgoType` and returns either Ok(())
+/// on success or Err(e) containing the wolfSSL library error. The
+/// default implementations return `Err(CRYPTOCB_UNAVAILABLE)` so that
+/// wolfCrypt falls back to software for operations the device does not
+/// handle.
+///
+/// Methods may be called concurrently from any thread using a wolfCrypt
+/// object bound to the device, hence the `Send + Sync` bound. A panic
+/// inside a method aborts the process since it cannot unwind into C.
+pub trait CryptoDevice: Send + Sync {
+    /// Fill `out` with random bytes (`WC_ALGO_TYPE_RNG`).
+    #[cfg(random)]
+    fn rng(&self, out: &mut [u8]) -> Result<(), Error> {
+        let _ = out;
+        Err(CRYPTOCB_UNAVAILABLE)
+    }
+
+    /// Fill `out` with entropy for seeding a DRBG (`WC_ALGO_TYPE_SEED`).
+    #[cfg(random)]
+    fn seed(&self, out: &mut [u8]) -> Result<(), Error> {
+        let _ = out;
+        Err(CRYPTOCB_UNAVAILABLE)
+    }
+
+    /// Perform a hash update and/or finalization (`WC_ALGO_TYPE_HASH`).
+    #[cfg(any(sha, sha256, sha384, sha512, sha3))]
+    fn hash(&self, req: HashRequest<'_>) -> Result<(), Error> {
+        let _ = req;
+        Err(CRYPTOCB_UNAVAILABLE)
+    }
+
+    /// Perform an HMAC update and/or finalization (`WC_ALGO_TYPE_HMAC`).
+    #[cfg(hmac)]
+    fn hmac(&self, req: HmacRequest<'_>) -> Result<(), Error> {
+        let _ = req;
+        Err(CRYPTOCB_UNAVAILABLE)
+    }
+
+    /// Perform an AES cipher operation (`WC_ALGO_TYPE_CIPHER`).
+    #[cfg(any(aes_cbc, aes_ctr, aes_ecb, aes_gcm))]
+    fn aes(&self, req: AesRequest<'_>) -> Result<(), Error> {
+        let _ = req;
+        Err(CRYPTOCB_UNAVAILABLE)
+    }
+
+    /// Perform an ECC public key operation (`WC_ALGO_TYPE_PK`).
+    #[cfg(any(ecc_sign, ecc_verify, ecc_dh))]
+    fn ecc(&self, req: EccRequest<'_>) -> Result<(), Error> {
+        let _ = req;
+        Err(CRYPTOCB_UNAVAILABLE)
+    }
+
+    /// Perform a raw RSA operation (`WC_PK_TYPE_RSA`).
+    #[cfg(rsa)]
+    fn rsa(&self, req: RsaRequest<'_>) -> Result<(), Error> {
+        let _ = req;
+        Err(CRYPTOCB_UNAVAILABLE)
+    }
+
+    /// Perform an Ed25519 operation (`WC_ALGO_TYPE_PK`).
+    #[cfg(ed25519)]
+    fn ed25519(&self, req: Ed25519Request<'_>) -> Result<(), Error> {
+        let _ = req;
+        Err(CRYPTOCB_UNAVAILABLE)
+    }
+}
+
+/// Hash request passed to [`CryptoDevice::hash()`].
+///
+/// wolfCrypt calls the device once per `update()` (with `input` set) and
+/// once per `finalize()` (with `digest` set). A one-shot hash may set both.
+#[cfg(any(sha, sha256, sha384, sha512, sha3))]
+pub struct HashRequest<'a> {
+    /// Hash algorithm, one of the `sys::wc_HashType_*` values.
+    pub hash_type: u32,
+    /// Address of the wolfCrypt hash object the request is made on, or 0
+    /// for a one-shot hash without an object. It identifies a running hash
+    /// between calls for as long as the object is not moved or dropped.
+    pub context: usize,
+    /// Data to add to the running hash.
+    pub input: Option<&'a [u8]>,
+    /// Buffer to receive the final digest; the device must reset the
+    /// running hash after writing it.
+    pub digest: Option<&'a mut [u8]>,
+}
+
+/// HMAC request passed to [`CryptoDevice::hmac()`].
+///
+/// As with [`HashRequest`], `input` is set for an update and `digest` for
+/// finalization. The key is not passed to the device; a device holding the
+/// key itself can tie it to the HMAC object through
+/// [`HmacRequest::dev_ctx`].
+#[cfg(hmac)]
+pub struct HmacRequest<'a> {
+    /// Underlying hash algorithm, one of the `sys::wc_HashType_*` values.
+    pub hash_type: u32,
+    /// Address of the wolfCrypt `Hmac` object the request is made on.
+    pub context: usize,
+    /// Device context pointer stored in the `Hmac` object.
+    pub dev_ctx: *mut core::ffi::c_void,
+    /// Data to add to the running MAC.
+    pub input: Option<&'a [u8]>,
+    /// Buffer to receive the final MAC.
+    pub digest: Option<&'a mut [u8]>,
+}
+
+/// Handle to the wolfCrypt `Aes` object a cipher request is made on.
+#[cfg(any(aes_cbc, aes_ctr, aes_ecb, aes_gcm))]
+pub struct AesKey<'a> {
+    aes: *mut sys::Aes,
+    _marker: PhantomData<&'a mut sys::Aes>,
+}
+
+#[cfg(any(aes_cbc, aes_ctr, aes_ecb, aes_gcm))]
+impl AesKey<'_> {
+    /// Get the raw AES key set on the object.
+    pub fn key(&self) -> &[u8] {
+        let aes = unsafe { &*self.aes };
+        let key = unsafe {
+            core::slice::from_raw_parts(aes.devKey.as_ptr() as *const u8,
+                core::mem::size_of_val(&aes.devKey))
+        };
+        let len = usize::try_from(aes.keylen).unwrap_or(0).min(key.len());
+        &key[..len]
+    }
+
+    /// Get the current IV (CBC) or counter (CTR) block.
+    pub fn iv(&self) -> [u8; 16] {
+        let aes = unsafe { &*self.aes };
+        let mut iv = [0u8; 16];
+        let reg = unsafe {
+            core::slice::from_raw_parts(aes.reg.as_ptr() as *const u8, iv.len())
+        };
+        iv.copy_from_slice(reg);
+        iv
+    }
+
+    /// Set the IV (CBC) or counter (CTR) block. A device implementing CBC
+    /// or CTR mode must store the chaining value here after each operation
+    /// so that subsequent calls continue the stream.
+    pub fn set_iv(&mut self, iv: &[u8; 16]) {
+        let aes = unsafe { &mut *self.aes };
+        let reg = unsafe {
+            core::slice::from_raw_parts_mut(aes.reg.as_mut_ptr() as *mut u8, iv.len())
+        };
+        reg.copy_from_slice(iv);
+    }
+
+    /// Get the device context pointer stored in the object.
+    pub fn dev_ctx(&self) -> *mut core::ffi::c_void {
+        unsafe { (*self.aes).devCtx }
+    }
+
+    /// Get the raw pointer to the wolfCrypt `Aes` object.
+    pub fn as_ptr(&self) -> *mut sys::Aes {
+        self.aes
+    }
+}
+
+/// Unauthenticated AES operation (CBC, CTR or ECB).
+#[cfg(any(aes_cbc, aes_ctr, aes_ecb))]
+pub struct AesCipher<'a> {
+    /// Key the operation is made with.
+    pub key: AesKey<'a>,
+    /// true to encrypt, false to decrypt.
+    pub encrypt: bool,
+    /// Input data.
+    pub input: &'a [u8],
+    /// Output buffer, the same length as `input`.
+    pub output: &'a mut [u8],
+}
+
+/// AES-GCM operation.
+#[cfg(aes_gcm)]
+pub struct AesGcm<'a> {
+    /// Key the operation is made with.
+    pub key: AesKey<'a>,
+    /// Input data.
+    pub input: &'a [u8],
+    /// Output buffer, the same length as `input`.
+    pub output: &'a mut [u8],
+    /// Initialization vector.
+    pub iv: &'a [u8],
+    /// Additional authenticated data.
+    pub auth_in: &'a [u8],
+}
+
+/// AES request passed to [`CryptoDevice::aes()`].
+#[cfg(any(aes_cbc, aes_ctr, aes_ecb, aes_gcm))]
+pub enum AesRequest<'a> {
+    /// AES-CBC encryption or decryption.
+    #[cfg(aes_cbc)]
+    Cbc(AesCipher<'a>),
+    /// AES-CTR encryption or decryption.
+    #[cfg(aes_ctr)]
+    Ctr(AesCipher<'a>),
+    /// AES-ECB encryption or decryption.
+    #[cfg(aes_ecb)]
+    Ecb(AesCipher<'a>),
+    /// AES-GCM encryption, writing the tag to `auth_tag`.
+    #[cfg(aes_gcm)]
+    GcmEncrypt {
+        /// Operation parameters.
+        gcm: AesGcm<'a>,
+        /// Buffer to receive the authentication tag.
+        auth_tag: &'a mut [u8],
+    },
+    /// AES-GCM decryption, verifying `auth_tag`. The device must return
+    /// `AES_GCM_AUTH_E` if the tag does not match.
+    #[cfg(aes_gcm)]
+    GcmDecrypt {
+        /// Operation parameters.
+        gcm: AesGcm<'a>,
+        /// Authentication tag to verify.
+        auth_tag: &'a [u8],
+    },
+}
+
+/// Handle to the wolfCrypt `ecc_key` object an ECC request is made on.
+#[cfg(any(ecc_sign, ecc_verify, ecc_dh))]
+pub struct EccKey<'a> {
+    key: *mut sys::ecc_key,
+    _marker: PhantomData<&'a mut sys::ecc_key>,
+}
+
+#[cfg(any(ecc_sign, ecc_verify, ecc_dh))]
+impl EccKey<'_> {
+    /// Get the curve ID of the key (one of the `ECC::SECP*` values).
+    pub fn curve_id(&self) -> i32 {
+        unsafe { sys::wc_ecc_get_curve_id((*self.key).idx) }
+    }
+
+    /// Export the public key in ANSI X9.63 format.
+    ///
+    /// # Parameters
+    ///
+    /// * `dout`: Buffer in which to store the public key.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to
+    /// `dout` or Err(e) containing the wolfSSL library error code value.
+    #[cfg(ecc_export)]
+    pub fn export_x963(&self, dout: &mut [u8]) -> Result<usize, Error> {
+        let mut dout_size = crate::buffer_len_to_u32(dout.len())?;
+        let rc = unsafe {
+            sys::wc_ecc_export_x963(self.key, dout.as_mut_ptr(), &mut dout_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(dout_size as usize)
+    }
+
+    /// Get the device context pointer stored in the key.
+    pub fn dev_ctx(&self) -> *mut core::ffi::c_void {
+        unsafe { (*self.key).devCtx }
+    }
+
+    /// Get the raw pointer to the wolfCrypt `ecc_key` object.
+    pub fn as_ptr(&self) -> *mut sys::ecc_key {
+        self.key
+    }
+}
+
+/// ECC request passed to [`CryptoDevice::ecc()`].
+#[cfg(any(ecc_sign, ecc_verify, ecc_dh))]
+pub enum EccRequest<'a> {
+    /// ECDSA signature of a hash. The device writes a DER encoded signature
+    /// to `signature` and its length to `signature_len`.
+    #[cfg(ecc_sign)]
+    Sign {
+        /// Private key.
+        key: EccKey<'a>,
+        /// Hash to sign.
+        hash: &'a [u8],
+        /// Buffer to receive the signature.
+        signature: &'a mut [u8],
+        /// Set to the signature length on success.
+        signature_len: &'a mut usize,
+    },
+    /// ECDSA verification of a DER encoded signature. The device sets
+    /// `valid` and returns Ok(()) whether or not the signature verifies.
+    #[cfg(ecc_verify)]
+    Verify {
+        /// Public key.
+        key: EccKey<'a>,
+        /// Signature to verify.
+        signature: &'a [u8],
+        /// Hash the signature was made over.
+        hash: &'a [u8],
+        /// Set to true if the signature is valid.
+        valid: &'a mut bool,
+    },
+    /// ECDH shared secret computation.
+    #[cfg(ecc_dh)]
+    SharedSecret {
+        /// Local private key.
+        private_key: EccKey<'a>,
+        /// Peer public key.
+        public_key: EccKey<'a>,
+        /// Buffer to receive the shared secret.
+        output: &'a mut [u8],
+        /// Set to the shared secret length on success.
+        output_len: &'a mut usize,
+    },
+}
+
+/// Handle to the wolfCrypt `RsaKey` object an RSA request is made on.
+#[cfg(rsa)]
+pub struct RsaKey<'a> {
+    key: *mut sys::RsaKey,
+    _marker: PhantomData<&'a mut sys::RsaKey>,
+}
+
+#[cfg(rsa)]
+impl RsaKey<'_> {
+    /// Get the size in bytes of the RSA modulus.
+    pub fn size(&self) -> Result<usize, Error> {
+        let rc = unsafe { sys::wc_RsaEncryptSize(self.key) };
+        if rc < 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(rc as usize)
+    }
+
+    /// Get the device context pointer stored in the key.
+    pub fn dev_ctx(&self) -> *mut core::ffi::c_void {
+        unsafe { (*self.key).devCtx }
+    }
+
+    /// Get the raw pointer to the wolfCrypt `RsaKey` object.
+    pub fn as_ptr(&self) -> *mut sys::RsaKey {
+        self.key
+    }
+}
+
+/// RSA request passed to [`CryptoDevice::rsa()`].
+///
+/// The request is a raw (already padded) modular exponentiation.
+#[cfg(rsa)]
+pub struct RsaRequest<'a> {
+    /// Key the operation is made with.
+    pub key: RsaKey<'a>,
+    /// Operation, one of `sys::RSA_PUBLIC_ENCRYPT`, `RSA_PUBLIC_DECRYPT`,
+    /// `RSA_PRIVATE_ENCRYPT` or `RSA_PRIVATE_DECRYPT`.
+    pub op: i32,
+    /// Input data.
+    pub input: &'a [u8],
+    /// Output buffer.
+    pub output: &'a mut [u8],
+    /// Set to the output length on success.
+    pub output_len: &'a mut usize,
+}
+
+/// Handle to the wolfCrypt `ed25519_key` object an Ed25519 request is made
+/// on.
+#[cfg(ed25519)]
+pub struct Ed25519Key<'a> {
+    key: *mut sys::ed25519_key,
+    _marker: PhantomData<&'a mut sys::ed25519_key>,
+}
+
+#[cfg(ed25519)]
+impl Ed25519Key<'_> {
+    /// Export the public key.
+    ///
+    /// # Parameters
+    ///
+    /// * `public`: Buffer in which to store the public key.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    #[cfg(ed25519_export)]
+    pub fn export_public(&self, public: &mut [u8; 32]) -> Result<(), Error> {
+        let mut public_size = public.len() as u32;
+        let rc = unsafe {
+            sys::wc_ed25519_export_public(self.key, public.as_mut_ptr(), &mut public_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Get the device context pointer stored in the key.
+    pub fn dev_ctx(&self) -> *mut core::ffi::c_void {
+        unsafe { (*self.key).devCtx }
+    }
+
+    /// Get the raw pointer to the wolfCrypt `ed25519_key` object.
+    pub fn as_ptr(&self) -> *mut sys::ed25519_key {
+        self.key
+    }
+}
+
+/// Ed25519 request passed to [`CryptoDevice::ed25519()`].
+#[cfg(ed25519)]
+pub enum Ed25519Request<'a> {
+    /// Signature of a message.
+    Sign {
+        /// Private key.
+        key: Ed25519Key<'a>,
+        /// Message to sign (the pre-hash for Ed25519ph).
+        message: &'a [u8],
+        /// Buffer to receive the signature.
+        signature: &'a mut [u8],
+        /// Set to the signature length on success.
+        signature_len: &'a mut usize,
+        /// Variant, one of `Ed25519::ED25519`, `ED25519CTX` or `ED25519PH`.
+        variant: u8,
+        /// Context string.
+        context: &'a [u8],
+    },
+    /// Verification of a signature. The device sets `valid` and returns
+    /// Ok(()) whether or not the signature verifies.
+    Verify {
+        /// Public key.
+        key: Ed25519Key<'a>,
+        /// Signature to verify.
+        signature: &'a [u8],
+        /// Signed message (the pre-hash for Ed25519ph).
+        message: &'a [u8],
+        /// Set to true if the signature is valid.
+        valid: &'a mut bool,
+        /// Variant, one of `Ed25519::ED25519`, `ED25519CTX` or `ED25519PH`.
+        variant: u8,
+        /// Context string.
+        context: &'a [u8],
+    },
+}
+
+/// Registry of the devices registered from Rust, looked up by device ID in
+/// the callback. The callback clones the device under the lock, so a device
+/// unregistered while one of its operations is in progress is only dropped
+/// once that operation returns. Device IDs are also released by `release()`
+/// with the lock held.
+struct Registry {
+    lock: AtomicBool,
+    slots: UnsafeCell<[Option<Slot>; sys::MAX_CRYPTO_DEVID_CALLBACKS as usize]>,
+}
+
+type Slot = (i32, Arc<dyn CryptoDevice>);
+
+// Safety: the slots are only accessed with the lock held.
+unsafe impl Sync for Registry {}
+
+static REGISTRY: Registry = Registry {
+    lock: AtomicBool::new(false),
+    slots: UnsafeCell::new([const { None }; sys::MAX_CRYPTO_DEVID_CALLBACKS as usize]),
+};
+
+impl Registry {
+    fn with<R>(&self, f: impl FnOnce(&mut [Option<Slot>]) -> R) -> R {
+        while self.lock.compare_exchange_weak(false, true, Ordering::Acquire,
+                Ordering::Relaxed).is_err() {
+            core::hint::spin_loop();
+        }
+        let r = f(unsafe { &mut *self.slots.get() });
+        self.lock.store(false, Ordering::Release);
+        r
+    }
+}
+
+/// Register a crypto callback device.
+///
+/// wolfCrypt objects created with the [`Device`] of the returned
+/// [`Registration`] route their operations to `device` until the
+/// `Registration` is dropped.
+///
+/// # Parameters
+///
+/// * `dev_id`: Device ID to register. Must not be `INVALID_DEVID`.
+/// * `device`: Device implementation.
+/// * `wolfcrypt`: Guard of the initialized library. The registration holds
+///   a clone of it until the device ID is released.
+///
+/// # Returns
+///
+/// Returns either Ok(Registration) owning the handle of the registered
+/// device on success or Err(e) containing the wolfSSL library error code
+/// value: `ALREADY_E` if `dev_id` is already registered, or still reserved
+/// by objects created with a dropped registration, or `BUFFER_E` if all
+/// device slots are in use.
+///
+/// # Example
+///
+/// ```rust
+/// use wolfssl_wolfcrypt::WolfCrypt;
+/// use wolfssl_wolfcrypt::cryptocb::{self, CryptoDevice};
+/// struct Passthrough;
+/// impl CryptoDevice for Passthrough {}
+/// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+/// let registration = cryptocb::register_device(0x4242, Passthrough, &wolfcrypt)
+///     .expect("Error with register_device()");
+/// assert_eq!(registration.device().id(), 0x4242);
+/// drop(registration);
+/// ```
+pub fn register_device<D: CryptoDevice + 'static>(dev_id: i32, device: D, wolfcrypt: &WolfCrypt)
+        -> Result<Registration, Error> {
+    if dev_id == sys::INVALID_DEVID {
+        return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+    }
+    REGISTRY.with(|slots| {
+        if slots.iter().flatten().any(|(id, _)| *id == dev_id) {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_ALREADY_E));
+        }
+        let slot = match slots.iter_mut().find(|slot| slot.is_none()) {
+            Some(slot) => slot,
+            None => return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E)),
+        };
+        let rc = unsafe {
+            sys::wc_CryptoCb_RegisterDevice(dev_id, Some(crypto_cb), core::ptr::null_mut())
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        *slot = Some((dev_id, Arc::new(device)));
+        Ok(Registration { device: Device::from_registered(dev_id, release, wolfcrypt) })
+    })
+}
+
+/// Registration of a crypto callback device with [`register_device()`].
+///
+/// The `Registration` owns the [`Device`] handle, which an
+/// [`Env`](crate::Env) borrows to create objects with the device. Dropping
+/// it unregisters the device and drops it. The device ID stays reserved
+/// until the objects created with the device are dropped.
+#[derive(Debug)]
+pub struct Registration {
+    device: Device,
+}
+
+impl Registration {
+    /// Get the handle of the registered device.
+    ///
+    /// # Returns
+    ///
+    /// Returns the device handle.
+    pub fn device(&self) -> &Device {
+        &self.device
+    }
+}
+
+impl Drop for Registration {
+    /// Unregister the device and drop it.
+    ///
+    /// Operations already in progress on other threads complete with the
+    /// device, which is dropped when the last of them returns. Objects
+    /// created with the device fall back to software after it is
+    /// unregistered.
+    fn drop(&mut self) {
+        let dev_id = self.device.id();
+        let device = REGISTRY.with(|slots| {
+            let slot = slots.iter_mut()
+                .find(|slot| matches!(slot, Some((id, _)) if *id == dev_id))?;
+            self.device.detach();
+            slot.take()
+        });
+        // The device is dropped after the lock is released.
+        drop(device);
+    }
+}
+
+/// Release a device ID once its registration and all objects created with
+/// the device have been dropped.
+fn release(dev_id: i32) {
+    REGISTRY.with(|_| unsafe { sys::wc_CryptoCb_UnRegisterDevice(dev_id) });
+}
+
+/// Build a shared slice from a C pointer and length, accepting NULL for an
+/// empty slice.
+unsafe fn slice<'a>(ptr: *const u8, len: u32) -> &'a [u8] {
+    if ptr.is_null() || len == 0 {
+        return &[];
+    }
+    unsafe { core::slice::from_raw_parts(ptr, len as usize) }
+}
+
+/// Build a mutable slice from a C pointer and length, accepting NULL for an
+/// empty slice.
+unsafe fn slice_mut<'a>(ptr: *mut u8, len: u32) -> &'a mut [u8] {
+    if ptr.is_null() || len == 0 {
+        return &mut [];
+    }
+    unsafe { core::slice::from_raw_parts_mut(ptr, len as usize) }
+}
+
+/// Build the input and output slices of an operation which wolfCrypt allows
+/// to be performed in place. Overlapping input is copied to `scratch` so
+/// the two slices never alias.
+#[cfg(any(aes_cbc, aes_ctr, aes_ecb, aes_gcm, rsa))]
+unsafe fn in_out(input: *const u8, in_len: u32, output: *mut u8,
+        out_len: u32, scratch: &mut Vec<u8>) -> (&[u8], &mut [u8]) {
+    let in_start = input as usize;
+    let out_start = output as usize;
+    let overlap = !input.is_null() && !output.is_null() &&
+        in_start < out_start + out_len as usize &&
+        out_start < in_start + in_len as usize;
+    let output = unsafe { slice_mut(output, out_len) };
+    if overlap {
+        scratch.extend_from_slice(unsafe { slice(input, in_len) });
+        return (scratch.as_slice(), output);
+    }
+    (unsafe { slice(input, in_len) }, output)
+}
+
+/// Callback registered with `wc_CryptoCb_RegisterDevice()`, dispatching
+/// `wc_CryptoInfo` requests to the Rust device registered under `dev_id`.
+unsafe extern "C" fn crypto_cb(dev_id: i32, info: *mut sys::wc_CryptoInfo,
+        _ctx: *mut core::ffi::c_void) -> i32 {
+    if info.is_null() {
+        return sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG;
+    }
+    let info = unsafe { &*info };
+    // Register and unregister commands are issued with the registry lock
+    // held, and are not passed to the device.
+    if info.algo_type as u32 == sys::wc_AlgoType_WC_ALGO_TYPE_NONE {
+        return CRYPTOCB_UNAVAILABLE.code();
+    }
+    let device = REGISTRY.with(|slots| {
+        slots.iter().flatten()
+            .find(|(id, _)| *id == dev_id)
+            .map(|(_, device)| Arc::clone(device))
+    });
+    let device = match device {
+        Some(device) => device,
+        None => return CRYPTOCB_UNAVAILABLE.code(),
+    };
+    let device = &*device;
+    let result = match info.algo_type as u32 {
+        #[cfg(random)]
+        sys::wc_AlgoType_WC_ALGO_TYPE_RNG => {
+            let rng = unsafe { &info.__bindgen_anon_1.rng };
+            device.rng(unsafe { slice_mut(rng.out, rng.sz) })
+        }
+        #[cfg(random)]
+        sys::wc_AlgoType_WC_ALGO_TYPE_SEED => {
+            let seed = unsafe { &info.__bindgen_anon_1.seed };
+            device.seed(unsafe { slice_mut(seed.seed, seed.sz) })
+        }
+        #[cfg(any(sha, sha256, sha384, sha512, sha3))]
+        sys::wc_AlgoType_WC_ALGO_TYPE_HASH => unsafe { dispatch_hash(device, info) },
+        #[cfg(hmac)]
+        sys::wc_AlgoType_WC_ALGO_TYPE_HMAC => unsafe { dispatch_hmac(device, info) },
+        #[cfg(any(aes_cbc, aes_ctr, aes_ecb, aes_gcm))]
+        sys::wc_AlgoType_WC_ALGO_TYPE_CIPHER => unsafe { dispatch_cipher(device, info) },
+        #[cfg(any(rsa, ecc_sign, ecc_verify, ecc_dh, ed25519))]
+        sys::wc_AlgoType_WC_ALGO_TYPE_PK => unsafe { dispatch_pk(device, info) },
+        _ => Err(CRYPTOCB_UNAVAILABLE),
+    };
+    match result {
+        Ok(()) => 0,
+        Err(e) => e.code(),
+    }
+}
+
+#[cfg(any(sha, sha256, sha384, sha512, sha3))]
+unsafe fn dispatch_hash(device: &dyn CryptoDevice, info: &sys::wc_CryptoInfo) -> Result<(), Error> {
+    let hash = unsafe { &info.__bindgen_anon_1.hash };
+    let hash_type = hash.type_ as u32;
+    let input = if hash.in_.is_null() {
+        None
+    } else {
+        Some(unsafe { slice(hash.in_, hash.inSz) })
+    };
+    let digest = if hash.digest.is_null() {
+        None
+    } else {
+        let size = if hash.outSz != 0 {
+            hash.outSz
+        } else {
+            let size = unsafe { sys::wc_HashGetDigestSize(hash_type) };
+            if size <= 0 {
+                return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+            }
+            size as u32
+        };
+        Some(unsafe { slice_mut(hash.digest, size) })
+    };
+    let context = unsafe { hash.__bindgen_anon_1.ctx } as usize;
+    device.hash(HashRequest { hash_type, context, input, digest })
+}
+
+#[cfg(hmac)]
+unsafe fn dispatch_hmac(device: &dyn CryptoDevice, info: &sys::wc_CryptoInfo) -> Result<(), Error> {
+    let hmac = unsafe { &info.__bindgen_anon_1.hmac };
+    if hmac.hmac.is_null() {
+        return Err(CRYPTOCB_UNAVAILABLE);
+    }
+    let input = if hmac.in_.is_null() {
+        None
+    } else {
+        Some(unsafe { slice(hmac.in_, hmac.inSz) })
+    };
+    let digest = if hmac.digest.is_null() {
+        None
+    } else {
+        let size = unsafe { sys::wc_HmacSizeByType(hmac.macType) };
+        if size <= 0 {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+        }
+        Some(unsafe { slice_mut(hmac.digest, size as u32) })
+    };
+    device.hmac(HmacRequest {
+        hash_type: hmac.macType as u32,
+        context: hmac.hmac as usize,
+        dev_ctx: unsafe { (*hmac.hmac).devCtx },
+        input,
+        digest,
+    })
+}
+
+#[cfg(any(aes_cbc, aes_ctr, aes_ecb, aes_gcm))]
+unsafe fn dispatch_cipher(device: &dyn CryptoDevice, info: &sys::wc_CryptoInfo) -> Result<(), Error> {
+    let cipher = unsafe { &info.__bindgen_anon_1.cipher };
+    let cipher_type = cipher.type_ as u32;
+    let encrypt = cipher.enc != 0;
+    let mut scratch = Vec::new();
+    #[cfg(aes_cbc)]
+    if cipher_type == sys::wc_CipherType_WC_CIPHER_AES_CBC {
+        let p = unsafe { &cipher.__bindgen_anon_1.aescbc };
+        let (input, output) = unsafe { in_out(p.in_, p.sz, p.out, p.sz, &mut scratch) };
+        return device.aes(AesRequest::Cbc(AesCipher {
+            key: AesKey { aes: p.aes, _marker: PhantomData },
+            encrypt, input, output,
+        }));
+    }
+    #[cfg(aes_ctr)]
+    if cipher_type == sys::wc_CipherType_WC_CIPHER_AES_CTR {
+        let p = unsafe { &cipher.__bindgen_anon_1.aesctr };
+        let (input, output) = unsafe { in_out(p.in_, p.sz, p.out, p.sz, &mut scratch) };
+        return device.aes(AesRequest::Ctr(AesCipher {
+            key: AesKey { aes: p.aes, _marker: PhantomData },
+            encrypt, input, output,
+        }));
+    }
+    #[cfg(aes_ecb)]
+    if cipher_type == sys::wc_CipherType_WC_CIPHER_AES_ECB {
+        let p = unsafe { &cipher.__bindgen_anon_1.aesecb };
+        let (input, output) = unsafe { in_out(p.in_, p.sz, p.out, p.sz, &mut scratch) };
+        return device.aes(AesRequest::Ecb(AesCipher {
+            key: AesKey { aes: p.aes, _marker: PhantomData },
+            encrypt, input, output,
+        }));
+    }
+    #[cfg(aes_gcm)]
+    if cipher_type == sys::wc_CipherType_WC_CIPHER_AES_GCM && encrypt {
+        let p = unsafe { &cipher.__bindgen_anon_1.aesgcm_enc };
+        if p.aes.is_null() {
+            return Err(CRYPTOCB_UNAVAILABLE);
+        }
+        let (input, output) = unsafe { in_out(p.in_, p.sz, p.out, p.sz, &mut scratch) };
+        return device.aes(AesRequest::GcmEncrypt {
+            gcm: AesGcm {
+                key: AesKey { aes: p.aes, _marker: PhantomData },
+                input, output,
+                iv: unsafe { slice(p.iv, p.ivSz) },
+                auth_in: unsafe { slice(p.authIn, p.authInSz) },
+            },
+            auth_tag: unsafe { slice_mut(p.authTag, p.authTagSz) },
+        });
+    }
+    #[cfg(aes_gcm)]
+    if cipher_type == sys::wc_CipherType_WC_CIPHER_AES_GCM && !encrypt {
+        let p = unsafe { &cipher.__bindgen_anon_1.aesgcm_dec };
+        if p.aes.is_null() {
+            return Err(CRYPTOCB_UNAVAILABLE);
+        }
+        let (input, output) = unsafe { in_out(p.in_, p.sz, p.out, p.sz, &mut scratch) };
+        return device.aes(AesRequest::GcmDecrypt {
+            gcm: AesGcm {
+                key: AesKey { aes: p.aes, _marker: PhantomData },
+                input, output,
+                iv: unsafe { slice(p.iv, p.ivSz) },
+                auth_in: unsafe { slice(p.authIn, p.authInSz) },
+            },
+            auth_tag: unsafe { slice(p.authTag, p.authTagSz) },
+        });
+    }
+    Err(CRYPTOCB_UNAVAILABLE)
+}
+
+#[cfg(any(rsa, ecc_sign, ecc_verify, ecc_dh, ed25519))]
+unsafe fn dispatch_pk(device: &dyn CryptoDevice, info: &sys::wc_CryptoInfo) -> Result<(), Error> {
+    let pk = unsafe { &info.__bindgen_anon_1.pk };
+    let pk_type = pk.type_ as u32;
+    #[cfg(rsa)]
+    if pk_type == sys::wc_PkType_WC_PK_TYPE_RSA {
+        let p = unsafe { &pk.__bindgen_anon_1.rsa };
+        if p.key.is_null() || p.outLen.is_null() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+        }
+        let mut scratch = Vec::new();
+        let (input, output) = unsafe {
+            in_out(p.in_, p.inLen, p.out, *p.outLen, &mut scratch)
+        };
+        let mut output_len = 0usize;
+        device.rsa(RsaRequest {
+            key: RsaKey { key: p.key, _marker: PhantomData },
+            op: p.type_,
+            input, output,
+            output_len: &mut output_len,
+        })?;
+        unsafe { *p.outLen = crate::buffer_len_to_u32(output_len)? };
+        return Ok(());
+    }
+    #[cfg(ecc_sign)]
+    if pk_type == sys::wc_PkType_WC_PK_TYPE_ECDSA_SIGN {
+        let p = unsafe { &pk.__bindgen_anon_1.eccsign };
+        if p.key.is_null() || p.outlen.is_null() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+        }
+        let mut signature_len = 0usize;
+        device.ecc(EccRequest::Sign {
+            key: EccKey { key: p.key, _marker: PhantomData },
+            hash: unsafe { slice(p.in_, p.inlen) },
+            signature: unsafe { slice_mut(p.out, *p.outlen) },
+            signature_len: &mut signature_len,
+        })?;
+        unsafe { *p.outlen = crate::buffer_len_to_u32(signature_len)? };
+        return Ok(());
+    }
+    #[cfg(ecc_verify)]
+    if pk_type == sys::wc_PkType_WC_PK_TYPE_ECDSA_VERIFY {
+        let p = unsafe { &pk.__bindgen_anon_1.eccverify };
+        if p.key.is_null() || p.res.is_null() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+        }
+        let mut valid = false;
+        device.ecc(EccRequest::Verify {
+            key: EccKey { key: p.key, _marker: PhantomData },
+            signature: unsafe { slice(p.sig, p.siglen) },
+            hash: unsafe { slice(p.hash, p.hashlen) },
+            valid: &mut valid,
+        })?;
+        unsafe { *p.res = valid as i32 };
+        return Ok(());
+    }
+    #[cfg(ecc_dh)]
+    if pk_type == sys::wc_PkType_WC_PK_TYPE_ECDH {
+        let p = unsafe { &pk.__bindgen_anon_1.ecdh };
+        if p.private_key.is_null() || p.public_key.is_null() || p.outlen.is_null() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+        }
+        let mut output_len = 0usize;
+        device.ecc(EccRequest::SharedSecret {
+            private_key: EccKey { key: p.private_key, _marker: PhantomData },
+            public_key: EccKey { key: p.public_key, _marker: PhantomData },
+            output: unsafe { slice_mut(p.out, *p.outlen) },
+            output_len: &mut output_len,
+        })?;
+        unsafe { *p.outlen = crate::buffer_len_to_u32(output_len)? };
+        return Ok(());
+    }
+    #[cfg(ed25519)]
+    if pk_type == sys::wc_PkType_WC_PK_TYPE_ED25519_SIGN {
+        let p = unsafe { &pk.__bindgen_anon_1.ed25519sign };
+        if p.key.is_null() || p.outLen.is_null() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+        }
+        let mut signature_len = 0usize;
+        device.ed25519(Ed25519Request::Sign {
+            key: Ed25519Key { key: p.key, _marker: PhantomData },
+            message: unsafe { slice(p.in_, p.inLen) },
+            signature: unsafe { slice_mut(p.out, *p.outLen) },
+            signature_len: &mut signature_len,
+            variant: p.type_,
+            context: unsafe { slice(p.context, p.contextLen as u32) },
+        })?;
+        unsafe { *p.outLen = crate::buffer_len_to_u32(signature_len)? };
+        return Ok(());
+    }
+    #[cfg(ed25519)]
+    if pk_type == sys::wc_PkType_WC_PK_TYPE_ED25519_VERIFY {
+        let p = unsafe { &pk.__bindgen_anon_1.ed25519verify };
+        if p.key.is_null() || p.res.is_null() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+        }
+        let mut valid = false;
+        device.ed25519(Ed25519Request::Verify {
+            key: Ed25519Key { key: p.key, _marker: PhantomData },
+            signature: unsafe { slice(p.sig, p.sigLen) },
+            message: unsafe { slice(p.msg, p.msgLen) },
+            valid: &mut valid,
+            variant: p.type_,
+            context: unsafe { slice(p.context, p.contextLen as u32) },
+        })?;
+        unsafe { *p.res = valid as i32 };
+        return Ok(());
+    }
+    Err(CRYPTOCB_UNAVAILABLE)
+}
@@ -27,14 +27,16 @@ functionality.
 
 #[cfg(random)]
 use crate::random::{RNG, RngHandle};
+use crate::Error;
 use crate::sys;
 use core::mem::MaybeUninit;
 
 pub struct Curve25519Key {
-    wc_key: sys::curve25519_key,
+    pub(crate) wc_key: sys::curve25519_key,
     /// RNG bound via `set_rng`, kept alive while the C struct holds its pointer.
     #[cfg(random)]
-    rng: Option<RngHandle>,
+    rng: Option<RngHandle<'static>>,
+    _wolfcrypt: crate::WolfCrypt,
 }
 
 impl Curve25519Key {
@@ -52,7 +54,7 @@ impl Curve25519Key {
     ///
     /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
     /// library error code value.
-    pub fn check_public(public: &[u8], big_endian: bool) -> Result<(), i32> {
+    pub fn check_public(public: &[u8], big_endian: bool) -> Result<(), Error> {
         let public_size = crate::buffer_len_to_u32(public.len())?;
         let endian = if big_endian {sys::EC25519_BIG_ENDIAN} else {sys::EC25519_LITTLE_ENDIAN};
         let rc = unsafe {
@@ -60,7 +62,7 @@ impl Curve25519Key {
                 endian as i32)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -70,32 +72,35 @@ impl Curve25519Key {
     /// # Parameters
     ///
     /// * `rng`: Random number generator struct to use for blinding operation.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
     /// Returns either Ok(curve25519key) on success or Err(e) containing the
     /// wolfSSL library error code value.
     #[cfg(random)]
-    pub fn generate(rng: &RNG) -> Result<Self, i32> {
+    pub fn generate(rng: &RNG, wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        let wolfcrypt = wolfcrypt.clone();
         let mut wc_key: MaybeUninit<sys::curve25519_key> = MaybeUninit::uninit();
         let rc = unsafe {
             sys::wc_curve25519_init(wc_key.as_mut_ptr())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_key = unsafe { wc_key.assume_init() };
         let mut curve25519key = Curve25519Key {
             wc_key,
             #[cfg(random)]
             rng: None,
+            _wolfcrypt: wolfcrypt,
         };
         let rc = unsafe {
             sys::wc_curve25519_make_key(rng.wc_rng, Self::KEYSIZE as i32,
                 &mut curve25519key.wc_key)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(curve25519key)
     }
@@ -111,15 +116,15 @@ impl Curve25519Key {
     /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
     /// library error code value.
     #[cfg(random)]
-    pub fn generate_priv(rng: &RNG, out: &mut [u8]) -> Result<(), i32> {
+    pub fn generate_priv(rng: &RNG, out: &mut [u8]) -> Result<(), Error> {
         if out.len() != Self::KEYSIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let rc = unsafe {
             sys::wc_curve25519_make_priv(rng.wc_rng, Self::KEYSIZE as i32, out.as_mut_ptr())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -129,32 +134,35 @@ impl Curve25519Key {
     /// # Parameters
     ///
     /// * `private`: Buffer containing the Curve25519 private key.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
     /// Returns either Ok(curve25519key) on success or Err(e) containing the
     /// wolfSSL library error code value.
-    pub fn import_private(private: &[u8]) -> Result<Self, i32> {
+    pub fn import_private(private: &[u8], wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        let wolfcrypt = wolfcrypt.clone();
         let private_size = crate::buffer_len_to_u32(private.len())?;
         let mut wc_key: MaybeUninit<sys::curve25519_key> = MaybeUninit::uninit();
         let rc = unsafe {
             sys::wc_curve25519_init(wc_key.as_mut_ptr())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_key = unsafe { wc_key.assume_init() };
         let mut curve25519key = Curve25519Key {
             wc_key,
             #[cfg(random)]
             rng: None,
+            _wolfcrypt: wolfcrypt,
         };
         let rc = unsafe {
             sys::wc_curve25519_import_private(private.as_ptr(), private_size,
                 &mut curve25519key.wc_key)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(curve25519key)
     }
@@ -165,25 +173,28 @@ impl Curve25519Key {
     ///
     /// * `private`: Buffer containing the Curve25519 private key.
     /// * `big_endian`: True for big-endian, false for little-endian.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
     /// Returns either Ok(curve25519key) on success or Err(e) containing the
     /// wolfSSL library error code value.
-    pub fn import_private_ex(private: &[u8], big_endian: bool) -> Result<Self, i32> {
+    pub fn import_private_ex(private: &[u8], big_endian: bool, wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        let wolfcrypt = wolfcrypt.clone();
         let private_size = crate::buffer_len_to_u32(private.len())?;
         let mut wc_key: MaybeUninit<sys::curve25519_key> = MaybeUninit::uninit();
         let rc = unsafe {
             sys::wc_curve25519_init(wc_key.as_mut_ptr())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_key = unsafe { wc_key.assume_init() };
         let mut curve25519key = Curve25519Key {
             wc_key,
             #[cfg(random)]
             rng: None,
+            _wolfcrypt: wolfcrypt,
         };
         let endian = if big_endian {sys::EC25519_BIG_ENDIAN} else {sys::EC25519_LITTLE_ENDIAN};
         let rc = unsafe {
@@ -191,7 +202,7 @@ impl Curve25519Key {
                 private_size, &mut curve25519key.wc_key, endian as i32)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(curve25519key)
     }
@@ -202,12 +213,14 @@ impl Curve25519Key {
     ///
     /// * `private`: Buffer containing the Curve25519 private key.
     /// * `public`: Buffer containing the Curve25519 public key.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
     /// Returns either Ok(curve25519key) on success or Err(e) containing the
     /// wolfSSL library error code value.
-    pub fn import_private_raw(private: &[u8], public: &[u8]) -> Result<Self, i32> {
+    pub fn import_private_raw(private: &[u8], public: &[u8], wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        let wolfcrypt = wolfcrypt.clone();
         let private_size = crate::buffer_len_to_u32(private.len())?;
         let public_size = crate::buffer_len_to_u32(public.len())?;
         let mut wc_key: MaybeUninit<sys::curve25519_key> = MaybeUninit::uninit();
@@ -215,13 +228,14 @@ impl Curve25519Key {
             sys::wc_curve25519_init(wc_key.as_mut_ptr())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_key = unsafe { wc_key.assume_init() };
         let mut curve25519key = Curve25519Key {
             wc_key,
             #[cfg(random)]
             rng: None,
+            _wolfcrypt: wolfcrypt,
         };
         let rc = unsafe {
             sys::wc_curve25519_import_private_raw(private.as_ptr(),
@@ -229,7 +243,7 @@ impl Curve25519Key {
                 &mut curve25519key.wc_key)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(curve25519key)
     }
@@ -241,12 +255,14 @@ impl Curve25519Key {
     /// * `private`: Buffer containing the Curve25519 private key.
     /// * `public`: Buffer containing the Curve25519 public key.
     /// * `big_endian`: True for big-endian, false for little-endian.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
     /// Returns either Ok(curve25519key) on success or Err(e) containing the
     /// wolfSSL library error code value.
-    pub fn import_private_raw_ex(private: &[u8], public: &[u8], big_endian: bool) -> Result<Self, i32> {
+    pub fn import_private_raw_ex(private: &[u8], public: &[u8], big_endian: bool, wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        let wolfcrypt = wolfcrypt.clone();
         let private_size = crate::buffer_len_to_u32(private.len())?;
         let public_size = crate::buffer_len_to_u32(public.len())?;
         let mut wc_key: MaybeUninit<sys::curve25519_key> = MaybeUninit::uninit();
@@ -254,13 +270,14 @@ impl Curve25519Key {
             sys::wc_curve25519_init(wc_key.as_mut_ptr())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_key = unsafe { wc_key.assume_init() };
         let mut curve25519key = Curve25519Key {
             wc_key,
             #[cfg(random)]
             rng: None,
+            _wolfcrypt: wolfcrypt,
         };
         let endian = if big_endian {sys::EC25519_BIG_ENDIAN} else {sys::EC25519_LITTLE_ENDIAN};
         let rc = unsafe {
@@ -269,7 +286,7 @@ impl Curve25519Key {
                 &mut curve25519key.wc_key, endian as i32)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(curve25519key)
     }
@@ -279,61 +296,87 @@ impl Curve25519Key {
     /// # Parameters
     ///
     /// * `public`: Buffer containing the Curve25519 public key.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
     /// Returns either Ok(curve25519key) on success or Err(e) containing the
     /// wolfSSL library error code value.
-    pub fn import_public(public: &[u8]) -> Result<Self, i32> {
+    pub fn import_public(public: &[u8], wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        let wolfcrypt = wolfcrypt.clone();
         let public_size = crate::buffer_len_to_u32(public.len())?;
         let mut wc_key: MaybeUninit<sys::curve25519_key> = MaybeUninit::uninit();
         let rc = unsafe {
             sys::wc_curve25519_init(wc_key.as_mut_ptr())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_key = unsafe { wc_key.assume_init() };
         let mut curve25519key = Curve25519Key {
             wc_key,
             #[cfg(random)]
             rng: None,
+            _wolfcrypt: wolfcrypt,
         };
         let rc = unsafe {
             sys::wc_curve25519_import_public(public.as_ptr(), public_size,
                 &mut curve25519key.wc_key)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(curve25519key)
     }
 
+    /// Create an empty Curve25519 key, to be filled in by a DER decoder.
+    #[cfg(curve25519_key_decode)]
+    pub(crate) fn new_empty(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        let wolfcrypt = wolfcrypt.clone();
+        let mut wc_key: MaybeUninit<sys::curve25519_key> = MaybeUninit::uninit();
+        let rc = unsafe {
+            sys::wc_curve25519_init(wc_key.as_mut_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        let wc_key = unsafe { wc_key.assume_init() };
+        Ok(Curve25519Key {
+            wc_key,
+            #[cfg(random)]
+            rng: None,
+            _wolfcrypt: wolfcrypt,
+        })
+    }
+
     /// Import a Curve25519 public key (big or little endian).
     ///
     /// # Parameters
     ///
     /// * `public`: Buffer containing the Curve25519 public key.
     /// * `big_endian`: True for big-endian, false for little-endian.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
     /// Returns either Ok(curve25519key) on success or Err(e) containing the
     /// wolfSSL library error code value.
-    pub fn import_public_ex(public: &[u8], big_endian: bool) -> Result<Self, i32> {
+    pub fn import_public_ex(public: &[u8], big_endian: bool, wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        let wolfcrypt = wolfcrypt.clone();
         let public_size = crate::buffer_len_to_u32(public.len())?;
         let mut wc_key: MaybeUninit<sys::curve25519_key> = MaybeUninit::uninit();
         let rc = unsafe {
             sys::wc_curve25519_init(wc_key.as_mut_ptr())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_key = unsafe { wc_key.assume_init() };
         let mut curve25519key = Curve25519Key {
             wc_key,
             #[cfg(random)]
             rng: None,
+            _wolfcrypt: wolfcrypt,
         };
         let endian = if big_endian {sys::EC25519_BIG_ENDIAN} else {sys::EC25519_LITTLE_ENDIAN};
         let rc = unsafe {
@@ -341,7 +384,7 @@ impl Curve25519Key {
                 &mut curve25519key.wc_key, endian as i32)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(curve25519key)
     }
@@ -357,7 +400,7 @@ impl Curve25519Key {
     ///
     /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
     /// library error code value.
-    pub fn make_pub(private: &[u8], public: &mut [u8]) -> Result<(), i32> {
+    pub fn make_pub(private: &[u8], public: &mut [u8]) -> Result<(), Error> {
         let private_size = crate::buffer_len_to_i32(private.len())?;
         let public_size = crate::buffer_len_to_i32(public.len())?;
         let rc = unsafe {
@@ -365,7 +408,7 @@ impl Curve25519Key {
                 private_size, private.as_ptr())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -384,7 +427,7 @@ impl Curve25519Key {
     /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
     /// library error code value.
     #[cfg(all(curve25519_blinding, random))]
-    pub fn make_pub_blind(private: &[u8], public: &mut [u8], rng: &RNG) -> Result<(), i32> {
+    pub fn make_pub_blind(private: &[u8], public: &mut [u8], rng: &RNG) -> Result<(), Error> {
         let private_size = crate::buffer_len_to_i32(private.len())?;
         let public_size = crate::buffer_len_to_i32(public.len())?;
         let rc = unsafe {
@@ -392,7 +435,7 @@ impl Curve25519Key {
                 private_size, private.as_ptr(), rng.wc_rng)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -410,7 +453,7 @@ impl Curve25519Key {
     ///
     /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
     /// library error code value.
-    pub fn make_pub_generic(private: &[u8], public: &mut [u8], basepoint: &[u8]) -> Result<(), i32> {
+    pub fn make_pub_generic(private: &[u8], public: &mut [u8], basepoint: &[u8]) -> Result<(), Error> {
         let private_size = crate::buffer_len_to_i32(private.len())?;
         let public_size = crate::buffer_len_to_i32(public.len())?;
         let basepoint_size = crate::buffer_len_to_i32(basepoint.len())?;
@@ -419,7 +462,7 @@ impl Curve25519Key {
                 private_size, private.as_ptr(), basepoint_size, basepoint.as_ptr())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -439,7 +482,7 @@ impl Curve25519Key {
     /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
     /// library error code value.
     #[cfg(all(curve25519_blinding, random))]
-    pub fn make_pub_generic_blind(private: &[u8], public: &mut [u8], basepoint: &[u8], rng: &RNG) -> Result<(), i32> {
+    pub fn make_pub_generic_blind(private: &[u8], public: &mut [u8], basepoint: &[u8], rng: &RNG) -> Result<(), Error> {
         let private_size = crate::buffer_len_to_i32(private.len())?;
         let public_size = crate::buffer_len_to_i32(public.len())?;
         let basepoint_size = crate::buffer_len_to_i32(basepoint.len())?;
@@ -449,7 +492,7 @@ impl Curve25519Key {
                 rng.wc_rng)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -468,14 +511,14 @@ impl Curve25519Key {
     ///
     /// Returns either Ok(size) containing the number of bytes written to `out`
     /// on success or Err(e) containing the wolfSSL library error code value.
-    pub fn shared_secret(private_key: &mut Curve25519Key, public_key: &mut Curve25519Key, out: &mut [u8]) -> Result<usize, i32> {
+    pub fn shared_secret(private_key: &mut Curve25519Key, public_key: &mut Curve25519Key, out: &mut [u8]) -> Result<usize, Error> {
         let mut outlen = crate::buffer_len_to_u32(out.len())?;
         let rc = unsafe {
             sys::wc_curve25519_shared_secret(&mut private_key.wc_key,
                 &mut public_key.wc_key, out.as_mut_ptr(), &mut outlen)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(outlen as usize)
     }
@@ -498,13 +541,13 @@ impl Curve25519Key {
     /// Returns Ok(()) on success or Err(e) containing the wolfSSL library
     /// error code value.
     #[cfg(all(curve25519_blinding, random))]
-    pub fn set_rng(&mut self, rng: RNG) -> Result<(), i32> {
+    pub fn set_rng(&mut self, rng: RNG<'static>) -> Result<(), Error> {
         let wc_rng = rng.wc_rng;
         let rc = unsafe {
             sys::wc_curve25519_set_rng(&mut self.wc_key, wc_rng)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         self.rng = Some(RngHandle::Owned(rng));
         Ok(())
@@ -513,13 +556,13 @@ impl Curve25519Key {
     /// Bind a shared `RNG` to this key. Available when the `alloc` feature
     /// is enabled.
     #[cfg(all(curve25519_blinding, random, feature = "alloc"))]
-    pub fn set_shared_rng(&mut self, rng: alloc::rc::Rc<RNG>) -> Result<(), i32> {
+    pub fn set_shared_rng(&mut self, rng: alloc::rc::Rc<RNG<'static>>) -> Result<(), Error> {
         let wc_rng = rng.wc_rng;
         let rc = unsafe {
             sys::wc_curve25519_set_rng(&mut self.wc_key, wc_rng)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         self.rng = Some(RngHandle::Shared(rng));
         Ok(())
@@ -527,7 +570,7 @@ impl Curve25519Key {
 
     /// Borrow the RNG previously bound via `set_rng` or `set_shared_rng`.
     #[cfg(random)]
-    pub fn rng(&self) -> Option<&RNG> {
+    pub fn rng(&self) -> Option<&RNG<'static>> {
         match &self.rng {
             Some(RngHandle::Owned(rng)) => Some(rng),
             #[cfg(feature = "alloc")]
@@ -551,7 +594,7 @@ impl Curve25519Key {
     ///
     /// Returns either Ok(size) containing the number of bytes written to `out`
     /// on success or Err(e) containing the wolfSSL library error code value.
-    pub fn shared_secret_ex(private_key: &mut Curve25519Key, public_key: &mut Curve25519Key, out: &mut [u8], big_endian: bool) -> Result<usize, i32> {
+    pub fn shared_secret_ex(private_key: &mut Curve25519Key, public_key: &mut Curve25519Key, out: &mut [u8], big_endian: bool) -> Result<usize, Error> {
         let mut outlen = crate::buffer_len_to_u32(out.len())?;
         let endian = if big_endian {sys::EC25519_BIG_ENDIAN} else {sys::EC25519_LITTLE_ENDIAN};
         let rc = unsafe {
@@ -559,7 +602,7 @@ impl Curve25519Key {
                 &mut public_key.wc_key, out.as_mut_ptr(), &mut outlen, endian as i32)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(outlen as usize)
     }
@@ -576,7 +619,7 @@ impl Curve25519Key {
     ///
     /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
     /// library error code value.
-    pub fn export_key_raw(&mut self, private: &mut [u8], public: &mut [u8]) -> Result<(), i32> {
+    pub fn export_key_raw(&mut self, private: &mut [u8], public: &mut [u8]) -> Result<(), Error> {
         let mut private_size = crate::buffer_len_to_u32(private.len())?;
         let mut public_size = crate::buffer_len_to_u32(public.len())?;
         let rc = unsafe {
@@ -585,7 +628,7 @@ impl Curve25519Key {
                 public.as_mut_ptr(), &mut public_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -603,7 +646,7 @@ impl Curve25519Key {
     ///
     /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
     /// library error code value.
-    pub fn export_key_raw_ex(&mut self, private: &mut [u8], public: &mut [u8], big_endian: bool) -> Result<(), i32> {
+    pub fn export_key_raw_ex(&mut self, private: &mut [u8], public: &mut [u8], big_endian: bool) -> Result<(), Error> {
         let mut private_size = crate::buffer_len_to_u32(private.len())?;
         let mut public_size = crate::buffer_len_to_u32(public.len())?;
         let endian = if big_endian {sys::EC25519_BIG_ENDIAN} else {sys::EC25519_LITTLE_ENDIAN};
@@ -613,7 +656,7 @@ impl Curve25519Key {
                 public.as_mut_ptr(), &mut public_size, endian as i32)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -629,14 +672,14 @@ impl Curve25519Key {
     ///
     /// Returns either Ok(size) containing the number of bytes written to `out`
     /// on success or Err(e) containing the wolfSSL library error code value.
-    pub fn export_private_raw(&mut self, out: &mut [u8]) -> Result<usize, i32> {
+    pub fn export_private_raw(&mut self, out: &mut [u8]) -> Result<usize, Error> {
         let mut outlen = crate::buffer_len_to_u32(out.len())?;
         let rc = unsafe {
             sys::wc_curve25519_export_private_raw(&mut self.wc_key,
                 out.as_mut_ptr(), &mut outlen)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(outlen as usize)
     }
@@ -653,7 +696,7 @@ impl Curve25519Key {
     ///
     /// Returns either Ok(size) containing the number of bytes written to `out`
     /// on success or Err(e) containing the wolfSSL library error code value.
-    pub fn export_private_raw_ex(&mut self, out: &mut [u8], big_endian: bool) -> Result<usize, i32> {
+    pub fn export_private_raw_ex(&mut self, out: &mut [u8], big_endian: bool) -> Result<usize, Error> {
         let mut outlen = crate::buffer_len_to_u32(out.len())?;
         let endian = if big_endian {sys::EC25519_BIG_ENDIAN} else {sys::EC25519_LITTLE_ENDIAN};
         let rc = unsafe {
@@ -661,7 +704,7 @@ impl Curve25519Key {
                 out.as_mut_ptr(), &mut outlen, endian as i32)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(outlen as usize)
     }
@@ -677,14 +720,14 @@ impl Curve25519Key {
     ///
     /// Returns either Ok(size) containing the number of bytes written to `out`
     /// on success or Err(e) containing the wolfSSL library error code value.
-    pub fn export_public(&mut self, out: &mut [u8]) -> Result<usize, i32> {
+    pub fn export_public(&mut self, out: &mut [u8]) -> Result<usize, Error> {
         let mut outlen = crate::buffer_len_to_u32(out.len())?;
         let rc = unsafe {
             sys::wc_curve25519_export_public(&mut self.wc_key,
                 out.as_mut_ptr(), &mut outlen)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(outlen as usize)
     }
@@ -701,7 +744,7 @@ impl Curve25519Key {
     ///
     /// Returns either Ok(size) containing the number of bytes written to `out`
     /// on success or Err(e) containing the wolfSSL library error code value.
-    pub fn export_public_ex(&mut self, out: &mut [u8], big_endian: bool) -> Result<usize, i32> {
+    pub fn export_public_ex(&mut self, out: &mut [u8], big_endian: bool) -> Result<usize, Error> {
         let mut outlen = crate::buffer_len_to_u32(out.len())?;
         let endian = if big_endian {sys::EC25519_BIG_ENDIAN} else {sys::EC25519_LITTLE_ENDIAN};
         let rc = unsafe {
@@ -709,7 +752,7 @@ impl Curve25519Key {
                 out.as_mut_ptr(), &mut outlen, endian as i32)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(outlen as usize)
     }
@@ -0,0 +1,480 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+This module provides a Rust wrapper for the wolfCrypt library's Curve448
+(X448) functionality.
+
+# Examples
+
+```rust
+#[cfg(random)]
+{
+use wolfssl_wolfcrypt::random::RNG;
+use wolfssl_wolfcrypt::curve448::X448;
+use wolfssl_wolfcrypt::WolfCrypt;
+let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+
+let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+let mut alice = X448::generate(&rng, &wolfcrypt).expect("Error with generate()");
+let mut bob = X448::generate(&rng, &wolfcrypt).expect("Error with generate()");
+let mut alice_secret = [0u8; X448::KEYSIZE];
+let mut bob_secret = [0u8; X448::KEYSIZE];
+X448::shared_secret(&mut alice, &mut bob, &mut alice_secret)
+    .expect("Error with shared_secret()");
+X448::shared_secret(&mut bob, &mut alice, &mut bob_secret)
+    .expect("Error with shared_secret()");
+assert_eq!(alice_secret, bob_secret);
+}
+```
+*/
+
+#![cfg(curve448)]
+
+#[cfg(random)]
+use crate::random::RNG;
+use crate::Error;
+use crate::sys;
+use core::mem::MaybeUninit;
+
+pub struct X448 {
+    pub(crate) wc_key: sys::curve448_key,
+    _wolfcrypt: crate::WolfCrypt,
+}
+
+impl X448 {
+    /// Curve448 private key size (56 bytes).
+    pub const KEYSIZE: usize = sys::CURVE448_KEY_SIZE as usize;
+    /// Curve448 public key size (56 bytes).
+    pub const PUB_KEYSIZE: usize = sys::CURVE448_PUB_KEY_SIZE as usize;
+
+    /// Create an empty key, to be filled in by an import or a DER decoder.
+    pub(crate) fn new_empty(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        let wolfcrypt = wolfcrypt.clone();
+        let mut wc_key: MaybeUninit<sys::curve448_key> = MaybeUninit::uninit();
+        let rc = unsafe {
+            sys::wc_curve448_init(wc_key.as_mut_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        let wc_key = unsafe { wc_key.assume_init() };
+        Ok(X448 { wc_key, _wolfcrypt: wolfcrypt })
+    }
+
+    /// Check that a public key buffer holds a valid Curve448 key value
+    /// given the endian ordering.
+    ///
+    /// # Parameters
+    ///
+    /// * `public`: Buffer containing the Curve448 public key.
+    /// * `big_endian`: True for big-endian, false for little-endian.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn check_public(public: &[u8], big_endian: bool) -> Result<(), Error> {
+        let public_size = crate::buffer_len_to_u32(public.len())?;
+        let endian = if big_endian {sys::EC448_BIG_ENDIAN} else {sys::EC448_LITTLE_ENDIAN};
+        let rc = unsafe {
+            sys::wc_curve448_check_public(public.as_ptr(), public_size,
+                endian as i32)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Generate a new key pair.
+    ///
+    /// # Parameters
+    ///
+    /// * `rng`: Random number generator to use.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(x448) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    #[cfg(random)]
+    pub fn generate(rng: &RNG, wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        let mut key = Self::new_empty(wolfcrypt)?;
+        let rc = unsafe {
+            sys::wc_curve448_make_key(rng.wc_rng, Self::KEYSIZE as i32,
+                &mut key.wc_key)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(key)
+    }
+
+    /// Import a Curve448 private key only (big-endian only).
+    ///
+    /// # Parameters
+    ///
+    /// * `private`: Buffer containing the Curve448 private key.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(x448) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn import_private(private: &[u8], wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::import_private_ex(private, true, wolfcrypt)
+    }
+
+    /// Import a Curve448 private key only (big or little endian).
+    ///
+    /// # Parameters
+    ///
+    /// * `private`: Buffer containing the Curve448 private key.
+    /// * `big_endian`: True for big-endian, false for little-endian.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(x448) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn import_private_ex(private: &[u8], big_endian: bool, wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        let private_size = crate::buffer_len_to_u32(private.len())?;
+        let mut key = Self::new_empty(wolfcrypt)?;
+        let endian = if big_endian {sys::EC448_BIG_ENDIAN} else {sys::EC448_LITTLE_ENDIAN};
+        let rc = unsafe {
+            sys::wc_curve448_import_private_ex(private.as_ptr(), private_size,
+                &mut key.wc_key, endian as i32)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(key)
+    }
+
+    /// Import a Curve448 public/private key pair (big-endian only).
+    ///
+    /// # Parameters
+    ///
+    /// * `private`: Buffer containing the Curve448 private key.
+    /// * `public`: Buffer containing the Curve448 public key.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(x448) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn import_private_raw(private: &[u8], public: &[u8], wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::import_private_raw_ex(private, public, true, wolfcrypt)
+    }
+
+    /// Import a Curve448 public/private key pair (big or little endian).
+    ///
+    /// # Parameters
+    ///
+    /// * `private`: Buffer containing the Curve448 private key.
+    /// * `public`: Buffer containing the Curve448 public key.
+    /// * `big_endian`: True for big-endian, false for little-endian.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(x448) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn import_private_raw_ex(private: &[u8], public: &[u8], big_endian: bool, wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        let private_size = crate::buffer_len_to_u32(private.len())?;
+        let public_size = crate::buffer_len_to_u32(public.len())?;
+        let mut key = Self::new_empty(wolfcrypt)?;
+        let endian = if big_endian {sys::EC448_BIG_ENDIAN} else {sys::EC448_LITTLE_ENDIAN};
+        let rc = unsafe {
+            sys::wc_curve448_import_private_raw_ex(private.as_ptr(),
+                private_size, public.as_ptr(), public_size, &mut key.wc_key,
+                endian as i32)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(key)
+    }
+
+    /// Import a Curve448 public key (big-endian only).
+    ///
+    /// # Parameters
+    ///
+    /// * `public`: Buffer containing the Curve448 public key.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(x448) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn import_public(public: &[u8], wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::import_public_ex(public, true, wolfcrypt)
+    }
+
+    /// Import a Curve448 public key (big or little endian).
+    ///
+    /// # Parameters
+    ///
+    /// * `public`: Buffer containing the Curve448 public key.
+    /// * `big_endian`: True for big-endian, false for little-endian.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(x448) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn import_public_ex(public: &[u8], big_endian: bool, wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        let public_size = crate::buffer_len_to_u32(public.len())?;
+        let mut key = Self::new_empty(wolfcrypt)?;
+        let endian = if big_endian {sys::EC448_BIG_ENDIAN} else {sys::EC448_LITTLE_ENDIAN};
+        let rc = unsafe {
+            sys::wc_curve448_import_public_ex(public.as_ptr(), public_size,
+                &mut key.wc_key, endian as i32)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(key)
+    }
+
+    /// Compute the public key from an existing private key using bare
+    /// little-endian vectors.
+    ///
+    /// The private key is not clamped by this function; keys imported into
+    /// an `X448` are clamped on import.
+    ///
+    /// # Parameters
+    ///
+    /// * `private`: Private key (input).
+    /// * `public`: Buffer in which to store the computed public key.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn make_pub(private: &[u8], public: &mut [u8]) -> Result<(), Error> {
+        let private_size = crate::buffer_len_to_i32(private.len())?;
+        let public_size = crate::buffer_len_to_i32(public.len())?;
+        let rc = unsafe {
+            sys::wc_curve448_make_pub(public_size, public.as_mut_ptr(),
+                private_size, private.as_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Compute a shared secret key given a secret private key and a received
+    /// public key. It stores the generated secret key in the buffer out and
+    /// returns the generated key size. Only supports big endian.
+    ///
+    /// # Parameters
+    ///
+    /// * `private_key`: X448 struct holding the user's private key.
+    /// * `public_key`: X448 struct holding the received public key.
+    /// * `out`: Output buffer in which to store the generated secret key.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to `out`
+    /// on success or Err(e) containing the wolfSSL library error code value.
+    pub fn shared_secret(private_key: &mut X448, public_key: &mut X448, out: &mut [u8]) -> Result<usize, Error> {
+        Self::shared_secret_ex(private_key, public_key, out, true)
+    }
+
+    /// Compute a shared secret key given a secret private key and a received
+    /// public key. It stores the generated secret key in the buffer out and
+    /// returns the generated key size. Supports big or little endian.
+    ///
+    /// # Parameters
+    ///
+    /// * `private_key`: X448 struct holding the user's private key.
+    /// * `public_key`: X448 struct holding the received public key.
+    /// * `out`: Output buffer in which to store the generated secret key.
+    /// * `big_endian`: True for big-endian, false for little-endian.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to `out`
+    /// on success or Err(e) containing the wolfSSL library error code value.
+    pub fn shared_secret_ex(private_key: &mut X448, public_key: &mut X448, out: &mut [u8], big_endian: bool) -> Result<usize, Error> {
+        let mut outlen = crate::buffer_len_to_u32(out.len())?;
+        let endian = if big_endian {sys::EC448_BIG_ENDIAN} else {sys::EC448_LITTLE_ENDIAN};
+        let rc = unsafe {
+            sys::wc_curve448_shared_secret_ex(&mut private_key.wc_key,
+                &mut public_key.wc_key, out.as_mut_ptr(), &mut outlen,
+                endian as i32)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(outlen as usize)
+    }
+
+    /// Export public and private keys from X448 struct to raw buffers
+    /// (big-endian only).
+    ///
+    /// # Parameters
+    ///
+    /// * `private`: Buffer in which to store the raw private key.
+    /// * `public`: Buffer in which to store the raw public key.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn export_key_raw(&mut self, private: &mut [u8], public: &mut [u8]) -> Result<(), Error> {
+        self.export_key_raw_ex(private, public, true)
+    }
+
+    /// Export public and private keys from X448 struct to raw buffers
+    /// (big or little endian).
+    ///
+    /// # Parameters
+    ///
+    /// * `private`: Buffer in which to store the raw private key.
+    /// * `public`: Buffer in which to store the raw public key.
+    /// * `big_endian`: True for big-endian, false for little-endian.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn export_key_raw_ex(&mut self, private: &mut [u8], public: &mut [u8], big_endian: bool) -> Result<(), Error> {
+        let mut private_size = crate::buffer_len_to_u32(private.len())?;
+        let mut public_size = crate::buffer_len_to_u32(public.len())?;
+        let endian = if big_endian {sys::EC448_BIG_ENDIAN} else {sys::EC448_LITTLE_ENDIAN};
+        let rc = unsafe {
+            sys::wc_curve448_export_key_raw_ex(&mut self.wc_key,
+                private.as_mut_ptr(), &mut private_size,
+                public.as_mut_ptr(), &mut public_size, endian as i32)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Export private key from X448 struct to a raw buffer (big-endian only).
+    ///
+    /// # Parameters
+    ///
+    /// * `out`: Buffer in which to store the raw private key.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to `out`
+    /// on success or Err(e) containing the wolfSSL library error code value.
+    pub fn export_private_raw(&mut self, out: &mut [u8]) -> Result<usize, Error> {
+        self.export_private_raw_ex(out, true)
+    }
+
+    /// Export private key from X448 struct to a raw buffer (big or little
+    /// endian).
+    ///
+    /// # Parameters
+    ///
+    /// * `out`: Buffer in which to store the raw private key.
+    /// * `big_endian`: True for big-endian, false for little-endian.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to `out`
+    /// on success or Err(e) containing the wolfSSL library error code value.
+    pub fn export_private_raw_ex(&mut self, out: &mut [u8], big_endian: bool) -> Result<usize, Error> {
+        let mut outlen = crate::buffer_len_to_u32(out.len())?;
+        let endian = if big_endian {sys::EC448_BIG_ENDIAN} else {sys::EC448_LITTLE_ENDIAN};
+        let rc = unsafe {
+            sys::wc_curve448_export_private_raw_ex(&mut self.wc_key,
+                out.as_mut_ptr(), &mut outlen, endian as i32)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(outlen as usize)
+    }
+
+    /// Export public key from X448 struct to a raw buffer (big-endian only).
+    ///
+    /// The public key is computed from the private key if it has not been
+    /// set.
+    ///
+    /// # Parameters
+    ///
+    /// * `out`: Buffer in which to store the raw public key.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to `out`
+    /// on success or Err(e) containing the wolfSSL library error code value.
+    pub fn export_public(&mut self, out: &mut [u8]) -> Result<usize, Error> {
+        self.export_public_ex(out, true)
+    }
+
+    /// Export public key from X448 struct to a raw buffer (big or little
+    /// endian).
+    ///
+    /// The public key is computed from the private key if it has not been
+    /// set.
+    ///
+    /// # Parameters
+    ///
+    /// * `out`: Buffer in which to store the raw public key.
+    /// * `big_endian`: True for big-endian, false for little-endian.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to `out`
+    /// on success or Err(e) containing the wolfSSL library error code value.
+    pub fn export_public_ex(&mut self, out: &mut [u8], big_endian: bool) -> Result<usize, Error> {
+        let mut outlen = crate::buffer_len_to_u32(out.len())?;
+        let endian = if big_endian {sys::EC448_BIG_ENDIAN} else {sys::EC448_LITTLE_ENDIAN};
+        let rc = unsafe {
+            sys::wc_curve448_export_public_ex(&mut self.wc_key,
+                out.as_mut_ptr(), &mut outlen, endian as i32)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(outlen as usize)
+    }
+}
+
+impl X448 {
+    fn zeroize(&mut self) {
+        unsafe { crate::zeroize_raw(&mut self.wc_key); }
+    }
+}
+
+impl Drop for X448 {
+    /// Safely free the underlying wolfSSL curve448_key context.
+    ///
+    /// This calls the `wc_curve448_free` wolfssl library function.
+    ///
+    /// The Rust Drop trait guarantees that this method is called when the
+    /// struct goes out of scope, automatically cleaning up resources and
+    /// preventing memory leaks.
+    fn drop(&mut self) {
+        unsafe { sys::wc_curve448_free(&mut self.wc_key); }
+        self.zeroize();
+    }
+}
@@ -28,16 +28,22 @@ wolfSSL `DhKey` object. It ensures proper initialization and deallocation.
 
 #![cfg(dh)]
 
+use crate::Error;
+use crate::memory::HeapHint;
 use crate::sys;
+use core::marker::PhantomData;
 #[cfg(random)]
 use crate::random::RNG;
 use core::mem::{MaybeUninit};
 
-pub struct DH {
-    wc_dhkey: sys::DhKey,
+pub struct DH<'a> {
+    pub(crate) wc_dhkey: sys::DhKey,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 
-impl DH {
+impl<'a> DH<'a> {
     /// ffdhe2048 named parameter group.
     #[cfg(dh_ffdhe_2048)]
     pub const FFDHE_2048: i32 = sys::WC_FFDHE_2048 as i32;
@@ -78,8 +84,10 @@ impl DH {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::dh::DH;
-    /// let mut rng = RNG::new().expect("Failed to create RNG");
-    /// let mut dh = DH::new_named(DH::FFDHE_2048).expect("Error with new_named()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
+    /// let mut dh = DH::new_named(DH::FFDHE_2048, &wolfcrypt).expect("Error with new_named()");
     /// let mut private = [0u8; 256];
     /// let mut private_size = 0u32;
     /// let mut public = [0u8; 256];
@@ -97,7 +105,7 @@ impl DH {
     /// DH::check_pub_value(p, public).expect("Error with check_pub_value()");
     /// }
     /// ```
-    pub fn check_pub_value(prime: &[u8], public: &[u8]) -> Result<(), i32> {
+    pub fn check_pub_value(prime: &[u8], public: &[u8]) -> Result<(), Error> {
         let prime_size = crate::buffer_len_to_u32(prime.len())?;
         let public_size = crate::buffer_len_to_u32(public.len())?;
         let rc = unsafe {
@@ -105,7 +113,7 @@ impl DH {
                 public.as_ptr(), public_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -130,7 +138,9 @@ impl DH {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::dh::DH;
-    /// let mut dh = DH::new_named(DH::FFDHE_2048).expect("Error with new_named()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut dh = DH::new_named(DH::FFDHE_2048, &wolfcrypt).expect("Error with new_named()");
     /// let mut p = [0u8; 256];
     /// let mut q = [0u8; 256];
     /// let mut g = [0u8; 256];
@@ -176,6 +186,7 @@ impl DH {
     ///
     /// * `rng`: `RNG` struct instance to use for random number generation.
     /// * `modulus_size`: Modulus size in bits.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
@@ -189,13 +200,15 @@ impl DH {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::dh::DH;
-    /// let mut rng = RNG::new().expect("Error with RNG::new()");
-    /// let mut dh = DH::generate(&mut rng, 2048).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error with RNG::new()");
+    /// let mut dh = DH::generate(&mut rng, 2048, &wolfcrypt).expect("Error with generate()");
     /// }
     /// ```
     #[cfg(all(dh_keygen, random))]
-    pub fn generate(rng: &RNG, modulus_size: i32) -> Result<Self, i32> {
-        Self::generate_ex(rng, modulus_size, None, None)
+    pub fn generate(rng: &RNG, modulus_size: i32, wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::generate_ex(rng, modulus_size, &crate::Env::new(wolfcrypt))
     }
 
     /// Create a new DH context by generating parameters with optional heap and
@@ -205,8 +218,7 @@ impl DH {
     ///
     /// * `rng`: `RNG` struct instance to use for random number generation.
     /// * `modulus_size`: Modulus size in bits.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -218,34 +230,32 @@ impl DH {
     /// ```rust
     /// #[cfg(all(dh_keygen, random))]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::dh::DH;
-    /// let mut rng = RNG::new().expect("Error with RNG::new()");
-    /// let mut dh = DH::generate_ex(&mut rng, 2048, None, None).expect("Error with generate_ex()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error with RNG::new()");
+    /// let mut dh = DH::generate_ex(&mut rng, 2048, &Env::new(&wolfcrypt)).expect("Error with generate_ex()");
     /// }
     /// ```
     #[cfg(all(dh_keygen, random))]
-    pub fn generate_ex(rng: &RNG, modulus_size: i32, heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn generate_ex(rng: &RNG, modulus_size: i32, env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let mut wc_dhkey: MaybeUninit<sys::DhKey> = MaybeUninit::uninit();
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_InitDhKey_ex(wc_dhkey.as_mut_ptr(), heap, dev_id) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_dhkey = unsafe { wc_dhkey.assume_init() };
-        let mut dh = DH { wc_dhkey };
+        let mut dh = DH { wc_dhkey, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData };
         let rc = unsafe {
             sys::wc_DhGenerateParams(rng.wc_rng, modulus_size, &mut dh.wc_dhkey)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(dh)
     }
@@ -308,6 +318,7 @@ impl DH {
     /// # Parameters
     ///
     /// * `name`: DH parameters name, one of DH::FFDHE_*.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
@@ -321,11 +332,13 @@ impl DH {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::dh::DH;
-    /// let mut dh = DH::new_named(DH::FFDHE_2048).expect("Error with new_named()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut dh = DH::new_named(DH::FFDHE_2048, &wolfcrypt).expect("Error with new_named()");
     /// }
     /// ```
-    pub fn new_named(name: i32) -> Result<Self, i32> {
-        Self::new_named_ex(name, None, None)
+    pub fn new_named(name: i32, wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_named_ex(name, &crate::Env::new(wolfcrypt))
     }
 
     /// Create a new DH context using the named parameter set with optional
@@ -334,8 +347,7 @@ impl DH {
     /// # Parameters
     ///
     /// * `name`: DH parameters name, one of DH::FFDHE_*.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -347,40 +359,56 @@ impl DH {
     /// ```rust
     /// #[cfg(all(dh_ffdhe_2048, random))]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::dh::DH;
-    /// let mut dh = DH::new_named_ex(DH::FFDHE_2048, None, None).expect("Error with new_named_ex()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut dh = DH::new_named_ex(DH::FFDHE_2048, &Env::new(&wolfcrypt)).expect("Error with new_named_ex()");
     /// }
     /// ```
-    pub fn new_named_ex(name: i32, heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn new_named_ex(name: i32, env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let mut wc_dhkey: MaybeUninit<sys::DhKey> = MaybeUninit::uninit();
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_InitDhKey_ex(wc_dhkey.as_mut_ptr(), heap, dev_id) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_dhkey = unsafe { wc_dhkey.assume_init() };
-        let mut dh = DH { wc_dhkey };
+        let mut dh = DH { wc_dhkey, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData };
         let rc = unsafe { sys::wc_DhSetNamedKey(&mut dh.wc_dhkey, name) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(dh)
     }
 
+    /// Create a DH context without parameters, to be filled in by a DER
+    /// decoder.
+    #[cfg(dh_key_to_der)]
+    pub(crate) fn new_empty(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        let wolfcrypt = wolfcrypt.clone();
+        let mut wc_dhkey: MaybeUninit<sys::DhKey> = MaybeUninit::uninit();
+        let rc = unsafe {
+            sys::wc_InitDhKey_ex(wc_dhkey.as_mut_ptr(), core::ptr::null_mut(),
+                sys::INVALID_DEVID)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        let wc_dhkey = unsafe { wc_dhkey.assume_init() };
+        Ok(DH { wc_dhkey, _wolfcrypt: wolfcrypt, _device: Default::default(), _heap: PhantomData })
+    }
+
     /// Create a new DH context using the given p and g parameters.
     ///
     /// # Parameters
     ///
     /// * `p`: DH 'p' parameter value.
     /// * `g`: DH 'g' parameter value.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
@@ -394,6 +422,8 @@ impl DH {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::dh::DH;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let p = [
     ///     0xc5u8, 0x7c, 0xa2, 0x4f, 0x4b, 0xd6, 0x8c, 0x3c,
     ///     0xda, 0xc7, 0xba, 0xaa, 0xea, 0x2e, 0x5c, 0x1e,
@@ -462,11 +492,11 @@ impl DH {
     ///     0x75, 0x52, 0x9d, 0x42, 0x51, 0x78, 0x62, 0x68,
     ///     0x05, 0x45, 0x15, 0xf8, 0xa2, 0x4e, 0xf3, 0x0b
     /// ];
-    /// let dh = DH::new_from_pg(&p, &g).expect("Error with new_from_pg()");
+    /// let dh = DH::new_from_pg(&p, &g, &wolfcrypt).expect("Error with new_from_pg()");
     /// }
     /// ```
-    pub fn new_from_pg(p: &[u8], g: &[u8]) -> Result<Self, i32> {
-        Self::new_from_pg_ex(p, g, None, None)
+    pub fn new_from_pg(p: &[u8], g: &[u8], wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_from_pg_ex(p, g, &crate::Env::new(wolfcrypt))
     }
 
     /// Create a new DH context using the given p and g parameters with
@@ -476,8 +506,7 @@ impl DH {
     ///
     /// * `p`: DH 'p' parameter value.
     /// * `g`: DH 'g' parameter value.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -489,8 +518,11 @@ impl DH {
     /// ```rust
     /// #[cfg(random)]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::dh::DH;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let p = [
     ///     0xc5u8, 0x7c, 0xa2, 0x4f, 0x4b, 0xd6, 0x8c, 0x3c,
     ///     0xda, 0xc7, 0xba, 0xaa, 0xea, 0x2e, 0x5c, 0x1e,
@@ -559,32 +591,27 @@ impl DH {
     ///     0x75, 0x52, 0x9d, 0x42, 0x51, 0x78, 0x62, 0x68,
     ///     0x05, 0x45, 0x15, 0xf8, 0xa2, 0x4e, 0xf3, 0x0b
     /// ];
-    /// let dh = DH::new_from_pg_ex(&p, &g, None, None).expect("Error with new_from_pg_ex()");
+    /// let dh = DH::new_from_pg_ex(&p, &g, &Env::new(&wolfcrypt)).expect("Error with new_from_pg_ex()");
     /// }
     /// ```
-    pub fn new_from_pg_ex(p: &[u8], g: &[u8], heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn new_from_pg_ex(p: &[u8], g: &[u8], env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let p_size = crate::buffer_len_to_u32(p.len())?;
         let g_size = crate::buffer_len_to_u32(g.len())?;
         let mut wc_dhkey: MaybeUninit<sys::DhKey> = MaybeUninit::uninit();
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_InitDhKey_ex(wc_dhkey.as_mut_ptr(), heap, dev_id) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_dhkey = unsafe { wc_dhkey.assume_init() };
-        let mut dh = DH { wc_dhkey };
+        let mut dh = DH { wc_dhkey, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData };
         let rc = unsafe {
             sys::wc_DhSetKey(&mut dh.wc_dhkey, p.as_ptr(), p_size, g.as_ptr(), g_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(dh)
     }
@@ -596,6 +623,7 @@ impl DH {
     /// * `p`: DH 'p' parameter value.
     /// * `g`: DH 'g' parameter value.
     /// * `q`: DH 'q' parameter value.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
@@ -609,6 +637,8 @@ impl DH {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::dh::DH;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let p = [
     ///     0xc5u8, 0x7c, 0xa2, 0x4f, 0x4b, 0xd6, 0x8c, 0x3c,
     ///     0xda, 0xc7, 0xba, 0xaa, 0xea, 0x2e, 0x5c, 0x1e,
@@ -683,11 +713,11 @@ impl DH {
     ///     0xec, 0x24, 0x5d, 0x78, 0x59, 0xe7, 0x8d, 0xb5,
     ///     0x40, 0x52, 0xed, 0x41
     /// ];
-    /// let dh = DH::new_from_pgq(&p, &g, &q).expect("Error with new_from_pgq()");
+    /// let dh = DH::new_from_pgq(&p, &g, &q, &wolfcrypt).expect("Error with new_from_pgq()");
     /// }
     /// ```
-    pub fn new_from_pgq(p: &[u8], g: &[u8], q: &[u8]) -> Result<Self, i32> {
-        Self::new_from_pgq_ex(p, g, q, None, None)
+    pub fn new_from_pgq(p: &[u8], g: &[u8], q: &[u8], wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_from_pgq_ex(p, g, q, &crate::Env::new(wolfcrypt))
     }
 
     /// Create a new DH context using the given p, g, and q parameters with
@@ -698,8 +728,7 @@ impl DH {
     /// * `p`: DH 'p' parameter value.
     /// * `g`: DH 'g' parameter value.
     /// * `q`: DH 'q' parameter value.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -711,8 +740,11 @@ impl DH {
     /// ```rust
     /// #[cfg(random)]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::dh::DH;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let p = [
     ///     0xc5u8, 0x7c, 0xa2, 0x4f, 0x4b, 0xd6, 0x8c, 0x3c,
     ///     0xda, 0xc7, 0xba, 0xaa, 0xea, 0x2e, 0x5c, 0x1e,
@@ -787,33 +819,28 @@ impl DH {
     ///     0xec, 0x24, 0x5d, 0x78, 0x59, 0xe7, 0x8d, 0xb5,
     ///     0x40, 0x52, 0xed, 0x41
     /// ];
-    /// let dh = DH::new_from_pgq_ex(&p, &g, &q, None, None).expect("Error with new_from_pgq_ex()");
+    /// let dh = DH::new_from_pgq_ex(&p, &g, &q, &Env::new(&wolfcrypt)).expect("Error with new_from_pgq_ex()");
     /// }
     /// ```
-    pub fn new_from_pgq_ex(p: &[u8], g: &[u8], q: &[u8], heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn new_from_pgq_ex(p: &[u8], g: &[u8], q: &[u8], env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let p_size = crate::buffer_len_to_u32(p.len())?;
         let g_size = crate::buffer_len_to_u32(g.len())?;
         let q_size = crate::buffer_len_to_u32(q.len())?;
         let mut wc_dhkey: MaybeUninit<sys::DhKey> = MaybeUninit::uninit();
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_InitDhKey_ex(wc_dhkey.as_mut_ptr(), heap, dev_id) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_dhkey = unsafe { wc_dhkey.assume_init() };
-        let mut dh = DH { wc_dhkey };
+        let mut dh = DH { wc_dhkey, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData };
         let rc = unsafe {
             sys::wc_DhSetKey_ex(&mut dh.wc_dhkey, p.as_ptr(), p_size, g.as_ptr(), g_size, q.as_ptr(), q_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(dh)
     }
@@ -829,6 +856,7 @@ impl DH {
     /// * `trusted`: Whether to skip the prime check for `p` parameter and mark
     ///   the DH context as trusted.
     /// * `rng`: `RNG` instance to use for random number generation.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
@@ -842,6 +870,8 @@ impl DH {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::dh::DH;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let p = [
     ///     0xc5u8, 0x7c, 0xa2, 0x4f, 0x4b, 0xd6, 0x8c, 0x3c,
     ///     0xda, 0xc7, 0xba, 0xaa, 0xea, 0x2e, 0x5c, 0x1e,
@@ -916,13 +946,13 @@ impl DH {
     ///     0xec, 0x24, 0x5d, 0x78, 0x59, 0xe7, 0x8d, 0xb5,
     ///     0x40, 0x52, 0xed, 0x41
     /// ];
-    /// let mut rng = RNG::new().expect("Failed to create RNG");
-    /// let dh = DH::new_from_pgq_with_check(&p, &g, &q, 0, &mut rng).expect("Error with new_from_pgq_with_check()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
+    /// let dh = DH::new_from_pgq_with_check(&p, &g, &q, 0, &mut rng, &wolfcrypt).expect("Error with new_from_pgq_with_check()");
     /// }
     /// ```
     #[cfg(random)]
-    pub fn new_from_pgq_with_check(p: &[u8], g: &[u8], q: &[u8], trusted: i32, rng: &RNG) -> Result<Self, i32> {
-        Self::new_from_pgq_with_check_ex(p, g, q, trusted, rng, None, None)
+    pub fn new_from_pgq_with_check(p: &[u8], g: &[u8], q: &[u8], trusted: i32, rng: &RNG, wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_from_pgq_with_check_ex(p, g, q, trusted, rng, &crate::Env::new(wolfcrypt))
     }
 
     /// Create a new DH context using the given p, g, and q parameters with
@@ -936,8 +966,7 @@ impl DH {
     /// * `trusted`: Whether to skip the prime check for `p` parameter and mark
     ///   the DH context as trusted.
     /// * `rng`: `RNG` instance to use for random number generation.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -949,8 +978,11 @@ impl DH {
     /// ```rust
     /// #[cfg(random)]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::dh::DH;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let p = [
     ///     0xc5u8, 0x7c, 0xa2, 0x4f, 0x4b, 0xd6, 0x8c, 0x3c,
     ///     0xda, 0xc7, 0xba, 0xaa, 0xea, 0x2e, 0x5c, 0x1e,
@@ -1025,35 +1057,30 @@ impl DH {
     ///     0xec, 0x24, 0x5d, 0x78, 0x59, 0xe7, 0x8d, 0xb5,
     ///     0x40, 0x52, 0xed, 0x41
     /// ];
-    /// let mut rng = RNG::new().expect("Failed to create RNG");
-    /// let dh = DH::new_from_pgq_with_check_ex(&p, &g, &q, 0, &mut rng, None, None).expect("Error with new_from_pgq_with_check_ex()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
+    /// let dh = DH::new_from_pgq_with_check_ex(&p, &g, &q, 0, &mut rng, &Env::new(&wolfcrypt)).expect("Error with new_from_pgq_with_check_ex()");
     /// }
     /// ```
     #[cfg(random)]
-    pub fn new_from_pgq_with_check_ex(p: &[u8], g: &[u8], q: &[u8], trusted: i32, rng: &RNG, heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn new_from_pgq_with_check_ex(p: &[u8], g: &[u8], q: &[u8], trusted: i32, rng: &RNG, env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let p_size = crate::buffer_len_to_u32(p.len())?;
         let g_size = crate::buffer_len_to_u32(g.len())?;
         let q_size = crate::buffer_len_to_u32(q.len())?;
         let mut wc_dhkey: MaybeUninit<sys::DhKey> = MaybeUninit::uninit();
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_InitDhKey_ex(wc_dhkey.as_mut_ptr(), heap, dev_id) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_dhkey = unsafe { wc_dhkey.assume_init() };
-        let mut dh = DH { wc_dhkey };
+        let mut dh = DH { wc_dhkey, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData };
         let rc = unsafe {
             sys::wc_DhSetCheckKey(&mut dh.wc_dhkey, p.as_ptr(), p_size, g.as_ptr(), g_size, q.as_ptr(), q_size, trusted, rng.wc_rng)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(dh)
     }
@@ -1078,8 +1105,10 @@ impl DH {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::dh::DH;
-    /// let mut rng = RNG::new().expect("Error with RNG::new()");
-    /// let mut dh = DH::new_named(DH::FFDHE_2048).expect("Error with new_named()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error with RNG::new()");
+    /// let mut dh = DH::new_named(DH::FFDHE_2048, &wolfcrypt).expect("Error with new_named()");
     /// let mut private = [0u8; 256];
     /// let mut private_size = 0u32;
     /// let mut public = [0u8; 256];
@@ -1090,7 +1119,7 @@ impl DH {
     /// dh.check_key_pair(public, private).expect("Error with check_key_pair()");
     /// }
     /// ```
-    pub fn check_key_pair(&mut self, public: &[u8], private: &[u8]) -> Result<(), i32> {
+    pub fn check_key_pair(&mut self, public: &[u8], private: &[u8]) -> Result<(), Error> {
         let public_size = crate::buffer_len_to_u32(public.len())?;
         let private_size = crate::buffer_len_to_u32(private.len())?;
         let rc = unsafe {
@@ -1099,7 +1128,7 @@ impl DH {
                 private.as_ptr(), private_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1124,8 +1153,10 @@ impl DH {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::dh::DH;
-    /// let mut rng = RNG::new().expect("Error with RNG::new()");
-    /// let mut dh = DH::new_named(DH::FFDHE_2048).expect("Error with new_named()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error with RNG::new()");
+    /// let mut dh = DH::new_named(DH::FFDHE_2048, &wolfcrypt).expect("Error with new_named()");
     /// let mut private = [0u8; 256];
     /// let mut private_size = 0u32;
     /// let mut public = [0u8; 256];
@@ -1135,14 +1166,14 @@ impl DH {
     /// dh.check_priv_key(private).expect("Error with check_priv_key()");
     /// }
     /// ```
-    pub fn check_priv_key(&mut self, private: &[u8]) -> Result<(), i32> {
+    pub fn check_priv_key(&mut self, private: &[u8]) -> Result<(), Error> {
         let private_size = crate::buffer_len_to_u32(private.len())?;
         let rc = unsafe {
             sys::wc_DhCheckPrivKey(&mut self.wc_dhkey,
                 private.as_ptr(), private_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1170,6 +1201,8 @@ impl DH {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::dh::DH;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let p = [
     ///     0xc5u8, 0x7c, 0xa2, 0x4f, 0x4b, 0xd6, 0x8c, 0x3c,
     ///     0xda, 0xc7, 0xba, 0xaa, 0xea, 0x2e, 0x5c, 0x1e,
@@ -1244,8 +1277,8 @@ impl DH {
     ///     0xec, 0x24, 0x5d, 0x78, 0x59, 0xe7, 0x8d, 0xb5,
     ///     0x40, 0x52, 0xed, 0x41
     /// ];
-    /// let mut rng = RNG::new().expect("Error with RNG::new()");
-    /// let mut dh = DH::new_from_pgq_with_check(&p, &g, &q, 0, &mut rng).expect("Error with new_from_pgq()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error with RNG::new()");
+    /// let mut dh = DH::new_from_pgq_with_check(&p, &g, &q, 0, &mut rng, &wolfcrypt).expect("Error with new_from_pgq()");
     /// let mut private = [0u8; 256];
     /// let mut private_size = 0u32;
     /// let mut public = [0u8; 256];
@@ -1255,7 +1288,7 @@ impl DH {
     /// dh.check_priv_key_ex(private, Some(&q)).expect("Error with check_priv_key_ex()");
     /// }
     /// ```
-    pub fn check_priv_key_ex(&mut self, private: &[u8], prime: Option<&[u8]>) -> Result<(), i32> {
+    pub fn check_priv_key_ex(&mut self, private: &[u8], prime: Option<&[u8]>) -> Result<(), Error> {
         let private_size = crate::buffer_len_to_u32(private.len())?;
         let mut prime_ptr: *const u8 = core::ptr::null();
         let mut prime_size = 0u32;
@@ -1269,7 +1302,7 @@ impl DH {
                 prime_ptr, prime_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1294,8 +1327,10 @@ impl DH {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::dh::DH;
-    /// let mut rng = RNG::new().expect("Error with RNG::new()");
-    /// let mut dh = DH::new_named(DH::FFDHE_2048).expect("Error with new_named()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error with RNG::new()");
+    /// let mut dh = DH::new_named(DH::FFDHE_2048, &wolfcrypt).expect("Error with new_named()");
     /// let mut private = [0u8; 256];
     /// let mut private_size = 0u32;
     /// let mut public = [0u8; 256];
@@ -1305,13 +1340,13 @@ impl DH {
     /// dh.check_pub_key(public).expect("Error with check_pub_key()");
     /// }
     /// ```
-    pub fn check_pub_key(&mut self, public: &[u8]) -> Result<(), i32> {
+    pub fn check_pub_key(&mut self, public: &[u8]) -> Result<(), Error> {
         let public_size = crate::buffer_len_to_u32(public.len())?;
         let rc = unsafe {
             sys::wc_DhCheckPubKey(&mut self.wc_dhkey, public.as_ptr(), public_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1337,6 +1372,8 @@ impl DH {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::dh::DH;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let p = [
     ///     0xc5u8, 0x7c, 0xa2, 0x4f, 0x4b, 0xd6, 0x8c, 0x3c,
     ///     0xda, 0xc7, 0xba, 0xaa, 0xea, 0x2e, 0x5c, 0x1e,
@@ -1418,8 +1455,8 @@ impl DH {
     ///     0xec, 0x24, 0x5d, 0x78, 0x59, 0xe7, 0x8d, 0xb5,
     ///     0x40, 0x52, 0xed, 0x41
     /// ];
-    /// let mut rng = RNG::new().expect("Error with RNG::new()");
-    /// let mut dh = DH::new_from_pgq_with_check(&p, &g, &q, 0, &mut rng).expect("Error with new_from_pgq()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error with RNG::new()");
+    /// let mut dh = DH::new_from_pgq_with_check(&p, &g, &q, 0, &mut rng, &wolfcrypt).expect("Error with new_from_pgq()");
     /// let mut private = [0u8; 256];
     /// let mut private_size = 0u32;
     /// let mut public = [0u8; 256];
@@ -1429,7 +1466,7 @@ impl DH {
     /// dh.check_pub_key_ex(public, &q0).expect("Error with check_pub_key_ex()");
     /// }
     /// ```
-    pub fn check_pub_key_ex(&mut self, public: &[u8], prime: &[u8]) -> Result<(), i32> {
+    pub fn check_pub_key_ex(&mut self, public: &[u8], prime: &[u8]) -> Result<(), Error> {
         let public_size = crate::buffer_len_to_u32(public.len())?;
         let prime_size = crate::buffer_len_to_u32(prime.len())?;
         let rc = unsafe {
@@ -1438,7 +1475,7 @@ impl DH {
                 prime.as_ptr(), prime_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1460,12 +1497,14 @@ impl DH {
     /// #[cfg(dh_ffdhe_2048)]
     /// {
     /// use wolfssl_wolfcrypt::dh::DH;
-    /// let mut dh = DH::new_named(DH::FFDHE_2048).expect("Error with new_named()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut dh = DH::new_named(DH::FFDHE_2048, &wolfcrypt).expect("Error with new_named()");
     /// let prime_size = dh.prime_size().expect("Error with prime_size()");
     /// assert_eq!(prime_size, 256);
     /// }
     /// ```
-    pub fn prime_size(&mut self) -> Result<usize, i32> {
+    pub fn prime_size(&mut self) -> Result<usize, Error> {
         let mut p_size = 0u32;
         let mut q_size = 0u32;
         let mut g_size = 0u32;
@@ -1480,7 +1519,7 @@ impl DH {
                 core::ptr::null_mut(), &mut g_size)
         };
         if rc != sys::wolfCrypt_ErrorCodes_LENGTH_ONLY_E {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(p_size as usize)
     }
@@ -1503,7 +1542,7 @@ impl DH {
     pub fn export_params_raw(&mut self,
             p: &mut [u8], p_size: &mut u32,
             q: &mut [u8], q_size: &mut u32,
-            g: &mut [u8], g_size: &mut u32) -> Result<(), i32> {
+            g: &mut [u8], g_size: &mut u32) -> Result<(), Error> {
         *p_size = crate::buffer_len_to_u32(p.len())?;
         *q_size = crate::buffer_len_to_u32(q.len())?;
         *g_size = crate::buffer_len_to_u32(g.len())?;
@@ -1514,7 +1553,7 @@ impl DH {
                 g.as_mut_ptr(), g_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1541,8 +1580,10 @@ impl DH {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::dh::DH;
-    /// let mut rng = RNG::new().expect("Failed to create RNG");
-    /// let mut dh = DH::new_named(DH::FFDHE_2048).expect("Error with new_named()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
+    /// let mut dh = DH::new_named(DH::FFDHE_2048, &wolfcrypt).expect("Error with new_named()");
     /// let mut private = [0u8; 256];
     /// let mut private_size = 0u32;
     /// let mut public = [0u8; 256];
@@ -1553,7 +1594,7 @@ impl DH {
     #[cfg(random)]
     pub fn generate_key_pair(&mut self, rng: &RNG,
             private: &mut [u8], private_size: &mut u32,
-            public: &mut [u8], public_size: &mut u32) -> Result<(), i32> {
+            public: &mut [u8], public_size: &mut u32) -> Result<(), Error> {
         *private_size = crate::buffer_len_to_u32(private.len())?;
         *public_size = crate::buffer_len_to_u32(public.len())?;
         let rc = unsafe {
@@ -1562,7 +1603,87 @@ impl DH {
                 public.as_mut_ptr(), public_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Store a public/private key pair in this DH context.
+    ///
+    /// The key pair is only needed to DER encode the key, e.g. with
+    /// [`crate::pkcs8::Pkcs8Export`]. Either key may be empty to store only
+    /// the other one.
+    ///
+    /// # Parameters
+    ///
+    /// * `private`: Buffer containing the private key.
+    /// * `public`: Buffer containing the public key.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) or Err(e) containing the wolfSSL library error
+    /// code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(all(dh_ffdhe_2048, dh_key_to_der, random))]
+    /// {
+    /// use wolfssl_wolfcrypt::random::RNG;
+    /// use wolfssl_wolfcrypt::dh::DH;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
+    /// let mut dh = DH::new_named(DH::FFDHE_2048, &wolfcrypt).expect("Error with new_named()");
+    /// let mut private = [0u8; 256];
+    /// let mut private_size = 0u32;
+    /// let mut public = [0u8; 256];
+    /// let mut public_size = 0u32;
+    /// dh.generate_key_pair(&mut rng, &mut private, &mut private_size, &mut public, &mut public_size).expect("Error with generate_key_pair()");
+    /// dh.import_key_pair(&private[..private_size as usize], &public[..public_size as usize]).expect("Error with import_key_pair()");
+    /// }
+    /// ```
+    #[cfg(dh_key_to_der)]
+    pub fn import_key_pair(&mut self, private: &[u8], public: &[u8]) -> Result<(), Error> {
+        let private_size = crate::buffer_len_to_u32(private.len())?;
+        let public_size = crate::buffer_len_to_u32(public.len())?;
+        let rc = unsafe {
+            sys::wc_DhImportKeyPair(&mut self.wc_dhkey,
+                private.as_ptr(), private_size, public.as_ptr(), public_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Export the public/private key pair stored in this DH context, e.g.
+    /// after decoding a PKCS #8 DH private key.
+    ///
+    /// # Parameters
+    ///
+    /// * `private`: Buffer in which to store the private key.
+    /// * `private_size`: Output parameter storing the private key size in bytes.
+    /// * `public`: Buffer in which to store the public key.
+    /// * `public_size`: Output parameter storing the public key size in bytes.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) or Err(e) containing the wolfSSL library error
+    /// code value.
+    #[cfg(dh_key_to_der)]
+    pub fn export_key_pair(&mut self,
+            private: &mut [u8], private_size: &mut u32,
+            public: &mut [u8], public_size: &mut u32) -> Result<(), Error> {
+        *private_size = crate::buffer_len_to_u32(private.len())?;
+        *public_size = crate::buffer_len_to_u32(public.len())?;
+        let rc = unsafe {
+            sys::wc_DhExportKeyPair(&mut self.wc_dhkey,
+                private.as_mut_ptr(), private_size,
+                public.as_mut_ptr(), public_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1595,8 +1716,10 @@ impl DH {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::dh::DH;
-    /// let mut rng = RNG::new().expect("Error with RNG::new()");
-    /// let mut dh = DH::new_named(DH::FFDHE_2048).expect("Error with new_named()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error with RNG::new()");
+    /// let mut dh = DH::new_named(DH::FFDHE_2048, &wolfcrypt).expect("Error with new_named()");
     /// let mut private0 = [0u8; 256];
     /// let mut private0_size = 0u32;
     /// let mut public0 = [0u8; 256];
@@ -1612,11 +1735,11 @@ impl DH {
     /// let ss0 = &ss0[0..ss0_size];
     /// }
     /// ```
-    pub fn shared_secret(&mut self, dout: &mut [u8], private: &[u8], other_pub: &[u8]) -> Result<usize, i32> {
+    pub fn shared_secret(&mut self, dout: &mut [u8], private: &[u8], other_pub: &[u8]) -> Result<usize, Error> {
         /* wc_DhAgree() does not check the buffer size, so reject a short
          * buffer before the call to avoid a buffer overrun. */
         if dout.len() < self.prime_size()? {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let mut dout_size = crate::buffer_len_to_u32(dout.len())?;
         let private_size = crate::buffer_len_to_u32(private.len())?;
@@ -1628,19 +1751,19 @@ impl DH {
                 other_pub.as_ptr(), other_pub_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(dout_size as usize)
     }
 }
 
-impl DH {
+impl<'a> DH<'a> {
     fn zeroize(&mut self) {
         unsafe { crate::zeroize_raw(&mut self.wc_dhkey); }
     }
 }
 
-impl Drop for DH {
+impl Drop for DH<'_> {
     /// Safely free the underlying wolfSSL DhKey context.
     ///
     /// This calls the `wc_FreeDhKey()` wolfssl library function.
@@ -28,24 +28,29 @@ wolfSSL `ecc_key` object. It ensures proper initialization and deallocation.
 
 #![cfg(ecc)]
 
+use crate::Error;
+use crate::memory::HeapHint;
 use crate::sys;
+use core::marker::PhantomData;
 #[cfg(random)]
 use crate::random::{RNG, RngHandle};
 
 /// Rust wrapper for wolfSSL `ecc_point` object.
-pub struct ECCPoint {
+pub struct ECCPoint<'a> {
     wc_ecc_point: *mut sys::ecc_point,
     heap: *mut core::ffi::c_void,
+    _wolfcrypt: crate::WolfCrypt,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 
-impl ECCPoint {
+impl<'a> ECCPoint<'a> {
     /// Import an ECCPoint from a DER-formatted buffer.
     ///
     /// # Parameters
     ///
     /// * `din`: DER-formatted buffer.
     /// * `curve_id`: Curve ID, e.g. ECC::SECP256R1.
-    /// * `heap`: Optional heap hint.
+    /// * `env`: Heap hint to use. The device is not used.
     ///
     /// # Returns
     ///
@@ -57,40 +62,41 @@ impl ECCPoint {
     /// ```rust
     /// #[cfg(all(ecc_import, random))]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::{ECC,ECCPoint};
-    /// let rng = RNG::new().expect("Failed to create RNG");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
     /// let curve_id = ECC::SECP256R1;
     /// let curve_size = ECC::get_curve_size_from_id(curve_id).expect("Error with get_curve_size_from_id()");
-    /// let mut ecc = ECC::generate_ex(curve_size, &rng, curve_id, None, None).expect("Error with generate()");
-    /// let ecc_point = ecc.make_pub_to_point(Some(&rng), None).expect("Error with make_pub_to_point()");
+    /// let mut ecc = ECC::generate_ex(curve_size, &rng, curve_id, &Env::new(&wolfcrypt)).expect("Error with generate()");
+    /// let ecc_point = ecc.make_pub_to_point(Some(&rng), &Env::new(&wolfcrypt)).expect("Error with make_pub_to_point()");
     /// let mut der = [0u8; 128];
     /// let size = ecc_point.export_der(&mut der, curve_id).expect("Error with export_der()");
-    /// ECCPoint::import_der(&der[0..size], curve_id, None).expect("Error with import_der()");
+    /// ECCPoint::import_der(&der[0..size], curve_id, &Env::new(&wolfcrypt)).expect("Error with import_der()");
     /// }
     /// ```
     #[cfg(ecc_import)]
-    pub fn import_der(din: &[u8], curve_id: i32, heap: Option<*mut core::ffi::c_void>) -> Result<Self, i32> {
+    pub fn import_der(din: &[u8], curve_id: i32, env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let curve_idx = unsafe { sys::wc_ecc_get_curve_idx(curve_id) };
         if curve_idx < 0 {
-            return Err(curve_idx);
+            return Err(Error::from(curve_idx));
         }
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
+        let heap = env.heap_ptr();
         let wc_ecc_point = unsafe { sys::wc_ecc_new_point_h(heap) };
         if wc_ecc_point.is_null() {
-            return Err(sys::wolfCrypt_ErrorCodes_MEMORY_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_MEMORY_E));
         }
-        let eccpoint = ECCPoint { wc_ecc_point, heap };
+        let eccpoint = ECCPoint { wc_ecc_point, heap, _wolfcrypt: wolfcrypt, _heap: PhantomData };
         let din_size = crate::buffer_len_to_u32(din.len())?;
         let rc = unsafe {
             sys::wc_ecc_import_point_der(din.as_ptr(), din_size, curve_idx,
                 eccpoint.wc_ecc_point)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(eccpoint)
     }
@@ -103,7 +109,7 @@ impl ECCPoint {
     /// * `curve_id`: Curve ID, e.g. ECC::SECP256R1.
     /// * `short_key_size`: if shortKeySize != 0 then key size is always
     ///   (din.len() - 1) / 2.
-    /// * `heap`: Optional heap hint.
+    /// * `env`: Heap hint to use. The device is not used.
     ///
     /// # Returns
     ///
@@ -115,40 +121,41 @@ impl ECCPoint {
     /// ```rust
     /// #[cfg(all(ecc_import, ecc_export, ecc_comp_key, random))]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::{ECC,ECCPoint};
-    /// let rng = RNG::new().expect("Failed to create RNG");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
     /// let curve_id = ECC::SECP256R1;
     /// let curve_size = ECC::get_curve_size_from_id(curve_id).expect("Error with get_curve_size_from_id()");
-    /// let mut ecc = ECC::generate_ex(curve_size, &rng, curve_id, None, None).expect("Error with generate()");
-    /// let ecc_point = ecc.make_pub_to_point(Some(&rng), None).expect("Error with make_pub_to_point()");
+    /// let mut ecc = ECC::generate_ex(curve_size, &rng, curve_id, &Env::new(&wolfcrypt)).expect("Error with generate()");
+    /// let ecc_point = ecc.make_pub_to_point(Some(&rng), &Env::new(&wolfcrypt)).expect("Error with make_pub_to_point()");
     /// let mut der = [0u8; 128];
     /// let size = ecc_point.export_der(&mut der, curve_id).expect("Error with export_der()");
-    /// ECCPoint::import_der_ex(&der[0..size], curve_id, 0, None).expect("Error with import_der_ex()");
+    /// ECCPoint::import_der_ex(&der[0..size], curve_id, 0, &Env::new(&wolfcrypt)).expect("Error with import_der_ex()");
     /// }
     /// ```
     #[cfg(ecc_import)]
-    pub fn import_der_ex(din: &[u8], curve_id: i32, short_key_size: i32, heap: Option<*mut core::ffi::c_void>) -> Result<Self, i32> {
+    pub fn import_der_ex(din: &[u8], curve_id: i32, short_key_size: i32, env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let curve_idx = unsafe { sys::wc_ecc_get_curve_idx(curve_id) };
         if curve_idx < 0 {
-            return Err(curve_idx);
+            return Err(Error::from(curve_idx));
         }
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
+        let heap = env.heap_ptr();
         let wc_ecc_point = unsafe { sys::wc_ecc_new_point_h(heap) };
         if wc_ecc_point.is_null() {
-            return Err(sys::wolfCrypt_ErrorCodes_MEMORY_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_MEMORY_E));
         }
-        let eccpoint = ECCPoint { wc_ecc_point, heap };
+        let eccpoint = ECCPoint { wc_ecc_point, heap, _wolfcrypt: wolfcrypt, _heap: PhantomData };
         let din_size = crate::buffer_len_to_u32(din.len())?;
         let rc = unsafe {
             sys::wc_ecc_import_point_der_ex(din.as_ptr(), din_size, curve_idx,
                 wc_ecc_point, short_key_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(eccpoint)
     }
@@ -170,24 +177,27 @@ impl ECCPoint {
     /// ```rust
     /// #[cfg(all(ecc_export, random))]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::{ECC,ECCPoint};
-    /// let rng = RNG::new().expect("Failed to create RNG");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
     /// let curve_id = ECC::SECP256R1;
     /// let curve_size = ECC::get_curve_size_from_id(curve_id).expect("Error with get_curve_size_from_id()");
-    /// let mut ecc = ECC::generate_ex(curve_size, &rng, curve_id, None, None).expect("Error with generate()");
-    /// let ecc_point = ecc.make_pub_to_point(Some(&rng), None).expect("Error with make_pub_to_point()");
+    /// let mut ecc = ECC::generate_ex(curve_size, &rng, curve_id, &Env::new(&wolfcrypt)).expect("Error with generate()");
+    /// let ecc_point = ecc.make_pub_to_point(Some(&rng), &Env::new(&wolfcrypt)).expect("Error with make_pub_to_point()");
     /// let mut der = [0u8; 128];
     /// let size = ecc_point.export_der(&mut der, curve_id).expect("Error with export_der()");
     /// assert!(size > 0 && size <= der.len());
-    /// ECCPoint::import_der(&der[0..size], curve_id, None).expect("Error with import_der()");
+    /// ECCPoint::import_der(&der[0..size], curve_id, &Env::new(&wolfcrypt)).expect("Error with import_der()");
     /// }
     /// ```
     #[cfg(ecc_export)]
-    pub fn export_der(&self, dout: &mut [u8], curve_id: i32) -> Result<usize, i32> {
+    pub fn export_der(&self, dout: &mut [u8], curve_id: i32) -> Result<usize, Error> {
         let curve_idx = unsafe { sys::wc_ecc_get_curve_idx(curve_id) };
         if curve_idx < 0 {
-            return Err(curve_idx);
+            return Err(Error::from(curve_idx));
         }
         let mut dout_size = crate::buffer_len_to_u32(dout.len())?;
         let rc = unsafe {
@@ -195,7 +205,7 @@ impl ECCPoint {
                 dout.as_mut_ptr(), &mut dout_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(dout_size as usize)
     }
@@ -217,22 +227,25 @@ impl ECCPoint {
     /// ```rust
     /// #[cfg(all(ecc_export, ecc_comp_key, random))]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::{ECC,ECCPoint};
-    /// let rng = RNG::new().expect("Failed to create RNG");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
     /// let curve_id = ECC::SECP256R1;
     /// let curve_size = ECC::get_curve_size_from_id(curve_id).expect("Error with get_curve_size_from_id()");
-    /// let mut ecc = ECC::generate_ex(curve_size, &rng, curve_id, None, None).expect("Error with generate()");
-    /// let ecc_point = ecc.make_pub_to_point(Some(&rng), None).expect("Error with make_pub_to_point()");
+    /// let mut ecc = ECC::generate_ex(curve_size, &rng, curve_id, &Env::new(&wolfcrypt)).expect("Error with generate()");
+    /// let ecc_point = ecc.make_pub_to_point(Some(&rng), &Env::new(&wolfcrypt)).expect("Error with make_pub_to_point()");
     /// let mut der = [0u8; 128];
     /// let size = ecc_point.export_der_compressed(&mut der, curve_id).expect("Error with export_der_compressed()");
     /// }
     /// ```
     #[cfg(all(ecc_export, ecc_comp_key))]
-    pub fn export_der_compressed(&self, dout: &mut [u8], curve_id: i32) -> Result<usize, i32> {
+    pub fn export_der_compressed(&self, dout: &mut [u8], curve_id: i32) -> Result<usize, Error> {
         let curve_idx = unsafe { sys::wc_ecc_get_curve_idx(curve_id) };
         if curve_idx < 0 {
-            return Err(curve_idx);
+            return Err(Error::from(curve_idx));
         }
         let mut dout_size = crate::buffer_len_to_u32(dout.len())?;
         let rc = unsafe {
@@ -240,7 +253,7 @@ impl ECCPoint {
                 dout.as_mut_ptr(), &mut dout_size, 1)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(dout_size as usize)
     }
@@ -253,11 +266,14 @@ impl ECCPoint {
     /// # extern crate std;
     /// #[cfg(random)]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::ECC;
-    /// let rng = RNG::new().expect("Failed to create RNG");
-    /// let mut ecc = ECC::generate(32, &rng, None, None).expect("Error with generate()");
-    /// let mut ecc_point = ecc.make_pub_to_point(Some(&rng), None).expect("Error with make_pub_to_point()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
+    /// let mut ecc = ECC::generate(32, &rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
+    /// let mut ecc_point = ecc.make_pub_to_point(Some(&rng), &Env::new(&wolfcrypt)).expect("Error with make_pub_to_point()");
     /// ecc_point.forcezero();
     /// }
     /// ```
@@ -266,14 +282,14 @@ impl ECCPoint {
     }
 }
 
-impl ECCPoint {
+impl<'a> ECCPoint<'a> {
     fn zeroize(&mut self) {
         self.wc_ecc_point = core::ptr::null_mut();
         self.heap = core::ptr::null_mut();
     }
 }
 
-impl Drop for ECCPoint {
+impl Drop for ECCPoint<'_> {
     /// Safely free the underlying wolfSSL ecc_point context.
     ///
     /// This calls the `wc_ecc_del_point_h()` wolfssl library function.
@@ -295,16 +311,19 @@ impl Drop for ECCPoint {
 /// An instance can be created with `generate()`, `import_x963()`,
 /// `import_x963_ex()`, `import_private_key()`, `import_private_key_ex()`,
 /// `import_raw()`, or `import_raw_ex()`.
-pub struct ECC {
+pub struct ECC<'a> {
     pub(crate) wc_ecc_key: *mut sys::ecc_key,
     /// RNG bound to this key via `set_rng`, kept alive for as long as the C
     /// struct holds its pointer.
     #[cfg(random)]
-    rng: Option<RngHandle>,
+    rng: Option<RngHandle<'a>>,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 
 #[cfg(ecc_curve_ids)]
-impl ECC {
+impl<'a> ECC<'a> {
     pub const CURVE_INVALID: i32 = sys::ecc_curve_ids_ECC_CURVE_INVALID;
     pub const CURVE_DEF: i32 = sys::ecc_curve_ids_ECC_CURVE_DEF;
     pub const SECP192R1: i32 = sys::ecc_curve_ids_ECC_SECP192R1;
@@ -348,7 +367,7 @@ impl ECC {
 }
 
 #[cfg(not(ecc_curve_ids))]
-impl ECC {
+impl<'a> ECC<'a> {
     pub const CURVE_INVALID: i32 = sys::ecc_curve_id_ECC_CURVE_INVALID;
     pub const CURVE_DEF: i32 = sys::ecc_curve_id_ECC_CURVE_DEF;
     pub const SECP192R1: i32 = sys::ecc_curve_id_ECC_SECP192R1;
@@ -391,16 +410,16 @@ impl ECC {
     pub const CURVE_MAX: i32 = sys::ecc_curve_id_ECC_CURVE_MAX;
 }
 
-impl ECC {
+impl<'a> ECC<'a> {
     pub const FLAG_NONE: i32 = sys::WC_ECC_FLAG_NONE as i32;
     pub const FLAG_COFACTOR: i32 = sys::WC_ECC_FLAG_COFACTOR as i32;
     pub const FLAG_DEC_SIGN: i32 = sys::WC_ECC_FLAG_DEC_SIGN as i32;
 
     /// Allocate and initialize a new `sys::ecc_key` on the C heap.
-    fn new_ecc_key(heap: *mut core::ffi::c_void, dev_id: i32) -> Result<*mut sys::ecc_key, i32> {
+    fn new_ecc_key(heap: *mut core::ffi::c_void, dev_id: i32) -> Result<*mut sys::ecc_key, Error> {
         let key = unsafe { sys::wc_ecc_key_new(heap) };
         if key.is_null() {
-            return Err(sys::wolfCrypt_ErrorCodes_MEMORY_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_MEMORY_E));
         }
         // wc_ecc_key_new() always initializes the key with INVALID_DEVID.
         // Calling wc_ecc_init_ex() a second time to install the user's dev_id
@@ -422,8 +441,7 @@ impl ECC {
     /// * `size`: Desired key length in bytes.
     /// * `rng`: Reference to a `RNG` struct to use for random number
     ///   generation while making the key.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -436,28 +454,35 @@ impl ECC {
     /// # extern crate std;
     /// #[cfg(random)]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::ECC;
-    /// let rng = RNG::new().expect("Failed to create RNG");
-    /// let mut ecc = ECC::generate(32, &rng, None, None).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
+    /// let mut ecc = ECC::generate(32, &rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
     /// ecc.check().expect("Error with check()");
     /// }
     /// ```
     #[cfg(random)]
-    pub fn generate(size: i32, rng: &RNG, heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
-        let heap = heap.unwrap_or(core::ptr::null_mut());
-        let dev_id = dev_id.unwrap_or(sys::INVALID_DEVID);
+    pub fn generate(size: i32, rng: &RNG, env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let wc_ecc_key = Self::new_ecc_key(heap, dev_id)?;
         let ecc = ECC {
             wc_ecc_key,
             #[cfg(random)]
             rng: None,
+            _wolfcrypt: wolfcrypt,
+            _device: env.device_ref(),
+            _heap: PhantomData,
         };
         let rc = unsafe {
             sys::wc_ecc_make_key(rng.wc_rng, size, ecc.wc_ecc_key)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(ecc)
     }
@@ -470,8 +495,7 @@ impl ECC {
     /// * `rng`: Reference to a `RNG` struct to use for random number
     ///   generation while making the key.
     /// * `curve_id`: Curve ID, e.g. ECC::SECP256R1.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -484,30 +508,37 @@ impl ECC {
     /// # extern crate std;
     /// #[cfg(random)]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::ECC;
-    /// let rng = RNG::new().expect("Failed to create RNG");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
     /// let curve_id = ECC::SECP256R1;
     /// let curve_size = ECC::get_curve_size_from_id(curve_id).expect("Error with get_curve_size_from_id()");
-    /// let mut ecc = ECC::generate_ex(curve_size, &rng, curve_id, None, None).expect("Error with generate_ex()");
+    /// let mut ecc = ECC::generate_ex(curve_size, &rng, curve_id, &Env::new(&wolfcrypt)).expect("Error with generate_ex()");
     /// ecc.check().expect("Error with check()");
     /// }
     /// ```
     #[cfg(random)]
-    pub fn generate_ex(size: i32, rng: &RNG, curve_id: i32, heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
-        let heap = heap.unwrap_or(core::ptr::null_mut());
-        let dev_id = dev_id.unwrap_or(sys::INVALID_DEVID);
+    pub fn generate_ex(size: i32, rng: &RNG, curve_id: i32, env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let wc_ecc_key = Self::new_ecc_key(heap, dev_id)?;
         let ecc = ECC {
             wc_ecc_key,
             #[cfg(random)]
             rng: None,
+            _wolfcrypt: wolfcrypt,
+            _device: env.device_ref(),
+            _heap: PhantomData,
         };
         let rc = unsafe {
             sys::wc_ecc_make_key_ex(rng.wc_rng, size, ecc.wc_ecc_key, curve_id)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(ecc)
     }
@@ -521,8 +552,7 @@ impl ECC {
     ///   generation while making the key.
     /// * `curve_id`: Curve ID, e.g. ECC::SECP256R1.
     /// * `flags`: Flags for making the key.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -535,30 +565,37 @@ impl ECC {
     /// # extern crate std;
     /// #[cfg(random)]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::ECC;
-    /// let rng = RNG::new().expect("Failed to create RNG");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
     /// let curve_id = ECC::SECP256R1;
     /// let curve_size = ECC::get_curve_size_from_id(curve_id).expect("Error with get_curve_size_from_id()");
-    /// let mut ecc = ECC::generate_ex2(curve_size, &rng, curve_id, ECC::FLAG_COFACTOR, None, None).expect("Error with generate_ex2()");
+    /// let mut ecc = ECC::generate_ex2(curve_size, &rng, curve_id, ECC::FLAG_COFACTOR, &Env::new(&wolfcrypt)).expect("Error with generate_ex2()");
     /// ecc.check().expect("Error with check()");
     /// }
     /// ```
     #[cfg(random)]
-    pub fn generate_ex2(size: i32, rng: &RNG, curve_id: i32, flags: i32, heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
-        let heap = heap.unwrap_or(core::ptr::null_mut());
-        let dev_id = dev_id.unwrap_or(sys::INVALID_DEVID);
+    pub fn generate_ex2(size: i32, rng: &RNG, curve_id: i32, flags: i32, env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let wc_ecc_key = Self::new_ecc_key(heap, dev_id)?;
         let ecc = ECC {
             wc_ecc_key,
             #[cfg(random)]
             rng: None,
+            _wolfcrypt: wolfcrypt,
+            _device: env.device_ref(),
+            _heap: PhantomData,
         };
         let rc = unsafe {
             sys::wc_ecc_make_key_ex2(rng.wc_rng, size, ecc.wc_ecc_key, curve_id, flags)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(ecc)
     }
@@ -580,19 +617,22 @@ impl ECC {
     /// # extern crate std;
     /// #[cfg(random)]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::ECC;
-    /// let rng = RNG::new().expect("Failed to create RNG");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
     /// let curve_id = ECC::SECP256R1;
     /// let curve_size = ECC::get_curve_size_from_id(curve_id).expect("Error with get_curve_size_from_id()");
-    /// let mut ecc = ECC::generate_ex(curve_size, &rng, curve_id, None, None).expect("Error with generate()");
+    /// let mut ecc = ECC::generate_ex(curve_size, &rng, curve_id, &Env::new(&wolfcrypt)).expect("Error with generate()");
     /// ecc.check().expect("Error with check()");
     /// }
     /// ```
-    pub fn get_curve_size_from_id(curve_id: i32) -> Result<i32, i32> {
+    pub fn get_curve_size_from_id(curve_id: i32) -> Result<i32, Error> {
         let rc = unsafe { sys::wc_ecc_get_curve_size_from_id(curve_id) };
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(rc)
     }
@@ -602,8 +642,7 @@ impl ECC {
     /// # Parameters
     ///
     /// * `der`: DER buffer containing the ECC public and private key pair.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -616,23 +655,30 @@ impl ECC {
     /// # extern crate std;
     /// #[cfg(random)]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::ECC;
     /// use std::fs;
-    /// let rng = RNG::new().expect("Failed to create RNG");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
     /// let key_path = "../../../certs/ecc-client-key.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut ecc = ECC::import_der(&der, None, None).expect("Error with import_der()");
+    /// let mut ecc = ECC::import_der(&der, &Env::new(&wolfcrypt)).expect("Error with import_der()");
     /// }
     /// ```
-    pub fn import_der(der: &[u8], heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
-        let heap = heap.unwrap_or(core::ptr::null_mut());
-        let dev_id = dev_id.unwrap_or(sys::INVALID_DEVID);
+    pub fn import_der(der: &[u8], env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let wc_ecc_key = Self::new_ecc_key(heap, dev_id)?;
         let ecc = ECC {
             wc_ecc_key,
             #[cfg(random)]
             rng: None,
+            _wolfcrypt: wolfcrypt,
+            _device: env.device_ref(),
+            _heap: PhantomData,
         };
         let mut idx = 0u32;
         let der_size = crate::buffer_len_to_u32(der.len())?;
@@ -640,7 +686,7 @@ impl ECC {
             sys::wc_EccPrivateKeyDecode(der.as_ptr(), &mut idx, ecc.wc_ecc_key, der_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(ecc)
     }
@@ -650,8 +696,7 @@ impl ECC {
     /// # Parameters
     ///
     /// * `der`: DER buffer containing the ECC public key.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -664,13 +709,16 @@ impl ECC {
     /// # extern crate std;
     /// #[cfg(random)]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::ECC;
     /// use std::fs;
-    /// let rng = RNG::new().expect("Failed to create RNG");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
     /// let key_path = "../../../certs/ecc-client-key.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut ecc = ECC::import_der(&der, None, None).expect("Error with import_der()");
+    /// let mut ecc = ECC::import_der(&der, &Env::new(&wolfcrypt)).expect("Error with import_der()");
     /// let hash = [0x42u8; 32];
     /// let mut signature = [0u8; 128];
     /// let signature_length = ecc.sign_hash(&hash, &mut signature, &rng).expect("Error with sign_hash()");
@@ -678,17 +726,21 @@ impl ECC {
     /// let signature = &mut signature[0..signature_length];
     /// let key_path = "../../../certs/ecc-client-keyPub.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut ecc = ECC::import_public_der(&der, None, None).expect("Error with import_public_der()");
+    /// let mut ecc = ECC::import_public_der(&der, &Env::new(&wolfcrypt)).expect("Error with import_public_der()");
     /// }
     /// ```
-    pub fn import_public_der(der: &[u8], heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
-        let heap = heap.unwrap_or(core::ptr::null_mut());
-        let dev_id = dev_id.unwrap_or(sys::INVALID_DEVID);
+    pub fn import_public_der(der: &[u8], env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let wc_ecc_key = Self::new_ecc_key(heap, dev_id)?;
         let ecc = ECC {
             wc_ecc_key,
             #[cfg(random)]
             rng: None,
+            _wolfcrypt: wolfcrypt,
+            _device: env.device_ref(),
+            _heap: PhantomData,
         };
         let mut idx = 0u32;
         let der_size = crate::buffer_len_to_u32(der.len())?;
@@ -696,7 +748,7 @@ impl ECC {
             sys::wc_EccPublicKeyDecode(der.as_ptr(), &mut idx, ecc.wc_ecc_key, der_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(ecc)
     }
@@ -711,8 +763,7 @@ impl ECC {
     ///
     /// * `priv_buf`: Buffer containing the raw private key.
     /// * `pub_buf`: Buffer containing the ANSI X9.63 formatted public key.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -724,10 +775,13 @@ impl ECC {
     /// ```rust
     /// #[cfg(all(ecc_import, random))]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::ECC;
-    /// let rng = RNG::new().expect("Failed to create RNG");
-    /// let mut ecc = ECC::generate(32, &rng, None, None).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
+    /// let mut ecc = ECC::generate(32, &rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
     /// let hash = [0x42u8; 32];
     /// let mut signature = [0u8; 128];
     /// let signature_length = ecc.sign_hash(&hash, &mut signature, &rng).expect("Error with sign_hash()");
@@ -737,20 +791,24 @@ impl ECC {
     /// let mut x963 = [0u8; 128];
     /// let x963_size = ecc.export_x963(&mut x963).expect("Error with export_x963()");
     /// let x963 = &x963[0..x963_size];
-    /// let mut ecc2 = ECC::import_private_key(&d, x963, None, None).expect("Error with import_private_key()");
+    /// let mut ecc2 = ECC::import_private_key(&d, x963, &Env::new(&wolfcrypt)).expect("Error with import_private_key()");
     /// let valid = ecc2.verify_hash(&signature, &hash).expect("Error with verify_hash()");
     /// assert_eq!(valid, true);
     /// }
     /// ```
     #[cfg(ecc_import)]
-    pub fn import_private_key(priv_buf: &[u8], pub_buf: &[u8], heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
-        let heap = heap.unwrap_or(core::ptr::null_mut());
-        let dev_id = dev_id.unwrap_or(sys::INVALID_DEVID);
+    pub fn import_private_key(priv_buf: &[u8], pub_buf: &[u8], env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let wc_ecc_key = Self::new_ecc_key(heap, dev_id)?;
         let ecc = ECC {
             wc_ecc_key,
             #[cfg(random)]
             rng: None,
+            _wolfcrypt: wolfcrypt,
+            _device: env.device_ref(),
+            _heap: PhantomData,
         };
         let priv_size = crate::buffer_len_to_u32(priv_buf.len())?;
         let pub_ptr = if pub_buf.is_empty() {core::ptr::null()} else {pub_buf.as_ptr()};
@@ -760,7 +818,7 @@ impl ECC {
                 pub_ptr, pub_size, ecc.wc_ecc_key)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(ecc)
     }
@@ -776,8 +834,7 @@ impl ECC {
     /// * `priv_buf`: Buffer containing the raw private key.
     /// * `pub_buf`: Buffer containing the ANSI X9.63 formatted public key.
     /// * `curve_id`: Curve ID, e.g. ECC::SECP256R1.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -789,12 +846,15 @@ impl ECC {
     /// ```rust
     /// #[cfg(all(ecc_import, random))]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::ECC;
-    /// let rng = RNG::new().expect("Failed to create RNG");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
     /// let curve_id = ECC::SECP256R1;
     /// let curve_size = ECC::get_curve_size_from_id(curve_id).expect("Error with get_curve_size_from_id()");
-    /// let mut ecc = ECC::generate_ex(curve_size, &rng, curve_id, None, None).expect("Error with generate_ex()");
+    /// let mut ecc = ECC::generate_ex(curve_size, &rng, curve_id, &Env::new(&wolfcrypt)).expect("Error with generate_ex()");
     /// let hash = [0x42u8; 32];
     /// let mut signature = [0u8; 128];
     /// let signature_length = ecc.sign_hash(&hash, &mut signature, &rng).expect("Error with sign_hash()");
@@ -804,20 +864,24 @@ impl ECC {
     /// let mut x963 = [0u8; 128];
     /// let x963_size = ecc.export_x963(&mut x963).expect("Error with export_x963()");
     /// let x963 = &x963[0..x963_size];
-    /// let mut ecc2 = ECC::import_private_key_ex(&d, x963, curve_id, None, None).expect("Error with import_private_key_ex()");
+    /// let mut ecc2 = ECC::import_private_key_ex(&d, x963, curve_id, &Env::new(&wolfcrypt)).expect("Error with import_private_key_ex()");
     /// let valid = ecc2.verify_hash(&signature, &hash).expect("Error with verify_hash()");
     /// assert_eq!(valid, true);
     /// }
     /// ```
     #[cfg(ecc_import)]
-    pub fn import_private_key_ex(priv_buf: &[u8], pub_buf: &[u8], curve_id: i32, heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
-        let heap = heap.unwrap_or(core::ptr::null_mut());
-        let dev_id = dev_id.unwrap_or(sys::INVALID_DEVID);
+    pub fn import_private_key_ex(priv_buf: &[u8], pub_buf: &[u8], curve_id: i32, env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let wc_ecc_key = Self::new_ecc_key(heap, dev_id)?;
         let ecc = ECC {
             wc_ecc_key,
             #[cfg(random)]
             rng: None,
+            _wolfcrypt: wolfcrypt,
+            _device: env.device_ref(),
+            _heap: PhantomData,
         };
         let priv_size = crate::buffer_len_to_u32(priv_buf.len())?;
         let pub_ptr = if pub_buf.is_empty() {core::ptr::null()} else {pub_buf.as_ptr()};
@@ -827,7 +891,7 @@ impl ECC {
                 pub_ptr, pub_size, ecc.wc_ecc_key, curve_id)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(ecc)
     }
@@ -841,8 +905,7 @@ impl ECC {
     /// * `qy`: Y component of public key as null terminated ASCII hex string.
     /// * `d`: Private key as null terminated ASCII hex string.
     /// * `curve_name`: Null terminated ASCII string containing the curve name.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -854,26 +917,33 @@ impl ECC {
     /// ```rust
     /// #[cfg(ecc_import)]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::ecc::ECC;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let qx = b"7a4e287890a1a47ad3457e52f2f76a83ce46cbc947616d0cbaa82323818a793d\0";
     /// let qy = b"eec4084f5b29ebf29c44cce3b3059610922f8b30ea6e8811742ac7238fe87308\0";
     /// let d  = b"8c14b793cb19137e323a6d2e2a870bca2e7a493ec1153b3a95feb8a4873f8d08\0";
-    /// ECC::import_raw(qx, qy, d, b"SECP256R1\0", None, None).expect("Error with import_raw()");
+    /// ECC::import_raw(qx, qy, d, b"SECP256R1\0", &Env::new(&wolfcrypt)).expect("Error with import_raw()");
     /// }
     /// ```
     #[cfg(ecc_import)]
-    pub fn import_raw(qx: &[u8], qy: &[u8], d: &[u8], curve_name: &[u8], heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn import_raw(qx: &[u8], qy: &[u8], d: &[u8], curve_name: &[u8], env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         if qx.is_empty() || qy.is_empty() || d.is_empty() || curve_name.is_empty() ||
             qx[qx.len() - 1] != 0 || qy[qy.len() - 1] != 0 || d[d.len() - 1] != 0 || curve_name[curve_name.len() - 1] != 0 {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
-        let heap = heap.unwrap_or(core::ptr::null_mut());
-        let dev_id = dev_id.unwrap_or(sys::INVALID_DEVID);
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let wc_ecc_key = Self::new_ecc_key(heap, dev_id)?;
         let ecc = ECC {
             wc_ecc_key,
             #[cfg(random)]
             rng: None,
+            _wolfcrypt: wolfcrypt,
+            _device: env.device_ref(),
+            _heap: PhantomData,
         };
         let qx_ptr = qx.as_ptr() as *const core::ffi::c_char;
         let qy_ptr = qy.as_ptr() as *const core::ffi::c_char;
@@ -884,7 +954,7 @@ impl ECC {
                 curve_name_ptr)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(ecc)
     }
@@ -898,8 +968,7 @@ impl ECC {
     /// * `qy`: Y component of public key as null terminated ASCII hex string.
     /// * `d`: Private key as null terminated ASCII hex string.
     /// * `curve_id`: Curve ID, e.g. ECC::SECP256R1.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -911,26 +980,33 @@ impl ECC {
     /// ```rust
     /// #[cfg(ecc_import)]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::ecc::ECC;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let qx = b"7a4e287890a1a47ad3457e52f2f76a83ce46cbc947616d0cbaa82323818a793d\0";
     /// let qy = b"eec4084f5b29ebf29c44cce3b3059610922f8b30ea6e8811742ac7238fe87308\0";
     /// let d  = b"8c14b793cb19137e323a6d2e2a870bca2e7a493ec1153b3a95feb8a4873f8d08\0";
-    /// ECC::import_raw_ex(qx, qy, d, ECC::SECP256R1, None, None).expect("Error with import_raw_ex()");
+    /// ECC::import_raw_ex(qx, qy, d, ECC::SECP256R1, &Env::new(&wolfcrypt)).expect("Error with import_raw_ex()");
     /// }
     /// ```
     #[cfg(ecc_import)]
-    pub fn import_raw_ex(qx: &[u8], qy: &[u8], d: &[u8], curve_id: i32, heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn import_raw_ex(qx: &[u8], qy: &[u8], d: &[u8], curve_id: i32, env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         if qx.is_empty() || qy.is_empty() || d.is_empty() ||
             qx[qx.len() - 1] != 0 || qy[qy.len() - 1] != 0 || d[d.len() - 1] != 0 {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
-        let heap = heap.unwrap_or(core::ptr::null_mut());
-        let dev_id = dev_id.unwrap_or(sys::INVALID_DEVID);
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let wc_ecc_key = Self::new_ecc_key(heap, dev_id)?;
         let ecc = ECC {
             wc_ecc_key,
             #[cfg(random)]
             rng: None,
+            _wolfcrypt: wolfcrypt,
+            _device: env.device_ref(),
+            _heap: PhantomData,
         };
         let qx_ptr = qx.as_ptr() as *const core::ffi::c_char;
         let qy_ptr = qy.as_ptr() as *const core::ffi::c_char;
@@ -940,7 +1016,7 @@ impl ECC {
                 d_ptr, curve_id)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(ecc)
     }
@@ -954,8 +1030,7 @@ impl ECC {
     /// * `qy`: Y component of public key in binary unsigned integer format.
     /// * `d`: Private key in binary unsigned integer format.
     /// * `curve_id`: Curve ID, e.g. ECC::SECP256R1.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -967,12 +1042,15 @@ impl ECC {
     /// ```rust
     /// #[cfg(all(ecc_import, random))]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::ECC;
-    /// let rng = RNG::new().expect("Failed to create RNG");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
     /// let curve_id = ECC::SECP256R1;
     /// let curve_size = ECC::get_curve_size_from_id(curve_id).expect("Error with get_curve_size_from_id()");
-    /// let mut ecc = ECC::generate_ex(curve_size, &rng, curve_id, None, None).expect("Error with generate()");
+    /// let mut ecc = ECC::generate_ex(curve_size, &rng, curve_id, &Env::new(&wolfcrypt)).expect("Error with generate()");
     /// let mut qx = [0u8; 32];
     /// let mut qx_len = 0u32;
     /// let mut qy = [0u8; 32];
@@ -980,29 +1058,151 @@ impl ECC {
     /// let mut d = [0u8; 32];
     /// let mut d_len = 0u32;
     /// ecc.export_ex(&mut qx, &mut qx_len, &mut qy, &mut qy_len, &mut d, &mut d_len, false).expect("Error with export_ex()");
-    /// let mut ecc2 = ECC::import_unsigned(&qx, &qy, &d, curve_id, None, None).expect("Error with import_unsigned()");
+    /// let mut ecc2 = ECC::import_unsigned(&qx, &qy, &d, curve_id, &Env::new(&wolfcrypt)).expect("Error with import_unsigned()");
     /// }
     /// ```
     #[cfg(ecc_import)]
-    pub fn import_unsigned(qx: &[u8], qy: &[u8], d: &[u8], curve_id: i32, heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn import_unsigned(qx: &[u8], qy: &[u8], d: &[u8], curve_id: i32, env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let curve_size = Self::get_curve_size_from_id(curve_id)? as usize;
         if qx.len() < curve_size || qy.len() < curve_size || d.len() < curve_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
-        let heap = heap.unwrap_or(core::ptr::null_mut());
-        let dev_id = dev_id.unwrap_or(sys::INVALID_DEVID);
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let wc_ecc_key = Self::new_ecc_key(heap, dev_id)?;
         let ecc = ECC {
             wc_ecc_key,
             #[cfg(random)]
             rng: None,
+            _wolfcrypt: wolfcrypt,
+            _device: env.device_ref(),
+            _heap: PhantomData,
         };
         let rc = unsafe {
             sys::wc_ecc_import_unsigned(ecc.wc_ecc_key, qx.as_ptr(),
                 qy.as_ptr(), d.as_ptr(), curve_id)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
+        }
+        Ok(ecc)
+    }
+
+    /// Create an ECC key referring to a private key held by a crypto callback
+    /// device (such as a PKCS#11 token) under the given key ID.
+    ///
+    /// The key material is not loaded; operations on the key are performed
+    /// by the device registered with `dev_id`, which locates the key by ID.
+    ///
+    /// # Parameters
+    ///
+    /// * `id`: Key identifier on the device (at most 32 bytes).
+    /// * `curve_id`: Curve ID of the key, e.g. `ECC::SECP256R1`.
+    /// * `env`: Heap hint and crypto callback device to use.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(ECC) containing the ECC struct instance or Err(e)
+    /// containing the wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(ecc_key_id)]
+    /// {
+    /// use wolfssl_wolfcrypt::Env;
+    /// use wolfssl_wolfcrypt::ecc::ECC;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let ecc = ECC::new_from_id(b"\x01\x02", ECC::SECP256R1, &Env::new(&wolfcrypt)).expect("Error with new_from_id()");
+    /// }
+    /// ```
+    #[cfg(ecc_key_id)]
+    pub fn new_from_id(id: &[u8], curve_id: i32, env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
+        let wc_ecc_key = Self::new_ecc_key(heap, dev_id)?;
+        let ecc = ECC {
+            wc_ecc_key,
+            #[cfg(random)]
+            rng: None,
+            _wolfcrypt: wolfcrypt,
+            _device: env.device_ref(),
+            _heap: PhantomData,
+        };
+        // As with the device ID in new_ecc_key(), set the ID in place rather
+        // than initializing the key a second time with wc_ecc_init_id().
+        let key = unsafe { &mut *ecc.wc_ecc_key };
+        if id.len() > key.id.len() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        key.id[..id.len()].copy_from_slice(id);
+        key.idLen = id.len() as i32;
+        let rc = unsafe { sys::wc_ecc_set_curve(ecc.wc_ecc_key, 0, curve_id) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(ecc)
+    }
+
+    /// Create an ECC key referring to a private key held by a crypto callback
+    /// device (such as a PKCS#11 token) under the given label.
+    ///
+    /// The key material is not loaded; operations on the key are performed
+    /// by the device registered with `dev_id`, which locates the key by
+    /// label.
+    ///
+    /// # Parameters
+    ///
+    /// * `label`: Key label on the device (1 to 32 bytes).
+    /// * `curve_id`: Curve ID of the key, e.g. `ECC::SECP256R1`.
+    /// * `env`: Heap hint and crypto callback device to use.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(ECC) containing the ECC struct instance or Err(e)
+    /// containing the wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(ecc_key_id)]
+    /// {
+    /// use wolfssl_wolfcrypt::Env;
+    /// use wolfssl_wolfcrypt::ecc::ECC;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let ecc = ECC::new_from_label("signing-key", ECC::SECP256R1, &Env::new(&wolfcrypt)).expect("Error with new_from_label()");
+    /// }
+    /// ```
+    #[cfg(ecc_key_id)]
+    pub fn new_from_label(label: &str, curve_id: i32, env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
+        let wc_ecc_key = Self::new_ecc_key(heap, dev_id)?;
+        let ecc = ECC {
+            wc_ecc_key,
+            #[cfg(random)]
+            rng: None,
+            _wolfcrypt: wolfcrypt,
+            _device: env.device_ref(),
+            _heap: PhantomData,
+        };
+        let key = unsafe { &mut *ecc.wc_ecc_key };
+        let label = label.as_bytes();
+        if label.is_empty() || label.len() > key.label.len() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        for (d, s) in key.label.iter_mut().zip(label) {
+            *d = *s as core::ffi::c_char;
+        }
+        key.labelLen = label.len() as i32;
+        let rc = unsafe { sys::wc_ecc_set_curve(ecc.wc_ecc_key, 0, curve_id) };
+        if rc != 0 {
+            return Err(Error::from(rc));
         }
         Ok(ecc)
     }
@@ -1015,8 +1215,7 @@ impl ECC {
     /// # Parameters
     ///
     /// * `din`: Buffer containing the ECC key encoded in ANSI X9.63 format.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -1028,32 +1227,39 @@ impl ECC {
     /// ```rust
     /// #[cfg(all(ecc_import, random))]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::ECC;
-    /// let rng = RNG::new().expect("Failed to create RNG");
-    /// let mut ecc = ECC::generate(32, &rng, None, None).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
+    /// let mut ecc = ECC::generate(32, &rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
     /// let mut x963 = [0u8; 128];
     /// let x963_size = ecc.export_x963(&mut x963).expect("Error with export_x963()");
     /// let x963 = &x963[0..x963_size];
-    /// let _ecc2 = ECC::import_x963(x963, None, None).expect("Error with import_x963()");
+    /// let _ecc2 = ECC::import_x963(x963, &Env::new(&wolfcrypt)).expect("Error with import_x963()");
     /// }
     /// ```
     #[cfg(ecc_import)]
-    pub fn import_x963(din: &[u8], heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<ECC, i32> {
+    pub fn import_x963(din: &[u8], env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let din_size = crate::buffer_len_to_u32(din.len())?;
-        let heap = heap.unwrap_or(core::ptr::null_mut());
-        let dev_id = dev_id.unwrap_or(sys::INVALID_DEVID);
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let wc_ecc_key = Self::new_ecc_key(heap, dev_id)?;
         let ecc = ECC {
             wc_ecc_key,
             #[cfg(random)]
             rng: None,
+            _wolfcrypt: wolfcrypt,
+            _device: env.device_ref(),
+            _heap: PhantomData,
         };
         let rc = unsafe {
             sys::wc_ecc_import_x963(din.as_ptr(), din_size, ecc.wc_ecc_key)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(ecc)
     }
@@ -1069,8 +1275,7 @@ impl ECC {
     ///
     /// * `din`: Buffer containing the ECC key encoded in ANSI X9.63 format.
     /// * `curve_id`: Curve ID, e.g. ECC::SECP256R1.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -1082,34 +1287,41 @@ impl ECC {
     /// ```rust
     /// #[cfg(all(ecc_import, random))]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::ECC;
-    /// let rng = RNG::new().expect("Failed to create RNG");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
     /// let curve_id = ECC::SECP256R1;
     /// let curve_size = ECC::get_curve_size_from_id(curve_id).expect("Error with get_curve_size_from_id()");
-    /// let mut ecc = ECC::generate_ex(curve_size, &rng, curve_id, None, None).expect("Error with generate_ex()");
+    /// let mut ecc = ECC::generate_ex(curve_size, &rng, curve_id, &Env::new(&wolfcrypt)).expect("Error with generate_ex()");
     /// let mut x963 = [0u8; 128];
     /// let x963_size = ecc.export_x963(&mut x963).expect("Error with export_x963()");
     /// let x963 = &x963[0..x963_size];
-    /// let _ecc2 = ECC::import_x963_ex(x963, curve_id, None, None).expect("Error with import_x963_ex()");
+    /// let _ecc2 = ECC::import_x963_ex(x963, curve_id, &Env::new(&wolfcrypt)).expect("Error with import_x963_ex()");
     /// }
     /// ```
     #[cfg(ecc_import)]
-    pub fn import_x963_ex(din: &[u8], curve_id: i32, heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<ECC, i32> {
+    pub fn import_x963_ex(din: &[u8], curve_id: i32, env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let din_size = crate::buffer_len_to_u32(din.len())?;
-        let heap = heap.unwrap_or(core::ptr::null_mut());
-        let dev_id = dev_id.unwrap_or(sys::INVALID_DEVID);
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let wc_ecc_key = Self::new_ecc_key(heap, dev_id)?;
         let ecc = ECC {
             wc_ecc_key,
             #[cfg(random)]
             rng: None,
+            _wolfcrypt: wolfcrypt,
+            _device: env.device_ref(),
+            _heap: PhantomData,
         };
         let rc = unsafe {
             sys::wc_ecc_import_x963_ex(din.as_ptr(), din_size, ecc.wc_ecc_key, curve_id)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(ecc)
     }
@@ -1137,8 +1349,11 @@ impl ECC {
     /// #[cfg(random)]
     /// {
     /// use std::fs;
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::ECC;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// fn bytes_to_asciiz_hex_string(bytes: &[u8]) -> String {
     ///     let mut hex_string = String::with_capacity(bytes.len() * 2 + 1);
     ///     for byte in bytes {
@@ -1147,10 +1362,10 @@ impl ECC {
     ///     hex_string.push('\0');
     ///     hex_string
     /// }
-    /// let rng = RNG::new().expect("Failed to create RNG");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
     /// let key_path = "../../../certs/ecc-client-key.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut ecc = ECC::import_der(&der, None, None).expect("Error with import_der()");
+    /// let mut ecc = ECC::import_der(&der, &Env::new(&wolfcrypt)).expect("Error with import_der()");
     /// let hash = [0x42u8; 32];
     /// let mut signature = [0u8; 128];
     /// let signature_length = ecc.sign_hash(&hash, &mut signature, &rng).expect("Error with sign_hash()");
@@ -1169,9 +1384,9 @@ impl ECC {
     /// assert_eq!(*signature, *&sig_out[0..sig_out_size]);
     /// }
     /// ```
-    pub fn rs_hex_to_sig(r: &[u8], s: &[u8], dout: &mut [u8]) -> Result<usize, i32> {
+    pub fn rs_hex_to_sig(r: &[u8], s: &[u8], dout: &mut [u8]) -> Result<usize, Error> {
         if r.is_empty() || s.is_empty() || r[r.len() - 1] != 0 || s[s.len() - 1] != 0 {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let mut dout_size = crate::buffer_len_to_u32(dout.len())?;
         let r_ptr = r.as_ptr() as *const core::ffi::c_char;
@@ -1181,7 +1396,7 @@ impl ECC {
                 &mut dout_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(dout_size as usize)
     }
@@ -1207,12 +1422,15 @@ impl ECC {
     /// #[cfg(random)]
     /// {
     /// use std::fs;
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::ECC;
-    /// let rng = RNG::new().expect("Failed to create RNG");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
     /// let key_path = "../../../certs/ecc-client-key.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut ecc = ECC::import_der(&der, None, None).expect("Error with import_der()");
+    /// let mut ecc = ECC::import_der(&der, &Env::new(&wolfcrypt)).expect("Error with import_der()");
     /// let hash = [0x42u8; 32];
     /// let mut signature = [0u8; 128];
     /// let signature_length = ecc.sign_hash(&hash, &mut signature, &rng).expect("Error with sign_hash()");
@@ -1229,7 +1447,7 @@ impl ECC {
     /// assert_eq!(*signature, *&sig_out[0..sig_out_size]);
     /// }
     /// ```
-    pub fn rs_bin_to_sig(r: &[u8], s: &[u8], dout: &mut [u8]) -> Result<usize, i32> {
+    pub fn rs_bin_to_sig(r: &[u8], s: &[u8], dout: &mut [u8]) -> Result<usize, Error> {
         let r_size = crate::buffer_len_to_u32(r.len())?;
         let s_size = crate::buffer_len_to_u32(s.len())?;
         let mut dout_size = crate::buffer_len_to_u32(dout.len())?;
@@ -1238,7 +1456,7 @@ impl ECC {
                 dout.as_mut_ptr(), &mut dout_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(dout_size as usize)
     }
@@ -1260,12 +1478,15 @@ impl ECC {
     /// #[cfg(random)]
     /// {
     /// use std::fs;
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::ECC;
-    /// let rng = RNG::new().expect("Failed to create RNG");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
     /// let key_path = "../../../certs/ecc-client-key.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut ecc = ECC::import_der(&der, None, None).expect("Error with import_der()");
+    /// let mut ecc = ECC::import_der(&der, &Env::new(&wolfcrypt)).expect("Error with import_der()");
     /// let hash = [0x42u8; 32];
     /// let mut signature = [0u8; 128];
     /// let signature_length = ecc.sign_hash(&hash, &mut signature, &rng).expect("Error with sign_hash()");
@@ -1282,7 +1503,7 @@ impl ECC {
     /// assert_eq!(*signature, *&sig_out[0..sig_out_size]);
     /// }
     /// ```
-    pub fn sig_to_rs(sig: &[u8], r: &mut [u8], r_size: &mut u32, s: &mut [u8], s_size: &mut u32) -> Result<(), i32> {
+    pub fn sig_to_rs(sig: &[u8], r: &mut [u8], r_size: &mut u32, s: &mut [u8], s_size: &mut u32) -> Result<(), Error> {
         let sig_len = crate::buffer_len_to_u32(sig.len())?;
         *r_size = crate::buffer_len_to_u32(r.len())?;
         *s_size = crate::buffer_len_to_u32(s.len())?;
@@ -1291,7 +1512,7 @@ impl ECC {
                 r.as_mut_ptr(), r_size, s.as_mut_ptr(), s_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1309,17 +1530,20 @@ impl ECC {
     /// # extern crate std;
     /// #[cfg(random)]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::ECC;
-    /// let rng = RNG::new().expect("Failed to create RNG");
-    /// let mut ecc = ECC::generate(32, &rng, None, None).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
+    /// let mut ecc = ECC::generate(32, &rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
     /// ecc.check().expect("Error with check()");
     /// }
     /// ```
-    pub fn check(&mut self) -> Result<(), i32> {
+    pub fn check(&mut self) -> Result<(), Error> {
         let rc = unsafe { sys::wc_ecc_check_key(self.wc_ecc_key) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1345,10 +1569,13 @@ impl ECC {
     /// ```rust
     /// #[cfg(all(ecc_import, random))]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::ECC;
-    /// let rng = RNG::new().expect("Failed to create RNG");
-    /// let mut ecc = ECC::generate(32, &rng, None, None).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
+    /// let mut ecc = ECC::generate(32, &rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
     /// let mut qx = [0u8; 32];
     /// let mut qx_len = 0u32;
     /// let mut qy = [0u8; 32];
@@ -1360,7 +1587,7 @@ impl ECC {
     /// ```
     #[cfg(ecc_import)]
     pub fn export(&mut self, qx: &mut [u8], qx_len: &mut u32,
-            qy: &mut [u8], qy_len: &mut u32, d: &mut [u8], d_len: &mut u32) -> Result<(), i32> {
+            qy: &mut [u8], qy_len: &mut u32, d: &mut [u8], d_len: &mut u32) -> Result<(), Error> {
         *qx_len = crate::buffer_len_to_u32(qx.len())?;
         *qy_len = crate::buffer_len_to_u32(qy.len())?;
         *d_len = crate::buffer_len_to_u32(d.len())?;
@@ -1371,7 +1598,7 @@ impl ECC {
                 d.as_mut_ptr(), d_len)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1400,10 +1627,13 @@ impl ECC {
     /// ```rust
     /// #[cfg(all(ecc_import, random))]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::ECC;
-    /// let rng = RNG::new().expect("Failed to create RNG");
-    /// let mut ecc = ECC::generate(32, &rng, None, None).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
+    /// let mut ecc = ECC::generate(32, &rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
     /// let mut qx = [0u8; 32];
     /// let mut qx_len = 0u32;
     /// let mut qy = [0u8; 32];
@@ -1417,7 +1647,7 @@ impl ECC {
     #[allow(clippy::too_many_arguments)]
     pub fn export_ex(&mut self, qx: &mut [u8], qx_len: &mut u32,
             qy: &mut [u8], qy_len: &mut u32, d: &mut [u8], d_len: &mut u32,
-            hex: bool) -> Result<(), i32> {
+            hex: bool) -> Result<(), Error> {
         *qx_len = crate::buffer_len_to_u32(qx.len())?;
         *qy_len = crate::buffer_len_to_u32(qy.len())?;
         *d_len = crate::buffer_len_to_u32(d.len())?;
@@ -1435,7 +1665,7 @@ impl ECC {
                 enc_type)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1456,24 +1686,27 @@ impl ECC {
     /// ```rust
     /// #[cfg(all(ecc_export, random))]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::ECC;
-    /// let rng = RNG::new().expect("Failed to create RNG");
-    /// let mut ecc = ECC::generate(32, &rng, None, None).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
+    /// let mut ecc = ECC::generate(32, &rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
     /// let mut d = [0u8; 32];
     /// let d_size = ecc.export_private(&mut d).expect("Error with export_private()");
     /// assert_eq!(d_size, 32);
     /// }
     /// ```
     #[cfg(ecc_export)]
-    pub fn export_private(&mut self, d: &mut [u8]) -> Result<usize, i32> {
+    pub fn export_private(&mut self, d: &mut [u8]) -> Result<usize, Error> {
         let mut d_size = crate::buffer_len_to_u32(d.len())?;
         let rc = unsafe {
             sys::wc_ecc_export_private_only(self.wc_ecc_key,
                 d.as_mut_ptr(), &mut d_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(d_size as usize)
     }
@@ -1497,10 +1730,13 @@ impl ECC {
     /// ```rust
     /// #[cfg(all(ecc_export, random))]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::ECC;
-    /// let rng = RNG::new().expect("Failed to create RNG");
-    /// let mut ecc = ECC::generate(32, &rng, None, None).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
+    /// let mut ecc = ECC::generate(32, &rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
     /// let mut qx = [0u8; 32];
     /// let mut qx_len = 0u32;
     /// let mut qy = [0u8; 32];
@@ -1510,7 +1746,7 @@ impl ECC {
     /// ```
     #[cfg(ecc_export)]
     pub fn export_public(&mut self, qx: &mut [u8], qx_len: &mut u32,
-            qy: &mut [u8], qy_len: &mut u32) -> Result<(), i32> {
+            qy: &mut [u8], qy_len: &mut u32) -> Result<(), Error> {
         *qx_len = crate::buffer_len_to_u32(qx.len())?;
         *qy_len = crate::buffer_len_to_u32(qy.len())?;
         let rc = unsafe {
@@ -1519,7 +1755,7 @@ impl ECC {
                 qy.as_mut_ptr(), qy_len)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1540,22 +1776,25 @@ impl ECC {
     /// ```rust
     /// #[cfg(all(ecc_export, random))]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::ECC;
-    /// let rng = RNG::new().expect("Failed to create RNG");
-    /// let mut ecc = ECC::generate(32, &rng, None, None).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
+    /// let mut ecc = ECC::generate(32, &rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
     /// let mut x963 = [0u8; 128];
     /// let _x963_size = ecc.export_x963(&mut x963).expect("Error with export_x963()");
     /// }
     /// ```
     #[cfg(ecc_export)]
-    pub fn export_x963(&mut self, dout: &mut [u8]) -> Result<usize, i32> {
+    pub fn export_x963(&mut self, dout: &mut [u8]) -> Result<usize, Error> {
         let mut out_len = crate::buffer_len_to_u32(dout.len())?;
         let rc = unsafe {
             sys::wc_ecc_export_x963(self.wc_ecc_key, dout.as_mut_ptr(), &mut out_len)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(out_len as usize)
     }
@@ -1576,22 +1815,25 @@ impl ECC {
     /// ```rust
     /// #[cfg(all(ecc_export, ecc_comp_key, random))]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::ECC;
-    /// let rng = RNG::new().expect("Failed to create RNG");
-    /// let mut ecc = ECC::generate(32, &rng, None, None).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
+    /// let mut ecc = ECC::generate(32, &rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
     /// let mut x963 = [0u8; 128];
     /// let _x963_size = ecc.export_x963_compressed(&mut x963).expect("Error with export_x963_compressed()");
     /// }
     /// ```
     #[cfg(all(ecc_export, ecc_comp_key))]
-    pub fn export_x963_compressed(&mut self, dout: &mut [u8]) -> Result<usize, i32> {
+    pub fn export_x963_compressed(&mut self, dout: &mut [u8]) -> Result<usize, Error> {
         let mut out_len = crate::buffer_len_to_u32(dout.len())?;
         let rc = unsafe {
             sys::wc_ecc_export_x963_ex(self.wc_ecc_key, dout.as_mut_ptr(), &mut out_len, 1)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(out_len as usize)
     }
@@ -1615,17 +1857,20 @@ impl ECC {
     /// #[cfg(random)]
     /// {
     /// use std::fs;
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::ECC;
-    /// let rng = RNG::new().expect("Failed to create RNG");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
     /// let key_path = "../../../certs/ecc-client-key.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut ecc = ECC::import_der(&der, None, None).expect("Error with import_der()");
+    /// let mut ecc = ECC::import_der(&der, &Env::new(&wolfcrypt)).expect("Error with import_der()");
     /// ecc.make_pub(Some(&rng)).expect("Error with make_pub()");
     /// }
     /// ```
     #[cfg(random)]
-    pub fn make_pub(&mut self, rng: Option<&RNG>) -> Result<(), i32> {
+    pub fn make_pub(&mut self, rng: Option<&RNG>) -> Result<(), Error> {
         let rng_ptr = match rng {
             Some(rng) => rng.wc_rng,
             None => core::ptr::null_mut(),
@@ -1634,7 +1879,7 @@ impl ECC {
             sys::wc_ecc_make_pub_ex(self.wc_ecc_key, core::ptr::null_mut(), rng_ptr)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1645,7 +1890,7 @@ impl ECC {
     ///
     /// * `rng`: RNG struct used to blind the private key value used in the
     ///   computation.
-    /// * `heap`: Optional heap hint.
+    /// * `env`: Heap hint to use. The device is not used.
     ///
     /// # Returns
     ///
@@ -1659,35 +1904,36 @@ impl ECC {
     /// #[cfg(random)]
     /// {
     /// use std::fs;
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::ECC;
-    /// let rng = RNG::new().expect("Failed to create RNG");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
     /// let key_path = "../../../certs/ecc-client-key.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut ecc = ECC::import_der(&der, None, None).expect("Error with import_der()");
-    /// ecc.make_pub_to_point(Some(&rng), None).expect("Error with make_pub_to_point()");
+    /// let mut ecc = ECC::import_der(&der, &Env::new(&wolfcrypt)).expect("Error with import_der()");
+    /// ecc.make_pub_to_point(Some(&rng), &Env::new(&wolfcrypt)).expect("Error with make_pub_to_point()");
     /// }
     /// ```
     #[cfg(random)]
-    pub fn make_pub_to_point(&mut self, rng: Option<&RNG>, heap: Option<*mut core::ffi::c_void>) -> Result<ECCPoint, i32> {
+    pub fn make_pub_to_point<'b>(&mut self, rng: Option<&RNG>, env: &crate::Env<'_, 'b>) -> Result<ECCPoint<'b>, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let rng_ptr = match rng {
             Some(rng) => rng.wc_rng,
             None => core::ptr::null_mut(),
         };
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
+        let heap = env.heap_ptr();
         let wc_ecc_point = unsafe { sys::wc_ecc_new_point_h(heap) };
         if wc_ecc_point.is_null() {
-            return Err(sys::wolfCrypt_ErrorCodes_MEMORY_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_MEMORY_E));
         }
-        let ecc_point = ECCPoint { wc_ecc_point, heap };
+        let ecc_point = ECCPoint { wc_ecc_point, heap, _wolfcrypt: wolfcrypt, _heap: PhantomData };
         let rc = unsafe {
             sys::wc_ecc_make_pub_ex(self.wc_ecc_key, wc_ecc_point, rng_ptr)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(ecc_point)
     }
@@ -1713,22 +1959,25 @@ impl ECC {
     /// # extern crate std;
     /// #[cfg(random)]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::ECC;
-    /// let blinding_rng = RNG::new().expect("Failed to create RNG");
-    /// let key_gen_rng = RNG::new().expect("Failed to create RNG");
-    /// let mut ecc = ECC::generate(32, &key_gen_rng, None, None).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let blinding_rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
+    /// let key_gen_rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
+    /// let mut ecc = ECC::generate(32, &key_gen_rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
     /// ecc.set_rng(blinding_rng).expect("Error with set_rng()");
     /// }
     /// ```
     #[cfg(random)]
-    pub fn set_rng(&mut self, rng: RNG) -> Result<(), i32> {
+    pub fn set_rng(&mut self, rng: RNG<'a>) -> Result<(), Error> {
         let wc_rng = rng.wc_rng;
         let rc = unsafe {
             sys::wc_ecc_set_rng(self.wc_ecc_key, wc_rng)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         self.rng = Some(RngHandle::Owned(rng));
         Ok(())
@@ -1737,13 +1986,13 @@ impl ECC {
     /// Bind a shared `RNG` to this key. Available when the `alloc` feature
     /// is enabled.
     #[cfg(all(random, feature = "alloc"))]
-    pub fn set_shared_rng(&mut self, rng: alloc::rc::Rc<RNG>) -> Result<(), i32> {
+    pub fn set_shared_rng(&mut self, rng: alloc::rc::Rc<RNG<'a>>) -> Result<(), Error> {
         let wc_rng = rng.wc_rng;
         let rc = unsafe {
             sys::wc_ecc_set_rng(self.wc_ecc_key, wc_rng)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         self.rng = Some(RngHandle::Shared(rng));
         Ok(())
@@ -1751,7 +2000,7 @@ impl ECC {
 
     /// Borrow the RNG previously bound via `set_rng` or `set_shared_rng`.
     #[cfg(random)]
-    pub fn rng(&self) -> Option<&RNG> {
+    pub fn rng(&self) -> Option<&RNG<'a>> {
         match &self.rng {
             Some(RngHandle::Owned(rng)) => Some(rng),
             #[cfg(feature = "alloc")]
@@ -1779,11 +2028,14 @@ impl ECC {
     /// #[cfg(all(ecc_dh, random, feature = "alloc"))]
     /// {
     /// use std::rc::Rc;
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::ECC;
-    /// let rng = Rc::new(RNG::new().expect("Failed to create RNG"));
-    /// let mut ecc0 = ECC::generate(32, &rng, None, None).expect("Error with generate()");
-    /// let mut ecc1 = ECC::generate(32, &rng, None, None).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = Rc::new(RNG::new(&wolfcrypt).expect("Failed to create RNG"));
+    /// let mut ecc0 = ECC::generate(32, &rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
+    /// let mut ecc1 = ECC::generate(32, &rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
     /// let mut ss0 = [0u8; 128];
     /// let mut ss1 = [0u8; 128];
     /// ecc0.set_shared_rng(Rc::clone(&rng)).expect("Error with set_shared_rng()");
@@ -1797,14 +2049,14 @@ impl ECC {
     /// }
     /// ```
     #[cfg(ecc_dh)]
-    pub fn shared_secret(&mut self, peer_key: &mut ECC, dout: &mut [u8]) -> Result<usize, i32> {
+    pub fn shared_secret(&mut self, peer_key: &mut ECC, dout: &mut [u8]) -> Result<usize, Error> {
         let mut out_len = crate::buffer_len_to_u32(dout.len())?;
         let rc = unsafe {
             sys::wc_ecc_shared_secret(self.wc_ecc_key,
                 peer_key.wc_ecc_key, dout.as_mut_ptr(), &mut out_len)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(out_len as usize)
     }
@@ -1829,12 +2081,15 @@ impl ECC {
     /// #[cfg(all(ecc_dh, random, feature = "alloc"))]
     /// {
     /// use std::rc::Rc;
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::ECC;
-    /// let rng = Rc::new(RNG::new().expect("Failed to create RNG"));
-    /// let mut ecc0 = ECC::generate(32, &rng, None, None).expect("Error with generate()");
-    /// let mut ecc1 = ECC::generate(32, &rng, None, None).expect("Error with generate()");
-    /// let ecc1_point = ecc1.make_pub_to_point(None, None).expect("Error with make_pub_to_point()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = Rc::new(RNG::new(&wolfcrypt).expect("Failed to create RNG"));
+    /// let mut ecc0 = ECC::generate(32, &rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
+    /// let mut ecc1 = ECC::generate(32, &rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
+    /// let ecc1_point = ecc1.make_pub_to_point(None, &Env::new(&wolfcrypt)).expect("Error with make_pub_to_point()");
     /// let mut ss0 = [0u8; 128];
     /// let mut ss1 = [0u8; 128];
     /// ecc0.set_shared_rng(Rc::clone(&rng)).expect("Error with set_shared_rng()");
@@ -1848,14 +2103,14 @@ impl ECC {
     /// }
     /// ```
     #[cfg(ecc_dh)]
-    pub fn shared_secret_ex(&mut self, peer: &ECCPoint, dout: &mut [u8]) -> Result<usize, i32> {
+    pub fn shared_secret_ex(&mut self, peer: &ECCPoint, dout: &mut [u8]) -> Result<usize, Error> {
         let mut out_len = crate::buffer_len_to_u32(dout.len())?;
         let rc = unsafe {
             sys::wc_ecc_shared_secret_ex(self.wc_ecc_key,
                 peer.wc_ecc_point, dout.as_mut_ptr(), &mut out_len)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(out_len as usize)
     }
@@ -1878,10 +2133,13 @@ impl ECC {
     /// ```rust
     /// #[cfg(all(ecc_sign, random))]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::ECC;
-    /// let rng = RNG::new().expect("Failed to create RNG");
-    /// let mut ecc = ECC::generate(32, &rng, None, None).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
+    /// let mut ecc = ECC::generate(32, &rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
     /// let hash = [0x42u8; 32];
     /// let mut signature = [0u8; 128];
     /// let signature_length = ecc.sign_hash(&hash, &mut signature, &rng).expect("Error with sign_hash()");
@@ -1891,7 +2149,7 @@ impl ECC {
     /// }
     /// ```
     #[cfg(all(ecc_sign, random))]
-    pub fn sign_hash(&mut self, din: &[u8], dout: &mut [u8], rng: &RNG) -> Result<usize, i32> {
+    pub fn sign_hash(&mut self, din: &[u8], dout: &mut [u8], rng: &RNG) -> Result<usize, Error> {
         let din_size = crate::buffer_len_to_u32(din.len())?;
         let mut dout_size = crate::buffer_len_to_u32(dout.len())?;
         let rc = unsafe {
@@ -1899,7 +2157,7 @@ impl ECC {
                 &mut dout_size, rng.wc_rng, self.wc_ecc_key)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(dout_size as usize)
     }
@@ -1921,10 +2179,13 @@ impl ECC {
     /// ```rust
     /// #[cfg(all(ecc_verify, random))]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ecc::ECC;
-    /// let rng = RNG::new().expect("Failed to create RNG");
-    /// let mut ecc = ECC::generate(32, &rng, None, None).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
+    /// let mut ecc = ECC::generate(32, &rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
     /// let hash = [0x42u8; 32];
     /// let mut signature = [0u8; 128];
     /// let signature_length = ecc.sign_hash(&hash, &mut signature, &rng).expect("Error with sign_hash()");
@@ -1934,7 +2195,7 @@ impl ECC {
     /// }
     /// ```
     #[cfg(ecc_verify)]
-    pub fn verify_hash(&mut self, sig: &[u8], hash: &[u8]) -> Result<bool, i32> {
+    pub fn verify_hash(&mut self, sig: &[u8], hash: &[u8]) -> Result<bool, Error> {
         let mut res: i32 = 0;
         let sig_len = crate::buffer_len_to_u32(sig.len())?;
         let hash_len = crate::buffer_len_to_u32(hash.len())?;
@@ -1943,13 +2204,13 @@ impl ECC {
                 hash.as_ptr(), hash_len, &mut res, self.wc_ecc_key)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(res == 1)
     }
 }
 
-impl Drop for ECC {
+impl Drop for ECC<'_> {
     /// Safely free the underlying wolfSSL ECC context.
     ///
     /// This calls the `wc_ecc_key_free()` wolfssl library function, which
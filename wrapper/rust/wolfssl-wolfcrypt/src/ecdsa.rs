@@ -160,8 +160,8 @@ macro_rules! define_ecdsa_curve {
         $(#[$meta])*
         #[cfg($hash_cfg)]
         pub struct $signing_key {
-            inner: ECC,
-            rng: RNG,
+            inner: ECC<'static>,
+            rng: RNG<'static>,
             pub_bytes: [u8; $x963_size],
         }
 
@@ -174,12 +174,12 @@ macro_rules! define_ecdsa_curve {
             pub const SCALAR_SIZE: usize = $field_size;
 
             /// Generate a fresh signing key using the provided RNG.
-            pub fn generate(mut rng: RNG) -> Result<Self, i32> {
+            pub fn generate(mut rng: RNG<'static>) -> Result<Self, crate::Error> {
+                let env = crate::Env::new(crate::WolfCrypt::global()?);
                 let ecc = ECC::generate_ex(
                     $field_size as i32,
                     &mut rng,
-                    $curve_id,
-                    None, None,
+                    $curve_id, &env,
                 )?;
                 Self::from_ecc(ecc, rng)
             }
@@ -191,9 +191,10 @@ macro_rules! define_ecdsa_curve {
                 qx: &[u8; $field_size],
                 qy: &[u8; $field_size],
                 d: &[u8; $field_size],
-                rng: RNG,
-            ) -> Result<Self, i32> {
-                let ecc = ECC::import_unsigned(qx, qy, d, $curve_id, None, None)?;
+                rng: RNG<'static>,
+            ) -> Result<Self, crate::Error> {
+                let env = crate::Env::new(crate::WolfCrypt::global()?);
+                let ecc = ECC::import_unsigned(qx, qy, d, $curve_id, &env)?;
                 Self::from_ecc(ecc, rng)
             }
 
@@ -203,30 +204,31 @@ macro_rules! define_ecdsa_curve {
             pub fn import_x963(
                 public_x963: &[u8; $x963_size],
                 d: &[u8; $field_size],
-                rng: RNG,
-            ) -> Result<Self, i32> {
+                rng: RNG<'static>,
+            ) -> Result<Self, crate::Error> {
+                let env = crate::Env::new(crate::WolfCrypt::global()?);
                 let ecc = ECC::import_private_key_ex(
-                    d, public_x963, $curve_id, None, None,
+                    d, public_x963, $curve_id, &env,
                 )?;
                 Self::from_ecc(ecc, rng)
             }
 
             /// Borrow the inner [`ECC`] key for operations not covered by the
             /// signature traits.
-            pub fn as_ecc(&self) -> &ECC { &self.inner }
+            pub fn as_ecc(&self) -> &ECC<'static> { &self.inner }
 
             /// Consume the signing key and return its `ECC` and `RNG` parts.
-            pub fn into_parts(self) -> (ECC, RNG) {
+            pub fn into_parts(self) -> (ECC<'static>, RNG<'static>) {
                 (self.inner, self.rng)
             }
 
             /// Helper that caches the X9.63 public key bytes from an already
             /// populated [`ECC`] and pairs it with the given `rng`.
-            fn from_ecc(mut ecc: ECC, rng: RNG) -> Result<Self, i32> {
+            fn from_ecc(mut ecc: ECC<'static>, rng: RNG<'static>) -> Result<Self, crate::Error> {
                 let mut pub_bytes = [0u8; $x963_size];
                 let written = ecc.export_x963(&mut pub_bytes)?;
                 if written != $x963_size {
-                    return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+                    return Err(crate::Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
                 }
                 Ok(Self { inner: ecc, rng, pub_bytes })
             }
@@ -313,7 +315,8 @@ macro_rules! define_ecdsa_curve {
             fn verify(&self, msg: &[u8], sig: &$signature) -> Result<(), Error> {
                 let mut der = [0u8; $der_max];
                 let der_len = rs_to_der::<$field_size>(&sig.0, &mut der)?;
-                let key = ECC::import_x963_ex(&self.pub_bytes, $curve_id, None, None)
+                let wolfcrypt = crate::WolfCrypt::global().map_err(|_| Error::new())?;
+                let key = ECC::import_x963_ex(&self.pub_bytes, $curve_id, &crate::Env::new(wolfcrypt))
                     .map_err(|_| Error::new())?;
                 let msg_len: u32 = msg.len().try_into().map_err(|_| Error::new())?;
                 let rc = unsafe {
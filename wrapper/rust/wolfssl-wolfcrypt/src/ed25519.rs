@@ -25,7 +25,10 @@ This module provides a Rust wrapper for the wolfCrypt library's EdDSA Curve
 
 #![cfg(ed25519)]
 
+use crate::Error;
+use crate::memory::HeapHint;
 use crate::sys;
+use core::marker::PhantomData;
 use crate::random::RNG;
 use core::mem::MaybeUninit;
 
@@ -35,11 +38,14 @@ use core::mem::MaybeUninit;
 /// It ensures proper initialization and deallocation.
 ///
 /// An instance can be created with `generate()` or `new()`.
-pub struct Ed25519 {
-    ws_key: sys::ed25519_key,
+pub struct Ed25519<'a> {
+    pub(crate) ws_key: sys::ed25519_key,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 
-impl Ed25519 {
+impl<'a> Ed25519<'a> {
     /** Size of private key only. */
     pub const KEY_SIZE: usize = sys::ED25519_KEY_SIZE as usize;
     /** Size of signature. */
@@ -58,6 +64,7 @@ impl Ed25519 {
     /// # Parameters
     ///
     /// * `rng`: `RNG` instance to use for random number generation.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
@@ -69,11 +76,13 @@ impl Ed25519 {
     /// ```rust
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// ```
-    pub fn generate(rng: &RNG) -> Result<Self, i32> {
-        Self::generate_ex(rng, None, None)
+    pub fn generate(rng: &RNG, wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::generate_ex(rng, &crate::Env::new(wolfcrypt))
     }
 
     /// Generate a new Ed25519 key with optional heap and device ID.
@@ -81,8 +90,7 @@ impl Ed25519 {
     /// # Parameters
     ///
     /// * `rng`: `RNG` instance to use for random number generation.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -92,33 +100,31 @@ impl Ed25519 {
     /// # Example
     ///
     /// ```rust
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let ed = Ed25519::generate_ex(&mut rng, None, None).expect("Error with generate_ex()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let ed = Ed25519::generate_ex(&mut rng, &Env::new(&wolfcrypt)).expect("Error with generate_ex()");
     /// ```
-    pub fn generate_ex(rng: &RNG, heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn generate_ex(rng: &RNG, env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let mut ws_key: MaybeUninit<sys::ed25519_key> = MaybeUninit::uninit();
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_ed25519_init_ex(ws_key.as_mut_ptr(), heap, dev_id) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let ws_key = unsafe { ws_key.assume_init() };
-        let mut ed25519 = Ed25519 { ws_key };
+        let mut ed25519 = Ed25519 { ws_key, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData };
         let rc = unsafe {
             sys::wc_ed25519_make_key(rng.wc_rng,
                 sys::ED25519_KEY_SIZE as i32, &mut ed25519.ws_key)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(ed25519)
     }
@@ -128,6 +134,10 @@ impl Ed25519 {
     /// A key will not be present but can be imported with one of the import
     /// functions.
     ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
     /// # Returns
     ///
     /// Returns either Ok(ed25519) containing the Ed25519 struct instance or
@@ -137,10 +147,12 @@ impl Ed25519 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let ed = Ed25519::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let ed = Ed25519::new(&wolfcrypt).expect("Error with new()");
     /// ```
-    pub fn new() -> Result<Self, i32> {
-        Self::new_ex(None, None)
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&crate::Env::new(wolfcrypt))
     }
 
     /// Create and initialize a new Ed25519 instance with optional heap and
@@ -151,8 +163,7 @@ impl Ed25519 {
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -162,25 +173,23 @@ impl Ed25519 {
     /// # Example
     ///
     /// ```rust
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let ed = Ed25519::new_ex(None, None).expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let ed = Ed25519::new_ex(&Env::new(&wolfcrypt)).expect("Error with new()");
     /// ```
-    pub fn new_ex(heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn new_ex(env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let mut ws_key: MaybeUninit<sys::ed25519_key> = MaybeUninit::uninit();
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_ed25519_init_ex(ws_key.as_mut_ptr(), heap, dev_id) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let ws_key = unsafe { ws_key.assume_init() };
-        let ed25519 = Ed25519 { ws_key };
+        let ed25519 = Ed25519 { ws_key, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData };
         Ok(ed25519)
     }
 
@@ -200,14 +209,16 @@ impl Ed25519 {
     /// ```rust
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// ed.check_key().expect("Error with check_key()");
     /// ```
-    pub fn check_key(&mut self) -> Result<(), i32> {
+    pub fn check_key(&mut self) -> Result<(), Error> {
         let rc = unsafe { sys::wc_ed25519_check_key(&mut self.ws_key) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -233,15 +244,17 @@ impl Ed25519 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let mut private = [0u8; Ed25519::PRV_KEY_SIZE];
     /// let mut public = [0u8; Ed25519::PUB_KEY_SIZE];
     /// ed.export_key(&mut private, &mut public).expect("Error with export_key()");
     /// }
     /// ```
     #[cfg(ed25519_export)]
-    pub fn export_key(&self, private: &mut [u8], public: &mut [u8]) -> Result<(), i32> {
+    pub fn export_key(&self, private: &mut [u8], public: &mut [u8]) -> Result<(), Error> {
         let mut private_size = crate::buffer_len_to_u32(private.len())?;
         let mut public_size = crate::buffer_len_to_u32(public.len())?;
         let rc = unsafe {
@@ -250,7 +263,7 @@ impl Ed25519 {
                 public.as_mut_ptr(), &mut public_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -275,21 +288,23 @@ impl Ed25519 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let mut public = [0u8; Ed25519::PUB_KEY_SIZE];
     /// ed.export_public(&mut public).expect("Error with export_public()");
     /// }
     /// ```
     #[cfg(ed25519_export)]
-    pub fn export_public(&self, public: &mut [u8]) -> Result<(), i32> {
+    pub fn export_public(&self, public: &mut [u8]) -> Result<(), Error> {
         let mut public_size = crate::buffer_len_to_u32(public.len())?;
         let rc = unsafe {
             sys::wc_ed25519_export_public(&self.ws_key, public.as_mut_ptr(),
                 &mut public_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -314,21 +329,23 @@ impl Ed25519 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let mut private = [0u8; Ed25519::PRV_KEY_SIZE];
     /// ed.export_private(&mut private).expect("Error with export_private()");
     /// }
     /// ```
     #[cfg(ed25519_export)]
-    pub fn export_private(&self, keyout: &mut [u8]) -> Result<(), i32> {
+    pub fn export_private(&self, keyout: &mut [u8]) -> Result<(), Error> {
         let mut keyout_size = crate::buffer_len_to_u32(keyout.len())?;
         let rc = unsafe {
             sys::wc_ed25519_export_private(&self.ws_key, keyout.as_mut_ptr(),
                 &mut keyout_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -353,21 +370,23 @@ impl Ed25519 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let mut private_only = [0u8; Ed25519::KEY_SIZE];
     /// ed.export_private_only(&mut private_only).expect("Error with export_private_only()");
     /// }
     /// ```
     #[cfg(ed25519_export)]
-    pub fn export_private_only(&self, private: &mut [u8]) -> Result<(), i32> {
+    pub fn export_private_only(&self, private: &mut [u8]) -> Result<(), Error> {
         let mut private_size = crate::buffer_len_to_u32(private.len())?;
         let rc = unsafe {
             sys::wc_ed25519_export_private_only(&self.ws_key,
                 private.as_mut_ptr(), &mut private_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -394,23 +413,25 @@ impl Ed25519 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let mut private = [0u8; Ed25519::PRV_KEY_SIZE];
     /// let mut public = [0u8; Ed25519::PUB_KEY_SIZE];
     /// ed.export_key(&mut private, &mut public).expect("Error with export_key()");
-    /// let mut ed = Ed25519::new().expect("Error with new()");
+    /// let mut ed = Ed25519::new(&wolfcrypt).expect("Error with new()");
     /// ed.import_public(&public).expect("Error with import_public()");
     /// }
     /// ```
     #[cfg(ed25519_import)]
-    pub fn import_public(&mut self, public: &[u8]) -> Result<(), i32> {
+    pub fn import_public(&mut self, public: &[u8]) -> Result<(), Error> {
         let public_size = crate::buffer_len_to_u32(public.len())?;
         let rc = unsafe {
             sys::wc_ed25519_import_public(public.as_ptr(), public_size, &mut self.ws_key)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -438,24 +459,26 @@ impl Ed25519 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let mut private = [0u8; Ed25519::PRV_KEY_SIZE];
     /// let mut public = [0u8; Ed25519::PUB_KEY_SIZE];
     /// ed.export_key(&mut private, &mut public).expect("Error with export_key()");
-    /// let mut ed = Ed25519::new().expect("Error with new()");
+    /// let mut ed = Ed25519::new(&wolfcrypt).expect("Error with new()");
     /// ed.import_public_ex(&public, false).expect("Error with import_public_ex()");
     /// }
     /// ```
     #[cfg(ed25519_import)]
-    pub fn import_public_ex(&mut self, public: &[u8], trusted: bool) -> Result<(), i32> {
+    pub fn import_public_ex(&mut self, public: &[u8], trusted: bool) -> Result<(), Error> {
         let public_size = crate::buffer_len_to_u32(public.len())?;
         let rc = unsafe {
             sys::wc_ed25519_import_public_ex(public.as_ptr(), public_size,
                 &mut self.ws_key, if trusted {1} else {0})
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -478,23 +501,25 @@ impl Ed25519 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let mut private_only = [0u8; Ed25519::KEY_SIZE];
     /// ed.export_private_only(&mut private_only).expect("Error with export_private_only()");
-    /// let mut ed = Ed25519::new().expect("Error with new()");
+    /// let mut ed = Ed25519::new(&wolfcrypt).expect("Error with new()");
     /// ed.import_private_only(&private_only).expect("Error with import_private_only()");
     /// }
     /// ```
     #[cfg(ed25519_import)]
-    pub fn import_private_only(&mut self, private: &[u8]) -> Result<(), i32> {
+    pub fn import_private_only(&mut self, private: &[u8]) -> Result<(), Error> {
         let private_size = crate::buffer_len_to_u32(private.len())?;
         let rc = unsafe {
             sys::wc_ed25519_import_private_only(private.as_ptr(), private_size,
                 &mut self.ws_key)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -522,17 +547,19 @@ impl Ed25519 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let mut private = [0u8; Ed25519::PRV_KEY_SIZE];
     /// let mut public = [0u8; Ed25519::PUB_KEY_SIZE];
     /// ed.export_key(&mut private, &mut public).expect("Error with export_key()");
-    /// let mut ed = Ed25519::new().expect("Error with new()");
+    /// let mut ed = Ed25519::new(&wolfcrypt).expect("Error with new()");
     /// ed.import_private_key(&private, Some(&public)).expect("Error with import_private_key()");
     /// }
     /// ```
     #[cfg(ed25519_import)]
-    pub fn import_private_key(&mut self, private: &[u8], public: Option<&[u8]>) -> Result<(), i32> {
+    pub fn import_private_key(&mut self, private: &[u8], public: Option<&[u8]>) -> Result<(), Error> {
         let private_size = crate::buffer_len_to_u32(private.len())?;
         let mut public_ptr: *const u8 = core::ptr::null();
         let mut public_size = 0u32;
@@ -545,7 +572,7 @@ impl Ed25519 {
                 public_ptr, public_size, &mut self.ws_key)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -573,17 +600,19 @@ impl Ed25519 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let mut private = [0u8; Ed25519::PRV_KEY_SIZE];
     /// let mut public = [0u8; Ed25519::PUB_KEY_SIZE];
     /// ed.export_key(&mut private, &mut public).expect("Error with export_key()");
-    /// let mut ed = Ed25519::new().expect("Error with new()");
+    /// let mut ed = Ed25519::new(&wolfcrypt).expect("Error with new()");
     /// ed.import_private_key_ex(&private, Some(&public), false).expect("Error with import_private_key_ex()");
     /// }
     /// ```
     #[cfg(ed25519_import)]
-    pub fn import_private_key_ex(&mut self, private: &[u8], public: Option<&[u8]>, trusted: bool) -> Result<(), i32> {
+    pub fn import_private_key_ex(&mut self, private: &[u8], public: Option<&[u8]>, trusted: bool) -> Result<(), Error> {
         let private_size = crate::buffer_len_to_u32(private.len())?;
         let mut public_ptr: *const u8 = core::ptr::null();
         let mut public_size = 0u32;
@@ -596,7 +625,7 @@ impl Ed25519 {
                 public_ptr, public_size, &mut self.ws_key, if trusted {1} else {0})
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -620,23 +649,25 @@ impl Ed25519 {
     /// ```rust
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let mut private = [0u8; Ed25519::KEY_SIZE];
     /// ed.export_private_only(&mut private).expect("Error with export_private_only()");
-    /// let mut ed = Ed25519::new().expect("Error with new()");
+    /// let mut ed = Ed25519::new(&wolfcrypt).expect("Error with new()");
     /// ed.import_private_only(&private).expect("Error with import_private_only()");
     /// let mut public = [0u8; Ed25519::KEY_SIZE];
     /// ed.make_public(&mut public).expect("Error with make_public()");
     /// ```
-    pub fn make_public(&mut self, pubkey: &mut [u8]) -> Result<(), i32> {
+    pub fn make_public(&mut self, pubkey: &mut [u8]) -> Result<(), Error> {
         let pubkey_size = crate::buffer_len_to_u32(pubkey.len())?;
         let rc = unsafe {
             sys::wc_ed25519_make_public(&mut self.ws_key,
                 pubkey.as_mut_ptr(), pubkey_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -661,15 +692,17 @@ impl Ed25519 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let message = [0x42u8, 33, 55, 66];
     /// let mut signature = [0u8; Ed25519::SIG_SIZE];
     /// ed.sign_msg(&message, &mut signature).expect("Error with sign_msg()");
     /// }
     /// ```
     #[cfg(ed25519_sign)]
-    pub fn sign_msg(&mut self, message: &[u8], signature: &mut [u8]) -> Result<usize, i32> {
+    pub fn sign_msg(&mut self, message: &[u8], signature: &mut [u8]) -> Result<usize, Error> {
         let message_size = crate::buffer_len_to_u32(message.len())?;
         let mut signature_size = crate::buffer_len_to_u32(signature.len())?;
         let rc = unsafe {
@@ -677,7 +710,7 @@ impl Ed25519 {
                 signature.as_mut_ptr(), &mut signature_size, &mut self.ws_key)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(signature_size as usize)
     }
@@ -705,8 +738,10 @@ impl Ed25519 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let message = [0x42u8, 33, 55, 66];
     /// let context = b"context";
     /// let mut signature = [0u8; Ed25519::SIG_SIZE];
@@ -714,10 +749,10 @@ impl Ed25519 {
     /// }
     /// ```
     #[cfg(ed25519_sign)]
-    pub fn sign_msg_ctx(&mut self, message: &[u8], context: &[u8], signature: &mut [u8]) -> Result<usize, i32> {
+    pub fn sign_msg_ctx(&mut self, message: &[u8], context: &[u8], signature: &mut [u8]) -> Result<usize, Error> {
         let message_size = crate::buffer_len_to_u32(message.len())?;
         if context.len() > 255 {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let context_size = context.len() as u8;
         let mut signature_size = crate::buffer_len_to_u32(signature.len())?;
@@ -727,7 +762,7 @@ impl Ed25519 {
                 context.as_ptr(), context_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(signature_size as usize)
     }
@@ -756,8 +791,10 @@ impl Ed25519 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let hash = [
     ///     0xddu8,0xaf,0x35,0xa1,0x93,0x61,0x7a,0xba,
     ///     0xcc,0x41,0x73,0x49,0xae,0x20,0x41,0x31,
@@ -774,14 +811,14 @@ impl Ed25519 {
     /// }
     /// ```
     #[cfg(ed25519_sign)]
-    pub fn sign_hash_ph(&mut self, hash: &[u8], context: Option<&[u8]>, signature: &mut [u8]) -> Result<usize, i32> {
+    pub fn sign_hash_ph(&mut self, hash: &[u8], context: Option<&[u8]>, signature: &mut [u8]) -> Result<usize, Error> {
         let hash_size = crate::buffer_len_to_u32(hash.len())?;
         let mut context_ptr: *const u8 = core::ptr::null();
         let mut context_size = 0u8;
         if let Some(context) = context {
             context_ptr = context.as_ptr();
             if context.len() > 255 {
-                return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+                return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
             }
             context_size = context.len() as u8;
         }
@@ -792,7 +829,7 @@ impl Ed25519 {
                 context_ptr, context_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(signature_size as usize)
     }
@@ -821,8 +858,10 @@ impl Ed25519 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let message = [0x42u8, 33, 55, 66];
     /// let context = b"context";
     /// let mut signature = [0u8; Ed25519::SIG_SIZE];
@@ -830,14 +869,14 @@ impl Ed25519 {
     /// }
     /// ```
     #[cfg(ed25519_sign)]
-    pub fn sign_msg_ph(&mut self, message: &[u8], context: Option<&[u8]>, signature: &mut [u8]) -> Result<usize, i32> {
+    pub fn sign_msg_ph(&mut self, message: &[u8], context: Option<&[u8]>, signature: &mut [u8]) -> Result<usize, Error> {
         let message_size = crate::buffer_len_to_u32(message.len())?;
         let mut context_ptr: *const u8 = core::ptr::null();
         let mut context_size = 0u8;
         if let Some(context) = context {
             context_ptr = context.as_ptr();
             if context.len() > 255 {
-                return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+                return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
             }
             context_size = context.len() as u8;
         }
@@ -848,7 +887,7 @@ impl Ed25519 {
                 context_ptr, context_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(signature_size as usize)
     }
@@ -877,8 +916,10 @@ impl Ed25519 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let message = [0x42u8, 33, 55, 66];
     /// let context = b"context";
     /// let mut signature = [0u8; Ed25519::SIG_SIZE];
@@ -886,14 +927,14 @@ impl Ed25519 {
     /// }
     /// ```
     #[cfg(ed25519_sign)]
-    pub fn sign_msg_ex(&mut self, din: &[u8], context: Option<&[u8]>, typ: u8, signature: &mut [u8]) -> Result<usize, i32> {
+    pub fn sign_msg_ex(&mut self, din: &[u8], context: Option<&[u8]>, typ: u8, signature: &mut [u8]) -> Result<usize, Error> {
         let din_size = crate::buffer_len_to_u32(din.len())?;
         let mut context_ptr: *const u8 = core::ptr::null();
         let mut context_size = 0u8;
         if let Some(context) = context {
             context_ptr = context.as_ptr();
             if context.len() > 255 {
-                return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+                return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
             }
             context_size = context.len() as u8;
         }
@@ -904,7 +945,7 @@ impl Ed25519 {
                 typ, context_ptr, context_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(signature_size as usize)
     }
@@ -928,8 +969,10 @@ impl Ed25519 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let message = [0x42u8, 33, 55, 66];
     /// let mut signature = [0u8; Ed25519::SIG_SIZE];
     /// ed.sign_msg(&message, &mut signature).expect("Error with sign_msg()");
@@ -938,7 +981,7 @@ impl Ed25519 {
     /// }
     /// ```
     #[cfg(ed25519_verify)]
-    pub fn verify_msg(&mut self, signature: &[u8], message: &[u8]) -> Result<bool, i32> {
+    pub fn verify_msg(&mut self, signature: &[u8], message: &[u8]) -> Result<bool, Error> {
         let signature_size = crate::buffer_len_to_u32(signature.len())?;
         let message_size = crate::buffer_len_to_u32(message.len())?;
         let mut res = 0i32;
@@ -952,7 +995,7 @@ impl Ed25519 {
             return Ok(false);
         }
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(res == 1)
     }
@@ -979,8 +1022,10 @@ impl Ed25519 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let message = b"Hello!";
     /// let context = b"context";
     /// let mut signature = [0u8; Ed25519::SIG_SIZE];
@@ -990,11 +1035,11 @@ impl Ed25519 {
     /// }
     /// ```
     #[cfg(ed25519_verify)]
-    pub fn verify_msg_ctx(&mut self, signature: &[u8], message: &[u8], context: &[u8]) -> Result<bool, i32> {
+    pub fn verify_msg_ctx(&mut self, signature: &[u8], message: &[u8], context: &[u8]) -> Result<bool, Error> {
         let signature_size = crate::buffer_len_to_u32(signature.len())?;
         let message_size = crate::buffer_len_to_u32(message.len())?;
         if context.len() > 255 {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let context_size = context.len() as u8;
         let mut res = 0i32;
@@ -1009,7 +1054,7 @@ impl Ed25519 {
             return Ok(false);
         }
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(res == 1)
     }
@@ -1038,8 +1083,10 @@ impl Ed25519 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let hash = [
     ///     0xddu8,0xaf,0x35,0xa1,0x93,0x61,0x7a,0xba,
     ///     0xcc,0x41,0x73,0x49,0xae,0x20,0x41,0x31,
@@ -1058,7 +1105,7 @@ impl Ed25519 {
     /// }
     /// ```
     #[cfg(ed25519_verify)]
-    pub fn verify_hash_ph(&mut self, signature: &[u8], hash: &[u8], context: Option<&[u8]>) -> Result<bool, i32> {
+    pub fn verify_hash_ph(&mut self, signature: &[u8], hash: &[u8], context: Option<&[u8]>) -> Result<bool, Error> {
         let signature_size = crate::buffer_len_to_u32(signature.len())?;
         let hash_size = crate::buffer_len_to_u32(hash.len())?;
         let mut context_ptr: *const u8 = core::ptr::null();
@@ -1066,7 +1113,7 @@ impl Ed25519 {
         if let Some(context) = context {
             context_ptr = context.as_ptr();
             if context.len() > 255 {
-                return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+                return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
             }
             context_size = context.len() as u8;
         }
@@ -1082,7 +1129,7 @@ impl Ed25519 {
             return Ok(false);
         }
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(res == 1)
     }
@@ -1110,8 +1157,10 @@ impl Ed25519 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let message = [0x42u8, 33, 55, 66];
     /// let context = b"context";
     /// let mut signature = [0u8; Ed25519::SIG_SIZE];
@@ -1121,7 +1170,7 @@ impl Ed25519 {
     /// }
     /// ```
     #[cfg(ed25519_verify)]
-    pub fn verify_msg_ph(&mut self, signature: &[u8], message: &[u8], context: Option<&[u8]>) -> Result<bool, i32> {
+    pub fn verify_msg_ph(&mut self, signature: &[u8], message: &[u8], context: Option<&[u8]>) -> Result<bool, Error> {
         let signature_size = crate::buffer_len_to_u32(signature.len())?;
         let message_size = crate::buffer_len_to_u32(message.len())?;
         let mut context_ptr: *const u8 = core::ptr::null();
@@ -1129,7 +1178,7 @@ impl Ed25519 {
         if let Some(context) = context {
             context_ptr = context.as_ptr();
             if context.len() > 255 {
-                return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+                return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
             }
             context_size = context.len() as u8;
         }
@@ -1145,7 +1194,7 @@ impl Ed25519 {
             return Ok(false);
         }
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(res == 1)
     }
@@ -1173,8 +1222,10 @@ impl Ed25519 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let message = [0x42u8, 33, 55, 66];
     /// let context = b"context";
     /// let mut signature = [0u8; Ed25519::SIG_SIZE];
@@ -1184,7 +1235,7 @@ impl Ed25519 {
     /// }
     /// ```
     #[cfg(ed25519_verify)]
-    pub fn verify_msg_ex(&mut self, signature: &[u8], din: &[u8], context: Option<&[u8]>, typ: u8) -> Result<bool, i32> {
+    pub fn verify_msg_ex(&mut self, signature: &[u8], din: &[u8], context: Option<&[u8]>, typ: u8) -> Result<bool, Error> {
         let signature_size = crate::buffer_len_to_u32(signature.len())?;
         let din_size = crate::buffer_len_to_u32(din.len())?;
         let mut context_ptr: *const u8 = core::ptr::null();
@@ -1192,7 +1243,7 @@ impl Ed25519 {
         if let Some(context) = context {
             context_ptr = context.as_ptr();
             if context.len() > 255 {
-                return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+                return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
             }
             context_size = context.len() as u8;
         }
@@ -1208,7 +1259,7 @@ impl Ed25519 {
             return Ok(false);
         }
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(res == 1)
     }
@@ -1233,8 +1284,10 @@ impl Ed25519 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let message = [0x42u8, 33, 55, 66];
     /// let mut signature = [0u8; Ed25519::SIG_SIZE];
     /// ed.sign_msg(&message, &mut signature).expect("Error with sign_msg()");
@@ -1246,14 +1299,14 @@ impl Ed25519 {
     /// }
     /// ```
     #[cfg(ed25519_streaming_verify)]
-    pub fn verify_msg_init(&mut self, signature: &[u8], context: Option<&[u8]>, typ: u8) -> Result<(), i32> {
+    pub fn verify_msg_init(&mut self, signature: &[u8], context: Option<&[u8]>, typ: u8) -> Result<(), Error> {
         let signature_size = crate::buffer_len_to_u32(signature.len())?;
         let mut context_ptr: *const u8 = core::ptr::null();
         let mut context_size = 0u8;
         if let Some(context) = context {
             context_ptr = context.as_ptr();
             if context.len() > 255 {
-                return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+                return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
             }
             context_size = context.len() as u8;
         }
@@ -1262,7 +1315,7 @@ impl Ed25519 {
                 &mut self.ws_key, typ, context_ptr, context_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1285,8 +1338,10 @@ impl Ed25519 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let message = [0x42u8, 33, 55, 66];
     /// let mut signature = [0u8; Ed25519::SIG_SIZE];
     /// ed.sign_msg(&message, &mut signature).expect("Error with sign_msg()");
@@ -1298,14 +1353,14 @@ impl Ed25519 {
     /// }
     /// ```
     #[cfg(ed25519_streaming_verify)]
-    pub fn verify_msg_update(&mut self, din: &[u8]) -> Result<(), i32> {
+    pub fn verify_msg_update(&mut self, din: &[u8]) -> Result<(), Error> {
         let din_size = crate::buffer_len_to_u32(din.len())?;
         let rc = unsafe {
             sys::wc_ed25519_verify_msg_update(din.as_ptr(), din_size,
                 &mut self.ws_key)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1328,8 +1383,10 @@ impl Ed25519 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let message = [0x42u8, 33, 55, 66];
     /// let mut signature = [0u8; Ed25519::SIG_SIZE];
     /// ed.sign_msg(&message, &mut signature).expect("Error with sign_msg()");
@@ -1341,7 +1398,7 @@ impl Ed25519 {
     /// }
     /// ```
     #[cfg(ed25519_streaming_verify)]
-    pub fn verify_msg_final(&mut self, signature: &[u8]) -> Result<bool, i32> {
+    pub fn verify_msg_final(&mut self, signature: &[u8]) -> Result<bool, Error> {
         let signature_size = crate::buffer_len_to_u32(signature.len())?;
         let mut res = 0i32;
         let rc = unsafe {
@@ -1354,7 +1411,7 @@ impl Ed25519 {
             return Ok(false);
         }
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(res == 1)
     }
@@ -1371,15 +1428,17 @@ impl Ed25519 {
     /// ```rust
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let key_size = ed.size().expect("Error with size()");
     /// assert_eq!(key_size, Ed25519::KEY_SIZE);
     /// ```
-    pub fn size(&self) -> Result<usize, i32> {
+    pub fn size(&self) -> Result<usize, Error> {
         let rc = unsafe { sys::wc_ed25519_size(&self.ws_key) };
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(rc as usize)
     }
@@ -1396,15 +1455,17 @@ impl Ed25519 {
     /// ```rust
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let priv_size = ed.priv_size().expect("Error with priv_size()");
     /// assert_eq!(priv_size, Ed25519::PRV_KEY_SIZE);
     /// ```
-    pub fn priv_size(&self) -> Result<usize, i32> {
+    pub fn priv_size(&self) -> Result<usize, Error> {
         let rc = unsafe { sys::wc_ed25519_priv_size(&self.ws_key) };
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(rc as usize)
     }
@@ -1421,15 +1482,17 @@ impl Ed25519 {
     /// ```rust
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let pub_size = ed.pub_size().expect("Error with pub_size()");
     /// assert_eq!(pub_size, Ed25519::PUB_KEY_SIZE);
     /// ```
-    pub fn pub_size(&self) -> Result<usize, i32> {
+    pub fn pub_size(&self) -> Result<usize, Error> {
         let rc = unsafe { sys::wc_ed25519_pub_size(&self.ws_key) };
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(rc as usize)
     }
@@ -1446,27 +1509,29 @@ impl Ed25519 {
     /// ```rust
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed25519::Ed25519;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let sig_size = ed.sig_size().expect("Error with sig_size()");
     /// assert_eq!(sig_size, Ed25519::SIG_SIZE);
     /// ```
-    pub fn sig_size(&self) -> Result<usize, i32> {
+    pub fn sig_size(&self) -> Result<usize, Error> {
         let rc = unsafe { sys::wc_ed25519_sig_size(&self.ws_key) };
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(rc as usize)
     }
 }
 
-impl Ed25519 {
+impl<'a> Ed25519<'a> {
     fn zeroize(&mut self) {
         unsafe { crate::zeroize_raw(&mut self.ws_key); }
     }
 }
 
-impl Drop for Ed25519 {
+impl Drop for Ed25519<'_> {
     /// Safely free the wolfSSL resources.
     fn drop(&mut self) {
         unsafe { sys::wc_ed25519_free(&mut self.ws_key); }
@@ -1560,7 +1625,7 @@ mod signature_impl {
     }
 
     #[cfg(all(ed25519_sign, ed25519_export))]
-    impl signature::Keypair for Ed25519 {
+    impl signature::Keypair for Ed25519<'_> {
         type VerifyingKey = VerifyingKey;
         fn verifying_key(&self) -> Self::VerifyingKey {
             let mut pub_key = [0u8; Ed25519::PUB_KEY_SIZE];
@@ -1570,7 +1635,7 @@ mod signature_impl {
     }
 
     #[cfg(ed25519_sign)]
-    impl signature::SignerMut<Signature> for Ed25519 {
+    impl signature::SignerMut<Signature> for Ed25519<'_> {
         fn try_sign(&mut self, msg: &[u8]) -> Result<Signature, Error> {
             let mut sig = [0u8; Ed25519::SIG_SIZE];
             self.sign_msg(msg, &mut sig).map_err(|_| Error::new())?;
@@ -1581,7 +1646,8 @@ mod signature_impl {
     #[cfg(all(ed25519_import, ed25519_verify))]
     impl signature::Verifier<Signature> for VerifyingKey {
         fn verify(&self, msg: &[u8], signature: &Signature) -> Result<(), Error> {
-            let mut key = Ed25519::new().map_err(|_| Error::new())?;
+            let wolfcrypt = crate::WolfCrypt::global().map_err(|_| Error::new())?;
+            let mut key = Ed25519::new(wolfcrypt).map_err(|_| Error::new())?;
             key.import_public(&self.0).map_err(|_| Error::new())?;
             let valid = key
                 .verify_msg(&signature.0, msg)
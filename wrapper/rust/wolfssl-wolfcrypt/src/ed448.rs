@@ -25,7 +25,10 @@ This module provides a Rust wrapper for the wolfCrypt library's EdDSA Curve
 
 #![cfg(ed448)]
 
+use crate::Error;
+use crate::memory::HeapHint;
 use crate::sys;
+use core::marker::PhantomData;
 use crate::random::RNG;
 use core::mem::MaybeUninit;
 
@@ -35,11 +38,14 @@ use core::mem::MaybeUninit;
 /// It ensures proper initialization and deallocation.
 ///
 /// An instance can be created with `generate()` or `new()`.
-pub struct Ed448 {
-    ws_key: sys::ed448_key,
+pub struct Ed448<'a> {
+    pub(crate) ws_key: sys::ed448_key,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 
-impl Ed448 {
+impl<'a> Ed448<'a> {
     /** Size of private key only. */
     pub const KEY_SIZE: usize = sys::ED448_KEY_SIZE as usize;
     /** Size of signature. */
@@ -57,6 +63,7 @@ impl Ed448 {
     /// # Parameters
     ///
     /// * `rng`: `RNG` instance to use for random number generation.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
@@ -68,11 +75,13 @@ impl Ed448 {
     /// ```rust
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// ```
-    pub fn generate(rng: &RNG) -> Result<Self, i32> {
-        Self::generate_ex(rng, None, None)
+    pub fn generate(rng: &RNG, wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::generate_ex(rng, &crate::Env::new(wolfcrypt))
     }
 
     /// Generate a new Ed448 key with optional heap and device ID.
@@ -80,8 +89,7 @@ impl Ed448 {
     /// # Parameters
     ///
     /// * `rng`: `RNG` instance to use for random number generation.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -91,33 +99,31 @@ impl Ed448 {
     /// # Example
     ///
     /// ```rust
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let ed = Ed448::generate_ex(&mut rng, None, None).expect("Error with generate_ex()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let ed = Ed448::generate_ex(&mut rng, &Env::new(&wolfcrypt)).expect("Error with generate_ex()");
     /// ```
-    pub fn generate_ex(rng: &RNG, heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn generate_ex(rng: &RNG, env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let mut ws_key: MaybeUninit<sys::ed448_key> = MaybeUninit::uninit();
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_ed448_init_ex(ws_key.as_mut_ptr(), heap, dev_id) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let ws_key = unsafe { ws_key.assume_init() };
-        let mut ed448 = Ed448 { ws_key };
+        let mut ed448 = Ed448 { ws_key, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData };
         let rc = unsafe {
             sys::wc_ed448_make_key(rng.wc_rng,
                 sys::ED448_KEY_SIZE as i32, &mut ed448.ws_key)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(ed448)
     }
@@ -127,6 +133,10 @@ impl Ed448 {
     /// A key will not be present but can be imported with one of the import
     /// functions.
     ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
     /// # Returns
     ///
     /// Returns either Ok(ed448) containing the Ed448 struct instance or
@@ -136,10 +146,12 @@ impl Ed448 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let ed = Ed448::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let ed = Ed448::new(&wolfcrypt).expect("Error with new()");
     /// ```
-    pub fn new() -> Result<Self, i32> {
-        Self::new_ex(None, None)
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&crate::Env::new(wolfcrypt))
     }
 
     /// Create and initialize a new Ed448 instance with optional heap and
@@ -150,8 +162,7 @@ impl Ed448 {
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -161,25 +172,23 @@ impl Ed448 {
     /// # Example
     ///
     /// ```rust
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let ed = Ed448::new_ex(None, None).expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let ed = Ed448::new_ex(&Env::new(&wolfcrypt)).expect("Error with new()");
     /// ```
-    pub fn new_ex(heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn new_ex(env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let mut ws_key: MaybeUninit<sys::ed448_key> = MaybeUninit::uninit();
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_ed448_init_ex(ws_key.as_mut_ptr(), heap, dev_id) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let ws_key = unsafe { ws_key.assume_init() };
-        let ed448 = Ed448 { ws_key };
+        let ed448 = Ed448 { ws_key, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData };
         Ok(ed448)
     }
 
@@ -199,14 +208,16 @@ impl Ed448 {
     /// ```rust
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// ed.check_key().expect("Error with check_key()");
     /// ```
-    pub fn check_key(&mut self) -> Result<(), i32> {
+    pub fn check_key(&mut self) -> Result<(), Error> {
         let rc = unsafe { sys::wc_ed448_check_key(&mut self.ws_key) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -232,15 +243,17 @@ impl Ed448 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let mut private = [0u8; Ed448::PRV_KEY_SIZE];
     /// let mut public = [0u8; Ed448::PUB_KEY_SIZE];
     /// ed.export_key(&mut private, &mut public).expect("Error with export_key()");
     /// }
     /// ```
     #[cfg(ed448_export)]
-    pub fn export_key(&self, private: &mut [u8], public: &mut [u8]) -> Result<(), i32> {
+    pub fn export_key(&self, private: &mut [u8], public: &mut [u8]) -> Result<(), Error> {
         let mut private_size = crate::buffer_len_to_u32(private.len())?;
         let mut public_size = crate::buffer_len_to_u32(public.len())?;
         let rc = unsafe {
@@ -249,7 +262,7 @@ impl Ed448 {
                 public.as_mut_ptr(), &mut public_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -273,21 +286,23 @@ impl Ed448 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let mut public = [0u8; Ed448::PUB_KEY_SIZE];
     /// ed.export_public(&mut public).expect("Error with export_public()");
     /// }
     /// ```
     #[cfg(ed448_export)]
-    pub fn export_public(&self, public: &mut [u8]) -> Result<(), i32> {
+    pub fn export_public(&self, public: &mut [u8]) -> Result<(), Error> {
         let mut public_size = crate::buffer_len_to_u32(public.len())?;
         let rc = unsafe {
             sys::wc_ed448_export_public(&self.ws_key, public.as_mut_ptr(),
                 &mut public_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -311,21 +326,23 @@ impl Ed448 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let mut private = [0u8; Ed448::PRV_KEY_SIZE];
     /// ed.export_private(&mut private).expect("Error with export_private()");
     /// }
     /// ```
     #[cfg(ed448_export)]
-    pub fn export_private(&self, keyout: &mut [u8]) -> Result<(), i32> {
+    pub fn export_private(&self, keyout: &mut [u8]) -> Result<(), Error> {
         let mut keyout_size = crate::buffer_len_to_u32(keyout.len())?;
         let rc = unsafe {
             sys::wc_ed448_export_private(&self.ws_key, keyout.as_mut_ptr(),
                 &mut keyout_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -349,21 +366,23 @@ impl Ed448 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let mut private_only = [0u8; Ed448::KEY_SIZE];
     /// ed.export_private_only(&mut private_only).expect("Error with export_private_only()");
     /// }
     /// ```
     #[cfg(ed448_export)]
-    pub fn export_private_only(&self, private: &mut [u8]) -> Result<(), i32> {
+    pub fn export_private_only(&self, private: &mut [u8]) -> Result<(), Error> {
         let mut private_size = crate::buffer_len_to_u32(private.len())?;
         let rc = unsafe {
             sys::wc_ed448_export_private_only(&self.ws_key,
                 private.as_mut_ptr(), &mut private_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -390,23 +409,25 @@ impl Ed448 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let mut private = [0u8; Ed448::PRV_KEY_SIZE];
     /// let mut public = [0u8; Ed448::PUB_KEY_SIZE];
     /// ed.export_key(&mut private, &mut public).expect("Error with export_key()");
-    /// let mut ed = Ed448::new().expect("Error with new()");
+    /// let mut ed = Ed448::new(&wolfcrypt).expect("Error with new()");
     /// ed.import_public(&public).expect("Error with import_public()");
     /// }
     /// ```
     #[cfg(ed448_import)]
-    pub fn import_public(&mut self, public: &[u8]) -> Result<(), i32> {
+    pub fn import_public(&mut self, public: &[u8]) -> Result<(), Error> {
         let public_size = crate::buffer_len_to_u32(public.len())?;
         let rc = unsafe {
             sys::wc_ed448_import_public(public.as_ptr(), public_size, &mut self.ws_key)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -434,24 +455,26 @@ impl Ed448 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let mut private = [0u8; Ed448::PRV_KEY_SIZE];
     /// let mut public = [0u8; Ed448::PUB_KEY_SIZE];
     /// ed.export_key(&mut private, &mut public).expect("Error with export_key()");
-    /// let mut ed = Ed448::new().expect("Error with new()");
+    /// let mut ed = Ed448::new(&wolfcrypt).expect("Error with new()");
     /// ed.import_public_ex(&public, false).expect("Error with import_public_ex()");
     /// }
     /// ```
     #[cfg(ed448_import)]
-    pub fn import_public_ex(&mut self, public: &[u8], trusted: bool) -> Result<(), i32> {
+    pub fn import_public_ex(&mut self, public: &[u8], trusted: bool) -> Result<(), Error> {
         let public_size = crate::buffer_len_to_u32(public.len())?;
         let rc = unsafe {
             sys::wc_ed448_import_public_ex(public.as_ptr(), public_size,
                 &mut self.ws_key, if trusted {1} else {0})
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -474,23 +497,25 @@ impl Ed448 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let mut private_only = [0u8; Ed448::KEY_SIZE];
     /// ed.export_private_only(&mut private_only).expect("Error with export_private_only()");
-    /// let mut ed = Ed448::new().expect("Error with new()");
+    /// let mut ed = Ed448::new(&wolfcrypt).expect("Error with new()");
     /// ed.import_private_only(&private_only).expect("Error with import_private_only()");
     /// }
     /// ```
     #[cfg(ed448_import)]
-    pub fn import_private_only(&mut self, private: &[u8]) -> Result<(), i32> {
+    pub fn import_private_only(&mut self, private: &[u8]) -> Result<(), Error> {
         let private_size = crate::buffer_len_to_u32(private.len())?;
         let rc = unsafe {
             sys::wc_ed448_import_private_only(private.as_ptr(), private_size,
                 &mut self.ws_key)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -518,17 +543,19 @@ impl Ed448 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let mut private = [0u8; Ed448::PRV_KEY_SIZE];
     /// let mut public = [0u8; Ed448::PUB_KEY_SIZE];
     /// ed.export_key(&mut private, &mut public).expect("Error with export_key()");
-    /// let mut ed = Ed448::new().expect("Error with new()");
+    /// let mut ed = Ed448::new(&wolfcrypt).expect("Error with new()");
     /// ed.import_private_key(&private, Some(&public)).expect("Error with import_private_key()");
     /// }
     /// ```
     #[cfg(ed448_import)]
-    pub fn import_private_key(&mut self, private: &[u8], public: Option<&[u8]>) -> Result<(), i32> {
+    pub fn import_private_key(&mut self, private: &[u8], public: Option<&[u8]>) -> Result<(), Error> {
         let private_size = crate::buffer_len_to_u32(private.len())?;
         let mut public_ptr: *const u8 = core::ptr::null();
         let mut public_size = 0u32;
@@ -541,7 +568,7 @@ impl Ed448 {
                 public_ptr, public_size, &mut self.ws_key)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -569,17 +596,19 @@ impl Ed448 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let mut private = [0u8; Ed448::PRV_KEY_SIZE];
     /// let mut public = [0u8; Ed448::PUB_KEY_SIZE];
     /// ed.export_key(&mut private, &mut public).expect("Error with export_key()");
-    /// let mut ed = Ed448::new().expect("Error with new()");
+    /// let mut ed = Ed448::new(&wolfcrypt).expect("Error with new()");
     /// ed.import_private_key_ex(&private, Some(&public), false).expect("Error with import_private_key_ex()");
     /// }
     /// ```
     #[cfg(ed448_import)]
-    pub fn import_private_key_ex(&mut self, private: &[u8], public: Option<&[u8]>, trusted: bool) -> Result<(), i32> {
+    pub fn import_private_key_ex(&mut self, private: &[u8], public: Option<&[u8]>, trusted: bool) -> Result<(), Error> {
         let private_size = crate::buffer_len_to_u32(private.len())?;
         let mut public_ptr: *const u8 = core::ptr::null();
         let mut public_size = 0u32;
@@ -592,7 +621,7 @@ impl Ed448 {
                 public_ptr, public_size, &mut self.ws_key, if trusted {1} else {0})
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -616,23 +645,25 @@ impl Ed448 {
     /// ```rust
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let mut private = [0u8; Ed448::KEY_SIZE];
     /// ed.export_private_only(&mut private).expect("Error with export_private_only()");
-    /// let mut ed = Ed448::new().expect("Error with new()");
+    /// let mut ed = Ed448::new(&wolfcrypt).expect("Error with new()");
     /// ed.import_private_only(&private).expect("Error with import_private_only()");
     /// let mut public = [0u8; Ed448::KEY_SIZE];
     /// ed.make_public(&mut public).expect("Error with make_public()");
     /// ```
-    pub fn make_public(&mut self, pubkey: &mut [u8]) -> Result<(), i32> {
+    pub fn make_public(&mut self, pubkey: &mut [u8]) -> Result<(), Error> {
         let pubkey_size = crate::buffer_len_to_u32(pubkey.len())?;
         let rc = unsafe {
             sys::wc_ed448_make_public(&mut self.ws_key,
                 pubkey.as_mut_ptr(), pubkey_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -660,8 +691,10 @@ impl Ed448 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let message = [0x42u8, 33, 55, 66];
     /// let context = b"context";
     /// let mut signature = [0u8; Ed448::SIG_SIZE];
@@ -669,14 +702,14 @@ impl Ed448 {
     /// }
     /// ```
     #[cfg(ed448_sign)]
-    pub fn sign_msg(&mut self, message: &[u8], context: Option<&[u8]>, signature: &mut [u8]) -> Result<usize, i32> {
+    pub fn sign_msg(&mut self, message: &[u8], context: Option<&[u8]>, signature: &mut [u8]) -> Result<usize, Error> {
         let message_size = crate::buffer_len_to_u32(message.len())?;
         let mut context_ptr: *const u8 = core::ptr::null();
         let mut context_size = 0u8;
         if let Some(context) = context {
             context_ptr = context.as_ptr();
             if context.len() > 255 {
-                return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+                return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
             }
             context_size = context.len() as u8;
         }
@@ -687,7 +720,7 @@ impl Ed448 {
                 context_ptr, context_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(signature_size as usize)
     }
@@ -716,8 +749,10 @@ impl Ed448 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let hash = [
     ///     0xddu8,0xaf,0x35,0xa1,0x93,0x61,0x7a,0xba,
     ///     0xcc,0x41,0x73,0x49,0xae,0x20,0x41,0x31,
@@ -734,14 +769,14 @@ impl Ed448 {
     /// }
     /// ```
     #[cfg(ed448_sign)]
-    pub fn sign_hash_ph(&mut self, hash: &[u8], context: Option<&[u8]>, signature: &mut [u8]) -> Result<usize, i32> {
+    pub fn sign_hash_ph(&mut self, hash: &[u8], context: Option<&[u8]>, signature: &mut [u8]) -> Result<usize, Error> {
         let hash_size = crate::buffer_len_to_u32(hash.len())?;
         let mut context_ptr: *const u8 = core::ptr::null();
         let mut context_size = 0u8;
         if let Some(context) = context {
             context_ptr = context.as_ptr();
             if context.len() > 255 {
-                return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+                return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
             }
             context_size = context.len() as u8;
         }
@@ -752,7 +787,7 @@ impl Ed448 {
                 context_ptr, context_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(signature_size as usize)
     }
@@ -781,8 +816,10 @@ impl Ed448 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let message = [0x42u8, 33, 55, 66];
     /// let context = b"context";
     /// let mut signature = [0u8; Ed448::SIG_SIZE];
@@ -790,14 +827,14 @@ impl Ed448 {
     /// }
     /// ```
     #[cfg(ed448_sign)]
-    pub fn sign_msg_ph(&mut self, message: &[u8], context: Option<&[u8]>, signature: &mut [u8]) -> Result<usize, i32> {
+    pub fn sign_msg_ph(&mut self, message: &[u8], context: Option<&[u8]>, signature: &mut [u8]) -> Result<usize, Error> {
         let message_size = crate::buffer_len_to_u32(message.len())?;
         let mut context_ptr: *const u8 = core::ptr::null();
         let mut context_size = 0u8;
         if let Some(context) = context {
             context_ptr = context.as_ptr();
             if context.len() > 255 {
-                return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+                return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
             }
             context_size = context.len() as u8;
         }
@@ -808,7 +845,7 @@ impl Ed448 {
                 context_ptr, context_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(signature_size as usize)
     }
@@ -837,8 +874,10 @@ impl Ed448 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let message = [0x42u8, 33, 55, 66];
     /// let context = b"context";
     /// let mut signature = [0u8; Ed448::SIG_SIZE];
@@ -846,14 +885,14 @@ impl Ed448 {
     /// }
     /// ```
     #[cfg(ed448_sign)]
-    pub fn sign_msg_ex(&mut self, din: &[u8], context: Option<&[u8]>, typ: u8, signature: &mut [u8]) -> Result<usize, i32> {
+    pub fn sign_msg_ex(&mut self, din: &[u8], context: Option<&[u8]>, typ: u8, signature: &mut [u8]) -> Result<usize, Error> {
         let din_size = crate::buffer_len_to_u32(din.len())?;
         let mut context_ptr: *const u8 = core::ptr::null();
         let mut context_size = 0u8;
         if let Some(context) = context {
             context_ptr = context.as_ptr();
             if context.len() > 255 {
-                return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+                return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
             }
             context_size = context.len() as u8;
         }
@@ -864,7 +903,7 @@ impl Ed448 {
                 typ, context_ptr, context_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(signature_size as usize)
     }
@@ -891,8 +930,10 @@ impl Ed448 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let message = b"Hello!";
     /// let context = b"context";
     /// let mut signature = [0u8; Ed448::SIG_SIZE];
@@ -902,7 +943,7 @@ impl Ed448 {
     /// }
     /// ```
     #[cfg(ed448_verify)]
-    pub fn verify_msg(&mut self, signature: &[u8], message: &[u8], context: Option<&[u8]>) -> Result<bool, i32> {
+    pub fn verify_msg(&mut self, signature: &[u8], message: &[u8], context: Option<&[u8]>) -> Result<bool, Error> {
         let signature_size = crate::buffer_len_to_u32(signature.len())?;
         let message_size = crate::buffer_len_to_u32(message.len())?;
         let mut context_ptr: *const u8 = core::ptr::null();
@@ -910,7 +951,7 @@ impl Ed448 {
         if let Some(context) = context {
             context_ptr = context.as_ptr();
             if context.len() > 255 {
-                return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+                return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
             }
             context_size = context.len() as u8;
         }
@@ -926,7 +967,7 @@ impl Ed448 {
             return Ok(false);
         }
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(res == 1)
     }
@@ -955,8 +996,10 @@ impl Ed448 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let hash = [
     ///     0xddu8,0xaf,0x35,0xa1,0x93,0x61,0x7a,0xba,
     ///     0xcc,0x41,0x73,0x49,0xae,0x20,0x41,0x31,
@@ -975,7 +1018,7 @@ impl Ed448 {
     /// }
     /// ```
     #[cfg(ed448_verify)]
-    pub fn verify_hash_ph(&mut self, signature: &[u8], hash: &[u8], context: Option<&[u8]>) -> Result<bool, i32> {
+    pub fn verify_hash_ph(&mut self, signature: &[u8], hash: &[u8], context: Option<&[u8]>) -> Result<bool, Error> {
         let signature_size = crate::buffer_len_to_u32(signature.len())?;
         let hash_size = crate::buffer_len_to_u32(hash.len())?;
         let mut context_ptr: *const u8 = core::ptr::null();
@@ -983,7 +1026,7 @@ impl Ed448 {
         if let Some(context) = context {
             context_ptr = context.as_ptr();
             if context.len() > 255 {
-                return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+                return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
             }
             context_size = context.len() as u8;
         }
@@ -999,7 +1042,7 @@ impl Ed448 {
             return Ok(false);
         }
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(res == 1)
     }
@@ -1027,8 +1070,10 @@ impl Ed448 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let message = [0x42u8, 33, 55, 66];
     /// let context = b"context";
     /// let mut signature = [0u8; Ed448::SIG_SIZE];
@@ -1038,7 +1083,7 @@ impl Ed448 {
     /// }
     /// ```
     #[cfg(ed448_verify)]
-    pub fn verify_msg_ph(&mut self, signature: &[u8], message: &[u8], context: Option<&[u8]>) -> Result<bool, i32> {
+    pub fn verify_msg_ph(&mut self, signature: &[u8], message: &[u8], context: Option<&[u8]>) -> Result<bool, Error> {
         let signature_size = crate::buffer_len_to_u32(signature.len())?;
         let message_size = crate::buffer_len_to_u32(message.len())?;
         let mut context_ptr: *const u8 = core::ptr::null();
@@ -1046,7 +1091,7 @@ impl Ed448 {
         if let Some(context) = context {
             context_ptr = context.as_ptr();
             if context.len() > 255 {
-                return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+                return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
             }
             context_size = context.len() as u8;
         }
@@ -1062,7 +1107,7 @@ impl Ed448 {
             return Ok(false);
         }
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(res == 1)
     }
@@ -1090,8 +1135,10 @@ impl Ed448 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let message = [0x42u8, 33, 55, 66];
     /// let context = b"context";
     /// let mut signature = [0u8; Ed448::SIG_SIZE];
@@ -1101,7 +1148,7 @@ impl Ed448 {
     /// }
     /// ```
     #[cfg(ed448_verify)]
-    pub fn verify_msg_ex(&mut self, signature: &[u8], din: &[u8], context: Option<&[u8]>, typ: u8) -> Result<bool, i32> {
+    pub fn verify_msg_ex(&mut self, signature: &[u8], din: &[u8], context: Option<&[u8]>, typ: u8) -> Result<bool, Error> {
         let signature_size = crate::buffer_len_to_u32(signature.len())?;
         let din_size = crate::buffer_len_to_u32(din.len())?;
         let mut context_ptr: *const u8 = core::ptr::null();
@@ -1109,7 +1156,7 @@ impl Ed448 {
         if let Some(context) = context {
             context_ptr = context.as_ptr();
             if context.len() > 255 {
-                return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+                return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
             }
             context_size = context.len() as u8;
         }
@@ -1125,7 +1172,7 @@ impl Ed448 {
             return Ok(false);
         }
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(res == 1)
     }
@@ -1150,8 +1197,10 @@ impl Ed448 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let message = [0x42u8, 33, 55, 66];
     /// let context = [0x42u8, 1, 2, 3];
     /// let mut signature = [0u8; Ed448::SIG_SIZE];
@@ -1164,14 +1213,14 @@ impl Ed448 {
     /// }
     /// ```
     #[cfg(ed448_streaming_verify)]
-    pub fn verify_msg_init(&mut self, signature: &[u8], context: Option<&[u8]>, typ: u8) -> Result<(), i32> {
+    pub fn verify_msg_init(&mut self, signature: &[u8], context: Option<&[u8]>, typ: u8) -> Result<(), Error> {
         let signature_size = crate::buffer_len_to_u32(signature.len())?;
         let mut context_ptr: *const u8 = core::ptr::null();
         let mut context_size = 0u8;
         if let Some(context) = context {
             context_ptr = context.as_ptr();
             if context.len() > 255 {
-                return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+                return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
             }
             context_size = context.len() as u8;
         }
@@ -1180,7 +1229,7 @@ impl Ed448 {
                 &mut self.ws_key, typ, context_ptr, context_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1203,8 +1252,10 @@ impl Ed448 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let message = [0x42u8, 33, 55, 66];
     /// let context = [0x42u8, 1, 2, 3];
     /// let mut signature = [0u8; Ed448::SIG_SIZE];
@@ -1217,14 +1268,14 @@ impl Ed448 {
     /// }
     /// ```
     #[cfg(ed448_streaming_verify)]
-    pub fn verify_msg_update(&mut self, din: &[u8]) -> Result<(), i32> {
+    pub fn verify_msg_update(&mut self, din: &[u8]) -> Result<(), Error> {
         let din_size = crate::buffer_len_to_u32(din.len())?;
         let rc = unsafe {
             sys::wc_ed448_verify_msg_update(din.as_ptr(), din_size,
                 &mut self.ws_key)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1247,8 +1298,10 @@ impl Ed448 {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let message = [0x42u8, 33, 55, 66];
     /// let context = [0x42u8, 1, 2, 3];
     /// let mut signature = [0u8; Ed448::SIG_SIZE];
@@ -1261,7 +1314,7 @@ impl Ed448 {
     /// }
     /// ```
     #[cfg(ed448_streaming_verify)]
-    pub fn verify_msg_final(&mut self, signature: &[u8]) -> Result<bool, i32> {
+    pub fn verify_msg_final(&mut self, signature: &[u8]) -> Result<bool, Error> {
         let signature_size = crate::buffer_len_to_u32(signature.len())?;
         let mut res = 0i32;
         let rc = unsafe {
@@ -1274,7 +1327,7 @@ impl Ed448 {
             return Ok(false);
         }
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(res == 1)
     }
@@ -1291,15 +1344,17 @@ impl Ed448 {
     /// ```rust
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let key_size = ed.size().expect("Error with size()");
     /// assert_eq!(key_size, Ed448::KEY_SIZE);
     /// ```
-    pub fn size(&self) -> Result<usize, i32> {
+    pub fn size(&self) -> Result<usize, Error> {
         let rc = unsafe { sys::wc_ed448_size(&self.ws_key) };
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(rc as usize)
     }
@@ -1316,15 +1371,17 @@ impl Ed448 {
     /// ```rust
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let priv_size = ed.priv_size().expect("Error with priv_size()");
     /// assert_eq!(priv_size, Ed448::PRV_KEY_SIZE);
     /// ```
-    pub fn priv_size(&self) -> Result<usize, i32> {
+    pub fn priv_size(&self) -> Result<usize, Error> {
         let rc = unsafe { sys::wc_ed448_priv_size(&self.ws_key) };
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(rc as usize)
     }
@@ -1341,15 +1398,17 @@ impl Ed448 {
     /// ```rust
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let pub_size = ed.pub_size().expect("Error with pub_size()");
     /// assert_eq!(pub_size, Ed448::PUB_KEY_SIZE);
     /// ```
-    pub fn pub_size(&self) -> Result<usize, i32> {
+    pub fn pub_size(&self) -> Result<usize, Error> {
         let rc = unsafe { sys::wc_ed448_pub_size(&self.ws_key) };
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(rc as usize)
     }
@@ -1366,27 +1425,29 @@ impl Ed448 {
     /// ```rust
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::ed448::Ed448;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     /// let sig_size = ed.sig_size().expect("Error with sig_size()");
     /// assert_eq!(sig_size, Ed448::SIG_SIZE);
     /// ```
-    pub fn sig_size(&self) -> Result<usize, i32> {
+    pub fn sig_size(&self) -> Result<usize, Error> {
         let rc = unsafe { sys::wc_ed448_sig_size(&self.ws_key) };
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(rc as usize)
     }
 }
 
-impl Ed448 {
+impl<'a> Ed448<'a> {
     fn zeroize(&mut self) {
         unsafe { crate::zeroize_raw(&mut self.ws_key); }
     }
 }
 
-impl Drop for Ed448 {
+impl Drop for Ed448<'_> {
     /// Safely free the wolfSSL resources.
     fn drop(&mut self) {
         unsafe { sys::wc_ed448_free(&mut self.ws_key); }
@@ -1484,7 +1545,7 @@ mod signature_impl {
     }
 
     #[cfg(all(ed448_sign, ed448_export))]
-    impl signature::Keypair for Ed448 {
+    impl signature::Keypair for Ed448<'_> {
         type VerifyingKey = VerifyingKey;
         fn verifying_key(&self) -> Self::VerifyingKey {
             let mut pub_key = [0u8; Ed448::PUB_KEY_SIZE];
@@ -1494,7 +1555,7 @@ mod signature_impl {
     }
 
     #[cfg(ed448_sign)]
-    impl signature::SignerMut<Signature> for Ed448 {
+    impl signature::SignerMut<Signature> for Ed448<'_> {
         fn try_sign(&mut self, msg: &[u8]) -> Result<Signature, Error> {
             let mut sig = [0u8; Ed448::SIG_SIZE];
             self.sign_msg(msg, None, &mut sig).map_err(|_| Error::new())?;
@@ -1505,7 +1566,8 @@ mod signature_impl {
     #[cfg(all(ed448_import, ed448_verify))]
     impl signature::Verifier<Signature> for VerifyingKey {
         fn verify(&self, msg: &[u8], signature: &Signature) -> Result<(), Error> {
-            let mut key = Ed448::new().map_err(|_| Error::new())?;
+            let wolfcrypt = crate::WolfCrypt::global().map_err(|_| Error::new())?;
+            let mut key = Ed448::new(wolfcrypt).map_err(|_| Error::new())?;
             key.import_public(&self.0).map_err(|_| Error::new())?;
             let valid = key
                 .verify_msg(&signature.0, msg, None)
@@ -0,0 +1,302 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+This module provides the [`Env`] context passed to every `new_ex()` style
+constructor in this crate, bundling the [`WolfCrypt`] guard, the
+[`HeapHint`] and the [`Device`] the wolfCrypt object is created with.
+
+A [`Device`] is a handle to a registered crypto callback device. It is owned
+by the registration of the device ([`crate::cryptocb::Registration`] or
+[`crate::pkcs11::Pkcs11Token`]), which an [`Env`] borrows it from, or
+obtained for the library default device ([`Device::default_device()`]), so
+an object cannot be bound to a device ID that was never registered.
+
+An object created with a registered device holds a reference to its
+registration. When the owner of the device is dropped, the device ID is left
+registered without a callback until the last of these objects is dropped:
+the objects fall back to the wolfCrypt software implementation and the ID
+cannot be registered for another device in the meantime.
+
+# Examples
+
+```rust
+#[cfg(sha256)]
+{
+use wolfssl_wolfcrypt::{Env, WolfCrypt};
+use wolfssl_wolfcrypt::sha::SHA256;
+
+let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+let env = Env::new(&wolfcrypt);
+let mut sha = SHA256::new_ex(&env).expect("Error with new_ex()");
+sha.update(b"abc").expect("Error with update()");
+}
+```
+*/
+
+use crate::WolfCrypt;
+use crate::memory::HeapHint;
+use crate::sys;
+#[cfg(all(wolf_crypto_cb, feature = "alloc"))]
+use alloc::sync::Arc;
+
+/// Handle to a crypto callback device registered with wolfCrypt.
+///
+/// Objects created with a device route their operations through it. An
+/// object whose device has since been unregistered falls back to the
+/// wolfCrypt software implementation, and keeps the device ID reserved
+/// until it is dropped.
+///
+/// A `Device` is not `Copy` or `Clone`: it is owned by the registration of
+/// the device and only borrowed by an [`Env`]. Two handles are equal when
+/// they have the same device ID.
+#[derive(Debug)]
+pub struct Device {
+    id: i32,
+    #[cfg(all(wolf_crypto_cb, feature = "alloc"))]
+    slot: Option<Arc<DeviceSlot>>,
+}
+
+impl PartialEq for Device {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Device {}
+
+impl core::hash::Hash for Device {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl Device {
+    /// Create a device handle for a device ID that was just registered.
+    ///
+    /// `release` is called to unregister the device ID once the handle and
+    /// every object created with it have been dropped. The owner of the
+    /// device must replace its callback with [`Device::detach()`] when it
+    /// stops serving the device.
+    #[cfg(all(wolf_crypto_cb, feature = "alloc"))]
+    pub(crate) fn from_registered(id: i32, release: fn(i32), wolfcrypt: &WolfCrypt) -> Self {
+        let slot = DeviceSlot { id, release, _wolfcrypt: wolfcrypt.clone() };
+        Device { id, slot: Some(Arc::new(slot)) }
+    }
+
+    /// Leave the device ID registered without a callback, so that objects
+    /// created with the device fall back to software until the ID is
+    /// released.
+    ///
+    /// The caller serializes this with the other registrations of its
+    /// devices.
+    #[cfg(all(wolf_crypto_cb, feature = "alloc"))]
+    pub(crate) fn detach(&self) {
+        unsafe {
+            sys::wc_CryptoCb_UnRegisterDevice(self.id);
+            sys::wc_CryptoCb_RegisterDevice(self.id, None, core::ptr::null_mut());
+        }
+    }
+
+    /// Get the wolfCrypt device ID of this device.
+    ///
+    /// # Returns
+    ///
+    /// Returns the device ID.
+    pub const fn id(&self) -> i32 {
+        self.id
+    }
+
+    /// Get the default device of the wolfSSL library.
+    ///
+    /// Calls `wc_CryptoCb_DefaultDevID()`, which returns the device selected
+    /// by the library build (e.g. `WC_USE_DEVID`) or the first registered
+    /// device.
+    ///
+    /// # Returns
+    ///
+    /// Returns Some(Device) or None if there is no default device.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(wolf_crypto_cb)]
+    /// {
+    /// use wolfssl_wolfcrypt::{Device, Env, WolfCrypt};
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let device = Device::default_device();
+    /// let env = match &device {
+    ///     Some(device) => Env::new(&wolfcrypt).with_device(device),
+    ///     None => Env::new(&wolfcrypt),
+    /// };
+    /// }
+    /// ```
+    #[cfg(wolf_crypto_cb)]
+    pub fn default_device() -> Option<Self> {
+        let id = unsafe { sys::wc_CryptoCb_DefaultDevID() };
+        if id == sys::INVALID_DEVID {
+            return None;
+        }
+        Some(Device {
+            id,
+            #[cfg(feature = "alloc")]
+            slot: None,
+        })
+    }
+}
+
+/// Registration of a device ID, shared by the [`Device`] handle and the
+/// objects created with it. The ID is released when the last of them is
+/// dropped. The library guard keeps wolfCrypt initialized until then.
+#[cfg(all(wolf_crypto_cb, feature = "alloc"))]
+#[derive(Debug)]
+struct DeviceSlot {
+    id: i32,
+    release: fn(i32),
+    _wolfcrypt: WolfCrypt,
+}
+
+#[cfg(all(wolf_crypto_cb, feature = "alloc"))]
+impl Drop for DeviceSlot {
+    fn drop(&mut self) {
+        (self.release)(self.id);
+    }
+}
+
+/// Reference a wolfCrypt object holds to the registration of the device it
+/// was created with, if any.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct DeviceRef {
+    #[cfg(all(wolf_crypto_cb, feature = "alloc"))]
+    _slot: Option<Arc<DeviceSlot>>,
+}
+
+/// Context a wolfCrypt object is created in: the library guard the object
+/// keeps a clone of, the heap hint for its memory and the crypto callback
+/// device for its operations.
+///
+/// `Env::new(&wolfcrypt)` uses the default allocator and the wolfCrypt software
+/// implementation. The device is borrowed for `'a` and the memory pool of
+/// the heap hint for `'h`. The objects created in the context carry `'h`,
+/// so they cannot outlive their memory pool, and hold a reference to the
+/// registration of the device (see the [module documentation](self)).
+#[derive(Clone, Debug)]
+pub struct Env<'a, 'h> {
+    wolfcrypt: WolfCrypt,
+    heap: Option<HeapHint<'h>>,
+    device: Option<&'a Device>,
+}
+
+impl<'a, 'h> Env<'a, 'h> {
+    /// Create a context with no heap hint and no device.
+    ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library. The context and
+    ///   each object created in it hold a clone of it.
+    ///
+    /// # Returns
+    ///
+    /// Returns the context.
+    pub fn new(wolfcrypt: &WolfCrypt) -> Self {
+        Env { wolfcrypt: wolfcrypt.clone(), heap: None, device: None }
+    }
+
+    /// Set the heap hint of the context.
+    ///
+    /// # Parameters
+    ///
+    /// * `heap`: Heap hint for the memory of the objects created.
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated context.
+    pub fn with_heap(mut self, heap: HeapHint<'h>) -> Self {
+        self.heap = Some(heap);
+        self
+    }
+
+    /// Set the crypto callback device of the context.
+    ///
+    /// # Parameters
+    ///
+    /// * `device`: Device for the operations of the objects created,
+    ///   borrowed from its registration.
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated context.
+    pub fn with_device(mut self, device: &'a Device) -> Self {
+        self.device = Some(device);
+        self
+    }
+
+    /// Get the library guard of the context.
+    ///
+    /// # Returns
+    ///
+    /// Returns the guard.
+    pub fn wolfcrypt(&self) -> &WolfCrypt {
+        &self.wolfcrypt
+    }
+
+    /// Get the heap hint of the context.
+    ///
+    /// # Returns
+    ///
+    /// Returns the heap hint, if any.
+    pub const fn heap(&self) -> Option<HeapHint<'h>> {
+        self.heap
+    }
+
+    /// Get the crypto callback device of the context.
+    ///
+    /// # Returns
+    ///
+    /// Returns the device, if any.
+    pub const fn device(&self) -> Option<&'a Device> {
+        self.device
+    }
+
+    /// Raw heap pointer to pass to wolfCrypt.
+    pub(crate) fn heap_ptr(&self) -> *mut core::ffi::c_void {
+        match self.heap {
+            Some(heap) => heap.as_ptr(),
+            None => core::ptr::null_mut(),
+        }
+    }
+
+    /// Raw device ID to pass to wolfCrypt.
+    pub(crate) fn dev_id(&self) -> i32 {
+        match self.device {
+            Some(device) => device.id,
+            None => sys::INVALID_DEVID,
+        }
+    }
+
+    /// Reference to the registration of the device, for an object created
+    /// with [`Env::dev_id()`] to hold.
+    pub(crate) fn device_ref(&self) -> DeviceRef {
+        DeviceRef {
+            #[cfg(all(wolf_crypto_cb, feature = "alloc"))]
+            _slot: self.device.and_then(|device| device.slot.clone()),
+        }
+    }
+}
@@ -0,0 +1,198 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+This module provides the [`Error`] type returned by every fallible function
+in this crate.
+
+An `Error` classifies a wolfCrypt error code (one of the
+`wolfCrypt_ErrorCodes` values from `error-crypt.h`) into a broad category
+and always carries the raw code, which is available from [`Error::code()`].
+The `Display` implementation renders the wolfCrypt error string for the code
+as returned by `wc_GetErrorString()`.
+
+# Examples
+
+```rust
+use wolfssl_wolfcrypt::Error;
+use wolfssl_wolfcrypt::sys;
+
+let err = Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+assert!(matches!(err, Error::BadFunc(_)));
+assert_eq!(err.code(), sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+assert_eq!(err, sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+```
+*/
+
+use crate::sys;
+use core::ffi::CStr;
+use core::fmt;
+
+/// Error returned by wolfCrypt operations.
+///
+/// Each variant carries the raw wolfCrypt error code it was created from.
+/// New variants may be added as more error codes are classified, so matches
+/// on this type must include a wildcard arm.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Error {
+    /// Bad function argument (`BAD_FUNC_ARG`, `ECC_BAD_ARG_E`).
+    BadFunc(i32),
+    /// Output buffer too small or bad buffer or data length (`BUFFER_E`,
+    /// `RSA_BUFFER_E`, `BAD_LENGTH_E`).
+    Buffer(i32),
+    /// Memory allocation failure (`MEMORY_E`, `MP_MEM`).
+    Memory(i32),
+    /// Random number generator or entropy source failure (`RNG_FAILURE_E`,
+    /// `MISSING_RNG_E`, `RAN_BLOCK_E`, ...).
+    Rng(i32),
+    /// Signature verification failure (`SIG_VERIFY_E`, `ASN_SIG_CONFIRM_E`).
+    Verify(i32),
+    /// Authentication tag or MAC mismatch (`AES_GCM_AUTH_E`,
+    /// `AES_CCM_AUTH_E`, `MAC_CMP_FAILED_E`, ...).
+    Auth(i32),
+    /// Object or key in the wrong state for the operation (`BAD_STATE_E`,
+    /// `MISSING_KEY`, `ECC_PRIV_KEY_E`, ...).
+    KeyState(i32),
+    /// Algorithm or feature not compiled into the wolfSSL library
+    /// (`NOT_COMPILED_IN`).
+    NotCompiledIn(i32),
+    /// ASN.1 / DER parsing or encoding failure (`ASN_PARSE_E`, ...).
+    Asn(i32),
+    /// Hardware or crypto callback device failure (`WC_HW_E`,
+    /// `CRYPTOCB_UNAVAILABLE`, ...).
+    Hardware(i32),
+    /// Any other wolfCrypt error code.
+    Other(i32),
+}
+
+impl Error {
+    /// Get the raw wolfCrypt error code.
+    ///
+    /// # Returns
+    ///
+    /// Returns the `wolfCrypt_ErrorCodes` value this error was created from.
+    pub const fn code(&self) -> i32 {
+        match *self {
+            Error::BadFunc(code)
+            | Error::Buffer(code)
+            | Error::Memory(code)
+            | Error::Rng(code)
+            | Error::Verify(code)
+            | Error::Auth(code)
+            | Error::KeyState(code)
+            | Error::NotCompiledIn(code)
+            | Error::Asn(code)
+            | Error::Hardware(code)
+            | Error::Other(code) => code,
+        }
+    }
+
+    /// Get the wolfCrypt error string for this error.
+    ///
+    /// # Returns
+    ///
+    /// Returns the string from `wc_GetErrorString()`, or `None` if it is not
+    /// valid UTF-8.
+    pub fn message(&self) -> Option<&'static str> {
+        let ptr = unsafe { sys::wc_GetErrorString(self.code()) };
+        if ptr.is_null() {
+            return None;
+        }
+        let s = unsafe { CStr::from_ptr(ptr) };
+        s.to_str().ok()
+    }
+}
+
+impl From<i32> for Error {
+    fn from(code: i32) -> Self {
+        match code {
+            sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG
+            | sys::wolfCrypt_ErrorCodes_ECC_BAD_ARG_E => Error::BadFunc(code),
+            sys::wolfCrypt_ErrorCodes_BUFFER_E
+            | sys::wolfCrypt_ErrorCodes_RSA_BUFFER_E
+            | sys::wolfCrypt_ErrorCodes_BAD_LENGTH_E => Error::Buffer(code),
+            sys::wolfCrypt_ErrorCodes_MEMORY_E
+            | sys::wolfCrypt_ErrorCodes_MP_MEM => Error::Memory(code),
+            sys::wolfCrypt_ErrorCodes_RNG_FAILURE_E
+            | sys::wolfCrypt_ErrorCodes_MISSING_RNG_E
+            | sys::wolfCrypt_ErrorCodes_OPEN_RAN_E
+            | sys::wolfCrypt_ErrorCodes_READ_RAN_E
+            | sys::wolfCrypt_ErrorCodes_RAN_BLOCK_E
+            | sys::wolfCrypt_ErrorCodes_DRBG_CONT_FIPS_E => Error::Rng(code),
+            sys::wolfCrypt_ErrorCodes_SIG_VERIFY_E
+            | sys::wolfCrypt_ErrorCodes_ASN_SIG_CONFIRM_E => Error::Verify(code),
+            sys::wolfCrypt_ErrorCodes_AES_GCM_AUTH_E
+            | sys::wolfCrypt_ErrorCodes_AES_CCM_AUTH_E
+            | sys::wolfCrypt_ErrorCodes_MAC_CMP_FAILED_E => Error::Auth(code),
+            sys::wolfCrypt_ErrorCodes_BAD_STATE_E
+            | sys::wolfCrypt_ErrorCodes_MISSING_KEY
+            | sys::wolfCrypt_ErrorCodes_ECC_PRIV_KEY_E
+            | sys::wolfCrypt_ErrorCodes_ECC_PRIVATEONLY_E
+            | sys::wolfCrypt_ErrorCodes_RSA_KEY_PAIR_E => Error::KeyState(code),
+            sys::wolfCrypt_ErrorCodes_NOT_COMPILED_IN => Error::NotCompiledIn(code),
+            sys::wolfCrypt_ErrorCodes_ASN_PARSE_E
+            | sys::wolfCrypt_ErrorCodes_ASN_VERSION_E
+            | sys::wolfCrypt_ErrorCodes_ASN_GETINT_E
+            | sys::wolfCrypt_ErrorCodes_ASN_RSA_KEY_E
+            | sys::wolfCrypt_ErrorCodes_ASN_OBJECT_ID_E
+            | sys::wolfCrypt_ErrorCodes_ASN_TAG_NULL_E
+            | sys::wolfCrypt_ErrorCodes_ASN_EXPECT_0_E
+            | sys::wolfCrypt_ErrorCodes_ASN_BITSTR_E
+            | sys::wolfCrypt_ErrorCodes_ASN_UNKNOWN_OID_E
+            | sys::wolfCrypt_ErrorCodes_ASN_INPUT_E
+            | sys::wolfCrypt_ErrorCodes_ASN_ECC_KEY_E
+            | sys::wolfCrypt_ErrorCodes_ASN_NO_PEM_HEADER => Error::Asn(code),
+            sys::wolfCrypt_ErrorCodes_WC_HW_E
+            | sys::wolfCrypt_ErrorCodes_WC_HW_WAIT_E
+            | sys::wolfCrypt_ErrorCodes_CRYPTOCB_UNAVAILABLE => Error::Hardware(code),
+            _ => Error::Other(code),
+        }
+    }
+}
+
+impl From<Error> for i32 {
+    fn from(err: Error) -> Self {
+        err.code()
+    }
+}
+
+impl PartialEq<i32> for Error {
+    fn eq(&self, other: &i32) -> bool {
+        self.code() == *other
+    }
+}
+
+impl PartialEq<Error> for i32 {
+    fn eq(&self, other: &Error) -> bool {
+        *self == other.code()
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.message() {
+            Some(msg) => write!(f, "{} ({})", msg, self.code()),
+            None => write!(f, "wolfCrypt error {}", self.code()),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
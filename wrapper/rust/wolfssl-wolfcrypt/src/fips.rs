@@ -1,5 +1,6 @@
 #![cfg(fips)]
 
+use crate::Error;
 use crate::sys;
 
 /// Enables or disables the ability to read private key data in FIPS mode.
@@ -15,19 +16,19 @@ use crate::sys;
 /// # Returns
 ///
 /// * `Ok(())` - The operation succeeded.
-/// * `Err(i32)` - The operation failed, returning the wolfSSL error code.
+/// * `Err(Error)` - The operation failed, returning the wolfSSL error.
 ///
 /// # Note
 ///
 /// This function applies to all key types (`WC_KEYTYPE_ALL`). Private key
 /// reading should be disabled again after the required operation is complete
 /// to maintain FIPS compliance.
-pub fn set_private_key_read_enable(enabled: i32) -> Result<(), i32> {
+pub fn set_private_key_read_enable(enabled: i32) -> Result<(), Error> {
     let rc = unsafe {
         sys::wolfCrypt_SetPrivateKeyReadEnable_fips(enabled, sys::wc_KeyType_WC_KEYTYPE_ALL)
     };
     if rc != 0 {
-        Err(rc)
+        Err(Error::from(rc))
     } else {
         Ok(())
     }
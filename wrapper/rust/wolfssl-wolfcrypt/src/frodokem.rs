@@ -0,0 +1,824 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+This module provides a Rust wrapper for the wolfCrypt library's FrodoKEM
+post-quantum key encapsulation functionality.
+
+FrodoKEM is a conservative key encapsulation mechanism based on the
+unstructured Learning With Errors problem. It has much larger keys and cipher
+texts than ML-KEM, but relies on fewer algebraic assumptions, which makes it a
+useful defense-in-depth companion to ML-KEM.
+
+The primary component is the [`FrodoKem`] struct, which manages the lifecycle
+of a wolfSSL `FrodoKemKey` object. It ensures proper initialization and
+deallocation.
+
+The key type is selected at construction time. Each of the three parameter
+sets can generate matrix A with SHAKE-128 or (with `WOLFSSL_FRODOKEM_AES`)
+AES-128, and is available as standard, salted FrodoKEM or (with
+`WOLFSSL_FRODOKEM_EPHEMERAL`) as eFrodoKEM, intended for ephemeral keys only:
+
+| Parameter set | Security | Public key | Cipher text | Shared secret |
+|---------------|----------|------------|-------------|---------------|
+| FrodoKEM-640  | 1        | 9616       | 9752        | 16            |
+| FrodoKEM-976  | 3        | 15632      | 15792       | 24            |
+| FrodoKEM-1344 | 5        | 21520      | 21696       | 32            |
+
+The eFrodoKEM cipher texts are 9720, 15744 and 21632 bytes respectively.
+
+# Examples
+
+```rust
+#[cfg(all(frodokem, frodokem_make_key, frodokem_encapsulate, frodokem_decapsulate, random))]
+{
+use wolfssl_wolfcrypt::random::RNG;
+use wolfssl_wolfcrypt::frodokem::FrodoKem;
+use wolfssl_wolfcrypt::WolfCrypt;
+let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+let mut rng = RNG::new(&wolfcrypt).expect("RNG creation failed");
+let mut alice = FrodoKem::generate(FrodoKem::TYPE_640_SHAKE, &mut rng, &wolfcrypt)
+    .expect("Key generation failed");
+let ct_size = alice.cipher_text_size().expect("cipher_text_size failed");
+let ss_size = alice.shared_secret_size().expect("shared_secret_size failed");
+let mut ct = vec![0u8; ct_size];
+let mut ss_alice = vec![0u8; ss_size];
+alice.encapsulate(&mut ct, &mut ss_alice, &mut rng)
+    .expect("Encapsulation failed");
+let mut ss_bob = vec![0u8; ss_size];
+alice.decapsulate(&mut ss_bob, &ct)
+    .expect("Decapsulation failed");
+assert_eq!(ss_alice, ss_bob);
+}
+```
+*/
+
+#![cfg(frodokem)]
+
+use crate::Error;
+use crate::memory::HeapHint;
+use crate::sys;
+use core::marker::PhantomData;
+#[cfg(random)]
+use crate::random::RNG;
+
+/// Rust wrapper for a wolfSSL `FrodoKemKey` object.
+///
+/// Manages the lifecycle of the underlying heap-allocated key, including
+/// initialization and deallocation via the [`Drop`] trait.
+///
+/// An instance is created with [`FrodoKem::generate()`],
+/// [`FrodoKem::generate_with_random()`], or [`FrodoKem::new()`].
+///
+/// This struct does not implement Send or Sync because it is not safe in the
+/// general case to access the underlying C API from multiple threads
+/// concurrently.
+pub struct FrodoKem<'a> {
+    ws_key: *mut sys::FrodoKemKey,
+    key_type: i32,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
+}
+
+impl<'a> FrodoKem<'a> {
+    /// FrodoKEM-640 with SHAKE-128 matrix generation (NIST Security Level 1).
+    pub const TYPE_640_SHAKE: i32 = sys::WC_FRODOKEM_640_SHAKE as i32;
+    /// FrodoKEM-976 with SHAKE-128 matrix generation (NIST Security Level 3).
+    pub const TYPE_976_SHAKE: i32 = sys::WC_FRODOKEM_976_SHAKE as i32;
+    /// FrodoKEM-1344 with SHAKE-128 matrix generation (NIST Security Level 5).
+    pub const TYPE_1344_SHAKE: i32 = sys::WC_FRODOKEM_1344_SHAKE as i32;
+    /// FrodoKEM-640 with AES-128 matrix generation.
+    pub const TYPE_640_AES: i32 = sys::WC_FRODOKEM_640_AES as i32;
+    /// FrodoKEM-976 with AES-128 matrix generation.
+    pub const TYPE_976_AES: i32 = sys::WC_FRODOKEM_976_AES as i32;
+    /// FrodoKEM-1344 with AES-128 matrix generation.
+    pub const TYPE_1344_AES: i32 = sys::WC_FRODOKEM_1344_AES as i32;
+    /// eFrodoKEM-640 (ephemeral) with SHAKE-128 matrix generation.
+    pub const TYPE_EFRODOKEM_640_SHAKE: i32 = sys::WC_EFRODOKEM_640_SHAKE as i32;
+    /// eFrodoKEM-976 (ephemeral) with SHAKE-128 matrix generation.
+    pub const TYPE_EFRODOKEM_976_SHAKE: i32 = sys::WC_EFRODOKEM_976_SHAKE as i32;
+    /// eFrodoKEM-1344 (ephemeral) with SHAKE-128 matrix generation.
+    pub const TYPE_EFRODOKEM_1344_SHAKE: i32 = sys::WC_EFRODOKEM_1344_SHAKE as i32;
+    /// eFrodoKEM-640 (ephemeral) with AES-128 matrix generation.
+    pub const TYPE_EFRODOKEM_640_AES: i32 = sys::WC_EFRODOKEM_640_AES as i32;
+    /// eFrodoKEM-976 (ephemeral) with AES-128 matrix generation.
+    pub const TYPE_EFRODOKEM_976_AES: i32 = sys::WC_EFRODOKEM_976_AES as i32;
+    /// eFrodoKEM-1344 (ephemeral) with AES-128 matrix generation.
+    pub const TYPE_EFRODOKEM_1344_AES: i32 = sys::WC_EFRODOKEM_1344_AES as i32;
+
+    /// Size in bytes of the seed used to generate matrix A.
+    const SEED_A_SIZE: usize = sys::FRODOKEM_SEEDA_SZ as usize;
+
+    /// Get `lensec / 8` and whether the key type is eFrodoKEM.
+    fn type_params(key_type: i32) -> Result<(usize, bool), Error> {
+        let known = (sys::FRODOKEM_BASE_MASK | sys::FRODOKEM_AES |
+            sys::FRODOKEM_EPHEMERAL) as i32;
+        if key_type & !known != 0 {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+        }
+        let len_sec = match (key_type & sys::FRODOKEM_BASE_MASK as i32) as u32 {
+            sys::WC_FRODOKEM_640 => sys::WC_FRODOKEM_640_LENSEC,
+            sys::WC_FRODOKEM_976 => sys::WC_FRODOKEM_976_LENSEC,
+            sys::WC_FRODOKEM_1344 => sys::WC_FRODOKEM_1344_LENSEC,
+            _ => return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG)),
+        };
+        let ephemeral = key_type & sys::FRODOKEM_EPHEMERAL as i32 != 0;
+        Ok((len_sec as usize, ephemeral))
+    }
+
+    /// Get the number of random bytes key generation requires for a key type.
+    ///
+    /// This is the length of the `rand` argument to
+    /// [`FrodoKem::generate_with_random()`].
+    ///
+    /// # Parameters
+    ///
+    /// * `key_type`: Key type. One of the `FrodoKem::TYPE_*` constants.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) or Err(e) containing the wolfSSL library error
+    /// code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(frodokem)]
+    /// {
+    /// use wolfssl_wolfcrypt::frodokem::FrodoKem;
+    /// let size = FrodoKem::makekey_rand_size(FrodoKem::TYPE_640_SHAKE)
+    ///     .expect("Error with makekey_rand_size()");
+    /// assert_eq!(size, 64);
+    /// }
+    /// ```
+    pub fn makekey_rand_size(key_type: i32) -> Result<usize, Error> {
+        let (len_sec, ephemeral) = Self::type_params(key_type)?;
+        // s || seedSE || z, where seedSE is lensec (eFrodoKEM) or 2 * lensec
+        // (FrodoKEM) bytes and z is the size of seedA.
+        let len_se = if ephemeral { len_sec } else { 2 * len_sec };
+        Ok(len_sec + len_se + Self::SEED_A_SIZE)
+    }
+
+    /// Get the number of random bytes encapsulation requires for a key type.
+    ///
+    /// This is the length of the `rand` argument to
+    /// [`FrodoKem::encapsulate_with_random()`].
+    ///
+    /// # Parameters
+    ///
+    /// * `key_type`: Key type. One of the `FrodoKem::TYPE_*` constants.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) or Err(e) containing the wolfSSL library error
+    /// code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(frodokem)]
+    /// {
+    /// use wolfssl_wolfcrypt::frodokem::FrodoKem;
+    /// let size = FrodoKem::enc_rand_size(FrodoKem::TYPE_640_SHAKE)
+    ///     .expect("Error with enc_rand_size()");
+    /// assert_eq!(size, 48);
+    /// }
+    /// ```
+    pub fn enc_rand_size(key_type: i32) -> Result<usize, Error> {
+        let (len_sec, ephemeral) = Self::type_params(key_type)?;
+        // u || salt, where eFrodoKEM has no salt and FrodoKEM has a salt of
+        // 2 * lensec bytes.
+        let len_salt = if ephemeral { 0 } else { 2 * len_sec };
+        Ok(len_sec + len_salt)
+    }
+
+    /// Generate a new FrodoKEM key pair using a random number generator.
+    ///
+    /// # Parameters
+    ///
+    /// * `key_type`: Key type. One of the `FrodoKem::TYPE_*` constants.
+    /// * `rng`: `RNG` instance to use for random number generation.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(FrodoKem) containing the key instance or Err(e)
+    /// containing the wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(all(frodokem, frodokem_make_key, random))]
+    /// {
+    /// use wolfssl_wolfcrypt::random::RNG;
+    /// use wolfssl_wolfcrypt::frodokem::FrodoKem;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let key = FrodoKem::generate(FrodoKem::TYPE_640_SHAKE, &mut rng, &wolfcrypt)
+    ///     .expect("Error with generate()");
+    /// }
+    /// ```
+    #[cfg(all(frodokem_make_key, random))]
+    pub fn generate(key_type: i32, rng: &RNG, wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::generate_ex(key_type, rng, &crate::Env::new(wolfcrypt))
+    }
+
+    /// Generate a new FrodoKEM key pair with optional heap hint and device ID.
+    ///
+    /// # Parameters
+    ///
+    /// * `key_type`: Key type. One of the `FrodoKem::TYPE_*` constants.
+    /// * `rng`: `RNG` instance to use for random number generation.
+    /// * `env`: Heap hint and crypto callback device to use.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(FrodoKem) containing the key instance or Err(e)
+    /// containing the wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(all(frodokem, frodokem_make_key, random))]
+    /// {
+    /// use wolfssl_wolfcrypt::Env;
+    /// use wolfssl_wolfcrypt::random::RNG;
+    /// use wolfssl_wolfcrypt::frodokem::FrodoKem;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let key = FrodoKem::generate_ex(FrodoKem::TYPE_640_SHAKE, &mut rng, &Env::new(&wolfcrypt))
+    ///     .expect("Error with generate_ex()");
+    /// }
+    /// ```
+    #[cfg(all(frodokem_make_key, random))]
+    pub fn generate_ex(
+        key_type: i32,
+        rng: &RNG,
+        env: &crate::Env,
+    ) -> Result<Self, Error> {
+        let key = Self::new_ex(key_type, env)?;
+        let rc = unsafe { sys::wc_FrodoKemKey_MakeKey(key.ws_key, rng.wc_rng) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(key)
+    }
+
+    /// Generate a FrodoKEM key pair from caller-supplied random bytes.
+    ///
+    /// Produces the same key pair for a given `(key_type, rand)` pair, enabling
+    /// deterministic key generation for known-answer tests. The `rand` buffer
+    /// must be exactly [`FrodoKem::makekey_rand_size()`] bytes
+    /// (`s || seedSE || z`).
+    ///
+    /// # Parameters
+    ///
+    /// * `key_type`: Key type. One of the `FrodoKem::TYPE_*` constants.
+    /// * `rand`: Random bytes.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(FrodoKem) containing the key instance or Err(e)
+    /// containing the wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(all(frodokem, frodokem_make_key))]
+    /// {
+    /// use wolfssl_wolfcrypt::frodokem::FrodoKem;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rand = [0x42u8; 64];
+    /// let key = FrodoKem::generate_with_random(FrodoKem::TYPE_640_SHAKE, &rand, &wolfcrypt)
+    ///     .expect("Error with generate_with_random()");
+    /// }
+    /// ```
+    #[cfg(frodokem_make_key)]
+    pub fn generate_with_random(key_type: i32, rand: &[u8], wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::generate_with_random_ex(key_type, rand, &crate::Env::new(wolfcrypt))
+    }
+
+    /// Generate a FrodoKEM key pair from caller-supplied random bytes with
+    /// optional heap hint and device ID.
+    ///
+    /// # Parameters
+    ///
+    /// * `key_type`: Key type. One of the `FrodoKem::TYPE_*` constants.
+    /// * `rand`: Random bytes. Must be `makekey_rand_size(key_type)` bytes.
+    /// * `env`: Heap hint and crypto callback device to use.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(FrodoKem) containing the key instance or Err(e)
+    /// containing the wolfSSL library error code value.
+    #[cfg(frodokem_make_key)]
+    pub fn generate_with_random_ex(
+        key_type: i32,
+        rand: &[u8],
+        env: &crate::Env,
+    ) -> Result<Self, Error> {
+        if rand.len() != Self::makekey_rand_size(key_type)? {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        let key = Self::new_ex(key_type, env)?;
+        let rc = unsafe {
+            sys::wc_FrodoKemKey_MakeKeyWithRandom(
+                key.ws_key,
+                rand.as_ptr(),
+                rand.len() as core::ffi::c_int,
+            )
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(key)
+    }
+
+    /// Create and initialize a new FrodoKEM key instance without generating
+    /// key material.
+    ///
+    /// Key material can be loaded afterwards using
+    /// [`FrodoKem::decode_public_key()`] or [`FrodoKem::decode_private_key()`].
+    ///
+    /// # Parameters
+    ///
+    /// * `key_type`: Key type. One of the `FrodoKem::TYPE_*` constants.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(FrodoKem) containing the key instance or Err(e)
+    /// containing the wolfSSL library error code value. `NOT_COMPILED_IN` is
+    /// returned for key types that are not enabled in the C library.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(frodokem)]
+    /// {
+    /// use wolfssl_wolfcrypt::frodokem::FrodoKem;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let key = FrodoKem::new(FrodoKem::TYPE_640_SHAKE, &wolfcrypt).expect("Error with new()");
+    /// }
+    /// ```
+    pub fn new(key_type: i32, wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(key_type, &crate::Env::new(wolfcrypt))
+    }
+
+    /// Create and initialize a new FrodoKEM key instance with optional heap
+    /// hint and device ID.
+    ///
+    /// # Parameters
+    ///
+    /// * `key_type`: Key type. One of the `FrodoKem::TYPE_*` constants.
+    /// * `env`: Heap hint and crypto callback device to use.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(FrodoKem) containing the key instance or Err(e)
+    /// containing the wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(frodokem)]
+    /// {
+    /// use wolfssl_wolfcrypt::Env;
+    /// use wolfssl_wolfcrypt::frodokem::FrodoKem;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let key = FrodoKem::new_ex(FrodoKem::TYPE_640_SHAKE, &Env::new(&wolfcrypt))
+    ///     .expect("Error with new_ex()");
+    /// }
+    /// ```
+    pub fn new_ex(
+        key_type: i32,
+        env: &crate::Env,
+    ) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        // Check the type first: wc_FrodoKemKey_New() only reports failure
+        // as a NULL key, which for a valid type means that it is not
+        // compiled in (or that allocation failed).
+        Self::type_params(key_type)?;
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
+        let ws_key = unsafe { sys::wc_FrodoKemKey_New(key_type, heap, dev_id) };
+        if ws_key.is_null() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_NOT_COMPILED_IN));
+        }
+        Ok(FrodoKem { ws_key, key_type, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData })
+    }
+
+    /// Get the key type this key was created with.
+    pub fn key_type(&self) -> i32 {
+        self.key_type
+    }
+
+    /// Get the cipher text size in bytes for this key's type.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) or Err(e) containing the wolfSSL library error
+    /// code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(frodokem)]
+    /// {
+    /// use wolfssl_wolfcrypt::frodokem::FrodoKem;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let key = FrodoKem::new(FrodoKem::TYPE_640_SHAKE, &wolfcrypt).expect("Error with new()");
+    /// let ct_size = key.cipher_text_size().expect("Error with cipher_text_size()");
+    /// assert_eq!(ct_size, 9752);
+    /// }
+    /// ```
+    pub fn cipher_text_size(&self) -> Result<usize, Error> {
+        let mut len = 0u32;
+        let rc = unsafe { sys::wc_FrodoKemKey_CipherTextSize(self.ws_key, &mut len) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(len as usize)
+    }
+
+    /// Get the shared secret size in bytes for this key's type.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) or Err(e) containing the wolfSSL library error
+    /// code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(frodokem)]
+    /// {
+    /// use wolfssl_wolfcrypt::frodokem::FrodoKem;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let key = FrodoKem::new(FrodoKem::TYPE_640_SHAKE, &wolfcrypt).expect("Error with new()");
+    /// let ss_size = key.shared_secret_size().expect("Error with shared_secret_size()");
+    /// assert_eq!(ss_size, 16);
+    /// }
+    /// ```
+    pub fn shared_secret_size(&self) -> Result<usize, Error> {
+        let mut len = 0u32;
+        let rc = unsafe { sys::wc_FrodoKemKey_SharedSecretSize(self.ws_key, &mut len) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(len as usize)
+    }
+
+    /// Get the private key size in bytes for this key's type.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) or Err(e) containing the wolfSSL library error
+    /// code value.
+    pub fn private_key_size(&self) -> Result<usize, Error> {
+        let mut len = 0u32;
+        let rc = unsafe { sys::wc_FrodoKemKey_PrivateKeySize(self.ws_key, &mut len) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(len as usize)
+    }
+
+    /// Get the public key size in bytes for this key's type.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) or Err(e) containing the wolfSSL library error
+    /// code value.
+    pub fn public_key_size(&self) -> Result<usize, Error> {
+        let mut len = 0u32;
+        let rc = unsafe { sys::wc_FrodoKemKey_PublicKeySize(self.ws_key, &mut len) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(len as usize)
+    }
+
+    /// Check that the output buffers match this key's cipher text and shared
+    /// secret sizes.
+    #[cfg(frodokem_encapsulate)]
+    fn check_encapsulate_buffers(&self, ct: &[u8], ss: &[u8]) -> Result<(), Error> {
+        if ct.len() != self.cipher_text_size()? {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        if ss.len() != self.shared_secret_size()? {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        Ok(())
+    }
+
+    /// Encapsulate: generate a shared secret and cipher text using this
+    /// public key and an RNG.
+    ///
+    /// The `ct` buffer must be exactly `cipher_text_size()` bytes.
+    /// The `ss` buffer must be exactly `shared_secret_size()` bytes.
+    ///
+    /// # Parameters
+    ///
+    /// * `ct`: Output buffer for the cipher text.
+    /// * `ss`: Output buffer for the shared secret.
+    /// * `rng`: `RNG` instance for random number generation.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(all(frodokem, frodokem_make_key, frodokem_encapsulate, random))]
+    /// {
+    /// use wolfssl_wolfcrypt::random::RNG;
+    /// use wolfssl_wolfcrypt::frodokem::FrodoKem;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut key = FrodoKem::generate(FrodoKem::TYPE_640_SHAKE, &mut rng, &wolfcrypt)
+    ///     .expect("Error with generate()");
+    /// let mut ct = vec![0u8; key.cipher_text_size().unwrap()];
+    /// let mut ss = vec![0u8; key.shared_secret_size().unwrap()];
+    /// key.encapsulate(&mut ct, &mut ss, &mut rng)
+    ///     .expect("Error with encapsulate()");
+    /// }
+    /// ```
+    #[cfg(all(frodokem_encapsulate, random))]
+    pub fn encapsulate(
+        &mut self,
+        ct: &mut [u8],
+        ss: &mut [u8],
+        rng: &RNG,
+    ) -> Result<(), Error> {
+        self.check_encapsulate_buffers(ct, ss)?;
+        let rc = unsafe {
+            sys::wc_FrodoKemKey_Encapsulate(
+                self.ws_key,
+                ct.as_mut_ptr(),
+                ss.as_mut_ptr(),
+                rng.wc_rng,
+            )
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Encapsulate using caller-supplied random bytes instead of an RNG.
+    ///
+    /// Produces the same cipher text and shared secret for a given
+    /// `(public_key, rand)` pair, enabling deterministic encapsulation for
+    /// known-answer tests. The `rand` buffer must be exactly
+    /// [`FrodoKem::enc_rand_size()`] bytes (`u || salt`).
+    ///
+    /// # Parameters
+    ///
+    /// * `ct`: Output buffer for the cipher text.
+    /// * `ss`: Output buffer for the shared secret.
+    /// * `rand`: Caller-supplied random bytes.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(all(frodokem, frodokem_make_key, frodokem_encapsulate))]
+    /// {
+    /// use wolfssl_wolfcrypt::frodokem::FrodoKem;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let key_rand = [0x42u8; 64];
+    /// let enc_rand = [0x55u8; 48];
+    /// let mut key = FrodoKem::generate_with_random(FrodoKem::TYPE_640_SHAKE, &key_rand, &wolfcrypt)
+    ///     .expect("Error with generate_with_random()");
+    /// let mut ct = vec![0u8; key.cipher_text_size().unwrap()];
+    /// let mut ss = vec![0u8; key.shared_secret_size().unwrap()];
+    /// key.encapsulate_with_random(&mut ct, &mut ss, &enc_rand)
+    ///     .expect("Error with encapsulate_with_random()");
+    /// }
+    /// ```
+    #[cfg(frodokem_encapsulate)]
+    pub fn encapsulate_with_random(
+        &mut self,
+        ct: &mut [u8],
+        ss: &mut [u8],
+        rand: &[u8],
+    ) -> Result<(), Error> {
+        if rand.len() != Self::enc_rand_size(self.key_type)? {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        self.check_encapsulate_buffers(ct, ss)?;
+        let rc = unsafe {
+            sys::wc_FrodoKemKey_EncapsulateWithRandom(
+                self.ws_key,
+                ct.as_mut_ptr(),
+                ss.as_mut_ptr(),
+                rand.as_ptr(),
+                rand.len() as core::ffi::c_int,
+            )
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Decapsulate: recover the shared secret from a cipher text using this
+    /// private key.
+    ///
+    /// The `ss` buffer must be exactly `shared_secret_size()` bytes.
+    /// The `ct` length is validated against the expected cipher text size for
+    /// the key type by the C library. A modified cipher text does not produce
+    /// an error but a pseudorandom shared secret (implicit rejection).
+    ///
+    /// # Parameters
+    ///
+    /// * `ss`: Output buffer for the shared secret.
+    /// * `ct`: Cipher text produced by [`FrodoKem::encapsulate()`] or
+    ///   [`FrodoKem::encapsulate_with_random()`].
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    #[cfg(frodokem_decapsulate)]
+    pub fn decapsulate(&mut self, ss: &mut [u8], ct: &[u8]) -> Result<(), Error> {
+        if ss.len() != self.shared_secret_size()? {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        let ct_size = crate::buffer_len_to_u32(ct.len())?;
+        let rc = unsafe {
+            sys::wc_FrodoKemKey_Decapsulate(
+                self.ws_key,
+                ss.as_mut_ptr(),
+                ct.as_ptr(),
+                ct_size,
+            )
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Encode (export) the public key to a byte buffer.
+    ///
+    /// The `out` buffer must be exactly `public_key_size()` bytes.
+    ///
+    /// # Parameters
+    ///
+    /// * `out`: Output buffer to receive the encoded public key.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) or Err(e) containing the wolfSSL library error
+    /// code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(all(frodokem, frodokem_make_key))]
+    /// {
+    /// use wolfssl_wolfcrypt::frodokem::FrodoKem;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let key = FrodoKem::generate_with_random(FrodoKem::TYPE_640_SHAKE, &[0x42u8; 64], &wolfcrypt)
+    ///     .expect("Error with generate_with_random()");
+    /// let mut pub_buf = vec![0u8; key.public_key_size().unwrap()];
+    /// key.encode_public_key(&mut pub_buf).expect("Error with encode_public_key()");
+    /// let mut key2 = FrodoKem::new(FrodoKem::TYPE_640_SHAKE, &wolfcrypt).expect("Error with new()");
+    /// key2.decode_public_key(&pub_buf).expect("Error with decode_public_key()");
+    /// }
+    /// ```
+    pub fn encode_public_key(&self, out: &mut [u8]) -> Result<(), Error> {
+        let out_size = crate::buffer_len_to_u32(out.len())?;
+        let rc = unsafe {
+            sys::wc_FrodoKemKey_EncodePublicKey(self.ws_key, out.as_mut_ptr(), out_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Encode (export) the private key to a byte buffer.
+    ///
+    /// The `out` buffer must be exactly `private_key_size()` bytes. The
+    /// encoding is `s || seedA || b || S^T || pkh` and includes the public
+    /// key.
+    ///
+    /// # Parameters
+    ///
+    /// * `out`: Output buffer to receive the encoded private key.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) or Err(e) containing the wolfSSL library error
+    /// code value.
+    pub fn encode_private_key(&self, out: &mut [u8]) -> Result<(), Error> {
+        let out_size = crate::buffer_len_to_u32(out.len())?;
+        let rc = unsafe {
+            sys::wc_FrodoKemKey_EncodePrivateKey(self.ws_key, out.as_mut_ptr(), out_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Decode (import) a public key from a byte buffer.
+    ///
+    /// # Parameters
+    ///
+    /// * `data`: Input buffer containing the encoded public key.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn decode_public_key(&mut self, data: &[u8]) -> Result<(), Error> {
+        let data_size = crate::buffer_len_to_u32(data.len())?;
+        let rc = unsafe {
+            sys::wc_FrodoKemKey_DecodePublicKey(self.ws_key, data.as_ptr(), data_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Decode (import) a private key from a byte buffer.
+    ///
+    /// # Parameters
+    ///
+    /// * `data`: Input buffer containing the encoded private key.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn decode_private_key(&mut self, data: &[u8]) -> Result<(), Error> {
+        let data_size = crate::buffer_len_to_u32(data.len())?;
+        let rc = unsafe {
+            sys::wc_FrodoKemKey_DecodePrivateKey(self.ws_key, data.as_ptr(), data_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+}
+
+impl<'a> FrodoKem<'a> {
+    fn zeroize(&mut self) {
+        self.ws_key = core::ptr::null_mut();
+    }
+}
+
+impl Drop for FrodoKem<'_> {
+    /// Safely free the underlying wolfSSL FrodoKEM key context.
+    ///
+    /// This calls `wc_FrodoKemKey_Delete()`, which frees and zeroizes the key
+    /// material. The Rust Drop trait guarantees this is called when the
+    /// `FrodoKem` struct goes out of scope.
+    fn drop(&mut self) {
+        unsafe {
+            sys::wc_FrodoKemKey_Delete(self.ws_key, core::ptr::null_mut());
+        }
+        self.zeroize();
+    }
+}
@@ -0,0 +1,408 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+RustCrypto `kem` trait implementations for the wolfCrypt FrodoKEM types.
+
+Provides [`kem::Kem`] marker types and associated encapsulation/decapsulation
+key types for each FrodoKEM key type:
+
+| Marker                   | Key type                                   |
+|--------------------------|--------------------------------------------|
+| [`FrodoKem640Shake`]     | [`FrodoKem::TYPE_640_SHAKE`]               |
+| [`FrodoKem976Shake`]     | [`FrodoKem::TYPE_976_SHAKE`]               |
+| [`FrodoKem1344Shake`]    | [`FrodoKem::TYPE_1344_SHAKE`]              |
+| [`FrodoKem640Aes`]       | [`FrodoKem::TYPE_640_AES`]                 |
+| [`FrodoKem976Aes`]       | [`FrodoKem::TYPE_976_AES`]                 |
+| [`FrodoKem1344Aes`]      | [`FrodoKem::TYPE_1344_AES`]                |
+| [`EFrodoKem640Shake`]    | [`FrodoKem::TYPE_EFRODOKEM_640_SHAKE`]     |
+| [`EFrodoKem976Shake`]    | [`FrodoKem::TYPE_EFRODOKEM_976_SHAKE`]     |
+| [`EFrodoKem1344Shake`]   | [`FrodoKem::TYPE_EFRODOKEM_1344_SHAKE`]    |
+| [`EFrodoKem640Aes`]      | [`FrodoKem::TYPE_EFRODOKEM_640_AES`]       |
+| [`EFrodoKem976Aes`]      | [`FrodoKem::TYPE_EFRODOKEM_976_AES`]       |
+| [`EFrodoKem1344Aes`]     | [`FrodoKem::TYPE_EFRODOKEM_1344_AES`]      |
+
+The encapsulation and decapsulation key types are named after the marker,
+e.g. [`FrodoKem640ShakeEncapsulationKey`] and
+[`FrodoKem640ShakeDecapsulationKey`]. The AES and eFrodoKEM types are only
+usable when the C library is built with `WOLFSSL_FRODOKEM_AES` and
+`WOLFSSL_FRODOKEM_EPHEMERAL` respectively; otherwise their operations panic
+like any other wolfCrypt failure in these trait implementations.
+
+Each encapsulation key implements [`kem::Encapsulate`] (with
+[`kem::TryKeyInit`] and [`kem::KeyExport`] for key serialization).
+
+Each decapsulation key implements [`kem::Decapsulate`] and
+[`kem::Generate`] (for key generation from a [`rand_core::CryptoRng`]).
+
+Key generation and encapsulation bridge a caller-supplied
+[`rand_core::CryptoRng`] to wolfCrypt's deterministic APIs by extracting the
+required random bytes from the RNG.
+
+# Examples
+
+```rust
+#[cfg(all(frodokem, frodokem_make_key, frodokem_encapsulate, frodokem_decapsulate, random, feature = "kem", feature = "rand_core"))]
+{
+use kem::{Kem, Encapsulate, Decapsulate};
+use kem::Generate;
+use wolfssl_wolfcrypt::random::RNG;
+use wolfssl_wolfcrypt::frodokem_kem::*;
+use wolfssl_wolfcrypt::WolfCrypt;
+let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+
+let mut rng = RNG::new(&wolfcrypt).expect("RNG creation failed");
+
+let (dk, ek) = FrodoKem640Shake::generate_keypair_from_rng(&mut rng);
+let (ct, k_send) = ek.encapsulate_with_rng(&mut rng);
+let k_recv = dk.decapsulate(&ct);
+assert_eq!(k_send, k_recv);
+}
+```
+*/
+
+#![cfg(all(feature = "kem", frodokem, frodokem_make_key, frodokem_encapsulate,
+    frodokem_decapsulate))]
+
+use crate::frodokem::FrodoKem;
+use kem::common::array::Array;
+use kem::common::typenum::{U16, U24, U32};
+use hybrid_array::sizes::{
+    U9616, U9720, U9752, U15632, U15744, U15792, U19888, U21520, U21632, U21696,
+    U31296, U43088,
+};
+
+macro_rules! impl_frodokem_kem {
+    (
+        kem = $kem:ident,
+        ek = $ek:ident,
+        dk = $dk:ident,
+        pk_typenum = $pk_tn:ty,
+        sk_typenum = $sk_tn:ty,
+        ct_typenum = $ct_tn:ty,
+        ss_typenum = $ss_tn:ty,
+        ss_len = $ss_len:expr,
+        makekey_rand_len = $mk_rand_len:expr,
+        enc_rand_len = $enc_rand_len:expr,
+        key_type = $key_type:expr $(,)?
+    ) => {
+        /// FrodoKEM key type marker implementing [`kem::Kem`].
+        #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $kem;
+
+        impl kem::Kem for $kem {
+            type DecapsulationKey = $dk;
+            type EncapsulationKey = $ek;
+            type SharedKeySize = $ss_tn;
+            type CiphertextSize = $ct_tn;
+        }
+
+        /// FrodoKEM encapsulation (public) key implementing [`kem::Encapsulate`].
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub struct $ek {
+            pk: Array<u8, $pk_tn>,
+        }
+
+        impl kem::KeySizeUser for $ek {
+            type KeySize = $pk_tn;
+        }
+
+        impl kem::TryKeyInit for $ek {
+            fn new(key: &kem::Key<Self>) -> Result<Self, kem::InvalidKey> {
+                let wolfcrypt = crate::WolfCrypt::global().map_err(|_| kem::InvalidKey)?;
+                let mut wc_key = FrodoKem::new($key_type, wolfcrypt)
+                    .map_err(|_| kem::InvalidKey)?;
+                wc_key.decode_public_key(key.as_ref())
+                    .map_err(|_| kem::InvalidKey)?;
+                Ok(Self { pk: key.clone() })
+            }
+        }
+
+        impl kem::KeyExport for $ek {
+            fn to_bytes(&self) -> kem::Key<Self> {
+                self.pk.clone()
+            }
+        }
+
+        impl kem::Encapsulate for $ek {
+            type Kem = $kem;
+
+            fn encapsulate_with_rng<R: kem::common::rand_core::CryptoRng + ?Sized>(
+                &self,
+                rng: &mut R,
+            ) -> (kem::Ciphertext<$kem>, kem::SharedKey<$kem>) {
+                let mut rand = [0u8; $enc_rand_len];
+                rng.fill_bytes(&mut rand);
+
+                let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+                let mut wc_key = FrodoKem::new($key_type, wolfcrypt)
+                    .expect("FrodoKem::new failed");
+                wc_key.decode_public_key(self.pk.as_ref())
+                    .expect("decode_public_key failed");
+
+                let mut ct: kem::Ciphertext<$kem> = Array::default();
+                let mut ss = [0u8; $ss_len];
+                wc_key.encapsulate_with_random(ct.as_mut(), &mut ss, &rand)
+                    .expect("encapsulate_with_random failed");
+                zeroize::Zeroize::zeroize(&mut rand[..]);
+
+                (ct, ss.into())
+            }
+        }
+
+        /// FrodoKEM decapsulation (private) key implementing
+        /// [`kem::Decapsulate`].
+        ///
+        /// The private key bytes are securely zeroized on drop.
+        pub struct $dk {
+            sk: Array<u8, $sk_tn>,
+            ek: $ek,
+        }
+
+        impl kem::Decapsulator for $dk {
+            type Kem = $kem;
+
+            fn encapsulation_key(&self) -> &$ek {
+                &self.ek
+            }
+        }
+
+        impl kem::Decapsulate for $dk {
+            fn decapsulate(
+                &self,
+                ct: &kem::Ciphertext<$kem>,
+            ) -> kem::SharedKey<$kem> {
+                let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+                let mut wc_key = FrodoKem::new($key_type, wolfcrypt)
+                    .expect("FrodoKem::new failed");
+                wc_key.decode_private_key(self.sk.as_ref())
+                    .expect("decode_private_key failed");
+
+                let mut ss = [0u8; $ss_len];
+                wc_key.decapsulate(&mut ss, ct.as_ref())
+                    .expect("decapsulate failed");
+
+                ss.into()
+            }
+        }
+
+        impl kem::Generate for $dk {
+            fn try_generate_from_rng<R: kem::common::rand_core::TryCryptoRng + ?Sized>(
+                rng: &mut R,
+            ) -> Result<Self, R::Error> {
+                let mut rand = [0u8; $mk_rand_len];
+                rng.try_fill_bytes(&mut rand)?;
+
+                let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+                let wc_key = FrodoKem::generate_with_random($key_type, &rand, wolfcrypt)
+                    .expect("generate_with_random failed");
+                zeroize::Zeroize::zeroize(&mut rand[..]);
+
+                let mut pk: Array<u8, $pk_tn> = Array::default();
+                let mut sk: Array<u8, $sk_tn> = Array::default();
+                wc_key.encode_public_key(pk.as_mut())
+                    .expect("encode_public_key failed");
+                wc_key.encode_private_key(sk.as_mut())
+                    .expect("encode_private_key failed");
+
+                Ok(Self {
+                    sk,
+                    ek: $ek { pk },
+                })
+            }
+        }
+
+        impl Drop for $dk {
+            fn drop(&mut self) {
+                use zeroize::Zeroize;
+                let sk_bytes: &mut [u8] = self.sk.as_mut();
+                sk_bytes.zeroize();
+            }
+        }
+    };
+}
+
+impl_frodokem_kem! {
+    kem = FrodoKem640Shake,
+    ek = FrodoKem640ShakeEncapsulationKey,
+    dk = FrodoKem640ShakeDecapsulationKey,
+    pk_typenum = U9616,
+    sk_typenum = U19888,
+    ct_typenum = U9752,
+    ss_typenum = U16,
+    ss_len = 16,
+    makekey_rand_len = 64,
+    enc_rand_len = 48,
+    key_type = FrodoKem::TYPE_640_SHAKE,
+}
+
+impl_frodokem_kem! {
+    kem = FrodoKem976Shake,
+    ek = FrodoKem976ShakeEncapsulationKey,
+    dk = FrodoKem976ShakeDecapsulationKey,
+    pk_typenum = U15632,
+    sk_typenum = U31296,
+    ct_typenum = U15792,
+    ss_typenum = U24,
+    ss_len = 24,
+    makekey_rand_len = 88,
+    enc_rand_len = 72,
+    key_type = FrodoKem::TYPE_976_SHAKE,
+}
+
+impl_frodokem_kem! {
+    kem = FrodoKem1344Shake,
+    ek = FrodoKem1344ShakeEncapsulationKey,
+    dk = FrodoKem1344ShakeDecapsulationKey,
+    pk_typenum = U21520,
+    sk_typenum = U43088,
+    ct_typenum = U21696,
+    ss_typenum = U32,
+    ss_len = 32,
+    makekey_rand_len = 112,
+    enc_rand_len = 96,
+    key_type = FrodoKem::TYPE_1344_SHAKE,
+}
+
+impl_frodokem_kem! {
+    kem = FrodoKem640Aes,
+    ek = FrodoKem640AesEncapsulationKey,
+    dk = FrodoKem640AesDecapsulationKey,
+    pk_typenum = U9616,
+    sk_typenum = U19888,
+    ct_typenum = U9752,
+    ss_typenum = U16,
+    ss_len = 16,
+    makekey_rand_len = 64,
+    enc_rand_len = 48,
+    key_type = FrodoKem::TYPE_640_AES,
+}
+
+impl_frodokem_kem! {
+    kem = FrodoKem976Aes,
+    ek = FrodoKem976AesEncapsulationKey,
+    dk = FrodoKem976AesDecapsulationKey,
+    pk_typenum = U15632,
+    sk_typenum = U31296,
+    ct_typenum = U15792,
+    ss_typenum = U24,
+    ss_len = 24,
+    makekey_rand_len = 88,
+    enc_rand_len = 72,
+    key_type = FrodoKem::TYPE_976_AES,
+}
+
+impl_frodokem_kem! {
+    kem = FrodoKem1344Aes,
+    ek = FrodoKem1344AesEncapsulationKey,
+    dk = FrodoKem1344AesDecapsulationKey,
+    pk_typenum = U21520,
+    sk_typenum = U43088,
+    ct_typenum = U21696,
+    ss_typenum = U32,
+    ss_len = 32,
+    makekey_rand_len = 112,
+    enc_rand_len = 96,
+    key_type = FrodoKem::TYPE_1344_AES,
+}
+
+impl_frodokem_kem! {
+    kem = EFrodoKem640Shake,
+    ek = EFrodoKem640ShakeEncapsulationKey,
+    dk = EFrodoKem640ShakeDecapsulationKey,
+    pk_typenum = U9616,
+    sk_typenum = U19888,
+    ct_typenum = U9720,
+    ss_typenum = U16,
+    ss_len = 16,
+    makekey_rand_len = 48,
+    enc_rand_len = 16,
+    key_type = FrodoKem::TYPE_EFRODOKEM_640_SHAKE,
+}
+
+impl_frodokem_kem! {
+    kem = EFrodoKem976Shake,
+    ek = EFrodoKem976ShakeEncapsulationKey,
+    dk = EFrodoKem976ShakeDecapsulationKey,
+    pk_typenum = U15632,
+    sk_typenum = U31296,
+    ct_typenum = U15744,
+    ss_typenum = U24,
+    ss_len = 24,
+    makekey_rand_len = 64,
+    enc_rand_len = 24,
+    key_type = FrodoKem::TYPE_EFRODOKEM_976_SHAKE,
+}
+
+impl_frodokem_kem! {
+    kem = EFrodoKem1344Shake,
+    ek = EFrodoKem1344ShakeEncapsulationKey,
+    dk = EFrodoKem1344ShakeDecapsulationKey,
+    pk_typenum = U21520,
+    sk_typenum = U43088,
+    ct_typenum = U21632,
+    ss_typenum = U32,
+    ss_len = 32,
+    makekey_rand_len = 80,
+    enc_rand_len = 32,
+    key_type = FrodoKem::TYPE_EFRODOKEM_1344_SHAKE,
+}
+
+impl_frodokem_kem! {
+    kem = EFrodoKem640Aes,
+    ek = EFrodoKem640AesEncapsulationKey,
+    dk = EFrodoKem640AesDecapsulationKey,
+    pk_typenum = U9616,
+    sk_typenum = U19888,
+    ct_typenum = U9720,
+    ss_typenum = U16,
+    ss_len = 16,
+    makekey_rand_len = 48,
+    enc_rand_len = 16,
+    key_type = FrodoKem::TYPE_EFRODOKEM_640_AES,
+}
+
+impl_frodokem_kem! {
+    kem = EFrodoKem976Aes,
+    ek = EFrodoKem976AesEncapsulationKey,
+    dk = EFrodoKem976AesDecapsulationKey,
+    pk_typenum = U15632,
+    sk_typenum = U31296,
+    ct_typenum = U15744,
+    ss_typenum = U24,
+    ss_len = 24,
+    makekey_rand_len = 64,
+    enc_rand_len = 24,
+    key_type = FrodoKem::TYPE_EFRODOKEM_976_AES,
+}
+
+impl_frodokem_kem! {
+    kem = EFrodoKem1344Aes,
+    ek = EFrodoKem1344AesEncapsulationKey,
+    dk = EFrodoKem1344AesDecapsulationKey,
+    pk_typenum = U21520,
+    sk_typenum = U43088,
+    ct_typenum = U21632,
+    ss_typenum = U32,
+    ss_len = 32,
+    makekey_rand_len = 80,
+    enc_rand_len = 32,
+    key_type = FrodoKem::TYPE_EFRODOKEM_1344_AES,
+}
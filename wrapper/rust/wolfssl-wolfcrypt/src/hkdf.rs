@@ -25,6 +25,7 @@ Derivation Function (HKDF) functionality.
 
 #![cfg(hkdf)]
 
+use crate::Error;
 use crate::sys;
 use crate::hmac::HMAC;
 
@@ -40,6 +41,7 @@ use crate::hmac::HMAC;
 /// * `key`: Initial Key Material (IKM).
 /// * `out`: Output buffer to store HKDF-Extract result. The size of this
 ///   buffer must match `HMAC::get_hmac_size_by_type(typ)`.
+/// * `wolfcrypt`: Guard of the initialized library.
 ///
 /// # Returns
 ///
@@ -52,13 +54,15 @@ use crate::hmac::HMAC;
 /// use wolfssl_wolfcrypt::hkdf::*;
 /// use wolfssl_wolfcrypt::hmac::HMAC;
 /// use wolfssl_wolfcrypt::sha::SHA256;
+/// use wolfssl_wolfcrypt::WolfCrypt;
+/// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
 /// let ikm = b"MyPassword0";
 /// let salt = b"12345678ABCDEFGH";
 /// let mut extract_out = [0u8; SHA256::DIGEST_SIZE];
-/// hkdf_extract(HMAC::TYPE_SHA256, Some(salt), ikm, &mut extract_out).expect("Error with hkdf_extract()");
+/// hkdf_extract(HMAC::TYPE_SHA256, Some(salt), ikm, &mut extract_out, &wolfcrypt).expect("Error with hkdf_extract()");
 /// ```
-pub fn hkdf_extract(typ: i32, salt: Option<&[u8]>, key: &[u8], out: &mut [u8]) -> Result<(), i32> {
-    hkdf_extract_ex(typ, salt, key, out, None, None)
+pub fn hkdf_extract(typ: i32, salt: Option<&[u8]>, key: &[u8], out: &mut [u8], wolfcrypt: &crate::WolfCrypt) -> Result<(), Error> {
+    hkdf_extract_ex(typ, salt, key, out, &crate::Env::new(wolfcrypt))
 }
 
 /// Perform HKDF-Extract operation (with optional heap and device ID).
@@ -73,8 +77,7 @@ pub fn hkdf_extract(typ: i32, salt: Option<&[u8]>, key: &[u8], out: &mut [u8]) -
 /// * `key`: Initial Key Material (IKM).
 /// * `out`: Output buffer to store HKDF-Extract result. The size of this
 ///   buffer must match `HMAC::get_hmac_size_by_type(typ)`.
-/// * `heap`: Optional heap hint.
-/// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+/// * `env`: Heap hint and crypto callback device to use.
 ///
 /// # Returns
 ///
@@ -84,15 +87,18 @@ pub fn hkdf_extract(typ: i32, salt: Option<&[u8]>, key: &[u8], out: &mut [u8]) -
 /// # Example
 ///
 /// ```rust
+/// use wolfssl_wolfcrypt::Env;
 /// use wolfssl_wolfcrypt::hkdf::*;
 /// use wolfssl_wolfcrypt::hmac::HMAC;
 /// use wolfssl_wolfcrypt::sha::SHA256;
+/// use wolfssl_wolfcrypt::WolfCrypt;
+/// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
 /// let ikm = b"MyPassword0";
 /// let salt = b"12345678ABCDEFGH";
 /// let mut extract_out = [0u8; SHA256::DIGEST_SIZE];
-/// hkdf_extract_ex(HMAC::TYPE_SHA256, Some(salt), ikm, &mut extract_out, None, None).expect("Error with hkdf_extract_ex()");
+/// hkdf_extract_ex(HMAC::TYPE_SHA256, Some(salt), ikm, &mut extract_out, &Env::new(&wolfcrypt)).expect("Error with hkdf_extract_ex()");
 /// ```
-pub fn hkdf_extract_ex(typ: i32, salt: Option<&[u8]>, key: &[u8], out: &mut [u8], heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<(), i32> {
+pub fn hkdf_extract_ex(typ: i32, salt: Option<&[u8]>, key: &[u8], out: &mut [u8], env: &crate::Env) -> Result<(), Error> {
     let mut salt_ptr = core::ptr::null();
     let mut salt_size = 0u32;
     if let Some(salt) = salt {
@@ -101,22 +107,16 @@ pub fn hkdf_extract_ex(typ: i32, salt: Option<&[u8]>, key: &[u8], out: &mut [u8]
     }
     let key_size = crate::buffer_len_to_u32(key.len())?;
     if out.len() != HMAC::get_hmac_size_by_type(typ)? {
-        return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+        return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
     }
-    let heap = match heap {
-        Some(heap) => heap,
-        None => core::ptr::null_mut(),
-    };
-    let dev_id = match dev_id {
-        Some(dev_id) => dev_id,
-        None => sys::INVALID_DEVID,
-    };
+    let heap = env.heap_ptr();
+    let dev_id = env.dev_id();
     let rc = unsafe {
         sys::wc_HKDF_Extract_ex(typ, salt_ptr, salt_size,
             key.as_ptr(), key_size, out.as_mut_ptr(), heap, dev_id)
     };
     if rc != 0 {
-        return Err(rc);
+        return Err(Error::from(rc));
     }
     Ok(())
 }
@@ -133,6 +133,7 @@ pub fn hkdf_extract_ex(typ: i32, salt: Option<&[u8]>, key: &[u8], out: &mut [u8]
 /// * `info`: Optional buffer containing additional info.
 /// * `out`: Output buffer to store HKDF-Expand result. The buffer can be
 ///   any size.
+/// * `wolfcrypt`: Guard of the initialized library.
 ///
 /// # Returns
 ///
@@ -145,16 +146,18 @@ pub fn hkdf_extract_ex(typ: i32, salt: Option<&[u8]>, key: &[u8], out: &mut [u8]
 /// use wolfssl_wolfcrypt::hkdf::*;
 /// use wolfssl_wolfcrypt::hmac::HMAC;
 /// use wolfssl_wolfcrypt::sha::SHA256;
+/// use wolfssl_wolfcrypt::WolfCrypt;
+/// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
 /// let ikm = b"MyPassword0";
 /// let salt = b"12345678ABCDEFGH";
 /// let mut extract_out = [0u8; SHA256::DIGEST_SIZE];
-/// hkdf_extract(HMAC::TYPE_SHA256, Some(salt), ikm, &mut extract_out).expect("Error with hkdf_extract()");
+/// hkdf_extract(HMAC::TYPE_SHA256, Some(salt), ikm, &mut extract_out, &wolfcrypt).expect("Error with hkdf_extract()");
 /// let info = b"0";
 /// let mut expand_out = [0u8; 16];
-/// hkdf_expand(HMAC::TYPE_SHA256, &extract_out, Some(info), &mut expand_out).expect("Error with hkdf_expand()");
+/// hkdf_expand(HMAC::TYPE_SHA256, &extract_out, Some(info), &mut expand_out, &wolfcrypt).expect("Error with hkdf_expand()");
 /// ```
-pub fn hkdf_expand(typ: i32, key: &[u8], info: Option<&[u8]>, out: &mut [u8]) -> Result<(), i32> {
-    hkdf_expand_ex(typ, key, info, out, None, None)
+pub fn hkdf_expand(typ: i32, key: &[u8], info: Option<&[u8]>, out: &mut [u8], wolfcrypt: &crate::WolfCrypt) -> Result<(), Error> {
+    hkdf_expand_ex(typ, key, info, out, &crate::Env::new(wolfcrypt))
 }
 
 /// Perform HKDF-Expand operation (with optional heap and device ID).
@@ -169,8 +172,7 @@ pub fn hkdf_expand(typ: i32, key: &[u8], info: Option<&[u8]>, out: &mut [u8]) ->
 /// * `info`: Optional buffer containing additional info.
 /// * `out`: Output buffer to store HKDF-Expand result. The buffer can be
 ///   any size.
-/// * `heap`: Optional heap hint.
-/// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+/// * `env`: Heap hint and crypto callback device to use.
 ///
 /// # Returns
 ///
@@ -180,18 +182,21 @@ pub fn hkdf_expand(typ: i32, key: &[u8], info: Option<&[u8]>, out: &mut [u8]) ->
 /// # Example
 ///
 /// ```rust
+/// use wolfssl_wolfcrypt::Env;
 /// use wolfssl_wolfcrypt::hkdf::*;
 /// use wolfssl_wolfcrypt::hmac::HMAC;
 /// use wolfssl_wolfcrypt::sha::SHA256;
+/// use wolfssl_wolfcrypt::WolfCrypt;
+/// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
 /// let ikm = b"MyPassword0";
 /// let salt = b"12345678ABCDEFGH";
 /// let mut extract_out = [0u8; SHA256::DIGEST_SIZE];
-/// hkdf_extract(HMAC::TYPE_SHA256, Some(salt), ikm, &mut extract_out).expect("Error with hkdf_extract()");
+/// hkdf_extract(HMAC::TYPE_SHA256, Some(salt), ikm, &mut extract_out, &wolfcrypt).expect("Error with hkdf_extract()");
 /// let info = b"0";
 /// let mut expand_out = [0u8; 16];
-/// hkdf_expand_ex(HMAC::TYPE_SHA256, &extract_out, Some(info), &mut expand_out, None, None).expect("Error with hkdf_expand_ex()");
+/// hkdf_expand_ex(HMAC::TYPE_SHA256, &extract_out, Some(info), &mut expand_out, &Env::new(&wolfcrypt)).expect("Error with hkdf_expand_ex()");
 /// ```
-pub fn hkdf_expand_ex(typ: i32, key: &[u8], info: Option<&[u8]>, out: &mut [u8], heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<(), i32> {
+pub fn hkdf_expand_ex(typ: i32, key: &[u8], info: Option<&[u8]>, out: &mut [u8], env: &crate::Env) -> Result<(), Error> {
     let key_size = crate::buffer_len_to_u32(key.len())?;
     let mut info_ptr = core::ptr::null();
     let mut info_size = 0u32;
@@ -200,20 +205,14 @@ pub fn hkdf_expand_ex(typ: i32, key: &[u8], info: Option<&[u8]>, out: &mut [u8],
         info_size = crate::buffer_len_to_u32(info.len())?;
     }
     let out_size = crate::buffer_len_to_u32(out.len())?;
-    let heap = match heap {
-        Some(heap) => heap,
-        None => core::ptr::null_mut(),
-    };
-    let dev_id = match dev_id {
-        Some(dev_id) => dev_id,
-        None => sys::INVALID_DEVID,
-    };
+    let heap = env.heap_ptr();
+    let dev_id = env.dev_id();
     let rc = unsafe {
         sys::wc_HKDF_Expand_ex(typ, key.as_ptr(), key_size,
             info_ptr, info_size, out.as_mut_ptr(), out_size, heap, dev_id)
     };
     if rc != 0 {
-        return Err(rc);
+        return Err(Error::from(rc));
     }
     Ok(())
 }
@@ -249,7 +248,7 @@ pub fn hkdf_expand_ex(typ: i32, key: &[u8], info: Option<&[u8]>, out: &mut [u8],
 /// let mut out = [0u8; 16];
 /// hkdf(HMAC::TYPE_SHA256, ikm, Some(salt), Some(info), &mut out).expect("Error with hkdf()");
 /// ```
-pub fn hkdf(typ: i32, key: &[u8], salt: Option<&[u8]>, info: Option<&[u8]>, out: &mut[u8]) -> Result<(), i32> {
+pub fn hkdf(typ: i32, key: &[u8], salt: Option<&[u8]>, info: Option<&[u8]>, out: &mut[u8]) -> Result<(), Error> {
     let key_size = crate::buffer_len_to_u32(key.len())?;
     let mut salt_ptr = core::ptr::null();
     let mut salt_size = 0u32;
@@ -269,7 +268,7 @@ pub fn hkdf(typ: i32, key: &[u8], salt: Option<&[u8]>, info: Option<&[u8]>, out:
             info_ptr, info_size, out.as_mut_ptr(), out_size)
     };
     if rc != 0 {
-        return Err(rc);
+        return Err(Error::from(rc));
     }
     Ok(())
 }
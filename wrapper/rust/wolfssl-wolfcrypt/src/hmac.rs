@@ -25,15 +25,21 @@ functionality.
 
 #![cfg(hmac)]
 
+use crate::Error;
+use crate::memory::HeapHint;
 use crate::sys;
+use core::marker::PhantomData;
 use core::mem::MaybeUninit;
 
 /// Rust wrapper for wolfSSL `Hmac` object.
-pub struct HMAC {
+pub struct HMAC<'a> {
     wc_hmac: sys::Hmac,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 
-impl HMAC {
+impl<'a> HMAC<'a> {
     pub const TYPE_MD5: i32 = sys::wc_HashType_WC_HASH_TYPE_MD5 as i32;
     pub const TYPE_SHA: i32 = sys::wc_HashType_WC_HASH_TYPE_SHA as i32;
     pub const TYPE_SHA256: i32 = sys::wc_HashType_WC_HASH_TYPE_SHA256 as i32;
@@ -59,10 +65,10 @@ impl HMAC {
     ///
     /// Returns either Ok(size) containing the HMAC hash size or Err(e)
     /// containing the wolfSSL library error code value.
-    pub fn get_hmac_size_by_type(typ: i32) -> Result<usize, i32> {
+    pub fn get_hmac_size_by_type(typ: i32) -> Result<usize, Error> {
         let rc = unsafe { sys::wc_HmacSizeByType(typ) };
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(rc as u32 as usize)
     }
@@ -73,6 +79,7 @@ impl HMAC {
     ///
     /// * `typ`: Hash type, one of `HMAC::TYPE_*`.
     /// * `key`: Encryption key.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
@@ -83,11 +90,13 @@ impl HMAC {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::hmac::HMAC;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let key = [0x42u8; 16];
-    /// let mut hmac = HMAC::new(HMAC::TYPE_SHA256, &key).expect("Error with new()");
+    /// let mut hmac = HMAC::new(HMAC::TYPE_SHA256, &key, &wolfcrypt).expect("Error with new()");
     /// ```
-    pub fn new(typ: i32, key: &[u8]) -> Result<Self, i32> {
-        Self::new_ex(typ, key, None, None)
+    pub fn new(typ: i32, key: &[u8], wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(typ, key, &crate::Env::new(wolfcrypt))
     }
 
     /// Create a new HMAC object with the given hash type and encryption key
@@ -97,8 +106,7 @@ impl HMAC {
     ///
     /// * `typ`: Hash type, one of `HMAC::TYPE_*`.
     /// * `key`: Encryption key.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -108,34 +116,32 @@ impl HMAC {
     /// # Example
     ///
     /// ```rust
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::hmac::HMAC;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let key = [0x42u8; 16];
-    /// let mut hmac = HMAC::new_ex(HMAC::TYPE_SHA256, &key, None, None).expect("Error with new_ex()");
+    /// let mut hmac = HMAC::new_ex(HMAC::TYPE_SHA256, &key, &Env::new(&wolfcrypt)).expect("Error with new_ex()");
     /// ```
-    pub fn new_ex(typ: i32, key: &[u8], heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn new_ex(typ: i32, key: &[u8], env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let key_size = crate::buffer_len_to_u32(key.len())?;
         let mut wc_hmac: MaybeUninit<sys::Hmac> = MaybeUninit::uninit();
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe {
             sys::wc_HmacInit(wc_hmac.as_mut_ptr(), heap, dev_id)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_hmac = unsafe { wc_hmac.assume_init() };
-        let mut hmac = HMAC { wc_hmac };
+        let mut hmac = HMAC { wc_hmac, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData };
         let rc = unsafe {
             sys::wc_HmacSetKey(&mut hmac.wc_hmac, typ, key.as_ptr(), key_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(hmac)
     }
@@ -147,6 +153,7 @@ impl HMAC {
     ///
     /// * `typ`: Hash type, one of `HMAC::TYPE_*`.
     /// * `key`: Encryption key.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
@@ -158,12 +165,14 @@ impl HMAC {
     /// ```rust
     /// #![cfg(hmac_setkey_ex)]
     /// use wolfssl_wolfcrypt::hmac::HMAC;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let key = [0x42u8; 3];
-    /// let mut hmac = HMAC::new_allow_short_key(HMAC::TYPE_SHA256, &key).expect("Error with new_allow_short_key()");
+    /// let mut hmac = HMAC::new_allow_short_key(HMAC::TYPE_SHA256, &key, &wolfcrypt).expect("Error with new_allow_short_key()");
     /// ```
     #[cfg(hmac_setkey_ex)]
-    pub fn new_allow_short_key(typ: i32, key: &[u8]) -> Result<Self, i32> {
-        Self::new_allow_short_key_ex(typ, key, None, None)
+    pub fn new_allow_short_key(typ: i32, key: &[u8], wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_allow_short_key_ex(typ, key, &crate::Env::new(wolfcrypt))
     }
 
     /// Create a new HMAC object with the given hash type and encryption key,
@@ -173,8 +182,7 @@ impl HMAC {
     ///
     /// * `typ`: Hash type, one of `HMAC::TYPE_*`.
     /// * `key`: Encryption key.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -185,35 +193,33 @@ impl HMAC {
     ///
     /// ```rust
     /// #![cfg(hmac_setkey_ex)]
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::hmac::HMAC;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let key = [0x42u8; 3];
-    /// let mut hmac = HMAC::new_allow_short_key_ex(HMAC::TYPE_SHA256, &key, None, None).expect("Error with new_allow_short_key_ex()");
+    /// let mut hmac = HMAC::new_allow_short_key_ex(HMAC::TYPE_SHA256, &key, &Env::new(&wolfcrypt)).expect("Error with new_allow_short_key_ex()");
     /// ```
     #[cfg(hmac_setkey_ex)]
-    pub fn new_allow_short_key_ex(typ: i32, key: &[u8], heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn new_allow_short_key_ex(typ: i32, key: &[u8], env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let key_size = crate::buffer_len_to_u32(key.len())?;
         let mut wc_hmac: MaybeUninit<sys::Hmac> = MaybeUninit::uninit();
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe {
             sys::wc_HmacInit(wc_hmac.as_mut_ptr(), heap, dev_id)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_hmac = unsafe { wc_hmac.assume_init() };
-        let mut hmac = HMAC { wc_hmac };
+        let mut hmac = HMAC { wc_hmac, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData };
         let rc = unsafe {
             sys::wc_HmacSetKey_ex(&mut hmac.wc_hmac, typ, key.as_ptr(), key_size, 1)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(hmac)
     }
@@ -236,17 +242,19 @@ impl HMAC {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::hmac::HMAC;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let key = [0x42u8; 16];
-    /// let mut hmac = HMAC::new(HMAC::TYPE_SHA256, &key).expect("Error with new()");
+    /// let mut hmac = HMAC::new(HMAC::TYPE_SHA256, &key, &wolfcrypt).expect("Error with new()");
     /// hmac.update(b"input").expect("Error with update()");
     /// ```
-    pub fn update(&mut self, data: &[u8]) -> Result<(), i32> {
+    pub fn update(&mut self, data: &[u8]) -> Result<(), Error> {
         let data_size = crate::buffer_len_to_u32(data.len())?;
         let rc = unsafe {
             sys::wc_HmacUpdate(&mut self.wc_hmac, data.as_ptr(), data_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -267,30 +275,32 @@ impl HMAC {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::hmac::HMAC;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let key = [0x42u8; 16];
-    /// let mut hmac = HMAC::new(HMAC::TYPE_SHA256, &key).expect("Error with new()");
+    /// let mut hmac = HMAC::new(HMAC::TYPE_SHA256, &key, &wolfcrypt).expect("Error with new()");
     /// hmac.update(b"input").expect("Error with update()");
     /// let hash_size = hmac.get_hmac_size().expect("Error with get_hmac_size()");
     /// let mut hash = vec![0u8; hash_size];
     /// hmac.finalize(&mut hash).expect("Error with finalize()");
     /// ```
-    pub fn finalize(&mut self, hash: &mut [u8]) -> Result<(), i32> {
+    pub fn finalize(&mut self, hash: &mut [u8]) -> Result<(), Error> {
         // Check the output buffer size since wc_HmacFinal() does not accept
         // a length parameter.
         let typ = self.wc_hmac.macType as i32;
         let rc = unsafe { sys::wc_HmacSizeByType(typ) };
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let expected_size = rc as usize;
         if hash.len() != expected_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let rc = unsafe {
             sys::wc_HmacFinal(&mut self.wc_hmac, hash.as_mut_ptr())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -306,31 +316,33 @@ impl HMAC {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::hmac::HMAC;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let key = [0x42u8; 16];
-    /// let mut hmac = HMAC::new(HMAC::TYPE_SHA256, &key).expect("Error with new()");
+    /// let mut hmac = HMAC::new(HMAC::TYPE_SHA256, &key, &wolfcrypt).expect("Error with new()");
     /// hmac.update(b"input").expect("Error with update()");
     /// let hash_size = hmac.get_hmac_size().expect("Error with get_hmac_size()");
     /// let mut hash = vec![0u8; hash_size];
     /// hmac.finalize(&mut hash).expect("Error with finalize()");
     /// ```
-    pub fn get_hmac_size(&self) -> Result<usize, i32> {
+    pub fn get_hmac_size(&self) -> Result<usize, Error> {
         let typ = self.wc_hmac.macType as u32 as i32;
         let rc = unsafe { sys::wc_HmacSizeByType(typ) };
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let expected_size = rc as u32 as usize;
         Ok(expected_size)
     }
 }
 
-impl HMAC {
+impl<'a> HMAC<'a> {
     fn zeroize(&mut self) {
         unsafe { crate::zeroize_raw(&mut self.wc_hmac); }
     }
 }
 
-impl Clone for HMAC {
+impl Clone for HMAC<'_> {
     /// Deep-copy the HMAC state via `wc_HmacCopy()`.
     ///
     /// Panics if the underlying wolfSSL copy fails.
@@ -344,11 +356,11 @@ impl Clone for HMAC {
             panic!("wc_HmacCopy() failed: {}", rc);
         }
         let wc_hmac = unsafe { wc_hmac.assume_init() };
-        HMAC { wc_hmac }
+        HMAC { wc_hmac, _wolfcrypt: self._wolfcrypt.clone(), _device: self._device.clone(), _heap: PhantomData }
     }
 }
 
-impl Drop for HMAC {
+impl Drop for HMAC<'_> {
     /// Safely free the underlying wolfSSL Hmac context.
     ///
     /// This calls the `wc_HmacFree()` wolfssl library function.
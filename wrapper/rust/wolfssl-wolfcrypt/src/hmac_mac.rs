@@ -44,7 +44,7 @@ macro_rules! impl_hmac_mac {
         $(#[$attr])*
         #[derive(Clone)]
         pub struct $name {
-            hmac: crate::hmac::HMAC,
+            hmac: crate::hmac::HMAC<'static>,
         }
 
         $(#[$attr])*
@@ -63,14 +63,16 @@ macro_rules! impl_hmac_mac {
         $(#[$attr])*
         impl digest::KeyInit for $name {
             fn new(key: &digest::Key<Self>) -> Self {
+                let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
                 Self {
-                    hmac: crate::hmac::HMAC::new($hmac_type, key.as_slice())
+                    hmac: crate::hmac::HMAC::new($hmac_type, key.as_slice(), wolfcrypt)
                         .expect("wolfCrypt HMAC init failed"),
                 }
             }
 
             fn new_from_slice(key: &[u8]) -> Result<Self, digest::InvalidLength> {
-                crate::hmac::HMAC::new($hmac_type, key)
+                let wolfcrypt = crate::WolfCrypt::global().map_err(|_| digest::InvalidLength)?;
+                crate::hmac::HMAC::new($hmac_type, key, wolfcrypt)
                     .map(|hmac| Self { hmac })
                     .map_err(|_| digest::InvalidLength)
             }
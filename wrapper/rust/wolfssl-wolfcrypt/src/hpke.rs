@@ -0,0 +1,1374 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+This module provides Hybrid Public Key Encryption (HPKE) as specified in
+RFC 9180, built on wolfCrypt's ECDH, X25519, X448, ML-KEM, HMAC, AES-GCM and
+ChaCha20-Poly1305 primitives.
+
+wolfCrypt's own `wc_Hpke*` API only implements the Base mode without an
+exporter, so the HPKE key schedule is implemented here on top of wolfCrypt
+HMAC in order to support all four modes (Base, PSK, Auth and AuthPSK) and
+secret export.
+
+A cipher suite is selected with [`Suite::new()`] from a [`Kem`], [`Kdf`] and
+[`Aead`]. The sender calls [`Suite::setup_sender()`] with the recipient's
+[`PublicKey`] to obtain a [`SenderContext`] and the encapsulated key `enc`.
+The recipient passes `enc` and its [`PrivateKey`] to
+[`Suite::setup_receiver()`] to obtain the matching [`ReceiverContext`]. The
+PSK and Auth modes are selected by passing a [`Psk`] and/or the sender's
+static key to the setup functions.
+
+The ML-KEM based KEMs (`ML-KEM-512`, `ML-KEM-768` and `ML-KEM-1024`) follow
+the HPKE post-quantum KEM draft. Their private keys are the 64 byte ML-KEM
+seed. They do not support the Auth modes or [`PrivateKey::derive()`].
+
+# Examples
+
+```rust
+#[cfg(all(hmac, random, curve25519, aes_gcm))]
+{
+use wolfssl_wolfcrypt::hpke::*;
+use wolfssl_wolfcrypt::random::RNG;
+use wolfssl_wolfcrypt::WolfCrypt;
+let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+
+let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+let suite = Suite::new(Kem::DhKemX25519HkdfSha256, Kdf::HkdfSha256,
+    Aead::Aes128Gcm);
+let sk_r = PrivateKey::generate(suite.kem, &rng, &wolfcrypt).expect("Error with generate()");
+
+let mut enc = [0u8; 32];
+let mut sender = suite.setup_sender(sk_r.public_key(), b"info", None, None,
+    &rng, &mut enc).expect("Error with setup_sender()");
+let mut ct = [0u8; 5 + TAG_SIZE];
+let ct_size = sender.seal(b"aad", b"hello", &mut ct).expect("Error with seal()");
+
+let mut receiver = suite.setup_receiver(&enc, &sk_r, b"info", None, None)
+    .expect("Error with setup_receiver()");
+let mut pt = [0u8; 5];
+let pt_size = receiver.open(b"aad", &ct[..ct_size], &mut pt).expect("Error with open()");
+assert_eq!(&pt[..pt_size], b"hello");
+}
+```
+*/
+
+#![cfg(all(hmac, random))]
+
+use crate::hmac::HMAC;
+use crate::random::RNG;
+use crate::{Error, WolfCrypt};
+use crate::sys;
+#[cfg(all(ecc, ecc_dh, ecc_import, ecc_export))]
+use crate::ecc::ECC;
+#[cfg(curve25519)]
+use crate::curve25519::Curve25519Key;
+#[cfg(curve448)]
+use crate::curve448::X448;
+#[cfg(mlkem)]
+use crate::mlkem::MlKem;
+use zeroize::Zeroize;
+
+/// Maximum serialized public key size of any supported KEM.
+pub const MAX_PUBLIC_KEY_SIZE: usize = 1568;
+/// Maximum encapsulated key size of any supported KEM.
+pub const MAX_ENC_SIZE: usize = 1568;
+/// Maximum serialized private key size of any supported KEM.
+pub const MAX_PRIVATE_KEY_SIZE: usize = 66;
+/// AEAD nonce size of all supported AEADs.
+pub const NONCE_SIZE: usize = 12;
+/// AEAD authentication tag size of all supported AEADs.
+pub const TAG_SIZE: usize = 16;
+
+const MAX_DH_SIZE: usize = 66;
+const MAX_DH_PUBLIC_KEY_SIZE: usize = 133;
+const MAX_HASH_SIZE: usize = 64;
+const MAX_KEY_SIZE: usize = 32;
+const MAX_SHARED_SECRET_SIZE: usize = 64;
+const MLKEM_SEED_SIZE: usize = 64;
+const HPKE_VERSION: &[u8] = b"HPKE-v1";
+
+/// Key encapsulation mechanism of an HPKE cipher suite.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kem {
+    DhKemP256HkdfSha256,
+    DhKemP384HkdfSha384,
+    DhKemP521HkdfSha512,
+    DhKemX25519HkdfSha256,
+    DhKemX448HkdfSha512,
+    MlKem512,
+    MlKem768,
+    MlKem1024,
+}
+
+impl Kem {
+    /// Get the IANA KEM identifier.
+    pub const fn id(self) -> u16 {
+        match self {
+            Kem::DhKemP256HkdfSha256 => 0x0010,
+            Kem::DhKemP384HkdfSha384 => 0x0011,
+            Kem::DhKemP521HkdfSha512 => 0x0012,
+            Kem::DhKemX25519HkdfSha256 => 0x0020,
+            Kem::DhKemX448HkdfSha512 => 0x0021,
+            Kem::MlKem512 => 0x0040,
+            Kem::MlKem768 => 0x0041,
+            Kem::MlKem1024 => 0x0042,
+        }
+    }
+
+    /// Look up a KEM by its IANA identifier.
+    ///
+    /// # Parameters
+    ///
+    /// * `id`: IANA KEM identifier.
+    ///
+    /// # Returns
+    ///
+    /// Returns Some(kem) for a supported identifier or None otherwise.
+    pub const fn from_id(id: u16) -> Option<Self> {
+        match id {
+            0x0010 => Some(Kem::DhKemP256HkdfSha256),
+            0x0011 => Some(Kem::DhKemP384HkdfSha384),
+            0x0012 => Some(Kem::DhKemP521HkdfSha512),
+            0x0020 => Some(Kem::DhKemX25519HkdfSha256),
+            0x0021 => Some(Kem::DhKemX448HkdfSha512),
+            0x0040 => Some(Kem::MlKem512),
+            0x0041 => Some(Kem::MlKem768),
+            0x0042 => Some(Kem::MlKem1024),
+            _ => None,
+        }
+    }
+
+    /// Get the size of a serialized public key (`Npk`).
+    pub const fn public_key_size(self) -> usize {
+        match self {
+            Kem::DhKemP256HkdfSha256 => 65,
+            Kem::DhKemP384HkdfSha384 => 97,
+            Kem::DhKemP521HkdfSha512 => 133,
+            Kem::DhKemX25519HkdfSha256 => 32,
+            Kem::DhKemX448HkdfSha512 => 56,
+            Kem::MlKem512 => 800,
+            Kem::MlKem768 => 1184,
+            Kem::MlKem1024 => 1568,
+        }
+    }
+
+    /// Get the size of a serialized private key (`Nsk`).
+    pub const fn private_key_size(self) -> usize {
+        match self {
+            Kem::DhKemP256HkdfSha256 => 32,
+            Kem::DhKemP384HkdfSha384 => 48,
+            Kem::DhKemP521HkdfSha512 => 66,
+            Kem::DhKemX25519HkdfSha256 => 32,
+            Kem::DhKemX448HkdfSha512 => 56,
+            Kem::MlKem512 | Kem::MlKem768 | Kem::MlKem1024 => MLKEM_SEED_SIZE,
+        }
+    }
+
+    /// Get the size of an encapsulated key (`Nenc`).
+    pub const fn enc_size(self) -> usize {
+        match self {
+            Kem::MlKem512 => 768,
+            Kem::MlKem768 => 1088,
+            Kem::MlKem1024 => 1568,
+            _ => self.public_key_size(),
+        }
+    }
+
+    /// Get the size of the KEM shared secret (`Nsecret`).
+    pub const fn shared_secret_size(self) -> usize {
+        match self {
+            Kem::DhKemP256HkdfSha256 | Kem::DhKemX25519HkdfSha256 => 32,
+            Kem::DhKemP384HkdfSha384 => 48,
+            Kem::DhKemP521HkdfSha512 | Kem::DhKemX448HkdfSha512 => 64,
+            Kem::MlKem512 | Kem::MlKem768 | Kem::MlKem1024 => 32,
+        }
+    }
+
+    /// Check whether this KEM supports the Auth and AuthPSK modes.
+    pub const fn supports_auth(self) -> bool {
+        self.is_dhkem()
+    }
+
+    const fn is_dhkem(self) -> bool {
+        !matches!(self, Kem::MlKem512 | Kem::MlKem768 | Kem::MlKem1024)
+    }
+
+    /// Size of a Diffie-Hellman shared value (`Ndh`).
+    const fn dh_size(self) -> usize {
+        self.private_key_size()
+    }
+
+    /// KDF used inside DHKEM.
+    const fn kdf(self) -> Kdf {
+        match self {
+            Kem::DhKemP384HkdfSha384 => Kdf::HkdfSha384,
+            Kem::DhKemP521HkdfSha512 | Kem::DhKemX448HkdfSha512 => Kdf::HkdfSha512,
+            _ => Kdf::HkdfSha256,
+        }
+    }
+
+    fn suite_id(self) -> [u8; 5] {
+        let id = self.id().to_be_bytes();
+        [b'K', b'E', b'M', id[0], id[1]]
+    }
+}
+
+/// Key derivation function of an HPKE cipher suite.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kdf {
+    HkdfSha256,
+    HkdfSha384,
+    HkdfSha512,
+}
+
+impl Kdf {
+    /// Get the IANA KDF identifier.
+    pub const fn id(self) -> u16 {
+        match self {
+            Kdf::HkdfSha256 => 0x0001,
+            Kdf::HkdfSha384 => 0x0002,
+            Kdf::HkdfSha512 => 0x0003,
+        }
+    }
+
+    /// Look up a KDF by its IANA identifier.
+    ///
+    /// # Parameters
+    ///
+    /// * `id`: IANA KDF identifier.
+    ///
+    /// # Returns
+    ///
+    /// Returns Some(kdf) for a supported identifier or None otherwise.
+    pub const fn from_id(id: u16) -> Option<Self> {
+        match id {
+            0x0001 => Some(Kdf::HkdfSha256),
+            0x0002 => Some(Kdf::HkdfSha384),
+            0x0003 => Some(Kdf::HkdfSha512),
+            _ => None,
+        }
+    }
+
+    /// Get the size of the underlying hash output (`Nh`).
+    pub const fn hash_size(self) -> usize {
+        match self {
+            Kdf::HkdfSha256 => 32,
+            Kdf::HkdfSha384 => 48,
+            Kdf::HkdfSha512 => 64,
+        }
+    }
+
+    const fn hmac_type(self) -> i32 {
+        match self {
+            Kdf::HkdfSha256 => HMAC::TYPE_SHA256,
+            Kdf::HkdfSha384 => HMAC::TYPE_SHA384,
+            Kdf::HkdfSha512 => HMAC::TYPE_SHA512,
+        }
+    }
+}
+
+/// AEAD of an HPKE cipher suite.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Aead {
+    Aes128Gcm,
+    Aes256Gcm,
+    ChaCha20Poly1305,
+    /// No AEAD; contexts can only be used to export secrets.
+    ExportOnly,
+}
+
+impl Aead {
+    /// Get the IANA AEAD identifier.
+    pub const fn id(self) -> u16 {
+        match self {
+            Aead::Aes128Gcm => 0x0001,
+            Aead::Aes256Gcm => 0x0002,
+            Aead::ChaCha20Poly1305 => 0x0003,
+            Aead::ExportOnly => 0xFFFF,
+        }
+    }
+
+    /// Look up an AEAD by its IANA identifier.
+    ///
+    /// # Parameters
+    ///
+    /// * `id`: IANA AEAD identifier.
+    ///
+    /// # Returns
+    ///
+    /// Returns Some(aead) for a supported identifier or None otherwise.
+    pub const fn from_id(id: u16) -> Option<Self> {
+        match id {
+            0x0001 => Some(Aead::Aes128Gcm),
+            0x0002 => Some(Aead::Aes256Gcm),
+            0x0003 => Some(Aead::ChaCha20Poly1305),
+            0xFFFF => Some(Aead::ExportOnly),
+            _ => None,
+        }
+    }
+
+    /// Get the AEAD key size (`Nk`), 0 for `ExportOnly`.
+    pub const fn key_size(self) -> usize {
+        match self {
+            Aead::Aes128Gcm => 16,
+            Aead::Aes256Gcm | Aead::ChaCha20Poly1305 => 32,
+            Aead::ExportOnly => 0,
+        }
+    }
+}
+
+/// HPKE mode, derived from the inputs given to the setup functions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    Base = 0,
+    Psk = 1,
+    Auth = 2,
+    AuthPsk = 3,
+}
+
+impl Mode {
+    fn new(psk: bool, auth: bool) -> Self {
+        match (psk, auth) {
+            (false, false) => Mode::Base,
+            (true, false) => Mode::Psk,
+            (false, true) => Mode::Auth,
+            (true, true) => Mode::AuthPsk,
+        }
+    }
+}
+
+/// Pre-shared key and its identifier for the PSK and AuthPSK modes.
+#[derive(Clone, Copy)]
+pub struct Psk<'a> {
+    /// Pre-shared key. RFC 9180 requires at least 32 bytes of entropy.
+    pub key: &'a [u8],
+    /// Identifier of the pre-shared key.
+    pub id: &'a [u8],
+}
+
+/// HPKE public key of a KEM.
+#[derive(Clone)]
+pub struct PublicKey {
+    kem: Kem,
+    bytes: [u8; MAX_PUBLIC_KEY_SIZE],
+    wolfcrypt: WolfCrypt,
+}
+
+impl PublicKey {
+    /// Deserialize and validate a public key (`DeserializePublicKey()`).
+    ///
+    /// # Parameters
+    ///
+    /// * `kem`: KEM the key belongs to.
+    /// * `data`: Serialized public key of `kem.public_key_size()` bytes.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(PublicKey) on success or Err(e) containing the
+    /// wolfSSL library error code value.
+    pub fn from_bytes(kem: Kem, data: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        if data.len() != kem.public_key_size() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        check_public_key(kem, data, wolfcrypt)?;
+        let mut bytes = [0u8; MAX_PUBLIC_KEY_SIZE];
+        bytes[..data.len()].copy_from_slice(data);
+        Ok(PublicKey { kem, bytes, wolfcrypt: wolfcrypt.clone() })
+    }
+
+    /// Get the KEM this key belongs to.
+    pub fn kem(&self) -> Kem {
+        self.kem
+    }
+
+    /// Get the serialized public key (`SerializePublicKey()`).
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.kem.public_key_size()]
+    }
+}
+
+/// HPKE private key of a KEM along with its public key.
+pub struct PrivateKey {
+    kem: Kem,
+    bytes: [u8; MAX_PRIVATE_KEY_SIZE],
+    public_key: PublicKey,
+}
+
+impl PrivateKey {
+    /// Generate a new random key pair (`GenerateKeyPair()`).
+    ///
+    /// # Parameters
+    ///
+    /// * `kem`: KEM to generate a key pair for.
+    /// * `rng`: `RNG` instance to use for random number generation.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(PrivateKey) on success or Err(e) containing the
+    /// wolfSSL library error code value.
+    pub fn generate(kem: Kem, rng: &RNG, wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        let mut ikm = [0u8; MAX_PRIVATE_KEY_SIZE];
+        let ikm_size = kem.private_key_size();
+        rng.generate_block(&mut ikm[..ikm_size])?;
+        let result = if kem.is_dhkem() {
+            Self::derive(kem, &ikm[..ikm_size], wolfcrypt)
+        } else {
+            Self::from_bytes(kem, &ikm[..ikm_size], wolfcrypt)
+        };
+        ikm.zeroize();
+        result
+    }
+
+    /// Deterministically derive a key pair from input keying material
+    /// (`DeriveKeyPair()`).
+    ///
+    /// This is only supported by the DHKEMs.
+    ///
+    /// # Parameters
+    ///
+    /// * `kem`: KEM to derive a key pair for.
+    /// * `ikm`: Input keying material of at least `kem.private_key_size()`
+    ///   bytes.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(PrivateKey) on success or Err(e) containing the
+    /// wolfSSL library error code value.
+    pub fn derive(kem: Kem, ikm: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        if !kem.is_dhkem() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+        }
+        if ikm.len() < kem.private_key_size() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        let kdf = kem.kdf();
+        let suite_id = kem.suite_id();
+        let sk_size = kem.private_key_size();
+        let mut prk = [0u8; MAX_HASH_SIZE];
+        let mut sk = [0u8; MAX_PRIVATE_KEY_SIZE];
+        let result = labeled_extract(kdf, &suite_id, &[], b"dkp_prk", ikm,
+                &mut prk[..kdf.hash_size()], wolfcrypt)
+            .and_then(|()| match kem {
+                Kem::DhKemX25519HkdfSha256 | Kem::DhKemX448HkdfSha512 => {
+                    labeled_expand(kdf, &suite_id, &prk[..kdf.hash_size()], b"sk",
+                        &[], &mut sk[..sk_size], wolfcrypt)?;
+                    Self::from_bytes(kem, &sk[..sk_size], wolfcrypt)
+                }
+                _ => {
+                    // Rejection sampling of a scalar in [1, n - 1].
+                    let bitmask = if kem == Kem::DhKemP521HkdfSha512 {0x01} else {0xFF};
+                    for counter in 0..=255u8 {
+                        labeled_expand(kdf, &suite_id, &prk[..kdf.hash_size()],
+                            b"candidate", &[counter], &mut sk[..sk_size], wolfcrypt)?;
+                        sk[0] &= bitmask;
+                        match Self::from_bytes(kem, &sk[..sk_size], wolfcrypt) {
+                            Err(e) if e == sys::wolfCrypt_ErrorCodes_ECC_PRIV_KEY_E => continue,
+                            result => return result,
+                        }
+                    }
+                    Err(Error::from(sys::wolfCrypt_ErrorCodes_ECC_PRIV_KEY_E))
+                }
+            });
+        prk.zeroize();
+        sk.zeroize();
+        result
+    }
+
+    /// Deserialize a private key and compute its public key
+    /// (`DeserializePrivateKey()`).
+    ///
+    /// X25519 and X448 private keys are clamped. ML-KEM private keys are the
+    /// 64 byte key generation seed.
+    ///
+    /// # Parameters
+    ///
+    /// * `kem`: KEM the key belongs to.
+    /// * `data`: Serialized private key of `kem.private_key_size()` bytes.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(PrivateKey) on success or Err(e) containing the
+    /// wolfSSL library error code value.
+    pub fn from_bytes(kem: Kem, data: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        if data.len() != kem.private_key_size() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        let mut key = PrivateKey {
+            kem,
+            bytes: [0u8; MAX_PRIVATE_KEY_SIZE],
+            public_key: PublicKey {
+                kem,
+                bytes: [0u8; MAX_PUBLIC_KEY_SIZE],
+                wolfcrypt: wolfcrypt.clone(),
+            },
+        };
+        let sk = &mut key.bytes[..data.len()];
+        sk.copy_from_slice(data);
+        match kem {
+            Kem::DhKemX25519HkdfSha256 => {
+                sk[0] &= 248;
+                sk[31] &= 127;
+                sk[31] |= 64;
+            }
+            Kem::DhKemX448HkdfSha512 => {
+                sk[0] &= 252;
+                sk[55] |= 128;
+            }
+            _ => {}
+        }
+        let pk = &mut key.public_key.bytes[..kem.public_key_size()];
+        match kem {
+            Kem::DhKemP256HkdfSha256 | Kem::DhKemP384HkdfSha384 | Kem::DhKemP521HkdfSha512 => {
+                ecc_public_key(kem, sk, pk, wolfcrypt)?
+            }
+            Kem::DhKemX25519HkdfSha256 => x25519_public_key(sk, pk)?,
+            Kem::DhKemX448HkdfSha512 => x448_public_key(sk, pk)?,
+            Kem::MlKem512 | Kem::MlKem768 | Kem::MlKem1024 => {
+                mlkem_public_key(kem, sk, pk, wolfcrypt)?
+            }
+        }
+        Ok(key)
+    }
+
+    /// Get the KEM this key belongs to.
+    pub fn kem(&self) -> Kem {
+        self.kem
+    }
+
+    /// Get the serialized private key (`SerializePrivateKey()`).
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.kem.private_key_size()]
+    }
+
+    /// Get the public key of this key pair.
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+}
+
+impl Drop for PrivateKey {
+    fn drop(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+/// HPKE cipher suite.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Suite {
+    pub kem: Kem,
+    pub kdf: Kdf,
+    pub aead: Aead,
+}
+
+impl Suite {
+    /// Create a cipher suite from its KEM, KDF and AEAD.
+    pub const fn new(kem: Kem, kdf: Kdf, aead: Aead) -> Self {
+        Suite { kem, kdf, aead }
+    }
+
+    fn id(&self) -> [u8; 10] {
+        let kem = self.kem.id().to_be_bytes();
+        let kdf = self.kdf.id().to_be_bytes();
+        let aead = self.aead.id().to_be_bytes();
+        [b'H', b'P', b'K', b'E', kem[0], kem[1], kdf[0], kdf[1], aead[0], aead[1]]
+    }
+
+    /// Set up a sender context for the recipient public key `pk_r`.
+    ///
+    /// The mode is Base, PSK, Auth or AuthPSK depending on whether `psk`
+    /// and/or the sender's static key `sk_s` are given.
+    ///
+    /// # Parameters
+    ///
+    /// * `pk_r`: Recipient public key.
+    /// * `info`: Application supplied context information.
+    /// * `psk`: Pre-shared key for the PSK and AuthPSK modes.
+    /// * `sk_s`: Sender private key for the Auth and AuthPSK modes.
+    /// * `rng`: `RNG` instance used for the ephemeral key or encapsulation.
+    /// * `enc`: Output buffer of `kem.enc_size()` bytes in which to store the
+    ///   encapsulated key to send to the recipient.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(SenderContext) on success or Err(e) containing the
+    /// wolfSSL library error code value.
+    pub fn setup_sender(&self, pk_r: &PublicKey, info: &[u8], psk: Option<Psk>,
+            sk_s: Option<&PrivateKey>, rng: &RNG, enc: &mut [u8]) -> Result<SenderContext, Error> {
+        self.setup_sender_internal(pk_r, info, psk, sk_s, Ephemeral::Rng(rng), enc)
+    }
+
+    /// Set up a sender context deterministically, as used by the RFC 9180
+    /// test vectors.
+    ///
+    /// For the DHKEMs the ephemeral key pair is derived from `ikm_e` with
+    /// `DeriveKeyPair()`. For ML-KEM `ikm_e` is the 32 byte encapsulation
+    /// randomness.
+    ///
+    /// # Parameters
+    ///
+    /// * `pk_r`: Recipient public key.
+    /// * `info`: Application supplied context information.
+    /// * `psk`: Pre-shared key for the PSK and AuthPSK modes.
+    /// * `sk_s`: Sender private key for the Auth and AuthPSK modes.
+    /// * `ikm_e`: Ephemeral input keying material.
+    /// * `enc`: Output buffer of `kem.enc_size()` bytes in which to store the
+    ///   encapsulated key to send to the recipient.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(SenderContext) on success or Err(e) containing the
+    /// wolfSSL library error code value.
+    pub fn setup_sender_with_ikm(&self, pk_r: &PublicKey, info: &[u8],
+            psk: Option<Psk>, sk_s: Option<&PrivateKey>, ikm_e: &[u8],
+            enc: &mut [u8]) -> Result<SenderContext, Error> {
+        self.setup_sender_internal(pk_r, info, psk, sk_s, Ephemeral::Ikm(ikm_e), enc)
+    }
+
+    fn setup_sender_internal(&self, pk_r: &PublicKey, info: &[u8], psk: Option<Psk>,
+            sk_s: Option<&PrivateKey>, ephemeral: Ephemeral,
+            enc: &mut [u8]) -> Result<SenderContext, Error> {
+        if pk_r.kem != self.kem || sk_s.is_some_and(|sk_s| sk_s.kem != self.kem) {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+        }
+        if enc.len() != self.kem.enc_size() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        let mode = Mode::new(psk.is_some(), sk_s.is_some());
+        let mut shared_secret = [0u8; MAX_SHARED_SECRET_SIZE];
+        let shared_secret_size = self.kem.shared_secret_size();
+        let result = encap(pk_r, sk_s, ephemeral, &mut shared_secret[..shared_secret_size], enc)
+            .and_then(|()| key_schedule(self, mode, &shared_secret[..shared_secret_size],
+                info, psk, &pk_r.wolfcrypt));
+        shared_secret.zeroize();
+        Ok(SenderContext { context: result? })
+    }
+
+    /// Set up a receiver context from the encapsulated key `enc`.
+    ///
+    /// The mode is Base, PSK, Auth or AuthPSK depending on whether `psk`
+    /// and/or the sender's static public key `pk_s` are given, and must
+    /// match the mode used by the sender.
+    ///
+    /// # Parameters
+    ///
+    /// * `enc`: Encapsulated key received from the sender.
+    /// * `sk_r`: Recipient private key.
+    /// * `info`: Application supplied context information.
+    /// * `psk`: Pre-shared key for the PSK and AuthPSK modes.
+    /// * `pk_s`: Sender public key for the Auth and AuthPSK modes.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(ReceiverContext) on success or Err(e) containing the
+    /// wolfSSL library error code value.
+    pub fn setup_receiver(&self, enc: &[u8], sk_r: &PrivateKey, info: &[u8],
+            psk: Option<Psk>, pk_s: Option<&PublicKey>) -> Result<ReceiverContext, Error> {
+        if sk_r.kem != self.kem || pk_s.is_some_and(|pk_s| pk_s.kem != self.kem) {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+        }
+        if enc.len() != self.kem.enc_size() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        let mode = Mode::new(psk.is_some(), pk_s.is_some());
+        let mut shared_secret = [0u8; MAX_SHARED_SECRET_SIZE];
+        let shared_secret_size = self.kem.shared_secret_size();
+        let result = decap(enc, sk_r, pk_s, &mut shared_secret[..shared_secret_size])
+            .and_then(|()| key_schedule(self, mode, &shared_secret[..shared_secret_size],
+                info, psk, &sk_r.public_key.wolfcrypt));
+        shared_secret.zeroize();
+        Ok(ReceiverContext { context: result? })
+    }
+}
+
+/// Encryption context state shared by senders and receivers.
+struct Context {
+    suite: Suite,
+    mode: Mode,
+    key: [u8; MAX_KEY_SIZE],
+    base_nonce: [u8; NONCE_SIZE],
+    exporter_secret: [u8; MAX_HASH_SIZE],
+    seq: u64,
+    wolfcrypt: WolfCrypt,
+}
+
+impl Context {
+    fn nonce(&self) -> Result<[u8; NONCE_SIZE], Error> {
+        if self.suite.aead == Aead::ExportOnly {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+        }
+        // The sequence number would overflow the u64 counter; RFC 9180
+        // requires the context to refuse further use instead of wrapping.
+        if self.seq == u64::MAX {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_STATE_E));
+        }
+        let mut nonce = self.base_nonce;
+        for (n, s) in nonce[NONCE_SIZE - 8..].iter_mut().zip(self.seq.to_be_bytes()) {
+            *n ^= s;
+        }
+        Ok(nonce)
+    }
+
+    fn export(&self, exporter_context: &[u8], out: &mut [u8]) -> Result<(), Error> {
+        let kdf = self.suite.kdf;
+        labeled_expand(kdf, &self.suite.id(), &self.exporter_secret[..kdf.hash_size()],
+            b"sec", exporter_context, out, &self.wolfcrypt)
+    }
+}
+
+impl Drop for Context {
+    fn drop(&mut self) {
+        self.key.zeroize();
+        self.base_nonce.zeroize();
+        self.exporter_secret.zeroize();
+    }
+}
+
+/// HPKE sender context returned by [`Suite::setup_sender()`].
+pub struct SenderContext {
+    context: Context,
+}
+
+impl SenderContext {
+    /// Encrypt and authenticate a message with the next sequence number.
+    ///
+    /// # Parameters
+    ///
+    /// * `aad`: Additional authenticated data.
+    /// * `pt`: Plaintext to encrypt.
+    /// * `ct`: Output buffer of at least `pt.len() + TAG_SIZE` bytes in which
+    ///   to store the ciphertext followed by the authentication tag.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to `ct`
+    /// or Err(e) containing the wolfSSL library error code value.
+    pub fn seal(&mut self, aad: &[u8], pt: &[u8], ct: &mut [u8]) -> Result<usize, Error> {
+        let ct_size = pt.len() + TAG_SIZE;
+        if ct.len() < ct_size {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        let context = &mut self.context;
+        let nonce = context.nonce()?;
+        let key = &context.key[..context.suite.aead.key_size()];
+        let (ct, tag) = ct[..ct_size].split_at_mut(pt.len());
+        match context.suite.aead {
+            Aead::Aes128Gcm | Aead::Aes256Gcm => {
+                aes_gcm_seal(key, &nonce, aad, pt, ct, tag, &context.wolfcrypt)?
+            }
+            Aead::ChaCha20Poly1305 => chacha20_poly1305_seal(key, &nonce, aad, pt, ct, tag)?,
+            Aead::ExportOnly => return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG)),
+        }
+        context.seq += 1;
+        Ok(ct_size)
+    }
+
+    /// Derive a secret from the exporter secret of this context.
+    ///
+    /// # Parameters
+    ///
+    /// * `exporter_context`: Application supplied exporter context.
+    /// * `out`: Output buffer in which to store the secret. Its length is
+    ///   the secret size and may be at most 255 times the KDF hash size.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn export(&self, exporter_context: &[u8], out: &mut [u8]) -> Result<(), Error> {
+        self.context.export(exporter_context, out)
+    }
+
+    /// Get the sequence number of the next message.
+    pub fn seq(&self) -> u64 {
+        self.context.seq
+    }
+
+    /// Get the mode this context was set up with.
+    pub fn mode(&self) -> Mode {
+        self.context.mode
+    }
+
+    /// Get the cipher suite of this context.
+    pub fn suite(&self) -> Suite {
+        self.context.suite
+    }
+}
+
+/// HPKE receiver context returned by [`Suite::setup_receiver()`].
+pub struct ReceiverContext {
+    context: Context,
+}
+
+impl ReceiverContext {
+    /// Authenticate and decrypt a message with the next sequence number.
+    ///
+    /// The sequence number is only advanced if the message is successfully
+    /// authenticated. On failure the contents of `pt` are zeroized.
+    ///
+    /// # Parameters
+    ///
+    /// * `aad`: Additional authenticated data.
+    /// * `ct`: Ciphertext followed by the authentication tag.
+    /// * `pt`: Output buffer of at least `ct.len() - TAG_SIZE` bytes in which
+    ///   to store the plaintext.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to `pt`
+    /// or Err(e) containing the wolfSSL library error code value.
+    pub fn open(&mut self, aad: &[u8], ct: &[u8], pt: &mut [u8]) -> Result<usize, Error> {
+        if ct.len() < TAG_SIZE {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        let pt_size = ct.len() - TAG_SIZE;
+        if pt.len() < pt_size {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        let context = &mut self.context;
+        let nonce = context.nonce()?;
+        let key = &context.key[..context.suite.aead.key_size()];
+        let (ct, tag) = ct.split_at(pt_size);
+        let pt = &mut pt[..pt_size];
+        let result = match context.suite.aead {
+            Aead::Aes128Gcm | Aead::Aes256Gcm => {
+                aes_gcm_open(key, &nonce, aad, ct, tag, pt, &context.wolfcrypt)
+            }
+            Aead::ChaCha20Poly1305 => chacha20_poly1305_open(key, &nonce, aad, ct, tag, pt),
+            Aead::ExportOnly => Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG)),
+        };
+        if let Err(e) = result {
+            pt.zeroize();
+            return Err(e);
+        }
+        context.seq += 1;
+        Ok(pt_size)
+    }
+
+    /// Derive a secret from the exporter secret of this context.
+    ///
+    /// # Parameters
+    ///
+    /// * `exporter_context`: Application supplied exporter context.
+    /// * `out`: Output buffer in which to store the secret. Its length is
+    ///   the secret size and may be at most 255 times the KDF hash size.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn export(&self, exporter_context: &[u8], out: &mut [u8]) -> Result<(), Error> {
+        self.context.export(exporter_context, out)
+    }
+
+    /// Get the sequence number of the next message.
+    pub fn seq(&self) -> u64 {
+        self.context.seq
+    }
+
+    /// Get the mode this context was set up with.
+    pub fn mode(&self) -> Mode {
+        self.context.mode
+    }
+
+    /// Get the cipher suite of this context.
+    pub fn suite(&self) -> Suite {
+        self.context.suite
+    }
+}
+
+/// Source of the sender's ephemeral key or encapsulation randomness.
+enum Ephemeral<'a> {
+    Rng(&'a RNG<'a>),
+    Ikm(&'a [u8]),
+}
+
+fn labeled_extract(kdf: Kdf, suite_id: &[u8], salt: &[u8], label: &[u8],
+        ikm: &[u8], prk: &mut [u8], wolfcrypt: &WolfCrypt) -> Result<(), Error> {
+    // An empty salt is equivalent to a string of Nh zero bytes.
+    let zeros = [0u8; MAX_HASH_SIZE];
+    let salt = if salt.is_empty() {&zeros[..kdf.hash_size()]} else {salt};
+    let mut hmac = HMAC::new(kdf.hmac_type(), salt, wolfcrypt)?;
+    hmac.update(HPKE_VERSION)?;
+    hmac.update(suite_id)?;
+    hmac.update(label)?;
+    hmac.update(ikm)?;
+    hmac.finalize(prk)
+}
+
+fn labeled_expand(kdf: Kdf, suite_id: &[u8], prk: &[u8], label: &[u8],
+        info: &[u8], out: &mut [u8], wolfcrypt: &WolfCrypt) -> Result<(), Error> {
+    let hash_size = kdf.hash_size();
+    if out.len() > 255 * hash_size {
+        return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+    }
+    let length = (out.len() as u16).to_be_bytes();
+    let mut t = [0u8; MAX_HASH_SIZE];
+    let mut t_size = 0;
+    for (i, chunk) in out.chunks_mut(hash_size).enumerate() {
+        let mut hmac = HMAC::new(kdf.hmac_type(), prk, wolfcrypt)?;
+        hmac.update(&t[..t_size])?;
+        hmac.update(&length)?;
+        hmac.update(HPKE_VERSION)?;
+        hmac.update(suite_id)?;
+        hmac.update(label)?;
+        hmac.update(info)?;
+        hmac.update(&[(i + 1) as u8])?;
+        hmac.finalize(&mut t[..hash_size])?;
+        t_size = hash_size;
+        chunk.copy_from_slice(&t[..chunk.len()]);
+    }
+    t.zeroize();
+    Ok(())
+}
+
+fn key_schedule(suite: &Suite, mode: Mode, shared_secret: &[u8], info: &[u8],
+        psk: Option<Psk>, wolfcrypt: &WolfCrypt) -> Result<Context, Error> {
+    let (psk, psk_id) = match psk {
+        Some(psk) => (psk.key, psk.id),
+        None => (&[][..], &[][..]),
+    };
+    if matches!(mode, Mode::Psk | Mode::AuthPsk) && (psk.is_empty() || psk_id.is_empty()) {
+        return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+    }
+    let kdf = suite.kdf;
+    let hash_size = kdf.hash_size();
+    let suite_id = suite.id();
+    let mut key_schedule_context = [0u8; 1 + 2 * MAX_HASH_SIZE];
+    key_schedule_context[0] = mode as u8;
+    labeled_extract(kdf, &suite_id, &[], b"psk_id_hash", psk_id,
+        &mut key_schedule_context[1..1 + hash_size], wolfcrypt)?;
+    labeled_extract(kdf, &suite_id, &[], b"info_hash", info,
+        &mut key_schedule_context[1 + hash_size..1 + 2 * hash_size], wolfcrypt)?;
+    let key_schedule_context = &key_schedule_context[..1 + 2 * hash_size];
+
+    let mut context = Context {
+        suite: *suite,
+        mode,
+        key: [0u8; MAX_KEY_SIZE],
+        base_nonce: [0u8; NONCE_SIZE],
+        exporter_secret: [0u8; MAX_HASH_SIZE],
+        seq: 0,
+        wolfcrypt: wolfcrypt.clone(),
+    };
+    let mut secret = [0u8; MAX_HASH_SIZE];
+    let secret_size = hash_size;
+    let result = labeled_extract(kdf, &suite_id, shared_secret, b"secret", psk,
+            &mut secret[..secret_size], wolfcrypt)
+        .and_then(|()| {
+            let secret = &secret[..secret_size];
+            if suite.aead != Aead::ExportOnly {
+                labeled_expand(kdf, &suite_id, secret, b"key", key_schedule_context,
+                    &mut context.key[..suite.aead.key_size()], wolfcrypt)?;
+                labeled_expand(kdf, &suite_id, secret, b"base_nonce", key_schedule_context,
+                    &mut context.base_nonce, wolfcrypt)?;
+            }
+            labeled_expand(kdf, &suite_id, secret, b"exp", key_schedule_context,
+                &mut context.exporter_secret[..hash_size], wolfcrypt)
+        });
+    secret.zeroize();
+    result?;
+    Ok(context)
+}
+
+fn encap(pk_r: &PublicKey, sk_s: Option<&PrivateKey>, ephemeral: Ephemeral,
+        shared_secret: &mut [u8], enc: &mut [u8]) -> Result<(), Error> {
+    let kem = pk_r.kem;
+    let wolfcrypt = &pk_r.wolfcrypt;
+    if !kem.is_dhkem() {
+        if sk_s.is_some() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+        }
+        return mlkem_encap(pk_r, ephemeral, shared_secret, enc);
+    }
+    let sk_e = match ephemeral {
+        Ephemeral::Rng(rng) => PrivateKey::generate(kem, rng, wolfcrypt)?,
+        Ephemeral::Ikm(ikm) => PrivateKey::derive(kem, ikm, wolfcrypt)?,
+    };
+    enc.copy_from_slice(sk_e.public_key().as_bytes());
+    let mut kem_context = [0u8; 3 * MAX_DH_PUBLIC_KEY_SIZE];
+    let mut kem_context_size = 0;
+    for pk in [Some(sk_e.public_key()), Some(pk_r), sk_s.map(PrivateKey::public_key)]
+            .into_iter().flatten() {
+        let pk = pk.as_bytes();
+        kem_context[kem_context_size..kem_context_size + pk.len()].copy_from_slice(pk);
+        kem_context_size += pk.len();
+    }
+    let dh_size = kem.dh_size();
+    let mut dh = [0u8; 2 * MAX_DH_SIZE];
+    let mut result = dh_exchange(&sk_e, pk_r, &mut dh[..dh_size]);
+    let mut total_size = dh_size;
+    if let Some(sk_s) = sk_s {
+        result = result.and_then(|()| dh_exchange(sk_s, pk_r, &mut dh[dh_size..2 * dh_size]));
+        total_size += dh_size;
+    }
+    let result = result.and_then(|()| extract_and_expand(kem, &dh[..total_size],
+        &kem_context[..kem_context_size], shared_secret, wolfcrypt));
+    dh.zeroize();
+    result
+}
+
+fn decap(enc: &[u8], sk_r: &PrivateKey, pk_s: Option<&PublicKey>,
+        shared_secret: &mut [u8]) -> Result<(), Error> {
+    let kem = sk_r.kem;
+    let wolfcrypt = &sk_r.public_key.wolfcrypt;
+    if !kem.is_dhkem() {
+        if pk_s.is_some() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+        }
+        return mlkem_decap(enc, sk_r, shared_secret);
+    }
+    let pk_e = PublicKey::from_bytes(kem, enc, wolfcrypt)?;
+    let mut kem_context = [0u8; 3 * MAX_DH_PUBLIC_KEY_SIZE];
+    let mut kem_context_size = 0;
+    for pk in [Some(&pk_e), Some(sk_r.public_key()), pk_s].into_iter().flatten() {
+        let pk = pk.as_bytes();
+        kem_context[kem_context_size..kem_context_size + pk.len()].copy_from_slice(pk);
+        kem_context_size += pk.len();
+    }
+    let dh_size = kem.dh_size();
+    let mut dh = [0u8; 2 * MAX_DH_SIZE];
+    let mut result = dh_exchange(sk_r, &pk_e, &mut dh[..dh_size]);
+    let mut total_size = dh_size;
+    if let Some(pk_s) = pk_s {
+        result = result.and_then(|()| dh_exchange(sk_r, pk_s, &mut dh[dh_size..2 * dh_size]));
+        total_size += dh_size;
+    }
+    let result = result.and_then(|()| extract_and_expand(kem, &dh[..total_size],
+        &kem_context[..kem_context_size], shared_secret, wolfcrypt));
+    dh.zeroize();
+    result
+}
+
+fn extract_and_expand(kem: Kem, dh: &[u8], kem_context: &[u8],
+        shared_secret: &mut [u8], wolfcrypt: &WolfCrypt) -> Result<(), Error> {
+    let kdf = kem.kdf();
+    let suite_id = kem.suite_id();
+    let mut eae_prk = [0u8; MAX_HASH_SIZE];
+    let result = labeled_extract(kdf, &suite_id, &[], b"eae_prk", dh,
+            &mut eae_prk[..kdf.hash_size()], wolfcrypt)
+        .and_then(|()| labeled_expand(kdf, &suite_id, &eae_prk[..kdf.hash_size()],
+            b"shared_secret", kem_context, shared_secret, wolfcrypt));
+    eae_prk.zeroize();
+    result
+}
+
+fn dh_exchange(sk: &PrivateKey, pk: &PublicKey, out: &mut [u8]) -> Result<(), Error> {
+    let sk_bytes = sk.as_bytes();
+    let pk_bytes = pk.as_bytes();
+    let wolfcrypt = &sk.public_key.wolfcrypt;
+    match sk.kem {
+        Kem::DhKemP256HkdfSha256 | Kem::DhKemP384HkdfSha384 | Kem::DhKemP521HkdfSha512 => {
+            ecc_dh(sk.kem, sk_bytes, pk_bytes, out, wolfcrypt)?
+        }
+        Kem::DhKemX25519HkdfSha256 => x25519_dh(sk_bytes, pk_bytes, out, wolfcrypt)?,
+        Kem::DhKemX448HkdfSha512 => x448_dh(sk_bytes, pk_bytes, out, wolfcrypt)?,
+        _ => return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG)),
+    }
+    // Reject the all-zero output of a small order peer public key.
+    if out.iter().fold(0u8, |acc, b| acc | b) == 0 {
+        return Err(Error::from(sys::wolfCrypt_ErrorCodes_ECC_OUT_OF_RANGE_E));
+    }
+    Ok(())
+}
+
+fn check_public_key(kem: Kem, pk: &[u8], wolfcrypt: &WolfCrypt) -> Result<(), Error> {
+    match kem {
+        Kem::DhKemP256HkdfSha256 | Kem::DhKemP384HkdfSha384 | Kem::DhKemP521HkdfSha512 => {
+            ecc_check_public_key(kem, pk, wolfcrypt)
+        }
+        Kem::DhKemX25519HkdfSha256 => x25519_check_public_key(pk),
+        Kem::DhKemX448HkdfSha512 => x448_check_public_key(pk),
+        Kem::MlKem512 | Kem::MlKem768 | Kem::MlKem1024 => {
+            mlkem_check_public_key(kem, pk, wolfcrypt)
+        }
+    }
+}
+
+/* DHKEM(P-256, P-384, P-521) */
+
+#[cfg(all(ecc, ecc_dh, ecc_import, ecc_export))]
+fn ecc_curve_id(kem: Kem) -> i32 {
+    match kem {
+        Kem::DhKemP384HkdfSha384 => ECC::SECP384R1,
+        Kem::DhKemP521HkdfSha512 => ECC::SECP521R1,
+        _ => ECC::SECP256R1,
+    }
+}
+
+#[cfg(all(ecc, ecc_dh, ecc_import, ecc_export))]
+fn ecc_public_key(kem: Kem, sk: &[u8], pk: &mut [u8], wolfcrypt: &WolfCrypt)
+        -> Result<(), Error> {
+    let env = crate::Env::new(wolfcrypt);
+    let mut key = ECC::import_private_key_ex(sk, &[], ecc_curve_id(kem), &env)?;
+    // wolfCrypt rejects scalars outside of [1, n - 1] with ECC_PRIV_KEY_E.
+    let rng = RNG::new(wolfcrypt)?;
+    key.make_pub(Some(&rng))?;
+    let pk_size = key.export_x963(pk)?;
+    if pk_size != pk.len() {
+        return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+    }
+    Ok(())
+}
+
+#[cfg(all(ecc, ecc_dh, ecc_import, ecc_export))]
+fn ecc_check_public_key(kem: Kem, pk: &[u8], wolfcrypt: &WolfCrypt) -> Result<(), Error> {
+    let mut key = ECC::import_x963_ex(pk, ecc_curve_id(kem), &crate::Env::new(wolfcrypt))?;
+    key.check()
+}
+
+#[cfg(all(ecc, ecc_dh, ecc_import, ecc_export))]
+fn ecc_dh(kem: Kem, sk: &[u8], pk: &[u8], out: &mut [u8], wolfcrypt: &WolfCrypt)
+        -> Result<(), Error> {
+    let curve_id = ecc_curve_id(kem);
+    let env = crate::Env::new(wolfcrypt);
+    let mut key = ECC::import_private_key_ex(sk, &[], curve_id, &env)?;
+    key.set_rng(RNG::new(wolfcrypt)?)?;
+    let mut peer = ECC::import_x963_ex(pk, curve_id, &env)?;
+    let out_size = key.shared_secret(&mut peer, out)?;
+    if out_size != out.len() {
+        return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+    }
+    Ok(())
+}
+
+#[cfg(not(all(ecc, ecc_dh, ecc_import, ecc_export)))]
+fn ecc_public_key(_kem: Kem, _sk: &[u8], _pk: &mut [u8], _wolfcrypt: &WolfCrypt)
+        -> Result<(), Error> {
+    Err(Error::from(sys::wolfCrypt_ErrorCodes_NOT_COMPILED_IN))
+}
+
+#[cfg(not(all(ecc, ecc_dh, ecc_import, ecc_export)))]
+fn ecc_check_public_key(_kem: Kem, _pk: &[u8], _wolfcrypt: &WolfCrypt) -> Result<(), Error> {
+    Err(Error::from(sys::wolfCrypt_ErrorCodes_NOT_COMPILED_IN))
+}
+
+#[cfg(not(all(ecc, ecc_dh, ecc_import, ecc_export)))]
+fn ecc_dh(_kem: Kem, _sk: &[u8], _pk: &[u8], _out: &mut [u8], _wolfcrypt: &WolfCrypt)
+        -> Result<(), Error> {
+    Err(Error::from(sys::wolfCrypt_ErrorCodes_NOT_COMPILED_IN))
+}
+
+/* DHKEM(X25519) */
+
+#[cfg(curve25519)]
+fn x25519_public_key(sk: &[u8], pk: &mut [u8]) -> Result<(), Error> {
+    Curve25519Key::make_pub(sk, pk)
+}
+
+#[cfg(curve25519)]
+fn x25519_check_public_key(pk: &[u8]) -> Result<(), Error> {
+    Curve25519Key::check_public(pk, false)
+}
+
+#[cfg(curve25519)]
+fn x25519_dh(sk: &[u8], pk: &[u8], out: &mut [u8], wolfcrypt: &WolfCrypt)
+        -> Result<(), Error> {
+    let mut private_key = Curve25519Key::import_private_ex(sk, false, wolfcrypt)?;
+    #[cfg(curve25519_blinding)]
+    private_key.set_rng(RNG::new(wolfcrypt)?)?;
+    let mut public_key = Curve25519Key::import_public_ex(pk, false, wolfcrypt)?;
+    let out_size = Curve25519Key::shared_secret_ex(&mut private_key, &mut public_key,
+        out, false)?;
+    if out_size != out.len() {
+        return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+    }
+    Ok(())
+}
+
+#[cfg(not(curve25519))]
+fn x25519_public_key(_sk: &[u8], _pk: &mut [u8]) -> Result<(), Error> {
+    Err(Error::from(sys::wolfCrypt_ErrorCodes_NOT_COMPILED_IN))
+}
+
+#[cfg(not(curve25519))]
+fn x25519_check_public_key(_pk: &[u8]) -> Result<(), Error> {
+    Err(Error::from(sys::wolfCrypt_ErrorCodes_NOT_COMPILED_IN))
+}
+
+#[cfg(not(curve25519))]
+fn x25519_dh(_sk: &[u8], _pk: &[u8], _out: &mut [u8], _wolfcrypt: &WolfCrypt)
+        -> Result<(), Error> {
+    Err(Error::from(sys::wolfCrypt_ErrorCodes_NOT_COMPILED_IN))
+}
+
+/* DHKEM(X448) */
+
+#[cfg(curve448)]
+fn x448_public_key(sk: &[u8], pk: &mut [u8]) -> Result<(), Error> {
+    X448::make_pub(sk, pk)
+}
+
+#[cfg(curve448)]
+fn x448_check_public_key(pk: &[u8]) -> Result<(), Error> {
+    X448::check_public(pk, false)
+}
+
+#[cfg(curve448)]
+fn x448_dh(sk: &[u8], pk: &[u8], out: &mut [u8], wolfcrypt: &WolfCrypt)
+        -> Result<(), Error> {
+    let mut private_key = X448::import_private_ex(sk, false, wolfcrypt)?;
+    let mut public_key = X448::import_public_ex(pk, false, wolfcrypt)?;
+    let out_size = X448::shared_secret_ex(&mut private_key, &mut public_key,
+        out, false)?;
+    if out_size != out.len() {
+        return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+    }
+    Ok(())
+}
+
+#[cfg(not(curve448))]
+fn x448_public_key(_sk: &[u8], _pk: &mut [u8]) -> Result<(), Error> {
+    Err(Error::from(sys::wolfCrypt_ErrorCodes_NOT_COMPILED_IN))
+}
+
+#[cfg(not(curve448))]
+fn x448_check_public_key(_pk: &[u8]) -> Result<(), Error> {
+    Err(Error::from(sys::wolfCrypt_ErrorCodes_NOT_COMPILED_IN))
+}
+
+#[cfg(not(curve448))]
+fn x448_dh(_sk: &[u8], _pk: &[u8], _out: &mut [u8], _wolfcrypt: &WolfCrypt)
+        -> Result<(), Error> {
+    Err(Error::from(sys::wolfCrypt_ErrorCodes_NOT_COMPILED_IN))
+}
+
+/* ML-KEM */
+
+#[cfg(mlkem)]
+fn mlkem_type(kem: Kem) -> i32 {
+    match kem {
+        Kem::MlKem512 => MlKem::TYPE_512,
+        Kem::MlKem768 => MlKem::TYPE_768,
+        _ => MlKem::TYPE_1024,
+    }
+}
+
+#[cfg(mlkem)]
+fn mlkem_public_key(kem: Kem, seed: &[u8], pk: &mut [u8], wolfcrypt: &WolfCrypt)
+        -> Result<(), Error> {
+    let key = MlKem::generate_with_random(mlkem_type(kem), seed, wolfcrypt)?;
+    key.encode_public_key(pk)
+}
+
+#[cfg(mlkem)]
+fn mlkem_check_public_key(kem: Kem, pk: &[u8], wolfcrypt: &WolfCrypt) -> Result<(), Error> {
+    let mut key = MlKem::new(mlkem_type(kem), wolfcrypt)?;
+    key.decode_public_key(pk)
+}
+
+#[cfg(mlkem)]
+fn mlkem_encap(pk_r: &PublicKey, ephemeral: Ephemeral, shared_secret: &mut [u8],
+        enc: &mut [u8]) -> Result<(), Error> {
+    let mut key = MlKem::new(mlkem_type(pk_r.kem), &pk_r.wolfcrypt)?;
+    key.decode_public_key(pk_r.as_bytes())?;
+    match ephemeral {
+        Ephemeral::Rng(rng) => key.encapsulate(enc, shared_secret, rng),
+        Ephemeral::Ikm(rand) => key.encapsulate_with_random(enc, shared_secret, rand),
+    }
+}
+
+#[cfg(mlkem)]
+fn mlkem_decap(enc: &[u8], sk_r: &PrivateKey, shared_secret: &mut [u8]) -> Result<(), Error> {
+    let mut key = MlKem::generate_with_random(mlkem_type(sk_r.kem), sk_r.as_bytes(),
+        &sk_r.public_key.wolfcrypt)?;
+    key.decapsulate(shared_secret, enc)
+}
+
+#[cfg(not(mlkem))]
+fn mlkem_public_key(_kem: Kem, _seed: &[u8], _pk: &mut [u8], _wolfcrypt: &WolfCrypt)
+        -> Result<(), Error> {
+    Err(Error::from(sys::wolfCrypt_ErrorCodes_NOT_COMPILED_IN))
+}
+
+#[cfg(not(mlkem))]
+fn mlkem_check_public_key(_kem: Kem, _pk: &[u8], _wolfcrypt: &WolfCrypt)
+        -> Result<(), Error> {
+    Err(Error::from(sys::wolfCrypt_ErrorCodes_NOT_COMPILED_IN))
+}
+
+#[cfg(not(mlkem))]
+fn mlkem_encap(_pk_r: &PublicKey, _ephemeral: Ephemeral, _shared_secret: &mut [u8],
+        _enc: &mut [u8]) -> Result<(), Error> {
+    Err(Error::from(sys::wolfCrypt_ErrorCodes_NOT_COMPILED_IN))
+}
+
+#[cfg(not(mlkem))]
+fn mlkem_decap(_enc: &[u8], _sk_r: &PrivateKey, _shared_secret: &mut [u8]) -> Result<(), Error> {
+    Err(Error::from(sys::wolfCrypt_ErrorCodes_NOT_COMPILED_IN))
+}
+
+/* AEADs */
+
+#[cfg(aes_gcm)]
+fn aes_gcm_seal(key: &[u8], nonce: &[u8], aad: &[u8], pt: &[u8], ct: &mut [u8],
+        tag: &mut [u8], wolfcrypt: &WolfCrypt) -> Result<(), Error> {
+    let mut gcm = crate::aes::GCM::new(wolfcrypt)?;
+    gcm.init(key)?;
+    gcm.encrypt(pt, ct, nonce, aad, tag)
+}
+
+#[cfg(aes_gcm)]
+fn aes_gcm_open(key: &[u8], nonce: &[u8], aad: &[u8], ct: &[u8], tag: &[u8],
+        pt: &mut [u8], wolfcrypt: &WolfCrypt) -> Result<(), Error> {
+    let mut gcm = crate::aes::GCM::new(wolfcrypt)?;
+    gcm.init(key)?;
+    gcm.decrypt(ct, pt, nonce, aad, tag)
+}
+
+#[cfg(not(aes_gcm))]
+fn aes_gcm_seal(_key: &[u8], _nonce: &[u8], _aad: &[u8], _pt: &[u8], _ct: &mut [u8],
+        _tag: &mut [u8], _wolfcrypt: &WolfCrypt) -> Result<(), Error> {
+    Err(Error::from(sys::wolfCrypt_ErrorCodes_NOT_COMPILED_IN))
+}
+
+#[cfg(not(aes_gcm))]
+fn aes_gcm_open(_key: &[u8], _nonce: &[u8], _aad: &[u8], _ct: &[u8], _tag: &[u8],
+        _pt: &mut [u8], _wolfcrypt: &WolfCrypt) -> Result<(), Error> {
+    Err(Error::from(sys::wolfCrypt_ErrorCodes_NOT_COMPILED_IN))
+}
+
+#[cfg(chacha20_poly1305)]
+fn chacha20_poly1305_seal(key: &[u8], nonce: &[u8], aad: &[u8], pt: &[u8],
+        ct: &mut [u8], tag: &mut [u8]) -> Result<(), Error> {
+    crate::chacha20_poly1305::ChaCha20Poly1305::encrypt(key, nonce, aad, pt, ct, tag)
+}
+
+#[cfg(chacha20_poly1305)]
+fn chacha20_poly1305_open(key: &[u8], nonce: &[u8], aad: &[u8], ct: &[u8],
+        tag: &[u8], pt: &mut [u8]) -> Result<(), Error> {
+    crate::chacha20_poly1305::ChaCha20Poly1305::decrypt(key, nonce, aad, ct, tag, pt)
+}
+
+#[cfg(not(chacha20_poly1305))]
+fn chacha20_poly1305_seal(_key: &[u8], _nonce: &[u8], _aad: &[u8], _pt: &[u8],
+        _ct: &mut [u8], _tag: &mut [u8]) -> Result<(), Error> {
+    Err(Error::from(sys::wolfCrypt_ErrorCodes_NOT_COMPILED_IN))
+}
+
+#[cfg(not(chacha20_poly1305))]
+fn chacha20_poly1305_open(_key: &[u8], _nonce: &[u8], _aad: &[u8], _ct: &[u8],
+        _tag: &[u8], _pt: &mut [u8]) -> Result<(), Error> {
+    Err(Error::from(sys::wolfCrypt_ErrorCodes_NOT_COMPILED_IN))
+}
@@ -0,0 +1,674 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+This module provides hybrid post-quantum/traditional key encapsulation
+mechanisms combining X25519 with ML-KEM-768, built from the
+[`crate::curve25519`], [`crate::mlkem`] and [`crate::sha`] wrappers.
+
+Two combiners are provided:
+
+| Type                  | Combiner                                            | Shared secret |
+|-----------------------|-----------------------------------------------------|---------------|
+| [`XWing`]             | X-Wing (draft-connolly-cfrg-xwing-kem): SHA3-256    | 32 bytes      |
+| [`X25519MlKem768`]    | TLS `X25519MLKEM768` (draft-ietf-tls-ecdhe-mlkem): concatenation | 64 bytes |
+
+Both use a 1216 byte public key (the ML-KEM-768 encapsulation key followed by
+the X25519 public key) and a 1120 byte cipher text (the ML-KEM-768 cipher text
+followed by the ephemeral X25519 public key). The private key of both types
+is a seed from which the component keys are derived, so the seed is all that
+needs to be stored:
+
+* [`XWing`] expands its 32 byte seed with SHAKE256 as specified by X-Wing.
+* [`X25519MlKem768`] uses a 96 byte seed: the 64 byte ML-KEM key generation
+  randomness `d || z` followed by the 32 byte X25519 private key.
+
+Deterministic key generation (`generate_from_seed()`) and encapsulation
+(`encapsulate_with_random()`) are provided for test vectors.
+
+# Examples
+
+```rust
+#[cfg(all(curve25519, mlkem, sha3, shake256, random))]
+{
+use wolfssl_wolfcrypt::hybrid::XWing;
+use wolfssl_wolfcrypt::random::RNG;
+use wolfssl_wolfcrypt::WolfCrypt;
+let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+let rng = RNG::new(&wolfcrypt).expect("RNG creation failed");
+let mut alice = XWing::generate(&rng, &wolfcrypt).expect("Key generation failed");
+let mut pk = [0u8; XWing::PUBLIC_KEY_SIZE];
+alice.encode_public_key(&mut pk).expect("encode_public_key failed");
+
+let mut bob = XWing::from_public_key(&pk, &wolfcrypt).expect("from_public_key failed");
+let mut ct = [0u8; XWing::CIPHER_TEXT_SIZE];
+let mut ss_bob = [0u8; XWing::SHARED_SECRET_SIZE];
+bob.encapsulate(&mut ct, &mut ss_bob, &rng).expect("Encapsulation failed");
+
+let mut ss_alice = [0u8; XWing::SHARED_SECRET_SIZE];
+alice.decapsulate(&mut ss_alice, &ct).expect("Decapsulation failed");
+assert_eq!(ss_alice, ss_bob);
+}
+```
+*/
+
+#![cfg(all(curve25519, mlkem))]
+
+use crate::curve25519::Curve25519Key;
+use crate::mlkem::MlKem;
+#[cfg(random)]
+use crate::random::RNG;
+#[cfg(all(sha3, shake256))]
+use crate::sha::{SHA3_256, SHAKE256};
+use crate::{Error, WolfCrypt};
+use crate::sys;
+use zeroize::Zeroize;
+
+/// ML-KEM-768 encapsulation key size in bytes.
+const MLKEM768_PUBLIC_KEY_SIZE: usize = 1184;
+/// ML-KEM-768 cipher text size in bytes.
+const MLKEM768_CIPHER_TEXT_SIZE: usize = 1088;
+/// X25519 key and shared secret size in bytes.
+const X25519_SIZE: usize = Curve25519Key::KEYSIZE;
+/// X25519 base point (u = 9).
+const X25519_BASE: [u8; X25519_SIZE] = {
+    let mut base = [0u8; X25519_SIZE];
+    base[0] = 9;
+    base
+};
+
+/// Compute X25519(scalar, point) as specified in RFC 7748.
+///
+/// wolfCrypt expects a clamped scalar, so the scalar is clamped here, and
+/// the unused top bit of the peer's u-coordinate is masked.
+fn x25519(scalar: &[u8; X25519_SIZE], point: &[u8; X25519_SIZE])
+        -> Result<[u8; X25519_SIZE], Error> {
+    let mut k = *scalar;
+    k[0] &= 248;
+    k[X25519_SIZE - 1] &= 127;
+    k[X25519_SIZE - 1] |= 64;
+    let mut u = *point;
+    u[X25519_SIZE - 1] &= 0x7f;
+    let mut out = [0u8; X25519_SIZE];
+    let rc = Curve25519Key::make_pub_generic(&k, &mut out, &u);
+    k.zeroize();
+    rc?;
+    Ok(out)
+}
+
+/// The ML-KEM-768 and X25519 component keys shared by both combiners.
+struct Components {
+    mlkem: MlKem<'static>,
+    /// ML-KEM-768 encapsulation key followed by the X25519 public key.
+    pk: [u8; MLKEM768_PUBLIC_KEY_SIZE + X25519_SIZE],
+    /// X25519 private key, present for decapsulation keys.
+    sk_x: Option<[u8; X25519_SIZE]>,
+    wolfcrypt: WolfCrypt,
+}
+
+impl Components {
+    /// Derive the component key pairs from ML-KEM key generation randomness
+    /// and an X25519 private key.
+    fn from_parts(mlkem_rand: &[u8], sk_x: &[u8; X25519_SIZE], wolfcrypt: &WolfCrypt)
+            -> Result<Self, Error> {
+        let mlkem = MlKem::generate_with_random(MlKem::TYPE_768, mlkem_rand, wolfcrypt)?;
+        let mut pk = [0u8; MLKEM768_PUBLIC_KEY_SIZE + X25519_SIZE];
+        mlkem.encode_public_key(&mut pk[..MLKEM768_PUBLIC_KEY_SIZE])?;
+        let pk_x = x25519(sk_x, &X25519_BASE)?;
+        pk[MLKEM768_PUBLIC_KEY_SIZE..].copy_from_slice(&pk_x);
+        Ok(Components { mlkem, pk, sk_x: Some(*sk_x), wolfcrypt: wolfcrypt.clone() })
+    }
+
+    /// Load an encapsulation-only key from an encoded public key.
+    fn from_public_key(public: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        if public.len() != MLKEM768_PUBLIC_KEY_SIZE + X25519_SIZE {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        let mut mlkem = MlKem::new(MlKem::TYPE_768, wolfcrypt)?;
+        mlkem.decode_public_key(&public[..MLKEM768_PUBLIC_KEY_SIZE])?;
+        let mut pk = [0u8; MLKEM768_PUBLIC_KEY_SIZE + X25519_SIZE];
+        pk.copy_from_slice(public);
+        Ok(Components { mlkem, pk, sk_x: None, wolfcrypt: wolfcrypt.clone() })
+    }
+
+    fn pk_x(&self) -> &[u8; X25519_SIZE] {
+        self.pk[MLKEM768_PUBLIC_KEY_SIZE..].try_into().unwrap()
+    }
+
+    /// Encapsulate to both components, writing `ct_M || ct_X` to `ct` and
+    /// returning `(ss_M, ss_X)`.
+    ///
+    /// `rand` is the 32 byte ML-KEM encapsulation randomness followed by the
+    /// 32 byte ephemeral X25519 private key.
+    fn encapsulate(&mut self, ct: &mut [u8], rand: &[u8])
+            -> Result<([u8; MlKem::SHARED_SECRET_SIZE], [u8; X25519_SIZE]), Error> {
+        if ct.len() != MLKEM768_CIPHER_TEXT_SIZE + X25519_SIZE ||
+                rand.len() != MlKem::ENC_RAND_SIZE + X25519_SIZE {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        let (rand_m, ek_x) = rand.split_at(MlKem::ENC_RAND_SIZE);
+        let ek_x: &[u8; X25519_SIZE] = ek_x.try_into().unwrap();
+        let (ct_m, ct_x) = ct.split_at_mut(MLKEM768_CIPHER_TEXT_SIZE);
+        let mut ss_m = [0u8; MlKem::SHARED_SECRET_SIZE];
+        self.mlkem.encapsulate_with_random(ct_m, &mut ss_m, rand_m)?;
+        ct_x.copy_from_slice(&x25519(ek_x, &X25519_BASE)?);
+        let ss_x = x25519(ek_x, self.pk_x())?;
+        Ok((ss_m, ss_x))
+    }
+
+    /// Decapsulate both components of `ct_M || ct_X`, returning
+    /// `(ss_M, ss_X)`.
+    fn decapsulate(&mut self, ct: &[u8])
+            -> Result<([u8; MlKem::SHARED_SECRET_SIZE], [u8; X25519_SIZE]), Error> {
+        let sk_x = match &self.sk_x {
+            Some(sk_x) => sk_x,
+            None => return Err(Error::from(sys::wolfCrypt_ErrorCodes_MISSING_KEY)),
+        };
+        if ct.len() != MLKEM768_CIPHER_TEXT_SIZE + X25519_SIZE {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        let (ct_m, ct_x) = ct.split_at(MLKEM768_CIPHER_TEXT_SIZE);
+        let mut ss_m = [0u8; MlKem::SHARED_SECRET_SIZE];
+        self.mlkem.decapsulate(&mut ss_m, ct_m)?;
+        let ss_x = x25519(sk_x, ct_x.try_into().unwrap())?;
+        Ok((ss_m, ss_x))
+    }
+}
+
+impl Drop for Components {
+    fn drop(&mut self) {
+        if let Some(sk_x) = self.sk_x.as_mut() {
+            sk_x.zeroize();
+        }
+    }
+}
+
+/// X-Wing hybrid KEM (X25519 + ML-KEM-768 with a SHA3-256 combiner).
+///
+/// The shared secret is
+/// `SHA3-256(ss_M || ss_X || ct_X || pk_X || "\.//^\")`.
+///
+/// An instance is created with [`XWing::generate()`] or
+/// [`XWing::generate_from_seed()`] (decapsulation key), or with
+/// [`XWing::from_public_key()`] (encapsulation key).
+#[cfg(all(sha3, shake256))]
+pub struct XWing {
+    keys: Components,
+    seed: Option<[u8; XWing::SEED_SIZE]>,
+}
+
+#[cfg(all(sha3, shake256))]
+impl XWing {
+    /// Private key (seed) size in bytes.
+    pub const SEED_SIZE: usize = 32;
+    /// Public key size in bytes (`pk_M || pk_X`).
+    pub const PUBLIC_KEY_SIZE: usize = MLKEM768_PUBLIC_KEY_SIZE + X25519_SIZE;
+    /// Cipher text size in bytes (`ct_M || ct_X`).
+    pub const CIPHER_TEXT_SIZE: usize = MLKEM768_CIPHER_TEXT_SIZE + X25519_SIZE;
+    /// Shared secret size in bytes.
+    pub const SHARED_SECRET_SIZE: usize = SHA3_256::DIGEST_SIZE;
+    /// Random bytes required for deterministic encapsulation.
+    pub const ENC_RAND_SIZE: usize = MlKem::ENC_RAND_SIZE + X25519_SIZE;
+
+    /// X-Wing combiner label.
+    const LABEL: &'static [u8] = b"\\.//^\\";
+
+    /// Generate a new X-Wing key pair from a random seed.
+    ///
+    /// # Parameters
+    ///
+    /// * `rng`: `RNG` instance to use for random number generation.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(XWing) containing the key instance or Err(e)
+    /// containing the wolfSSL library error code value.
+    #[cfg(random)]
+    pub fn generate(rng: &RNG, wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        let mut seed = [0u8; Self::SEED_SIZE];
+        rng.generate_block(&mut seed)?;
+        let key = Self::generate_from_seed(&seed, wolfcrypt);
+        seed.zeroize();
+        key
+    }
+
+    /// Derive an X-Wing key pair from a seed (the X-Wing private key).
+    ///
+    /// The seed is expanded with SHAKE256 into the ML-KEM-768 key generation
+    /// randomness and the X25519 private key.
+    ///
+    /// # Parameters
+    ///
+    /// * `seed`: Private key seed. Must be `SEED_SIZE` (32) bytes.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(XWing) containing the key instance or Err(e)
+    /// containing the wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(all(curve25519, mlkem, sha3, shake256))]
+    /// {
+    /// use wolfssl_wolfcrypt::hybrid::XWing;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let key = XWing::generate_from_seed(&[0x42u8; 32], &wolfcrypt)
+    ///     .expect("Error with generate_from_seed()");
+    /// }
+    /// ```
+    pub fn generate_from_seed(seed: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        let seed: [u8; Self::SEED_SIZE] = seed.try_into()
+            .map_err(|_| sys::wolfCrypt_ErrorCodes_BUFFER_E)?;
+        let mut expanded = [0u8; MlKem::MAKEKEY_RAND_SIZE + X25519_SIZE];
+        let mut shake = SHAKE256::new(wolfcrypt)?;
+        shake.update(&seed)?;
+        shake.finalize(&mut expanded)?;
+        let (mlkem_rand, sk_x) = expanded.split_at(MlKem::MAKEKEY_RAND_SIZE);
+        let keys = Components::from_parts(mlkem_rand, sk_x.try_into().unwrap(), wolfcrypt);
+        expanded.zeroize();
+        Ok(XWing { keys: keys?, seed: Some(seed) })
+    }
+
+    /// Load an X-Wing encapsulation key from an encoded public key.
+    ///
+    /// # Parameters
+    ///
+    /// * `public`: Public key. Must be `PUBLIC_KEY_SIZE` (1216) bytes.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(XWing) containing the key instance or Err(e)
+    /// containing the wolfSSL library error code value.
+    pub fn from_public_key(public: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        let keys = Components::from_public_key(public, wolfcrypt)?;
+        Ok(XWing { keys, seed: None })
+    }
+
+    /// Encode (export) the public key.
+    ///
+    /// # Parameters
+    ///
+    /// * `out`: Output buffer. Must be `PUBLIC_KEY_SIZE` (1216) bytes.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn encode_public_key(&self, out: &mut [u8]) -> Result<(), Error> {
+        if out.len() != Self::PUBLIC_KEY_SIZE {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        out.copy_from_slice(&self.keys.pk);
+        Ok(())
+    }
+
+    /// Export the private key seed.
+    ///
+    /// # Parameters
+    ///
+    /// * `out`: Output buffer. Must be `SEED_SIZE` (32) bytes.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value. `MISSING_KEY` is returned for an
+    /// encapsulation-only key.
+    pub fn export_seed(&self, out: &mut [u8]) -> Result<(), Error> {
+        let seed = self.seed.as_ref().ok_or(sys::wolfCrypt_ErrorCodes_MISSING_KEY)?;
+        if out.len() != Self::SEED_SIZE {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        out.copy_from_slice(seed);
+        Ok(())
+    }
+
+    fn combine(ss_m: &[u8], ss_x: &[u8], ct_x: &[u8], pk_x: &[u8], ss: &mut [u8],
+            wolfcrypt: &WolfCrypt) -> Result<(), Error> {
+        let mut sha3 = SHA3_256::new(wolfcrypt)?;
+        sha3.update(ss_m)?;
+        sha3.update(ss_x)?;
+        sha3.update(ct_x)?;
+        sha3.update(pk_x)?;
+        sha3.update(Self::LABEL)?;
+        sha3.finalize(ss)
+    }
+
+    /// Encapsulate: generate a shared secret and cipher text using this
+    /// public key and an RNG.
+    ///
+    /// # Parameters
+    ///
+    /// * `ct`: Output buffer for the cipher text. Must be `CIPHER_TEXT_SIZE`
+    ///   (1120) bytes.
+    /// * `ss`: Output buffer for the shared secret. Must be
+    ///   `SHARED_SECRET_SIZE` (32) bytes.
+    /// * `rng`: `RNG` instance for random number generation.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    #[cfg(random)]
+    pub fn encapsulate(&mut self, ct: &mut [u8], ss: &mut [u8], rng: &RNG) -> Result<(), Error> {
+        let mut rand = [0u8; Self::ENC_RAND_SIZE];
+        rng.generate_block(&mut rand)?;
+        let rc = self.encapsulate_with_random(ct, ss, &rand);
+        rand.zeroize();
+        rc
+    }
+
+    /// Encapsulate using caller-supplied random bytes instead of an RNG
+    /// (X-Wing `EncapsulateDerand`).
+    ///
+    /// # Parameters
+    ///
+    /// * `ct`: Output buffer for the cipher text. Must be `CIPHER_TEXT_SIZE`
+    ///   (1120) bytes.
+    /// * `ss`: Output buffer for the shared secret. Must be
+    ///   `SHARED_SECRET_SIZE` (32) bytes.
+    /// * `rand`: `ENC_RAND_SIZE` (64) bytes: the ML-KEM-768 encapsulation
+    ///   randomness followed by the ephemeral X25519 private key.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn encapsulate_with_random(&mut self, ct: &mut [u8], ss: &mut [u8], rand: &[u8])
+            -> Result<(), Error> {
+        if ss.len() != Self::SHARED_SECRET_SIZE {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        let (mut ss_m, mut ss_x) = self.keys.encapsulate(ct, rand)?;
+        let rc = Self::combine(&ss_m, &ss_x, &ct[MLKEM768_CIPHER_TEXT_SIZE..],
+            self.keys.pk_x(), ss, &self.keys.wolfcrypt);
+        ss_m.zeroize();
+        ss_x.zeroize();
+        rc
+    }
+
+    /// Decapsulate: recover the shared secret from a cipher text using this
+    /// private key.
+    ///
+    /// # Parameters
+    ///
+    /// * `ss`: Output buffer for the shared secret. Must be
+    ///   `SHARED_SECRET_SIZE` (32) bytes.
+    /// * `ct`: Cipher text. Must be `CIPHER_TEXT_SIZE` (1120) bytes.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn decapsulate(&mut self, ss: &mut [u8], ct: &[u8]) -> Result<(), Error> {
+        if ss.len() != Self::SHARED_SECRET_SIZE {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        let (mut ss_m, mut ss_x) = self.keys.decapsulate(ct)?;
+        let rc = Self::combine(&ss_m, &ss_x, &ct[MLKEM768_CIPHER_TEXT_SIZE..],
+            self.keys.pk_x(), ss, &self.keys.wolfcrypt);
+        ss_m.zeroize();
+        ss_x.zeroize();
+        rc
+    }
+}
+
+#[cfg(all(sha3, shake256))]
+impl Drop for XWing {
+    fn drop(&mut self) {
+        if let Some(seed) = self.seed.as_mut() {
+            seed.zeroize();
+        }
+    }
+}
+
+/// TLS `X25519MLKEM768` hybrid KEM (X25519 + ML-KEM-768 with the
+/// concatenation combiner).
+///
+/// The shared secret is `ss_M || ss_X`, as used as the TLS 1.3 (EC)DHE
+/// shared secret. An all-zero X25519 shared secret is rejected with
+/// `ECC_OUT_OF_RANGE_E`.
+///
+/// An instance is created with [`X25519MlKem768::generate()`] or
+/// [`X25519MlKem768::generate_from_seed()`] (decapsulation key), or with
+/// [`X25519MlKem768::from_public_key()`] (encapsulation key).
+pub struct X25519MlKem768 {
+    keys: Components,
+    seed: Option<[u8; X25519MlKem768::SEED_SIZE]>,
+}
+
+impl X25519MlKem768 {
+    /// Private key (seed) size in bytes: ML-KEM-768 key generation
+    /// randomness followed by the X25519 private key.
+    pub const SEED_SIZE: usize = MlKem::MAKEKEY_RAND_SIZE + X25519_SIZE;
+    /// Public key (client key share) size in bytes (`pk_M || pk_X`).
+    pub const PUBLIC_KEY_SIZE: usize = MLKEM768_PUBLIC_KEY_SIZE + X25519_SIZE;
+    /// Cipher text (server key share) size in bytes (`ct_M || ct_X`).
+    pub const CIPHER_TEXT_SIZE: usize = MLKEM768_CIPHER_TEXT_SIZE + X25519_SIZE;
+    /// Shared secret size in bytes (`ss_M || ss_X`).
+    pub const SHARED_SECRET_SIZE: usize = MlKem::SHARED_SECRET_SIZE + X25519_SIZE;
+    /// Random bytes required for deterministic encapsulation.
+    pub const ENC_RAND_SIZE: usize = MlKem::ENC_RAND_SIZE + X25519_SIZE;
+
+    /// Generate a new X25519MLKEM768 key pair from a random seed.
+    ///
+    /// # Parameters
+    ///
+    /// * `rng`: `RNG` instance to use for random number generation.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(X25519MlKem768) containing the key instance or
+    /// Err(e) containing the wolfSSL library error code value.
+    #[cfg(random)]
+    pub fn generate(rng: &RNG, wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        let mut seed = [0u8; Self::SEED_SIZE];
+        rng.generate_block(&mut seed)?;
+        let key = Self::generate_from_seed(&seed, wolfcrypt);
+        seed.zeroize();
+        key
+    }
+
+    /// Derive an X25519MLKEM768 key pair from a seed.
+    ///
+    /// # Parameters
+    ///
+    /// * `seed`: `SEED_SIZE` (96) bytes: the ML-KEM-768 key generation
+    ///   randomness `d || z` followed by the X25519 private key.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(X25519MlKem768) containing the key instance or
+    /// Err(e) containing the wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(all(curve25519, mlkem))]
+    /// {
+    /// use wolfssl_wolfcrypt::hybrid::X25519MlKem768;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let key = X25519MlKem768::generate_from_seed(&[0x42u8; 96], &wolfcrypt)
+    ///     .expect("Error with generate_from_seed()");
+    /// }
+    /// ```
+    pub fn generate_from_seed(seed: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        let seed: [u8; Self::SEED_SIZE] = seed.try_into()
+            .map_err(|_| sys::wolfCrypt_ErrorCodes_BUFFER_E)?;
+        let (mlkem_rand, sk_x) = seed.split_at(MlKem::MAKEKEY_RAND_SIZE);
+        let keys = Components::from_parts(mlkem_rand, sk_x.try_into().unwrap(), wolfcrypt)?;
+        Ok(X25519MlKem768 { keys, seed: Some(seed) })
+    }
+
+    /// Load an X25519MLKEM768 encapsulation key from an encoded public key
+    /// (client key share).
+    ///
+    /// # Parameters
+    ///
+    /// * `public`: Public key. Must be `PUBLIC_KEY_SIZE` (1216) bytes.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(X25519MlKem768) containing the key instance or
+    /// Err(e) containing the wolfSSL library error code value.
+    pub fn from_public_key(public: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        let keys = Components::from_public_key(public, wolfcrypt)?;
+        Ok(X25519MlKem768 { keys, seed: None })
+    }
+
+    /// Encode (export) the public key (client key share).
+    ///
+    /// # Parameters
+    ///
+    /// * `out`: Output buffer. Must be `PUBLIC_KEY_SIZE` (1216) bytes.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn encode_public_key(&self, out: &mut [u8]) -> Result<(), Error> {
+        if out.len() != Self::PUBLIC_KEY_SIZE {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        out.copy_from_slice(&self.keys.pk);
+        Ok(())
+    }
+
+    /// Export the private key seed.
+    ///
+    /// # Parameters
+    ///
+    /// * `out`: Output buffer. Must be `SEED_SIZE` (96) bytes.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value. `MISSING_KEY` is returned for an
+    /// encapsulation-only key.
+    pub fn export_seed(&self, out: &mut [u8]) -> Result<(), Error> {
+        let seed = self.seed.as_ref().ok_or(sys::wolfCrypt_ErrorCodes_MISSING_KEY)?;
+        if out.len() != Self::SEED_SIZE {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        out.copy_from_slice(seed);
+        Ok(())
+    }
+
+    fn combine(ss_m: &[u8], ss_x: &[u8], ss: &mut [u8]) -> Result<(), Error> {
+        if ss_x.iter().fold(0u8, |acc, b| acc | b) == 0 {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_ECC_OUT_OF_RANGE_E));
+        }
+        let (out_m, out_x) = ss.split_at_mut(MlKem::SHARED_SECRET_SIZE);
+        out_m.copy_from_slice(ss_m);
+        out_x.copy_from_slice(ss_x);
+        Ok(())
+    }
+
+    /// Encapsulate: generate a shared secret and cipher text (server key
+    /// share) using this public key and an RNG.
+    ///
+    /// # Parameters
+    ///
+    /// * `ct`: Output buffer for the cipher text. Must be `CIPHER_TEXT_SIZE`
+    ///   (1120) bytes.
+    /// * `ss`: Output buffer for the shared secret. Must be
+    ///   `SHARED_SECRET_SIZE` (64) bytes.
+    /// * `rng`: `RNG` instance for random number generation.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    #[cfg(random)]
+    pub fn encapsulate(&mut self, ct: &mut [u8], ss: &mut [u8], rng: &RNG) -> Result<(), Error> {
+        let mut rand = [0u8; Self::ENC_RAND_SIZE];
+        rng.generate_block(&mut rand)?;
+        let rc = self.encapsulate_with_random(ct, ss, &rand);
+        rand.zeroize();
+        rc
+    }
+
+    /// Encapsulate using caller-supplied random bytes instead of an RNG.
+    ///
+    /// # Parameters
+    ///
+    /// * `ct`: Output buffer for the cipher text. Must be `CIPHER_TEXT_SIZE`
+    ///   (1120) bytes.
+    /// * `ss`: Output buffer for the shared secret. Must be
+    ///   `SHARED_SECRET_SIZE` (64) bytes.
+    /// * `rand`: `ENC_RAND_SIZE` (64) bytes: the ML-KEM-768 encapsulation
+    ///   randomness followed by the ephemeral X25519 private key.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn encapsulate_with_random(&mut self, ct: &mut [u8], ss: &mut [u8], rand: &[u8])
+            -> Result<(), Error> {
+        if ss.len() != Self::SHARED_SECRET_SIZE {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        let (mut ss_m, mut ss_x) = self.keys.encapsulate(ct, rand)?;
+        let rc = Self::combine(&ss_m, &ss_x, ss);
+        ss_m.zeroize();
+        ss_x.zeroize();
+        rc
+    }
+
+    /// Decapsulate: recover the shared secret from a cipher text (server
+    /// key share) using this private key.
+    ///
+    /// # Parameters
+    ///
+    /// * `ss`: Output buffer for the shared secret. Must be
+    ///   `SHARED_SECRET_SIZE` (64) bytes.
+    /// * `ct`: Cipher text. Must be `CIPHER_TEXT_SIZE` (1120) bytes.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn decapsulate(&mut self, ss: &mut [u8], ct: &[u8]) -> Result<(), Error> {
+        if ss.len() != Self::SHARED_SECRET_SIZE {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        let (mut ss_m, mut ss_x) = self.keys.decapsulate(ct)?;
+        let rc = Self::combine(&ss_m, &ss_x, ss);
+        ss_m.zeroize();
+        ss_x.zeroize();
+        rc
+    }
+}
+
+impl Drop for X25519MlKem768 {
+    fn drop(&mut self) {
+        if let Some(seed) = self.seed.as_mut() {
+            seed.zeroize();
+        }
+    }
+}
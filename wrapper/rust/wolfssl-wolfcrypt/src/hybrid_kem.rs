@@ -0,0 +1,189 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+RustCrypto `kem` trait implementations for the X-Wing hybrid KEM.
+
+Provides the [`kem::Kem`] marker type [`XWing768`] with the associated
+[`XWing768EncapsulationKey`] and [`XWing768DecapsulationKey`] types, built on
+[`crate::hybrid::XWing`].
+
+The encapsulation key implements [`kem::Encapsulate`] (with
+[`kem::TryKeyInit`] and [`kem::KeyExport`] for key serialization). The
+decapsulation key implements [`kem::Decapsulate`] and [`kem::Generate`] (for
+key generation from a [`rand_core::CryptoRng`]) and holds only the 32 byte
+X-Wing seed.
+
+The TLS `X25519MLKEM768` combiner is not exposed through these traits since
+its all-zero X25519 shared secret check cannot be reported by the infallible
+[`kem::Decapsulate`]; use [`crate::hybrid::X25519MlKem768`] directly.
+
+# Examples
+
+```rust
+#[cfg(all(curve25519, mlkem, sha3, shake256, random, feature = "kem", feature = "rand_core"))]
+{
+use kem::{Kem, Encapsulate, Decapsulate};
+use kem::Generate;
+use wolfssl_wolfcrypt::random::RNG;
+use wolfssl_wolfcrypt::hybrid_kem::*;
+use wolfssl_wolfcrypt::WolfCrypt;
+let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+
+let mut rng = RNG::new(&wolfcrypt).expect("RNG creation failed");
+
+let (dk, ek) = XWing768::generate_keypair_from_rng(&mut rng);
+let (ct, k_send) = ek.encapsulate_with_rng(&mut rng);
+let k_recv = dk.decapsulate(&ct);
+assert_eq!(k_send, k_recv);
+}
+```
+*/
+
+#![cfg(all(feature = "kem", curve25519, mlkem, sha3, shake256))]
+
+use crate::hybrid::XWing;
+use kem::common::array::Array;
+use kem::common::typenum::U32;
+use hybrid_array::sizes::{U1120, U1216};
+
+/// X-Wing (X25519 + ML-KEM-768) marker implementing [`kem::Kem`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct XWing768;
+
+impl kem::Kem for XWing768 {
+    type DecapsulationKey = XWing768DecapsulationKey;
+    type EncapsulationKey = XWing768EncapsulationKey;
+    type SharedKeySize = U32;
+    type CiphertextSize = U1120;
+}
+
+/// X-Wing encapsulation (public) key implementing [`kem::Encapsulate`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct XWing768EncapsulationKey {
+    pk: Array<u8, U1216>,
+}
+
+impl kem::KeySizeUser for XWing768EncapsulationKey {
+    type KeySize = U1216;
+}
+
+impl kem::TryKeyInit for XWing768EncapsulationKey {
+    fn new(key: &kem::Key<Self>) -> Result<Self, kem::InvalidKey> {
+        let wolfcrypt = crate::WolfCrypt::global().map_err(|_| kem::InvalidKey)?;
+        XWing::from_public_key(key.as_ref(), wolfcrypt)
+            .map_err(|_| kem::InvalidKey)?;
+        Ok(Self { pk: key.clone() })
+    }
+}
+
+impl kem::KeyExport for XWing768EncapsulationKey {
+    fn to_bytes(&self) -> kem::Key<Self> {
+        self.pk.clone()
+    }
+}
+
+impl kem::Encapsulate for XWing768EncapsulationKey {
+    type Kem = XWing768;
+
+    fn encapsulate_with_rng<R: kem::common::rand_core::CryptoRng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> (kem::Ciphertext<XWing768>, kem::SharedKey<XWing768>) {
+        let mut rand = [0u8; XWing::ENC_RAND_SIZE];
+        rng.fill_bytes(&mut rand);
+
+        let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+        let mut key = XWing::from_public_key(self.pk.as_ref(), wolfcrypt)
+            .expect("XWing::from_public_key failed");
+
+        let mut ct = [0u8; XWing::CIPHER_TEXT_SIZE];
+        let mut ss = [0u8; XWing::SHARED_SECRET_SIZE];
+        key.encapsulate_with_random(&mut ct, &mut ss, &rand)
+            .expect("encapsulate_with_random failed");
+        zeroize::Zeroize::zeroize(&mut rand[..]);
+
+        (ct.into(), ss.into())
+    }
+}
+
+/// X-Wing decapsulation (private) key implementing [`kem::Decapsulate`].
+///
+/// The seed bytes are securely zeroized on drop.
+pub struct XWing768DecapsulationKey {
+    seed: Array<u8, U32>,
+    ek: XWing768EncapsulationKey,
+}
+
+impl kem::Decapsulator for XWing768DecapsulationKey {
+    type Kem = XWing768;
+
+    fn encapsulation_key(&self) -> &XWing768EncapsulationKey {
+        &self.ek
+    }
+}
+
+impl kem::Decapsulate for XWing768DecapsulationKey {
+    fn decapsulate(
+        &self,
+        ct: &kem::Ciphertext<XWing768>,
+    ) -> kem::SharedKey<XWing768> {
+        let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+        let mut key = XWing::generate_from_seed(self.seed.as_ref(), wolfcrypt)
+            .expect("XWing::generate_from_seed failed");
+
+        let mut ss = [0u8; XWing::SHARED_SECRET_SIZE];
+        key.decapsulate(&mut ss, ct.as_ref())
+            .expect("decapsulate failed");
+
+        ss.into()
+    }
+}
+
+impl kem::Generate for XWing768DecapsulationKey {
+    fn try_generate_from_rng<R: kem::common::rand_core::TryCryptoRng + ?Sized>(
+        rng: &mut R,
+    ) -> Result<Self, R::Error> {
+        let mut seed = [0u8; XWing::SEED_SIZE];
+        rng.try_fill_bytes(&mut seed)?;
+
+        let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+        let key = XWing::generate_from_seed(&seed, wolfcrypt)
+            .expect("XWing::generate_from_seed failed");
+        let mut pk = [0u8; XWing::PUBLIC_KEY_SIZE];
+        key.encode_public_key(&mut pk)
+            .expect("encode_public_key failed");
+
+        let dk = Self {
+            seed: seed.into(),
+            ek: XWing768EncapsulationKey { pk: pk.into() },
+        };
+        zeroize::Zeroize::zeroize(&mut seed[..]);
+        Ok(dk)
+    }
+}
+
+impl Drop for XWing768DecapsulationKey {
+    fn drop(&mut self) {
+        use zeroize::Zeroize;
+        let seed_bytes: &mut [u8] = self.seed.as_mut();
+        seed_bytes.zeroize();
+    }
+}
@@ -23,6 +23,7 @@ This module provides a Rust wrapper for the wolfCrypt library's Key Derivation
 Function (KDF) functionality.
 */
 
+use crate::Error;
 use crate::sys;
 #[cfg(all(hmac, kdf_tls13))]
 use crate::hmac::HMAC;
@@ -58,6 +59,7 @@ pub const SRTCP_INDEX_LEN: usize = sys::WC_SRTCP_INDEX_LEN as usize;
 /// * `salt`: Salt value to use for key derivation.
 /// * `iterations`: Number of times to process the hash.
 /// * `typ`: Hash type, one of `HMAC::TYPE_*`.
+/// * `wolfcrypt`: Guard of the initialized library.
 /// * `out`: Output buffer in which to store the generated key.
 ///
 /// # Returns
@@ -72,6 +74,8 @@ pub const SRTCP_INDEX_LEN: usize = sys::WC_SRTCP_INDEX_LEN as usize;
 /// {
 /// use wolfssl_wolfcrypt::kdf::pbkdf2;
 /// use wolfssl_wolfcrypt::hmac::HMAC;
+/// use wolfssl_wolfcrypt::WolfCrypt;
+/// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
 /// let password = b"passwordpassword";
 /// let salt = [0x78u8, 0x57, 0x8E, 0x5a, 0x5d, 0x63, 0xcb, 0x06];
 /// let iterations = 2048;
@@ -80,13 +84,13 @@ pub const SRTCP_INDEX_LEN: usize = sys::WC_SRTCP_INDEX_LEN as usize;
 ///     0x2d, 0xd4, 0xf9, 0x37, 0xd4, 0x95, 0x16, 0xa7, 0x2a, 0x9a, 0x21, 0xd1
 /// ];
 /// let mut keyout = [0u8; 24];
-/// pbkdf2(password, &salt, iterations, HMAC::TYPE_SHA256, &mut keyout).expect("Error with pbkdf2()");
+/// pbkdf2(password, &salt, iterations, HMAC::TYPE_SHA256, &wolfcrypt, &mut keyout).expect("Error with pbkdf2()");
 /// assert_eq!(keyout, expected_key);
 /// }
 /// ```
 #[cfg(kdf_pbkdf2)]
-pub fn pbkdf2(password: &[u8], salt: &[u8], iterations: i32, typ: i32, out: &mut [u8]) -> Result<(), i32> {
-    pbkdf2_ex(password, salt, iterations, typ, None, None, out)
+pub fn pbkdf2(password: &[u8], salt: &[u8], iterations: i32, typ: i32, wolfcrypt: &crate::WolfCrypt, out: &mut [u8]) -> Result<(), Error> {
+    pbkdf2_ex(password, salt, iterations, typ, &crate::Env::new(wolfcrypt), out)
 }
 
 /// Implement Password Based Key Derivation Function 2 (PBKDF2) converting an
@@ -100,8 +104,7 @@ pub fn pbkdf2(password: &[u8], salt: &[u8], iterations: i32, typ: i32, out: &mut
 /// * `salt`: Salt value to use for key derivation.
 /// * `iterations`: Number of times to process the hash.
 /// * `typ`: Hash type, one of `HMAC::TYPE_*`.
-/// * `heap`: Optional heap hint.
-/// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+/// * `env`: Heap hint and crypto callback device to use.
 /// * `out`: Output buffer in which to store the generated key.
 ///
 /// # Returns
@@ -114,8 +117,11 @@ pub fn pbkdf2(password: &[u8], salt: &[u8], iterations: i32, typ: i32, out: &mut
 /// ```rust
 /// #[cfg(all(hmac, kdf_pbkdf2))]
 /// {
+/// use wolfssl_wolfcrypt::Env;
 /// use wolfssl_wolfcrypt::kdf::pbkdf2_ex;
 /// use wolfssl_wolfcrypt::hmac::HMAC;
+/// use wolfssl_wolfcrypt::WolfCrypt;
+/// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
 /// let password = b"passwordpassword";
 /// let salt = [0x78u8, 0x57, 0x8E, 0x5a, 0x5d, 0x63, 0xcb, 0x06];
 /// let iterations = 2048;
@@ -124,29 +130,23 @@ pub fn pbkdf2(password: &[u8], salt: &[u8], iterations: i32, typ: i32, out: &mut
 ///     0x2d, 0xd4, 0xf9, 0x37, 0xd4, 0x95, 0x16, 0xa7, 0x2a, 0x9a, 0x21, 0xd1
 /// ];
 /// let mut keyout = [0u8; 24];
-/// pbkdf2_ex(password, &salt, iterations, HMAC::TYPE_SHA256, None, None, &mut keyout).expect("Error with pbkdf2_ex()");
+/// pbkdf2_ex(password, &salt, iterations, HMAC::TYPE_SHA256, &Env::new(&wolfcrypt), &mut keyout).expect("Error with pbkdf2_ex()");
 /// assert_eq!(keyout, expected_key);
 /// }
 /// ```
 #[cfg(kdf_pbkdf2)]
-pub fn pbkdf2_ex(password: &[u8], salt: &[u8], iterations: i32, typ: i32, heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>, out: &mut [u8]) -> Result<(), i32> {
+pub fn pbkdf2_ex(password: &[u8], salt: &[u8], iterations: i32, typ: i32, env: &crate::Env, out: &mut [u8]) -> Result<(), Error> {
     let password_size = crate::buffer_len_to_i32(password.len())?;
     let salt_size = crate::buffer_len_to_i32(salt.len())?;
     let out_size = crate::buffer_len_to_i32(out.len())?;
-    let heap = match heap {
-        Some(heap) => heap,
-        None => core::ptr::null_mut(),
-    };
-    let dev_id = match dev_id {
-        Some(dev_id) => dev_id,
-        None => sys::INVALID_DEVID,
-    };
+    let heap = env.heap_ptr();
+    let dev_id = env.dev_id();
     let rc = unsafe {
         sys::wc_PBKDF2_ex(out.as_mut_ptr(), password.as_ptr(), password_size,
             salt.as_ptr(), salt_size, iterations, out_size, typ, heap, dev_id)
     };
     if rc != 0 {
-        return Err(rc);
+        return Err(Error::from(rc));
     }
     Ok(())
 }
@@ -170,6 +170,7 @@ pub fn pbkdf2_ex(password: &[u8], salt: &[u8], iterations: i32, typ: i32, heap:
 ///   encryption or decryption. ID=2: pseudorandom bits are to be used an IV
 ///   (Initial Value) for encryption or decryption.  ID=3: pseudorandom bits
 ///   are to be used as an integrity key for MACing.
+/// * `wolfcrypt`: Guard of the initialized library.
 /// * `out`: Output buffer in which to store the generated key.
 ///
 /// # Returns
@@ -184,6 +185,8 @@ pub fn pbkdf2_ex(password: &[u8], salt: &[u8], iterations: i32, typ: i32, heap:
 /// {
 /// use wolfssl_wolfcrypt::kdf::pkcs12_pbkdf;
 /// use wolfssl_wolfcrypt::hmac::HMAC;
+/// use wolfssl_wolfcrypt::WolfCrypt;
+/// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
 /// let password = [0x00u8, 0x73, 0x00, 0x6d, 0x00, 0x65, 0x00, 0x67, 0x00, 0x00];
 /// let salt = [0x0au8, 0x58, 0xCF, 0x64, 0x53, 0x0d, 0x82, 0x3f];
 /// let expected_key = [
@@ -193,13 +196,13 @@ pub fn pbkdf2_ex(password: &[u8], salt: &[u8], iterations: i32, typ: i32, heap:
 /// ];
 /// let iterations = 1;
 /// let mut keyout = [0u8; 24];
-/// pkcs12_pbkdf(&password, &salt, iterations, HMAC::TYPE_SHA256, 1, &mut keyout).expect("Error with pkcs12_pbkdf()");
+/// pkcs12_pbkdf(&password, &salt, iterations, HMAC::TYPE_SHA256, 1, &wolfcrypt, &mut keyout).expect("Error with pkcs12_pbkdf()");
 /// assert_eq!(keyout, expected_key);
 /// }
 /// ```
 #[cfg(kdf_pkcs12)]
-pub fn pkcs12_pbkdf(password: &[u8], salt: &[u8], iterations: i32, typ: i32, id: i32, out: &mut [u8]) -> Result<(), i32> {
-    pkcs12_pbkdf_ex(password, salt, iterations, typ, id, None, out)
+pub fn pkcs12_pbkdf(password: &[u8], salt: &[u8], iterations: i32, typ: i32, id: i32, wolfcrypt: &crate::WolfCrypt, out: &mut [u8]) -> Result<(), Error> {
+    pkcs12_pbkdf_ex(password, salt, iterations, typ, id, &crate::Env::new(wolfcrypt), out)
 }
 
 /// This function implements the Password Based Key Derivation Function
@@ -208,7 +211,7 @@ pub fn pkcs12_pbkdf(password: &[u8], salt: &[u8], iterations: i32, typ: i32, id:
 /// in `out`. It allows the user to select any of the supported HMAC hash
 /// functions, including: WC_MD5, WC_SHA, WC_SHA256, WC_SHA384, WC_SHA512,
 /// WC_SHA3_224, WC_SHA3_256, WC_SHA3_384 or WC_SHA3_512.
-/// This version allows an optional heap hint parameter.
+/// This version takes the heap hint to use from `env`.
 ///
 /// # Parameters
 ///
@@ -222,7 +225,7 @@ pub fn pkcs12_pbkdf(password: &[u8], salt: &[u8], iterations: i32, typ: i32, id:
 ///   encryption or decryption. ID=2: pseudorandom bits are to be used an IV
 ///   (Initial Value) for encryption or decryption.  ID=3: pseudorandom bits
 ///   are to be used as an integrity key for MACing.
-/// * `heap`: Optional heap hint.
+/// * `env`: Heap hint to use. The device is not used.
 /// * `out`: Output buffer in which to store the generated key.
 ///
 /// # Returns
@@ -235,8 +238,11 @@ pub fn pkcs12_pbkdf(password: &[u8], salt: &[u8], iterations: i32, typ: i32, id:
 /// ```rust
 /// #[cfg(all(hmac, kdf_pkcs12))]
 /// {
+/// use wolfssl_wolfcrypt::Env;
 /// use wolfssl_wolfcrypt::kdf::pkcs12_pbkdf_ex;
 /// use wolfssl_wolfcrypt::hmac::HMAC;
+/// use wolfssl_wolfcrypt::WolfCrypt;
+/// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
 /// let password = [0x00u8, 0x73, 0x00, 0x6d, 0x00, 0x65, 0x00, 0x67, 0x00, 0x00];
 /// let salt = [0x0au8, 0x58, 0xCF, 0x64, 0x53, 0x0d, 0x82, 0x3f];
 /// let expected_key = [
@@ -246,25 +252,22 @@ pub fn pkcs12_pbkdf(password: &[u8], salt: &[u8], iterations: i32, typ: i32, id:
 /// ];
 /// let iterations = 1;
 /// let mut keyout = [0u8; 24];
-/// pkcs12_pbkdf_ex(&password, &salt, iterations, HMAC::TYPE_SHA256, 1, None, &mut keyout).expect("Error with pkcs12_pbkdf_ex()");
+/// pkcs12_pbkdf_ex(&password, &salt, iterations, HMAC::TYPE_SHA256, 1, &Env::new(&wolfcrypt), &mut keyout).expect("Error with pkcs12_pbkdf_ex()");
 /// assert_eq!(keyout, expected_key);
 /// }
 /// ```
 #[cfg(kdf_pkcs12)]
-pub fn pkcs12_pbkdf_ex(password: &[u8], salt: &[u8], iterations: i32, typ: i32, id: i32, heap: Option<*mut core::ffi::c_void>, out: &mut [u8]) -> Result<(), i32> {
+pub fn pkcs12_pbkdf_ex(password: &[u8], salt: &[u8], iterations: i32, typ: i32, id: i32, env: &crate::Env, out: &mut [u8]) -> Result<(), Error> {
     let password_size = crate::buffer_len_to_i32(password.len())?;
     let salt_size = crate::buffer_len_to_i32(salt.len())?;
     let out_size = crate::buffer_len_to_i32(out.len())?;
-    let heap = match heap {
-        Some(heap) => heap,
-        None => core::ptr::null_mut(),
-    };
+    let heap = env.heap_ptr();
     let rc = unsafe {
         sys::wc_PKCS12_PBKDF_ex(out.as_mut_ptr(), password.as_ptr(), password_size,
             salt.as_ptr(), salt_size, iterations, out_size, typ, id, heap)
     };
     if rc != 0 {
-        return Err(rc);
+        return Err(Error::from(rc));
     }
     Ok(())
 }
@@ -314,7 +317,7 @@ pub fn pkcs12_pbkdf_ex(password: &[u8], salt: &[u8], iterations: i32, typ: i32,
 /// ```
 #[cfg(kdf_scrypt)]
 pub fn scrypt(password: &[u8], salt: &[u8], cost: i32, block_size: i32,
-        parallel: i32, out: &mut [u8]) -> Result<(), i32> {
+        parallel: i32, out: &mut [u8]) -> Result<(), Error> {
     let password_size = crate::buffer_len_to_i32(password.len())?;
     let salt_size = crate::buffer_len_to_i32(salt.len())?;
     let out_size = crate::buffer_len_to_i32(out.len())?;
@@ -323,7 +326,7 @@ pub fn scrypt(password: &[u8], salt: &[u8], cost: i32, block_size: i32,
             salt.as_ptr(), salt_size, cost, block_size, parallel, out_size)
     };
     if rc != 0 {
-        return Err(rc);
+        return Err(Error::from(rc));
     }
     Ok(())
 }
@@ -363,7 +366,7 @@ pub fn scrypt(password: &[u8], salt: &[u8], cost: i32, block_size: i32,
 /// ```
 #[cfg(kdf_scrypt)]
 pub fn scrypt_ex(password: &[u8], salt: &[u8], iterations: u32,
-        block_size: i32, parallel: i32, out: &mut [u8]) -> Result<(), i32> {
+        block_size: i32, parallel: i32, out: &mut [u8]) -> Result<(), Error> {
     let password_size = crate::buffer_len_to_i32(password.len())?;
     let salt_size = crate::buffer_len_to_i32(salt.len())?;
     let out_size = crate::buffer_len_to_i32(out.len())?;
@@ -372,7 +375,7 @@ pub fn scrypt_ex(password: &[u8], salt: &[u8], iterations: u32,
             salt.as_ptr(), salt_size, iterations, block_size, parallel, out_size)
     };
     if rc != 0 {
-        return Err(rc);
+        return Err(Error::from(rc));
     }
     Ok(())
 }
@@ -387,6 +390,7 @@ pub fn scrypt_ex(password: &[u8], salt: &[u8], iterations: u32,
 /// * `out`: Output buffer to store TLS1.3 HKDF-Extract result (generated
 ///   Pseudo-Random Key (PRK)). The size of this buffer must match
 ///   `HMAC::get_hmac_size_by_type(typ)`.
+/// * `wolfcrypt`: Guard of the initialized library.
 ///
 /// # Returns
 ///
@@ -401,13 +405,15 @@ pub fn scrypt_ex(password: &[u8], salt: &[u8], iterations: u32,
 /// use wolfssl_wolfcrypt::hmac::HMAC;
 /// use wolfssl_wolfcrypt::kdf::*;
 /// use wolfssl_wolfcrypt::sha::SHA256;
+/// use wolfssl_wolfcrypt::WolfCrypt;
+/// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
 /// let mut secret = [0u8; SHA256::DIGEST_SIZE];
-/// tls13_hkdf_extract(HMAC::TYPE_SHA256, None, None, &mut secret).expect("Error with tls13_hkdf_extract()");
+/// tls13_hkdf_extract(HMAC::TYPE_SHA256, None, None, &mut secret, &wolfcrypt).expect("Error with tls13_hkdf_extract()");
 /// }
 /// ```
 #[cfg(all(hmac, kdf_tls13))]
-pub fn tls13_hkdf_extract(typ: i32, salt: Option<&[u8]>, key: Option<&mut [u8]>, out: &mut [u8]) -> Result<(), i32> {
-    tls13_hkdf_extract_ex(typ, salt, key, out, None, None)
+pub fn tls13_hkdf_extract(typ: i32, salt: Option<&[u8]>, key: Option<&mut [u8]>, out: &mut [u8], wolfcrypt: &crate::WolfCrypt) -> Result<(), Error> {
+    tls13_hkdf_extract_ex(typ, salt, key, out, &crate::Env::new(wolfcrypt))
 }
 
 /// Perform RFC 5869 HKDF-Extract operation for TLS v1.3 key derivation (with
@@ -421,8 +427,7 @@ pub fn tls13_hkdf_extract(typ: i32, salt: Option<&[u8]>, key: Option<&mut [u8]>,
 /// * `out`: Output buffer to store TLS1.3 HKDF-Extract result (generated
 ///   Pseudo-Random Key (PRK)). The size of this buffer must match
 ///   `HMAC::get_hmac_size_by_type(typ)`.
-/// * `heap`: Optional heap hint.
-/// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+/// * `env`: Heap hint and crypto callback device to use.
 ///
 /// # Returns
 ///
@@ -434,15 +439,18 @@ pub fn tls13_hkdf_extract(typ: i32, salt: Option<&[u8]>, key: Option<&mut [u8]>,
 /// ```rust
 /// #[cfg(all(hmac, kdf_tls13))]
 /// {
+/// use wolfssl_wolfcrypt::Env;
 /// use wolfssl_wolfcrypt::hmac::HMAC;
 /// use wolfssl_wolfcrypt::kdf::*;
 /// use wolfssl_wolfcrypt::sha::SHA256;
+/// use wolfssl_wolfcrypt::WolfCrypt;
+/// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
 /// let mut secret = [0u8; SHA256::DIGEST_SIZE];
-/// tls13_hkdf_extract_ex(HMAC::TYPE_SHA256, None, None, &mut secret, None, None).expect("Error with tls13_hkdf_extract_ex()");
+/// tls13_hkdf_extract_ex(HMAC::TYPE_SHA256, None, None, &mut secret, &Env::new(&wolfcrypt)).expect("Error with tls13_hkdf_extract_ex()");
 /// }
 /// ```
 #[cfg(all(hmac, kdf_tls13))]
-pub fn tls13_hkdf_extract_ex(typ: i32, salt: Option<&[u8]>, key: Option<&mut [u8]>, out: &mut [u8], heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<(), i32> {
+pub fn tls13_hkdf_extract_ex(typ: i32, salt: Option<&[u8]>, key: Option<&mut [u8]>, out: &mut [u8], env: &crate::Env) -> Result<(), Error> {
     let mut salt_ptr = core::ptr::null();
     let mut salt_size = 0u32;
     if let Some(salt) = salt {
@@ -457,22 +465,16 @@ pub fn tls13_hkdf_extract_ex(typ: i32, salt: Option<&[u8]>, key: Option<&mut [u8
         ikm_size = crate::buffer_len_to_u32(key.len())?;
     }
     if out.len() != HMAC::get_hmac_size_by_type(typ)? {
-        return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+        return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
     }
-    let heap = match heap {
-        Some(heap) => heap,
-        None => core::ptr::null_mut(),
-    };
-    let dev_id = match dev_id {
-        Some(dev_id) => dev_id,
-        None => sys::INVALID_DEVID,
-    };
+    let heap = env.heap_ptr();
+    let dev_id = env.dev_id();
     let rc = unsafe {
         sys::wc_Tls13_HKDF_Extract_ex(out.as_mut_ptr(), salt_ptr, salt_size,
             ikm_ptr, ikm_size, typ, heap, dev_id)
     };
     if rc != 0 {
-        return Err(rc);
+        return Err(Error::from(rc));
     }
     Ok(())
 }
@@ -491,6 +493,7 @@ pub fn tls13_hkdf_extract_ex(typ: i32, salt: Option<&[u8]>, key: Option<&mut [u8
 /// * `info`: Buffer containing additional info.
 /// * `out`: Output buffer to store TLS1.3 HKDF-Expand result. The buffer can be
 ///   any size.
+/// * `wolfcrypt`: Guard of the initialized library.
 ///
 /// # Returns
 ///
@@ -505,6 +508,8 @@ pub fn tls13_hkdf_extract_ex(typ: i32, salt: Option<&[u8]>, key: Option<&mut [u8
 /// use wolfssl_wolfcrypt::hmac::HMAC;
 /// use wolfssl_wolfcrypt::kdf::*;
 /// use wolfssl_wolfcrypt::sha::SHA256;
+/// use wolfssl_wolfcrypt::WolfCrypt;
+/// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
 /// let hash_hello1 = [
 ///     0x63u8, 0x83, 0x58, 0xab, 0x36, 0xcd, 0x0c, 0xf3,
 ///     0x26, 0x07, 0xb5, 0x5f, 0x0b, 0x8b, 0x45, 0xd6,
@@ -517,18 +522,18 @@ pub fn tls13_hkdf_extract_ex(typ: i32, salt: Option<&[u8]>, key: Option<&mut [u8
 ///     0x5e, 0x58, 0x5d, 0xed, 0xcd, 0x0b, 0x96, 0xd3
 /// ];
 /// let mut secret = [0u8; SHA256::DIGEST_SIZE];
-/// tls13_hkdf_extract(HMAC::TYPE_SHA256, None, None, &mut secret).expect("Error with tls13_hkdf_extract()");
+/// tls13_hkdf_extract(HMAC::TYPE_SHA256, None, None, &mut secret, &wolfcrypt).expect("Error with tls13_hkdf_extract()");
 /// let protocol_label = b"tls13 ";
 /// let ce_traffic_label = b"c e traffic";
 /// let mut expand_out = [0u8; SHA256::DIGEST_SIZE];
 /// tls13_hkdf_expand_label(HMAC::TYPE_SHA256, &secret,
 ///     protocol_label, ce_traffic_label,
-///     &hash_hello1, &mut expand_out).expect("Error with tls13_hkdf_expand_label()");
+///     &hash_hello1, &mut expand_out, &wolfcrypt).expect("Error with tls13_hkdf_expand_label()");
 /// }
 /// ```
 #[cfg(all(hmac, kdf_tls13))]
-pub fn tls13_hkdf_expand_label(typ: i32, key: &[u8], protocol: &[u8], label: &[u8], info: &[u8], out: &mut [u8]) -> Result<(), i32> {
-    tls13_hkdf_expand_label_ex(typ, key, protocol, label, info, out, None, None)
+pub fn tls13_hkdf_expand_label(typ: i32, key: &[u8], protocol: &[u8], label: &[u8], info: &[u8], out: &mut [u8], wolfcrypt: &crate::WolfCrypt) -> Result<(), Error> {
+    tls13_hkdf_expand_label_ex(typ, key, protocol, label, info, out, &crate::Env::new(wolfcrypt))
 }
 
 /// Perform RFC 5869 HKDF-Expand operation for TLS v1.3 key derivation (with
@@ -546,8 +551,7 @@ pub fn tls13_hkdf_expand_label(typ: i32, key: &[u8], protocol: &[u8], label: &[u
 /// * `info`: Buffer containing additional info.
 /// * `out`: Output buffer to store TLS1.3 HKDF-Expand result. The buffer can be
 ///   any size.
-/// * `heap`: Optional heap hint.
-/// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+/// * `env`: Heap hint and crypto callback device to use.
 ///
 /// # Returns
 ///
@@ -559,9 +563,12 @@ pub fn tls13_hkdf_expand_label(typ: i32, key: &[u8], protocol: &[u8], label: &[u
 /// ```rust
 /// #[cfg(all(hmac, kdf_tls13))]
 /// {
+/// use wolfssl_wolfcrypt::Env;
 /// use wolfssl_wolfcrypt::hmac::HMAC;
 /// use wolfssl_wolfcrypt::kdf::*;
 /// use wolfssl_wolfcrypt::sha::SHA256;
+/// use wolfssl_wolfcrypt::WolfCrypt;
+/// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
 /// let hash_hello1 = [
 ///     0x63u8, 0x83, 0x58, 0xab, 0x36, 0xcd, 0x0c, 0xf3,
 ///     0x26, 0x07, 0xb5, 0x5f, 0x0b, 0x8b, 0x45, 0xd6,
@@ -574,31 +581,25 @@ pub fn tls13_hkdf_expand_label(typ: i32, key: &[u8], protocol: &[u8], label: &[u
 ///     0x5e, 0x58, 0x5d, 0xed, 0xcd, 0x0b, 0x96, 0xd3
 /// ];
 /// let mut secret = [0u8; SHA256::DIGEST_SIZE];
-/// tls13_hkdf_extract(HMAC::TYPE_SHA256, None, None, &mut secret).expect("Error with tls13_hkdf_extract()");
+/// tls13_hkdf_extract(HMAC::TYPE_SHA256, None, None, &mut secret, &wolfcrypt).expect("Error with tls13_hkdf_extract()");
 /// let protocol_label = b"tls13 ";
 /// let ce_traffic_label = b"c e traffic";
 /// let mut expand_out = [0u8; SHA256::DIGEST_SIZE];
 /// tls13_hkdf_expand_label_ex(HMAC::TYPE_SHA256, &secret,
 ///     protocol_label, ce_traffic_label,
-///     &hash_hello1, &mut expand_out, None, None).expect("Error with tls13_hkdf_expand_label_ex()");
+///     &hash_hello1, &mut expand_out, &Env::new(&wolfcrypt)).expect("Error with tls13_hkdf_expand_label_ex()");
 /// }
 /// ```
 #[cfg(all(hmac, kdf_tls13))]
 #[allow(clippy::too_many_arguments)]
-pub fn tls13_hkdf_expand_label_ex(typ: i32, key: &[u8], protocol: &[u8], label: &[u8], info: &[u8], out: &mut [u8], heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<(), i32> {
+pub fn tls13_hkdf_expand_label_ex(typ: i32, key: &[u8], protocol: &[u8], label: &[u8], info: &[u8], out: &mut [u8], env: &crate::Env) -> Result<(), Error> {
     let key_size = crate::buffer_len_to_u32(key.len())?;
     let protocol_size = crate::buffer_len_to_u32(protocol.len())?;
     let label_size = crate::buffer_len_to_u32(label.len())?;
     let info_size = crate::buffer_len_to_u32(info.len())?;
     let out_size = crate::buffer_len_to_u32(out.len())?;
-    let heap = match heap {
-        Some(heap) => heap,
-        None => core::ptr::null_mut(),
-    };
-    let dev_id = match dev_id {
-        Some(dev_id) => dev_id,
-        None => sys::INVALID_DEVID,
-    };
+    let heap = env.heap_ptr();
+    let dev_id = env.dev_id();
     let rc = unsafe {
         sys::wc_Tls13_HKDF_Expand_Label_ex(out.as_mut_ptr(), out_size,
             key.as_ptr(), key_size, protocol.as_ptr(), protocol_size,
@@ -606,7 +607,7 @@ pub fn tls13_hkdf_expand_label_ex(typ: i32, key: &[u8], protocol: &[u8], label:
             heap, dev_id)
     };
     if rc != 0 {
-        return Err(rc);
+        return Err(Error::from(rc));
     }
     Ok(())
 }
@@ -642,7 +643,7 @@ pub fn tls13_hkdf_expand_label_ex(typ: i32, key: &[u8], protocol: &[u8], label:
 /// }
 /// ```
 #[cfg(kdf_ssh)]
-pub fn ssh_kdf(typ: i32, key_id: u8, k: &[u8], h: &[u8], session_id: &[u8], key: &mut [u8]) -> Result<(), i32> {
+pub fn ssh_kdf(typ: i32, key_id: u8, k: &[u8], h: &[u8], session_id: &[u8], key: &mut [u8]) -> Result<(), Error> {
     let key_size = crate::buffer_len_to_u32(key.len())?;
     let k_size = crate::buffer_len_to_u32(k.len())?;
     let h_size = crate::buffer_len_to_u32(h.len())?;
@@ -654,7 +655,7 @@ pub fn ssh_kdf(typ: i32, key_id: u8, k: &[u8], h: &[u8], session_id: &[u8], key:
             session_id.as_ptr(), session_size)
     };
     if rc != 0 {
-        return Err(rc);
+        return Err(Error::from(rc));
     }
     Ok(())
 }
@@ -695,7 +696,7 @@ pub fn ssh_kdf(typ: i32, key_id: u8, k: &[u8], h: &[u8], session_id: &[u8], key:
 /// ```
 #[cfg(kdf_srtp)]
 pub fn srtp_kdf(key: &[u8], salt: &[u8], kdr_index: i32, idx: &[u8; SRTP_INDEX_LEN],
-        key1: &mut [u8], key2: &mut [u8], key3: &mut [u8]) -> Result<(), i32> {
+        key1: &mut [u8], key2: &mut [u8], key3: &mut [u8]) -> Result<(), Error> {
     let key_size = crate::buffer_len_to_u32(key.len())?;
     let salt_size = crate::buffer_len_to_u32(salt.len())?;
     let key1_size = crate::buffer_len_to_u32(key1.len())?;
@@ -707,7 +708,7 @@ pub fn srtp_kdf(key: &[u8], salt: &[u8], kdr_index: i32, idx: &[u8; SRTP_INDEX_L
             key2.as_mut_ptr(), key2_size, key3.as_mut_ptr(), key3_size)
     };
     if rc != 0 {
-        return Err(rc);
+        return Err(Error::from(rc));
     }
     Ok(())
 }
@@ -745,7 +746,7 @@ pub fn srtp_kdf(key: &[u8], salt: &[u8], kdr_index: i32, idx: &[u8; SRTP_INDEX_L
 /// ```
 #[cfg(kdf_srtp)]
 pub fn srtp_kdf_label(key: &[u8], salt: &[u8], kdr_index: i32, idx: &[u8; SRTP_INDEX_LEN],
-        label: u8, keyout: &mut [u8]) -> Result<(), i32> {
+        label: u8, keyout: &mut [u8]) -> Result<(), Error> {
     let key_size = crate::buffer_len_to_u32(key.len())?;
     let salt_size = crate::buffer_len_to_u32(salt.len())?;
     let keyout_size = crate::buffer_len_to_u32(keyout.len())?;
@@ -754,7 +755,7 @@ pub fn srtp_kdf_label(key: &[u8], salt: &[u8], kdr_index: i32, idx: &[u8; SRTP_I
             kdr_index, idx.as_ptr(), label, keyout.as_mut_ptr(), keyout_size)
     };
     if rc != 0 {
-        return Err(rc);
+        return Err(Error::from(rc));
     }
     Ok(())
 }
@@ -795,7 +796,7 @@ pub fn srtp_kdf_label(key: &[u8], salt: &[u8], kdr_index: i32, idx: &[u8; SRTP_I
 /// ```
 #[cfg(kdf_srtp)]
 pub fn srtcp_kdf(key: &[u8], salt: &[u8], kdr_index: i32, idx: &[u8; SRTCP_INDEX_LEN],
-        key1: &mut [u8], key2: &mut [u8], key3: &mut [u8]) -> Result<(), i32> {
+        key1: &mut [u8], key2: &mut [u8], key3: &mut [u8]) -> Result<(), Error> {
     let key_size = crate::buffer_len_to_u32(key.len())?;
     let salt_size = crate::buffer_len_to_u32(salt.len())?;
     let key1_size = crate::buffer_len_to_u32(key1.len())?;
@@ -807,7 +808,7 @@ pub fn srtcp_kdf(key: &[u8], salt: &[u8], kdr_index: i32, idx: &[u8; SRTCP_INDEX
             key2.as_mut_ptr(), key2_size, key3.as_mut_ptr(), key3_size)
     };
     if rc != 0 {
-        return Err(rc);
+        return Err(Error::from(rc));
     }
     Ok(())
 }
@@ -845,7 +846,7 @@ pub fn srtcp_kdf(key: &[u8], salt: &[u8], kdr_index: i32, idx: &[u8; SRTCP_INDEX
 /// ```
 #[cfg(kdf_srtp)]
 pub fn srtcp_kdf_label(key: &[u8], salt: &[u8], kdr_index: i32, idx: &[u8; SRTCP_INDEX_LEN],
-        label: u8, keyout: &mut [u8]) -> Result<(), i32> {
+        label: u8, keyout: &mut [u8]) -> Result<(), Error> {
     let key_size = crate::buffer_len_to_u32(key.len())?;
     let salt_size = crate::buffer_len_to_u32(salt.len())?;
     let keyout_size = crate::buffer_len_to_u32(keyout.len())?;
@@ -854,7 +855,7 @@ pub fn srtcp_kdf_label(key: &[u8], salt: &[u8], kdr_index: i32, idx: &[u8; SRTCP
             kdr_index, idx.as_ptr(), label, keyout.as_mut_ptr(), keyout_size)
     };
     if rc != 0 {
-        return Err(rc);
+        return Err(Error::from(rc));
     }
     Ok(())
 }
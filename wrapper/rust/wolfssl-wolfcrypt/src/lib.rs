@@ -22,6 +22,8 @@
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 /* bindgen-generated bindings to the C library */
 pub mod sys;
@@ -49,28 +51,52 @@ pub mod blake2;
 pub mod blake2_digest;
 #[cfg(all(any(blake2b, blake2s), feature = "mac"))]
 pub mod blake2_mac;
+pub mod cert;
+pub mod cert_gen;
 pub mod chacha20_poly1305;
 pub mod cmac;
 #[cfg(all(cmac, feature = "mac"))]
 pub mod cmac_mac;
+pub mod coding;
+pub mod composite_mldsa;
+pub mod cryptocb;
 pub mod curve25519;
+pub mod curve448;
 pub mod dh;
 pub mod ecc;
 #[cfg(feature = "signature")]
 pub mod ecdsa;
 pub mod ed25519;
 pub mod ed448;
+pub mod env;
+pub mod error;
 pub mod fips;
+pub mod frodokem;
+#[cfg(all(feature = "kem", frodokem, frodokem_make_key, frodokem_encapsulate,
+    frodokem_decapsulate))]
+pub mod frodokem_kem;
 pub mod hkdf;
 pub mod hmac;
 #[cfg(all(hmac, feature = "mac"))]
 pub mod hmac_mac;
+pub mod hpke;
+pub mod hybrid;
+#[cfg(all(feature = "kem", curve25519, mlkem, sha3, shake256))]
+pub mod hybrid_kem;
 pub mod kdf;
 pub mod lms;
+pub mod logging;
+pub mod memory;
 pub mod mldsa;
 pub mod mlkem;
 #[cfg(all(feature = "kem", mlkem))]
 pub mod mlkem_kem;
+pub mod pkcs11;
+pub mod pkcs12;
+pub mod pkcs7;
+pub mod pkcs8;
+#[cfg(all(pkcs8, feature = "pkcs8"))]
+pub mod pkcs8_spki;
 pub mod prf;
 pub mod random;
 pub mod rsa;
@@ -78,6 +104,8 @@ pub mod rsa;
 pub mod rsa_oaep;
 #[cfg(feature = "signature")]
 pub mod rsa_pkcs1v15;
+#[cfg(feature = "rustls")]
+pub mod rustls_provider;
 pub mod sha;
 #[cfg(all(feature = "password-hash", hmac, kdf_pbkdf2))]
 pub mod pbkdf2_password_hash;
@@ -85,15 +113,158 @@ pub mod pbkdf2_password_hash;
 pub mod scrypt_password_hash;
 #[cfg(feature = "digest")]
 pub mod sha_digest;
+pub mod slhdsa;
+pub mod tsp;
+pub mod xmss;
+
+pub use env::{Device, Env};
+pub use error::Error;
+
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 
 /// Convert a buffer length to `u32`, returning `BUFFER_E` if it overflows.
-pub(crate) fn buffer_len_to_u32(len: usize) -> Result<u32, i32> {
-    u32::try_from(len).map_err(|_| sys::wolfCrypt_ErrorCodes_BUFFER_E)
+pub(crate) fn buffer_len_to_u32(len: usize) -> Result<u32, Error> {
+    u32::try_from(len).map_err(|_| Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E))
 }
 
 /// Convert a buffer length to `i32`, returning `BUFFER_E` if it overflows.
-pub(crate) fn buffer_len_to_i32(len: usize) -> Result<i32, i32> {
-    i32::try_from(len).map_err(|_| sys::wolfCrypt_ErrorCodes_BUFFER_E)
+pub(crate) fn buffer_len_to_i32(len: usize) -> Result<i32, Error> {
+    i32::try_from(len).map_err(|_| Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E))
+}
+
+/// Reference to the initialized wolfCrypt library.
+///
+/// Creating the first `WolfCrypt` initializes wolfCrypt (`wolfCrypt_Init()`)
+/// and dropping the last one cleans it up again (`wolfCrypt_Cleanup()`).
+/// Each guard, including each clone, holds one reference on a reference
+/// count kept by this crate; cloning a guard only increments that count.
+///
+/// Every constructor of this crate takes a `&WolfCrypt`, either directly or
+/// through an [`Env`], and the object created keeps a clone of it until it
+/// is dropped, so the library cannot be cleaned up while any object is
+/// still in use. Keeping a guard alive for as long as the application uses
+/// wolfCrypt avoids initializing and cleaning up the library each time its
+/// last object is dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use wolfssl_wolfcrypt::WolfCrypt;
+/// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+/// // ... use the library ...
+/// drop(wolfcrypt);
+/// ```
+#[derive(Debug)]
+pub struct WolfCrypt {
+    _private: (),
+}
+
+/// Number of live [`WolfCrypt`] guards.
+static WOLFCRYPT_REFS: AtomicUsize = AtomicUsize::new(0);
+/// Serializes the transitions of [`WOLFCRYPT_REFS`] from and to zero with
+/// the `wolfCrypt_Init()` and `wolfCrypt_Cleanup()` calls they trigger.
+static WOLFCRYPT_LOCK: AtomicBool = AtomicBool::new(false);
+/// Guard returned by [`WolfCrypt::global()`]; it is never dropped.
+static WOLFCRYPT_GLOBAL: WolfCrypt = WolfCrypt { _private: () };
+/// Whether [`WOLFCRYPT_GLOBAL`] holds its reference yet.
+static WOLFCRYPT_GLOBAL_INIT: AtomicBool = AtomicBool::new(false);
+
+fn with_wolfcrypt_lock<R>(f: impl FnOnce() -> R) -> R {
+    while WOLFCRYPT_LOCK.compare_exchange_weak(false, true, Ordering::Acquire,
+            Ordering::Relaxed).is_err() {
+        core::hint::spin_loop();
+    }
+    let r = f();
+    WOLFCRYPT_LOCK.store(false, Ordering::Release);
+    r
+}
+
+impl WolfCrypt {
+    /// Initialize wolfCrypt, or take another reference on the library if it
+    /// is already initialized.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(WolfCrypt) on success or Err(e) containing the
+    /// wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// ```
+    pub fn new() -> Result<Self, Error> {
+        with_wolfcrypt_lock(|| {
+            if WOLFCRYPT_REFS.load(Ordering::Acquire) == 0 {
+                let rc = unsafe { sys::wolfCrypt_Init() };
+                if rc != 0 {
+                    return Err(Error::from(rc));
+                }
+            }
+            WOLFCRYPT_REFS.fetch_add(1, Ordering::AcqRel);
+            Ok(WolfCrypt { _private: () })
+        })
+    }
+
+    /// Get the process wide guard, initializing wolfCrypt the first time it
+    /// is called.
+    ///
+    /// The global guard is never dropped, so wolfCrypt stays initialized
+    /// until the process exits. It is used by the implementations of
+    /// external traits whose constructors cannot take a guard, such as
+    /// the RustCrypto `KeyInit` and `Default` implementations and the
+    /// rustls crypto provider.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(&WolfCrypt) on success or Err(e) containing the
+    /// wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::global().expect("Error with WolfCrypt::global()");
+    /// let _guard = wolfcrypt.clone();
+    /// ```
+    pub fn global() -> Result<&'static WolfCrypt, Error> {
+        if !WOLFCRYPT_GLOBAL_INIT.load(Ordering::Acquire) {
+            let wolfcrypt = WolfCrypt::new()?;
+            if WOLFCRYPT_GLOBAL_INIT.swap(true, Ordering::AcqRel) {
+                // Another thread took the global reference first.
+                drop(wolfcrypt);
+            } else {
+                core::mem::forget(wolfcrypt);
+            }
+        }
+        Ok(&WOLFCRYPT_GLOBAL)
+    }
+}
+
+impl Clone for WolfCrypt {
+    /// Take another reference on the initialized library.
+    ///
+    /// This only increments the reference count: the library is already
+    /// initialized since `self` holds a reference.
+    fn clone(&self) -> Self {
+        WOLFCRYPT_REFS.fetch_add(1, Ordering::AcqRel);
+        WolfCrypt { _private: () }
+    }
+}
+
+impl Drop for WolfCrypt {
+    /// Release the reference, cleaning up wolfCrypt if this was the last one.
+    ///
+    /// This calls the `wolfCrypt_Cleanup()` wolfssl library function when
+    /// the last reference is released.
+    fn drop(&mut self) {
+        with_wolfcrypt_lock(|| {
+            if WOLFCRYPT_REFS.fetch_sub(1, Ordering::AcqRel) == 1 {
+                unsafe { sys::wolfCrypt_Cleanup(); }
+            }
+        });
+    }
 }
 
 /// Initialize resources used by wolfCrypt.
@@ -106,15 +277,17 @@ pub(crate) fn buffer_len_to_i32(len: usize) -> Result<i32, i32> {
 /// # Example
 ///
 /// ```rust
+/// #![allow(deprecated)]
 /// use wolfssl_wolfcrypt::*;
 /// wolfcrypt_init().expect("Error with wolfcrypt_init()");
 /// // ... use the library ...
 /// wolfcrypt_cleanup().expect("wolfCrypt_Cleanup failed");
 /// ```
-pub fn wolfcrypt_init() -> Result<(), i32> {
+#[deprecated(note = "use WolfCrypt::new(), which cleans up when dropped")]
+pub fn wolfcrypt_init() -> Result<(), Error> {
     let rc = unsafe { sys::wolfCrypt_Init() };
     if rc != 0 {
-        return Err(rc);
+        return Err(Error::from(rc));
     }
     Ok(())
 }
@@ -126,11 +299,15 @@ pub fn wolfcrypt_init() -> Result<(), i32> {
 /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
 /// library error code value.
 ///
+/// Calling this while objects of this crate are alive releases a reference
+/// they hold, so it must only be paired with [`wolfcrypt_init`].
+///
 /// See also: [`wolfcrypt_init`]
-pub fn wolfcrypt_cleanup() -> Result<(), i32> {
+#[deprecated(note = "drop the WolfCrypt guard instead")]
+pub fn wolfcrypt_cleanup() -> Result<(), Error> {
     let rc = unsafe { sys::wolfCrypt_Cleanup() };
     if rc != 0 {
-        return Err(rc);
+        return Err(Error::from(rc));
     }
     Ok(())
 }
@@ -42,29 +42,36 @@ Predefined parameter sets are available as `Lms::PARM_*` constants
 (e.g., `Lms::PARM_L1_H5_W4`), or parameters can be set directly via
 [`Lms::set_parameters()`].
 
-Signing requires private key I/O callbacks to persist the evolving private
-key state. Register callbacks with [`Lms::set_write_cb()`] and
-[`Lms::set_read_cb()`] before calling [`Lms::make_key()`] or
-[`Lms::reload()`]. These methods are absent when wolfCrypt is built with
-`WOLFSSL_LMS_VERIFY_ONLY`.
+Signing requires storage to persist the evolving private key state. Give
+the key an [`LmsStateStore`] with [`Lms::set_state_store()`] (the `alloc`
+feature), such as the in-memory [`MemoryStateStore`] or the file-backed
+[`FileStateStore`] (the `std` feature), or register raw callbacks with
+[`Lms::set_write_cb()`] and [`Lms::set_read_cb()`], before calling
+[`Lms::make_key()`] or [`Lms::reload()`]. These methods are absent when
+wolfCrypt is built with `WOLFSSL_LMS_VERIFY_ONLY`.
+
+A signature is only returned by [`Lms::sign()`] once the store has
+accepted the advanced private key state; if the store fails, the signature
+is erased and an error returned.
 
 # Examples
 
-```rust,no_run
-#[cfg(all(lms, lms_make_key, random))]
+```rust
+#[cfg(all(lms, lms_make_key, random, feature = "alloc"))]
 {
 use wolfssl_wolfcrypt::random::RNG;
-use wolfssl_wolfcrypt::lms::Lms;
-use wolfssl_wolfcrypt::sys;
+use wolfssl_wolfcrypt::lms::{Lms, MemoryStateStore};
+use wolfssl_wolfcrypt::WolfCrypt;
+let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
 
-let mut rng = RNG::new().expect("RNG creation failed");
-let mut key = Lms::new().expect("Lms::new() failed");
+let mut rng = RNG::new(&wolfcrypt).expect("RNG creation failed");
+let mut key = Lms::new(&wolfcrypt).expect("Lms::new() failed");
 
 // Use a small, fast parameter set for demonstration.
 key.set_parm(Lms::PARM_L1_H5_W8).expect("set_parm failed");
 
-// The private key I/O callbacks must be registered before making a key.
-// (Omitted here for brevity; see set_write_cb / set_read_cb.)
+// The private key state store must be set before making a key.
+key.set_state_store(MemoryStateStore::new()).expect("set_state_store failed");
 
 let sig_len = key.get_sig_len().expect("get_sig_len failed");
 let pub_len = key.get_pub_len().expect("get_pub_len failed");
@@ -75,7 +82,7 @@ key.make_key(&mut rng).expect("make_key failed");
 key.sign(b"hello", &mut sig).expect("sign failed");
 key.export_pub_raw(&mut pub_buf).expect("export_pub_raw failed");
 
-let mut vkey = Lms::new().expect("Lms::new() for verify failed");
+let mut vkey = Lms::new(&wolfcrypt).expect("Lms::new() for verify failed");
 vkey.set_parm(Lms::PARM_L1_H5_W8).expect("set_parm failed");
 vkey.import_pub_raw(&pub_buf).expect("import_pub_raw failed");
 vkey.verify(&sig, b"hello").expect("verify failed");
@@ -85,10 +92,224 @@ vkey.verify(&sig, b"hello").expect("verify failed");
 
 #![cfg(lms)]
 
+use crate::Error;
+use crate::memory::HeapHint;
 use crate::sys;
+use core::marker::PhantomData;
 use core::mem::MaybeUninit;
 #[cfg(all(lms_make_key, random))]
 use crate::random::RNG;
+#[cfg(all(lms_make_key, feature = "alloc"))]
+use alloc::{boxed::Box, rc::Rc, vec::Vec};
+#[cfg(all(lms_make_key, feature = "alloc"))]
+use core::cell::RefCell;
+#[cfg(all(lms_make_key, feature = "std"))]
+use std::path::{Path, PathBuf};
+#[cfg(all(lms_make_key, feature = "alloc"))]
+use zeroize::Zeroize;
+
+/// Persistent storage for the private key state of an [`Lms`] key.
+///
+/// The LMS/HSS private key state advances with every signature. It is
+/// written by [`Lms::make_key()`] and [`Lms::sign()`] and read by
+/// [`Lms::reload()`]. Reusing a state that has already been used to sign
+/// reuses one-time keys and breaks the security of the scheme, so a store
+/// must only return `Ok(())` from `write()` once the new state will survive
+/// a crash or power loss, and must never hand back an older state from
+/// `read()`.
+///
+/// Set a store on a key with [`Lms::set_state_store()`].
+#[cfg(lms_make_key)]
+pub trait LmsStateStore {
+    /// Replace the stored private key state with `state`.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) once the state is persisted or Err(e)
+    /// containing a wolfSSL library error code value. On error the signature
+    /// being created is erased.
+    fn write(&mut self, state: &[u8]) -> Result<(), Error>;
+
+    /// Read the stored private key state into `state`.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) when exactly `state.len()` bytes were read or
+    /// Err(e) containing a wolfSSL library error code value.
+    fn read(&mut self, state: &mut [u8]) -> Result<(), Error>;
+}
+
+/// An [`LmsStateStore`] holding the private key state in memory.
+///
+/// Clones share the same state, so a clone kept by the caller can be given
+/// to another key to [`Lms::reload()`] it. Each write replaces the state in
+/// a single step and the previous state is zeroized. The state does not
+/// survive the process; use [`FileStateStore`] for durable storage.
+#[cfg(all(lms_make_key, feature = "alloc"))]
+#[derive(Clone, Default)]
+pub struct MemoryStateStore {
+    state: Rc<RefCell<Vec<u8>>>,
+}
+
+#[cfg(all(lms_make_key, feature = "alloc"))]
+impl MemoryStateStore {
+    /// Create a new, empty in-memory store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check whether no state has been written yet.
+    pub fn is_empty(&self) -> bool {
+        self.state.borrow().is_empty()
+    }
+
+    /// Get a copy of the stored private key state.
+    pub fn state(&self) -> Vec<u8> {
+        self.state.borrow().clone()
+    }
+}
+
+#[cfg(all(lms_make_key, feature = "alloc"))]
+impl LmsStateStore for MemoryStateStore {
+    fn write(&mut self, state: &[u8]) -> Result<(), Error> {
+        let mut old = self.state.replace(state.to_vec());
+        old.as_mut_slice().zeroize();
+        Ok(())
+    }
+
+    fn read(&mut self, state: &mut [u8]) -> Result<(), Error> {
+        let stored = self.state.borrow();
+        if stored.len() != state.len() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_STATE_E));
+        }
+        state.copy_from_slice(&stored);
+        Ok(())
+    }
+}
+
+#[cfg(all(lms_make_key, feature = "alloc"))]
+impl Drop for MemoryStateStore {
+    /// Zeroize the private key state when the last clone is dropped.
+    fn drop(&mut self) {
+        if Rc::strong_count(&self.state) == 1 {
+            self.state.borrow_mut().as_mut_slice().zeroize();
+        }
+    }
+}
+
+/// An [`LmsStateStore`] holding the private key state in a file.
+///
+/// Each write goes to a temporary file next to the state file (the state
+/// file name with `.tmp` appended) which is synced to disk and then renamed
+/// over the state file, and the directory is synced (on Unix) so the rename
+/// itself is durable. `write()` only returns once all of this succeeded, so
+/// [`Lms::sign()`] never releases a signature whose advanced state could be
+/// lost, and a crash leaves either the old or the new state in place.
+#[cfg(all(lms_make_key, feature = "std"))]
+pub struct FileStateStore {
+    path: PathBuf,
+    tmp_path: PathBuf,
+}
+
+#[cfg(all(lms_make_key, feature = "std"))]
+impl FileStateStore {
+    /// Create a store using the state file at `path`.
+    ///
+    /// The file is not accessed until the store is written or read.
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref().to_path_buf();
+        let mut tmp_path = path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        FileStateStore { path, tmp_path: PathBuf::from(tmp_path) }
+    }
+
+    /// Get the path of the state file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn write_durably(&self, state: &[u8]) -> std::io::Result<()> {
+        use std::io::Write;
+        let mut file = std::fs::File::create(&self.tmp_path)?;
+        file.write_all(state)?;
+        file.sync_all()?;
+        drop(file);
+        std::fs::rename(&self.tmp_path, &self.path)?;
+        #[cfg(unix)]
+        {
+            let dir = match self.path.parent() {
+                Some(dir) if !dir.as_os_str().is_empty() => dir,
+                _ => Path::new("."),
+            };
+            std::fs::File::open(dir)?.sync_all()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(lms_make_key, feature = "std"))]
+impl LmsStateStore for FileStateStore {
+    fn write(&mut self, state: &[u8]) -> Result<(), Error> {
+        match self.write_durably(state) {
+            Ok(()) => Ok(()),
+            Err(_) => {
+                let _ = std::fs::remove_file(&self.tmp_path);
+                Err(Error::from(sys::wolfCrypt_ErrorCodes_IO_FAILED_E))
+            }
+        }
+    }
+
+    fn read(&mut self, state: &mut [u8]) -> Result<(), Error> {
+        let mut stored = std::fs::read(&self.path)
+            .map_err(|_| sys::wolfCrypt_ErrorCodes_IO_FAILED_E)?;
+        let rc = if stored.len() == state.len() {
+            state.copy_from_slice(&stored);
+            Ok(())
+        } else {
+            Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_STATE_E))
+        };
+        stored.as_mut_slice().zeroize();
+        rc
+    }
+}
+
+/// Write callback registered by [`Lms::set_state_store()`].
+#[cfg(all(lms_make_key, feature = "alloc"))]
+unsafe extern "C" fn state_store_write(
+    priv_data: *const u8,
+    priv_sz: u32,
+    context: *mut core::ffi::c_void,
+) -> i32 {
+    if priv_data.is_null() || context.is_null() {
+        return sys::wc_LmsRc_WC_LMS_RC_BAD_ARG as i32;
+    }
+    // Safety: the context is the boxed store owned by the key.
+    let store = unsafe { &mut *(context as *mut Box<dyn LmsStateStore>) };
+    let state = unsafe { core::slice::from_raw_parts(priv_data, priv_sz as usize) };
+    match store.write(state) {
+        Ok(()) => sys::wc_LmsRc_WC_LMS_RC_SAVED_TO_NV_MEMORY as i32,
+        Err(_) => sys::wc_LmsRc_WC_LMS_RC_WRITE_FAIL as i32,
+    }
+}
+
+/// Read callback registered by [`Lms::set_state_store()`].
+#[cfg(all(lms_make_key, feature = "alloc"))]
+unsafe extern "C" fn state_store_read(
+    priv_data: *mut u8,
+    priv_sz: u32,
+    context: *mut core::ffi::c_void,
+) -> i32 {
+    if priv_data.is_null() || context.is_null() {
+        return sys::wc_LmsRc_WC_LMS_RC_BAD_ARG as i32;
+    }
+    // Safety: the context is the boxed store owned by the key.
+    let store = unsafe { &mut *(context as *mut Box<dyn LmsStateStore>) };
+    let state = unsafe { core::slice::from_raw_parts_mut(priv_data, priv_sz as usize) };
+    match store.read(state) {
+        Ok(()) => sys::wc_LmsRc_WC_LMS_RC_READ_TO_MEMORY as i32,
+        Err(_) => sys::wc_LmsRc_WC_LMS_RC_READ_FAIL as i32,
+    }
+}
 
 /// Rust wrapper for a wolfSSL `LmsKey` object (LMS/HSS, RFC 8554).
 ///
@@ -102,12 +323,19 @@ use crate::random::RNG;
 /// When `WOLFSSL_LMS_VERIFY_ONLY` is **not** set, private-key callbacks must
 /// be registered (see [`Lms::set_write_cb()`] and [`Lms::set_read_cb()`])
 /// before calling [`Lms::make_key()`] or [`Lms::reload()`].
-pub struct Lms {
+pub struct Lms<'a> {
     ws_key: sys::LmsKey,
+    /// Private key state store, double boxed so the context pointer given
+    /// to wolfCrypt is thin and stable across moves of the key.
+    #[cfg(all(lms_make_key, feature = "alloc"))]
+    state_store: Option<Box<Box<dyn LmsStateStore>>>,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 
 #[cfg(lms_sha256_256)]
-impl Lms {
+impl<'a> Lms<'a> {
     pub const PARM_L1_H5_W1: u32 = sys::wc_LmsParm_WC_LMS_PARM_L1_H5_W1;
     pub const PARM_L1_H5_W2: u32 = sys::wc_LmsParm_WC_LMS_PARM_L1_H5_W2;
     pub const PARM_L1_H5_W4: u32 = sys::wc_LmsParm_WC_LMS_PARM_L1_H5_W4;
@@ -146,7 +374,7 @@ impl Lms {
 }
 
 #[cfg(lms_sha256_192)]
-impl Lms {
+impl<'a> Lms<'a> {
     pub const PARM_SHA256_192_L1_H5_W1 : u32 = sys::wc_LmsParm_WC_LMS_PARM_SHA256_192_L1_H5_W1;
     pub const PARM_SHA256_192_L1_H5_W2 : u32 = sys::wc_LmsParm_WC_LMS_PARM_SHA256_192_L1_H5_W2;
     pub const PARM_SHA256_192_L1_H5_W4 : u32 = sys::wc_LmsParm_WC_LMS_PARM_SHA256_192_L1_H5_W4;
@@ -169,12 +397,16 @@ impl Lms {
     pub const PARM_SHA256_192_L4_H5_W8 : u32 = sys::wc_LmsParm_WC_LMS_PARM_SHA256_192_L4_H5_W8;
 }
 
-impl Lms {
+impl<'a> Lms<'a> {
     /// Length of the LMS Key ID (`WC_LMS_I_LEN` = 16 bytes).
     pub const KEY_ID_LEN: usize = sys::WC_LMS_I_LEN as usize;
 
     /// Create and initialize a new `Lms` key instance.
     ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
     /// # Returns
     ///
     /// Returns either Ok(Lms) containing the key instance or Err(e)
@@ -186,11 +418,13 @@ impl Lms {
     /// #[cfg(lms)]
     /// {
     /// use wolfssl_wolfcrypt::lms::Lms;
-    /// let key = Lms::new().expect("Error with Lms::new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let key = Lms::new(&wolfcrypt).expect("Error with Lms::new()");
     /// }
     /// ```
-    pub fn new() -> Result<Self, i32> {
-        Self::new_ex(None, None)
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&crate::Env::new(wolfcrypt))
     }
 
     /// Create and initialize a new `Lms` key instance with optional heap hint
@@ -198,8 +432,7 @@ impl Lms {
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id`: Optional device ID for crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -211,29 +444,33 @@ impl Lms {
     /// ```rust
     /// #[cfg(lms)]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::lms::Lms;
-    /// let key = Lms::new_ex(None, None).expect("Error with Lms::new_ex()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let key = Lms::new_ex(&Env::new(&wolfcrypt)).expect("Error with Lms::new_ex()");
     /// }
     /// ```
     pub fn new_ex(
-        heap: Option<*mut core::ffi::c_void>,
-        dev_id: Option<i32>,
-    ) -> Result<Self, i32> {
-        let heap = match heap {
-            Some(h) => h,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(id) => id,
-            None => sys::INVALID_DEVID,
-        };
+        env: &crate::Env,
+    ) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let mut ws_key: MaybeUninit<sys::LmsKey> = MaybeUninit::uninit();
         let rc = unsafe { sys::wc_LmsKey_Init(ws_key.as_mut_ptr(), heap, dev_id) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let ws_key = unsafe { ws_key.assume_init() };
-        let lms = Lms { ws_key };
+        let lms = Lms {
+            ws_key,
+            #[cfg(all(lms_make_key, feature = "alloc"))]
+            state_store: None,
+            _wolfcrypt: wolfcrypt,
+            _device: env.device_ref(),
+            _heap: PhantomData,
+        };
         Ok(lms)
     }
 
@@ -257,14 +494,16 @@ impl Lms {
     /// #[cfg(lms)]
     /// {
     /// use wolfssl_wolfcrypt::lms::Lms;
-    /// let mut key = Lms::new().expect("Error with Lms::new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut key = Lms::new(&wolfcrypt).expect("Error with Lms::new()");
     /// key.set_parm(Lms::PARM_L1_H5_W8).expect("Error with set_parm()");
     /// }
     /// ```
-    pub fn set_parm(&mut self, parm: u32) -> Result<(), i32> {
+    pub fn set_parm(&mut self, parm: u32) -> Result<(), Error> {
         let rc = unsafe { sys::wc_LmsKey_SetLmsParm(&mut self.ws_key, parm) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -288,16 +527,18 @@ impl Lms {
     /// #[cfg(lms)]
     /// {
     /// use wolfssl_wolfcrypt::lms::Lms;
-    /// let mut key = Lms::new().expect("Error with Lms::new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut key = Lms::new(&wolfcrypt).expect("Error with Lms::new()");
     /// key.set_parameters(1, 5, 8).expect("Error with set_parameters()");
     /// }
     /// ```
-    pub fn set_parameters(&mut self, levels: i32, height: i32, winternitz: i32) -> Result<(), i32> {
+    pub fn set_parameters(&mut self, levels: i32, height: i32, winternitz: i32) -> Result<(), Error> {
         let rc = unsafe {
             sys::wc_LmsKey_SetParameters(&mut self.ws_key, levels, height, winternitz)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -315,14 +556,16 @@ impl Lms {
     /// #[cfg(lms)]
     /// {
     /// use wolfssl_wolfcrypt::lms::Lms;
-    /// let mut key = Lms::new().expect("Error with Lms::new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut key = Lms::new(&wolfcrypt).expect("Error with Lms::new()");
     /// key.set_parameters(1, 5, 8).expect("Error with set_parameters()");
     /// let (levels, height, winternitz) = key.get_parameters()
     ///     .expect("Error with get_parameters()");
     /// assert_eq!((levels, height, winternitz), (1, 5, 8));
     /// }
     /// ```
-    pub fn get_parameters(&self) -> Result<(i32, i32, i32), i32> {
+    pub fn get_parameters(&self) -> Result<(i32, i32, i32), Error> {
         let mut levels = 0i32;
         let mut height = 0i32;
         let mut winternitz = 0i32;
@@ -335,7 +578,7 @@ impl Lms {
             )
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok((levels, height, winternitz))
     }
@@ -355,10 +598,10 @@ impl Lms {
     /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
     /// library error code value.
     #[cfg(lms_make_key)]
-    pub fn set_write_cb(&mut self, write_cb: sys::wc_lms_write_private_key_cb) -> Result<(), i32> {
+    pub fn set_write_cb(&mut self, write_cb: sys::wc_lms_write_private_key_cb) -> Result<(), Error> {
         let rc = unsafe { sys::wc_LmsKey_SetWriteCb(&mut self.ws_key, write_cb) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -377,11 +620,61 @@ impl Lms {
     /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
     /// library error code value.
     #[cfg(lms_make_key)]
-    pub fn set_read_cb(&mut self, read_cb: sys::wc_lms_read_private_key_cb) -> Result<(), i32> {
+    pub fn set_read_cb(&mut self, read_cb: sys::wc_lms_read_private_key_cb) -> Result<(), Error> {
         let rc = unsafe { sys::wc_LmsKey_SetReadCb(&mut self.ws_key, read_cb) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Set the store used to persist and restore the private key state.
+    ///
+    /// Registers write and read callbacks that call `store`, replacing any
+    /// callbacks and context set with [`Lms::set_write_cb()`],
+    /// [`Lms::set_read_cb()`] and [`Lms::set_context()`]. The key owns the
+    /// store from then on.
+    ///
+    /// # Parameters
+    ///
+    /// * `store`: Private key state store.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(all(lms, lms_make_key, random, feature = "alloc"))]
+    /// {
+    /// use wolfssl_wolfcrypt::random::RNG;
+    /// use wolfssl_wolfcrypt::lms::{Lms, MemoryStateStore};
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let store = MemoryStateStore::new();
+    /// let mut key = Lms::new(&wolfcrypt).expect("Error with Lms::new()");
+    /// key.set_parm(Lms::PARM_L1_H5_W8).expect("Error with set_parm()");
+    /// key.set_state_store(store.clone()).expect("Error with set_state_store()");
+    /// key.make_key(&rng).expect("Error with make_key()");
+    /// assert!(!store.is_empty());
+    /// }
+    /// ```
+    #[cfg(all(lms_make_key, feature = "alloc"))]
+    pub fn set_state_store<S: LmsStateStore + 'static>(&mut self, store: S) -> Result<(), Error> {
+        let mut store: Box<Box<dyn LmsStateStore>> = Box::new(Box::new(store));
+        let context = store.as_mut() as *mut Box<dyn LmsStateStore> as *mut core::ffi::c_void;
+        self.set_write_cb(Some(state_store_write))?;
+        self.set_read_cb(Some(state_store_read))?;
+        let rc = unsafe { sys::wc_LmsKey_SetContext(&mut self.ws_key, context) };
+        if rc != 0 {
+            return Err(Error::from(rc));
         }
+        // The previous store, if any, is only dropped once the context no
+        // longer references it.
+        self.state_store = Some(store);
         Ok(())
     }
 
@@ -403,10 +696,10 @@ impl Lms {
     /// may invoke the registered write or read callbacks (i.e., across calls
     /// to [`Lms::make_key()`], [`Lms::reload()`], and [`Lms::sign()`]).
     #[cfg(lms_make_key)]
-    pub unsafe fn set_context(&mut self, context: *mut core::ffi::c_void) -> Result<(), i32> {
+    pub unsafe fn set_context(&mut self, context: *mut core::ffi::c_void) -> Result<(), Error> {
         let rc = unsafe { sys::wc_LmsKey_SetContext(&mut self.ws_key, context) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -434,18 +727,20 @@ impl Lms {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::lms::Lms;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut key = Lms::new().expect("Error with Lms::new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut key = Lms::new(&wolfcrypt).expect("Error with Lms::new()");
     /// key.set_parm(Lms::PARM_L1_H5_W8).expect("Error with set_parm()");
     /// // Register write/read callbacks before calling make_key().
     /// // key.make_key(&mut rng).expect("Error with make_key()");
     /// }
     /// ```
     #[cfg(all(lms_make_key, random))]
-    pub fn make_key(&mut self, rng: &RNG) -> Result<(), i32> {
+    pub fn make_key(&mut self, rng: &RNG) -> Result<(), Error> {
         let rc = unsafe { sys::wc_LmsKey_MakeKey(&mut self.ws_key, rng.wc_rng) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -461,10 +756,10 @@ impl Lms {
     /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
     /// library error code value.
     #[cfg(lms_make_key)]
-    pub fn reload(&mut self) -> Result<(), i32> {
+    pub fn reload(&mut self) -> Result<(), Error> {
         let rc = unsafe { sys::wc_LmsKey_Reload(&mut self.ws_key) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -476,11 +771,11 @@ impl Lms {
     /// Returns either Ok(length) on success or Err(e) containing the wolfSSL
     /// library error code value.
     #[cfg(lms_make_key)]
-    pub fn get_priv_len(&self) -> Result<usize, i32> {
+    pub fn get_priv_len(&self) -> Result<usize, Error> {
         let mut len = 0u32;
         let rc = unsafe { sys::wc_LmsKey_GetPrivLen(&self.ws_key, &mut len) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(len as usize)
     }
@@ -488,8 +783,9 @@ impl Lms {
     /// Sign a message with this LMS/HSS private key.
     ///
     /// The `sig` buffer must be at least `get_sig_len()` bytes. The write
-    /// callback is invoked to persist the updated private key state after
-    /// signing.
+    /// callback (or state store) is invoked to persist the updated private
+    /// key state after signing; if it fails, `sig` is zeroized and an error
+    /// is returned.
     ///
     /// # Parameters
     ///
@@ -509,8 +805,10 @@ impl Lms {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::lms::Lms;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut key = Lms::new().expect("Error with Lms::new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut key = Lms::new(&wolfcrypt).expect("Error with Lms::new()");
     /// key.set_parm(Lms::PARM_L1_H5_W8).expect("Error with set_parm()");
     /// // Register callbacks and make key first (omitted for brevity).
     /// // let sig_len = key.get_sig_len().unwrap();
@@ -520,12 +818,12 @@ impl Lms {
     /// }
     /// ```
     #[cfg(lms_make_key)]
-    pub fn sign(&mut self, msg: &[u8], sig: &mut [u8]) -> Result<usize, i32> {
+    pub fn sign(&mut self, msg: &[u8], sig: &mut [u8]) -> Result<usize, Error> {
         // wc_LmsKey_Sign treats sigSz as write-only: it writes sig_len bytes
         // to sig without reading or checking *sigSz beforehand. Validate here.
         let expected_sig_len = self.get_sig_len()?;
         if sig.len() < expected_sig_len {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let mut sig_sz = crate::buffer_len_to_u32(sig.len())?;
         let msg_sz = crate::buffer_len_to_i32(msg.len())?;
@@ -539,7 +837,7 @@ impl Lms {
             )
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(sig_sz as usize)
     }
@@ -554,10 +852,10 @@ impl Lms {
     /// Returns either Ok(true) if any signatures remain, Ok(false) if
     /// exhausted, or Err(e) containing the wolfSSL library error code value.
     #[cfg(lms_make_key)]
-    pub fn has_sigs_left(&mut self) -> Result<bool, i32> {
+    pub fn has_sigs_left(&mut self) -> Result<bool, Error> {
         let rc = unsafe { sys::wc_LmsKey_SigsLeft(&mut self.ws_key) };
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(rc != 0)
     }
@@ -575,17 +873,19 @@ impl Lms {
     /// #[cfg(lms)]
     /// {
     /// use wolfssl_wolfcrypt::lms::Lms;
-    /// let mut key = Lms::new().expect("Error with Lms::new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut key = Lms::new(&wolfcrypt).expect("Error with Lms::new()");
     /// key.set_parm(Lms::PARM_L1_H5_W8).expect("Error with set_parm()");
     /// let sig_len = key.get_sig_len().expect("Error with get_sig_len()");
     /// assert!(sig_len > 0);
     /// }
     /// ```
-    pub fn get_sig_len(&self) -> Result<usize, i32> {
+    pub fn get_sig_len(&self) -> Result<usize, Error> {
         let mut len = 0u32;
         let rc = unsafe { sys::wc_LmsKey_GetSigLen(&self.ws_key, &mut len) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(len as usize)
     }
@@ -603,17 +903,19 @@ impl Lms {
     /// #[cfg(lms)]
     /// {
     /// use wolfssl_wolfcrypt::lms::Lms;
-    /// let mut key = Lms::new().expect("Error with Lms::new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut key = Lms::new(&wolfcrypt).expect("Error with Lms::new()");
     /// key.set_parm(Lms::PARM_L1_H5_W8).expect("Error with set_parm()");
     /// let pub_len = key.get_pub_len().expect("Error with get_pub_len()");
     /// assert!(pub_len > 0);
     /// }
     /// ```
-    pub fn get_pub_len(&self) -> Result<usize, i32> {
+    pub fn get_pub_len(&self) -> Result<usize, Error> {
         let mut len = 0u32;
         let rc = unsafe { sys::wc_LmsKey_GetPubLen(&self.ws_key, &mut len) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(len as usize)
     }
@@ -631,12 +933,12 @@ impl Lms {
     ///
     /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
     /// library error code value.
-    pub fn export_pub_from(&mut self, src: &Lms) -> Result<(), i32> {
+    pub fn export_pub_from(&mut self, src: &Lms) -> Result<(), Error> {
         let rc = unsafe {
             sys::wc_LmsKey_ExportPub(&mut self.ws_key, &src.ws_key)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -661,7 +963,9 @@ impl Lms {
     /// #[cfg(all(lms, lms_make_key, random))]
     /// {
     /// use wolfssl_wolfcrypt::lms::Lms;
-    /// let mut key = Lms::new().expect("Error with Lms::new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut key = Lms::new(&wolfcrypt).expect("Error with Lms::new()");
     /// key.set_parm(Lms::PARM_L1_H5_W8).expect("Error with set_parm()");
     /// // After make_key():
     /// // let pub_len = key.get_pub_len().unwrap();
@@ -669,13 +973,13 @@ impl Lms {
     /// // let written = key.export_pub_raw(&mut pub_buf).unwrap();
     /// }
     /// ```
-    pub fn export_pub_raw(&self, out: &mut [u8]) -> Result<usize, i32> {
+    pub fn export_pub_raw(&self, out: &mut [u8]) -> Result<usize, Error> {
         let mut out_len = crate::buffer_len_to_u32(out.len())?;
         let rc = unsafe {
             sys::wc_LmsKey_ExportPubRaw(&self.ws_key, out.as_mut_ptr(), &mut out_len)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(out_len as usize)
     }
@@ -699,17 +1003,19 @@ impl Lms {
     /// # Example
     ///
     /// ```rust,ignore
-    /// let mut key = Lms::new().expect("Error with Lms::new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut key = Lms::new(&wolfcrypt).expect("Error with Lms::new()");
     /// key.set_parm(Lms::PARM_L1_H5_W8).expect("set_parm failed");
     /// key.import_pub_raw(&pub_buf).expect("Error with import_pub_raw()");
     /// ```
-    pub fn import_pub_raw(&mut self, data: &[u8]) -> Result<(), i32> {
+    pub fn import_pub_raw(&mut self, data: &[u8]) -> Result<(), Error> {
         let data_size = crate::buffer_len_to_u32(data.len())?;
         let rc = unsafe {
             sys::wc_LmsKey_ImportPubRaw(&mut self.ws_key, data.as_ptr(), data_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -729,12 +1035,12 @@ impl Lms {
     /// Returns either Ok(()) if the signature is valid, or Err(e) containing
     /// the wolfSSL library error code value (including a verification failure
     /// code).
-    pub fn verify(&mut self, sig: &[u8], msg: &[u8]) -> Result<(), i32> {
+    pub fn verify(&mut self, sig: &[u8], msg: &[u8]) -> Result<(), Error> {
         // wc_lms.c validates sigSz, but ext_lms.c passes sigSz through to
         // hss_validate_signature without checking it. Validate here for both.
         let expected_sig_len = self.get_sig_len()?;
         if sig.len() != expected_sig_len {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let sig_sz = crate::buffer_len_to_u32(sig.len())?;
         let msg_sz = crate::buffer_len_to_i32(msg.len())?;
@@ -748,7 +1054,7 @@ impl Lms {
             )
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -765,34 +1071,34 @@ impl Lms {
     ///
     /// Returns either Ok(usize) containing the key ID length on success,
     /// or Err(e) containing the wolfSSL library error code value.
-    pub fn get_kid(&mut self, kid: &mut [u8]) -> Result<usize, i32> {
+    pub fn get_kid(&mut self, kid: &mut [u8]) -> Result<usize, Error> {
         let mut kid_ptr: *const u8 = core::ptr::null();
         let mut kid_sz: u32 = 0;
         let rc = unsafe {
             sys::wc_LmsKey_GetKid(&mut self.ws_key, &mut kid_ptr, &mut kid_sz)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         if kid_ptr.is_null() {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let src = unsafe { core::slice::from_raw_parts(kid_ptr, kid_sz as usize) };
         if kid.len() < src.len() {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         kid[..src.len()].copy_from_slice(src);
         Ok(src.len())
     }
 }
 
-impl Lms {
+impl<'a> Lms<'a> {
     fn zeroize(&mut self) {
         unsafe { crate::zeroize_raw(&mut self.ws_key); }
     }
 }
 
-impl Drop for Lms {
+impl Drop for Lms<'_> {
     /// Safely free the underlying wolfSSL LMS/HSS key context.
     ///
     /// This calls `wc_LmsKey_Free()`. The Rust Drop trait guarantees this
@@ -0,0 +1,533 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+This module provides a Rust interface to the wolfSSL debug logging and error
+queue functionality.
+
+Debug messages produced by a wolfSSL library built with `--enable-debug`
+(`DEBUG_WOLFSSL`) can be sent to a Rust function with
+[`set_logging_callback()`], to a closure with [`set_logging_closure()`]
+(`alloc` feature) or to the [`log`](https://docs.rs/log) crate with
+[`forward_to_log()`] (`log` feature), under the `wolfssl` target. Messages
+are only produced after [`debugging_on()`] is called.
+
+When the wolfSSL error queue is available (`OPENSSL_EXTRA` or
+`DEBUG_WOLFSSL_VERBOSE`), the pending error nodes can be inspected with
+[`peek_error_node()`] and consumed with [`error_nodes()`], each node
+carrying the error code, reason and source file and line recorded by the
+library. The queue is freed when wolfCrypt is cleaned up, so entries are
+only kept while a [`WolfCrypt`](crate::WolfCrypt) guard or an object of this
+crate keeps the library initialized. The error queue functions are internal to the wolfSSL library, so
+using them requires linking with a static library or a shared library built
+without hidden symbol visibility.
+
+With `WOLFSSL_DEBUG_TRACE_ERROR_CODES`, tracing of each error code returned
+inside the library can be toggled with [`set_trace_error_codes()`].
+
+# Examples
+
+```rust
+use core::sync::atomic::{AtomicUsize, Ordering};
+use wolfssl_wolfcrypt::logging::{self, LogLevel};
+#[cfg(random)]
+use wolfssl_wolfcrypt::random::RNG;
+use wolfssl_wolfcrypt::WolfCrypt;
+let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+
+static MESSAGES: AtomicUsize = AtomicUsize::new(0);
+
+fn on_message(_level: LogLevel, _message: &str) {
+    MESSAGES.fetch_add(1, Ordering::Relaxed);
+}
+
+if logging::set_logging_callback(on_message).is_ok() {
+    logging::debugging_on().expect("Error with debugging_on()");
+    #[cfg(random)]
+    {
+    let _rng = RNG::new(&wolfcrypt).expect("Error with RNG::new()");
+    }
+    logging::debugging_off();
+    logging::clear_logging_callback().expect("Error with clear_logging_callback()");
+}
+```
+*/
+
+use crate::Error;
+use crate::sys;
+#[cfg(feature = "alloc")]
+use alloc::sync::Arc;
+use core::cell::UnsafeCell;
+use core::ffi::{CStr, c_char};
+#[cfg(error_queue)]
+use core::fmt;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// Level of a wolfSSL debug message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LogLevel {
+    /// Error message (`ERROR_LOG`).
+    Error,
+    /// Informational message (`INFO_LOG`).
+    Info,
+    /// Function entry (`ENTER_LOG`).
+    Enter,
+    /// Function exit (`LEAVE_LOG`).
+    Leave,
+    /// Certificate processing message (`CERT_LOG`).
+    Cert,
+    /// Any other message (`OTHER_LOG`).
+    Other,
+}
+
+impl LogLevel {
+    fn from_raw(level: i32) -> Self {
+        match level as u32 {
+            sys::wc_LogLevels_ERROR_LOG => LogLevel::Error,
+            sys::wc_LogLevels_INFO_LOG => LogLevel::Info,
+            sys::wc_LogLevels_ENTER_LOG => LogLevel::Enter,
+            sys::wc_LogLevels_LEAVE_LOG => LogLevel::Leave,
+            sys::wc_LogLevels_CERT_LOG => LogLevel::Cert,
+            _ => LogLevel::Other,
+        }
+    }
+}
+
+/// Boxed closure registered with [`set_logging_closure()`].
+#[cfg(feature = "alloc")]
+type LogClosure = Arc<dyn Fn(LogLevel, &str) + Send + Sync>;
+
+/// Destination of the messages received by the logging callback.
+#[derive(Clone)]
+enum Sink {
+    Function(fn(LogLevel, &str)),
+    #[cfg(feature = "alloc")]
+    Closure(LogClosure),
+}
+
+/// Current sink, behind a spin lock which is only held to read or replace
+/// the sink so that a sink may itself produce log messages.
+struct SinkCell {
+    lock: AtomicBool,
+    sink: UnsafeCell<Option<Sink>>,
+}
+
+// Safety: the sink is only accessed with the lock held.
+unsafe impl Sync for SinkCell {}
+
+static SINK: SinkCell = SinkCell {
+    lock: AtomicBool::new(false),
+    sink: UnsafeCell::new(None),
+};
+
+impl SinkCell {
+    fn with<R>(&self, f: impl FnOnce(&mut Option<Sink>) -> R) -> R {
+        while self.lock.compare_exchange_weak(false, true, Ordering::Acquire,
+                Ordering::Relaxed).is_err() {
+            core::hint::spin_loop();
+        }
+        let r = f(unsafe { &mut *self.sink.get() });
+        self.lock.store(false, Ordering::Release);
+        r
+    }
+}
+
+/// Callback registered with `wolfSSL_SetLoggingCb()`, passing each message
+/// to the current sink.
+unsafe extern "C" fn logging_cb(level: i32, message: *const c_char) {
+    if message.is_null() {
+        return;
+    }
+    let Some(sink) = SINK.with(|sink| sink.clone()) else {
+        return;
+    };
+    let message = utf8_prefix(unsafe { CStr::from_ptr(message) }.to_bytes());
+    let level = LogLevel::from_raw(level);
+    match sink {
+        Sink::Function(f) => f(level, message),
+        #[cfg(feature = "alloc")]
+        Sink::Closure(f) => f(level, message),
+    }
+}
+
+/// Return the longest valid UTF-8 prefix of `bytes`.
+fn utf8_prefix(bytes: &[u8]) -> &str {
+    match core::str::from_utf8(bytes) {
+        Ok(s) => s,
+        Err(e) => unsafe { core::str::from_utf8_unchecked(&bytes[..e.valid_up_to()]) },
+    }
+}
+
+/// Install `sink` and register the logging callback with wolfSSL.
+fn set_sink(sink: Sink) -> Result<(), Error> {
+    let rc = unsafe { sys::wolfSSL_SetLoggingCb(Some(logging_cb)) };
+    if rc != 0 {
+        return Err(Error::from(rc));
+    }
+    // Drop the previous sink outside of the lock.
+    let previous = SINK.with(|current| current.replace(sink));
+    drop(previous);
+    Ok(())
+}
+
+/// Send wolfSSL debug messages to a function.
+///
+/// Replaces any callback installed previously with this module. Messages
+/// are only produced after [`debugging_on()`] is called. The function may
+/// be called concurrently from any thread using wolfSSL. A panic inside
+/// the function aborts the process since it cannot unwind into C.
+///
+/// # Parameters
+///
+/// * `f`: Function receiving the level and text of each message.
+///
+/// # Returns
+///
+/// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+/// library error code value: `NOT_COMPILED_IN` if the wolfSSL library was
+/// built without `DEBUG_WOLFSSL`.
+///
+/// # Example
+///
+/// ```rust
+/// use wolfssl_wolfcrypt::logging::{self, LogLevel};
+/// fn on_message(level: LogLevel, message: &str) {
+///     if level == LogLevel::Error {
+///         let _ = message;
+///     }
+/// }
+/// if logging::set_logging_callback(on_message).is_ok() {
+///     logging::clear_logging_callback().expect("Error with clear_logging_callback()");
+/// }
+/// ```
+pub fn set_logging_callback(f: fn(LogLevel, &str)) -> Result<(), Error> {
+    set_sink(Sink::Function(f))
+}
+
+/// Send wolfSSL debug messages to a closure.
+///
+/// Replaces any callback installed previously with this module. Messages
+/// are only produced after [`debugging_on()`] is called. The closure may
+/// be called concurrently from any thread using wolfSSL, hence the
+/// `Send + Sync` bound. A panic inside the closure aborts the process since
+/// it cannot unwind into C.
+///
+/// # Parameters
+///
+/// * `f`: Closure receiving the level and text of each message.
+///
+/// # Returns
+///
+/// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+/// library error code value: `NOT_COMPILED_IN` if the wolfSSL library was
+/// built without `DEBUG_WOLFSSL`.
+///
+/// # Example
+///
+/// ```rust
+/// use std::sync::{Arc, Mutex};
+/// use wolfssl_wolfcrypt::logging;
+/// let lines = Arc::new(Mutex::new(Vec::new()));
+/// let sink = lines.clone();
+/// if logging::set_logging_closure(move |_level, message| {
+///     sink.lock().unwrap().push(message.to_string());
+/// }).is_ok() {
+///     logging::clear_logging_callback().expect("Error with clear_logging_callback()");
+/// }
+/// ```
+#[cfg(feature = "alloc")]
+pub fn set_logging_closure<F>(f: F) -> Result<(), Error>
+where
+    F: Fn(LogLevel, &str) + Send + Sync + 'static,
+{
+    set_sink(Sink::Closure(Arc::new(f)))
+}
+
+/// Send wolfSSL debug messages to the `log` crate.
+///
+/// Messages are logged under the `wolfssl` target. `ERROR_LOG` messages are
+/// logged at the `Error` level, `INFO_LOG` messages at the `Info` level,
+/// function entry and exit messages at the `Trace` level and all other
+/// messages at the `Debug` level. Replaces any callback installed previously
+/// with this module. Messages are only produced after [`debugging_on()`] is
+/// called.
+///
+/// # Returns
+///
+/// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+/// library error code value: `NOT_COMPILED_IN` if the wolfSSL library was
+/// built without `DEBUG_WOLFSSL`.
+///
+/// # Example
+///
+/// ```rust
+/// use wolfssl_wolfcrypt::logging;
+/// if logging::forward_to_log().is_ok() {
+///     logging::debugging_on().expect("Error with debugging_on()");
+/// }
+/// ```
+#[cfg(feature = "log")]
+pub fn forward_to_log() -> Result<(), Error> {
+    set_logging_callback(log_message)
+}
+
+#[cfg(feature = "log")]
+fn log_message(level: LogLevel, message: &str) {
+    let level = match level {
+        LogLevel::Error => log::Level::Error,
+        LogLevel::Info => log::Level::Info,
+        LogLevel::Enter | LogLevel::Leave => log::Level::Trace,
+        LogLevel::Cert | LogLevel::Other => log::Level::Debug,
+    };
+    log::log!(target: "wolfssl", level, "{}", message);
+}
+
+/// Stop sending wolfSSL debug messages to the callback installed with this
+/// module, restoring the wolfSSL default output.
+///
+/// # Returns
+///
+/// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+/// library error code value: `NOT_COMPILED_IN` if the wolfSSL library was
+/// built without `DEBUG_WOLFSSL`.
+pub fn clear_logging_callback() -> Result<(), Error> {
+    let rc = unsafe { sys::wolfSSL_SetLoggingCb(None) };
+    if rc != 0 {
+        return Err(Error::from(rc));
+    }
+    let previous = SINK.with(|current| current.take());
+    drop(previous);
+    Ok(())
+}
+
+/// Turn on wolfSSL debug messages.
+///
+/// # Returns
+///
+/// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+/// library error code value: `NOT_COMPILED_IN` if the wolfSSL library was
+/// built without `DEBUG_WOLFSSL`.
+pub fn debugging_on() -> Result<(), Error> {
+    let rc = unsafe { sys::wolfSSL_Debugging_ON() };
+    if rc != 0 {
+        return Err(Error::from(rc));
+    }
+    Ok(())
+}
+
+/// Turn off wolfSSL debug messages.
+pub fn debugging_off() {
+    unsafe { sys::wolfSSL_Debugging_OFF() };
+}
+
+/// Enable or disable tracing of the error codes returned inside the wolfSSL
+/// library (`WOLFSSL_DEBUG_TRACE_ERROR_CODES`).
+///
+/// # Parameters
+///
+/// * `enabled`: Whether error codes are traced.
+///
+/// # Returns
+///
+/// Returns whether tracing was enabled before the call.
+#[cfg(debug_trace_error_codes)]
+pub fn set_trace_error_codes(enabled: bool) -> bool {
+    unsafe { sys::wc_debug_trace_error_codes_set(enabled as i32) != 0 }
+}
+
+/// Check whether tracing of the error codes returned inside the wolfSSL
+/// library is enabled.
+///
+/// # Returns
+///
+/// Returns true if error codes are traced.
+#[cfg(debug_trace_error_codes)]
+pub fn trace_error_codes_enabled() -> bool {
+    unsafe { sys::wc_debug_trace_error_codes_enabled() != 0 }
+}
+
+/// Maximum size of the reason and file strings of an error node.
+#[cfg(error_queue)]
+const ERROR_NODE_STR_SIZE: usize = sys::WOLFSSL_MAX_ERROR_SZ as usize;
+
+/// Entry of the wolfSSL error queue.
+///
+/// The reason and file strings are copied out of the queue, truncated to
+/// `WOLFSSL_MAX_ERROR_SZ - 1` bytes.
+#[cfg(error_queue)]
+#[derive(Clone)]
+pub struct ErrorNode {
+    error: Error,
+    line: i32,
+    file: [u8; ERROR_NODE_STR_SIZE],
+    file_len: usize,
+    reason: [u8; ERROR_NODE_STR_SIZE],
+    reason_len: usize,
+}
+
+#[cfg(error_queue)]
+impl ErrorNode {
+    /// Read an error node with `f`, which calls `wc_PeekErrorNode()` or
+    /// `wc_PullErrorNode()`.
+    fn read(f: impl FnOnce(*mut *const c_char, *mut *const c_char, *mut i32) -> i32)
+            -> Option<Self> {
+        let mut file: *const c_char = core::ptr::null();
+        let mut reason: *const c_char = core::ptr::null();
+        let mut line: i32 = 0;
+        let rc = f(&mut file, &mut reason, &mut line);
+        // The file is only set when a node was found.
+        if file.is_null() {
+            return None;
+        }
+        let mut node = ErrorNode {
+            error: Error::from(rc),
+            line,
+            file: [0u8; ERROR_NODE_STR_SIZE],
+            file_len: 0,
+            reason: [0u8; ERROR_NODE_STR_SIZE],
+            reason_len: 0,
+        };
+        node.file_len = unsafe { copy_c_str(file, &mut node.file) };
+        node.reason_len = unsafe { copy_c_str(reason, &mut node.reason) };
+        Some(node)
+    }
+
+    /// Get the error recorded by this node.
+    pub fn error(&self) -> Error {
+        self.error
+    }
+
+    /// Get the reason string recorded by this node.
+    pub fn reason(&self) -> &str {
+        utf8_prefix(&self.reason[..self.reason_len])
+    }
+
+    /// Get the source file name recorded by this node.
+    pub fn file(&self) -> &str {
+        utf8_prefix(&self.file[..self.file_len])
+    }
+
+    /// Get the source line number recorded by this node.
+    pub fn line(&self) -> i32 {
+        self.line
+    }
+}
+
+#[cfg(error_queue)]
+impl fmt::Debug for ErrorNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ErrorNode")
+            .field("error", &self.error)
+            .field("reason", &self.reason())
+            .field("file", &self.file())
+            .field("line", &self.line)
+            .finish()
+    }
+}
+
+#[cfg(error_queue)]
+impl fmt::Display for ErrorNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {} ({})", self.file(), self.line, self.reason(),
+            self.error.code())
+    }
+}
+
+/// Copy the NUL terminated string at `ptr` into `buf`, truncating it to the
+/// size of `buf`, and return the number of bytes copied.
+#[cfg(error_queue)]
+unsafe fn copy_c_str(ptr: *const c_char, buf: &mut [u8]) -> usize {
+    if ptr.is_null() {
+        return 0;
+    }
+    let bytes = unsafe { CStr::from_ptr(ptr) }.to_bytes();
+    let len = bytes.len().min(buf.len());
+    buf[..len].copy_from_slice(&bytes[..len]);
+    len
+}
+
+/// Get a copy of an entry of the wolfSSL error queue without removing it.
+///
+/// # Parameters
+///
+/// * `idx`: Index of the entry, 0 being the oldest pending entry.
+///
+/// # Returns
+///
+/// Returns the entry, or None if there is no entry at `idx`.
+///
+/// # Example
+///
+/// ```rust
+/// use wolfssl_wolfcrypt::logging;
+/// logging::clear_error_nodes();
+/// assert!(logging::peek_error_node(0).is_none());
+/// ```
+#[cfg(error_queue)]
+pub fn peek_error_node(idx: usize) -> Option<ErrorNode> {
+    let idx = i32::try_from(idx).ok()?;
+    ErrorNode::read(|file, reason, line| unsafe {
+        sys::wc_PeekErrorNode(idx, file, reason, line)
+    })
+}
+
+/// Iterator over the pending entries of the wolfSSL error queue, returned
+/// by [`error_nodes()`].
+#[cfg(error_queue)]
+pub struct ErrorNodes {
+    _private: (),
+}
+
+#[cfg(error_queue)]
+impl Iterator for ErrorNodes {
+    type Item = ErrorNode;
+
+    fn next(&mut self) -> Option<ErrorNode> {
+        ErrorNode::read(|file, reason, line| unsafe {
+            sys::wc_PullErrorNode(file, reason, line)
+        })
+    }
+}
+
+/// Iterate over the pending entries of the wolfSSL error queue, oldest
+/// first, removing each entry from the queue as it is returned.
+///
+/// # Returns
+///
+/// Returns an iterator yielding a copy of each pending entry.
+///
+/// # Example
+///
+/// ```rust
+/// use wolfssl_wolfcrypt::logging;
+/// for node in logging::error_nodes() {
+///     println!("{}", node);
+/// }
+/// ```
+#[cfg(error_queue)]
+pub fn error_nodes() -> ErrorNodes {
+    ErrorNodes { _private: () }
+}
+
+/// Remove all entries from the wolfSSL error queue.
+#[cfg(error_queue)]
+pub fn clear_error_nodes() {
+    unsafe { sys::wc_ClearErrorNodes() };
+}
@@ -0,0 +1,397 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+This module provides control over the memory used by the wolfCrypt library.
+
+Every `new_ex()` style constructor in this crate accepts an
+[`Env`](crate::Env) holding an optional [`HeapHint`] that is passed to
+wolfCrypt as the `heap` argument of its `XMALLOC()` and `XFREE()` calls. No
+heap hint selects the default allocator.
+
+With the `alloc` feature, [`use_rust_allocator()`] routes wolfCrypt
+allocations made without a static memory pool into the Rust global
+allocator through `wolfSSL_SetAllocators()`, so that they are accounted for
+by (and obey the constraints of) the `#[global_allocator]` of the
+application.
+
+When the wolfSSL library is built with `WOLFSSL_STATIC_MEMORY`
+(`--enable-staticmemory`), a [`StaticHeap`] partitions a caller supplied
+buffer into the wolfCrypt memory pool with `wc_LoadStaticMemory()`. A
+`StaticHeap` provides a [`HeapHint`] for any [`Env`](crate::Env), and all
+memory used by the objects created with it then comes from that buffer.
+The hint borrows the `StaticHeap`, and the `Env` and every object created
+with it carry the lifetime of that borrow, so the compiler rejects any
+object that would outlive its memory pool.
+
+# Examples
+
+```rust
+#[cfg(all(static_memory, sha256))]
+{
+use wolfssl_wolfcrypt::Env;
+use wolfssl_wolfcrypt::memory::StaticHeap;
+use wolfssl_wolfcrypt::sha::SHA256;
+use wolfssl_wolfcrypt::WolfCrypt;
+let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+
+let mut buf = vec![0u8; 64 * 1024];
+let heap = StaticHeap::new(&mut buf).expect("Error with StaticHeap::new()");
+let mut sha = SHA256::new_ex(&Env::new(&wolfcrypt).with_heap(heap.hint())).expect("Error with new_ex()");
+sha.update(b"abc").expect("Error with update()");
+let mut hash = [0u8; SHA256::DIGEST_SIZE];
+sha.finalize(&mut hash).expect("Error with finalize()");
+}
+```
+*/
+
+use crate::Error;
+use crate::sys;
+use core::ffi::c_void;
+use core::marker::PhantomData;
+
+/* Memory pool a heap hint allocates from. */
+#[cfg(static_memory)]
+type HeapRef<'a> = &'a StaticHeap<'a>;
+#[cfg(not(static_memory))]
+type HeapRef<'a> = &'a ();
+
+/// Heap hint passed to wolfCrypt when creating an object.
+///
+/// A `HeapHint` is obtained from a [`StaticHeap`] with
+/// [`StaticHeap::hint()`], or from a raw wolfSSL heap pointer with
+/// [`HeapHint::from_raw()`]. It borrows the memory pool for `'a`, and the
+/// objects created with it cannot outlive that borrow.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HeapHint<'a> {
+    ptr: *mut c_void,
+    _heap: PhantomData<HeapRef<'a>>,
+}
+
+/* A heap hint is an opaque token. The wolfSSL memory pool it refers to is
+ * protected by its own mutex. */
+unsafe impl Send for HeapHint<'_> {}
+unsafe impl Sync for HeapHint<'_> {}
+
+impl HeapHint<'_> {
+    /// Create a heap hint from a raw wolfSSL heap pointer.
+    ///
+    /// # Parameters
+    ///
+    /// * `ptr`: Heap pointer passed unchanged to wolfCrypt.
+    ///
+    /// # Returns
+    ///
+    /// Returns the heap hint.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be null or a heap hint understood by the allocator the
+    /// wolfSSL library was built with (e.g. a `WOLFSSL_HEAP_HINT` from
+    /// `wc_LoadStaticMemory()`), and must remain valid for the lifetime
+    /// of the hint, which bounds the objects created with it.
+    pub const unsafe fn from_raw(ptr: *mut c_void) -> Self {
+        HeapHint { ptr, _heap: PhantomData }
+    }
+
+    /// Get the raw heap pointer passed to wolfCrypt.
+    ///
+    /// # Returns
+    ///
+    /// Returns the raw heap pointer.
+    pub const fn as_ptr(self) -> *mut c_void {
+        self.ptr
+    }
+}
+
+/* Size of the header stored in front of each allocation made through the
+ * Rust global allocator. It holds the requested size, needed to rebuild the
+ * layout on free, and keeps the returned pointer aligned like malloc(). */
+#[cfg(all(feature = "alloc", memory_callbacks, not(static_memory)))]
+const ALLOC_HEADER_SIZE: usize = 16;
+
+#[cfg(all(feature = "alloc", memory_callbacks, not(static_memory)))]
+fn alloc_layout(size: usize) -> Option<core::alloc::Layout> {
+    let total = size.checked_add(ALLOC_HEADER_SIZE)?;
+    core::alloc::Layout::from_size_align(total, ALLOC_HEADER_SIZE).ok()
+}
+
+#[cfg(all(feature = "alloc", memory_callbacks, not(static_memory)))]
+unsafe extern "C" fn rust_malloc(size: usize) -> *mut c_void {
+    let Some(layout) = alloc_layout(size) else {
+        return core::ptr::null_mut();
+    };
+    let base = unsafe { alloc::alloc::alloc(layout) };
+    if base.is_null() {
+        return core::ptr::null_mut();
+    }
+    unsafe {
+        (base as *mut usize).write(size);
+        base.add(ALLOC_HEADER_SIZE) as *mut c_void
+    }
+}
+
+#[cfg(all(feature = "alloc", memory_callbacks, not(static_memory)))]
+unsafe extern "C" fn rust_free(ptr: *mut c_void) {
+    if ptr.is_null() {
+        return;
+    }
+    unsafe {
+        let base = (ptr as *mut u8).sub(ALLOC_HEADER_SIZE);
+        let size = (base as *const usize).read();
+        let layout = core::alloc::Layout::from_size_align_unchecked(
+            size + ALLOC_HEADER_SIZE, ALLOC_HEADER_SIZE);
+        alloc::alloc::dealloc(base, layout);
+    }
+}
+
+#[cfg(all(feature = "alloc", memory_callbacks, not(static_memory)))]
+unsafe extern "C" fn rust_realloc(ptr: *mut c_void, size: usize) -> *mut c_void {
+    if ptr.is_null() {
+        return unsafe { rust_malloc(size) };
+    }
+    if alloc_layout(size).is_none() {
+        return core::ptr::null_mut();
+    }
+    unsafe {
+        let base = (ptr as *mut u8).sub(ALLOC_HEADER_SIZE);
+        let old_size = (base as *const usize).read();
+        let old_layout = core::alloc::Layout::from_size_align_unchecked(
+            old_size + ALLOC_HEADER_SIZE, ALLOC_HEADER_SIZE);
+        let new_base = alloc::alloc::realloc(base, old_layout,
+            size + ALLOC_HEADER_SIZE);
+        if new_base.is_null() {
+            /* As with realloc(), the original allocation is left intact. */
+            return core::ptr::null_mut();
+        }
+        (new_base as *mut usize).write(size);
+        new_base.add(ALLOC_HEADER_SIZE) as *mut c_void
+    }
+}
+
+/// Route wolfCrypt dynamic memory allocations into the Rust global
+/// allocator.
+///
+/// Installs allocation callbacks with `wolfSSL_SetAllocators()` so that
+/// `XMALLOC()`, `XREALLOC()` and `XFREE()` use the `#[global_allocator]` of
+/// the application instead of the C library `malloc()`. Not available when
+/// the wolfSSL library is built with `WOLFSSL_STATIC_MEMORY`, which does
+/// not use these callbacks.
+///
+/// # Returns
+///
+/// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+/// library error code value.
+///
+/// # Safety
+///
+/// Memory allocated by one allocator cannot be freed by the other, so this
+/// must be called before wolfCrypt makes any allocation (before creating a
+/// [`crate::WolfCrypt`] guard or any object), and the
+/// allocators must not be changed again while wolfCrypt memory is still
+/// allocated. The wolfSSL library must not be built with
+/// `WOLFSSL_DEBUG_MEMORY`, which changes the callback signatures.
+///
+/// # Example
+///
+/// ```rust
+/// #[cfg(all(feature = "alloc", memory_callbacks, not(static_memory)))]
+/// unsafe {
+///     wolfssl_wolfcrypt::memory::use_rust_allocator().expect("Error with use_rust_allocator()");
+/// }
+/// ```
+#[cfg(all(feature = "alloc", memory_callbacks, not(static_memory)))]
+pub unsafe fn use_rust_allocator() -> Result<(), Error> {
+    let rc = unsafe {
+        sys::wolfSSL_SetAllocators(Some(rust_malloc), Some(rust_free),
+            Some(rust_realloc))
+    };
+    if rc != 0 {
+        return Err(Error::from(rc));
+    }
+    Ok(())
+}
+
+/// Restore the default wolfCrypt allocator (the C library `malloc()`).
+///
+/// # Returns
+///
+/// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+/// library error code value.
+///
+/// # Safety
+///
+/// No memory allocated by wolfCrypt through previously installed allocation
+/// callbacks may still be allocated.
+#[cfg(memory_callbacks)]
+pub unsafe fn use_default_allocator() -> Result<(), Error> {
+    let rc = unsafe { sys::wolfSSL_SetAllocators(None, None, None) };
+    if rc != 0 {
+        return Err(Error::from(rc));
+    }
+    Ok(())
+}
+
+/// Memory pool backed by a caller supplied buffer.
+///
+/// Wraps `wc_LoadStaticMemory()` and `wc_UnloadStaticMemory()`. The buffer
+/// is borrowed for the lifetime of the `StaticHeap`, and the objects created
+/// with its [`hint()`](StaticHeap::hint) borrow the `StaticHeap` itself.
+#[cfg(static_memory)]
+pub struct StaticHeap<'a> {
+    hint: *mut sys::WOLFSSL_HEAP_HINT,
+    _buf: PhantomData<&'a mut [u8]>,
+}
+
+/* The memory pool is protected by its own mutex (single threaded builds of
+ * the wolfSSL library have no other thread to share it with). */
+#[cfg(static_memory)]
+unsafe impl Send for StaticHeap<'_> {}
+#[cfg(static_memory)]
+unsafe impl Sync for StaticHeap<'_> {}
+
+#[cfg(static_memory)]
+impl<'a> StaticHeap<'a> {
+    /// Create a general purpose memory pool in the given buffer.
+    ///
+    /// The buffer is partitioned into the default wolfSSL bucket sizes
+    /// (`WOLFMEM_BUCKETS` and `WOLFMEM_DIST`).
+    ///
+    /// # Parameters
+    ///
+    /// * `buf`: Buffer holding the memory pool and its bookkeeping.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(StaticHeap) on success or Err(e) containing the
+    /// wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(static_memory)]
+    /// {
+    /// use wolfssl_wolfcrypt::memory::StaticHeap;
+    /// let mut buf = [0u8; 16 * 1024];
+    /// let heap = StaticHeap::new(&mut buf).expect("Error with StaticHeap::new()");
+    /// }
+    /// ```
+    pub fn new(buf: &'a mut [u8]) -> Result<Self, Error> {
+        Self::new_ex(buf, sys::WOLFMEM_GENERAL as i32, 0)
+    }
+
+    /// Create a memory pool in the given buffer with the given flags.
+    ///
+    /// # Parameters
+    ///
+    /// * `buf`: Buffer holding the memory pool and its bookkeeping.
+    /// * `flag`: Pool type and options (`WOLFMEM_GENERAL`,
+    ///   `WOLFMEM_TRACK_STATS`, ...).
+    /// * `max`: Maximum number of concurrent handshakes or IO connections
+    ///   (0 for no limit).
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(StaticHeap) on success or Err(e) containing the
+    /// wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(static_memory)]
+    /// {
+    /// use wolfssl_wolfcrypt::memory::StaticHeap;
+    /// use wolfssl_wolfcrypt::sys;
+    /// let mut buf = [0u8; 16 * 1024];
+    /// let flag = (sys::WOLFMEM_GENERAL | sys::WOLFMEM_TRACK_STATS) as i32;
+    /// let heap = StaticHeap::new_ex(&mut buf, flag, 0).expect("Error with StaticHeap::new_ex()");
+    /// }
+    /// ```
+    pub fn new_ex(buf: &'a mut [u8], flag: i32, max: i32) -> Result<Self, Error> {
+        /* wolfSSL places its bookkeeping structures at the start of the
+         * buffer, so skip any unaligned leading bytes. */
+        let align = core::mem::align_of::<sys::WOLFSSL_HEAP>();
+        let offset = buf.as_ptr().align_offset(align);
+        if offset > buf.len() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+        }
+        let buf = &mut buf[offset..];
+        let buf_size = u32::try_from(buf.len())
+            .map_err(|_| Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG))?;
+        let mut hint: *mut sys::WOLFSSL_HEAP_HINT = core::ptr::null_mut();
+        let rc = unsafe {
+            sys::wc_LoadStaticMemory(&mut hint, buf.as_mut_ptr(), buf_size,
+                flag, max)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(StaticHeap { hint, _buf: PhantomData })
+    }
+
+    /// Get the heap hint for creating objects in this memory pool.
+    ///
+    /// The hint borrows the `StaticHeap`, so it cannot be dropped while an
+    /// object created with the hint exists.
+    ///
+    /// # Returns
+    ///
+    /// Returns the heap hint to create objects with through
+    /// [`Env::with_heap()`](crate::Env::with_heap).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(all(static_memory, random))]
+    /// {
+    /// use wolfssl_wolfcrypt::Env;
+    /// use wolfssl_wolfcrypt::memory::StaticHeap;
+    /// use wolfssl_wolfcrypt::random::RNG;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut buf = [0u8; 64 * 1024];
+    /// let heap = StaticHeap::new(&mut buf).expect("Error with StaticHeap::new()");
+    /// let rng = RNG::new_ex(&Env::new(&wolfcrypt).with_heap(heap.hint())).expect("Error with RNG::new_ex()");
+    /// drop(rng);
+    /// }
+    /// ```
+    pub fn hint(&self) -> HeapHint<'_> {
+        HeapHint { ptr: self.hint as *mut c_void, _heap: PhantomData }
+    }
+
+    /// Use this memory pool for all wolfCrypt allocations made without a
+    /// heap hint.
+    ///
+    /// Objects created without a heap hint do not borrow the memory pool,
+    /// so it must live for `'static`.
+    ///
+    /// Calls `wolfSSL_SetGlobalHeapHint()`.
+    pub fn set_global(&'static self) {
+        unsafe { sys::wolfSSL_SetGlobalHeapHint(self.hint as *mut c_void) };
+    }
+}
+
+#[cfg(static_memory)]
+impl Drop for StaticHeap<'_> {
+    /// Release the memory pool with `wc_UnloadStaticMemory()`.
+    fn drop(&mut self) {
+        unsafe { sys::wc_UnloadStaticMemory(self.hint) };
+    }
+}
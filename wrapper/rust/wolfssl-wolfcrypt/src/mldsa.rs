@@ -42,8 +42,10 @@ Three security parameter sets are supported, selected via
 {
 use wolfssl_wolfcrypt::random::RNG;
 use wolfssl_wolfcrypt::mldsa::MlDsa;
-let mut rng = RNG::new().expect("RNG creation failed");
-let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng)
+use wolfssl_wolfcrypt::WolfCrypt;
+let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+let mut rng = RNG::new(&wolfcrypt).expect("RNG creation failed");
+let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng, &wolfcrypt)
     .expect("Key generation failed");
 let message = b"Hello, ML-DSA!";
 let mut sig = vec![0u8; key.sig_size().expect("sig_size failed")];
@@ -58,7 +60,10 @@ assert!(valid);
 
 #![cfg(mldsa)]
 
+use crate::Error;
+use crate::memory::HeapHint;
 use crate::sys;
+use core::marker::PhantomData;
 #[cfg(all(random, any(mldsa_make_key, mldsa_sign)))]
 use crate::random::RNG;
 use core::mem::MaybeUninit;
@@ -70,11 +75,14 @@ use core::mem::MaybeUninit;
 ///
 /// An instance is created with [`MlDsa::generate()`],
 /// [`MlDsa::generate_from_seed()`], or [`MlDsa::new()`].
-pub struct MlDsa {
-    ws_key: sys::wc_MlDsaKey,
+pub struct MlDsa<'a> {
+    pub(crate) ws_key: sys::wc_MlDsaKey,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 
-impl MlDsa {
+impl<'a> MlDsa<'a> {
     /// ML-DSA-44 security parameter set (NIST Level 2).
     pub const LEVEL_44: u8 = sys::WC_ML_DSA_44 as u8;
     /// ML-DSA-65 security parameter set (NIST Level 3).
@@ -140,6 +148,7 @@ impl MlDsa {
     /// * `level`: Security parameter set. One of [`MlDsa::LEVEL_44`],
     ///   [`MlDsa::LEVEL_65`], or [`MlDsa::LEVEL_87`].
     /// * `rng`: `RNG` instance to use for random number generation.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
@@ -153,14 +162,16 @@ impl MlDsa {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::mldsa::MlDsa;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng)
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng, &wolfcrypt)
     ///     .expect("Error with generate()");
     /// }
     /// ```
     #[cfg(all(mldsa_make_key, random))]
-    pub fn generate(level: u8, rng: &RNG) -> Result<Self, i32> {
-        Self::generate_ex(level, rng, None, None)
+    pub fn generate(level: u8, rng: &RNG, wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::generate_ex(level, rng, &crate::Env::new(wolfcrypt))
     }
 
     /// Generate a new ML-DSA key pair with optional heap hint and device ID.
@@ -170,8 +181,7 @@ impl MlDsa {
     /// * `level`: Security parameter set. One of [`MlDsa::LEVEL_44`],
     ///   [`MlDsa::LEVEL_65`], or [`MlDsa::LEVEL_87`].
     /// * `rng`: `RNG` instance to use for random number generation.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id`: Optional device ID for crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -183,10 +193,13 @@ impl MlDsa {
     /// ```rust
     /// #[cfg(all(mldsa, mldsa_make_key, random))]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::mldsa::MlDsa;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let key = MlDsa::generate_ex(MlDsa::LEVEL_44, &mut rng, None, None)
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let key = MlDsa::generate_ex(MlDsa::LEVEL_44, &mut rng, &Env::new(&wolfcrypt))
     ///     .expect("Error with generate_ex()");
     /// }
     /// ```
@@ -194,17 +207,16 @@ impl MlDsa {
     pub fn generate_ex(
         level: u8,
         rng: &RNG,
-        heap: Option<*mut core::ffi::c_void>,
-        dev_id: Option<i32>,
-    ) -> Result<Self, i32> {
-        let mut key = Self::new_ex(heap, dev_id)?;
+        env: &crate::Env,
+    ) -> Result<Self, Error> {
+        let mut key = Self::new_ex(env)?;
         let rc = unsafe { sys::wc_MlDsaKey_SetParams(&mut key.ws_key, level) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let rc = unsafe { sys::wc_MlDsaKey_MakeKey(&mut key.ws_key, rng.wc_rng) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(key)
     }
@@ -219,6 +231,7 @@ impl MlDsa {
     /// * `level`: Security parameter set. One of [`MlDsa::LEVEL_44`],
     ///   [`MlDsa::LEVEL_65`], or [`MlDsa::LEVEL_87`].
     /// * `seed`: Seed bytes. Must be `MLDSA_SEED_SZ` (32) bytes.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
@@ -231,14 +244,16 @@ impl MlDsa {
     /// #[cfg(all(mldsa, mldsa_make_key_from_seed))]
     /// {
     /// use wolfssl_wolfcrypt::mldsa::MlDsa;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let seed = [0x42u8; 32];
-    /// let key = MlDsa::generate_from_seed(MlDsa::LEVEL_44, &seed)
+    /// let key = MlDsa::generate_from_seed(MlDsa::LEVEL_44, &seed, &wolfcrypt)
     ///     .expect("Error with generate_from_seed()");
     /// }
     /// ```
     #[cfg(mldsa_make_key_from_seed)]
-    pub fn generate_from_seed(level: u8, seed: &[u8]) -> Result<Self, i32> {
-        Self::generate_from_seed_ex(level, seed, None, None)
+    pub fn generate_from_seed(level: u8, seed: &[u8], wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::generate_from_seed_ex(level, seed, &crate::Env::new(wolfcrypt))
     }
 
     /// Generate an ML-DSA key pair from a fixed seed with optional heap hint
@@ -249,8 +264,7 @@ impl MlDsa {
     /// * `level`: Security parameter set. One of [`MlDsa::LEVEL_44`],
     ///   [`MlDsa::LEVEL_65`], or [`MlDsa::LEVEL_87`].
     /// * `seed`: Seed bytes. Must be `MLDSA_SEED_SZ` (32) bytes.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id`: Optional device ID for crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -262,9 +276,12 @@ impl MlDsa {
     /// ```rust
     /// #[cfg(all(mldsa, mldsa_make_key_from_seed))]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::mldsa::MlDsa;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let seed = [0x42u8; 32];
-    /// let key = MlDsa::generate_from_seed_ex(MlDsa::LEVEL_44, &seed, None, None)
+    /// let key = MlDsa::generate_from_seed_ex(MlDsa::LEVEL_44, &seed, &Env::new(&wolfcrypt))
     ///     .expect("Error with generate_from_seed_ex()");
     /// }
     /// ```
@@ -272,22 +289,21 @@ impl MlDsa {
     pub fn generate_from_seed_ex(
         level: u8,
         seed: &[u8],
-        heap: Option<*mut core::ffi::c_void>,
-        dev_id: Option<i32>,
-    ) -> Result<Self, i32> {
+        env: &crate::Env,
+    ) -> Result<Self, Error> {
         if seed.len() != Self::MLDSA_SEED_SZ {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
-        let mut key = Self::new_ex(heap, dev_id)?;
+        let mut key = Self::new_ex(env)?;
         let rc = unsafe { sys::wc_MlDsaKey_SetParams(&mut key.ws_key, level) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let rc = unsafe {
             sys::wc_MlDsaKey_MakeKeyFromSeed(&mut key.ws_key, seed.as_ptr())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(key)
     }
@@ -297,6 +313,10 @@ impl MlDsa {
     /// The security level and key material can be set afterwards using
     /// [`MlDsa::set_level()`] and one of the import functions.
     ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
     /// # Returns
     ///
     /// Returns either Ok(MlDsa) containing the key instance or Err(e)
@@ -308,11 +328,13 @@ impl MlDsa {
     /// #[cfg(mldsa)]
     /// {
     /// use wolfssl_wolfcrypt::mldsa::MlDsa;
-    /// let key = MlDsa::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let key = MlDsa::new(&wolfcrypt).expect("Error with new()");
     /// }
     /// ```
-    pub fn new() -> Result<Self, i32> {
-        Self::new_ex(None, None)
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&crate::Env::new(wolfcrypt))
     }
 
     /// Create and initialize a new ML-DSA key instance with optional heap
@@ -320,8 +342,7 @@ impl MlDsa {
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id`: Optional device ID for crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -333,29 +354,26 @@ impl MlDsa {
     /// ```rust
     /// #[cfg(mldsa)]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::mldsa::MlDsa;
-    /// let key = MlDsa::new_ex(None, None).expect("Error with new_ex()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let key = MlDsa::new_ex(&Env::new(&wolfcrypt)).expect("Error with new_ex()");
     /// }
     /// ```
     pub fn new_ex(
-        heap: Option<*mut core::ffi::c_void>,
-        dev_id: Option<i32>,
-    ) -> Result<Self, i32> {
+        env: &crate::Env,
+    ) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let mut ws_key: MaybeUninit<sys::wc_MlDsaKey> = MaybeUninit::uninit();
-        let heap = match heap {
-            Some(h) => h,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(id) => id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_MlDsaKey_Init(ws_key.as_mut_ptr(), heap, dev_id) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let ws_key = unsafe { ws_key.assume_init() };
-        Ok(MlDsa { ws_key })
+        Ok(MlDsa { ws_key, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData })
     }
 
     /// Set the security parameter level for this key.
@@ -379,14 +397,16 @@ impl MlDsa {
     /// #[cfg(mldsa)]
     /// {
     /// use wolfssl_wolfcrypt::mldsa::MlDsa;
-    /// let mut key = MlDsa::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut key = MlDsa::new(&wolfcrypt).expect("Error with new()");
     /// key.set_level(MlDsa::LEVEL_65).expect("Error with set_level()");
     /// }
     /// ```
-    pub fn set_level(&mut self, level: u8) -> Result<(), i32> {
+    pub fn set_level(&mut self, level: u8) -> Result<(), Error> {
         let rc = unsafe { sys::wc_MlDsaKey_SetParams(&mut self.ws_key, level) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -404,17 +424,19 @@ impl MlDsa {
     /// #[cfg(mldsa)]
     /// {
     /// use wolfssl_wolfcrypt::mldsa::MlDsa;
-    /// let mut key = MlDsa::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut key = MlDsa::new(&wolfcrypt).expect("Error with new()");
     /// key.set_level(MlDsa::LEVEL_87).expect("Error with set_level()");
     /// let level = key.get_level().expect("Error with get_level()");
     /// assert_eq!(level, MlDsa::LEVEL_87);
     /// }
     /// ```
-    pub fn get_level(&mut self) -> Result<u8, i32> {
+    pub fn get_level(&mut self) -> Result<u8, Error> {
         let mut level = 0u8;
         let rc = unsafe { sys::wc_MlDsaKey_GetParams(&mut self.ws_key, &mut level) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(level)
     }
@@ -433,17 +455,19 @@ impl MlDsa {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::mldsa::MlDsa;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng)
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng, &wolfcrypt)
     ///     .expect("Error with generate()");
     /// let sz = key.size().expect("Error with size()");
     /// assert_eq!(sz, MlDsa::LEVEL2_KEY_SIZE);
     /// }
     /// ```
-    pub fn size(&mut self) -> Result<usize, i32> {
+    pub fn size(&mut self) -> Result<usize, Error> {
         let rc = unsafe { sys::wc_MlDsaKey_Size(&mut self.ws_key) };
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(rc as usize)
     }
@@ -463,17 +487,19 @@ impl MlDsa {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::mldsa::MlDsa;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng)
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng, &wolfcrypt)
     ///     .expect("Error with generate()");
     /// let sz = key.priv_size().expect("Error with priv_size()");
     /// assert_eq!(sz, MlDsa::LEVEL2_PRV_KEY_SIZE);
     /// }
     /// ```
-    pub fn priv_size(&mut self) -> Result<usize, i32> {
+    pub fn priv_size(&mut self) -> Result<usize, Error> {
         let rc = unsafe { sys::wc_MlDsaKey_PrivSize(&mut self.ws_key) };
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(rc as usize)
     }
@@ -492,17 +518,19 @@ impl MlDsa {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::mldsa::MlDsa;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng)
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng, &wolfcrypt)
     ///     .expect("Error with generate()");
     /// let sz = key.pub_size().expect("Error with pub_size()");
     /// assert_eq!(sz, MlDsa::LEVEL2_PUB_KEY_SIZE);
     /// }
     /// ```
-    pub fn pub_size(&mut self) -> Result<usize, i32> {
+    pub fn pub_size(&mut self) -> Result<usize, Error> {
         let rc = unsafe { sys::wc_MlDsaKey_PubSize(&mut self.ws_key) };
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(rc as usize)
     }
@@ -521,17 +549,19 @@ impl MlDsa {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::mldsa::MlDsa;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng)
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng, &wolfcrypt)
     ///     .expect("Error with generate()");
     /// let sz = key.sig_size().expect("Error with sig_size()");
     /// assert_eq!(sz, MlDsa::LEVEL2_SIG_SIZE);
     /// }
     /// ```
-    pub fn sig_size(&mut self) -> Result<usize, i32> {
+    pub fn sig_size(&mut self) -> Result<usize, Error> {
         let rc = unsafe { sys::wc_MlDsaKey_SigSize(&mut self.ws_key) };
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(rc as usize)
     }
@@ -550,17 +580,19 @@ impl MlDsa {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::mldsa::MlDsa;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng)
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng, &wolfcrypt)
     ///     .expect("Error with generate()");
     /// key.check_key().expect("Error with check_key()");
     /// }
     /// ```
     #[cfg(mldsa_check_key)]
-    pub fn check_key(&mut self) -> Result<(), i32> {
+    pub fn check_key(&mut self) -> Result<(), Error> {
         let rc = unsafe { sys::wc_MlDsaKey_CheckKey(&mut self.ws_key) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -583,24 +615,26 @@ impl MlDsa {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::mldsa::MlDsa;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng)
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng, &wolfcrypt)
     ///     .expect("Error with generate()");
     /// let mut pub_buf = vec![0u8; key.pub_size().unwrap()];
     /// key.export_public(&mut pub_buf).expect("Error with export_public()");
-    /// let mut key2 = MlDsa::new().expect("Error with new()");
+    /// let mut key2 = MlDsa::new(&wolfcrypt).expect("Error with new()");
     /// key2.set_level(MlDsa::LEVEL_44).expect("Error with set_level()");
     /// key2.import_public(&pub_buf).expect("Error with import_public()");
     /// }
     /// ```
     #[cfg(mldsa_import)]
-    pub fn import_public(&mut self, public: &[u8]) -> Result<(), i32> {
+    pub fn import_public(&mut self, public: &[u8]) -> Result<(), Error> {
         let public_size = crate::buffer_len_to_u32(public.len())?;
         let rc = unsafe {
             sys::wc_MlDsaKey_ImportPubRaw(&mut self.ws_key, public.as_ptr(), public_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -626,24 +660,26 @@ impl MlDsa {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::mldsa::MlDsa;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng)
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng, &wolfcrypt)
     ///     .expect("Error with generate()");
     /// let mut priv_buf = vec![0u8; key.size().unwrap()];
     /// key.export_private(&mut priv_buf).expect("Error with export_private()");
-    /// let mut key2 = MlDsa::new().expect("Error with new()");
+    /// let mut key2 = MlDsa::new(&wolfcrypt).expect("Error with new()");
     /// key2.set_level(MlDsa::LEVEL_44).expect("Error with set_level()");
     /// key2.import_private(&priv_buf).expect("Error with import_private()");
     /// }
     /// ```
     #[cfg(mldsa_import)]
-    pub fn import_private(&mut self, private: &[u8]) -> Result<(), i32> {
+    pub fn import_private(&mut self, private: &[u8]) -> Result<(), Error> {
         let private_size = crate::buffer_len_to_u32(private.len())?;
         let rc = unsafe {
             sys::wc_MlDsaKey_ImportPrivRaw(&mut self.ws_key, private.as_ptr(), private_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -667,19 +703,21 @@ impl MlDsa {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::mldsa::MlDsa;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng)
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng, &wolfcrypt)
     ///     .expect("Error with generate()");
     /// let mut priv_buf = vec![0u8; key.size().unwrap()];
     /// let mut pub_buf = vec![0u8; key.pub_size().unwrap()];
     /// key.export_key(&mut priv_buf, &mut pub_buf).expect("Error with export_key()");
-    /// let mut key2 = MlDsa::new().expect("Error with new()");
+    /// let mut key2 = MlDsa::new(&wolfcrypt).expect("Error with new()");
     /// key2.set_level(MlDsa::LEVEL_44).expect("Error with set_level()");
     /// key2.import_key(&priv_buf, &pub_buf).expect("Error with import_key()");
     /// }
     /// ```
     #[cfg(mldsa_import)]
-    pub fn import_key(&mut self, private: &[u8], public: &[u8]) -> Result<(), i32> {
+    pub fn import_key(&mut self, private: &[u8], public: &[u8]) -> Result<(), Error> {
         let private_size = crate::buffer_len_to_u32(private.len())?;
         let public_size = crate::buffer_len_to_u32(public.len())?;
         let rc = unsafe {
@@ -690,7 +728,7 @@ impl MlDsa {
             )
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -714,8 +752,10 @@ impl MlDsa {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::mldsa::MlDsa;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng)
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng, &wolfcrypt)
     ///     .expect("Error with generate()");
     /// let mut pub_buf = vec![0u8; key.pub_size().unwrap()];
     /// let written = key.export_public(&mut pub_buf).expect("Error with export_public()");
@@ -723,13 +763,13 @@ impl MlDsa {
     /// }
     /// ```
     #[cfg(mldsa_export)]
-    pub fn export_public(&mut self, public: &mut [u8]) -> Result<usize, i32> {
+    pub fn export_public(&mut self, public: &mut [u8]) -> Result<usize, Error> {
         let mut public_size = crate::buffer_len_to_u32(public.len())?;
         let rc = unsafe {
             sys::wc_MlDsaKey_ExportPubRaw(&mut self.ws_key, public.as_mut_ptr(), &mut public_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(public_size as usize)
     }
@@ -753,8 +793,10 @@ impl MlDsa {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::mldsa::MlDsa;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng)
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng, &wolfcrypt)
     ///     .expect("Error with generate()");
     /// let mut priv_buf = vec![0u8; key.size().unwrap()];
     /// let written = key.export_private(&mut priv_buf).expect("Error with export_private()");
@@ -762,7 +804,7 @@ impl MlDsa {
     /// }
     /// ```
     #[cfg(mldsa_export)]
-    pub fn export_private(&mut self, private: &mut [u8]) -> Result<usize, i32> {
+    pub fn export_private(&mut self, private: &mut [u8]) -> Result<usize, Error> {
         let mut private_size = crate::buffer_len_to_u32(private.len())?;
         let rc = unsafe {
             sys::wc_MlDsaKey_ExportPrivRaw(
@@ -770,7 +812,7 @@ impl MlDsa {
             )
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(private_size as usize)
     }
@@ -797,8 +839,10 @@ impl MlDsa {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::mldsa::MlDsa;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng)
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng, &wolfcrypt)
     ///     .expect("Error with generate()");
     /// let mut priv_buf = vec![0u8; key.size().unwrap()];
     /// let mut pub_buf = vec![0u8; key.pub_size().unwrap()];
@@ -806,7 +850,7 @@ impl MlDsa {
     /// }
     /// ```
     #[cfg(mldsa_export)]
-    pub fn export_key(&mut self, private: &mut [u8], public: &mut [u8]) -> Result<(), i32> {
+    pub fn export_key(&mut self, private: &mut [u8], public: &mut [u8]) -> Result<(), Error> {
         let mut private_size = crate::buffer_len_to_u32(private.len())?;
         let mut public_size = crate::buffer_len_to_u32(public.len())?;
         let rc = unsafe {
@@ -817,7 +861,7 @@ impl MlDsa {
             )
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -844,8 +888,10 @@ impl MlDsa {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::mldsa::MlDsa;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng)
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng, &wolfcrypt)
     ///     .expect("Error with generate()");
     /// let message = b"Hello, ML-DSA!";
     /// let mut sig = vec![0u8; key.sig_size().unwrap()];
@@ -860,7 +906,7 @@ impl MlDsa {
         msg: &[u8],
         sig: &mut [u8],
         rng: &RNG,
-    ) -> Result<usize, i32> {
+    ) -> Result<usize, Error> {
         let msg_len = crate::buffer_len_to_u32(msg.len())?;
         let mut sig_len = crate::buffer_len_to_u32(sig.len())?;
         let rc = unsafe {
@@ -873,7 +919,7 @@ impl MlDsa {
             )
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(sig_len as usize)
     }
@@ -901,8 +947,10 @@ impl MlDsa {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::mldsa::MlDsa;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng)
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng, &wolfcrypt)
     ///     .expect("Error with generate()");
     /// let message = b"Hello, ML-DSA!";
     /// let ctx = b"my context";
@@ -918,9 +966,9 @@ impl MlDsa {
         msg: &[u8],
         sig: &mut [u8],
         rng: &RNG,
-    ) -> Result<usize, i32> {
+    ) -> Result<usize, Error> {
         if ctx.len() > 255 {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let ctx_len = ctx.len() as u8;
         let msg_len = crate::buffer_len_to_u32(msg.len())?;
@@ -935,7 +983,7 @@ impl MlDsa {
             )
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(sig_len as usize)
     }
@@ -967,9 +1015,9 @@ impl MlDsa {
         hash: &[u8],
         sig: &mut [u8],
         rng: &RNG,
-    ) -> Result<usize, i32> {
+    ) -> Result<usize, Error> {
         if ctx.len() > 255 {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let ctx_len = ctx.len() as u8;
         let hash_len = crate::buffer_len_to_u32(hash.len())?;
@@ -985,7 +1033,7 @@ impl MlDsa {
             )
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(sig_len as usize)
     }
@@ -1012,8 +1060,10 @@ impl MlDsa {
     /// #[cfg(all(mldsa, mldsa_make_key_from_seed, mldsa_sign_with_seed))]
     /// {
     /// use wolfssl_wolfcrypt::mldsa::MlDsa;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let key_seed = [0x42u8; 32];
-    /// let mut key = MlDsa::generate_from_seed(MlDsa::LEVEL_44, &key_seed)
+    /// let mut key = MlDsa::generate_from_seed(MlDsa::LEVEL_44, &key_seed, &wolfcrypt)
     ///     .expect("Error with generate_from_seed()");
     /// let message = b"Hello, ML-DSA!";
     /// let sign_seed = [0x55u8; 32];
@@ -1028,9 +1078,9 @@ impl MlDsa {
         msg: &[u8],
         sig: &mut [u8],
         seed: &[u8],
-    ) -> Result<usize, i32> {
+    ) -> Result<usize, Error> {
         if seed.len() != sys::MLDSA_RND_SZ as usize {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let msg_len = crate::buffer_len_to_u32(msg.len())?;
         let mut sig_len = crate::buffer_len_to_u32(sig.len())?;
@@ -1044,7 +1094,7 @@ impl MlDsa {
             )
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(sig_len as usize)
     }
@@ -1069,12 +1119,12 @@ impl MlDsa {
         msg: &[u8],
         sig: &mut [u8],
         seed: &[u8],
-    ) -> Result<usize, i32> {
+    ) -> Result<usize, Error> {
         if ctx.len() > 255 {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         if seed.len() != sys::MLDSA_RND_SZ as usize {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let ctx_len = ctx.len() as u8;
         let msg_len = crate::buffer_len_to_u32(msg.len())?;
@@ -1089,7 +1139,7 @@ impl MlDsa {
             )
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(sig_len as usize)
     }
@@ -1117,12 +1167,12 @@ impl MlDsa {
         hash: &[u8],
         sig: &mut [u8],
         seed: &[u8],
-    ) -> Result<usize, i32> {
+    ) -> Result<usize, Error> {
         if ctx.len() > 255 {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         if seed.len() != sys::MLDSA_RND_SZ as usize {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let ctx_len = ctx.len() as u8;
         let hash_len = crate::buffer_len_to_u32(hash.len())?;
@@ -1138,7 +1188,7 @@ impl MlDsa {
             )
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(sig_len as usize)
     }
@@ -1162,8 +1212,10 @@ impl MlDsa {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::mldsa::MlDsa;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng)
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng, &wolfcrypt)
     ///     .expect("Error with generate()");
     /// let message = b"Hello, ML-DSA!";
     /// let mut sig = vec![0u8; key.sig_size().unwrap()];
@@ -1175,7 +1227,7 @@ impl MlDsa {
     /// }
     /// ```
     #[cfg(mldsa_verify)]
-    pub fn verify_msg(&mut self, sig: &[u8], msg: &[u8]) -> Result<bool, i32> {
+    pub fn verify_msg(&mut self, sig: &[u8], msg: &[u8]) -> Result<bool, Error> {
         let sig_len = crate::buffer_len_to_u32(sig.len())?;
         let msg_len = crate::buffer_len_to_u32(msg.len())?;
         let mut res = 0i32;
@@ -1189,7 +1241,7 @@ impl MlDsa {
             )
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(res == 1)
     }
@@ -1214,8 +1266,10 @@ impl MlDsa {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::mldsa::MlDsa;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng)
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng, &wolfcrypt)
     ///     .expect("Error with generate()");
     /// let message = b"Hello, ML-DSA!";
     /// let ctx = b"my context";
@@ -1228,9 +1282,9 @@ impl MlDsa {
     /// }
     /// ```
     #[cfg(mldsa_verify)]
-    pub fn verify_ctx_msg(&mut self, sig: &[u8], ctx: &[u8], msg: &[u8]) -> Result<bool, i32> {
+    pub fn verify_ctx_msg(&mut self, sig: &[u8], ctx: &[u8], msg: &[u8]) -> Result<bool, Error> {
         if ctx.len() > 255 {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let sig_len = crate::buffer_len_to_u32(sig.len())?;
         let ctx_len = ctx.len() as u8;
@@ -1246,7 +1300,7 @@ impl MlDsa {
             )
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(res == 1)
     }
@@ -1274,9 +1328,9 @@ impl MlDsa {
         ctx: &[u8],
         hash_alg: i32,
         hash: &[u8],
-    ) -> Result<bool, i32> {
+    ) -> Result<bool, Error> {
         if ctx.len() > 255 {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let sig_len = crate::buffer_len_to_u32(sig.len())?;
         let ctx_len = ctx.len() as u8;
@@ -1293,19 +1347,19 @@ impl MlDsa {
             )
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(res == 1)
     }
 }
 
-impl MlDsa {
+impl<'a> MlDsa<'a> {
     fn zeroize(&mut self) {
         unsafe { crate::zeroize_raw(&mut self.ws_key); }
     }
 }
 
-impl Drop for MlDsa {
+impl Drop for MlDsa<'_> {
     /// Safely free the underlying wolfSSL ML-DSA key context.
     ///
     /// This calls `wc_MlDsaKey_Free()`. The Rust Drop trait guarantees this
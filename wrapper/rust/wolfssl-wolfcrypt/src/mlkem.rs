@@ -42,8 +42,10 @@ construction time:
 {
 use wolfssl_wolfcrypt::random::RNG;
 use wolfssl_wolfcrypt::mlkem::MlKem;
-let mut rng = RNG::new().expect("RNG creation failed");
-let mut alice = MlKem::generate(MlKem::TYPE_768, &mut rng)
+use wolfssl_wolfcrypt::WolfCrypt;
+let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+let mut rng = RNG::new(&wolfcrypt).expect("RNG creation failed");
+let mut alice = MlKem::generate(MlKem::TYPE_768, &mut rng, &wolfcrypt)
     .expect("Key generation failed");
 let ct_size = alice.cipher_text_size().expect("cipher_text_size failed");
 let ss_size = alice.shared_secret_size().expect("shared_secret_size failed");
@@ -61,7 +63,10 @@ assert_eq!(ss_alice, ss_bob);
 
 #![cfg(mlkem)]
 
+use crate::Error;
+use crate::memory::HeapHint;
 use crate::sys;
+use core::marker::PhantomData;
 #[cfg(random)]
 use crate::random::RNG;
 
@@ -76,11 +81,14 @@ use crate::random::RNG;
 /// This struct does not implement Send or Sync because it is not safe in the
 /// general case to access the underlying C API from multiple threads
 /// concurrently.
-pub struct MlKem {
+pub struct MlKem<'a> {
     ws_key: *mut sys::MlKemKey,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 
-impl MlKem {
+impl<'a> MlKem<'a> {
     /// ML-KEM-512 key type (NIST Security Level 1).
     pub const TYPE_512: i32 = sys::WC_ML_KEM_512 as i32;
     /// ML-KEM-768 key type (NIST Security Level 3).
@@ -104,6 +112,7 @@ impl MlKem {
     /// * `key_type`: Key type. One of [`MlKem::TYPE_512`], [`MlKem::TYPE_768`],
     ///   or [`MlKem::TYPE_1024`].
     /// * `rng`: `RNG` instance to use for random number generation.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
@@ -117,14 +126,16 @@ impl MlKem {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::mlkem::MlKem;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let key = MlKem::generate(MlKem::TYPE_768, &mut rng)
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let key = MlKem::generate(MlKem::TYPE_768, &mut rng, &wolfcrypt)
     ///     .expect("Error with generate()");
     /// }
     /// ```
     #[cfg(random)]
-    pub fn generate(key_type: i32, rng: &RNG) -> Result<Self, i32> {
-        Self::generate_ex(key_type, rng, None, None)
+    pub fn generate(key_type: i32, rng: &RNG, wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::generate_ex(key_type, rng, &crate::Env::new(wolfcrypt))
     }
 
     /// Generate a new ML-KEM key pair with optional heap hint and device ID.
@@ -134,8 +145,7 @@ impl MlKem {
     /// * `key_type`: Key type. One of [`MlKem::TYPE_512`], [`MlKem::TYPE_768`],
     ///   or [`MlKem::TYPE_1024`].
     /// * `rng`: `RNG` instance to use for random number generation.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id`: Optional device ID for crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -147,10 +157,13 @@ impl MlKem {
     /// ```rust
     /// #[cfg(all(mlkem, random))]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::mlkem::MlKem;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let key = MlKem::generate_ex(MlKem::TYPE_768, &mut rng, None, None)
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let key = MlKem::generate_ex(MlKem::TYPE_768, &mut rng, &Env::new(&wolfcrypt))
     ///     .expect("Error with generate_ex()");
     /// }
     /// ```
@@ -158,13 +171,12 @@ impl MlKem {
     pub fn generate_ex(
         key_type: i32,
         rng: &RNG,
-        heap: Option<*mut core::ffi::c_void>,
-        dev_id: Option<i32>,
-    ) -> Result<Self, i32> {
-        let key = Self::new_ex(key_type, heap, dev_id)?;
+        env: &crate::Env,
+    ) -> Result<Self, Error> {
+        let key = Self::new_ex(key_type, env)?;
         let rc = unsafe { sys::wc_MlKemKey_MakeKey(key.ws_key, rng.wc_rng) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(key)
     }
@@ -180,6 +192,7 @@ impl MlKem {
     /// * `key_type`: Key type. One of [`MlKem::TYPE_512`], [`MlKem::TYPE_768`],
     ///   or [`MlKem::TYPE_1024`].
     /// * `rand`: Random bytes. Must be `MAKEKEY_RAND_SIZE` (64) bytes.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
@@ -192,13 +205,15 @@ impl MlKem {
     /// #[cfg(mlkem)]
     /// {
     /// use wolfssl_wolfcrypt::mlkem::MlKem;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let rand = [0x42u8; 64];
-    /// let key = MlKem::generate_with_random(MlKem::TYPE_768, &rand)
+    /// let key = MlKem::generate_with_random(MlKem::TYPE_768, &rand, &wolfcrypt)
     ///     .expect("Error with generate_with_random()");
     /// }
     /// ```
-    pub fn generate_with_random(key_type: i32, rand: &[u8]) -> Result<Self, i32> {
-        Self::generate_with_random_ex(key_type, rand, None, None)
+    pub fn generate_with_random(key_type: i32, rand: &[u8], wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::generate_with_random_ex(key_type, rand, &crate::Env::new(wolfcrypt))
     }
 
     /// Generate an ML-KEM key pair from caller-supplied random bytes with
@@ -209,8 +224,7 @@ impl MlKem {
     /// * `key_type`: Key type. One of [`MlKem::TYPE_512`], [`MlKem::TYPE_768`],
     ///   or [`MlKem::TYPE_1024`].
     /// * `rand`: Random bytes. Must be `MAKEKEY_RAND_SIZE` (64) bytes.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id`: Optional device ID for crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -222,22 +236,24 @@ impl MlKem {
     /// ```rust
     /// #[cfg(mlkem)]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::mlkem::MlKem;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let rand = [0x42u8; 64];
-    /// let key = MlKem::generate_with_random_ex(MlKem::TYPE_768, &rand, None, None)
+    /// let key = MlKem::generate_with_random_ex(MlKem::TYPE_768, &rand, &Env::new(&wolfcrypt))
     ///     .expect("Error with generate_with_random_ex()");
     /// }
     /// ```
     pub fn generate_with_random_ex(
         key_type: i32,
         rand: &[u8],
-        heap: Option<*mut core::ffi::c_void>,
-        dev_id: Option<i32>,
-    ) -> Result<Self, i32> {
+        env: &crate::Env,
+    ) -> Result<Self, Error> {
         if rand.len() != Self::MAKEKEY_RAND_SIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
-        let key = Self::new_ex(key_type, heap, dev_id)?;
+        let key = Self::new_ex(key_type, env)?;
         let rc = unsafe {
             sys::wc_MlKemKey_MakeKeyWithRandom(
                 key.ws_key,
@@ -246,7 +262,7 @@ impl MlKem {
             )
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(key)
     }
@@ -261,6 +277,7 @@ impl MlKem {
     ///
     /// * `key_type`: Key type. One of [`MlKem::TYPE_512`], [`MlKem::TYPE_768`],
     ///   or [`MlKem::TYPE_1024`].
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
@@ -273,11 +290,13 @@ impl MlKem {
     /// #[cfg(mlkem)]
     /// {
     /// use wolfssl_wolfcrypt::mlkem::MlKem;
-    /// let key = MlKem::new(MlKem::TYPE_768).expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let key = MlKem::new(MlKem::TYPE_768, &wolfcrypt).expect("Error with new()");
     /// }
     /// ```
-    pub fn new(key_type: i32) -> Result<Self, i32> {
-        Self::new_ex(key_type, None, None)
+    pub fn new(key_type: i32, wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(key_type, &crate::Env::new(wolfcrypt))
     }
 
     /// Create and initialize a new ML-KEM key instance with optional heap hint
@@ -287,8 +306,7 @@ impl MlKem {
     ///
     /// * `key_type`: Key type. One of [`MlKem::TYPE_512`], [`MlKem::TYPE_768`],
     ///   or [`MlKem::TYPE_1024`].
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id`: Optional device ID for crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -300,28 +318,25 @@ impl MlKem {
     /// ```rust
     /// #[cfg(mlkem)]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::mlkem::MlKem;
-    /// let key = MlKem::new_ex(MlKem::TYPE_768, None, None).expect("Error with new_ex()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let key = MlKem::new_ex(MlKem::TYPE_768, &Env::new(&wolfcrypt)).expect("Error with new_ex()");
     /// }
     /// ```
     pub fn new_ex(
         key_type: i32,
-        heap: Option<*mut core::ffi::c_void>,
-        dev_id: Option<i32>,
-    ) -> Result<Self, i32> {
-        let heap = match heap {
-            Some(h) => h,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(id) => id,
-            None => sys::INVALID_DEVID,
-        };
+        env: &crate::Env,
+    ) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let ws_key = unsafe { sys::wc_MlKemKey_New(key_type, heap, dev_id) };
         if ws_key.is_null() {
-            return Err(sys::wolfCrypt_ErrorCodes_MEMORY_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_MEMORY_E));
         }
-        Ok(MlKem { ws_key })
+        Ok(MlKem { ws_key, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData })
     }
 
     /// Get the cipher text size in bytes for this key's type.
@@ -337,16 +352,18 @@ impl MlKem {
     /// #[cfg(mlkem)]
     /// {
     /// use wolfssl_wolfcrypt::mlkem::MlKem;
-    /// let mut key = MlKem::new(MlKem::TYPE_768).expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut key = MlKem::new(MlKem::TYPE_768, &wolfcrypt).expect("Error with new()");
     /// let ct_size = key.cipher_text_size().expect("Error with cipher_text_size()");
     /// assert!(ct_size > 0);
     /// }
     /// ```
-    pub fn cipher_text_size(&self) -> Result<usize, i32> {
+    pub fn cipher_text_size(&self) -> Result<usize, Error> {
         let mut len = 0u32;
         let rc = unsafe { sys::wc_MlKemKey_CipherTextSize(self.ws_key, &mut len) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(len as usize)
     }
@@ -364,16 +381,18 @@ impl MlKem {
     /// #[cfg(mlkem)]
     /// {
     /// use wolfssl_wolfcrypt::mlkem::MlKem;
-    /// let mut key = MlKem::new(MlKem::TYPE_768).expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut key = MlKem::new(MlKem::TYPE_768, &wolfcrypt).expect("Error with new()");
     /// let ss_size = key.shared_secret_size().expect("Error with shared_secret_size()");
     /// assert_eq!(ss_size, MlKem::SHARED_SECRET_SIZE);
     /// }
     /// ```
-    pub fn shared_secret_size(&self) -> Result<usize, i32> {
+    pub fn shared_secret_size(&self) -> Result<usize, Error> {
         let mut len = 0u32;
         let rc = unsafe { sys::wc_MlKemKey_SharedSecretSize(self.ws_key, &mut len) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(len as usize)
     }
@@ -391,16 +410,18 @@ impl MlKem {
     /// #[cfg(mlkem)]
     /// {
     /// use wolfssl_wolfcrypt::mlkem::MlKem;
-    /// let mut key = MlKem::new(MlKem::TYPE_768).expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut key = MlKem::new(MlKem::TYPE_768, &wolfcrypt).expect("Error with new()");
     /// let priv_size = key.private_key_size().expect("Error with private_key_size()");
     /// assert!(priv_size > 0);
     /// }
     /// ```
-    pub fn private_key_size(&self) -> Result<usize, i32> {
+    pub fn private_key_size(&self) -> Result<usize, Error> {
         let mut len = 0u32;
         let rc = unsafe { sys::wc_MlKemKey_PrivateKeySize(self.ws_key, &mut len) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(len as usize)
     }
@@ -418,16 +439,18 @@ impl MlKem {
     /// #[cfg(mlkem)]
     /// {
     /// use wolfssl_wolfcrypt::mlkem::MlKem;
-    /// let mut key = MlKem::new(MlKem::TYPE_768).expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut key = MlKem::new(MlKem::TYPE_768, &wolfcrypt).expect("Error with new()");
     /// let pub_size = key.public_key_size().expect("Error with public_key_size()");
     /// assert!(pub_size > 0);
     /// }
     /// ```
-    pub fn public_key_size(&self) -> Result<usize, i32> {
+    pub fn public_key_size(&self) -> Result<usize, Error> {
         let mut len = 0u32;
         let rc = unsafe { sys::wc_MlKemKey_PublicKeySize(self.ws_key, &mut len) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(len as usize)
     }
@@ -456,8 +479,10 @@ impl MlKem {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::mlkem::MlKem;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut key = MlKem::generate(MlKem::TYPE_768, &mut rng)
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut key = MlKem::generate(MlKem::TYPE_768, &mut rng, &wolfcrypt)
     ///     .expect("Error with generate()");
     /// let ct_size = key.cipher_text_size().unwrap();
     /// let ss_size = key.shared_secret_size().unwrap();
@@ -473,16 +498,16 @@ impl MlKem {
         ct: &mut [u8],
         ss: &mut [u8],
         rng: &RNG,
-    ) -> Result<(), i32> {
+    ) -> Result<(), Error> {
         // Verify the cipher text length is as expected based on the parameter
         // set (key type) in use.
         let expected_ct_size = self.cipher_text_size()?;
         if ct.len() != expected_ct_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         // Verify the shared secret length is as expected.
         if ss.len() != Self::SHARED_SECRET_SIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let rc = unsafe {
             sys::wc_MlKemKey_Encapsulate(
@@ -493,7 +518,7 @@ impl MlKem {
             )
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -521,9 +546,11 @@ impl MlKem {
     /// #[cfg(mlkem)]
     /// {
     /// use wolfssl_wolfcrypt::mlkem::MlKem;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let key_rand = [0x42u8; 64];
     /// let enc_rand = [0x55u8; 32];
-    /// let mut key = MlKem::generate_with_random(MlKem::TYPE_768, &key_rand)
+    /// let mut key = MlKem::generate_with_random(MlKem::TYPE_768, &key_rand, &wolfcrypt)
     ///     .expect("Error with generate_with_random()");
     /// let ct_size = key.cipher_text_size().unwrap();
     /// let ss_size = key.shared_secret_size().unwrap();
@@ -538,19 +565,19 @@ impl MlKem {
         ct: &mut [u8],
         ss: &mut [u8],
         rand: &[u8],
-    ) -> Result<(), i32> {
+    ) -> Result<(), Error> {
         if rand.len() != Self::ENC_RAND_SIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         // Verify the cipher text length is as expected based on the parameter
         // set (key type) in use.
         let expected_ct_size = self.cipher_text_size()?;
         if ct.len() != expected_ct_size {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         // Verify the shared secret length is as expected.
         if ss.len() != Self::SHARED_SECRET_SIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let rc = unsafe {
             sys::wc_MlKemKey_EncapsulateWithRandom(
@@ -562,7 +589,7 @@ impl MlKem {
             )
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -592,8 +619,10 @@ impl MlKem {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::mlkem::MlKem;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut key = MlKem::generate(MlKem::TYPE_768, &mut rng)
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut key = MlKem::generate(MlKem::TYPE_768, &mut rng, &wolfcrypt)
     ///     .expect("Error with generate()");
     /// let ct_size = key.cipher_text_size().unwrap();
     /// let ss_size = key.shared_secret_size().unwrap();
@@ -607,10 +636,10 @@ impl MlKem {
     /// assert_eq!(ss_enc, ss_dec);
     /// }
     /// ```
-    pub fn decapsulate(&mut self, ss: &mut [u8], ct: &[u8]) -> Result<(), i32> {
+    pub fn decapsulate(&mut self, ss: &mut [u8], ct: &[u8]) -> Result<(), Error> {
         // Verify the shared secret length is as expected.
         if ss.len() != Self::SHARED_SECRET_SIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let ct_size = crate::buffer_len_to_u32(ct.len())?;
         let rc = unsafe {
@@ -622,7 +651,7 @@ impl MlKem {
             )
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -647,21 +676,23 @@ impl MlKem {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::mlkem::MlKem;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut key = MlKem::generate(MlKem::TYPE_768, &mut rng)
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut key = MlKem::generate(MlKem::TYPE_768, &mut rng, &wolfcrypt)
     ///     .expect("Error with generate()");
     /// let pub_size = key.public_key_size().unwrap();
     /// let mut pub_buf = vec![0u8; pub_size];
     /// key.encode_public_key(&mut pub_buf).expect("Error with encode_public_key()");
     /// }
     /// ```
-    pub fn encode_public_key(&self, out: &mut [u8]) -> Result<(), i32> {
+    pub fn encode_public_key(&self, out: &mut [u8]) -> Result<(), Error> {
         let out_size = crate::buffer_len_to_u32(out.len())?;
         let rc = unsafe {
             sys::wc_MlKemKey_EncodePublicKey(self.ws_key, out.as_mut_ptr(), out_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -686,21 +717,23 @@ impl MlKem {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::mlkem::MlKem;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut key = MlKem::generate(MlKem::TYPE_768, &mut rng)
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut key = MlKem::generate(MlKem::TYPE_768, &mut rng, &wolfcrypt)
     ///     .expect("Error with generate()");
     /// let priv_size = key.private_key_size().unwrap();
     /// let mut priv_buf = vec![0u8; priv_size];
     /// key.encode_private_key(&mut priv_buf).expect("Error with encode_private_key()");
     /// }
     /// ```
-    pub fn encode_private_key(&self, out: &mut [u8]) -> Result<(), i32> {
+    pub fn encode_private_key(&self, out: &mut [u8]) -> Result<(), Error> {
         let out_size = crate::buffer_len_to_u32(out.len())?;
         let rc = unsafe {
             sys::wc_MlKemKey_EncodePrivateKey(self.ws_key, out.as_mut_ptr(), out_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -723,23 +756,25 @@ impl MlKem {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::mlkem::MlKem;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut key = MlKem::generate(MlKem::TYPE_768, &mut rng)
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut key = MlKem::generate(MlKem::TYPE_768, &mut rng, &wolfcrypt)
     ///     .expect("Error with generate()");
     /// let pub_size = key.public_key_size().unwrap();
     /// let mut pub_buf = vec![0u8; pub_size];
     /// key.encode_public_key(&mut pub_buf).expect("Error with encode_public_key()");
-    /// let mut key2 = MlKem::new(MlKem::TYPE_768).expect("Error with new()");
+    /// let mut key2 = MlKem::new(MlKem::TYPE_768, &wolfcrypt).expect("Error with new()");
     /// key2.decode_public_key(&pub_buf).expect("Error with decode_public_key()");
     /// }
     /// ```
-    pub fn decode_public_key(&mut self, data: &[u8]) -> Result<(), i32> {
+    pub fn decode_public_key(&mut self, data: &[u8]) -> Result<(), Error> {
         let data_size = crate::buffer_len_to_u32(data.len())?;
         let rc = unsafe {
             sys::wc_MlKemKey_DecodePublicKey(self.ws_key, data.as_ptr(), data_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -762,35 +797,37 @@ impl MlKem {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::mlkem::MlKem;
-    /// let mut rng = RNG::new().expect("Error creating RNG");
-    /// let mut key = MlKem::generate(MlKem::TYPE_768, &mut rng)
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut key = MlKem::generate(MlKem::TYPE_768, &mut rng, &wolfcrypt)
     ///     .expect("Error with generate()");
     /// let priv_size = key.private_key_size().unwrap();
     /// let mut priv_buf = vec![0u8; priv_size];
     /// key.encode_private_key(&mut priv_buf).expect("Error with encode_private_key()");
-    /// let mut key2 = MlKem::new(MlKem::TYPE_768).expect("Error with new()");
+    /// let mut key2 = MlKem::new(MlKem::TYPE_768, &wolfcrypt).expect("Error with new()");
     /// key2.decode_private_key(&priv_buf).expect("Error with decode_private_key()");
     /// }
     /// ```
-    pub fn decode_private_key(&mut self, data: &[u8]) -> Result<(), i32> {
+    pub fn decode_private_key(&mut self, data: &[u8]) -> Result<(), Error> {
         let data_size = crate::buffer_len_to_u32(data.len())?;
         let rc = unsafe {
             sys::wc_MlKemKey_DecodePrivateKey(self.ws_key, data.as_ptr(), data_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
 }
 
-impl MlKem {
+impl<'a> MlKem<'a> {
     fn zeroize(&mut self) {
         self.ws_key = core::ptr::null_mut();
     }
 }
 
-impl Drop for MlKem {
+impl Drop for MlKem<'_> {
     /// Safely free the underlying wolfSSL ML-KEM key context.
     ///
     /// This calls `wc_MlKemKey_Delete()`. The Rust Drop trait guarantees this
@@ -49,8 +49,10 @@ use kem::{Kem, Encapsulate, Decapsulate};
 use kem::Generate;
 use wolfssl_wolfcrypt::random::RNG;
 use wolfssl_wolfcrypt::mlkem_kem::*;
+use wolfssl_wolfcrypt::WolfCrypt;
+let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
 
-let mut rng = RNG::new().expect("RNG creation failed");
+let mut rng = RNG::new(&wolfcrypt).expect("RNG creation failed");
 
 let (dk, ek) = MlKem768::generate_keypair_from_rng(&mut rng);
 let (ct, k_send) = ek.encapsulate_with_rng(&mut rng);
@@ -102,7 +104,8 @@ macro_rules! impl_mlkem_kem {
 
         impl kem::TryKeyInit for $ek {
             fn new(key: &kem::Key<Self>) -> Result<Self, kem::InvalidKey> {
-                let mut wc_key = crate::mlkem::MlKem::new($key_type)
+                let wolfcrypt = crate::WolfCrypt::global().map_err(|_| kem::InvalidKey)?;
+                let mut wc_key = crate::mlkem::MlKem::new($key_type, wolfcrypt)
                     .map_err(|_| kem::InvalidKey)?;
                 wc_key.decode_public_key(key.as_ref())
                     .map_err(|_| kem::InvalidKey)?;
@@ -126,7 +129,8 @@ macro_rules! impl_mlkem_kem {
                 let mut rand = [0u8; crate::mlkem::MlKem::ENC_RAND_SIZE];
                 rng.fill_bytes(&mut rand);
 
-                let mut wc_key = crate::mlkem::MlKem::new($key_type)
+                let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+                let mut wc_key = crate::mlkem::MlKem::new($key_type, wolfcrypt)
                     .expect("MlKem::new failed");
                 wc_key.decode_public_key(self.pk.as_ref())
                     .expect("decode_public_key failed");
@@ -162,7 +166,8 @@ macro_rules! impl_mlkem_kem {
                 &self,
                 ct: &kem::Ciphertext<$kem>,
             ) -> kem::SharedKey<$kem> {
-                let mut wc_key = crate::mlkem::MlKem::new($key_type)
+                let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+                let mut wc_key = crate::mlkem::MlKem::new($key_type, wolfcrypt)
                     .expect("MlKem::new failed");
                 wc_key.decode_private_key(self.sk.as_ref())
                     .expect("decode_private_key failed");
@@ -182,8 +187,9 @@ macro_rules! impl_mlkem_kem {
                 let mut rand = [0u8; crate::mlkem::MlKem::MAKEKEY_RAND_SIZE];
                 rng.try_fill_bytes(&mut rand)?;
 
+                let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
                 let wc_key = crate::mlkem::MlKem::generate_with_random(
-                    $key_type, &rand,
+                    $key_type, &rand, wolfcrypt,
                 ).expect("generate_with_random failed");
                 zeroize::Zeroize::zeroize(&mut rand[..]);
 
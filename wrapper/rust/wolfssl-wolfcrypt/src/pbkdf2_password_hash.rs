@@ -232,7 +232,9 @@ impl password_hash::CustomizedPasswordHasher<PasswordHash> for Pbkdf2 {
 
         let mut out_buf = [0u8; Output::MAX_LENGTH];
         let out_slice = &mut out_buf[..params.output_len];
-        kdf::pbkdf2(password, salt.as_ref(), iterations, algorithm.hmac_type(), out_slice)
+        let wolfcrypt = crate::WolfCrypt::global().map_err(|_| Error::Crypto)?;
+        kdf::pbkdf2(password, salt.as_ref(), iterations, algorithm.hmac_type(), wolfcrypt,
+                out_slice)
             .map_err(|_| Error::Crypto)?;
         let output = Output::new(out_slice)?;
 
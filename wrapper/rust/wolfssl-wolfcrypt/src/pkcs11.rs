@@ -0,0 +1,282 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+This module provides a Rust wrapper for the wolfCrypt PKCS#11 backend
+(`wc_pkcs11.h`), allowing keys held in a PKCS#11 token (HSM, smart card or
+SoftHSM) to be used through the existing `ecc::ECC`, `rsa::RSA` and
+`aes::GCM` types.
+
+A [`Pkcs11Dev`] loads the PKCS#11 module, a [`Pkcs11Token`] selects a token
+and logs in with the user PIN, and [`Pkcs11Token::register_device()`] binds
+the token to a device ID. Objects created with the [`Device`] handle
+borrowed from [`Pkcs11Token::device()`], such as
+`ECC::generate(32, &rng, &Env::new(&wolfcrypt).with_device(device))` or key handles
+created with
+`ECC::new_from_id()`, `ECC::new_from_label()`, `RSA::new_from_id()`,
+`RSA::new_from_label()`, `GCM::new_from_id()` and `GCM::new_from_label()`,
+then have their operations performed by the token.
+
+# Examples
+
+```rust,no_run
+#[cfg(all(ecc, ecc_key_id, ecc_sign, random))]
+{
+use wolfssl_wolfcrypt::{Env, WolfCrypt};
+use wolfssl_wolfcrypt::ecc::ECC;
+use wolfssl_wolfcrypt::pkcs11::{Pkcs11Dev, Pkcs11Token};
+use wolfssl_wolfcrypt::random::RNG;
+
+let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+let dev = Pkcs11Dev::new(c"/usr/lib/softhsm/libsofthsm2.so", &Env::new(&wolfcrypt))
+    .expect("Error with Pkcs11Dev::new()");
+let mut token = Pkcs11Token::new(&dev, None, Some(c"wolfssl"), Some(b"1234"))
+    .expect("Error with Pkcs11Token::new()");
+token.open(true).expect("Error with open()");
+token.register_device(1).expect("Error with register_device()");
+let device = token.device().expect("Error with device()");
+
+let rng = RNG::new(&wolfcrypt).expect("Error with RNG::new()");
+let env = Env::new(&wolfcrypt).with_device(device);
+let mut key = ECC::new_from_label("signing-key", ECC::SECP256R1, &env)
+    .expect("Error with new_from_label()");
+let hash = [0x42u8; 32];
+let mut sig = [0u8; 128];
+let sig_len = key.sign_hash(&hash, &mut sig, &rng).expect("Error with sign_hash()");
+}
+```
+*/
+
+#![cfg(all(pkcs11, feature = "alloc"))]
+
+use crate::{Device, Error};
+use crate::memory::HeapHint;
+use crate::sys;
+use alloc::boxed::Box;
+use core::ffi::CStr;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use zeroize::Zeroize;
+
+/// A loaded PKCS#11 module.
+///
+/// The module is initialized with `C_Initialize` on creation and finalized
+/// when the `Pkcs11Dev` is dropped. Tokens borrow the `Pkcs11Dev` so that it
+/// outlives them.
+pub struct Pkcs11Dev<'a> {
+    ws_dev: sys::Pkcs11Dev,
+    _wolfcrypt: crate::WolfCrypt,
+    _heap: PhantomData<HeapHint<'a>>,
+}
+
+impl<'a> Pkcs11Dev<'a> {
+    /// Load and initialize a PKCS#11 module.
+    ///
+    /// # Parameters
+    ///
+    /// * `library`: Path to the PKCS#11 shared library, e.g. the SoftHSMv2
+    ///   `libsofthsm2.so`.
+    /// * `env`: Heap hint to use. The device is not used.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(Pkcs11Dev) on success or Err(e) containing the
+    /// wolfSSL library error code value.
+    pub fn new(library: &CStr, env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let heap = env.heap_ptr();
+        let mut ws_dev: MaybeUninit<sys::Pkcs11Dev> = MaybeUninit::zeroed();
+        let rc = unsafe {
+            sys::wc_Pkcs11_Initialize(ws_dev.as_mut_ptr(), library.as_ptr(), heap)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        let ws_dev = unsafe { ws_dev.assume_init() };
+        Ok(Pkcs11Dev { ws_dev, _wolfcrypt: wolfcrypt, _heap: PhantomData })
+    }
+}
+
+impl Drop for Pkcs11Dev<'_> {
+    /// Finalize the PKCS#11 module and unload the library.
+    fn drop(&mut self) {
+        unsafe { sys::wc_Pkcs11_Finalize(&mut self.ws_dev); }
+    }
+}
+
+/// A PKCS#11 token used as a wolfCrypt crypto callback device.
+///
+/// The token is registered with [`Pkcs11Token::register_device()`] and
+/// unregistered, with its sessions closed, when the `Pkcs11Token` is
+/// dropped. wolfCrypt objects created with the device must not be used
+/// after that; operations on them fall back to software, which fails for
+/// keys created by ID or label. The device ID stays reserved until these
+/// objects are dropped.
+pub struct Pkcs11Token<'a> {
+    /// Boxed since its address is the context registered with wolfCrypt.
+    ws_token: Box<sys::Pkcs11Token>,
+    /// User PIN referenced by the token for logging in on each session.
+    user_pin: Option<Box<[u8]>>,
+    device: Option<Device>,
+    _dev: PhantomData<&'a Pkcs11Dev<'a>>,
+    _wolfcrypt: crate::WolfCrypt,
+}
+
+impl<'a> Pkcs11Token<'a> {
+    /// Set up a token for use, selected by slot and/or token name.
+    ///
+    /// The token holds a clone of the library guard of `dev`.
+    ///
+    /// # Parameters
+    ///
+    /// * `dev`: PKCS#11 module providing the token.
+    /// * `slot_id`: Slot of the token, or None for the first slot (or the
+    ///   slot holding the token named `token_name`).
+    /// * `token_name`: Optional label of the token.
+    /// * `user_pin`: Optional user PIN to log in with. Without it, sessions
+    ///   are opened without logging in and only public objects are usable.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(Pkcs11Token) on success or Err(e) containing the
+    /// wolfSSL library error code value.
+    pub fn new(dev: &'a Pkcs11Dev, slot_id: Option<i32>, token_name: Option<&CStr>,
+            user_pin: Option<&[u8]>) -> Result<Self, Error> {
+        let wolfcrypt = dev._wolfcrypt.clone();
+        let slot_id = slot_id.unwrap_or(-1);
+        let token_name = match token_name {
+            Some(name) => name.as_ptr(),
+            None => core::ptr::null(),
+        };
+        let mut ws_token: Box<sys::Pkcs11Token> =
+            Box::new(unsafe { MaybeUninit::zeroed().assume_init() });
+        // wolfCrypt keeps a pointer to the PIN, so it is copied into a
+        // buffer owned by the token.
+        let user_pin: Option<Box<[u8]>> = user_pin.map(Box::from);
+        let dev_ptr = &dev.ws_dev as *const sys::Pkcs11Dev as *mut sys::Pkcs11Dev;
+        let rc = match &user_pin {
+            Some(pin) => {
+                let pin_size = crate::buffer_len_to_i32(pin.len())?;
+                unsafe {
+                    sys::wc_Pkcs11Token_Init(ws_token.as_mut(), dev_ptr, slot_id,
+                        token_name, pin.as_ptr(), pin_size)
+                }
+            }
+            None => unsafe {
+                sys::wc_Pkcs11Token_Init_NoLogin(ws_token.as_mut(), dev_ptr, slot_id,
+                    token_name)
+            },
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(Pkcs11Token {
+            ws_token,
+            user_pin,
+            device: None,
+            _dev: PhantomData,
+            _wolfcrypt: wolfcrypt,
+        })
+    }
+
+    /// Open a session on the token that is kept for all operations until
+    /// [`Pkcs11Token::close()`] is called.
+    ///
+    /// Without an open session, each operation opens and closes its own
+    /// session, so keys generated on the token as session objects do not
+    /// outlive the operation.
+    ///
+    /// # Parameters
+    ///
+    /// * `read_write`: Whether to open a read/write session, required to
+    ///   generate or store keys.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn open(&mut self, read_write: bool) -> Result<(), Error> {
+        let rc = unsafe {
+            sys::wc_Pkcs11Token_Open(self.ws_token.as_mut(), read_write as i32)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Close the session opened with [`Pkcs11Token::open()`], destroying
+    /// any session objects.
+    pub fn close(&mut self) {
+        unsafe { sys::wc_Pkcs11Token_Close(self.ws_token.as_mut()); }
+    }
+
+    /// Register the token as the crypto callback device for `dev_id`.
+    ///
+    /// # Parameters
+    ///
+    /// * `dev_id`: Device ID to register the token under.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value: `ALREADY_E` if `dev_id` is already
+    /// registered or `BAD_STATE_E` if this token is already registered.
+    /// The device handle to create wolfCrypt objects with is then borrowed
+    /// with [`Pkcs11Token::device()`].
+    pub fn register_device(&mut self, dev_id: i32) -> Result<(), Error> {
+        if self.device.is_some() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_STATE_E));
+        }
+        let rc = unsafe {
+            sys::wc_CryptoCb_RegisterDevice(dev_id, Some(sys::wc_Pkcs11_CryptoDevCb),
+                self.ws_token.as_mut() as *mut sys::Pkcs11Token as *mut core::ffi::c_void)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        self.device = Some(Device::from_registered(dev_id, release, &self._wolfcrypt));
+        Ok(())
+    }
+
+    /// Get the device the token is registered as, if any.
+    pub fn device(&self) -> Option<&Device> {
+        self.device.as_ref()
+    }
+}
+
+/// Release a device ID once its token and all objects created with the
+/// device have been dropped.
+fn release(dev_id: i32) {
+    unsafe { sys::wc_CryptoCb_UnRegisterDevice(dev_id); }
+}
+
+impl Drop for Pkcs11Token<'_> {
+    /// Unregister the device, close all sessions and zeroize the PIN.
+    fn drop(&mut self) {
+        if let Some(device) = &self.device {
+            device.detach();
+        }
+        unsafe { sys::wc_Pkcs11Token_Final(self.ws_token.as_mut()); }
+        if let Some(pin) = self.user_pin.as_mut() {
+            pin.zeroize();
+        }
+    }
+}
@@ -0,0 +1,494 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+This module provides a Rust wrapper for the wolfCrypt library's PKCS #12
+functionality (`wc_PKCS12_*()`).
+
+A [`PKCS12`] instance is created by decoding a DER encoded bundle with
+[`PKCS12::from_der()`] or by building a new bundle with
+[`PKCS12::create()`]. [`PKCS12::parse()`] decrypts a bundle into a
+[`Bundle`] holding the private key, the leaf certificate and the CA chain.
+
+# Examples
+
+```rust
+#[cfg(rsa)]
+{
+use std::fs;
+use wolfssl_wolfcrypt::pkcs12::{PKCS12, PrivateKey};
+use wolfssl_wolfcrypt::WolfCrypt;
+let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+
+let der = fs::read("../../../certs/test-servercert.p12").expect("Error reading bundle");
+let pkcs12 = PKCS12::from_der(&der, &wolfcrypt).expect("Error with from_der()");
+let bundle = pkcs12.parse("wolfSSL test").expect("Error with parse()");
+assert!(matches!(bundle.private_key(), Ok(PrivateKey::Rsa(_))));
+assert!(bundle.cert().is_some());
+}
+```
+*/
+
+#![cfg(all(pkcs12, feature = "alloc"))]
+
+use crate::Error;
+use crate::memory::HeapHint;
+use crate::sys;
+use core::marker::PhantomData;
+use alloc::vec::Vec;
+use zeroize::Zeroize;
+
+#[cfg(ecc)]
+use crate::ecc::ECC;
+#[cfg(all(ed25519, ed25519_import))]
+use crate::ed25519::Ed25519;
+#[cfg(rsa)]
+use crate::rsa::RSA;
+
+/// Free a buffer allocated by the wolfSSL library.
+unsafe fn free_buffer(buf: *mut u8) {
+    if !buf.is_null() {
+        unsafe { sys::wolfSSL_Free(buf as *mut core::ffi::c_void); }
+    }
+}
+
+/// Read the DER element with the given tag at `*idx`, returning its content
+/// and advancing `*idx` past it.
+fn der_read<'a>(input: &'a [u8], idx: &mut usize, tag: u8) -> Result<&'a [u8], Error> {
+    let parse_err = Error::from(sys::wolfCrypt_ErrorCodes_ASN_PARSE_E);
+    let mut i = *idx;
+    if input.get(i) != Some(&tag) {
+        return Err(parse_err);
+    }
+    i += 1;
+    let first = *input.get(i).ok_or(parse_err)? as usize;
+    i += 1;
+    let len = if first < 0x80 {
+        first
+    } else {
+        let count = first & 0x7f;
+        if count == 0 || count > 3 {
+            return Err(parse_err);
+        }
+        let bytes = input.get(i..i + count).ok_or(parse_err)?;
+        i += count;
+        bytes.iter().fold(0usize, |len, b| (len << 8) | *b as usize)
+    };
+    let content = input.get(i..i + len).ok_or(parse_err)?;
+    *idx = i + len;
+    Ok(content)
+}
+
+/// Get the algorithm OID of a DER encoded PKCS #8 private key.
+fn pkcs8_key_oid(pkcs8: &[u8]) -> Result<&[u8], Error> {
+    let mut idx = 0;
+    let key_info = der_read(pkcs8, &mut idx, 0x30)?;
+    idx = 0;
+    der_read(key_info, &mut idx, 0x02)?;
+    let algorithm = der_read(key_info, &mut idx, 0x30)?;
+    idx = 0;
+    der_read(algorithm, &mut idx, 0x06)
+}
+
+#[cfg(rsa)]
+const OID_RSA: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x01];
+#[cfg(ecc)]
+const OID_EC_PUBLIC_KEY: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01];
+#[cfg(all(ed25519, ed25519_import))]
+const OID_ED25519: &[u8] = &[0x2b, 0x65, 0x70];
+
+/// Private key decoded from a PKCS #12 bundle.
+#[allow(clippy::large_enum_variant)]
+pub enum PrivateKey {
+    #[cfg(rsa)]
+    Rsa(RSA<'static>),
+    #[cfg(ecc)]
+    Ecc(ECC<'static>),
+    #[cfg(all(ed25519, ed25519_import))]
+    Ed25519(Ed25519<'static>),
+}
+
+/// Contents of a decrypted PKCS #12 bundle.
+///
+/// The private key DER is zeroized when the `Bundle` is dropped.
+pub struct Bundle {
+    key: Option<Vec<u8>>,
+    cert: Option<Vec<u8>>,
+    ca_certs: Vec<Vec<u8>>,
+    wolfcrypt: crate::WolfCrypt,
+}
+
+impl Bundle {
+    /// Get the DER encoded PKCS #8 private key.
+    ///
+    /// # Returns
+    ///
+    /// Returns the private key, or None if the bundle does not contain one.
+    pub fn private_key_der(&self) -> Option<&[u8]> {
+        self.key.as_deref()
+    }
+
+    /// Decode the private key into the matching key type.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(PrivateKey) containing the decoded key or Err(e)
+    /// containing the wolfSSL library error code value. `NOT_COMPILED_IN`
+    /// is returned for key algorithms that are not supported or not
+    /// enabled.
+    pub fn private_key(&self) -> Result<PrivateKey, Error> {
+        let der = match &self.key {
+            Some(key) => key.as_slice(),
+            None => return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG)),
+        };
+        match pkcs8_key_oid(der)? {
+            #[cfg(rsa)]
+            OID_RSA => Ok(PrivateKey::Rsa(RSA::new_from_der(der, &self.wolfcrypt)?)),
+            #[cfg(ecc)]
+            OID_EC_PUBLIC_KEY => {
+                Ok(PrivateKey::Ecc(ECC::import_der(der, &crate::Env::new(&self.wolfcrypt))?))
+            }
+            #[cfg(all(ed25519, ed25519_import))]
+            OID_ED25519 => {
+                let mut key = Ed25519::new(&self.wolfcrypt)?;
+                let der_size = crate::buffer_len_to_u32(der.len())?;
+                let mut idx = 0u32;
+                let rc = unsafe {
+                    sys::wc_Ed25519PrivateKeyDecode(der.as_ptr(), &mut idx, &mut key.ws_key,
+                        der_size)
+                };
+                if rc != 0 {
+                    return Err(Error::from(rc));
+                }
+                Ok(PrivateKey::Ed25519(key))
+            }
+            _ => Err(Error::from(sys::wolfCrypt_ErrorCodes_NOT_COMPILED_IN)),
+        }
+    }
+
+    /// Get the DER encoded certificate matching the private key.
+    ///
+    /// # Returns
+    ///
+    /// Returns the certificate, or None if the bundle does not contain one.
+    pub fn cert(&self) -> Option<&[u8]> {
+        self.cert.as_deref()
+    }
+
+    /// Get the DER encoded additional (CA) certificates, in bundle order.
+    pub fn ca_certs(&self) -> impl Iterator<Item = &[u8]> {
+        self.ca_certs.iter().map(|cert| cert.as_slice())
+    }
+}
+
+impl Drop for Bundle {
+    fn drop(&mut self) {
+        if let Some(key) = &mut self.key {
+            key.as_mut_slice().zeroize();
+        }
+    }
+}
+
+/// Options for [`PKCS12::create()`].
+#[derive(Clone, Copy, Debug)]
+pub struct CreateOptions {
+    /// Encryption of the private key bag, e.g. [`PKCS12::PBE_AES256_CBC`],
+    /// or [`PKCS12::PBE_NONE`] to store the key unencrypted.
+    pub key_pbe: i32,
+    /// Encryption of the certificate bags, or [`PKCS12::PBE_NONE`] to store
+    /// the certificates unencrypted.
+    pub cert_pbe: i32,
+    /// Key derivation iteration count used for encryption.
+    pub iterations: i32,
+    /// Key derivation iteration count used for the integrity MAC, or 0 to
+    /// create the bundle without a MAC. wolfCrypt always uses HMAC-SHA256
+    /// for the MAC when SHA-256 is available.
+    pub mac_iterations: i32,
+}
+
+impl Default for CreateOptions {
+    fn default() -> Self {
+        CreateOptions {
+            key_pbe: PKCS12::PBE_AES256_CBC,
+            cert_pbe: PKCS12::PBE_AES256_CBC,
+            iterations: PKCS12::DEFAULT_ITERATIONS,
+            mac_iterations: PKCS12::DEFAULT_ITERATIONS,
+        }
+    }
+}
+
+/// Rust wrapper for a wolfSSL `WC_PKCS12` object.
+pub struct PKCS12<'a> {
+    wc_pkcs12: *mut sys::WC_PKCS12,
+    heap: *mut core::ffi::c_void,
+    _wolfcrypt: crate::WolfCrypt,
+    _heap: PhantomData<HeapHint<'a>>,
+}
+
+impl<'a> PKCS12<'a> {
+    /// No encryption.
+    pub const PBE_NONE: i32 = -1;
+    /// PKCS #12 PBE with SHA-1 and 128-bit RC4.
+    pub const PBE_SHA1_RC4_128: i32 = sys::PBESTypes_PBE_SHA1_RC4_128 as i32;
+    /// PKCS #5 PBES1 with SHA-1 and DES-CBC.
+    pub const PBE_SHA1_DES: i32 = sys::PBESTypes_PBE_SHA1_DES as i32;
+    /// PKCS #12 PBE with SHA-1 and 3-key Triple-DES-CBC.
+    pub const PBE_SHA1_DES3: i32 = sys::PBESTypes_PBE_SHA1_DES3 as i32;
+    /// PKCS #5 PBES2 with PBKDF2 and AES-128-CBC.
+    pub const PBE_AES128_CBC: i32 = sys::PBESTypes_PBE_AES128_CBC as i32;
+    /// PKCS #5 PBES2 with PBKDF2 and AES-256-CBC.
+    pub const PBE_AES256_CBC: i32 = sys::PBESTypes_PBE_AES256_CBC as i32;
+
+    /// Default key derivation iteration count.
+    pub const DEFAULT_ITERATIONS: i32 = sys::WC_PKCS12_ITT_DEFAULT as i32;
+
+    /// Decode a DER encoded PKCS #12 bundle.
+    ///
+    /// # Parameters
+    ///
+    /// * `der`: DER encoded bundle.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(PKCS12) containing the PKCS12 instance or Err(e)
+    /// containing the wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::fs;
+    /// use wolfssl_wolfcrypt::pkcs12::PKCS12;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let der = fs::read("../../../certs/test-servercert.p12").expect("Error reading bundle");
+    /// let pkcs12 = PKCS12::from_der(&der, &wolfcrypt).expect("Error with from_der()");
+    /// ```
+    pub fn from_der(der: &[u8], wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::from_der_ex(der, &crate::Env::new(wolfcrypt))
+    }
+
+    /// Decode a DER encoded PKCS #12 bundle with the given heap hint.
+    ///
+    /// # Parameters
+    ///
+    /// * `der`: DER encoded bundle.
+    /// * `env`: Heap hint to use. The device is not used.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(PKCS12) containing the PKCS12 instance or Err(e)
+    /// containing the wolfSSL library error code value.
+    pub fn from_der_ex(der: &[u8], env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let der_size = crate::buffer_len_to_u32(der.len())?;
+        let heap = env.heap_ptr();
+        let wc_pkcs12 = unsafe { sys::wc_PKCS12_new_ex(heap) };
+        if wc_pkcs12.is_null() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_MEMORY_E));
+        }
+        let pkcs12 = PKCS12 { wc_pkcs12, heap, _wolfcrypt: wolfcrypt, _heap: PhantomData };
+        let rc = unsafe { sys::wc_d2i_PKCS12(der.as_ptr(), der_size, pkcs12.wc_pkcs12) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(pkcs12)
+    }
+
+    /// Create a new PKCS #12 bundle.
+    ///
+    /// # Parameters
+    ///
+    /// * `password`: Password used for encryption and the integrity MAC.
+    /// * `key`: DER encoded private key in traditional (e.g. PKCS #1 or
+    ///   SEC 1) format.
+    /// * `cert`: DER encoded certificate matching `key`.
+    /// * `ca_certs`: DER encoded additional (CA) certificates.
+    /// * `options`: Encryption and MAC options.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(PKCS12) containing the PKCS12 instance or Err(e)
+    /// containing the wolfSSL library error code value.
+    pub fn create(password: &str, key: &[u8], cert: &[u8], ca_certs: &[&[u8]], options: &CreateOptions, wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::create_ex(password, key, cert, ca_certs, options, &crate::Env::new(wolfcrypt))
+    }
+
+    /// Create a new PKCS #12 bundle with the given heap hint.
+    ///
+    /// # Parameters
+    ///
+    /// * `password`: Password used for encryption and the integrity MAC.
+    /// * `key`: DER encoded private key in traditional (e.g. PKCS #1 or
+    ///   SEC 1) format.
+    /// * `cert`: DER encoded certificate matching `key`.
+    /// * `ca_certs`: DER encoded additional (CA) certificates.
+    /// * `options`: Encryption and MAC options.
+    /// * `env`: Heap hint to use. The device is not used.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(PKCS12) containing the PKCS12 instance or Err(e)
+    /// containing the wolfSSL library error code value.
+    pub fn create_ex(password: &str, key: &[u8], cert: &[u8], ca_certs: &[&[u8]],
+            options: &CreateOptions, env: &crate::Env) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let password_size = crate::buffer_len_to_u32(password.len())?;
+        let key_size = crate::buffer_len_to_u32(key.len())?;
+        let cert_size = crate::buffer_len_to_u32(cert.len())?;
+        let heap = env.heap_ptr();
+        let mut ca_list: Vec<sys::WC_DerCertList> = Vec::with_capacity(ca_certs.len());
+        for ca_cert in ca_certs {
+            ca_list.push(sys::WC_DerCertList {
+                buffer: ca_cert.as_ptr() as *mut u8,
+                bufferSz: crate::buffer_len_to_u32(ca_cert.len())?,
+                next: core::ptr::null_mut(),
+            });
+        }
+        for i in 1..ca_list.len() {
+            let next: *mut sys::WC_DerCertList = &mut ca_list[i];
+            ca_list[i - 1].next = next;
+        }
+        let ca_ptr = match ca_list.first_mut() {
+            Some(first) => first as *mut sys::WC_DerCertList,
+            None => core::ptr::null_mut(),
+        };
+        let wc_pkcs12 = unsafe {
+            sys::wc_PKCS12_create(password.as_ptr() as *mut core::ffi::c_char,
+                password_size, core::ptr::null_mut(), key.as_ptr() as *mut u8, key_size,
+                cert.as_ptr() as *mut u8, cert_size, ca_ptr, options.key_pbe,
+                options.cert_pbe, options.iterations, options.mac_iterations, 0, heap)
+        };
+        if wc_pkcs12.is_null() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_WC_FAILURE));
+        }
+        Ok(PKCS12 { wc_pkcs12, heap, _wolfcrypt: wolfcrypt, _heap: PhantomData })
+    }
+
+    /// Get the size of the DER encoding of this bundle.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the DER size or Err(e) containing
+    /// the wolfSSL library error code value.
+    pub fn der_size(&self) -> Result<usize, Error> {
+        let mut der_size = 0i32;
+        let rc = unsafe {
+            sys::wc_i2d_PKCS12(self.wc_pkcs12, core::ptr::null_mut(), &mut der_size)
+        };
+        if rc != sys::wolfCrypt_ErrorCodes_LENGTH_ONLY_E {
+            return Err(Error::from(rc));
+        }
+        Ok(der_size as usize)
+    }
+
+    /// DER encode this bundle.
+    ///
+    /// # Parameters
+    ///
+    /// * `dout`: Buffer in which to store the DER encoding. See
+    ///   [`PKCS12::der_size()`].
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to
+    /// `dout` or Err(e) containing the wolfSSL library error code value.
+    pub fn to_der(&self, dout: &mut [u8]) -> Result<usize, Error> {
+        let mut dout_size = crate::buffer_len_to_i32(dout.len())?;
+        let mut dout_ptr = dout.as_mut_ptr();
+        let rc = unsafe { sys::wc_i2d_PKCS12(self.wc_pkcs12, &mut dout_ptr, &mut dout_size) };
+        if rc < 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(rc as usize)
+    }
+
+    /// Verify the integrity MAC and decrypt this bundle.
+    ///
+    /// # Parameters
+    ///
+    /// * `password`: Bundle password. Must not contain NUL characters.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(Bundle) containing the private key and
+    /// certificates or Err(e) containing the wolfSSL library error code
+    /// value.
+    pub fn parse(&self, password: &str) -> Result<Bundle, Error> {
+        if password.as_bytes().contains(&0) {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+        }
+        let mut c_password: Vec<u8> = Vec::with_capacity(password.len() + 1);
+        c_password.extend_from_slice(password.as_bytes());
+        c_password.push(0);
+
+        let mut key_ptr: *mut u8 = core::ptr::null_mut();
+        let mut key_size = 0u32;
+        let mut cert_ptr: *mut u8 = core::ptr::null_mut();
+        let mut cert_size = 0u32;
+        let mut ca_list: *mut sys::WC_DerCertList = core::ptr::null_mut();
+        let rc = unsafe {
+            sys::wc_PKCS12_parse_ex(self.wc_pkcs12,
+                c_password.as_ptr() as *const core::ffi::c_char, &mut key_ptr, &mut key_size,
+                &mut cert_ptr, &mut cert_size, &mut ca_list, 1)
+        };
+        c_password.as_mut_slice().zeroize();
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+
+        let mut bundle = Bundle {
+            key: None,
+            cert: None,
+            ca_certs: Vec::new(),
+            wolfcrypt: self._wolfcrypt.clone(),
+        };
+        unsafe {
+            if !key_ptr.is_null() {
+                let key = core::slice::from_raw_parts_mut(key_ptr, key_size as usize);
+                bundle.key = Some(key.to_vec());
+                key.zeroize();
+                free_buffer(key_ptr);
+            }
+            if !cert_ptr.is_null() {
+                bundle.cert = Some(core::slice::from_raw_parts(cert_ptr, cert_size as usize).to_vec());
+                free_buffer(cert_ptr);
+            }
+            let mut node = ca_list;
+            while !node.is_null() {
+                let buffer = core::slice::from_raw_parts((*node).buffer, (*node).bufferSz as usize);
+                bundle.ca_certs.push(buffer.to_vec());
+                node = (*node).next;
+            }
+            sys::wc_FreeCertList(ca_list, self.heap);
+        }
+        Ok(bundle)
+    }
+}
+
+impl Drop for PKCS12<'_> {
+    /// Safely free the underlying wolfSSL PKCS12 object.
+    ///
+    /// This calls the `wc_PKCS12_free()` wolfssl library function.
+    fn drop(&mut self) {
+        unsafe { sys::wc_PKCS12_free(self.wc_pkcs12); }
+    }
+}
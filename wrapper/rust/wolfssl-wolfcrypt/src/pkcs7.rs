@@ -0,0 +1,756 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+This module provides a Rust wrapper for the wolfCrypt library's PKCS #7 / CMS
+functionality (`wc_PKCS7_*()`).
+
+The [`PKCS7`] struct can create and verify SignedData (with attached or
+detached content and optional custom signed attributes) and encrypt and
+decrypt EnvelopedData for KeyTransRecipientInfo (RSA) and
+KeyAgreeRecipientInfo (ECC) recipients. Private keys are given as
+[`crate::rsa::RSA`] or [`crate::ecc::ECC`] instances through the
+[`Pkcs7Key`] trait.
+
+Verifying SignedData checks the signature using the signer certificate
+embedded in the message. It does not check that the signer certificate is
+trusted; that must be done separately using [`PKCS7::signer_certificate()`].
+
+# Examples
+
+```rust
+#[cfg(all(rsa, rsa_key_to_der))]
+{
+use std::fs;
+use wolfssl_wolfcrypt::pkcs7::PKCS7;
+use wolfssl_wolfcrypt::random::RNG;
+use wolfssl_wolfcrypt::rsa::RSA;
+use wolfssl_wolfcrypt::WolfCrypt;
+let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+
+let cert = fs::read("../../../certs/server-cert.der").expect("Error reading cert");
+let key_der = fs::read("../../../certs/server-key.der").expect("Error reading key");
+let mut key = RSA::new_from_der(&key_der, &wolfcrypt).expect("Error with new_from_der()");
+let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+
+let mut signer = PKCS7::new_with_cert(&cert, &wolfcrypt).expect("Error with new_with_cert()");
+let mut signed = [0u8; 4096];
+let signed_size = signer.encode_signed_data(b"firmware image", &mut key,
+    PKCS7::HASH_SHA256, &rng, &mut signed).expect("Error with encode_signed_data()");
+
+let mut verifier = PKCS7::new(&wolfcrypt).expect("Error with new()");
+verifier.verify_signed_data(&signed[..signed_size])
+    .expect("Error with verify_signed_data()");
+assert_eq!(verifier.content(), Some(&b"firmware image"[..]));
+assert_eq!(verifier.signer_certificate(), Some(&cert[..]));
+}
+```
+*/
+
+#![cfg(all(pkcs7, random))]
+
+use crate::random::RNG;
+use crate::Error;
+use crate::memory::HeapHint;
+use crate::sys;
+use core::marker::PhantomData;
+use zeroize::Zeroize;
+
+/// Size of the temporary buffer used to DER encode a private key, large
+/// enough for an 8192-bit RSA private key.
+const MAX_KEY_DER_SIZE: usize = 5120;
+
+mod private {
+    use crate::Error;
+
+    pub trait Sealed {
+        /// wolfSSL `Key_Sum` OID of the key algorithm.
+        fn pkcs7_key_oid(&self) -> i32;
+        /// DER encode the private key into `dout`, returning its size.
+        fn pkcs7_key_der(&mut self, dout: &mut [u8]) -> Result<usize, Error>;
+    }
+}
+
+/// A private key which can sign PKCS #7 SignedData or decrypt PKCS #7
+/// EnvelopedData.
+///
+/// This trait is implemented for the key types of this crate and cannot be
+/// implemented outside of it.
+pub trait Pkcs7Key: private::Sealed {}
+
+#[cfg(all(ecc, ecc_export))]
+impl private::Sealed for crate::ecc::ECC<'_> {
+    fn pkcs7_key_oid(&self) -> i32 {
+        sys::Key_Sum_ECDSAk as i32
+    }
+    fn pkcs7_key_der(&mut self, dout: &mut [u8]) -> Result<usize, Error> {
+        let dout_size = crate::buffer_len_to_u32(dout.len())?;
+        let rc = unsafe {
+            sys::wc_EccKeyToDer(self.wc_ecc_key, dout.as_mut_ptr(), dout_size)
+        };
+        if rc < 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(rc as usize)
+    }
+}
+#[cfg(all(ecc, ecc_export))]
+impl Pkcs7Key for crate::ecc::ECC<'_> {}
+
+#[cfg(all(rsa, rsa_key_to_der))]
+impl private::Sealed for crate::rsa::RSA<'_> {
+    fn pkcs7_key_oid(&self) -> i32 {
+        sys::Key_Sum_RSAk as i32
+    }
+    fn pkcs7_key_der(&mut self, dout: &mut [u8]) -> Result<usize, Error> {
+        let dout_size = crate::buffer_len_to_u32(dout.len())?;
+        let rc = unsafe {
+            sys::wc_RsaKeyToDer(&mut self.wc_rsakey, dout.as_mut_ptr(), dout_size)
+        };
+        if rc < 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(rc as usize)
+    }
+}
+#[cfg(all(rsa, rsa_key_to_der))]
+impl Pkcs7Key for crate::rsa::RSA<'_> {}
+
+/// A custom signed attribute to include in SignedData.
+///
+/// Wraps a wolfSSL `PKCS7Attrib` referencing a DER encoded attribute type
+/// OID and a DER encoded attribute value (e.g. a `SET` or `OCTET STRING`).
+#[repr(transparent)]
+pub struct Attribute<'a> {
+    wc_attrib: sys::PKCS7Attrib,
+    _phantom: PhantomData<&'a [u8]>,
+}
+
+impl<'a> Attribute<'a> {
+    /// Create a new signed attribute.
+    ///
+    /// # Parameters
+    ///
+    /// * `oid`: DER encoded attribute type OID, including the tag and
+    ///   length bytes.
+    /// * `value`: DER encoded attribute value.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(Attribute) or Err(e) containing the wolfSSL library
+    /// error code value.
+    pub fn new(oid: &'a [u8], value: &'a [u8]) -> Result<Self, Error> {
+        let wc_attrib = sys::PKCS7Attrib {
+            oid: oid.as_ptr(),
+            oidSz: crate::buffer_len_to_u32(oid.len())?,
+            value: value.as_ptr(),
+            valueSz: crate::buffer_len_to_u32(value.len())?,
+        };
+        Ok(Attribute { wc_attrib, _phantom: PhantomData })
+    }
+}
+
+/// Rust wrapper for a wolfSSL `wc_PKCS7` object.
+///
+/// The lifetime `'a` covers the certificates, signed attributes and
+/// messages referenced (but not copied) by the underlying C object.
+pub struct PKCS7<'a> {
+    wc_pkcs7: *mut sys::wc_PKCS7,
+    _phantom: PhantomData<&'a [u8]>,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
+}
+
+impl<'a> PKCS7<'a> {
+    /// SHA-256 digest algorithm for SignedData.
+    pub const HASH_SHA256: i32 = sys::Hash_Sum_SHA256h as i32;
+    /// SHA-384 digest algorithm for SignedData.
+    pub const HASH_SHA384: i32 = sys::Hash_Sum_SHA384h as i32;
+    /// SHA-512 digest algorithm for SignedData.
+    pub const HASH_SHA512: i32 = sys::Hash_Sum_SHA512h as i32;
+
+    /// AES-128-CBC content encryption for EnvelopedData.
+    pub const AES128_CBC: i32 = sys::Block_Sum_AES128CBCb as i32;
+    /// AES-192-CBC content encryption for EnvelopedData.
+    pub const AES192_CBC: i32 = sys::Block_Sum_AES192CBCb as i32;
+    /// AES-256-CBC content encryption for EnvelopedData.
+    pub const AES256_CBC: i32 = sys::Block_Sum_AES256CBCb as i32;
+
+    /// AES-128 key wrap for KeyAgreeRecipientInfo.
+    pub const AES128_WRAP: i32 = sys::KeyWrap_Sum_AES128_WRAP as i32;
+    /// AES-192 key wrap for KeyAgreeRecipientInfo.
+    pub const AES192_WRAP: i32 = sys::KeyWrap_Sum_AES192_WRAP as i32;
+    /// AES-256 key wrap for KeyAgreeRecipientInfo.
+    pub const AES256_WRAP: i32 = sys::KeyWrap_Sum_AES256_WRAP as i32;
+
+    /// ECDH key agreement with the X9.63 SHA-256 KDF.
+    pub const ECDH_SHA256_KDF: i32 =
+        sys::Key_Agree_dhSinglePass_stdDH_sha256kdf_scheme as i32;
+    /// ECDH key agreement with the X9.63 SHA-384 KDF.
+    pub const ECDH_SHA384_KDF: i32 =
+        sys::Key_Agree_dhSinglePass_stdDH_sha384kdf_scheme as i32;
+    /// ECDH key agreement with the X9.63 SHA-512 KDF.
+    pub const ECDH_SHA512_KDF: i32 =
+        sys::Key_Agree_dhSinglePass_stdDH_sha512kdf_scheme as i32;
+
+    /// Identify a signer or recipient by certificate issuer and serial
+    /// number.
+    pub const SID_ISSUER_AND_SERIAL_NUMBER: i32 =
+        sys::Cms_Options_CMS_ISSUER_AND_SERIAL_NUMBER as i32;
+    /// Identify a signer or recipient by certificate subject key identifier.
+    pub const SID_SKID: i32 = sys::Cms_Options_CMS_SKID as i32;
+
+    /// Create a new `PKCS7` instance with no certificate.
+    ///
+    /// Use this to verify SignedData or to create EnvelopedData with
+    /// [`PKCS7::add_recipient_ktri()`] or [`PKCS7::add_recipient_kari()`].
+    ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(PKCS7) containing the PKCS7 instance or Err(e)
+    /// containing the wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use wolfssl_wolfcrypt::pkcs7::PKCS7;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let pkcs7 = PKCS7::new(&wolfcrypt).expect("Error with new()");
+    /// ```
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&crate::Env::new(wolfcrypt))
+    }
+
+    /// Create a new `PKCS7` instance with no certificate with optional heap
+    /// and device ID.
+    ///
+    /// # Parameters
+    ///
+    /// * `env`: Heap hint and crypto callback device to use.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(PKCS7) containing the PKCS7 instance or Err(e)
+    /// containing the wolfSSL library error code value.
+    pub fn new_ex(env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
+        let wc_pkcs7 = unsafe { sys::wc_PKCS7_New(heap, dev_id) };
+        if wc_pkcs7.is_null() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_MEMORY_E));
+        }
+        Ok(PKCS7 { wc_pkcs7, _phantom: PhantomData, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData })
+    }
+
+    /// Create a new `PKCS7` instance for the given certificate.
+    ///
+    /// The certificate is the signer certificate when creating SignedData,
+    /// and the recipient certificate when decrypting EnvelopedData.
+    ///
+    /// # Parameters
+    ///
+    /// * `cert`: DER encoded certificate.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(PKCS7) containing the PKCS7 instance or Err(e)
+    /// containing the wolfSSL library error code value.
+    pub fn new_with_cert(cert: &'a [u8], wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_with_cert_ex(cert, &crate::Env::new(wolfcrypt))
+    }
+
+    /// Create a new `PKCS7` instance for the given certificate with
+    /// optional heap and device ID.
+    ///
+    /// # Parameters
+    ///
+    /// * `cert`: DER encoded certificate.
+    /// * `env`: Heap hint and crypto callback device to use.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(PKCS7) containing the PKCS7 instance or Err(e)
+    /// containing the wolfSSL library error code value.
+    pub fn new_with_cert_ex(cert: &'a [u8], env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let cert_size = crate::buffer_len_to_u32(cert.len())?;
+        let pkcs7 = Self::new_ex(env)?;
+        let rc = unsafe {
+            sys::wc_PKCS7_InitWithCert(pkcs7.wc_pkcs7, cert.as_ptr() as *mut u8, cert_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(pkcs7)
+    }
+
+    /// Add a certificate to include in SignedData, typically an
+    /// intermediate CA certificate of the signer.
+    ///
+    /// # Parameters
+    ///
+    /// * `cert`: DER encoded certificate.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn add_certificate(&mut self, cert: &'a [u8]) -> Result<(), Error> {
+        let cert_size = crate::buffer_len_to_u32(cert.len())?;
+        let rc = unsafe {
+            sys::wc_PKCS7_AddCertificate(self.wc_pkcs7, cert.as_ptr() as *mut u8, cert_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Set whether SignedData is created with detached content.
+    ///
+    /// A detached signature does not include the content; the verifier
+    /// must supply it to [`PKCS7::verify_signed_data_detached()`].
+    ///
+    /// # Parameters
+    ///
+    /// * `detached`: Whether to create detached signatures.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn set_detached(&mut self, detached: bool) -> Result<(), Error> {
+        let rc = unsafe { sys::wc_PKCS7_SetDetached(self.wc_pkcs7, detached as u16) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Set custom signed attributes to include in SignedData.
+    ///
+    /// These are added in addition to the default contentType,
+    /// messageDigest and signingTime attributes unless
+    /// [`PKCS7::set_no_default_signed_attributes()`] is called.
+    ///
+    /// # Parameters
+    ///
+    /// * `attributes`: Signed attributes.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn set_signed_attributes(&mut self, attributes: &'a [Attribute<'a>]) -> Result<(), Error> {
+        let count = crate::buffer_len_to_u32(attributes.len())?;
+        unsafe {
+            (*self.wc_pkcs7).signedAttribs = attributes.as_ptr() as *mut sys::PKCS7Attrib;
+            (*self.wc_pkcs7).signedAttribsSz = count;
+        }
+        Ok(())
+    }
+
+    /// Do not include the default contentType, messageDigest and
+    /// signingTime signed attributes in SignedData.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn set_no_default_signed_attributes(&mut self) -> Result<(), Error> {
+        let rc = unsafe { sys::wc_PKCS7_NoDefaultSignedAttribs(self.wc_pkcs7) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Set how the signer is identified in SignedData.
+    ///
+    /// # Parameters
+    ///
+    /// * `sid_type`: [`PKCS7::SID_ISSUER_AND_SERIAL_NUMBER`] (the default)
+    ///   or [`PKCS7::SID_SKID`].
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn set_signer_identifier_type(&mut self, sid_type: i32) -> Result<(), Error> {
+        let rc = unsafe { sys::wc_PKCS7_SetSignerIdentifierType(self.wc_pkcs7, sid_type) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Run `f` with the DER encoding of `key` and `rng` set on the
+    /// underlying object, clearing both afterwards.
+    fn with_private_key<K: Pkcs7Key, F: FnOnce(*mut sys::wc_PKCS7) -> i32>(&mut self,
+            key: &mut K, rng: &RNG, f: F) -> Result<i32, Error> {
+        let mut key_der = [0u8; MAX_KEY_DER_SIZE];
+        let key_der_size = match key.pkcs7_key_der(&mut key_der) {
+            Ok(size) => size,
+            Err(e) => {
+                key_der.zeroize();
+                return Err(e);
+            }
+        };
+        let p = self.wc_pkcs7;
+        let rc = unsafe {
+            (*p).privateKey = key_der.as_mut_ptr();
+            (*p).privateKeySz = key_der_size as u32;
+            (*p).rng = rng.wc_rng;
+            let rc = f(p);
+            (*p).privateKey = core::ptr::null_mut();
+            (*p).privateKeySz = 0;
+            (*p).rng = core::ptr::null_mut();
+            rc
+        };
+        key_der.zeroize();
+        if rc < 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(rc)
+    }
+
+    /// Create SignedData for the given content.
+    ///
+    /// This instance must have been created with
+    /// [`PKCS7::new_with_cert()`] using the signer certificate matching
+    /// `key`.
+    ///
+    /// # Parameters
+    ///
+    /// * `content`: Content to sign.
+    /// * `key`: Signer private key.
+    /// * `hash`: Digest algorithm, e.g. [`PKCS7::HASH_SHA256`].
+    /// * `rng`: `RNG` instance to use for signing.
+    /// * `dout`: Buffer in which to store the DER encoded SignedData.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to
+    /// `dout` or Err(e) containing the wolfSSL library error code value.
+    pub fn encode_signed_data<K: Pkcs7Key>(&mut self, content: &[u8], key: &mut K,
+            hash: i32, rng: &RNG, dout: &mut [u8]) -> Result<usize, Error> {
+        let content_size = crate::buffer_len_to_u32(content.len())?;
+        let dout_size = crate::buffer_len_to_u32(dout.len())?;
+        let key_oid = key.pkcs7_key_oid();
+        let rc = self.with_private_key(key, rng, |p| unsafe {
+            (*p).content = content.as_ptr() as *mut u8;
+            (*p).contentSz = content_size;
+            (*p).hashOID = hash;
+            (*p).encryptOID = key_oid;
+            let rc = sys::wc_PKCS7_EncodeSignedData(p, dout.as_mut_ptr(), dout_size);
+            (*p).content = core::ptr::null_mut();
+            (*p).contentSz = 0;
+            rc
+        })?;
+        Ok(rc as usize)
+    }
+
+    /// Verify SignedData with attached content.
+    ///
+    /// On success the content, embedded certificates and signed attributes
+    /// can be retrieved with [`PKCS7::content()`],
+    /// [`PKCS7::certificates()`] and [`PKCS7::signed_attribute()`].
+    ///
+    /// # Parameters
+    ///
+    /// * `msg`: DER encoded SignedData.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) if the signature is valid or Err(e) containing
+    /// the wolfSSL library error code value.
+    pub fn verify_signed_data(&mut self, msg: &'a [u8]) -> Result<(), Error> {
+        let msg_size = crate::buffer_len_to_u32(msg.len())?;
+        let rc = unsafe {
+            sys::wc_PKCS7_VerifySignedData(self.wc_pkcs7, msg.as_ptr() as *mut u8, msg_size)
+        };
+        if rc < 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Verify SignedData with detached content.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg`: DER encoded SignedData.
+    /// * `content`: Content that was signed.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) if the signature is valid or Err(e) containing
+    /// the wolfSSL library error code value.
+    pub fn verify_signed_data_detached(&mut self, msg: &'a [u8], content: &'a [u8]) -> Result<(), Error> {
+        let content_size = crate::buffer_len_to_u32(content.len())?;
+        unsafe {
+            (*self.wc_pkcs7).content = content.as_ptr() as *mut u8;
+            (*self.wc_pkcs7).contentSz = content_size;
+        }
+        self.verify_signed_data(msg)
+    }
+
+    /// Get the content of verified SignedData.
+    ///
+    /// # Returns
+    ///
+    /// Returns the content, or None if there is none.
+    pub fn content(&self) -> Option<&[u8]> {
+        let (content, content_size) = unsafe {
+            ((*self.wc_pkcs7).content, (*self.wc_pkcs7).contentSz)
+        };
+        if content.is_null() {
+            return None;
+        }
+        Some(unsafe { core::slice::from_raw_parts(content, content_size as usize) })
+    }
+
+    /// Get the certificates embedded in verified SignedData.
+    ///
+    /// # Returns
+    ///
+    /// Returns an iterator over the DER encoded certificates.
+    pub fn certificates(&self) -> impl Iterator<Item = &[u8]> {
+        let p = self.wc_pkcs7;
+        let (certs, sizes) = unsafe { (&(*p).cert, &(*p).certSz) };
+        certs.iter().zip(sizes.iter())
+            .take_while(|(cert, _)| !cert.is_null())
+            .map(|(cert, size)| unsafe { core::slice::from_raw_parts(*cert, *size as usize) })
+    }
+
+    /// Get the certificate whose key verified the SignedData signature.
+    ///
+    /// # Returns
+    ///
+    /// Returns the DER encoded signer certificate, or None if no signature
+    /// has been verified.
+    pub fn signer_certificate(&self) -> Option<&[u8]> {
+        let (cert, cert_size) = unsafe {
+            ((*self.wc_pkcs7).verifyCert, (*self.wc_pkcs7).verifyCertSz)
+        };
+        if cert.is_null() {
+            return None;
+        }
+        Some(unsafe { core::slice::from_raw_parts(cert, cert_size as usize) })
+    }
+
+    /// Get the value of a signed attribute of verified SignedData.
+    ///
+    /// # Parameters
+    ///
+    /// * `oid`: Attribute type OID without the tag and length bytes.
+    /// * `dout`: Buffer in which to store the DER encoded attribute value.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to
+    /// `dout` or Err(e) containing the wolfSSL library error code value.
+    pub fn signed_attribute(&self, oid: &[u8], dout: &mut [u8]) -> Result<usize, Error> {
+        let oid_size = crate::buffer_len_to_u32(oid.len())?;
+        let mut dout_size = crate::buffer_len_to_u32(dout.len())?;
+        let rc = unsafe {
+            sys::wc_PKCS7_GetAttributeValue(self.wc_pkcs7, oid.as_ptr(), oid_size,
+                dout.as_mut_ptr(), &mut dout_size)
+        };
+        if rc < 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(rc as usize)
+    }
+
+    /// Set the content encryption algorithm for EnvelopedData.
+    ///
+    /// The content encryption key is generated when the first recipient is
+    /// added, so this must be called before [`PKCS7::add_recipient_ktri()`]
+    /// or [`PKCS7::add_recipient_kari()`].
+    ///
+    /// # Parameters
+    ///
+    /// * `cipher`: Content encryption algorithm, e.g.
+    ///   [`PKCS7::AES256_CBC`].
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn set_content_cipher(&mut self, cipher: i32) -> Result<(), Error> {
+        let p = self.wc_pkcs7;
+        unsafe {
+            if (*p).cekSz != 0 {
+                return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_STATE_E));
+            }
+            (*p).encryptOID = cipher;
+        }
+        Ok(())
+    }
+
+    /// Add a KeyTransRecipientInfo recipient to EnvelopedData.
+    ///
+    /// The content encryption key is encrypted with the RSA public key of
+    /// the recipient certificate. The content cipher must already have been
+    /// set with [`PKCS7::set_content_cipher()`].
+    ///
+    /// # Parameters
+    ///
+    /// * `cert`: DER encoded recipient certificate.
+    /// * `sid_type`: [`PKCS7::SID_ISSUER_AND_SERIAL_NUMBER`] or
+    ///   [`PKCS7::SID_SKID`].
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn add_recipient_ktri(&mut self, cert: &[u8], sid_type: i32) -> Result<(), Error> {
+        let cert_size = crate::buffer_len_to_u32(cert.len())?;
+        let rc = unsafe {
+            sys::wc_PKCS7_AddRecipient_KTRI(self.wc_pkcs7, cert.as_ptr(), cert_size, sid_type)
+        };
+        if rc < 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Add a KeyAgreeRecipientInfo recipient to EnvelopedData.
+    ///
+    /// The content encryption key is wrapped with a key derived from an
+    /// ephemeral-static ECDH agreement with the ECC public key of the
+    /// recipient certificate. The content cipher must already have been
+    /// set with [`PKCS7::set_content_cipher()`].
+    ///
+    /// # Parameters
+    ///
+    /// * `cert`: DER encoded recipient certificate.
+    /// * `key_wrap`: Key wrap algorithm, e.g. [`PKCS7::AES256_WRAP`].
+    /// * `key_agree`: Key agreement algorithm, e.g.
+    ///   [`PKCS7::ECDH_SHA256_KDF`].
+    /// * `ukm`: Optional user keying material.
+    /// * `rng`: `RNG` instance to use for the key agreement.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn add_recipient_kari(&mut self, cert: &[u8], key_wrap: i32, key_agree: i32,
+            ukm: Option<&[u8]>, rng: &RNG) -> Result<(), Error> {
+        let cert_size = crate::buffer_len_to_u32(cert.len())?;
+        let (ukm_ptr, ukm_size) = match ukm {
+            Some(ukm) => (ukm.as_ptr() as *mut u8, crate::buffer_len_to_u32(ukm.len())?),
+            None => (core::ptr::null_mut(), 0),
+        };
+        let p = self.wc_pkcs7;
+        let rc = unsafe {
+            (*p).rng = rng.wc_rng;
+            let rc = sys::wc_PKCS7_AddRecipient_KARI(p, cert.as_ptr(), cert_size,
+                key_wrap, key_agree, ukm_ptr, ukm_size, 0);
+            (*p).rng = core::ptr::null_mut();
+            rc
+        };
+        if rc < 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Encrypt content as EnvelopedData for the added recipients.
+    ///
+    /// # Parameters
+    ///
+    /// * `content`: Content to encrypt.
+    /// * `rng`: `RNG` instance to use.
+    /// * `dout`: Buffer in which to store the DER encoded EnvelopedData.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to
+    /// `dout` or Err(e) containing the wolfSSL library error code value.
+    pub fn encode_enveloped_data(&mut self, content: &[u8], rng: &RNG,
+            dout: &mut [u8]) -> Result<usize, Error> {
+        let content_size = crate::buffer_len_to_u32(content.len())?;
+        let dout_size = crate::buffer_len_to_u32(dout.len())?;
+        let p = self.wc_pkcs7;
+        let rc = unsafe {
+            (*p).content = content.as_ptr() as *mut u8;
+            (*p).contentSz = content_size;
+            if (*p).contentOID == 0 {
+                (*p).contentOID = sys::PKCS7_TYPES_DATA as i32;
+            }
+            (*p).rng = rng.wc_rng;
+            let rc = sys::wc_PKCS7_EncodeEnvelopedData(p, dout.as_mut_ptr(), dout_size);
+            (*p).content = core::ptr::null_mut();
+            (*p).contentSz = 0;
+            (*p).rng = core::ptr::null_mut();
+            rc
+        };
+        if rc < 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(rc as usize)
+    }
+
+    /// Decrypt EnvelopedData.
+    ///
+    /// This instance must have been created with
+    /// [`PKCS7::new_with_cert()`] using the recipient certificate matching
+    /// `key`.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg`: DER encoded EnvelopedData.
+    /// * `key`: Recipient private key.
+    /// * `rng`: `RNG` instance to use.
+    /// * `dout`: Buffer in which to store the decrypted content.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to
+    /// `dout` or Err(e) containing the wolfSSL library error code value.
+    pub fn decode_enveloped_data<K: Pkcs7Key>(&mut self, msg: &[u8], key: &mut K,
+            rng: &RNG, dout: &mut [u8]) -> Result<usize, Error> {
+        let msg_size = crate::buffer_len_to_u32(msg.len())?;
+        let dout_size = crate::buffer_len_to_u32(dout.len())?;
+        let rc = self.with_private_key(key, rng, |p| unsafe {
+            sys::wc_PKCS7_DecodeEnvelopedData(p, msg.as_ptr() as *mut u8, msg_size,
+                dout.as_mut_ptr(), dout_size)
+        })?;
+        Ok(rc as usize)
+    }
+}
+
+impl<'a> Drop for PKCS7<'a> {
+    /// Safely free the underlying wolfSSL PKCS7 object.
+    ///
+    /// This calls the `wc_PKCS7_Free()` wolfssl library function.
+    fn drop(&mut self) {
+        unsafe { sys::wc_PKCS7_Free(self.wc_pkcs7); }
+    }
+}
@@ -0,0 +1,1131 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+This module provides uniform PKCS #8 private key and X.509
+SubjectPublicKeyInfo (SPKI) public key encoding for the key types of this
+crate.
+
+Four traits are provided:
+
+* [`Pkcs8Export`]: encode a private key as PKCS #8 DER or PEM, optionally
+  password encrypted (PBES2 with AES-256-CBC and HMAC-SHA256).
+* [`Pkcs8Import`]: decode a private key from PKCS #8 DER or PEM, optionally
+  password encrypted.
+* [`SpkiExport`]: encode a public key as SPKI DER or PEM.
+* [`SpkiImport`]: decode a public key from SPKI DER or PEM.
+
+They are implemented for the following types, depending on the wolfSSL
+build options:
+
+| Key type                          | Algorithm                          |
+|-----------------------------------|------------------------------------|
+| [`crate::rsa::RSA`]               | RSA                                |
+| [`crate::ecc::ECC`]               | ECDSA / ECDH                       |
+| [`crate::ed25519::Ed25519`]       | Ed25519                            |
+| [`crate::ed448::Ed448`]           | Ed448                              |
+| [`crate::curve25519::Curve25519Key`] | X25519                          |
+| [`crate::curve448::X448`]         | X448                               |
+| [`crate::dh::DH`]                 | Finite field Diffie-Hellman        |
+| [`crate::mldsa::MlDsa`]           | ML-DSA-44, ML-DSA-65, ML-DSA-87    |
+| [`crate::mlkem::MlKem`]           | ML-KEM-512, ML-KEM-768, ML-KEM-1024 |
+
+The raw X25519 and X448 keys inside the DER encoding are read and written
+big-endian by wolfCrypt, so these encodings round trip with this crate but the
+key values differ from the little-endian encoding of RFC 8410.
+
+A `DH` private key is encoded from the key pair stored with
+[`crate::dh::DH::import_key_pair()`].
+
+ML-KEM private keys are encoded in the `expandedKey` form since wolfCrypt
+does not retain the key generation seed. The `seed`, `expandedKey` and
+`both` forms are accepted when decoding.
+
+With the `pkcs8` crate feature enabled, these types also implement the
+RustCrypto `pkcs8` and `spki` traits; see [`crate::pkcs8_spki`].
+
+# Examples
+
+```rust
+#[cfg(ecc_key_to_pkcs8)]
+{
+use std::fs;
+use wolfssl_wolfcrypt::ecc::ECC;
+use wolfssl_wolfcrypt::pkcs8::{Pkcs8Export, Pkcs8Import};
+use wolfssl_wolfcrypt::WolfCrypt;
+let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+
+let der = fs::read("../../../certs/ecc-keyPkcs8.der").expect("Error reading key");
+let key = ECC::import_pkcs8_der(&der, &wolfcrypt).expect("Error with import_pkcs8_der()");
+let mut out = [0u8; 256];
+let out_size = key.export_pkcs8_der(&mut out).expect("Error with export_pkcs8_der()");
+let key = ECC::import_pkcs8_der(&out[..out_size], &wolfcrypt).expect("Error with import_pkcs8_der()");
+}
+```
+*/
+
+#![cfg(pkcs8)]
+
+#[cfg(all(feature = "alloc", any(der_to_pem, key_pem_to_der, pub_key_pem_to_der, pkcs8_encrypt)))]
+use alloc::vec;
+#[cfg(all(feature = "alloc", pkcs8_encrypt, random))]
+use crate::random::RNG;
+use crate::{Error, WolfCrypt};
+use crate::sys;
+#[cfg(any(all(feature = "alloc", any(der_to_pem, key_pem_to_der, pkcs8_encrypt)),
+    all(rsa, rsa_key_to_der)))]
+use zeroize::Zeroize;
+
+/// Size of the temporary buffer used to hold a DER encoded key when
+/// converting it to PEM or encrypting it. Large enough for an 8192-bit RSA
+/// private key or an ML-DSA-87 private key.
+#[cfg(feature = "alloc")]
+pub const MAX_DER_SIZE: usize = 8192;
+
+/// Size of the temporary buffer used to hold a PKCS #1 RSA private key
+/// before wrapping it in PKCS #8, large enough for an 8192-bit key.
+#[cfg(all(rsa, rsa_key_to_der))]
+const MAX_RSA_KEY_DER_SIZE: usize = 5120;
+
+/// A private key which can be encoded as a PKCS #8 `PrivateKeyInfo`.
+pub trait Pkcs8Export {
+    /// Encode the private key as PKCS #8 DER.
+    ///
+    /// # Parameters
+    ///
+    /// * `dout`: Output buffer in which to store the DER encoded key.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to
+    /// `dout` or Err(e) containing the wolfSSL library error code value.
+    fn export_pkcs8_der(&self, dout: &mut [u8]) -> Result<usize, Error>;
+
+    /// Encode the private key as a PEM "PRIVATE KEY".
+    ///
+    /// # Parameters
+    ///
+    /// * `dout`: Output buffer in which to store the PEM encoded key.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to
+    /// `dout` or Err(e) containing the wolfSSL library error code value.
+    #[cfg(all(feature = "alloc", der_to_pem))]
+    fn export_pkcs8_pem(&self, dout: &mut [u8]) -> Result<usize, Error> {
+        let mut der = vec![0u8; MAX_DER_SIZE];
+        let result = self.export_pkcs8_der(&mut der).and_then(|der_size| {
+            der_to_pem(&der[..der_size], sys::CertType_PKCS8_PRIVATEKEY_TYPE as i32, dout)
+        });
+        der.as_mut_slice().zeroize();
+        result
+    }
+
+    /// Encode the private key as a password encrypted PKCS #8
+    /// `EncryptedPrivateKeyInfo` in DER format.
+    ///
+    /// The key is encrypted with PBES2 using PBKDF2 with HMAC-SHA256 and a
+    /// random salt, and AES-256-CBC.
+    ///
+    /// # Parameters
+    ///
+    /// * `password`: Password to derive the encryption key from.
+    /// * `iterations`: PBKDF2 iteration count.
+    /// * `rng`: `RNG` instance used to generate the salt and IV.
+    /// * `dout`: Output buffer in which to store the encrypted key.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to
+    /// `dout` or Err(e) containing the wolfSSL library error code value.
+    #[cfg(all(feature = "alloc", pkcs8_encrypt, random))]
+    fn export_encrypted_pkcs8_der(&self, password: &[u8], iterations: i32,
+            rng: &RNG, dout: &mut [u8]) -> Result<usize, Error> {
+        let mut der = vec![0u8; MAX_DER_SIZE];
+        let result = self.export_pkcs8_der(&mut der).and_then(|der_size| {
+            encrypt_pkcs8(&mut der[..der_size], password, iterations, rng, dout)
+        });
+        der.as_mut_slice().zeroize();
+        result
+    }
+}
+
+/// A private key which can be decoded from a PKCS #8 `PrivateKeyInfo`.
+pub trait Pkcs8Import: Sized {
+    /// Decode a private key from PKCS #8 DER.
+    ///
+    /// # Parameters
+    ///
+    /// * `der`: Buffer containing the DER encoded key.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(key) containing the decoded key or Err(e)
+    /// containing the wolfSSL library error code value.
+    fn import_pkcs8_der(der: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error>;
+
+    /// Decode a private key from a PEM "PRIVATE KEY".
+    ///
+    /// # Parameters
+    ///
+    /// * `pem`: Buffer containing the PEM encoded key.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(key) containing the decoded key or Err(e)
+    /// containing the wolfSSL library error code value.
+    #[cfg(all(feature = "alloc", key_pem_to_der))]
+    fn import_pkcs8_pem(pem: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        let pem_size = crate::buffer_len_to_i32(pem.len())?;
+        let mut der = vec![0u8; pem.len()];
+        let rc = unsafe {
+            sys::wc_KeyPemToDer(pem.as_ptr(), pem_size, der.as_mut_ptr(),
+                pem_size, core::ptr::null())
+        };
+        let result = if rc < 0 {
+            Err(Error::from(rc))
+        } else {
+            Self::import_pkcs8_der(&der[..rc as usize], wolfcrypt)
+        };
+        der.as_mut_slice().zeroize();
+        result
+    }
+
+    /// Decode a private key from a password encrypted PKCS #8
+    /// `EncryptedPrivateKeyInfo` in DER format.
+    ///
+    /// # Parameters
+    ///
+    /// * `der`: Buffer containing the DER encoded encrypted key.
+    /// * `password`: Password to derive the decryption key from.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(key) containing the decoded key or Err(e)
+    /// containing the wolfSSL library error code value.
+    #[cfg(all(feature = "alloc", pkcs8_encrypt))]
+    fn import_encrypted_pkcs8_der(der: &[u8], password: &[u8], wolfcrypt: &WolfCrypt)
+            -> Result<Self, Error> {
+        let der_size = crate::buffer_len_to_u32(der.len())?;
+        let password_size = crate::buffer_len_to_i32(password.len())?;
+        let mut decrypted = der.to_vec();
+        let rc = unsafe {
+            sys::wc_DecryptPKCS8Key(decrypted.as_mut_ptr(), der_size,
+                password.as_ptr() as *const core::ffi::c_char, password_size)
+        };
+        let result = if rc < 0 {
+            Err(Error::from(rc))
+        } else {
+            Self::import_pkcs8_der(&decrypted[..rc as usize], wolfcrypt)
+        };
+        decrypted.as_mut_slice().zeroize();
+        result
+    }
+}
+
+/// A public key which can be encoded as an X.509 `SubjectPublicKeyInfo`.
+pub trait SpkiExport {
+    /// Encode the public key as SPKI DER.
+    ///
+    /// # Parameters
+    ///
+    /// * `dout`: Output buffer in which to store the DER encoded key.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to
+    /// `dout` or Err(e) containing the wolfSSL library error code value.
+    fn export_spki_der(&self, dout: &mut [u8]) -> Result<usize, Error>;
+
+    /// Encode the public key as a PEM "PUBLIC KEY".
+    ///
+    /// # Parameters
+    ///
+    /// * `dout`: Output buffer in which to store the PEM encoded key.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to
+    /// `dout` or Err(e) containing the wolfSSL library error code value.
+    #[cfg(all(feature = "alloc", der_to_pem))]
+    fn export_spki_pem(&self, dout: &mut [u8]) -> Result<usize, Error> {
+        let mut der = vec![0u8; MAX_DER_SIZE];
+        let der_size = self.export_spki_der(&mut der)?;
+        der_to_pem(&der[..der_size], sys::CertType_PUBLICKEY_TYPE as i32, dout)
+    }
+}
+
+/// A public key which can be decoded from an X.509 `SubjectPublicKeyInfo`.
+pub trait SpkiImport: Sized {
+    /// Decode a public key from SPKI DER.
+    ///
+    /// # Parameters
+    ///
+    /// * `der`: Buffer containing the DER encoded key.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(key) containing the decoded key or Err(e)
+    /// containing the wolfSSL library error code value.
+    fn import_spki_der(der: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error>;
+
+    /// Decode a public key from a PEM "PUBLIC KEY".
+    ///
+    /// # Parameters
+    ///
+    /// * `pem`: Buffer containing the PEM encoded key.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(key) containing the decoded key or Err(e)
+    /// containing the wolfSSL library error code value.
+    #[cfg(all(feature = "alloc", pub_key_pem_to_der))]
+    fn import_spki_pem(pem: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        let pem_size = crate::buffer_len_to_i32(pem.len())?;
+        let mut der = vec![0u8; pem.len()];
+        let rc = unsafe {
+            sys::wc_PubKeyPemToDer(pem.as_ptr(), pem_size, der.as_mut_ptr(),
+                pem_size)
+        };
+        if rc < 0 {
+            return Err(Error::from(rc));
+        }
+        Self::import_spki_der(&der[..rc as usize], wolfcrypt)
+    }
+}
+
+#[cfg(all(feature = "alloc", der_to_pem))]
+fn der_to_pem(der: &[u8], pem_type: i32, dout: &mut [u8]) -> Result<usize, Error> {
+    let der_size = crate::buffer_len_to_u32(der.len())?;
+    let dout_size = crate::buffer_len_to_u32(dout.len())?;
+    let rc = unsafe {
+        sys::wc_DerToPem(der.as_ptr(), der_size, dout.as_mut_ptr(), dout_size,
+            pem_type)
+    };
+    if rc < 0 {
+        return Err(Error::from(rc));
+    }
+    Ok(rc as usize)
+}
+
+#[cfg(all(feature = "alloc", pkcs8_encrypt, random))]
+fn encrypt_pkcs8(der: &mut [u8], password: &[u8], iterations: i32, rng: &RNG,
+        dout: &mut [u8]) -> Result<usize, Error> {
+    let der_size = crate::buffer_len_to_u32(der.len())?;
+    let password_size = crate::buffer_len_to_i32(password.len())?;
+    let mut dout_size = crate::buffer_len_to_u32(dout.len())?;
+    /* The ENC_PKCS8_ALG_* values only match the OID sums when wolfSSL is
+     * built with WOLFSSL_OLD_OID_SUM, so pass the block cipher OID sum. */
+    let rc = unsafe {
+        sys::wc_EncryptPKCS8Key_ex(der.as_mut_ptr(), der_size,
+            dout.as_mut_ptr(), &mut dout_size,
+            password.as_ptr() as *const core::ffi::c_char, password_size,
+            sys::EncPkcs8Types_ENC_PKCS8_VER_PKCS5 as i32,
+            sys::EncPkcs8Types_ENC_PKCS8_PBES2 as i32,
+            sys::Block_Sum_AES256CBCb as i32,
+            core::ptr::null_mut(), 0, iterations,
+            sys::HMAC_Sum_HMAC_SHA256_OID as i32, rng.wc_rng,
+            core::ptr::null_mut())
+    };
+    if rc < 0 {
+        return Err(Error::from(rc));
+    }
+    Ok(rc as usize)
+}
+
+/// Check the return value of a wolfSSL function returning an encoded size.
+fn encoded_size(rc: i32) -> Result<usize, Error> {
+    if rc < 0 {
+        return Err(Error::from(rc));
+    }
+    Ok(rc as usize)
+}
+
+/* RSA */
+
+#[cfg(all(rsa, rsa_key_to_der))]
+impl Pkcs8Export for crate::rsa::RSA<'_> {
+    fn export_pkcs8_der(&self, dout: &mut [u8]) -> Result<usize, Error> {
+        let mut key_der = [0u8; MAX_RSA_KEY_DER_SIZE];
+        let rc = unsafe {
+            sys::wc_RsaKeyToDer(&self.wc_rsakey as *const _ as *mut _,
+                key_der.as_mut_ptr(), MAX_RSA_KEY_DER_SIZE as u32)
+        };
+        let result = encoded_size(rc).and_then(|key_der_size| {
+            let key_der = &mut key_der[..key_der_size];
+            let key_der_size = key_der_size as u32;
+            /* wc_CreatePKCS8Key() does not check the output buffer size, so
+             * query the encoded size first. */
+            let mut pkcs8_size = 0u32;
+            let rc = unsafe {
+                sys::wc_CreatePKCS8Key(core::ptr::null_mut(), &mut pkcs8_size,
+                    key_der.as_mut_ptr(), key_der_size,
+                    sys::Key_Sum_RSAk as i32, core::ptr::null(), 0)
+            };
+            if rc != sys::wolfCrypt_ErrorCodes_LENGTH_ONLY_E {
+                return Err(Error::from(rc));
+            }
+            if dout.len() < pkcs8_size as usize {
+                return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
+            }
+            let rc = unsafe {
+                sys::wc_CreatePKCS8Key(dout.as_mut_ptr(), &mut pkcs8_size,
+                    key_der.as_mut_ptr(), key_der_size,
+                    sys::Key_Sum_RSAk as i32, core::ptr::null(), 0)
+            };
+            encoded_size(rc)
+        });
+        key_der.zeroize();
+        result
+    }
+}
+
+#[cfg(rsa)]
+impl Pkcs8Import for crate::rsa::RSA<'_> {
+    fn import_pkcs8_der(der: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        crate::rsa::RSA::new_from_der(der, wolfcrypt)
+    }
+}
+
+#[cfg(all(rsa, rsa_key_to_public_der))]
+impl SpkiExport for crate::rsa::RSA<'_> {
+    fn export_spki_der(&self, dout: &mut [u8]) -> Result<usize, Error> {
+        let dout_size = crate::buffer_len_to_u32(dout.len())?;
+        let rc = unsafe {
+            sys::wc_RsaKeyToPublicDer(&self.wc_rsakey as *const _ as *mut _,
+                dout.as_mut_ptr(), dout_size)
+        };
+        encoded_size(rc)
+    }
+}
+
+#[cfg(rsa)]
+impl SpkiImport for crate::rsa::RSA<'_> {
+    fn import_spki_der(der: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        crate::rsa::RSA::new_public_from_der(der, wolfcrypt)
+    }
+}
+
+/* ECC */
+
+#[cfg(all(ecc, ecc_key_to_pkcs8))]
+impl Pkcs8Export for crate::ecc::ECC<'_> {
+    fn export_pkcs8_der(&self, dout: &mut [u8]) -> Result<usize, Error> {
+        let mut dout_size = crate::buffer_len_to_u32(dout.len())?;
+        let rc = unsafe {
+            sys::wc_EccKeyToPKCS8(self.wc_ecc_key, dout.as_mut_ptr(), &mut dout_size)
+        };
+        encoded_size(rc)
+    }
+}
+
+#[cfg(ecc)]
+impl Pkcs8Import for crate::ecc::ECC<'_> {
+    fn import_pkcs8_der(der: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        crate::ecc::ECC::import_der(der, &crate::Env::new(wolfcrypt))
+    }
+}
+
+#[cfg(all(ecc, ecc_export))]
+impl SpkiExport for crate::ecc::ECC<'_> {
+    fn export_spki_der(&self, dout: &mut [u8]) -> Result<usize, Error> {
+        let dout_size = crate::buffer_len_to_u32(dout.len())?;
+        let rc = unsafe {
+            sys::wc_EccPublicKeyToDer(self.wc_ecc_key, dout.as_mut_ptr(), dout_size, 1)
+        };
+        encoded_size(rc)
+    }
+}
+
+#[cfg(ecc)]
+impl SpkiImport for crate::ecc::ECC<'_> {
+    fn import_spki_der(der: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        crate::ecc::ECC::import_public_der(der, &crate::Env::new(wolfcrypt))
+    }
+}
+
+/* Ed25519 */
+
+#[cfg(all(ed25519, ed25519_export))]
+impl Pkcs8Export for crate::ed25519::Ed25519<'_> {
+    fn export_pkcs8_der(&self, dout: &mut [u8]) -> Result<usize, Error> {
+        let dout_size = crate::buffer_len_to_u32(dout.len())?;
+        let rc = unsafe {
+            sys::wc_Ed25519PrivateKeyToDer(&self.ws_key, dout.as_mut_ptr(), dout_size)
+        };
+        encoded_size(rc)
+    }
+}
+
+#[cfg(all(ed25519, ed25519_import))]
+impl Pkcs8Import for crate::ed25519::Ed25519<'_> {
+    fn import_pkcs8_der(der: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        let mut key = crate::ed25519::Ed25519::new(wolfcrypt)?;
+        let der_size = crate::buffer_len_to_u32(der.len())?;
+        let mut idx = 0u32;
+        let rc = unsafe {
+            sys::wc_Ed25519PrivateKeyDecode(der.as_ptr(), &mut idx, &mut key.ws_key,
+                der_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        /* The public key is optional in the PKCS #8 encoding but is needed
+         * for signing. */
+        let mut public = [0u8; crate::ed25519::Ed25519::PUB_KEY_SIZE];
+        key.make_public(&mut public)?;
+        key.import_public(&public)?;
+        Ok(key)
+    }
+}
+
+#[cfg(all(ed25519, ed25519_export))]
+impl SpkiExport for crate::ed25519::Ed25519<'_> {
+    fn export_spki_der(&self, dout: &mut [u8]) -> Result<usize, Error> {
+        let dout_size = crate::buffer_len_to_u32(dout.len())?;
+        let rc = unsafe {
+            sys::wc_Ed25519PublicKeyToDer(&self.ws_key, dout.as_mut_ptr(), dout_size, 1)
+        };
+        encoded_size(rc)
+    }
+}
+
+#[cfg(all(ed25519, ed25519_import))]
+impl SpkiImport for crate::ed25519::Ed25519<'_> {
+    fn import_spki_der(der: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        let mut key = crate::ed25519::Ed25519::new(wolfcrypt)?;
+        let der_size = crate::buffer_len_to_u32(der.len())?;
+        let mut idx = 0u32;
+        let rc = unsafe {
+            sys::wc_Ed25519PublicKeyDecode(der.as_ptr(), &mut idx, &mut key.ws_key,
+                der_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(key)
+    }
+}
+
+/* Ed448 */
+
+#[cfg(all(ed448, ed448_export))]
+impl Pkcs8Export for crate::ed448::Ed448<'_> {
+    fn export_pkcs8_der(&self, dout: &mut [u8]) -> Result<usize, Error> {
+        let dout_size = crate::buffer_len_to_u32(dout.len())?;
+        let rc = unsafe {
+            sys::wc_Ed448PrivateKeyToDer(&self.ws_key, dout.as_mut_ptr(), dout_size)
+        };
+        encoded_size(rc)
+    }
+}
+
+#[cfg(all(ed448, ed448_import))]
+impl Pkcs8Import for crate::ed448::Ed448<'_> {
+    fn import_pkcs8_der(der: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        let mut key = crate::ed448::Ed448::new(wolfcrypt)?;
+        let der_size = crate::buffer_len_to_u32(der.len())?;
+        let mut idx = 0u32;
+        let rc = unsafe {
+            sys::wc_Ed448PrivateKeyDecode(der.as_ptr(), &mut idx, &mut key.ws_key,
+                der_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        /* The public key is optional in the PKCS #8 encoding but is needed
+         * for signing. */
+        let mut public = [0u8; crate::ed448::Ed448::PUB_KEY_SIZE];
+        key.make_public(&mut public)?;
+        key.import_public(&public)?;
+        Ok(key)
+    }
+}
+
+#[cfg(all(ed448, ed448_export))]
+impl SpkiExport for crate::ed448::Ed448<'_> {
+    fn export_spki_der(&self, dout: &mut [u8]) -> Result<usize, Error> {
+        let dout_size = crate::buffer_len_to_u32(dout.len())?;
+        let rc = unsafe {
+            sys::wc_Ed448PublicKeyToDer(&self.ws_key, dout.as_mut_ptr(), dout_size, 1)
+        };
+        encoded_size(rc)
+    }
+}
+
+#[cfg(all(ed448, ed448_import))]
+impl SpkiImport for crate::ed448::Ed448<'_> {
+    fn import_spki_der(der: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        let mut key = crate::ed448::Ed448::new(wolfcrypt)?;
+        let der_size = crate::buffer_len_to_u32(der.len())?;
+        let mut idx = 0u32;
+        let rc = unsafe {
+            sys::wc_Ed448PublicKeyDecode(der.as_ptr(), &mut idx, &mut key.ws_key,
+                der_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(key)
+    }
+}
+
+/* X25519 */
+
+#[cfg(all(curve25519, curve25519_key_to_der))]
+impl Pkcs8Export for crate::curve25519::Curve25519Key {
+    fn export_pkcs8_der(&self, dout: &mut [u8]) -> Result<usize, Error> {
+        let dout_size = crate::buffer_len_to_u32(dout.len())?;
+        let rc = unsafe {
+            sys::wc_Curve25519PrivateKeyToDer(&self.wc_key as *const _ as *mut _,
+                dout.as_mut_ptr(), dout_size)
+        };
+        encoded_size(rc)
+    }
+}
+
+#[cfg(all(curve25519, curve25519_key_decode))]
+impl Pkcs8Import for crate::curve25519::Curve25519Key {
+    fn import_pkcs8_der(der: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        let mut key = crate::curve25519::Curve25519Key::new_empty(wolfcrypt)?;
+        let der_size = crate::buffer_len_to_u32(der.len())?;
+        let mut idx = 0u32;
+        let rc = unsafe {
+            sys::wc_Curve25519PrivateKeyDecode(der.as_ptr(), &mut idx, &mut key.wc_key,
+                der_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(key)
+    }
+}
+
+#[cfg(all(curve25519, curve25519_key_to_der))]
+impl SpkiExport for crate::curve25519::Curve25519Key {
+    fn export_spki_der(&self, dout: &mut [u8]) -> Result<usize, Error> {
+        let dout_size = crate::buffer_len_to_u32(dout.len())?;
+        let rc = unsafe {
+            sys::wc_Curve25519PublicKeyToDer(&self.wc_key as *const _ as *mut _,
+                dout.as_mut_ptr(), dout_size, 1)
+        };
+        encoded_size(rc)
+    }
+}
+
+#[cfg(all(curve25519, curve25519_key_decode))]
+impl SpkiImport for crate::curve25519::Curve25519Key {
+    fn import_spki_der(der: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        let mut key = crate::curve25519::Curve25519Key::new_empty(wolfcrypt)?;
+        let der_size = crate::buffer_len_to_u32(der.len())?;
+        let mut idx = 0u32;
+        let rc = unsafe {
+            sys::wc_Curve25519PublicKeyDecode(der.as_ptr(), &mut idx, &mut key.wc_key,
+                der_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(key)
+    }
+}
+
+/* X448 */
+
+#[cfg(all(curve448, curve448_key_to_der))]
+impl Pkcs8Export for crate::curve448::X448 {
+    fn export_pkcs8_der(&self, dout: &mut [u8]) -> Result<usize, Error> {
+        let dout_size = crate::buffer_len_to_u32(dout.len())?;
+        let rc = unsafe {
+            sys::wc_Curve448PrivateKeyToDer(&self.wc_key as *const _ as *mut _,
+                dout.as_mut_ptr(), dout_size)
+        };
+        encoded_size(rc)
+    }
+}
+
+#[cfg(all(curve448, curve448_key_decode))]
+impl Pkcs8Import for crate::curve448::X448 {
+    fn import_pkcs8_der(der: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        let mut key = crate::curve448::X448::new_empty(wolfcrypt)?;
+        let der_size = crate::buffer_len_to_u32(der.len())?;
+        let mut idx = 0u32;
+        let rc = unsafe {
+            sys::wc_Curve448PrivateKeyDecode(der.as_ptr(), &mut idx, &mut key.wc_key,
+                der_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(key)
+    }
+}
+
+#[cfg(all(curve448, curve448_key_to_der))]
+impl SpkiExport for crate::curve448::X448 {
+    fn export_spki_der(&self, dout: &mut [u8]) -> Result<usize, Error> {
+        let dout_size = crate::buffer_len_to_u32(dout.len())?;
+        let rc = unsafe {
+            sys::wc_Curve448PublicKeyToDer(&self.wc_key as *const _ as *mut _,
+                dout.as_mut_ptr(), dout_size, 1)
+        };
+        encoded_size(rc)
+    }
+}
+
+#[cfg(all(curve448, curve448_key_decode))]
+impl SpkiImport for crate::curve448::X448 {
+    fn import_spki_der(der: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        let mut key = crate::curve448::X448::new_empty(wolfcrypt)?;
+        let der_size = crate::buffer_len_to_u32(der.len())?;
+        let mut idx = 0u32;
+        let rc = unsafe {
+            sys::wc_Curve448PublicKeyDecode(der.as_ptr(), &mut idx, &mut key.wc_key,
+                der_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(key)
+    }
+}
+
+/* DH */
+
+#[cfg(all(dh, dh_key_to_der))]
+impl Pkcs8Export for crate::dh::DH<'_> {
+    fn export_pkcs8_der(&self, dout: &mut [u8]) -> Result<usize, Error> {
+        let mut dout_size = crate::buffer_len_to_u32(dout.len())?;
+        let rc = unsafe {
+            sys::wc_DhPrivKeyToDer(&self.wc_dhkey as *const _ as *mut _,
+                dout.as_mut_ptr(), &mut dout_size)
+        };
+        encoded_size(rc)
+    }
+}
+
+#[cfg(all(dh, dh_key_to_der))]
+impl Pkcs8Import for crate::dh::DH<'_> {
+    fn import_pkcs8_der(der: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        if dh_first_inner_tag(der) != Some(0x02) {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_ASN_PARSE_E));
+        }
+        let mut key = crate::dh::DH::new_empty(wolfcrypt)?;
+        let der_size = crate::buffer_len_to_u32(der.len())?;
+        let mut idx = 0u32;
+        let rc = unsafe {
+            sys::wc_DhKeyDecode(der.as_ptr(), &mut idx, &mut key.wc_dhkey, der_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(key)
+    }
+}
+
+#[cfg(all(dh, dh_key_to_der))]
+impl SpkiExport for crate::dh::DH<'_> {
+    fn export_spki_der(&self, dout: &mut [u8]) -> Result<usize, Error> {
+        let mut dout_size = crate::buffer_len_to_u32(dout.len())?;
+        let rc = unsafe {
+            sys::wc_DhPubKeyToDer(&self.wc_dhkey as *const _ as *mut _,
+                dout.as_mut_ptr(), &mut dout_size)
+        };
+        encoded_size(rc)
+    }
+}
+
+#[cfg(all(dh, dh_key_to_der))]
+impl SpkiImport for crate::dh::DH<'_> {
+    fn import_spki_der(der: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        /* wc_DhKeyDecode() accepts both the PKCS #8 and SPKI encodings, so
+         * check that this is a SubjectPublicKeyInfo (AlgorithmIdentifier
+         * first) rather than a PrivateKeyInfo (version first). */
+        if dh_first_inner_tag(der) != Some(0x30) {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_ASN_PARSE_E));
+        }
+        let mut key = crate::dh::DH::new_empty(wolfcrypt)?;
+        let der_size = crate::buffer_len_to_u32(der.len())?;
+        let mut idx = 0u32;
+        let rc = unsafe {
+            sys::wc_DhKeyDecode(der.as_ptr(), &mut idx, &mut key.wc_dhkey, der_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(key)
+    }
+}
+
+/// Return the tag of the first element inside the outer SEQUENCE of a DER
+/// encoded key.
+#[cfg(all(dh, dh_key_to_der))]
+fn dh_first_inner_tag(der: &[u8]) -> Option<u8> {
+    if *der.first()? != 0x30 {
+        return None;
+    }
+    let len_byte = *der.get(1)?;
+    let header_size = if len_byte < 0x80 {
+        2
+    } else {
+        2 + (len_byte & 0x7f) as usize
+    };
+    der.get(header_size).copied()
+}
+
+/* ML-DSA */
+
+#[cfg(all(mldsa, mldsa_private_key_to_der))]
+impl Pkcs8Export for crate::mldsa::MlDsa<'_> {
+    fn export_pkcs8_der(&self, dout: &mut [u8]) -> Result<usize, Error> {
+        let dout_size = crate::buffer_len_to_u32(dout.len())?;
+        let rc = unsafe {
+            sys::wc_MlDsaKey_PrivateKeyToDer(&self.ws_key as *const _ as *mut _,
+                dout.as_mut_ptr(), dout_size)
+        };
+        encoded_size(rc)
+    }
+}
+
+#[cfg(all(mldsa, mldsa_private_key_to_der))]
+impl Pkcs8Import for crate::mldsa::MlDsa<'_> {
+    fn import_pkcs8_der(der: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        let mut key = crate::mldsa::MlDsa::new(wolfcrypt)?;
+        let der_size = crate::buffer_len_to_u32(der.len())?;
+        let mut idx = 0u32;
+        let rc = unsafe {
+            sys::wc_MlDsaKey_PrivateKeyDecode(&mut key.ws_key, der.as_ptr(), der_size,
+                &mut idx)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(key)
+    }
+}
+
+#[cfg(all(mldsa, mldsa_public_key_to_der))]
+impl SpkiExport for crate::mldsa::MlDsa<'_> {
+    fn export_spki_der(&self, dout: &mut [u8]) -> Result<usize, Error> {
+        let dout_size = crate::buffer_len_to_u32(dout.len())?;
+        let rc = unsafe {
+            sys::wc_MlDsaKey_PublicKeyToDer(&self.ws_key as *const _ as *mut _,
+                dout.as_mut_ptr(), dout_size, 1)
+        };
+        encoded_size(rc)
+    }
+}
+
+#[cfg(all(mldsa, mldsa_public_key_decode))]
+impl SpkiImport for crate::mldsa::MlDsa<'_> {
+    fn import_spki_der(der: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        let mut key = crate::mldsa::MlDsa::new(wolfcrypt)?;
+        let der_size = crate::buffer_len_to_u32(der.len())?;
+        let mut idx = 0u32;
+        let rc = unsafe {
+            sys::wc_MlDsaKey_PublicKeyDecode(&mut key.ws_key, der.as_ptr(), der_size,
+                &mut idx)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(key)
+    }
+}
+
+/* ML-KEM
+ *
+ * wolfCrypt has no ASN.1 support for ML-KEM keys, so the encodings of
+ * RFC 9935 are implemented here. */
+
+#[cfg(mlkem)]
+mod mlkem_der {
+    use crate::{Error, WolfCrypt};
+    use crate::mlkem::MlKem;
+    use crate::sys;
+
+    const TAG_INTEGER: u8 = 0x02;
+    const TAG_BIT_STRING: u8 = 0x03;
+    const TAG_OCTET_STRING: u8 = 0x04;
+    const TAG_OID: u8 = 0x06;
+    const TAG_SEQUENCE: u8 = 0x30;
+    const TAG_SEED: u8 = 0x80;
+
+    /// id-alg-ml-kem-* OID (2.16.840.1.101.3.4.4.x) without its last byte.
+    const OID_PREFIX: [u8; 8] = [0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x04];
+    /// Size of the DER encoded AlgorithmIdentifier.
+    const ALGORITHM_SIZE: usize = 2 + 2 + OID_PREFIX.len() + 1;
+    const SEED_SIZE: usize = 64;
+
+    /// Key type, last OID byte, public key size and expanded private key
+    /// size of each ML-KEM parameter set (FIPS 203).
+    const PARAMS: [(i32, u8, usize, usize); 3] = [
+        (MlKem::TYPE_512, 1, 800, 1632),
+        (MlKem::TYPE_768, 2, 1184, 2400),
+        (MlKem::TYPE_1024, 3, 1568, 3168),
+    ];
+
+    fn params_for_key(key: &MlKem) -> Result<(i32, u8, usize, usize), Error> {
+        let public_size = key.public_key_size()?;
+        PARAMS.iter().copied().find(|p| p.2 == public_size)
+            .ok_or(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG))
+    }
+
+    fn length_size(len: usize) -> usize {
+        match len {
+            0..=0x7f => 1,
+            0x80..=0xff => 2,
+            _ => 3,
+        }
+    }
+
+    struct Writer<'a> {
+        dout: &'a mut [u8],
+        idx: usize,
+    }
+
+    impl<'a> Writer<'a> {
+        fn header(&mut self, tag: u8, len: usize) -> Result<(), Error> {
+            let mut header = [tag, 0, 0, 0];
+            let header_size = match length_size(len) {
+                1 => {
+                    header[1] = len as u8;
+                    2
+                }
+                2 => {
+                    header[1] = 0x81;
+                    header[2] = len as u8;
+                    3
+                }
+                _ => {
+                    header[1] = 0x82;
+                    header[2] = (len >> 8) as u8;
+                    header[3] = len as u8;
+                    4
+                }
+            };
+            self.bytes(&header[..header_size])
+        }
+
+        fn bytes(&mut self, data: &[u8]) -> Result<(), Error> {
+            self.reserve(data.len())?.copy_from_slice(data);
+            Ok(())
+        }
+
+        fn reserve(&mut self, len: usize) -> Result<&mut [u8], Error> {
+            let start = self.idx;
+            self.idx += len;
+            self.dout.get_mut(start..self.idx).ok_or(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E))
+        }
+
+        fn algorithm(&mut self, oid_last: u8) -> Result<(), Error> {
+            self.header(TAG_SEQUENCE, ALGORITHM_SIZE - 2)?;
+            self.header(TAG_OID, OID_PREFIX.len() + 1)?;
+            self.bytes(&OID_PREFIX)?;
+            self.bytes(&[oid_last])
+        }
+    }
+
+    /// Read a DER element with the given tag at `*idx`, returning its
+    /// contents and advancing `*idx` past it.
+    fn read<'a>(input: &'a [u8], idx: &mut usize, tag: u8) -> Result<&'a [u8], Error> {
+        let err = Error::from(sys::wolfCrypt_ErrorCodes_ASN_PARSE_E);
+        if input.get(*idx) != Some(&tag) {
+            return Err(err);
+        }
+        let mut pos = *idx + 1;
+        let first = *input.get(pos).ok_or(err)? as usize;
+        pos += 1;
+        let len = match first {
+            0..=0x7f => first,
+            0x81 => {
+                let len = *input.get(pos).ok_or(err)? as usize;
+                pos += 1;
+                len
+            }
+            0x82 => {
+                let bytes = input.get(pos..pos + 2).ok_or(err)?;
+                pos += 2;
+                ((bytes[0] as usize) << 8) | bytes[1] as usize
+            }
+            _ => return Err(err),
+        };
+        let contents = input.get(pos..pos + len).ok_or(err)?;
+        *idx = pos + len;
+        Ok(contents)
+    }
+
+    /// Decode an AlgorithmIdentifier, returning the ML-KEM parameters.
+    fn read_algorithm(input: &[u8], idx: &mut usize) -> Result<(i32, u8, usize, usize), Error> {
+        let algorithm = read(input, idx, TAG_SEQUENCE)?;
+        let mut algorithm_idx = 0;
+        let oid = read(algorithm, &mut algorithm_idx, TAG_OID)?;
+        if algorithm_idx != algorithm.len() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_ASN_PARSE_E));
+        }
+        match oid.split_last() {
+            Some((last, prefix)) if prefix == OID_PREFIX => {
+                PARAMS.iter().copied().find(|p| p.1 == *last)
+                    .ok_or(Error::from(sys::wolfCrypt_ErrorCodes_ASN_UNKNOWN_OID_E))
+            }
+            _ => Err(Error::from(sys::wolfCrypt_ErrorCodes_ASN_UNKNOWN_OID_E)),
+        }
+    }
+
+    pub(super) fn export_pkcs8(key: &MlKem, dout: &mut [u8]) -> Result<usize, Error> {
+        let (_, oid_last, _, private_size) = params_for_key(key)?;
+        let inner_size = 1 + length_size(private_size) + private_size;
+        let body_size = 3 + ALGORITHM_SIZE + 1 + length_size(inner_size) + inner_size;
+        let mut writer = Writer { dout, idx: 0 };
+        writer.header(TAG_SEQUENCE, body_size)?;
+        writer.bytes(&[TAG_INTEGER, 1, 0])?;
+        writer.algorithm(oid_last)?;
+        writer.header(TAG_OCTET_STRING, inner_size)?;
+        writer.header(TAG_OCTET_STRING, private_size)?;
+        key.encode_private_key(writer.reserve(private_size)?)?;
+        Ok(writer.idx)
+    }
+
+    pub(super) fn import_pkcs8(der: &[u8], wolfcrypt: &WolfCrypt) -> Result<MlKem<'static>, Error> {
+        let err = Error::from(sys::wolfCrypt_ErrorCodes_ASN_PARSE_E);
+        let mut idx = 0;
+        let body = read(der, &mut idx, TAG_SEQUENCE)?;
+        if idx != der.len() {
+            return Err(err);
+        }
+        let mut body_idx = 0;
+        let version = read(body, &mut body_idx, TAG_INTEGER)?;
+        if version != [0] && version != [1] {
+            return Err(err);
+        }
+        let (key_type, _, _, private_size) = read_algorithm(body, &mut body_idx)?;
+        let inner = read(body, &mut body_idx, TAG_OCTET_STRING)?;
+        /* Any attributes and public key following are ignored. */
+        let mut inner_idx = 0;
+        let key = match inner.first() {
+            Some(&TAG_SEED) => {
+                let seed = read(inner, &mut inner_idx, TAG_SEED)?;
+                if seed.len() != SEED_SIZE {
+                    return Err(err);
+                }
+                MlKem::generate_with_random(key_type, seed, wolfcrypt)?
+            }
+            Some(&TAG_OCTET_STRING) => {
+                let expanded = read(inner, &mut inner_idx, TAG_OCTET_STRING)?;
+                if expanded.len() != private_size {
+                    return Err(err);
+                }
+                let mut key = MlKem::new(key_type, wolfcrypt)?;
+                key.decode_private_key(expanded)?;
+                key
+            }
+            Some(&TAG_SEQUENCE) => {
+                let both = read(inner, &mut inner_idx, TAG_SEQUENCE)?;
+                let mut both_idx = 0;
+                let seed = read(both, &mut both_idx, TAG_OCTET_STRING)?;
+                let expanded = read(both, &mut both_idx, TAG_OCTET_STRING)?;
+                if seed.len() != SEED_SIZE || expanded.len() != private_size
+                        || both_idx != both.len() {
+                    return Err(err);
+                }
+                /* The expanded key must be consistent with the seed. */
+                let key = MlKem::generate_with_random(key_type, seed, wolfcrypt)?;
+                let mut check = [0u8; 3168];
+                let check = &mut check[..private_size];
+                key.encode_private_key(check)?;
+                let consistent = check == expanded;
+                zeroize::Zeroize::zeroize(check);
+                if !consistent {
+                    return Err(Error::from(sys::wolfCrypt_ErrorCodes_PUBLIC_KEY_E));
+                }
+                key
+            }
+            _ => return Err(err),
+        };
+        if inner_idx != inner.len() {
+            return Err(err);
+        }
+        Ok(key)
+    }
+
+    pub(super) fn export_spki(key: &MlKem, dout: &mut [u8]) -> Result<usize, Error> {
+        let (_, oid_last, public_size, _) = params_for_key(key)?;
+        let bits_size = 1 + public_size;
+        let body_size = ALGORITHM_SIZE + 1 + length_size(bits_size) + bits_size;
+        let mut writer = Writer { dout, idx: 0 };
+        writer.header(TAG_SEQUENCE, body_size)?;
+        writer.algorithm(oid_last)?;
+        writer.header(TAG_BIT_STRING, bits_size)?;
+        writer.bytes(&[0])?;
+        key.encode_public_key(writer.reserve(public_size)?)?;
+        Ok(writer.idx)
+    }
+
+    pub(super) fn import_spki(der: &[u8], wolfcrypt: &WolfCrypt) -> Result<MlKem<'static>, Error> {
+        let err = Error::from(sys::wolfCrypt_ErrorCodes_ASN_PARSE_E);
+        let mut idx = 0;
+        let body = read(der, &mut idx, TAG_SEQUENCE)?;
+        if idx != der.len() {
+            return Err(err);
+        }
+        let mut body_idx = 0;
+        let (key_type, _, public_size, _) = read_algorithm(body, &mut body_idx)?;
+        let bits = read(body, &mut body_idx, TAG_BIT_STRING)?;
+        if body_idx != body.len() || bits.len() != 1 + public_size || bits[0] != 0 {
+            return Err(err);
+        }
+        let mut key = MlKem::new(key_type, wolfcrypt)?;
+        key.decode_public_key(&bits[1..])?;
+        Ok(key)
+    }
+}
+
+#[cfg(mlkem)]
+impl Pkcs8Export for crate::mlkem::MlKem<'_> {
+    fn export_pkcs8_der(&self, dout: &mut [u8]) -> Result<usize, Error> {
+        mlkem_der::export_pkcs8(self, dout)
+    }
+}
+
+#[cfg(mlkem)]
+impl Pkcs8Import for crate::mlkem::MlKem<'_> {
+    fn import_pkcs8_der(der: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        mlkem_der::import_pkcs8(der, wolfcrypt)
+    }
+}
+
+#[cfg(mlkem)]
+impl SpkiExport for crate::mlkem::MlKem<'_> {
+    fn export_spki_der(&self, dout: &mut [u8]) -> Result<usize, Error> {
+        mlkem_der::export_spki(self, dout)
+    }
+}
+
+#[cfg(mlkem)]
+impl SpkiImport for crate::mlkem::MlKem<'_> {
+    fn import_spki_der(der: &[u8], wolfcrypt: &WolfCrypt) -> Result<Self, Error> {
+        mlkem_der::import_spki(der, wolfcrypt)
+    }
+}
@@ -0,0 +1,206 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+RustCrypto `pkcs8` and `spki` trait implementations for the wolfCrypt key
+types.
+
+Every type implementing [`crate::pkcs8::Pkcs8Export`],
+[`crate::pkcs8::Pkcs8Import`], [`crate::pkcs8::SpkiExport`] or
+[`crate::pkcs8::SpkiImport`] also implements the corresponding RustCrypto
+trait:
+
+| wolfCrypt trait | RustCrypto trait                                     |
+|-----------------|------------------------------------------------------|
+| `Pkcs8Export`   | [`pkcs8::EncodePrivateKey`]                          |
+| `Pkcs8Import`   | [`pkcs8::DecodePrivateKey`] (via `TryFrom<PrivateKeyInfo>`) |
+| `SpkiExport`    | [`spki::EncodePublicKey`]                            |
+| `SpkiImport`    | [`spki::DecodePublicKey`] (via `TryFrom<SubjectPublicKeyInfoRef>`) |
+
+wolfCrypt error codes are reported as `KeyMalformed`.
+
+# Examples
+
+```rust
+#[cfg(all(ecc, ecc_key_to_pkcs8, feature = "pkcs8"))]
+{
+use pkcs8::{DecodePrivateKey, EncodePrivateKey};
+use wolfssl_wolfcrypt::ecc::ECC;
+
+let der = std::fs::read("../../../certs/ecc-keyPkcs8.der").expect("Error reading key");
+let key = ECC::from_pkcs8_der(&der).expect("Error with from_pkcs8_der()");
+let doc = key.to_pkcs8_der().expect("Error with to_pkcs8_der()");
+assert_eq!(doc.as_bytes(), &der[..]);
+}
+```
+*/
+
+#![cfg(all(pkcs8, feature = "pkcs8"))]
+
+use alloc::vec;
+use ::pkcs8::der::Encode;
+use zeroize::Zeroize;
+
+macro_rules! impl_encode_private_key {
+    ($ty:ty) => {
+        impl ::pkcs8::EncodePrivateKey for $ty {
+            fn to_pkcs8_der(&self) -> ::pkcs8::Result<::pkcs8::SecretDocument> {
+                let mut der = vec![0u8; crate::pkcs8::MAX_DER_SIZE];
+                let result = crate::pkcs8::Pkcs8Export::export_pkcs8_der(self, &mut der)
+                    .map_err(|_| ::pkcs8::Error::KeyMalformed)
+                    .and_then(|der_size| {
+                        ::pkcs8::SecretDocument::try_from(&der[..der_size])
+                            .map_err(::pkcs8::Error::from)
+                    });
+                der.as_mut_slice().zeroize();
+                result
+            }
+        }
+    };
+}
+
+macro_rules! impl_decode_private_key {
+    ($ty:ty) => {
+        impl TryFrom<::pkcs8::PrivateKeyInfo<'_>> for $ty {
+            type Error = ::pkcs8::Error;
+
+            fn try_from(info: ::pkcs8::PrivateKeyInfo<'_>) -> ::pkcs8::Result<Self> {
+                let mut der = info.to_der()?;
+                let result = crate::WolfCrypt::global()
+                    .and_then(|wolfcrypt| {
+                        <$ty as crate::pkcs8::Pkcs8Import>::import_pkcs8_der(&der, wolfcrypt)
+                    })
+                    .map_err(|_| ::pkcs8::Error::KeyMalformed);
+                der.as_mut_slice().zeroize();
+                result
+            }
+        }
+    };
+}
+
+macro_rules! impl_encode_public_key {
+    ($ty:ty) => {
+        impl ::spki::EncodePublicKey for $ty {
+            fn to_public_key_der(&self) -> ::spki::Result<::spki::Document> {
+                let mut der = vec![0u8; crate::pkcs8::MAX_DER_SIZE];
+                let der_size = crate::pkcs8::SpkiExport::export_spki_der(self, &mut der)
+                    .map_err(|_| ::spki::Error::KeyMalformed)?;
+                Ok(::spki::Document::try_from(&der[..der_size])?)
+            }
+        }
+    };
+}
+
+macro_rules! impl_decode_public_key {
+    ($ty:ty) => {
+        impl TryFrom<::spki::SubjectPublicKeyInfoRef<'_>> for $ty {
+            type Error = ::spki::Error;
+
+            fn try_from(info: ::spki::SubjectPublicKeyInfoRef<'_>) -> ::spki::Result<Self> {
+                let der = info.to_der()?;
+                let wolfcrypt = crate::WolfCrypt::global()
+                    .map_err(|_| ::spki::Error::KeyMalformed)?;
+                <$ty as crate::pkcs8::SpkiImport>::import_spki_der(&der, wolfcrypt)
+                    .map_err(|_| ::spki::Error::KeyMalformed)
+            }
+        }
+    };
+}
+
+#[cfg(all(rsa, rsa_key_to_der))]
+impl_encode_private_key!(crate::rsa::RSA<'_>);
+#[cfg(rsa)]
+impl_decode_private_key!(crate::rsa::RSA<'_>);
+#[cfg(all(rsa, rsa_key_to_public_der))]
+impl_encode_public_key!(crate::rsa::RSA<'_>);
+#[cfg(rsa)]
+impl_decode_public_key!(crate::rsa::RSA<'_>);
+
+#[cfg(all(ecc, ecc_key_to_pkcs8))]
+impl_encode_private_key!(crate::ecc::ECC<'_>);
+#[cfg(ecc)]
+impl_decode_private_key!(crate::ecc::ECC<'_>);
+#[cfg(all(ecc, ecc_export))]
+impl_encode_public_key!(crate::ecc::ECC<'_>);
+#[cfg(ecc)]
+impl_decode_public_key!(crate::ecc::ECC<'_>);
+
+#[cfg(all(ed25519, ed25519_export))]
+impl_encode_private_key!(crate::ed25519::Ed25519<'_>);
+#[cfg(all(ed25519, ed25519_import))]
+impl_decode_private_key!(crate::ed25519::Ed25519<'_>);
+#[cfg(all(ed25519, ed25519_export))]
+impl_encode_public_key!(crate::ed25519::Ed25519<'_>);
+#[cfg(all(ed25519, ed25519_import))]
+impl_decode_public_key!(crate::ed25519::Ed25519<'_>);
+
+#[cfg(all(ed448, ed448_export))]
+impl_encode_private_key!(crate::ed448::Ed448<'_>);
+#[cfg(all(ed448, ed448_import))]
+impl_decode_private_key!(crate::ed448::Ed448<'_>);
+#[cfg(all(ed448, ed448_export))]
+impl_encode_public_key!(crate::ed448::Ed448<'_>);
+#[cfg(all(ed448, ed448_import))]
+impl_decode_public_key!(crate::ed448::Ed448<'_>);
+
+#[cfg(all(curve25519, curve25519_key_to_der))]
+impl_encode_private_key!(crate::curve25519::Curve25519Key);
+#[cfg(all(curve25519, curve25519_key_decode))]
+impl_decode_private_key!(crate::curve25519::Curve25519Key);
+#[cfg(all(curve25519, curve25519_key_to_der))]
+impl_encode_public_key!(crate::curve25519::Curve25519Key);
+#[cfg(all(curve25519, curve25519_key_decode))]
+impl_decode_public_key!(crate::curve25519::Curve25519Key);
+
+#[cfg(all(curve448, curve448_key_to_der))]
+impl_encode_private_key!(crate::curve448::X448);
+#[cfg(all(curve448, curve448_key_decode))]
+impl_decode_private_key!(crate::curve448::X448);
+#[cfg(all(curve448, curve448_key_to_der))]
+impl_encode_public_key!(crate::curve448::X448);
+#[cfg(all(curve448, curve448_key_decode))]
+impl_decode_public_key!(crate::curve448::X448);
+
+#[cfg(all(dh, dh_key_to_der))]
+impl_encode_private_key!(crate::dh::DH<'_>);
+#[cfg(all(dh, dh_key_to_der))]
+impl_decode_private_key!(crate::dh::DH<'_>);
+#[cfg(all(dh, dh_key_to_der))]
+impl_encode_public_key!(crate::dh::DH<'_>);
+#[cfg(all(dh, dh_key_to_der))]
+impl_decode_public_key!(crate::dh::DH<'_>);
+
+#[cfg(all(mldsa, mldsa_private_key_to_der))]
+impl_encode_private_key!(crate::mldsa::MlDsa<'_>);
+#[cfg(all(mldsa, mldsa_private_key_to_der))]
+impl_decode_private_key!(crate::mldsa::MlDsa<'_>);
+#[cfg(all(mldsa, mldsa_public_key_to_der))]
+impl_encode_public_key!(crate::mldsa::MlDsa<'_>);
+#[cfg(all(mldsa, mldsa_public_key_decode))]
+impl_decode_public_key!(crate::mldsa::MlDsa<'_>);
+
+#[cfg(mlkem)]
+impl_encode_private_key!(crate::mlkem::MlKem<'_>);
+#[cfg(mlkem)]
+impl_decode_private_key!(crate::mlkem::MlKem<'_>);
+#[cfg(mlkem)]
+impl_encode_public_key!(crate::mlkem::MlKem<'_>);
+#[cfg(mlkem)]
+impl_decode_public_key!(crate::mlkem::MlKem<'_>);
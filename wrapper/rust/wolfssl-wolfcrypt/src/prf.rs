@@ -25,6 +25,7 @@ Function (PRF) functionality.
 
 #![cfg(prf)]
 
+use crate::Error;
 use crate::sys;
 
 pub const PRF_HASH_NONE: i32 = sys::wc_MACAlgorithm_no_mac as i32;
@@ -45,6 +46,7 @@ pub const PRF_HASH_SM3: i32 = sys::wc_MACAlgorithm_sm3_mac as i32;
 /// * `secret`: Secret key.
 /// * `seed`: Seed.
 /// * `hash_type`: PRF Hash type, one of `PRF_HASH_*`.
+/// * `wolfcrypt`: Guard of the initialized library.
 /// * `dout`: Output buffer.
 ///
 /// # Returns
@@ -58,6 +60,8 @@ pub const PRF_HASH_SM3: i32 = sys::wc_MACAlgorithm_sm3_mac as i32;
 /// #[cfg(sha384)]
 /// {
 /// use wolfssl_wolfcrypt::prf::*;
+/// use wolfssl_wolfcrypt::WolfCrypt;
+/// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
 /// let secret = [0x10u8, 0xbc, 0xb4, 0xa2, 0xe8, 0xdc, 0xf1, 0x9b, 0x4c,
 ///     0x51, 0x9c, 0xed, 0x31, 0x1b, 0x51, 0x57, 0x02, 0x3f,
 ///     0xa1, 0x7d, 0xfb, 0x0e, 0xf3, 0x4e, 0x8f, 0x6f, 0x71,
@@ -72,11 +76,11 @@ pub const PRF_HASH_SM3: i32 = sys::wc_MACAlgorithm_sm3_mac as i32;
 ///     0x91, 0xde, 0x5c, 0xc0, 0x47, 0x7c, 0xa8, 0xae, 0xcf,
 ///     0x5d, 0x93, 0x5f, 0x4c, 0x92, 0xcc, 0x98, 0x5b, 0x43];
 /// let mut out = [0u8; 12];
-/// prf(&secret, &seed, PRF_HASH_SHA384, &mut out).expect("Error with prf()");
+/// prf(&secret, &seed, PRF_HASH_SHA384, &wolfcrypt, &mut out).expect("Error with prf()");
 /// }
 /// ```
-pub fn prf(secret: &[u8], seed: &[u8], hash_type: i32, dout: &mut [u8]) -> Result<(), i32> {
-    prf_ex(secret, seed, hash_type, None, None, dout)
+pub fn prf(secret: &[u8], seed: &[u8], hash_type: i32, wolfcrypt: &crate::WolfCrypt, dout: &mut [u8]) -> Result<(), Error> {
+    prf_ex(secret, seed, hash_type, &crate::Env::new(wolfcrypt), dout)
 }
 
 /// Pseudo Random Function for MD5, SHA-1, SHA-256, SHA-384, or SHA-512 with
@@ -87,8 +91,7 @@ pub fn prf(secret: &[u8], seed: &[u8], hash_type: i32, dout: &mut [u8]) -> Resul
 /// * `secret`: Secret key.
 /// * `seed`: Seed.
 /// * `hash_type`: PRF Hash type, one of `PRF_HASH_*`.
-/// * `heap`: Optional heap hint.
-/// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+/// * `env`: Heap hint and crypto callback device to use.
 /// * `dout`: Output buffer.
 ///
 /// # Returns
@@ -101,7 +104,10 @@ pub fn prf(secret: &[u8], seed: &[u8], hash_type: i32, dout: &mut [u8]) -> Resul
 /// ```rust
 /// #[cfg(sha384)]
 /// {
+/// use wolfssl_wolfcrypt::Env;
 /// use wolfssl_wolfcrypt::prf::*;
+/// use wolfssl_wolfcrypt::WolfCrypt;
+/// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
 /// let secret = [0x10u8, 0xbc, 0xb4, 0xa2, 0xe8, 0xdc, 0xf1, 0x9b, 0x4c,
 ///     0x51, 0x9c, 0xed, 0x31, 0x1b, 0x51, 0x57, 0x02, 0x3f,
 ///     0xa1, 0x7d, 0xfb, 0x0e, 0xf3, 0x4e, 0x8f, 0x6f, 0x71,
@@ -116,21 +122,15 @@ pub fn prf(secret: &[u8], seed: &[u8], hash_type: i32, dout: &mut [u8]) -> Resul
 ///     0x91, 0xde, 0x5c, 0xc0, 0x47, 0x7c, 0xa8, 0xae, 0xcf,
 ///     0x5d, 0x93, 0x5f, 0x4c, 0x92, 0xcc, 0x98, 0x5b, 0x43];
 /// let mut out = [0u8; 12];
-/// prf_ex(&secret, &seed, PRF_HASH_SHA384, None, None, &mut out).expect("Error with prf_ex()");
+/// prf_ex(&secret, &seed, PRF_HASH_SHA384, &Env::new(&wolfcrypt), &mut out).expect("Error with prf_ex()");
 /// }
 /// ```
-pub fn prf_ex(secret: &[u8], seed: &[u8], hash_type: i32, heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>, dout: &mut [u8]) -> Result<(), i32> {
+pub fn prf_ex(secret: &[u8], seed: &[u8], hash_type: i32, env: &crate::Env, dout: &mut [u8]) -> Result<(), Error> {
     let secret_size = crate::buffer_len_to_u32(secret.len())?;
     let seed_size = crate::buffer_len_to_u32(seed.len())?;
     let dout_size = crate::buffer_len_to_u32(dout.len())?;
-    let heap = match heap {
-        Some(heap) => heap,
-        None => core::ptr::null_mut(),
-    };
-    let dev_id = match dev_id {
-        Some(dev_id) => dev_id,
-        None => sys::INVALID_DEVID,
-    };
+    let heap = env.heap_ptr();
+    let dev_id = env.dev_id();
     let rc = unsafe {
         sys::wc_PRF(dout.as_mut_ptr(), dout_size,
             secret.as_ptr(), secret_size,
@@ -138,7 +138,7 @@ pub fn prf_ex(secret: &[u8], seed: &[u8], hash_type: i32, heap: Option<*mut core
             hash_type, heap, dev_id)
     };
     if rc != 0 {
-        return Err(rc);
+        return Err(Error::from(rc));
     }
     Ok(())
 }
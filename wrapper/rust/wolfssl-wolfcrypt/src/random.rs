@@ -29,9 +29,11 @@ wolfSSL `WC_RNG` object. It ensures proper initialization and deallocation.
 
 ```rust
 use wolfssl_wolfcrypt::random::RNG;
+use wolfssl_wolfcrypt::WolfCrypt;
+let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
 
 // Create a RNG instance.
-let rng = RNG::new().expect("Failed to create RNG");
+let rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
 
 // Generate a single random byte value.
 let byte = rng.generate_byte().expect("Failed to generate a single byte");
@@ -44,7 +46,10 @@ rng.generate_block(&mut buffer).expect("Failed to generate a block");
 
 #![cfg(random)]
 
+use crate::Error;
+use crate::memory::HeapHint;
 use crate::sys;
+use core::marker::PhantomData;
 use core::mem::size_of_val;
 use num_traits::PrimInt;
 
@@ -60,14 +65,23 @@ use num_traits::PrimInt;
 /// All generation methods take `&self`. The actual mutation of the DRBG state
 /// happens through the raw pointer in the C library; the `RNG` struct itself
 /// is logically immutable after construction.
-pub struct RNG {
+pub struct RNG<'a> {
     pub(crate) wc_rng: *mut sys::WC_RNG,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 
-// Safety: the only field of `RNG` is a non-null pointer to a `WC_RNG` that
-// lives on the C heap and is never reassigned after construction. Moving the
+// Safety: `wc_rng` is a non-null pointer to a `WC_RNG` on the C heap that is
+// owned by this struct and never reassigned after construction; the C state
+// holds no thread-local data. `_wolfcrypt` is an atomically reference counted
+// library guard that may be dropped on any thread, and `_device` is a
+// reference counted handle on the registration of the device of the RNG,
+// which may also be released from any thread. `_heap` is a zero-sized
+// marker for the lifetime of the memory pool the `WC_RNG` was allocated from,
+// and that pool is protected by its own mutex (see `HeapHint`). Moving the
 // struct between threads is sound.
-unsafe impl Send for RNG {}
+unsafe impl Send for RNG<'_> {}
 
 // Note: `RNG` is intentionally not `Sync`. The underlying C `WC_RNG` state is
 // mutated by every call to a generation routine, with no internal locking.
@@ -77,24 +91,28 @@ unsafe impl Send for RNG {}
 /// Storage for an RNG that a consumer (e.g. `RSA`, `ECC`) has been bound to
 /// via `set_rng`. The consumer keeps the `RngHandle` alive for as long as the
 /// C struct holds its pointer, ensuring the `WC_RNG` outlives the consumer.
-pub(crate) enum RngHandle {
-    Owned(RNG),
+pub(crate) enum RngHandle<'a> {
+    Owned(RNG<'a>),
     #[cfg(feature = "alloc")]
-    Shared(alloc::rc::Rc<RNG>),
+    Shared(alloc::rc::Rc<RNG<'a>>),
 }
 
-impl RNG {
+impl<'a> RNG<'a> {
     /// Initialize a new `RNG` instance.
     ///
     /// This function wraps the wolfssl library function `wc_InitRng`, which
     /// performs the necessary initialization for the RNG context.
     ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
     /// # Returns
     ///
     /// A Result which is Ok(RNG) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn new() -> Result<Self, i32> {
-        RNG::new_ex(None, None)
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        RNG::new_ex(&crate::Env::new(wolfcrypt))
     }
 
     /// Initialize a new `RNG` instance with optional heap and device ID.
@@ -104,39 +122,33 @@ impl RNG {
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
     /// A Result which is Ok(RNG) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn new_ex(heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn new_ex(env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         #[cfg(fips)]
         {
             let rc = unsafe {
                 sys::wc_SetSeed_Cb_fips(Some(sys::wc_GenerateSeed))
             };
             if rc != 0 {
-                return Err(rc);
+                return Err(Error::from(rc));
             }
         }
         let mut wc_rng: *mut sys::WC_RNG = core::ptr::null_mut();
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe {
             sys::wc_rng_new_ex(&mut wc_rng, core::ptr::null_mut(), 0, heap, dev_id)
         };
         if rc == 0 {
-            Ok(RNG {wc_rng})
+            Ok(RNG {wc_rng, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData})
         } else {
-            Err(rc)
+            Err(Error::from(rc))
         }
     }
 
@@ -146,12 +158,16 @@ impl RNG {
     /// which performs the necessary initialization for the RNG context and
     /// accepts a nonce input buffer.
     ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
     /// # Returns
     ///
     /// A Result which is Ok(RNG) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn new_with_nonce<T: PrimInt>(nonce: &mut [T]) -> Result<Self, i32> {
-        RNG::new_with_nonce_ex(nonce, None, None)
+    pub fn new_with_nonce<T: PrimInt>(nonce: &mut [T], wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        RNG::new_with_nonce_ex(nonce, &crate::Env::new(wolfcrypt))
     }
 
     /// Initialize a new `RNG` instance and provide a nonce input.
@@ -162,41 +178,35 @@ impl RNG {
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
     /// A Result which is Ok(RNG) on success or an Err containing the wolfSSL
     /// library return code on failure.
-    pub fn new_with_nonce_ex<T: PrimInt>(nonce: &mut [T], heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn new_with_nonce_ex<T: PrimInt>(nonce: &mut [T], env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         #[cfg(fips)]
         {
             let rc = unsafe {
                 sys::wc_SetSeed_Cb_fips(Some(sys::wc_GenerateSeed))
             };
             if rc != 0 {
-                return Err(rc);
+                return Err(Error::from(rc));
             }
         }
         let ptr = nonce.as_mut_ptr() as *mut u8;
         let size = crate::buffer_len_to_u32(size_of_val(nonce))?;
         let mut wc_rng: *mut sys::WC_RNG = core::ptr::null_mut();
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe {
             sys::wc_rng_new_ex(&mut wc_rng, ptr, size, heap, dev_id)
         };
         if rc == 0 {
-            Ok(RNG {wc_rng})
+            Ok(RNG {wc_rng, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData})
         } else {
-            Err(rc)
+            Err(Error::from(rc))
         }
     }
 
@@ -209,6 +219,7 @@ impl RNG {
     /// * `seed_b`: Optional buffer containing more seed data. If present, the
     ///   DRBG will be reseeded.
     /// * `output`: Output buffer.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
@@ -220,15 +231,17 @@ impl RNG {
     /// ```rust
     /// #![cfg(random_hashdrbg)]
     /// use wolfssl_wolfcrypt::random::RNG;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let nonce = [99u8, 88, 77, 66];
     /// let seed_a = [42u8, 33, 55, 88];
     /// let seed_b = [45u8, 10, 20, 30];
     /// let mut output = [0u8; 128];
-    /// RNG::health_test(Some(&nonce), &seed_a, Some(&seed_b), &mut output).expect("Error with health_test()");
+    /// RNG::health_test(Some(&nonce), &seed_a, Some(&seed_b), &mut output, &wolfcrypt).expect("Error with health_test()");
     /// ```
     #[cfg(random_hashdrbg)]
-    pub fn health_test(nonce: Option<&[u8]>, seed_a: &[u8], seed_b: Option<&[u8]>, output: &mut [u8]) -> Result<(), i32> {
-        Self::health_test_ex(nonce, seed_a, seed_b, output, None, None)
+    pub fn health_test(nonce: Option<&[u8]>, seed_a: &[u8], seed_b: Option<&[u8]>, output: &mut [u8], wolfcrypt: &crate::WolfCrypt) -> Result<(), Error> {
+        Self::health_test_ex(nonce, seed_a, seed_b, output, &crate::Env::new(wolfcrypt))
     }
 
     /// Create and test functionality of DRBG with optional heap and device ID.
@@ -240,8 +253,7 @@ impl RNG {
     /// * `seed_b`: Optional buffer containing more seed data. If present, the
     ///   DRBG will be reseeded.
     /// * `output`: Output buffer.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -252,15 +264,18 @@ impl RNG {
     ///
     /// ```rust
     /// #![cfg(random_hashdrbg)]
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /// let nonce = [99u8, 88, 77, 66];
     /// let seed_a = [42u8, 33, 55, 88];
     /// let seed_b = [45u8, 10, 20, 30];
     /// let mut output = [0u8; 128];
-    /// RNG::health_test_ex(Some(&nonce), &seed_a, Some(&seed_b), &mut output, None, None).expect("Error with health_test_ex()");
+    /// RNG::health_test_ex(Some(&nonce), &seed_a, Some(&seed_b), &mut output, &Env::new(&wolfcrypt)).expect("Error with health_test_ex()");
     /// ```
     #[cfg(random_hashdrbg)]
-    pub fn health_test_ex(nonce: Option<&[u8]>, seed_a: &[u8], seed_b: Option<&[u8]>, output: &mut [u8], heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<(), i32> {
+    pub fn health_test_ex(nonce: Option<&[u8]>, seed_a: &[u8], seed_b: Option<&[u8]>, output: &mut [u8], env: &crate::Env) -> Result<(), Error> {
         let mut nonce_ptr = core::ptr::null();
         let mut nonce_size = 0u32;
         if let Some(nonce) = nonce {
@@ -275,14 +290,8 @@ impl RNG {
             seed_b_size = crate::buffer_len_to_u32(seed_b.len())?;
         }
         let output_size = crate::buffer_len_to_u32(output.len())?;
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe {
             sys::wc_RNG_HealthTest_ex(if seed_b_size > 0 {1} else {0},
                 nonce_ptr, nonce_size,
@@ -292,7 +301,7 @@ impl RNG {
                 heap, dev_id)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -317,11 +326,11 @@ impl RNG {
     /// RNG::test_seed(&seed).expect("Error with test_seed()");
     /// ```
     #[cfg(random_hashdrbg)]
-    pub fn test_seed(seed: &[u8]) -> Result<(), i32> {
+    pub fn test_seed(seed: &[u8]) -> Result<(), Error> {
         let seed_size = crate::buffer_len_to_u32(seed.len())?;
         let rc = unsafe { sys::wc_RNG_TestSeed(seed.as_ptr(), seed_size) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -335,13 +344,13 @@ impl RNG {
     ///
     /// A `Result` which is `Ok(u8)` containing the random byte on success or
     /// an `Err` with the wolfssl library return code on failure.
-    pub fn generate_byte(&self) -> Result<u8, i32> {
+    pub fn generate_byte(&self) -> Result<u8, Error> {
         let mut b: u8 = 0;
         let rc = unsafe { sys::wc_RNG_GenerateByte(self.wc_rng, &mut b) };
         if rc == 0 {
             Ok(b)
         } else {
-            Err(rc)
+            Err(Error::from(rc))
         }
     }
 
@@ -359,14 +368,14 @@ impl RNG {
     ///
     /// A `Result` which is `Ok(())` on success or an `Err` with the wolfssl
     /// library return code on failure.
-    pub fn generate_block<T: PrimInt>(&self, buf: &mut [T]) -> Result<(), i32> {
+    pub fn generate_block<T: PrimInt>(&self, buf: &mut [T]) -> Result<(), Error> {
         let ptr = buf.as_mut_ptr() as *mut u8;
         let size = crate::buffer_len_to_u32(size_of_val(buf))?;
         let rc = unsafe { sys::wc_RNG_GenerateBlock(self.wc_rng, ptr, size) };
         if rc == 0 {
             Ok(())
         } else {
-            Err(rc)
+            Err(Error::from(rc))
         }
     }
 
@@ -386,18 +395,20 @@ impl RNG {
     /// ```rust
     /// #![cfg(random_hashdrbg)]
     /// use wolfssl_wolfcrypt::random::RNG;
-    /// let mut rng = RNG::new().expect("Failed to create RNG");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
     /// let seed = [1u8, 2, 3, 4];
     /// rng.reseed(&seed).expect("Error with reseed()");
     /// ```
     #[cfg(random_hashdrbg)]
-    pub fn reseed(&self, seed: &[u8]) -> Result<(), i32> {
+    pub fn reseed(&self, seed: &[u8]) -> Result<(), Error> {
         let seed_size = crate::buffer_len_to_u32(seed.len())?;
         let rc = unsafe {
             sys::wc_RNG_DRBG_Reseed(self.wc_rng, seed.as_ptr(), seed_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -410,7 +421,7 @@ impl RNG {
 /// `CryptoRng` apply automatically. wolfSSL RNG failures cause a panic, which
 /// is consistent with the infallible contract.
 #[cfg(feature = "rand_core")]
-impl rand_core::TryRng for RNG {
+impl rand_core::TryRng for RNG<'_> {
     type Error = core::convert::Infallible;
 
     fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
@@ -429,9 +440,9 @@ impl rand_core::TryRng for RNG {
 
 /// Mark `RNG` as a cryptographically secure random number generator.
 #[cfg(feature = "rand_core")]
-impl rand_core::TryCryptoRng for RNG {}
+impl rand_core::TryCryptoRng for RNG<'_> {}
 
-impl Drop for RNG {
+impl Drop for RNG<'_> {
     /// Safely free the underlying wolfSSL RNG context.
     ///
     /// This calls the `wc_rng_free` wolfssl library function, which frees the
@@ -34,11 +34,13 @@ wolfSSL `RsaKey` object. It ensures proper initialization and deallocation.
 use std::fs;
 use wolfssl_wolfcrypt::random::RNG;
 use wolfssl_wolfcrypt::rsa::RSA;
+use wolfssl_wolfcrypt::WolfCrypt;
+let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
 
-let rng = std::rc::Rc::new(RNG::new().expect("Error creating RNG"));
+let rng = std::rc::Rc::new(RNG::new(&wolfcrypt).expect("Error creating RNG"));
 let key_path = "../../../certs/client-keyPub.der";
 let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-let mut rsa = RSA::new_public_from_der(&der).expect("Error with new_public_from_der()");
+let mut rsa = RSA::new_public_from_der(&der, &wolfcrypt).expect("Error with new_public_from_der()");
 rsa.set_shared_rng(std::rc::Rc::clone(&rng)).expect("Error with set_shared_rng()");
 let plain: &[u8] = b"Test message";
 let mut enc: [u8; 512] = [0; 512];
@@ -47,7 +49,7 @@ assert!(enc_len > 0 && enc_len <= 512);
 
 let key_path = "../../../certs/client-key.der";
 let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-let mut rsa = RSA::new_from_der(&der).expect("Error with new_from_der()");
+let mut rsa = RSA::new_from_der(&der, &wolfcrypt).expect("Error with new_from_der()");
 rsa.set_shared_rng(std::rc::Rc::clone(&rng)).expect("Error with set_shared_rng()");
 let mut plain_out: [u8; 512] = [0; 512];
 let dec_len = rsa.private_decrypt(&enc[0..enc_len], &mut plain_out).expect("Error with private_decrypt()");
@@ -59,7 +61,10 @@ assert_eq!(plain_out[0..dec_len], *plain);
 
 #![cfg(rsa)]
 
+use crate::Error;
+use crate::memory::HeapHint;
 use crate::sys;
+use core::marker::PhantomData;
 #[cfg(random)]
 use crate::random::{RNG, RngHandle};
 use core::mem::{MaybeUninit};
@@ -70,15 +75,18 @@ use core::mem::{MaybeUninit};
 ///
 /// An instance can be created with `new_from_der()`, `new_public_from_der()`,
 /// or `generate()`.
-pub struct RSA {
+pub struct RSA<'a> {
     pub(crate) wc_rsakey: sys::RsaKey,
     /// RNG bound to this key via `set_rng`. Kept alive here so the C struct's
     /// internal `WC_RNG` pointer remains valid for as long as the key exists.
     #[cfg(random)]
-    rng: Option<RngHandle>,
+    rng: Option<RngHandle<'a>>,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 
-impl RSA {
+impl<'a> RSA<'a> {
     // Hash type constants used for PSS sign and verify methods.
     pub const HASH_TYPE_NONE       : u32 = sys::wc_HashType_WC_HASH_TYPE_NONE;
     pub const HASH_TYPE_MD2        : u32 = sys::wc_HashType_WC_HASH_TYPE_MD2;
@@ -137,6 +145,7 @@ impl RSA {
     /// # Parameters
     ///
     /// * `der`: DER-encoded input buffer.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
@@ -152,11 +161,13 @@ impl RSA {
     /// use std::fs;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::rsa::RSA;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     ///
-    /// let rng = std::rc::Rc::new(RNG::new().expect("Error creating RNG"));
+    /// let rng = std::rc::Rc::new(RNG::new(&wolfcrypt).expect("Error creating RNG"));
     /// let key_path = "../../../certs/client-keyPub.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut rsa = RSA::new_public_from_der(&der).expect("Error with new_public_from_der()");
+    /// let mut rsa = RSA::new_public_from_der(&der, &wolfcrypt).expect("Error with new_public_from_der()");
     /// rsa.set_shared_rng(std::rc::Rc::clone(&rng)).expect("Error with set_shared_rng()");
     /// let plain: &[u8] = b"Test message";
     /// let mut enc: [u8; 512] = [0; 512];
@@ -165,7 +176,7 @@ impl RSA {
     ///
     /// let key_path = "../../../certs/client-key.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut rsa = RSA::new_from_der(&der).expect("Error with new_from_der()");
+    /// let mut rsa = RSA::new_from_der(&der, &wolfcrypt).expect("Error with new_from_der()");
     /// rsa.set_shared_rng(std::rc::Rc::clone(&rng)).expect("Error with set_shared_rng()");
     /// let mut plain_out: [u8; 512] = [0; 512];
     /// let dec_len = rsa.private_decrypt(&enc[0..enc_len], &mut plain_out).expect("Error with private_decrypt()");
@@ -173,8 +184,8 @@ impl RSA {
     /// assert_eq!(plain_out[0..dec_len], *plain);
     /// }
     /// ```
-    pub fn new_from_der(der: &[u8]) -> Result<Self, i32> {
-        Self::new_from_der_ex(der, None, None)
+    pub fn new_from_der(der: &[u8], wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_from_der_ex(der, &crate::Env::new(wolfcrypt))
     }
 
     /// Load a public and private RSA keypair from DER-encoded buffer with
@@ -183,8 +194,7 @@ impl RSA {
     /// # Parameters
     ///
     /// * `der`: DER-encoded input buffer.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -198,13 +208,16 @@ impl RSA {
     /// #[cfg(all(random, feature = "alloc"))]
     /// {
     /// use std::fs;
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::rsa::RSA;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     ///
-    /// let rng = std::rc::Rc::new(RNG::new().expect("Error creating RNG"));
+    /// let rng = std::rc::Rc::new(RNG::new(&wolfcrypt).expect("Error creating RNG"));
     /// let key_path = "../../../certs/client-keyPub.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut rsa = RSA::new_public_from_der(&der).expect("Error with new_public_from_der()");
+    /// let mut rsa = RSA::new_public_from_der(&der, &wolfcrypt).expect("Error with new_public_from_der()");
     /// rsa.set_shared_rng(std::rc::Rc::clone(&rng)).expect("Error with set_shared_rng()");
     /// let plain: &[u8] = b"Test message";
     /// let mut enc: [u8; 512] = [0; 512];
@@ -213,7 +226,7 @@ impl RSA {
     ///
     /// let key_path = "../../../certs/client-key.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut rsa = RSA::new_from_der_ex(&der, None, None).expect("Error with new_from_der_ex()");
+    /// let mut rsa = RSA::new_from_der_ex(&der, &Env::new(&wolfcrypt)).expect("Error with new_from_der_ex()");
     /// rsa.set_shared_rng(std::rc::Rc::clone(&rng)).expect("Error with set_shared_rng()");
     /// let mut plain_out: [u8; 512] = [0; 512];
     /// let dec_len = rsa.private_decrypt(&enc[0..enc_len], &mut plain_out).expect("Error with private_decrypt()");
@@ -221,20 +234,15 @@ impl RSA {
     /// assert_eq!(plain_out[0..dec_len], *plain);
     /// }
     /// ```
-    pub fn new_from_der_ex(der: &[u8], heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn new_from_der_ex(der: &[u8], env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let der_size = crate::buffer_len_to_u32(der.len())?;
         let mut wc_rsakey: MaybeUninit<sys::RsaKey> = MaybeUninit::uninit();
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_InitRsaKey_ex(wc_rsakey.as_mut_ptr(), heap, dev_id) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let mut wc_rsakey = unsafe { wc_rsakey.assume_init() };
         let mut idx: u32 = 0;
@@ -243,12 +251,129 @@ impl RSA {
         };
         if rc != 0 {
             unsafe { sys::wc_FreeRsaKey(&mut wc_rsakey); }
-            return Err(rc);
+            return Err(Error::from(rc));
+        }
+        let rsa = RSA {
+            wc_rsakey,
+            #[cfg(random)]
+            rng: None,
+            _wolfcrypt: wolfcrypt,
+            _device: env.device_ref(),
+            _heap: PhantomData,
+        };
+        Ok(rsa)
+    }
+
+    /// Create an RSA key referring to a private key held by a crypto callback
+    /// device (such as a PKCS#11 token) under the given key ID.
+    ///
+    /// The key material is not loaded; operations on the key are performed
+    /// by the device registered with `dev_id`, which locates the key by ID.
+    ///
+    /// # Parameters
+    ///
+    /// * `id`: Key identifier on the device (at most 32 bytes).
+    /// * `env`: Heap hint and crypto callback device to use.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(RSA) containing the RSA struct instance or Err(e)
+    /// containing the wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(rsa_key_id)]
+    /// {
+    /// use wolfssl_wolfcrypt::Env;
+    /// use wolfssl_wolfcrypt::rsa::RSA;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rsa = RSA::new_from_id(b"\x01\x02", &Env::new(&wolfcrypt)).expect("Error with new_from_id()");
+    /// }
+    /// ```
+    #[cfg(rsa_key_id)]
+    pub fn new_from_id(id: &[u8], env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let id_size = crate::buffer_len_to_i32(id.len())?;
+        let mut wc_rsakey: MaybeUninit<sys::RsaKey> = MaybeUninit::uninit();
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
+        let rc = unsafe {
+            sys::wc_InitRsaKey_Id(wc_rsakey.as_mut_ptr(), id.as_ptr() as *mut u8,
+                id_size, heap, dev_id)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        let wc_rsakey = unsafe { wc_rsakey.assume_init() };
+        let rsa = RSA {
+            wc_rsakey,
+            #[cfg(random)]
+            rng: None,
+            _wolfcrypt: wolfcrypt,
+            _device: env.device_ref(),
+            _heap: PhantomData,
+        };
+        Ok(rsa)
+    }
+
+    /// Create an RSA key referring to a private key held by a crypto callback
+    /// device (such as a PKCS#11 token) under the given label.
+    ///
+    /// The key material is not loaded; operations on the key are performed
+    /// by the device registered with `dev_id`, which locates the key by
+    /// label.
+    ///
+    /// # Parameters
+    ///
+    /// * `label`: Key label on the device (1 to 32 bytes).
+    /// * `env`: Heap hint and crypto callback device to use.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(RSA) containing the RSA struct instance or Err(e)
+    /// containing the wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(rsa_key_id)]
+    /// {
+    /// use wolfssl_wolfcrypt::Env;
+    /// use wolfssl_wolfcrypt::rsa::RSA;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rsa = RSA::new_from_label("signing-key", &Env::new(&wolfcrypt)).expect("Error with new_from_label()");
+    /// }
+    /// ```
+    #[cfg(rsa_key_id)]
+    pub fn new_from_label(label: &str, env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let mut c_label = [0 as core::ffi::c_char; sys::RSA_MAX_LABEL_LEN as usize + 1];
+        if label.len() >= c_label.len() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
+        for (d, s) in c_label.iter_mut().zip(label.as_bytes()) {
+            *d = *s as core::ffi::c_char;
+        }
+        let mut wc_rsakey: MaybeUninit<sys::RsaKey> = MaybeUninit::uninit();
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
+        let rc = unsafe {
+            sys::wc_InitRsaKey_Label(wc_rsakey.as_mut_ptr(), c_label.as_ptr(), heap, dev_id)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        let wc_rsakey = unsafe { wc_rsakey.assume_init() };
         let rsa = RSA {
             wc_rsakey,
             #[cfg(random)]
             rng: None,
+            _wolfcrypt: wolfcrypt,
+            _device: env.device_ref(),
+            _heap: PhantomData,
         };
         Ok(rsa)
     }
@@ -258,6 +383,7 @@ impl RSA {
     /// # Parameters
     ///
     /// * `der`: DER-encoded input buffer.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
@@ -273,11 +399,13 @@ impl RSA {
     /// use std::fs;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::rsa::RSA;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     ///
-    /// let rng = std::rc::Rc::new(RNG::new().expect("Error creating RNG"));
+    /// let rng = std::rc::Rc::new(RNG::new(&wolfcrypt).expect("Error creating RNG"));
     /// let key_path = "../../../certs/client-keyPub.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut rsa = RSA::new_public_from_der(&der).expect("Error with new_public_from_der()");
+    /// let mut rsa = RSA::new_public_from_der(&der, &wolfcrypt).expect("Error with new_public_from_der()");
     /// rsa.set_shared_rng(std::rc::Rc::clone(&rng)).expect("Error with set_shared_rng()");
     /// let plain: &[u8] = b"Test message";
     /// let mut enc: [u8; 512] = [0; 512];
@@ -286,7 +414,7 @@ impl RSA {
     ///
     /// let key_path = "../../../certs/client-key.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut rsa = RSA::new_from_der(&der).expect("Error with new_from_der()");
+    /// let mut rsa = RSA::new_from_der(&der, &wolfcrypt).expect("Error with new_from_der()");
     /// rsa.set_shared_rng(std::rc::Rc::clone(&rng)).expect("Error with set_shared_rng()");
     /// let mut plain_out: [u8; 512] = [0; 512];
     /// let dec_len = rsa.private_decrypt(&enc[0..enc_len], &mut plain_out).expect("Error with private_decrypt()");
@@ -294,8 +422,8 @@ impl RSA {
     /// assert_eq!(plain_out[0..dec_len], *plain);
     /// }
     /// ```
-    pub fn new_public_from_der(der: &[u8]) -> Result<Self, i32> {
-        Self::new_public_from_der_ex(der, None, None)
+    pub fn new_public_from_der(der: &[u8], wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_public_from_der_ex(der, &crate::Env::new(wolfcrypt))
     }
 
     /// Load a public RSA key from DER-encoded buffer with optional heap and
@@ -304,8 +432,7 @@ impl RSA {
     /// # Parameters
     ///
     /// * `der`: DER-encoded input buffer.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -319,13 +446,16 @@ impl RSA {
     /// #[cfg(all(random, feature = "alloc"))]
     /// {
     /// use std::fs;
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::rsa::RSA;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     ///
-    /// let rng = std::rc::Rc::new(RNG::new().expect("Error creating RNG"));
+    /// let rng = std::rc::Rc::new(RNG::new(&wolfcrypt).expect("Error creating RNG"));
     /// let key_path = "../../../certs/client-keyPub.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut rsa = RSA::new_public_from_der_ex(&der, None, None).expect("Error with new_public_from_der_ex()");
+    /// let mut rsa = RSA::new_public_from_der_ex(&der, &Env::new(&wolfcrypt)).expect("Error with new_public_from_der_ex()");
     /// rsa.set_shared_rng(std::rc::Rc::clone(&rng)).expect("Error with set_shared_rng()");
     /// let plain: &[u8] = b"Test message";
     /// let mut enc: [u8; 512] = [0; 512];
@@ -334,7 +464,7 @@ impl RSA {
     ///
     /// let key_path = "../../../certs/client-key.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut rsa = RSA::new_from_der(&der).expect("Error with new_from_der()");
+    /// let mut rsa = RSA::new_from_der(&der, &wolfcrypt).expect("Error with new_from_der()");
     /// rsa.set_shared_rng(std::rc::Rc::clone(&rng)).expect("Error with set_shared_rng()");
     /// let mut plain_out: [u8; 512] = [0; 512];
     /// let dec_len = rsa.private_decrypt(&enc[0..enc_len], &mut plain_out).expect("Error with private_decrypt()");
@@ -342,20 +472,15 @@ impl RSA {
     /// assert_eq!(plain_out[0..dec_len], *plain);
     /// }
     /// ```
-    pub fn new_public_from_der_ex(der: &[u8], heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn new_public_from_der_ex(der: &[u8], env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let der_size = crate::buffer_len_to_u32(der.len())?;
         let mut wc_rsakey: MaybeUninit<sys::RsaKey> = MaybeUninit::uninit();
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_InitRsaKey_ex(wc_rsakey.as_mut_ptr(), heap, dev_id) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let mut wc_rsakey = unsafe { wc_rsakey.assume_init() };
         let mut idx: u32 = 0;
@@ -364,12 +489,15 @@ impl RSA {
         };
         if rc != 0 {
             unsafe { sys::wc_FreeRsaKey(&mut wc_rsakey); }
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let rsa = RSA {
             wc_rsakey,
             #[cfg(random)]
             rng: None,
+            _wolfcrypt: wolfcrypt,
+            _device: env.device_ref(),
+            _heap: PhantomData,
         };
         Ok(rsa)
     }
@@ -381,35 +509,31 @@ impl RSA {
     ///
     /// * `n`: Big-endian modulus bytes.
     /// * `e`: Big-endian public exponent bytes.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
     /// Returns either Ok(RSA) containing the RSA struct instance or Err(e)
     /// containing the wolfSSL library error code value.
-    pub fn new_public_from_raw(n: &[u8], e: &[u8]) -> Result<Self, i32> {
-        Self::new_public_from_raw_ex(n, e, None, None)
+    pub fn new_public_from_raw(n: &[u8], e: &[u8], wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_public_from_raw_ex(n, e, &crate::Env::new(wolfcrypt))
     }
 
     /// Create a new RSA public key from raw modulus (`n`) and public
     /// exponent (`e`) bytes with optional heap and device ID.
     pub fn new_public_from_raw_ex(
         n: &[u8], e: &[u8],
-        heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>,
-    ) -> Result<Self, i32> {
+        env: &crate::Env,
+    ) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let n_size = crate::buffer_len_to_u32(n.len())?;
         let e_size = crate::buffer_len_to_u32(e.len())?;
         let mut wc_rsakey: MaybeUninit<sys::RsaKey> = MaybeUninit::uninit();
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_InitRsaKey_ex(wc_rsakey.as_mut_ptr(), heap, dev_id) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let mut wc_rsakey = unsafe { wc_rsakey.assume_init() };
         let rc = unsafe {
@@ -421,12 +545,15 @@ impl RSA {
         };
         if rc != 0 {
             unsafe { sys::wc_FreeRsaKey(&mut wc_rsakey); }
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(RSA {
             wc_rsakey,
             #[cfg(random)]
             rng: None,
+            _wolfcrypt: wolfcrypt,
+            _device: env.device_ref(),
+            _heap: PhantomData,
         })
     }
 
@@ -448,6 +575,7 @@ impl RSA {
     ///   choice is 65537.
     /// * `rng`: Reference to a `RNG` struct to use for random number
     ///   generation while making the key.
+    /// * `wolfcrypt`: Guard of the initialized library.
     ///
     /// # Returns
     ///
@@ -461,17 +589,19 @@ impl RSA {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::rsa::RSA;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     ///
-    /// let rng = RNG::new().expect("Error creating RNG");
-    /// let mut rsa = RSA::generate(2048, 65537, &rng).expect("Error with generate()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut rsa = RSA::generate(2048, 65537, &rng, &wolfcrypt).expect("Error with generate()");
     /// rsa.check().expect("Error with check()");
     /// let encrypt_size = rsa.get_encrypt_size().expect("Error with get_encrypt_size()");
     /// assert_eq!(encrypt_size, 256);
     /// }
     /// ```
     #[cfg(all(random, rsa_keygen))]
-    pub fn generate(size: i32, e: i32, rng: &RNG) -> Result<Self, i32> {
-        Self::generate_ex(size, e, rng, None, None)
+    pub fn generate(size: i32, e: i32, rng: &RNG, wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::generate_ex(size, e, rng, &crate::Env::new(wolfcrypt))
     }
 
     /// Generate a new RSA key using the given size and exponent with optional
@@ -493,8 +623,7 @@ impl RSA {
     ///   choice is 65537.
     /// * `rng`: Reference to a `RNG` struct to use for random number
     ///   generation while making the key.
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -506,30 +635,28 @@ impl RSA {
     /// ```rust
     /// #[cfg(rsa_keygen)]
     /// {
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::rsa::RSA;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     ///
-    /// let rng = RNG::new().expect("Error creating RNG");
-    /// let mut rsa = RSA::generate_ex(2048, 65537, &rng, None, None).expect("Error with generate_ex()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut rsa = RSA::generate_ex(2048, 65537, &rng, &Env::new(&wolfcrypt)).expect("Error with generate_ex()");
     /// rsa.check().expect("Error with check()");
     /// let encrypt_size = rsa.get_encrypt_size().expect("Error with get_encrypt_size()");
     /// assert_eq!(encrypt_size, 256);
     /// }
     /// ```
     #[cfg(all(random, rsa_keygen))]
-    pub fn generate_ex(size: i32, e: i32, rng: &RNG, heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn generate_ex(size: i32, e: i32, rng: &RNG, env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let mut wc_rsakey: MaybeUninit<sys::RsaKey> = MaybeUninit::uninit();
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_InitRsaKey_ex(wc_rsakey.as_mut_ptr(), heap, dev_id) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let mut wc_rsakey = unsafe { wc_rsakey.assume_init() };
         let e = e as core::ffi::c_long;
@@ -538,12 +665,15 @@ impl RSA {
         };
         if rc != 0 {
             unsafe { sys::wc_FreeRsaKey(&mut wc_rsakey); }
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let rsa = RSA {
             wc_rsakey,
             #[cfg(random)]
             rng: None,
+            _wolfcrypt: wolfcrypt,
+            _device: env.device_ref(),
+            _heap: PhantomData,
         };
         Ok(rsa)
     }
@@ -575,9 +705,11 @@ impl RSA {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::rsa::RSA;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     ///
-    /// let rng = RNG::new().expect("Error creating RNG");
-    /// let mut rsa = RSA::generate(2048, 65537, &rng).expect("Error with generate()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut rsa = RSA::generate(2048, 65537, &rng, &wolfcrypt).expect("Error with generate()");
     /// let mut e: [u8; 256] = [0; 256];
     /// let mut e_size: u32 = 0;
     /// let mut n: [u8; 256] = [0; 256];
@@ -598,7 +730,7 @@ impl RSA {
             n: &mut [u8], n_size: &mut u32,
             d: &mut [u8], d_size: &mut u32,
             p: &mut [u8], p_size: &mut u32,
-            q: &mut [u8], q_size: &mut u32) -> Result<(), i32> {
+            q: &mut [u8], q_size: &mut u32) -> Result<(), Error> {
         *e_size = crate::buffer_len_to_u32(e.len())?;
         *n_size = crate::buffer_len_to_u32(n.len())?;
         *d_size = crate::buffer_len_to_u32(d.len())?;
@@ -617,7 +749,7 @@ impl RSA {
                 q.as_mut_ptr(), q_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -643,9 +775,11 @@ impl RSA {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::rsa::RSA;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     ///
-    /// let rng = RNG::new().expect("Error creating RNG");
-    /// let mut rsa = RSA::generate(2048, 65537, &rng).expect("Error with generate()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut rsa = RSA::generate(2048, 65537, &rng, &wolfcrypt).expect("Error with generate()");
     /// let mut e: [u8; 256] = [0; 256];
     /// let mut e_size: u32 = 0;
     /// let mut n: [u8; 256] = [0; 256];
@@ -655,7 +789,7 @@ impl RSA {
     /// ```
     pub fn export_public_key(&mut self,
             e: &mut [u8], e_size: &mut u32,
-            n: &mut [u8], n_size: &mut u32) -> Result<(), i32> {
+            n: &mut [u8], n_size: &mut u32) -> Result<(), Error> {
         *e_size = crate::buffer_len_to_u32(e.len())?;
         *n_size = crate::buffer_len_to_u32(n.len())?;
         #[cfg(rsa_const_api)]
@@ -667,7 +801,7 @@ impl RSA {
                 e.as_mut_ptr(), e_size, n.as_mut_ptr(), n_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -686,17 +820,19 @@ impl RSA {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::rsa::RSA;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     ///
-    /// let rng = RNG::new().expect("Error creating RNG");
-    /// let mut rsa = RSA::generate(2048, 65537, &rng).expect("Error with generate()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut rsa = RSA::generate(2048, 65537, &rng, &wolfcrypt).expect("Error with generate()");
     /// let encrypt_size = rsa.get_encrypt_size().expect("Error with get_encrypt_size()");
     /// assert_eq!(encrypt_size, 256);
     /// }
     /// ```
-    pub fn get_encrypt_size(&self) -> Result<usize, i32> {
+    pub fn get_encrypt_size(&self) -> Result<usize, Error> {
         let rc = unsafe { sys::wc_RsaEncryptSize(&self.wc_rsakey) };
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(rc as usize)
     }
@@ -715,16 +851,18 @@ impl RSA {
     /// {
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::rsa::RSA;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     ///
-    /// let rng = RNG::new().expect("Error creating RNG");
-    /// let mut rsa = RSA::generate(2048, 65537, &rng).expect("Error with generate()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let mut rsa = RSA::generate(2048, 65537, &rng, &wolfcrypt).expect("Error with generate()");
     /// rsa.check().expect("Error with check()");
     /// }
     /// ```
-    pub fn check(&mut self) -> Result<(), i32> {
+    pub fn check(&mut self) -> Result<(), Error> {
         let rc = unsafe { sys::wc_CheckRsaKey(&mut self.wc_rsakey) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -754,11 +892,13 @@ impl RSA {
     /// use std::fs;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::rsa::RSA;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     ///
-    /// let rng = std::rc::Rc::new(RNG::new().expect("Error creating RNG"));
+    /// let rng = std::rc::Rc::new(RNG::new(&wolfcrypt).expect("Error creating RNG"));
     /// let key_path = "../../../certs/client-keyPub.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut rsa = RSA::new_public_from_der(&der).expect("Error with new_public_from_der()");
+    /// let mut rsa = RSA::new_public_from_der(&der, &wolfcrypt).expect("Error with new_public_from_der()");
     /// rsa.set_shared_rng(std::rc::Rc::clone(&rng)).expect("Error with set_shared_rng()");
     /// let plain: &[u8] = b"Test message";
     /// let mut enc: [u8; 512] = [0; 512];
@@ -767,7 +907,7 @@ impl RSA {
     ///
     /// let key_path = "../../../certs/client-key.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut rsa = RSA::new_from_der(&der).expect("Error with new_from_der()");
+    /// let mut rsa = RSA::new_from_der(&der, &wolfcrypt).expect("Error with new_from_der()");
     /// rsa.set_shared_rng(std::rc::Rc::clone(&rng)).expect("Error with set_shared_rng()");
     /// let mut plain_out: [u8; 512] = [0; 512];
     /// let dec_len = rsa.private_decrypt(&enc[0..enc_len], &mut plain_out).expect("Error with private_decrypt()");
@@ -776,7 +916,7 @@ impl RSA {
     /// }
     /// ```
     #[cfg(random)]
-    pub fn public_encrypt(&mut self, din: &[u8], dout: &mut [u8], rng: &RNG) -> Result<usize, i32> {
+    pub fn public_encrypt(&mut self, din: &[u8], dout: &mut [u8], rng: &RNG) -> Result<usize, Error> {
         let din_size = crate::buffer_len_to_u32(din.len())?;
         let dout_size = crate::buffer_len_to_u32(dout.len())?;
         let rc = unsafe {
@@ -785,7 +925,7 @@ impl RSA {
                 rng.wc_rng)
         };
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(rc as usize)
     }
@@ -813,11 +953,13 @@ impl RSA {
     /// use std::fs;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::rsa::RSA;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     ///
-    /// let rng = std::rc::Rc::new(RNG::new().expect("Error creating RNG"));
+    /// let rng = std::rc::Rc::new(RNG::new(&wolfcrypt).expect("Error creating RNG"));
     /// let key_path = "../../../certs/client-keyPub.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut rsa = RSA::new_public_from_der(&der).expect("Error with new_public_from_der()");
+    /// let mut rsa = RSA::new_public_from_der(&der, &wolfcrypt).expect("Error with new_public_from_der()");
     /// rsa.set_shared_rng(std::rc::Rc::clone(&rng)).expect("Error with set_shared_rng()");
     /// let plain: &[u8] = b"Test message";
     /// let mut enc: [u8; 512] = [0; 512];
@@ -826,7 +968,7 @@ impl RSA {
     ///
     /// let key_path = "../../../certs/client-key.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut rsa = RSA::new_from_der(&der).expect("Error with new_from_der()");
+    /// let mut rsa = RSA::new_from_der(&der, &wolfcrypt).expect("Error with new_from_der()");
     /// rsa.set_shared_rng(std::rc::Rc::clone(&rng)).expect("Error with set_shared_rng()");
     /// let mut plain_out: [u8; 512] = [0; 512];
     /// let dec_len = rsa.private_decrypt(&enc[0..enc_len], &mut plain_out).expect("Error with private_decrypt()");
@@ -834,7 +976,7 @@ impl RSA {
     /// assert_eq!(plain_out[0..dec_len], *plain);
     /// }
     /// ```
-    pub fn private_decrypt(&mut self, din: &[u8], dout: &mut [u8]) -> Result<usize, i32> {
+    pub fn private_decrypt(&mut self, din: &[u8], dout: &mut [u8]) -> Result<usize, Error> {
         let din_size = crate::buffer_len_to_u32(din.len())?;
         let dout_size = crate::buffer_len_to_u32(dout.len())?;
         let rc = unsafe {
@@ -842,7 +984,7 @@ impl RSA {
                 dout.as_mut_ptr(), dout_size, &mut self.wc_rsakey)
         };
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(rc as usize)
     }
@@ -876,11 +1018,13 @@ impl RSA {
     /// use std::fs;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::rsa::RSA;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     ///
-    /// let rng = std::rc::Rc::new(RNG::new().expect("Error creating RNG"));
+    /// let rng = std::rc::Rc::new(RNG::new(&wolfcrypt).expect("Error creating RNG"));
     /// let key_path = "../../../certs/client-keyPub.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut rsa = RSA::new_public_from_der(&der).expect("Error with new_public_from_der()");
+    /// let mut rsa = RSA::new_public_from_der(&der, &wolfcrypt).expect("Error with new_public_from_der()");
     /// rsa.set_shared_rng(std::rc::Rc::clone(&rng)).expect("Error with set_shared_rng()");
     /// let plain: &[u8] = b"Test message";
     /// let mut enc: [u8; 512] = [0; 512];
@@ -889,7 +1033,7 @@ impl RSA {
     ///
     /// let key_path = "../../../certs/client-key.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut rsa = RSA::new_from_der(&der).expect("Error with new_from_der()");
+    /// let mut rsa = RSA::new_from_der(&der, &wolfcrypt).expect("Error with new_from_der()");
     /// rsa.set_shared_rng(std::rc::Rc::clone(&rng)).expect("Error with set_shared_rng()");
     /// let mut plain_out: [u8; 512] = [0; 512];
     /// let dec_len = rsa.private_decrypt_oaep(&enc[0..enc_len], &mut plain_out, RSA::HASH_TYPE_SHA256, RSA::MGF1SHA256).expect("Error with private_decrypt_oaep()");
@@ -898,7 +1042,7 @@ impl RSA {
     /// }
     /// ```
     #[cfg(all(random, rsa_oaep))]
-    pub fn public_encrypt_oaep(&mut self, din: &[u8], dout: &mut [u8], hash_algo: u32, mgf: i32, rng: &RNG) -> Result<usize, i32> {
+    pub fn public_encrypt_oaep(&mut self, din: &[u8], dout: &mut [u8], hash_algo: u32, mgf: i32, rng: &RNG) -> Result<usize, Error> {
         self.public_encrypt_oaep_ex(din, dout, hash_algo, mgf, None, rng)
     }
 
@@ -924,7 +1068,7 @@ impl RSA {
     /// The size returned specifies the number of bytes written to the `dout`
     /// buffer.
     #[cfg(all(random, rsa_oaep))]
-    pub fn public_encrypt_oaep_ex(&mut self, din: &[u8], dout: &mut [u8], hash_algo: u32, mgf: i32, label: Option<&[u8]>, rng: &RNG) -> Result<usize, i32> {
+    pub fn public_encrypt_oaep_ex(&mut self, din: &[u8], dout: &mut [u8], hash_algo: u32, mgf: i32, label: Option<&[u8]>, rng: &RNG) -> Result<usize, Error> {
         let din_size = crate::buffer_len_to_u32(din.len())?;
         let dout_size = crate::buffer_len_to_u32(dout.len())?;
         let (label_ptr, label_size) = match label {
@@ -941,7 +1085,7 @@ impl RSA {
                 hash_algo, mgf, label_ptr, label_size)
         };
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(rc as usize)
     }
@@ -964,7 +1108,7 @@ impl RSA {
     /// The size returned specifies the number of bytes written to the `dout`
     /// buffer.
     #[cfg(rsa_oaep)]
-    pub fn private_decrypt_oaep(&mut self, din: &[u8], dout: &mut [u8], hash_algo: u32, mgf: i32) -> Result<usize, i32> {
+    pub fn private_decrypt_oaep(&mut self, din: &[u8], dout: &mut [u8], hash_algo: u32, mgf: i32) -> Result<usize, Error> {
         self.private_decrypt_oaep_ex(din, dout, hash_algo, mgf, None)
     }
 
@@ -987,7 +1131,7 @@ impl RSA {
     /// The size returned specifies the number of bytes written to the `dout`
     /// buffer.
     #[cfg(rsa_oaep)]
-    pub fn private_decrypt_oaep_ex(&mut self, din: &[u8], dout: &mut [u8], hash_algo: u32, mgf: i32, label: Option<&[u8]>) -> Result<usize, i32> {
+    pub fn private_decrypt_oaep_ex(&mut self, din: &[u8], dout: &mut [u8], hash_algo: u32, mgf: i32, label: Option<&[u8]>) -> Result<usize, Error> {
         let din_size = crate::buffer_len_to_u32(din.len())?;
         let dout_size = crate::buffer_len_to_u32(dout.len())?;
         let (label_ptr, label_size) = match label {
@@ -1004,7 +1148,7 @@ impl RSA {
                 hash_algo, mgf, label_ptr, label_size)
         };
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(rc as usize)
     }
@@ -1036,12 +1180,14 @@ impl RSA {
     /// use std::fs;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::rsa::RSA;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     ///
-    /// let rng = std::rc::Rc::new(RNG::new().expect("Error creating RNG"));
+    /// let rng = std::rc::Rc::new(RNG::new(&wolfcrypt).expect("Error creating RNG"));
     ///
     /// let key_path = "../../../certs/client-key.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut rsa = RSA::new_from_der(&der).expect("Error with new_from_der()");
+    /// let mut rsa = RSA::new_from_der(&der, &wolfcrypt).expect("Error with new_from_der()");
     /// let msg: &[u8] = b"This is the string to be signed!";
     /// let mut signature: [u8; 512] = [0; 512];
     /// let sig_len = rsa.pss_sign(msg, &mut signature, RSA::HASH_TYPE_SHA256, RSA::MGF1SHA256, &rng).expect("Error with pss_sign()");
@@ -1049,7 +1195,7 @@ impl RSA {
     ///
     /// let key_path = "../../../certs/client-keyPub.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut rsa = RSA::new_public_from_der(&der).expect("Error with new_public_from_der()");
+    /// let mut rsa = RSA::new_public_from_der(&der, &wolfcrypt).expect("Error with new_public_from_der()");
     /// rsa.set_shared_rng(std::rc::Rc::clone(&rng)).expect("Error with set_shared_rng()");
     /// let signature = &signature[0..sig_len];
     /// let mut verify_out: [u8; 512] = [0; 512];
@@ -1062,7 +1208,7 @@ impl RSA {
     /// }
     /// ```
     #[cfg(all(random, rsa_pss))]
-    pub fn pss_sign(&mut self, din: &[u8], dout: &mut [u8], hash_algo: u32, mgf: i32, rng: &RNG) -> Result<usize, i32> {
+    pub fn pss_sign(&mut self, din: &[u8], dout: &mut [u8], hash_algo: u32, mgf: i32, rng: &RNG) -> Result<usize, Error> {
         let din_size = crate::buffer_len_to_u32(din.len())?;
         let dout_size = crate::buffer_len_to_u32(dout.len())?;
         let rc = unsafe {
@@ -1070,7 +1216,7 @@ impl RSA {
                 hash_algo, mgf, &mut self.wc_rsakey, rng.wc_rng)
         };
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(rc as usize)
     }
@@ -1099,12 +1245,14 @@ impl RSA {
     /// use std::fs;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::rsa::RSA;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     ///
-    /// let rng = std::rc::Rc::new(RNG::new().expect("Error creating RNG"));
+    /// let rng = std::rc::Rc::new(RNG::new(&wolfcrypt).expect("Error creating RNG"));
     ///
     /// let key_path = "../../../certs/client-key.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut rsa = RSA::new_from_der(&der).expect("Error with new_from_der()");
+    /// let mut rsa = RSA::new_from_der(&der, &wolfcrypt).expect("Error with new_from_der()");
     /// let msg: &[u8] = b"This is the string to be signed!";
     /// let mut signature: [u8; 512] = [0; 512];
     /// let sig_len = rsa.pss_sign(msg, &mut signature, RSA::HASH_TYPE_SHA256, RSA::MGF1SHA256, &rng).expect("Error with pss_sign()");
@@ -1112,7 +1260,7 @@ impl RSA {
     ///
     /// let key_path = "../../../certs/client-keyPub.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut rsa = RSA::new_public_from_der(&der).expect("Error with new_public_from_der()");
+    /// let mut rsa = RSA::new_public_from_der(&der, &wolfcrypt).expect("Error with new_public_from_der()");
     /// rsa.set_shared_rng(std::rc::Rc::clone(&rng)).expect("Error with set_shared_rng()");
     /// let signature = &signature[0..sig_len];
     /// let mut verify_out: [u8; 512] = [0; 512];
@@ -1125,7 +1273,7 @@ impl RSA {
     /// }
     /// ```
     #[cfg(all(rsa_pss, rsa_const_api))]
-    pub fn pss_check_padding(&mut self, din: &[u8], sig: &[u8], hash_algo: u32) -> Result<(), i32> {
+    pub fn pss_check_padding(&mut self, din: &[u8], sig: &[u8], hash_algo: u32) -> Result<(), Error> {
         let din_size = crate::buffer_len_to_u32(din.len())?;
         let sig_size = crate::buffer_len_to_u32(sig.len())?;
         let rc = unsafe {
@@ -1133,7 +1281,7 @@ impl RSA {
                 sig.as_ptr(), sig_size, hash_algo)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1165,12 +1313,14 @@ impl RSA {
     /// use std::fs;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::rsa::RSA;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     ///
-    /// let rng = std::rc::Rc::new(RNG::new().expect("Error creating RNG"));
+    /// let rng = std::rc::Rc::new(RNG::new(&wolfcrypt).expect("Error creating RNG"));
     ///
     /// let key_path = "../../../certs/client-key.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut rsa = RSA::new_from_der(&der).expect("Error with new_from_der()");
+    /// let mut rsa = RSA::new_from_der(&der, &wolfcrypt).expect("Error with new_from_der()");
     /// let msg: &[u8] = b"This is the string to be signed!";
     /// let mut signature: [u8; 512] = [0; 512];
     /// let sig_len = rsa.pss_sign(msg, &mut signature, RSA::HASH_TYPE_SHA256, RSA::MGF1SHA256, &rng).expect("Error with pss_sign()");
@@ -1178,7 +1328,7 @@ impl RSA {
     ///
     /// let key_path = "../../../certs/client-keyPub.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut rsa = RSA::new_public_from_der(&der).expect("Error with new_public_from_der()");
+    /// let mut rsa = RSA::new_public_from_der(&der, &wolfcrypt).expect("Error with new_public_from_der()");
     /// rsa.set_shared_rng(std::rc::Rc::clone(&rng)).expect("Error with set_shared_rng()");
     /// let signature = &signature[0..sig_len];
     /// let mut verify_out: [u8; 512] = [0; 512];
@@ -1191,7 +1341,7 @@ impl RSA {
     /// }
     /// ```
     #[cfg(all(rsa_pss, rsa_const_api))]
-    pub fn pss_verify(&mut self, din: &[u8], dout: &mut [u8], hash_algo: u32, mgf: i32) -> Result<usize, i32> {
+    pub fn pss_verify(&mut self, din: &[u8], dout: &mut [u8], hash_algo: u32, mgf: i32) -> Result<usize, Error> {
         let din_size = crate::buffer_len_to_u32(din.len())?;
         let dout_size = crate::buffer_len_to_u32(dout.len())?;
         let rc = unsafe {
@@ -1200,7 +1350,7 @@ impl RSA {
                 hash_algo, mgf, &mut self.wc_rsakey)
         };
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(rc as usize)
     }
@@ -1236,12 +1386,14 @@ impl RSA {
     /// use std::fs;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::rsa::RSA;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     ///
-    /// let rng = std::rc::Rc::new(RNG::new().expect("Error creating RNG"));
+    /// let rng = std::rc::Rc::new(RNG::new(&wolfcrypt).expect("Error creating RNG"));
     ///
     /// let key_path = "../../../certs/client-key.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut rsa = RSA::new_from_der(&der).expect("Error with new_from_der()");
+    /// let mut rsa = RSA::new_from_der(&der, &wolfcrypt).expect("Error with new_from_der()");
     /// let msg: &[u8] = b"This is the string to be signed!";
     /// let mut signature: [u8; 512] = [0; 512];
     /// let sig_len = rsa.pss_sign(msg, &mut signature, RSA::HASH_TYPE_SHA256, RSA::MGF1SHA256, &rng).expect("Error with pss_sign()");
@@ -1249,7 +1401,7 @@ impl RSA {
     ///
     /// let key_path = "../../../certs/client-keyPub.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut rsa = RSA::new_public_from_der(&der).expect("Error with new_public_from_der()");
+    /// let mut rsa = RSA::new_public_from_der(&der, &wolfcrypt).expect("Error with new_public_from_der()");
     /// rsa.set_shared_rng(std::rc::Rc::clone(&rng)).expect("Error with set_shared_rng()");
     /// let signature = &signature[0..sig_len];
     /// let mut verify_out: [u8; 512] = [0; 512];
@@ -1262,7 +1414,7 @@ impl RSA {
     /// }
     /// ```
     #[cfg(all(rsa_pss, rsa_const_api))]
-    pub fn pss_verify_check(&mut self, din: &[u8], dout: &mut [u8], digest: &[u8], hash_algo: u32, mgf: i32) -> Result<usize, i32> {
+    pub fn pss_verify_check(&mut self, din: &[u8], dout: &mut [u8], digest: &[u8], hash_algo: u32, mgf: i32) -> Result<usize, Error> {
         let din_size = crate::buffer_len_to_u32(din.len())?;
         let dout_size = crate::buffer_len_to_u32(dout.len())?;
         let digest_size = crate::buffer_len_to_u32(digest.len())?;
@@ -1272,7 +1424,7 @@ impl RSA {
                 hash_algo, mgf, &mut self.wc_rsakey)
         };
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(rc as usize)
     }
@@ -1306,12 +1458,14 @@ impl RSA {
     /// use std::fs;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::rsa::RSA;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     ///
-    /// let rng = RNG::new().expect("Error creating RNG");
+    /// let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
     ///
     /// let key_path = "../../../certs/client-key.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut rsa = RSA::new_from_der(&der).expect("Error with new_from_der()");
+    /// let mut rsa = RSA::new_from_der(&der, &wolfcrypt).expect("Error with new_from_der()");
     /// let msg = b"A rsa_direct() test input string";
     /// let mut plain = [0u8; 256];
     /// plain[..msg.len()].copy_from_slice(msg);
@@ -1325,7 +1479,7 @@ impl RSA {
     /// }
     /// ```
     #[cfg(all(rsa_direct, rsa_const_api))]
-    pub fn rsa_direct(&mut self, din: &[u8], dout: &mut [u8], typ: i32, rng: &RNG) -> Result<usize, i32> {
+    pub fn rsa_direct(&mut self, din: &[u8], dout: &mut [u8], typ: i32, rng: &RNG) -> Result<usize, Error> {
         let din_size = crate::buffer_len_to_u32(din.len())?;
         let mut dout_size = crate::buffer_len_to_u32(dout.len())?;
         let rc = unsafe {
@@ -1334,7 +1488,7 @@ impl RSA {
                 &mut self.wc_rsakey, typ, rng.wc_rng)
         };
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(dout_size as usize)
     }
@@ -1363,20 +1517,22 @@ impl RSA {
     /// use std::fs;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::rsa::RSA;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     ///
     /// let key_path = "../../../certs/client-keyPub.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut rsa = RSA::new_public_from_der(&der).expect("Error with new_public_from_der()");
-    /// rsa.set_rng(RNG::new().expect("Error creating RNG")).expect("Error with set_rng()");
+    /// let mut rsa = RSA::new_public_from_der(&der, &wolfcrypt).expect("Error with new_public_from_der()");
+    /// rsa.set_rng(RNG::new(&wolfcrypt).expect("Error creating RNG")).expect("Error with set_rng()");
     /// let plain: &[u8] = b"Test message";
     /// let mut enc: [u8; 512] = [0; 512];
-    /// let enc_rng = RNG::new().expect("Error creating RNG");
+    /// let enc_rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
     /// let enc_len = rsa.public_encrypt(plain, &mut enc, &enc_rng).expect("Error with public_encrypt()");
     /// assert!(enc_len > 0 && enc_len <= 512);
     /// let key_path = "../../../certs/client-key.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut rsa = RSA::new_from_der(&der).expect("Error with new_from_der()");
-    /// rsa.set_rng(RNG::new().expect("Error creating RNG")).expect("Error with set_rng()");
+    /// let mut rsa = RSA::new_from_der(&der, &wolfcrypt).expect("Error with new_from_der()");
+    /// rsa.set_rng(RNG::new(&wolfcrypt).expect("Error creating RNG")).expect("Error with set_rng()");
     /// let mut plain_out: [u8; 512] = [0; 512];
     /// let dec_len = rsa.private_decrypt(&enc[0..enc_len], &mut plain_out).expect("Error with private_decrypt()");
     /// assert!(dec_len as usize == plain.len());
@@ -1384,13 +1540,13 @@ impl RSA {
     /// }
     /// ```
     #[cfg(random)]
-    pub fn set_rng(&mut self, rng: RNG) -> Result<(), i32> {
+    pub fn set_rng(&mut self, rng: RNG<'a>) -> Result<(), Error> {
         let wc_rng = rng.wc_rng;
         let rc = unsafe {
             sys::wc_RsaSetRNG(&mut self.wc_rsakey, wc_rng)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         self.rng = Some(RngHandle::Owned(rng));
         Ok(())
@@ -1402,13 +1558,13 @@ impl RSA {
     /// among multiple consumers and used directly by the caller. Available
     /// when the `alloc` feature is enabled.
     #[cfg(all(random, feature = "alloc"))]
-    pub fn set_shared_rng(&mut self, rng: alloc::rc::Rc<RNG>) -> Result<(), i32> {
+    pub fn set_shared_rng(&mut self, rng: alloc::rc::Rc<RNG<'a>>) -> Result<(), Error> {
         let wc_rng = rng.wc_rng;
         let rc = unsafe {
             sys::wc_RsaSetRNG(&mut self.wc_rsakey, wc_rng)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         self.rng = Some(RngHandle::Shared(rng));
         Ok(())
@@ -1416,7 +1572,7 @@ impl RSA {
 
     /// Borrow the RNG previously bound via `set_rng` or `set_shared_rng`.
     #[cfg(random)]
-    pub fn rng(&self) -> Option<&RNG> {
+    pub fn rng(&self) -> Option<&RNG<'a>> {
         match &self.rng {
             Some(RngHandle::Owned(rng)) => Some(rng),
             #[cfg(feature = "alloc")]
@@ -1450,12 +1606,14 @@ impl RSA {
     /// use std::fs;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::rsa::RSA;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     ///
-    /// let rng = std::rc::Rc::new(RNG::new().expect("Error creating RNG"));
+    /// let rng = std::rc::Rc::new(RNG::new(&wolfcrypt).expect("Error creating RNG"));
     ///
     /// let key_path = "../../../certs/client-key.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut rsa = RSA::new_from_der(&der).expect("Error with new_from_der()");
+    /// let mut rsa = RSA::new_from_der(&der, &wolfcrypt).expect("Error with new_from_der()");
     /// let msg: &[u8] = b"This is the string to be signed!";
     /// let mut signature: [u8; 512] = [0; 512];
     /// let sig_len = rsa.ssl_sign(msg, &mut signature, &rng).expect("Error with ssl_sign()");
@@ -1463,7 +1621,7 @@ impl RSA {
     ///
     /// let key_path = "../../../certs/client-keyPub.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut rsa = RSA::new_public_from_der(&der).expect("Error with new_public_from_der()");
+    /// let mut rsa = RSA::new_public_from_der(&der, &wolfcrypt).expect("Error with new_public_from_der()");
     /// rsa.set_shared_rng(std::rc::Rc::clone(&rng)).expect("Error with set_shared_rng()");
     /// let signature = &signature[0..sig_len];
     /// let mut verify_out: [u8; 512] = [0; 512];
@@ -1472,7 +1630,7 @@ impl RSA {
     /// }
     /// ```
     #[cfg(random)]
-    pub fn ssl_sign(&mut self, din: &[u8], dout: &mut [u8], rng: &RNG) -> Result<usize, i32> {
+    pub fn ssl_sign(&mut self, din: &[u8], dout: &mut [u8], rng: &RNG) -> Result<usize, Error> {
         let din_size = crate::buffer_len_to_u32(din.len())?;
         let dout_size = crate::buffer_len_to_u32(dout.len())?;
         let rc = unsafe {
@@ -1481,7 +1639,7 @@ impl RSA {
                 &mut self.wc_rsakey, rng.wc_rng)
         };
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(rc as usize)
     }
@@ -1512,12 +1670,14 @@ impl RSA {
     /// use std::fs;
     /// use wolfssl_wolfcrypt::random::RNG;
     /// use wolfssl_wolfcrypt::rsa::RSA;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     ///
-    /// let rng = std::rc::Rc::new(RNG::new().expect("Error creating RNG"));
+    /// let rng = std::rc::Rc::new(RNG::new(&wolfcrypt).expect("Error creating RNG"));
     ///
     /// let key_path = "../../../certs/client-key.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut rsa = RSA::new_from_der(&der).expect("Error with new_from_der()");
+    /// let mut rsa = RSA::new_from_der(&der, &wolfcrypt).expect("Error with new_from_der()");
     /// let msg: &[u8] = b"This is the string to be signed!";
     /// let mut signature: [u8; 512] = [0; 512];
     /// let sig_len = rsa.ssl_sign(msg, &mut signature, &rng).expect("Error with ssl_sign()");
@@ -1525,7 +1685,7 @@ impl RSA {
     ///
     /// let key_path = "../../../certs/client-keyPub.der";
     /// let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    /// let mut rsa = RSA::new_public_from_der(&der).expect("Error with new_public_from_der()");
+    /// let mut rsa = RSA::new_public_from_der(&der, &wolfcrypt).expect("Error with new_public_from_der()");
     /// rsa.set_shared_rng(std::rc::Rc::clone(&rng)).expect("Error with set_shared_rng()");
     /// let signature = &signature[0..sig_len];
     /// let mut verify_out: [u8; 512] = [0; 512];
@@ -1533,7 +1693,7 @@ impl RSA {
     /// assert!(verify_out_size > 0 && verify_out_size <= 512);
     /// }
     /// ```
-    pub fn ssl_verify(&mut self, din: &[u8], dout: &mut [u8]) -> Result<usize, i32> {
+    pub fn ssl_verify(&mut self, din: &[u8], dout: &mut [u8]) -> Result<usize, Error> {
         let din_size = crate::buffer_len_to_u32(din.len())?;
         let dout_size = crate::buffer_len_to_u32(dout.len())?;
         let rc = unsafe {
@@ -1541,19 +1701,19 @@ impl RSA {
                 dout.as_mut_ptr(), dout_size, &mut self.wc_rsakey)
         };
         if rc < 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(rc as usize)
     }
 }
 
-impl RSA {
+impl<'a> RSA<'a> {
     fn zeroize(&mut self) {
         unsafe { crate::zeroize_raw(&mut self.wc_rsakey); }
     }
 }
 
-impl Drop for RSA {
+impl Drop for RSA<'_> {
     /// Safely free the underlying wolfSSL RSA context.
     ///
     /// This calls the `wc_FreeRsaKey` wolfssl library function.
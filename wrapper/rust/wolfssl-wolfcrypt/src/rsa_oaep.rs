@@ -45,9 +45,11 @@ shape of those traits without claiming conformance.
 {
 use wolfssl_wolfcrypt::random::RNG;
 use wolfssl_wolfcrypt::rsa_oaep::{Sha256, EncryptingKey, DecryptingKey};
+use wolfssl_wolfcrypt::WolfCrypt;
+let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
 
-let pad_rng = RNG::new().expect("RNG");
-let mut dk: DecryptingKey<Sha256, 256> = DecryptingKey::generate(RNG::new().expect("RNG")).expect("dk");
+let pad_rng = RNG::new(&wolfcrypt).expect("RNG");
+let mut dk: DecryptingKey<Sha256, 256> = DecryptingKey::generate(RNG::new(&wolfcrypt).expect("RNG")).expect("dk");
 let ek: EncryptingKey<Sha256, 256> = dk.encrypting_key().expect("ek");
 
 let msg = b"hello, OAEP";
@@ -64,6 +66,7 @@ assert_eq!(&buf[..n], msg);
 use core::marker::PhantomData;
 
 use crate::rsa::RSA;
+use crate::Error;
 use crate::sys;
 #[cfg(random)]
 use crate::random::RNG;
@@ -162,8 +165,8 @@ impl<const N: usize> AsRef<[u8]> for Ciphertext<N> {
 }
 
 impl<const N: usize> TryFrom<&[u8]> for Ciphertext<N> {
-    type Error = i32;
-    fn try_from(bytes: &[u8]) -> Result<Self, i32> {
+    type Error = Error;
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
         let arr: [u8; N] = bytes.try_into()
             .map_err(|_| sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG)?;
         Ok(Self(arr))
@@ -176,10 +179,10 @@ impl<const N: usize> From<Ciphertext<N>> for [u8; N] {
     }
 }
 
-fn check_modulus_size(rsa: &RSA, expected: usize) -> Result<(), i32> {
+fn check_modulus_size(rsa: &RSA, expected: usize) -> Result<(), Error> {
     let actual = rsa.get_encrypt_size()?;
     if actual != expected {
-        return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+        return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
     }
     Ok(())
 }
@@ -221,9 +224,9 @@ impl<H: Hash, const N: usize> Eq for EncryptingKey<H, N> {}
 impl<H: Hash, const N: usize> EncryptingKey<H, N> {
     /// Construct an encrypting key from raw big-endian modulus (`n`) and
     /// public exponent (`e`) bytes.
-    pub fn from_components(n: &[u8], e: &[u8]) -> Result<Self, i32> {
+    pub fn from_components(n: &[u8], e: &[u8]) -> Result<Self, Error> {
         if n.len() != N || e.is_empty() || e.len() > MAX_E_LEN {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         let mut n_arr = [0u8; N];
         n_arr.copy_from_slice(n);
@@ -239,7 +242,7 @@ impl<H: Hash, const N: usize> EncryptingKey<H, N> {
 
     /// Adopt the public part of an existing [`RSA`] key, verifying its
     /// modulus size in bytes matches `N`.
-    pub fn from_rsa(rsa: &RSA) -> Result<Self, i32> {
+    pub fn from_rsa(rsa: &RSA) -> Result<Self, Error> {
         check_modulus_size(rsa, N)?;
         let mut n = [0u8; N];
         let mut e = [0u8; MAX_E_LEN];
@@ -259,10 +262,10 @@ impl<H: Hash, const N: usize> EncryptingKey<H, N> {
             )
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         if (n_len as usize) != N || e_len == 0 || (e_len as usize) > MAX_E_LEN {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         Ok(Self {
             n,
@@ -274,8 +277,9 @@ impl<H: Hash, const N: usize> EncryptingKey<H, N> {
 
     /// Construct an encrypting key from a DER-encoded `SubjectPublicKeyInfo`
     /// / PKCS#1 public key.
-    pub fn from_public_der(der: &[u8]) -> Result<Self, i32> {
-        let rsa = RSA::new_public_from_der(der)?;
+    pub fn from_public_der(der: &[u8]) -> Result<Self, Error> {
+        let wolfcrypt = crate::WolfCrypt::global()?;
+        let rsa = RSA::new_public_from_der(der, wolfcrypt)?;
         Self::from_rsa(&rsa)
     }
 
@@ -291,24 +295,25 @@ impl<H: Hash, const N: usize> EncryptingKey<H, N> {
 
     /// Encrypt `msg` with RSAES-OAEP, returning the fixed-size ciphertext.
     #[cfg(random)]
-    pub fn encrypt(&self, rng: &RNG, msg: &[u8]) -> Result<Ciphertext<N>, i32> {
+    pub fn encrypt(&self, rng: &RNG, msg: &[u8]) -> Result<Ciphertext<N>, Error> {
         self.encrypt_inner(rng, msg, None)
     }
 
     /// Encrypt `msg` with RSAES-OAEP using an associated `label`, returning
     /// the fixed-size ciphertext.
     #[cfg(random)]
-    pub fn encrypt_with_label(&self, rng: &RNG, msg: &[u8], label: &[u8]) -> Result<Ciphertext<N>, i32> {
+    pub fn encrypt_with_label(&self, rng: &RNG, msg: &[u8], label: &[u8]) -> Result<Ciphertext<N>, Error> {
         self.encrypt_inner(rng, msg, Some(label))
     }
 
     #[cfg(random)]
-    fn encrypt_inner(&self, rng: &RNG, msg: &[u8], label: Option<&[u8]>) -> Result<Ciphertext<N>, i32> {
-        let mut rsa = RSA::new_public_from_raw(&self.n, self.exponent())?;
+    fn encrypt_inner(&self, rng: &RNG, msg: &[u8], label: Option<&[u8]>) -> Result<Ciphertext<N>, Error> {
+        let wolfcrypt = crate::WolfCrypt::global()?;
+        let mut rsa = RSA::new_public_from_raw(&self.n, self.exponent(), wolfcrypt)?;
         let mut out = [0u8; N];
         let len = rsa.public_encrypt_oaep_ex(msg, &mut out, H::HASH_TYPE, H::MGF, label, rng)?;
         if len != N {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         Ok(Ciphertext(out))
     }
@@ -319,7 +324,7 @@ impl<H: Hash, const N: usize> EncryptingKey<H, N> {
 /// `H` selects the OAEP hash; `N` is the expected modulus size in bytes
 /// (e.g. `256` for RSA-2048, `384` for RSA-3072).
 pub struct DecryptingKey<H: Hash, const N: usize> {
-    inner: RSA,
+    inner: RSA<'static>,
     _hash: PhantomData<H>,
 }
 
@@ -327,9 +332,10 @@ impl<H: Hash, const N: usize> DecryptingKey<H, N> {
     /// Generate a fresh `N * 8`-bit RSA key with public exponent 65537. The
     /// `rng` is consumed and bound to the key for blinding during decryption.
     #[cfg(all(random, rsa_keygen))]
-    pub fn generate(rng: RNG) -> Result<Self, i32> {
+    pub fn generate(rng: RNG<'static>) -> Result<Self, Error> {
         let bits: i32 = (N * 8).try_into().map_err(|_| sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG)?;
-        let mut rsa = RSA::generate(bits, 65537, &rng)?;
+        let wolfcrypt = crate::WolfCrypt::global()?;
+        let mut rsa = RSA::generate(bits, 65537, &rng, wolfcrypt)?;
         rsa.set_rng(rng)?;
         Ok(Self { inner: rsa, _hash: PhantomData })
     }
@@ -338,7 +344,7 @@ impl<H: Hash, const N: usize> DecryptingKey<H, N> {
     /// matches `N`. The `rng` is consumed and bound to the key for blinding
     /// during decryption.
     #[cfg(random)]
-    pub fn from_rsa(rsa: RSA, rng: RNG) -> Result<Self, i32> {
+    pub fn from_rsa(rsa: RSA<'static>, rng: RNG<'static>) -> Result<Self, Error> {
         check_modulus_size(&rsa, N)?;
         let mut rsa = rsa;
         rsa.set_rng(rng)?;
@@ -348,39 +354,40 @@ impl<H: Hash, const N: usize> DecryptingKey<H, N> {
     /// Construct a decrypting key from a DER-encoded PKCS#1 private key. The
     /// `rng` is consumed and bound to the key for blinding during decryption.
     #[cfg(random)]
-    pub fn from_private_der(der: &[u8], rng: RNG) -> Result<Self, i32> {
-        let rsa = RSA::new_from_der(der)?;
+    pub fn from_private_der(der: &[u8], rng: RNG<'static>) -> Result<Self, Error> {
+        let wolfcrypt = crate::WolfCrypt::global()?;
+        let rsa = RSA::new_from_der(der, wolfcrypt)?;
         Self::from_rsa(rsa, rng)
     }
 
     /// Borrow the inner [`RSA`] key.
-    pub fn as_rsa(&self) -> &RSA {
+    pub fn as_rsa(&self) -> &RSA<'static> {
         &self.inner
     }
 
     /// Consume the decrypting key and return its inner [`RSA`].
-    pub fn into_rsa(self) -> RSA {
+    pub fn into_rsa(self) -> RSA<'static> {
         self.inner
     }
 
     /// Derive the matching [`EncryptingKey`] from this decrypting key.
-    pub fn encrypting_key(&self) -> Result<EncryptingKey<H, N>, i32> {
+    pub fn encrypting_key(&self) -> Result<EncryptingKey<H, N>, Error> {
         EncryptingKey::from_rsa(&self.inner)
     }
 
     /// Decrypt `ciphertext` and write the recovered plaintext into `out`,
     /// returning the plaintext length.
-    pub fn decrypt(&mut self, ciphertext: &Ciphertext<N>, out: &mut [u8]) -> Result<usize, i32> {
+    pub fn decrypt(&mut self, ciphertext: &Ciphertext<N>, out: &mut [u8]) -> Result<usize, Error> {
         self.decrypt_inner(ciphertext, out, None)
     }
 
     /// Decrypt `ciphertext` with an associated `label` and write the
     /// recovered plaintext into `out`, returning the plaintext length.
-    pub fn decrypt_with_label(&mut self, ciphertext: &Ciphertext<N>, out: &mut [u8], label: &[u8]) -> Result<usize, i32> {
+    pub fn decrypt_with_label(&mut self, ciphertext: &Ciphertext<N>, out: &mut [u8], label: &[u8]) -> Result<usize, Error> {
         self.decrypt_inner(ciphertext, out, Some(label))
     }
 
-    fn decrypt_inner(&mut self, ciphertext: &Ciphertext<N>, out: &mut [u8], label: Option<&[u8]>) -> Result<usize, i32> {
+    fn decrypt_inner(&mut self, ciphertext: &Ciphertext<N>, out: &mut [u8], label: Option<&[u8]>) -> Result<usize, Error> {
         self.inner.private_decrypt_oaep_ex(&ciphertext.0, out, H::HASH_TYPE, H::MGF, label)
     }
 }
@@ -128,10 +128,10 @@ impl<const N: usize> SignatureEncoding for Signature<N> {
     type Repr = [u8; N];
 }
 
-fn check_modulus_size(rsa: &RSA, expected: usize) -> Result<(), i32> {
+fn check_modulus_size(rsa: &RSA, expected: usize) -> Result<(), crate::Error> {
     let actual = rsa.get_encrypt_size()?;
     if actual != expected {
-        return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+        return Err(crate::Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
     }
     Ok(())
 }
@@ -141,34 +141,35 @@ fn check_modulus_size(rsa: &RSA, expected: usize) -> Result<(), i32> {
 /// `H` selects the hash used in DigestInfo encoding; `N` is the expected
 /// modulus size in bytes (e.g. `256` for RSA-2048, `384` for RSA-3072).
 pub struct SigningKey<H: Hash, const N: usize> {
-    inner: RSA,
-    rng: RNG,
+    inner: RSA<'static>,
+    rng: RNG<'static>,
     _hash: PhantomData<H>,
 }
 
 impl<H: Hash, const N: usize> SigningKey<H, N> {
     /// Generate a fresh `N * 8`-bit RSA key with public exponent 65537.
     #[cfg(rsa_keygen)]
-    pub fn generate(rng: RNG) -> Result<Self, i32> {
+    pub fn generate(rng: RNG<'static>) -> Result<Self, crate::Error> {
         let bits: i32 = (N * 8).try_into().map_err(|_| sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG)?;
-        let rsa = RSA::generate(bits, 65537, &rng)?;
+        let wolfcrypt = crate::WolfCrypt::global()?;
+        let rsa = RSA::generate(bits, 65537, &rng, wolfcrypt)?;
         Ok(Self { inner: rsa, rng, _hash: PhantomData })
     }
 
     /// Adopt an existing [`RSA`] key, verifying that its modulus size in
     /// bytes matches `N`.
-    pub fn from_rsa(rsa: RSA, rng: RNG) -> Result<Self, i32> {
+    pub fn from_rsa(rsa: RSA<'static>, rng: RNG<'static>) -> Result<Self, crate::Error> {
         check_modulus_size(&rsa, N)?;
         Ok(Self { inner: rsa, rng, _hash: PhantomData })
     }
 
     /// Borrow the inner [`RSA`] key.
-    pub fn as_rsa(&self) -> &RSA {
+    pub fn as_rsa(&self) -> &RSA<'static> {
         &self.inner
     }
 
     /// Consume the signing key and return its `RSA` and `RNG` parts.
-    pub fn into_parts(self) -> (RSA, RNG) {
+    pub fn into_parts(self) -> (RSA<'static>, RNG<'static>) {
         (self.inner, self.rng)
     }
 }
@@ -254,7 +255,7 @@ impl<H: Hash, const N: usize> VerifyingKey<H, N> {
 
     /// Adopt an existing [`RSA`] public key, verifying its modulus size in
     /// bytes matches `N`.
-    pub fn from_rsa(rsa: RSA) -> Result<Self, i32> {
+    pub fn from_rsa(rsa: RSA) -> Result<Self, crate::Error> {
         check_modulus_size(&rsa, N)?;
         let mut n = [0u8; N];
         let mut e = [0u8; MAX_E_LEN];
@@ -274,10 +275,10 @@ impl<H: Hash, const N: usize> VerifyingKey<H, N> {
             )
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(crate::Error::from(rc));
         }
         if (n_len as usize) != N || e_len == 0 || (e_len as usize) > MAX_E_LEN {
-            return Err(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+            return Err(crate::Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
         }
         Ok(Self {
             n,
@@ -289,8 +290,9 @@ impl<H: Hash, const N: usize> VerifyingKey<H, N> {
 
     /// Construct a verifying key from a DER-encoded `SubjectPublicKeyInfo`
     /// / PKCS#1 public key.
-    pub fn from_public_der(der: &[u8]) -> Result<Self, i32> {
-        let rsa = RSA::new_public_from_der(der)?;
+    pub fn from_public_der(der: &[u8]) -> Result<Self, crate::Error> {
+        let wolfcrypt = crate::WolfCrypt::global()?;
+        let rsa = RSA::new_public_from_der(der, wolfcrypt)?;
         Self::from_rsa(rsa)
     }
 
@@ -308,7 +310,8 @@ impl<H: Hash, const N: usize> VerifyingKey<H, N> {
 impl<H: Hash, const N: usize> Verifier<Signature<N>> for VerifyingKey<H, N> {
     fn verify(&self, msg: &[u8], signature: &Signature<N>) -> Result<(), Error> {
         let msg_len: u32 = msg.len().try_into().map_err(|_| Error::new())?;
-        let mut rsa = RSA::new_public_from_raw(&self.n, self.exponent())
+        let wolfcrypt = crate::WolfCrypt::global().map_err(|_| Error::new())?;
+        let mut rsa = RSA::new_public_from_raw(&self.n, self.exponent(), wolfcrypt)
             .map_err(|_| Error::new())?;
         let rc = unsafe {
             sys::wc_SignatureVerify(
@@ -0,0 +1,157 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+Keyed AEAD instances shared by the TLS 1.2 and TLS 1.3 record protection
+code.
+*/
+
+use alloc::vec::Vec;
+
+use rustls::crypto::cipher::NONCE_LEN;
+use zeroize::Zeroize;
+
+#[cfg(aes_gcm)]
+use super::Exclusive;
+#[cfg(aes_gcm)]
+use crate::aes::GCM;
+#[cfg(chacha20_poly1305)]
+use crate::chacha20_poly1305::ChaCha20Poly1305;
+
+/// Authentication tag length of every AEAD supported by this provider.
+pub(crate) const TAG_LEN: usize = 16;
+
+/// Largest plaintext fragment a peer may send (RFC 8446 section 5.1).
+pub(crate) const MAX_FRAGMENT_LEN: usize = 16384;
+
+#[derive(Clone, Copy)]
+pub(crate) enum Algorithm {
+    #[cfg(aes_gcm)]
+    Aes128Gcm,
+    #[cfg(aes_gcm)]
+    Aes256Gcm,
+    #[cfg(chacha20_poly1305)]
+    ChaCha20Poly1305,
+}
+
+impl Algorithm {
+    pub(crate) fn key_len(self) -> usize {
+        match self {
+            #[cfg(aes_gcm)]
+            Algorithm::Aes128Gcm => 16,
+            #[cfg(aes_gcm)]
+            Algorithm::Aes256Gcm => 32,
+            #[cfg(chacha20_poly1305)]
+            Algorithm::ChaCha20Poly1305 => ChaCha20Poly1305::KEYSIZE,
+        }
+    }
+
+    /// Key the algorithm with `key`.
+    ///
+    /// Returns `None` if wolfCrypt rejects the key. rustls builds encrypters
+    /// and decrypters infallibly, so the error is reported by the first
+    /// record operation instead.
+    pub(crate) fn with_key(self, key: &[u8]) -> Option<Keyed> {
+        match self {
+            #[cfg(aes_gcm)]
+            Algorithm::Aes128Gcm | Algorithm::Aes256Gcm => {
+                if key.len() != self.key_len() {
+                    return None;
+                }
+                let wolfcrypt = crate::WolfCrypt::global().ok()?;
+                let mut gcm = GCM::new(wolfcrypt).ok()?;
+                gcm.init(key).ok()?;
+                Some(Keyed::Gcm(Exclusive::new(gcm)))
+            }
+            #[cfg(chacha20_poly1305)]
+            Algorithm::ChaCha20Poly1305 => {
+                let key: [u8; ChaCha20Poly1305::KEYSIZE] = key.try_into().ok()?;
+                Some(Keyed::ChaCha20Poly1305(key))
+            }
+        }
+    }
+}
+
+#[allow(clippy::large_enum_variant)]
+pub(crate) enum Keyed {
+    #[cfg(aes_gcm)]
+    Gcm(Exclusive<GCM<'static>>),
+    #[cfg(chacha20_poly1305)]
+    ChaCha20Poly1305([u8; ChaCha20Poly1305::KEYSIZE]),
+}
+
+impl Keyed {
+    /// Encrypt `plain` into `out`, which must be `plain.len() + TAG_LEN`
+    /// bytes long. The tag is written after the ciphertext.
+    pub(crate) fn seal(&mut self, nonce: &[u8; NONCE_LEN], aad: &[u8], plain: &[u8],
+            out: &mut [u8]) -> Result<(), crate::Error> {
+        let (ciphertext, tag) = out.split_at_mut(plain.len());
+        match self {
+            #[cfg(aes_gcm)]
+            Keyed::Gcm(gcm) => gcm.get_mut().encrypt(plain, ciphertext, nonce, aad, tag),
+            #[cfg(chacha20_poly1305)]
+            Keyed::ChaCha20Poly1305(key) =>
+                ChaCha20Poly1305::encrypt(key, nonce, aad, plain, ciphertext, tag),
+        }
+    }
+
+    /// Decrypt `ciphertext || tag` held in `payload` in place.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(len) containing the length of the plaintext now at
+    /// the start of `payload` or Err(e) containing the wolfSSL library error
+    /// code value.
+    pub(crate) fn open(&mut self, nonce: &[u8; NONCE_LEN], aad: &[u8], payload: &mut [u8])
+            -> Result<usize, crate::Error> {
+        let plain_len = payload.len() - TAG_LEN;
+        let sealed = payload.to_vec();
+        let (ciphertext, tag) = sealed.split_at(plain_len);
+        let plain = &mut payload[..plain_len];
+        let rc = match self {
+            #[cfg(aes_gcm)]
+            Keyed::Gcm(gcm) => gcm.get_mut().decrypt(ciphertext, plain, nonce, aad, tag),
+            #[cfg(chacha20_poly1305)]
+            Keyed::ChaCha20Poly1305(key) =>
+                ChaCha20Poly1305::decrypt(key, nonce, aad, ciphertext, tag, plain),
+        };
+        if rc.is_err() {
+            plain.zeroize();
+        }
+        rc.map(|()| plain_len)
+    }
+}
+
+impl Drop for Keyed {
+    fn drop(&mut self) {
+        match self {
+            // `GCM` zeroizes its key schedule when dropped.
+            #[cfg(aes_gcm)]
+            Keyed::Gcm(_) => {}
+            #[cfg(chacha20_poly1305)]
+            Keyed::ChaCha20Poly1305(key) => key.zeroize(),
+        }
+    }
+}
+
+/// Zeroize a temporary plaintext buffer.
+pub(crate) fn wipe(mut buf: Vec<u8>) {
+    buf.zeroize();
+}
@@ -0,0 +1,228 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+Transcript hash implementations over the wolfCrypt SHA-2 wrappers.
+*/
+
+use alloc::boxed::Box;
+
+use rustls::crypto::hash::{self, HashAlgorithm};
+
+use crate::hmac::HMAC;
+#[cfg(sha384)]
+use crate::sha::SHA384;
+use crate::sha::SHA256;
+
+pub(crate) static SHA256: Hash = Hash(HashAlgorithm::SHA256);
+#[cfg(sha384)]
+pub(crate) static SHA384: Hash = Hash(HashAlgorithm::SHA384);
+
+/// Return the output length in bytes of `alg`.
+pub(crate) fn output_len(alg: HashAlgorithm) -> usize {
+    match alg {
+        #[cfg(sha384)]
+        HashAlgorithm::SHA384 => SHA384::DIGEST_SIZE,
+        _ => SHA256::DIGEST_SIZE,
+    }
+}
+
+/// Return the wolfCrypt HMAC/HKDF hash type (`HMAC::TYPE_*`) for `alg`.
+pub(crate) fn hmac_type(alg: HashAlgorithm) -> i32 {
+    match alg {
+        #[cfg(sha384)]
+        HashAlgorithm::SHA384 => HMAC::TYPE_SHA384,
+        _ => HMAC::TYPE_SHA256,
+    }
+}
+
+pub(crate) struct Hash(pub(crate) HashAlgorithm);
+
+impl hash::Hash for Hash {
+    fn start(&self) -> Box<dyn hash::Context> {
+        Box::new(Context(State::new(self.0)))
+    }
+
+    fn hash(&self, data: &[u8]) -> hash::Output {
+        let mut state = State::new(self.0);
+        state.update(data);
+        state.finish()
+    }
+
+    fn output_len(&self) -> usize {
+        output_len(self.0)
+    }
+
+    fn algorithm(&self) -> HashAlgorithm {
+        self.0
+    }
+
+    fn fips(&self) -> bool {
+        super::fips()
+    }
+}
+
+struct Context(State);
+
+// Safety: the hash states are created without a device and own all of their
+// C state, so they may be moved to another thread. The only `&self` access
+// is forking, which copies the state with `wc_Sha256Copy()` or
+// `wc_Sha384Copy()` and only reads it, so concurrent forks are sound.
+unsafe impl Send for Context {}
+unsafe impl Sync for Context {}
+
+impl hash::Context for Context {
+    fn fork_finish(&self) -> hash::Output {
+        self.0.fork().finish()
+    }
+
+    fn fork(&self) -> Box<dyn hash::Context> {
+        Box::new(Context(self.0.fork()))
+    }
+
+    fn finish(mut self: Box<Self>) -> hash::Output {
+        self.0.finish()
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+}
+
+enum State {
+    Sha256(SHA256<'static>),
+    #[cfg(sha384)]
+    Sha384(SHA384<'static>),
+}
+
+// The rustls hash traits are infallible. The wolfCrypt SHA-2 functions only
+// fail on invalid arguments, which the fixed-size buffers below rule out, so
+// a failure here indicates a broken library build and panics.
+impl State {
+    fn new(alg: HashAlgorithm) -> Self {
+        let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+        match alg {
+            #[cfg(sha384)]
+            HashAlgorithm::SHA384 =>
+                State::Sha384(SHA384::new(wolfcrypt).expect("Error with SHA384::new()")),
+            _ => State::Sha256(SHA256::new(wolfcrypt).expect("Error with SHA256::new()")),
+        }
+    }
+
+    fn fork(&self) -> Self {
+        match self {
+            State::Sha256(sha) => State::Sha256(sha.try_clone().expect("Error with try_clone()")),
+            #[cfg(sha384)]
+            State::Sha384(sha) => State::Sha384(sha.try_clone().expect("Error with try_clone()")),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        let rc = match self {
+            State::Sha256(sha) => sha.update(data),
+            #[cfg(sha384)]
+            State::Sha384(sha) => sha.update(data),
+        };
+        rc.expect("Error with update()");
+    }
+
+    fn finish(&mut self) -> hash::Output {
+        match self {
+            State::Sha256(sha) => {
+                let mut out = [0u8; SHA256::DIGEST_SIZE];
+                sha.finalize(&mut out).expect("Error with finalize()");
+                hash::Output::new(&out)
+            }
+            #[cfg(sha384)]
+            State::Sha384(sha) => {
+                let mut out = [0u8; SHA384::DIGEST_SIZE];
+                sha.finalize(&mut out).expect("Error with finalize()");
+                hash::Output::new(&out)
+            }
+        }
+    }
+}
+
+/// One-shot hash of `data` with the wolfCrypt hash type `hash_type`
+/// (`sys::wc_HashType_*`) into `out`, which must be exactly the digest size.
+///
+/// # Returns
+///
+/// Returns either Ok(()) on success or Err(e) containing the wolfSSL library
+/// error code value.
+#[cfg(any(ecc, rsa))]
+pub(crate) fn digest(hash_type: u32, data: &[u8], out: &mut [u8]) -> Result<(), crate::Error> {
+    let data_size = crate::buffer_len_to_u32(data.len())?;
+    let out_size = crate::buffer_len_to_u32(out.len())?;
+    let rc = unsafe {
+        crate::sys::wc_Hash(hash_type, data.as_ptr(), data_size, out.as_mut_ptr(), out_size)
+    };
+    if rc != 0 {
+        return Err(crate::Error::from(rc));
+    }
+    Ok(())
+}
+
+/// A hash function used by a TLS signature scheme.
+#[cfg(any(ecc, rsa))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum SigHash {
+    Sha256,
+    #[cfg(sha384)]
+    Sha384,
+    #[cfg(sha512)]
+    Sha512,
+}
+
+#[cfg(any(ecc, rsa))]
+impl SigHash {
+    /// Largest digest length of any `SigHash`.
+    pub(crate) const MAX_LEN: usize = 64;
+
+    /// The wolfCrypt hash type (`sys::wc_HashType_*`).
+    pub(crate) fn hash_type(self) -> u32 {
+        match self {
+            SigHash::Sha256 => crate::sys::wc_HashType_WC_HASH_TYPE_SHA256,
+            #[cfg(sha384)]
+            SigHash::Sha384 => crate::sys::wc_HashType_WC_HASH_TYPE_SHA384,
+            #[cfg(sha512)]
+            SigHash::Sha512 => crate::sys::wc_HashType_WC_HASH_TYPE_SHA512,
+        }
+    }
+
+    /// The digest length in bytes.
+    pub(crate) fn len(self) -> usize {
+        match self {
+            SigHash::Sha256 => 32,
+            #[cfg(sha384)]
+            SigHash::Sha384 => 48,
+            #[cfg(sha512)]
+            SigHash::Sha512 => 64,
+        }
+    }
+
+    /// Hash `data`, returning the digest in a buffer of `SigHash::MAX_LEN`
+    /// bytes of which the first `len()` are used.
+    pub(crate) fn digest(self, data: &[u8]) -> Result<[u8; SigHash::MAX_LEN], crate::Error> {
+        let mut out = [0u8; SigHash::MAX_LEN];
+        digest(self.hash_type(), data, &mut out[..self.len()])?;
+        Ok(out)
+    }
+}
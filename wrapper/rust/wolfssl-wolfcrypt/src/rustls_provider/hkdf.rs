@@ -0,0 +1,119 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+TLS 1.3 HKDF implementation over the wolfCrypt [`crate::hkdf`] functions.
+*/
+
+use alloc::boxed::Box;
+
+use rustls::crypto::hash::HashAlgorithm;
+use rustls::crypto::hmac;
+use rustls::crypto::tls13::{self, HkdfExpander, OkmBlock, OutputLengthError};
+
+use super::hash::{hmac_type, output_len};
+use crate::hkdf::{hkdf_expand, hkdf_extract};
+
+pub(crate) static HKDF_SHA256: Hkdf = Hkdf(HashAlgorithm::SHA256);
+#[cfg(sha384)]
+pub(crate) static HKDF_SHA384: Hkdf = Hkdf(HashAlgorithm::SHA384);
+
+pub(crate) struct Hkdf(HashAlgorithm);
+
+impl Hkdf {
+    fn extract(&self, salt: Option<&[u8]>, secret: &[u8]) -> Box<dyn HkdfExpander> {
+        let hash_len = output_len(self.0);
+        let zeroes = [0u8; OkmBlock::MAX_LEN];
+        let salt = salt.unwrap_or(&zeroes[..hash_len]);
+        let mut prk = [0u8; OkmBlock::MAX_LEN];
+        // HKDF-Extract only fails on invalid arguments; the PRK buffer is
+        // sized from the hash type so this indicates a broken library build.
+        let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+        hkdf_extract(hmac_type(self.0), Some(salt), secret, &mut prk[..hash_len], wolfcrypt)
+            .expect("Error with hkdf_extract()");
+        let expander = Expander {
+            alg: self.0,
+            prk: OkmBlock::new(&prk[..hash_len]),
+        };
+        zeroize::Zeroize::zeroize(&mut prk);
+        Box::new(expander)
+    }
+}
+
+impl tls13::Hkdf for Hkdf {
+    fn extract_from_zero_ikm(&self, salt: Option<&[u8]>) -> Box<dyn HkdfExpander> {
+        let zeroes = [0u8; OkmBlock::MAX_LEN];
+        self.extract(salt, &zeroes[..output_len(self.0)])
+    }
+
+    fn extract_from_secret(&self, salt: Option<&[u8]>, secret: &[u8]) -> Box<dyn HkdfExpander> {
+        self.extract(salt, secret)
+    }
+
+    fn expander_for_okm(&self, okm: &OkmBlock) -> Box<dyn HkdfExpander> {
+        Box::new(Expander {
+            alg: self.0,
+            prk: OkmBlock::new(okm.as_ref()),
+        })
+    }
+
+    fn hmac_sign(&self, key: &OkmBlock, message: &[u8]) -> hmac::Tag {
+        super::hmac::sign(self.0, key.as_ref(), message, &[], &[])
+    }
+
+    fn fips(&self) -> bool {
+        super::fips()
+    }
+}
+
+/// HKDF-Expand state holding the pseudorandom key.
+///
+/// `OkmBlock` zeroizes the key when dropped.
+struct Expander {
+    alg: HashAlgorithm,
+    prk: OkmBlock,
+}
+
+impl HkdfExpander for Expander {
+    fn expand_slice(&self, info: &[&[u8]], output: &mut [u8]) -> Result<(), OutputLengthError> {
+        if output.len() > 255 * self.hash_len() {
+            return Err(OutputLengthError);
+        }
+        let info = super::concat(info);
+        let wolfcrypt = crate::WolfCrypt::global().map_err(|_| OutputLengthError)?;
+        hkdf_expand(hmac_type(self.alg), self.prk.as_ref(), Some(&info), output, wolfcrypt)
+            .map_err(|_| OutputLengthError)
+    }
+
+    fn expand_block(&self, info: &[&[u8]]) -> OkmBlock {
+        let mut block = [0u8; OkmBlock::MAX_LEN];
+        let output = &mut block[..self.hash_len()];
+        // A single hash-length block is always a valid output length.
+        self.expand_slice(info, output)
+            .expect("Error with hkdf_expand()");
+        let okm = OkmBlock::new(output);
+        zeroize::Zeroize::zeroize(&mut block);
+        okm
+    }
+
+    fn hash_len(&self) -> usize {
+        output_len(self.alg)
+    }
+}
@@ -0,0 +1,103 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+HMAC implementations over the wolfCrypt [`crate::hmac::HMAC`] wrapper, used
+by the TLS 1.2 PRF.
+*/
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use rustls::crypto::hash::HashAlgorithm;
+use rustls::crypto::hmac;
+use zeroize::Zeroize;
+
+use super::hash::{hmac_type, output_len};
+use crate::hmac::HMAC;
+
+pub(crate) static HMAC_SHA256: Hmac = Hmac(HashAlgorithm::SHA256);
+#[cfg(sha384)]
+pub(crate) static HMAC_SHA384: Hmac = Hmac(HashAlgorithm::SHA384);
+
+pub(crate) struct Hmac(HashAlgorithm);
+
+impl hmac::Hmac for Hmac {
+    fn with_key(&self, key: &[u8]) -> Box<dyn hmac::Key> {
+        Box::new(Key {
+            alg: self.0,
+            key: key.to_vec(),
+        })
+    }
+
+    fn hash_output_len(&self) -> usize {
+        output_len(self.0)
+    }
+
+    fn fips(&self) -> bool {
+        super::fips()
+    }
+}
+
+/// HMAC key.
+///
+/// `hmac::Key::sign_concat()` takes `&self` and may be called concurrently,
+/// so a fresh wolfCrypt HMAC context is keyed for every tag rather than
+/// sharing one.
+struct Key {
+    alg: HashAlgorithm,
+    key: Vec<u8>,
+}
+
+impl hmac::Key for Key {
+    fn sign_concat(&self, first: &[u8], middle: &[&[u8]], last: &[u8]) -> hmac::Tag {
+        sign(self.alg, &self.key, first, middle, last)
+    }
+
+    fn tag_len(&self) -> usize {
+        output_len(self.alg)
+    }
+}
+
+impl Drop for Key {
+    fn drop(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+/// Compute `HMAC(key, first || middle || last)`.
+///
+/// The rustls HMAC traits are infallible. TLS only uses HMAC with secrets at
+/// least as long as the hash output, so wolfCrypt failing here indicates a
+/// broken library build and panics.
+pub(crate) fn sign(alg: HashAlgorithm, key: &[u8], first: &[u8], middle: &[&[u8]],
+        last: &[u8]) -> hmac::Tag {
+    let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+    let mut hmac = HMAC::new(hmac_type(alg), key, wolfcrypt).expect("Error with HMAC::new()");
+    hmac.update(first).expect("Error with update()");
+    for part in middle {
+        hmac.update(part).expect("Error with update()");
+    }
+    hmac.update(last).expect("Error with update()");
+    let mut tag = [0u8; hmac::Tag::MAX_LEN];
+    let tag = &mut tag[..output_len(alg)];
+    hmac.finalize(tag).expect("Error with finalize()");
+    hmac::Tag::new(tag)
+}
@@ -0,0 +1,349 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+Key exchange groups.
+
+| Group                 | wolfCrypt wrapper                                       |
+|-----------------------|---------------------------------------------------------|
+| [`X25519`]            | [`crate::curve25519::Curve25519Key`]                    |
+| [`SECP256R1`]         | [`crate::ecc::ECC`]                                     |
+| [`SECP384R1`]         | [`crate::ecc::ECC`]                                     |
+| [`X25519MLKEM768`]    | [`crate::mlkem::MlKem`] + [`crate::curve25519::Curve25519Key`] |
+
+[`X25519MLKEM768`] follows draft-ietf-tls-ecdhe-mlkem: the ML-KEM-768
+component comes first in both key shares and in the shared secret. It is only
+offered for TLS 1.3.
+*/
+
+use alloc::boxed::Box;
+#[cfg(any(all(ecc, ecc_dh, ecc_import, ecc_export), all(curve25519, mlkem)))]
+use alloc::vec::Vec;
+
+use rustls::crypto::{ActiveKeyExchange, SharedSecret, SupportedKxGroup};
+#[cfg(all(curve25519, mlkem))]
+use rustls::crypto::CompletedKeyExchange;
+use rustls::{Error, NamedGroup, PeerMisbehaved};
+#[cfg(all(curve25519, mlkem))]
+use rustls::ProtocolVersion;
+
+use super::{Exclusive, with_rng, wolfcrypt_error};
+#[cfg(curve25519)]
+use crate::curve25519::Curve25519Key;
+#[cfg(all(ecc, ecc_dh, ecc_import, ecc_export))]
+use crate::ecc::ECC;
+#[cfg(all(curve25519, mlkem))]
+use crate::mlkem::MlKem;
+#[cfg(any(all(ecc, ecc_dh, ecc_import, ecc_export), curve25519_blinding))]
+use crate::random::RNG;
+
+const INVALID_KEY_SHARE: Error = Error::PeerMisbehaved(PeerMisbehaved::InvalidKeyShare);
+
+/// The X25519 key exchange group (RFC 7748).
+#[cfg(curve25519)]
+pub static X25519: &dyn SupportedKxGroup = &X25519Group;
+
+/// The secp256r1 (NIST P-256) ECDHE key exchange group.
+#[cfg(all(ecc, ecc_dh, ecc_import, ecc_export))]
+pub static SECP256R1: &dyn SupportedKxGroup = &EcGroup {
+    name: NamedGroup::secp256r1,
+    curve_id: ECC::SECP256R1,
+};
+
+/// The secp384r1 (NIST P-384) ECDHE key exchange group.
+#[cfg(all(ecc, ecc_dh, ecc_import, ecc_export))]
+pub static SECP384R1: &dyn SupportedKxGroup = &EcGroup {
+    name: NamedGroup::secp384r1,
+    curve_id: ECC::SECP384R1,
+};
+
+/// The X25519MLKEM768 hybrid post-quantum key exchange group.
+#[cfg(all(curve25519, mlkem))]
+pub static X25519MLKEM768: &dyn SupportedKxGroup = &X25519MlKem768Group;
+
+#[cfg(curve25519)]
+#[derive(Debug)]
+struct X25519Group;
+
+#[cfg(curve25519)]
+impl SupportedKxGroup for X25519Group {
+    fn start(&self) -> Result<Box<dyn ActiveKeyExchange>, Error> {
+        Ok(Box::new(X25519KeyExchange::generate()?))
+    }
+
+    fn name(&self) -> NamedGroup {
+        NamedGroup::X25519
+    }
+}
+
+/// Ephemeral X25519 key pair.
+///
+/// TLS encodes X25519 values little-endian (RFC 7748), so the `_ex`
+/// wolfCrypt functions are used with `big_endian = false` throughout.
+#[cfg(curve25519)]
+struct X25519KeyExchange {
+    key: Exclusive<Curve25519Key>,
+    pub_key: [u8; Curve25519Key::KEYSIZE],
+}
+
+#[cfg(curve25519)]
+impl X25519KeyExchange {
+    fn generate() -> Result<Self, Error> {
+        let wolfcrypt = crate::WolfCrypt::global().map_err(wolfcrypt_error)?;
+        let mut key = with_rng(|rng| Curve25519Key::generate(rng, wolfcrypt))
+            .map_err(wolfcrypt_error)?;
+        let mut pub_key = [0u8; Curve25519Key::KEYSIZE];
+        key.export_public_ex(&mut pub_key, false).map_err(wolfcrypt_error)?;
+        #[cfg(curve25519_blinding)]
+        key.set_rng(RNG::new(wolfcrypt).map_err(wolfcrypt_error)?).map_err(wolfcrypt_error)?;
+        Ok(X25519KeyExchange {
+            key: Exclusive::new(key),
+            pub_key,
+        })
+    }
+
+    fn agree(mut self, peer_pub_key: &[u8]) -> Result<SharedSecret, Error> {
+        if peer_pub_key.len() != Curve25519Key::KEYSIZE
+            || Curve25519Key::check_public(peer_pub_key, false).is_err() {
+            return Err(INVALID_KEY_SHARE);
+        }
+        let wolfcrypt = crate::WolfCrypt::global().map_err(wolfcrypt_error)?;
+        let mut peer = Curve25519Key::import_public_ex(peer_pub_key, false, wolfcrypt)
+            .map_err(|_| INVALID_KEY_SHARE)?;
+        let mut secret = [0u8; Curve25519Key::KEYSIZE];
+        let len = Curve25519Key::shared_secret_ex(self.key.get_mut(), &mut peer, &mut secret, false)
+            .map_err(|_| INVALID_KEY_SHARE)?;
+        let shared = SharedSecret::from(&secret[..len]);
+        zeroize::Zeroize::zeroize(&mut secret);
+        Ok(shared)
+    }
+}
+
+#[cfg(curve25519)]
+impl ActiveKeyExchange for X25519KeyExchange {
+    fn complete(self: Box<Self>, peer_pub_key: &[u8]) -> Result<SharedSecret, Error> {
+        self.agree(peer_pub_key)
+    }
+
+    fn pub_key(&self) -> &[u8] {
+        &self.pub_key
+    }
+
+    fn group(&self) -> NamedGroup {
+        NamedGroup::X25519
+    }
+}
+
+#[cfg(all(ecc, ecc_dh, ecc_import, ecc_export))]
+#[derive(Debug)]
+struct EcGroup {
+    name: NamedGroup,
+    curve_id: i32,
+}
+
+#[cfg(all(ecc, ecc_dh, ecc_import, ecc_export))]
+impl SupportedKxGroup for EcGroup {
+    fn start(&self) -> Result<Box<dyn ActiveKeyExchange>, Error> {
+        let size = ECC::get_curve_size_from_id(self.curve_id).map_err(wolfcrypt_error)?;
+        let wolfcrypt = crate::WolfCrypt::global().map_err(wolfcrypt_error)?;
+        let env = crate::Env::new(wolfcrypt);
+        let mut key = with_rng(|rng| ECC::generate_ex(size, rng, self.curve_id, &env))
+            .map_err(wolfcrypt_error)?;
+        // Uncompressed point: 0x04 || X || Y.
+        let mut pub_key = alloc::vec![0u8; 1 + 2 * size as usize];
+        let len = key.export_x963(&mut pub_key).map_err(wolfcrypt_error)?;
+        pub_key.truncate(len);
+        // wolfCrypt's timing-resistant ECDH blinds with an RNG bound to the
+        // private key.
+        key.set_rng(RNG::new(wolfcrypt).map_err(wolfcrypt_error)?).map_err(wolfcrypt_error)?;
+        Ok(Box::new(EcKeyExchange {
+            name: self.name,
+            curve_id: self.curve_id,
+            size: size as usize,
+            key: Exclusive::new(key),
+            pub_key,
+        }))
+    }
+
+    fn name(&self) -> NamedGroup {
+        self.name
+    }
+
+    fn fips(&self) -> bool {
+        super::fips()
+    }
+}
+
+#[cfg(all(ecc, ecc_dh, ecc_import, ecc_export))]
+struct EcKeyExchange {
+    name: NamedGroup,
+    curve_id: i32,
+    size: usize,
+    key: Exclusive<ECC<'static>>,
+    pub_key: Vec<u8>,
+}
+
+#[cfg(all(ecc, ecc_dh, ecc_import, ecc_export))]
+impl ActiveKeyExchange for EcKeyExchange {
+    fn complete(mut self: Box<Self>, peer_pub_key: &[u8]) -> Result<SharedSecret, Error> {
+        // TLS 1.3 and RFC 8422 only allow the uncompressed point format.
+        if peer_pub_key.len() != 1 + 2 * self.size || peer_pub_key[0] != 0x04 {
+            return Err(INVALID_KEY_SHARE);
+        }
+        let wolfcrypt = crate::WolfCrypt::global().map_err(wolfcrypt_error)?;
+        let mut peer = ECC::import_x963_ex(peer_pub_key, self.curve_id, &crate::Env::new(wolfcrypt))
+            .map_err(|_| INVALID_KEY_SHARE)?;
+        peer.check().map_err(|_| INVALID_KEY_SHARE)?;
+        let mut secret = alloc::vec![0u8; self.size];
+        let len = self.key.get_mut().shared_secret(&mut peer, &mut secret)
+            .map_err(|_| INVALID_KEY_SHARE)?;
+        secret.truncate(len);
+        Ok(SharedSecret::from(secret))
+    }
+
+    fn pub_key(&self) -> &[u8] {
+        &self.pub_key
+    }
+
+    fn group(&self) -> NamedGroup {
+        self.name
+    }
+}
+
+/// ML-KEM-768 encapsulation key length in bytes.
+#[cfg(all(curve25519, mlkem))]
+const MLKEM768_ENCAP_LEN: usize = 1184;
+
+/// ML-KEM-768 cipher text length in bytes.
+#[cfg(all(curve25519, mlkem))]
+const MLKEM768_CIPHERTEXT_LEN: usize = 1088;
+
+#[cfg(all(curve25519, mlkem))]
+#[derive(Debug)]
+struct X25519MlKem768Group;
+
+#[cfg(all(curve25519, mlkem))]
+impl SupportedKxGroup for X25519MlKem768Group {
+    fn start(&self) -> Result<Box<dyn ActiveKeyExchange>, Error> {
+        let classical = X25519KeyExchange::generate()?;
+        let wolfcrypt = crate::WolfCrypt::global().map_err(wolfcrypt_error)?;
+        let mlkem = with_rng(|rng| MlKem::generate(MlKem::TYPE_768, rng, wolfcrypt))
+            .map_err(wolfcrypt_error)?;
+        let mut pub_key = alloc::vec![0u8; MLKEM768_ENCAP_LEN + Curve25519Key::KEYSIZE];
+        mlkem.encode_public_key(&mut pub_key[..MLKEM768_ENCAP_LEN])
+            .map_err(wolfcrypt_error)?;
+        pub_key[MLKEM768_ENCAP_LEN..].copy_from_slice(&classical.pub_key);
+        Ok(Box::new(X25519MlKem768KeyExchange {
+            classical,
+            mlkem: Exclusive::new(mlkem),
+            pub_key,
+        }))
+    }
+
+    fn start_and_complete(&self, client_share: &[u8]) -> Result<CompletedKeyExchange, Error> {
+        if client_share.len() != MLKEM768_ENCAP_LEN + Curve25519Key::KEYSIZE {
+            return Err(INVALID_KEY_SHARE);
+        }
+        let (encap_key, classical_share) = client_share.split_at(MLKEM768_ENCAP_LEN);
+
+        let wolfcrypt = crate::WolfCrypt::global().map_err(wolfcrypt_error)?;
+        let mut mlkem = MlKem::new(MlKem::TYPE_768, wolfcrypt).map_err(wolfcrypt_error)?;
+        mlkem.decode_public_key(encap_key).map_err(|_| INVALID_KEY_SHARE)?;
+        let mut ciphertext = alloc::vec![0u8; MLKEM768_CIPHERTEXT_LEN];
+        let mut mlkem_secret = [0u8; MlKem::SHARED_SECRET_SIZE];
+        with_rng(|rng| mlkem.encapsulate(&mut ciphertext, &mut mlkem_secret, rng))
+            .map_err(wolfcrypt_error)?;
+
+        let classical = X25519KeyExchange::generate()?;
+        let mut pub_key = ciphertext;
+        pub_key.extend_from_slice(&classical.pub_key);
+        let classical_secret = classical.agree(classical_share)?;
+
+        let mut secret = Vec::with_capacity(
+            MlKem::SHARED_SECRET_SIZE + classical_secret.secret_bytes().len());
+        secret.extend_from_slice(&mlkem_secret);
+        secret.extend_from_slice(classical_secret.secret_bytes());
+        zeroize::Zeroize::zeroize(&mut mlkem_secret);
+
+        Ok(CompletedKeyExchange {
+            group: NamedGroup::X25519MLKEM768,
+            pub_key,
+            secret: SharedSecret::from(secret),
+        })
+    }
+
+    fn name(&self) -> NamedGroup {
+        NamedGroup::X25519MLKEM768
+    }
+
+    fn usable_for_version(&self, version: ProtocolVersion) -> bool {
+        version == ProtocolVersion::TLSv1_3
+    }
+}
+
+#[cfg(all(curve25519, mlkem))]
+struct X25519MlKem768KeyExchange {
+    classical: X25519KeyExchange,
+    mlkem: Exclusive<MlKem<'static>>,
+    pub_key: Vec<u8>,
+}
+
+#[cfg(all(curve25519, mlkem))]
+impl ActiveKeyExchange for X25519MlKem768KeyExchange {
+    fn complete(mut self: Box<Self>, peer_pub_key: &[u8]) -> Result<SharedSecret, Error> {
+        if peer_pub_key.len() != MLKEM768_CIPHERTEXT_LEN + Curve25519Key::KEYSIZE {
+            return Err(INVALID_KEY_SHARE);
+        }
+        let (ciphertext, classical_share) = peer_pub_key.split_at(MLKEM768_CIPHERTEXT_LEN);
+
+        let mut mlkem_secret = [0u8; MlKem::SHARED_SECRET_SIZE];
+        self.mlkem.get_mut().decapsulate(&mut mlkem_secret, ciphertext)
+            .map_err(|_| INVALID_KEY_SHARE)?;
+        let this = *self;
+        let classical_secret = this.classical.agree(classical_share)?;
+
+        let mut secret = Vec::with_capacity(
+            MlKem::SHARED_SECRET_SIZE + classical_secret.secret_bytes().len());
+        secret.extend_from_slice(&mlkem_secret);
+        secret.extend_from_slice(classical_secret.secret_bytes());
+        zeroize::Zeroize::zeroize(&mut mlkem_secret);
+        Ok(SharedSecret::from(secret))
+    }
+
+    /// Allow the X25519 share to double as a standalone X25519 key share.
+    fn hybrid_component(&self) -> Option<(NamedGroup, &[u8])> {
+        Some((NamedGroup::X25519, &self.classical.pub_key))
+    }
+
+    fn complete_hybrid_component(
+        self: Box<Self>,
+        peer_pub_key: &[u8],
+    ) -> Result<SharedSecret, Error> {
+        self.classical.agree(peer_pub_key)
+    }
+
+    fn pub_key(&self) -> &[u8] {
+        &self.pub_key
+    }
+
+    fn group(&self) -> NamedGroup {
+        NamedGroup::X25519MLKEM768
+    }
+}
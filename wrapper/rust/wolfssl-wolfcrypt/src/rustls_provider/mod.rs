@@ -0,0 +1,299 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+A [`rustls::crypto::CryptoProvider`] backed by wolfCrypt.
+
+This keeps rustls as the TLS state machine while routing every cryptographic
+operation through the wolfCrypt wrappers in this crate:
+
+| rustls component                 | wolfCrypt wrapper                                   |
+|----------------------------------|-----------------------------------------------------|
+| Record protection (AEAD)         | [`crate::aes::GCM`], [`crate::chacha20_poly1305::ChaCha20Poly1305`] |
+| Transcript hash                  | [`crate::sha::SHA256`], [`crate::sha::SHA384`]      |
+| TLS 1.3 key schedule (HKDF)      | [`crate::hkdf`], [`crate::hmac::HMAC`]              |
+| TLS 1.2 PRF                      | [`crate::hmac::HMAC`]                               |
+| Key exchange                     | [`crate::curve25519::Curve25519Key`], [`crate::ecc::ECC`], [`crate::mlkem::MlKem`] |
+| Signature verification / signing | [`crate::ecc::ECC`], [`crate::rsa::RSA`], [`crate::ed25519::Ed25519`] |
+| Secure random                    | [`crate::random::RNG`]                              |
+
+Which cipher suites, key exchange groups and signature algorithms are
+available depends on the algorithms enabled in the wolfSSL library the crate
+is built against; anything that is compiled out is simply left out of
+[`provider()`].
+
+The hybrid [`kx_group::X25519MLKEM768`] group is offered first when ML-KEM
+and Curve25519 are both available.
+
+When the wolfSSL library is a FIPS build, the `fips()` methods of the
+provider components report `true` for the FIPS-approved algorithms so that
+`rustls::ClientConfig::fips()` / `rustls::ServerConfig::fips()` reflect the
+configuration.
+
+# Examples
+
+```rust
+#[cfg(all(feature = "rustls", random, hmac, hkdf, sha256, aes_gcm))]
+{
+use std::sync::Arc;
+use wolfssl_wolfcrypt::rustls_provider;
+
+let provider = Arc::new(rustls_provider::provider());
+let config = rustls::ClientConfig::builder_with_provider(provider)
+    .with_safe_default_protocol_versions()
+    .expect("provider supports TLS 1.2/1.3")
+    .with_root_certificates(rustls::RootCertStore::empty())
+    .with_no_client_auth();
+}
+```
+*/
+
+#![cfg(all(feature = "rustls", random, hmac, hkdf, sha256, any(aes_gcm, chacha20_poly1305)))]
+
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use std::sync::Mutex;
+
+use rustls::SupportedCipherSuite;
+use rustls::crypto::{
+    CryptoProvider, GetRandomFailed, KeyProvider, SecureRandom, SupportedKxGroup,
+};
+use rustls::pki_types::PrivateKeyDer;
+use rustls::sign::SigningKey;
+
+use crate::random::RNG;
+
+mod aead;
+mod hash;
+mod hkdf;
+mod hmac;
+pub mod kx_group;
+pub mod sign;
+mod tls12;
+mod tls13;
+mod verify;
+
+/// Cipher suites supported by this provider.
+pub mod cipher_suite {
+    #[cfg(aes_gcm)]
+    pub use super::tls12::{
+        TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256, TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+    };
+    #[cfg(all(aes_gcm, sha384))]
+    pub use super::tls12::{
+        TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384, TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+    };
+    #[cfg(chacha20_poly1305)]
+    pub use super::tls12::{
+        TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256,
+        TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256,
+    };
+    #[cfg(aes_gcm)]
+    pub use super::tls13::TLS13_AES_128_GCM_SHA256;
+    #[cfg(all(aes_gcm, sha384))]
+    pub use super::tls13::TLS13_AES_256_GCM_SHA384;
+    #[cfg(chacha20_poly1305)]
+    pub use super::tls13::TLS13_CHACHA20_POLY1305_SHA256;
+}
+
+/// Build a [`CryptoProvider`] using wolfCrypt for all cryptography.
+///
+/// The provider contains every cipher suite in [`ALL_CIPHER_SUITES`], every
+/// key exchange group in [`ALL_KX_GROUPS`] and every signature verification
+/// algorithm available in the wolfSSL build.
+///
+/// # Returns
+///
+/// Returns a `CryptoProvider` which can be passed to
+/// `rustls::ClientConfig::builder_with_provider()` or
+/// `rustls::ServerConfig::builder_with_provider()`, or installed as the
+/// process default with `CryptoProvider::install_default()`.
+pub fn provider() -> CryptoProvider {
+    CryptoProvider {
+        cipher_suites: ALL_CIPHER_SUITES.to_vec(),
+        kx_groups: ALL_KX_GROUPS.to_vec(),
+        signature_verification_algorithms: verify::SUPPORTED_SIG_ALGS,
+        secure_random: &Provider,
+        key_provider: &Provider,
+    }
+}
+
+/// All cipher suites supported by this provider, in order of preference.
+pub static ALL_CIPHER_SUITES: &[SupportedCipherSuite] = &[
+    #[cfg(all(aes_gcm, sha384))]
+    tls13::TLS13_AES_256_GCM_SHA384,
+    #[cfg(aes_gcm)]
+    tls13::TLS13_AES_128_GCM_SHA256,
+    #[cfg(chacha20_poly1305)]
+    tls13::TLS13_CHACHA20_POLY1305_SHA256,
+    #[cfg(all(aes_gcm, sha384))]
+    tls12::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384,
+    #[cfg(aes_gcm)]
+    tls12::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+    #[cfg(chacha20_poly1305)]
+    tls12::TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256,
+    #[cfg(all(aes_gcm, sha384))]
+    tls12::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+    #[cfg(aes_gcm)]
+    tls12::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+    #[cfg(chacha20_poly1305)]
+    tls12::TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256,
+];
+
+/// All key exchange groups supported by this provider, in order of
+/// preference.
+pub static ALL_KX_GROUPS: &[&dyn SupportedKxGroup] = &[
+    #[cfg(all(curve25519, mlkem))]
+    kx_group::X25519MLKEM768,
+    #[cfg(curve25519)]
+    kx_group::X25519,
+    #[cfg(all(ecc, ecc_dh, ecc_import, ecc_export))]
+    kx_group::SECP256R1,
+    #[cfg(all(ecc, ecc_dh, ecc_import, ecc_export))]
+    kx_group::SECP384R1,
+];
+
+/// Process-wide RNG shared by the provider components.
+///
+/// `RNG` is `Send` but not `Sync`, so access is serialized through a mutex.
+/// It is created on first use so that building a provider does not require
+/// the wolfCrypt library to be initialized yet.
+static SHARED_RNG: Mutex<Option<RNG>> = Mutex::new(None);
+
+/// Run `f` with the shared provider RNG, creating it on first use.
+pub(crate) fn with_rng<R>(f: impl FnOnce(&RNG) -> Result<R, crate::Error>) -> Result<R, crate::Error> {
+    let mut guard = SHARED_RNG.lock().unwrap_or_else(|e| e.into_inner());
+    let rng = match &mut *guard {
+        Some(rng) => rng,
+        slot => slot.insert(RNG::new(crate::WolfCrypt::global()?)?),
+    };
+    f(rng)
+}
+
+/// Wrapper for a wolfCrypt object owned by a rustls provider object.
+///
+/// rustls requires its provider objects to be `Send + Sync`. The wolfCrypt
+/// structs wrapped here hold raw pointers into C memory and so do not get
+/// these traits automatically. `Send` is implemented below for each wrapped
+/// type. The object is only reachable through `&mut self`, so sharing the
+/// wrapper between threads gives no concurrent access to it.
+pub(crate) struct Exclusive<T>(T);
+
+impl<T> Exclusive<T> {
+    pub(crate) fn new(inner: T) -> Self {
+        Exclusive(inner)
+    }
+
+    pub(crate) fn get_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+// Safety: the provider creates the `RsaKey` from the global `WolfCrypt`
+// guard with no heap hint and no device. No RNG is bound to the key; every
+// signing operation borrows the mutex-protected shared RNG through
+// `with_rng`. The key owns its C state, which holds no thread-local data.
+#[cfg(rsa)]
+unsafe impl Send for Exclusive<crate::rsa::RSA<'_>> {}
+// Safety: the `ecc_key` is allocated by wolfCrypt with no heap hint and no
+// device. Signing keys have no RNG bound and use `with_rng`; key exchange
+// keys own the `RNG` bound by `set_rng` (never a shared `Rc` handle), which
+// is itself `Send`. The key's internal pointer to it stays valid across moves
+// because the `WC_RNG` lives on the C heap.
+#[cfg(ecc)]
+unsafe impl Send for Exclusive<crate::ecc::ECC<'_>> {}
+// Safety: the `ed25519_key` is stored inline, has no heap hint, device or
+// RNG, and holds no pointers to other data.
+#[cfg(ed25519)]
+unsafe impl Send for Exclusive<crate::ed25519::Ed25519<'_>> {}
+// Safety: the `curve25519_key` is stored inline with no device. The key owns
+// the `RNG` bound by `set_rng` (never a shared `Rc` handle), which is `Send`.
+// The `WC_RNG` lives on the C heap, so the key's pointer to it is unaffected
+// by moving the key.
+#[cfg(curve25519)]
+unsafe impl Send for Exclusive<crate::curve25519::Curve25519Key> {}
+// Safety: the `MlKemKey` is allocated by wolfCrypt with no heap hint and no
+// device, and no RNG is bound to it; encapsulation borrows the shared RNG
+// through `with_rng`.
+#[cfg(mlkem)]
+unsafe impl Send for Exclusive<crate::mlkem::MlKem<'_>> {}
+// Safety: the `Aes` state is stored inline with no heap hint and no device,
+// and only holds the expanded key and GCM tables; AEAD operations need no
+// RNG.
+#[cfg(aes_gcm)]
+unsafe impl Send for Exclusive<crate::aes::GCM<'_>> {}
+
+// Safety: `&Exclusive<T>` gives no access to the wrapped object.
+unsafe impl<T> Sync for Exclusive<T> where Exclusive<T>: Send {}
+
+// Key material is never printed.
+impl<T> core::fmt::Debug for Exclusive<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("..")
+    }
+}
+
+/// Whether the wolfSSL library is a FIPS build.
+pub(crate) const fn fips() -> bool {
+    cfg!(fips)
+}
+
+/// Convert a wolfCrypt error into a `rustls::Error`.
+pub(crate) fn wolfcrypt_error(err: crate::Error) -> rustls::Error {
+    rustls::Error::General(alloc::format!("wolfCrypt error: {}", err))
+}
+
+#[derive(Debug)]
+struct Provider;
+
+impl SecureRandom for Provider {
+    fn fill(&self, buf: &mut [u8]) -> Result<(), GetRandomFailed> {
+        with_rng(|rng| rng.generate_block(buf)).map_err(|_| GetRandomFailed)
+    }
+
+    fn fips(&self) -> bool {
+        fips()
+    }
+}
+
+impl KeyProvider for Provider {
+    fn load_private_key(
+        &self,
+        key_der: PrivateKeyDer<'static>,
+    ) -> Result<Arc<dyn SigningKey>, rustls::Error> {
+        sign::any_supported_type(&key_der)
+    }
+
+    fn fips(&self) -> bool {
+        fips()
+    }
+}
+
+/// Concatenate `parts` into a single buffer.
+///
+/// The wolfCrypt HKDF and HMAC one-shot functions take a single contiguous
+/// input while rustls passes inputs as lists of slices.
+fn concat(parts: &[&[u8]]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(parts.iter().map(|p| p.len()).sum());
+    for part in parts {
+        out.extend_from_slice(part);
+    }
+    out
+}
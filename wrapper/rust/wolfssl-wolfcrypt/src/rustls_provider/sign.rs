@@ -0,0 +1,411 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+Signing keys for TLS authentication.
+
+[`any_supported_type()`] loads a DER private key into the matching wolfCrypt
+key type:
+
+| Key format      | Key types tried                      |
+|-----------------|--------------------------------------|
+| PKCS #1         | RSA                                  |
+| SEC1            | ECDSA (P-256, P-384)                 |
+| PKCS #8         | RSA, ECDSA (P-256, P-384), Ed25519   |
+
+RSA keys offer RSA-PSS ahead of PKCS #1 v1.5 signatures, strongest hash
+first.
+*/
+
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use std::sync::Mutex;
+
+use rustls::pki_types::PrivateKeyDer;
+use rustls::sign::{Signer, SigningKey};
+use rustls::{Error, SignatureAlgorithm, SignatureScheme};
+
+#[allow(unused_imports)]
+use super::{Exclusive, with_rng, wolfcrypt_error};
+#[cfg(any(all(ecc, ecc_sign), rsa))]
+use super::hash::SigHash;
+#[cfg(all(ecc, ecc_sign))]
+use crate::ecc::ECC;
+#[cfg(all(ed25519, ed25519_sign))]
+use crate::ed25519::Ed25519;
+#[cfg(rsa)]
+use crate::rsa::RSA;
+#[allow(unused_imports)]
+use crate::sys;
+
+/// Load a DER-encoded private key into a [`SigningKey`].
+///
+/// # Parameters
+///
+/// * `der`: PKCS #1 (RSA), SEC1 (ECDSA) or PKCS #8 (RSA, ECDSA, Ed25519)
+///   private key.
+///
+/// # Returns
+///
+/// Returns either Ok(key) containing the signing key or Err(e) containing a
+/// `rustls::Error` if the key format or algorithm is not supported by the
+/// wolfSSL build.
+///
+/// # Example
+///
+/// ```rust
+/// #[cfg(all(feature = "rustls", random, hmac, hkdf, sha256, aes_gcm, rsa))]
+/// {
+/// use std::fs;
+/// use rustls::pki_types::{PrivateKeyDer, PrivatePkcs1KeyDer};
+/// use wolfssl_wolfcrypt::rustls_provider::sign;
+///
+/// let der = fs::read("../../../certs/server-key.der").expect("Error reading key file");
+/// let key = PrivateKeyDer::Pkcs1(PrivatePkcs1KeyDer::from(der));
+/// let key = sign::any_supported_type(&key).expect("Error with any_supported_type()");
+/// assert_eq!(key.algorithm(), rustls::SignatureAlgorithm::RSA);
+/// }
+/// ```
+pub fn any_supported_type(der: &PrivateKeyDer<'_>) -> Result<Arc<dyn SigningKey>, Error> {
+    match der {
+        #[cfg(rsa)]
+        PrivateKeyDer::Pkcs1(der) => {
+            Ok(Arc::new(RsaSigningKey::new(der.secret_pkcs1_der())?))
+        }
+        #[cfg(all(ecc, ecc_sign))]
+        PrivateKeyDer::Sec1(der) => {
+            Ok(Arc::new(EcdsaSigningKey::new(der.secret_sec1_der())?))
+        }
+        PrivateKeyDer::Pkcs8(der) => {
+            #[allow(unused_variables)]
+            let der = der.secret_pkcs8_der();
+            #[cfg(rsa)]
+            if let Ok(key) = RsaSigningKey::new(der) {
+                return Ok(Arc::new(key));
+            }
+            #[cfg(all(ecc, ecc_sign))]
+            if let Ok(key) = EcdsaSigningKey::new(der) {
+                return Ok(Arc::new(key));
+            }
+            #[cfg(all(ed25519, ed25519_sign))]
+            if let Ok(key) = Ed25519SigningKey::new(der) {
+                return Ok(Arc::new(key));
+            }
+            Err(Error::General("unsupported PKCS #8 private key".into()))
+        }
+        _ => Err(Error::General("unsupported private key format".into())),
+    }
+}
+
+/// An RSA private key.
+#[cfg(rsa)]
+#[derive(Debug)]
+pub struct RsaSigningKey {
+    key: Arc<Mutex<Exclusive<RSA<'static>>>>,
+}
+
+/// Signature schemes offered by RSA keys, in order of preference.
+#[cfg(rsa)]
+static RSA_SCHEMES: &[SignatureScheme] = &[
+    #[cfg(all(rsa_pss, sha512))]
+    SignatureScheme::RSA_PSS_SHA512,
+    #[cfg(all(rsa_pss, sha384))]
+    SignatureScheme::RSA_PSS_SHA384,
+    #[cfg(rsa_pss)]
+    SignatureScheme::RSA_PSS_SHA256,
+    #[cfg(sha512)]
+    SignatureScheme::RSA_PKCS1_SHA512,
+    #[cfg(sha384)]
+    SignatureScheme::RSA_PKCS1_SHA384,
+    SignatureScheme::RSA_PKCS1_SHA256,
+];
+
+#[cfg(rsa)]
+impl RsaSigningKey {
+    /// Decode a PKCS #1 or PKCS #8 RSA private key.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(key) or Err(e) containing a `rustls::Error`.
+    pub fn new(der: &[u8]) -> Result<Self, Error> {
+        let wolfcrypt = crate::WolfCrypt::global().map_err(wolfcrypt_error)?;
+        let key = RSA::new_from_der(der, wolfcrypt).map_err(wolfcrypt_error)?;
+        Ok(RsaSigningKey {
+            key: Arc::new(Mutex::new(Exclusive::new(key))),
+        })
+    }
+}
+
+#[cfg(rsa)]
+impl SigningKey for RsaSigningKey {
+    fn choose_scheme(&self, offered: &[SignatureScheme]) -> Option<Box<dyn Signer>> {
+        let scheme = RSA_SCHEMES.iter().find(|scheme| offered.contains(scheme))?;
+        Some(Box::new(RsaSigner {
+            key: Arc::clone(&self.key),
+            scheme: *scheme,
+        }))
+    }
+
+    fn algorithm(&self) -> SignatureAlgorithm {
+        SignatureAlgorithm::RSA
+    }
+}
+
+#[cfg(rsa)]
+#[derive(Debug)]
+struct RsaSigner {
+    key: Arc<Mutex<Exclusive<RSA<'static>>>>,
+    scheme: SignatureScheme,
+}
+
+#[cfg(rsa)]
+impl RsaSigner {
+    fn hash(&self) -> SigHash {
+        match self.scheme {
+            #[cfg(sha512)]
+            SignatureScheme::RSA_PSS_SHA512 | SignatureScheme::RSA_PKCS1_SHA512 =>
+                SigHash::Sha512,
+            #[cfg(sha384)]
+            SignatureScheme::RSA_PSS_SHA384 | SignatureScheme::RSA_PKCS1_SHA384 =>
+                SigHash::Sha384,
+            _ => SigHash::Sha256,
+        }
+    }
+
+    fn is_pss(&self) -> bool {
+        matches!(self.scheme, SignatureScheme::RSA_PSS_SHA256
+            | SignatureScheme::RSA_PSS_SHA384 | SignatureScheme::RSA_PSS_SHA512)
+    }
+}
+
+#[cfg(rsa)]
+impl Signer for RsaSigner {
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut guard = self.key.lock().unwrap_or_else(|e| e.into_inner());
+        let rsa = guard.get_mut();
+        let hash = self.hash();
+        let mut sig = alloc::vec![0u8; rsa.get_encrypt_size().map_err(wolfcrypt_error)?];
+        let len = match self.is_pss() {
+            #[cfg(rsa_pss)]
+            true => {
+                let digest = hash.digest(message).map_err(wolfcrypt_error)?;
+                with_rng(|rng| rsa.pss_sign(&digest[..hash.len()], &mut sig, hash.hash_type(),
+                    super::verify::pss_mgf(hash), rng))
+            }
+            _ => {
+                let message_len = crate::buffer_len_to_u32(message.len())
+                    .map_err(wolfcrypt_error)?;
+                let mut sig_len = crate::buffer_len_to_u32(sig.len())
+                    .map_err(wolfcrypt_error)?;
+                with_rng(|rng| {
+                    // wc_SignatureGenerate() hashes the message and signs the
+                    // DigestInfo encoding of the result.
+                    let rc = unsafe {
+                        sys::wc_SignatureGenerate(
+                            hash.hash_type(),
+                            sys::wc_SignatureType_WC_SIGNATURE_TYPE_RSA_W_ENC,
+                            message.as_ptr(), message_len,
+                            sig.as_mut_ptr(), &mut sig_len,
+                            &mut rsa.wc_rsakey as *mut _ as *mut core::ffi::c_void,
+                            size_of::<sys::RsaKey>() as u32,
+                            rng.wc_rng,
+                        )
+                    };
+                    if rc != 0 {
+                        return Err(crate::Error::from(rc));
+                    }
+                    Ok(sig_len as usize)
+                })
+            }
+        }.map_err(wolfcrypt_error)?;
+        sig.truncate(len);
+        Ok(sig)
+    }
+
+    fn scheme(&self) -> SignatureScheme {
+        self.scheme
+    }
+}
+
+/// Largest DER-encoded ECDSA signature for the supported curves (P-384),
+/// with room for the SEQUENCE/INTEGER headers and sign padding.
+#[cfg(all(ecc, ecc_sign))]
+const ECDSA_MAX_SIG_LEN: usize = 2 * 48 + 16;
+
+/// An ECDSA private key on P-256 or P-384.
+#[cfg(all(ecc, ecc_sign))]
+#[derive(Debug)]
+pub struct EcdsaSigningKey {
+    key: Arc<Mutex<Exclusive<ECC<'static>>>>,
+    scheme: SignatureScheme,
+}
+
+#[cfg(all(ecc, ecc_sign))]
+impl EcdsaSigningKey {
+    /// Decode a SEC1 or PKCS #8 ECC private key.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(key) or Err(e) containing a `rustls::Error`. Keys on
+    /// curves other than P-256 and P-384 are rejected.
+    pub fn new(der: &[u8]) -> Result<Self, Error> {
+        let wolfcrypt = crate::WolfCrypt::global().map_err(wolfcrypt_error)?;
+        let key = ECC::import_der(der, &crate::Env::new(wolfcrypt)).map_err(wolfcrypt_error)?;
+        let size = unsafe { sys::wc_ecc_size(key.wc_ecc_key) };
+        let scheme = match size {
+            32 => SignatureScheme::ECDSA_NISTP256_SHA256,
+            #[cfg(sha384)]
+            48 => SignatureScheme::ECDSA_NISTP384_SHA384,
+            _ => return Err(Error::General("unsupported ECDSA curve".into())),
+        };
+        Ok(EcdsaSigningKey {
+            key: Arc::new(Mutex::new(Exclusive::new(key))),
+            scheme,
+        })
+    }
+}
+
+#[cfg(all(ecc, ecc_sign))]
+impl SigningKey for EcdsaSigningKey {
+    fn choose_scheme(&self, offered: &[SignatureScheme]) -> Option<Box<dyn Signer>> {
+        if !offered.contains(&self.scheme) {
+            return None;
+        }
+        Some(Box::new(EcdsaSigner {
+            key: Arc::clone(&self.key),
+            scheme: self.scheme,
+        }))
+    }
+
+    fn algorithm(&self) -> SignatureAlgorithm {
+        SignatureAlgorithm::ECDSA
+    }
+}
+
+#[cfg(all(ecc, ecc_sign))]
+#[derive(Debug)]
+struct EcdsaSigner {
+    key: Arc<Mutex<Exclusive<ECC<'static>>>>,
+    scheme: SignatureScheme,
+}
+
+#[cfg(all(ecc, ecc_sign))]
+impl Signer for EcdsaSigner {
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Error> {
+        let hash = match self.scheme {
+            #[cfg(sha384)]
+            SignatureScheme::ECDSA_NISTP384_SHA384 => SigHash::Sha384,
+            _ => SigHash::Sha256,
+        };
+        let digest = hash.digest(message).map_err(wolfcrypt_error)?;
+        let mut guard = self.key.lock().unwrap_or_else(|e| e.into_inner());
+        let mut sig = alloc::vec![0u8; ECDSA_MAX_SIG_LEN];
+        // The signature is written as a DER-encoded ECDSA-Sig-Value.
+        let len = with_rng(|rng| guard.get_mut().sign_hash(&digest[..hash.len()], &mut sig, rng))
+            .map_err(wolfcrypt_error)?;
+        sig.truncate(len);
+        Ok(sig)
+    }
+
+    fn scheme(&self) -> SignatureScheme {
+        self.scheme
+    }
+}
+
+/// An Ed25519 private key.
+#[cfg(all(ed25519, ed25519_sign))]
+#[derive(Debug)]
+pub struct Ed25519SigningKey {
+    key: Arc<Mutex<Exclusive<Ed25519<'static>>>>,
+}
+
+#[cfg(all(ed25519, ed25519_sign))]
+impl Ed25519SigningKey {
+    /// Decode a PKCS #8 Ed25519 private key.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(key) or Err(e) containing a `rustls::Error`.
+    pub fn new(der: &[u8]) -> Result<Self, Error> {
+        let wolfcrypt = crate::WolfCrypt::global().map_err(wolfcrypt_error)?;
+        let mut key = Ed25519::new(wolfcrypt).map_err(wolfcrypt_error)?;
+        let der_size = crate::buffer_len_to_u32(der.len()).map_err(wolfcrypt_error)?;
+        let mut idx: u32 = 0;
+        let rc = unsafe {
+            sys::wc_Ed25519PrivateKeyDecode(der.as_ptr(), &mut idx, &mut key.ws_key, der_size)
+        };
+        if rc != 0 {
+            return Err(wolfcrypt_error(rc.into()));
+        }
+        // PKCS #8 v1 keys carry no public key; derive it so signing works.
+        // wc_ed25519_make_public() only writes the output buffer, so the
+        // public key must be imported back into the key.
+        let mut public = [0u8; Ed25519::PUB_KEY_SIZE];
+        key.make_public(&mut public).map_err(wolfcrypt_error)?;
+        let rc = unsafe {
+            sys::wc_ed25519_import_public_ex(public.as_ptr(), public.len() as u32,
+                &mut key.ws_key, 1)
+        };
+        if rc != 0 {
+            return Err(wolfcrypt_error(rc.into()));
+        }
+        Ok(Ed25519SigningKey {
+            key: Arc::new(Mutex::new(Exclusive::new(key))),
+        })
+    }
+}
+
+#[cfg(all(ed25519, ed25519_sign))]
+impl SigningKey for Ed25519SigningKey {
+    fn choose_scheme(&self, offered: &[SignatureScheme]) -> Option<Box<dyn Signer>> {
+        if !offered.contains(&SignatureScheme::ED25519) {
+            return None;
+        }
+        Some(Box::new(Ed25519Signer {
+            key: Arc::clone(&self.key),
+        }))
+    }
+
+    fn algorithm(&self) -> SignatureAlgorithm {
+        SignatureAlgorithm::ED25519
+    }
+}
+
+#[cfg(all(ed25519, ed25519_sign))]
+#[derive(Debug)]
+struct Ed25519Signer {
+    key: Arc<Mutex<Exclusive<Ed25519<'static>>>>,
+}
+
+#[cfg(all(ed25519, ed25519_sign))]
+impl Signer for Ed25519Signer {
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut guard = self.key.lock().unwrap_or_else(|e| e.into_inner());
+        let mut sig = alloc::vec![0u8; Ed25519::SIG_SIZE];
+        let len = guard.get_mut().sign_msg(message, &mut sig).map_err(wolfcrypt_error)?;
+        sig.truncate(len);
+        Ok(sig)
+    }
+
+    fn scheme(&self) -> SignatureScheme {
+        SignatureScheme::ED25519
+    }
+}
@@ -0,0 +1,392 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+TLS 1.2 ECDHE cipher suites and record protection.
+
+The TLS 1.2 PRF is rustls' generic `PrfUsingHmac` over the wolfCrypt HMAC
+provider.
+*/
+
+use alloc::boxed::Box;
+
+use rustls::crypto::cipher::{
+    AeadKey, InboundOpaqueMessage, InboundPlainMessage, Iv, KeyBlockShape, MessageDecrypter,
+    MessageEncrypter, NONCE_LEN, Nonce, OutboundOpaqueMessage, OutboundPlainMessage,
+    PrefixedPayload, Tls12AeadAlgorithm, UnsupportedOperationError, make_tls12_aad,
+};
+use rustls::crypto::tls12::PrfUsingHmac;
+use rustls::crypto::{CipherSuiteCommon, KeyExchangeAlgorithm};
+use rustls::{
+    CipherSuite, ConnectionTrafficSecrets, Error, SignatureScheme, SupportedCipherSuite,
+    Tls12CipherSuite,
+};
+
+use super::aead::{self, Algorithm, Keyed, MAX_FRAGMENT_LEN, TAG_LEN};
+
+/// The TLS 1.2 cipher suite TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256.
+#[cfg(aes_gcm)]
+pub static TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256: SupportedCipherSuite =
+    SupportedCipherSuite::Tls12(&Tls12CipherSuite {
+        common: CipherSuiteCommon {
+            suite: CipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256,
+            hash_provider: &super::hash::SHA256,
+            confidentiality_limit: 1 << 24,
+        },
+        kx: KeyExchangeAlgorithm::ECDHE,
+        sign: TLS12_ECDSA_SCHEMES,
+        aead_alg: &Gcm(Algorithm::Aes128Gcm),
+        prf_provider: &PrfUsingHmac(&super::hmac::HMAC_SHA256),
+    });
+
+/// The TLS 1.2 cipher suite TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384.
+#[cfg(all(aes_gcm, sha384))]
+pub static TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384: SupportedCipherSuite =
+    SupportedCipherSuite::Tls12(&Tls12CipherSuite {
+        common: CipherSuiteCommon {
+            suite: CipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384,
+            hash_provider: &super::hash::SHA384,
+            confidentiality_limit: 1 << 24,
+        },
+        kx: KeyExchangeAlgorithm::ECDHE,
+        sign: TLS12_ECDSA_SCHEMES,
+        aead_alg: &Gcm(Algorithm::Aes256Gcm),
+        prf_provider: &PrfUsingHmac(&super::hmac::HMAC_SHA384),
+    });
+
+/// The TLS 1.2 cipher suite TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256.
+#[cfg(chacha20_poly1305)]
+pub static TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256: SupportedCipherSuite =
+    SupportedCipherSuite::Tls12(&Tls12CipherSuite {
+        common: CipherSuiteCommon {
+            suite: CipherSuite::TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256,
+            hash_provider: &super::hash::SHA256,
+            confidentiality_limit: u64::MAX,
+        },
+        kx: KeyExchangeAlgorithm::ECDHE,
+        sign: TLS12_ECDSA_SCHEMES,
+        aead_alg: &ChaCha20Poly1305,
+        prf_provider: &PrfUsingHmac(&super::hmac::HMAC_SHA256),
+    });
+
+/// The TLS 1.2 cipher suite TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256.
+#[cfg(aes_gcm)]
+pub static TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256: SupportedCipherSuite =
+    SupportedCipherSuite::Tls12(&Tls12CipherSuite {
+        common: CipherSuiteCommon {
+            suite: CipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256,
+            hash_provider: &super::hash::SHA256,
+            confidentiality_limit: 1 << 24,
+        },
+        kx: KeyExchangeAlgorithm::ECDHE,
+        sign: TLS12_RSA_SCHEMES,
+        aead_alg: &Gcm(Algorithm::Aes128Gcm),
+        prf_provider: &PrfUsingHmac(&super::hmac::HMAC_SHA256),
+    });
+
+/// The TLS 1.2 cipher suite TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384.
+#[cfg(all(aes_gcm, sha384))]
+pub static TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384: SupportedCipherSuite =
+    SupportedCipherSuite::Tls12(&Tls12CipherSuite {
+        common: CipherSuiteCommon {
+            suite: CipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384,
+            hash_provider: &super::hash::SHA384,
+            confidentiality_limit: 1 << 24,
+        },
+        kx: KeyExchangeAlgorithm::ECDHE,
+        sign: TLS12_RSA_SCHEMES,
+        aead_alg: &Gcm(Algorithm::Aes256Gcm),
+        prf_provider: &PrfUsingHmac(&super::hmac::HMAC_SHA384),
+    });
+
+/// The TLS 1.2 cipher suite TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256.
+#[cfg(chacha20_poly1305)]
+pub static TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256: SupportedCipherSuite =
+    SupportedCipherSuite::Tls12(&Tls12CipherSuite {
+        common: CipherSuiteCommon {
+            suite: CipherSuite::TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256,
+            hash_provider: &super::hash::SHA256,
+            confidentiality_limit: u64::MAX,
+        },
+        kx: KeyExchangeAlgorithm::ECDHE,
+        sign: TLS12_RSA_SCHEMES,
+        aead_alg: &ChaCha20Poly1305,
+        prf_provider: &PrfUsingHmac(&super::hmac::HMAC_SHA256),
+    });
+
+static TLS12_ECDSA_SCHEMES: &[SignatureScheme] = &[
+    SignatureScheme::ED25519,
+    SignatureScheme::ECDSA_NISTP384_SHA384,
+    SignatureScheme::ECDSA_NISTP256_SHA256,
+];
+
+static TLS12_RSA_SCHEMES: &[SignatureScheme] = &[
+    SignatureScheme::RSA_PSS_SHA512,
+    SignatureScheme::RSA_PSS_SHA384,
+    SignatureScheme::RSA_PSS_SHA256,
+    SignatureScheme::RSA_PKCS1_SHA512,
+    SignatureScheme::RSA_PKCS1_SHA384,
+    SignatureScheme::RSA_PKCS1_SHA256,
+];
+
+/// Length of the explicit nonce carried in each AES-GCM record.
+#[cfg(aes_gcm)]
+const GCM_EXPLICIT_NONCE_LEN: usize = 8;
+
+/// Length of the implicit nonce salt derived from the key block.
+#[cfg(aes_gcm)]
+const GCM_FIXED_IV_LEN: usize = 4;
+
+/// AES-GCM record protection (RFC 5288).
+#[cfg(aes_gcm)]
+struct Gcm(Algorithm);
+
+#[cfg(aes_gcm)]
+impl Tls12AeadAlgorithm for Gcm {
+    fn encrypter(&self, key: AeadKey, iv: &[u8], extra: &[u8]) -> Box<dyn MessageEncrypter> {
+        Box::new(GcmMessageEncrypter {
+            key: self.0.with_key(key.as_ref()),
+            iv: gcm_iv(iv, extra),
+        })
+    }
+
+    fn decrypter(&self, key: AeadKey, iv: &[u8]) -> Box<dyn MessageDecrypter> {
+        let mut salt = [0u8; GCM_FIXED_IV_LEN];
+        salt.copy_from_slice(iv);
+        Box::new(GcmMessageDecrypter {
+            key: self.0.with_key(key.as_ref()),
+            salt,
+        })
+    }
+
+    fn key_block_shape(&self) -> KeyBlockShape {
+        KeyBlockShape {
+            enc_key_len: self.0.key_len(),
+            fixed_iv_len: GCM_FIXED_IV_LEN,
+            explicit_nonce_len: GCM_EXPLICIT_NONCE_LEN,
+        }
+    }
+
+    fn extract_keys(
+        &self,
+        key: AeadKey,
+        iv: &[u8],
+        explicit: &[u8],
+    ) -> Result<ConnectionTrafficSecrets, UnsupportedOperationError> {
+        let iv = gcm_iv(iv, explicit);
+        match self.0 {
+            Algorithm::Aes128Gcm => Ok(ConnectionTrafficSecrets::Aes128Gcm { key, iv }),
+            Algorithm::Aes256Gcm => Ok(ConnectionTrafficSecrets::Aes256Gcm { key, iv }),
+            #[allow(unreachable_patterns)]
+            _ => Err(UnsupportedOperationError),
+        }
+    }
+
+    fn fips(&self) -> bool {
+        super::fips()
+    }
+}
+
+#[cfg(aes_gcm)]
+fn gcm_iv(write_iv: &[u8], explicit: &[u8]) -> Iv {
+    let mut iv = [0u8; NONCE_LEN];
+    iv[..GCM_FIXED_IV_LEN].copy_from_slice(write_iv);
+    iv[GCM_FIXED_IV_LEN..].copy_from_slice(explicit);
+    Iv::new(iv)
+}
+
+#[cfg(aes_gcm)]
+struct GcmMessageEncrypter {
+    key: Option<Keyed>,
+    iv: Iv,
+}
+
+#[cfg(aes_gcm)]
+struct GcmMessageDecrypter {
+    key: Option<Keyed>,
+    salt: [u8; GCM_FIXED_IV_LEN],
+}
+
+#[cfg(aes_gcm)]
+impl MessageEncrypter for GcmMessageEncrypter {
+    fn encrypt(
+        &mut self,
+        msg: OutboundPlainMessage<'_>,
+        seq: u64,
+    ) -> Result<OutboundOpaqueMessage, Error> {
+        let total_len = self.encrypted_payload_len(msg.payload.len());
+        let key = self.key.as_mut().ok_or(Error::EncryptError)?;
+
+        let nonce = Nonce::new(&self.iv, seq).0;
+        let aad = make_tls12_aad(seq, msg.typ, msg.version, msg.payload.len());
+        let plain = msg.payload.to_vec();
+        let mut sealed = alloc::vec![0u8; plain.len() + TAG_LEN];
+        let rc = key.seal(&nonce, &aad, &plain, &mut sealed);
+        aead::wipe(plain);
+        rc.map_err(|_| Error::EncryptError)?;
+
+        let mut payload = PrefixedPayload::with_capacity(total_len);
+        payload.extend_from_slice(&nonce[GCM_FIXED_IV_LEN..]);
+        payload.extend_from_slice(&sealed);
+        Ok(OutboundOpaqueMessage::new(msg.typ, msg.version, payload))
+    }
+
+    fn encrypted_payload_len(&self, payload_len: usize) -> usize {
+        payload_len + GCM_EXPLICIT_NONCE_LEN + TAG_LEN
+    }
+}
+
+#[cfg(aes_gcm)]
+impl MessageDecrypter for GcmMessageDecrypter {
+    fn decrypt<'a>(
+        &mut self,
+        mut msg: InboundOpaqueMessage<'a>,
+        seq: u64,
+    ) -> Result<InboundPlainMessage<'a>, Error> {
+        let key = self.key.as_mut().ok_or(Error::DecryptError)?;
+        let payload = &mut msg.payload;
+        if payload.len() < GCM_EXPLICIT_NONCE_LEN + TAG_LEN {
+            return Err(Error::DecryptError);
+        }
+
+        let mut nonce = [0u8; NONCE_LEN];
+        nonce[..GCM_FIXED_IV_LEN].copy_from_slice(&self.salt);
+        nonce[GCM_FIXED_IV_LEN..].copy_from_slice(&payload[..GCM_EXPLICIT_NONCE_LEN]);
+        let aad = make_tls12_aad(seq, msg.typ, msg.version,
+            payload.len() - GCM_EXPLICIT_NONCE_LEN - TAG_LEN);
+
+        let plain_len = key.open(&nonce, &aad, &mut payload[GCM_EXPLICIT_NONCE_LEN..])
+            .map_err(|_| Error::DecryptError)?;
+        if plain_len > MAX_FRAGMENT_LEN {
+            return Err(Error::PeerSentOversizedRecord);
+        }
+
+        Ok(msg.into_plain_message_range(
+            GCM_EXPLICIT_NONCE_LEN..GCM_EXPLICIT_NONCE_LEN + plain_len))
+    }
+}
+
+/// ChaCha20-Poly1305 record protection (RFC 7905).
+#[cfg(chacha20_poly1305)]
+struct ChaCha20Poly1305;
+
+#[cfg(chacha20_poly1305)]
+impl Tls12AeadAlgorithm for ChaCha20Poly1305 {
+    fn encrypter(&self, key: AeadKey, iv: &[u8], _: &[u8]) -> Box<dyn MessageEncrypter> {
+        Box::new(ChaCha20Poly1305MessageEncrypter {
+            key: Algorithm::ChaCha20Poly1305.with_key(key.as_ref()),
+            iv: Iv::copy(iv),
+        })
+    }
+
+    fn decrypter(&self, key: AeadKey, iv: &[u8]) -> Box<dyn MessageDecrypter> {
+        Box::new(ChaCha20Poly1305MessageDecrypter {
+            key: Algorithm::ChaCha20Poly1305.with_key(key.as_ref()),
+            iv: Iv::copy(iv),
+        })
+    }
+
+    fn key_block_shape(&self) -> KeyBlockShape {
+        KeyBlockShape {
+            enc_key_len: Algorithm::ChaCha20Poly1305.key_len(),
+            fixed_iv_len: NONCE_LEN,
+            explicit_nonce_len: 0,
+        }
+    }
+
+    fn extract_keys(
+        &self,
+        key: AeadKey,
+        iv: &[u8],
+        _explicit: &[u8],
+    ) -> Result<ConnectionTrafficSecrets, UnsupportedOperationError> {
+        Ok(ConnectionTrafficSecrets::Chacha20Poly1305 { key, iv: Iv::copy(iv) })
+    }
+
+    fn fips(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(chacha20_poly1305)]
+struct ChaCha20Poly1305MessageEncrypter {
+    key: Option<Keyed>,
+    iv: Iv,
+}
+
+#[cfg(chacha20_poly1305)]
+struct ChaCha20Poly1305MessageDecrypter {
+    key: Option<Keyed>,
+    iv: Iv,
+}
+
+#[cfg(chacha20_poly1305)]
+impl MessageEncrypter for ChaCha20Poly1305MessageEncrypter {
+    fn encrypt(
+        &mut self,
+        msg: OutboundPlainMessage<'_>,
+        seq: u64,
+    ) -> Result<OutboundOpaqueMessage, Error> {
+        let total_len = self.encrypted_payload_len(msg.payload.len());
+        let key = self.key.as_mut().ok_or(Error::EncryptError)?;
+
+        let nonce = Nonce::new(&self.iv, seq).0;
+        let aad = make_tls12_aad(seq, msg.typ, msg.version, msg.payload.len());
+        let plain = msg.payload.to_vec();
+        let mut sealed = alloc::vec![0u8; total_len];
+        let rc = key.seal(&nonce, &aad, &plain, &mut sealed);
+        aead::wipe(plain);
+        rc.map_err(|_| Error::EncryptError)?;
+
+        let mut payload = PrefixedPayload::with_capacity(total_len);
+        payload.extend_from_slice(&sealed);
+        Ok(OutboundOpaqueMessage::new(msg.typ, msg.version, payload))
+    }
+
+    fn encrypted_payload_len(&self, payload_len: usize) -> usize {
+        payload_len + TAG_LEN
+    }
+}
+
+#[cfg(chacha20_poly1305)]
+impl MessageDecrypter for ChaCha20Poly1305MessageDecrypter {
+    fn decrypt<'a>(
+        &mut self,
+        mut msg: InboundOpaqueMessage<'a>,
+        seq: u64,
+    ) -> Result<InboundPlainMessage<'a>, Error> {
+        let key = self.key.as_mut().ok_or(Error::DecryptError)?;
+        let payload = &mut msg.payload;
+        if payload.len() < TAG_LEN {
+            return Err(Error::DecryptError);
+        }
+
+        let nonce = Nonce::new(&self.iv, seq).0;
+        let aad = make_tls12_aad(seq, msg.typ, msg.version, payload.len() - TAG_LEN);
+        let plain_len = key.open(&nonce, &aad, payload)
+            .map_err(|_| Error::DecryptError)?;
+        if plain_len > MAX_FRAGMENT_LEN {
+            return Err(Error::PeerSentOversizedRecord);
+        }
+
+        payload.truncate(plain_len);
+        Ok(msg.into_plain_message())
+    }
+}
@@ -0,0 +1,197 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+TLS 1.3 cipher suites and record protection.
+*/
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use rustls::crypto::CipherSuiteCommon;
+use rustls::crypto::cipher::{
+    AeadKey, InboundOpaqueMessage, InboundPlainMessage, Iv, MessageDecrypter,
+    MessageEncrypter, Nonce, OutboundOpaqueMessage, OutboundPlainMessage, PrefixedPayload,
+    Tls13AeadAlgorithm, UnsupportedOperationError, make_tls13_aad,
+};
+use rustls::{
+    CipherSuite, ConnectionTrafficSecrets, ContentType, Error, ProtocolVersion,
+    SupportedCipherSuite, Tls13CipherSuite,
+};
+
+use super::aead::{self, Algorithm, Keyed, TAG_LEN};
+
+/// The TLS 1.3 cipher suite TLS_AES_128_GCM_SHA256.
+#[cfg(aes_gcm)]
+pub static TLS13_AES_128_GCM_SHA256: SupportedCipherSuite =
+    SupportedCipherSuite::Tls13(&Tls13CipherSuite {
+        common: CipherSuiteCommon {
+            suite: CipherSuite::TLS13_AES_128_GCM_SHA256,
+            hash_provider: &super::hash::SHA256,
+            confidentiality_limit: 1 << 24,
+        },
+        hkdf_provider: &super::hkdf::HKDF_SHA256,
+        aead_alg: &Aead(Algorithm::Aes128Gcm),
+        quic: None,
+    });
+
+/// The TLS 1.3 cipher suite TLS_AES_256_GCM_SHA384.
+#[cfg(all(aes_gcm, sha384))]
+pub static TLS13_AES_256_GCM_SHA384: SupportedCipherSuite =
+    SupportedCipherSuite::Tls13(&Tls13CipherSuite {
+        common: CipherSuiteCommon {
+            suite: CipherSuite::TLS13_AES_256_GCM_SHA384,
+            hash_provider: &super::hash::SHA384,
+            confidentiality_limit: 1 << 24,
+        },
+        hkdf_provider: &super::hkdf::HKDF_SHA384,
+        aead_alg: &Aead(Algorithm::Aes256Gcm),
+        quic: None,
+    });
+
+/// The TLS 1.3 cipher suite TLS_CHACHA20_POLY1305_SHA256.
+#[cfg(chacha20_poly1305)]
+pub static TLS13_CHACHA20_POLY1305_SHA256: SupportedCipherSuite =
+    SupportedCipherSuite::Tls13(&Tls13CipherSuite {
+        common: CipherSuiteCommon {
+            suite: CipherSuite::TLS13_CHACHA20_POLY1305_SHA256,
+            hash_provider: &super::hash::SHA256,
+            confidentiality_limit: u64::MAX,
+        },
+        hkdf_provider: &super::hkdf::HKDF_SHA256,
+        aead_alg: &Aead(Algorithm::ChaCha20Poly1305),
+        quic: None,
+    });
+
+struct Aead(Algorithm);
+
+impl Tls13AeadAlgorithm for Aead {
+    fn encrypter(&self, key: AeadKey, iv: Iv) -> Box<dyn MessageEncrypter> {
+        Box::new(Tls13MessageEncrypter {
+            key: self.0.with_key(key.as_ref()),
+            iv,
+        })
+    }
+
+    fn decrypter(&self, key: AeadKey, iv: Iv) -> Box<dyn MessageDecrypter> {
+        Box::new(Tls13MessageDecrypter {
+            key: self.0.with_key(key.as_ref()),
+            iv,
+        })
+    }
+
+    fn key_len(&self) -> usize {
+        self.0.key_len()
+    }
+
+    fn extract_keys(
+        &self,
+        key: AeadKey,
+        iv: Iv,
+    ) -> Result<ConnectionTrafficSecrets, UnsupportedOperationError> {
+        Ok(match self.0 {
+            #[cfg(aes_gcm)]
+            Algorithm::Aes128Gcm => ConnectionTrafficSecrets::Aes128Gcm { key, iv },
+            #[cfg(aes_gcm)]
+            Algorithm::Aes256Gcm => ConnectionTrafficSecrets::Aes256Gcm { key, iv },
+            #[cfg(chacha20_poly1305)]
+            Algorithm::ChaCha20Poly1305 =>
+                ConnectionTrafficSecrets::Chacha20Poly1305 { key, iv },
+        })
+    }
+
+    fn fips(&self) -> bool {
+        match self.0 {
+            #[cfg(chacha20_poly1305)]
+            Algorithm::ChaCha20Poly1305 => false,
+            #[allow(unreachable_patterns)]
+            _ => super::fips(),
+        }
+    }
+}
+
+struct Tls13MessageEncrypter {
+    key: Option<Keyed>,
+    iv: Iv,
+}
+
+struct Tls13MessageDecrypter {
+    key: Option<Keyed>,
+    iv: Iv,
+}
+
+impl MessageEncrypter for Tls13MessageEncrypter {
+    fn encrypt(
+        &mut self,
+        msg: OutboundPlainMessage<'_>,
+        seq: u64,
+    ) -> Result<OutboundOpaqueMessage, Error> {
+        let total_len = self.encrypted_payload_len(msg.payload.len());
+        let key = self.key.as_mut().ok_or(Error::EncryptError)?;
+
+        // TLSInnerPlaintext: content || ContentType, no padding.
+        let mut plain = Vec::with_capacity(total_len);
+        msg.payload.copy_to_vec(&mut plain);
+        plain.extend_from_slice(&msg.typ.to_array());
+
+        let nonce = Nonce::new(&self.iv, seq).0;
+        let aad = make_tls13_aad(total_len);
+        let mut sealed = alloc::vec![0u8; total_len];
+        let rc = key.seal(&nonce, &aad, &plain, &mut sealed);
+        aead::wipe(plain);
+        rc.map_err(|_| Error::EncryptError)?;
+
+        let mut payload = PrefixedPayload::with_capacity(total_len);
+        payload.extend_from_slice(&sealed);
+        Ok(OutboundOpaqueMessage::new(
+            ContentType::ApplicationData,
+            // All TLS 1.3 records carry TLS 1.2 as the legacy record version
+            // (RFC 8446 section 5.1).
+            ProtocolVersion::TLSv1_2,
+            payload,
+        ))
+    }
+
+    fn encrypted_payload_len(&self, payload_len: usize) -> usize {
+        payload_len + 1 + TAG_LEN
+    }
+}
+
+impl MessageDecrypter for Tls13MessageDecrypter {
+    fn decrypt<'a>(
+        &mut self,
+        mut msg: InboundOpaqueMessage<'a>,
+        seq: u64,
+    ) -> Result<InboundPlainMessage<'a>, Error> {
+        let key = self.key.as_mut().ok_or(Error::DecryptError)?;
+        let payload = &mut msg.payload;
+        if payload.len() < TAG_LEN {
+            return Err(Error::DecryptError);
+        }
+
+        let nonce = Nonce::new(&self.iv, seq).0;
+        let aad = make_tls13_aad(payload.len());
+        let plain_len = key.open(&nonce, &aad, payload)
+            .map_err(|_| Error::DecryptError)?;
+
+        payload.truncate(plain_len);
+        msg.into_tls13_unpadded_message()
+    }
+}
@@ -0,0 +1,354 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+Signature verification algorithms used for certificate and handshake
+signature checks.
+
+rustls passes the contents of the SubjectPublicKeyInfo BIT STRING as the
+public key: an uncompressed point for ECDSA, a PKCS #1 `RSAPublicKey` for RSA
+and the raw 32-byte key for Ed25519.
+*/
+
+use rustls::SignatureScheme;
+use rustls::crypto::WebPkiSupportedAlgorithms;
+#[allow(unused_imports)]
+use rustls::pki_types::{
+    AlgorithmIdentifier, InvalidSignature, SignatureVerificationAlgorithm, alg_id,
+};
+
+#[cfg(any(ecc, rsa))]
+use super::hash::SigHash;
+#[cfg(all(ecc, ecc_verify, ecc_import))]
+use crate::ecc::ECC;
+#[cfg(all(ed25519, ed25519_import, ed25519_verify))]
+use crate::ed25519::Ed25519;
+#[cfg(rsa)]
+use crate::rsa::RSA;
+#[cfg(rsa)]
+use crate::sys;
+
+/// Signature verification algorithms supported by the provider.
+pub(crate) static SUPPORTED_SIG_ALGS: WebPkiSupportedAlgorithms = WebPkiSupportedAlgorithms {
+    all: &[
+        #[cfg(all(ecc, ecc_verify, ecc_import))]
+        ECDSA_P256_SHA256,
+        #[cfg(all(ecc, ecc_verify, ecc_import, sha384))]
+        ECDSA_P384_SHA384,
+        #[cfg(all(ed25519, ed25519_import, ed25519_verify))]
+        ED25519,
+        #[cfg(all(rsa, rsa_pss, rsa_const_api, sha512))]
+        RSA_PSS_SHA512,
+        #[cfg(all(rsa, rsa_pss, rsa_const_api, sha384))]
+        RSA_PSS_SHA384,
+        #[cfg(all(rsa, rsa_pss, rsa_const_api))]
+        RSA_PSS_SHA256,
+        #[cfg(all(rsa, sha512))]
+        RSA_PKCS1_SHA512,
+        #[cfg(all(rsa, sha384))]
+        RSA_PKCS1_SHA384,
+        #[cfg(rsa)]
+        RSA_PKCS1_SHA256,
+    ],
+    mapping: &[
+        #[cfg(all(ecc, ecc_verify, ecc_import, sha384))]
+        (SignatureScheme::ECDSA_NISTP384_SHA384, &[ECDSA_P384_SHA384]),
+        #[cfg(all(ecc, ecc_verify, ecc_import))]
+        (SignatureScheme::ECDSA_NISTP256_SHA256, &[ECDSA_P256_SHA256]),
+        #[cfg(all(ed25519, ed25519_import, ed25519_verify))]
+        (SignatureScheme::ED25519, &[ED25519]),
+        #[cfg(all(rsa, rsa_pss, rsa_const_api, sha512))]
+        (SignatureScheme::RSA_PSS_SHA512, &[RSA_PSS_SHA512]),
+        #[cfg(all(rsa, rsa_pss, rsa_const_api, sha384))]
+        (SignatureScheme::RSA_PSS_SHA384, &[RSA_PSS_SHA384]),
+        #[cfg(all(rsa, rsa_pss, rsa_const_api))]
+        (SignatureScheme::RSA_PSS_SHA256, &[RSA_PSS_SHA256]),
+        #[cfg(all(rsa, sha512))]
+        (SignatureScheme::RSA_PKCS1_SHA512, &[RSA_PKCS1_SHA512]),
+        #[cfg(all(rsa, sha384))]
+        (SignatureScheme::RSA_PKCS1_SHA384, &[RSA_PKCS1_SHA384]),
+        #[cfg(rsa)]
+        (SignatureScheme::RSA_PKCS1_SHA256, &[RSA_PKCS1_SHA256]),
+    ],
+};
+
+/// ECDSA with SHA-256 over P-256.
+#[cfg(all(ecc, ecc_verify, ecc_import))]
+pub static ECDSA_P256_SHA256: &dyn SignatureVerificationAlgorithm = &EcdsaVerify {
+    curve_id: ECC::SECP256R1,
+    hash: SigHash::Sha256,
+    public_key_alg_id: alg_id::ECDSA_P256,
+    signature_alg_id: alg_id::ECDSA_SHA256,
+};
+
+/// ECDSA with SHA-384 over P-384.
+#[cfg(all(ecc, ecc_verify, ecc_import, sha384))]
+pub static ECDSA_P384_SHA384: &dyn SignatureVerificationAlgorithm = &EcdsaVerify {
+    curve_id: ECC::SECP384R1,
+    hash: SigHash::Sha384,
+    public_key_alg_id: alg_id::ECDSA_P384,
+    signature_alg_id: alg_id::ECDSA_SHA384,
+};
+
+/// Ed25519 (RFC 8032).
+#[cfg(all(ed25519, ed25519_import, ed25519_verify))]
+pub static ED25519: &dyn SignatureVerificationAlgorithm = &Ed25519Verify;
+
+/// RSA PKCS #1 v1.5 with SHA-256.
+#[cfg(rsa)]
+pub static RSA_PKCS1_SHA256: &dyn SignatureVerificationAlgorithm = &RsaPkcs1Verify {
+    hash: SigHash::Sha256,
+    signature_alg_id: alg_id::RSA_PKCS1_SHA256,
+};
+
+/// RSA PKCS #1 v1.5 with SHA-384.
+#[cfg(all(rsa, sha384))]
+pub static RSA_PKCS1_SHA384: &dyn SignatureVerificationAlgorithm = &RsaPkcs1Verify {
+    hash: SigHash::Sha384,
+    signature_alg_id: alg_id::RSA_PKCS1_SHA384,
+};
+
+/// RSA PKCS #1 v1.5 with SHA-512.
+#[cfg(all(rsa, sha512))]
+pub static RSA_PKCS1_SHA512: &dyn SignatureVerificationAlgorithm = &RsaPkcs1Verify {
+    hash: SigHash::Sha512,
+    signature_alg_id: alg_id::RSA_PKCS1_SHA512,
+};
+
+/// RSA-PSS with SHA-256 and MGF1-SHA-256.
+#[cfg(all(rsa, rsa_pss, rsa_const_api))]
+pub static RSA_PSS_SHA256: &dyn SignatureVerificationAlgorithm = &RsaPssVerify {
+    hash: SigHash::Sha256,
+    signature_alg_id: alg_id::RSA_PSS_SHA256,
+};
+
+/// RSA-PSS with SHA-384 and MGF1-SHA-384.
+#[cfg(all(rsa, rsa_pss, rsa_const_api, sha384))]
+pub static RSA_PSS_SHA384: &dyn SignatureVerificationAlgorithm = &RsaPssVerify {
+    hash: SigHash::Sha384,
+    signature_alg_id: alg_id::RSA_PSS_SHA384,
+};
+
+/// RSA-PSS with SHA-512 and MGF1-SHA-512.
+#[cfg(all(rsa, rsa_pss, rsa_const_api, sha512))]
+pub static RSA_PSS_SHA512: &dyn SignatureVerificationAlgorithm = &RsaPssVerify {
+    hash: SigHash::Sha512,
+    signature_alg_id: alg_id::RSA_PSS_SHA512,
+};
+
+#[cfg(all(ecc, ecc_verify, ecc_import))]
+#[derive(Debug)]
+struct EcdsaVerify {
+    curve_id: i32,
+    hash: SigHash,
+    public_key_alg_id: AlgorithmIdentifier,
+    signature_alg_id: AlgorithmIdentifier,
+}
+
+#[cfg(all(ecc, ecc_verify, ecc_import))]
+impl SignatureVerificationAlgorithm for EcdsaVerify {
+    fn verify_signature(
+        &self,
+        public_key: &[u8],
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<(), InvalidSignature> {
+        let digest = self.hash.digest(message).map_err(|_| InvalidSignature)?;
+        let wolfcrypt = crate::WolfCrypt::global().map_err(|_| InvalidSignature)?;
+        let mut key = ECC::import_x963_ex(public_key, self.curve_id, &crate::Env::new(wolfcrypt))
+            .map_err(|_| InvalidSignature)?;
+        // The signature is the DER-encoded ECDSA-Sig-Value.
+        match key.verify_hash(signature, &digest[..self.hash.len()]) {
+            Ok(true) => Ok(()),
+            _ => Err(InvalidSignature),
+        }
+    }
+
+    fn public_key_alg_id(&self) -> AlgorithmIdentifier {
+        self.public_key_alg_id
+    }
+
+    fn signature_alg_id(&self) -> AlgorithmIdentifier {
+        self.signature_alg_id
+    }
+
+    fn fips(&self) -> bool {
+        super::fips()
+    }
+}
+
+#[cfg(all(ed25519, ed25519_import, ed25519_verify))]
+#[derive(Debug)]
+struct Ed25519Verify;
+
+#[cfg(all(ed25519, ed25519_import, ed25519_verify))]
+impl SignatureVerificationAlgorithm for Ed25519Verify {
+    fn verify_signature(
+        &self,
+        public_key: &[u8],
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<(), InvalidSignature> {
+        if public_key.len() != Ed25519::PUB_KEY_SIZE || signature.len() != Ed25519::SIG_SIZE {
+            return Err(InvalidSignature);
+        }
+        let wolfcrypt = crate::WolfCrypt::global().map_err(|_| InvalidSignature)?;
+        let mut key = Ed25519::new(wolfcrypt).map_err(|_| InvalidSignature)?;
+        key.import_public(public_key).map_err(|_| InvalidSignature)?;
+        match key.verify_msg(signature, message) {
+            Ok(true) => Ok(()),
+            _ => Err(InvalidSignature),
+        }
+    }
+
+    fn public_key_alg_id(&self) -> AlgorithmIdentifier {
+        alg_id::ED25519
+    }
+
+    fn signature_alg_id(&self) -> AlgorithmIdentifier {
+        alg_id::ED25519
+    }
+
+    fn fips(&self) -> bool {
+        super::fips()
+    }
+}
+
+/// Smallest RSA modulus accepted, in bytes (2048 bits).
+#[cfg(rsa)]
+const RSA_MIN_MODULUS_LEN: usize = 2048 / 8;
+
+/// Largest RSA modulus accepted, in bytes (8192 bits).
+#[cfg(rsa)]
+const RSA_MAX_MODULUS_LEN: usize = 8192 / 8;
+
+/// Decode a PKCS #1 `RSAPublicKey` and check the modulus size is in the
+/// range accepted for TLS.
+#[cfg(rsa)]
+fn rsa_public_key(public_key: &[u8]) -> Result<RSA<'static>, InvalidSignature> {
+    let wolfcrypt = crate::WolfCrypt::global().map_err(|_| InvalidSignature)?;
+    let rsa = RSA::new_public_from_der(public_key, wolfcrypt).map_err(|_| InvalidSignature)?;
+    let len = rsa.get_encrypt_size().map_err(|_| InvalidSignature)?;
+    if !(RSA_MIN_MODULUS_LEN..=RSA_MAX_MODULUS_LEN).contains(&len) {
+        return Err(InvalidSignature);
+    }
+    Ok(rsa)
+}
+
+#[cfg(rsa)]
+#[derive(Debug)]
+struct RsaPkcs1Verify {
+    hash: SigHash,
+    signature_alg_id: AlgorithmIdentifier,
+}
+
+#[cfg(rsa)]
+impl SignatureVerificationAlgorithm for RsaPkcs1Verify {
+    fn verify_signature(
+        &self,
+        public_key: &[u8],
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<(), InvalidSignature> {
+        let mut rsa = rsa_public_key(public_key)?;
+        let message_len = crate::buffer_len_to_u32(message.len()).map_err(|_| InvalidSignature)?;
+        let signature_len = crate::buffer_len_to_u32(signature.len())
+            .map_err(|_| InvalidSignature)?;
+        // wc_SignatureVerify() hashes the message and checks the DigestInfo
+        // encoding of the result.
+        let rc = unsafe {
+            sys::wc_SignatureVerify(
+                self.hash.hash_type(),
+                sys::wc_SignatureType_WC_SIGNATURE_TYPE_RSA_W_ENC,
+                message.as_ptr(), message_len,
+                signature.as_ptr(), signature_len,
+                &mut rsa.wc_rsakey as *mut _ as *mut core::ffi::c_void,
+                size_of::<sys::RsaKey>() as u32,
+            )
+        };
+        if rc != 0 {
+            return Err(InvalidSignature);
+        }
+        Ok(())
+    }
+
+    fn public_key_alg_id(&self) -> AlgorithmIdentifier {
+        alg_id::RSA_ENCRYPTION
+    }
+
+    fn signature_alg_id(&self) -> AlgorithmIdentifier {
+        self.signature_alg_id
+    }
+
+    fn fips(&self) -> bool {
+        super::fips()
+    }
+}
+
+/// Return the MGF1 type (`RSA::MGF1*`) matching `hash`.
+#[cfg(all(rsa, rsa_pss))]
+pub(crate) fn pss_mgf(hash: SigHash) -> i32 {
+    match hash {
+        SigHash::Sha256 => RSA::MGF1SHA256,
+        #[cfg(sha384)]
+        SigHash::Sha384 => RSA::MGF1SHA384,
+        #[cfg(sha512)]
+        SigHash::Sha512 => RSA::MGF1SHA512,
+    }
+}
+
+#[cfg(all(rsa, rsa_pss, rsa_const_api))]
+#[derive(Debug)]
+struct RsaPssVerify {
+    hash: SigHash,
+    signature_alg_id: AlgorithmIdentifier,
+}
+
+#[cfg(all(rsa, rsa_pss, rsa_const_api))]
+impl SignatureVerificationAlgorithm for RsaPssVerify {
+    fn verify_signature(
+        &self,
+        public_key: &[u8],
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<(), InvalidSignature> {
+        let mut rsa = rsa_public_key(public_key)?;
+        let digest = self.hash.digest(message).map_err(|_| InvalidSignature)?;
+        let mut out = [0u8; RSA_MAX_MODULUS_LEN];
+        // TLS requires the PSS salt length to equal the digest length, which
+        // is what wc_RsaPSS_VerifyCheck() checks for.
+        rsa.pss_verify_check(signature, &mut out, &digest[..self.hash.len()],
+                self.hash.hash_type(), pss_mgf(self.hash))
+            .map_err(|_| InvalidSignature)?;
+        Ok(())
+    }
+
+    fn public_key_alg_id(&self) -> AlgorithmIdentifier {
+        alg_id::RSA_ENCRYPTION
+    }
+
+    fn signature_alg_id(&self) -> AlgorithmIdentifier {
+        self.signature_alg_id
+    }
+
+    fn fips(&self) -> bool {
+        super::fips()
+    }
+}
@@ -23,22 +23,32 @@ This module provides a Rust wrapper for the wolfCrypt library's Secure Hash
 Algorithm (SHA) functionality.
 */
 
+use crate::Error;
+use crate::memory::HeapHint;
 use crate::sys;
+use core::marker::PhantomData;
 use core::mem::MaybeUninit;
 
 /// Context for SHA-1 computation.
 #[cfg(sha)]
-pub struct SHA {
+pub struct SHA<'a> {
     wc_sha: sys::wc_Sha,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 
 #[cfg(sha)]
-impl SHA {
+impl<'a> SHA<'a> {
     /// SHA-1 digest size in bytes.
     pub const DIGEST_SIZE: usize = sys::WC_SHA_DIGEST_SIZE as usize;
 
     /// Build a new SHA instance.
     ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
     /// # Returns
     ///
     /// Returns either Ok(sha) containing the SHA struct instance or Err(e)
@@ -48,18 +58,19 @@ impl SHA {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA;
-    /// let sha = SHA::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let sha = SHA::new(&wolfcrypt).expect("Error with new()");
     /// ```
-    pub fn new() -> Result<Self, i32> {
-        Self::new_ex(None, None)
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&crate::Env::new(wolfcrypt))
     }
 
     /// Build a new SHA instance with optional heap and device ID.
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -69,25 +80,23 @@ impl SHA {
     /// # Example
     ///
     /// ```rust
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::sha::SHA;
-    /// let sha = SHA::new_ex(None, None).expect("Error with new_ex()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let sha = SHA::new_ex(&Env::new(&wolfcrypt)).expect("Error with new_ex()");
     /// ```
-    pub fn new_ex(heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn new_ex(env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let mut wc_sha: MaybeUninit<sys::wc_Sha> = MaybeUninit::uninit();
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_InitSha_ex(wc_sha.as_mut_ptr(), heap, dev_id) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_sha = unsafe { wc_sha.assume_init() };
-        let sha = SHA { wc_sha };
+        let sha = SHA { wc_sha, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData };
         Ok(sha)
     }
 
@@ -105,11 +114,13 @@ impl SHA {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA;
-    /// let mut sha = SHA::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA::new(&wolfcrypt).expect("Error with new()");
     /// sha.init().expect("Error with init()");
     /// ```
-    pub fn init(&mut self) -> Result<(), i32> {
-        self.init_ex(None, None)
+    pub fn init(&mut self) -> Result<(), Error> {
+        self.init_ex(&crate::Env::new(&self._wolfcrypt))
     }
 
     /// Reinitialize a SHA instance for a new hash calculation with optional
@@ -120,8 +131,7 @@ impl SHA {
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -131,22 +141,20 @@ impl SHA {
     /// # Example
     ///
     /// ```rust
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::sha::SHA;
-    /// let mut sha = SHA::new().expect("Error with new()");
-    /// sha.init_ex(None, None).expect("Error with init_ex()");
-    /// ```
-    pub fn init_ex(&mut self, heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<(), i32> {
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA::new(&wolfcrypt).expect("Error with new()");
+    /// sha.init_ex(&Env::new(&wolfcrypt)).expect("Error with init_ex()");
+    /// ```
+    pub fn init_ex(&mut self, env: &crate::Env<'_, 'a>) -> Result<(), Error> {
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_InitSha_ex(&mut self.wc_sha, heap, dev_id) };
+        self._device = env.device_ref();
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -166,16 +174,18 @@ impl SHA {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA;
-    /// let mut sha = SHA::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA::new(&wolfcrypt).expect("Error with new()");
     /// sha.update(b"input").expect("Error with update()");
     /// ```
-    pub fn update(&mut self, data: &[u8]) -> Result<(), i32> {
+    pub fn update(&mut self, data: &[u8]) -> Result<(), Error> {
         let data_size = crate::buffer_len_to_u32(data.len())?;
         let rc = unsafe {
             sys::wc_ShaUpdate(&mut self.wc_sha, data.as_ptr(), data_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -196,34 +206,73 @@ impl SHA {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA;
-    /// let mut sha = SHA::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA::new(&wolfcrypt).expect("Error with new()");
     /// sha.update(b"input").expect("Error with update()");
     /// let mut hash = [0u8; SHA::DIGEST_SIZE];
     /// sha.finalize(&mut hash).expect("Error with finalize()");
     /// ```
-    pub fn finalize(&mut self, hash: &mut [u8]) -> Result<(), i32> {
+    pub fn finalize(&mut self, hash: &mut [u8]) -> Result<(), Error> {
         if hash.len() != Self::DIGEST_SIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let rc = unsafe {
             sys::wc_ShaFinal(&mut self.wc_sha, hash.as_mut_ptr())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
 }
 
 #[cfg(sha)]
-impl SHA {
+impl<'a> SHA<'a> {
     fn zeroize(&mut self) {
         unsafe { crate::zeroize_raw(&mut self.wc_sha); }
     }
 }
 
 #[cfg(sha)]
-impl Drop for SHA {
+impl<'a> SHA<'a> {
+    /// Create a copy of the SHA state via `wc_ShaCopy()`, for example to
+    /// retrieve an intermediate hash and continue the calculation.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(SHA) containing the copy or Err(e) containing
+    /// the wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use wolfssl_wolfcrypt::sha::SHA;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA::new(&wolfcrypt).expect("Error with new()");
+    /// sha.update(b"input").expect("Error with update()");
+    /// let mut copy = sha.try_clone().expect("Error with try_clone()");
+    /// let mut hash = [0u8; SHA::DIGEST_SIZE];
+    /// copy.finalize(&mut hash).expect("Error with finalize()");
+    /// ```
+    pub fn try_clone(&self) -> Result<Self, Error> {
+        let mut wc_sha: MaybeUninit<sys::wc_Sha> = MaybeUninit::uninit();
+        // wc_ShaCopy() takes a non-const pointer but only reads the source.
+        let rc = unsafe {
+            sys::wc_ShaCopy(&self.wc_sha as *const _ as *mut _,
+                wc_sha.as_mut_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        let wc_sha = unsafe { wc_sha.assume_init() };
+        Ok(SHA { wc_sha, _wolfcrypt: self._wolfcrypt.clone(), _device: self._device.clone(), _heap: PhantomData })
+    }
+}
+
+#[cfg(sha)]
+impl Drop for SHA<'_> {
     /// Safely free the underlying wolfSSL SHA context.
     ///
     /// This calls the `wc_ShaFree` wolfssl library function.
@@ -239,17 +288,24 @@ impl Drop for SHA {
 
 /// Context for SHA-224 (SHA-2) computation.
 #[cfg(sha224)]
-pub struct SHA224 {
+pub struct SHA224<'a> {
     wc_sha224: sys::wc_Sha224,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 
 #[cfg(sha224)]
-impl SHA224 {
+impl<'a> SHA224<'a> {
     /// SHA-224 digest size in bytes.
     pub const DIGEST_SIZE: usize = sys::WC_SHA224_DIGEST_SIZE as usize;
 
     /// Build a new SHA224 instance.
     ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
     /// # Returns
     ///
     /// Returns either Ok(sha) containing the SHA224 struct instance or Err(e)
@@ -259,18 +315,19 @@ impl SHA224 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA224;
-    /// let sha = SHA224::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let sha = SHA224::new(&wolfcrypt).expect("Error with new()");
     /// ```
-    pub fn new() -> Result<Self, i32> {
-        Self::new_ex(None, None)
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&crate::Env::new(wolfcrypt))
     }
 
     /// Build a new SHA224 instance with optional heap and device ID.
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -280,25 +337,23 @@ impl SHA224 {
     /// # Example
     ///
     /// ```rust
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::sha::SHA224;
-    /// let sha = SHA224::new_ex(None, None).expect("Error with new_ex()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let sha = SHA224::new_ex(&Env::new(&wolfcrypt)).expect("Error with new_ex()");
     /// ```
-    pub fn new_ex(heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn new_ex(env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let mut wc_sha224: MaybeUninit<sys::wc_Sha224> = MaybeUninit::uninit();
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_InitSha224_ex(wc_sha224.as_mut_ptr(), heap, dev_id) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_sha224 = unsafe { wc_sha224.assume_init() };
-        let sha224 = SHA224 { wc_sha224 };
+        let sha224 = SHA224 { wc_sha224, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData };
         Ok(sha224)
     }
 
@@ -316,11 +371,13 @@ impl SHA224 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA224;
-    /// let mut sha = SHA224::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA224::new(&wolfcrypt).expect("Error with new()");
     /// sha.init().expect("Error with init()");
     /// ```
-    pub fn init(&mut self) -> Result<(), i32> {
-        self.init_ex(None, None)
+    pub fn init(&mut self) -> Result<(), Error> {
+        self.init_ex(&crate::Env::new(&self._wolfcrypt))
     }
 
     /// Reinitialize a SHA224 instance for a new hash calculation with optional
@@ -331,8 +388,7 @@ impl SHA224 {
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -342,22 +398,20 @@ impl SHA224 {
     /// # Example
     ///
     /// ```rust
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::sha::SHA224;
-    /// let mut sha = SHA224::new().expect("Error with new()");
-    /// sha.init_ex(None, None).expect("Error with init_ex()");
-    /// ```
-    pub fn init_ex(&mut self, heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<(), i32> {
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA224::new(&wolfcrypt).expect("Error with new()");
+    /// sha.init_ex(&Env::new(&wolfcrypt)).expect("Error with init_ex()");
+    /// ```
+    pub fn init_ex(&mut self, env: &crate::Env<'_, 'a>) -> Result<(), Error> {
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_InitSha224_ex(&mut self.wc_sha224, heap, dev_id) };
+        self._device = env.device_ref();
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -377,16 +431,18 @@ impl SHA224 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA224;
-    /// let mut sha = SHA224::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA224::new(&wolfcrypt).expect("Error with new()");
     /// sha.update(b"input").expect("Error with update()");
     /// ```
-    pub fn update(&mut self, data: &[u8]) -> Result<(), i32> {
+    pub fn update(&mut self, data: &[u8]) -> Result<(), Error> {
         let data_size = crate::buffer_len_to_u32(data.len())?;
         let rc = unsafe {
             sys::wc_Sha224Update(&mut self.wc_sha224, data.as_ptr(), data_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -407,34 +463,73 @@ impl SHA224 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA224;
-    /// let mut sha = SHA224::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA224::new(&wolfcrypt).expect("Error with new()");
     /// sha.update(b"input").expect("Error with update()");
     /// let mut hash = [0u8; SHA224::DIGEST_SIZE];
     /// sha.finalize(&mut hash).expect("Error with finalize()");
     /// ```
-    pub fn finalize(&mut self, hash: &mut [u8]) -> Result<(), i32> {
+    pub fn finalize(&mut self, hash: &mut [u8]) -> Result<(), Error> {
         if hash.len() != Self::DIGEST_SIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let rc = unsafe {
             sys::wc_Sha224Final(&mut self.wc_sha224, hash.as_mut_ptr())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
 }
 
 #[cfg(sha224)]
-impl SHA224 {
+impl<'a> SHA224<'a> {
     fn zeroize(&mut self) {
         unsafe { crate::zeroize_raw(&mut self.wc_sha224); }
     }
 }
 
 #[cfg(sha224)]
-impl Drop for SHA224 {
+impl<'a> SHA224<'a> {
+    /// Create a copy of the SHA224 state via `wc_Sha224Copy()`, for example to
+    /// retrieve an intermediate hash and continue the calculation.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(SHA224) containing the copy or Err(e) containing
+    /// the wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use wolfssl_wolfcrypt::sha::SHA224;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA224::new(&wolfcrypt).expect("Error with new()");
+    /// sha.update(b"input").expect("Error with update()");
+    /// let mut copy = sha.try_clone().expect("Error with try_clone()");
+    /// let mut hash = [0u8; SHA224::DIGEST_SIZE];
+    /// copy.finalize(&mut hash).expect("Error with finalize()");
+    /// ```
+    pub fn try_clone(&self) -> Result<Self, Error> {
+        let mut wc_sha224: MaybeUninit<sys::wc_Sha224> = MaybeUninit::uninit();
+        // wc_Sha224Copy() takes a non-const pointer but only reads the source.
+        let rc = unsafe {
+            sys::wc_Sha224Copy(&self.wc_sha224 as *const _ as *mut _,
+                wc_sha224.as_mut_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        let wc_sha224 = unsafe { wc_sha224.assume_init() };
+        Ok(SHA224 { wc_sha224, _wolfcrypt: self._wolfcrypt.clone(), _device: self._device.clone(), _heap: PhantomData })
+    }
+}
+
+#[cfg(sha224)]
+impl Drop for SHA224<'_> {
     /// Safely free the underlying wolfSSL SHA224 context.
     ///
     /// This calls the `wc_Sha224Free` wolfssl library function.
@@ -450,17 +545,24 @@ impl Drop for SHA224 {
 
 /// Context for SHA-256 (SHA-2) computation.
 #[cfg(sha256)]
-pub struct SHA256 {
+pub struct SHA256<'a> {
     wc_sha256: sys::wc_Sha256,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 
 #[cfg(sha256)]
-impl SHA256 {
+impl<'a> SHA256<'a> {
     /// SHA-256 digest size in bytes.
     pub const DIGEST_SIZE: usize = sys::WC_SHA256_DIGEST_SIZE as usize;
 
     /// Build a new SHA256 instance.
     ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
     /// # Returns
     ///
     /// Returns either Ok(sha) containing the SHA256 struct instance or Err(e)
@@ -470,18 +572,19 @@ impl SHA256 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA256;
-    /// let sha = SHA256::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let sha = SHA256::new(&wolfcrypt).expect("Error with new()");
     /// ```
-    pub fn new() -> Result<Self, i32> {
-        Self::new_ex(None, None)
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&crate::Env::new(wolfcrypt))
     }
 
     /// Build a new SHA256 instance with optional heap and device ID.
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -491,25 +594,23 @@ impl SHA256 {
     /// # Example
     ///
     /// ```rust
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::sha::SHA256;
-    /// let sha = SHA256::new_ex(None, None).expect("Error with new_ex()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let sha = SHA256::new_ex(&Env::new(&wolfcrypt)).expect("Error with new_ex()");
     /// ```
-    pub fn new_ex(heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn new_ex(env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let mut wc_sha256: MaybeUninit<sys::wc_Sha256> = MaybeUninit::uninit();
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_InitSha256_ex(wc_sha256.as_mut_ptr(), heap, dev_id) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_sha256 = unsafe { wc_sha256.assume_init() };
-        let sha256 = SHA256 { wc_sha256 };
+        let sha256 = SHA256 { wc_sha256, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData };
         Ok(sha256)
     }
 
@@ -527,11 +628,13 @@ impl SHA256 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA256;
-    /// let mut sha = SHA256::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA256::new(&wolfcrypt).expect("Error with new()");
     /// sha.init().expect("Error with init()");
     /// ```
-    pub fn init(&mut self) -> Result<(), i32> {
-        self.init_ex(None, None)
+    pub fn init(&mut self) -> Result<(), Error> {
+        self.init_ex(&crate::Env::new(&self._wolfcrypt))
     }
 
     /// Reinitialize a SHA256 instance for a new hash calculation with optional
@@ -542,8 +645,7 @@ impl SHA256 {
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -553,22 +655,20 @@ impl SHA256 {
     /// # Example
     ///
     /// ```rust
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::sha::SHA256;
-    /// let mut sha = SHA256::new().expect("Error with new()");
-    /// sha.init_ex(None, None).expect("Error with init_ex()");
-    /// ```
-    pub fn init_ex(&mut self, heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<(), i32> {
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA256::new(&wolfcrypt).expect("Error with new()");
+    /// sha.init_ex(&Env::new(&wolfcrypt)).expect("Error with init_ex()");
+    /// ```
+    pub fn init_ex(&mut self, env: &crate::Env<'_, 'a>) -> Result<(), Error> {
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_InitSha256_ex(&mut self.wc_sha256, heap, dev_id) };
+        self._device = env.device_ref();
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -588,16 +688,18 @@ impl SHA256 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA256;
-    /// let mut sha = SHA256::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA256::new(&wolfcrypt).expect("Error with new()");
     /// sha.update(b"input").expect("Error with update()");
     /// ```
-    pub fn update(&mut self, data: &[u8]) -> Result<(), i32> {
+    pub fn update(&mut self, data: &[u8]) -> Result<(), Error> {
         let data_size = crate::buffer_len_to_u32(data.len())?;
         let rc = unsafe {
             sys::wc_Sha256Update(&mut self.wc_sha256, data.as_ptr(), data_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -618,34 +720,73 @@ impl SHA256 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA256;
-    /// let mut sha = SHA256::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA256::new(&wolfcrypt).expect("Error with new()");
     /// sha.update(b"input").expect("Error with update()");
     /// let mut hash = [0u8; SHA256::DIGEST_SIZE];
     /// sha.finalize(&mut hash).expect("Error with finalize()");
     /// ```
-    pub fn finalize(&mut self, hash: &mut [u8]) -> Result<(), i32> {
+    pub fn finalize(&mut self, hash: &mut [u8]) -> Result<(), Error> {
         if hash.len() != Self::DIGEST_SIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let rc = unsafe {
             sys::wc_Sha256Final(&mut self.wc_sha256, hash.as_mut_ptr())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
 }
 
 #[cfg(sha256)]
-impl SHA256 {
+impl<'a> SHA256<'a> {
     fn zeroize(&mut self) {
         unsafe { crate::zeroize_raw(&mut self.wc_sha256); }
     }
 }
 
 #[cfg(sha256)]
-impl Drop for SHA256 {
+impl<'a> SHA256<'a> {
+    /// Create a copy of the SHA256 state via `wc_Sha256Copy()`, for example to
+    /// retrieve an intermediate hash and continue the calculation.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(SHA256) containing the copy or Err(e) containing
+    /// the wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use wolfssl_wolfcrypt::sha::SHA256;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA256::new(&wolfcrypt).expect("Error with new()");
+    /// sha.update(b"input").expect("Error with update()");
+    /// let mut copy = sha.try_clone().expect("Error with try_clone()");
+    /// let mut hash = [0u8; SHA256::DIGEST_SIZE];
+    /// copy.finalize(&mut hash).expect("Error with finalize()");
+    /// ```
+    pub fn try_clone(&self) -> Result<Self, Error> {
+        let mut wc_sha256: MaybeUninit<sys::wc_Sha256> = MaybeUninit::uninit();
+        // wc_Sha256Copy() takes a non-const pointer but only reads the source.
+        let rc = unsafe {
+            sys::wc_Sha256Copy(&self.wc_sha256 as *const _ as *mut _,
+                wc_sha256.as_mut_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        let wc_sha256 = unsafe { wc_sha256.assume_init() };
+        Ok(SHA256 { wc_sha256, _wolfcrypt: self._wolfcrypt.clone(), _device: self._device.clone(), _heap: PhantomData })
+    }
+}
+
+#[cfg(sha256)]
+impl Drop for SHA256<'_> {
     /// Safely free the underlying wolfSSL SHA256 context.
     ///
     /// This calls the `wc_Sha256Free` wolfssl library function.
@@ -661,17 +802,24 @@ impl Drop for SHA256 {
 
 /// Context for SHA-384 (SHA-2) computation.
 #[cfg(sha384)]
-pub struct SHA384 {
+pub struct SHA384<'a> {
     wc_sha384: sys::wc_Sha384,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 
 #[cfg(sha384)]
-impl SHA384 {
+impl<'a> SHA384<'a> {
     /// SHA-384 digest size in bytes.
     pub const DIGEST_SIZE: usize = sys::WC_SHA384_DIGEST_SIZE as usize;
 
     /// Build a new SHA384 instance.
     ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
     /// # Returns
     ///
     /// Returns either Ok(sha) containing the SHA384 struct instance or Err(e)
@@ -681,18 +829,19 @@ impl SHA384 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA384;
-    /// let sha = SHA384::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let sha = SHA384::new(&wolfcrypt).expect("Error with new()");
     /// ```
-    pub fn new() -> Result<Self, i32> {
-        Self::new_ex(None, None)
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&crate::Env::new(wolfcrypt))
     }
 
     /// Build a new SHA384 instance with optional heap and device ID.
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -702,25 +851,23 @@ impl SHA384 {
     /// # Example
     ///
     /// ```rust
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::sha::SHA384;
-    /// let sha = SHA384::new_ex(None, None).expect("Error with new_ex()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let sha = SHA384::new_ex(&Env::new(&wolfcrypt)).expect("Error with new_ex()");
     /// ```
-    pub fn new_ex(heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn new_ex(env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let mut wc_sha384: MaybeUninit<sys::wc_Sha384> = MaybeUninit::uninit();
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_InitSha384_ex(wc_sha384.as_mut_ptr(), heap, dev_id) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_sha384 = unsafe { wc_sha384.assume_init() };
-        let sha384 = SHA384 { wc_sha384 };
+        let sha384 = SHA384 { wc_sha384, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData };
         Ok(sha384)
     }
 
@@ -738,11 +885,13 @@ impl SHA384 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA384;
-    /// let mut sha = SHA384::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA384::new(&wolfcrypt).expect("Error with new()");
     /// sha.init().expect("Error with init()");
     /// ```
-    pub fn init(&mut self) -> Result<(), i32> {
-        self.init_ex(None, None)
+    pub fn init(&mut self) -> Result<(), Error> {
+        self.init_ex(&crate::Env::new(&self._wolfcrypt))
     }
 
     /// Reinitialize a SHA384 instance for a new hash calculation with optional
@@ -753,8 +902,7 @@ impl SHA384 {
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -764,22 +912,20 @@ impl SHA384 {
     /// # Example
     ///
     /// ```rust
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::sha::SHA384;
-    /// let mut sha = SHA384::new().expect("Error with new()");
-    /// sha.init_ex(None, None).expect("Error with init_ex()");
-    /// ```
-    pub fn init_ex(&mut self, heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<(), i32> {
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA384::new(&wolfcrypt).expect("Error with new()");
+    /// sha.init_ex(&Env::new(&wolfcrypt)).expect("Error with init_ex()");
+    /// ```
+    pub fn init_ex(&mut self, env: &crate::Env<'_, 'a>) -> Result<(), Error> {
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_InitSha384_ex(&mut self.wc_sha384, heap, dev_id) };
+        self._device = env.device_ref();
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -799,16 +945,18 @@ impl SHA384 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA384;
-    /// let mut sha = SHA384::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA384::new(&wolfcrypt).expect("Error with new()");
     /// sha.update(b"input").expect("Error with update()");
     /// ```
-    pub fn update(&mut self, data: &[u8]) -> Result<(), i32> {
+    pub fn update(&mut self, data: &[u8]) -> Result<(), Error> {
         let data_size = crate::buffer_len_to_u32(data.len())?;
         let rc = unsafe {
             sys::wc_Sha384Update(&mut self.wc_sha384, data.as_ptr(), data_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -829,34 +977,73 @@ impl SHA384 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA384;
-    /// let mut sha = SHA384::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA384::new(&wolfcrypt).expect("Error with new()");
     /// sha.update(b"input").expect("Error with update()");
     /// let mut hash = [0u8; SHA384::DIGEST_SIZE];
     /// sha.finalize(&mut hash).expect("Error with finalize()");
     /// ```
-    pub fn finalize(&mut self, hash: &mut [u8]) -> Result<(), i32> {
+    pub fn finalize(&mut self, hash: &mut [u8]) -> Result<(), Error> {
         if hash.len() != Self::DIGEST_SIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let rc = unsafe {
             sys::wc_Sha384Final(&mut self.wc_sha384, hash.as_mut_ptr())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
 }
 
 #[cfg(sha384)]
-impl SHA384 {
+impl<'a> SHA384<'a> {
     fn zeroize(&mut self) {
         unsafe { crate::zeroize_raw(&mut self.wc_sha384); }
     }
 }
 
 #[cfg(sha384)]
-impl Drop for SHA384 {
+impl<'a> SHA384<'a> {
+    /// Create a copy of the SHA384 state via `wc_Sha384Copy()`, for example to
+    /// retrieve an intermediate hash and continue the calculation.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(SHA384) containing the copy or Err(e) containing
+    /// the wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use wolfssl_wolfcrypt::sha::SHA384;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA384::new(&wolfcrypt).expect("Error with new()");
+    /// sha.update(b"input").expect("Error with update()");
+    /// let mut copy = sha.try_clone().expect("Error with try_clone()");
+    /// let mut hash = [0u8; SHA384::DIGEST_SIZE];
+    /// copy.finalize(&mut hash).expect("Error with finalize()");
+    /// ```
+    pub fn try_clone(&self) -> Result<Self, Error> {
+        let mut wc_sha384: MaybeUninit<sys::wc_Sha384> = MaybeUninit::uninit();
+        // wc_Sha384Copy() takes a non-const pointer but only reads the source.
+        let rc = unsafe {
+            sys::wc_Sha384Copy(&self.wc_sha384 as *const _ as *mut _,
+                wc_sha384.as_mut_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        let wc_sha384 = unsafe { wc_sha384.assume_init() };
+        Ok(SHA384 { wc_sha384, _wolfcrypt: self._wolfcrypt.clone(), _device: self._device.clone(), _heap: PhantomData })
+    }
+}
+
+#[cfg(sha384)]
+impl Drop for SHA384<'_> {
     /// Safely free the underlying wolfSSL SHA384 context.
     ///
     /// This calls the `wc_Sha384Free` wolfssl library function.
@@ -872,17 +1059,24 @@ impl Drop for SHA384 {
 
 /// Context for SHA-512 (SHA-2) computation.
 #[cfg(sha512)]
-pub struct SHA512 {
+pub struct SHA512<'a> {
     wc_sha512: sys::wc_Sha512,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 
 #[cfg(sha512)]
-impl SHA512 {
+impl<'a> SHA512<'a> {
     /// SHA-512 digest size in bytes.
     pub const DIGEST_SIZE: usize = sys::WC_SHA512_DIGEST_SIZE as usize;
 
     /// Build a new SHA512 instance.
     ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
     /// # Returns
     ///
     /// Returns either Ok(sha) containing the SHA512 struct instance or Err(e)
@@ -892,18 +1086,19 @@ impl SHA512 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA512;
-    /// let sha = SHA512::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let sha = SHA512::new(&wolfcrypt).expect("Error with new()");
     /// ```
-    pub fn new() -> Result<Self, i32> {
-        Self::new_ex(None, None)
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&crate::Env::new(wolfcrypt))
     }
 
     /// Build a new SHA512 instance with optional heap and device ID.
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -913,25 +1108,23 @@ impl SHA512 {
     /// # Example
     ///
     /// ```rust
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::sha::SHA512;
-    /// let sha = SHA512::new_ex(None, None).expect("Error with new_ex()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let sha = SHA512::new_ex(&Env::new(&wolfcrypt)).expect("Error with new_ex()");
     /// ```
-    pub fn new_ex(heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn new_ex(env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let mut wc_sha512: MaybeUninit<sys::wc_Sha512> = MaybeUninit::uninit();
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_InitSha512_ex(wc_sha512.as_mut_ptr(), heap, dev_id) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_sha512 = unsafe { wc_sha512.assume_init() };
-        let sha512 = SHA512 { wc_sha512 };
+        let sha512 = SHA512 { wc_sha512, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData };
         Ok(sha512)
     }
 
@@ -949,11 +1142,13 @@ impl SHA512 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA512;
-    /// let mut sha = SHA512::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA512::new(&wolfcrypt).expect("Error with new()");
     /// sha.init().expect("Error with init()");
     /// ```
-    pub fn init(&mut self) -> Result<(), i32> {
-        self.init_ex(None, None)
+    pub fn init(&mut self) -> Result<(), Error> {
+        self.init_ex(&crate::Env::new(&self._wolfcrypt))
     }
 
     /// Reinitialize a SHA512 instance for a new hash calculation with optional
@@ -964,8 +1159,7 @@ impl SHA512 {
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -975,22 +1169,20 @@ impl SHA512 {
     /// # Example
     ///
     /// ```rust
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::sha::SHA512;
-    /// let mut sha = SHA512::new().expect("Error with new()");
-    /// sha.init_ex(None, None).expect("Error with init_ex()");
-    /// ```
-    pub fn init_ex(&mut self, heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<(), i32> {
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA512::new(&wolfcrypt).expect("Error with new()");
+    /// sha.init_ex(&Env::new(&wolfcrypt)).expect("Error with init_ex()");
+    /// ```
+    pub fn init_ex(&mut self, env: &crate::Env<'_, 'a>) -> Result<(), Error> {
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_InitSha512_ex(&mut self.wc_sha512, heap, dev_id) };
+        self._device = env.device_ref();
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1010,16 +1202,18 @@ impl SHA512 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA512;
-    /// let mut sha = SHA512::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA512::new(&wolfcrypt).expect("Error with new()");
     /// sha.update(b"input").expect("Error with update()");
     /// ```
-    pub fn update(&mut self, data: &[u8]) -> Result<(), i32> {
+    pub fn update(&mut self, data: &[u8]) -> Result<(), Error> {
         let data_size = crate::buffer_len_to_u32(data.len())?;
         let rc = unsafe {
             sys::wc_Sha512Update(&mut self.wc_sha512, data.as_ptr(), data_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1040,34 +1234,73 @@ impl SHA512 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA512;
-    /// let mut sha = SHA512::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA512::new(&wolfcrypt).expect("Error with new()");
     /// sha.update(b"input").expect("Error with update()");
     /// let mut hash = [0u8; SHA512::DIGEST_SIZE];
     /// sha.finalize(&mut hash).expect("Error with finalize()");
     /// ```
-    pub fn finalize(&mut self, hash: &mut [u8]) -> Result<(), i32> {
+    pub fn finalize(&mut self, hash: &mut [u8]) -> Result<(), Error> {
         if hash.len() != Self::DIGEST_SIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let rc = unsafe {
             sys::wc_Sha512Final(&mut self.wc_sha512, hash.as_mut_ptr())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
 }
 
 #[cfg(sha512)]
-impl SHA512 {
+impl<'a> SHA512<'a> {
     fn zeroize(&mut self) {
         unsafe { crate::zeroize_raw(&mut self.wc_sha512); }
     }
 }
 
 #[cfg(sha512)]
-impl Drop for SHA512 {
+impl<'a> SHA512<'a> {
+    /// Create a copy of the SHA512 state via `wc_Sha512Copy()`, for example to
+    /// retrieve an intermediate hash and continue the calculation.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(SHA512) containing the copy or Err(e) containing
+    /// the wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use wolfssl_wolfcrypt::sha::SHA512;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA512::new(&wolfcrypt).expect("Error with new()");
+    /// sha.update(b"input").expect("Error with update()");
+    /// let mut copy = sha.try_clone().expect("Error with try_clone()");
+    /// let mut hash = [0u8; SHA512::DIGEST_SIZE];
+    /// copy.finalize(&mut hash).expect("Error with finalize()");
+    /// ```
+    pub fn try_clone(&self) -> Result<Self, Error> {
+        let mut wc_sha512: MaybeUninit<sys::wc_Sha512> = MaybeUninit::uninit();
+        // wc_Sha512Copy() takes a non-const pointer but only reads the source.
+        let rc = unsafe {
+            sys::wc_Sha512Copy(&self.wc_sha512 as *const _ as *mut _,
+                wc_sha512.as_mut_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        let wc_sha512 = unsafe { wc_sha512.assume_init() };
+        Ok(SHA512 { wc_sha512, _wolfcrypt: self._wolfcrypt.clone(), _device: self._device.clone(), _heap: PhantomData })
+    }
+}
+
+#[cfg(sha512)]
+impl Drop for SHA512<'_> {
     /// Safely free the underlying wolfSSL SHA512 context.
     ///
     /// This calls the `wc_Sha512Free` wolfssl library function.
@@ -1083,17 +1316,24 @@ impl Drop for SHA512 {
 
 /// Context for SHA3-224 computation.
 #[cfg(sha3)]
-pub struct SHA3_224 {
+pub struct SHA3_224<'a> {
     wc_sha3: sys::wc_Sha3,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 
 #[cfg(sha3)]
-impl SHA3_224 {
+impl<'a> SHA3_224<'a> {
     /// SHA3-224 digest size in bytes.
     pub const DIGEST_SIZE: usize = sys::WC_SHA3_224_DIGEST_SIZE as usize;
 
     /// Build a new SHA3_224 instance.
     ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
     /// # Returns
     ///
     /// Returns either Ok(sha) containing the SHA3_224 struct instance or Err(e)
@@ -1103,18 +1343,19 @@ impl SHA3_224 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA3_224;
-    /// let sha = SHA3_224::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let sha = SHA3_224::new(&wolfcrypt).expect("Error with new()");
     /// ```
-    pub fn new() -> Result<Self, i32> {
-        Self::new_ex(None, None)
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&crate::Env::new(wolfcrypt))
     }
 
     /// Build a new SHA3_224 instance with optional heap and device ID.
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -1124,25 +1365,23 @@ impl SHA3_224 {
     /// # Example
     ///
     /// ```rust
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::sha::SHA3_224;
-    /// let sha = SHA3_224::new_ex(None, None).expect("Error with new_ex()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let sha = SHA3_224::new_ex(&Env::new(&wolfcrypt)).expect("Error with new_ex()");
     /// ```
-    pub fn new_ex(heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn new_ex(env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let mut wc_sha3: MaybeUninit<sys::wc_Sha3> = MaybeUninit::uninit();
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_InitSha3_224(wc_sha3.as_mut_ptr(), heap, dev_id) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_sha3 = unsafe { wc_sha3.assume_init() };
-        let sha3_224 = SHA3_224 { wc_sha3 };
+        let sha3_224 = SHA3_224 { wc_sha3, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData };
         Ok(sha3_224)
     }
 
@@ -1160,11 +1399,13 @@ impl SHA3_224 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA3_224;
-    /// let mut sha = SHA3_224::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA3_224::new(&wolfcrypt).expect("Error with new()");
     /// sha.init().expect("Error with init()");
     /// ```
-    pub fn init(&mut self) -> Result<(), i32> {
-        self.init_ex(None, None)
+    pub fn init(&mut self) -> Result<(), Error> {
+        self.init_ex(&crate::Env::new(&self._wolfcrypt))
     }
 
     /// Reinitialize a SHA3_224 instance for a new hash calculation with
@@ -1175,8 +1416,7 @@ impl SHA3_224 {
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -1186,22 +1426,20 @@ impl SHA3_224 {
     /// # Example
     ///
     /// ```rust
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::sha::SHA3_224;
-    /// let mut sha = SHA3_224::new().expect("Error with new()");
-    /// sha.init_ex(None, None).expect("Error with init_ex()");
-    /// ```
-    pub fn init_ex(&mut self, heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<(), i32> {
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA3_224::new(&wolfcrypt).expect("Error with new()");
+    /// sha.init_ex(&Env::new(&wolfcrypt)).expect("Error with init_ex()");
+    /// ```
+    pub fn init_ex(&mut self, env: &crate::Env<'_, 'a>) -> Result<(), Error> {
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_InitSha3_224(&mut self.wc_sha3, heap, dev_id) };
+        self._device = env.device_ref();
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1221,16 +1459,18 @@ impl SHA3_224 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA3_224;
-    /// let mut sha = SHA3_224::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA3_224::new(&wolfcrypt).expect("Error with new()");
     /// sha.update(b"input").expect("Error with update()");
     /// ```
-    pub fn update(&mut self, data: &[u8]) -> Result<(), i32> {
+    pub fn update(&mut self, data: &[u8]) -> Result<(), Error> {
         let data_size = crate::buffer_len_to_u32(data.len())?;
         let rc = unsafe {
             sys::wc_Sha3_224_Update(&mut self.wc_sha3, data.as_ptr(), data_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1251,34 +1491,36 @@ impl SHA3_224 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA3_224;
-    /// let mut sha = SHA3_224::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA3_224::new(&wolfcrypt).expect("Error with new()");
     /// sha.update(b"input").expect("Error with update()");
     /// let mut hash = [0u8; SHA3_224::DIGEST_SIZE];
     /// sha.finalize(&mut hash).expect("Error with finalize()");
     /// ```
-    pub fn finalize(&mut self, hash: &mut [u8]) -> Result<(), i32> {
+    pub fn finalize(&mut self, hash: &mut [u8]) -> Result<(), Error> {
         if hash.len() != Self::DIGEST_SIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let rc = unsafe {
             sys::wc_Sha3_224_Final(&mut self.wc_sha3, hash.as_mut_ptr())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
 }
 
 #[cfg(sha3)]
-impl SHA3_224 {
+impl<'a> SHA3_224<'a> {
     fn zeroize(&mut self) {
         unsafe { crate::zeroize_raw(&mut self.wc_sha3); }
     }
 }
 
 #[cfg(sha3)]
-impl Drop for SHA3_224 {
+impl Drop for SHA3_224<'_> {
     /// Safely free the underlying wolfSSL SHA3_224 context.
     ///
     /// This calls the `wc_Sha3_224_Free` wolfssl library function.
@@ -1294,17 +1536,24 @@ impl Drop for SHA3_224 {
 
 /// Context for SHA3-256 computation.
 #[cfg(sha3)]
-pub struct SHA3_256 {
+pub struct SHA3_256<'a> {
     wc_sha3: sys::wc_Sha3,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 
 #[cfg(sha3)]
-impl SHA3_256 {
+impl<'a> SHA3_256<'a> {
     /// SHA3-256 digest size in bytes.
     pub const DIGEST_SIZE: usize = sys::WC_SHA3_256_DIGEST_SIZE as usize;
 
     /// Build a new SHA3_256 instance.
     ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
     /// # Returns
     ///
     /// Returns either Ok(sha) containing the SHA3_256 struct instance or Err(e)
@@ -1314,18 +1563,19 @@ impl SHA3_256 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA3_256;
-    /// let sha = SHA3_256::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let sha = SHA3_256::new(&wolfcrypt).expect("Error with new()");
     /// ```
-    pub fn new() -> Result<Self, i32> {
-        Self::new_ex(None, None)
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&crate::Env::new(wolfcrypt))
     }
 
     /// Build a new SHA3_256 instance with optional heap and device ID.
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -1335,25 +1585,23 @@ impl SHA3_256 {
     /// # Example
     ///
     /// ```rust
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::sha::SHA3_256;
-    /// let sha = SHA3_256::new_ex(None, None).expect("Error with new_ex()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let sha = SHA3_256::new_ex(&Env::new(&wolfcrypt)).expect("Error with new_ex()");
     /// ```
-    pub fn new_ex(heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn new_ex(env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let mut wc_sha3: MaybeUninit<sys::wc_Sha3> = MaybeUninit::uninit();
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_InitSha3_256(wc_sha3.as_mut_ptr(), heap, dev_id) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_sha3 = unsafe { wc_sha3.assume_init() };
-        let sha3_256 = SHA3_256 { wc_sha3 };
+        let sha3_256 = SHA3_256 { wc_sha3, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData };
         Ok(sha3_256)
     }
 
@@ -1371,11 +1619,13 @@ impl SHA3_256 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA3_256;
-    /// let mut sha = SHA3_256::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA3_256::new(&wolfcrypt).expect("Error with new()");
     /// sha.init().expect("Error with init()");
     /// ```
-    pub fn init(&mut self) -> Result<(), i32> {
-        self.init_ex(None, None)
+    pub fn init(&mut self) -> Result<(), Error> {
+        self.init_ex(&crate::Env::new(&self._wolfcrypt))
     }
 
     /// Reinitialize a SHA3_256 instance for a new hash calculation with
@@ -1386,8 +1636,7 @@ impl SHA3_256 {
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -1397,22 +1646,20 @@ impl SHA3_256 {
     /// # Example
     ///
     /// ```rust
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::sha::SHA3_256;
-    /// let mut sha = SHA3_256::new().expect("Error with new()");
-    /// sha.init_ex(None, None).expect("Error with init_ex()");
-    /// ```
-    pub fn init_ex(&mut self, heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<(), i32> {
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA3_256::new(&wolfcrypt).expect("Error with new()");
+    /// sha.init_ex(&Env::new(&wolfcrypt)).expect("Error with init_ex()");
+    /// ```
+    pub fn init_ex(&mut self, env: &crate::Env<'_, 'a>) -> Result<(), Error> {
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_InitSha3_256(&mut self.wc_sha3, heap, dev_id) };
+        self._device = env.device_ref();
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1432,16 +1679,18 @@ impl SHA3_256 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA3_256;
-    /// let mut sha = SHA3_256::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA3_256::new(&wolfcrypt).expect("Error with new()");
     /// sha.update(b"input").expect("Error with update()");
     /// ```
-    pub fn update(&mut self, data: &[u8]) -> Result<(), i32> {
+    pub fn update(&mut self, data: &[u8]) -> Result<(), Error> {
         let data_size = crate::buffer_len_to_u32(data.len())?;
         let rc = unsafe {
             sys::wc_Sha3_256_Update(&mut self.wc_sha3, data.as_ptr(), data_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1462,34 +1711,36 @@ impl SHA3_256 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA3_256;
-    /// let mut sha = SHA3_256::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA3_256::new(&wolfcrypt).expect("Error with new()");
     /// sha.update(b"input").expect("Error with update()");
     /// let mut hash = [0u8; SHA3_256::DIGEST_SIZE];
     /// sha.finalize(&mut hash).expect("Error with finalize()");
     /// ```
-    pub fn finalize(&mut self, hash: &mut [u8]) -> Result<(), i32> {
+    pub fn finalize(&mut self, hash: &mut [u8]) -> Result<(), Error> {
         if hash.len() != Self::DIGEST_SIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let rc = unsafe {
             sys::wc_Sha3_256_Final(&mut self.wc_sha3, hash.as_mut_ptr())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
 }
 
 #[cfg(sha3)]
-impl SHA3_256 {
+impl<'a> SHA3_256<'a> {
     fn zeroize(&mut self) {
         unsafe { crate::zeroize_raw(&mut self.wc_sha3); }
     }
 }
 
 #[cfg(sha3)]
-impl Drop for SHA3_256 {
+impl Drop for SHA3_256<'_> {
     /// Safely free the underlying wolfSSL SHA3_256 context.
     ///
     /// This calls the `wc_Sha3_256_Free` wolfssl library function.
@@ -1505,17 +1756,24 @@ impl Drop for SHA3_256 {
 
 /// Context for SHA3-384 computation.
 #[cfg(sha3)]
-pub struct SHA3_384 {
+pub struct SHA3_384<'a> {
     wc_sha3: sys::wc_Sha3,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 
 #[cfg(sha3)]
-impl SHA3_384 {
+impl<'a> SHA3_384<'a> {
     /// SHA3-384 digest size in bytes.
     pub const DIGEST_SIZE: usize = sys::WC_SHA3_384_DIGEST_SIZE as usize;
 
     /// Build a new SHA3_384 instance.
     ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
     /// # Returns
     ///
     /// Returns either Ok(sha) containing the SHA3_384 struct instance or Err(e)
@@ -1525,18 +1783,19 @@ impl SHA3_384 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA3_384;
-    /// let sha = SHA3_384::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let sha = SHA3_384::new(&wolfcrypt).expect("Error with new()");
     /// ```
-    pub fn new() -> Result<Self, i32> {
-        Self::new_ex(None, None)
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&crate::Env::new(wolfcrypt))
     }
 
     /// Build a new SHA3_384 instance with optional heap and device ID.
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -1546,25 +1805,23 @@ impl SHA3_384 {
     /// # Example
     ///
     /// ```rust
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::sha::SHA3_384;
-    /// let sha = SHA3_384::new_ex(None, None).expect("Error with new_ex()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let sha = SHA3_384::new_ex(&Env::new(&wolfcrypt)).expect("Error with new_ex()");
     /// ```
-    pub fn new_ex(heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn new_ex(env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let mut wc_sha3: MaybeUninit<sys::wc_Sha3> = MaybeUninit::uninit();
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_InitSha3_384(wc_sha3.as_mut_ptr(), heap, dev_id) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_sha3 = unsafe { wc_sha3.assume_init() };
-        let sha3_384 = SHA3_384 { wc_sha3 };
+        let sha3_384 = SHA3_384 { wc_sha3, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData };
         Ok(sha3_384)
     }
 
@@ -1582,11 +1839,13 @@ impl SHA3_384 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA3_384;
-    /// let mut sha = SHA3_384::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA3_384::new(&wolfcrypt).expect("Error with new()");
     /// sha.init().expect("Error with init()");
     /// ```
-    pub fn init(&mut self) -> Result<(), i32> {
-        self.init_ex(None, None)
+    pub fn init(&mut self) -> Result<(), Error> {
+        self.init_ex(&crate::Env::new(&self._wolfcrypt))
     }
 
     /// Reinitialize a SHA3_384 instance for a new hash calculation with
@@ -1597,8 +1856,7 @@ impl SHA3_384 {
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -1608,22 +1866,20 @@ impl SHA3_384 {
     /// # Example
     ///
     /// ```rust
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::sha::SHA3_384;
-    /// let mut sha = SHA3_384::new().expect("Error with new()");
-    /// sha.init_ex(None, None).expect("Error with init_ex()");
-    /// ```
-    pub fn init_ex(&mut self, heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<(), i32> {
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA3_384::new(&wolfcrypt).expect("Error with new()");
+    /// sha.init_ex(&Env::new(&wolfcrypt)).expect("Error with init_ex()");
+    /// ```
+    pub fn init_ex(&mut self, env: &crate::Env<'_, 'a>) -> Result<(), Error> {
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_InitSha3_384(&mut self.wc_sha3, heap, dev_id) };
+        self._device = env.device_ref();
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1643,16 +1899,18 @@ impl SHA3_384 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA3_384;
-    /// let mut sha = SHA3_384::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA3_384::new(&wolfcrypt).expect("Error with new()");
     /// sha.update(b"input").expect("Error with update()");
     /// ```
-    pub fn update(&mut self, data: &[u8]) -> Result<(), i32> {
+    pub fn update(&mut self, data: &[u8]) -> Result<(), Error> {
         let data_size = crate::buffer_len_to_u32(data.len())?;
         let rc = unsafe {
             sys::wc_Sha3_384_Update(&mut self.wc_sha3, data.as_ptr(), data_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1673,34 +1931,36 @@ impl SHA3_384 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA3_384;
-    /// let mut sha = SHA3_384::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA3_384::new(&wolfcrypt).expect("Error with new()");
     /// sha.update(b"input").expect("Error with update()");
     /// let mut hash = [0u8; SHA3_384::DIGEST_SIZE];
     /// sha.finalize(&mut hash).expect("Error with finalize()");
     /// ```
-    pub fn finalize(&mut self, hash: &mut [u8]) -> Result<(), i32> {
+    pub fn finalize(&mut self, hash: &mut [u8]) -> Result<(), Error> {
         if hash.len() != Self::DIGEST_SIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let rc = unsafe {
             sys::wc_Sha3_384_Final(&mut self.wc_sha3, hash.as_mut_ptr())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
 }
 
 #[cfg(sha3)]
-impl SHA3_384 {
+impl<'a> SHA3_384<'a> {
     fn zeroize(&mut self) {
         unsafe { crate::zeroize_raw(&mut self.wc_sha3); }
     }
 }
 
 #[cfg(sha3)]
-impl Drop for SHA3_384 {
+impl Drop for SHA3_384<'_> {
     /// Safely free the underlying wolfSSL SHA3_384 context.
     ///
     /// This calls the `wc_Sha3_384_Free` wolfssl library function.
@@ -1716,17 +1976,24 @@ impl Drop for SHA3_384 {
 
 /// Context for SHA3-512 computation.
 #[cfg(sha3)]
-pub struct SHA3_512 {
+pub struct SHA3_512<'a> {
     wc_sha3: sys::wc_Sha3,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 
 #[cfg(sha3)]
-impl SHA3_512 {
+impl<'a> SHA3_512<'a> {
     /// SHA3-512 digest size in bytes.
     pub const DIGEST_SIZE: usize = sys::WC_SHA3_512_DIGEST_SIZE as usize;
 
     /// Build a new SHA3_512 instance.
     ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
     /// # Returns
     ///
     /// Returns either Ok(sha) containing the SHA3_512 struct instance or Err(e)
@@ -1736,18 +2003,19 @@ impl SHA3_512 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA3_512;
-    /// let sha = SHA3_512::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let sha = SHA3_512::new(&wolfcrypt).expect("Error with new()");
     /// ```
-    pub fn new() -> Result<Self, i32> {
-        Self::new_ex(None, None)
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&crate::Env::new(wolfcrypt))
     }
 
     /// Build a new SHA3_512 instance with optional heap and device ID.
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -1757,25 +2025,23 @@ impl SHA3_512 {
     /// # Example
     ///
     /// ```rust
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::sha::SHA3_512;
-    /// let sha = SHA3_512::new_ex(None, None).expect("Error with new_ex()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let sha = SHA3_512::new_ex(&Env::new(&wolfcrypt)).expect("Error with new_ex()");
     /// ```
-    pub fn new_ex(heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn new_ex(env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let mut wc_sha3: MaybeUninit<sys::wc_Sha3> = MaybeUninit::uninit();
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_InitSha3_512(wc_sha3.as_mut_ptr(), heap, dev_id) };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_sha3 = unsafe { wc_sha3.assume_init() };
-        let sha3_512 = SHA3_512 { wc_sha3 };
+        let sha3_512 = SHA3_512 { wc_sha3, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData };
         Ok(sha3_512)
     }
 
@@ -1793,11 +2059,13 @@ impl SHA3_512 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA3_512;
-    /// let mut sha = SHA3_512::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA3_512::new(&wolfcrypt).expect("Error with new()");
     /// sha.init().expect("Error with init()");
     /// ```
-    pub fn init(&mut self) -> Result<(), i32> {
-        self.init_ex(None, None)
+    pub fn init(&mut self) -> Result<(), Error> {
+        self.init_ex(&crate::Env::new(&self._wolfcrypt))
     }
 
     /// Reinitialize a SHA3_512 instance for a new hash calculation with
@@ -1808,8 +2076,7 @@ impl SHA3_512 {
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -1819,22 +2086,20 @@ impl SHA3_512 {
     /// # Example
     ///
     /// ```rust
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::sha::SHA3_512;
-    /// let mut sha = SHA3_512::new().expect("Error with new()");
-    /// sha.init_ex(None, None).expect("Error with init_ex()");
-    /// ```
-    pub fn init_ex(&mut self, heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<(), i32> {
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA3_512::new(&wolfcrypt).expect("Error with new()");
+    /// sha.init_ex(&Env::new(&wolfcrypt)).expect("Error with init_ex()");
+    /// ```
+    pub fn init_ex(&mut self, env: &crate::Env<'_, 'a>) -> Result<(), Error> {
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe { sys::wc_InitSha3_512(&mut self.wc_sha3, heap, dev_id) };
+        self._device = env.device_ref();
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1854,16 +2119,18 @@ impl SHA3_512 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA3_512;
-    /// let mut sha = SHA3_512::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA3_512::new(&wolfcrypt).expect("Error with new()");
     /// sha.update(b"input").expect("Error with update()");
     /// ```
-    pub fn update(&mut self, data: &[u8]) -> Result<(), i32> {
+    pub fn update(&mut self, data: &[u8]) -> Result<(), Error> {
         let data_size = crate::buffer_len_to_u32(data.len())?;
         let rc = unsafe {
             sys::wc_Sha3_512_Update(&mut self.wc_sha3, data.as_ptr(), data_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -1884,34 +2151,36 @@ impl SHA3_512 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHA3_512;
-    /// let mut sha = SHA3_512::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHA3_512::new(&wolfcrypt).expect("Error with new()");
     /// sha.update(b"input").expect("Error with update()");
     /// let mut hash = [0u8; SHA3_512::DIGEST_SIZE];
     /// sha.finalize(&mut hash).expect("Error with finalize()");
     /// ```
-    pub fn finalize(&mut self, hash: &mut [u8]) -> Result<(), i32> {
+    pub fn finalize(&mut self, hash: &mut [u8]) -> Result<(), Error> {
         if hash.len() != Self::DIGEST_SIZE {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let rc = unsafe {
             sys::wc_Sha3_512_Final(&mut self.wc_sha3, hash.as_mut_ptr())
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
 }
 
 #[cfg(sha3)]
-impl SHA3_512 {
+impl<'a> SHA3_512<'a> {
     fn zeroize(&mut self) {
         unsafe { crate::zeroize_raw(&mut self.wc_sha3); }
     }
 }
 
 #[cfg(sha3)]
-impl Drop for SHA3_512 {
+impl Drop for SHA3_512<'_> {
     /// Safely free the underlying wolfSSL SHA3_512 context.
     ///
     /// This calls the `wc_Sha3_512_Free` wolfssl library function.
@@ -1927,17 +2196,24 @@ impl Drop for SHA3_512 {
 
 /// Context for SHAKE128 (SHA-3) computation.
 #[cfg(shake128)]
-pub struct SHAKE128 {
+pub struct SHAKE128<'a> {
     wc_shake: sys::wc_Shake,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 
 #[cfg(shake128)]
-impl SHAKE128 {
+impl<'a> SHAKE128<'a> {
     /// Squeeze block size.
     pub const SQUEEZE_BLOCK_SIZE: usize = sys::WC_SHA3_128_BLOCK_SIZE as usize;
 
     /// Build a new SHAKE128 instance.
     ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
     /// # Returns
     ///
     /// Returns either Ok(sha) containing the SHAKE128 struct instance or Err(e)
@@ -1947,18 +2223,19 @@ impl SHAKE128 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHAKE128;
-    /// let sha = SHAKE128::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let sha = SHAKE128::new(&wolfcrypt).expect("Error with new()");
     /// ```
-    pub fn new() -> Result<Self, i32> {
-        Self::new_ex(None, None)
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&crate::Env::new(wolfcrypt))
     }
 
     /// Build a new SHAKE128 instance with optional heap and device ID.
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -1968,27 +2245,25 @@ impl SHAKE128 {
     /// # Example
     ///
     /// ```rust
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::sha::SHAKE128;
-    /// let sha = SHAKE128::new_ex(None, None).expect("Error with new_ex()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let sha = SHAKE128::new_ex(&Env::new(&wolfcrypt)).expect("Error with new_ex()");
     /// ```
-    pub fn new_ex(heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn new_ex(env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let mut wc_shake: MaybeUninit<sys::wc_Shake> = MaybeUninit::uninit();
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe {
             sys::wc_InitShake128(wc_shake.as_mut_ptr(), heap, dev_id)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_shake = unsafe { wc_shake.assume_init() };
-        let shake128 = SHAKE128 { wc_shake };
+        let shake128 = SHAKE128 { wc_shake, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData };
         Ok(shake128)
     }
 
@@ -2006,11 +2281,13 @@ impl SHAKE128 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHAKE128;
-    /// let mut sha = SHAKE128::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHAKE128::new(&wolfcrypt).expect("Error with new()");
     /// sha.init().expect("Error with init()");
     /// ```
-    pub fn init(&mut self) -> Result<(), i32> {
-        self.init_ex(None, None)
+    pub fn init(&mut self) -> Result<(), Error> {
+        self.init_ex(&crate::Env::new(&self._wolfcrypt))
     }
 
     /// Reinitialize a SHAKE128 instance for a new hash calculation with
@@ -2021,8 +2298,7 @@ impl SHAKE128 {
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -2032,24 +2308,22 @@ impl SHAKE128 {
     /// # Example
     ///
     /// ```rust
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::sha::SHAKE128;
-    /// let mut sha = SHAKE128::new().expect("Error with new()");
-    /// sha.init_ex(None, None).expect("Error with init_ex()");
-    /// ```
-    pub fn init_ex(&mut self, heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<(), i32> {
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHAKE128::new(&wolfcrypt).expect("Error with new()");
+    /// sha.init_ex(&Env::new(&wolfcrypt)).expect("Error with init_ex()");
+    /// ```
+    pub fn init_ex(&mut self, env: &crate::Env<'_, 'a>) -> Result<(), Error> {
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe {
             sys::wc_InitShake128(&mut self.wc_shake, heap, dev_id)
         };
+        self._device = env.device_ref();
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -2069,16 +2343,18 @@ impl SHAKE128 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHAKE128;
-    /// let mut sha = SHAKE128::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHAKE128::new(&wolfcrypt).expect("Error with new()");
     /// sha.update(b"input").expect("Error with update()");
     /// ```
-    pub fn update(&mut self, data: &[u8]) -> Result<(), i32> {
+    pub fn update(&mut self, data: &[u8]) -> Result<(), Error> {
         let data_size = crate::buffer_len_to_u32(data.len())?;
         let rc = unsafe {
             sys::wc_Shake128_Update(&mut self.wc_shake, data.as_ptr(), data_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -2098,18 +2374,20 @@ impl SHAKE128 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHAKE128;
-    /// let mut sha = SHAKE128::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHAKE128::new(&wolfcrypt).expect("Error with new()");
     /// sha.update(b"input").expect("Error with update()");
     /// let mut hash = [0u8; 32];
     /// sha.finalize(&mut hash).expect("Error with finalize()");
     /// ```
-    pub fn finalize(&mut self, hash: &mut [u8]) -> Result<(), i32> {
+    pub fn finalize(&mut self, hash: &mut [u8]) -> Result<(), Error> {
         let hash_size = crate::buffer_len_to_u32(hash.len())?;
         let rc = unsafe {
             sys::wc_Shake128_Final(&mut self.wc_shake, hash.as_mut_ptr(), hash_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -2129,16 +2407,18 @@ impl SHAKE128 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHAKE128;
-    /// let mut sha = SHAKE128::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHAKE128::new(&wolfcrypt).expect("Error with new()");
     /// sha.absorb(b"input").expect("Error with absorb()");
     /// ```
-    pub fn absorb(&mut self, data: &[u8]) -> Result<(), i32> {
+    pub fn absorb(&mut self, data: &[u8]) -> Result<(), Error> {
         let data_size = crate::buffer_len_to_u32(data.len())?;
         let rc = unsafe {
             sys::wc_Shake128_Absorb(&mut self.wc_shake, data.as_ptr(), data_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -2160,36 +2440,38 @@ impl SHAKE128 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHAKE128;
-    /// let mut sha = SHAKE128::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHAKE128::new(&wolfcrypt).expect("Error with new()");
     /// sha.absorb(b"input").expect("Error with absorb()");
     /// let mut buffer = [0u8; 2 * SHAKE128::SQUEEZE_BLOCK_SIZE];
     /// sha.squeeze_blocks(&mut buffer).expect("Error with squeeze_blocks()");
     /// ```
-    pub fn squeeze_blocks(&mut self, dout: &mut [u8]) -> Result<(), i32> {
+    pub fn squeeze_blocks(&mut self, dout: &mut [u8]) -> Result<(), Error> {
         let dout_size = crate::buffer_len_to_u32(dout.len())?;
         if dout_size % (Self::SQUEEZE_BLOCK_SIZE as u32) != 0 {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let n_blocks = dout_size / (Self::SQUEEZE_BLOCK_SIZE as u32);
         let rc = unsafe {
             sys::wc_Shake128_SqueezeBlocks(&mut self.wc_shake, dout.as_mut_ptr(), n_blocks)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
 }
 
 #[cfg(shake128)]
-impl SHAKE128 {
+impl<'a> SHAKE128<'a> {
     fn zeroize(&mut self) {
         unsafe { crate::zeroize_raw(&mut self.wc_shake); }
     }
 }
 
 #[cfg(shake128)]
-impl Drop for SHAKE128 {
+impl Drop for SHAKE128<'_> {
     /// Safely free the underlying wolfSSL SHAKE128 context.
     ///
     /// This calls the `wc_Shake128_Free` wolfssl library function.
@@ -2205,17 +2487,24 @@ impl Drop for SHAKE128 {
 
 /// Context for SHAKE256 (SHA-3) computation.
 #[cfg(shake256)]
-pub struct SHAKE256 {
+pub struct SHAKE256<'a> {
     wc_shake: sys::wc_Shake,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
 }
 
 #[cfg(shake256)]
-impl SHAKE256 {
+impl<'a> SHAKE256<'a> {
     /// Squeeze block size.
     pub const SQUEEZE_BLOCK_SIZE: usize = sys::WC_SHA3_256_BLOCK_SIZE as usize;
 
     /// Build a new SHAKE256 instance.
     ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
     /// # Returns
     ///
     /// Returns either Ok(sha) containing the SHAKE256 struct instance or Err(e)
@@ -2225,18 +2514,19 @@ impl SHAKE256 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHAKE256;
-    /// let sha = SHAKE256::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let sha = SHAKE256::new(&wolfcrypt).expect("Error with new()");
     /// ```
-    pub fn new() -> Result<Self, i32> {
-        Self::new_ex(None, None)
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&crate::Env::new(wolfcrypt))
     }
 
     /// Build a new SHAKE256 instance with optional heap and device ID.
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -2246,27 +2536,25 @@ impl SHAKE256 {
     /// # Example
     ///
     /// ```rust
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::sha::SHAKE256;
-    /// let sha = SHAKE256::new_ex(None, None).expect("Error with new_ex()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let sha = SHAKE256::new_ex(&Env::new(&wolfcrypt)).expect("Error with new_ex()");
     /// ```
-    pub fn new_ex(heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<Self, i32> {
+    pub fn new_ex(env: &crate::Env<'_, 'a>) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
         let mut wc_shake: MaybeUninit<sys::wc_Shake> = MaybeUninit::uninit();
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe {
             sys::wc_InitShake256(wc_shake.as_mut_ptr(), heap, dev_id)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         let wc_shake = unsafe { wc_shake.assume_init() };
-        let shake256 = SHAKE256 { wc_shake };
+        let shake256 = SHAKE256 { wc_shake, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData };
         Ok(shake256)
     }
 
@@ -2284,11 +2572,13 @@ impl SHAKE256 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHAKE256;
-    /// let mut sha = SHAKE256::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHAKE256::new(&wolfcrypt).expect("Error with new()");
     /// sha.init().expect("Error with init()");
     /// ```
-    pub fn init(&mut self) -> Result<(), i32> {
-        self.init_ex(None, None)
+    pub fn init(&mut self) -> Result<(), Error> {
+        self.init_ex(&crate::Env::new(&self._wolfcrypt))
     }
 
     /// Reinitialize a SHAKE256 instance for a new hash calculation with
@@ -2299,8 +2589,7 @@ impl SHAKE256 {
     ///
     /// # Parameters
     ///
-    /// * `heap`: Optional heap hint.
-    /// * `dev_id` Optional device ID to use with crypto callbacks or async hardware.
+    /// * `env`: Heap hint and crypto callback device to use.
     ///
     /// # Returns
     ///
@@ -2310,24 +2599,22 @@ impl SHAKE256 {
     /// # Example
     ///
     /// ```rust
+    /// use wolfssl_wolfcrypt::Env;
     /// use wolfssl_wolfcrypt::sha::SHAKE256;
-    /// let mut sha = SHAKE256::new().expect("Error with new()");
-    /// sha.init_ex(None, None).expect("Error with init_ex()");
-    /// ```
-    pub fn init_ex(&mut self, heap: Option<*mut core::ffi::c_void>, dev_id: Option<i32>) -> Result<(), i32> {
-        let heap = match heap {
-            Some(heap) => heap,
-            None => core::ptr::null_mut(),
-        };
-        let dev_id = match dev_id {
-            Some(dev_id) => dev_id,
-            None => sys::INVALID_DEVID,
-        };
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHAKE256::new(&wolfcrypt).expect("Error with new()");
+    /// sha.init_ex(&Env::new(&wolfcrypt)).expect("Error with init_ex()");
+    /// ```
+    pub fn init_ex(&mut self, env: &crate::Env<'_, 'a>) -> Result<(), Error> {
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
         let rc = unsafe {
             sys::wc_InitShake256(&mut self.wc_shake, heap, dev_id)
         };
+        self._device = env.device_ref();
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -2347,16 +2634,18 @@ impl SHAKE256 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHAKE256;
-    /// let mut sha = SHAKE256::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHAKE256::new(&wolfcrypt).expect("Error with new()");
     /// sha.update(b"input").expect("Error with update()");
     /// ```
-    pub fn update(&mut self, data: &[u8]) -> Result<(), i32> {
+    pub fn update(&mut self, data: &[u8]) -> Result<(), Error> {
         let data_size = crate::buffer_len_to_u32(data.len())?;
         let rc = unsafe {
             sys::wc_Shake256_Update(&mut self.wc_shake, data.as_ptr(), data_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -2376,18 +2665,20 @@ impl SHAKE256 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHAKE256;
-    /// let mut sha = SHAKE256::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHAKE256::new(&wolfcrypt).expect("Error with new()");
     /// sha.update(b"input").expect("Error with update()");
     /// let mut hash = [0u8; 32];
     /// sha.finalize(&mut hash).expect("Error with finalize()");
     /// ```
-    pub fn finalize(&mut self, hash: &mut [u8]) -> Result<(), i32> {
+    pub fn finalize(&mut self, hash: &mut [u8]) -> Result<(), Error> {
         let hash_size = crate::buffer_len_to_u32(hash.len())?;
         let rc = unsafe {
             sys::wc_Shake256_Final(&mut self.wc_shake, hash.as_mut_ptr(), hash_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -2407,16 +2698,18 @@ impl SHAKE256 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHAKE256;
-    /// let mut sha = SHAKE256::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHAKE256::new(&wolfcrypt).expect("Error with new()");
     /// sha.absorb(b"input").expect("Error with absorb()");
     /// ```
-    pub fn absorb(&mut self, data: &[u8]) -> Result<(), i32> {
+    pub fn absorb(&mut self, data: &[u8]) -> Result<(), Error> {
         let data_size = crate::buffer_len_to_u32(data.len())?;
         let rc = unsafe {
             sys::wc_Shake256_Absorb(&mut self.wc_shake, data.as_ptr(), data_size)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
@@ -2438,36 +2731,38 @@ impl SHAKE256 {
     ///
     /// ```rust
     /// use wolfssl_wolfcrypt::sha::SHAKE256;
-    /// let mut sha = SHAKE256::new().expect("Error with new()");
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut sha = SHAKE256::new(&wolfcrypt).expect("Error with new()");
     /// sha.absorb(b"input").expect("Error with absorb()");
     /// let mut buffer = [0u8; 2 * SHAKE256::SQUEEZE_BLOCK_SIZE];
     /// sha.squeeze_blocks(&mut buffer).expect("Error with squeeze_blocks()");
     /// ```
-    pub fn squeeze_blocks(&mut self, dout: &mut [u8]) -> Result<(), i32> {
+    pub fn squeeze_blocks(&mut self, dout: &mut [u8]) -> Result<(), Error> {
         let dout_size = crate::buffer_len_to_u32(dout.len())?;
         if dout_size % (Self::SQUEEZE_BLOCK_SIZE as u32) != 0 {
-            return Err(sys::wolfCrypt_ErrorCodes_BUFFER_E);
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E));
         }
         let n_blocks = dout_size / (Self::SQUEEZE_BLOCK_SIZE as u32);
         let rc = unsafe {
             sys::wc_Shake256_SqueezeBlocks(&mut self.wc_shake, dout.as_mut_ptr(), n_blocks)
         };
         if rc != 0 {
-            return Err(rc);
+            return Err(Error::from(rc));
         }
         Ok(())
     }
 }
 
 #[cfg(shake256)]
-impl SHAKE256 {
+impl<'a> SHAKE256<'a> {
     fn zeroize(&mut self) {
         unsafe { crate::zeroize_raw(&mut self.wc_shake); }
     }
 }
 
 #[cfg(shake256)]
-impl Drop for SHAKE256 {
+impl Drop for SHAKE256<'_> {
     /// Safely free the underlying wolfSSL SHAKE256 context.
     ///
     /// This calls the `wc_Shake256_Free` wolfssl library function.
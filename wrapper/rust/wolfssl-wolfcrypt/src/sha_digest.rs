@@ -46,7 +46,8 @@ macro_rules! impl_digest_traits {
         $(#[$attr])*
         impl Default for $ty {
             fn default() -> Self {
-                <$ty>::new().expect("wolfCrypt hash init failed")
+                let wolfcrypt = crate::WolfCrypt::global().expect("wolfCrypt_Init failed");
+                <$ty>::new(wolfcrypt).expect("wolfCrypt hash init failed")
             }
         }
 
@@ -98,45 +99,45 @@ macro_rules! impl_digest_traits {
 
 impl_digest_traits! {
     #[cfg(sha)]
-    crate::sha::SHA, out = U20, block = U64
+    crate::sha::SHA<'_>, out = U20, block = U64
 }
 
 impl_digest_traits! {
     #[cfg(sha224)]
-    crate::sha::SHA224, out = U28, block = U64
+    crate::sha::SHA224<'_>, out = U28, block = U64
 }
 
 impl_digest_traits! {
     #[cfg(sha256)]
-    crate::sha::SHA256, out = U32, block = U64
+    crate::sha::SHA256<'_>, out = U32, block = U64
 }
 
 impl_digest_traits! {
     #[cfg(sha384)]
-    crate::sha::SHA384, out = U48, block = U128
+    crate::sha::SHA384<'_>, out = U48, block = U128
 }
 
 impl_digest_traits! {
     #[cfg(sha512)]
-    crate::sha::SHA512, out = U64, block = U128
+    crate::sha::SHA512<'_>, out = U64, block = U128
 }
 
 impl_digest_traits! {
     #[cfg(sha3)]
-    crate::sha::SHA3_224, out = U28, block = U144
+    crate::sha::SHA3_224<'_>, out = U28, block = U144
 }
 
 impl_digest_traits! {
     #[cfg(sha3)]
-    crate::sha::SHA3_256, out = U32, block = U136
+    crate::sha::SHA3_256<'_>, out = U32, block = U136
 }
 
 impl_digest_traits! {
     #[cfg(sha3)]
-    crate::sha::SHA3_384, out = U48, block = U104
+    crate::sha::SHA3_384<'_>, out = U48, block = U104
 }
 
 impl_digest_traits! {
     #[cfg(sha3)]
-    crate::sha::SHA3_512, out = U64, block = U72
+    crate::sha::SHA3_512<'_>, out = U64, block = U72
 }
@@ -0,0 +1,1156 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+This module provides a Rust wrapper for the wolfCrypt library's SLH-DSA
+(FIPS 205 Stateless Hash-Based Digital Signature Algorithm) post-quantum
+signature functionality.
+
+The primary component is the [`SlhDsa`] struct, which manages the lifecycle
+of a wolfSSL `SlhDsaKey` object. It ensures proper initialization and
+deallocation.
+
+The parameter set is chosen when the key is created. There are twelve
+parameter sets, built on SHAKE or (with `WOLFSSL_SLHDSA_SHA2`) SHA-2, at
+three security categories, each in a small (`s`, smaller and slower
+signatures) and a fast (`f`) variant:
+
+| Constant                 | Security | Public key | Signature |
+|--------------------------|----------|------------|-----------|
+| `SHAKE_128S`/`SHA2_128S` | 1        | 32         | 7856      |
+| `SHAKE_128F`/`SHA2_128F` | 1        | 32         | 17088     |
+| `SHAKE_192S`/`SHA2_192S` | 3        | 48         | 16224     |
+| `SHAKE_192F`/`SHA2_192F` | 3        | 48         | 35664     |
+| `SHAKE_256S`/`SHA2_256S` | 5        | 64         | 29792     |
+| `SHAKE_256F`/`SHA2_256F` | 5        | 64         | 49856     |
+
+Messages are signed with an optional context string (at most 255 bytes),
+either hedged (with an RNG or caller-supplied randomness) or
+deterministically. HashSLH-DSA signs a digest of the message instead, and the
+FIPS 205 internal interface signs a caller-formatted message `M'`.
+
+With the `signature` feature, [`SlhDsa`] implements the RustCrypto
+`signature` crate's `SignerMut` (deterministic, empty context) and `Keypair`
+traits, and [`VerifyingKey`] implements `Verifier`, for [`Signature<N>`]
+where `N` is the signature size of the parameter set.
+
+# Examples
+
+```rust
+#[cfg(all(slhdsa, slhdsa_make_key, random))]
+{
+use wolfssl_wolfcrypt::random::RNG;
+use wolfssl_wolfcrypt::slhdsa::SlhDsa;
+use wolfssl_wolfcrypt::WolfCrypt;
+let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+let rng = RNG::new(&wolfcrypt).expect("RNG creation failed");
+let mut key = SlhDsa::generate(SlhDsa::SHAKE_128F, &rng, &wolfcrypt)
+    .expect("Key generation failed");
+let message = b"Hello, SLH-DSA!";
+let mut sig = vec![0u8; key.sig_size().expect("sig_size failed")];
+let sig_len = key.sign_ctx_msg(b"context", message, &mut sig, &rng)
+    .expect("Signing failed");
+let valid = key.verify_ctx_msg(&sig[..sig_len], b"context", message)
+    .expect("Verification failed");
+assert!(valid);
+}
+```
+*/
+
+#![cfg(slhdsa)]
+
+use crate::Error;
+use crate::memory::HeapHint;
+use crate::sys;
+use core::marker::PhantomData;
+#[cfg(all(slhdsa_make_key, random))]
+use crate::random::RNG;
+use core::mem::MaybeUninit;
+
+/// Rust wrapper for a wolfSSL `SlhDsaKey` object.
+///
+/// Manages the lifecycle of the underlying key, including initialization and
+/// deallocation via the [`Drop`] trait.
+///
+/// An instance is created with [`SlhDsa::generate()`],
+/// [`SlhDsa::generate_from_seed()`], or [`SlhDsa::new()`] followed by a key
+/// import.
+pub struct SlhDsa<'a> {
+    ws_key: sys::SlhDsaKey,
+    param: u32,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
+}
+
+impl<'a> SlhDsa<'a> {
+    /// SLH-DSA-SHAKE-128s parameter set.
+    pub const SHAKE_128S: u32 = sys::SlhDsaParam_SLHDSA_SHAKE128S;
+    /// SLH-DSA-SHAKE-128f parameter set.
+    pub const SHAKE_128F: u32 = sys::SlhDsaParam_SLHDSA_SHAKE128F;
+    /// SLH-DSA-SHAKE-192s parameter set.
+    pub const SHAKE_192S: u32 = sys::SlhDsaParam_SLHDSA_SHAKE192S;
+    /// SLH-DSA-SHAKE-192f parameter set.
+    pub const SHAKE_192F: u32 = sys::SlhDsaParam_SLHDSA_SHAKE192F;
+    /// SLH-DSA-SHAKE-256s parameter set.
+    pub const SHAKE_256S: u32 = sys::SlhDsaParam_SLHDSA_SHAKE256S;
+    /// SLH-DSA-SHAKE-256f parameter set.
+    pub const SHAKE_256F: u32 = sys::SlhDsaParam_SLHDSA_SHAKE256F;
+    /// SLH-DSA-SHA2-128s parameter set.
+    #[cfg(slhdsa_sha2)]
+    pub const SHA2_128S: u32 = sys::SlhDsaParam_SLHDSA_SHA2_128S;
+    /// SLH-DSA-SHA2-128f parameter set.
+    #[cfg(slhdsa_sha2)]
+    pub const SHA2_128F: u32 = sys::SlhDsaParam_SLHDSA_SHA2_128F;
+    /// SLH-DSA-SHA2-192s parameter set.
+    #[cfg(slhdsa_sha2)]
+    pub const SHA2_192S: u32 = sys::SlhDsaParam_SLHDSA_SHA2_192S;
+    /// SLH-DSA-SHA2-192f parameter set.
+    #[cfg(slhdsa_sha2)]
+    pub const SHA2_192F: u32 = sys::SlhDsaParam_SLHDSA_SHA2_192F;
+    /// SLH-DSA-SHA2-256s parameter set.
+    #[cfg(slhdsa_sha2)]
+    pub const SHA2_256S: u32 = sys::SlhDsaParam_SLHDSA_SHA2_256S;
+    /// SLH-DSA-SHA2-256f parameter set.
+    #[cfg(slhdsa_sha2)]
+    pub const SHA2_256F: u32 = sys::SlhDsaParam_SLHDSA_SHA2_256F;
+
+    /// Public key size in bytes of the 128-bit parameter sets.
+    pub const PUB_KEY_SIZE_128: usize = 2 * sys::WC_SLHDSA_N_128 as usize;
+    /// Public key size in bytes of the 192-bit parameter sets.
+    pub const PUB_KEY_SIZE_192: usize = 2 * sys::WC_SLHDSA_N_192 as usize;
+    /// Public key size in bytes of the 256-bit parameter sets.
+    pub const PUB_KEY_SIZE_256: usize = 2 * sys::WC_SLHDSA_N_256 as usize;
+    /// Private key size in bytes of the 128-bit parameter sets.
+    pub const PRV_KEY_SIZE_128: usize = 4 * sys::WC_SLHDSA_N_128 as usize;
+    /// Private key size in bytes of the 192-bit parameter sets.
+    pub const PRV_KEY_SIZE_192: usize = 4 * sys::WC_SLHDSA_N_192 as usize;
+    /// Private key size in bytes of the 256-bit parameter sets.
+    pub const PRV_KEY_SIZE_256: usize = 4 * sys::WC_SLHDSA_N_256 as usize;
+    /// Signature size in bytes of the 128s parameter sets.
+    pub const SIG_SIZE_128S: usize = sys::WC_SLHDSA_SHAKE128S_SIG_LEN as usize;
+    /// Signature size in bytes of the 128f parameter sets.
+    pub const SIG_SIZE_128F: usize = sys::WC_SLHDSA_SHAKE128F_SIG_LEN as usize;
+    /// Signature size in bytes of the 192s parameter sets.
+    pub const SIG_SIZE_192S: usize = sys::WC_SLHDSA_SHAKE192S_SIG_LEN as usize;
+    /// Signature size in bytes of the 192f parameter sets.
+    pub const SIG_SIZE_192F: usize = sys::WC_SLHDSA_SHAKE192F_SIG_LEN as usize;
+    /// Signature size in bytes of the 256s parameter sets.
+    pub const SIG_SIZE_256S: usize = sys::WC_SLHDSA_SHAKE256S_SIG_LEN as usize;
+    /// Signature size in bytes of the 256f parameter sets.
+    pub const SIG_SIZE_256F: usize = sys::WC_SLHDSA_SHAKE256F_SIG_LEN as usize;
+
+    /// Maximum context string length in bytes.
+    pub const MAX_CTX_SIZE: usize = 255;
+
+    /// Generate a new SLH-DSA key pair using an RNG.
+    ///
+    /// # Parameters
+    ///
+    /// * `param`: Parameter set, one of the `SlhDsa::SHAKE_*` or
+    ///   `SlhDsa::SHA2_*` constants.
+    /// * `rng`: `RNG` instance to use for random number generation.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(SlhDsa) containing the key instance or Err(e)
+    /// containing the wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(all(slhdsa, slhdsa_make_key, random))]
+    /// {
+    /// use wolfssl_wolfcrypt::random::RNG;
+    /// use wolfssl_wolfcrypt::slhdsa::SlhDsa;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let key = SlhDsa::generate(SlhDsa::SHAKE_128F, &rng, &wolfcrypt)
+    ///     .expect("Error with generate()");
+    /// }
+    /// ```
+    #[cfg(all(slhdsa_make_key, random))]
+    pub fn generate(param: u32, rng: &RNG, wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::generate_ex(param, rng, &crate::Env::new(wolfcrypt))
+    }
+
+    /// Generate a new SLH-DSA key pair with optional heap hint and device ID.
+    ///
+    /// # Parameters
+    ///
+    /// * `param`: Parameter set, one of the `SlhDsa::SHAKE_*` or
+    ///   `SlhDsa::SHA2_*` constants.
+    /// * `rng`: `RNG` instance to use for random number generation.
+    /// * `env`: Heap hint and crypto callback device to use.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(SlhDsa) containing the key instance or Err(e)
+    /// containing the wolfSSL library error code value.
+    #[cfg(all(slhdsa_make_key, random))]
+    pub fn generate_ex(
+        param: u32,
+        rng: &RNG,
+        env: &crate::Env,
+    ) -> Result<Self, Error> {
+        let mut key = Self::new_ex(param, env)?;
+        let rc = unsafe { sys::wc_SlhDsaKey_MakeKey(&mut key.ws_key, rng.wc_rng) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(key)
+    }
+
+    /// Generate an SLH-DSA key pair from fixed seeds (FIPS 205
+    /// `slh_keygen_internal`).
+    ///
+    /// Produces the same key pair for the same parameter set and seeds.
+    ///
+    /// # Parameters
+    ///
+    /// * `param`: Parameter set, one of the `SlhDsa::SHAKE_*` or
+    ///   `SlhDsa::SHA2_*` constants.
+    /// * `sk_seed`: Secret seed, `n` bytes (half the public key size).
+    /// * `sk_prf`: PRF key, `n` bytes.
+    /// * `pk_seed`: Public seed, `n` bytes.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(SlhDsa) containing the key instance or Err(e)
+    /// containing the wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(all(slhdsa, slhdsa_make_key))]
+    /// {
+    /// use wolfssl_wolfcrypt::slhdsa::SlhDsa;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let key = SlhDsa::generate_from_seed(SlhDsa::SHAKE_128F,
+    ///     &[1u8; 16], &[2u8; 16], &[3u8; 16], &wolfcrypt)
+    ///     .expect("Error with generate_from_seed()");
+    /// }
+    /// ```
+    #[cfg(slhdsa_make_key)]
+    pub fn generate_from_seed(
+        param: u32,
+        sk_seed: &[u8],
+        sk_prf: &[u8],
+        pk_seed: &[u8],
+        wolfcrypt: &crate::WolfCrypt,
+    ) -> Result<Self, Error> {
+        let sk_seed_len = crate::buffer_len_to_u32(sk_seed.len())?;
+        let sk_prf_len = crate::buffer_len_to_u32(sk_prf.len())?;
+        let pk_seed_len = crate::buffer_len_to_u32(pk_seed.len())?;
+        let mut key = Self::new(param, wolfcrypt)?;
+        let rc = unsafe {
+            sys::wc_SlhDsaKey_MakeKeyWithRandom(
+                &mut key.ws_key,
+                sk_seed.as_ptr(), sk_seed_len,
+                sk_prf.as_ptr(), sk_prf_len,
+                pk_seed.as_ptr(), pk_seed_len,
+            )
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(key)
+    }
+
+    /// Create and initialize a new SLH-DSA key instance without key material.
+    ///
+    /// Key material can then be loaded with [`SlhDsa::import_public()`] or
+    /// [`SlhDsa::import_private()`].
+    ///
+    /// # Parameters
+    ///
+    /// * `param`: Parameter set, one of the `SlhDsa::SHAKE_*` or
+    ///   `SlhDsa::SHA2_*` constants.
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(SlhDsa) containing the key instance or Err(e)
+    /// containing the wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(slhdsa)]
+    /// {
+    /// use wolfssl_wolfcrypt::slhdsa::SlhDsa;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let key = SlhDsa::new(SlhDsa::SHAKE_128S, &wolfcrypt).expect("Error with new()");
+    /// }
+    /// ```
+    pub fn new(param: u32, wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(param, &crate::Env::new(wolfcrypt))
+    }
+
+    /// Create and initialize a new SLH-DSA key instance with optional heap
+    /// hint and device ID.
+    ///
+    /// # Parameters
+    ///
+    /// * `param`: Parameter set, one of the `SlhDsa::SHAKE_*` or
+    ///   `SlhDsa::SHA2_*` constants.
+    /// * `env`: Heap hint and crypto callback device to use.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(SlhDsa) containing the key instance or Err(e)
+    /// containing the wolfSSL library error code value.
+    pub fn new_ex(
+        param: u32,
+        env: &crate::Env,
+    ) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let mut ws_key: MaybeUninit<sys::SlhDsaKey> = MaybeUninit::uninit();
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
+        let rc = unsafe { sys::wc_SlhDsaKey_Init(ws_key.as_mut_ptr(), param, heap, dev_id) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        let ws_key = unsafe { ws_key.assume_init() };
+        Ok(SlhDsa { ws_key, param, _wolfcrypt: wolfcrypt, _device: env.device_ref(), _heap: PhantomData })
+    }
+
+    /// Get the parameter set of this key.
+    pub fn param(&self) -> u32 {
+        self.param
+    }
+
+    /// Get a mutable pointer to the key for wolfCrypt functions that take a
+    /// non-const key but only read it.
+    fn ws_key_ptr(&self) -> *mut sys::SlhDsaKey {
+        &self.ws_key as *const sys::SlhDsaKey as *mut sys::SlhDsaKey
+    }
+
+    /// Get the private key size in bytes.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) or Err(e) containing the wolfSSL library
+    /// error code value.
+    #[cfg(slhdsa_make_key)]
+    pub fn priv_size(&self) -> Result<usize, Error> {
+        let rc = unsafe { sys::wc_SlhDsaKey_PrivateSize(self.ws_key_ptr()) };
+        if rc < 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(rc as usize)
+    }
+
+    /// Get the public key size in bytes.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) or Err(e) containing the wolfSSL library
+    /// error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(slhdsa)]
+    /// {
+    /// use wolfssl_wolfcrypt::slhdsa::SlhDsa;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let key = SlhDsa::new(SlhDsa::SHAKE_192F, &wolfcrypt).expect("Error with new()");
+    /// assert_eq!(key.pub_size().unwrap(), SlhDsa::PUB_KEY_SIZE_192);
+    /// }
+    /// ```
+    pub fn pub_size(&self) -> Result<usize, Error> {
+        let rc = unsafe { sys::wc_SlhDsaKey_PublicSize(self.ws_key_ptr()) };
+        if rc < 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(rc as usize)
+    }
+
+    /// Get the signature size in bytes.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) or Err(e) containing the wolfSSL library
+    /// error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(slhdsa)]
+    /// {
+    /// use wolfssl_wolfcrypt::slhdsa::SlhDsa;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let key = SlhDsa::new(SlhDsa::SHAKE_192F, &wolfcrypt).expect("Error with new()");
+    /// assert_eq!(key.sig_size().unwrap(), SlhDsa::SIG_SIZE_192F);
+    /// }
+    /// ```
+    pub fn sig_size(&self) -> Result<usize, Error> {
+        let rc = unsafe { sys::wc_SlhDsaKey_SigSize(self.ws_key_ptr()) };
+        if rc < 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(rc as usize)
+    }
+
+    /// Check that the public key root matches the private key seeds.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) if the key is consistent or Err(e) containing
+    /// the wolfSSL library error code value.
+    #[cfg(slhdsa_make_key)]
+    pub fn check_key(&mut self) -> Result<(), Error> {
+        let rc = unsafe { sys::wc_SlhDsaKey_CheckKey(&mut self.ws_key) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Import a raw public key (`PK.seed || PK.root`).
+    ///
+    /// # Parameters
+    ///
+    /// * `public`: Raw public key. Must be `pub_size()` bytes.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn import_public(&mut self, public: &[u8]) -> Result<(), Error> {
+        let public_size = crate::buffer_len_to_u32(public.len())?;
+        let rc = unsafe {
+            sys::wc_SlhDsaKey_ImportPublic(&mut self.ws_key, public.as_ptr(), public_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Import a raw private key (`SK.seed || SK.prf || PK.seed || PK.root`).
+    ///
+    /// The private key includes the public key, so the key can both sign
+    /// and verify afterwards.
+    ///
+    /// # Parameters
+    ///
+    /// * `private`: Raw private key. Must be `priv_size()` bytes.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    #[cfg(slhdsa_make_key)]
+    pub fn import_private(&mut self, private: &[u8]) -> Result<(), Error> {
+        let private_size = crate::buffer_len_to_u32(private.len())?;
+        let rc = unsafe {
+            sys::wc_SlhDsaKey_ImportPrivate(&mut self.ws_key, private.as_ptr(), private_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Export the raw public key.
+    ///
+    /// # Parameters
+    ///
+    /// * `public`: Output buffer. Must be at least `pub_size()` bytes.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written or
+    /// Err(e) containing the wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(all(slhdsa, slhdsa_make_key, random))]
+    /// {
+    /// use wolfssl_wolfcrypt::random::RNG;
+    /// use wolfssl_wolfcrypt::slhdsa::SlhDsa;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let key = SlhDsa::generate(SlhDsa::SHAKE_128F, &rng, &wolfcrypt)
+    ///     .expect("Error with generate()");
+    /// let mut pub_buf = [0u8; SlhDsa::PUB_KEY_SIZE_128];
+    /// key.export_public(&mut pub_buf).expect("Error with export_public()");
+    /// let mut key2 = SlhDsa::new(SlhDsa::SHAKE_128F, &wolfcrypt).expect("Error with new()");
+    /// key2.import_public(&pub_buf).expect("Error with import_public()");
+    /// }
+    /// ```
+    pub fn export_public(&self, public: &mut [u8]) -> Result<usize, Error> {
+        let mut public_size = crate::buffer_len_to_u32(public.len())?;
+        let rc = unsafe {
+            sys::wc_SlhDsaKey_ExportPublic(self.ws_key_ptr(), public.as_mut_ptr(), &mut public_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(public_size as usize)
+    }
+
+    /// Export the raw private key.
+    ///
+    /// # Parameters
+    ///
+    /// * `private`: Output buffer. Must be at least `priv_size()` bytes.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written or
+    /// Err(e) containing the wolfSSL library error code value.
+    #[cfg(slhdsa_make_key)]
+    pub fn export_private(&self, private: &mut [u8]) -> Result<usize, Error> {
+        let mut private_size = crate::buffer_len_to_u32(private.len())?;
+        let rc = unsafe {
+            sys::wc_SlhDsaKey_ExportPrivate(self.ws_key_ptr(), private.as_mut_ptr(), &mut private_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(private_size as usize)
+    }
+
+    /// Check a context string length and convert it for wolfCrypt.
+    fn ctx_len(ctx: &[u8]) -> Result<u8, Error> {
+        u8::try_from(ctx.len()).map_err(|_| Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E))
+    }
+
+    /// Check that caller-supplied signing randomness is `n` bytes.
+    #[cfg(slhdsa_make_key)]
+    fn check_add_rnd(&self, add_rnd: &[u8]) -> Result<(), Error> {
+        if add_rnd.len() * 2 != self.pub_size()? {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_LENGTH_E));
+        }
+        Ok(())
+    }
+
+    /// Map a wolfCrypt verify return code to a verification result.
+    fn verify_result(rc: i32) -> Result<bool, Error> {
+        if rc == 0 {
+            Ok(true)
+        } else if rc == sys::wolfCrypt_ErrorCodes_SIG_VERIFY_E {
+            Ok(false)
+        } else {
+            Err(Error::from(rc))
+        }
+    }
+
+    /// Sign a message (hedged, empty context string).
+    ///
+    /// # Parameters
+    ///
+    /// * `msg`: Message to sign.
+    /// * `sig`: Output buffer. Must be at least `sig_size()` bytes.
+    /// * `rng`: RNG instance providing the signing randomness.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to `sig`
+    /// on success or Err(e) containing the wolfSSL library error code value.
+    #[cfg(all(slhdsa_make_key, random))]
+    pub fn sign_msg(&mut self, msg: &[u8], sig: &mut [u8], rng: &RNG) -> Result<usize, Error> {
+        self.sign_ctx_msg(&[], msg, sig, rng)
+    }
+
+    /// Sign a message with a context string (hedged).
+    ///
+    /// # Parameters
+    ///
+    /// * `ctx`: Context string (at most 255 bytes).
+    /// * `msg`: Message to sign.
+    /// * `sig`: Output buffer. Must be at least `sig_size()` bytes.
+    /// * `rng`: RNG instance providing the signing randomness. For
+    ///   deterministic signing use [`SlhDsa::sign_ctx_msg_deterministic()`].
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to `sig`
+    /// on success or Err(e) containing the wolfSSL library error code value.
+    #[cfg(all(slhdsa_make_key, random))]
+    pub fn sign_ctx_msg(
+        &mut self,
+        ctx: &[u8],
+        msg: &[u8],
+        sig: &mut [u8],
+        rng: &RNG,
+    ) -> Result<usize, Error> {
+        let ctx_len = Self::ctx_len(ctx)?;
+        let msg_len = crate::buffer_len_to_u32(msg.len())?;
+        let mut sig_len = crate::buffer_len_to_u32(sig.len())?;
+        let rc = unsafe {
+            sys::wc_SlhDsaKey_Sign(
+                &mut self.ws_key,
+                ctx.as_ptr(), ctx_len,
+                msg.as_ptr(), msg_len,
+                sig.as_mut_ptr(), &mut sig_len,
+                rng.wc_rng,
+            )
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(sig_len as usize)
+    }
+
+    /// Sign a message with a context string deterministically.
+    ///
+    /// The signing randomness is replaced by `PK.seed`, so the same key,
+    /// context and message always give the same signature.
+    ///
+    /// # Parameters
+    ///
+    /// * `ctx`: Context string (at most 255 bytes).
+    /// * `msg`: Message to sign.
+    /// * `sig`: Output buffer. Must be at least `sig_size()` bytes.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to `sig`
+    /// on success or Err(e) containing the wolfSSL library error code value.
+    #[cfg(slhdsa_make_key)]
+    pub fn sign_ctx_msg_deterministic(
+        &mut self,
+        ctx: &[u8],
+        msg: &[u8],
+        sig: &mut [u8],
+    ) -> Result<usize, Error> {
+        let ctx_len = Self::ctx_len(ctx)?;
+        let msg_len = crate::buffer_len_to_u32(msg.len())?;
+        let mut sig_len = crate::buffer_len_to_u32(sig.len())?;
+        let rc = unsafe {
+            sys::wc_SlhDsaKey_SignDeterministic(
+                &mut self.ws_key,
+                ctx.as_ptr(), ctx_len,
+                msg.as_ptr(), msg_len,
+                sig.as_mut_ptr(), &mut sig_len,
+            )
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(sig_len as usize)
+    }
+
+    /// Sign a message with a context string using caller-supplied signing
+    /// randomness.
+    ///
+    /// # Parameters
+    ///
+    /// * `ctx`: Context string (at most 255 bytes).
+    /// * `msg`: Message to sign.
+    /// * `sig`: Output buffer. Must be at least `sig_size()` bytes.
+    /// * `add_rnd`: Signing randomness (`opt_rand`), `n` bytes (half the
+    ///   public key size).
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to `sig`
+    /// on success or Err(e) containing the wolfSSL library error code value.
+    #[cfg(slhdsa_make_key)]
+    pub fn sign_ctx_msg_with_random(
+        &mut self,
+        ctx: &[u8],
+        msg: &[u8],
+        sig: &mut [u8],
+        add_rnd: &[u8],
+    ) -> Result<usize, Error> {
+        self.check_add_rnd(add_rnd)?;
+        let ctx_len = Self::ctx_len(ctx)?;
+        let msg_len = crate::buffer_len_to_u32(msg.len())?;
+        let mut sig_len = crate::buffer_len_to_u32(sig.len())?;
+        let rc = unsafe {
+            sys::wc_SlhDsaKey_SignWithRandom(
+                &mut self.ws_key,
+                ctx.as_ptr(), ctx_len,
+                msg.as_ptr(), msg_len,
+                sig.as_mut_ptr(), &mut sig_len,
+                add_rnd.as_ptr(),
+            )
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(sig_len as usize)
+    }
+
+    /// Sign a pre-hashed message with a context string (HashSLH-DSA,
+    /// hedged).
+    ///
+    /// # Parameters
+    ///
+    /// * `ctx`: Context string (at most 255 bytes).
+    /// * `hash_alg`: Hash algorithm of `hash` (e.g.
+    ///   `sys::wc_HashType_WC_HASH_TYPE_SHA256`).
+    /// * `hash`: Digest of the message; its length must match `hash_alg`.
+    /// * `sig`: Output buffer. Must be at least `sig_size()` bytes.
+    /// * `rng`: RNG instance providing the signing randomness.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to `sig`
+    /// on success or Err(e) containing the wolfSSL library error code value.
+    #[cfg(all(slhdsa_make_key, random))]
+    pub fn sign_ctx_hash(
+        &mut self,
+        ctx: &[u8],
+        hash_alg: u32,
+        hash: &[u8],
+        sig: &mut [u8],
+        rng: &RNG,
+    ) -> Result<usize, Error> {
+        let ctx_len = Self::ctx_len(ctx)?;
+        let hash_len = crate::buffer_len_to_u32(hash.len())?;
+        let mut sig_len = crate::buffer_len_to_u32(sig.len())?;
+        let rc = unsafe {
+            sys::wc_SlhDsaKey_SignHash(
+                &mut self.ws_key,
+                ctx.as_ptr(), ctx_len,
+                hash.as_ptr(), hash_len,
+                hash_alg,
+                sig.as_mut_ptr(), &mut sig_len,
+                rng.wc_rng,
+            )
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(sig_len as usize)
+    }
+
+    /// Sign a pre-hashed message with a context string deterministically
+    /// (HashSLH-DSA).
+    ///
+    /// # Parameters
+    ///
+    /// * `ctx`: Context string (at most 255 bytes).
+    /// * `hash_alg`: Hash algorithm of `hash`.
+    /// * `hash`: Digest of the message; its length must match `hash_alg`.
+    /// * `sig`: Output buffer. Must be at least `sig_size()` bytes.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to `sig`
+    /// on success or Err(e) containing the wolfSSL library error code value.
+    #[cfg(slhdsa_make_key)]
+    pub fn sign_ctx_hash_deterministic(
+        &mut self,
+        ctx: &[u8],
+        hash_alg: u32,
+        hash: &[u8],
+        sig: &mut [u8],
+    ) -> Result<usize, Error> {
+        let ctx_len = Self::ctx_len(ctx)?;
+        let hash_len = crate::buffer_len_to_u32(hash.len())?;
+        let mut sig_len = crate::buffer_len_to_u32(sig.len())?;
+        let rc = unsafe {
+            sys::wc_SlhDsaKey_SignHashDeterministic(
+                &mut self.ws_key,
+                ctx.as_ptr(), ctx_len,
+                hash.as_ptr(), hash_len,
+                hash_alg,
+                sig.as_mut_ptr(), &mut sig_len,
+            )
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(sig_len as usize)
+    }
+
+    /// Sign a pre-hashed message with a context string using caller-supplied
+    /// signing randomness (HashSLH-DSA).
+    ///
+    /// # Parameters
+    ///
+    /// * `ctx`: Context string (at most 255 bytes).
+    /// * `hash_alg`: Hash algorithm of `hash`.
+    /// * `hash`: Digest of the message; its length must match `hash_alg`.
+    /// * `sig`: Output buffer. Must be at least `sig_size()` bytes.
+    /// * `add_rnd`: Signing randomness, `n` bytes (half the public key size).
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to `sig`
+    /// on success or Err(e) containing the wolfSSL library error code value.
+    #[cfg(slhdsa_make_key)]
+    pub fn sign_ctx_hash_with_random(
+        &mut self,
+        ctx: &[u8],
+        hash_alg: u32,
+        hash: &[u8],
+        sig: &mut [u8],
+        add_rnd: &[u8],
+    ) -> Result<usize, Error> {
+        self.check_add_rnd(add_rnd)?;
+        let ctx_len = Self::ctx_len(ctx)?;
+        let hash_len = crate::buffer_len_to_u32(hash.len())?;
+        let mut sig_len = crate::buffer_len_to_u32(sig.len())?;
+        let rc = unsafe {
+            sys::wc_SlhDsaKey_SignHashWithRandom(
+                &mut self.ws_key,
+                ctx.as_ptr(), ctx_len,
+                hash.as_ptr(), hash_len,
+                hash_alg,
+                sig.as_mut_ptr(), &mut sig_len,
+                add_rnd.as_ptr(),
+            )
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(sig_len as usize)
+    }
+
+    /// Sign an already formatted message `M'` deterministically using the
+    /// FIPS 205 internal interface (`slh_sign_internal`).
+    ///
+    /// For pure SLH-DSA, `M' = 0x00 || len(ctx) || ctx || msg`. This is
+    /// intended for protocols and test vectors that build `M'` themselves.
+    ///
+    /// # Parameters
+    ///
+    /// * `mprime`: Formatted message.
+    /// * `sig`: Output buffer. Must be at least `sig_size()` bytes.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to `sig`
+    /// on success or Err(e) containing the wolfSSL library error code value.
+    #[cfg(slhdsa_make_key)]
+    pub fn sign_internal_deterministic(&mut self, mprime: &[u8], sig: &mut [u8]) -> Result<usize, Error> {
+        let mprime_len = crate::buffer_len_to_u32(mprime.len())?;
+        let mut sig_len = crate::buffer_len_to_u32(sig.len())?;
+        let rc = unsafe {
+            sys::wc_SlhDsaKey_SignMsgDeterministic(
+                &mut self.ws_key,
+                mprime.as_ptr(), mprime_len,
+                sig.as_mut_ptr(), &mut sig_len,
+            )
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(sig_len as usize)
+    }
+
+    /// Sign an already formatted message `M'` with caller-supplied signing
+    /// randomness using the FIPS 205 internal interface.
+    ///
+    /// # Parameters
+    ///
+    /// * `mprime`: Formatted message.
+    /// * `sig`: Output buffer. Must be at least `sig_size()` bytes.
+    /// * `add_rnd`: Signing randomness, `n` bytes (half the public key size).
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to `sig`
+    /// on success or Err(e) containing the wolfSSL library error code value.
+    #[cfg(slhdsa_make_key)]
+    pub fn sign_internal_with_random(
+        &mut self,
+        mprime: &[u8],
+        sig: &mut [u8],
+        add_rnd: &[u8],
+    ) -> Result<usize, Error> {
+        self.check_add_rnd(add_rnd)?;
+        let mprime_len = crate::buffer_len_to_u32(mprime.len())?;
+        let mut sig_len = crate::buffer_len_to_u32(sig.len())?;
+        let rc = unsafe {
+            sys::wc_SlhDsaKey_SignMsgWithRandom(
+                &mut self.ws_key,
+                mprime.as_ptr(), mprime_len,
+                sig.as_mut_ptr(), &mut sig_len,
+                add_rnd.as_ptr(),
+            )
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(sig_len as usize)
+    }
+
+    /// Verify a message signature made with an empty context string.
+    ///
+    /// # Parameters
+    ///
+    /// * `sig`: Signature to verify.
+    /// * `msg`: Message the signature was created over.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(true) if the signature is valid, Ok(false) if it is
+    /// invalid, or Err(e) containing the wolfSSL library error code value.
+    pub fn verify_msg(&mut self, sig: &[u8], msg: &[u8]) -> Result<bool, Error> {
+        self.verify_ctx_msg(sig, &[], msg)
+    }
+
+    /// Verify a message signature made with a context string.
+    ///
+    /// # Parameters
+    ///
+    /// * `sig`: Signature to verify.
+    /// * `ctx`: Context string used when signing.
+    /// * `msg`: Message the signature was created over.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(true) if the signature is valid, Ok(false) if it is
+    /// invalid, or Err(e) containing the wolfSSL library error code value.
+    pub fn verify_ctx_msg(&mut self, sig: &[u8], ctx: &[u8], msg: &[u8]) -> Result<bool, Error> {
+        let ctx_len = Self::ctx_len(ctx)?;
+        let msg_len = crate::buffer_len_to_u32(msg.len())?;
+        let sig_len = crate::buffer_len_to_u32(sig.len())?;
+        let rc = unsafe {
+            sys::wc_SlhDsaKey_Verify(
+                &mut self.ws_key,
+                ctx.as_ptr(), ctx_len,
+                msg.as_ptr(), msg_len,
+                sig.as_ptr(), sig_len,
+            )
+        };
+        Self::verify_result(rc)
+    }
+
+    /// Verify a pre-hashed message signature made with a context string
+    /// (HashSLH-DSA).
+    ///
+    /// # Parameters
+    ///
+    /// * `sig`: Signature to verify.
+    /// * `ctx`: Context string used when signing.
+    /// * `hash_alg`: Hash algorithm of `hash`.
+    /// * `hash`: Digest of the message.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(true) if the signature is valid, Ok(false) if it is
+    /// invalid, or Err(e) containing the wolfSSL library error code value.
+    pub fn verify_ctx_hash(
+        &mut self,
+        sig: &[u8],
+        ctx: &[u8],
+        hash_alg: u32,
+        hash: &[u8],
+    ) -> Result<bool, Error> {
+        let ctx_len = Self::ctx_len(ctx)?;
+        let hash_len = crate::buffer_len_to_u32(hash.len())?;
+        let sig_len = crate::buffer_len_to_u32(sig.len())?;
+        let rc = unsafe {
+            sys::wc_SlhDsaKey_VerifyHash(
+                &mut self.ws_key,
+                ctx.as_ptr(), ctx_len,
+                hash.as_ptr(), hash_len,
+                hash_alg,
+                sig.as_ptr(), sig_len,
+            )
+        };
+        Self::verify_result(rc)
+    }
+
+    /// Verify a signature over an already formatted message `M'` using the
+    /// FIPS 205 internal interface (`slh_verify_internal`).
+    ///
+    /// # Parameters
+    ///
+    /// * `sig`: Signature to verify.
+    /// * `mprime`: Formatted message.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(true) if the signature is valid, Ok(false) if it is
+    /// invalid, or Err(e) containing the wolfSSL library error code value.
+    pub fn verify_internal(&mut self, sig: &[u8], mprime: &[u8]) -> Result<bool, Error> {
+        let mprime_len = crate::buffer_len_to_u32(mprime.len())?;
+        let sig_len = crate::buffer_len_to_u32(sig.len())?;
+        let rc = unsafe {
+            sys::wc_SlhDsaKey_VerifyMsg(
+                &mut self.ws_key,
+                mprime.as_ptr(), mprime_len,
+                sig.as_ptr(), sig_len,
+            )
+        };
+        Self::verify_result(rc)
+    }
+}
+
+impl<'a> SlhDsa<'a> {
+    fn zeroize(&mut self) {
+        unsafe { crate::zeroize_raw(&mut self.ws_key); }
+    }
+}
+
+impl Drop for SlhDsa<'_> {
+    /// Safely free the underlying wolfSSL SLH-DSA key context.
+    ///
+    /// This calls `wc_SlhDsaKey_Free()`. The Rust Drop trait guarantees this
+    /// is called when the `SlhDsa` struct goes out of scope.
+    fn drop(&mut self) {
+        unsafe { sys::wc_SlhDsaKey_Free(&mut self.ws_key); }
+        self.zeroize();
+    }
+}
+
+/// RustCrypto `signature` crate trait implementations.
+///
+/// SLH-DSA signature sizes depend on the parameter set, so [`Signature`] is
+/// generic over its size `N` (one of the `SlhDsa::SIG_SIZE_*` constants);
+/// signing or verifying with a size that does not match the key's parameter
+/// set fails. Signing through [`signature::SignerMut`] is deterministic and
+/// uses an empty context string.
+#[cfg(feature = "signature")]
+mod signature_impl {
+    use super::SlhDsa;
+    use signature::Error;
+
+    /// SLH-DSA signature of `N` bytes.
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct Signature<const N: usize>([u8; N]);
+
+    impl<const N: usize> Signature<N> {
+        /// Construct a signature from its raw bytes.
+        pub const fn from_bytes(bytes: [u8; N]) -> Self {
+            Self(bytes)
+        }
+
+        /// Return the raw signature bytes.
+        pub const fn to_bytes(&self) -> [u8; N] {
+            self.0
+        }
+    }
+
+    impl<const N: usize> AsRef<[u8]> for Signature<N> {
+        fn as_ref(&self) -> &[u8] {
+            &self.0
+        }
+    }
+
+    impl<const N: usize> TryFrom<&[u8]> for Signature<N> {
+        type Error = Error;
+        fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+            let arr: [u8; N] = bytes.try_into().map_err(|_| Error::new())?;
+            Ok(Self(arr))
+        }
+    }
+
+    impl<const N: usize> From<Signature<N>> for [u8; N] {
+        fn from(sig: Signature<N>) -> Self {
+            sig.0
+        }
+    }
+
+    impl<const N: usize> signature::SignatureEncoding for Signature<N> {
+        type Repr = [u8; N];
+    }
+
+    /// SLH-DSA verifying (public) key.
+    ///
+    /// Owns the parameter set and a copy of the raw public key, and
+    /// instantiates a short-lived wolfCrypt `SlhDsaKey` on each verification.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub struct VerifyingKey {
+        param: u32,
+        key: [u8; SlhDsa::PUB_KEY_SIZE_256],
+        len: usize,
+    }
+
+    impl VerifyingKey {
+        /// Construct a verifying key from a parameter set and raw public key.
+        pub fn from_bytes(param: u32, bytes: &[u8]) -> Result<Self, Error> {
+            let expected = crate::WolfCrypt::global()
+                .and_then(|wolfcrypt| SlhDsa::new(param, wolfcrypt))
+                .and_then(|k| k.pub_size())
+                .map_err(|_| Error::new())?;
+            if bytes.len() != expected {
+                return Err(Error::new());
+            }
+            let mut key = [0u8; SlhDsa::PUB_KEY_SIZE_256];
+            key[..bytes.len()].copy_from_slice(bytes);
+            Ok(Self { param, key, len: bytes.len() })
+        }
+
+        /// Return the parameter set.
+        pub fn param(&self) -> u32 {
+            self.param
+        }
+
+        /// Return the raw public key bytes.
+        pub fn as_bytes(&self) -> &[u8] {
+            &self.key[..self.len]
+        }
+    }
+
+    impl AsRef<[u8]> for VerifyingKey {
+        fn as_ref(&self) -> &[u8] {
+            self.as_bytes()
+        }
+    }
+
+    impl signature::Keypair for SlhDsa<'_> {
+        type VerifyingKey = VerifyingKey;
+        fn verifying_key(&self) -> Self::VerifyingKey {
+            let mut key = [0u8; SlhDsa::PUB_KEY_SIZE_256];
+            let len = self.export_public(&mut key).expect("slhdsa export_public failed");
+            VerifyingKey { param: self.param, key, len }
+        }
+    }
+
+    #[cfg(slhdsa_make_key)]
+    impl<const N: usize> signature::SignerMut<Signature<N>> for SlhDsa<'_> {
+        fn try_sign(&mut self, msg: &[u8]) -> Result<Signature<N>, Error> {
+            if self.sig_size().map_err(|_| Error::new())? != N {
+                return Err(Error::new());
+            }
+            let mut sig = [0u8; N];
+            self.sign_ctx_msg_deterministic(&[], msg, &mut sig)
+                .map_err(|_| Error::new())?;
+            Ok(Signature(sig))
+        }
+    }
+
+    impl<const N: usize> signature::Verifier<Signature<N>> for VerifyingKey {
+        fn verify(&self, msg: &[u8], signature: &Signature<N>) -> Result<(), Error> {
+            let wolfcrypt = crate::WolfCrypt::global().map_err(|_| Error::new())?;
+            let mut key = SlhDsa::new(self.param, wolfcrypt).map_err(|_| Error::new())?;
+            key.import_public(self.as_bytes()).map_err(|_| Error::new())?;
+            let valid = key
+                .verify_msg(&signature.0, msg)
+                .map_err(|_| Error::new())?;
+            if valid { Ok(()) } else { Err(Error::new()) }
+        }
+    }
+}
+
+#[cfg(feature = "signature")]
+pub use signature_impl::{Signature, VerifyingKey};
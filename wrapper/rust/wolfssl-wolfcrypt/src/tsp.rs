@@ -0,0 +1,958 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+This module provides a Rust wrapper for the wolfCrypt library's RFC 3161
+Time-Stamp Protocol functionality (`wc_Tsp*()`).
+
+A requester builds a [`TimeStampReq`] from the digest of the data to be
+time-stamped (computed with e.g. [`crate::sha::SHA256`]), encodes it and
+sends it to a Time Stamping Authority (TSA). The TSA's reply is decoded as a
+[`TimeStampResp`], whose time-stamp token is verified with
+[`TimeStampResp::verify()`]. This checks the CMS signature of the token and
+that the signer certificate is valid for time-stamping, and returns the
+signed [`TstInfo`]. [`TstInfo::check_request()`] then binds the token to the
+request sent by comparing the message imprint, nonce and policy.
+
+A TSA builds a [`TstInfo`] from a decoded request with
+[`TstInfo::from_request()`], signs it into a token with [`TstInfo::sign()`]
+and returns it in a [`TimeStampResp`].
+
+# Examples
+
+```rust
+#[cfg(all(tsp_requester, tsp_responder, tsp_verifier, tsp_sign, tsp_verify, random, sha256))]
+{
+use std::fs;
+use wolfssl_wolfcrypt::random::RNG;
+use wolfssl_wolfcrypt::sha::SHA256;
+use wolfssl_wolfcrypt::tsp::*;
+use wolfssl_wolfcrypt::WolfCrypt;
+let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+
+let data = b"release-1.0.tar.gz";
+let mut digest = [0u8; SHA256::DIGEST_SIZE];
+let mut sha = SHA256::new(&wolfcrypt).expect("Error with SHA256::new()");
+sha.update(data).expect("Error with update()");
+sha.finalize(&mut digest).expect("Error with finalize()");
+
+let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+let mut req = TimeStampReq::new(TimeStampReq::HASH_SHA256, &digest)
+    .expect("Error with TimeStampReq::new()");
+req.generate_nonce(&rng, 8).expect("Error with generate_nonce()");
+req.set_cert_req(true);
+let mut req_der = [0u8; 256];
+let req_size = req.encode(&mut req_der).expect("Error with encode()");
+
+// TSA side.
+let cert = fs::read("../../../certs/tsa-cert.der").expect("Error reading cert");
+let key = fs::read("../../../certs/tsa-key.der").expect("Error reading key");
+let received = TimeStampReq::decode(&req_der[..req_size]).expect("Error with decode()");
+let policy = [0x2b, 0x06, 0x01, 0x04, 0x01, 0x87, 0x67, 0x01];
+let tst_info = TstInfo::from_request(&received, &policy, &[0x01],
+    Some("20260604120000Z")).expect("Error with from_request()");
+let mut token = [0u8; 4096];
+let token_size = tst_info.sign(&cert, &key, TstInfo::KEY_TYPE_RSA,
+    TimeStampReq::HASH_SHA256, &rng, &mut token).expect("Error with sign()");
+let mut resp = TimeStampResp::new(TimeStampResp::STATUS_GRANTED, None, 0)
+    .expect("Error with TimeStampResp::new()");
+resp.set_token(&token[..token_size]).expect("Error with set_token()");
+let mut resp_der = [0u8; 4096];
+let resp_size = resp.encode(&mut resp_der).expect("Error with encode()");
+
+// Requester side.
+let resp = TimeStampResp::decode(&resp_der[..resp_size]).expect("Error with decode()");
+let tst_info = resp.verify_data(Some(&cert), data).expect("Error with verify_data()");
+tst_info.check_request(&req).expect("Error with check_request()");
+assert_eq!(tst_info.gen_time(), Some("20260604120000Z"));
+}
+```
+*/
+
+#![cfg(tsp)]
+
+#[cfg(random)]
+use crate::random::RNG;
+use crate::Error;
+use crate::sys;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+
+/// Build a slice from a wolfSSL pointer and length pair.
+fn raw_slice<'b>(ptr: *const u8, len: u32) -> Option<&'b [u8]> {
+    if ptr.is_null() || len == 0 {
+        return None;
+    }
+    Some(unsafe { core::slice::from_raw_parts(ptr, len as usize) })
+}
+
+/// Rust wrapper for a wolfSSL `TspRequest` object, an RFC 3161
+/// TimeStampReq.
+///
+/// All fields are copied into the object so it does not borrow from the
+/// values used to build it or from the DER it was decoded from.
+pub struct TimeStampReq {
+    wc_req: sys::TspRequest,
+}
+
+impl TimeStampReq {
+    /// SHA-256 message imprint or token signature hash.
+    pub const HASH_SHA256: i32 = sys::wc_HashType_WC_HASH_TYPE_SHA256 as i32;
+    /// SHA-384 message imprint or token signature hash.
+    pub const HASH_SHA384: i32 = sys::wc_HashType_WC_HASH_TYPE_SHA384 as i32;
+    /// SHA-512 message imprint or token signature hash.
+    pub const HASH_SHA512: i32 = sys::wc_HashType_WC_HASH_TYPE_SHA512 as i32;
+
+    /// Maximum size of a nonce in bytes.
+    pub const MAX_NONCE_SIZE: usize = sys::MAX_TS_NONCE_SZ as usize;
+
+    /// Create a new TimeStampReq for a message digest.
+    ///
+    /// # Parameters
+    ///
+    /// * `hash_type`: Hash algorithm of the digest, one of `HASH_SHA256`,
+    ///   `HASH_SHA384` or `HASH_SHA512`.
+    /// * `hash`: Digest of the data to be time-stamped. Must be the digest
+    ///   size of `hash_type`.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(TimeStampReq) or Err(e) containing the wolfSSL
+    /// library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(all(tsp_requester, sha256))]
+    /// {
+    /// use wolfssl_wolfcrypt::sha::SHA256;
+    /// use wolfssl_wolfcrypt::tsp::TimeStampReq;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut digest = [0u8; SHA256::DIGEST_SIZE];
+    /// let mut sha = SHA256::new(&wolfcrypt).expect("Error with SHA256::new()");
+    /// sha.update(b"data").expect("Error with update()");
+    /// sha.finalize(&mut digest).expect("Error with finalize()");
+    /// let req = TimeStampReq::new(TimeStampReq::HASH_SHA256, &digest)
+    ///     .expect("Error with TimeStampReq::new()");
+    /// assert_eq!(req.hash(), &digest);
+    /// }
+    /// ```
+    #[cfg(tsp_requester)]
+    pub fn new(hash_type: i32, hash: &[u8]) -> Result<Self, Error> {
+        let hash_size = crate::buffer_len_to_u32(hash.len())?;
+        let mut req: MaybeUninit<sys::TspRequest> = MaybeUninit::zeroed();
+        let rc = unsafe { sys::wc_TspRequest_Init(req.as_mut_ptr()) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        let mut req = TimeStampReq { wc_req: unsafe { req.assume_init() } };
+        let rc = unsafe {
+            sys::wc_TspRequest_SetHashType(&mut req.wc_req, hash_type as sys::wc_HashType)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        if req.wc_req.imprint.hashSz != hash_size {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+        }
+        let rc = unsafe {
+            sys::wc_TspRequest_SetHash(&mut req.wc_req, hash.as_ptr(), hash_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(req)
+    }
+
+    /// Decode a DER encoded TimeStampReq, e.g. as received by a TSA.
+    ///
+    /// Requests with extensions are rejected.
+    ///
+    /// # Parameters
+    ///
+    /// * `der`: DER encoded TimeStampReq.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(TimeStampReq) or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn decode(der: &[u8]) -> Result<Self, Error> {
+        let der_size = crate::buffer_len_to_u32(der.len())?;
+        let mut req: MaybeUninit<sys::TspRequest> = MaybeUninit::zeroed();
+        let rc = unsafe {
+            sys::wc_TspRequest_Decode(req.as_mut_ptr(), der.as_ptr(), der_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(TimeStampReq { wc_req: unsafe { req.assume_init() } })
+    }
+
+    /// Encode the TimeStampReq as DER.
+    ///
+    /// # Parameters
+    ///
+    /// * `dout`: Buffer in which to store the DER encoding.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to
+    /// `dout` or Err(e) containing the wolfSSL library error code value.
+    #[cfg(tsp_requester)]
+    pub fn encode(&self, dout: &mut [u8]) -> Result<usize, Error> {
+        let mut dout_size = crate::buffer_len_to_u32(dout.len())?;
+        let rc = unsafe {
+            sys::wc_TspRequest_Encode(&self.wc_req, dout.as_mut_ptr(), &mut dout_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(dout_size as usize)
+    }
+
+    /// Set the nonce of the request.
+    ///
+    /// The nonce is a big-endian number; leading zero bytes are stripped.
+    ///
+    /// # Parameters
+    ///
+    /// * `nonce`: Nonce, at most `MAX_NONCE_SIZE` bytes.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    #[cfg(tsp_requester)]
+    pub fn set_nonce(&mut self, nonce: &[u8]) -> Result<(), Error> {
+        let nonce_size = crate::buffer_len_to_u32(nonce.len())?;
+        let rc = unsafe {
+            sys::wc_TspRequest_SetNonce(&mut self.wc_req, nonce.as_ptr(), nonce_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Set a random nonce of the given size.
+    ///
+    /// # Parameters
+    ///
+    /// * `rng`: `RNG` instance to use to generate the nonce.
+    /// * `size`: Size of the nonce in bytes, at most `MAX_NONCE_SIZE`.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    #[cfg(all(tsp_requester, random))]
+    pub fn generate_nonce(&mut self, rng: &RNG, size: usize) -> Result<(), Error> {
+        let size = crate::buffer_len_to_u32(size)?;
+        let rc = unsafe {
+            sys::wc_TspRequest_GenerateNonce(&mut self.wc_req, rng.wc_rng, size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Set the TSA policy requested.
+    ///
+    /// # Parameters
+    ///
+    /// * `policy`: Policy OID as the content of an OBJECT IDENTIFIER, i.e.
+    ///   without the tag and length bytes.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    #[cfg(tsp_requester)]
+    pub fn set_policy(&mut self, policy: &[u8]) -> Result<(), Error> {
+        let policy_size = crate::buffer_len_to_u32(policy.len())?;
+        let rc = unsafe {
+            sys::wc_TspRequest_SetPolicy(&mut self.wc_req, policy.as_ptr(), policy_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Set whether the TSA certificate is requested in the response token.
+    ///
+    /// # Parameters
+    ///
+    /// * `cert_req`: True to request the TSA certificate.
+    #[cfg(tsp_requester)]
+    pub fn set_cert_req(&mut self, cert_req: bool) {
+        self.wc_req.certReq = cert_req as u8;
+    }
+
+    /// Get the hash algorithm of the message imprint as a `HASH_*` value.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(hash_type) or Err(e) containing the wolfSSL library
+    /// error code value (`HASH_TYPE_E` when the algorithm is not a known
+    /// hash).
+    #[cfg(any(tsp_requester, tsp_verifier))]
+    pub fn hash_type(&self) -> Result<i32, Error> {
+        let mut hash_type: sys::wc_HashType = 0;
+        let rc = unsafe { sys::wc_TspRequest_GetHashType(&self.wc_req, &mut hash_type) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(hash_type as i32)
+    }
+
+    /// Get the hash of the message imprint.
+    pub fn hash(&self) -> &[u8] {
+        &self.wc_req.imprint.hash[..self.wc_req.imprint.hashSz as usize]
+    }
+
+    /// Get the nonce, if set.
+    pub fn nonce(&self) -> Option<&[u8]> {
+        raw_slice(self.wc_req.nonce.as_ptr(), self.wc_req.nonceSz)
+    }
+
+    /// Get the requested TSA policy OID content, if set.
+    pub fn policy(&self) -> Option<&[u8]> {
+        raw_slice(self.wc_req.policy.as_ptr(), self.wc_req.policySz)
+    }
+
+    /// Check whether the TSA certificate is requested in the response token.
+    pub fn cert_req(&self) -> bool {
+        self.wc_req.certReq != 0
+    }
+}
+
+/// Rust wrapper for a wolfSSL `TspTstInfo` object, the RFC 3161 TSTInfo
+/// signed by a TSA.
+///
+/// The lifetime `'a` covers the values referenced (but not copied) by the
+/// underlying C object: the request, policy, serial, time and TSA name
+/// given when building it, or the token it was decoded from.
+pub struct TstInfo<'a> {
+    wc_tst: sys::TspTstInfo,
+    _phantom: PhantomData<&'a [u8]>,
+}
+
+impl<'a> TstInfo<'a> {
+    /// RSA TSA signing key for `sign()`.
+    pub const KEY_TYPE_RSA: i32 = sys::wc_PkType_WC_PK_TYPE_RSA as i32;
+    /// ECC TSA signing key for `sign()`.
+    pub const KEY_TYPE_ECC: i32 = sys::wc_PkType_WC_PK_TYPE_ECDSA_SIGN as i32;
+
+    /// Create a TSTInfo responding to a request.
+    ///
+    /// The message imprint and nonce of the request are echoed.
+    ///
+    /// # Parameters
+    ///
+    /// * `req`: Decoded request being time-stamped.
+    /// * `policy`: TSA policy OID as the content of an OBJECT IDENTIFIER.
+    /// * `serial`: Serial number of the time-stamp as a big-endian number,
+    ///   unique for each time-stamp issued by the TSA.
+    /// * `gen_time`: Time of the time-stamp as a GeneralizedTime string
+    ///   (`YYYYMMDDhhmmss[.s...]Z`), or None to use the current time when
+    ///   encoding.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(TstInfo) or Err(e) containing the wolfSSL library
+    /// error code value.
+    #[cfg(tsp_responder)]
+    pub fn from_request(req: &'a TimeStampReq, policy: &'a [u8], serial: &'a [u8],
+            gen_time: Option<&'a str>) -> Result<Self, Error> {
+        let policy_size = crate::buffer_len_to_u32(policy.len())?;
+        let serial_size = crate::buffer_len_to_u32(serial.len())?;
+        let (gen_time_ptr, gen_time_size) = match gen_time {
+            Some(t) => (t.as_ptr(), crate::buffer_len_to_u32(t.len())?),
+            None => (core::ptr::null(), 0),
+        };
+        let mut tst: MaybeUninit<sys::TspTstInfo> = MaybeUninit::zeroed();
+        let rc = unsafe { sys::wc_TspTstInfo_Init(tst.as_mut_ptr()) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        let mut tst_info = TstInfo {
+            wc_tst: unsafe { tst.assume_init() },
+            _phantom: PhantomData,
+        };
+        let rc = unsafe {
+            sys::wc_TspTstInfo_SetFromRequest(&mut tst_info.wc_tst, &req.wc_req,
+                policy.as_ptr(), policy_size, serial.as_ptr(), serial_size,
+                gen_time_ptr, gen_time_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(tst_info)
+    }
+
+    /// Set the accuracy of the time-stamp.
+    ///
+    /// # Parameters
+    ///
+    /// * `seconds`: Accuracy in seconds, 0 when not present.
+    /// * `millis`: Accuracy in milliseconds, 0 or 1 to 999.
+    /// * `micros`: Accuracy in microseconds, 0 or 1 to 999.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    #[cfg(tsp_responder)]
+    pub fn set_accuracy(&mut self, seconds: u32, millis: u16, micros: u16) -> Result<(), Error> {
+        let rc = unsafe {
+            sys::wc_TspTstInfo_SetAccuracy(&mut self.wc_tst, seconds, millis, micros)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Set whether time-stamps from the TSA are strictly ordered.
+    ///
+    /// # Parameters
+    ///
+    /// * `ordering`: True when time-stamps are strictly ordered.
+    #[cfg(tsp_responder)]
+    pub fn set_ordering(&mut self, ordering: bool) {
+        self.wc_tst.ordering = ordering as u8;
+    }
+
+    /// Set the name of the TSA.
+    ///
+    /// When present, the name must correspond to a subject name of the TSA
+    /// certificate for the token to verify.
+    ///
+    /// # Parameters
+    ///
+    /// * `tsa`: DER encoded GeneralName of the TSA.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    #[cfg(tsp_responder)]
+    pub fn set_tsa(&mut self, tsa: &'a [u8]) -> Result<(), Error> {
+        let tsa_size = crate::buffer_len_to_u32(tsa.len())?;
+        let rc = unsafe {
+            sys::wc_TspTstInfo_SetTsa(&mut self.wc_tst, tsa.as_ptr(), tsa_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Encode the TSTInfo as DER.
+    ///
+    /// # Parameters
+    ///
+    /// * `dout`: Buffer in which to store the DER encoding.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to
+    /// `dout` or Err(e) containing the wolfSSL library error code value.
+    #[cfg(tsp_responder)]
+    pub fn encode(&self, dout: &mut [u8]) -> Result<usize, Error> {
+        let mut dout_size = crate::buffer_len_to_u32(dout.len())?;
+        let rc = unsafe {
+            sys::wc_TspTstInfo_Encode(&self.wc_tst, dout.as_mut_ptr(), &mut dout_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(dout_size as usize)
+    }
+
+    /// Sign the TSTInfo, creating a TimeStampToken: CMS SignedData with
+    /// the TSTInfo as content and a SigningCertificateV2 attribute.
+    ///
+    /// The TSA certificate is included in the token. It must have a
+    /// critical extended key usage of time-stamping only.
+    ///
+    /// # Parameters
+    ///
+    /// * `cert`: DER encoded TSA certificate.
+    /// * `key`: DER encoded TSA private key.
+    /// * `key_type`: Type of `key`, `KEY_TYPE_RSA` or `KEY_TYPE_ECC`.
+    /// * `hash_type`: Signature hash algorithm, one of the
+    ///   `TimeStampReq::HASH_*` values.
+    /// * `rng`: `RNG` instance to use for signing.
+    /// * `dout`: Buffer in which to store the DER encoded token.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to
+    /// `dout` or Err(e) containing the wolfSSL library error code value.
+    #[cfg(all(tsp_sign, random))]
+    pub fn sign(&self, cert: &[u8], key: &[u8], key_type: i32, hash_type: i32,
+            rng: &RNG, dout: &mut [u8]) -> Result<usize, Error> {
+        let cert_size = crate::buffer_len_to_u32(cert.len())?;
+        let key_size = crate::buffer_len_to_u32(key.len())?;
+        let mut dout_size = crate::buffer_len_to_u32(dout.len())?;
+        let rc = unsafe {
+            sys::wc_TspTstInfo_Sign(&self.wc_tst, cert.as_ptr(), cert_size,
+                key.as_ptr(), key_size, key_type as sys::wc_PkType,
+                hash_type as sys::wc_HashType, rng.wc_rng, dout.as_mut_ptr(),
+                &mut dout_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(dout_size as usize)
+    }
+
+    /// Decode a DER encoded TSTInfo.
+    ///
+    /// This does not verify a token signature; use
+    /// [`TimeStampResp::verify()`] to obtain the TSTInfo of a response.
+    ///
+    /// # Parameters
+    ///
+    /// * `der`: DER encoded TSTInfo, referenced by the returned object.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(TstInfo) or Err(e) containing the wolfSSL library
+    /// error code value.
+    #[cfg(tsp_verifier)]
+    pub fn decode(der: &'a [u8]) -> Result<Self, Error> {
+        let der_size = crate::buffer_len_to_u32(der.len())?;
+        let mut tst: MaybeUninit<sys::TspTstInfo> = MaybeUninit::zeroed();
+        let rc = unsafe {
+            sys::wc_TspTstInfo_Decode(tst.as_mut_ptr(), der.as_ptr(), der_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(TstInfo {
+            wc_tst: unsafe { tst.assume_init() },
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Check the TSTInfo matches the request sent: the same message imprint
+    /// and, when set in the request, the same nonce and policy.
+    ///
+    /// # Parameters
+    ///
+    /// * `req`: Request sent to the TSA.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) when the TSTInfo matches or Err(e) containing
+    /// the wolfSSL library error code value (`TSP_VERIFY_E` on a mismatch).
+    #[cfg(tsp_verifier)]
+    pub fn check_request(&self, req: &TimeStampReq) -> Result<(), Error> {
+        let rc = unsafe { sys::wc_TspTstInfo_CheckRequest(&self.wc_tst, &req.wc_req) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Check the TSA name is present and is the expected name.
+    ///
+    /// # Parameters
+    ///
+    /// * `tsa`: Expected DER encoded GeneralName of the TSA.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) when the name matches or Err(e) containing the
+    /// wolfSSL library error code value.
+    #[cfg(tsp_verifier)]
+    pub fn check_tsa_name(&self, tsa: &[u8]) -> Result<(), Error> {
+        let tsa_size = crate::buffer_len_to_u32(tsa.len())?;
+        let rc = unsafe {
+            sys::wc_TspTstInfo_CheckTsaName(&self.wc_tst, tsa.as_ptr(), tsa_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Check the message imprint is the hash of the given data.
+    ///
+    /// # Parameters
+    ///
+    /// * `data`: Data that was time-stamped.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) when the imprint matches or Err(e) containing
+    /// the wolfSSL library error code value.
+    #[cfg(tsp_verifier)]
+    pub fn verify_data(&self, data: &[u8]) -> Result<(), Error> {
+        let data_size = crate::buffer_len_to_u32(data.len())?;
+        let rc = unsafe {
+            sys::wc_TspTstInfo_VerifyData(&self.wc_tst, data.as_ptr(), data_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Check the time of the time-stamp is within `tolerance` seconds of the
+    /// current time.
+    ///
+    /// # Parameters
+    ///
+    /// * `tolerance`: Acceptable difference from the current time in
+    ///   seconds.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) when the time is acceptable or Err(e)
+    /// containing the wolfSSL library error code value.
+    #[cfg(tsp_check_gen_time)]
+    pub fn check_gen_time(&self, tolerance: u32) -> Result<(), Error> {
+        let rc = unsafe { sys::wc_TspTstInfo_CheckGenTime(&self.wc_tst, tolerance) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Get the version, always 1.
+    pub fn version(&self) -> u8 {
+        self.wc_tst.version
+    }
+
+    /// Get the TSA policy OID content.
+    pub fn policy(&self) -> Option<&'a [u8]> {
+        raw_slice(self.wc_tst.policy, self.wc_tst.policySz)
+    }
+
+    /// Get the wolfSSL OID sum of the message imprint hash algorithm.
+    pub fn hash_oid(&self) -> u32 {
+        self.wc_tst.imprint.hashAlgOID
+    }
+
+    /// Get the hash of the message imprint.
+    pub fn hash(&self) -> &[u8] {
+        &self.wc_tst.imprint.hash[..self.wc_tst.imprint.hashSz as usize]
+    }
+
+    /// Get the serial number of the time-stamp as a big-endian number.
+    pub fn serial(&self) -> Option<&'a [u8]> {
+        raw_slice(self.wc_tst.serial, self.wc_tst.serialSz)
+    }
+
+    /// Get the time of the time-stamp as a GeneralizedTime string.
+    pub fn gen_time(&self) -> Option<&'a str> {
+        let gen_time = raw_slice(self.wc_tst.genTime, self.wc_tst.genTimeSz)?;
+        core::str::from_utf8(gen_time).ok()
+    }
+
+    /// Get the time of the time-stamp in seconds since the Unix epoch.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(seconds) or Err(e) containing the wolfSSL library
+    /// error code value.
+    #[cfg(tsp_gen_time_as_time)]
+    pub fn gen_time_unix(&self) -> Result<i64, Error> {
+        let mut t: sys::time_t = 0;
+        let rc = unsafe { sys::wc_TspTstInfo_GetGenTimeAsTime(&self.wc_tst, &mut t) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(t as i64)
+    }
+
+    /// Get the accuracy as (seconds, milliseconds, microseconds), each 0
+    /// when not present.
+    pub fn accuracy(&self) -> (u32, u16, u16) {
+        let accuracy = &self.wc_tst.accuracy;
+        (accuracy.seconds, accuracy.millis, accuracy.micros)
+    }
+
+    /// Check whether time-stamps from the TSA are strictly ordered.
+    pub fn ordering(&self) -> bool {
+        self.wc_tst.ordering != 0
+    }
+
+    /// Get the nonce echoed from the request, if present.
+    pub fn nonce(&self) -> Option<&'a [u8]> {
+        raw_slice(self.wc_tst.nonce, self.wc_tst.nonceSz)
+    }
+
+    /// Get the DER encoded GeneralName of the TSA, if present.
+    pub fn tsa(&self) -> Option<&'a [u8]> {
+        raw_slice(self.wc_tst.tsa, self.wc_tst.tsaSz)
+    }
+}
+
+/// Rust wrapper for a wolfSSL `TspResponse` object, an RFC 3161
+/// TimeStampResp.
+///
+/// The lifetime `'a` covers the status string and token referenced (but
+/// not copied) by the underlying C object, or the DER it was decoded from.
+pub struct TimeStampResp<'a> {
+    wc_resp: sys::TspResponse,
+    _phantom: PhantomData<&'a [u8]>,
+}
+
+impl<'a> TimeStampResp<'a> {
+    /// The time-stamp was granted.
+    pub const STATUS_GRANTED: u32 = sys::TspPkiStatus_WC_TSP_PKISTATUS_GRANTED;
+    /// The time-stamp was granted with modifications.
+    pub const STATUS_GRANTED_WITH_MODS: u32 =
+        sys::TspPkiStatus_WC_TSP_PKISTATUS_GRANTED_WITH_MODS;
+    /// The request was rejected.
+    pub const STATUS_REJECTION: u32 = sys::TspPkiStatus_WC_TSP_PKISTATUS_REJECTION;
+    /// The request has not yet been processed.
+    pub const STATUS_WAITING: u32 = sys::TspPkiStatus_WC_TSP_PKISTATUS_WAITING;
+    /// A certificate revocation is imminent.
+    pub const STATUS_REVOCATION_WARNING: u32 =
+        sys::TspPkiStatus_WC_TSP_PKISTATUS_REVOCATION_WARNING;
+    /// A certificate revocation has occurred.
+    pub const STATUS_REVOCATION_NOTIFICATION: u32 =
+        sys::TspPkiStatus_WC_TSP_PKISTATUS_REVOCATION_NOTIFICATION;
+
+    /// Unrecognized or unsupported algorithm.
+    pub const FAIL_BAD_ALG: u32 = sys::WC_TSP_FAIL_BAD_ALG;
+    /// Transaction not permitted or supported.
+    pub const FAIL_BAD_REQUEST: u32 = sys::WC_TSP_FAIL_BAD_REQUEST;
+    /// The data submitted has the wrong format.
+    pub const FAIL_BAD_DATA_FORMAT: u32 = sys::WC_TSP_FAIL_BAD_DATA_FORMAT;
+    /// The TSA's time source is not available.
+    pub const FAIL_TIME_NOT_AVAILABLE: u32 = sys::WC_TSP_FAIL_TIME_NOT_AVAILABLE;
+    /// The requested TSA policy is not supported.
+    pub const FAIL_UNACCEPTED_POLICY: u32 = sys::WC_TSP_FAIL_UNACCEPTED_POLICY;
+    /// The requested extension is not supported.
+    pub const FAIL_UNACCEPTED_EXTENSION: u32 = sys::WC_TSP_FAIL_UNACCEPTED_EXTENSION;
+    /// The additional information requested is not available.
+    pub const FAIL_ADD_INFO_NOT_AVAILABLE: u32 = sys::WC_TSP_FAIL_ADD_INFO_NOT_AVAILABLE;
+    /// The request cannot be handled due to system failure.
+    pub const FAIL_SYSTEM_FAILURE: u32 = sys::WC_TSP_FAIL_SYSTEM_FAILURE;
+
+    /// Create a new TimeStampResp with the given status and no token.
+    ///
+    /// # Parameters
+    ///
+    /// * `status`: One of the `STATUS_*` values.
+    /// * `status_string`: Optional human readable status text.
+    /// * `fail_info`: `FAIL_*` value when the request was rejected, 0
+    ///   otherwise.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(TimeStampResp) or Err(e) containing the wolfSSL
+    /// library error code value.
+    #[cfg(tsp_responder)]
+    pub fn new(status: u32, status_string: Option<&'a str>, fail_info: u32) -> Result<Self, Error> {
+        let (str_ptr, str_size) = match status_string {
+            Some(s) => (s.as_ptr(), crate::buffer_len_to_u32(s.len())?),
+            None => (core::ptr::null(), 0),
+        };
+        let mut resp: MaybeUninit<sys::TspResponse> = MaybeUninit::zeroed();
+        let rc = unsafe { sys::wc_TspResponse_Init(resp.as_mut_ptr()) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        let mut resp = TimeStampResp {
+            wc_resp: unsafe { resp.assume_init() },
+            _phantom: PhantomData,
+        };
+        let rc = unsafe {
+            sys::wc_TspResponse_SetStatus(&mut resp.wc_resp, status, str_ptr,
+                str_size, fail_info)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(resp)
+    }
+
+    /// Set the time-stamp token of the response.
+    ///
+    /// # Parameters
+    ///
+    /// * `token`: DER encoded TimeStampToken, as created by
+    ///   [`TstInfo::sign()`].
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    #[cfg(tsp_responder)]
+    pub fn set_token(&mut self, token: &'a [u8]) -> Result<(), Error> {
+        self.wc_resp.tokenSz = crate::buffer_len_to_u32(token.len())?;
+        self.wc_resp.token = token.as_ptr();
+        Ok(())
+    }
+
+    /// Encode the TimeStampResp as DER.
+    ///
+    /// # Parameters
+    ///
+    /// * `dout`: Buffer in which to store the DER encoding.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(size) containing the number of bytes written to
+    /// `dout` or Err(e) containing the wolfSSL library error code value.
+    #[cfg(tsp_responder)]
+    pub fn encode(&self, dout: &mut [u8]) -> Result<usize, Error> {
+        let mut dout_size = crate::buffer_len_to_u32(dout.len())?;
+        let rc = unsafe {
+            sys::wc_TspResponse_Encode(&self.wc_resp, dout.as_mut_ptr(), &mut dout_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(dout_size as usize)
+    }
+
+    /// Decode a DER encoded TimeStampResp, e.g. as received from a TSA.
+    ///
+    /// # Parameters
+    ///
+    /// * `der`: DER encoded TimeStampResp, referenced by the returned
+    ///   object.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(TimeStampResp) or Err(e) containing the wolfSSL
+    /// library error code value.
+    #[cfg(tsp_verifier)]
+    pub fn decode(der: &'a [u8]) -> Result<Self, Error> {
+        let der_size = crate::buffer_len_to_u32(der.len())?;
+        let mut resp: MaybeUninit<sys::TspResponse> = MaybeUninit::zeroed();
+        let rc = unsafe {
+            sys::wc_TspResponse_Decode(resp.as_mut_ptr(), der.as_ptr(), der_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(TimeStampResp {
+            wc_resp: unsafe { resp.assume_init() },
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Verify the time-stamp token of the response.
+    ///
+    /// The response must be granted and carry a token. The CMS signature of
+    /// the token is verified and the signer certificate checked to be valid
+    /// for time-stamping and to match the SigningCertificateV2 attribute.
+    ///
+    /// # Parameters
+    ///
+    /// * `cert`: DER encoded trusted TSA certificate. The token signer must
+    ///   be this certificate; it is also used when the token does not
+    ///   include the signer certificate. With None the signature is checked
+    ///   against the certificate in the token only and no trust in the
+    ///   signer is established.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(TstInfo) containing the signed TSTInfo or Err(e)
+    /// containing the wolfSSL library error code value (`TSP_VERIFY_E` when
+    /// the response is not granted or the token does not verify).
+    #[cfg(tsp_verify)]
+    pub fn verify(&self, cert: Option<&[u8]>) -> Result<TstInfo<'a>, Error> {
+        let (cert_ptr, cert_size) = match cert {
+            Some(c) => (c.as_ptr(), crate::buffer_len_to_u32(c.len())?),
+            None => (core::ptr::null(), 0),
+        };
+        let mut tst: MaybeUninit<sys::TspTstInfo> = MaybeUninit::zeroed();
+        // The response is not modified by the verify.
+        let rc = unsafe {
+            sys::wc_TspResponse_Verify(&self.wc_resp as *const _ as *mut _, cert_ptr,
+                cert_size, tst.as_mut_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(TstInfo {
+            wc_tst: unsafe { tst.assume_init() },
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Verify the time-stamp token of the response as with `verify()` and
+    /// check the token is over the given data.
+    ///
+    /// # Parameters
+    ///
+    /// * `cert`: DER encoded trusted TSA certificate; see `verify()`.
+    /// * `data`: Data that was time-stamped.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(TstInfo) containing the signed TSTInfo or Err(e)
+    /// containing the wolfSSL library error code value.
+    #[cfg(tsp_verify)]
+    pub fn verify_data(&self, cert: Option<&[u8]>, data: &[u8]) -> Result<TstInfo<'a>, Error> {
+        let (cert_ptr, cert_size) = match cert {
+            Some(c) => (c.as_ptr(), crate::buffer_len_to_u32(c.len())?),
+            None => (core::ptr::null(), 0),
+        };
+        let data_size = crate::buffer_len_to_u32(data.len())?;
+        let mut tst: MaybeUninit<sys::TspTstInfo> = MaybeUninit::zeroed();
+        // The response is not modified by the verify.
+        let rc = unsafe {
+            sys::wc_TspResponse_VerifyData(&self.wc_resp as *const _ as *mut _, cert_ptr,
+                cert_size, data.as_ptr(), data_size, tst.as_mut_ptr())
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(TstInfo {
+            wc_tst: unsafe { tst.assume_init() },
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Get the PKIStatus of the response, one of the `STATUS_*` values.
+    pub fn status(&self) -> u32 {
+        self.wc_resp.status as u32
+    }
+
+    /// Get the status text of the response, if present and valid UTF-8.
+    pub fn status_string(&self) -> Option<&'a str> {
+        let s = raw_slice(self.wc_resp.statusString, self.wc_resp.statusStringSz)?;
+        core::str::from_utf8(s).ok()
+    }
+
+    /// Get the `FAIL_*` failure information, 0 when not present.
+    pub fn fail_info(&self) -> u32 {
+        self.wc_resp.failInfo
+    }
+
+    /// Get the DER encoded time-stamp token, if present.
+    pub fn token(&self) -> Option<&'a [u8]> {
+        raw_slice(self.wc_resp.token, self.wc_resp.tokenSz)
+    }
+}
@@ -0,0 +1,820 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+/*!
+This module provides a Rust wrapper for the wolfCrypt library's XMSS and
+XMSS^MT (eXtended Merkle Signature Scheme) post-quantum stateful hash-based
+signature functionality (RFC 8391, NIST SP 800-208).
+
+The primary component is the [`Xmss`] struct, which manages the lifecycle of
+a wolfSSL `XmssKey` object. It ensures proper initialization and
+deallocation.
+
+A parameter set is selected by name with [`Xmss::set_param_str()`], for
+example `"XMSS-SHA2_10_256"` (single tree of height 10) or
+`"XMSSMT-SHA2_20/4_256"` (hypertree of total height 20 split into 4 layers).
+The names of the SP 800-208 parameter sets are available as `Xmss::XMSS_*`
+and `Xmss::XMSSMT_*` constants. A key of total height `h` can create `2^h`
+signatures.
+
+Signing requires storage to persist the evolving private key state. Give
+the key an [`LmsStateStore`](crate::lms::LmsStateStore) with
+[`Xmss::set_state_store()`] (the `alloc` feature; the store types live in
+the [`lms`](crate::lms) module), or register raw callbacks with
+[`Xmss::set_write_cb()`] and [`Xmss::set_read_cb()`], before calling
+[`Xmss::make_key()`] or [`Xmss::reload()`]. These methods are absent when
+wolfCrypt is built with `WOLFSSL_XMSS_VERIFY_ONLY`, in which case a key can
+only import a raw public key with [`Xmss::import_pub_raw()`] and verify.
+
+A signature is only returned by [`Xmss::sign()`] once the private key state
+has been persisted; if persisting fails, the signature is erased and an
+error returned.
+
+# Examples
+
+```rust
+#[cfg(all(xmss, xmss_make_key, lms_make_key, random, feature = "alloc"))]
+{
+use wolfssl_wolfcrypt::random::RNG;
+use wolfssl_wolfcrypt::lms::MemoryStateStore;
+use wolfssl_wolfcrypt::xmss::Xmss;
+use wolfssl_wolfcrypt::WolfCrypt;
+let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+
+let rng = RNG::new(&wolfcrypt).expect("RNG creation failed");
+let mut key = Xmss::new(&wolfcrypt).expect("Xmss::new() failed");
+key.set_param_str(Xmss::XMSS_SHA2_10_256).expect("set_param_str failed");
+key.set_state_store(MemoryStateStore::new()).expect("set_state_store failed");
+key.make_key(&rng).expect("make_key failed");
+
+let mut sig = vec![0u8; key.get_sig_len().expect("get_sig_len failed")];
+let mut pub_buf = vec![0u8; key.get_pub_len().expect("get_pub_len failed")];
+key.sign(b"hello", &mut sig).expect("sign failed");
+key.export_pub_raw(&mut pub_buf).expect("export_pub_raw failed");
+
+let mut vkey = Xmss::new(&wolfcrypt).expect("Xmss::new() for verify failed");
+vkey.set_param_str(Xmss::XMSS_SHA2_10_256).expect("set_param_str failed");
+vkey.import_pub_raw(&pub_buf).expect("import_pub_raw failed");
+vkey.verify(&sig, b"hello").expect("verify failed");
+}
+```
+*/
+
+#![cfg(xmss)]
+
+use crate::Error;
+use crate::memory::HeapHint;
+use crate::sys;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+#[cfg(all(xmss_make_key, random))]
+use crate::random::RNG;
+#[cfg(all(xmss_make_key, lms_make_key, feature = "alloc"))]
+use crate::lms::LmsStateStore;
+#[cfg(all(xmss_make_key, lms_make_key, feature = "alloc"))]
+use alloc::boxed::Box;
+
+/// Write callback registered by [`Xmss::set_state_store()`].
+#[cfg(all(xmss_make_key, lms_make_key, feature = "alloc"))]
+unsafe extern "C" fn state_store_write(
+    priv_data: *const u8,
+    priv_sz: u32,
+    context: *mut core::ffi::c_void,
+) -> sys::wc_XmssRc {
+    if priv_data.is_null() || context.is_null() {
+        return sys::wc_XmssRc_WC_XMSS_RC_BAD_ARG;
+    }
+    // Safety: the context is the boxed store owned by the key.
+    let store = unsafe { &mut *(context as *mut Box<dyn LmsStateStore>) };
+    let state = unsafe { core::slice::from_raw_parts(priv_data, priv_sz as usize) };
+    match store.write(state) {
+        Ok(()) => sys::wc_XmssRc_WC_XMSS_RC_SAVED_TO_NV_MEMORY,
+        Err(_) => sys::wc_XmssRc_WC_XMSS_RC_WRITE_FAIL,
+    }
+}
+
+/// Read callback registered by [`Xmss::set_state_store()`].
+#[cfg(all(xmss_make_key, lms_make_key, feature = "alloc"))]
+unsafe extern "C" fn state_store_read(
+    priv_data: *mut u8,
+    priv_sz: u32,
+    context: *mut core::ffi::c_void,
+) -> sys::wc_XmssRc {
+    if priv_data.is_null() || context.is_null() {
+        return sys::wc_XmssRc_WC_XMSS_RC_BAD_ARG;
+    }
+    // Safety: the context is the boxed store owned by the key.
+    let store = unsafe { &mut *(context as *mut Box<dyn LmsStateStore>) };
+    let state = unsafe { core::slice::from_raw_parts_mut(priv_data, priv_sz as usize) };
+    match store.read(state) {
+        Ok(()) => sys::wc_XmssRc_WC_XMSS_RC_READ_TO_MEMORY,
+        Err(_) => sys::wc_XmssRc_WC_XMSS_RC_READ_FAIL,
+    }
+}
+
+/// Rust wrapper for a wolfSSL `XmssKey` object (XMSS/XMSS^MT, RFC 8391).
+///
+/// Manages the lifecycle of the underlying key, including initialization and
+/// deallocation via the [`Drop`] trait.
+///
+/// An instance is created with [`Xmss::new()`] or [`Xmss::new_ex()`]. The
+/// parameter set must be selected with [`Xmss::set_param_str()`] before
+/// generating or importing a key.
+///
+/// When `WOLFSSL_XMSS_VERIFY_ONLY` is **not** set, a state store or
+/// private-key callbacks must be registered (see
+/// [`Xmss::set_state_store()`], [`Xmss::set_write_cb()`] and
+/// [`Xmss::set_read_cb()`]) before calling [`Xmss::make_key()`] or
+/// [`Xmss::reload()`].
+pub struct Xmss<'a> {
+    ws_key: sys::XmssKey,
+    /// Private key state store, double boxed so the context pointer given
+    /// to wolfCrypt is thin and stable across moves of the key.
+    #[cfg(all(xmss_make_key, lms_make_key, feature = "alloc"))]
+    state_store: Option<Box<Box<dyn LmsStateStore>>>,
+    _wolfcrypt: crate::WolfCrypt,
+    _device: crate::env::DeviceRef,
+    _heap: PhantomData<HeapHint<'a>>,
+}
+
+/// XMSS parameter set names (SP 800-208).
+impl<'a> Xmss<'a> {
+    pub const XMSS_SHA2_10_256         : &'static str = "XMSS-SHA2_10_256";
+    pub const XMSS_SHA2_16_256         : &'static str = "XMSS-SHA2_16_256";
+    pub const XMSS_SHA2_20_256         : &'static str = "XMSS-SHA2_20_256";
+    pub const XMSS_SHA2_10_192         : &'static str = "XMSS-SHA2_10_192";
+    pub const XMSS_SHA2_16_192         : &'static str = "XMSS-SHA2_16_192";
+    pub const XMSS_SHA2_20_192         : &'static str = "XMSS-SHA2_20_192";
+    pub const XMSS_SHAKE256_10_256     : &'static str = "XMSS-SHAKE256_10_256";
+    pub const XMSS_SHAKE256_16_256     : &'static str = "XMSS-SHAKE256_16_256";
+    pub const XMSS_SHAKE256_20_256     : &'static str = "XMSS-SHAKE256_20_256";
+    pub const XMSS_SHAKE256_10_192     : &'static str = "XMSS-SHAKE256_10_192";
+    pub const XMSS_SHAKE256_16_192     : &'static str = "XMSS-SHAKE256_16_192";
+    pub const XMSS_SHAKE256_20_192     : &'static str = "XMSS-SHAKE256_20_192";
+}
+
+/// XMSS^MT parameter set names (SP 800-208).
+impl<'a> Xmss<'a> {
+    pub const XMSSMT_SHA2_20_2_256     : &'static str = "XMSSMT-SHA2_20/2_256";
+    pub const XMSSMT_SHA2_20_4_256     : &'static str = "XMSSMT-SHA2_20/4_256";
+    pub const XMSSMT_SHA2_40_2_256     : &'static str = "XMSSMT-SHA2_40/2_256";
+    pub const XMSSMT_SHA2_40_4_256     : &'static str = "XMSSMT-SHA2_40/4_256";
+    pub const XMSSMT_SHA2_40_8_256     : &'static str = "XMSSMT-SHA2_40/8_256";
+    pub const XMSSMT_SHA2_60_3_256     : &'static str = "XMSSMT-SHA2_60/3_256";
+    pub const XMSSMT_SHA2_60_6_256     : &'static str = "XMSSMT-SHA2_60/6_256";
+    pub const XMSSMT_SHA2_60_12_256    : &'static str = "XMSSMT-SHA2_60/12_256";
+    pub const XMSSMT_SHA2_20_2_192     : &'static str = "XMSSMT-SHA2_20/2_192";
+    pub const XMSSMT_SHA2_20_4_192     : &'static str = "XMSSMT-SHA2_20/4_192";
+    pub const XMSSMT_SHA2_40_2_192     : &'static str = "XMSSMT-SHA2_40/2_192";
+    pub const XMSSMT_SHA2_40_4_192     : &'static str = "XMSSMT-SHA2_40/4_192";
+    pub const XMSSMT_SHA2_40_8_192     : &'static str = "XMSSMT-SHA2_40/8_192";
+    pub const XMSSMT_SHA2_60_3_192     : &'static str = "XMSSMT-SHA2_60/3_192";
+    pub const XMSSMT_SHA2_60_6_192     : &'static str = "XMSSMT-SHA2_60/6_192";
+    pub const XMSSMT_SHA2_60_12_192    : &'static str = "XMSSMT-SHA2_60/12_192";
+    pub const XMSSMT_SHAKE256_20_2_256 : &'static str = "XMSSMT-SHAKE256_20/2_256";
+    pub const XMSSMT_SHAKE256_20_4_256 : &'static str = "XMSSMT-SHAKE256_20/4_256";
+    pub const XMSSMT_SHAKE256_40_2_256 : &'static str = "XMSSMT-SHAKE256_40/2_256";
+    pub const XMSSMT_SHAKE256_40_4_256 : &'static str = "XMSSMT-SHAKE256_40/4_256";
+    pub const XMSSMT_SHAKE256_40_8_256 : &'static str = "XMSSMT-SHAKE256_40/8_256";
+    pub const XMSSMT_SHAKE256_60_3_256 : &'static str = "XMSSMT-SHAKE256_60/3_256";
+    pub const XMSSMT_SHAKE256_60_6_256 : &'static str = "XMSSMT-SHAKE256_60/6_256";
+    pub const XMSSMT_SHAKE256_60_12_256: &'static str = "XMSSMT-SHAKE256_60/12_256";
+    pub const XMSSMT_SHAKE256_20_2_192 : &'static str = "XMSSMT-SHAKE256_20/2_192";
+    pub const XMSSMT_SHAKE256_20_4_192 : &'static str = "XMSSMT-SHAKE256_20/4_192";
+    pub const XMSSMT_SHAKE256_40_2_192 : &'static str = "XMSSMT-SHAKE256_40/2_192";
+    pub const XMSSMT_SHAKE256_40_4_192 : &'static str = "XMSSMT-SHAKE256_40/4_192";
+    pub const XMSSMT_SHAKE256_40_8_192 : &'static str = "XMSSMT-SHAKE256_40/8_192";
+    pub const XMSSMT_SHAKE256_60_3_192 : &'static str = "XMSSMT-SHAKE256_60/3_192";
+    pub const XMSSMT_SHAKE256_60_6_192 : &'static str = "XMSSMT-SHAKE256_60/6_192";
+    pub const XMSSMT_SHAKE256_60_12_192: &'static str = "XMSSMT-SHAKE256_60/12_192";
+}
+
+impl<'a> Xmss<'a> {
+    /// Longest supported parameter set name, in bytes.
+    const MAX_PARAM_STR_LEN: usize = 31;
+
+    /// Create and initialize a new `Xmss` key instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `wolfcrypt`: Guard of the initialized library.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(Xmss) containing the key instance or Err(e)
+    /// containing the wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(xmss)]
+    /// {
+    /// use wolfssl_wolfcrypt::xmss::Xmss;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let key = Xmss::new(&wolfcrypt).expect("Error with Xmss::new()");
+    /// }
+    /// ```
+    pub fn new(wolfcrypt: &crate::WolfCrypt) -> Result<Self, Error> {
+        Self::new_ex(&crate::Env::new(wolfcrypt))
+    }
+
+    /// Create and initialize a new `Xmss` key instance with optional heap
+    /// hint and device ID.
+    ///
+    /// # Parameters
+    ///
+    /// * `env`: Heap hint and crypto callback device to use.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(Xmss) containing the key instance or Err(e)
+    /// containing the wolfSSL library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(xmss)]
+    /// {
+    /// use wolfssl_wolfcrypt::Env;
+    /// use wolfssl_wolfcrypt::xmss::Xmss;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let key = Xmss::new_ex(&Env::new(&wolfcrypt)).expect("Error with Xmss::new_ex()");
+    /// }
+    /// ```
+    pub fn new_ex(
+        env: &crate::Env,
+    ) -> Result<Self, Error> {
+        let wolfcrypt = env.wolfcrypt().clone();
+        let heap = env.heap_ptr();
+        let dev_id = env.dev_id();
+        let mut ws_key: MaybeUninit<sys::XmssKey> = MaybeUninit::uninit();
+        let rc = unsafe { sys::wc_XmssKey_Init(ws_key.as_mut_ptr(), heap, dev_id) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        let ws_key = unsafe { ws_key.assume_init() };
+        let xmss = Xmss {
+            ws_key,
+            #[cfg(all(xmss_make_key, lms_make_key, feature = "alloc"))]
+            state_store: None,
+            _wolfcrypt: wolfcrypt,
+            _device: env.device_ref(),
+            _heap: PhantomData,
+        };
+        Ok(xmss)
+    }
+
+    /// Select the XMSS or XMSS^MT parameter set by name.
+    ///
+    /// Use the `Xmss::XMSS_*` and `Xmss::XMSSMT_*` constants or any other
+    /// parameter set name from RFC 8391 supported by the wolfCrypt build.
+    /// The parameter set can only be selected once per key.
+    ///
+    /// # Parameters
+    ///
+    /// * `param`: Parameter set name, e.g. `"XMSS-SHA2_10_256"`.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(xmss)]
+    /// {
+    /// use wolfssl_wolfcrypt::xmss::Xmss;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut key = Xmss::new(&wolfcrypt).expect("Error with Xmss::new()");
+    /// key.set_param_str(Xmss::XMSS_SHA2_10_256).expect("Error with set_param_str()");
+    /// }
+    /// ```
+    pub fn set_param_str(&mut self, param: &str) -> Result<(), Error> {
+        // wc_XmssKey_SetParamStr expects a NUL-terminated C string.
+        let param = param.as_bytes();
+        if param.len() > Self::MAX_PARAM_STR_LEN || param.contains(&0) {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+        }
+        let mut c_param = [0u8; Self::MAX_PARAM_STR_LEN + 1];
+        c_param[..param.len()].copy_from_slice(param);
+        let rc = unsafe {
+            sys::wc_XmssKey_SetParamStr(&mut self.ws_key, c_param.as_ptr() as *const core::ffi::c_char)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Get the name of the parameter set selected for this key.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(name) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(all(xmss, xmss_get_param_str))]
+    /// {
+    /// use wolfssl_wolfcrypt::xmss::Xmss;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut key = Xmss::new(&wolfcrypt).expect("Error with Xmss::new()");
+    /// key.set_param_str(Xmss::XMSSMT_SHA2_20_4_256).expect("Error with set_param_str()");
+    /// let name = key.get_param_str().expect("Error with get_param_str()");
+    /// assert_eq!(name, Xmss::XMSSMT_SHA2_20_4_256);
+    /// }
+    /// ```
+    #[cfg(xmss_get_param_str)]
+    pub fn get_param_str(&self) -> Result<&'static str, Error> {
+        let mut name: *const core::ffi::c_char = core::ptr::null();
+        let rc = unsafe { sys::wc_XmssKey_GetParamStr(&self.ws_key, &mut name) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        if name.is_null() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG));
+        }
+        // The name points into a static table in wolfCrypt.
+        let name = unsafe { core::ffi::CStr::from_ptr(name) };
+        name.to_str().map_err(|_| Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG))
+    }
+
+    /// Register a callback to write (persist) the private key.
+    ///
+    /// The callback is called by [`Xmss::make_key()`] and [`Xmss::sign()`]
+    /// whenever the private key state must be saved to non-volatile storage.
+    ///
+    /// # Parameters
+    ///
+    /// * `write_cb`: Callback function of type
+    ///   `wc_xmss_write_private_key_cb`.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    #[cfg(xmss_make_key)]
+    pub fn set_write_cb(&mut self, write_cb: sys::wc_xmss_write_private_key_cb) -> Result<(), Error> {
+        let rc = unsafe { sys::wc_XmssKey_SetWriteCb(&mut self.ws_key, write_cb) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Register a callback to read (restore) the private key.
+    ///
+    /// The callback is called by [`Xmss::reload()`] whenever the private key
+    /// must be read from non-volatile storage.
+    ///
+    /// # Parameters
+    ///
+    /// * `read_cb`: Callback function of type `wc_xmss_read_private_key_cb`.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    #[cfg(xmss_make_key)]
+    pub fn set_read_cb(&mut self, read_cb: sys::wc_xmss_read_private_key_cb) -> Result<(), Error> {
+        let rc = unsafe { sys::wc_XmssKey_SetReadCb(&mut self.ws_key, read_cb) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Set the store used to persist and restore the private key state.
+    ///
+    /// Registers write and read callbacks that call `store`, replacing any
+    /// callbacks and context set with [`Xmss::set_write_cb()`],
+    /// [`Xmss::set_read_cb()`] and [`Xmss::set_context()`]. The key owns the
+    /// store from then on. The [`lms`](crate::lms) module provides the
+    /// in-memory `MemoryStateStore` and the file-backed `FileStateStore`.
+    ///
+    /// # Parameters
+    ///
+    /// * `store`: Private key state store.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(all(xmss, xmss_make_key, lms_make_key, random, feature = "alloc"))]
+    /// {
+    /// use wolfssl_wolfcrypt::random::RNG;
+    /// use wolfssl_wolfcrypt::lms::MemoryStateStore;
+    /// use wolfssl_wolfcrypt::xmss::Xmss;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    /// let store = MemoryStateStore::new();
+    /// let mut key = Xmss::new(&wolfcrypt).expect("Error with Xmss::new()");
+    /// key.set_param_str(Xmss::XMSS_SHA2_10_256).expect("Error with set_param_str()");
+    /// key.set_state_store(store.clone()).expect("Error with set_state_store()");
+    /// key.make_key(&rng).expect("Error with make_key()");
+    /// assert!(!store.is_empty());
+    /// }
+    /// ```
+    #[cfg(all(xmss_make_key, lms_make_key, feature = "alloc"))]
+    pub fn set_state_store<S: LmsStateStore + 'static>(&mut self, store: S) -> Result<(), Error> {
+        let mut store: Box<Box<dyn LmsStateStore>> = Box::new(Box::new(store));
+        let context = store.as_mut() as *mut Box<dyn LmsStateStore> as *mut core::ffi::c_void;
+        self.set_write_cb(Some(state_store_write))?;
+        self.set_read_cb(Some(state_store_read))?;
+        let rc = unsafe { sys::wc_XmssKey_SetContext(&mut self.ws_key, context) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        // The previous store, if any, is only dropped once the context no
+        // longer references it.
+        self.state_store = Some(store);
+        Ok(())
+    }
+
+    /// Set the context pointer passed to the private key I/O callbacks.
+    ///
+    /// # Parameters
+    ///
+    /// * `context`: An arbitrary pointer passed unchanged to the write and
+    ///   read callbacks.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure `context` remains valid for as long as the key
+    /// may invoke the registered write or read callbacks (i.e., across calls
+    /// to [`Xmss::make_key()`], [`Xmss::reload()`], and [`Xmss::sign()`]).
+    #[cfg(xmss_make_key)]
+    pub unsafe fn set_context(&mut self, context: *mut core::ffi::c_void) -> Result<(), Error> {
+        let rc = unsafe { sys::wc_XmssKey_SetContext(&mut self.ws_key, context) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Generate a new XMSS/XMSS^MT key pair using an RNG.
+    ///
+    /// The parameter set must be selected with [`Xmss::set_param_str()`] and
+    /// a state store or private key callbacks must be registered before
+    /// calling this function. Key generation time grows with the tree
+    /// height (of a single layer for XMSS^MT).
+    ///
+    /// # Parameters
+    ///
+    /// * `rng`: `RNG` instance to use for random number generation.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    #[cfg(all(xmss_make_key, random))]
+    pub fn make_key(&mut self, rng: &RNG) -> Result<(), Error> {
+        let rc = unsafe { sys::wc_XmssKey_MakeKey(&mut self.ws_key, rng.wc_rng) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Reload a previously generated XMSS/XMSS^MT key from non-volatile
+    /// storage.
+    ///
+    /// The read callback (or state store) is called to restore the private
+    /// key state. The parameter set must have been selected and the read
+    /// callback registered before calling this function.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    #[cfg(xmss_make_key)]
+    pub fn reload(&mut self) -> Result<(), Error> {
+        let rc = unsafe { sys::wc_XmssKey_Reload(&mut self.ws_key) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Get the encoded private key length in bytes.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(length) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    #[cfg(xmss_make_key)]
+    pub fn get_priv_len(&self) -> Result<usize, Error> {
+        let mut len = 0u32;
+        let rc = unsafe { sys::wc_XmssKey_GetPrivLen(&self.ws_key, &mut len) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(len as usize)
+    }
+
+    /// Sign a message with this XMSS/XMSS^MT private key.
+    ///
+    /// The `sig` buffer must be at least `get_sig_len()` bytes and `msg`
+    /// must not be empty. The write callback (or state store) is invoked to
+    /// persist the updated private key state before the signature is
+    /// returned; if it fails, `sig` is zeroized and an error is returned.
+    /// Once the last signature has been made, further calls fail with
+    /// `BAD_STATE_E`; see [`Xmss::has_sigs_left()`].
+    ///
+    /// # Parameters
+    ///
+    /// * `msg`: Message bytes to sign.
+    /// * `sig`: Output buffer for the signature. Must be at least
+    ///   `get_sig_len()` bytes.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(sig_len) containing the number of bytes written to
+    /// `sig` on success, or Err(e) containing the wolfSSL library error code.
+    #[cfg(xmss_make_key)]
+    pub fn sign(&mut self, msg: &[u8], sig: &mut [u8]) -> Result<usize, Error> {
+        let mut sig_sz = crate::buffer_len_to_u32(sig.len())?;
+        let msg_sz = crate::buffer_len_to_i32(msg.len())?;
+        let rc = unsafe {
+            sys::wc_XmssKey_Sign(
+                &mut self.ws_key,
+                sig.as_mut_ptr(),
+                &mut sig_sz,
+                msg.as_ptr(),
+                msg_sz,
+            )
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(sig_sz as usize)
+    }
+
+    /// Return whether there are more signatures remaining for this key.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(true) if any signatures remain, Ok(false) if
+    /// exhausted, or Err(e) containing the wolfSSL library error code value.
+    #[cfg(xmss_make_key)]
+    pub fn has_sigs_left(&mut self) -> Result<bool, Error> {
+        let rc = unsafe { sys::wc_XmssKey_SigsLeft(&mut self.ws_key) };
+        if rc < 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(rc != 0)
+    }
+
+    /// Get the signature length in bytes for this key's parameter set.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(length) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(xmss)]
+    /// {
+    /// use wolfssl_wolfcrypt::xmss::Xmss;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut key = Xmss::new(&wolfcrypt).expect("Error with Xmss::new()");
+    /// key.set_param_str(Xmss::XMSS_SHA2_10_256).expect("Error with set_param_str()");
+    /// let sig_len = key.get_sig_len().expect("Error with get_sig_len()");
+    /// assert_eq!(sig_len, 2500);
+    /// }
+    /// ```
+    pub fn get_sig_len(&self) -> Result<usize, Error> {
+        let mut len = 0u32;
+        let rc = unsafe { sys::wc_XmssKey_GetSigLen(&self.ws_key, &mut len) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(len as usize)
+    }
+
+    /// Get the raw public key length in bytes for this key's parameter set.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(length) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #[cfg(xmss)]
+    /// {
+    /// use wolfssl_wolfcrypt::xmss::Xmss;
+    /// use wolfssl_wolfcrypt::WolfCrypt;
+    /// let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    /// let mut key = Xmss::new(&wolfcrypt).expect("Error with Xmss::new()");
+    /// key.set_param_str(Xmss::XMSS_SHA2_10_256).expect("Error with set_param_str()");
+    /// let pub_len = key.get_pub_len().expect("Error with get_pub_len()");
+    /// assert_eq!(pub_len, 68);
+    /// }
+    /// ```
+    pub fn get_pub_len(&self) -> Result<usize, Error> {
+        let mut len = 0u32;
+        let rc = unsafe { sys::wc_XmssKey_GetPubLen(&self.ws_key, &mut len) };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(len as usize)
+    }
+
+    /// Copy the public key from `src` into this key instance.
+    ///
+    /// After a successful call, this key is a verify-only key with the same
+    /// parameter set as `src`.
+    ///
+    /// # Parameters
+    ///
+    /// * `src`: Source key to copy the public portion from.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn export_pub_from(&mut self, src: &Xmss) -> Result<(), Error> {
+        let rc = unsafe {
+            sys::wc_XmssKey_ExportPub(&mut self.ws_key, &src.ws_key)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Export the raw public key bytes into `out`.
+    ///
+    /// The raw public key starts with the 4-byte parameter set OID
+    /// (RFC 8391 Appendix B.1/C.1).
+    ///
+    /// # Parameters
+    ///
+    /// * `out`: Output buffer for the raw public key. Must be at least
+    ///   `get_pub_len()` bytes.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(length) containing the number of bytes written on
+    /// success, or Err(e) containing the wolfSSL library error code.
+    pub fn export_pub_raw(&self, out: &mut [u8]) -> Result<usize, Error> {
+        let mut out_len = crate::buffer_len_to_u32(out.len())?;
+        let rc = unsafe {
+            sys::wc_XmssKey_ExportPubRaw(&self.ws_key, out.as_mut_ptr(), &mut out_len)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(out_len as usize)
+    }
+
+    /// Import a raw public key from `data` into this key instance.
+    ///
+    /// The parameter set **must** be selected with
+    /// [`Xmss::set_param_str()`] before calling this function, and the
+    /// length of `data` must match `get_pub_len()`. After a successful
+    /// import the key is a verify-only key.
+    ///
+    /// # Parameters
+    ///
+    /// * `data`: Buffer containing the raw public key bytes.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    pub fn import_pub_raw(&mut self, data: &[u8]) -> Result<(), Error> {
+        let data_size = crate::buffer_len_to_u32(data.len())?;
+        let rc = unsafe {
+            sys::wc_XmssKey_ImportPubRaw(&mut self.ws_key, data.as_ptr(), data_size)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Import a raw public key from `data`, deriving the parameter set from
+    /// the OID at the start of the key.
+    ///
+    /// The OID numbering differs between XMSS and XMSS^MT, so `is_xmssmt`
+    /// selects which to use. If a parameter set has already been selected,
+    /// the OID and `is_xmssmt` must match it. After a successful import the
+    /// key is a verify-only key.
+    ///
+    /// # Parameters
+    ///
+    /// * `data`: Buffer containing the raw public key bytes.
+    /// * `is_xmssmt`: Whether the key is an XMSS^MT key.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on success or Err(e) containing the wolfSSL
+    /// library error code value.
+    #[cfg(xmss_import_pub_raw_ex)]
+    pub fn import_pub_raw_ex(&mut self, data: &[u8], is_xmssmt: bool) -> Result<(), Error> {
+        let data_size = crate::buffer_len_to_u32(data.len())?;
+        let rc = unsafe {
+            sys::wc_XmssKey_ImportPubRaw_ex(&mut self.ws_key, data.as_ptr(), data_size,
+                is_xmssmt as i32)
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Verify an XMSS/XMSS^MT signature over a message.
+    ///
+    /// The key must have a public key loaded (via [`Xmss::make_key()`],
+    /// [`Xmss::reload()`], [`Xmss::export_pub_from()`] or
+    /// [`Xmss::import_pub_raw()`]).
+    ///
+    /// # Parameters
+    ///
+    /// * `sig`: Signature bytes to verify. Must be exactly `get_sig_len()`
+    ///   bytes.
+    /// * `msg`: Message bytes that were signed.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) if the signature is valid, or Err(e) containing
+    /// the wolfSSL library error code value (including a verification failure
+    /// code).
+    pub fn verify(&mut self, sig: &[u8], msg: &[u8]) -> Result<(), Error> {
+        let sig_sz = crate::buffer_len_to_u32(sig.len())?;
+        let msg_sz = crate::buffer_len_to_i32(msg.len())?;
+        let rc = unsafe {
+            sys::wc_XmssKey_Verify(
+                &mut self.ws_key,
+                sig.as_ptr(),
+                sig_sz,
+                msg.as_ptr(),
+                msg_sz,
+            )
+        };
+        if rc != 0 {
+            return Err(Error::from(rc));
+        }
+        Ok(())
+    }
+}
+
+impl<'a> Xmss<'a> {
+    fn zeroize(&mut self) {
+        unsafe { crate::zeroize_raw(&mut self.ws_key); }
+    }
+}
+
+impl Drop for Xmss<'_> {
+    /// Safely free the underlying wolfSSL XMSS key context.
+    ///
+    /// This calls `wc_XmssKey_Free()`, which also zeroizes and frees the
+    /// in-memory private key. The Rust Drop trait guarantees this is called
+    /// when the `Xmss` struct goes out of scope.
+    fn drop(&mut self) {
+        unsafe {
+            sys::wc_XmssKey_Free(&mut self.ws_key);
+        }
+        self.zeroize();
+    }
+}
@@ -1,3 +1,5 @@
+use wolfssl_wolfcrypt::WolfCrypt;
+
 #[cfg(fips)]
 fn setup_fips()
 {
@@ -6,8 +8,10 @@ fn setup_fips()
 }
 
 #[allow(dead_code)]
-pub fn setup()
+pub fn setup() -> WolfCrypt
 {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     #[cfg(fips)]
     setup_fips();
+    wolfcrypt
 }
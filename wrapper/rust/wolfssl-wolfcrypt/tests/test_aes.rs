@@ -56,7 +56,8 @@ const BIG_MSG: [u8; 384] = [
 #[test]
 #[cfg(aes_cbc)]
 fn test_cbc_encrypt_decrypt() {
-    let mut cbc = CBC::new().expect("Failed to create CBC");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut cbc = CBC::new(&wolfcrypt).expect("Failed to create CBC");
     let key: &[u8; 16] = b"0123456789abcdef";
     let iv: &[u8; 16] = b"1234567890abcdef";
     let msg: [u8; 16] = [
@@ -80,7 +81,8 @@ fn test_cbc_encrypt_decrypt() {
 #[test]
 #[cfg(aes_cbc)]
 fn test_cbc_big_msg() {
-    let mut cbc = CBC::new().expect("Failed to create CBC");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut cbc = CBC::new(&wolfcrypt).expect("Failed to create CBC");
     let big_key = b"0123456789abcdeffedcba9876543210";
     let iv: &[u8; 16] = b"1234567890abcdef";
     let mut big_cipher: [u8; 384] = [0; 384];
@@ -99,6 +101,7 @@ fn test_cbc_big_msg() {
 #[test]
 #[cfg(aes_ccm)]
 fn test_ccm_encrypt_decrypt() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     let key: [u8; 16] = [
         0xc0, 0xc1, 0xc2, 0xc3, 0xc4, 0xc5, 0xc6, 0xc7,
         0xc8, 0xc9, 0xca, 0xcb, 0xcc, 0xcd, 0xce, 0xcf
@@ -151,7 +154,7 @@ fn test_ccm_encrypt_decrypt() {
         0x89, 0xd8, 0xd2, 0x02, 0xc5, 0xcf, 0xae, 0xf4
     ];
 
-    let mut ccm = CCM::new().expect("Failed to create CCM");
+    let mut ccm = CCM::new(&wolfcrypt).expect("Failed to create CCM");
     ccm.init(&key).expect("Error with init()");
     let mut auth_tag_out: [u8; 8] = [0; 8];
     let mut cipher_out: [u8; 23] = [0; 23];
@@ -182,7 +185,8 @@ fn test_ccm_encrypt_decrypt() {
 #[test]
 #[cfg(aes_ccm)]
 fn test_ccm_big_msg() {
-    let mut ccm = CCM::new().expect("Failed to create CCM");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut ccm = CCM::new(&wolfcrypt).expect("Failed to create CCM");
     let big_key = b"0123456789abcdeffedcba9876543210";
     let nonce: [u8; 7] = [0x00, 0x00, 0x00, 0x03, 0x02, 0x01, 0x00];
     let auth_data: [u8; 8] = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
@@ -201,7 +205,8 @@ fn test_ccm_big_msg() {
 #[test]
 #[cfg(aes_cfb)]
 fn test_cfb_encrypt_decrypt() {
-    let mut cfb = CFB::new().expect("Failed to create CFB");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut cfb = CFB::new(&wolfcrypt).expect("Failed to create CFB");
     let key: [u8; 16] = [
         0x2b,0x7e,0x15,0x16,0x28,0xae,0xd2,0xa6,
         0xab,0xf7,0x15,0x88,0x09,0xcf,0x4f,0x3c
@@ -241,7 +246,8 @@ fn test_cfb_encrypt_decrypt() {
 #[test]
 #[cfg(aes_cfb)]
 fn test_cfb_big_msg() {
-    let mut cfb = CFB::new().expect("Failed to create CFB");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut cfb = CFB::new(&wolfcrypt).expect("Failed to create CFB");
     let big_key = b"0123456789abcdeffedcba9876543210";
     let iv: [u8; 16] = [
         0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,
@@ -259,6 +265,7 @@ fn test_cfb_big_msg() {
 #[test]
 #[cfg(aes_cfb)]
 fn test_cfb_encrypt1() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     /* Test vector taken from wolfcrypt aescfb1_test() (AES-128). */
     let key: [u8; 16] = [
         0xcd,0xef,0x9d,0x06,0x61,0xba,0xe4,0x73,
@@ -269,8 +276,8 @@ fn test_cfb_encrypt1() {
         0x2a,0x3b,0x98,0x43,0xad,0x20,0xe2,0xb7
     ];
     let msg: [u8; 1] = [0xC0];
-    let mut enc = CFB::new().expect("Failed to create CFB");
-    let mut dec = CFB::new().expect("Failed to create CFB");
+    let mut enc = CFB::new(&wolfcrypt).expect("Failed to create CFB");
+    let mut dec = CFB::new(&wolfcrypt).expect("Failed to create CFB");
     enc.init(&key, &iv).expect("Error with init()");
     dec.init(&key, &iv).expect("Error with init()");
     let mut cipher: [u8; 1] = [0];
@@ -287,6 +294,7 @@ fn test_cfb_encrypt1() {
 #[test]
 #[cfg(aes_ctr)]
 fn test_ctr_encrypt_decrypt() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     let iv: [u8; 16] = [
         0xf0,0xf1,0xf2,0xf3,0xf4,0xf5,0xf6,0xf7,
         0xf8,0xf9,0xfa,0xfb,0xfc,0xfd,0xfe,0xff
@@ -315,7 +323,7 @@ fn test_ctr_encrypt_decrypt() {
         0x1e,0x03,0x1d,0xda,0x2f,0xbe,0x03,0xd1,
         0x79,0x21,0x70,0xa0,0xf3,0x00,0x9c,0xee
     ];
-    let mut ctr = CTR::new().expect("Failed to create CTR");
+    let mut ctr = CTR::new(&wolfcrypt).expect("Failed to create CTR");
     ctr.init(&key, &iv).expect("Error with init()");
     let mut outbuf: [u8; 64] = [0; 64];
     ctr.encrypt(&msg, &mut outbuf).expect("Error with encrypt()");
@@ -329,7 +337,8 @@ fn test_ctr_encrypt_decrypt() {
 #[test]
 #[cfg(aes_ctr)]
 fn test_ctr_big_msg() {
-    let mut ctr = CTR::new().expect("Failed to create CTR");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut ctr = CTR::new(&wolfcrypt).expect("Failed to create CTR");
     let big_key = b"0123456789abcdeffedcba9876543210";
     let iv: [u8; 16] = [
         0x00,0x01,0x02,0x03,0x04,0x05,0x06,0x07,
@@ -385,7 +394,8 @@ fn test_eax_one_shot_encrypt_decrypt() {
 #[test]
 #[cfg(aes_ecb)]
 fn test_ecb_encrypt_decrypt() {
-    let mut ecb = ECB::new().expect("Failed to create ECB");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut ecb = ECB::new(&wolfcrypt).expect("Failed to create ECB");
     let key_128: &[u8; 16] = b"0123456789abcdef";
     let msg: [u8; 16] = [
         0x6e, 0x6f, 0x77, 0x20, 0x69, 0x73, 0x20, 0x74,
@@ -408,6 +418,7 @@ fn test_ecb_encrypt_decrypt() {
 #[test]
 #[cfg(aes_gcm)]
 fn test_gcm_encrypt_decrypt() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     let key: [u8; 16] = [
         0x29, 0x8e, 0xfa, 0x1c, 0xcf, 0x29, 0xcf, 0x62,
         0xae, 0x68, 0x24, 0xbf, 0xc1, 0x95, 0x57, 0xfc
@@ -436,7 +447,7 @@ fn test_gcm_encrypt_decrypt() {
         0x54, 0x24, 0x65, 0xef, 0x59, 0x93, 0x16, 0xf7,
         0x3a, 0x7a, 0x56, 0x05, 0x09, 0xa2, 0xd9, 0xf2
     ];
-    let mut gcm = GCM::new().expect("Failed to create GCM");
+    let mut gcm = GCM::new(&wolfcrypt).expect("Failed to create GCM");
     gcm.init(&key).expect("Error with init()");
     let mut cipher: [u8; 32] = [0; 32];
     let mut auth_tag: [u8; 16] = [0; 16];
@@ -451,6 +462,7 @@ fn test_gcm_encrypt_decrypt() {
 #[test]
 #[cfg(aes_gcm_stream)]
 fn test_gcmstream_encrypt_decrypt() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     let plain: [u8; 60] = [
         0xd9, 0x31, 0x32, 0x25, 0xf8, 0x84, 0x06, 0xe5,
         0xa5, 0x59, 0x09, 0xc5, 0xaf, 0xf5, 0x26, 0x9a,
@@ -490,7 +502,7 @@ fn test_gcmstream_encrypt_decrypt() {
         0x76, 0xfc, 0x6e, 0xce, 0x0f, 0x4e, 0x17, 0x68,
         0xcd, 0xdf, 0x88, 0x53, 0xbb, 0x2d, 0x55, 0x1b
     ];
-    let mut gcmstream = GCMStream::new().expect("Failed to create GCMStream");
+    let mut gcmstream = GCMStream::new(&wolfcrypt).expect("Failed to create GCMStream");
     for chunk_size in 1..=auth.len() {
         gcmstream.init(&key, &iv).expect("Error with init()");
         let mut cipher: [u8; 60] = [0; 60];
@@ -522,6 +534,7 @@ fn test_gcmstream_encrypt_decrypt() {
 #[test]
 #[cfg(aes_ofb)]
 fn test_ofb_encrypt_decrypt() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     let key: [u8; 32] = [
         0xc4,0xc7,0xfa,0xd6,0x53,0x5c,0xb8,0x71,
         0x4a,0x5c,0x40,0x77,0x9a,0x8b,0xa1,0xd2,
@@ -548,7 +561,7 @@ fn test_ofb_encrypt_decrypt() {
         0x04,0x53,0xe1,0x73,0xf5,0x18,0x74,0xae,
         0xfd,0x64,0xa2,0xe1,0xe2,0x76,0x13,0xb0
     ];
-    let mut ofb = OFB::new().expect("Failed to create OFB");
+    let mut ofb = OFB::new(&wolfcrypt).expect("Failed to create OFB");
     ofb.init(&key, &iv).expect("Error with init()");
     let mut cipher: [u8; 48] = [0; 48];
     ofb.encrypt(&plain, &mut cipher).expect("Error with encrypt()");
@@ -562,6 +575,7 @@ fn test_ofb_encrypt_decrypt() {
 #[test]
 #[cfg(aes_xts)]
 fn test_xts_one_shot() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     let key: [u8; 32] = [
         0xa1, 0xb9, 0x0c, 0xba, 0x3f, 0x06, 0xac, 0x35,
         0x3b, 0x2c, 0x34, 0x38, 0x76, 0x08, 0x17, 0x62,
@@ -591,7 +605,7 @@ fn test_xts_one_shot() {
         0x77, 0x8a, 0xe8, 0xb4, 0x3c, 0xb9, 0x8d, 0x5a
     ];
 
-    let mut xts = XTS::new().expect("Failed to create XTS");
+    let mut xts = XTS::new(&wolfcrypt).expect("Failed to create XTS");
     xts.init_encrypt(&key).expect("Error with init_encrypt()");
     let mut cipher: [u8; 16] = [0; 16];
     xts.encrypt(&plain, &mut cipher, &tweak).expect("Error with encrypt()");
@@ -614,6 +628,7 @@ fn test_xts_one_shot() {
 #[test]
 #[cfg(aes_xts)]
 fn test_xts_sector_128() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     let keys: [u8; 32] = [
         0xa3, 0xe4, 0x0d, 0x5b, 0xd4, 0xb6, 0xbb, 0xed,
         0xb2, 0xd1, 0x8c, 0x70, 0x0a, 0xd2, 0xdb, 0x22,
@@ -630,7 +645,7 @@ fn test_xts_sector_128() {
     ];
     let sector = 141;
 
-    let mut xts = XTS::new().expect("Failed to create XTS");
+    let mut xts = XTS::new(&wolfcrypt).expect("Failed to create XTS");
     xts.init_encrypt(&keys).expect("Error with init_encrypt()");
     let mut cipher: [u8; 16] = [0; 16];
     xts.encrypt_sector(&plain, &mut cipher, sector).expect("Error with encrypt_sector()");
@@ -645,6 +660,7 @@ fn test_xts_sector_128() {
 #[test]
 #[cfg(aes_xts)]
 fn test_xts_sector_256() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     let keys: [u8; 64] = [
         0xef, 0x01, 0x0c, 0xa1, 0xa3, 0x66, 0x3e, 0x32,
         0x53, 0x43, 0x49, 0xbc, 0x0b, 0xae, 0x62, 0x23,
@@ -669,7 +685,7 @@ fn test_xts_sector_256() {
     ];
     let sector = 187;
 
-    let mut xts = XTS::new().expect("Failed to create XTS");
+    let mut xts = XTS::new(&wolfcrypt).expect("Failed to create XTS");
     xts.init_encrypt(&keys).expect("Error with init_encrypt()");
     let mut cipher: [u8; 32] = [0; 32];
     xts.encrypt_sector(&plain, &mut cipher, sector).expect("Error with encrypt_sector()");
@@ -684,6 +700,7 @@ fn test_xts_sector_256() {
 #[test]
 #[cfg(aes_xts)]
 fn test_xts_consecutive_sectors() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     let keys: [u8; 32] = [
         0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11,
         0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11,
@@ -792,7 +809,7 @@ fn test_xts_consecutive_sectors() {
     let sector = 0x000000ffffffffff;
     let sector_size = 512;
 
-    let mut xts = XTS::new().expect("Failed to create XTS");
+    let mut xts = XTS::new(&wolfcrypt).expect("Failed to create XTS");
     xts.init_encrypt(&keys).expect("Error with init_encrypt()");
     let mut cipher: [u8; 544] = [0; 544];
     xts.encrypt_consecutive_sectors(&plain, &mut cipher, sector, sector_size).expect("Error with encrypt_consecutive_sectors()");
@@ -807,6 +824,7 @@ fn test_xts_consecutive_sectors() {
 #[test]
 #[cfg(aes_xts_stream)]
 fn test_xtsstream() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     let keys: [u8; 32] = [
         0x39, 0x25, 0x79, 0x05, 0xdf, 0xcc, 0x77, 0x76,
         0x6c, 0x87, 0x0a, 0x80, 0x6a, 0x60, 0xe3, 0xc0,
@@ -830,7 +848,7 @@ fn test_xtsstream() {
         0xff, 0x8d, 0xbc, 0x1d, 0x9f, 0x7f, 0xc8, 0x22
     ];
 
-    let mut xtsstream = XTSStream::new().expect("Failed to create XTSStream");
+    let mut xtsstream = XTSStream::new(&wolfcrypt).expect("Failed to create XTSStream");
     xtsstream.init_encrypt(&keys, &tweak).expect("Error with init_encrypt()");
     let mut cipher: [u8; 32] = [0; 32];
     xtsstream.encrypt_update(&plain[0..16], &mut cipher[0..16]).expect("Error with encrypt_update()");
@@ -847,6 +865,7 @@ fn test_xtsstream() {
 #[test]
 #[cfg(aes_xts_stream)]
 fn test_xtsstream_big_msg() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     let key: [u8; 32] = [
         0xa1, 0xb9, 0x0c, 0xba, 0x3f, 0x06, 0xac, 0x35,
         0x3b, 0x2c, 0x34, 0x38, 0x76, 0x08, 0x17, 0x62,
@@ -858,7 +877,7 @@ fn test_xtsstream_big_msg() {
         0x6e, 0x4b, 0x92, 0x01, 0x3e, 0x76, 0x8a, 0xd5
     ];
 
-    let mut xtsstream = XTSStream::new().expect("Failed to create XTSStream");
+    let mut xtsstream = XTSStream::new(&wolfcrypt).expect("Failed to create XTSStream");
     xtsstream.init_encrypt(&key, &tweak).expect("Error with init_encrypt()");
     let mut cipher: [u8; 384] = [0; 384];
     let mut i = 0;
@@ -898,6 +917,7 @@ fn test_xtsstream_big_msg() {
 
 #[cfg(feature = "aead")]
 use aead::{Aead, AeadInPlace, KeyInit, Payload};
+use wolfssl_wolfcrypt::WolfCrypt;
 
 /// NIST SP 800-38D, Test Case 2:
 /// Key  = 00000000000000000000000000000000
@@ -2,10 +2,12 @@
 use wolfssl_wolfcrypt::blake2::*;
 #[cfg(any(blake2b, blake2s))]
 use wolfssl_wolfcrypt::sys;
+use wolfssl_wolfcrypt::WolfCrypt;
 
 #[test]
 #[cfg(blake2b)]
 fn test_blake2b() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     let expected_hashes: [&[u8]; 3] = [
         &[
             0x78, 0x6A, 0x02, 0xF7, 0x42, 0x01, 0x59, 0x03,
@@ -40,7 +42,7 @@ fn test_blake2b() {
     ];
 
     for (i, expected_hash) in expected_hashes.iter().enumerate() {
-        let mut blake2b = BLAKE2b::new(expected_hash.len()).expect("Error with new()");
+        let mut blake2b = BLAKE2b::new(expected_hash.len(), &wolfcrypt).expect("Error with new()");
         let mut input = vec![0u8; i];
         for idx in 0..input.len() {
             input[idx] = idx as u8;
@@ -55,7 +57,8 @@ fn test_blake2b() {
 #[test]
 #[cfg(blake2b)]
 fn test_blake2b_finalize_empty_buffer() {
-    let mut blake2b = BLAKE2b::new(64).expect("Error with new()");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut blake2b = BLAKE2b::new(64, &wolfcrypt).expect("Error with new()");
     let mut hash: [u8; 0] = [];
     let rc = blake2b.finalize(&mut hash).expect_err("finalize() should fail");
     assert_eq!(rc, sys::wolfCrypt_ErrorCodes_BUFFER_E);
@@ -64,6 +67,7 @@ fn test_blake2b_finalize_empty_buffer() {
 #[test]
 #[cfg(blake2b_hmac)]
 fn test_blake2b_hmac() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     let key1 = [0x41u8, 0x42, 0x43, 0x44];
     let message1 = [0x48u8, 0x65, 0x6c, 0x6c, 0x6f];
     let expected1 = [
@@ -110,14 +114,14 @@ fn test_blake2b_hmac() {
     BLAKE2bHmac::hmac(&message2, &key2, &mut out2).expect("Error with hmac()");
     assert_eq!(out2, expected2);
 
-    let mut hmac_blake2b = BLAKE2bHmac::new(&key1).expect("Error with new()");
+    let mut hmac_blake2b = BLAKE2bHmac::new(&key1, &wolfcrypt).expect("Error with new()");
     hmac_blake2b.update(&message1[0..4]).expect("Error with update()");
     hmac_blake2b.update(&message1[4..]).expect("Error with update()");
     let mut out1 = [0u8; 64];
     hmac_blake2b.finalize(&key1, &mut out1).expect("Error with finalize()");
     assert_eq!(out1, expected1);
 
-    let mut hmac_blake2b = BLAKE2bHmac::new(&key2).expect("Error with new()");
+    let mut hmac_blake2b = BLAKE2bHmac::new(&key2, &wolfcrypt).expect("Error with new()");
     hmac_blake2b.update(&message2[0..48]).expect("Error with update()");
     hmac_blake2b.update(&message2[48..]).expect("Error with update()");
     let mut out2 = [0u8; 64];
@@ -128,6 +132,7 @@ fn test_blake2b_hmac() {
 #[test]
 #[cfg(blake2s)]
 fn test_blake2s() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     let expected_hashes: [&[u8]; 3] = [
         &[
             0x69, 0x21, 0x7a, 0x30, 0x79, 0x90, 0x80, 0x94,
@@ -150,7 +155,7 @@ fn test_blake2s() {
     ];
 
     for (i, expected_hash) in expected_hashes.iter().enumerate() {
-        let mut blake2s = BLAKE2s::new(expected_hash.len()).expect("Error with new()");
+        let mut blake2s = BLAKE2s::new(expected_hash.len(), &wolfcrypt).expect("Error with new()");
         let mut input = vec![0u8; i];
         for idx in 0..input.len() {
             input[idx] = idx as u8;
@@ -165,7 +170,8 @@ fn test_blake2s() {
 #[test]
 #[cfg(blake2s)]
 fn test_blake2s_finalize_empty_buffer() {
-    let mut blake2s = BLAKE2s::new(32).expect("Error with new()");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut blake2s = BLAKE2s::new(32, &wolfcrypt).expect("Error with new()");
     let mut hash: [u8; 0] = [];
     let rc = blake2s.finalize(&mut hash).expect_err("finalize() should fail");
     assert_eq!(rc, sys::wolfCrypt_ErrorCodes_BUFFER_E);
@@ -174,6 +180,7 @@ fn test_blake2s_finalize_empty_buffer() {
 #[test]
 #[cfg(blake2s_hmac)]
 fn test_blake2s_hmac() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     let key1 = [0x41u8, 0x42, 0x43, 0x44];
     let message1 = [0x48u8, 0x65, 0x6c, 0x6c, 0x6f];
     let expected1 = [
@@ -209,14 +216,14 @@ fn test_blake2s_hmac() {
     BLAKE2sHmac::hmac(&message2, &key2, &mut out2).expect("Error with hmac()");
     assert_eq!(out2, expected2);
 
-    let mut hmac_blake2s = BLAKE2sHmac::new(&key1).expect("Error with new()");
+    let mut hmac_blake2s = BLAKE2sHmac::new(&key1, &wolfcrypt).expect("Error with new()");
     hmac_blake2s.update(&message1[0..4]).expect("Error with update()");
     hmac_blake2s.update(&message1[4..]).expect("Error with update()");
     let mut out1 = [0u8; 32];
     hmac_blake2s.finalize(&key1, &mut out1).expect("Error with finalize()");
     assert_eq!(out1, expected1);
 
-    let mut hmac_blake2s = BLAKE2sHmac::new(&key2).expect("Error with new()");
+    let mut hmac_blake2s = BLAKE2sHmac::new(&key2, &wolfcrypt).expect("Error with new()");
     hmac_blake2s.update(&message2[0..48]).expect("Error with update()");
     hmac_blake2s.update(&message2[48..]).expect("Error with update()");
     let mut out2 = [0u8; 32];
@@ -58,9 +58,9 @@ fn test_digest_blake2b_512() {
 fn test_digest_blake2b_384() {
     use wolfssl_wolfcrypt::blake2::BLAKE2b;
     use wolfssl_wolfcrypt::blake2_digest::Blake2b384;
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut reference = BLAKE2b::new(48).expect("Error with new()");
+    let mut reference = BLAKE2b::new(48, &wolfcrypt).expect("Error with new()");
     reference.update(b"abc").expect("Error with update()");
     let mut expected = [0u8; 48];
     reference.finalize(&mut expected).expect("Error with finalize()");
@@ -73,9 +73,9 @@ fn test_digest_blake2b_384() {
 fn test_digest_blake2b_256() {
     use wolfssl_wolfcrypt::blake2::BLAKE2b;
     use wolfssl_wolfcrypt::blake2_digest::Blake2b256;
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut reference = BLAKE2b::new(32).expect("Error with new()");
+    let mut reference = BLAKE2b::new(32, &wolfcrypt).expect("Error with new()");
     reference.update(b"abc").expect("Error with update()");
     let mut expected = [0u8; 32];
     reference.finalize(&mut expected).expect("Error with finalize()");
@@ -100,9 +100,9 @@ fn test_digest_blake2s_256() {
 fn test_digest_blake2s_192() {
     use wolfssl_wolfcrypt::blake2::BLAKE2s;
     use wolfssl_wolfcrypt::blake2_digest::Blake2s192;
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut reference = BLAKE2s::new(24).expect("Error with new()");
+    let mut reference = BLAKE2s::new(24, &wolfcrypt).expect("Error with new()");
     reference.update(b"abc").expect("Error with update()");
     let mut expected = [0u8; 24];
     reference.finalize(&mut expected).expect("Error with finalize()");
@@ -115,9 +115,9 @@ fn test_digest_blake2s_192() {
 fn test_digest_blake2s_128() {
     use wolfssl_wolfcrypt::blake2::BLAKE2s;
     use wolfssl_wolfcrypt::blake2_digest::Blake2s128;
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut reference = BLAKE2s::new(16).expect("Error with new()");
+    let mut reference = BLAKE2s::new(16, &wolfcrypt).expect("Error with new()");
     reference.update(b"abc").expect("Error with update()");
     let mut expected = [0u8; 16];
     reference.finalize(&mut expected).expect("Error with finalize()");
@@ -1,17 +1,19 @@
 #![cfg(all(any(blake2b, blake2s), feature = "mac"))]
 
 use digest::{KeyInit, Mac};
+use wolfssl_wolfcrypt::WolfCrypt;
 
 #[test]
 #[cfg(blake2b)]
 fn test_blake2b_mac_512() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     use wolfssl_wolfcrypt::blake2::BLAKE2b;
     use wolfssl_wolfcrypt::blake2_mac::Blake2bMac512;
 
     let key = [0x42u8; 64];
     let input = b"The quick brown fox jumps over the lazy dog";
 
-    let mut reference = BLAKE2b::new_with_key(64, &key)
+    let mut reference = BLAKE2b::new_with_key(64, &key, &wolfcrypt)
         .expect("Error with new_with_key()");
     reference.update(input).expect("Error with update()");
     let mut expected = [0u8; 64];
@@ -28,13 +30,14 @@ fn test_blake2b_mac_512() {
 #[test]
 #[cfg(blake2b)]
 fn test_blake2b_mac_256() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     use wolfssl_wolfcrypt::blake2::BLAKE2b;
     use wolfssl_wolfcrypt::blake2_mac::Blake2bMac256;
 
     let key = [0x33u8; 64];
     let input = b"libsodium crypto_generichash analog";
 
-    let mut reference = BLAKE2b::new_with_key(32, &key)
+    let mut reference = BLAKE2b::new_with_key(32, &key, &wolfcrypt)
         .expect("Error with new_with_key()");
     reference.update(input).expect("Error with update()");
     let mut expected = [0u8; 32];
@@ -51,13 +54,14 @@ fn test_blake2b_mac_256() {
 #[test]
 #[cfg(blake2b)]
 fn test_blake2b_mac_384() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     use wolfssl_wolfcrypt::blake2::BLAKE2b;
     use wolfssl_wolfcrypt::blake2_mac::Blake2bMac384;
 
     let key = [0x77u8; 64];
     let input = b"sha-384 sized blake2b mac";
 
-    let mut reference = BLAKE2b::new_with_key(48, &key)
+    let mut reference = BLAKE2b::new_with_key(48, &key, &wolfcrypt)
         .expect("Error with new_with_key()");
     reference.update(input).expect("Error with update()");
     let mut expected = [0u8; 48];
@@ -74,13 +78,14 @@ fn test_blake2b_mac_384() {
 #[test]
 #[cfg(blake2b)]
 fn test_blake2b_mac_512_chunked() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     use wolfssl_wolfcrypt::blake2::BLAKE2b;
     use wolfssl_wolfcrypt::blake2_mac::Blake2bMac512;
 
     let key = [0xA5u8; 64];
     let input: Vec<u8> = (0u8..200).collect();
 
-    let mut reference = BLAKE2b::new_with_key(64, &key)
+    let mut reference = BLAKE2b::new_with_key(64, &key, &wolfcrypt)
         .expect("Error with new_with_key()");
     reference.update(&input).expect("Error with update()");
     let mut expected = [0u8; 64];
@@ -99,13 +104,14 @@ fn test_blake2b_mac_512_chunked() {
 #[test]
 #[cfg(blake2s)]
 fn test_blake2s_mac_128() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     use wolfssl_wolfcrypt::blake2::BLAKE2s;
     use wolfssl_wolfcrypt::blake2_mac::Blake2sMac128;
 
     let key = [0x55u8; 32];
     let input = b"short blake2s mac";
 
-    let mut reference = BLAKE2s::new_with_key(16, &key)
+    let mut reference = BLAKE2s::new_with_key(16, &key, &wolfcrypt)
         .expect("Error with new_with_key()");
     reference.update(input).expect("Error with update()");
     let mut expected = [0u8; 16];
@@ -122,13 +128,14 @@ fn test_blake2s_mac_128() {
 #[test]
 #[cfg(blake2s)]
 fn test_blake2s_mac_192() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     use wolfssl_wolfcrypt::blake2::BLAKE2s;
     use wolfssl_wolfcrypt::blake2_mac::Blake2sMac192;
 
     let key = [0x99u8; 32];
     let input = b"medium blake2s mac";
 
-    let mut reference = BLAKE2s::new_with_key(24, &key)
+    let mut reference = BLAKE2s::new_with_key(24, &key, &wolfcrypt)
         .expect("Error with new_with_key()");
     reference.update(input).expect("Error with update()");
     let mut expected = [0u8; 24];
@@ -145,13 +152,14 @@ fn test_blake2s_mac_192() {
 #[test]
 #[cfg(blake2s)]
 fn test_blake2s_mac_256() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     use wolfssl_wolfcrypt::blake2::BLAKE2s;
     use wolfssl_wolfcrypt::blake2_mac::Blake2sMac256;
 
     let key = [0x42u8; 32];
     let input = b"The quick brown fox jumps over the lazy dog";
 
-    let mut reference = BLAKE2s::new_with_key(32, &key)
+    let mut reference = BLAKE2s::new_with_key(32, &key, &wolfcrypt)
         .expect("Error with new_with_key()");
     reference.update(input).expect("Error with update()");
     let mut expected = [0u8; 32];
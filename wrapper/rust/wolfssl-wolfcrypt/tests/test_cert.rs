@@ -0,0 +1,130 @@
+#![cfg(cert)]
+
+mod common;
+
+use std::fs;
+use wolfssl_wolfcrypt::cert::*;
+
+fn read_cert(path: &str) -> Vec<u8> {
+    fs::read(path).expect("Error reading cert file")
+}
+
+#[test]
+fn test_parse_rsa_cert() {
+    let wolfcrypt = common::setup();
+
+    let der = read_cert("../../../certs/server-cert.der");
+    let cert = DecodedCert::parse(&der, &wolfcrypt).expect("Error with parse()");
+
+    assert_eq!(cert.version(), 3);
+    assert_eq!(cert.serial(), &[0x01]);
+    assert_eq!(cert.subject().unwrap(),
+        "/C=US/ST=Montana/L=Bozeman/O=wolfSSL/OU=Support/CN=www.wolfssl.com/emailAddress=facts@wolfssl.com");
+    assert_eq!(cert.issuer().unwrap(),
+        "/C=US/ST=Montana/L=Bozeman/O=Sawtooth/OU=Consulting/CN=www.wolfssl.com/emailAddress=facts@wolfssl.com");
+    assert_eq!(cert.common_name(), Some("www.wolfssl.com"));
+    assert!(!cert.is_self_signed());
+    assert_eq!(cert.key_type(), KeyType::Rsa);
+
+    let not_before = cert.not_before().expect("Error with not_before()");
+    let not_after = cert.not_after().expect("Error with not_after()");
+    assert!(not_before < not_after);
+    assert!(not_before.unix_time() < not_after.unix_time());
+
+    let bc = cert.basic_constraints().expect("Error with basic_constraints()");
+    assert!(bc.ca);
+    assert_eq!(cert.key_usage(), None);
+    assert_eq!(cert.ext_key_usage(),
+        Some(DecodedCert::EXT_KEY_USAGE_SERVER_AUTH | DecodedCert::EXT_KEY_USAGE_CLIENT_AUTH));
+
+    let names: Vec<GeneralName> = cert.subject_alt_names().collect();
+    assert!(names.contains(&GeneralName::Dns("example.com")));
+    assert!(names.contains(&GeneralName::Ip(&[127, 0, 0, 1])));
+
+    // TBSCertificate is the first element of the outer SEQUENCE.
+    let tbs = cert.tbs();
+    assert_eq!(tbs[0], 0x30);
+    assert!(der.windows(tbs.len()).any(|w| w == tbs));
+    assert_eq!(cert.signature().len(), 256);
+    assert!(der.ends_with(cert.signature()));
+
+    let mut spki = [0u8; 512];
+    let spki_len = cert.public_key_info(&mut spki).expect("Error with public_key_info()");
+    assert!(spki_len > cert.public_key().len());
+
+    #[cfg(rsa)]
+    {
+        let rsa = cert.rsa_public_key().expect("Error with rsa_public_key()");
+        assert_eq!(rsa.get_encrypt_size().expect("Error with get_encrypt_size()"), 256);
+    }
+    #[cfg(ecc)]
+    assert!(cert.ecc_public_key().is_err());
+}
+
+#[test]
+fn test_parse_invalid() {
+    let wolfcrypt = common::setup();
+
+    let der = read_cert("../../../certs/server-cert.der");
+    assert!(DecodedCert::parse(&der[..der.len() / 2], &wolfcrypt).is_err());
+    assert!(DecodedCert::parse(&[], &wolfcrypt).is_err());
+}
+
+#[test]
+#[cfg(ecc)]
+fn test_parse_ecc_cert() {
+    let wolfcrypt = common::setup();
+
+    let der = read_cert("../../../certs/ca-ecc-cert.der");
+    let cert = DecodedCert::parse(&der, &wolfcrypt).expect("Error with parse()");
+
+    assert_eq!(cert.key_type(), KeyType::Ecc);
+    assert!(cert.is_self_signed());
+    let bc = cert.basic_constraints().expect("Error with basic_constraints()");
+    assert!(bc.ca);
+    assert_eq!(cert.key_usage(), Some(DecodedCert::KEY_USAGE_DIGITAL_SIGNATURE
+        | DecodedCert::KEY_USAGE_KEY_CERT_SIGN | DecodedCert::KEY_USAGE_CRL_SIGN));
+
+    let mut ecc = cert.ecc_public_key().expect("Error with ecc_public_key()");
+    ecc.check().expect("Error with check()");
+}
+
+#[test]
+#[cfg(all(ed25519, ed25519_import))]
+fn test_parse_ed25519_cert() {
+    let wolfcrypt = common::setup();
+
+    let der = read_cert("../../../certs/ed25519/ca-ed25519.der");
+    let cert = DecodedCert::parse(&der, &wolfcrypt).expect("Error with parse()");
+
+    assert_eq!(cert.key_type(), KeyType::Ed25519);
+    assert_eq!(cert.public_key().len(), 32);
+    assert_eq!(cert.signature().len(), 64);
+    cert.ed25519_public_key().expect("Error with ed25519_public_key()");
+}
+
+#[test]
+#[cfg(all(ed448, ed448_import))]
+fn test_parse_ed448_cert() {
+    let wolfcrypt = common::setup();
+
+    let der = read_cert("../../../certs/ed448/ca-ed448.der");
+    let cert = DecodedCert::parse(&der, &wolfcrypt).expect("Error with parse()");
+
+    assert_eq!(cert.key_type(), KeyType::Ed448);
+    assert_eq!(cert.public_key().len(), 57);
+    assert_eq!(cert.signature().len(), 114);
+    cert.ed448_public_key().expect("Error with ed448_public_key()");
+}
+
+#[test]
+#[cfg(all(mldsa, mldsa_import))]
+fn test_parse_mldsa_cert() {
+    let wolfcrypt = common::setup();
+
+    let der = read_cert("../../../certs/mldsa/mldsa44-cert.der");
+    let cert = DecodedCert::parse(&der, &wolfcrypt).expect("Error with parse()");
+
+    assert_eq!(cert.key_type(), KeyType::MlDsa44);
+    cert.mldsa_public_key().expect("Error with mldsa_public_key()");
+}
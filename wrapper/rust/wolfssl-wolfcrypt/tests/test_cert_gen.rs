@@ -0,0 +1,202 @@
+#![cfg(all(cert, cert_gen, random))]
+
+mod common;
+
+#[cfg(ecc)]
+use wolfssl_wolfcrypt::Env;
+use wolfssl_wolfcrypt::cert::*;
+use wolfssl_wolfcrypt::cert_gen::*;
+use wolfssl_wolfcrypt::random::RNG;
+
+fn ca_name() -> Name<'static> {
+    Name {
+        country: Some("US"),
+        state: Some("Montana"),
+        org: Some("wolfSSL"),
+        common_name: Some("Test Root CA"),
+        ..Default::default()
+    }
+}
+
+#[test]
+#[cfg(ecc)]
+fn test_self_signed_ecc() {
+    use wolfssl_wolfcrypt::ecc::ECC;
+    let wolfcrypt = common::setup();
+
+    let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut key = ECC::generate(32, &rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
+    let mut builder = CertBuilder::new(&wolfcrypt).expect("Error with new()");
+    builder.set_subject(&ca_name()).expect("Error with set_subject()");
+    builder.set_serial(&[0x01, 0x02, 0x03]).expect("Error with set_serial()");
+    let not_before = Time::from_unix_time(1_700_000_000).unwrap();
+    let not_after = Time::from_unix_time(2_700_000_000).unwrap();
+    builder.set_validity(&not_before, &not_after).expect("Error with set_validity()");
+    builder.set_basic_constraints(&BasicConstraints { ca: true, path_len: Some(1) })
+        .expect("Error with set_basic_constraints()");
+    #[cfg(cert_ext)]
+    {
+        builder.set_key_usage(DecodedCert::KEY_USAGE_KEY_CERT_SIGN | DecodedCert::KEY_USAGE_CRL_SIGN);
+        builder.set_subject_key_id(&mut key).expect("Error with set_subject_key_id()");
+    }
+
+    let mut der = [0u8; 2048];
+    let der_size = builder.sign_self(&mut key, &rng, &mut der).expect("Error with sign_self()");
+    let cert = DecodedCert::parse(&der[..der_size], &wolfcrypt).expect("Error with parse()");
+
+    assert_eq!(cert.subject().unwrap(), "/C=US/ST=Montana/O=wolfSSL/CN=Test Root CA");
+    assert_eq!(cert.issuer().unwrap(), cert.subject().unwrap());
+    assert!(cert.is_self_signed());
+    assert_eq!(cert.serial(), &[0x01, 0x02, 0x03]);
+    assert_eq!(cert.not_before(), Some(not_before));
+    assert_eq!(cert.not_after(), Some(not_after));
+    assert_eq!(cert.basic_constraints(), Some(BasicConstraints { ca: true, path_len: Some(1) }));
+    assert_eq!(cert.key_type(), KeyType::Ecc);
+    assert_eq!(cert.signature_oid(), wolfssl_wolfcrypt::sys::Ctc_SigType_CTC_SHA256wECDSA);
+    #[cfg(cert_ext)]
+    assert_eq!(cert.key_usage(),
+        Some(DecodedCert::KEY_USAGE_KEY_CERT_SIGN | DecodedCert::KEY_USAGE_CRL_SIGN));
+}
+
+#[test]
+#[cfg(all(ecc, ed25519, cert_alt_names))]
+fn test_issued_cert() {
+    use wolfssl_wolfcrypt::ecc::ECC;
+    use wolfssl_wolfcrypt::ed25519::Ed25519;
+    let wolfcrypt = common::setup();
+
+    let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut ca_key = ECC::generate(32, &rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
+    let mut ca = CertBuilder::new(&wolfcrypt).expect("Error with new()");
+    ca.set_subject(&ca_name()).expect("Error with set_subject()");
+    ca.set_basic_constraints(&BasicConstraints { ca: true, path_len: None })
+        .expect("Error with set_basic_constraints()");
+    let mut ca_der = [0u8; 2048];
+    let ca_der_size = ca.sign_self(&mut ca_key, &rng, &mut ca_der).expect("Error with sign_self()");
+    let ca_der = &ca_der[..ca_der_size];
+
+    let mut leaf_key = Ed25519::generate(&rng, &wolfcrypt).expect("Error with generate()");
+    let mut leaf = CertBuilder::new(&wolfcrypt).expect("Error with new()");
+    leaf.set_subject(&Name {
+        common_name: Some("device-0001"),
+        serial_number: Some("0001"),
+        ..Default::default()
+    }).expect("Error with set_subject()");
+    leaf.set_issuer_from_cert(ca_der).expect("Error with set_issuer_from_cert()");
+    leaf.set_days_valid(365);
+    leaf.add_alt_name(&GeneralName::Dns("device-0001.example.com"))
+        .expect("Error with add_alt_name()");
+    leaf.add_alt_name(&GeneralName::Ip(&[192, 168, 1, 1])).expect("Error with add_alt_name()");
+    leaf.add_alt_name(&GeneralName::Email("ops@example.com")).expect("Error with add_alt_name()");
+    assert!(leaf.add_alt_name(&GeneralName::Ip(&[1, 2, 3])).is_err());
+    #[cfg(cert_ext)]
+    {
+        leaf.set_ext_key_usage(DecodedCert::EXT_KEY_USAGE_CLIENT_AUTH);
+        leaf.set_auth_key_id(&mut ca_key).expect("Error with set_auth_key_id()");
+    }
+
+    let mut der = [0u8; 2048];
+    let der_size = leaf.sign(&mut leaf_key, &mut ca_key, &rng, &mut der).expect("Error with sign()");
+    let cert = DecodedCert::parse(&der[..der_size], &wolfcrypt).expect("Error with parse()");
+
+    let ca_cert = DecodedCert::parse(ca_der, &wolfcrypt).expect("Error with parse()");
+    assert_eq!(cert.issuer().unwrap(), ca_cert.subject().unwrap());
+    assert!(!cert.is_self_signed());
+    assert_eq!(cert.common_name(), Some("device-0001"));
+    assert_eq!(cert.key_type(), KeyType::Ed25519);
+    assert_eq!(cert.basic_constraints(), None);
+    // The order of the decoded names depends on the wolfSSL build options.
+    let names: Vec<GeneralName> = cert.subject_alt_names().collect();
+    assert_eq!(names.len(), 3);
+    assert!(names.contains(&GeneralName::Dns("device-0001.example.com")));
+    assert!(names.contains(&GeneralName::Ip(&[192, 168, 1, 1])));
+    assert!(names.contains(&GeneralName::Email("ops@example.com")));
+    #[cfg(cert_ext)]
+    assert_eq!(cert.ext_key_usage(), Some(DecodedCert::EXT_KEY_USAGE_CLIENT_AUTH));
+    let not_before = cert.not_before().unwrap().unix_time();
+    let not_after = cert.not_after().unwrap().unix_time();
+    // wolfSSL backdates notBefore by one day.
+    assert_eq!(not_after - not_before, 366 * 86400);
+}
+
+#[test]
+#[cfg(all(mldsa, mldsa_make_key))]
+fn test_self_signed_mldsa() {
+    use wolfssl_wolfcrypt::mldsa::MlDsa;
+    let wolfcrypt = common::setup();
+
+    let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut key = MlDsa::generate(MlDsa::LEVEL_44, &rng, &wolfcrypt).expect("Error with generate()");
+    let mut builder = CertBuilder::new(&wolfcrypt).expect("Error with new()");
+    builder.set_subject(&ca_name()).expect("Error with set_subject()");
+    let mut der = vec![0u8; 8192];
+    let der_size = builder.sign_self(&mut key, &rng, &mut der).expect("Error with sign_self()");
+    let cert = DecodedCert::parse(&der[..der_size], &wolfcrypt).expect("Error with parse()");
+    assert_eq!(cert.key_type(), KeyType::MlDsa44);
+}
+
+#[test]
+fn test_invalid_args() {
+    let wolfcrypt = common::setup();
+
+    let mut builder = CertBuilder::new(&wolfcrypt).expect("Error with new()");
+    let long = "x".repeat(256);
+    assert!(builder.set_subject(&Name { common_name: Some(&long), ..Default::default() }).is_err());
+    assert!(builder.set_subject(&Name { common_name: Some("a\0b"), ..Default::default() }).is_err());
+    assert!(builder.set_serial(&[]).is_err());
+    assert!(builder.set_serial(&[0u8; 21]).is_err());
+    assert!(builder.set_basic_constraints(&BasicConstraints { ca: false, path_len: Some(0) }).is_err());
+    assert!(builder.set_basic_constraints(&BasicConstraints { ca: true, path_len: Some(256) }).is_err());
+    let t1 = Time::from_unix_time(2_000_000_000).unwrap();
+    let t2 = Time::from_unix_time(1_000_000_000).unwrap();
+    assert!(builder.set_validity(&t1, &t2).is_err());
+}
+
+#[test]
+#[cfg(all(ecc, cert_req))]
+fn test_csr() {
+    use wolfssl_wolfcrypt::ecc::ECC;
+    let wolfcrypt = common::setup();
+
+    let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut key = ECC::generate(48, &rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
+    let mut csr = CsrBuilder::new(&wolfcrypt).expect("Error with new()");
+    csr.set_subject(&Name {
+        org: Some("wolfSSL"),
+        common_name: Some("device-0002"),
+        ..Default::default()
+    }).expect("Error with set_subject()");
+    csr.set_challenge_password("secret").expect("Error with set_challenge_password()");
+    #[cfg(cert_alt_names)]
+    csr.add_alt_name(&GeneralName::Dns("device-0002.example.com"))
+        .expect("Error with add_alt_name()");
+    let mut der = [0u8; 2048];
+    let der_size = csr.sign(&mut key, &rng, &mut der).expect("Error with sign()");
+    assert_eq!(der[0], 0x30);
+    assert!(der_size > 0);
+
+    #[cfg(all(der_to_pem, feature = "alloc"))]
+    {
+        let mut pem = [0u8; 4096];
+        let pem_size = csr.sign_pem(&mut key, &rng, &mut pem).expect("Error with sign_pem()");
+        let pem = std::str::from_utf8(&pem[..pem_size]).unwrap();
+        assert!(pem.starts_with("-----BEGIN CERTIFICATE REQUEST-----"));
+    }
+}
+
+#[test]
+#[cfg(all(rsa, der_to_pem, feature = "alloc"))]
+fn test_self_signed_rsa_pem() {
+    use wolfssl_wolfcrypt::rsa::RSA;
+    let wolfcrypt = common::setup();
+
+    let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut key = RSA::generate(2048, 65537, &rng, &wolfcrypt).expect("Error with generate()");
+    let mut builder = CertBuilder::new(&wolfcrypt).expect("Error with new()");
+    builder.set_subject(&ca_name()).expect("Error with set_subject()");
+    let mut pem = [0u8; 4096];
+    let pem_size = builder.sign_self_pem(&mut key, &rng, &mut pem).expect("Error with sign_self_pem()");
+    let pem = std::str::from_utf8(&pem[..pem_size]).unwrap();
+    assert!(pem.starts_with("-----BEGIN CERTIFICATE-----"));
+    assert!(pem.trim_end().ends_with("-----END CERTIFICATE-----"));
+}
@@ -1,10 +1,12 @@
 #![cfg(chacha20_poly1305)]
 
 use wolfssl_wolfcrypt::chacha20_poly1305::*;
+use wolfssl_wolfcrypt::Error;
 use wolfssl_wolfcrypt::sys;
 
 #[test]
 fn test_chacha20_poly1305_1() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     let key1 = [
         0x80u8, 0x81, 0x82, 0x83, 0x84, 0x85, 0x86, 0x87,
         0x88, 0x89, 0x8a, 0x8b, 0x8c, 0x8d, 0x8e, 0x8f,
@@ -64,7 +66,7 @@ fn test_chacha20_poly1305_1() {
     ];
 
     /* Encrypt */
-    let mut ccp = ChaCha20Poly1305::new(&key1, &iv1, true).expect("Error with new()");
+    let mut ccp = ChaCha20Poly1305::new(&key1, &iv1, true, &wolfcrypt).expect("Error with new()");
     ccp.update_aad(&aad1).expect("Error with update_aad()");
     let mut out_cipher1 = [0u8; 114];
     ccp.update_data(&plaintext1, &mut out_cipher1).expect("Error with update_data()");
@@ -74,7 +76,7 @@ fn test_chacha20_poly1305_1() {
     assert_eq!(out_auth_tag_1, auth_tag_1);
 
     /* Decrypt */
-    let mut ccp = ChaCha20Poly1305::new(&key1, &iv1, false).expect("Error with new()");
+    let mut ccp = ChaCha20Poly1305::new(&key1, &iv1, false, &wolfcrypt).expect("Error with new()");
     ccp.update_aad(&aad1).expect("Error with update_aad()");
     let mut out_plaintext1 = [0u8; 114];
     ccp.update_data(&cipher1, &mut out_plaintext1).expect("Error with update_data()");
@@ -86,6 +88,7 @@ fn test_chacha20_poly1305_1() {
 
 #[test]
 fn test_chacha20_poly1305_2() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     let key2 = [
         0x1cu8, 0x92, 0x40, 0xa5, 0xeb, 0x55, 0xd3, 0x8a,
         0xf3, 0x33, 0x88, 0x86, 0x04, 0xf6, 0xb5, 0xf0,
@@ -183,7 +186,7 @@ fn test_chacha20_poly1305_2() {
     ];
 
     /* Encrypt */
-    let mut ccp = ChaCha20Poly1305::new(&key2, &iv2, true).expect("Error with new()");
+    let mut ccp = ChaCha20Poly1305::new(&key2, &iv2, true, &wolfcrypt).expect("Error with new()");
     ccp.update_aad(&aad2).expect("Error with update_aad()");
     let mut out_cipher2 = [0u8; 265];
     ccp.update_data(&plaintext2[0..128], &mut out_cipher2[0..128]).expect("Error with update_data()");
@@ -194,7 +197,7 @@ fn test_chacha20_poly1305_2() {
     assert_eq!(out_auth_tag_2, auth_tag_2);
 
     /* Decrypt */
-    let mut ccp = ChaCha20Poly1305::new(&key2, &iv2, false).expect("Error with new()");
+    let mut ccp = ChaCha20Poly1305::new(&key2, &iv2, false, &wolfcrypt).expect("Error with new()");
     ccp.update_aad(&aad2).expect("Error with update_aad()");
     let mut out_plaintext2 = [0u8; 265];
     ccp.update_data(&cipher2[0..128], &mut out_plaintext2[0..128]).expect("Error with update_data()");
@@ -207,6 +210,7 @@ fn test_chacha20_poly1305_2() {
 
 #[test]
 fn test_chacha20_poly1305_finalize_verify() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     let key1 = [
         0x80u8, 0x81, 0x82, 0x83, 0x84, 0x85, 0x86, 0x87,
         0x88, 0x89, 0x8a, 0x8b, 0x8c, 0x8d, 0x8e, 0x8f,
@@ -243,7 +247,7 @@ fn test_chacha20_poly1305_finalize_verify() {
     ];
 
     /* Encrypt to obtain cipher text and authentication tag. */
-    let mut ccp = ChaCha20Poly1305::new(&key1, &iv1, true).expect("Error with new()");
+    let mut ccp = ChaCha20Poly1305::new(&key1, &iv1, true, &wolfcrypt).expect("Error with new()");
     ccp.update_aad(&aad1).expect("Error with update_aad()");
     let mut cipher1 = [0u8; 114];
     ccp.update_data(&plaintext1, &mut cipher1).expect("Error with update_data()");
@@ -251,7 +255,7 @@ fn test_chacha20_poly1305_finalize_verify() {
     ccp.finalize(&mut auth_tag_1).expect("Error with finalize()");
 
     /* Decrypt and verify the correct authentication tag. */
-    let mut ccp = ChaCha20Poly1305::new(&key1, &iv1, false).expect("Error with new()");
+    let mut ccp = ChaCha20Poly1305::new(&key1, &iv1, false, &wolfcrypt).expect("Error with new()");
     ccp.update_aad(&aad1).expect("Error with update_aad()");
     let mut out_plaintext1 = [0u8; 114];
     ccp.update_data(&cipher1, &mut out_plaintext1).expect("Error with update_data()");
@@ -261,12 +265,12 @@ fn test_chacha20_poly1305_finalize_verify() {
     /* Decrypt and verify a tampered authentication tag, which must fail. */
     let mut bad_auth_tag = auth_tag_1;
     bad_auth_tag[0] ^= 0xff;
-    let mut ccp = ChaCha20Poly1305::new(&key1, &iv1, false).expect("Error with new()");
+    let mut ccp = ChaCha20Poly1305::new(&key1, &iv1, false, &wolfcrypt).expect("Error with new()");
     ccp.update_aad(&aad1).expect("Error with update_aad()");
     let mut out_plaintext1 = [0u8; 114];
     ccp.update_data(&cipher1, &mut out_plaintext1).expect("Error with update_data()");
     let rc = ccp.finalize_verify(&bad_auth_tag);
-    assert_eq!(rc, Err(sys::wolfCrypt_ErrorCodes_MAC_CMP_FAILED_E));
+    assert_eq!(rc, Err(Error::from(sys::wolfCrypt_ErrorCodes_MAC_CMP_FAILED_E)));
 }
 
 #[test]
@@ -373,6 +377,7 @@ fn test_chacha20_poly1305_decrypt_short_plaintext_buffer() {
 
 #[cfg(feature = "aead")]
 use aead::{Aead, AeadInPlace, KeyInit, Payload};
+use wolfssl_wolfcrypt::WolfCrypt;
 
 /// RFC 8439, Section 2.8.2 test vector.
 ///
@@ -1,10 +1,14 @@
 #![cfg(cmac)]
 
+#[cfg(aes)]
+use wolfssl_wolfcrypt::Env;
 use wolfssl_wolfcrypt::cmac::CMAC;
+use wolfssl_wolfcrypt::WolfCrypt;
 
 #[test]
 #[cfg(aes)]
 fn test_cmac() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     let key = [
         0x2bu8, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6,
         0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c
@@ -21,7 +25,7 @@ fn test_cmac() {
         0x06u8, 0x0a, 0x16, 0xb4, 0x6b, 0x4d, 0x41, 0x44,
         0xf7, 0x9b, 0xdd, 0x9d, 0xd0, 0x4a, 0x28, 0x7c
     ];
-    let mut cmac = CMAC::new(&key).expect("Error with new()");
+    let mut cmac = CMAC::new(&key, &wolfcrypt).expect("Error with new()");
     cmac.update(&message).expect("Error with update()");
     let mut finalize_out = [0u8; 16];
     cmac.finalize(&mut finalize_out).expect("Error with finalize()");
@@ -35,12 +39,12 @@ fn test_cmac() {
     let valid = CMAC::verify(&key, &message, &incorrect_cmac).expect("Error with verify()");
     assert!(!valid);
 
-    let mut cmac = CMAC::new(&key).expect("Error with new()");
+    let mut cmac = CMAC::new(&key, &wolfcrypt).expect("Error with new()");
     let mut generate_out = [0u8; 16];
-    cmac.generate_ex(&key, &message, &mut generate_out, None, None).expect("Error with generate_ex()");
+    cmac.generate_ex(&key, &message, &mut generate_out, &Env::new(&wolfcrypt)).expect("Error with generate_ex()");
     assert_eq!(generate_out, finalize_out);
-    let valid = cmac.verify_ex(&key, &message, &generate_out, None, None).expect("Error with verify_ex()");
+    let valid = cmac.verify_ex(&key, &message, &generate_out, &Env::new(&wolfcrypt)).expect("Error with verify_ex()");
     assert!(valid);
-    let valid = cmac.verify_ex(&key, &message, &incorrect_cmac, None, None).expect("Error with verify_ex()");
+    let valid = cmac.verify_ex(&key, &message, &incorrect_cmac, &Env::new(&wolfcrypt)).expect("Error with verify_ex()");
     assert!(!valid);
 }
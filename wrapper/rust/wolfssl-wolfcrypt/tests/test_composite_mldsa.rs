@@ -0,0 +1,214 @@
+#![cfg(all(mldsa, mldsa_make_key_from_seed, mldsa_sign, mldsa_verify,
+    mldsa_import, mldsa_export, sha256, sha512, random,
+    any(all(ecc, ecc_sign, ecc_verify, ecc_import, ecc_export, ecc_key_to_der, sha384),
+        all(ed25519, ed25519_sign, ed25519_verify, ed25519_import, ed25519_export))))]
+
+mod common;
+
+use wolfssl_wolfcrypt::composite_mldsa::CompositeMlDsa;
+use wolfssl_wolfcrypt::random::RNG;
+use wolfssl_wolfcrypt::Error;
+use wolfssl_wolfcrypt::sys;
+
+/// Composite algorithms available in this build.
+fn algs() -> Vec<u32> {
+    #[allow(unused_mut)]
+    let mut algs = Vec::new();
+    #[cfg(all(ed25519, ed25519_sign, ed25519_verify, ed25519_import, ed25519_export))]
+    {
+        #[cfg(mldsa_level2)]
+        algs.push(CompositeMlDsa::MLDSA44_ED25519_SHA512);
+        #[cfg(mldsa_level3)]
+        algs.push(CompositeMlDsa::MLDSA65_ED25519_SHA512);
+    }
+    #[cfg(all(ecc, ecc_sign, ecc_verify, ecc_import, ecc_export, ecc_key_to_der, sha384))]
+    {
+        #[cfg(mldsa_level2)]
+        algs.push(CompositeMlDsa::MLDSA44_ECDSA_P256_SHA256);
+        #[cfg(mldsa_level3)]
+        algs.push(CompositeMlDsa::MLDSA65_ECDSA_P256_SHA512);
+        #[cfg(mldsa_level3)]
+        algs.push(CompositeMlDsa::MLDSA65_ECDSA_P384_SHA512);
+        #[cfg(mldsa_level5)]
+        algs.push(CompositeMlDsa::MLDSA87_ECDSA_P384_SHA512);
+    }
+    algs
+}
+
+fn is_ed25519(alg: u32) -> bool {
+    alg == CompositeMlDsa::MLDSA44_ED25519_SHA512 ||
+        alg == CompositeMlDsa::MLDSA65_ED25519_SHA512
+}
+
+#[test]
+fn test_invalid_alg() {
+    let wolfcrypt = common::setup();
+    let rng = RNG::new(&wolfcrypt).expect("Error with RNG::new()");
+    assert_eq!(CompositeMlDsa::generate(99, &rng, &wolfcrypt).err(),
+        Some(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG)));
+    assert_eq!(CompositeMlDsa::import_public(99, &[0u8; 64], &wolfcrypt).err(),
+        Some(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG)));
+}
+
+#[test]
+fn test_sign_verify() {
+    let wolfcrypt = common::setup();
+    let rng = RNG::new(&wolfcrypt).expect("Error with RNG::new()");
+    let message = b"composite message";
+    let ctx = b"composite context";
+    for alg in algs() {
+        let mut key = CompositeMlDsa::generate(alg, &rng, &wolfcrypt).expect("Error with generate()");
+        assert_eq!(key.alg(), alg);
+        assert!(key.label().starts_with(b"COMPSIG-MLDSA"));
+        let mut sig = [0u8; CompositeMlDsa::MAX_SIG_SIZE];
+        let sig_len = key.sign_ctx_msg(ctx, message, &mut sig, &rng)
+            .expect("Error with sign_ctx_msg()");
+        assert!(sig_len <= key.sig_size());
+        let sig = &mut sig[..sig_len];
+        assert!(key.verify_ctx_msg(sig, ctx, message).expect("Error with verify_ctx_msg()"));
+
+        /* Wrong context or message */
+        assert!(!key.verify_ctx_msg(sig, b"other context", message)
+            .expect("Error with verify_ctx_msg()"));
+        assert!(!key.verify_msg(sig, message).expect("Error with verify_msg()"));
+        assert!(!key.verify_ctx_msg(sig, ctx, b"other message")
+            .expect("Error with verify_ctx_msg()"));
+
+        /* Each component must verify */
+        sig[0] ^= 1;
+        assert!(!key.verify_ctx_msg(sig, ctx, message).expect("Error with verify_ctx_msg()"));
+        sig[0] ^= 1;
+        let trad_index = if is_ed25519(alg) { sig_len - 64 } else { sig_len - 1 };
+        sig[trad_index] ^= 1;
+        assert!(!key.verify_ctx_msg(sig, ctx, message).expect("Error with verify_ctx_msg()"));
+        sig[trad_index] ^= 1;
+
+        /* Truncated signature */
+        assert!(!matches!(key.verify_ctx_msg(&sig[..sig_len - 1], ctx, message), Ok(true)));
+    }
+}
+
+#[test]
+fn test_ctx_too_long() {
+    let wolfcrypt = common::setup();
+    let rng = RNG::new(&wolfcrypt).expect("Error with RNG::new()");
+    for alg in algs() {
+        let mut key = CompositeMlDsa::generate(alg, &rng, &wolfcrypt).expect("Error with generate()");
+        let ctx = [0u8; CompositeMlDsa::MAX_CTX_SIZE + 1];
+        let mut sig = [0u8; CompositeMlDsa::MAX_SIG_SIZE];
+        assert_eq!(key.sign_ctx_msg(&ctx, b"message", &mut sig, &rng),
+            Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E)));
+    }
+}
+
+#[test]
+fn test_import_export() {
+    let wolfcrypt = common::setup();
+    let rng = RNG::new(&wolfcrypt).expect("Error with RNG::new()");
+    let message = b"composite message";
+    for alg in algs() {
+        let mut key = CompositeMlDsa::generate(alg, &rng, &wolfcrypt).expect("Error with generate()");
+        let mut public = [0u8; CompositeMlDsa::MAX_PUB_KEY_SIZE];
+        let public_len = key.export_public(&mut public).expect("Error with export_public()");
+        assert_eq!(public_len, key.pub_size());
+        let public = &public[..public_len];
+        let mut private = [0u8; CompositeMlDsa::MAX_PRV_KEY_SIZE];
+        let private_len = key.export_private(&mut private).expect("Error with export_private()");
+        let private = &private[..private_len];
+
+        /* A key imported from the private key signs for the same public key. */
+        let mut key2 = CompositeMlDsa::import_private(alg, private, &wolfcrypt)
+            .expect("Error with import_private()");
+        let mut public2 = [0u8; CompositeMlDsa::MAX_PUB_KEY_SIZE];
+        let public2_len = key2.export_public(&mut public2).expect("Error with export_public()");
+        assert_eq!(&public2[..public2_len], public);
+        let mut sig = [0u8; CompositeMlDsa::MAX_SIG_SIZE];
+        let sig_len = key2.sign_msg(message, &mut sig, &rng).expect("Error with sign_msg()");
+
+        let mut verifier = CompositeMlDsa::import_public(alg, public, &wolfcrypt)
+            .expect("Error with import_public()");
+        assert!(verifier.verify_msg(&sig[..sig_len], message).expect("Error with verify_msg()"));
+
+        /* A public key cannot sign or export a private key. */
+        assert_eq!(verifier.sign_msg(message, &mut sig, &rng),
+            Err(Error::from(sys::wolfCrypt_ErrorCodes_MISSING_KEY)));
+        let mut out = [0u8; CompositeMlDsa::MAX_PRV_KEY_SIZE];
+        assert_eq!(verifier.export_private(&mut out), Err(Error::from(sys::wolfCrypt_ErrorCodes_MISSING_KEY)));
+
+        /* Wrong public key length */
+        assert!(CompositeMlDsa::import_public(alg, &public[..public_len - 1], &wolfcrypt).is_err());
+    }
+}
+
+/// Check the message representative construction by verifying each
+/// component separately with the ML-DSA and Ed25519 wrappers.
+#[test]
+#[cfg(all(mldsa_level3, ed25519, ed25519_sign, ed25519_verify, ed25519_import,
+    ed25519_export))]
+fn test_components_ed25519() {
+    use wolfssl_wolfcrypt::ed25519::Ed25519;
+    use wolfssl_wolfcrypt::mldsa::MlDsa;
+    use wolfssl_wolfcrypt::sha::SHA512;
+    let wolfcrypt = common::setup();
+    let rng = RNG::new(&wolfcrypt).expect("Error with RNG::new()");
+    let alg = CompositeMlDsa::MLDSA65_ED25519_SHA512;
+    let message = b"composite message";
+    let ctx = b"ctx";
+    let mut key = CompositeMlDsa::generate(alg, &rng, &wolfcrypt).expect("Error with generate()");
+    let mut sig = [0u8; CompositeMlDsa::MAX_SIG_SIZE];
+    let sig_len = key.sign_ctx_msg(ctx, message, &mut sig, &rng)
+        .expect("Error with sign_ctx_msg()");
+    assert_eq!(sig_len, 3309 + 64);
+    let mut public = [0u8; CompositeMlDsa::MAX_PUB_KEY_SIZE];
+    let public_len = key.export_public(&mut public).expect("Error with export_public()");
+    assert_eq!(public_len, 1952 + 32);
+
+    let label = b"COMPSIG-MLDSA65-Ed25519-SHA512";
+    assert_eq!(key.label(), label);
+    let mut m_prime = Vec::new();
+    m_prime.extend_from_slice(b"CompositeAlgorithmSignatures2025");
+    m_prime.extend_from_slice(label);
+    m_prime.push(ctx.len() as u8);
+    m_prime.extend_from_slice(ctx);
+    let mut digest = [0u8; SHA512::DIGEST_SIZE];
+    let mut sha = SHA512::new(&wolfcrypt).expect("Error with SHA512::new()");
+    sha.update(message).expect("Error with update()");
+    sha.finalize(&mut digest).expect("Error with finalize()");
+    m_prime.extend_from_slice(&digest);
+
+    let mut mldsa = MlDsa::new(&wolfcrypt).expect("Error with MlDsa::new()");
+    mldsa.set_level(MlDsa::LEVEL_65).expect("Error with set_level()");
+    mldsa.import_public(&public[..1952]).expect("Error with import_public()");
+    assert!(mldsa.verify_ctx_msg(&sig[..3309], label, &m_prime)
+        .expect("Error with verify_ctx_msg()"));
+
+    let mut ed = Ed25519::new(&wolfcrypt).expect("Error with Ed25519::new()");
+    ed.import_public(&public[1952..public_len]).expect("Error with import_public()");
+    assert!(ed.verify_msg(&sig[3309..sig_len], &m_prime).expect("Error with verify_msg()"));
+}
+
+#[test]
+#[cfg(feature = "signature")]
+fn test_signature_traits() {
+    use signature::{Keypair, SignerMut, Verifier};
+    use wolfssl_wolfcrypt::composite_mldsa::{Signature, SigningKey, VerifyingKey};
+    let wolfcrypt = common::setup();
+    let message = b"message to sign via RustCrypto signature trait";
+    for alg in algs() {
+        let rng = RNG::new(&wolfcrypt).expect("Error with RNG::new()");
+        let mut signing_key = SigningKey::generate(alg, rng).expect("Error with generate()");
+        let verifying_key = signing_key.verifying_key();
+        assert_eq!(verifying_key.alg(), alg);
+        let sig: Signature = signing_key.try_sign(message).expect("Error with try_sign()");
+        verifying_key.verify(message, &sig).expect("Error with verify()");
+        assert!(verifying_key.verify(b"other message", &sig).is_err());
+
+        let verifying_key2 = VerifyingKey::from_bytes(alg, verifying_key.as_bytes())
+            .expect("Error with from_bytes()");
+        let sig2 = Signature::try_from(sig.as_bytes()).expect("Error with try_from()");
+        verifying_key2.verify(message, &sig2).expect("Error with verify()");
+
+        let (key, _rng) = signing_key.into_parts();
+        assert_eq!(key.alg(), alg);
+    }
+}
@@ -0,0 +1,270 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+#![cfg(all(wolf_crypto_cb, feature = "alloc"))]
+
+mod common;
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use wolfssl_wolfcrypt::cryptocb::{self, CryptoDevice, Registration};
+#[cfg(sha256)]
+use wolfssl_wolfcrypt::cryptocb::HashRequest;
+#[cfg(any(ecc_sign, ecc_verify, ecc_dh))]
+use wolfssl_wolfcrypt::cryptocb::EccRequest;
+#[cfg(all(ecc, ecc_sign, ecc_verify, random))]
+use wolfssl_wolfcrypt::ecc::ECC;
+#[cfg(random)]
+use wolfssl_wolfcrypt::random::RNG;
+#[cfg(sha256)]
+use wolfssl_wolfcrypt::sha::SHA256;
+#[cfg(any(random, sha256))]
+use wolfssl_wolfcrypt::Env;
+use wolfssl_wolfcrypt::{Error, WolfCrypt};
+use wolfssl_wolfcrypt::sys;
+
+/// Software mock device: deterministic RNG output, SHA-256 computed by
+/// buffering the data and hashing it in software, and ECDSA operations
+/// counted and left to the wolfCrypt software fallback.
+#[derive(Default)]
+struct MockDevice {
+    rng_calls: AtomicUsize,
+    hash_calls: AtomicUsize,
+    ecc_sign_calls: AtomicUsize,
+    ecc_verify_calls: AtomicUsize,
+    hashes: Mutex<HashMap<usize, Vec<u8>>>,
+}
+
+// Implemented on a leaked reference so the test keeps access to the
+// counters after the device is moved into the registry.
+impl CryptoDevice for &'static MockDevice {
+    #[cfg(random)]
+    fn rng(&self, out: &mut [u8]) -> Result<(), Error> {
+        self.rng_calls.fetch_add(1, Ordering::SeqCst);
+        for (i, b) in out.iter_mut().enumerate() {
+            *b = i as u8 ^ 0xa5;
+        }
+        Ok(())
+    }
+
+    #[cfg(sha256)]
+    fn hash(&self, req: HashRequest<'_>) -> Result<(), Error> {
+        if req.hash_type != sys::wc_HashType_WC_HASH_TYPE_SHA256 {
+            return Err(cryptocb::CRYPTOCB_UNAVAILABLE);
+        }
+        self.hash_calls.fetch_add(1, Ordering::SeqCst);
+        let mut hashes = self.hashes.lock().unwrap();
+        let data = hashes.entry(req.context).or_default();
+        if let Some(input) = req.input {
+            data.extend_from_slice(input);
+        }
+        if let Some(digest) = req.digest {
+            let data = hashes.remove(&req.context).unwrap_or_default();
+            // Software SHA-256 object with no device ID.
+            let mut sha = SHA256::new(WolfCrypt::global()?)?;
+            sha.update(&data)?;
+            sha.finalize(digest)?;
+        }
+        Ok(())
+    }
+
+    #[cfg(any(ecc_sign, ecc_verify, ecc_dh))]
+    fn ecc(&self, req: EccRequest<'_>) -> Result<(), Error> {
+        match req {
+            #[cfg(ecc_sign)]
+            EccRequest::Sign { .. } => {
+                self.ecc_sign_calls.fetch_add(1, Ordering::SeqCst);
+            }
+            #[cfg(ecc_verify)]
+            EccRequest::Verify { .. } => {
+                self.ecc_verify_calls.fetch_add(1, Ordering::SeqCst);
+            }
+            #[allow(unreachable_patterns)]
+            _ => {}
+        }
+        Err(cryptocb::CRYPTOCB_UNAVAILABLE)
+    }
+}
+
+/// Registers a device for the duration of a test.
+fn register<D: CryptoDevice + 'static>(dev_id: i32, device: D, wolfcrypt: &WolfCrypt)
+        -> Registration {
+    cryptocb::register_device(dev_id, device, wolfcrypt).expect("Error with register_device()")
+}
+
+fn mock() -> &'static MockDevice {
+    Box::leak(Box::new(MockDevice::default()))
+}
+
+#[test]
+fn test_register_errors() {
+    let wolfcrypt = common::setup();
+    struct Passthrough;
+    impl CryptoDevice for Passthrough {}
+
+    assert_eq!(cryptocb::register_device(sys::INVALID_DEVID, Passthrough, &wolfcrypt).err(),
+        Some(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG)));
+    let registration = register(0x5100, Passthrough, &wolfcrypt);
+    assert_eq!(registration.device().id(), 0x5100);
+    assert_eq!(cryptocb::register_device(0x5100, Passthrough, &wolfcrypt).err(),
+        Some(Error::from(sys::wolfCrypt_ErrorCodes_ALREADY_E)));
+    drop(registration);
+    let registration = register(0x5100, Passthrough, &wolfcrypt);
+    drop(registration);
+}
+
+#[test]
+#[cfg(random)]
+fn test_rng() {
+    let wolfcrypt = common::setup();
+    let device = mock();
+    let reg = register(0x5101, device, &wolfcrypt);
+
+    let rng = RNG::new_ex(&Env::new(&wolfcrypt).with_device(reg.device())).expect("Error with RNG::new_ex()");
+    let mut buf = [0u8; 48];
+    rng.generate_block(&mut buf).expect("Error with generate_block()");
+    for (i, b) in buf.iter().enumerate() {
+        assert_eq!(*b, i as u8 ^ 0xa5);
+    }
+    assert!(device.rng_calls.load(Ordering::SeqCst) >= 1);
+}
+
+#[test]
+#[cfg(random)]
+fn test_unregister_in_progress() {
+    use std::sync::{Arc, Barrier};
+    use std::sync::atomic::AtomicBool;
+    let wolfcrypt = common::setup();
+
+    /// Device whose RNG blocks until released, recording when it is dropped.
+    struct BlockingRng {
+        started: Arc<Barrier>,
+        release: Arc<Barrier>,
+        dropped: Arc<AtomicBool>,
+    }
+
+    impl CryptoDevice for BlockingRng {
+        fn rng(&self, out: &mut [u8]) -> Result<(), Error> {
+            self.started.wait();
+            self.release.wait();
+            out.fill(0x3c);
+            Ok(())
+        }
+    }
+
+    impl Drop for BlockingRng {
+        fn drop(&mut self) {
+            self.dropped.store(true, Ordering::SeqCst);
+        }
+    }
+
+    let started = Arc::new(Barrier::new(2));
+    let release = Arc::new(Barrier::new(2));
+    let dropped = Arc::new(AtomicBool::new(false));
+    let registration = register(0x5105, BlockingRng {
+        started: started.clone(),
+        release: release.clone(),
+        dropped: dropped.clone(),
+    }, &wolfcrypt);
+
+    let rng = RNG::new_ex(&Env::new(&wolfcrypt).with_device(registration.device()))
+        .expect("Error with RNG::new_ex()");
+    let worker = std::thread::spawn(move || {
+        let mut buf = [0u8; 16];
+        rng.generate_block(&mut buf).expect("Error with generate_block()");
+        buf
+    });
+    started.wait();
+    drop(registration);
+    // The operation in progress keeps the device alive.
+    assert!(!dropped.load(Ordering::SeqCst));
+    release.wait();
+    assert_eq!(worker.join().unwrap(), [0x3c; 16]);
+    assert!(dropped.load(Ordering::SeqCst));
+}
+
+#[test]
+#[cfg(random)]
+fn test_device_id_reserved() {
+    let wolfcrypt = common::setup();
+    let device = mock();
+    let reg = register(0x5106, device, &wolfcrypt);
+    let rng = RNG::new_ex(&Env::new(&wolfcrypt).with_device(reg.device()))
+        .expect("Error with RNG::new_ex()");
+    drop(reg);
+
+    // The RNG falls back to software and keeps the device ID reserved.
+    let calls = device.rng_calls.load(Ordering::SeqCst);
+    let mut buf = [0u8; 16];
+    rng.generate_block(&mut buf).expect("Error with generate_block()");
+    assert_eq!(device.rng_calls.load(Ordering::SeqCst), calls);
+    assert_eq!(cryptocb::register_device(0x5106, mock(), &wolfcrypt).err(),
+        Some(Error::from(sys::wolfCrypt_ErrorCodes_ALREADY_E)));
+
+    drop(rng);
+    let reg = register(0x5106, mock(), &wolfcrypt);
+    drop(reg);
+}
+
+#[test]
+#[cfg(sha256)]
+fn test_sha256() {
+    let wolfcrypt = common::setup();
+    let device = mock();
+    let reg = register(0x5102, device, &wolfcrypt);
+    let msg = b"hello, crypto callback device";
+
+    let mut expected = [0u8; SHA256::DIGEST_SIZE];
+    let mut sha = SHA256::new(&wolfcrypt).expect("Error with new()");
+    sha.update(msg).expect("Error with update()");
+    sha.finalize(&mut expected).expect("Error with finalize()");
+
+    let mut digest = [0u8; SHA256::DIGEST_SIZE];
+    let mut sha = SHA256::new_ex(&Env::new(&wolfcrypt).with_device(reg.device())).expect("Error with new_ex()");
+    sha.update(&msg[..5]).expect("Error with update()");
+    sha.update(&msg[5..]).expect("Error with update()");
+    sha.finalize(&mut digest).expect("Error with finalize()");
+    assert_eq!(digest, expected);
+    assert!(device.hash_calls.load(Ordering::SeqCst) >= 3);
+}
+
+#[test]
+#[cfg(all(ecc, ecc_sign, ecc_verify, random))]
+fn test_ecc_fallback() {
+    let wolfcrypt = common::setup();
+    let device = mock();
+    let reg = register(0x5103, device, &wolfcrypt);
+    let rng = RNG::new(&wolfcrypt).expect("Error with RNG::new()");
+
+    let mut ecc = ECC::generate(32, &rng, &Env::new(&wolfcrypt).with_device(reg.device())).expect("Error with generate()");
+    let hash = [0x42u8; 32];
+    let mut sig = [0u8; 128];
+    let sig_len = ecc.sign_hash(&hash, &mut sig, &rng).expect("Error with sign_hash()");
+    let valid = ecc.verify_hash(&sig[..sig_len], &hash).expect("Error with verify_hash()");
+    assert!(valid);
+    assert_eq!(device.ecc_sign_calls.load(Ordering::SeqCst), 1);
+    assert_eq!(device.ecc_verify_calls.load(Ordering::SeqCst), 1);
+
+    drop(reg);
+    let valid = ecc.verify_hash(&sig[..sig_len], &hash).expect("Error with verify_hash()");
+    assert!(valid);
+    assert_eq!(device.ecc_verify_calls.load(Ordering::SeqCst), 1);
+}
@@ -4,10 +4,12 @@
 use std::rc::Rc;
 use wolfssl_wolfcrypt::curve25519::*;
 use wolfssl_wolfcrypt::random::RNG;
+use wolfssl_wolfcrypt::WolfCrypt;
 
 #[test]
 fn test_check_pub() {
-    let mut rng = RNG::new().expect("Error with new()");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Error with new()");
     let mut private_buffer = [0u8; Curve25519Key::KEYSIZE];
     Curve25519Key::generate_priv(&mut rng, &mut private_buffer).expect("Error with generate_priv()");
     let mut public_buffer = [0u8; Curve25519Key::KEYSIZE];
@@ -17,70 +19,78 @@ fn test_check_pub() {
 
 #[test]
 fn test_generate_priv() {
-    let mut rng = RNG::new().expect("Error with new()");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Error with new()");
     let mut private_buffer = [0u8; Curve25519Key::KEYSIZE];
     Curve25519Key::generate_priv(&mut rng, &mut private_buffer).expect("Error with generate_priv()");
 }
 
 #[test]
 fn test_import_export_private() {
-    let mut rng = RNG::new().expect("Error with new()");
-    let mut curve25519key = Curve25519Key::generate(&mut rng).expect("Error with generate()");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Error with new()");
+    let mut curve25519key = Curve25519Key::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     let mut private_buffer = [0u8; Curve25519Key::KEYSIZE];
     curve25519key.export_private_raw(&mut private_buffer).expect("Error with export_private_raw()");
-    Curve25519Key::import_private(&private_buffer).expect("Error with import_private()");
+    Curve25519Key::import_private(&private_buffer, &wolfcrypt).expect("Error with import_private()");
 }
 
 #[test]
 fn test_import_export_private_ex() {
-    let mut rng = RNG::new().expect("Error with new()");
-    let mut curve25519key = Curve25519Key::generate(&mut rng).expect("Error with generate()");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Error with new()");
+    let mut curve25519key = Curve25519Key::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     let mut private_buffer = [0u8; Curve25519Key::KEYSIZE];
     curve25519key.export_private_raw_ex(&mut private_buffer, false).expect("Error with export_private_raw_ex()");
-    Curve25519Key::import_private_ex(&private_buffer, false).expect("Error with import_private_ex()");
+    Curve25519Key::import_private_ex(&private_buffer, false, &wolfcrypt).expect("Error with import_private_ex()");
 }
 
 #[test]
 fn test_import_export_raw() {
-    let mut rng = RNG::new().expect("Error with new()");
-    let mut curve25519key = Curve25519Key::generate(&mut rng).expect("Error with generate()");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Error with new()");
+    let mut curve25519key = Curve25519Key::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     let mut private_buffer = [0u8; Curve25519Key::KEYSIZE];
     let mut public_buffer = [0u8; Curve25519Key::KEYSIZE];
     curve25519key.export_key_raw(&mut private_buffer, &mut public_buffer).expect("Error with export_key_raw()");
-    Curve25519Key::import_private_raw(&private_buffer, &public_buffer).expect("Error with import_private_raw()");
+    Curve25519Key::import_private_raw(&private_buffer, &public_buffer, &wolfcrypt).expect("Error with import_private_raw()");
 }
 
 #[test]
 fn test_import_export_raw_ex() {
-    let mut rng = RNG::new().expect("Error with new()");
-    let mut curve25519key = Curve25519Key::generate(&mut rng).expect("Error with generate()");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Error with new()");
+    let mut curve25519key = Curve25519Key::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     let mut private_buffer = [0u8; Curve25519Key::KEYSIZE];
     let mut public_buffer = [0u8; Curve25519Key::KEYSIZE];
     curve25519key.export_key_raw_ex(&mut private_buffer, &mut public_buffer, false).expect("Error with export_key_raw_ex()");
-    Curve25519Key::import_private_raw_ex(&private_buffer, &public_buffer, false).expect("Error with import_private_raw_ex()");
+    Curve25519Key::import_private_raw_ex(&private_buffer, &public_buffer, false, &wolfcrypt).expect("Error with import_private_raw_ex()");
 }
 
 #[test]
 fn test_import_export_public() {
-    let mut rng = RNG::new().expect("Error with new()");
-    let mut curve25519key = Curve25519Key::generate(&mut rng).expect("Error with generate()");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Error with new()");
+    let mut curve25519key = Curve25519Key::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     let mut public_buffer = [0u8; Curve25519Key::KEYSIZE];
     curve25519key.export_public(&mut public_buffer).expect("Error with export_public()");
-    Curve25519Key::import_public(&public_buffer).expect("Error with import_public()");
+    Curve25519Key::import_public(&public_buffer, &wolfcrypt).expect("Error with import_public()");
 }
 
 #[test]
 fn test_import_export_public_ex() {
-    let mut rng = RNG::new().expect("Error with new()");
-    let mut curve25519key = Curve25519Key::generate(&mut rng).expect("Error with generate()");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Error with new()");
+    let mut curve25519key = Curve25519Key::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     let mut public_buffer = [0u8; Curve25519Key::KEYSIZE];
     curve25519key.export_public_ex(&mut public_buffer, false).expect("Error with export_public_ex()");
-    Curve25519Key::import_public_ex(&public_buffer, false).expect("Error with import_public_ex()");
+    Curve25519Key::import_public_ex(&public_buffer, false, &wolfcrypt).expect("Error with import_public_ex()");
 }
 
 #[test]
 fn test_make_pub() {
-    let mut rng = RNG::new().expect("Error with new()");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Error with new()");
     let mut private_buffer = [0u8; Curve25519Key::KEYSIZE];
     Curve25519Key::generate_priv(&mut rng, &mut private_buffer).expect("Error with generate_priv()");
     let mut public_buffer = [0u8; Curve25519Key::KEYSIZE];
@@ -90,7 +100,8 @@ fn test_make_pub() {
 #[test]
 #[cfg(curve25519_blinding)]
 fn test_make_pub_blind() {
-    let mut rng = RNG::new().expect("Error with new()");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Error with new()");
     let mut private_buffer = [0u8; Curve25519Key::KEYSIZE];
     Curve25519Key::generate_priv(&mut rng, &mut private_buffer).expect("Error with generate_priv()");
     let mut public_buffer = [0u8; Curve25519Key::KEYSIZE];
@@ -99,12 +110,13 @@ fn test_make_pub_blind() {
 
 #[test]
 fn test_shared_secret() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     #[cfg(curve25519_blinding)]
-    let rng = Rc::new(RNG::new().expect("Error with new()"));
+    let rng = Rc::new(RNG::new(&wolfcrypt).expect("Error with new()"));
     #[cfg(not(curve25519_blinding))]
-    let rng = RNG::new().expect("Error with new()");
-    let mut key1 = Curve25519Key::generate(&rng).expect("Error with generate()");
-    let mut key2 = Curve25519Key::generate(&rng).expect("Error with generate()");
+    let rng = RNG::new(&wolfcrypt).expect("Error with new()");
+    let mut key1 = Curve25519Key::generate(&rng, &wolfcrypt).expect("Error with generate()");
+    let mut key2 = Curve25519Key::generate(&rng, &wolfcrypt).expect("Error with generate()");
 
     #[cfg(curve25519_blinding)]
     key1.set_shared_rng(Rc::clone(&rng)).expect("Error with set_shared_rng()");
@@ -113,9 +125,9 @@ fn test_shared_secret() {
 
     let mut public_buffer = [0u8; Curve25519Key::KEYSIZE];
     key1.export_public(&mut public_buffer).expect("Error with export_public()");
-    let mut key1public = Curve25519Key::import_public(&public_buffer).expect("Error with import_public()");
+    let mut key1public = Curve25519Key::import_public(&public_buffer, &wolfcrypt).expect("Error with import_public()");
     key2.export_public(&mut public_buffer).expect("Error with export_public()");
-    let mut key2public = Curve25519Key::import_public(&public_buffer).expect("Error with import_public()");
+    let mut key2public = Curve25519Key::import_public(&public_buffer, &wolfcrypt).expect("Error with import_public()");
 
     let mut ss1 = [0u8; Curve25519Key::KEYSIZE];
     let mut ss2 = [0u8; Curve25519Key::KEYSIZE];
@@ -127,12 +139,13 @@ fn test_shared_secret() {
 
 #[test]
 fn test_shared_secret_ex() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     #[cfg(curve25519_blinding)]
-    let rng = Rc::new(RNG::new().expect("Error with new()"));
+    let rng = Rc::new(RNG::new(&wolfcrypt).expect("Error with new()"));
     #[cfg(not(curve25519_blinding))]
-    let rng = RNG::new().expect("Error with new()");
-    let mut key1 = Curve25519Key::generate(&rng).expect("Error with generate()");
-    let mut key2 = Curve25519Key::generate(&rng).expect("Error with generate()");
+    let rng = RNG::new(&wolfcrypt).expect("Error with new()");
+    let mut key1 = Curve25519Key::generate(&rng, &wolfcrypt).expect("Error with generate()");
+    let mut key2 = Curve25519Key::generate(&rng, &wolfcrypt).expect("Error with generate()");
 
     #[cfg(curve25519_blinding)]
     key1.set_shared_rng(Rc::clone(&rng)).expect("Error with set_shared_rng()");
@@ -141,9 +154,9 @@ fn test_shared_secret_ex() {
 
     let mut public_buffer = [0u8; Curve25519Key::KEYSIZE];
     key1.export_public(&mut public_buffer).expect("Error with export_public()");
-    let mut key1public = Curve25519Key::import_public(&public_buffer).expect("Error with import_public()");
+    let mut key1public = Curve25519Key::import_public(&public_buffer, &wolfcrypt).expect("Error with import_public()");
     key2.export_public(&mut public_buffer).expect("Error with export_public()");
-    let mut key2public = Curve25519Key::import_public(&public_buffer).expect("Error with import_public()");
+    let mut key2public = Curve25519Key::import_public(&public_buffer, &wolfcrypt).expect("Error with import_public()");
 
     let mut ss1 = [0u8; Curve25519Key::KEYSIZE];
     let mut ss2 = [0u8; Curve25519Key::KEYSIZE];
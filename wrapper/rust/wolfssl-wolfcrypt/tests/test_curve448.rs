@@ -0,0 +1,146 @@
+#![cfg(all(curve448, random))]
+
+use wolfssl_wolfcrypt::curve448::*;
+use wolfssl_wolfcrypt::random::RNG;
+use wolfssl_wolfcrypt::WolfCrypt;
+
+// RFC 7748 section 6.2 test vectors (little-endian).
+const ALICE_PRIVATE: &str = "9a8f4925d1519f5775cf46b04b5800d4ee9ee8bae8bc5565d498c28dd9c9baf574a9419744897391006382a6f127ab1d9ac2d8c0a598726b";
+const ALICE_PUBLIC: &str = "9b08f7cc31b7e3e67d22d5aea121074a273bd2b83de09c63faa73d2c22c5d9bbc836647241d953d40c5b12da88120d53177f80e532c41fa0";
+const BOB_PRIVATE: &str = "1c306a7ac2a0e2e0990b294470cba339e6453772b075811d8fad0d1d6927c120bb5ee8972b0d3e21374c9c921b09d1b0366f10b65173992d";
+const BOB_PUBLIC: &str = "3eb7a829b0cd20f5bcfc0b599b6feccf6da4627107bdb0d4f345b43027d8b972fc3e34fb4232a13ca706dcb57aec3dae07bdc1c67bf33609";
+const SHARED_SECRET: &str = "07fff4181ac6cc95ec1c16a94a0f74d12da232ce40a77552281d282bb60c0b56fd2464c335543936521c24403085d59a449a5037514a879d";
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+}
+
+#[test]
+fn test_check_pub() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let rng = RNG::new(&wolfcrypt).expect("Error with new()");
+    let mut key = X448::generate(&rng, &wolfcrypt).expect("Error with generate()");
+    let mut public_buffer = [0u8; X448::PUB_KEYSIZE];
+    key.export_public_ex(&mut public_buffer, false).expect("Error with export_public_ex()");
+    X448::check_public(&public_buffer, false).expect("Error with check_public()");
+    assert!(X448::check_public(&public_buffer[1..], false).is_err());
+}
+
+#[test]
+fn test_make_pub() {
+    let mut private_buffer = hex(ALICE_PRIVATE);
+    private_buffer[0] &= 0xfc;
+    private_buffer[X448::KEYSIZE - 1] |= 0x80;
+    let mut public_buffer = [0u8; X448::PUB_KEYSIZE];
+    X448::make_pub(&private_buffer, &mut public_buffer).expect("Error with make_pub()");
+    assert_eq!(public_buffer.to_vec(), hex(ALICE_PUBLIC));
+}
+
+#[test]
+fn test_import_export_private() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let rng = RNG::new(&wolfcrypt).expect("Error with new()");
+    let mut key = X448::generate(&rng, &wolfcrypt).expect("Error with generate()");
+    let mut private_buffer = [0u8; X448::KEYSIZE];
+    key.export_private_raw(&mut private_buffer).expect("Error with export_private_raw()");
+    let mut key = X448::import_private(&private_buffer, &wolfcrypt).expect("Error with import_private()");
+    let mut private_buffer2 = [0u8; X448::KEYSIZE];
+    key.export_private_raw(&mut private_buffer2).expect("Error with export_private_raw()");
+    assert_eq!(private_buffer, private_buffer2);
+}
+
+#[test]
+fn test_import_export_private_ex() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut key = X448::import_private_ex(&hex(ALICE_PRIVATE), false, &wolfcrypt)
+        .expect("Error with import_private_ex()");
+    let mut private_buffer = [0u8; X448::KEYSIZE];
+    key.export_private_raw_ex(&mut private_buffer, false).expect("Error with export_private_raw_ex()");
+    let mut key = X448::import_private_ex(&private_buffer, false, &wolfcrypt)
+        .expect("Error with import_private_ex()");
+    let mut public_buffer = [0u8; X448::PUB_KEYSIZE];
+    key.export_public_ex(&mut public_buffer, false).expect("Error with export_public_ex()");
+    assert_eq!(public_buffer.to_vec(), hex(ALICE_PUBLIC));
+}
+
+#[test]
+fn test_import_export_raw() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let rng = RNG::new(&wolfcrypt).expect("Error with new()");
+    let mut key = X448::generate(&rng, &wolfcrypt).expect("Error with generate()");
+    let mut private_buffer = [0u8; X448::KEYSIZE];
+    let mut public_buffer = [0u8; X448::PUB_KEYSIZE];
+    key.export_key_raw(&mut private_buffer, &mut public_buffer).expect("Error with export_key_raw()");
+    X448::import_private_raw(&private_buffer, &public_buffer, &wolfcrypt).expect("Error with import_private_raw()");
+}
+
+#[test]
+fn test_import_export_raw_ex() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let rng = RNG::new(&wolfcrypt).expect("Error with new()");
+    let mut key = X448::generate(&rng, &wolfcrypt).expect("Error with generate()");
+    let mut private_buffer = [0u8; X448::KEYSIZE];
+    let mut public_buffer = [0u8; X448::PUB_KEYSIZE];
+    key.export_key_raw_ex(&mut private_buffer, &mut public_buffer, false).expect("Error with export_key_raw_ex()");
+    X448::import_private_raw_ex(&private_buffer, &public_buffer, false, &wolfcrypt).expect("Error with import_private_raw_ex()");
+}
+
+#[test]
+fn test_import_export_public() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut key = X448::import_public(&hex(BOB_PUBLIC), &wolfcrypt).expect("Error with import_public()");
+    let mut public_buffer = [0u8; X448::PUB_KEYSIZE];
+    key.export_public(&mut public_buffer).expect("Error with export_public()");
+    assert_eq!(public_buffer.to_vec(), hex(BOB_PUBLIC));
+}
+
+#[test]
+fn test_import_export_public_ex() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut key = X448::import_public_ex(&hex(BOB_PUBLIC), false, &wolfcrypt).expect("Error with import_public_ex()");
+    let mut public_buffer = [0u8; X448::PUB_KEYSIZE];
+    key.export_public_ex(&mut public_buffer, false).expect("Error with export_public_ex()");
+    assert_eq!(public_buffer.to_vec(), hex(BOB_PUBLIC));
+}
+
+#[test]
+fn test_shared_secret() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let rng = RNG::new(&wolfcrypt).expect("Error with new()");
+    let mut key1 = X448::generate(&rng, &wolfcrypt).expect("Error with generate()");
+    let mut key2 = X448::generate(&rng, &wolfcrypt).expect("Error with generate()");
+
+    let mut public_buffer = [0u8; X448::PUB_KEYSIZE];
+    key1.export_public(&mut public_buffer).expect("Error with export_public()");
+    let mut key1public = X448::import_public(&public_buffer, &wolfcrypt).expect("Error with import_public()");
+    key2.export_public(&mut public_buffer).expect("Error with export_public()");
+    let mut key2public = X448::import_public(&public_buffer, &wolfcrypt).expect("Error with import_public()");
+
+    let mut ss1 = [0u8; X448::KEYSIZE];
+    let mut ss2 = [0u8; X448::KEYSIZE];
+    X448::shared_secret(&mut key1, &mut key2public, &mut ss1).expect("Error with shared_secret()");
+    X448::shared_secret(&mut key2, &mut key1public, &mut ss2).expect("Error with shared_secret()");
+
+    assert_eq!(ss1, ss2);
+}
+
+#[test]
+fn test_shared_secret_ex() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut alice = X448::import_private_ex(&hex(ALICE_PRIVATE), false, &wolfcrypt)
+        .expect("Error with import_private_ex()");
+    let mut bob = X448::import_private_ex(&hex(BOB_PRIVATE), false, &wolfcrypt)
+        .expect("Error with import_private_ex()");
+    let mut alice_public = X448::import_public_ex(&hex(ALICE_PUBLIC), false, &wolfcrypt)
+        .expect("Error with import_public_ex()");
+    let mut bob_public = X448::import_public_ex(&hex(BOB_PUBLIC), false, &wolfcrypt)
+        .expect("Error with import_public_ex()");
+
+    let mut ss1 = [0u8; X448::KEYSIZE];
+    let mut ss2 = [0u8; X448::KEYSIZE];
+    X448::shared_secret_ex(&mut alice, &mut bob_public, &mut ss1, false).expect("Error with shared_secret_ex()");
+    X448::shared_secret_ex(&mut bob, &mut alice_public, &mut ss2, false).expect("Error with shared_secret_ex()");
+
+    assert_eq!(ss1.to_vec(), hex(SHARED_SECRET));
+    assert_eq!(ss2.to_vec(), hex(SHARED_SECRET));
+}
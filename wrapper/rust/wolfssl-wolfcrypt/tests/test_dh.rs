@@ -10,6 +10,7 @@ use wolfssl_wolfcrypt::random::RNG;
 #[test]
 #[cfg(all(dh_keygen, dh_ffdhe_2048))]
 fn test_dh_named_parameters() {
+    let wolfcrypt = common::setup();
     assert_eq!(DH::get_min_key_size_for_named_parameters(DH::FFDHE_2048), 29);
 
     let mut p_size = 0u32;
@@ -19,7 +20,7 @@ fn test_dh_named_parameters() {
 
     assert!(p_size > 0u32);
     assert!(g_size > 0u32);
-    let mut dh = DH::new_named(DH::FFDHE_2048).expect("Error with new_named()");
+    let mut dh = DH::new_named(DH::FFDHE_2048, &wolfcrypt).expect("Error with new_named()");
 
     let mut p = [0u8; 256];
     let mut q = [0u8; 256];
@@ -33,9 +34,9 @@ fn test_dh_named_parameters() {
 #[test]
 #[cfg(all(dh_keygen, random))]
 fn test_generate_params() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error with RNG::new()");
-    let mut dh = DH::generate(&mut rng, 2048).expect("Error with generate()");
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error with RNG::new()");
+    let mut dh = DH::generate(&mut rng, 2048, &wolfcrypt).expect("Error with generate()");
 
     let mut private = [0u8; 256];
     let mut private_size = 0u32;
@@ -47,8 +48,9 @@ fn test_generate_params() {
 #[test]
 #[cfg(all(dh_keygen, dh_ffdhe_2048, random))]
 fn test_generate_key_pair() {
-    let mut rng = RNG::new().expect("Error with RNG::new()");
-    let mut dh = DH::new_named(DH::FFDHE_2048).expect("Error with new_named()");
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error with RNG::new()");
+    let mut dh = DH::new_named(DH::FFDHE_2048, &wolfcrypt).expect("Error with new_named()");
 
     let mut p = [0u8; 256];
     let mut q = [0u8; 256];
@@ -78,7 +80,7 @@ fn test_generate_key_pair() {
 #[test]
 #[cfg(random)]
 fn test_dh_checks() {
-    common::setup();
+    let wolfcrypt = common::setup();
     let p = [
         0xc5u8, 0x7c, 0xa2, 0x4f, 0x4b, 0xd6, 0x8c, 0x3c,
         0xda, 0xc7, 0xba, 0xaa, 0xea, 0x2e, 0x5c, 0x1e,
@@ -160,10 +162,10 @@ fn test_dh_checks() {
         0xec, 0x24, 0x5d, 0x78, 0x59, 0xe7, 0x8d, 0xb5,
         0x40, 0x52, 0xed, 0x41
     ];
-    let mut rng = RNG::new().expect("Error with RNG::new()");
-    let _dh = DH::new_from_pg(&p, &g).expect("Error with new_from_pg()");
-    let _dh = DH::new_from_pgq(&p, &g, &q).expect("Error with new_from_pgq()");
-    let mut dh = DH::new_from_pgq_with_check(&p, &g, &q, 0, &mut rng).expect("Error with new_from_pgq()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Error with RNG::new()");
+    let _dh = DH::new_from_pg(&p, &g, &wolfcrypt).expect("Error with new_from_pg()");
+    let _dh = DH::new_from_pgq(&p, &g, &q, &wolfcrypt).expect("Error with new_from_pgq()");
+    let mut dh = DH::new_from_pgq_with_check(&p, &g, &q, 0, &mut rng, &wolfcrypt).expect("Error with new_from_pgq()");
     let mut private = [0u8; 256];
     let mut private_size = 0u32;
     let mut public = [0u8; 256];
@@ -180,8 +182,9 @@ fn test_dh_checks() {
 #[test]
 #[cfg(all(dh_ffdhe_2048, random))]
 fn test_dh_shared_secret() {
-    let mut rng = RNG::new().expect("Error with RNG::new()");
-    let mut dh = DH::new_named(DH::FFDHE_2048).expect("Error with new_named()");
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error with RNG::new()");
+    let mut dh = DH::new_named(DH::FFDHE_2048, &wolfcrypt).expect("Error with new_named()");
 
     let mut private0 = [0u8; 256];
     let mut private0_size = 0u32;
@@ -214,17 +217,17 @@ fn test_dh_shared_secret() {
 #[test]
 #[cfg(dh_ffdhe_2048)]
 fn test_dh_prime_size() {
-    common::setup();
-    let mut dh = DH::new_named(DH::FFDHE_2048).expect("Error with new_named()");
+    let wolfcrypt = common::setup();
+    let mut dh = DH::new_named(DH::FFDHE_2048, &wolfcrypt).expect("Error with new_named()");
     assert_eq!(dh.prime_size().expect("Error with prime_size()"), 256);
 }
 
 #[test]
 #[cfg(all(dh_ffdhe_2048, random))]
 fn test_dh_shared_secret_buffer_too_small() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error with RNG::new()");
-    let mut dh = DH::new_named(DH::FFDHE_2048).expect("Error with new_named()");
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error with RNG::new()");
+    let mut dh = DH::new_named(DH::FFDHE_2048, &wolfcrypt).expect("Error with new_named()");
 
     let mut private = [0u8; 256];
     let mut private_size = 0u32;
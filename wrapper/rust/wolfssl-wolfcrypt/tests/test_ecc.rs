@@ -6,6 +6,8 @@ mod common;
 use std::fs;
 #[cfg(all(ecc_dh, random))]
 use std::rc::Rc;
+#[cfg(any(ecc_import, random))]
+use wolfssl_wolfcrypt::Env;
 use wolfssl_wolfcrypt::ecc::*;
 #[cfg(random)]
 use wolfssl_wolfcrypt::random::RNG;
@@ -15,67 +17,67 @@ use wolfssl_wolfcrypt::sys;
 #[test]
 #[cfg(random)]
 fn test_ecc_generate() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Failed to create RNG");
-    let mut ecc = ECC::generate(32, &mut rng, None, None).expect("Error with generate()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
+    let mut ecc = ECC::generate(32, &mut rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
     ecc.check().expect("Error with check()");
 }
 
 #[test]
 #[cfg(random)]
 fn test_ecc_generate_ex() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Failed to create RNG");
+    let mut rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
     let curve_id = ECC::SECP256R1;
     let curve_size = ECC::get_curve_size_from_id(curve_id).expect("Error with get_curve_size_from_id()");
     assert_eq!(curve_size, 32);
-    let mut ecc = ECC::generate_ex(curve_size, &mut rng, curve_id, None, None).expect("Error with generate_ex()");
+    let mut ecc = ECC::generate_ex(curve_size, &mut rng, curve_id, &Env::new(&wolfcrypt)).expect("Error with generate_ex()");
     ecc.check().expect("Error with check()");
 }
 
 #[test]
 #[cfg(all(ecc_import, ecc_export, random))]
 fn test_ecc_import_x963() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Failed to create RNG");
+    let mut rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
     let curve_id = ECC::SECP256R1;
     let curve_size = ECC::get_curve_size_from_id(curve_id).expect("Error with get_curve_size_from_id()");
     assert_eq!(curve_size, 32);
-    let mut ecc = ECC::generate_ex(curve_size, &mut rng, curve_id, None, None).expect("Error with generate_ex()");
+    let mut ecc = ECC::generate_ex(curve_size, &mut rng, curve_id, &Env::new(&wolfcrypt)).expect("Error with generate_ex()");
     ecc.check().expect("Error with check()");
 
     let mut x963 = [0u8; 128];
     let x963_size = ecc.export_x963(&mut x963).expect("Error with export_x963()");
     let x963 = &x963[0..x963_size];
-    let mut ecc = ECC::import_x963_ex(x963, ECC::SECP256R1, None, None).expect("Error with import_x963_ex");
+    let mut ecc = ECC::import_x963_ex(x963, ECC::SECP256R1, &Env::new(&wolfcrypt)).expect("Error with import_x963_ex");
     ecc.check().expect("Error with check()");
 }
 
 #[test]
 #[cfg(random)]
 fn test_ecc_generate_ex2() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Failed to create RNG");
+    let mut rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
     let curve_id = ECC::SECP256R1;
     let curve_size = ECC::get_curve_size_from_id(curve_id).expect("Error with get_curve_size_from_id()");
     assert_eq!(curve_size, 32);
-    let mut ecc = ECC::generate_ex2(curve_size, &mut rng, curve_id, ECC::FLAG_COFACTOR, None, None).expect("Error with generate_ex2()");
+    let mut ecc = ECC::generate_ex2(curve_size, &mut rng, curve_id, ECC::FLAG_COFACTOR, &Env::new(&wolfcrypt)).expect("Error with generate_ex2()");
     ecc.check().expect("Error with check()");
 }
 
 #[test]
 #[cfg(all(ecc_import, ecc_export, ecc_sign, ecc_verify, random))]
 fn test_ecc_import_export_sign_verify() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Failed to create RNG");
+    let mut rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
     let key_path = "../../../certs/ecc-client-key.der";
     let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    let mut ecc = ECC::import_der(&der, None, None).expect("Error with import_der()");
+    let mut ecc = ECC::import_der(&der, &Env::new(&wolfcrypt)).expect("Error with import_der()");
     let hash = [0x42u8; 32];
     let mut signature = [0u8; 128];
     let signature_length = ecc.sign_hash(&hash, &mut signature, &mut rng).expect("Error with sign_hash()");
@@ -84,14 +86,14 @@ fn test_ecc_import_export_sign_verify() {
     let signature = &mut signature[0..signature_length];
     let key_path = "../../../certs/ecc-client-keyPub.der";
     let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    let mut ecc = ECC::import_public_der(&der, None, None).expect("Error with import_public_der()");
+    let mut ecc = ECC::import_public_der(&der, &Env::new(&wolfcrypt)).expect("Error with import_public_der()");
     let valid = ecc.verify_hash(&signature, &hash).expect("Error with verify_hash()");
     assert_eq!(valid, true);
 
     let mut x963 = [0u8; 128];
     let x963_size = ecc.export_x963(&mut x963).expect("Error with export_x963()");
     let x963 = &x963[0..x963_size];
-    let mut ecc = ECC::import_x963(x963, None, None).expect("Error with import_x963");
+    let mut ecc = ECC::import_x963(x963, &Env::new(&wolfcrypt)).expect("Error with import_x963");
     let valid = ecc.verify_hash(&signature, &hash).expect("Error with verify_hash()");
     assert_eq!(valid, true);
 
@@ -100,7 +102,7 @@ fn test_ecc_import_export_sign_verify() {
         let mut x963 = [0u8; 128];
         let x963_size = ecc.export_x963_compressed(&mut x963).expect("Error with export_x963_compressed()");
         let x963 = &x963[0..x963_size];
-        let mut ecc = ECC::import_x963(x963, None, None).expect("Error with import_x963");
+        let mut ecc = ECC::import_x963(x963, &Env::new(&wolfcrypt)).expect("Error with import_x963");
         let valid = ecc.verify_hash(&signature, &hash).expect("Error with verify_hash()");
         assert_eq!(valid, true);
     }
@@ -144,11 +146,11 @@ fn test_ecc_import_export_sign_verify() {
 #[test]
 #[cfg(all(ecc_dh, random))]
 fn test_ecc_shared_secret() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let rng = Rc::new(RNG::new().expect("Failed to create RNG"));
-    let mut ecc0 = ECC::generate(32, &rng, None, None).expect("Error with generate()");
-    let mut ecc1 = ECC::generate(32, &rng, None, None).expect("Error with generate()");
+    let rng = Rc::new(RNG::new(&wolfcrypt).expect("Failed to create RNG"));
+    let mut ecc0 = ECC::generate(32, &rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
+    let mut ecc1 = ECC::generate(32, &rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
     let mut ss0 = [0u8; 128];
     let mut ss1 = [0u8; 128];
     ecc0.set_shared_rng(Rc::clone(&rng)).expect("Error with set_shared_rng()");
@@ -161,7 +163,7 @@ fn test_ecc_shared_secret() {
     assert_eq!(*ss0, *ss1);
 
     let mut ss0 = [0u8; 128];
-    let ecc_point = ecc1.make_pub_to_point(None, None).expect("Error with make_pub_to_point()");
+    let ecc_point = ecc1.make_pub_to_point(None, &Env::new(&wolfcrypt)).expect("Error with make_pub_to_point()");
     let ss0_size = ecc0.shared_secret_ex(&ecc_point, &mut ss0).expect("Error with shared_secret_ex()");
     let ss0 = &ss0[0..ss0_size];
     assert_eq!(*ss0, *ss1);
@@ -170,10 +172,10 @@ fn test_ecc_shared_secret() {
 #[test]
 #[cfg(all(ecc_export, random))]
 fn test_ecc_export() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Failed to create RNG");
-    let mut ecc = ECC::generate(32, &mut rng, None, None).expect("Error with generate()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
+    let mut ecc = ECC::generate(32, &mut rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
     let mut qx = [0u8; 32];
     let mut qx_len = 0u32;
     let mut qy = [0u8; 32];
@@ -186,10 +188,10 @@ fn test_ecc_export() {
 #[test]
 #[cfg(all(ecc_export, random))]
 fn test_ecc_export_ex() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Failed to create RNG");
-    let mut ecc = ECC::generate(32, &mut rng, None, None).expect("Error with generate()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
+    let mut ecc = ECC::generate(32, &mut rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
     let mut qx = [0u8; 32];
     let mut qx_len = 0u32;
     let mut qy = [0u8; 32];
@@ -202,10 +204,10 @@ fn test_ecc_export_ex() {
 #[test]
 #[cfg(all(ecc_import, ecc_export, ecc_sign, ecc_verify, random))]
 fn test_ecc_import_export_private() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Failed to create RNG");
-    let mut ecc = ECC::generate(32, &mut rng, None, None).expect("Error with generate()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
+    let mut ecc = ECC::generate(32, &mut rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
     let hash = [0x42u8; 32];
     let mut signature = [0u8; 128];
     let signature_length = ecc.sign_hash(&hash, &mut signature, &mut rng).expect("Error with sign_hash()");
@@ -218,22 +220,22 @@ fn test_ecc_import_export_private() {
     let x963_size = ecc.export_x963(&mut x963).expect("Error with export_x963()");
     let x963 = &x963[0..x963_size];
 
-    let mut ecc2 = ECC::import_private_key(&d, x963, None, None).expect("Error with import_private_key()");
+    let mut ecc2 = ECC::import_private_key(&d, x963, &Env::new(&wolfcrypt)).expect("Error with import_private_key()");
     let valid = ecc2.verify_hash(&signature, &hash).expect("Error with verify_hash()");
     assert_eq!(valid, true);
 
-    ECC::import_private_key(&d, &[], None, None).expect("Error with import_private_key()");
+    ECC::import_private_key(&d, &[], &Env::new(&wolfcrypt)).expect("Error with import_private_key()");
 }
 
 #[test]
 #[cfg(all(ecc_import, ecc_export, ecc_sign, ecc_verify, random))]
 fn test_ecc_import_export_private_ex() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Failed to create RNG");
+    let mut rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
     let curve_id = ECC::SECP256R1;
     let curve_size = ECC::get_curve_size_from_id(curve_id).expect("Error with get_curve_size_from_id()");
-    let mut ecc = ECC::generate_ex(curve_size, &mut rng, curve_id, None, None).expect("Error with generate_ex()");
+    let mut ecc = ECC::generate_ex(curve_size, &mut rng, curve_id, &Env::new(&wolfcrypt)).expect("Error with generate_ex()");
     let hash = [0x42u8; 32];
     let mut signature = [0u8; 128];
     let signature_length = ecc.sign_hash(&hash, &mut signature, &mut rng).expect("Error with sign_hash()");
@@ -246,20 +248,20 @@ fn test_ecc_import_export_private_ex() {
     let x963_size = ecc.export_x963(&mut x963).expect("Error with export_x963()");
     let x963 = &x963[0..x963_size];
 
-    let mut ecc2 = ECC::import_private_key_ex(&d, x963, curve_id, None, None).expect("Error with import_private_key_ex()");
+    let mut ecc2 = ECC::import_private_key_ex(&d, x963, curve_id, &Env::new(&wolfcrypt)).expect("Error with import_private_key_ex()");
     let valid = ecc2.verify_hash(&signature, &hash).expect("Error with verify_hash()");
     assert_eq!(valid, true);
 
-    ECC::import_private_key_ex(&d, &[], curve_id, None, None).expect("Error with import_private_key_ex()");
+    ECC::import_private_key_ex(&d, &[], curve_id, &Env::new(&wolfcrypt)).expect("Error with import_private_key_ex()");
 }
 
 #[test]
 #[cfg(all(ecc_export, random))]
 fn test_ecc_export_public() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Failed to create RNG");
-    let mut ecc = ECC::generate(32, &mut rng, None, None).expect("Error with generate()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
+    let mut ecc = ECC::generate(32, &mut rng, &Env::new(&wolfcrypt)).expect("Error with generate()");
     let mut qx = [0u8; 32];
     let mut qx_len = 0u32;
     let mut qy = [0u8; 32];
@@ -270,12 +272,12 @@ fn test_ecc_export_public() {
 #[test]
 #[cfg(all(ecc_import, ecc_export, ecc_sign, ecc_verify, random))]
 fn test_ecc_import_unsigned() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Failed to create RNG");
+    let mut rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
     let curve_id = ECC::SECP256R1;
     let curve_size = ECC::get_curve_size_from_id(curve_id).expect("Error with get_curve_size_from_id()");
-    let mut ecc = ECC::generate_ex(curve_size, &mut rng, curve_id, None, None).expect("Error with generate()");
+    let mut ecc = ECC::generate_ex(curve_size, &mut rng, curve_id, &Env::new(&wolfcrypt)).expect("Error with generate()");
     let mut qx = [0u8; 32];
     let mut qx_len = 0u32;
     let mut qy = [0u8; 32];
@@ -284,7 +286,7 @@ fn test_ecc_import_unsigned() {
     let mut d_len = 0u32;
     ecc.export_ex(&mut qx, &mut qx_len, &mut qy, &mut qy_len, &mut d, &mut d_len, false).expect("Error with export_ex()");
 
-    let mut ecc2 = ECC::import_unsigned(&qx, &qy, &d, curve_id, None, None).expect("Error with import_unsigned()");
+    let mut ecc2 = ECC::import_unsigned(&qx, &qy, &d, curve_id, &Env::new(&wolfcrypt)).expect("Error with import_unsigned()");
 
     let hash = [0x42u8; 32];
     let mut signature = [0u8; 128];
@@ -297,7 +299,7 @@ fn test_ecc_import_unsigned() {
 #[test]
 #[cfg(ecc_import)]
 fn test_ecc_import_unsigned_short_slices() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
     let curve_id = ECC::SECP256R1;
     let qx = [0u8; 32];
@@ -314,7 +316,7 @@ fn test_ecc_import_unsigned_short_slices() {
         (&qx,       &qy,        &empty   ),
     ];
     for (qx, qy, d) in cases {
-        match ECC::import_unsigned(qx, qy, d, curve_id, None, None) {
+        match ECC::import_unsigned(qx, qy, d, curve_id, &Env::new(&wolfcrypt)) {
             Ok(_) => panic!("import_unsigned() should fail with short slice"),
             Err(rc) => assert_eq!(rc, sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG),
         }
@@ -324,28 +326,28 @@ fn test_ecc_import_unsigned_short_slices() {
 #[test]
 #[cfg(random)]
 fn test_ecc_make_pub() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Failed to create RNG");
+    let mut rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
     let key_path = "../../../certs/ecc-client-key.der";
     let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    let mut ecc = ECC::import_der(&der, None, None).expect("Error with import_der()");
+    let mut ecc = ECC::import_der(&der, &Env::new(&wolfcrypt)).expect("Error with import_der()");
     ecc.make_pub(Some(&mut rng)).expect("Error with make_pub()");
     ecc.make_pub(None).expect("Error with make_pub()");
-    ecc.make_pub_to_point(Some(&mut rng), None).expect("Error with make_pub_to_point()");
-    ecc.make_pub_to_point(None, None).expect("Error with make_pub_to_point()");
+    ecc.make_pub_to_point(Some(&mut rng), &Env::new(&wolfcrypt)).expect("Error with make_pub_to_point()");
+    ecc.make_pub_to_point(None, &Env::new(&wolfcrypt)).expect("Error with make_pub_to_point()");
 }
 
 #[test]
 #[cfg(all(ecc_export, random))]
 fn test_ecc_point() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Failed to create RNG");
+    let mut rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
     let curve_id = ECC::SECP256R1;
     let curve_size = ECC::get_curve_size_from_id(curve_id).expect("Error with get_curve_size_from_id()");
-    let mut ecc = ECC::generate_ex(curve_size, &mut rng, curve_id, None, None).expect("Error with generate()");
-    let mut ecc_point = ecc.make_pub_to_point(Some(&mut rng), None).expect("Error with make_pub_to_point()");
+    let mut ecc = ECC::generate_ex(curve_size, &mut rng, curve_id, &Env::new(&wolfcrypt)).expect("Error with generate()");
+    let mut ecc_point = ecc.make_pub_to_point(Some(&mut rng), &Env::new(&wolfcrypt)).expect("Error with make_pub_to_point()");
     let mut der = [0u8; 128];
     let size = ecc_point.export_der(&mut der, curve_id).expect("Error with export_der()");
     assert!(size > 0 && size <= der.len());
@@ -355,30 +357,30 @@ fn test_ecc_point() {
 #[test]
 #[cfg(all(all(ecc_import, ecc_export, random)))]
 fn test_ecc_point_import() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Failed to create RNG");
+    let mut rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
     let curve_id = ECC::SECP256R1;
     let curve_size = ECC::get_curve_size_from_id(curve_id).expect("Error with get_curve_size_from_id()");
-    let mut ecc = ECC::generate_ex(curve_size, &mut rng, curve_id, None, None).expect("Error with generate()");
-    let mut ecc_point = ecc.make_pub_to_point(Some(&mut rng), None).expect("Error with make_pub_to_point()");
+    let mut ecc = ECC::generate_ex(curve_size, &mut rng, curve_id, &Env::new(&wolfcrypt)).expect("Error with generate()");
+    let mut ecc_point = ecc.make_pub_to_point(Some(&mut rng), &Env::new(&wolfcrypt)).expect("Error with make_pub_to_point()");
     let mut der = [0u8; 128];
     let size = ecc_point.export_der(&mut der, curve_id).expect("Error with export_der()");
     assert!(size > 0 && size <= der.len());
-    ECCPoint::import_der(&der[0..size], curve_id, None).expect("Error with import_der()");
+    ECCPoint::import_der(&der[0..size], curve_id, &Env::new(&wolfcrypt)).expect("Error with import_der()");
     ecc_point.forcezero();
 }
 
 #[test]
 #[cfg(all(ecc_import, ecc_export, ecc_comp_key, random))]
 fn test_ecc_point_import_compressed() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Failed to create RNG");
+    let mut rng = RNG::new(&wolfcrypt).expect("Failed to create RNG");
     let curve_id = ECC::SECP256R1;
     let curve_size = ECC::get_curve_size_from_id(curve_id).expect("Error with get_curve_size_from_id()");
-    let mut ecc = ECC::generate_ex(curve_size, &mut rng, curve_id, None, None).expect("Error with generate()");
-    let mut ecc_point = ecc.make_pub_to_point(Some(&mut rng), None).expect("Error with make_pub_to_point()");
+    let mut ecc = ECC::generate_ex(curve_size, &mut rng, curve_id, &Env::new(&wolfcrypt)).expect("Error with generate()");
+    let mut ecc_point = ecc.make_pub_to_point(Some(&mut rng), &Env::new(&wolfcrypt)).expect("Error with make_pub_to_point()");
     let mut der = [0u8; 128];
     let _size = ecc_point.export_der_compressed(&mut der, curve_id).expect("Error with export_der_compressed()");
     ecc_point.forcezero();
@@ -387,19 +389,19 @@ fn test_ecc_point_import_compressed() {
 #[test]
 #[cfg(ecc_import)]
 fn test_ecc_import() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
     let qx = b"7a4e287890a1a47ad3457e52f2f76a83ce46cbc947616d0cbaa82323818a793d\0";
     let qy = b"eec4084f5b29ebf29c44cce3b3059610922f8b30ea6e8811742ac7238fe87308\0";
     let d  = b"8c14b793cb19137e323a6d2e2a870bca2e7a493ec1153b3a95feb8a4873f8d08\0";
-    ECC::import_raw(qx, qy, d, b"SECP256R1\0", None, None).expect("Error with import_raw()");
-    ECC::import_raw_ex(qx, qy, d, ECC::SECP256R1, None, None).expect("Error with import_raw_ex()");
+    ECC::import_raw(qx, qy, d, b"SECP256R1\0", &Env::new(&wolfcrypt)).expect("Error with import_raw()");
+    ECC::import_raw_ex(qx, qy, d, ECC::SECP256R1, &Env::new(&wolfcrypt)).expect("Error with import_raw_ex()");
 }
 
 #[test]
 #[cfg(ecc_import)]
 fn test_ecc_import_raw_not_null_terminated() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
     let qx = b"7a4e287890a1a47ad3457e52f2f76a83ce46cbc947616d0cbaa82323818a793d\0";
     let qy = b"eec4084f5b29ebf29c44cce3b3059610922f8b30ea6e8811742ac7238fe87308\0";
@@ -411,21 +413,21 @@ fn test_ecc_import_raw_not_null_terminated() {
     let curve_name_no_nul: &[u8] = b"SECP256R1";
     let empty: &[u8] = b"";
 
-    assert!(ECC::import_raw(qx_no_nul, qy, d, curve_name, None, None).is_err());
-    assert!(ECC::import_raw(qx, qy_no_nul, d, curve_name, None, None).is_err());
-    assert!(ECC::import_raw(qx, qy, d_no_nul, curve_name, None, None).is_err());
-    assert!(ECC::import_raw(qx, qy, d, curve_name_no_nul, None, None).is_err());
-    assert!(ECC::import_raw(empty, qy, d, curve_name, None, None).is_err());
-    assert!(ECC::import_raw(qx, empty, d, curve_name, None, None).is_err());
-    assert!(ECC::import_raw(qx, qy, empty, curve_name, None, None).is_err());
-    assert!(ECC::import_raw(qx, qy, d, empty, None, None).is_err());
-
-    assert!(ECC::import_raw_ex(qx_no_nul, qy, d, ECC::SECP256R1, None, None).is_err());
-    assert!(ECC::import_raw_ex(qx, qy_no_nul, d, ECC::SECP256R1, None, None).is_err());
-    assert!(ECC::import_raw_ex(qx, qy, d_no_nul, ECC::SECP256R1, None, None).is_err());
-    assert!(ECC::import_raw_ex(empty, qy, d, ECC::SECP256R1, None, None).is_err());
-    assert!(ECC::import_raw_ex(qx, empty, d, ECC::SECP256R1, None, None).is_err());
-    assert!(ECC::import_raw_ex(qx, qy, empty, ECC::SECP256R1, None, None).is_err());
+    assert!(ECC::import_raw(qx_no_nul, qy, d, curve_name, &Env::new(&wolfcrypt)).is_err());
+    assert!(ECC::import_raw(qx, qy_no_nul, d, curve_name, &Env::new(&wolfcrypt)).is_err());
+    assert!(ECC::import_raw(qx, qy, d_no_nul, curve_name, &Env::new(&wolfcrypt)).is_err());
+    assert!(ECC::import_raw(qx, qy, d, curve_name_no_nul, &Env::new(&wolfcrypt)).is_err());
+    assert!(ECC::import_raw(empty, qy, d, curve_name, &Env::new(&wolfcrypt)).is_err());
+    assert!(ECC::import_raw(qx, empty, d, curve_name, &Env::new(&wolfcrypt)).is_err());
+    assert!(ECC::import_raw(qx, qy, empty, curve_name, &Env::new(&wolfcrypt)).is_err());
+    assert!(ECC::import_raw(qx, qy, d, empty, &Env::new(&wolfcrypt)).is_err());
+
+    assert!(ECC::import_raw_ex(qx_no_nul, qy, d, ECC::SECP256R1, &Env::new(&wolfcrypt)).is_err());
+    assert!(ECC::import_raw_ex(qx, qy_no_nul, d, ECC::SECP256R1, &Env::new(&wolfcrypt)).is_err());
+    assert!(ECC::import_raw_ex(qx, qy, d_no_nul, ECC::SECP256R1, &Env::new(&wolfcrypt)).is_err());
+    assert!(ECC::import_raw_ex(empty, qy, d, ECC::SECP256R1, &Env::new(&wolfcrypt)).is_err());
+    assert!(ECC::import_raw_ex(qx, empty, d, ECC::SECP256R1, &Env::new(&wolfcrypt)).is_err());
+    assert!(ECC::import_raw_ex(qx, qy, empty, ECC::SECP256R1, &Env::new(&wolfcrypt)).is_err());
 }
 
 #[test]
@@ -3,6 +3,8 @@
 mod common;
 
 use signature::{Keypair, SignerMut, Verifier};
+#[cfg(sha256)]
+use wolfssl_wolfcrypt::Env;
 use wolfssl_wolfcrypt::random::RNG;
 
 #[test]
@@ -10,9 +12,9 @@ use wolfssl_wolfcrypt::random::RNG;
 fn test_p256_sign_verify() {
     use wolfssl_wolfcrypt::ecdsa::{P256Signature, P256SigningKey, P256VerifyingKey};
 
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let rng = RNG::new().expect("RNG");
+    let rng = RNG::new(&wolfcrypt).expect("RNG");
     let mut sk = P256SigningKey::generate(rng).expect("generate P256");
 
     let msg = b"ecdsa p256 signature trait test";
@@ -51,11 +53,11 @@ fn test_p256_import_unsigned_and_x963() {
     use wolfssl_wolfcrypt::ecc::ECC;
     use wolfssl_wolfcrypt::ecdsa::{P256SigningKey, P256VerifyingKey};
 
-    common::setup();
+    let wolfcrypt = common::setup();
 
     // Start from a freshly generated key so we have known-good (qx, qy, d).
-    let mut rng = RNG::new().expect("RNG");
-    let mut src = ECC::generate_ex(32, &mut rng, ECC::SECP256R1, None, None)
+    let mut rng = RNG::new(&wolfcrypt).expect("RNG");
+    let mut src = ECC::generate_ex(32, &mut rng, ECC::SECP256R1, &Env::new(&wolfcrypt))
         .expect("generate ECC");
     let mut qx = [0u8; 32];
     let mut qy = [0u8; 32];
@@ -75,14 +77,14 @@ fn test_p256_import_unsigned_and_x963() {
     let msg = b"ecdsa p256 import path";
 
     // Path 1: raw unsigned components.
-    let rng = RNG::new().expect("RNG");
+    let rng = RNG::new(&wolfcrypt).expect("RNG");
     let mut sk_a = P256SigningKey::import_unsigned(&qx, &qy, &d_buf, rng)
         .expect("import_unsigned");
     let sig_a = sk_a.sign(msg);
     sk_a.verifying_key().verify(msg, &sig_a).expect("verify a");
 
     // Path 2: X9.63 public + private scalar.
-    let rng = RNG::new().expect("RNG");
+    let rng = RNG::new(&wolfcrypt).expect("RNG");
     let mut sk_b = P256SigningKey::import_x963(&x963, &d_buf, rng)
         .expect("import_x963");
     let sig_b = sk_b.sign(msg);
@@ -102,9 +104,9 @@ fn test_p256_import_unsigned_and_x963() {
 fn test_p384_sign_verify() {
     use wolfssl_wolfcrypt::ecdsa::{P384Signature, P384SigningKey, P384VerifyingKey};
 
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let rng = RNG::new().expect("RNG");
+    let rng = RNG::new(&wolfcrypt).expect("RNG");
     let mut sk = P384SigningKey::generate(rng).expect("generate P384");
 
     let msg = b"ecdsa p384 signature trait test";
@@ -124,9 +126,9 @@ fn test_p384_sign_verify() {
 fn test_p521_sign_verify() {
     use wolfssl_wolfcrypt::ecdsa::{P521Signature, P521SigningKey, P521VerifyingKey};
 
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let rng = RNG::new().expect("RNG");
+    let rng = RNG::new(&wolfcrypt).expect("RNG");
     let mut sk = P521SigningKey::generate(rng).expect("generate P521");
 
     let msg = b"ecdsa p521 signature trait test";
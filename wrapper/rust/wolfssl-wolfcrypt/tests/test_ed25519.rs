@@ -8,13 +8,13 @@ use wolfssl_wolfcrypt::ed25519::*;
 #[test]
 #[cfg(all(ed25519_import, ed25519_export))]
 fn test_make_public() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     let mut private = [0u8; Ed25519::KEY_SIZE];
     ed.export_private_only(&mut private).expect("Error with export_private_only()");
-    let mut ed = Ed25519::new().expect("Error with new()");
+    let mut ed = Ed25519::new(&wolfcrypt).expect("Error with new()");
     ed.import_private_only(&private).expect("Error with import_private_only()");
     let mut public = [0u8; Ed25519::KEY_SIZE];
     ed.make_public(&mut public).expect("Error with make_public()");
@@ -22,14 +22,16 @@ fn test_make_public() {
 
 #[test]
 fn test_check_key() {
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     ed.check_key().expect("Error with check_key()");
 }
 
 #[test]
 #[cfg(all(ed25519_import, ed25519_sign, ed25519_verify))]
 fn test_sign_verify() {
+    let wolfcrypt = common::setup();
     let private_key = [
         0xc5u8,0xaa,0x8d,0xf4,0x3f,0x9f,0x83,0x7b,
         0xed,0xb7,0x44,0x2f,0x31,0xdc,0xb7,0xb1,
@@ -54,7 +56,7 @@ fn test_sign_verify() {
         0xc0,0x27,0xbe,0xce,0xea,0x1e,0xc4,0x0a
     ];
 
-    let mut ed = Ed25519::new().expect("Error with new()");
+    let mut ed = Ed25519::new(&wolfcrypt).expect("Error with new()");
     ed.import_private_key(&private_key, Some(&public_key)).expect("Error with import_private_key()");
 
     let mut signature = [0u8; Ed25519::SIG_SIZE];
@@ -75,6 +77,7 @@ fn test_sign_verify() {
 #[test]
 #[cfg(all(ed25519_import, ed25519_sign, ed25519_streaming_verify))]
 fn test_sign_streaming_verify() {
+    let wolfcrypt = common::setup();
     let private_key = [
         0xc5u8,0xaa,0x8d,0xf4,0x3f,0x9f,0x83,0x7b,
         0xed,0xb7,0x44,0x2f,0x31,0xdc,0xb7,0xb1,
@@ -99,7 +102,7 @@ fn test_sign_streaming_verify() {
         0xc0,0x27,0xbe,0xce,0xea,0x1e,0xc4,0x0a
     ];
 
-    let mut ed = Ed25519::new().expect("Error with new()");
+    let mut ed = Ed25519::new(&wolfcrypt).expect("Error with new()");
     ed.import_private_key(&private_key, Some(&public_key)).expect("Error with import_private_key()");
 
     let mut signature = [0u8; Ed25519::SIG_SIZE];
@@ -116,6 +119,7 @@ fn test_sign_streaming_verify() {
 #[test]
 #[cfg(all(ed25519_import, ed25519_sign, ed25519_verify))]
 fn test_ctx_sign_verify() {
+    let wolfcrypt = common::setup();
     let private_key = [
         0x03u8,0x05,0x33,0x4e,0x38,0x1a,0xf7,0x8f,
         0x14,0x1c,0xb6,0x66,0xf6,0x19,0x9f,0x57,
@@ -144,7 +148,7 @@ fn test_ctx_sign_verify() {
         0x1f,0x4b,0x88,0x8e,0x4e,0x7e,0xdb,0x0d
     ];
 
-    let mut ed = Ed25519::new().expect("Error with new()");
+    let mut ed = Ed25519::new(&wolfcrypt).expect("Error with new()");
     ed.import_private_key(&private_key, Some(&public_key)).expect("Error with import_private_key()");
 
     let mut signature = [0u8; Ed25519::SIG_SIZE];
@@ -158,6 +162,7 @@ fn test_ctx_sign_verify() {
 #[test]
 #[cfg(all(ed25519_import, ed25519_sign, ed25519_verify))]
 fn test_ph_sign_verify() {
+    let wolfcrypt = common::setup();
     let private_key = [
         0x83u8,0x3f,0xe6,0x24,0x09,0x23,0x7b,0x9d,
         0x62,0xec,0x77,0x58,0x75,0x20,0x91,0x1e,
@@ -193,7 +198,7 @@ fn test_ph_sign_verify() {
         0x3f,0x73,0xe6,0xe3,0xba,0xa8,0x1c,0x0e
     ];
 
-    let mut ed = Ed25519::new().expect("Error with new()");
+    let mut ed = Ed25519::new(&wolfcrypt).expect("Error with new()");
     ed.import_private_key(&private_key, Some(&public_key)).expect("Error with import_private_key()");
 
     let mut signature = [0u8; Ed25519::SIG_SIZE];
@@ -226,10 +231,10 @@ fn test_ph_sign_verify() {
 #[test]
 #[cfg(all(ed25519_sign, ed25519_verify))]
 fn test_verify_msg_bad_sig() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
 
     let message = [0x42u8, 33, 55, 66];
     let mut signature = [0u8; Ed25519::SIG_SIZE];
@@ -242,10 +247,10 @@ fn test_verify_msg_bad_sig() {
 #[test]
 #[cfg(all(ed25519_sign, ed25519_verify))]
 fn test_verify_msg_ex_bad_sig() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
 
     let message = [0x42u8, 33, 55, 66];
     let mut signature = [0u8; Ed25519::SIG_SIZE];
@@ -258,10 +263,10 @@ fn test_verify_msg_ex_bad_sig() {
 #[test]
 #[cfg(all(ed25519_sign, ed25519_verify))]
 fn test_verify_msg_ctx_bad_sig() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
 
     let message = [0x42u8, 33, 55, 66];
     let context = b"context";
@@ -275,10 +280,10 @@ fn test_verify_msg_ctx_bad_sig() {
 #[test]
 #[cfg(all(ed25519_sign, ed25519_verify))]
 fn test_verify_msg_ph_bad_sig() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
 
     let message = [0x42u8, 33, 55, 66];
     let context = b"context";
@@ -292,10 +297,10 @@ fn test_verify_msg_ph_bad_sig() {
 #[test]
 #[cfg(all(ed25519_sign, ed25519_verify))]
 fn test_verify_hash_ph_bad_sig() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
 
     let hash = [0x55u8; 64];
     let context = b"context";
@@ -309,10 +314,10 @@ fn test_verify_hash_ph_bad_sig() {
 #[test]
 #[cfg(all(ed25519_sign, ed25519_streaming_verify))]
 fn test_verify_msg_final_bad_sig() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
 
     let message = [0x42u8, 33, 55, 66];
     let mut signature = [0u8; Ed25519::SIG_SIZE];
@@ -329,10 +334,10 @@ fn test_verify_msg_final_bad_sig() {
 #[test]
 #[cfg(all(ed25519_import, ed25519_export))]
 fn test_import_export() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
 
     let mut private = [0u8; Ed25519::PRV_KEY_SIZE];
     let mut public = [0u8; Ed25519::PUB_KEY_SIZE];
@@ -349,10 +354,10 @@ fn test_import_export() {
     let mut private_only = [0u8; Ed25519::KEY_SIZE];
     ed.export_private_only(&mut private_only).expect("Error with export_private_only()");
 
-    let mut ed = Ed25519::new().expect("Error with new()");
+    let mut ed = Ed25519::new(&wolfcrypt).expect("Error with new()");
     ed.import_private_key_ex(&private, Some(&public), false).expect("Error with import_private_key_ex()");
 
-    let mut ed = Ed25519::new().expect("Error with new()");
+    let mut ed = Ed25519::new(&wolfcrypt).expect("Error with new()");
     ed.import_private_only(&private_only).expect("Error with import_private_only()");
     ed.import_public(&public).expect("Error with import_public()");
     ed.import_public_ex(&public, false).expect("Error with import_public_ex()");
@@ -363,10 +368,10 @@ fn test_import_export() {
 fn test_signature_traits() {
     use signature::{Keypair, SignerMut, Verifier};
 
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
 
     let message = b"message to sign via RustCrypto signature trait";
     let sig: Signature = ed.sign(message);
@@ -397,8 +402,9 @@ fn test_signature_traits() {
 
 #[test]
 fn test_sizes() {
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let ed = Ed25519::generate(&mut rng).expect("Error with generate()");
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let ed = Ed25519::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
 
     let size = ed.size().expect("Error with size()");
     assert_eq!(size, Ed25519::KEY_SIZE);
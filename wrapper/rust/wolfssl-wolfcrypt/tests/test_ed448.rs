@@ -8,13 +8,13 @@ use wolfssl_wolfcrypt::ed448::*;
 #[test]
 #[cfg(all(ed448_import, ed448_export))]
 fn test_make_public() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     let mut private = [0u8; Ed448::KEY_SIZE];
     ed.export_private_only(&mut private).expect("Error with export_private_only()");
-    let mut ed = Ed448::new().expect("Error with new()");
+    let mut ed = Ed448::new(&wolfcrypt).expect("Error with new()");
     ed.import_private_only(&private).expect("Error with import_private_only()");
     let mut public = [0u8; Ed448::KEY_SIZE];
     ed.make_public(&mut public).expect("Error with make_public()");
@@ -22,14 +22,16 @@ fn test_make_public() {
 
 #[test]
 fn test_check_key() {
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
     ed.check_key().expect("Error with check_key()");
 }
 
 #[test]
 #[cfg(all(ed448_import, ed448_sign, ed448_verify))]
 fn test_sign_verify() {
+    let wolfcrypt = common::setup();
     let private_key = [
         0xc4u8, 0xea, 0xb0, 0x5d, 0x35, 0x70, 0x07, 0xc6,
         0x32, 0xf3, 0xdb, 0xb4, 0x84, 0x89, 0x92, 0x4d,
@@ -70,7 +72,7 @@ fn test_sign_verify() {
         0x3c, 0x00
     ];
 
-    let mut ed = Ed448::new().expect("Error with new()");
+    let mut ed = Ed448::new(&wolfcrypt).expect("Error with new()");
     ed.import_private_key(&private_key, Some(&public_key)).expect("Error with import_private_key()");
 
     let mut signature = [0u8; Ed448::SIG_SIZE];
@@ -91,6 +93,7 @@ fn test_sign_verify() {
 #[test]
 #[cfg(all(ed448_import, ed448_sign, ed448_streaming_verify))]
 fn test_sign_streaming_verify() {
+    let wolfcrypt = common::setup();
     let private_key = [
         0xc4u8, 0xea, 0xb0, 0x5d, 0x35, 0x70, 0x07, 0xc6,
         0x32, 0xf3, 0xdb, 0xb4, 0x84, 0x89, 0x92, 0x4d,
@@ -131,7 +134,7 @@ fn test_sign_streaming_verify() {
         0x3c, 0x00
     ];
 
-    let mut ed = Ed448::new().expect("Error with new()");
+    let mut ed = Ed448::new(&wolfcrypt).expect("Error with new()");
     ed.import_private_key(&private_key, Some(&public_key)).expect("Error with import_private_key()");
 
     let mut signature = [0u8; Ed448::SIG_SIZE];
@@ -147,6 +150,7 @@ fn test_sign_streaming_verify() {
 #[test]
 #[cfg(all(ed448_import, ed448_sign, ed448_verify))]
 fn test_ph_sign_verify() {
+    let wolfcrypt = common::setup();
     let private_key = [
         0x83u8, 0x3f, 0xe6, 0x24, 0x09, 0x23, 0x7b, 0x9d,
         0x62, 0xec, 0x77, 0x58, 0x75, 0x20, 0x91, 0x1e,
@@ -197,7 +201,7 @@ fn test_ph_sign_verify() {
         0x21, 0x00
     ];
 
-    let mut ed = Ed448::new().expect("Error with new()");
+    let mut ed = Ed448::new(&wolfcrypt).expect("Error with new()");
     ed.import_private_key(&private_key, Some(&public_key)).expect("Error with import_private_key()");
 
     let mut signature = [0u8; Ed448::SIG_SIZE];
@@ -230,10 +234,10 @@ fn test_ph_sign_verify() {
 #[test]
 #[cfg(all(ed448_sign, ed448_verify))]
 fn test_verify_msg_bad_sig() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
 
     let message = [0x42u8, 33, 55, 66];
     let mut signature = [0u8; Ed448::SIG_SIZE];
@@ -246,10 +250,10 @@ fn test_verify_msg_bad_sig() {
 #[test]
 #[cfg(all(ed448_sign, ed448_verify))]
 fn test_verify_msg_ex_bad_sig() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
 
     let message = [0x42u8, 33, 55, 66];
     let mut signature = [0u8; Ed448::SIG_SIZE];
@@ -262,10 +266,10 @@ fn test_verify_msg_ex_bad_sig() {
 #[test]
 #[cfg(all(ed448_sign, ed448_verify))]
 fn test_verify_msg_ph_bad_sig() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
 
     let message = [0x42u8, 33, 55, 66];
     let context = b"context";
@@ -279,10 +283,10 @@ fn test_verify_msg_ph_bad_sig() {
 #[test]
 #[cfg(all(ed448_sign, ed448_verify))]
 fn test_verify_hash_ph_bad_sig() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
 
     let hash = [0x55u8; 64];
     let context = b"context";
@@ -296,10 +300,10 @@ fn test_verify_hash_ph_bad_sig() {
 #[test]
 #[cfg(all(ed448_sign, ed448_streaming_verify))]
 fn test_verify_msg_final_bad_sig() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
 
     let message = [0x42u8, 33, 55, 66];
     let mut signature = [0u8; Ed448::SIG_SIZE];
@@ -315,10 +319,10 @@ fn test_verify_msg_final_bad_sig() {
 #[test]
 #[cfg(all(ed448_import, ed448_export))]
 fn test_import_export() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
 
     let mut private = [0u8; Ed448::PRV_KEY_SIZE];
     let mut public = [0u8; Ed448::PUB_KEY_SIZE];
@@ -335,10 +339,10 @@ fn test_import_export() {
     let mut private_only = [0u8; Ed448::KEY_SIZE];
     ed.export_private_only(&mut private_only).expect("Error with export_private_only()");
 
-    let mut ed = Ed448::new().expect("Error with new()");
+    let mut ed = Ed448::new(&wolfcrypt).expect("Error with new()");
     ed.import_private_key_ex(&private, Some(&public), false).expect("Error with import_private_key_ex()");
 
-    let mut ed = Ed448::new().expect("Error with new()");
+    let mut ed = Ed448::new(&wolfcrypt).expect("Error with new()");
     ed.import_private_only(&private_only).expect("Error with import_private_only()");
     ed.import_public(&public).expect("Error with import_public()");
     ed.import_public_ex(&public, false).expect("Error with import_public_ex()");
@@ -349,10 +353,10 @@ fn test_import_export() {
 fn test_signature_traits() {
     use signature::{Keypair, SignerMut, Verifier};
 
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
 
     let message = b"message to sign via RustCrypto signature trait";
     let sig: Signature = ed.sign(message);
@@ -383,8 +387,9 @@ fn test_signature_traits() {
 
 #[test]
 fn test_sizes() {
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let ed = Ed448::generate(&mut rng).expect("Error with generate()");
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let ed = Ed448::generate(&mut rng, &wolfcrypt).expect("Error with generate()");
 
     let size = ed.size().expect("Error with size()");
     assert_eq!(size, Ed448::KEY_SIZE);
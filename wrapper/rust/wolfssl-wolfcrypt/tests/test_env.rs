@@ -0,0 +1,65 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+mod common;
+
+#[cfg(all(wolf_crypto_cb, feature = "alloc"))]
+use wolfssl_wolfcrypt::cryptocb::{self, CryptoDevice};
+use wolfssl_wolfcrypt::Env;
+use wolfssl_wolfcrypt::memory::HeapHint;
+#[cfg(random)]
+use wolfssl_wolfcrypt::random::RNG;
+
+#[test]
+fn test_env_new() {
+    let wolfcrypt = common::setup();
+
+    let env = Env::new(&wolfcrypt);
+    assert_eq!(env.heap(), None);
+    assert_eq!(env.device(), None);
+
+    let mut value = 0u8;
+    let hint = unsafe { HeapHint::from_raw(&mut value as *mut u8 as *mut core::ffi::c_void) };
+    let env = Env::new(&wolfcrypt).with_heap(hint);
+    assert_eq!(env.heap(), Some(hint));
+    assert_eq!(env.device(), None);
+}
+
+#[test]
+#[cfg(all(wolf_crypto_cb, feature = "alloc"))]
+fn test_env_device() {
+    let wolfcrypt = common::setup();
+    struct Passthrough;
+    impl CryptoDevice for Passthrough {}
+
+    let registration = cryptocb::register_device(0x5200, Passthrough, &wolfcrypt).expect("Error with register_device()");
+    let env = Env::new(&wolfcrypt).with_device(registration.device());
+    assert_eq!(env.device(), Some(registration.device()));
+    assert_eq!(env.heap(), None);
+
+    #[cfg(random)]
+    {
+        let rng = RNG::new_ex(&env).expect("Error with RNG::new_ex()");
+        let mut buf = [0u8; 16];
+        rng.generate_block(&mut buf).expect("Error with generate_block()");
+    }
+
+    drop(registration);
+}
@@ -0,0 +1,45 @@
+mod common;
+
+use wolfssl_wolfcrypt::Error;
+use wolfssl_wolfcrypt::sys;
+
+#[test]
+fn test_error_classify() {
+    common::setup();
+
+    let vectors = [
+        (sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG, Error::BadFunc(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG)),
+        (sys::wolfCrypt_ErrorCodes_BUFFER_E, Error::Buffer(sys::wolfCrypt_ErrorCodes_BUFFER_E)),
+        (sys::wolfCrypt_ErrorCodes_MEMORY_E, Error::Memory(sys::wolfCrypt_ErrorCodes_MEMORY_E)),
+        (sys::wolfCrypt_ErrorCodes_RNG_FAILURE_E, Error::Rng(sys::wolfCrypt_ErrorCodes_RNG_FAILURE_E)),
+        (sys::wolfCrypt_ErrorCodes_SIG_VERIFY_E, Error::Verify(sys::wolfCrypt_ErrorCodes_SIG_VERIFY_E)),
+        (sys::wolfCrypt_ErrorCodes_AES_GCM_AUTH_E, Error::Auth(sys::wolfCrypt_ErrorCodes_AES_GCM_AUTH_E)),
+        (sys::wolfCrypt_ErrorCodes_BAD_STATE_E, Error::KeyState(sys::wolfCrypt_ErrorCodes_BAD_STATE_E)),
+        (sys::wolfCrypt_ErrorCodes_NOT_COMPILED_IN, Error::NotCompiledIn(sys::wolfCrypt_ErrorCodes_NOT_COMPILED_IN)),
+        (sys::wolfCrypt_ErrorCodes_ASN_PARSE_E, Error::Asn(sys::wolfCrypt_ErrorCodes_ASN_PARSE_E)),
+        (sys::wolfCrypt_ErrorCodes_WC_HW_E, Error::Hardware(sys::wolfCrypt_ErrorCodes_WC_HW_E)),
+        (sys::wolfCrypt_ErrorCodes_LENGTH_ONLY_E, Error::Other(sys::wolfCrypt_ErrorCodes_LENGTH_ONLY_E)),
+    ];
+    for (code, expected) in vectors {
+        let err = Error::from(code);
+        assert_eq!(err, expected);
+        assert_eq!(err.code(), code);
+        assert_eq!(err, code);
+        assert_eq!(i32::from(err), code);
+    }
+}
+
+#[test]
+fn test_error_display() {
+    common::setup();
+
+    let err = Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG);
+    let msg = err.message().expect("Error with message()");
+    assert!(!msg.is_empty());
+    let text = format!("{}", err);
+    assert!(text.starts_with(msg));
+    assert!(text.ends_with(&format!("({})", sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG)));
+
+    let source: &dyn core::error::Error = &err;
+    assert!(source.source().is_none());
+}
@@ -0,0 +1,220 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+#![cfg(frodokem)]
+
+mod common;
+
+use wolfssl_wolfcrypt::{Env, WolfCrypt};
+use wolfssl_wolfcrypt::frodokem::FrodoKem;
+#[cfg(all(frodokem_make_key, random))]
+use wolfssl_wolfcrypt::random::RNG;
+use wolfssl_wolfcrypt::Error;
+use wolfssl_wolfcrypt::sys;
+
+const ALL_TYPES: [i32; 12] = [
+    FrodoKem::TYPE_640_SHAKE,
+    FrodoKem::TYPE_976_SHAKE,
+    FrodoKem::TYPE_1344_SHAKE,
+    FrodoKem::TYPE_640_AES,
+    FrodoKem::TYPE_976_AES,
+    FrodoKem::TYPE_1344_AES,
+    FrodoKem::TYPE_EFRODOKEM_640_SHAKE,
+    FrodoKem::TYPE_EFRODOKEM_976_SHAKE,
+    FrodoKem::TYPE_EFRODOKEM_1344_SHAKE,
+    FrodoKem::TYPE_EFRODOKEM_640_AES,
+    FrodoKem::TYPE_EFRODOKEM_976_AES,
+    FrodoKem::TYPE_EFRODOKEM_1344_AES,
+];
+
+/// Create a key of the given type, or None when the type is not compiled in.
+fn new_if_enabled(key_type: i32, wolfcrypt: &WolfCrypt) -> Option<FrodoKem<'static>> {
+    match FrodoKem::new(key_type, wolfcrypt) {
+        Ok(key) => Some(key),
+        Err(e) if e == sys::wolfCrypt_ErrorCodes_NOT_COMPILED_IN => None,
+        Err(e) => panic!("Error with new() for key_type {}: {}", key_type, e),
+    }
+}
+
+/// Verify the type constants have the correct numeric values required by
+/// the wolfCrypt API.
+#[test]
+fn test_type_constants() {
+    assert_eq!(FrodoKem::TYPE_640_SHAKE, 0);
+    assert_eq!(FrodoKem::TYPE_976_SHAKE, 1);
+    assert_eq!(FrodoKem::TYPE_1344_SHAKE, 2);
+    assert_eq!(FrodoKem::TYPE_640_AES, 0x10);
+    assert_eq!(FrodoKem::TYPE_EFRODOKEM_640_SHAKE, 0x20);
+    assert_eq!(FrodoKem::TYPE_EFRODOKEM_1344_AES, 0x32);
+}
+
+/// Verify the random input sizes for each key type.
+#[test]
+fn test_rand_sizes() {
+    let expected = [
+        (FrodoKem::TYPE_640_SHAKE, 64, 48),
+        (FrodoKem::TYPE_976_SHAKE, 88, 72),
+        (FrodoKem::TYPE_1344_SHAKE, 112, 96),
+        (FrodoKem::TYPE_640_AES, 64, 48),
+        (FrodoKem::TYPE_EFRODOKEM_640_SHAKE, 48, 16),
+        (FrodoKem::TYPE_EFRODOKEM_976_SHAKE, 64, 24),
+        (FrodoKem::TYPE_EFRODOKEM_1344_AES, 80, 32),
+    ];
+    for (key_type, makekey, enc) in expected {
+        assert_eq!(FrodoKem::makekey_rand_size(key_type).unwrap(), makekey);
+        assert_eq!(FrodoKem::enc_rand_size(key_type).unwrap(), enc);
+    }
+    assert!(FrodoKem::makekey_rand_size(3).is_err());
+    assert!(FrodoKem::enc_rand_size(0x40).is_err());
+}
+
+/// Verify that `new()` rejects invalid key types.
+#[test]
+fn test_new_invalid_type() {
+    let wolfcrypt = common::setup();
+    assert_eq!(FrodoKem::new(3, &wolfcrypt).err(), Some(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG)));
+    assert_eq!(FrodoKem::new(0x40, &wolfcrypt).err(), Some(Error::from(sys::wolfCrypt_ErrorCodes_BAD_FUNC_ARG)));
+    assert!(FrodoKem::new_ex(-1, &Env::new(&wolfcrypt)).is_err());
+}
+
+/// Verify the runtime size queries for each enabled key type.
+#[test]
+fn test_size_queries() {
+    let wolfcrypt = common::setup();
+    let sizes = [
+        (9616, 19888, 9752, 9720, 16),
+        (15632, 31296, 15792, 15744, 24),
+        (21520, 43088, 21696, 21632, 32),
+    ];
+    for key_type in ALL_TYPES {
+        let Some(key) = new_if_enabled(key_type, &wolfcrypt) else { continue };
+        assert_eq!(key.key_type(), key_type);
+        let (pub_size, priv_size, ct_size, ect_size, ss_size) = sizes[(key_type & 0x0f) as usize];
+        let ephemeral = key_type & 0x20 != 0;
+        assert_eq!(key.public_key_size().unwrap(), pub_size);
+        assert_eq!(key.private_key_size().unwrap(), priv_size);
+        assert_eq!(key.cipher_text_size().unwrap(), if ephemeral { ect_size } else { ct_size });
+        assert_eq!(key.shared_secret_size().unwrap(), ss_size);
+    }
+    // The standard SHAKE parameter sets are enabled by default.
+    assert!(new_if_enabled(FrodoKem::TYPE_640_SHAKE, &wolfcrypt).is_some());
+}
+
+/// Verify encapsulation and decapsulation round trip for each enabled key type.
+#[test]
+#[cfg(all(frodokem_make_key, frodokem_encapsulate, frodokem_decapsulate, random))]
+fn test_encapsulate_decapsulate() {
+    let wolfcrypt = common::setup();
+    let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    for key_type in ALL_TYPES {
+        if new_if_enabled(key_type, &wolfcrypt).is_none() {
+            continue;
+        }
+        let mut key = FrodoKem::generate(key_type, &rng, &wolfcrypt).expect("Error with generate()");
+        let mut ct = vec![0u8; key.cipher_text_size().unwrap()];
+        let mut ss_enc = vec![0u8; key.shared_secret_size().unwrap()];
+        let mut ss_dec = vec![0u8; key.shared_secret_size().unwrap()];
+        key.encapsulate(&mut ct, &mut ss_enc, &rng).expect("Error with encapsulate()");
+        key.decapsulate(&mut ss_dec, &ct).expect("Error with decapsulate()");
+        assert_eq!(ss_enc, ss_dec, "shared secrets differ for key_type {}", key_type);
+
+        // Implicit rejection: a modified cipher text gives a different secret.
+        ct[0] ^= 0x01;
+        key.decapsulate(&mut ss_dec, &ct).expect("Error with decapsulate()");
+        assert_ne!(ss_enc, ss_dec);
+    }
+}
+
+/// Verify that the deterministic variants are deterministic.
+#[test]
+#[cfg(all(frodokem_make_key, frodokem_encapsulate, frodokem_decapsulate))]
+fn test_with_random() {
+    let wolfcrypt = common::setup();
+    let key_type = FrodoKem::TYPE_640_SHAKE;
+    let key_rand = [0x42u8; 64];
+    let enc_rand = [0x55u8; 48];
+
+    let mut key1 = FrodoKem::generate_with_random(key_type, &key_rand, &wolfcrypt)
+        .expect("Error with generate_with_random()");
+    let key2 = FrodoKem::generate_with_random_ex(key_type, &key_rand, &Env::new(&wolfcrypt))
+        .expect("Error with generate_with_random_ex()");
+    let mut pk1 = vec![0u8; key1.public_key_size().unwrap()];
+    let mut pk2 = vec![0u8; key2.public_key_size().unwrap()];
+    key1.encode_public_key(&mut pk1).expect("Error with encode_public_key()");
+    key2.encode_public_key(&mut pk2).expect("Error with encode_public_key()");
+    assert_eq!(pk1, pk2);
+
+    let mut ct1 = vec![0u8; key1.cipher_text_size().unwrap()];
+    let mut ct2 = vec![0u8; key1.cipher_text_size().unwrap()];
+    let mut ss1 = [0u8; 16];
+    let mut ss2 = [0u8; 16];
+    key1.encapsulate_with_random(&mut ct1, &mut ss1, &enc_rand)
+        .expect("Error with encapsulate_with_random()");
+    key1.encapsulate_with_random(&mut ct2, &mut ss2, &enc_rand)
+        .expect("Error with encapsulate_with_random()");
+    assert_eq!(ct1, ct2);
+    assert_eq!(ss1, ss2);
+
+    let mut ss_dec = [0u8; 16];
+    key1.decapsulate(&mut ss_dec, &ct1).expect("Error with decapsulate()");
+    assert_eq!(ss1, ss_dec);
+
+    // Wrong random input, cipher text and shared secret lengths.
+    assert!(FrodoKem::generate_with_random(key_type, &key_rand[..63], &wolfcrypt).is_err());
+    assert!(key1.encapsulate_with_random(&mut ct1, &mut ss1, &enc_rand[..47]).is_err());
+    assert!(key1.encapsulate_with_random(&mut ct1[1..], &mut ss1, &enc_rand).is_err());
+    assert!(key1.encapsulate_with_random(&mut ct1, &mut [0u8; 32], &enc_rand).is_err());
+    assert!(key1.decapsulate(&mut ss_dec, &ct1[1..]).is_err());
+}
+
+/// Verify public and private key encode/decode round trips.
+#[test]
+#[cfg(all(frodokem_make_key, frodokem_encapsulate, frodokem_decapsulate))]
+fn test_encode_decode() {
+    let wolfcrypt = common::setup();
+    let key_type = FrodoKem::TYPE_640_SHAKE;
+    let key = FrodoKem::generate_with_random(key_type, &[0x42u8; 64], &wolfcrypt)
+        .expect("Error with generate_with_random()");
+    let mut pk = vec![0u8; key.public_key_size().unwrap()];
+    let mut sk = vec![0u8; key.private_key_size().unwrap()];
+    key.encode_public_key(&mut pk).expect("Error with encode_public_key()");
+    key.encode_private_key(&mut sk).expect("Error with encode_private_key()");
+    assert!(key.encode_public_key(&mut pk[1..]).is_err());
+    assert!(key.encode_private_key(&mut sk[1..]).is_err());
+
+    let mut pub_key = FrodoKem::new(key_type, &wolfcrypt).expect("Error with new()");
+    pub_key.decode_public_key(&pk).expect("Error with decode_public_key()");
+    let mut priv_key = FrodoKem::new(key_type, &wolfcrypt).expect("Error with new()");
+    priv_key.decode_private_key(&sk).expect("Error with decode_private_key()");
+    assert!(priv_key.decode_public_key(&pk[1..]).is_err());
+    assert!(priv_key.decode_private_key(&sk[1..]).is_err());
+
+    let mut ct = vec![0u8; pub_key.cipher_text_size().unwrap()];
+    let mut ss_enc = [0u8; 16];
+    let mut ss_dec = [0u8; 16];
+    pub_key.encapsulate_with_random(&mut ct, &mut ss_enc, &[0x55u8; 48])
+        .expect("Error with encapsulate_with_random()");
+    priv_key.decapsulate(&mut ss_dec, &ct).expect("Error with decapsulate()");
+    assert_eq!(ss_enc, ss_dec);
+
+    // A key without a public key cannot encapsulate.
+    let mut empty = FrodoKem::new(key_type, &wolfcrypt).expect("Error with new()");
+    assert!(empty.encapsulate_with_random(&mut ct, &mut ss_enc, &[0x55u8; 48]).is_err());
+}
@@ -0,0 +1,130 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+#![cfg(all(frodokem, frodokem_make_key, frodokem_encapsulate, frodokem_decapsulate,
+    random, feature = "kem", feature = "rand_core"))]
+
+mod common;
+
+use kem::{Decapsulate, Decapsulator, Encapsulate, Kem, TryKeyInit, KeyExport};
+use kem::Generate;
+use wolfssl_wolfcrypt::frodokem::FrodoKem;
+use wolfssl_wolfcrypt::frodokem_kem::*;
+use wolfssl_wolfcrypt::random::RNG;
+
+/// Verify that the compile-time sizes used by the kem types match the runtime
+/// sizes reported by wolfCrypt.
+#[test]
+fn test_sizes_match_runtime() {
+    let wolfcrypt = common::setup();
+
+    let key = FrodoKem::new(FrodoKem::TYPE_640_SHAKE, &wolfcrypt).expect("new TYPE_640_SHAKE");
+    assert_eq!(key.public_key_size().unwrap(), 9616);
+    assert_eq!(key.private_key_size().unwrap(), 19888);
+    assert_eq!(key.cipher_text_size().unwrap(), 9752);
+    assert_eq!(key.shared_secret_size().unwrap(), 16);
+
+    let key = FrodoKem::new(FrodoKem::TYPE_976_SHAKE, &wolfcrypt).expect("new TYPE_976_SHAKE");
+    assert_eq!(key.public_key_size().unwrap(), 15632);
+    assert_eq!(key.private_key_size().unwrap(), 31296);
+    assert_eq!(key.cipher_text_size().unwrap(), 15792);
+    assert_eq!(key.shared_secret_size().unwrap(), 24);
+
+    let key = FrodoKem::new(FrodoKem::TYPE_1344_SHAKE, &wolfcrypt).expect("new TYPE_1344_SHAKE");
+    assert_eq!(key.public_key_size().unwrap(), 21520);
+    assert_eq!(key.private_key_size().unwrap(), 43088);
+    assert_eq!(key.cipher_text_size().unwrap(), 21696);
+    assert_eq!(key.shared_secret_size().unwrap(), 32);
+}
+
+/// Generate, encapsulate, and decapsulate with FrodoKEM-640 via the kem traits.
+#[test]
+fn test_kem_640_round_trip() {
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("RNG creation failed");
+
+    let (dk, ek) = FrodoKem640Shake::generate_keypair_from_rng(&mut rng);
+    let (ct, k_send) = ek.encapsulate_with_rng(&mut rng);
+    let k_recv = dk.decapsulate(&ct);
+    assert_eq!(k_send, k_recv);
+}
+
+/// Generate, encapsulate, and decapsulate with FrodoKEM-976 via the kem traits.
+#[test]
+fn test_kem_976_round_trip() {
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("RNG creation failed");
+
+    let (dk, ek) = FrodoKem976Shake::generate_keypair_from_rng(&mut rng);
+    let (ct, k_send) = ek.encapsulate_with_rng(&mut rng);
+    let k_recv = dk.decapsulate(&ct);
+    assert_eq!(k_send, k_recv);
+}
+
+/// Generate, encapsulate, and decapsulate with FrodoKEM-1344 via the kem
+/// traits.
+#[test]
+fn test_kem_1344_round_trip() {
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("RNG creation failed");
+
+    let (dk, ek) = FrodoKem1344Shake::generate_keypair_from_rng(&mut rng);
+    let (ct, k_send) = ek.encapsulate_with_rng(&mut rng);
+    let k_recv = dk.decapsulate(&ct);
+    assert_eq!(k_send, k_recv);
+}
+
+/// Verify that a tampered ciphertext produces a different shared secret
+/// (FrodoKEM implicit rejection).
+#[test]
+fn test_implicit_rejection() {
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("RNG creation failed");
+
+    let (dk, ek) = FrodoKem640Shake::generate_keypair_from_rng(&mut rng);
+    let (ct, k_send) = ek.encapsulate_with_rng(&mut rng);
+
+    let mut ct_tampered = ct.clone();
+    ct_tampered[0] ^= 0xFF;
+    let k_tampered = dk.decapsulate(&ct_tampered);
+
+    assert_eq!(k_send, dk.decapsulate(&ct));
+    assert_ne!(k_send, k_tampered);
+}
+
+/// Verify that `TryKeyInit` and `KeyExport` round-trip the encapsulation key
+/// and that `Decapsulator::encapsulation_key` returns a usable key.
+#[test]
+fn test_ek_export_import() {
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("RNG creation failed");
+
+    let dk = FrodoKem640ShakeDecapsulationKey::generate_from_rng(&mut rng);
+    let ek = dk.encapsulation_key();
+
+    let exported = ek.to_bytes();
+    let ek2 = FrodoKem640ShakeEncapsulationKey::new(&exported)
+        .expect("TryKeyInit failed");
+    assert_eq!(*ek, ek2);
+
+    let (ct, k_send) = ek2.encapsulate_with_rng(&mut rng);
+    let k_recv = dk.decapsulate(&ct);
+    assert_eq!(k_send, k_recv);
+}
@@ -2,9 +2,11 @@
 
 use wolfssl_wolfcrypt::hmac::*;
 use wolfssl_wolfcrypt::sha::SHA256;
+use wolfssl_wolfcrypt::WolfCrypt;
 
 #[test]
 fn test_hmac_sha256() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
     let hmac_size = HMAC::get_hmac_size_by_type(HMAC::TYPE_SHA256).expect("Error with get_hmac_size_by_type()");
     assert_eq!(hmac_size, SHA256::DIGEST_SIZE);
 
@@ -38,13 +40,13 @@ fn test_hmac_sha256() {
             continue;
         }
         #[cfg(not(hmac_setkey_ex))]
-        let mut hmac = HMAC::new(HMAC::TYPE_SHA256, keys[i]).expect("Error with new()");
+        let mut hmac = HMAC::new(HMAC::TYPE_SHA256, keys[i], &wolfcrypt).expect("Error with new()");
         #[cfg(hmac_setkey_ex)]
         let mut hmac =
             if keys[i].len() < 14 {
-                HMAC::new_allow_short_key(HMAC::TYPE_SHA256, keys[i]).expect("Error with new_allow_short_key()")
+                HMAC::new_allow_short_key(HMAC::TYPE_SHA256, keys[i], &wolfcrypt).expect("Error with new_allow_short_key()")
             } else {
-                HMAC::new(HMAC::TYPE_SHA256, keys[i]).expect("Error with new()")
+                HMAC::new(HMAC::TYPE_SHA256, keys[i], &wolfcrypt).expect("Error with new()")
             };
         let hmac_size = hmac.get_hmac_size().expect("Error with get_hmac_size()");
         assert_eq!(hmac_size, SHA256::DIGEST_SIZE);
@@ -0,0 +1,415 @@
+#![cfg(all(hmac, random))]
+
+mod common;
+
+use wolfssl_wolfcrypt::hpke::*;
+use wolfssl_wolfcrypt::random::RNG;
+use wolfssl_wolfcrypt::WolfCrypt;
+
+const INFO: &[u8] = b"Ode on a Grecian Urn";
+const PT: &[u8] = b"Beauty is truth, truth beauty";
+const PSK: &str = "0247fd33b913760fa1fa51e1892d9f307fbe65eb171e8132c2af18555a738b82";
+const PSK_ID: &str = "456e6e796e20447572696e206172616e204d6f726961";
+const EXPORTER_CONTEXTS: [&[u8]; 3] = [b"", &[0x00], b"TestContext"];
+
+/// Test vector from RFC 9180 Appendix A, encrypting PT with aad "Count-<seq>"
+/// and exporting 32 bytes for each of EXPORTER_CONTEXTS.
+#[allow(dead_code)]
+struct Vector {
+    suite: Suite,
+    mode: Mode,
+    ikm_e: &'static str,
+    ikm_r: &'static str,
+    ikm_s: Option<&'static str>,
+    pk_rm: &'static str,
+    enc: &'static str,
+    cts: [&'static str; 2],
+    exports: [&'static str; 3],
+}
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+}
+
+#[allow(dead_code)]
+fn check_vector(v: &Vector, wolfcrypt: &WolfCrypt) {
+    let suite = v.suite;
+    let sk_r = PrivateKey::derive(suite.kem, &hex(v.ikm_r), wolfcrypt).expect("Error with derive()");
+    assert_eq!(sk_r.public_key().as_bytes(), &hex(v.pk_rm)[..]);
+    let sk_s = v.ikm_s.map(|ikm| {
+        PrivateKey::derive(suite.kem, &hex(ikm), wolfcrypt).expect("Error with derive()")
+    });
+    let pk_s = sk_s.as_ref().map(PrivateKey::public_key);
+    let psk_key = hex(PSK);
+    let psk_id = hex(PSK_ID);
+    let psk = matches!(v.mode, Mode::Psk | Mode::AuthPsk)
+        .then_some(Psk { key: &psk_key, id: &psk_id });
+
+    let mut enc = vec![0u8; suite.kem.enc_size()];
+    let mut sender = suite.setup_sender_with_ikm(sk_r.public_key(), INFO, psk, sk_s.as_ref(),
+        &hex(v.ikm_e), &mut enc).expect("Error with setup_sender_with_ikm()");
+    assert_eq!(enc, hex(v.enc));
+    assert_eq!(sender.mode(), v.mode);
+    let mut receiver = suite.setup_receiver(&enc, &sk_r, INFO, psk, pk_s)
+        .expect("Error with setup_receiver()");
+    assert_eq!(receiver.mode(), v.mode);
+
+    for (seq, expected) in v.cts.iter().enumerate() {
+        let aad = format!("Count-{}", seq);
+        let mut ct = [0u8; 64];
+        let ct_size = sender.seal(aad.as_bytes(), PT, &mut ct).expect("Error with seal()");
+        assert_eq!(&ct[..ct_size], &hex(expected)[..]);
+        let mut pt = [0u8; 64];
+        let pt_size = receiver.open(aad.as_bytes(), &ct[..ct_size], &mut pt)
+            .expect("Error with open()");
+        assert_eq!(&pt[..pt_size], PT);
+    }
+    assert_eq!(sender.seq(), 2);
+    assert_eq!(receiver.seq(), 2);
+
+    for (exporter_context, expected) in EXPORTER_CONTEXTS.iter().zip(v.exports) {
+        let mut secret = [0u8; 32];
+        sender.export(exporter_context, &mut secret).expect("Error with export()");
+        assert_eq!(secret[..], hex(expected)[..]);
+        receiver.export(exporter_context, &mut secret).expect("Error with export()");
+        assert_eq!(secret[..], hex(expected)[..]);
+    }
+}
+
+#[allow(dead_code)]
+const X25519_SUITE: Suite = Suite::new(Kem::DhKemX25519HkdfSha256, Kdf::HkdfSha256,
+    Aead::Aes128Gcm);
+
+#[test]
+#[cfg(all(curve25519, aes_gcm))]
+fn test_rfc9180_x25519_base() {
+    let wolfcrypt = common::setup();
+    check_vector(&Vector {
+        suite: X25519_SUITE,
+        mode: Mode::Base,
+        ikm_e: "7268600d403fce431561aef583ee1613527cff655c1343f29812e66706df3234",
+        ikm_r: "6db9df30aa07dd42ee5e8181afdb977e538f5e1fec8a06223f33f7013e525037",
+        ikm_s: None,
+        pk_rm: "3948cfe0ad1ddb695d780e59077195da6c56506b027329794ab02bca80815c4d",
+        enc: "37fda3567bdbd628e88668c3c8d7e97d1d1253b6d4ea6d44c150f741f1bf4431",
+        cts: [
+            "f938558b5d72f1a23810b4be2ab4f84331acc02fc97babc53a52ae8218a355a96d8770ac83d07bea87e13c512a",
+            "af2d7e9ac9ae7e270f46ba1f975be53c09f8d875bdc8535458c2494e8a6eab251c03d0c22a56b8ca42c2063b84",
+        ],
+        exports: [
+            "3853fe2b4035195a573ffc53856e77058e15d9ea064de3e59f4961d0095250ee",
+            "2e8f0b54673c7029649d4eb9d5e33bf1872cf76d623ff164ac185da9e88c21a5",
+            "e9e43065102c3836401bed8c3c3c75ae46be1639869391d62c61f1ec7af54931",
+        ],
+    }, &wolfcrypt);
+}
+
+#[test]
+#[cfg(all(curve25519, aes_gcm))]
+fn test_rfc9180_x25519_psk() {
+    let wolfcrypt = common::setup();
+    check_vector(&Vector {
+        suite: X25519_SUITE,
+        mode: Mode::Psk,
+        ikm_e: "78628c354e46f3e169bd231be7b2ff1c77aa302460a26dbfa15515684c00130b",
+        ikm_r: "d4a09d09f575fef425905d2ab396c1449141463f698f8efdb7accfaff8995098",
+        ikm_s: None,
+        pk_rm: "9fed7e8c17387560e92cc6462a68049657246a09bfa8ade7aefe589672016366",
+        enc: "0ad0950d9fb9588e59690b74f1237ecdf1d775cd60be2eca57af5a4b0471c91b",
+        cts: [
+            "e52c6fed7f758d0cf7145689f21bc1be6ec9ea097fef4e959440012f4feb73fb611b946199e681f4cfc34db8ea",
+            "49f3b19b28a9ea9f43e8c71204c00d4a490ee7f61387b6719db765e948123b45b61633ef059ba22cd62437c8ba",
+        ],
+        exports: [
+            "dff17af354c8b41673567db6259fd6029967b4e1aad13023c2ae5df8f4f43bf6",
+            "6a847261d8207fe596befb52928463881ab493da345b10e1dcc645e3b94e2d95",
+            "8aff52b45a1be3a734bc7a41e20b4e055ad4c4d22104b0c20285a7c4302401cd",
+        ],
+    }, &wolfcrypt);
+}
+
+#[test]
+#[cfg(all(curve25519, aes_gcm))]
+fn test_rfc9180_x25519_auth() {
+    let wolfcrypt = common::setup();
+    check_vector(&Vector {
+        suite: X25519_SUITE,
+        mode: Mode::Auth,
+        ikm_e: "6e6d8f200ea2fb20c30b003a8b4f433d2f4ed4c2658d5bc8ce2fef718059c9f7",
+        ikm_r: "f1d4a30a4cef8d6d4e3b016e6fd3799ea057db4f345472ed302a67ce1c20cdec",
+        ikm_s: Some("94b020ce91d73fca4649006c7e7329a67b40c55e9e93cc907d282bbbff386f58"),
+        pk_rm: "1632d5c2f71c2b38d0a8fcc359355200caa8b1ffdf28618080466c909cb69b2e",
+        enc: "23fb952571a14a25e3d678140cd0e5eb47a0961bb18afcf85896e5453c312e76",
+        cts: [
+            "5fd92cc9d46dbf8943e72a07e42f363ed5f721212cd90bcfd072bfd9f44e06b80fd17824947496e21b680c141b",
+            "d3736bb256c19bfa93d79e8f80b7971262cb7c887e35c26370cfed62254369a1b52e3d505b79dd699f002bc8ed",
+        ],
+        exports: [
+            "28c70088017d70c896a8420f04702c5a321d9cbf0279fba899b59e51bac72c85",
+            "25dfc004b0892be1888c3914977aa9c9bbaf2c7471708a49e1195af48a6f29ce",
+            "5a0131813abc9a522cad678eb6bafaabc43389934adb8097d23c5ff68059eb64",
+        ],
+    }, &wolfcrypt);
+}
+
+#[test]
+#[cfg(all(curve25519, aes_gcm))]
+fn test_rfc9180_x25519_auth_psk() {
+    let wolfcrypt = common::setup();
+    check_vector(&Vector {
+        suite: X25519_SUITE,
+        mode: Mode::AuthPsk,
+        ikm_e: "4303619085a20ebcf18edd22782952b8a7161e1dbae6e46e143a52a96127cf84",
+        ikm_r: "4b16221f3b269a88e207270b5e1de28cb01f847841b344b8314d6a622fe5ee90",
+        ikm_s: Some("62f77dcf5df0dd7eac54eac9f654f426d4161ec850cc65c54f8b65d2e0b4e345"),
+        pk_rm: "1d11a3cd247ae48e901939659bd4d79b6b959e1f3e7d66663fbc9412dd4e0976",
+        enc: "820818d3c23993492cc5623ab437a48a0a7ca3e9639c140fe1e33811eb844b7c",
+        cts: [
+            "a84c64df1e11d8fd11450039d4fe64ff0c8a99fca0bd72c2d4c3e0400bc14a40f27e45e141a24001697737533e",
+            "4d19303b848f424fc3c3beca249b2c6de0a34083b8e909b6aa4c3688505c05ffe0c8f57a0a4c5ab9da127435d9",
+        ],
+        exports: [
+            "08f7e20644bb9b8af54ad66d2067457c5f9fcb2a23d9f6cb4445c0797b330067",
+            "52e51ff7d436557ced5265ff8b94ce69cf7583f49cdb374e6aad801fc063b010",
+            "a30c20370c026bbea4dca51cb63761695132d342bae33a6a11527d3e7679436d",
+        ],
+    }, &wolfcrypt);
+}
+
+#[test]
+#[cfg(all(ecc, ecc_dh, ecc_import, ecc_export, aes_gcm))]
+fn test_rfc9180_p256_base() {
+    let wolfcrypt = common::setup();
+    check_vector(&Vector {
+        suite: Suite::new(Kem::DhKemP256HkdfSha256, Kdf::HkdfSha256, Aead::Aes128Gcm),
+        mode: Mode::Base,
+        ikm_e: "4270e54ffd08d79d5928020af4686d8f6b7d35dbe470265f1f5aa22816ce860e",
+        ikm_r: "668b37171f1072f3cf12ea8a236a45df23fc13b82af3609ad1e354f6ef817550",
+        ikm_s: None,
+        pk_rm: "04fe8c19ce0905191ebc298a9245792531f26f0cece2460639e8bc39cb7f706a82\
+                6a779b4cf969b8a0e539c7f62fb3d30ad6aa8f80e30f1d128aafd68a2ce72ea0",
+        enc: "04a92719c6195d5085104f469a8b9814d5838ff72b60501e2c4466e5e67b325ac9\
+              8536d7b61a1af4b78e5b7f951c0900be863c403ce65c9bfcb9382657222d18c4",
+        cts: [
+            "5ad590bb8baa577f8619db35a36311226a896e7342a6d836d8b7bcd2f20b6c7f9076ac232e3ab2523f39513434",
+            "fa6f037b47fc21826b610172ca9637e82d6e5801eb31cbd3748271affd4ecb06646e0329cbdf3c3cd655b28e82",
+        ],
+        exports: [
+            "5e9bc3d236e1911d95e65b576a8a86d478fb827e8bdfe77b741b289890490d4d",
+            "6cff87658931bda83dc857e6353efe4987a201b849658d9b047aab4cf216e796",
+            "d8f1ea7942adbba7412c6d431c62d01371ea476b823eb697e1f6e6cae1dab85a",
+        ],
+    }, &wolfcrypt);
+}
+
+#[test]
+#[cfg(all(ecc, ecc_dh, ecc_import, ecc_export, aes_gcm))]
+fn test_rfc9180_p521_base() {
+    let wolfcrypt = common::setup();
+    check_vector(&Vector {
+        suite: Suite::new(Kem::DhKemP521HkdfSha512, Kdf::HkdfSha512, Aead::Aes256Gcm),
+        mode: Mode::Base,
+        ikm_e: "7f06ab8215105fc46aceeb2e3dc5028b44364f960426eb0d8e4026c2f8b5d7e7a9\
+                86688f1591abf5ab753c357a5d6f0440414b4ed4ede71317772ac98d9239f70904",
+        ikm_r: "2ad954bbe39b7122529f7dde780bff626cd97f850d0784a432784e69d86eccaade\
+                43b6c10a8ffdb94bf943c6da479db137914ec835a7e715e36e45e29b587bab3bf1",
+        ikm_s: None,
+        pk_rm: "0401b45498c1714e2dce167d3caf162e45e0642afc7ed435df7902ccae0e84ba0f\
+                7d373f646b7738bbbdca11ed91bdeae3cdcba3301f2457be452f271fa6837580e6\
+                61012af49583a62e48d44bed350c7118c0d8dc861c238c72a2bda17f64704f464b\
+                57338e7f40b60959480c0e58e6559b190d81663ed816e523b6b6a418f66d2451ec64",
+        enc: "040138b385ca16bb0d5fa0c0665fbbd7e69e3ee29f63991d3e9b5fa740aab8900a\
+              aeed46ed73a49055758425a0ce36507c54b29cc5b85a5cee6bae0cf1c21f2731ec\
+              e2013dc3fb7c8d21654bb161b463962ca19e8c654ff24c94dd2898de12051f1ed0\
+              692237fb02b2f8d1dc1c73e9b366b529eb436e98a996ee522aef863dd5739d2f29b0",
+        cts: [
+            "170f8beddfe949b75ef9c387e201baf4132fa7374593dfafa90768788b7b2b200aafcc6d80ea4c795a7c5b841a",
+            "d9ee248e220ca24ac00bbbe7e221a832e4f7fa64c4fbab3945b6f3af0c5ecd5e16815b328be4954a05fd352256",
+        ],
+        exports: [
+            "05e2e5bd9f0c30832b80a279ff211cc65eceb0d97001524085d609ead60d0412",
+            "fca69744bb537f5b7a1596dbf34eaa8d84bf2e3ee7f1a155d41bd3624aa92b63",
+            "f389beaac6fcf6c0d9376e20f97e364f0609a88f1bc76d7328e9104df8477013",
+        ],
+    }, &wolfcrypt);
+}
+
+#[test]
+fn test_round_trip() {
+    let wolfcrypt = common::setup();
+
+    let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut kems = Vec::new();
+    #[cfg(all(ecc, ecc_dh, ecc_import, ecc_export))]
+    kems.extend([Kem::DhKemP256HkdfSha256, Kem::DhKemP384HkdfSha384,
+        Kem::DhKemP521HkdfSha512]);
+    #[cfg(curve25519)]
+    kems.push(Kem::DhKemX25519HkdfSha256);
+    #[cfg(curve448)]
+    kems.push(Kem::DhKemX448HkdfSha512);
+    #[cfg(mlkem)]
+    kems.extend([Kem::MlKem512, Kem::MlKem768, Kem::MlKem1024]);
+    let mut aeads = vec![Aead::ExportOnly];
+    #[cfg(aes_gcm)]
+    aeads.extend([Aead::Aes128Gcm, Aead::Aes256Gcm]);
+    #[cfg(chacha20_poly1305)]
+    aeads.push(Aead::ChaCha20Poly1305);
+    let psk = Psk { key: &[0x42u8; 32], id: b"psk id" };
+
+    for &kem in &kems {
+        let sk_r = PrivateKey::generate(kem, &rng, &wolfcrypt).expect("Error with generate()");
+        let sk_r2 = PrivateKey::from_bytes(kem, sk_r.as_bytes(), &wolfcrypt)
+            .expect("Error with from_bytes()");
+        assert_eq!(sk_r.public_key().as_bytes(), sk_r2.public_key().as_bytes());
+        let pk_r = PublicKey::from_bytes(kem, sk_r.public_key().as_bytes(), &wolfcrypt)
+            .expect("Error with from_bytes()");
+        let sk_s = PrivateKey::generate(kem, &rng, &wolfcrypt).expect("Error with generate()");
+
+        for kdf in [Kdf::HkdfSha256, Kdf::HkdfSha384, Kdf::HkdfSha512] {
+            for &aead in &aeads {
+                let suite = Suite::new(kem, kdf, aead);
+                for (psk, auth) in [(None, false), (Some(psk), false), (None, true),
+                        (Some(psk), true)] {
+                    if auth && !kem.supports_auth() {
+                        continue;
+                    }
+                    let sk_s = auth.then_some(&sk_s);
+                    let pk_s = sk_s.map(PrivateKey::public_key);
+                    let mut enc = vec![0u8; kem.enc_size()];
+                    let mut sender = suite.setup_sender(&pk_r, INFO, psk, sk_s, &rng, &mut enc)
+                        .expect("Error with setup_sender()");
+                    let mut receiver = suite.setup_receiver(&enc, &sk_r, INFO, psk, pk_s)
+                        .expect("Error with setup_receiver()");
+
+                    let mut sender_secret = [0u8; 100];
+                    let mut receiver_secret = [0u8; 100];
+                    sender.export(b"context", &mut sender_secret).expect("Error with export()");
+                    receiver.export(b"context", &mut receiver_secret).expect("Error with export()");
+                    assert_eq!(sender_secret, receiver_secret);
+
+                    let mut ct = [0u8; 64];
+                    let mut pt = [0u8; 64];
+                    if aead == Aead::ExportOnly {
+                        assert!(sender.seal(b"", PT, &mut ct).is_err());
+                        continue;
+                    }
+                    for _ in 0..3 {
+                        let ct_size = sender.seal(b"aad", PT, &mut ct).expect("Error with seal()");
+                        let pt_size = receiver.open(b"aad", &ct[..ct_size], &mut pt)
+                            .expect("Error with open()");
+                        assert_eq!(&pt[..pt_size], PT);
+                    }
+                    assert_eq!(receiver.seq(), 3);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg(all(curve25519, aes_gcm))]
+fn test_open_failures() {
+    let wolfcrypt = common::setup();
+
+    let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let suite = X25519_SUITE;
+    let sk_r = PrivateKey::generate(suite.kem, &rng, &wolfcrypt).expect("Error with generate()");
+    let psk = Psk { key: &[0x42u8; 32], id: b"psk id" };
+    let mut enc = [0u8; 32];
+    let mut sender = suite.setup_sender(sk_r.public_key(), INFO, Some(psk), None, &rng,
+        &mut enc).expect("Error with setup_sender()");
+    let mut ct = [0u8; 64];
+    let ct_size = sender.seal(b"aad", PT, &mut ct).expect("Error with seal()");
+    let ct = &ct[..ct_size];
+    let mut pt = [0u8; 64];
+
+    let mut receiver = suite.setup_receiver(&enc, &sk_r, INFO, Some(psk), None)
+        .expect("Error with setup_receiver()");
+    assert!(receiver.open(b"bad", ct, &mut pt).is_err());
+    assert!(pt.iter().all(|&b| b == 0));
+    assert_eq!(receiver.seq(), 0);
+    assert!(receiver.open(b"aad", &ct[..TAG_SIZE - 1], &mut pt).is_err());
+    assert!(receiver.open(b"aad", ct, &mut pt[..PT.len() - 1]).is_err());
+    let pt_size = receiver.open(b"aad", ct, &mut pt).expect("Error with open()");
+    assert_eq!(&pt[..pt_size], PT);
+    assert!(receiver.open(b"aad", ct, &mut pt).is_err());
+
+    let other_psk = Psk { key: &[0x43u8; 32], id: b"psk id" };
+    for (info, psk) in [(&b"other info"[..], Some(psk)), (INFO, Some(other_psk)), (INFO, None)] {
+        let mut receiver = suite.setup_receiver(&enc, &sk_r, info, psk, None)
+            .expect("Error with setup_receiver()");
+        assert!(receiver.open(b"aad", ct, &mut pt).is_err());
+    }
+}
+
+#[test]
+#[cfg(curve25519)]
+fn test_invalid_args() {
+    let wolfcrypt = common::setup();
+
+    let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let suite = Suite::new(Kem::DhKemX25519HkdfSha256, Kdf::HkdfSha256, Aead::ExportOnly);
+    let sk_r = PrivateKey::generate(suite.kem, &rng, &wolfcrypt).expect("Error with generate()");
+    let mut enc = [0u8; 32];
+
+    assert!(suite.setup_sender(sk_r.public_key(), INFO, None, None, &rng,
+        &mut enc[..31]).is_err());
+    let empty_psk = Psk { key: b"", id: b"psk id" };
+    assert!(suite.setup_sender(sk_r.public_key(), INFO, Some(empty_psk), None, &rng,
+        &mut enc).is_err());
+    let other_suite = Suite::new(Kem::DhKemP256HkdfSha256, Kdf::HkdfSha256, Aead::ExportOnly);
+    assert!(other_suite.setup_sender(sk_r.public_key(), INFO, None, None, &rng,
+        &mut [0u8; 65]).is_err());
+    assert!(suite.setup_receiver(&enc[..31], &sk_r, INFO, None, None).is_err());
+
+    let mut sender = suite.setup_sender(sk_r.public_key(), INFO, None, None, &rng,
+        &mut enc).expect("Error with setup_sender()");
+    let mut secret = vec![0u8; 255 * 32 + 1];
+    assert!(sender.export(b"", &mut secret).is_err());
+    sender.export(b"", &mut secret[..255 * 32]).expect("Error with export()");
+    assert!(sender.seal(b"", PT, &mut [0u8; 64]).is_err());
+
+    assert!(PrivateKey::derive(suite.kem, &[0u8; 31], &wolfcrypt).is_err());
+    assert!(PrivateKey::from_bytes(suite.kem, &[0u8; 33], &wolfcrypt).is_err());
+    assert!(PublicKey::from_bytes(suite.kem, &[0u8; 31], &wolfcrypt).is_err());
+
+    assert_eq!(Kem::from_id(0x0020), Some(Kem::DhKemX25519HkdfSha256));
+    assert_eq!(Kdf::from_id(Kdf::HkdfSha384.id()), Some(Kdf::HkdfSha384));
+    assert_eq!(Aead::from_id(0xFFFF), Some(Aead::ExportOnly));
+    assert_eq!(Kem::from_id(0x0030), None);
+}
+
+#[test]
+#[cfg(mlkem)]
+fn test_mlkem() {
+    let wolfcrypt = common::setup();
+
+    let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let suite = Suite::new(Kem::MlKem768, Kdf::HkdfSha256, Aead::ExportOnly);
+    let sk_r = PrivateKey::generate(suite.kem, &rng, &wolfcrypt).expect("Error with generate()");
+    let sk_s = PrivateKey::generate(suite.kem, &rng, &wolfcrypt).expect("Error with generate()");
+    assert_eq!(sk_r.as_bytes().len(), 64);
+    assert_eq!(sk_r.public_key().as_bytes().len(), 1184);
+    assert!(PrivateKey::derive(suite.kem, &[0u8; 64], &wolfcrypt).is_err());
+
+    let mut enc = vec![0u8; suite.kem.enc_size()];
+    assert!(suite.setup_sender(sk_r.public_key(), INFO, None, Some(&sk_s), &rng,
+        &mut enc).is_err());
+
+    // Deterministic encapsulation with fixed randomness.
+    let rand = [0x5Au8; 32];
+    let mut enc2 = vec![0u8; suite.kem.enc_size()];
+    let sender = suite.setup_sender_with_ikm(sk_r.public_key(), INFO, None, None, &rand,
+        &mut enc).expect("Error with setup_sender_with_ikm()");
+    let sender2 = suite.setup_sender_with_ikm(sk_r.public_key(), INFO, None, None, &rand,
+        &mut enc2).expect("Error with setup_sender_with_ikm()");
+    assert_eq!(enc, enc2);
+    let receiver = suite.setup_receiver(&enc, &sk_r, INFO, None, None)
+        .expect("Error with setup_receiver()");
+    let mut secrets = [[0u8; 32]; 3];
+    sender.export(b"", &mut secrets[0]).expect("Error with export()");
+    sender2.export(b"", &mut secrets[1]).expect("Error with export()");
+    receiver.export(b"", &mut secrets[2]).expect("Error with export()");
+    assert_eq!(secrets[0], secrets[1]);
+    assert_eq!(secrets[0], secrets[2]);
+}
@@ -0,0 +1,486 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+#![cfg(all(curve25519, mlkem))]
+
+mod common;
+
+use wolfssl_wolfcrypt::curve25519::Curve25519Key;
+#[cfg(all(sha3, shake256))]
+use wolfssl_wolfcrypt::hybrid::XWing;
+use wolfssl_wolfcrypt::hybrid::X25519MlKem768;
+use wolfssl_wolfcrypt::mlkem::MlKem;
+#[cfg(random)]
+use wolfssl_wolfcrypt::random::RNG;
+#[cfg(all(sha3, shake256))]
+use wolfssl_wolfcrypt::sha::{SHA3_256, SHAKE256};
+use wolfssl_wolfcrypt::Error;
+use wolfssl_wolfcrypt::sys;
+
+/// X-Wing test vector 1 from draft-connolly-cfrg-xwing-kem. The seed and
+/// eseed are the first 96 bytes of SHAKE128 of the empty string.
+#[cfg(all(sha3, shake256))]
+const XWING_SEED: &str = "7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26";
+#[cfg(all(sha3, shake256))]
+const XWING_ESEED: &str = "3cb1eea988004b93103cfb0aeefd2a686e01fa4a58e8a3639ca8a1e3f9ae57e2\
+    35b8cc873c23dc62b8d260169afa2f75ab916a58d974918835d25e6a435085b2";
+#[cfg(all(sha3, shake256))]
+const XWING_PK: &str = "e2236b35a8c24b39b10aa1323a96a919a2ced88400633a7b07131713fc14b2b5\
+    b19cfc3da5fa1a92c49f25513e0fd30d6b1611c9ab9635d7086727a4b7d21d34\
+    244e66969cf15b3b2a785329f61b096b277ea037383479a6b556de7231fe4b7f\
+    a9c9ac24c0699a0018a5253401bacfa905ca816573e56a2d2e067e9b7287533b\
+    a13a937dedb31fa44baced40769923610034ae31e619a170245199b3c5c39864\
+    859fe1b4c9717a07c30495bdfb98a0a002ccf56c1286cef5041dede3c44cf16b\
+    f562c7448518026b3d8b9940680abd38a1575fd27b58da063bfac32c39c30869\
+    374c05c1aeb1898b6b303cc68be455346ee0af699636224a148ca2aea1046311\
+    1c709f69b69c70ce8538746698c4c60a9aef0030c7924ceec42a5d36816f545e\
+    ae13293460b3acb37ea0e13d70e4aa78686da398a8397c08eaf96882113fe4f7\
+    bad4da40b0501e1c753efe73053c87014e8661c33099afe8bede414a5b1aa27d\
+    8392b3e131e9a70c1055878240cad0f40d5fe3cdf85236ead97e2a97448363b2\
+    808caafd516cd25052c5c362543c2517e4acd0e60ec07163009b6425fc32277a\
+    cee71c24bab53ed9f29e74c66a0a3564955998d76b96a9a8b50d1635a4d7a67e\
+    b42df5644d330457293a8042f53cc7a69288f17ed55827e82b28e82665a86a14\
+    fbd96645eca8172c044f83bc0d8c0b4c8626985631ca87af829068f1358963cb\
+    333664ca482763ba3b3bb208577f9ba6ac62c25f76592743b64be519317714cb\
+    4102cb7b2f9a25b2b4f0615de31decd9ca55026d6da0b65111b16fe52feed8a4\
+    87e144462a6dba93728f500b6ffc49e515569ef25fed17aff520507368253525\
+    860f58be3be61c964604a6ac814e6935596402a520a4670b3d284318866593d1\
+    5a4bb01c35e3e587ee0c67d2880d6f2407fb7a70712b838deb96c5d7bf2b44bc\
+    f6038ccbe33fbcf51a54a584fe90083c91c7a6d43d4fb15f48c60c2fd66e0a8a\
+    ad4ad64e5c42bb8877c0ebec2b5e387c8a988fdc23beb9e16c8757781e0a1499\
+    c61e138c21f216c29d076979871caa6942bafc090544bee99b54b16cb9a9a364\
+    d6246d9f42cce53c66b59c45c8f9ae9299a75d15180c3c952151a91b7a107724\
+    29dc4cbae6fcc622fa8018c63439f890630b9928db6bb7f9438ae4065ed34d73\
+    d486f3f52f90f0807dc88dfdd8c728e954f1ac35c06c000ce41a0582580e3bb5\
+    7b672972890ac5e7988e7850657116f1b57d0809aaedec0bede1ae148148311c\
+    6f7e317346e5189fb8cd635b986f8c0bdd27641c584b778b3a911a80be1c9692\
+    ab8e1bbb12839573cce19df183b45835bbb55052f9fc66a1678ef2a36dea7841\
+    1e6c8d60501b4e60592d13698a943b509185db912e2ea10be06171236b327c71\
+    716094c964a68b03377f513a05bcd99c1f346583bb052977a10a12adfc758034\
+    e5617da4c1276585e5774e1f3b9978b09d0e9c44d3bc86151c43aad185712717\
+    340223ac381d21150a04294e97bb13bbda21b5a182b6da969e19a7fd072737fa\
+    8e880a53c2428e3d049b7d2197405296ddb361912a7bcf4827ced611d0c7a7da\
+    104dde4322095339f64a61d5bb108ff0bf4d780cae509fb22c256914193ff734\
+    9042581237d522828824ee3bdfd07fb03f1f942d2ea179fe722f06cc03de5b69\
+    859edb06eff389b27dce59844570216223593d4ba32d9abac8cd049040ef6534";
+#[cfg(all(sha3, shake256))]
+const XWING_CT: &str = "b83aa828d4d62b9a83ceffe1d3d3bb1ef31264643c070c5798927e41fb07914a\
+    273f8f96e7826cd5375a283d7da885304c5de0516a0f0654243dc5b97f8bfeb8\
+    31f68251219aabdd723bc6512041acbaef8af44265524942b902e68ffd23221c\
+    da70b1b55d776a92d1143ea3a0c475f63ee6890157c7116dae3f62bf72f60acd\
+    2bb8cc31ce2ba0de364f52b8ed38c79d719715963a5dd3842d8e8b43ab704e47\
+    59b5327bf027c63c8fa857c4908d5a8a7b88ac7f2be394d93c3706ddd4e698cc\
+    6ce370101f4d0213254238b4a2e8821b6e414a1cf20f6c1244b699046f5a01ca\
+    a0a1a55516300b40d2048c77cc73afba79afeea9d2c0118bdf2adb8870dc328c\
+    5516cc45b1a2058141039e2c90a110a9e16b318dfb53bd49a126d6b73f215787\
+    517b8917cc01cabd107d06859854ee8b4f9861c226d3764c87339ab16c3667d2\
+    f49384e55456dd40414b70a6af841585f4c90c68725d57704ee8ee7ce6e2f9be\
+    582dbee985e038ffc346ebfb4e22158b6c84374a9ab4a44e1f91de5aac5197f8\
+    9bc5e5442f51f9a5937b102ba3beaebf6e1c58380a4a5fedce4a4e5026f88f52\
+    8f59ffd2db41752b3a3d90efabe463899b7d40870c530c8841e8712b733668ed\
+    033adbfafb2d49d37a44d4064e5863eb0af0a08d47b3cc888373bc05f7a33b84\
+    1bc2587c57eb69554e8a3767b7506917b6b70498727f16eac1a36ec8d8cfaf75\
+    1549f2277db277e8a55a9a5106b23a0206b4721fa9b3048552c5bd5b594d6e24\
+    7f38c18c591aea7f56249c72ce7b117afcc3a8621582f9cf71787e183dee0936\
+    7976e98409ad9217a497df888042384d7707a6b78f5f7fb8409e3b5351753734\
+    61b776002d799cbad62860be70573ecbe13b246e0da7e93a52168e0fb6a9756b\
+    895ef7f0147a0dc81bfa644b088a9228160c0f9acf1379a2941cd28c06ebc80e\
+    44e17aa2f8177010afd78a97ce0868d1629ebb294c5151812c583daeb8868522\
+    0f4da9118112e07041fcc24d5564a99fdbde28869fe0722387d7a9a4d16e1cc8\
+    555917e09944aa5ebaaaec2cf62693afad42a3f518fce67d273cc6c9fb5472b3\
+    80e8573ec7de06a3ba2fd5f931d725b493026cb0acbd3fe62d00e4c790d965d7\
+    a03a3c0b4222ba8c2a9a16e2ac658f572ae0e746eafc4feba023576f08942278\
+    a041fb82a70a595d5bacbf297ce2029898a71e5c3b0d1c6228b485b1ade509b3\
+    5fbca7eca97b2132e7cb6bc465375146b7dceac969308ac0c2ac89e7863eb894\
+    3015b24314cafb9c7c0e85fe543d56658c213632599efabfc1ec49dd8c88547b\
+    b2cc40c9d38cbd3099b4547840560531d0188cd1e9c23a0ebee0a03d5577d66b\
+    1d2bcb4baaf21cc7fef1e03806ca96299df0dfbc56e1b2b43e4fc20c37f834c4\
+    af62127e7dae86c3c25a2f696ac8b589dec71d595bfbe94b5ed4bc07d800b330\
+    796fda89edb77be0294136139354eb8cd37591578f9c600dd9be8ec6219fdd50\
+    7adf3397ed4d68707b8d13b24ce4cd8fb22851bfe9d632407f31ed6f7cb1600d\
+    e56f17576740ce2a32fc5145030145cfb97e63e0e41d354274a079d3e6fb2e15";
+#[cfg(all(sha3, shake256))]
+const XWING_SS: &str = "d2df0522128f09dd8e2c92b1e905c793d8f57a54c3da25861f10bf4ca613e384";
+
+/// X25519MLKEM768 vector: the seed and encapsulation randomness are the first
+/// 160 bytes of SHAKE128 of the empty string. The expected values were
+/// computed with the OpenSSL 3.5 ML-KEM-768 implementation and an independent
+/// X25519 implementation.
+const X25519_MLKEM768_SEED: &str = "7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26\
+    3cb1eea988004b93103cfb0aeefd2a686e01fa4a58e8a3639ca8a1e3f9ae57e2\
+    35b8cc873c23dc62b8d260169afa2f75ab916a58d974918835d25e6a435085b2";
+const X25519_MLKEM768_RAND: &str = "badfd6dfaac359a5efbb7bcc4b59d538df9a04302e10c8bc1cbf1a0b3a5120ea\
+    17cda7cfad765f5623474d368ccca8af0007cd9f5e4c849f167a580b14aabdef";
+const X25519_MLKEM768_PK: &str = "7820320230238e447acfa99b6332b7531c7ce542031b93ca14258f5f98b30c87\
+    3f6d01b2c5853f3402691804804708a7ecfa5ba5980fef8836cef5a82dac8375\
+    e06234cbbed64cc5a4b2ac0684a0b4d9863e6644a8334849c0cfdbfb851b84b0\
+    e48c6fb04801d0522fd576689b164467604ab4da2e34abcf76dc9244674c4e57\
+    30f5787c679021424c1340154d2449330dbc940eba7be10399ec940a6b354060\
+    14996650b148a18943477f5e950f41a01ab63488faf206f0f225a73109677784\
+    791b6dec980e60eb8f96bbbf472222b0248a12f96188a17d68b83e52c9827ba2\
+    c6bc82750b31ae46b9827a2877fcb338003a68b8c1aa9c966b55d20378741157\
+    7705f6db1559f9b4258a571820505d83c1009aa2b284b375a7ac616a1b2942a2\
+    b494c43eb370c1b94acd522b208c17c735077e602f41410cfb1b3ea1092158c3\
+    b6ea43cc7aa70309a60fa9644721332a0dc455bdb563d5e66ee21210872a598d\
+    13a95183119a507a76343b454bb3204bad83469b562752a73a00e5e8c5a6d987\
+    0aca93786905c20b8502f45d9114a8d2c7bd2ee0087fb780fa5078588b026f14\
+    196cc068f6a75e7b4a443aea41425caf2da212a3e6adf6445164c2908f409d43\
+    965ee40b89d654c8413097486a9b90e2b0447438911836b7ca84e76b7a8ee600\
+    a13b55a8b83da0b20825382bdac3be25046024c788764053f1922cfaa2a1b816\
+    2230a36dbb36cf8e62c8a1b15f9d5190070ab4f570a3baf8637c04ad1951a4b4\
+    610b5b7ab23356c39e36a8128558a25914e1a8af76e47712f85438b19e8d04ce\
+    923520d3ab0ac4e40858f2362dd49db446b7edf7a0dd4c83aa307efa8776b3b8\
+    c7a10a1fdfe68eda1b9cd42ac0cec4760c259318082c18415d2cc29367f606ee\
+    ea1c0145389ea288f383a68dd620127aaca88bcb6b4245a6644f05a6b9f1163d\
+    38e17e57cb27cc5c467b2cca94f974cfd8150d5294e152200b39bafa469b9cfa\
+    c3aceb7b56b47b13f17181ea95877810a777ae9d1560ed5119ab09b76ab120e3\
+    c61961d58c3a931669a05fd2882f5197b7c3b047a197aa820c208a5cb5bc40be\
+    b82588f5610b0b354eb85039b0a41dfd39248f165abb027afd6817e00c8dadaa\
+    cb29cb25e424750932ae27756a98b7b7492853909ca95a56922a1336906c02b8\
+    1205bb58694de8c02a005c232609c87997b16ab6bc790dd3293003b9b4867b2b\
+    6e04c45618503594aa269c9d4fc39aa1ec363fbc57c70241310b1d6b4498e8a5\
+    56c487b16e45aa9bb9b1a6a9bb2a446f6ee309edec1d5532997a8694ddb991e3\
+    a677f895885fa93481ea217eba1c474bc48188147fdc032281cca5c30d5b1c3e\
+    d90932bde14855929f5e224f273875364b600c7c916bd31f66d7bbc470c536e6\
+    00e8da7f31fb3b994573e77796c53969371a5e30c371a03250e426c2dbda9eee\
+    d39b6b650338806605d35e64d559d0910d2ef751afa833002c905db55ee00981\
+    75f21111822f6a9c1aabcb9771cc153bb4b619d735fa8223d5f06a8b2b7908e3\
+    2d8f7b829dac88c9caa4742a12a2a44bc61420f7d26b87c3c833c56cb51acc1b\
+    d48c9a648b4f608e4feb378e6554b73ca8e37b9d5fa76b441c0397f8c0aa39b2\
+    348abc3c6d0d13a6bd91d62a43c6516063dfd5bf77f0f1084224437b47a82ba2\
+    e56f17576740ce2a32fc5145030145cfb97e63e0e41d354274a079d3e6fb2e15";
+const X25519_MLKEM768_CT: &str = "124bf94eab820433afeab369ad58da85e5ef46106da26e2c8b9768c80925d85a\
+    040520a25df3b28c012a2dca7592970edec240ddac28c6c2cee2cd0fc9650fd2\
+    647754d0f39b554b1fa7772ac1f231c919f6453e0bb3a7de1f7c36608fea9a79\
+    c71a2a2b00a5b529184fd2a70f786721cae4ebbb06ca8b076d7deead9411737c\
+    7dfb45b085880c6ee63492e912c845b980b6e1a07404c16cccd752ce75d38df6\
+    12bfe97c0f73bcad66456a2fc53bd3fe9424fefae6a3499019a80bd28162d29d\
+    b128dd8f9ffb7bccd4db1ba62fcc174dae78691940269f02f41c11755b84f9f6\
+    a75bd6b0acb77baa4ad3238bdee6bf6a66f598329777639810587d1eec349ad1\
+    11a9879822e39d33d97ee6d2e15f688a99b67be3b1f489b468fd3c3b1bde7ba2\
+    c1ba5707d5add5fd4bc9a3e3df39436dda20fe06b8be18f8b7dd65933bb50d34\
+    b04755a696ee791fd18f9ebcb5ace7ccd12ac6279d29dee6dc69f4b8c0fb11ef\
+    76f278991b6a9c1921d2e6acf43723d594ec92162c4db14e83745fd50e65c3a6\
+    cf800a71276f52e75e526ef7a35d3f45ad535b699fc77c0e395f96de233ea3aa\
+    749ed44f633acf9bee7d083823517e2a23192bf4cdc4d5db1c2765e4d6f902e6\
+    eb7bc6861785fd5c73dd9e1771bc961ed9a68188f190ee2a60ece22d8b2c2e2f\
+    a42c31b680b13b316ae24bdb6885e61f108e536a26bd914612857adba99f266c\
+    0065b578e3149175b5ff04b2438ae4734ad79beea67fadb8aba0d7b86579ab04\
+    6446bdbac4e34c3f2545ae0082e6cf3a07869776c97a10b57e0536080306ba65\
+    ed779d840e91d762f99f491be580f0944861de361028c7a5027ade038e746745\
+    be471f26e9306750a931cb89b281a698285771158619ea2178993ddf09f0c527\
+    1e042cdb0551db3d0f3bedb26be7e09afb7858bf18c63527b2fc2de22fbb326e\
+    6dd4e50e486907228373edd858b2ee2557a47156d28dafe3511b98b4dfc6ca55\
+    db68a4bc76dd74feeee1cdec255cbcc5a02b02c3a22923a3e5e449124b88b2f3\
+    1f4eb89af707234c3d932f61bb020144ef450c055d6c68a47e340e23d20c30b9\
+    f7bb1635e40a0b3adc10c2035e76c0d61212c877782b432c5bb8562a25497900\
+    30f72f9a9c0369ccc7570cf10d04fd50ff139d6d8a74d1ef67a471d840ba58e6\
+    d621328a33601aba2a53fb4f5ad151bd7152ac3fc0c260cc8816eebe3efeaba8\
+    83087d71edf6cf0ad8f2ab9b5eb43296f21c25476a86257d2489103693780ab1\
+    36bc0a77fe150258655d1138327b667f8d86171f8d6ae77529aa77b57e63fd20\
+    bbb5f9701de939bc0768b12ad3c91ba24b0b5a6c593264054e5d7acd11cb27dc\
+    f675c28161510ca9e81c56d253998430809be5852306f2677dca8c0bb0dafc47\
+    d7e94d90928ec1ccacb4a3b04fbcdb6cd19d1d6f7e67979ef099a48b21fb932f\
+    0422bc4d035f7de16965aed07418684df815fab5b5e64ce9b3f013ae4417e7cc\
+    c8faa538c8d29b5917bb0b9e8d66c50b5f2d87bc5050e4bb955968223c386f47\
+    b06b4975f00207b357550d24405189412ea6a83ad56c4873f499fdbdc761aa72";
+const X25519_MLKEM768_SS: &str = "e5fd598677e5fa97a498fbe2cdb6eb4b30902544a3bc755e5f447bfb5d7a9b7b\
+    42e10435fb59a3c7002f81ed5b4881fefbe91db088593d5f6e55f87b73a05136";
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+}
+
+fn clamp(mut k: [u8; 32]) -> [u8; 32] {
+    k[0] &= 248;
+    k[31] &= 127;
+    k[31] |= 64;
+    k
+}
+
+/// Verify the size constants.
+#[test]
+fn test_constants() {
+    assert_eq!(X25519MlKem768::SEED_SIZE, 96);
+    assert_eq!(X25519MlKem768::PUBLIC_KEY_SIZE, 1216);
+    assert_eq!(X25519MlKem768::CIPHER_TEXT_SIZE, 1120);
+    assert_eq!(X25519MlKem768::SHARED_SECRET_SIZE, 64);
+    assert_eq!(X25519MlKem768::ENC_RAND_SIZE, 64);
+    #[cfg(all(sha3, shake256))]
+    {
+        assert_eq!(XWing::SEED_SIZE, 32);
+        assert_eq!(XWing::PUBLIC_KEY_SIZE, 1216);
+        assert_eq!(XWing::CIPHER_TEXT_SIZE, 1120);
+        assert_eq!(XWing::SHARED_SECRET_SIZE, 32);
+        assert_eq!(XWing::ENC_RAND_SIZE, 64);
+    }
+}
+
+#[test]
+#[cfg(all(sha3, shake256, random))]
+fn test_xwing_round_trip() {
+    let wolfcrypt = common::setup();
+    let rng = RNG::new(&wolfcrypt).expect("Error with RNG::new()");
+    let mut alice = XWing::generate(&rng, &wolfcrypt).expect("Error with generate()");
+    let mut pk = [0u8; XWing::PUBLIC_KEY_SIZE];
+    alice.encode_public_key(&mut pk).expect("Error with encode_public_key()");
+
+    let mut bob = XWing::from_public_key(&pk, &wolfcrypt).expect("Error with from_public_key()");
+    let mut ct = [0u8; XWing::CIPHER_TEXT_SIZE];
+    let mut ss_bob = [0u8; XWing::SHARED_SECRET_SIZE];
+    bob.encapsulate(&mut ct, &mut ss_bob, &rng).expect("Error with encapsulate()");
+
+    let mut ss_alice = [0u8; XWing::SHARED_SECRET_SIZE];
+    alice.decapsulate(&mut ss_alice, &ct).expect("Error with decapsulate()");
+    assert_eq!(ss_alice, ss_bob);
+
+    /* An encapsulation-only key has no seed and cannot decapsulate. */
+    let mut seed = [0u8; XWing::SEED_SIZE];
+    assert_eq!(bob.export_seed(&mut seed), Err(Error::from(sys::wolfCrypt_ErrorCodes_MISSING_KEY)));
+    assert_eq!(bob.decapsulate(&mut ss_alice, &ct), Err(Error::from(sys::wolfCrypt_ErrorCodes_MISSING_KEY)));
+}
+
+/// Check the X-Wing draft test vector: key generation from the seed,
+/// encapsulation with the eseed, and decapsulation.
+#[test]
+#[cfg(all(sha3, shake256))]
+fn test_xwing_kat() {
+    let wolfcrypt = common::setup();
+    let mut key = XWing::generate_from_seed(&hex(XWING_SEED), &wolfcrypt)
+        .expect("Error with generate_from_seed()");
+    let mut pk = [0u8; XWing::PUBLIC_KEY_SIZE];
+    key.encode_public_key(&mut pk).expect("Error with encode_public_key()");
+    assert_eq!(pk[..], hex(XWING_PK)[..]);
+
+    let mut bob = XWing::from_public_key(&pk, &wolfcrypt).expect("Error with from_public_key()");
+    let mut ct = [0u8; XWing::CIPHER_TEXT_SIZE];
+    let mut ss = [0u8; XWing::SHARED_SECRET_SIZE];
+    bob.encapsulate_with_random(&mut ct, &mut ss, &hex(XWING_ESEED))
+        .expect("Error with encapsulate_with_random()");
+    assert_eq!(ct[..], hex(XWING_CT)[..]);
+    assert_eq!(ss[..], hex(XWING_SS)[..]);
+
+    let mut ss2 = [0u8; XWing::SHARED_SECRET_SIZE];
+    key.decapsulate(&mut ss2, &ct).expect("Error with decapsulate()");
+    assert_eq!(ss2, ss);
+}
+
+/// Recompute X-Wing key derivation, encapsulation and the combiner from the
+/// component wrappers and compare.
+#[test]
+#[cfg(all(sha3, shake256))]
+fn test_xwing_derand_matches_components() {
+    let wolfcrypt = common::setup();
+    let seed = [0x5au8; XWing::SEED_SIZE];
+    let mut eseed = [0u8; XWing::ENC_RAND_SIZE];
+    for (i, b) in eseed.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+
+    let mut key = XWing::generate_from_seed(&seed, &wolfcrypt).expect("Error with generate_from_seed()");
+    let mut exported = [0u8; XWing::SEED_SIZE];
+    key.export_seed(&mut exported).expect("Error with export_seed()");
+    assert_eq!(exported, seed);
+    let mut pk = [0u8; XWing::PUBLIC_KEY_SIZE];
+    key.encode_public_key(&mut pk).expect("Error with encode_public_key()");
+    let mut ct = [0u8; XWing::CIPHER_TEXT_SIZE];
+    let mut ss = [0u8; XWing::SHARED_SECRET_SIZE];
+    key.encapsulate_with_random(&mut ct, &mut ss, &eseed)
+        .expect("Error with encapsulate_with_random()");
+
+    /* Key derivation */
+    let mut expanded = [0u8; 96];
+    let mut shake = SHAKE256::new(&wolfcrypt).expect("Error with SHAKE256::new()");
+    shake.update(&seed).expect("Error with update()");
+    shake.finalize(&mut expanded).expect("Error with finalize()");
+    let mut mlkem = MlKem::generate_with_random(MlKem::TYPE_768, &expanded[..64], &wolfcrypt)
+        .expect("Error with generate_with_random()");
+    let mut pk_m = [0u8; 1184];
+    mlkem.encode_public_key(&mut pk_m).expect("Error with encode_public_key()");
+    assert_eq!(pk[..1184], pk_m);
+    let sk_x = clamp(expanded[64..].try_into().unwrap());
+    let mut pk_x = [0u8; 32];
+    Curve25519Key::make_pub(&sk_x, &mut pk_x).expect("Error with make_pub()");
+    assert_eq!(pk[1184..], pk_x);
+
+    /* Encapsulation */
+    let mut ct_m = [0u8; 1088];
+    let mut ss_m = [0u8; 32];
+    mlkem.encapsulate_with_random(&mut ct_m, &mut ss_m, &eseed[..32])
+        .expect("Error with encapsulate_with_random()");
+    assert_eq!(ct[..1088], ct_m);
+    let ek_x = clamp(eseed[32..].try_into().unwrap());
+    let mut ct_x = [0u8; 32];
+    Curve25519Key::make_pub(&ek_x, &mut ct_x).expect("Error with make_pub()");
+    assert_eq!(ct[1088..], ct_x);
+    let mut ss_x = [0u8; 32];
+    Curve25519Key::make_pub_generic(&ek_x, &mut ss_x, &pk_x)
+        .expect("Error with make_pub_generic()");
+
+    /* Combiner */
+    let mut expected = [0u8; 32];
+    let mut sha3 = SHA3_256::new(&wolfcrypt).expect("Error with SHA3_256::new()");
+    sha3.update(&ss_m).expect("Error with update()");
+    sha3.update(&ss_x).expect("Error with update()");
+    sha3.update(&ct_x).expect("Error with update()");
+    sha3.update(&pk_x).expect("Error with update()");
+    sha3.update(&[0x5c, 0x2e, 0x2f, 0x2f, 0x5e, 0x5c]).expect("Error with update()");
+    sha3.finalize(&mut expected).expect("Error with finalize()");
+    assert_eq!(ss, expected);
+
+    let mut ss2 = [0u8; XWing::SHARED_SECRET_SIZE];
+    key.decapsulate(&mut ss2, &ct).expect("Error with decapsulate()");
+    assert_eq!(ss2, ss);
+
+    /* A modified ML-KEM cipher text gives an implicit rejection secret. */
+    ct[0] ^= 1;
+    key.decapsulate(&mut ss2, &ct).expect("Error with decapsulate()");
+    assert_ne!(ss2, ss);
+}
+
+#[test]
+#[cfg(all(sha3, shake256))]
+fn test_xwing_bad_lengths() {
+    let wolfcrypt = common::setup();
+    assert!(XWing::generate_from_seed(&[0u8; 31], &wolfcrypt).is_err());
+    assert!(XWing::from_public_key(&[0u8; XWing::PUBLIC_KEY_SIZE - 1], &wolfcrypt).is_err());
+    let mut key = XWing::generate_from_seed(&[1u8; 32], &wolfcrypt).expect("Error with generate_from_seed()");
+    let mut ct = [0u8; XWing::CIPHER_TEXT_SIZE];
+    let mut ss = [0u8; XWing::SHARED_SECRET_SIZE];
+    assert!(key.encapsulate_with_random(&mut ct, &mut ss, &[0u8; 63]).is_err());
+    assert!(key.encapsulate_with_random(&mut ct[..1119], &mut ss, &[0u8; 64]).is_err());
+    assert!(key.decapsulate(&mut ss[..31], &ct).is_err());
+}
+
+#[test]
+#[cfg(random)]
+fn test_x25519_mlkem768_round_trip() {
+    let wolfcrypt = common::setup();
+    let rng = RNG::new(&wolfcrypt).expect("Error with RNG::new()");
+    let mut client = X25519MlKem768::generate(&rng, &wolfcrypt).expect("Error with generate()");
+    let mut share = [0u8; X25519MlKem768::PUBLIC_KEY_SIZE];
+    client.encode_public_key(&mut share).expect("Error with encode_public_key()");
+
+    let mut server = X25519MlKem768::from_public_key(&share, &wolfcrypt)
+        .expect("Error with from_public_key()");
+    let mut ct = [0u8; X25519MlKem768::CIPHER_TEXT_SIZE];
+    let mut ss_server = [0u8; X25519MlKem768::SHARED_SECRET_SIZE];
+    server.encapsulate(&mut ct, &mut ss_server, &rng).expect("Error with encapsulate()");
+
+    let mut ss_client = [0u8; X25519MlKem768::SHARED_SECRET_SIZE];
+    client.decapsulate(&mut ss_client, &ct).expect("Error with decapsulate()");
+    assert_eq!(ss_client, ss_server);
+}
+
+/// Check the X25519MLKEM768 vector: key generation from the seed,
+/// encapsulation with fixed randomness, and decapsulation.
+#[test]
+fn test_x25519_mlkem768_kat() {
+    let wolfcrypt = common::setup();
+    let mut key = X25519MlKem768::generate_from_seed(&hex(X25519_MLKEM768_SEED), &wolfcrypt)
+        .expect("Error with generate_from_seed()");
+    let mut pk = [0u8; X25519MlKem768::PUBLIC_KEY_SIZE];
+    key.encode_public_key(&mut pk).expect("Error with encode_public_key()");
+    assert_eq!(pk[..], hex(X25519_MLKEM768_PK)[..]);
+
+    let mut server = X25519MlKem768::from_public_key(&pk, &wolfcrypt)
+        .expect("Error with from_public_key()");
+    let mut ct = [0u8; X25519MlKem768::CIPHER_TEXT_SIZE];
+    let mut ss = [0u8; X25519MlKem768::SHARED_SECRET_SIZE];
+    server.encapsulate_with_random(&mut ct, &mut ss, &hex(X25519_MLKEM768_RAND))
+        .expect("Error with encapsulate_with_random()");
+    assert_eq!(ct[..], hex(X25519_MLKEM768_CT)[..]);
+    assert_eq!(ss[..], hex(X25519_MLKEM768_SS)[..]);
+
+    let mut ss2 = [0u8; X25519MlKem768::SHARED_SECRET_SIZE];
+    key.decapsulate(&mut ss2, &ct).expect("Error with decapsulate()");
+    assert_eq!(ss2, ss);
+}
+
+/// The TLS combiner is the concatenation of the ML-KEM-768 and X25519 shared
+/// secrets.
+#[test]
+fn test_x25519_mlkem768_matches_components() {
+    let wolfcrypt = common::setup();
+    let mut seed = [0u8; X25519MlKem768::SEED_SIZE];
+    for (i, b) in seed.iter_mut().enumerate() {
+        *b = (i * 7) as u8;
+    }
+    let rand = [0xa5u8; X25519MlKem768::ENC_RAND_SIZE];
+
+    let mut key = X25519MlKem768::generate_from_seed(&seed, &wolfcrypt)
+        .expect("Error with generate_from_seed()");
+    let mut exported = [0u8; X25519MlKem768::SEED_SIZE];
+    key.export_seed(&mut exported).expect("Error with export_seed()");
+    assert_eq!(exported, seed);
+    let mut pk = [0u8; X25519MlKem768::PUBLIC_KEY_SIZE];
+    key.encode_public_key(&mut pk).expect("Error with encode_public_key()");
+    let mut ct = [0u8; X25519MlKem768::CIPHER_TEXT_SIZE];
+    let mut ss = [0u8; X25519MlKem768::SHARED_SECRET_SIZE];
+    key.encapsulate_with_random(&mut ct, &mut ss, &rand)
+        .expect("Error with encapsulate_with_random()");
+
+    let mut mlkem = MlKem::generate_with_random(MlKem::TYPE_768, &seed[..64], &wolfcrypt)
+        .expect("Error with generate_with_random()");
+    let mut pk_m = [0u8; 1184];
+    mlkem.encode_public_key(&mut pk_m).expect("Error with encode_public_key()");
+    assert_eq!(pk[..1184], pk_m);
+    let mut ct_m = [0u8; 1088];
+    let mut ss_m = [0u8; 32];
+    mlkem.encapsulate_with_random(&mut ct_m, &mut ss_m, &rand[..32])
+        .expect("Error with encapsulate_with_random()");
+    assert_eq!(ct[..1088], ct_m);
+    assert_eq!(ss[..32], ss_m);
+
+    /* X25519 part, computed with the Curve25519Key API */
+    let sk_x = clamp(seed[64..].try_into().unwrap());
+    let ek_x = clamp(rand[32..].try_into().unwrap());
+    let mut priv_key = Curve25519Key::import_private_raw_ex(&sk_x, &pk[1184..], false, &wolfcrypt)
+        .expect("Error with import_private_raw_ex()");
+    let mut pub_key = Curve25519Key::import_public_ex(&ct[1088..], false, &wolfcrypt)
+        .expect("Error with import_public_ex()");
+    let mut ss_x = [0u8; 32];
+    Curve25519Key::shared_secret_ex(&mut priv_key, &mut pub_key, &mut ss_x, false)
+        .expect("Error with shared_secret_ex()");
+    assert_eq!(ss[32..], ss_x);
+    let mut ct_x = [0u8; 32];
+    Curve25519Key::make_pub(&ek_x, &mut ct_x).expect("Error with make_pub()");
+    assert_eq!(ct[1088..], ct_x);
+
+    let mut ss2 = [0u8; X25519MlKem768::SHARED_SECRET_SIZE];
+    key.decapsulate(&mut ss2, &ct).expect("Error with decapsulate()");
+    assert_eq!(ss2, ss);
+}
+
+/// A low-order X25519 point in the cipher text gives an all-zero X25519
+/// shared secret, which the TLS combiner rejects.
+#[test]
+fn test_x25519_mlkem768_zero_shared_secret() {
+    let wolfcrypt = common::setup();
+    let mut key = X25519MlKem768::generate_from_seed(&[3u8; X25519MlKem768::SEED_SIZE], &wolfcrypt)
+        .expect("Error with generate_from_seed()");
+    let mut ct = [0u8; X25519MlKem768::CIPHER_TEXT_SIZE];
+    let mut ss = [0u8; X25519MlKem768::SHARED_SECRET_SIZE];
+    key.encapsulate_with_random(&mut ct, &mut ss, &[4u8; X25519MlKem768::ENC_RAND_SIZE])
+        .expect("Error with encapsulate_with_random()");
+    ct[1088..].fill(0);
+    assert_eq!(key.decapsulate(&mut ss, &ct),
+        Err(Error::from(sys::wolfCrypt_ErrorCodes_ECC_OUT_OF_RANGE_E)));
+}
@@ -0,0 +1,72 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+#![cfg(all(curve25519, mlkem, sha3, shake256, random, feature = "kem", feature = "rand_core"))]
+
+mod common;
+
+use kem::{Decapsulate, Decapsulator, Encapsulate, Kem, TryKeyInit, KeyExport};
+use kem::Generate;
+use wolfssl_wolfcrypt::hybrid::XWing;
+use wolfssl_wolfcrypt::hybrid_kem::*;
+use wolfssl_wolfcrypt::random::RNG;
+
+/// Generate, encapsulate, and decapsulate with X-Wing via the kem traits.
+#[test]
+fn test_kem_xwing_round_trip() {
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("RNG creation failed");
+
+    let (dk, ek) = XWing768::generate_keypair_from_rng(&mut rng);
+    let (ct, k_send) = ek.encapsulate_with_rng(&mut rng);
+    let k_recv = dk.decapsulate(&ct);
+    assert_eq!(k_send, k_recv);
+    assert_eq!(ct.len(), XWing::CIPHER_TEXT_SIZE);
+    assert_eq!(k_send.len(), XWing::SHARED_SECRET_SIZE);
+}
+
+/// Round trip the encapsulation key through its byte encoding and
+/// encapsulate to the reimported key.
+#[test]
+fn test_kem_xwing_ek_export_import() {
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("RNG creation failed");
+
+    let (dk, ek) = XWing768::generate_keypair_from_rng(&mut rng);
+    let bytes = ek.to_bytes();
+    assert_eq!(bytes.len(), XWing::PUBLIC_KEY_SIZE);
+    let ek2 = XWing768EncapsulationKey::new(&bytes).expect("TryKeyInit failed");
+    assert_eq!(&ek2, dk.encapsulation_key());
+
+    let (ct, k_send) = ek2.encapsulate_with_rng(&mut rng);
+    assert_eq!(k_send, dk.decapsulate(&ct));
+}
+
+/// A modified cipher text decapsulates to a different shared secret.
+#[test]
+fn test_kem_xwing_implicit_rejection() {
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("RNG creation failed");
+
+    let (dk, ek) = XWing768::generate_keypair_from_rng(&mut rng);
+    let (mut ct, k_send) = ek.encapsulate_with_rng(&mut rng);
+    ct[0] ^= 1;
+    assert_ne!(k_send, dk.decapsulate(&ct));
+}
@@ -2,6 +2,8 @@
 
 mod common;
 
+#[cfg(any(all(hmac, kdf_pbkdf2), all(hmac, kdf_tls13)))]
+use wolfssl_wolfcrypt::Env;
 #[cfg(all(hmac, any(kdf_pbkdf2, kdf_tls13)))]
 use wolfssl_wolfcrypt::hmac::HMAC;
 use wolfssl_wolfcrypt::kdf::*;
@@ -11,7 +13,7 @@ use wolfssl_wolfcrypt::sha::SHA256;
 #[test]
 #[cfg(all(hmac, kdf_pbkdf2))]
 fn test_pbkdf2() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
     let password = b"passwordpassword";
     let salt = [0x78u8, 0x57, 0x8E, 0x5a, 0x5d, 0x63, 0xcb, 0x06];
@@ -22,17 +24,18 @@ fn test_pbkdf2() {
     ];
 
     let mut keyout = [0u8; 24];
-    pbkdf2(password, &salt, iterations, HMAC::TYPE_SHA256, &mut keyout).expect("Error with pbkdf2()");
+    pbkdf2(password, &salt, iterations, HMAC::TYPE_SHA256, &wolfcrypt, &mut keyout).expect("Error with pbkdf2()");
     assert_eq!(keyout, expected_key);
 
     let mut keyout = [0u8; 24];
-    pbkdf2_ex(password, &salt, iterations, HMAC::TYPE_SHA256, None, None, &mut keyout).expect("Error with pbkdf2_ex()");
+    pbkdf2_ex(password, &salt, iterations, HMAC::TYPE_SHA256, &Env::new(&wolfcrypt), &mut keyout).expect("Error with pbkdf2_ex()");
     assert_eq!(keyout, expected_key);
 }
 
 #[test]
 #[cfg(all(hmac, kdf_pbkdf2))]
 fn test_pkcs12_pbkdf() {
+    let wolfcrypt = common::setup();
     let password = [0x00u8, 0x73, 0x00, 0x6d, 0x00, 0x65, 0x00, 0x67, 0x00, 0x00];
     let salt = [0x0au8, 0x58, 0xCF, 0x64, 0x53, 0x0d, 0x82, 0x3f];
     let expected_key = [
@@ -43,18 +46,18 @@ fn test_pkcs12_pbkdf() {
     let iterations = 1;
 
     let mut keyout = [0u8; 24];
-    pkcs12_pbkdf(&password, &salt, iterations, HMAC::TYPE_SHA256, 1, &mut keyout).expect("Error with pkcs12_pbkdf()");
+    pkcs12_pbkdf(&password, &salt, iterations, HMAC::TYPE_SHA256, 1, &wolfcrypt, &mut keyout).expect("Error with pkcs12_pbkdf()");
     assert_eq!(keyout, expected_key);
 
     let mut keyout = [0u8; 24];
-    pkcs12_pbkdf_ex(&password, &salt, iterations, HMAC::TYPE_SHA256, 1, None, &mut keyout).expect("Error with pkcs12_pbkdf_ex()");
+    pkcs12_pbkdf_ex(&password, &salt, iterations, HMAC::TYPE_SHA256, 1, &Env::new(&wolfcrypt), &mut keyout).expect("Error with pkcs12_pbkdf_ex()");
     assert_eq!(keyout, expected_key);
 }
 
 #[test]
 #[cfg(all(hmac, kdf_tls13))]
 fn test_tls13_hkdf_extract_expand() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
     let hash_hello1 = [
         0x63u8, 0x83, 0x58, 0xab, 0x36, 0xcd, 0x0c, 0xf3,
@@ -70,8 +73,8 @@ fn test_tls13_hkdf_extract_expand() {
 
     let mut secret = [0u8; SHA256::DIGEST_SIZE];
 
-    tls13_hkdf_extract(HMAC::TYPE_SHA256, None, None, &mut secret).expect("Error with tls13_hkdf_extract()");
-    tls13_hkdf_extract_ex(HMAC::TYPE_SHA256, None, None, &mut secret, None, None).expect("Error with tls13_hkdf_extract_ex()");
+    tls13_hkdf_extract(HMAC::TYPE_SHA256, None, None, &mut secret, &wolfcrypt).expect("Error with tls13_hkdf_extract()");
+    tls13_hkdf_extract_ex(HMAC::TYPE_SHA256, None, None, &mut secret, &Env::new(&wolfcrypt)).expect("Error with tls13_hkdf_extract_ex()");
 
     let protocol_label = b"tls13 ";
     let ce_traffic_label = b"c e traffic";
@@ -79,10 +82,10 @@ fn test_tls13_hkdf_extract_expand() {
 
     tls13_hkdf_expand_label(HMAC::TYPE_SHA256, &secret,
         protocol_label, ce_traffic_label,
-        &hash_hello1, &mut expand_out).expect("Error with tls13_hkdf_expand_label()");
+        &hash_hello1, &mut expand_out, &wolfcrypt).expect("Error with tls13_hkdf_expand_label()");
     tls13_hkdf_expand_label_ex(HMAC::TYPE_SHA256, &secret,
         protocol_label, ce_traffic_label,
-        &hash_hello1, &mut expand_out, None, None).expect("Error with tls13_hkdf_expand_label_ex()");
+        &hash_hello1, &mut expand_out, &Env::new(&wolfcrypt)).expect("Error with tls13_hkdf_expand_label_ex()");
 
     assert_eq!(expand_out, client_early_traffic_secret);
 }
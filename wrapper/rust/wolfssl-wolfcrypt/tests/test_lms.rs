@@ -22,7 +22,13 @@
 
 mod common;
 
+use wolfssl_wolfcrypt::{Env, WolfCrypt};
 use wolfssl_wolfcrypt::lms::Lms;
+#[cfg(all(lms_make_key, feature = "alloc"))]
+use wolfssl_wolfcrypt::lms::{LmsStateStore, MemoryStateStore};
+#[cfg(all(lms_make_key, feature = "std"))]
+use wolfssl_wolfcrypt::lms::FileStateStore;
+use wolfssl_wolfcrypt::Error;
 use wolfssl_wolfcrypt::sys;
 #[cfg(all(lms_make_key, random))]
 use wolfssl_wolfcrypt::random::RNG;
@@ -85,22 +91,22 @@ fn test_key_id_len_constant() {
 /// Verify that `new()` succeeds.
 #[test]
 fn test_new() {
-    common::setup();
-    Lms::new().expect("Error with Lms::new()");
+    let wolfcrypt = common::setup();
+    Lms::new(&wolfcrypt).expect("Error with Lms::new()");
 }
 
 /// Verify that `new_ex()` accepts the optional heap and device ID parameters.
 #[test]
 fn test_new_ex() {
-    common::setup();
-    Lms::new_ex(None, None).expect("Error with Lms::new_ex()");
+    let wolfcrypt = common::setup();
+    Lms::new_ex(&Env::new(&wolfcrypt)).expect("Error with Lms::new_ex()");
 }
 
 /// Verify that `set_parm()` accepts a predefined wc_LmsParm value.
 #[test]
 fn test_set_parm() {
-    common::setup();
-    let mut key = Lms::new().expect("Error with Lms::new()");
+    let wolfcrypt = common::setup();
+    let mut key = Lms::new(&wolfcrypt).expect("Error with Lms::new()");
     key.set_parm(Lms::PARM_L1_H5_W8).expect("Error with set_parm()");
 }
 
@@ -108,12 +114,12 @@ fn test_set_parm() {
 /// `get_parameters()` returns them unchanged.
 #[test]
 fn test_set_get_parameters() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
     for &(levels, height, winternitz) in &[(1, 5, 8), (1, 10, 4), (2, 5, 8)] {
         // Use a fresh key for each parameter set; wc_LmsKey_SetParameters does
         // not allow re-setting parameters on an already-configured key.
-        let mut k = Lms::new().expect("Error with Lms::new()");
+        let mut k = Lms::new(&wolfcrypt).expect("Error with Lms::new()");
         k.set_parameters(levels, height, winternitz)
             .expect("Error with set_parameters()");
         let (l, h, w) = k.get_parameters().expect("Error with get_parameters()");
@@ -127,13 +133,13 @@ fn test_set_get_parameters() {
 /// after setting a predefined parameter set.
 #[test]
 fn test_size_queries_after_set_parm() {
-    common::setup();
+    let wolfcrypt = common::setup();
     for &parm in &[
         Lms::PARM_L1_H5_W8,
         Lms::PARM_L1_H5_W4,
         Lms::PARM_L1_H10_W8,
     ] {
-        let mut key = Lms::new().expect("Error with Lms::new()");
+        let mut key = Lms::new(&wolfcrypt).expect("Error with Lms::new()");
         key.set_parm(parm).expect("Error with set_parm()");
         let sig_len = key.get_sig_len().expect("Error with get_sig_len()");
         let pub_len = key.get_pub_len().expect("Error with get_pub_len()");
@@ -146,12 +152,12 @@ fn test_size_queries_after_set_parm() {
 /// level count increases signature size significantly).
 #[test]
 fn test_sig_len_increases_with_levels() {
-    common::setup();
-    let mut key1 = Lms::new().expect("Error with Lms::new()");
+    let wolfcrypt = common::setup();
+    let mut key1 = Lms::new(&wolfcrypt).expect("Error with Lms::new()");
     key1.set_parm(Lms::PARM_L1_H5_W8).expect("Error with set_parm()");
     let sig_len_l1 = key1.get_sig_len().expect("Error with get_sig_len() L1");
 
-    let mut key2 = Lms::new().expect("Error with Lms::new()");
+    let mut key2 = Lms::new(&wolfcrypt).expect("Error with Lms::new()");
     key2.set_parm(Lms::PARM_L2_H5_W8).expect("Error with set_parm()");
     let sig_len_l2 = key2.get_sig_len().expect("Error with get_sig_len() L2");
 
@@ -172,8 +178,8 @@ fn test_sig_len_increases_with_levels() {
 #[test]
 #[cfg(lms_make_key)]
 fn test_get_priv_len() {
-    common::setup();
-    let mut key = Lms::new().expect("Error with Lms::new()");
+    let wolfcrypt = common::setup();
+    let mut key = Lms::new(&wolfcrypt).expect("Error with Lms::new()");
     key.set_parm(Lms::PARM_L1_H5_W8).expect("Error with set_parm()");
     let priv_len = key.get_priv_len().expect("Error with get_priv_len()");
     assert!(priv_len > 0, "priv_len must be positive");
@@ -187,12 +193,12 @@ fn test_get_priv_len() {
 #[test]
 #[cfg(all(lms_make_key, random))]
 fn test_make_key() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error creating RNG");
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
     let mut store = Box::new(KeyStore { buf: [0u8; 16384] });
     let ctx = store.as_mut() as *mut KeyStore as *mut core::ffi::c_void;
 
-    let mut key = Lms::new().expect("Error with Lms::new()");
+    let mut key = Lms::new(&wolfcrypt).expect("Error with Lms::new()");
     key.set_parm(Lms::PARM_L1_H5_W8).expect("Error with set_parm()");
     setup_callbacks(&mut key, ctx);
     key.make_key(&mut rng).expect("Error with make_key()");
@@ -204,12 +210,12 @@ fn test_make_key() {
 #[test]
 #[cfg(all(lms_make_key, random))]
 fn test_sign_verify() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error creating RNG");
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
     let mut store = Box::new(KeyStore { buf: [0u8; 16384] });
     let ctx = store.as_mut() as *mut KeyStore as *mut core::ffi::c_void;
 
-    let mut key = Lms::new().expect("Error with Lms::new()");
+    let mut key = Lms::new(&wolfcrypt).expect("Error with Lms::new()");
     key.set_parm(Lms::PARM_L1_H5_W8).expect("Error with set_parm()");
     setup_callbacks(&mut key, ctx);
     key.make_key(&mut rng).expect("Error with make_key()");
@@ -230,12 +236,12 @@ fn test_sign_verify() {
 #[test]
 #[cfg(all(lms_make_key, random))]
 fn test_sign_tampered_message() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error creating RNG");
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
     let mut store = Box::new(KeyStore { buf: [0u8; 16384] });
     let ctx = store.as_mut() as *mut KeyStore as *mut core::ffi::c_void;
 
-    let mut key = Lms::new().expect("Error with Lms::new()");
+    let mut key = Lms::new(&wolfcrypt).expect("Error with Lms::new()");
     key.set_parm(Lms::PARM_L1_H5_W8).expect("Error with set_parm()");
     setup_callbacks(&mut key, ctx);
     key.make_key(&mut rng).expect("Error with make_key()");
@@ -255,12 +261,12 @@ fn test_sign_tampered_message() {
 #[test]
 #[cfg(all(lms_make_key, random))]
 fn test_sign_tampered_signature() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error creating RNG");
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
     let mut store = Box::new(KeyStore { buf: [0u8; 16384] });
     let ctx = store.as_mut() as *mut KeyStore as *mut core::ffi::c_void;
 
-    let mut key = Lms::new().expect("Error with Lms::new()");
+    let mut key = Lms::new(&wolfcrypt).expect("Error with Lms::new()");
     key.set_parm(Lms::PARM_L1_H5_W8).expect("Error with set_parm()");
     setup_callbacks(&mut key, ctx);
     key.make_key(&mut rng).expect("Error with make_key()");
@@ -284,12 +290,12 @@ fn test_sign_tampered_signature() {
 #[test]
 #[cfg(all(lms_make_key, random))]
 fn test_export_pub_raw_import_verify() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error creating RNG");
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
     let mut store = Box::new(KeyStore { buf: [0u8; 16384] });
     let ctx = store.as_mut() as *mut KeyStore as *mut core::ffi::c_void;
 
-    let mut sign_key = Lms::new().expect("Error with Lms::new()");
+    let mut sign_key = Lms::new(&wolfcrypt).expect("Error with Lms::new()");
     sign_key.set_parm(Lms::PARM_L1_H5_W8).expect("Error with set_parm()");
     setup_callbacks(&mut sign_key, ctx);
     sign_key.make_key(&mut rng).expect("Error with make_key()");
@@ -308,7 +314,7 @@ fn test_export_pub_raw_import_verify() {
 
     // Import the raw public key into a new key and verify.
     // wc_LmsKey_ImportPubRaw requires params to be set first.
-    let mut verify_key = Lms::new().expect("Error with Lms::new() for verify");
+    let mut verify_key = Lms::new(&wolfcrypt).expect("Error with Lms::new() for verify");
     verify_key.set_parm(Lms::PARM_L1_H5_W8).expect("Error with set_parm() for verify key");
     verify_key.import_pub_raw(&pub_buf)
         .expect("Error with import_pub_raw()");
@@ -323,12 +329,12 @@ fn test_export_pub_raw_import_verify() {
 #[test]
 #[cfg(all(lms_make_key, random))]
 fn test_export_pub_from() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error creating RNG");
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
     let mut store = Box::new(KeyStore { buf: [0u8; 16384] });
     let ctx = store.as_mut() as *mut KeyStore as *mut core::ffi::c_void;
 
-    let mut sign_key = Lms::new().expect("Error with Lms::new()");
+    let mut sign_key = Lms::new(&wolfcrypt).expect("Error with Lms::new()");
     sign_key.set_parm(Lms::PARM_L1_H5_W8).expect("Error with set_parm()");
     setup_callbacks(&mut sign_key, ctx);
     sign_key.make_key(&mut rng).expect("Error with make_key()");
@@ -339,7 +345,7 @@ fn test_export_pub_from() {
     sign_key.sign(message, &mut sig).expect("Error with sign()");
 
     // Copy the public portion into a fresh key.
-    let mut verify_key = Lms::new().expect("Error with Lms::new() for verify");
+    let mut verify_key = Lms::new(&wolfcrypt).expect("Error with Lms::new() for verify");
     verify_key.set_parm(Lms::PARM_L1_H5_W8).expect("Error with set_parm() for verify key");
     verify_key.export_pub_from(&sign_key)
         .expect("Error with export_pub_from()");
@@ -355,12 +361,12 @@ fn test_export_pub_from() {
 #[test]
 #[cfg(all(lms_make_key, random))]
 fn test_has_sigs_left_after_make_key() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error creating RNG");
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
     let mut store = Box::new(KeyStore { buf: [0u8; 16384] });
     let ctx = store.as_mut() as *mut KeyStore as *mut core::ffi::c_void;
 
-    let mut key = Lms::new().expect("Error with Lms::new()");
+    let mut key = Lms::new(&wolfcrypt).expect("Error with Lms::new()");
     key.set_parm(Lms::PARM_L1_H5_W8).expect("Error with set_parm()");
     setup_callbacks(&mut key, ctx);
     key.make_key(&mut rng).expect("Error with make_key()");
@@ -376,12 +382,12 @@ fn test_has_sigs_left_after_make_key() {
 #[test]
 #[cfg(all(lms_make_key, random))]
 fn test_get_kid() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error creating RNG");
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
     let mut store = Box::new(KeyStore { buf: [0u8; 16384] });
     let ctx = store.as_mut() as *mut KeyStore as *mut core::ffi::c_void;
 
-    let mut key = Lms::new().expect("Error with Lms::new()");
+    let mut key = Lms::new(&wolfcrypt).expect("Error with Lms::new()");
     key.set_parm(Lms::PARM_L1_H5_W8).expect("Error with set_parm()");
     setup_callbacks(&mut key, ctx);
     key.make_key(&mut rng).expect("Error with make_key()");
@@ -399,13 +405,13 @@ fn test_get_kid() {
 #[test]
 #[cfg(all(lms_make_key, random))]
 fn test_reload() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error creating RNG");
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
     let mut store = Box::new(KeyStore { buf: [0u8; 16384] });
     let ctx = store.as_mut() as *mut KeyStore as *mut core::ffi::c_void;
 
     // Generate a key pair and export the public key.
-    let mut key = Lms::new().expect("Error with Lms::new()");
+    let mut key = Lms::new(&wolfcrypt).expect("Error with Lms::new()");
     key.set_parm(Lms::PARM_L1_H5_W8).expect("Error with set_parm()");
     setup_callbacks(&mut key, ctx);
     key.make_key(&mut rng).expect("Error with make_key()");
@@ -415,7 +421,7 @@ fn test_reload() {
     key.export_pub_raw(&mut pub_buf).expect("Error with export_pub_raw()");
 
     // Create a new key, reload from NV storage (written by make_key above).
-    let mut reloaded = Lms::new().expect("Error with Lms::new() for reload");
+    let mut reloaded = Lms::new(&wolfcrypt).expect("Error with Lms::new() for reload");
     reloaded.set_parm(Lms::PARM_L1_H5_W8).expect("Error with set_parm() for reload");
     setup_callbacks(&mut reloaded, ctx);
     reloaded.reload().expect("Error with reload()");
@@ -426,7 +432,7 @@ fn test_reload() {
     let mut sig = vec![0u8; sig_len];
     reloaded.sign(message, &mut sig).expect("Error with sign() after reload");
 
-    let mut verify_key = Lms::new().expect("Error with Lms::new() for verify");
+    let mut verify_key = Lms::new(&wolfcrypt).expect("Error with Lms::new() for verify");
     // wc_LmsKey_ImportPubRaw requires params to be set first.
     verify_key.set_parm(Lms::PARM_L1_H5_W8).expect("Error with set_parm() for verify key");
     verify_key.import_pub_raw(&pub_buf).expect("Error with import_pub_raw()");
@@ -441,8 +447,8 @@ fn test_reload() {
 #[test]
 #[cfg(all(lms_make_key, random))]
 fn test_sign_verify_multiple_parms() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error creating RNG");
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
 
     for &parm in &[
         Lms::PARM_L1_H5_W8,
@@ -452,7 +458,7 @@ fn test_sign_verify_multiple_parms() {
         let mut store = Box::new(KeyStore { buf: [0u8; 16384] });
         let ctx = store.as_mut() as *mut KeyStore as *mut core::ffi::c_void;
 
-        let mut key = Lms::new().expect("Error with Lms::new()");
+        let mut key = Lms::new(&wolfcrypt).expect("Error with Lms::new()");
         key.set_parm(parm).expect("Error with set_parm()");
         setup_callbacks(&mut key, ctx);
         key.make_key(&mut rng)
@@ -470,3 +476,158 @@ fn test_sign_verify_multiple_parms() {
         let _ = store;
     }
 }
+
+// ---------------------------------------------------------------------------
+// LmsStateStore tests
+// ---------------------------------------------------------------------------
+
+/// Generate a key using `store`, sign a message and return the exported
+/// public key and the signature.
+#[cfg(all(lms_make_key, random, feature = "alloc"))]
+fn make_key_and_sign<S: LmsStateStore + 'static>(store: S, message: &[u8], wolfcrypt: &WolfCrypt) -> (Vec<u8>, Vec<u8>) {
+    let rng = RNG::new(wolfcrypt).expect("Error creating RNG");
+    let mut key = Lms::new(wolfcrypt).expect("Error with Lms::new()");
+    key.set_parm(Lms::PARM_L1_H5_W8).expect("Error with set_parm()");
+    key.set_state_store(store).expect("Error with set_state_store()");
+    key.make_key(&rng).expect("Error with make_key()");
+
+    let mut pub_buf = vec![0u8; key.get_pub_len().expect("Error with get_pub_len()")];
+    key.export_pub_raw(&mut pub_buf).expect("Error with export_pub_raw()");
+    let mut sig = vec![0u8; key.get_sig_len().expect("Error with get_sig_len()")];
+    key.sign(message, &mut sig).expect("Error with sign()");
+    (pub_buf, sig)
+}
+
+/// Verify `sig` over `message` with the raw public key `pub_buf`.
+#[cfg(all(lms_make_key, random, feature = "alloc"))]
+fn verify_with(pub_buf: &[u8], sig: &[u8], message: &[u8], wolfcrypt: &WolfCrypt) {
+    let mut verify_key = Lms::new(wolfcrypt).expect("Error with Lms::new() for verify");
+    verify_key.set_parm(Lms::PARM_L1_H5_W8).expect("Error with set_parm() for verify key");
+    verify_key.import_pub_raw(pub_buf).expect("Error with import_pub_raw()");
+    verify_key.verify(sig, message).expect("Error with verify()");
+}
+
+/// Verify that a key signing through a `MemoryStateStore` advances the
+/// stored state and that a second key reloaded from a clone of the store
+/// continues from that state.
+#[test]
+#[cfg(all(lms_make_key, random, feature = "alloc"))]
+fn test_memory_state_store() {
+    let wolfcrypt = common::setup();
+    let store = MemoryStateStore::new();
+    assert!(store.is_empty());
+
+    let message = b"MemoryStateStore message";
+    let (pub_buf, sig) = make_key_and_sign(store.clone(), message, &wolfcrypt);
+    verify_with(&pub_buf, &sig, message, &wolfcrypt);
+    let state = store.state();
+    assert!(!state.is_empty());
+
+    let mut reloaded = Lms::new(&wolfcrypt).expect("Error with Lms::new() for reload");
+    reloaded.set_parm(Lms::PARM_L1_H5_W8).expect("Error with set_parm() for reload");
+    reloaded.set_state_store(store.clone()).expect("Error with set_state_store()");
+    reloaded.reload().expect("Error with reload()");
+    let mut sig2 = vec![0u8; sig.len()];
+    reloaded.sign(message, &mut sig2).expect("Error with sign() after reload");
+    verify_with(&pub_buf, &sig2, message, &wolfcrypt);
+
+    // The reloaded key must not reuse the one-time key of the first signature.
+    assert_ne!(sig, sig2);
+    assert_ne!(state, store.state());
+}
+
+/// Verify that a `MemoryStateStore` refuses to read a state of the wrong length.
+#[test]
+#[cfg(all(lms_make_key, feature = "alloc"))]
+fn test_memory_state_store_read_len() {
+    let mut store = MemoryStateStore::new();
+    let mut buf = [0u8; 4];
+    assert!(store.read(&mut buf).is_err());
+    store.write(&[1, 2, 3, 4]).expect("Error with write()");
+    store.read(&mut buf).expect("Error with read()");
+    assert_eq!(buf, [1, 2, 3, 4]);
+    assert!(store.read(&mut [0u8; 3]).is_err());
+}
+
+/// A store whose writes fail once `fail` is set.
+#[cfg(all(lms_make_key, feature = "alloc"))]
+struct FailingStateStore {
+    inner: MemoryStateStore,
+    fail: std::rc::Rc<core::cell::Cell<bool>>,
+}
+
+#[cfg(all(lms_make_key, feature = "alloc"))]
+impl LmsStateStore for FailingStateStore {
+    fn write(&mut self, state: &[u8]) -> Result<(), Error> {
+        if self.fail.get() {
+            return Err(Error::from(sys::wolfCrypt_ErrorCodes_IO_FAILED_E));
+        }
+        self.inner.write(state)
+    }
+
+    fn read(&mut self, state: &mut [u8]) -> Result<(), Error> {
+        self.inner.read(state)
+    }
+}
+
+/// Verify that `sign()` fails and releases no signature when the state
+/// store cannot persist the advanced state.
+#[test]
+#[cfg(all(lms_make_key, random, feature = "alloc"))]
+fn test_state_store_write_failure() {
+    let wolfcrypt = common::setup();
+    let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let fail = std::rc::Rc::new(core::cell::Cell::new(false));
+    let store = FailingStateStore { inner: MemoryStateStore::new(), fail: fail.clone() };
+
+    let mut key = Lms::new(&wolfcrypt).expect("Error with Lms::new()");
+    key.set_parm(Lms::PARM_L1_H5_W8).expect("Error with set_parm()");
+    key.set_state_store(store).expect("Error with set_state_store()");
+    key.make_key(&rng).expect("Error with make_key()");
+
+    fail.set(true);
+    let mut sig = vec![0u8; key.get_sig_len().expect("Error with get_sig_len()")];
+    assert!(key.sign(b"message", &mut sig).is_err());
+    assert!(sig.iter().all(|&b| b == 0), "signature released after failed write");
+}
+
+/// Verify that a key signing through a `FileStateStore` persists its state
+/// to the file, leaves no temporary file behind, and can be reloaded.
+#[test]
+#[cfg(all(lms_make_key, random, feature = "std"))]
+fn test_file_state_store() {
+    let wolfcrypt = common::setup();
+    let path = std::env::temp_dir().join(format!("wolfssl-lms-state-{}", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let message = b"FileStateStore message";
+    let (pub_buf, sig) = make_key_and_sign(FileStateStore::new(&path), message, &wolfcrypt);
+    verify_with(&pub_buf, &sig, message, &wolfcrypt);
+    let state = std::fs::read(&path).expect("Error reading state file");
+    let mut tmp_path = path.clone().into_os_string();
+    tmp_path.push(".tmp");
+    assert!(!std::path::Path::new(&tmp_path).exists());
+
+    let mut reloaded = Lms::new(&wolfcrypt).expect("Error with Lms::new() for reload");
+    reloaded.set_parm(Lms::PARM_L1_H5_W8).expect("Error with set_parm() for reload");
+    reloaded.set_state_store(FileStateStore::new(&path)).expect("Error with set_state_store()");
+    reloaded.reload().expect("Error with reload()");
+    let mut sig2 = vec![0u8; sig.len()];
+    reloaded.sign(message, &mut sig2).expect("Error with sign() after reload");
+    verify_with(&pub_buf, &sig2, message, &wolfcrypt);
+    assert_ne!(state, std::fs::read(&path).expect("Error reading state file"));
+
+    std::fs::remove_file(&path).expect("Error removing state file");
+}
+
+/// Verify that `FileStateStore` reports an error for a missing state file
+/// and a write to a nonexistent directory.
+#[test]
+#[cfg(all(lms_make_key, feature = "std"))]
+fn test_file_state_store_errors() {
+    let dir = std::env::temp_dir().join(format!("wolfssl-lms-missing-{}", std::process::id()));
+    let mut store = FileStateStore::new(dir.join("state"));
+    assert_eq!(store.path(), dir.join("state"));
+    assert!(store.read(&mut [0u8; 4]).is_err());
+    assert!(store.write(&[1, 2, 3, 4]).is_err());
+}
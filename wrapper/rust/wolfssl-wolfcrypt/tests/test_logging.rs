@@ -0,0 +1,98 @@
+mod common;
+
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use wolfssl_wolfcrypt::Error;
+use wolfssl_wolfcrypt::logging::{self, LogLevel};
+#[cfg(random)]
+use wolfssl_wolfcrypt::random::RNG;
+use wolfssl_wolfcrypt::sys;
+
+/* The logging callback and error queue are process wide state. */
+static LOGGING_LOCK: Mutex<()> = Mutex::new(());
+
+static MESSAGES: AtomicUsize = AtomicUsize::new(0);
+
+fn on_message(_level: LogLevel, message: &str) {
+    assert!(!message.contains('\0'));
+    MESSAGES.fetch_add(1, Ordering::Relaxed);
+}
+
+#[test]
+fn test_logging_callback() {
+    let wolfcrypt = common::setup();
+    let _guard = LOGGING_LOCK.lock().unwrap();
+
+    match logging::set_logging_callback(on_message) {
+        Ok(()) => {}
+        Err(e) if e == sys::wolfCrypt_ErrorCodes_NOT_COMPILED_IN => return,
+        Err(e) => panic!("Error with set_logging_callback(): {e}"),
+    }
+    logging::debugging_on().expect("Error with debugging_on()");
+    #[cfg(random)]
+    {
+        let _rng = RNG::new(&wolfcrypt).expect("Error with RNG::new()");
+    }
+    logging::debugging_off();
+    logging::clear_logging_callback().expect("Error with clear_logging_callback()");
+
+    /* Nothing is delivered once the callback has been cleared. */
+    let count = MESSAGES.load(Ordering::Relaxed);
+    assert_eq!(logging::debugging_on(), Ok(()));
+    logging::debugging_off();
+    assert_eq!(MESSAGES.load(Ordering::Relaxed), count);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_logging_closure() {
+    common::setup();
+    let _guard = LOGGING_LOCK.lock().unwrap();
+
+    let seen = std::sync::Arc::new(AtomicUsize::new(0));
+    let counter = seen.clone();
+    match logging::set_logging_closure(move |_level, _message| {
+        counter.fetch_add(1, Ordering::Relaxed);
+    }) {
+        Ok(()) => {}
+        Err(e) if e == sys::wolfCrypt_ErrorCodes_NOT_COMPILED_IN => return,
+        Err(e) => panic!("Error with set_logging_closure(): {e}"),
+    }
+    logging::clear_logging_callback().expect("Error with clear_logging_callback()");
+    /* Clearing the callback drops the stored closure. */
+    assert_eq!(std::sync::Arc::strong_count(&seen), 1);
+}
+
+#[test]
+fn test_debugging_on_not_compiled_in() {
+    common::setup();
+    let _guard = LOGGING_LOCK.lock().unwrap();
+
+    match logging::debugging_on() {
+        Ok(()) => logging::debugging_off(),
+        Err(e) => assert_eq!(e, Error::from(sys::wolfCrypt_ErrorCodes_NOT_COMPILED_IN)),
+    }
+}
+
+#[test]
+#[cfg(error_queue)]
+fn test_error_queue() {
+    common::setup();
+    let _guard = LOGGING_LOCK.lock().unwrap();
+
+    logging::clear_error_nodes();
+    assert!(logging::peek_error_node(0).is_none());
+    assert_eq!(logging::error_nodes().count(), 0);
+}
+
+#[test]
+#[cfg(debug_trace_error_codes)]
+fn test_trace_error_codes() {
+    common::setup();
+    let _guard = LOGGING_LOCK.lock().unwrap();
+
+    let previous = logging::set_trace_error_codes(false);
+    assert!(!logging::trace_error_codes_enabled());
+    logging::set_trace_error_codes(previous);
+    assert_eq!(logging::trace_error_codes_enabled(), previous);
+}
@@ -0,0 +1,64 @@
+mod common;
+
+#[cfg(sha256)]
+use wolfssl_wolfcrypt::Env;
+use wolfssl_wolfcrypt::memory::HeapHint;
+#[cfg(all(static_memory, sha256))]
+use wolfssl_wolfcrypt::memory::StaticHeap;
+#[cfg(sha256)]
+use wolfssl_wolfcrypt::sha::SHA256;
+
+#[cfg(sha256)]
+#[allow(dead_code)]
+fn sha256_abc(env: &Env) {
+    let expected = [
+        0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea, 0x41, 0x41, 0x40, 0xde,
+        0x5d, 0xae, 0x22, 0x23, 0xb0, 0x03, 0x61, 0xa3, 0x96, 0x17, 0x7a, 0x9c,
+        0xb4, 0x10, 0xff, 0x61, 0xf2, 0x00, 0x15, 0xad,
+    ];
+    let mut sha = SHA256::new_ex(env).expect("Error with new_ex()");
+    sha.update(b"abc").expect("Error with update()");
+    let mut hash = [0u8; SHA256::DIGEST_SIZE];
+    sha.finalize(&mut hash).expect("Error with finalize()");
+    assert_eq!(hash, expected);
+}
+
+#[test]
+fn test_heap_hint_from_raw() {
+    common::setup();
+
+    let mut value = 0u8;
+    let ptr = &mut value as *mut u8 as *mut core::ffi::c_void;
+    let hint = unsafe { HeapHint::from_raw(ptr) };
+    assert_eq!(hint.as_ptr(), ptr);
+    assert_eq!(hint, unsafe { HeapHint::from_raw(ptr) });
+}
+
+/* Only one test in this file may use wolfCrypt while the allocators are
+ * switched, since the test threads share the wolfCrypt allocator state. */
+#[test]
+#[cfg(all(feature = "alloc", memory_callbacks, not(static_memory)))]
+fn test_rust_allocator() {
+    unsafe {
+        wolfssl_wolfcrypt::memory::use_rust_allocator().expect("Error with use_rust_allocator()");
+    }
+    let wolfcrypt = common::setup();
+
+    #[cfg(sha256)]
+    sha256_abc(&Env::new(&wolfcrypt));
+}
+
+#[test]
+#[cfg(all(static_memory, sha256))]
+fn test_static_heap() {
+    let wolfcrypt = common::setup();
+
+    let mut buf = vec![0u8; 128 * 1024];
+    let heap = StaticHeap::new(&mut buf).expect("Error with StaticHeap::new()");
+    sha256_abc(&Env::new(&wolfcrypt).with_heap(heap.hint()));
+    drop(heap);
+
+    /* A buffer too small for the pool bookkeeping is rejected. */
+    let mut small = [0u8; 8];
+    assert!(StaticHeap::new(&mut small).is_err());
+}
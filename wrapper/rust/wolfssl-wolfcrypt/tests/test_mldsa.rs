@@ -22,6 +22,7 @@
 
 mod common;
 
+use wolfssl_wolfcrypt::Env;
 use wolfssl_wolfcrypt::mldsa::MlDsa;
 #[cfg(all(random, any(mldsa_make_key, mldsa_sign)))]
 use wolfssl_wolfcrypt::random::RNG;
@@ -38,9 +39,9 @@ fn test_level_constants() {
 /// Verify `new()` + `set_level()` + `get_level()` for all three parameter sets.
 #[test]
 fn test_new_and_level() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut key = MlDsa::new().expect("Error with new()");
+    let mut key = MlDsa::new(&wolfcrypt).expect("Error with new()");
 
     key.set_level(MlDsa::LEVEL_44).expect("Error with set_level()");
     assert_eq!(key.get_level().expect("Error with get_level()"), MlDsa::LEVEL_44);
@@ -55,8 +56,8 @@ fn test_new_and_level() {
 /// Verify that `new_ex()` accepts the optional heap and device ID parameters.
 #[test]
 fn test_new_ex() {
-    common::setup();
-    let mut key = MlDsa::new_ex(None, None).expect("Error with new_ex()");
+    let wolfcrypt = common::setup();
+    let mut key = MlDsa::new_ex(&Env::new(&wolfcrypt)).expect("Error with new_ex()");
     key.set_level(MlDsa::LEVEL_44).expect("Error with set_level()");
     assert_eq!(key.get_level().expect("Error with get_level()"), MlDsa::LEVEL_44);
 }
@@ -66,9 +67,9 @@ fn test_new_ex() {
 #[test]
 #[cfg(all(mldsa_make_key, mldsa_level2))]
 fn test_sizes_level44() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng)
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng, &wolfcrypt)
         .expect("Error with generate()");
     assert_eq!(key.size().expect("Error with size()"), MlDsa::LEVEL2_KEY_SIZE);
     assert_eq!(key.priv_size().expect("Error with priv_size()"), MlDsa::LEVEL2_PRV_KEY_SIZE);
@@ -81,9 +82,9 @@ fn test_sizes_level44() {
 #[test]
 #[cfg(all(mldsa_make_key, mldsa_level3))]
 fn test_sizes_level65() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut key = MlDsa::generate(MlDsa::LEVEL_65, &mut rng)
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut key = MlDsa::generate(MlDsa::LEVEL_65, &mut rng, &wolfcrypt)
         .expect("Error with generate()");
     assert_eq!(key.size().expect("Error with size()"), MlDsa::LEVEL3_KEY_SIZE);
     assert_eq!(key.priv_size().expect("Error with priv_size()"), MlDsa::LEVEL3_PRV_KEY_SIZE);
@@ -96,9 +97,9 @@ fn test_sizes_level65() {
 #[test]
 #[cfg(all(mldsa_make_key, mldsa_level5))]
 fn test_sizes_level87() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut key = MlDsa::generate(MlDsa::LEVEL_87, &mut rng)
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut key = MlDsa::generate(MlDsa::LEVEL_87, &mut rng, &wolfcrypt)
         .expect("Error with generate()");
     assert_eq!(key.size().expect("Error with size()"), MlDsa::LEVEL5_KEY_SIZE);
     assert_eq!(key.priv_size().expect("Error with priv_size()"), MlDsa::LEVEL5_PRV_KEY_SIZE);
@@ -110,9 +111,9 @@ fn test_sizes_level87() {
 #[test]
 #[cfg(all(mldsa_make_key, mldsa_check_key))]
 fn test_check_key_level44() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng)
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng, &wolfcrypt)
         .expect("Error with generate()");
     key.check_key().expect("Error with check_key()");
 }
@@ -121,9 +122,9 @@ fn test_check_key_level44() {
 #[test]
 #[cfg(all(mldsa_make_key, mldsa_check_key))]
 fn test_check_key_level65() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut key = MlDsa::generate(MlDsa::LEVEL_65, &mut rng)
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut key = MlDsa::generate(MlDsa::LEVEL_65, &mut rng, &wolfcrypt)
         .expect("Error with generate()");
     key.check_key().expect("Error with check_key()");
 }
@@ -132,9 +133,9 @@ fn test_check_key_level65() {
 #[test]
 #[cfg(all(mldsa_make_key, mldsa_check_key))]
 fn test_check_key_level87() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut key = MlDsa::generate(MlDsa::LEVEL_87, &mut rng)
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut key = MlDsa::generate(MlDsa::LEVEL_87, &mut rng, &wolfcrypt)
         .expect("Error with generate()");
     key.check_key().expect("Error with check_key()");
 }
@@ -146,9 +147,9 @@ fn test_check_key_level87() {
 #[test]
 #[cfg(all(mldsa_make_key, mldsa_sign, mldsa_verify))]
 fn test_sign_verify_level44() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng)
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng, &wolfcrypt)
         .expect("Error with generate()");
     let message = b"Hello, ML-DSA-44!";
     let mut sig = vec![0u8; key.sig_size().expect("Error with sig_size()")];
@@ -170,9 +171,9 @@ fn test_sign_verify_level44() {
 #[test]
 #[cfg(all(mldsa_make_key, mldsa_sign, mldsa_verify))]
 fn test_sign_verify_level65() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut key = MlDsa::generate(MlDsa::LEVEL_65, &mut rng)
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut key = MlDsa::generate(MlDsa::LEVEL_65, &mut rng, &wolfcrypt)
         .expect("Error with generate()");
     let message = b"Hello, ML-DSA-65!";
     let mut sig = vec![0u8; key.sig_size().expect("Error with sig_size()")];
@@ -189,9 +190,9 @@ fn test_sign_verify_level65() {
 #[test]
 #[cfg(all(mldsa_make_key, mldsa_sign, mldsa_verify))]
 fn test_sign_verify_level87() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut key = MlDsa::generate(MlDsa::LEVEL_87, &mut rng)
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut key = MlDsa::generate(MlDsa::LEVEL_87, &mut rng, &wolfcrypt)
         .expect("Error with generate()");
     let message = b"Hello, ML-DSA-87!";
     let mut sig = vec![0u8; key.sig_size().expect("Error with sig_size()")];
@@ -210,9 +211,9 @@ fn test_sign_verify_level87() {
 #[test]
 #[cfg(all(mldsa_make_key, mldsa_sign, mldsa_verify))]
 fn test_sign_ctx_verify_level44() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng)
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng, &wolfcrypt)
         .expect("Error with generate()");
     let message = b"Context-bound message";
     let ctx = b"my context";
@@ -239,9 +240,9 @@ fn test_sign_ctx_verify_level44() {
 #[test]
 #[cfg(all(mldsa_make_key, mldsa_import, mldsa_export, mldsa_sign, mldsa_verify))]
 fn test_import_export_level44() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng)
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng, &wolfcrypt)
         .expect("Error with generate()");
 
     let priv_size = key.size().expect("Error with size()");
@@ -270,7 +271,7 @@ fn test_import_export_level44() {
         .expect("Error with sign_msg()");
 
     // Re-import public key only and verify.
-    let mut pub_key = MlDsa::new().expect("Error with new()");
+    let mut pub_key = MlDsa::new(&wolfcrypt).expect("Error with new()");
     pub_key.set_level(MlDsa::LEVEL_44).expect("Error with set_level()");
     pub_key.import_public(&pub_buf).expect("Error with import_public()");
     let valid = pub_key.verify_msg(&sig[..sig_len], message)
@@ -278,7 +279,7 @@ fn test_import_export_level44() {
     assert!(valid, "Imported public key should accept original signature");
 
     // Re-import private key, sign a message, and verify with the original key.
-    let mut priv_key = MlDsa::new().expect("Error with new()");
+    let mut priv_key = MlDsa::new(&wolfcrypt).expect("Error with new()");
     priv_key.set_level(MlDsa::LEVEL_44).expect("Error with set_level()");
     priv_key.import_private(&priv_buf).expect("Error with import_private()");
     let mut sig2 = vec![0u8; sig_size];
@@ -294,9 +295,9 @@ fn test_import_export_level44() {
 #[test]
 #[cfg(all(mldsa_make_key, mldsa_import, mldsa_export, mldsa_sign, mldsa_verify))]
 fn test_import_key_level44() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng)
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut key = MlDsa::generate(MlDsa::LEVEL_44, &mut rng, &wolfcrypt)
         .expect("Error with generate()");
 
     let priv_size = key.size().expect("Error with size()");
@@ -307,7 +308,7 @@ fn test_import_key_level44() {
     let mut pub_buf = vec![0u8; pub_size];
     key.export_key(&mut priv_buf, &mut pub_buf).expect("Error with export_key()");
 
-    let mut key2 = MlDsa::new().expect("Error with new()");
+    let mut key2 = MlDsa::new(&wolfcrypt).expect("Error with new()");
     key2.set_level(MlDsa::LEVEL_44).expect("Error with set_level()");
     key2.import_key(&priv_buf, &pub_buf).expect("Error with import_key()");
 
@@ -325,13 +326,13 @@ fn test_import_key_level44() {
 #[test]
 #[cfg(all(mldsa_make_key_from_seed, mldsa_export))]
 fn test_generate_from_seed_determinism() {
-    common::setup();
+    let wolfcrypt = common::setup();
     // MLDSA_SEED_SZ = 32 bytes
     let seed = [0x42u8; 32];
 
-    let mut key1 = MlDsa::generate_from_seed(MlDsa::LEVEL_44, &seed)
+    let mut key1 = MlDsa::generate_from_seed(MlDsa::LEVEL_44, &seed, &wolfcrypt)
         .expect("Error with generate_from_seed() first call");
-    let mut key2 = MlDsa::generate_from_seed(MlDsa::LEVEL_44, &seed)
+    let mut key2 = MlDsa::generate_from_seed(MlDsa::LEVEL_44, &seed, &wolfcrypt)
         .expect("Error with generate_from_seed() second call");
 
     let pub_size = key1.pub_size().expect("Error with pub_size()");
@@ -355,14 +356,14 @@ fn test_generate_from_seed_determinism() {
 #[test]
 #[cfg(all(mldsa_make_key_from_seed, mldsa_sign_with_seed, mldsa_verify))]
 fn test_sign_with_seed_determinism() {
-    common::setup();
+    let wolfcrypt = common::setup();
     // MLDSA_SEED_SZ = 32 bytes
     let key_seed = [0x42u8; 32];
     // MLDSA_RND_SZ = 32 bytes
     let sign_seed = [0x55u8; 32];
     let message = b"Deterministic ML-DSA signing test";
 
-    let mut key = MlDsa::generate_from_seed(MlDsa::LEVEL_44, &key_seed)
+    let mut key = MlDsa::generate_from_seed(MlDsa::LEVEL_44, &key_seed, &wolfcrypt)
         .expect("Error with generate_from_seed()");
 
     let sig_size = key.sig_size().expect("Error with sig_size()");
@@ -387,13 +388,13 @@ fn test_sign_with_seed_determinism() {
 #[test]
 #[cfg(all(mldsa_make_key_from_seed, mldsa_sign_with_seed, mldsa_verify))]
 fn test_sign_ctx_with_seed_determinism() {
-    common::setup();
+    let wolfcrypt = common::setup();
     let key_seed = [0x11u8; 32];
     let sign_seed = [0x22u8; 32];
     let message = b"Context deterministic signing test";
     let ctx = b"test-context";
 
-    let mut key = MlDsa::generate_from_seed(MlDsa::LEVEL_44, &key_seed)
+    let mut key = MlDsa::generate_from_seed(MlDsa::LEVEL_44, &key_seed, &wolfcrypt)
         .expect("Error with generate_from_seed()");
 
     let sig_size = key.sig_size().expect("Error with sig_size()");
@@ -418,13 +419,13 @@ fn test_sign_ctx_with_seed_determinism() {
 #[test]
 #[cfg(all(mldsa_make_key_from_seed, mldsa_sign_with_seed, mldsa_verify))]
 fn test_seed_sign_verify_all_levels() {
-    common::setup();
+    let wolfcrypt = common::setup();
     let key_seed = [0xABu8; 32];
     let sign_seed = [0xCDu8; 32];
     let message = b"All-levels seed sign/verify test";
 
     for level in [MlDsa::LEVEL_44, MlDsa::LEVEL_65, MlDsa::LEVEL_87] {
-        let mut key = MlDsa::generate_from_seed(level, &key_seed)
+        let mut key = MlDsa::generate_from_seed(level, &key_seed, &wolfcrypt)
             .expect("Error with generate_from_seed()");
         let sig_size = key.sig_size().expect("Error with sig_size()");
         let mut sig = vec![0u8; sig_size];
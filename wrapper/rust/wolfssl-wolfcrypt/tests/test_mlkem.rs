@@ -22,6 +22,7 @@
 
 mod common;
 
+use wolfssl_wolfcrypt::Env;
 use wolfssl_wolfcrypt::mlkem::MlKem;
 #[cfg(random)]
 use wolfssl_wolfcrypt::random::RNG;
@@ -47,25 +48,25 @@ fn test_shared_constants() {
 /// Verify that `new()` creates an initialized key for each type.
 #[test]
 fn test_new() {
-    common::setup();
-    MlKem::new(MlKem::TYPE_512).expect("Error with new() TYPE_512");
-    MlKem::new(MlKem::TYPE_768).expect("Error with new() TYPE_768");
-    MlKem::new(MlKem::TYPE_1024).expect("Error with new() TYPE_1024");
+    let wolfcrypt = common::setup();
+    MlKem::new(MlKem::TYPE_512, &wolfcrypt).expect("Error with new() TYPE_512");
+    MlKem::new(MlKem::TYPE_768, &wolfcrypt).expect("Error with new() TYPE_768");
+    MlKem::new(MlKem::TYPE_1024, &wolfcrypt).expect("Error with new() TYPE_1024");
 }
 
 /// Verify that `new_ex()` accepts the optional heap and device ID parameters.
 #[test]
 fn test_new_ex() {
-    common::setup();
-    MlKem::new_ex(MlKem::TYPE_768, None, None).expect("Error with new_ex()");
+    let wolfcrypt = common::setup();
+    MlKem::new_ex(MlKem::TYPE_768, &Env::new(&wolfcrypt)).expect("Error with new_ex()");
 }
 
 /// Verify that the runtime size queries return plausible values for each key type.
 #[test]
 fn test_size_queries() {
-    common::setup();
+    let wolfcrypt = common::setup();
     for key_type in [MlKem::TYPE_512, MlKem::TYPE_768, MlKem::TYPE_1024] {
-        let key = MlKem::new(key_type).expect("Error with new()");
+        let key = MlKem::new(key_type, &wolfcrypt).expect("Error with new()");
         let pub_size = key.public_key_size().expect("Error with public_key_size()");
         let priv_size = key.private_key_size().expect("Error with private_key_size()");
         let ct_size = key.cipher_text_size().expect("Error with cipher_text_size()");
@@ -87,9 +88,9 @@ fn test_size_queries() {
 #[test]
 #[cfg(random)]
 fn test_encap_decap_type512() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut key = MlKem::generate(MlKem::TYPE_512, &mut rng)
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut key = MlKem::generate(MlKem::TYPE_512, &mut rng, &wolfcrypt)
         .expect("Error with generate() TYPE_512");
 
     let ct_size = key.cipher_text_size().expect("Error with cipher_text_size()");
@@ -113,9 +114,9 @@ fn test_encap_decap_type512() {
 #[test]
 #[cfg(random)]
 fn test_encap_decap_type768() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut key = MlKem::generate(MlKem::TYPE_768, &mut rng)
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut key = MlKem::generate(MlKem::TYPE_768, &mut rng, &wolfcrypt)
         .expect("Error with generate() TYPE_768");
 
     let ct_size = key.cipher_text_size().expect("Error with cipher_text_size()");
@@ -147,9 +148,9 @@ fn test_encap_decap_type768() {
 #[test]
 #[cfg(random)]
 fn test_encap_decap_type1024() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut key = MlKem::generate(MlKem::TYPE_1024, &mut rng)
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut key = MlKem::generate(MlKem::TYPE_1024, &mut rng, &wolfcrypt)
         .expect("Error with generate() TYPE_1024");
 
     let ct_size = key.cipher_text_size().expect("Error with cipher_text_size()");
@@ -171,13 +172,13 @@ fn test_encap_decap_type1024() {
 /// bytes produce the same key pair on repeated calls.
 #[test]
 fn test_generate_with_random_determinism() {
-    common::setup();
+    let wolfcrypt = common::setup();
     // MAKEKEY_RAND_SIZE = 64 bytes
     let rand = [0x42u8; 64];
 
-    let key1 = MlKem::generate_with_random(MlKem::TYPE_768, &rand)
+    let key1 = MlKem::generate_with_random(MlKem::TYPE_768, &rand, &wolfcrypt)
         .expect("Error with generate_with_random() first call");
-    let key2 = MlKem::generate_with_random(MlKem::TYPE_768, &rand)
+    let key2 = MlKem::generate_with_random(MlKem::TYPE_768, &rand, &wolfcrypt)
         .expect("Error with generate_with_random() second call");
 
     let pub_size = key1.public_key_size().expect("Error with public_key_size()");
@@ -199,11 +200,11 @@ fn test_generate_with_random_determinism() {
 /// key and random bytes produce the same cipher text and shared secret.
 #[test]
 fn test_encapsulate_with_random_determinism() {
-    common::setup();
+    let wolfcrypt = common::setup();
     let key_rand = [0x11u8; 64];
     let enc_rand = [0x22u8; 32];
 
-    let mut key = MlKem::generate_with_random(MlKem::TYPE_768, &key_rand)
+    let mut key = MlKem::generate_with_random(MlKem::TYPE_768, &key_rand, &wolfcrypt)
         .expect("Error with generate_with_random()");
 
     let ct_size = key.cipher_text_size().expect("Error with cipher_text_size()");
@@ -234,9 +235,9 @@ fn test_encapsulate_with_random_determinism() {
 #[test]
 #[cfg(random)]
 fn test_encode_decode_public_key() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut key = MlKem::generate(MlKem::TYPE_768, &mut rng)
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut key = MlKem::generate(MlKem::TYPE_768, &mut rng, &wolfcrypt)
         .expect("Error with generate()");
 
     let pub_size = key.public_key_size().expect("Error with public_key_size()");
@@ -247,7 +248,7 @@ fn test_encode_decode_public_key() {
     key.encode_public_key(&mut pub_buf).expect("Error with encode_public_key()");
 
     // Re-import public key and encapsulate.
-    let mut pub_key = MlKem::new(MlKem::TYPE_768).expect("Error with new()");
+    let mut pub_key = MlKem::new(MlKem::TYPE_768, &wolfcrypt).expect("Error with new()");
     pub_key.decode_public_key(&pub_buf).expect("Error with decode_public_key()");
 
     let mut ct = vec![0u8; ct_size];
@@ -268,9 +269,9 @@ fn test_encode_decode_public_key() {
 #[test]
 #[cfg(random)]
 fn test_encode_decode_private_key() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error creating RNG");
-    let mut key = MlKem::generate(MlKem::TYPE_768, &mut rng)
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut key = MlKem::generate(MlKem::TYPE_768, &mut rng, &wolfcrypt)
         .expect("Error with generate()");
 
     let priv_size = key.private_key_size().expect("Error with private_key_size()");
@@ -287,7 +288,7 @@ fn test_encode_decode_private_key() {
         .expect("Error with encapsulate()");
 
     // Re-import private key and decapsulate.
-    let mut priv_key = MlKem::new(MlKem::TYPE_768).expect("Error with new()");
+    let mut priv_key = MlKem::new(MlKem::TYPE_768, &wolfcrypt).expect("Error with new()");
     priv_key.decode_private_key(&priv_buf).expect("Error with decode_private_key()");
 
     let mut ss_dec = vec![0u8; ss_size];
@@ -302,11 +303,11 @@ fn test_encode_decode_private_key() {
 #[test]
 #[cfg(random)]
 fn test_encap_decap_all_types() {
-    common::setup();
-    let mut rng = RNG::new().expect("Error creating RNG");
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
 
     for key_type in [MlKem::TYPE_512, MlKem::TYPE_768, MlKem::TYPE_1024] {
-        let mut key = MlKem::generate(key_type, &mut rng)
+        let mut key = MlKem::generate(key_type, &mut rng, &wolfcrypt)
             .expect("Error with generate()");
 
         let ct_size = key.cipher_text_size().expect("Error with cipher_text_size()");
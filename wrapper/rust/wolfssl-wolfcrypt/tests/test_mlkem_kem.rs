@@ -32,19 +32,19 @@ use wolfssl_wolfcrypt::random::RNG;
 /// sizes reported by wolfCrypt.
 #[test]
 fn test_sizes_match_runtime() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let key512 = MlKem::new(MlKem::TYPE_512).expect("new TYPE_512");
+    let key512 = MlKem::new(MlKem::TYPE_512, &wolfcrypt).expect("new TYPE_512");
     assert_eq!(key512.public_key_size().unwrap(), 800);
     assert_eq!(key512.private_key_size().unwrap(), 1632);
     assert_eq!(key512.cipher_text_size().unwrap(), 768);
 
-    let key768 = MlKem::new(MlKem::TYPE_768).expect("new TYPE_768");
+    let key768 = MlKem::new(MlKem::TYPE_768, &wolfcrypt).expect("new TYPE_768");
     assert_eq!(key768.public_key_size().unwrap(), 1184);
     assert_eq!(key768.private_key_size().unwrap(), 2400);
     assert_eq!(key768.cipher_text_size().unwrap(), 1088);
 
-    let key1024 = MlKem::new(MlKem::TYPE_1024).expect("new TYPE_1024");
+    let key1024 = MlKem::new(MlKem::TYPE_1024, &wolfcrypt).expect("new TYPE_1024");
     assert_eq!(key1024.public_key_size().unwrap(), 1568);
     assert_eq!(key1024.private_key_size().unwrap(), 3168);
     assert_eq!(key1024.cipher_text_size().unwrap(), 1568);
@@ -53,8 +53,8 @@ fn test_sizes_match_runtime() {
 /// Generate, encapsulate, and decapsulate with ML-KEM-512 via the kem traits.
 #[test]
 fn test_kem_512_round_trip() {
-    common::setup();
-    let mut rng = RNG::new().expect("RNG creation failed");
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("RNG creation failed");
 
     let (dk, ek) = MlKem512::generate_keypair_from_rng(&mut rng);
     let (ct, k_send) = ek.encapsulate_with_rng(&mut rng);
@@ -65,8 +65,8 @@ fn test_kem_512_round_trip() {
 /// Generate, encapsulate, and decapsulate with ML-KEM-768 via the kem traits.
 #[test]
 fn test_kem_768_round_trip() {
-    common::setup();
-    let mut rng = RNG::new().expect("RNG creation failed");
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("RNG creation failed");
 
     let (dk, ek) = MlKem768::generate_keypair_from_rng(&mut rng);
     let (ct, k_send) = ek.encapsulate_with_rng(&mut rng);
@@ -77,8 +77,8 @@ fn test_kem_768_round_trip() {
 /// Generate, encapsulate, and decapsulate with ML-KEM-1024 via the kem traits.
 #[test]
 fn test_kem_1024_round_trip() {
-    common::setup();
-    let mut rng = RNG::new().expect("RNG creation failed");
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("RNG creation failed");
 
     let (dk, ek) = MlKem1024::generate_keypair_from_rng(&mut rng);
     let (ct, k_send) = ek.encapsulate_with_rng(&mut rng);
@@ -90,8 +90,8 @@ fn test_kem_1024_round_trip() {
 /// key and that the associated encapsulation key is consistent.
 #[test]
 fn test_generate_from_rng() {
-    common::setup();
-    let mut rng = RNG::new().expect("RNG creation failed");
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("RNG creation failed");
 
     let dk = MlKem768DecapsulationKey::generate_from_rng(&mut rng);
     let ek = dk.encapsulation_key();
@@ -105,8 +105,8 @@ fn test_generate_from_rng() {
 /// (ML-KEM implicit rejection).
 #[test]
 fn test_implicit_rejection() {
-    common::setup();
-    let mut rng = RNG::new().expect("RNG creation failed");
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("RNG creation failed");
 
     let (dk, ek) = MlKem768::generate_keypair_from_rng(&mut rng);
     let (ct, k_send) = ek.encapsulate_with_rng(&mut rng);
@@ -122,8 +122,8 @@ fn test_implicit_rejection() {
 /// Verify that `TryKeyInit` and `KeyExport` round-trip the encapsulation key.
 #[test]
 fn test_ek_export_import() {
-    common::setup();
-    let mut rng = RNG::new().expect("RNG creation failed");
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("RNG creation failed");
 
     let (dk, ek) = MlKem768::generate_keypair_from_rng(&mut rng);
 
@@ -155,8 +155,8 @@ fn test_ek_try_new_zeroed_key() {
 /// can be used for encapsulation.
 #[test]
 fn test_decapsulator_encapsulation_key() {
-    common::setup();
-    let mut rng = RNG::new().expect("RNG creation failed");
+    let wolfcrypt = common::setup();
+    let mut rng = RNG::new(&wolfcrypt).expect("RNG creation failed");
 
     let dk = MlKem512DecapsulationKey::generate_from_rng(&mut rng);
     let ek = dk.encapsulation_key().clone();
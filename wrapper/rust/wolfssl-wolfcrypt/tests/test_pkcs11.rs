@@ -0,0 +1,168 @@
+/*
+ * Copyright (C) 2006-2026 wolfSSL Inc.
+ *
+ * This file is part of wolfSSL.
+ *
+ * wolfSSL is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * wolfSSL is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1335, USA
+ */
+
+// These tests need a PKCS#11 module with an initialized token, e.g.
+// SoftHSMv2:
+//
+//   softhsm2-util --init-token --free --label wolfssl --so-pin 0000 --pin 1234
+//   PKCS11_LIBRARY=/usr/lib/softhsm/libsofthsm2.so PKCS11_TOKEN=wolfssl \
+//       PKCS11_PIN=1234 cargo test --features alloc --test test_pkcs11
+//
+// They are skipped when PKCS11_LIBRARY is not set.
+
+#![cfg(all(pkcs11, feature = "alloc"))]
+
+mod common;
+
+use std::ffi::CString;
+use std::sync::{Mutex, MutexGuard};
+#[cfg(aes_gcm)]
+use wolfssl_wolfcrypt::aes::GCM;
+#[cfg(all(ecc, ecc_sign, ecc_verify, random))]
+use wolfssl_wolfcrypt::ecc::ECC;
+use wolfssl_wolfcrypt::pkcs11::{Pkcs11Dev, Pkcs11Token};
+#[cfg(random)]
+use wolfssl_wolfcrypt::random::RNG;
+use wolfssl_wolfcrypt::{Env, Error};
+use wolfssl_wolfcrypt::sys;
+
+// Dropping a token closes all sessions on its slot, so the tests must not
+// share the token concurrently.
+static TOKEN_LOCK: Mutex<()> = Mutex::new(());
+
+struct Config {
+    library: CString,
+    token: Option<CString>,
+    pin: Option<String>,
+}
+
+fn config() -> Option<(Config, MutexGuard<'static, ()>)> {
+    let library = std::env::var("PKCS11_LIBRARY").ok()?;
+    let guard = TOKEN_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    Some((Config {
+        library: CString::new(library).expect("Error with CString::new()"),
+        token: std::env::var("PKCS11_TOKEN").ok()
+            .map(|t| CString::new(t).expect("Error with CString::new()")),
+        pin: std::env::var("PKCS11_PIN").ok(),
+    }, guard))
+}
+
+fn open_token<'a>(dev: &'a Pkcs11Dev, config: &Config, dev_id: i32) -> Pkcs11Token<'a> {
+    let mut token = Pkcs11Token::new(dev, None, config.token.as_deref(),
+        config.pin.as_deref().map(str::as_bytes)).expect("Error with Pkcs11Token::new()");
+    token.open(true).expect("Error with open()");
+    assert_eq!(token.device(), None);
+    token.register_device(dev_id).expect("Error with register_device()");
+    assert_eq!(token.device().map(|device| device.id()), Some(dev_id));
+    token
+}
+
+#[test]
+fn test_token_register() {
+    let wolfcrypt = common::setup();
+    let Some((config, _guard)) = config() else { return };
+    let dev = Pkcs11Dev::new(&config.library, &Env::new(&wolfcrypt)).expect("Error with Pkcs11Dev::new()");
+    let mut token = open_token(&dev, &config, 0x6100);
+    assert_eq!(token.register_device(0x6101), Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_STATE_E)));
+
+    let mut token2 = Pkcs11Token::new(&dev, None, config.token.as_deref(),
+        config.pin.as_deref().map(str::as_bytes)).expect("Error with Pkcs11Token::new()");
+    assert_eq!(token2.register_device(0x6100), Err(Error::from(sys::wolfCrypt_ErrorCodes_ALREADY_E)));
+    drop(token);
+    token2.register_device(0x6100).expect("Error with register_device()");
+}
+
+#[test]
+#[cfg(all(ecc, ecc_sign, ecc_verify, random))]
+fn test_ecc_token_key() {
+    let wolfcrypt = common::setup();
+    let Some((config, _guard)) = config() else { return };
+    let dev = Pkcs11Dev::new(&config.library, &Env::new(&wolfcrypt)).expect("Error with Pkcs11Dev::new()");
+    let token = open_token(&dev, &config, 0x6102);
+    let env = Env::new(&wolfcrypt).with_device(token.device().expect("Error with device()"));
+    let rng = RNG::new(&wolfcrypt).expect("Error with RNG::new()");
+
+    let mut key = ECC::generate(32, &rng, &env).expect("Error with generate()");
+    let hash = [0x42u8; 32];
+    let mut sig = [0u8; 128];
+    let sig_len = key.sign_hash(&hash, &mut sig, &rng).expect("Error with sign_hash()");
+    let valid = key.verify_hash(&sig[..sig_len], &hash).expect("Error with verify_hash()");
+    assert!(valid);
+    let mut bad_hash = hash;
+    bad_hash[0] ^= 1;
+    let valid = key.verify_hash(&sig[..sig_len], &bad_hash).expect("Error with verify_hash()");
+    assert!(!valid);
+}
+
+#[test]
+#[cfg(all(ecc, ecc_key_id, ecc_sign, random))]
+fn test_ecc_missing_key() {
+    let wolfcrypt = common::setup();
+    let Some((config, _guard)) = config() else { return };
+    let dev = Pkcs11Dev::new(&config.library, &Env::new(&wolfcrypt)).expect("Error with Pkcs11Dev::new()");
+    let token = open_token(&dev, &config, 0x6103);
+    let env = Env::new(&wolfcrypt).with_device(token.device().expect("Error with device()"));
+    let rng = RNG::new(&wolfcrypt).expect("Error with RNG::new()");
+    let hash = [0x42u8; 32];
+    let mut sig = [0u8; 128];
+
+    let mut key = ECC::new_from_id(b"\xde\xad\xbe\xef", ECC::SECP256R1, &env)
+        .expect("Error with new_from_id()");
+    assert!(key.sign_hash(&hash, &mut sig, &rng).is_err());
+    let mut key = ECC::new_from_label("no-such-key", ECC::SECP256R1, &env)
+        .expect("Error with new_from_label()");
+    assert!(key.sign_hash(&hash, &mut sig, &rng).is_err());
+
+    assert_eq!(ECC::new_from_id(&[0u8; 33], ECC::SECP256R1, &env).err(),
+        Some(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E)));
+    assert_eq!(ECC::new_from_label("", ECC::SECP256R1, &env).err(),
+        Some(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E)));
+}
+
+#[test]
+#[cfg(aes_gcm)]
+fn test_aes_gcm_session_key() {
+    let wolfcrypt = common::setup();
+    let Some((config, _guard)) = config() else { return };
+    let dev = Pkcs11Dev::new(&config.library, &Env::new(&wolfcrypt)).expect("Error with Pkcs11Dev::new()");
+    let token = open_token(&dev, &config, 0x6104);
+    let env = Env::new(&wolfcrypt).with_device(token.device().expect("Error with device()"));
+    let key = [0x11u8; 32];
+    let iv = [0x22u8; 12];
+    let auth = b"additional data";
+    let plain = b"PKCS#11 token AES-GCM test data!";
+
+    let mut sw = GCM::new(&wolfcrypt).expect("Error with GCM::new()");
+    sw.init(&key).expect("Error with init()");
+    let mut expected = [0u8; 32];
+    let mut expected_tag = [0u8; 16];
+    sw.encrypt(plain, &mut expected, &iv, auth, &mut expected_tag).expect("Error with encrypt()");
+
+    let mut hw = GCM::new_ex(&env).expect("Error with GCM::new_ex()");
+    hw.init(&key).expect("Error with init()");
+    let mut cipher = [0u8; 32];
+    let mut tag = [0u8; 16];
+    hw.encrypt(plain, &mut cipher, &iv, auth, &mut tag).expect("Error with encrypt()");
+    assert_eq!(cipher, expected);
+    assert_eq!(tag, expected_tag);
+    let mut plain_out = [0u8; 32];
+    hw.decrypt(&cipher, &mut plain_out, &iv, auth, &tag).expect("Error with decrypt()");
+    assert_eq!(&plain_out, plain);
+}
@@ -0,0 +1,105 @@
+#![cfg(all(pkcs12, feature = "alloc"))]
+
+mod common;
+
+use std::fs;
+use wolfssl_wolfcrypt::pkcs12::*;
+
+const SERVER_P12: &str = "../../../certs/test-servercert.p12";
+const SERVER_P12_PASSWORD: &str = "wolfSSL test";
+
+#[test]
+#[cfg(rsa)]
+fn test_parse_rsa() {
+    let wolfcrypt = common::setup();
+
+    let der = fs::read(SERVER_P12).expect("Error reading bundle");
+    let cert = fs::read("../../../certs/server-cert.der").expect("Error reading cert");
+    let pkcs12 = PKCS12::from_der(&der, &wolfcrypt).expect("Error with from_der()");
+    let bundle = pkcs12.parse(SERVER_P12_PASSWORD).expect("Error with parse()");
+
+    assert!(matches!(bundle.private_key(), Ok(PrivateKey::Rsa(_))));
+    assert_eq!(bundle.private_key_der().unwrap()[0], 0x30);
+    assert_eq!(bundle.cert(), Some(&cert[..]));
+    assert_eq!(bundle.ca_certs().count(), 2);
+
+    assert!(pkcs12.parse("bad").is_err());
+    assert!(pkcs12.parse("wolfSSL\0test").is_err());
+}
+
+#[test]
+#[cfg(ecc)]
+fn test_parse_ecc() {
+    let wolfcrypt = common::setup();
+
+    let der = fs::read("../../../certs/ecc-rsa-server.p12").expect("Error reading bundle");
+    let pkcs12 = PKCS12::from_der(&der, &wolfcrypt).expect("Error with from_der()");
+    let bundle = pkcs12.parse("").expect("Error with parse()");
+    assert!(matches!(bundle.private_key(), Ok(PrivateKey::Ecc(_))));
+    assert!(bundle.cert().is_some());
+    assert_eq!(bundle.ca_certs().count(), 1);
+}
+
+#[test]
+fn test_to_der() {
+    let wolfcrypt = common::setup();
+
+    let der = fs::read(SERVER_P12).expect("Error reading bundle");
+    let pkcs12 = PKCS12::from_der(&der, &wolfcrypt).expect("Error with from_der()");
+    let der_size = pkcs12.der_size().expect("Error with der_size()");
+    assert_eq!(der_size, der.len());
+
+    let mut out = vec![0u8; der_size];
+    assert!(pkcs12.to_der(&mut out[..der_size - 1]).is_err());
+    let out_size = pkcs12.to_der(&mut out).expect("Error with to_der()");
+    assert_eq!(out_size, der.len());
+    assert_eq!(out, der);
+
+    assert!(PKCS12::from_der(&der[..der.len() / 2], &wolfcrypt).is_err());
+}
+
+#[test]
+#[cfg(rsa)]
+fn test_create() {
+    let wolfcrypt = common::setup();
+
+    let key = fs::read("../../../certs/server-key.der").expect("Error reading key");
+    let cert = fs::read("../../../certs/server-cert.der").expect("Error reading cert");
+    let ca_cert = fs::read("../../../certs/ca-cert.der").expect("Error reading cert");
+    let ca_certs: [&[u8]; 1] = [&ca_cert];
+
+    let all_options = [
+        CreateOptions::default(),
+        CreateOptions {
+            key_pbe: PKCS12::PBE_AES128_CBC,
+            cert_pbe: PKCS12::PBE_NONE,
+            iterations: 1000,
+            mac_iterations: 1,
+        },
+        CreateOptions {
+            key_pbe: PKCS12::PBE_NONE,
+            cert_pbe: PKCS12::PBE_NONE,
+            iterations: 1000,
+            mac_iterations: 0,
+        },
+    ];
+    for options in &all_options {
+        let pkcs12 = PKCS12::create("export password", &key, &cert, &ca_certs, options, &wolfcrypt)
+            .expect("Error with create()");
+        let mut der = vec![0u8; pkcs12.der_size().expect("Error with der_size()")];
+        let der_size = pkcs12.to_der(&mut der).expect("Error with to_der()");
+
+        let pkcs12 = PKCS12::from_der(&der[..der_size], &wolfcrypt).expect("Error with from_der()");
+        let bundle = pkcs12.parse("export password").expect("Error with parse()");
+        assert!(matches!(bundle.private_key(), Ok(PrivateKey::Rsa(_))));
+        assert_eq!(bundle.cert(), Some(&cert[..]));
+        let parsed_ca_certs: Vec<&[u8]> = bundle.ca_certs().collect();
+        assert_eq!(parsed_ca_certs, ca_certs);
+        if options.mac_iterations > 0 {
+            assert!(pkcs12.parse("wrong password").is_err());
+        }
+    }
+
+    let options = CreateOptions { key_pbe: 1234, ..Default::default() };
+    assert!(PKCS12::create("export password", &key, &cert, &[], &options, &wolfcrypt).is_err());
+}
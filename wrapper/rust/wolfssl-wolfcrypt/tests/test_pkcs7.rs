@@ -0,0 +1,169 @@
+#![cfg(all(pkcs7, random))]
+
+mod common;
+
+use std::fs;
+#[cfg(all(ecc, ecc_export))]
+use wolfssl_wolfcrypt::Env;
+use wolfssl_wolfcrypt::pkcs7::*;
+use wolfssl_wolfcrypt::random::RNG;
+
+const CONTENT: &[u8] = b"firmware image v1.2.3";
+
+// SCEP messageType attribute, as used by the wolfSSL PKCS#7 tests.
+const MESSAGE_TYPE_OID: &[u8] = &[
+    0x06, 0x0a, 0x60, 0x86, 0x48, 0x01, 0x86, 0xF8, 0x45, 0x01, 0x09, 0x02
+];
+const MESSAGE_TYPE_VALUE: &[u8] = &[0x13, 0x02, b'1', b'9'];
+
+#[test]
+#[cfg(all(rsa, rsa_key_to_der))]
+fn test_signed_data_rsa() {
+    use wolfssl_wolfcrypt::rsa::RSA;
+    let wolfcrypt = common::setup();
+
+    let cert = fs::read("../../../certs/server-cert.der").expect("Error reading cert");
+    let ca_cert = fs::read("../../../certs/ca-cert.der").expect("Error reading cert");
+    let key_der = fs::read("../../../certs/server-key.der").expect("Error reading key");
+    let mut key = RSA::new_from_der(&key_der, &wolfcrypt).expect("Error with new_from_der()");
+    let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+
+    let attributes = [Attribute::new(MESSAGE_TYPE_OID, MESSAGE_TYPE_VALUE)
+        .expect("Error with Attribute::new()")];
+    let mut signer = PKCS7::new_with_cert(&cert, &wolfcrypt).expect("Error with new_with_cert()");
+    signer.add_certificate(&ca_cert).expect("Error with add_certificate()");
+    signer.set_signed_attributes(&attributes).expect("Error with set_signed_attributes()");
+    let mut signed = [0u8; 8192];
+    let signed_size = signer.encode_signed_data(CONTENT, &mut key, PKCS7::HASH_SHA256,
+        &rng, &mut signed).expect("Error with encode_signed_data()");
+    let signed = &signed[..signed_size];
+
+    let mut verifier = PKCS7::new(&wolfcrypt).expect("Error with new()");
+    verifier.verify_signed_data(signed).expect("Error with verify_signed_data()");
+    assert_eq!(verifier.content(), Some(CONTENT));
+    assert_eq!(verifier.signer_certificate(), Some(&cert[..]));
+    let certs: Vec<&[u8]> = verifier.certificates().collect();
+    assert!(certs.contains(&&cert[..]));
+    assert!(certs.contains(&&ca_cert[..]));
+
+    let mut value = [0u8; 16];
+    let value_size = verifier.signed_attribute(&MESSAGE_TYPE_OID[2..], &mut value)
+        .expect("Error with signed_attribute()");
+    assert_eq!(&value[..value_size], MESSAGE_TYPE_VALUE);
+
+    let mut tampered = signed.to_vec();
+    let pos = tampered.windows(CONTENT.len()).position(|w| w == CONTENT).unwrap();
+    tampered[pos] ^= 0x01;
+    let mut verifier = PKCS7::new(&wolfcrypt).expect("Error with new()");
+    assert!(verifier.verify_signed_data(&tampered).is_err());
+}
+
+#[test]
+#[cfg(all(ecc, ecc_export))]
+fn test_signed_data_ecc_detached() {
+    use wolfssl_wolfcrypt::ecc::ECC;
+    let wolfcrypt = common::setup();
+
+    let cert = fs::read("../../../certs/server-ecc.der").expect("Error reading cert");
+    let key_der = fs::read("../../../certs/ecc-key.der").expect("Error reading key");
+    let mut key = ECC::import_der(&key_der, &Env::new(&wolfcrypt)).expect("Error with import_der()");
+    let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+
+    let mut signer = PKCS7::new_with_cert(&cert, &wolfcrypt).expect("Error with new_with_cert()");
+    signer.set_detached(true).expect("Error with set_detached()");
+    signer.set_signer_identifier_type(PKCS7::SID_SKID)
+        .expect("Error with set_signer_identifier_type()");
+    let mut signed = [0u8; 4096];
+    let signed_size = signer.encode_signed_data(CONTENT, &mut key, PKCS7::HASH_SHA384,
+        &rng, &mut signed).expect("Error with encode_signed_data()");
+    let signed = &signed[..signed_size];
+    assert!(!signed.windows(CONTENT.len()).any(|w| w == CONTENT));
+
+    let mut verifier = PKCS7::new(&wolfcrypt).expect("Error with new()");
+    verifier.verify_signed_data_detached(signed, CONTENT)
+        .expect("Error with verify_signed_data_detached()");
+    assert_eq!(verifier.signer_certificate(), Some(&cert[..]));
+
+    let mut verifier = PKCS7::new(&wolfcrypt).expect("Error with new()");
+    assert!(verifier.verify_signed_data_detached(signed, b"firmware image v1.2.4").is_err());
+}
+
+#[test]
+#[cfg(all(rsa, rsa_key_to_der))]
+fn test_enveloped_data_ktri() {
+    use wolfssl_wolfcrypt::rsa::RSA;
+    let wolfcrypt = common::setup();
+
+    let cert = fs::read("../../../certs/server-cert.der").expect("Error reading cert");
+    let key_der = fs::read("../../../certs/server-key.der").expect("Error reading key");
+    let mut key = RSA::new_from_der(&key_der, &wolfcrypt).expect("Error with new_from_der()");
+    let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+
+    let mut envelope = PKCS7::new(&wolfcrypt).expect("Error with new()");
+    envelope.set_content_cipher(PKCS7::AES256_CBC).expect("Error with set_content_cipher()");
+    envelope.add_recipient_ktri(&cert, PKCS7::SID_ISSUER_AND_SERIAL_NUMBER)
+        .expect("Error with add_recipient_ktri()");
+    let mut enveloped = [0u8; 4096];
+    let enveloped_size = envelope.encode_enveloped_data(CONTENT, &rng, &mut enveloped)
+        .expect("Error with encode_enveloped_data()");
+    let enveloped = &enveloped[..enveloped_size];
+    assert!(!enveloped.windows(CONTENT.len()).any(|w| w == CONTENT));
+
+    let mut recipient = PKCS7::new_with_cert(&cert, &wolfcrypt).expect("Error with new_with_cert()");
+    let mut decrypted = [0u8; 256];
+    let decrypted_size = recipient.decode_enveloped_data(enveloped, &mut key, &rng,
+        &mut decrypted).expect("Error with decode_enveloped_data()");
+    assert_eq!(&decrypted[..decrypted_size], CONTENT);
+
+    let mut tampered = enveloped.to_vec();
+    let last = tampered.len() - 1;
+    tampered[last] ^= 0x01;
+    let mut recipient = PKCS7::new_with_cert(&cert, &wolfcrypt).expect("Error with new_with_cert()");
+    let result = recipient.decode_enveloped_data(&tampered, &mut key, &rng, &mut decrypted);
+    assert!(!matches!(result, Ok(size) if &decrypted[..size] == CONTENT));
+}
+
+#[test]
+#[cfg(all(ecc, ecc_export))]
+fn test_enveloped_data_kari() {
+    use wolfssl_wolfcrypt::ecc::ECC;
+    let wolfcrypt = common::setup();
+
+    let cert = fs::read("../../../certs/server-ecc.der").expect("Error reading cert");
+    let key_der = fs::read("../../../certs/ecc-key.der").expect("Error reading key");
+    let mut key = ECC::import_der(&key_der, &Env::new(&wolfcrypt)).expect("Error with import_der()");
+    let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let ukm = [0x55u8; 16];
+
+    let mut envelope = PKCS7::new(&wolfcrypt).expect("Error with new()");
+    envelope.set_content_cipher(PKCS7::AES128_CBC).expect("Error with set_content_cipher()");
+    envelope.add_recipient_kari(&cert, PKCS7::AES256_WRAP, PKCS7::ECDH_SHA256_KDF,
+        Some(&ukm), &rng).expect("Error with add_recipient_kari()");
+    assert!(envelope.set_content_cipher(PKCS7::AES256_CBC).is_err());
+    let mut enveloped = [0u8; 4096];
+    let enveloped_size = envelope.encode_enveloped_data(CONTENT, &rng, &mut enveloped)
+        .expect("Error with encode_enveloped_data()");
+
+    let mut recipient = PKCS7::new_with_cert(&cert, &wolfcrypt).expect("Error with new_with_cert()");
+    let mut decrypted = [0u8; 256];
+    let decrypted_size = recipient.decode_enveloped_data(&enveloped[..enveloped_size],
+        &mut key, &rng, &mut decrypted).expect("Error with decode_enveloped_data()");
+    assert_eq!(&decrypted[..decrypted_size], CONTENT);
+
+    let other = fs::read("../../../certs/client-ecc-cert.der").expect("Error reading cert");
+    let mut recipient = PKCS7::new_with_cert(&other, &wolfcrypt).expect("Error with new_with_cert()");
+    assert!(recipient.decode_enveloped_data(&enveloped[..enveloped_size], &mut key, &rng,
+        &mut decrypted).is_err());
+}
+
+#[test]
+fn test_invalid_args() {
+    let wolfcrypt = common::setup();
+
+    let mut pkcs7 = PKCS7::new(&wolfcrypt).expect("Error with new()");
+    assert_eq!(pkcs7.content(), None);
+    assert_eq!(pkcs7.signer_certificate(), None);
+    assert_eq!(pkcs7.certificates().count(), 0);
+    assert!(pkcs7.verify_signed_data(&[0x30, 0x03, 0x02, 0x01, 0x00]).is_err());
+    assert!(PKCS7::new_with_cert(&[0x30, 0x00], &wolfcrypt).is_err());
+}
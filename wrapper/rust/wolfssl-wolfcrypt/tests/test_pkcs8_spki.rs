@@ -0,0 +1,90 @@
+#![cfg(all(pkcs8, feature = "pkcs8"))]
+
+mod common;
+
+use pkcs8::{DecodePrivateKey, EncodePrivateKey};
+use spki::{DecodePublicKey, EncodePublicKey};
+use std::fs;
+
+#[test]
+#[cfg(all(rsa, rsa_key_to_der, rsa_key_to_public_der))]
+fn test_rsa() {
+    use wolfssl_wolfcrypt::rsa::RSA;
+    common::setup();
+
+    let der = fs::read("../../../certs/server-keyPkcs8.der").expect("Error reading key");
+    let pub_der = fs::read("../../../certs/server-keyPub.der").expect("Error reading key");
+    let key = RSA::from_pkcs8_der(&der).expect("Error with from_pkcs8_der()");
+    let doc = key.to_pkcs8_der().expect("Error with to_pkcs8_der()");
+    assert_eq!(doc.as_bytes(), &der[..]);
+    let doc = key.to_public_key_der().expect("Error with to_public_key_der()");
+    assert_eq!(doc.as_bytes(), &pub_der[..]);
+
+    let key = RSA::from_public_key_der(&pub_der).expect("Error with from_public_key_der()");
+    let doc = key.to_public_key_der().expect("Error with to_public_key_der()");
+    assert_eq!(doc.as_bytes(), &pub_der[..]);
+
+    assert!(RSA::from_pkcs8_der(&pub_der).is_err());
+}
+
+#[test]
+#[cfg(all(ecc, ecc_key_to_pkcs8, ecc_export))]
+fn test_ecc() {
+    use wolfssl_wolfcrypt::ecc::ECC;
+    common::setup();
+
+    let der = fs::read("../../../certs/ecc-keyPkcs8.der").expect("Error reading key");
+    let pub_der = fs::read("../../../certs/ecc-keyPub.der").expect("Error reading key");
+    let key = ECC::from_pkcs8_der(&der).expect("Error with from_pkcs8_der()");
+    let doc = key.to_pkcs8_der().expect("Error with to_pkcs8_der()");
+    let key = ECC::from_pkcs8_der(doc.as_bytes()).expect("Error with from_pkcs8_der()");
+    let doc = key.to_public_key_der().expect("Error with to_public_key_der()");
+    assert_eq!(doc.as_bytes(), &pub_der[..]);
+
+    let key = ECC::from_public_key_der(&pub_der).expect("Error with from_public_key_der()");
+    let doc = key.to_public_key_der().expect("Error with to_public_key_der()");
+    assert_eq!(doc.as_bytes(), &pub_der[..]);
+}
+
+#[test]
+#[cfg(all(curve448, curve448_key_decode, curve448_key_to_der, random))]
+fn test_x448() {
+    use wolfssl_wolfcrypt::curve448::X448;
+    use wolfssl_wolfcrypt::random::RNG;
+    let wolfcrypt = common::setup();
+
+    let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut key = X448::generate(&rng, &wolfcrypt).expect("Error with generate()");
+    let mut public = [0u8; X448::PUB_KEYSIZE];
+    key.export_public(&mut public).expect("Error with export_public()");
+
+    let doc = key.to_pkcs8_der().expect("Error with to_pkcs8_der()");
+    let key = X448::from_pkcs8_der(doc.as_bytes()).expect("Error with from_pkcs8_der()");
+    assert_eq!(key.to_pkcs8_der().expect("Error with to_pkcs8_der()").as_bytes(),
+        doc.as_bytes());
+    let pub_doc = key.to_public_key_der().expect("Error with to_public_key_der()");
+
+    let mut key = X448::from_public_key_der(pub_doc.as_bytes())
+        .expect("Error with from_public_key_der()");
+    let mut public2 = [0u8; X448::PUB_KEYSIZE];
+    key.export_public(&mut public2).expect("Error with export_public()");
+    assert_eq!(public, public2);
+}
+
+#[test]
+#[cfg(mlkem)]
+fn test_mlkem() {
+    use wolfssl_wolfcrypt::mlkem::MlKem;
+    let wolfcrypt = common::setup();
+
+    let key = MlKem::generate_with_random(MlKem::TYPE_768, &[0x42u8; 64], &wolfcrypt)
+        .expect("Error with generate_with_random()");
+    let doc = key.to_pkcs8_der().expect("Error with to_pkcs8_der()");
+    let imported = MlKem::from_pkcs8_der(doc.as_bytes()).expect("Error with from_pkcs8_der()");
+    assert_eq!(imported.to_pkcs8_der().unwrap().as_bytes(), doc.as_bytes());
+
+    let doc = key.to_public_key_der().expect("Error with to_public_key_der()");
+    let imported = MlKem::from_public_key_der(doc.as_bytes())
+        .expect("Error with from_public_key_der()");
+    assert_eq!(imported.to_public_key_der().unwrap().as_bytes(), doc.as_bytes());
+}
@@ -6,7 +6,7 @@ use wolfssl_wolfcrypt::prf::*;
 
 #[test]
 fn test_prf() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
     let secret = [0x10u8, 0xbc, 0xb4, 0xa2, 0xe8, 0xdc, 0xf1, 0x9b, 0x4c,
         0x51, 0x9c, 0xed, 0x31, 0x1b, 0x51, 0x57, 0x02, 0x3f,
@@ -28,7 +28,7 @@ fn test_prf() {
 
     let mut out = [0u8; 12];
 
-    prf(&secret, &seed, PRF_HASH_SHA384, &mut out).expect("Error with prf()");
+    prf(&secret, &seed, PRF_HASH_SHA384, &wolfcrypt, &mut out).expect("Error with prf()");
 
     assert_eq!(out, expected);
 }
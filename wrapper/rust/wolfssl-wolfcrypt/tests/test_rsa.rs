@@ -14,10 +14,10 @@ use wolfssl_wolfcrypt::rsa::*;
 #[test]
 #[cfg(rsa_keygen)]
 fn test_rsa_generate() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let rng = RNG::new().expect("Error creating RNG");
-    let mut rsa = RSA::generate(2048, 65537, &rng).expect("Error with generate()");
+    let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut rsa = RSA::generate(2048, 65537, &rng, &wolfcrypt).expect("Error with generate()");
     rsa.check().expect("Error with check()");
 
     let encrypt_size = rsa.get_encrypt_size().expect("Error with get_encrypt_size()");
@@ -60,10 +60,11 @@ fn test_rsa_generate() {
 #[test]
 #[cfg(random)]
 fn test_rsa_encrypt_decrypt() {
-    let rng = Rc::new(RNG::new().expect("Error creating RNG"));
+    let wolfcrypt = common::setup();
+    let rng = Rc::new(RNG::new(&wolfcrypt).expect("Error creating RNG"));
     let key_path = "../../../certs/client-keyPub.der";
     let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    let mut rsa = RSA::new_public_from_der(&der).expect("Error with new_public_from_der()");
+    let mut rsa = RSA::new_public_from_der(&der, &wolfcrypt).expect("Error with new_public_from_der()");
     rsa.set_shared_rng(Rc::clone(&rng)).expect("Error with set_shared_rng()");
     let plain: &[u8] = b"Test message";
     let mut enc: [u8; 512] = [0; 512];
@@ -72,7 +73,7 @@ fn test_rsa_encrypt_decrypt() {
 
     let key_path = "../../../certs/client-key.der";
     let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    let mut rsa = RSA::new_from_der(&der).expect("Error with new_from_der()");
+    let mut rsa = RSA::new_from_der(&der, &wolfcrypt).expect("Error with new_from_der()");
     rsa.set_shared_rng(Rc::clone(&rng)).expect("Error with set_shared_rng()");
     let mut plain_out: [u8; 512] = [0; 512];
     let dec_len = rsa.private_decrypt(&enc[0..enc_len], &mut plain_out).expect("Error with private_decrypt()");
@@ -83,11 +84,12 @@ fn test_rsa_encrypt_decrypt() {
 #[test]
 #[cfg(all(sha256, random, rsa_pss))]
 fn test_rsa_pss() {
-    let rng = Rc::new(RNG::new().expect("Error creating RNG"));
+    let wolfcrypt = common::setup();
+    let rng = Rc::new(RNG::new(&wolfcrypt).expect("Error creating RNG"));
 
     let key_path = "../../../certs/client-key.der";
     let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    let mut rsa = RSA::new_from_der(&der).expect("Error with new_from_der()");
+    let mut rsa = RSA::new_from_der(&der, &wolfcrypt).expect("Error with new_from_der()");
     let msg: &[u8] = b"This is the string to be signed!";
     let mut signature: [u8; 512] = [0; 512];
     let sig_len = rsa.pss_sign(msg, &mut signature, RSA::HASH_TYPE_SHA256, RSA::MGF1SHA256, &rng).expect("Error with pss_sign()");
@@ -95,7 +97,7 @@ fn test_rsa_pss() {
 
     let key_path = "../../../certs/client-keyPub.der";
     let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    let mut rsa = RSA::new_public_from_der(&der).expect("Error with new_public_from_der()");
+    let mut rsa = RSA::new_public_from_der(&der, &wolfcrypt).expect("Error with new_public_from_der()");
     rsa.set_shared_rng(Rc::clone(&rng)).expect("Error with set_shared_rng()");
     let signature = &signature[0..sig_len];
     let mut verify_out: [u8; 512] = [0; 512];
@@ -110,11 +112,12 @@ fn test_rsa_pss() {
 #[test]
 #[cfg(rsa_direct)]
 fn test_rsa_direct() {
-    let rng = RNG::new().expect("Error creating RNG");
+    let wolfcrypt = common::setup();
+    let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
 
     let key_path = "../../../certs/client-key.der";
     let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    let mut rsa = RSA::new_from_der(&der).expect("Error with new_from_der()");
+    let mut rsa = RSA::new_from_der(&der, &wolfcrypt).expect("Error with new_from_der()");
     let msg = b"A rsa_direct() test input string";
     let mut plain = [0u8; 256];
     plain[..msg.len()].copy_from_slice(msg);
@@ -130,13 +133,13 @@ fn test_rsa_direct() {
 #[test]
 #[cfg(all(sha256, random, rsa_oaep))]
 fn test_rsa_oaep() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let rng = Rc::new(RNG::new().expect("Error creating RNG"));
+    let rng = Rc::new(RNG::new(&wolfcrypt).expect("Error creating RNG"));
 
     let key_path = "../../../certs/client-keyPub.der";
     let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    let mut rsa = RSA::new_public_from_der(&der).expect("Error with new_public_from_der()");
+    let mut rsa = RSA::new_public_from_der(&der, &wolfcrypt).expect("Error with new_public_from_der()");
     rsa.set_shared_rng(Rc::clone(&rng)).expect("Error with set_shared_rng()");
     let plain: &[u8] = b"OAEP plain text test message";
     let mut enc: [u8; 512] = [0; 512];
@@ -145,7 +148,7 @@ fn test_rsa_oaep() {
 
     let key_path = "../../../certs/client-key.der";
     let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    let mut rsa = RSA::new_from_der(&der).expect("Error with new_from_der()");
+    let mut rsa = RSA::new_from_der(&der, &wolfcrypt).expect("Error with new_from_der()");
     rsa.set_shared_rng(Rc::clone(&rng)).expect("Error with set_shared_rng()");
     let mut plain_out: [u8; 512] = [0; 512];
     let dec_len = rsa.private_decrypt_oaep(&enc[0..enc_len], &mut plain_out, RSA::HASH_TYPE_SHA256, RSA::MGF1SHA256).expect("Error with private_decrypt_oaep()");
@@ -161,14 +164,14 @@ fn test_rsa_oaep() {
 #[test]
 #[cfg(all(sha256, random, rsa_oaep))]
 fn test_rsa_oaep_with_label() {
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let rng = Rc::new(RNG::new().expect("Error creating RNG"));
+    let rng = Rc::new(RNG::new(&wolfcrypt).expect("Error creating RNG"));
     let label: &[u8] = b"a non-empty OAEP label";
 
     let key_path = "../../../certs/client-keyPub.der";
     let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    let mut rsa = RSA::new_public_from_der(&der).expect("Error with new_public_from_der()");
+    let mut rsa = RSA::new_public_from_der(&der, &wolfcrypt).expect("Error with new_public_from_der()");
     rsa.set_shared_rng(Rc::clone(&rng)).expect("Error with set_shared_rng()");
     let plain: &[u8] = b"OAEP with label";
     let mut enc: [u8; 512] = [0; 512];
@@ -177,7 +180,7 @@ fn test_rsa_oaep_with_label() {
 
     let key_path = "../../../certs/client-key.der";
     let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    let mut rsa = RSA::new_from_der(&der).expect("Error with new_from_der()");
+    let mut rsa = RSA::new_from_der(&der, &wolfcrypt).expect("Error with new_from_der()");
     rsa.set_shared_rng(Rc::clone(&rng)).expect("Error with set_shared_rng()");
 
     // Wrong label must fail.
@@ -194,11 +197,12 @@ fn test_rsa_oaep_with_label() {
 #[test]
 #[cfg(random)]
 fn test_rsa_ssl() {
-    let rng = Rc::new(RNG::new().expect("Error creating RNG"));
+    let wolfcrypt = common::setup();
+    let rng = Rc::new(RNG::new(&wolfcrypt).expect("Error creating RNG"));
 
     let key_path = "../../../certs/client-key.der";
     let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    let mut rsa = RSA::new_from_der(&der).expect("Error with new_from_der()");
+    let mut rsa = RSA::new_from_der(&der, &wolfcrypt).expect("Error with new_from_der()");
     let msg: &[u8] = b"This is the string to be signed!";
     let mut signature: [u8; 512] = [0; 512];
     let sig_len = rsa.ssl_sign(msg, &mut signature, &rng).expect("Error with ssl_sign()");
@@ -206,7 +210,7 @@ fn test_rsa_ssl() {
 
     let key_path = "../../../certs/client-keyPub.der";
     let der: Vec<u8> = fs::read(key_path).expect("Error reading key file");
-    let mut rsa = RSA::new_public_from_der(&der).expect("Error with new_public_from_der()");
+    let mut rsa = RSA::new_public_from_der(&der, &wolfcrypt).expect("Error with new_public_from_der()");
     rsa.set_shared_rng(Rc::clone(&rng)).expect("Error with set_shared_rng()");
     let signature = &signature[0..sig_len];
     let mut verify_out: [u8; 512] = [0; 512];
@@ -10,11 +10,11 @@ use wolfssl_wolfcrypt::random::RNG;
 fn test_rsa2048_sha256_oaep_round_trip() {
     use wolfssl_wolfcrypt::rsa_oaep::{Ciphertext, DecryptingKey, EncryptingKey, Sha256};
 
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let pad_rng = RNG::new().expect("RNG");
+    let pad_rng = RNG::new(&wolfcrypt).expect("RNG");
     let mut dk: DecryptingKey<Sha256, 256> =
-        DecryptingKey::generate(RNG::new().expect("RNG")).expect("generate 2048");
+        DecryptingKey::generate(RNG::new(&wolfcrypt).expect("RNG")).expect("generate 2048");
     let ek: EncryptingKey<Sha256, 256> = dk.encrypting_key().expect("encrypting_key");
 
     let msg = b"rsa oaep sha256 round trip test";
@@ -47,9 +47,9 @@ fn test_rsa2048_sha256_oaep_round_trip() {
 fn test_rsa2048_sha384_oaep_with_der_keys() {
     use wolfssl_wolfcrypt::rsa_oaep::{DecryptingKey, EncryptingKey, Sha384};
 
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let pad_rng = RNG::new().expect("RNG");
+    let pad_rng = RNG::new(&wolfcrypt).expect("RNG");
 
     let pub_der: Vec<u8> = fs::read("../../../certs/client-keyPub.der")
         .expect("read client-keyPub.der");
@@ -58,7 +58,7 @@ fn test_rsa2048_sha384_oaep_with_der_keys() {
 
     let ek: EncryptingKey<Sha384, 256> = EncryptingKey::from_public_der(&pub_der)
         .expect("EncryptingKey::from_public_der");
-    let mut dk: DecryptingKey<Sha384, 256> = DecryptingKey::from_private_der(&priv_der, RNG::new().expect("RNG"))
+    let mut dk: DecryptingKey<Sha384, 256> = DecryptingKey::from_private_der(&priv_der, RNG::new(&wolfcrypt).expect("RNG"))
         .expect("DecryptingKey::from_private_der");
 
     let msg = b"oaep sha384 + der keys";
@@ -73,11 +73,11 @@ fn test_rsa2048_sha384_oaep_with_der_keys() {
 fn test_oaep_label_round_trip_and_mismatch() {
     use wolfssl_wolfcrypt::rsa_oaep::{DecryptingKey, EncryptingKey, Sha256};
 
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let pad_rng = RNG::new().expect("RNG");
+    let pad_rng = RNG::new(&wolfcrypt).expect("RNG");
     let mut dk: DecryptingKey<Sha256, 256> =
-        DecryptingKey::generate(RNG::new().expect("RNG")).expect("generate 2048");
+        DecryptingKey::generate(RNG::new(&wolfcrypt).expect("RNG")).expect("generate 2048");
     let ek: EncryptingKey<Sha256, 256> = dk.encrypting_key().expect("encrypting_key");
 
     let msg = b"oaep with label";
@@ -103,15 +103,15 @@ fn test_oaep_modulus_size_mismatch_rejected() {
     use wolfssl_wolfcrypt::rsa::RSA;
     use wolfssl_wolfcrypt::rsa_oaep::{DecryptingKey, EncryptingKey, Sha256};
 
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let rng = RNG::new().expect("RNG");
-    let rsa2048 = RSA::generate(2048, 65537, &rng).expect("generate");
+    let rng = RNG::new(&wolfcrypt).expect("RNG");
+    let rsa2048 = RSA::generate(2048, 65537, &rng, &wolfcrypt).expect("generate");
 
     let ek_result: Result<EncryptingKey<Sha256, 384>, _> = EncryptingKey::from_rsa(&rsa2048);
     assert!(ek_result.is_err(), "encrypting key modulus mismatch must be rejected");
 
-    let dk_rng = RNG::new().expect("RNG");
+    let dk_rng = RNG::new(&wolfcrypt).expect("RNG");
     let dk_result: Result<DecryptingKey<Sha256, 384>, _> = DecryptingKey::from_rsa(rsa2048, dk_rng);
     assert!(dk_result.is_err(), "decrypting key modulus mismatch must be rejected");
 }
@@ -121,11 +121,11 @@ fn test_oaep_modulus_size_mismatch_rejected() {
 fn test_oaep_tampered_ciphertext_rejected() {
     use wolfssl_wolfcrypt::rsa_oaep::{DecryptingKey, Sha256};
 
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let pad_rng = RNG::new().expect("RNG");
+    let pad_rng = RNG::new(&wolfcrypt).expect("RNG");
     let mut dk: DecryptingKey<Sha256, 256> =
-        DecryptingKey::generate(RNG::new().expect("RNG")).expect("generate 2048");
+        DecryptingKey::generate(RNG::new(&wolfcrypt).expect("RNG")).expect("generate 2048");
     let ek = dk.encrypting_key().expect("encrypting_key");
 
     let msg = b"some bytes";
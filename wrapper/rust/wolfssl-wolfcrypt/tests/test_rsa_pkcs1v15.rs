@@ -10,9 +10,9 @@ use wolfssl_wolfcrypt::random::RNG;
 fn test_rsa2048_sha256_sign_verify() {
     use wolfssl_wolfcrypt::rsa_pkcs1v15::{Sha256, Signature, SigningKey, VerifyingKey};
 
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let rng = RNG::new().expect("RNG");
+    let rng = RNG::new(&wolfcrypt).expect("RNG");
     let mut sk: SigningKey<Sha256, 256> = SigningKey::generate(rng).expect("generate 2048");
 
     let msg = b"rsa pkcs1v15 sha256 signature trait test";
@@ -48,9 +48,9 @@ fn test_rsa2048_sha256_sign_verify() {
 fn test_rsa3072_sha384_sign_verify() {
     use wolfssl_wolfcrypt::rsa_pkcs1v15::{Sha384, Signature, SigningKey, VerifyingKey};
 
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let rng = RNG::new().expect("RNG");
+    let rng = RNG::new(&wolfcrypt).expect("RNG");
     let mut sk: SigningKey<Sha384, 384> = SigningKey::generate(rng).expect("generate 3072");
 
     let msg = b"rsa pkcs1v15 sha384 signature trait test";
@@ -71,10 +71,10 @@ fn test_modulus_size_mismatch_rejected() {
     use wolfssl_wolfcrypt::rsa::RSA;
     use wolfssl_wolfcrypt::rsa_pkcs1v15::{Sha256, SigningKey};
 
-    common::setup();
+    let wolfcrypt = common::setup();
 
-    let mut rng = RNG::new().expect("RNG");
-    let rsa2048 = RSA::generate(2048, 65537, &mut rng).expect("generate");
+    let mut rng = RNG::new(&wolfcrypt).expect("RNG");
+    let rsa2048 = RSA::generate(2048, 65537, &mut rng, &wolfcrypt).expect("generate");
     // Attempt to adopt a 2048-bit key as if it were 3072.
     let result: Result<SigningKey<Sha256, 384>, _> = SigningKey::from_rsa(rsa2048, rng);
     assert!(result.is_err(), "modulus size mismatch must be rejected");
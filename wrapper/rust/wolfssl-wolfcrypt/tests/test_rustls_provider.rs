@@ -0,0 +1,329 @@
+#![cfg(all(feature = "rustls", random, hmac, hkdf, sha256, aes_gcm, curve25519))]
+
+mod common;
+
+use std::fs;
+use std::io::{Read, Write};
+use std::sync::Arc;
+
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::crypto::{CryptoProvider, verify_tls12_signature, verify_tls13_signature};
+use rustls::pki_types::{
+    CertificateDer, PrivateKeyDer, PrivatePkcs1KeyDer, PrivatePkcs8KeyDer, PrivateSec1KeyDer,
+    ServerName, UnixTime,
+};
+use rustls::{
+    ClientConfig, ClientConnection, Connection, DigitallySignedStruct, NamedGroup,
+    ProtocolVersion, ServerConfig, ServerConnection, SignatureScheme,
+    SupportedCipherSuite, SupportedProtocolVersion,
+};
+use wolfssl_wolfcrypt::rustls_provider;
+
+const CERTS_DIR: &str = "../../../certs";
+
+fn read_der(name: &str) -> Vec<u8> {
+    let path = format!("{}/{}", CERTS_DIR, name);
+    fs::read(&path).expect("Error reading DER file")
+}
+
+fn rsa_chain() -> (Vec<CertificateDer<'static>>, PrivateKeyDer<'static>) {
+    let cert = CertificateDer::from(read_der("server-cert.der"));
+    let key = PrivateKeyDer::Pkcs1(PrivatePkcs1KeyDer::from(read_der("server-key.der")));
+    (vec![cert], key)
+}
+
+fn wolfcrypt() -> Arc<CryptoProvider> {
+    Arc::new(rustls_provider::provider())
+}
+
+fn ring() -> Arc<CryptoProvider> {
+    Arc::new(rustls::crypto::ring::default_provider())
+}
+
+fn client_config(provider: Arc<CryptoProvider>, versions: &[&'static SupportedProtocolVersion])
+        -> ClientConfig {
+    let cert = CertificateDer::from(read_der("server-cert.der"));
+    pinned_client_config(provider, versions, cert)
+}
+
+fn server_config(provider: Arc<CryptoProvider>, versions: &[&'static SupportedProtocolVersion],
+        chain: Vec<CertificateDer<'static>>, key: PrivateKeyDer<'static>) -> ServerConfig {
+    ServerConfig::builder_with_provider(provider)
+        .with_protocol_versions(versions)
+        .expect("Error with with_protocol_versions()")
+        .with_no_client_auth()
+        .with_single_cert(chain, key)
+        .expect("Error with with_single_cert()")
+}
+
+/// Move all pending TLS records from `from` to `to`.
+fn transfer(from: &mut Connection, to: &mut Connection) {
+    let mut buf = Vec::new();
+    while from.wants_write() {
+        from.write_tls(&mut buf).expect("Error with write_tls()");
+    }
+    let mut rd = &buf[..];
+    while !rd.is_empty() {
+        to.read_tls(&mut rd).expect("Error with read_tls()");
+        to.process_new_packets().expect("Error with process_new_packets()");
+    }
+}
+
+/// Run a handshake between `client` and `server` in memory, then send
+/// application data both ways.
+fn connect(client: ClientConfig, server: ServerConfig) -> (Connection, Connection) {
+    let name = ServerName::try_from("example.com").unwrap();
+    let mut client = Connection::from(ClientConnection::new(Arc::new(client), name)
+        .expect("Error with ClientConnection::new()"));
+    let mut server = Connection::from(ServerConnection::new(Arc::new(server))
+        .expect("Error with ServerConnection::new()"));
+
+    for _ in 0..16 {
+        if !client.is_handshaking() && !server.is_handshaking() {
+            break;
+        }
+        transfer(&mut client, &mut server);
+        transfer(&mut server, &mut client);
+    }
+    assert!(!client.is_handshaking());
+    assert!(!server.is_handshaking());
+
+    let mut buf = [0u8; 11];
+    client.writer().write_all(b"hello world").unwrap();
+    transfer(&mut client, &mut server);
+    server.reader().read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, b"hello world");
+
+    server.writer().write_all(b"dlrow olleh").unwrap();
+    transfer(&mut server, &mut client);
+    client.reader().read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, b"dlrow olleh");
+
+    (client, server)
+}
+
+/// Accepts one expected end-entity certificate without name or chain
+/// checks, but verifies handshake signatures with the client's provider.
+///
+/// The wolfSSL test certificates cannot be checked by the webpki verifier:
+/// server-cert.der is marked CA:TRUE and the ECC and Ed25519 certificates
+/// carry no subjectAltName.
+#[derive(Debug)]
+struct PinnedCert {
+    cert: CertificateDer<'static>,
+    provider: Arc<CryptoProvider>,
+}
+
+impl ServerCertVerifier for PinnedCert {
+    fn verify_server_cert(&self, end_entity: &CertificateDer<'_>,
+            _intermediates: &[CertificateDer<'_>], _server_name: &ServerName<'_>,
+            _ocsp_response: &[u8], _now: UnixTime) -> Result<ServerCertVerified, rustls::Error> {
+        assert_eq!(end_entity.as_ref(), self.cert.as_ref());
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(&self, message: &[u8], cert: &CertificateDer<'_>,
+            dss: &DigitallySignedStruct) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls12_signature(message, cert, dss,
+            &self.provider.signature_verification_algorithms)
+    }
+
+    fn verify_tls13_signature(&self, message: &[u8], cert: &CertificateDer<'_>,
+            dss: &DigitallySignedStruct) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls13_signature(message, cert, dss,
+            &self.provider.signature_verification_algorithms)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.provider.signature_verification_algorithms.supported_schemes()
+    }
+}
+
+fn pinned_client_config(provider: Arc<CryptoProvider>,
+        versions: &[&'static SupportedProtocolVersion], cert: CertificateDer<'static>)
+        -> ClientConfig {
+    let verifier = Arc::new(PinnedCert { cert, provider: provider.clone() });
+    ClientConfig::builder_with_provider(provider)
+        .with_protocol_versions(versions)
+        .expect("Error with with_protocol_versions()")
+        .dangerous()
+        .with_custom_certificate_verifier(verifier)
+        .with_no_client_auth()
+}
+
+fn with_suite(suite: SupportedCipherSuite) -> Arc<CryptoProvider> {
+    Arc::new(CryptoProvider {
+        cipher_suites: vec![suite],
+        ..rustls_provider::provider()
+    })
+}
+
+#[test]
+fn test_provider_contents() {
+    let provider = rustls_provider::provider();
+    assert_eq!(provider.cipher_suites.len(), rustls_provider::ALL_CIPHER_SUITES.len());
+    assert!(provider.cipher_suites.iter()
+        .any(|s| s.suite() == rustls::CipherSuite::TLS13_AES_128_GCM_SHA256));
+    assert!(provider.kx_groups.iter().any(|g| g.name() == NamedGroup::X25519));
+    #[cfg(mlkem)]
+    assert_eq!(provider.kx_groups[0].name(), NamedGroup::X25519MLKEM768);
+
+    let mut buf = [0u8; 32];
+    provider.secure_random.fill(&mut buf).expect("Error with fill()");
+    assert_ne!(buf, [0u8; 32]);
+}
+
+#[test]
+#[cfg(all(rsa, rsa_pss, rsa_const_api))]
+fn test_tls13_wolfcrypt_client_ring_server() {
+    common::setup();
+    let (chain, key) = rsa_chain();
+    let (client, _server) = connect(
+        client_config(wolfcrypt(), &[&rustls::version::TLS13]),
+        server_config(ring(), &[&rustls::version::TLS13], chain, key));
+    assert_eq!(client.protocol_version(), Some(ProtocolVersion::TLSv1_3));
+    // ring does not implement X25519MLKEM768, so the server picks the X25519
+    // share embedded in the hybrid key share.
+    assert_eq!(client.negotiated_key_exchange_group().unwrap().name(), NamedGroup::X25519);
+}
+
+#[test]
+#[cfg(all(rsa, rsa_pss, rsa_const_api))]
+fn test_tls13_ring_client_wolfcrypt_server() {
+    common::setup();
+    let (chain, key) = rsa_chain();
+    let (_client, server) = connect(
+        client_config(ring(), &[&rustls::version::TLS13]),
+        server_config(wolfcrypt(), &[&rustls::version::TLS13], chain, key));
+    assert_eq!(server.protocol_version(), Some(ProtocolVersion::TLSv1_3));
+}
+
+#[test]
+#[cfg(all(rsa, rsa_pss, rsa_const_api))]
+fn test_tls13_cipher_suites() {
+    common::setup();
+    for suite in rustls_provider::ALL_CIPHER_SUITES.iter()
+            .filter(|s| s.version() == &rustls::version::TLS13) {
+        let (chain, key) = rsa_chain();
+        let (client, _server) = connect(
+            client_config(with_suite(*suite), &[&rustls::version::TLS13]),
+            server_config(ring(), &[&rustls::version::TLS13], chain, key));
+        assert_eq!(client.negotiated_cipher_suite(), Some(*suite));
+    }
+}
+
+#[test]
+#[cfg(all(rsa, rsa_pss, rsa_const_api))]
+fn test_tls12_rsa_cipher_suites() {
+    common::setup();
+    for suite in rustls_provider::ALL_CIPHER_SUITES.iter()
+            .filter(|s| s.version() == &rustls::version::TLS12)
+            .filter(|s| format!("{:?}", s.suite()).contains("_RSA_")) {
+        // wolfCrypt client against a ring server.
+        let (chain, key) = rsa_chain();
+        let (client, _server) = connect(
+            client_config(with_suite(*suite), &[&rustls::version::TLS12]),
+            server_config(ring(), &[&rustls::version::TLS12], chain, key));
+        assert_eq!(client.negotiated_cipher_suite(), Some(*suite));
+        assert_eq!(client.protocol_version(), Some(ProtocolVersion::TLSv1_2));
+
+        // ring client against a wolfCrypt server.
+        let (chain, key) = rsa_chain();
+        let (_client, server) = connect(
+            client_config(ring(), &[&rustls::version::TLS12]),
+            server_config(with_suite(*suite), &[&rustls::version::TLS12], chain, key));
+        assert_eq!(server.negotiated_cipher_suite(), Some(*suite));
+    }
+}
+
+#[test]
+#[cfg(all(ecc, ecc_sign, ecc_verify, ecc_import))]
+fn test_tls12_ecdsa_cipher_suites() {
+    common::setup();
+    let cert = CertificateDer::from(read_der("server-ecc.der"));
+    for suite in rustls_provider::ALL_CIPHER_SUITES.iter()
+            .filter(|s| s.version() == &rustls::version::TLS12)
+            .filter(|s| format!("{:?}", s.suite()).contains("_ECDSA_")) {
+        let key = PrivateKeyDer::Sec1(PrivateSec1KeyDer::from(read_der("ecc-key.der")));
+        let (client, _server) = connect(
+            pinned_client_config(with_suite(*suite), &[&rustls::version::TLS12], cert.clone()),
+            server_config(wolfcrypt(), &[&rustls::version::TLS12], vec![cert.clone()], key));
+        assert_eq!(client.negotiated_cipher_suite(), Some(*suite));
+    }
+}
+
+#[test]
+#[cfg(all(ecc, ecc_sign, ecc_verify, ecc_import))]
+fn test_tls13_ecdsa_ring_client() {
+    common::setup();
+    let cert = CertificateDer::from(read_der("server-ecc.der"));
+    let key = PrivateKeyDer::Sec1(PrivateSec1KeyDer::from(read_der("ecc-key.der")));
+    connect(
+        pinned_client_config(ring(), &[&rustls::version::TLS13], cert.clone()),
+        server_config(wolfcrypt(), &[&rustls::version::TLS13], vec![cert], key));
+}
+
+#[test]
+#[cfg(all(ed25519, ed25519_sign, ed25519_import, ed25519_verify))]
+fn test_tls13_ed25519() {
+    common::setup();
+    let cert = CertificateDer::from(read_der("ed25519/server-ed25519.der"));
+    let key = PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(
+        read_der("ed25519/server-ed25519-priv.der")));
+    connect(
+        pinned_client_config(wolfcrypt(), &[&rustls::version::TLS13], cert.clone()),
+        server_config(wolfcrypt(), &[&rustls::version::TLS13], vec![cert], key));
+}
+
+#[test]
+#[cfg(all(rsa, rsa_pss, rsa_const_api, mlkem))]
+fn test_x25519mlkem768() {
+    common::setup();
+    let (chain, key) = rsa_chain();
+    let (client, server) = connect(
+        client_config(wolfcrypt(), &[&rustls::version::TLS13]),
+        server_config(wolfcrypt(), &[&rustls::version::TLS13], chain, key));
+    assert_eq!(client.negotiated_key_exchange_group().unwrap().name(),
+        NamedGroup::X25519MLKEM768);
+    assert_eq!(server.negotiated_key_exchange_group().unwrap().name(),
+        NamedGroup::X25519MLKEM768);
+}
+
+#[test]
+#[cfg(all(rsa, rsa_pss, rsa_const_api, ecc, ecc_dh, ecc_import, ecc_export))]
+fn test_ecdhe_groups() {
+    common::setup();
+    for group in [rustls_provider::kx_group::SECP256R1, rustls_provider::kx_group::SECP384R1] {
+        let provider = Arc::new(CryptoProvider {
+            kx_groups: vec![group],
+            ..rustls_provider::provider()
+        });
+        for version in [&rustls::version::TLS13, &rustls::version::TLS12] {
+            let (chain, key) = rsa_chain();
+            let (client, _server) = connect(
+                client_config(provider.clone(), &[version]),
+                server_config(ring(), &[version], chain, key));
+            assert_eq!(client.negotiated_key_exchange_group().unwrap().name(), group.name());
+        }
+    }
+}
+
+#[test]
+#[cfg(rsa)]
+fn test_load_private_key() {
+    common::setup();
+    let provider = rustls_provider::provider();
+    let (_, key) = rsa_chain();
+    let key = provider.key_provider.load_private_key(key)
+        .expect("Error with load_private_key()");
+    assert_eq!(key.algorithm(), rustls::SignatureAlgorithm::RSA);
+    assert!(key.choose_scheme(&[SignatureScheme::ED25519]).is_none());
+    let signer = key.choose_scheme(&[SignatureScheme::RSA_PKCS1_SHA256])
+        .expect("Error with choose_scheme()");
+    assert_eq!(signer.scheme(), SignatureScheme::RSA_PKCS1_SHA256);
+    let sig = signer.sign(b"message").expect("Error with sign()");
+    assert_eq!(sig.len(), 256);
+
+    let bad = PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(vec![0x30, 0x00]));
+    assert!(provider.key_provider.load_private_key(bad).is_err());
+}
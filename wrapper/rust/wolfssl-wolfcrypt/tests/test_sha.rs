@@ -1,9 +1,11 @@
 use wolfssl_wolfcrypt::sha::*;
+use wolfssl_wolfcrypt::WolfCrypt;
 
 #[test]
 #[cfg(sha)]
 fn test_sha() {
-    let mut sha = SHA::new().expect("Error with new()");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut sha = SHA::new(&wolfcrypt).expect("Error with new()");
     fn test1(sha: &mut SHA, input: &[u8], expected_hash: &[u8]) {
         sha.init().expect("Error with init()");
         sha.update(input).expect("Error with update()");
@@ -29,7 +31,8 @@ fn test_sha() {
 #[test]
 #[cfg(sha224)]
 fn test_sha224() {
-    let mut sha = SHA224::new().expect("Error with new()");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut sha = SHA224::new(&wolfcrypt).expect("Error with new()");
     fn test1(sha: &mut SHA224, input: &[u8], expected_hash: &[u8]) {
         sha.init().expect("Error with init()");
         sha.update(input).expect("Error with update()");
@@ -49,7 +52,8 @@ fn test_sha224() {
 #[test]
 #[cfg(sha256)]
 fn test_sha256() {
-    let mut sha = SHA256::new().expect("Error with new()");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut sha = SHA256::new(&wolfcrypt).expect("Error with new()");
     fn test1(sha: &mut SHA256, input: &[u8], expected_hash: &[u8]) {
         sha.init().expect("Error with init()");
         sha.update(input).expect("Error with update()");
@@ -75,7 +79,8 @@ fn test_sha256() {
 #[test]
 #[cfg(sha384)]
 fn test_sha384() {
-    let mut sha = SHA384::new().expect("Error with new()");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut sha = SHA384::new(&wolfcrypt).expect("Error with new()");
     fn test1(sha: &mut SHA384, input: &[u8], expected_hash: &[u8]) {
         sha.init().expect("Error with init()");
         sha.update(input).expect("Error with update()");
@@ -98,7 +103,8 @@ fn test_sha384() {
 #[test]
 #[cfg(sha512)]
 fn test_sha512() {
-    let mut sha = SHA512::new().expect("Error with new()");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut sha = SHA512::new(&wolfcrypt).expect("Error with new()");
     fn test1(sha: &mut SHA512, input: &[u8], expected_hash: &[u8]) {
         sha.init().expect("Error with init()");
         sha.update(input).expect("Error with update()");
@@ -118,10 +124,30 @@ fn test_sha512() {
         b"\x8e\x95\x9b\x75\xda\xe3\x13\xda\x8c\xf4\xf7\x28\x14\xfc\x14\x3f\x8f\x77\x79\xc6\xeb\x9f\x7f\xa1\x72\x99\xae\xad\xb6\x88\x90\x18\x50\x1d\x28\x9e\x49\x00\xf7\xe4\x33\x1b\x99\xde\xc4\xb5\x43\x3a\xc7\xd3\x29\xee\xb6\xdd\x26\x54\x5e\x96\xe5\x5b\x87\x4b\xe9\x09");
 }
 
+#[test]
+#[cfg(sha256)]
+fn test_sha256_try_clone() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut sha = SHA256::new(&wolfcrypt).expect("Error with new()");
+    sha.update(b"abc").expect("Error with update()");
+    let mut fork = sha.try_clone().expect("Error with try_clone()");
+    sha.update(b"def").expect("Error with update()");
+    let mut hash = [0u8; SHA256::DIGEST_SIZE];
+    fork.finalize(&mut hash).expect("Error with finalize()");
+    assert_eq!(hash, *b"\xBA\x78\x16\xBF\x8F\x01\xCF\xEA\x41\x41\x40\xDE\x5D\xAE\x22\x23\xB0\x03\x61\xA3\x96\x17\x7A\x9C\xB4\x10\xFF\x61\xF2\x00\x15\xAD");
+    let mut expected = SHA256::new(&wolfcrypt).expect("Error with new()");
+    expected.update(b"abcdef").expect("Error with update()");
+    let mut expected_hash = [0u8; SHA256::DIGEST_SIZE];
+    expected.finalize(&mut expected_hash).expect("Error with finalize()");
+    sha.finalize(&mut hash).expect("Error with finalize()");
+    assert_eq!(hash, expected_hash);
+}
+
 #[test]
 #[cfg(sha3)]
 fn test_sha3_224() {
-    let mut sha = SHA3_224::new().expect("Error with new()");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut sha = SHA3_224::new(&wolfcrypt).expect("Error with new()");
     fn test1(sha: &mut SHA3_224, input: &[u8], expected_hash: &[u8]) {
         sha.init().expect("Error with init()");
         sha.update(input).expect("Error with update()");
@@ -144,7 +170,8 @@ fn test_sha3_224() {
 #[test]
 #[cfg(sha3)]
 fn test_sha3_256() {
-    let mut sha = SHA3_256::new().expect("Error with new()");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut sha = SHA3_256::new(&wolfcrypt).expect("Error with new()");
     fn test1(sha: &mut SHA3_256, input: &[u8], expected_hash: &[u8]) {
         sha.init().expect("Error with init()");
         sha.update(input).expect("Error with update()");
@@ -167,7 +194,8 @@ fn test_sha3_256() {
 #[test]
 #[cfg(sha3)]
 fn test_sha3_384() {
-    let mut sha = SHA3_384::new().expect("Error with new()");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut sha = SHA3_384::new(&wolfcrypt).expect("Error with new()");
     fn test1(sha: &mut SHA3_384, input: &[u8], expected_hash: &[u8]) {
         sha.init().expect("Error with init()");
         sha.update(input).expect("Error with update()");
@@ -194,7 +222,8 @@ fn test_sha3_384() {
 #[test]
 #[cfg(sha3)]
 fn test_sha3_512() {
-    let mut sha = SHA3_512::new().expect("Error with new()");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut sha = SHA3_512::new(&wolfcrypt).expect("Error with new()");
     fn test1(sha: &mut SHA3_512, input: &[u8], expected_hash: &[u8]) {
         sha.init().expect("Error with init()");
         sha.update(input).expect("Error with update()");
@@ -217,7 +246,8 @@ fn test_sha3_512() {
 #[test]
 #[cfg(shake128)]
 fn test_shake128() {
-    let mut sha = SHAKE128::new().expect("Error with new()");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut sha = SHAKE128::new(&wolfcrypt).expect("Error with new()");
     fn test1(sha: &mut SHAKE128, input: &[u8], expected_hash: &[u8]) {
         sha.init().expect("Error with init()");
         sha.update(input).expect("Error with update()");
@@ -248,7 +278,8 @@ fn test_shake128() {
 #[test]
 #[cfg(shake128)]
 fn test_shake128_absorb_squeeze() {
-    let mut sha = SHAKE128::new().expect("Error with new()");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut sha = SHAKE128::new(&wolfcrypt).expect("Error with new()");
     fn test1(sha: &mut SHAKE128, input: &[u8], expected_squeeze_out: &[u8]) {
         sha.init().expect("Error with init()");
         sha.absorb(input).expect("Error with absorb()");
@@ -279,7 +310,8 @@ fn test_shake128_absorb_squeeze() {
 #[test]
 #[cfg(shake256)]
 fn test_shake256() {
-    let mut sha = SHAKE256::new().expect("Error with new()");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut sha = SHAKE256::new(&wolfcrypt).expect("Error with new()");
     fn test1(sha: &mut SHAKE256, input: &[u8], expected_hash: &[u8]) {
         sha.init().expect("Error with init()");
         sha.update(input).expect("Error with update()");
@@ -310,7 +342,8 @@ fn test_shake256() {
 #[test]
 #[cfg(shake256)]
 fn test_shake256_absorb_squeeze() {
-    let mut sha = SHAKE256::new().expect("Error with new()");
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let mut sha = SHAKE256::new(&wolfcrypt).expect("Error with new()");
     fn test1(sha: &mut SHAKE256, input: &[u8], expected_squeeze_out: &[u8]) {
         sha.init().expect("Error with init()");
         sha.absorb(input).expect("Error with absorb()");
@@ -0,0 +1,341 @@
+#![cfg(slhdsa)]
+
+mod common;
+
+use wolfssl_wolfcrypt::{Env, WolfCrypt};
+use wolfssl_wolfcrypt::slhdsa::SlhDsa;
+#[cfg(all(slhdsa_make_key, random))]
+use wolfssl_wolfcrypt::random::RNG;
+#[cfg(slhdsa_make_key)]
+use wolfssl_wolfcrypt::Error;
+#[cfg(slhdsa_make_key)]
+use wolfssl_wolfcrypt::sys;
+
+#[cfg(slhdsa_make_key)]
+const SK_SEED: [u8; 16] = [0x11; 16];
+#[cfg(slhdsa_make_key)]
+const SK_PRF: [u8; 16] = [0x22; 16];
+#[cfg(slhdsa_make_key)]
+const PK_SEED: [u8; 16] = [0x33; 16];
+
+#[test]
+fn test_new() {
+    let wolfcrypt = common::setup();
+    let key = SlhDsa::new(SlhDsa::SHAKE_128S, &wolfcrypt).expect("Error with new()");
+    assert_eq!(key.param(), SlhDsa::SHAKE_128S);
+    SlhDsa::new_ex(SlhDsa::SHAKE_256F, &Env::new(&wolfcrypt)).expect("Error with new_ex()");
+    assert!(SlhDsa::new(0xff, &wolfcrypt).is_err());
+}
+
+#[test]
+fn test_sizes() {
+    let wolfcrypt = common::setup();
+    let mut params = vec![
+        (SlhDsa::SHAKE_128S, SlhDsa::PUB_KEY_SIZE_128, SlhDsa::SIG_SIZE_128S),
+        (SlhDsa::SHAKE_128F, SlhDsa::PUB_KEY_SIZE_128, SlhDsa::SIG_SIZE_128F),
+        (SlhDsa::SHAKE_192S, SlhDsa::PUB_KEY_SIZE_192, SlhDsa::SIG_SIZE_192S),
+        (SlhDsa::SHAKE_192F, SlhDsa::PUB_KEY_SIZE_192, SlhDsa::SIG_SIZE_192F),
+        (SlhDsa::SHAKE_256S, SlhDsa::PUB_KEY_SIZE_256, SlhDsa::SIG_SIZE_256S),
+        (SlhDsa::SHAKE_256F, SlhDsa::PUB_KEY_SIZE_256, SlhDsa::SIG_SIZE_256F),
+    ];
+    #[cfg(slhdsa_sha2)]
+    params.extend_from_slice(&[
+        (SlhDsa::SHA2_128S, SlhDsa::PUB_KEY_SIZE_128, SlhDsa::SIG_SIZE_128S),
+        (SlhDsa::SHA2_128F, SlhDsa::PUB_KEY_SIZE_128, SlhDsa::SIG_SIZE_128F),
+        (SlhDsa::SHA2_192S, SlhDsa::PUB_KEY_SIZE_192, SlhDsa::SIG_SIZE_192S),
+        (SlhDsa::SHA2_192F, SlhDsa::PUB_KEY_SIZE_192, SlhDsa::SIG_SIZE_192F),
+        (SlhDsa::SHA2_256S, SlhDsa::PUB_KEY_SIZE_256, SlhDsa::SIG_SIZE_256S),
+        (SlhDsa::SHA2_256F, SlhDsa::PUB_KEY_SIZE_256, SlhDsa::SIG_SIZE_256F),
+    ]);
+    for (param, pub_size, sig_size) in params {
+        let key = SlhDsa::new(param, &wolfcrypt).expect("Error with new()");
+        assert_eq!(key.pub_size().expect("Error with pub_size()"), pub_size);
+        assert_eq!(key.sig_size().expect("Error with sig_size()"), sig_size);
+        #[cfg(slhdsa_make_key)]
+        assert_eq!(key.priv_size().expect("Error with priv_size()"), 2 * pub_size);
+    }
+}
+
+#[cfg(all(slhdsa_make_key, random))]
+fn sign_verify(param: u32, wolfcrypt: &WolfCrypt) {
+    let rng = RNG::new(wolfcrypt).expect("Error creating RNG");
+    let mut key = SlhDsa::generate(param, &rng, wolfcrypt).expect("Error with generate()");
+    key.check_key().expect("Error with check_key()");
+
+    let msg = b"Test message for SLH-DSA";
+    let ctx = b"test context";
+    let mut sig = vec![0u8; key.sig_size().expect("Error with sig_size()")];
+
+    let sig_len = key.sign_msg(msg, &mut sig, &rng).expect("Error with sign_msg()");
+    assert_eq!(sig_len, sig.len());
+    assert!(key.verify_msg(&sig, msg).expect("Error with verify_msg()"));
+    assert!(!key.verify_ctx_msg(&sig, ctx, msg).expect("Error with verify_ctx_msg()"));
+
+    let sig_len = key.sign_ctx_msg(ctx, msg, &mut sig, &rng).expect("Error with sign_ctx_msg()");
+    assert_eq!(sig_len, sig.len());
+    assert!(key.verify_ctx_msg(&sig, ctx, msg).expect("Error with verify_ctx_msg()"));
+    assert!(!key.verify_ctx_msg(&sig, b"other context", msg).expect("Error with verify_ctx_msg()"));
+    assert!(!key.verify_msg(&sig, msg).expect("Error with verify_msg()"));
+
+    let mut bad_msg = *msg;
+    bad_msg[0] ^= 0x01;
+    assert!(!key.verify_ctx_msg(&sig, ctx, &bad_msg).expect("Error with verify_ctx_msg()"));
+    sig[0] ^= 0x01;
+    assert!(!key.verify_ctx_msg(&sig, ctx, msg).expect("Error with verify_ctx_msg()"));
+    assert!(key.verify_ctx_msg(&sig[1..], ctx, msg).is_err());
+}
+
+#[test]
+#[cfg(all(slhdsa_make_key, random))]
+fn test_sign_verify_shake() {
+    let wolfcrypt = common::setup();
+    sign_verify(SlhDsa::SHAKE_128F, &wolfcrypt);
+}
+
+#[test]
+#[cfg(all(slhdsa_make_key, slhdsa_sha2, random))]
+fn test_sign_verify_sha2() {
+    let wolfcrypt = common::setup();
+    sign_verify(SlhDsa::SHA2_128F, &wolfcrypt);
+}
+
+#[test]
+#[cfg(all(slhdsa_make_key, random))]
+fn test_ctx_too_long() {
+    let wolfcrypt = common::setup();
+    let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut key = SlhDsa::generate(SlhDsa::SHAKE_128F, &rng, &wolfcrypt).expect("Error with generate()");
+    let ctx = [0u8; SlhDsa::MAX_CTX_SIZE + 1];
+    let mut sig = vec![0u8; SlhDsa::SIG_SIZE_128F];
+    assert_eq!(key.sign_ctx_msg(&ctx, b"msg", &mut sig, &rng),
+        Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E)));
+    assert_eq!(key.verify_ctx_msg(&sig, &ctx, b"msg"),
+        Err(Error::from(sys::wolfCrypt_ErrorCodes_BUFFER_E)));
+}
+
+#[test]
+#[cfg(slhdsa_make_key)]
+fn test_deterministic_and_with_random() {
+    let wolfcrypt = common::setup();
+    let mut key = SlhDsa::generate_from_seed(SlhDsa::SHAKE_128F, &SK_SEED, &SK_PRF, &PK_SEED, &wolfcrypt)
+        .expect("Error with generate_from_seed()");
+    let msg = b"Deterministic message";
+    let ctx = b"ctx";
+
+    let mut sig1 = vec![0u8; SlhDsa::SIG_SIZE_128F];
+    let mut sig2 = vec![0u8; SlhDsa::SIG_SIZE_128F];
+    key.sign_ctx_msg_deterministic(ctx, msg, &mut sig1)
+        .expect("Error with sign_ctx_msg_deterministic()");
+    key.sign_ctx_msg_deterministic(ctx, msg, &mut sig2)
+        .expect("Error with sign_ctx_msg_deterministic()");
+    assert_eq!(sig1, sig2);
+    assert!(key.verify_ctx_msg(&sig1, ctx, msg).expect("Error with verify_ctx_msg()"));
+
+    // Deterministic signing uses PK.seed as the signing randomness.
+    key.sign_ctx_msg_with_random(ctx, msg, &mut sig2, &PK_SEED)
+        .expect("Error with sign_ctx_msg_with_random()");
+    assert_eq!(sig1, sig2);
+
+    key.sign_ctx_msg_with_random(ctx, msg, &mut sig2, &[0x44; 16])
+        .expect("Error with sign_ctx_msg_with_random()");
+    assert_ne!(sig1, sig2);
+    assert!(key.verify_ctx_msg(&sig2, ctx, msg).expect("Error with verify_ctx_msg()"));
+
+    assert_eq!(key.sign_ctx_msg_with_random(ctx, msg, &mut sig2, &[0x44; 15]),
+        Err(Error::from(sys::wolfCrypt_ErrorCodes_BAD_LENGTH_E)));
+}
+
+#[test]
+#[cfg(all(slhdsa_make_key, random))]
+fn test_hedged_signatures_differ() {
+    let wolfcrypt = common::setup();
+    let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut key = SlhDsa::generate_from_seed(SlhDsa::SHAKE_128F, &SK_SEED, &SK_PRF, &PK_SEED, &wolfcrypt)
+        .expect("Error with generate_from_seed()");
+    let msg = b"Hedged message";
+    let mut sig1 = vec![0u8; SlhDsa::SIG_SIZE_128F];
+    let mut sig2 = vec![0u8; SlhDsa::SIG_SIZE_128F];
+    key.sign_msg(msg, &mut sig1, &rng).expect("Error with sign_msg()");
+    key.sign_msg(msg, &mut sig2, &rng).expect("Error with sign_msg()");
+    assert_ne!(sig1, sig2);
+    assert!(key.verify_msg(&sig1, msg).expect("Error with verify_msg()"));
+    assert!(key.verify_msg(&sig2, msg).expect("Error with verify_msg()"));
+}
+
+#[test]
+#[cfg(slhdsa_make_key)]
+fn test_sign_verify_hash() {
+    let wolfcrypt = common::setup();
+    let mut key = SlhDsa::generate_from_seed(SlhDsa::SHAKE_128F, &SK_SEED, &SK_PRF, &PK_SEED, &wolfcrypt)
+        .expect("Error with generate_from_seed()");
+    let hash_alg = sys::wc_HashType_WC_HASH_TYPE_SHA256;
+    let hash = [0x5au8; 32];
+    let ctx = b"hash ctx";
+    let mut sig = vec![0u8; SlhDsa::SIG_SIZE_128F];
+
+    key.sign_ctx_hash_deterministic(ctx, hash_alg, &hash, &mut sig)
+        .expect("Error with sign_ctx_hash_deterministic()");
+    assert!(key.verify_ctx_hash(&sig, ctx, hash_alg, &hash).expect("Error with verify_ctx_hash()"));
+    // A HashSLH-DSA signature is not a pure SLH-DSA signature over the digest.
+    assert!(!key.verify_ctx_msg(&sig, ctx, &hash).expect("Error with verify_ctx_msg()"));
+
+    let mut bad_hash = hash;
+    bad_hash[0] ^= 0x01;
+    assert!(!key.verify_ctx_hash(&sig, ctx, hash_alg, &bad_hash).expect("Error with verify_ctx_hash()"));
+
+    key.sign_ctx_hash_with_random(ctx, hash_alg, &hash, &mut sig, &[0x44; 16])
+        .expect("Error with sign_ctx_hash_with_random()");
+    assert!(key.verify_ctx_hash(&sig, ctx, hash_alg, &hash).expect("Error with verify_ctx_hash()"));
+
+    // The digest length must match the hash algorithm.
+    assert!(key.sign_ctx_hash_deterministic(ctx, hash_alg, &hash[..31], &mut sig).is_err());
+
+    #[cfg(random)]
+    {
+        let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+        key.sign_ctx_hash(ctx, hash_alg, &hash, &mut sig, &rng)
+            .expect("Error with sign_ctx_hash()");
+        assert!(key.verify_ctx_hash(&sig, ctx, hash_alg, &hash).expect("Error with verify_ctx_hash()"));
+    }
+}
+
+#[test]
+#[cfg(slhdsa_make_key)]
+fn test_sign_verify_internal() {
+    let wolfcrypt = common::setup();
+    let mut key = SlhDsa::generate_from_seed(SlhDsa::SHAKE_128F, &SK_SEED, &SK_PRF, &PK_SEED, &wolfcrypt)
+        .expect("Error with generate_from_seed()");
+    let msg = b"Internal message";
+    let ctx = b"internal ctx";
+
+    // M' = 0x00 || len(ctx) || ctx || msg for pure SLH-DSA.
+    let mut mprime = vec![0u8, ctx.len() as u8];
+    mprime.extend_from_slice(ctx);
+    mprime.extend_from_slice(msg);
+
+    let mut sig = vec![0u8; SlhDsa::SIG_SIZE_128F];
+    let mut sig_internal = vec![0u8; SlhDsa::SIG_SIZE_128F];
+    key.sign_ctx_msg_deterministic(ctx, msg, &mut sig)
+        .expect("Error with sign_ctx_msg_deterministic()");
+    key.sign_internal_deterministic(&mprime, &mut sig_internal)
+        .expect("Error with sign_internal_deterministic()");
+    assert_eq!(sig, sig_internal);
+    assert!(key.verify_internal(&sig, &mprime).expect("Error with verify_internal()"));
+
+    key.sign_internal_with_random(&mprime, &mut sig_internal, &[0x44; 16])
+        .expect("Error with sign_internal_with_random()");
+    assert!(key.verify_internal(&sig_internal, &mprime).expect("Error with verify_internal()"));
+    assert!(key.verify_ctx_msg(&sig_internal, ctx, msg).expect("Error with verify_ctx_msg()"));
+    assert!(!key.verify_internal(&sig_internal, msg).expect("Error with verify_internal()"));
+}
+
+#[test]
+#[cfg(slhdsa_make_key)]
+fn test_generate_from_seed() {
+    let wolfcrypt = common::setup();
+    let key1 = SlhDsa::generate_from_seed(SlhDsa::SHAKE_128F, &SK_SEED, &SK_PRF, &PK_SEED, &wolfcrypt)
+        .expect("Error with generate_from_seed()");
+    let key2 = SlhDsa::generate_from_seed(SlhDsa::SHAKE_128F, &SK_SEED, &SK_PRF, &PK_SEED, &wolfcrypt)
+        .expect("Error with generate_from_seed()");
+    let mut pub1 = [0u8; SlhDsa::PUB_KEY_SIZE_128];
+    let mut pub2 = [0u8; SlhDsa::PUB_KEY_SIZE_128];
+    key1.export_public(&mut pub1).expect("Error with export_public()");
+    key2.export_public(&mut pub2).expect("Error with export_public()");
+    assert_eq!(pub1, pub2);
+    // The public key starts with PK.seed.
+    assert_eq!(&pub1[..16], &PK_SEED);
+
+    assert!(SlhDsa::generate_from_seed(SlhDsa::SHAKE_128F, &SK_SEED[..15], &SK_PRF, &PK_SEED, &wolfcrypt).is_err());
+    assert!(SlhDsa::generate_from_seed(SlhDsa::SHAKE_192F, &SK_SEED, &SK_PRF, &PK_SEED, &wolfcrypt).is_err());
+}
+
+#[test]
+#[cfg(slhdsa_make_key)]
+fn test_import_export() {
+    let wolfcrypt = common::setup();
+    let mut key = SlhDsa::generate_from_seed(SlhDsa::SHAKE_128F, &SK_SEED, &SK_PRF, &PK_SEED, &wolfcrypt)
+        .expect("Error with generate_from_seed()");
+    let mut priv_buf = [0u8; SlhDsa::PRV_KEY_SIZE_128];
+    let mut pub_buf = [0u8; SlhDsa::PUB_KEY_SIZE_128];
+    let priv_len = key.export_private(&mut priv_buf).expect("Error with export_private()");
+    let pub_len = key.export_public(&mut pub_buf).expect("Error with export_public()");
+    assert_eq!(priv_len, SlhDsa::PRV_KEY_SIZE_128);
+    assert_eq!(pub_len, SlhDsa::PUB_KEY_SIZE_128);
+    assert_eq!(&priv_buf[..16], &SK_SEED);
+    assert_eq!(&priv_buf[32..], &pub_buf);
+
+    let mut short = [0u8; SlhDsa::PUB_KEY_SIZE_128 - 1];
+    assert!(key.export_public(&mut short).is_err());
+
+    let msg = b"Import/export message";
+    let mut sig = vec![0u8; SlhDsa::SIG_SIZE_128F];
+    key.sign_ctx_msg_deterministic(&[], msg, &mut sig)
+        .expect("Error with sign_ctx_msg_deterministic()");
+
+    let mut priv_key = SlhDsa::new(SlhDsa::SHAKE_128F, &wolfcrypt).expect("Error with new()");
+    priv_key.import_private(&priv_buf).expect("Error with import_private()");
+    priv_key.check_key().expect("Error with check_key()");
+    let mut sig2 = vec![0u8; SlhDsa::SIG_SIZE_128F];
+    priv_key.sign_ctx_msg_deterministic(&[], msg, &mut sig2)
+        .expect("Error with sign_ctx_msg_deterministic()");
+    assert_eq!(sig, sig2);
+
+    let mut pub_key = SlhDsa::new(SlhDsa::SHAKE_128F, &wolfcrypt).expect("Error with new()");
+    pub_key.import_public(&pub_buf).expect("Error with import_public()");
+    assert!(pub_key.verify_msg(&sig, msg).expect("Error with verify_msg()"));
+    assert!(pub_key.sign_ctx_msg_deterministic(&[], msg, &mut sig2).is_err());
+
+    let mut bad = SlhDsa::new(SlhDsa::SHAKE_128F, &wolfcrypt).expect("Error with new()");
+    assert!(bad.import_public(&pub_buf[..31]).is_err());
+    assert!(bad.import_private(&priv_buf[..63]).is_err());
+
+    // A private key whose public root does not match fails check_key().
+    priv_buf[63] ^= 0x01;
+    let mut bad = SlhDsa::new(SlhDsa::SHAKE_128F, &wolfcrypt).expect("Error with new()");
+    bad.import_private(&priv_buf).expect("Error with import_private()");
+    assert!(bad.check_key().is_err());
+}
+
+#[test]
+#[cfg(all(feature = "signature", slhdsa_make_key))]
+fn test_signature_traits() {
+    use signature::{Keypair, SignerMut, Verifier};
+    use wolfssl_wolfcrypt::slhdsa::{Signature, VerifyingKey};
+
+    let wolfcrypt = common::setup();
+
+    let mut key = SlhDsa::generate_from_seed(SlhDsa::SHAKE_128F, &SK_SEED, &SK_PRF, &PK_SEED, &wolfcrypt)
+        .expect("Error with generate_from_seed()");
+
+    let message = b"message to sign via RustCrypto signature trait";
+    let sig: Signature<{ SlhDsa::SIG_SIZE_128F }> = key.sign(message);
+
+    // SignerMut signs deterministically with an empty context.
+    let mut expected = vec![0u8; SlhDsa::SIG_SIZE_128F];
+    key.sign_ctx_msg_deterministic(&[], message, &mut expected)
+        .expect("Error with sign_ctx_msg_deterministic()");
+    assert_eq!(sig.as_ref(), expected.as_slice());
+
+    let bytes = sig.to_bytes();
+    let sig_round_trip = Signature::<{ SlhDsa::SIG_SIZE_128F }>::try_from(bytes.as_ref())
+        .expect("Signature::try_from bytes");
+    assert_eq!(sig, sig_round_trip);
+    assert!(Signature::<{ SlhDsa::SIG_SIZE_128F }>::try_from(&bytes[..bytes.len() - 1]).is_err());
+
+    // A signature size that does not match the parameter set is rejected.
+    let wrong: Result<Signature<{ SlhDsa::SIG_SIZE_128S }>, _> = key.try_sign(message);
+    assert!(wrong.is_err());
+
+    let vk: VerifyingKey = key.verifying_key();
+    assert_eq!(vk.param(), SlhDsa::SHAKE_128F);
+    vk.verify(message, &sig).expect("Verifier::verify failed");
+
+    let mut tampered = *message;
+    tampered[0] ^= 0x01;
+    assert!(vk.verify(&tampered, &sig).is_err());
+
+    let vk2 = VerifyingKey::from_bytes(SlhDsa::SHAKE_128F, vk.as_bytes())
+        .expect("VerifyingKey::from_bytes");
+    assert_eq!(vk, vk2);
+    assert!(VerifyingKey::from_bytes(SlhDsa::SHAKE_192F, vk.as_bytes()).is_err());
+}
@@ -0,0 +1,305 @@
+#![cfg(all(tsp, tsp_requester))]
+
+mod common;
+
+use wolfssl_wolfcrypt::tsp::*;
+use wolfssl_wolfcrypt::WolfCrypt;
+
+// 1.3.6.1.4.1.999.1 - test TSA policy.
+const POLICY: &[u8] = &[0x2b, 0x06, 0x01, 0x04, 0x01, 0x87, 0x67, 0x01];
+const NONCE: &[u8] = &[0xc3, 0x5a, 0x10, 0x42, 0x77, 0x08, 0x99, 0x01];
+const DATA: &[u8] = b"release-1.0.tar.gz";
+
+#[cfg(sha256)]
+fn sha256(data: &[u8], wolfcrypt: &WolfCrypt) -> [u8; 32] {
+    use wolfssl_wolfcrypt::sha::SHA256;
+    let mut digest = [0u8; SHA256::DIGEST_SIZE];
+    let mut sha = SHA256::new(wolfcrypt).expect("Error with SHA256::new()");
+    sha.update(data).expect("Error with update()");
+    sha.finalize(&mut digest).expect("Error with finalize()");
+    digest
+}
+
+#[test]
+#[cfg(sha256)]
+fn test_request_encode_decode() {
+    let wolfcrypt = common::setup();
+
+    let digest = sha256(DATA, &wolfcrypt);
+    let mut req = TimeStampReq::new(TimeStampReq::HASH_SHA256, &digest)
+        .expect("Error with TimeStampReq::new()");
+    assert_eq!(req.hash(), &digest);
+    assert_eq!(req.nonce(), None);
+    assert_eq!(req.policy(), None);
+    assert!(!req.cert_req());
+
+    req.set_nonce(NONCE).expect("Error with set_nonce()");
+    req.set_policy(POLICY).expect("Error with set_policy()");
+    req.set_cert_req(true);
+    let mut der = [0u8; 256];
+    let der_size = req.encode(&mut der).expect("Error with encode()");
+
+    let decoded = TimeStampReq::decode(&der[..der_size]).expect("Error with decode()");
+    assert_eq!(decoded.hash_type(), Ok(TimeStampReq::HASH_SHA256));
+    assert_eq!(decoded.hash(), &digest);
+    assert_eq!(decoded.nonce(), Some(NONCE));
+    assert_eq!(decoded.policy(), Some(POLICY));
+    assert!(decoded.cert_req());
+
+    // Leading zero bytes of a nonce are stripped.
+    req.set_nonce(&[0x00, 0x00, 0x12, 0x34]).expect("Error with set_nonce()");
+    assert_eq!(req.nonce(), Some(&[0x12, 0x34][..]));
+
+    let mut small = [0u8; 16];
+    assert!(req.encode(&mut small).is_err());
+    assert!(TimeStampReq::decode(&der[..der_size - 1]).is_err());
+}
+
+#[test]
+#[cfg(all(sha256, random))]
+fn test_request_generate_nonce() {
+    use wolfssl_wolfcrypt::random::RNG;
+    let wolfcrypt = common::setup();
+
+    let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut req = TimeStampReq::new(TimeStampReq::HASH_SHA256, &sha256(DATA, &wolfcrypt))
+        .expect("Error with TimeStampReq::new()");
+    req.generate_nonce(&rng, 8).expect("Error with generate_nonce()");
+    let nonce = req.nonce().expect("Error with nonce()");
+    assert_eq!(nonce.len(), 8);
+    assert!(nonce[0] != 0 && nonce[0] & 0x80 == 0);
+
+    assert!(req.generate_nonce(&rng, 0).is_err());
+    assert!(req.generate_nonce(&rng, TimeStampReq::MAX_NONCE_SIZE + 1).is_err());
+}
+
+#[test]
+fn test_request_invalid_args() {
+    common::setup();
+
+    // Digest size must match the hash type.
+    assert!(TimeStampReq::new(TimeStampReq::HASH_SHA256, &[0u8; 20]).is_err());
+    assert!(TimeStampReq::new(TimeStampReq::HASH_SHA512, &[0u8; 32]).is_err());
+
+    let mut req = TimeStampReq::new(TimeStampReq::HASH_SHA256, &[0x5au8; 32])
+        .expect("Error with TimeStampReq::new()");
+    assert!(req.set_nonce(&[]).is_err());
+    assert!(req.set_nonce(&[0x01; TimeStampReq::MAX_NONCE_SIZE + 1]).is_err());
+    assert!(req.set_policy(&[]).is_err());
+}
+
+#[cfg(all(sha256, random, tsp_responder, tsp_verifier, tsp_sign, tsp_verify))]
+mod tsa {
+    use super::*;
+    use std::fs;
+    use wolfssl_wolfcrypt::Error;
+    use wolfssl_wolfcrypt::random::RNG;
+    use wolfssl_wolfcrypt::sys;
+
+    const SERIAL: &[u8] = &[0x9a, 0x33];
+    const GEN_TIME: &str = "20260604120000Z";
+
+    /// Fake TSA: time-stamp a DER encoded request and return the DER
+    /// encoded response.
+    fn respond(req_der: &[u8], cert: &[u8], key: &[u8], key_type: i32,
+            tsa_name: Option<&[u8]>, wolfcrypt: &WolfCrypt) -> Vec<u8> {
+        let rng = RNG::new(wolfcrypt).expect("Error creating RNG");
+        let req = TimeStampReq::decode(req_der).expect("Error with decode()");
+        let mut tst_info = TstInfo::from_request(&req, POLICY, SERIAL, Some(GEN_TIME))
+            .expect("Error with from_request()");
+        tst_info.set_accuracy(1, 500, 0).expect("Error with set_accuracy()");
+        tst_info.set_ordering(true);
+        if let Some(name) = tsa_name {
+            tst_info.set_tsa(name).expect("Error with set_tsa()");
+        }
+        let mut token = [0u8; 8192];
+        let token_size = tst_info.sign(cert, key, key_type, TimeStampReq::HASH_SHA256,
+            &rng, &mut token).expect("Error with sign()");
+        let mut resp = TimeStampResp::new(TimeStampResp::STATUS_GRANTED, Some("OK"), 0)
+            .expect("Error with TimeStampResp::new()");
+        resp.set_token(&token[..token_size]).expect("Error with set_token()");
+        let mut resp_der = [0u8; 8192];
+        let resp_size = resp.encode(&mut resp_der).expect("Error with encode()");
+        resp_der[..resp_size].to_vec()
+    }
+
+    fn request(wolfcrypt: &WolfCrypt) -> (TimeStampReq, Vec<u8>) {
+        let mut req = TimeStampReq::new(TimeStampReq::HASH_SHA256, &sha256(DATA, wolfcrypt))
+            .expect("Error with TimeStampReq::new()");
+        req.set_nonce(NONCE).expect("Error with set_nonce()");
+        req.set_policy(POLICY).expect("Error with set_policy()");
+        req.set_cert_req(true);
+        let mut der = [0u8; 256];
+        let der_size = req.encode(&mut der).expect("Error with encode()");
+        (req, der[..der_size].to_vec())
+    }
+
+    #[test]
+    fn test_rsa_time_stamp() {
+        let wolfcrypt = common::setup();
+
+        let cert = fs::read("../../../certs/tsa-cert.der").expect("Error reading cert");
+        let key = fs::read("../../../certs/tsa-key.der").expect("Error reading key");
+        let (req, req_der) = request(&wolfcrypt);
+        let resp_der = respond(&req_der, &cert, &key, TstInfo::KEY_TYPE_RSA, None, &wolfcrypt);
+
+        let resp = TimeStampResp::decode(&resp_der).expect("Error with decode()");
+        assert_eq!(resp.status(), TimeStampResp::STATUS_GRANTED);
+        assert_eq!(resp.status_string(), Some("OK"));
+        assert_eq!(resp.fail_info(), 0);
+        assert!(resp.token().is_some());
+
+        let tst_info = resp.verify(Some(&cert)).expect("Error with verify()");
+        tst_info.check_request(&req).expect("Error with check_request()");
+        tst_info.verify_data(DATA).expect("Error with verify_data()");
+        assert_eq!(tst_info.version(), 1);
+        assert_eq!(tst_info.policy(), Some(POLICY));
+        assert_eq!(tst_info.hash_oid(), sys::Hash_Sum_SHA256h);
+        assert_eq!(tst_info.hash(), &sha256(DATA, &wolfcrypt));
+        assert_eq!(tst_info.serial(), Some(SERIAL));
+        assert_eq!(tst_info.gen_time(), Some(GEN_TIME));
+        assert_eq!(tst_info.accuracy(), (1, 500, 0));
+        assert!(tst_info.ordering());
+        assert_eq!(tst_info.nonce(), Some(NONCE));
+        assert_eq!(tst_info.tsa(), None);
+        assert!(tst_info.verify_data(b"other data").is_err());
+
+        let tst_info = resp.verify_data(Some(&cert), DATA).expect("Error with verify_data()");
+        assert_eq!(tst_info.serial(), Some(SERIAL));
+        assert_eq!(resp.verify_data(Some(&cert), b"other data").err(),
+            Some(Error::from(sys::wolfCrypt_ErrorCodes_TSP_VERIFY_E)));
+
+        // The certificate embedded in the token is used when none is given.
+        resp.verify(None).expect("Error with verify()");
+    }
+
+    #[test]
+    #[cfg(ecc)]
+    fn test_ecc_time_stamp() {
+        let wolfcrypt = common::setup();
+
+        let cert = fs::read("../../../certs/tsa-ecc-cert.der").expect("Error reading cert");
+        let key = fs::read("../../../certs/tsa-ecc-key.der").expect("Error reading key");
+        let (req, req_der) = request(&wolfcrypt);
+        let resp_der = respond(&req_der, &cert, &key, TstInfo::KEY_TYPE_ECC, None, &wolfcrypt);
+
+        let resp = TimeStampResp::decode(&resp_der).expect("Error with decode()");
+        let tst_info = resp.verify_data(Some(&cert), DATA).expect("Error with verify_data()");
+        tst_info.check_request(&req).expect("Error with check_request()");
+
+        // The signer must be the pinned TSA certificate.
+        let rsa_cert = fs::read("../../../certs/tsa-cert.der").expect("Error reading cert");
+        assert_eq!(resp.verify(Some(&rsa_cert)).err(),
+            Some(Error::from(sys::wolfCrypt_ErrorCodes_TSP_VERIFY_E)));
+    }
+
+    #[test]
+    fn test_check_request_mismatch() {
+        let wolfcrypt = common::setup();
+
+        let cert = fs::read("../../../certs/tsa-cert.der").expect("Error reading cert");
+        let key = fs::read("../../../certs/tsa-key.der").expect("Error reading key");
+        let (_, req_der) = request(&wolfcrypt);
+        let resp_der = respond(&req_der, &cert, &key, TstInfo::KEY_TYPE_RSA, None, &wolfcrypt);
+        let resp = TimeStampResp::decode(&resp_der).expect("Error with decode()");
+        let tst_info = resp.verify(Some(&cert)).expect("Error with verify()");
+
+        let mut other = TimeStampReq::new(TimeStampReq::HASH_SHA256, &sha256(DATA, &wolfcrypt))
+            .expect("Error with TimeStampReq::new()");
+        other.set_nonce(&[0x01, 0x02]).expect("Error with set_nonce()");
+        assert_eq!(tst_info.check_request(&other).err(),
+            Some(Error::from(sys::wolfCrypt_ErrorCodes_TSP_VERIFY_E)));
+
+        let other = TimeStampReq::new(TimeStampReq::HASH_SHA256, &sha256(b"other data", &wolfcrypt))
+            .expect("Error with TimeStampReq::new()");
+        assert_eq!(tst_info.check_request(&other).err(),
+            Some(Error::from(sys::wolfCrypt_ErrorCodes_TSP_VERIFY_E)));
+    }
+
+    #[test]
+    fn test_tsa_name() {
+        let wolfcrypt = common::setup();
+
+        // dNSName GeneralNames: the subjectAltName of the TSA certificate
+        // and another name.
+        let name: &[u8] = b"\x82\x0ftsa.wolfssl.com";
+        let other_name: &[u8] = b"\x82\x03tsa";
+        let cert = fs::read("../../../certs/tsa-cert.der").expect("Error reading cert");
+        let key = fs::read("../../../certs/tsa-key.der").expect("Error reading key");
+        let (req, req_der) = request(&wolfcrypt);
+        let mut tst_info = TstInfo::from_request(&req, POLICY, SERIAL, Some(GEN_TIME))
+            .expect("Error with from_request()");
+        tst_info.set_tsa(name).expect("Error with set_tsa()");
+        let mut der = [0u8; 512];
+        let der_size = tst_info.encode(&mut der).expect("Error with encode()");
+
+        let decoded = TstInfo::decode(&der[..der_size]).expect("Error with decode()");
+        assert_eq!(decoded.tsa(), Some(name));
+        decoded.check_tsa_name(name).expect("Error with check_tsa_name()");
+        assert!(decoded.check_tsa_name(other_name).is_err());
+        decoded.check_request(&req).expect("Error with check_request()");
+
+        let resp_der = respond(&req_der, &cert, &key, TstInfo::KEY_TYPE_RSA, Some(name), &wolfcrypt);
+        let resp = TimeStampResp::decode(&resp_der).expect("Error with decode()");
+        let tst_info = resp.verify(Some(&cert)).expect("Error with verify()");
+        tst_info.check_tsa_name(name).expect("Error with check_tsa_name()");
+
+        // A TSA name not matching the signer certificate fails to verify.
+        let resp_der = respond(&req_der, &cert, &key, TstInfo::KEY_TYPE_RSA,
+            Some(other_name), &wolfcrypt);
+        let resp = TimeStampResp::decode(&resp_der).expect("Error with decode()");
+        assert_eq!(resp.verify(Some(&cert)).err(),
+            Some(Error::from(sys::wolfCrypt_ErrorCodes_TSP_VERIFY_E)));
+    }
+
+    #[test]
+    fn test_bad_signer_cert() {
+        let wolfcrypt = common::setup();
+
+        // Signed with the TSA key but its certificate has a key usage not
+        // permitted for time-stamping.
+        let cert = fs::read("../../../certs/tsa-bad-ku-cert.der").expect("Error reading cert");
+        let key = fs::read("../../../certs/tsa-key.der").expect("Error reading key");
+        let (_, req_der) = request(&wolfcrypt);
+        let resp_der = respond(&req_der, &cert, &key, TstInfo::KEY_TYPE_RSA, None, &wolfcrypt);
+        let resp = TimeStampResp::decode(&resp_der).expect("Error with decode()");
+        assert_eq!(resp.verify(Some(&cert)).err(), Some(Error::from(sys::wolfCrypt_ErrorCodes_KEYUSAGE_E)));
+    }
+
+    #[test]
+    fn test_tampered_token() {
+        let wolfcrypt = common::setup();
+
+        let cert = fs::read("../../../certs/tsa-cert.der").expect("Error reading cert");
+        let key = fs::read("../../../certs/tsa-key.der").expect("Error reading key");
+        let (_, req_der) = request(&wolfcrypt);
+        let mut resp_der = respond(&req_der, &cert, &key, TstInfo::KEY_TYPE_RSA, None, &wolfcrypt);
+        // serialNumber INTEGER of the TSTInfo.
+        let serial: &[u8] = &[0x02, 0x03, 0x00, 0x9a, 0x33];
+        let pos = resp_der.windows(serial.len()).position(|w| w == serial)
+            .expect("Serial not found");
+        resp_der[pos + 4] ^= 0x01;
+        let resp = TimeStampResp::decode(&resp_der).expect("Error with decode()");
+        assert!(resp.verify(Some(&cert)).is_err());
+    }
+
+    #[test]
+    fn test_rejection() {
+        common::setup();
+
+        let resp = TimeStampResp::new(TimeStampResp::STATUS_REJECTION,
+            Some("unsupported algorithm"), TimeStampResp::FAIL_BAD_ALG)
+            .expect("Error with TimeStampResp::new()");
+        let mut der = [0u8; 256];
+        let der_size = resp.encode(&mut der).expect("Error with encode()");
+
+        let resp = TimeStampResp::decode(&der[..der_size]).expect("Error with decode()");
+        assert_eq!(resp.status(), TimeStampResp::STATUS_REJECTION);
+        assert_eq!(resp.status_string(), Some("unsupported algorithm"));
+        assert_eq!(resp.fail_info(), TimeStampResp::FAIL_BAD_ALG);
+        assert_eq!(resp.token(), None);
+        let cert = fs::read("../../../certs/tsa-cert.der").expect("Error reading cert");
+        assert_eq!(resp.verify(Some(&cert)).err(),
+            Some(Error::from(sys::wolfCrypt_ErrorCodes_TSP_VERIFY_E)));
+    }
+}
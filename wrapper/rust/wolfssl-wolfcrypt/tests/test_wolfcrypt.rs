@@ -1,7 +1,53 @@
 use wolfssl_wolfcrypt::*;
 
 #[test]
+#[allow(deprecated)]
 fn test_wolfcrypt_init_and_cleanup() {
     wolfcrypt_init().expect("Error with wolfcrypt_init()");
     wolfcrypt_cleanup().expect("Error with wolfcrypt_cleanup()");
 }
+
+#[test]
+fn test_wolfcrypt_guard() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let clone = wolfcrypt.clone();
+    drop(wolfcrypt);
+    #[cfg(sha256)]
+    let mut sha = sha::SHA256::new(&clone).expect("Error with SHA256::new()");
+    drop(clone);
+
+    /* The object keeps wolfCrypt initialized after the last guard is
+     * dropped. */
+    #[cfg(sha256)]
+    {
+        sha.update(b"abc").expect("Error with update()");
+        let mut hash = [0u8; sha::SHA256::DIGEST_SIZE];
+        sha.finalize(&mut hash).expect("Error with finalize()");
+        assert_eq!(hash[..4], [0xba, 0x78, 0x16, 0xbf]);
+    }
+}
+
+#[test]
+fn test_wolfcrypt_env() {
+    let wolfcrypt = WolfCrypt::new().expect("Error with WolfCrypt::new()");
+    let env = Env::new(&wolfcrypt);
+    drop(wolfcrypt);
+    let _clone = env.wolfcrypt().clone();
+    #[cfg(sha256)]
+    {
+        let mut sha = sha::SHA256::new_ex(&env).expect("Error with SHA256::new_ex()");
+        drop(env);
+        sha.update(b"abc").expect("Error with update()");
+    }
+}
+
+#[test]
+fn test_wolfcrypt_global() {
+    let wolfcrypt = WolfCrypt::global().expect("Error with WolfCrypt::global()");
+    let again = WolfCrypt::global().expect("Error with WolfCrypt::global()");
+    assert!(core::ptr::eq(wolfcrypt, again));
+    let clone = wolfcrypt.clone();
+    drop(clone);
+    #[cfg(sha256)]
+    sha::SHA256::new(wolfcrypt).expect("Error with SHA256::new()");
+}
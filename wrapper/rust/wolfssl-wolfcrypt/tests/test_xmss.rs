@@ -0,0 +1,254 @@
+#![cfg(xmss)]
+
+mod common;
+
+use wolfssl_wolfcrypt::{Env, WolfCrypt};
+use wolfssl_wolfcrypt::xmss::Xmss;
+#[cfg(all(xmss_make_key, lms_make_key, feature = "alloc"))]
+use wolfssl_wolfcrypt::lms::MemoryStateStore;
+#[cfg(all(xmss_make_key, random))]
+use wolfssl_wolfcrypt::random::RNG;
+#[cfg(xmss_make_key)]
+use wolfssl_wolfcrypt::sys;
+
+const PARAM: &str = Xmss::XMSS_SHA2_10_256;
+const PARAM_MT: &str = Xmss::XMSSMT_SHA2_20_4_256;
+
+#[test]
+fn test_new() {
+    let wolfcrypt = common::setup();
+    Xmss::new(&wolfcrypt).expect("Error with Xmss::new()");
+    Xmss::new_ex(&Env::new(&wolfcrypt)).expect("Error with Xmss::new_ex()");
+}
+
+#[test]
+fn test_set_param_str() {
+    let wolfcrypt = common::setup();
+    let mut key = Xmss::new(&wolfcrypt).expect("Error with Xmss::new()");
+    key.set_param_str(PARAM).expect("Error with set_param_str()");
+    // The parameter set can only be selected once.
+    assert!(key.set_param_str(PARAM).is_err());
+
+    for bad in ["", "XMSS-SHA2_11_256", "XMSS-SHA2_10_256\0", "XMSSMT-SHA2_20/4_256_TOO_LONG_NAME"] {
+        let mut key = Xmss::new(&wolfcrypt).expect("Error with Xmss::new()");
+        assert!(key.set_param_str(bad).is_err(), "{bad:?} accepted");
+    }
+}
+
+#[test]
+#[cfg(xmss_get_param_str)]
+fn test_get_param_str() {
+    let wolfcrypt = common::setup();
+    for param in [PARAM, PARAM_MT] {
+        let mut key = Xmss::new(&wolfcrypt).expect("Error with Xmss::new()");
+        assert!(key.get_param_str().is_err());
+        key.set_param_str(param).expect("Error with set_param_str()");
+        assert_eq!(key.get_param_str().expect("Error with get_param_str()"), param);
+    }
+}
+
+#[test]
+fn test_sizes() {
+    let wolfcrypt = common::setup();
+    let mut key = Xmss::new(&wolfcrypt).expect("Error with Xmss::new()");
+    assert!(key.get_sig_len().is_err());
+    key.set_param_str(PARAM).expect("Error with set_param_str()");
+    // RFC 8391: 4-byte index + n + len * n + h * n, with n = 32, len = 67.
+    assert_eq!(key.get_sig_len().expect("Error with get_sig_len()"), 4 + 32 + 67 * 32 + 10 * 32);
+    // RFC 8391: 4-byte OID + root + seed.
+    assert_eq!(key.get_pub_len().expect("Error with get_pub_len()"), 4 + 2 * 32);
+
+    let mut key = Xmss::new(&wolfcrypt).expect("Error with Xmss::new()");
+    key.set_param_str(PARAM_MT).expect("Error with set_param_str()");
+    // XMSS^MT: 3-byte index + n + d * len * n + h * n.
+    assert_eq!(key.get_sig_len().expect("Error with get_sig_len()"), 3 + 32 + 4 * 67 * 32 + 20 * 32);
+}
+
+/// Private key NV storage for the raw callback test.
+#[cfg(xmss_make_key)]
+struct KeyStore {
+    buf: [u8; 2048],
+    len: usize,
+}
+
+#[cfg(xmss_make_key)]
+unsafe extern "C" fn write_key_cb(
+    priv_data: *const u8,
+    priv_sz: u32,
+    ctx: *mut core::ffi::c_void,
+) -> sys::wc_XmssRc {
+    let store = unsafe { &mut *(ctx as *mut KeyStore) };
+    let priv_sz = priv_sz as usize;
+    if priv_sz > store.buf.len() {
+        return sys::wc_XmssRc_WC_XMSS_RC_WRITE_FAIL;
+    }
+    store.buf[..priv_sz]
+        .copy_from_slice(unsafe { core::slice::from_raw_parts(priv_data, priv_sz) });
+    store.len = priv_sz;
+    sys::wc_XmssRc_WC_XMSS_RC_SAVED_TO_NV_MEMORY
+}
+
+#[cfg(xmss_make_key)]
+unsafe extern "C" fn read_key_cb(
+    priv_data: *mut u8,
+    priv_sz: u32,
+    ctx: *mut core::ffi::c_void,
+) -> sys::wc_XmssRc {
+    let store = unsafe { &*(ctx as *mut KeyStore) };
+    let priv_sz = priv_sz as usize;
+    if priv_sz != store.len {
+        return sys::wc_XmssRc_WC_XMSS_RC_READ_FAIL;
+    }
+    unsafe { core::slice::from_raw_parts_mut(priv_data, priv_sz) }
+        .copy_from_slice(&store.buf[..priv_sz]);
+    sys::wc_XmssRc_WC_XMSS_RC_READ_TO_MEMORY
+}
+
+#[test]
+#[cfg(all(xmss_make_key, random))]
+fn test_raw_callbacks() {
+    let wolfcrypt = common::setup();
+    let rng = RNG::new(&wolfcrypt).expect("Error creating RNG");
+    let mut store = Box::new(KeyStore { buf: [0u8; 2048], len: 0 });
+    let ctx = store.as_mut() as *mut KeyStore as *mut core::ffi::c_void;
+
+    let mut key = Xmss::new(&wolfcrypt).expect("Error with Xmss::new()");
+    key.set_param_str(PARAM).expect("Error with set_param_str()");
+    key.set_write_cb(Some(write_key_cb)).expect("Error with set_write_cb()");
+    key.set_read_cb(Some(read_key_cb)).expect("Error with set_read_cb()");
+    // Safety: store outlives all callback invocations.
+    unsafe { key.set_context(ctx).expect("Error with set_context()") };
+    key.make_key(&rng).expect("Error with make_key()");
+    assert_eq!(store.len, key.get_priv_len().expect("Error with get_priv_len()"));
+
+    let message = b"XMSS raw callback message";
+    let mut sig = vec![0u8; key.get_sig_len().expect("Error with get_sig_len()")];
+    let written = key.sign(message, &mut sig).expect("Error with sign()");
+    assert_eq!(written, sig.len());
+    key.verify(&sig, message).expect("Error with verify()");
+
+    let mut short_sig = vec![0u8; sig.len() - 1];
+    assert!(key.sign(message, &mut short_sig).is_err());
+    assert!(key.sign(b"", &mut sig).is_err());
+    assert!(key.verify(&sig[..sig.len() - 1], message).is_err());
+
+    drop(key);
+    let _ = store;
+}
+
+#[cfg(all(xmss_make_key, lms_make_key, random, feature = "alloc"))]
+fn make_key(param: &str, store: MemoryStateStore, wolfcrypt: &WolfCrypt) -> Xmss<'static> {
+    let rng = RNG::new(wolfcrypt).expect("Error creating RNG");
+    let mut key = Xmss::new(wolfcrypt).expect("Error with Xmss::new()");
+    key.set_param_str(param).expect("Error with set_param_str()");
+    key.set_state_store(store).expect("Error with set_state_store()");
+    key.make_key(&rng).expect("Error with make_key()");
+    key
+}
+
+#[test]
+#[cfg(all(xmss_make_key, lms_make_key, random, feature = "alloc"))]
+fn test_sign_verify() {
+    let wolfcrypt = common::setup();
+    for param in [PARAM, PARAM_MT] {
+        let store = MemoryStateStore::new();
+        let mut key = make_key(param, store.clone(), &wolfcrypt);
+        assert!(key.has_sigs_left().expect("Error with has_sigs_left()"));
+
+        let message = b"XMSS sign/verify message";
+        let mut sig = vec![0u8; key.get_sig_len().expect("Error with get_sig_len()")];
+        let state = store.state();
+        key.sign(message, &mut sig).expect("Error with sign()");
+        assert_ne!(state, store.state(), "state not advanced by sign()");
+        key.verify(&sig, message).expect("Error with verify()");
+
+        // A second signature uses the next one-time key.
+        let mut sig2 = vec![0u8; sig.len()];
+        key.sign(message, &mut sig2).expect("Error with sign()");
+        assert_ne!(sig, sig2);
+        key.verify(&sig2, message).expect("Error with verify()");
+
+        assert!(key.verify(&sig, b"XMSS sign/verify messagf").is_err());
+        let last = sig.len() - 1;
+        sig[last] ^= 0x01;
+        assert!(key.verify(&sig, message).is_err());
+    }
+}
+
+#[test]
+#[cfg(all(xmss_make_key, lms_make_key, random, feature = "alloc"))]
+fn test_verify_only() {
+    let wolfcrypt = common::setup();
+    let mut key = make_key(PARAM, MemoryStateStore::new(), &wolfcrypt);
+    let message = b"XMSS verify-only message";
+    let mut sig = vec![0u8; key.get_sig_len().expect("Error with get_sig_len()")];
+    key.sign(message, &mut sig).expect("Error with sign()");
+
+    let mut pub_buf = vec![0u8; key.get_pub_len().expect("Error with get_pub_len()")];
+    let written = key.export_pub_raw(&mut pub_buf).expect("Error with export_pub_raw()");
+    assert_eq!(written, pub_buf.len());
+    assert!(key.export_pub_raw(&mut [0u8; 4]).is_err());
+
+    let mut vkey = Xmss::new(&wolfcrypt).expect("Error with Xmss::new()");
+    vkey.set_param_str(PARAM).expect("Error with set_param_str()");
+    assert!(vkey.import_pub_raw(&pub_buf[..pub_buf.len() - 1]).is_err());
+    vkey.import_pub_raw(&pub_buf).expect("Error with import_pub_raw()");
+    vkey.verify(&sig, message).expect("Error with verify()");
+    let mut sig2 = vec![0u8; sig.len()];
+    assert!(vkey.sign(message, &mut sig2).is_err());
+
+    let mut vkey = Xmss::new(&wolfcrypt).expect("Error with Xmss::new()");
+    vkey.export_pub_from(&key).expect("Error with export_pub_from()");
+    vkey.verify(&sig, message).expect("Error with verify()");
+}
+
+#[test]
+#[cfg(all(xmss_import_pub_raw_ex, xmss_make_key, lms_make_key, random, feature = "alloc"))]
+fn test_import_pub_raw_ex() {
+    let wolfcrypt = common::setup();
+    let mut key = make_key(PARAM_MT, MemoryStateStore::new(), &wolfcrypt);
+    let message = b"XMSS^MT import message";
+    let mut sig = vec![0u8; key.get_sig_len().expect("Error with get_sig_len()")];
+    key.sign(message, &mut sig).expect("Error with sign()");
+    let mut pub_buf = vec![0u8; key.get_pub_len().expect("Error with get_pub_len()")];
+    key.export_pub_raw(&mut pub_buf).expect("Error with export_pub_raw()");
+
+    // No parameter set selected: derived from the OID.
+    let mut vkey = Xmss::new(&wolfcrypt).expect("Error with Xmss::new()");
+    vkey.import_pub_raw_ex(&pub_buf, true).expect("Error with import_pub_raw_ex()");
+    #[cfg(xmss_get_param_str)]
+    assert_eq!(vkey.get_param_str().expect("Error with get_param_str()"), PARAM_MT);
+    vkey.verify(&sig, message).expect("Error with verify()");
+
+    // The OID must match a pre-selected parameter set.
+    let mut vkey = Xmss::new(&wolfcrypt).expect("Error with Xmss::new()");
+    vkey.set_param_str(Xmss::XMSSMT_SHA2_20_2_256).expect("Error with set_param_str()");
+    assert!(vkey.import_pub_raw_ex(&pub_buf, true).is_err());
+}
+
+#[test]
+#[cfg(all(xmss_make_key, lms_make_key, random, feature = "alloc"))]
+fn test_reload() {
+    let wolfcrypt = common::setup();
+    let store = MemoryStateStore::new();
+    let mut key = make_key(PARAM, store.clone(), &wolfcrypt);
+    let message = b"XMSS reload message";
+    let mut sig = vec![0u8; key.get_sig_len().expect("Error with get_sig_len()")];
+    key.sign(message, &mut sig).expect("Error with sign()");
+    let mut pub_buf = vec![0u8; key.get_pub_len().expect("Error with get_pub_len()")];
+    key.export_pub_raw(&mut pub_buf).expect("Error with export_pub_raw()");
+    drop(key);
+
+    let mut reloaded = Xmss::new(&wolfcrypt).expect("Error with Xmss::new()");
+    reloaded.set_param_str(PARAM).expect("Error with set_param_str()");
+    reloaded.set_state_store(store.clone()).expect("Error with set_state_store()");
+    reloaded.reload().expect("Error with reload()");
+    let mut sig2 = vec![0u8; sig.len()];
+    reloaded.sign(message, &mut sig2).expect("Error with sign() after reload");
+    assert_ne!(sig, sig2);
+
+    let mut vkey = Xmss::new(&wolfcrypt).expect("Error with Xmss::new()");
+    vkey.set_param_str(PARAM).expect("Error with set_param_str()");
+    vkey.import_pub_raw(&pub_buf).expect("Error with import_pub_raw()");
+    vkey.verify(&sig2, message).expect("Error with verify()");
+}